@@ -0,0 +1,5 @@
+DROP INDEX idx_work_sessions_unbilled;
+ALTER TABLE work_sessions DROP COLUMN invoice_id;
+DROP TABLE invoice_line_items;
+DROP TABLE invoices;
+DROP TYPE invoice_status;
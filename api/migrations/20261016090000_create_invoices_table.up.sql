@@ -0,0 +1,33 @@
+CREATE TYPE invoice_status AS ENUM ('draft', 'finalized');
+
+CREATE TABLE invoices (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    company_id UUID NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    status invoice_status NOT NULL DEFAULT 'draft',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE TABLE invoice_line_items (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    invoice_id UUID NOT NULL REFERENCES invoices(id) ON DELETE CASCADE,
+    job_id UUID NOT NULL REFERENCES jobs(id) ON DELETE CASCADE,
+    description VARCHAR NOT NULL,
+    session_count INTEGER NOT NULL,
+    duration_seconds BIGINT NOT NULL,
+    rounded_hours DECIMAL NOT NULL,
+    hourly_rate DECIMAL,
+    amount DECIMAL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT chk_line_item_amount_requires_rate CHECK (
+        (hourly_rate IS NULL AND amount IS NULL)
+        OR
+        (hourly_rate IS NOT NULL AND amount IS NOT NULL)
+    )
+);
+
+ALTER TABLE work_sessions
+    ADD COLUMN invoice_id UUID REFERENCES invoices(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_work_sessions_unbilled ON work_sessions (user_id, job_id) WHERE invoice_id IS NULL;
@@ -0,0 +1,3 @@
+ALTER TABLE work_sessions DROP COLUMN approval_id;
+DROP TABLE timesheet_approvals;
+DROP TYPE approval_status;
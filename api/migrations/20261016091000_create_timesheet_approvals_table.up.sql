@@ -0,0 +1,29 @@
+CREATE TYPE approval_status AS ENUM ('pending', 'approved', 'disputed');
+
+CREATE TABLE timesheet_approvals (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    company_id UUID NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    period_start DATE NOT NULL,
+    period_end DATE NOT NULL,
+    token_hash VARCHAR NOT NULL UNIQUE,
+    status approval_status NOT NULL DEFAULT 'pending',
+    approver_email VARCHAR,
+    dispute_reason TEXT,
+    responded_at TIMESTAMPTZ,
+    expires_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT chk_period_end_after_start CHECK (period_end >= period_start),
+    CONSTRAINT chk_response_recorded CHECK (
+        (status = 'pending' AND responded_at IS NULL)
+        OR
+        (status != 'pending' AND responded_at IS NOT NULL AND approver_email IS NOT NULL)
+    ),
+    CONSTRAINT chk_dispute_reason_for_disputes CHECK (
+        status = 'disputed' OR dispute_reason IS NULL
+    )
+);
+
+ALTER TABLE work_sessions
+    ADD COLUMN approval_id UUID REFERENCES timesheet_approvals(id) ON DELETE SET NULL;
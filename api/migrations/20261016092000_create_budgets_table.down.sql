@@ -0,0 +1,3 @@
+DROP TABLE budget_alerts;
+DROP TABLE budgets;
+DROP TYPE budget_type;
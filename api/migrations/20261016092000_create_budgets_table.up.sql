@@ -0,0 +1,27 @@
+CREATE TYPE budget_type AS ENUM ('hours', 'amount');
+
+CREATE TABLE budgets (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    company_id UUID REFERENCES companies(id) ON DELETE CASCADE,
+    job_id UUID REFERENCES jobs(id) ON DELETE CASCADE,
+    budget_type budget_type NOT NULL,
+    monthly_limit DECIMAL NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT chk_budget_single_target CHECK (
+        (company_id IS NOT NULL AND job_id IS NULL)
+        OR
+        (company_id IS NULL AND job_id IS NOT NULL)
+    ),
+    CONSTRAINT chk_budget_positive_limit CHECK (monthly_limit > 0)
+);
+
+CREATE TABLE budget_alerts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    budget_id UUID NOT NULL REFERENCES budgets(id) ON DELETE CASCADE,
+    period_month DATE NOT NULL,
+    threshold_percent INTEGER NOT NULL,
+    sent_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    UNIQUE(budget_id, period_month, threshold_percent)
+);
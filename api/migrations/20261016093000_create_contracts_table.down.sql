@@ -0,0 +1,2 @@
+DROP TABLE contracts;
+DROP TYPE contract_rate_type;
@@ -0,0 +1,35 @@
+CREATE TYPE contract_rate_type AS ENUM ('hourly', 'fixed', 'retainer');
+
+CREATE TABLE contracts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    company_id UUID NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    title VARCHAR(255) NOT NULL,
+    start_date DATE NOT NULL,
+    end_date DATE,
+    rate_type contract_rate_type NOT NULL,
+    rate_amount DECIMAL NOT NULL,
+    terms TEXT,
+    renewal_reminder_days INTEGER,
+    renewal_reminder_sent_at TIMESTAMPTZ,
+    document_file_name VARCHAR(255),
+    document_content_type VARCHAR(255),
+    document_data BYTEA,
+    document_uploaded_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT chk_contract_dates CHECK (end_date IS NULL OR end_date >= start_date),
+    CONSTRAINT chk_contract_rate_amount CHECK (rate_amount >= 0),
+    CONSTRAINT chk_contract_reminder_days CHECK (
+        renewal_reminder_days IS NULL OR renewal_reminder_days > 0
+    ),
+    CONSTRAINT chk_contract_document CHECK (
+        (document_data IS NULL AND document_file_name IS NULL AND document_content_type IS NULL)
+        OR
+        (document_data IS NOT NULL AND document_file_name IS NOT NULL AND document_content_type IS NOT NULL)
+    )
+);
+
+CREATE INDEX idx_contracts_company_id ON contracts(company_id);
+CREATE INDEX idx_contracts_renewal_due ON contracts(end_date)
+    WHERE renewal_reminder_days IS NOT NULL AND renewal_reminder_sent_at IS NULL;
@@ -0,0 +1,12 @@
+CREATE TABLE time_off (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    start_date DATE NOT NULL,
+    end_date DATE NOT NULL,
+    note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT chk_time_off_dates CHECK (end_date >= start_date)
+);
+
+CREATE INDEX idx_time_off_user_dates ON time_off(user_id, start_date, end_date);
@@ -0,0 +1,2 @@
+ALTER TABLE work_sessions DROP COLUMN toggl_id;
+ALTER TABLE jobs DROP COLUMN toggl_id;
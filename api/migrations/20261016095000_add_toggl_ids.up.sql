@@ -0,0 +1,2 @@
+ALTER TABLE jobs ADD COLUMN toggl_id BIGINT GENERATED ALWAYS AS IDENTITY UNIQUE;
+ALTER TABLE work_sessions ADD COLUMN toggl_id BIGINT GENERATED ALWAYS AS IDENTITY UNIQUE;
@@ -0,0 +1,15 @@
+CREATE TABLE work_session_drafts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    job_id UUID REFERENCES jobs(id) ON DELETE SET NULL,
+    job_name VARCHAR NOT NULL,
+    duration_minutes INTEGER NOT NULL CHECK (duration_minutes > 0),
+    message_id VARCHAR,
+    received_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_work_session_drafts_user_id ON work_session_drafts (user_id);
+CREATE UNIQUE INDEX idx_work_session_drafts_message_id
+    ON work_session_drafts (user_id, message_id)
+    WHERE message_id IS NOT NULL;
@@ -0,0 +1,3 @@
+ALTER TABLE jobs DROP COLUMN board_position;
+ALTER TABLE jobs DROP COLUMN board_column;
+DROP TYPE job_board_column;
@@ -0,0 +1,7 @@
+CREATE TYPE job_board_column AS ENUM ('lead', 'active', 'invoiced', 'paid');
+
+ALTER TABLE jobs
+    ADD COLUMN board_column job_board_column NOT NULL DEFAULT 'active',
+    ADD COLUMN board_position INTEGER NOT NULL DEFAULT 0 CHECK (board_position >= 0);
+
+CREATE INDEX idx_jobs_board ON jobs (user_id, board_column, board_position);
@@ -0,0 +1,20 @@
+CREATE TYPE lead_status AS ENUM ('open', 'won', 'lost');
+
+CREATE TABLE leads (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    title VARCHAR NOT NULL,
+    company_name VARCHAR NOT NULL,
+    source VARCHAR,
+    expected_value DECIMAL CHECK (expected_value >= 0),
+    probability INTEGER NOT NULL DEFAULT 50 CHECK (probability BETWEEN 0 AND 100),
+    follow_up_date DATE,
+    notes TEXT,
+    status lead_status NOT NULL DEFAULT 'open',
+    converted_job_id UUID REFERENCES jobs(id) ON DELETE SET NULL,
+    closed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_leads_user_id ON leads (user_id, status);
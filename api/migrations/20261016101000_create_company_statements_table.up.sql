@@ -0,0 +1,15 @@
+CREATE TABLE company_statements (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    company_id UUID NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    recipient_email VARCHAR NOT NULL,
+    period_start DATE NOT NULL,
+    period_end DATE NOT NULL,
+    total_hours DECIMAL NOT NULL,
+    total_payments DECIMAL NOT NULL,
+    sent_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT chk_statement_period CHECK (period_end >= period_start)
+);
+
+CREATE INDEX idx_company_statements_company_sent_at
+    ON company_statements (company_id, sent_at DESC);
@@ -0,0 +1 @@
+ALTER TABLE auth_codes DROP COLUMN failed_attempts;
@@ -0,0 +1 @@
+ALTER TABLE auth_codes ADD COLUMN failed_attempts INT NOT NULL DEFAULT 0;
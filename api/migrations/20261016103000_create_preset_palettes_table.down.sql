@@ -0,0 +1,8 @@
+CREATE TYPE preset_palette AS ENUM ('catppuccin', 'tokyo-night', 'everforest');
+
+ALTER TABLE user_appearance_preferences
+    DROP CONSTRAINT fk_active_preset_palette,
+    ALTER COLUMN active_preset_palette TYPE preset_palette
+        USING active_preset_palette::preset_palette;
+
+DROP TABLE preset_palettes;
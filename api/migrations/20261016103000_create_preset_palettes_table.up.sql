@@ -0,0 +1,43 @@
+CREATE TABLE preset_palettes (
+    slug VARCHAR PRIMARY KEY,
+    name VARCHAR NOT NULL UNIQUE,
+    position INT NOT NULL,
+    seed_green VARCHAR NOT NULL,
+    seed_red VARCHAR NOT NULL,
+    seed_yellow VARCHAR NOT NULL,
+    seed_blue VARCHAR NOT NULL,
+    seed_magenta VARCHAR NOT NULL,
+    seed_cyan VARCHAR NOT NULL,
+    generated_tokens JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+INSERT INTO preset_palettes (
+    slug, name, position,
+    seed_green, seed_red, seed_yellow, seed_blue, seed_magenta, seed_cyan,
+    generated_tokens
+)
+VALUES
+    (
+        'catppuccin', 'Catppuccin', 0,
+        '#a6e3a1', '#f38ba8', '#f9e2af', '#89b4fa', '#cba6f7', '#94e2d5',
+        '{"background": "#cdd6f4", "text": "#1e1e2e", "primary-100": "#89b4fa", "primary-80": "#a1c3fb", "primary-60": "#b8d2fc", "secondary-100": "#cba6f7", "secondary-80": "#d5b8f9", "secondary-60": "#e0cafa", "green-100": "#a6e3a1", "green-80": "#b8e9b4", "green-60": "#caeec7", "red-100": "#f38ba8", "red-80": "#f5a2b9", "red-60": "#f8b9cb", "yellow-100": "#f9e2af", "yellow-80": "#fae8bf", "yellow-60": "#fbeecf", "blue-100": "#89b4fa", "blue-80": "#a1c3fb", "blue-60": "#b8d2fc", "magenta-100": "#cba6f7", "magenta-80": "#d5b8f9", "magenta-60": "#e0cafa", "cyan-100": "#94e2d5", "cyan-80": "#a9e8dd", "cyan-60": "#bfeee6"}'
+    ),
+    (
+        'tokyo-night', 'Tokyo Night', 1,
+        '#9ece6a', '#f7768e', '#e0af68', '#7aa2f7', '#bb9af7', '#7dcfff',
+        '{"background": "#c0caf5", "text": "#1f2335", "primary-100": "#7aa2f7", "primary-80": "#95b5f9", "primary-60": "#afc7fa", "secondary-100": "#bb9af7", "secondary-80": "#c9aef9", "secondary-60": "#d6c2fa", "green-100": "#9ece6a", "green-80": "#b1d888", "green-60": "#c5e2a6", "red-100": "#f7768e", "red-80": "#f991a5", "red-60": "#faadbb", "yellow-100": "#e0af68", "yellow-80": "#e6bf86", "yellow-60": "#eccfa4", "blue-100": "#7aa2f7", "blue-80": "#95b5f9", "blue-60": "#afc7fa", "magenta-100": "#bb9af7", "magenta-80": "#c9aef9", "magenta-60": "#d6c2fa", "cyan-100": "#7dcfff", "cyan-80": "#97d9ff", "cyan-60": "#b1e2ff"}'
+    ),
+    (
+        'everforest', 'Everforest', 2,
+        '#a7c080', '#e67e80', '#dbbc7f', '#7fbbb3', '#d699b6', '#83c092',
+        '{"background": "#d3c6aa", "text": "#2d353b", "primary-100": "#7fbbb3", "primary-80": "#99c9c2", "primary-60": "#b2d6d1", "secondary-100": "#d699b6", "secondary-80": "#deadc5", "secondary-60": "#e6c2d3", "green-100": "#a7c080", "green-80": "#b9cd99", "green-60": "#cad9b3", "red-100": "#e67e80", "red-80": "#eb9899", "red-60": "#f0b2b3", "yellow-100": "#dbbc7f", "yellow-80": "#e2c999", "yellow-60": "#e9d7b2", "blue-100": "#7fbbb3", "blue-80": "#99c9c2", "blue-60": "#b2d6d1", "magenta-100": "#d699b6", "magenta-80": "#deadc5", "magenta-60": "#e6c2d3", "cyan-100": "#83c092", "cyan-80": "#9ccda8", "cyan-60": "#b5d9be"}'
+    );
+
+ALTER TABLE user_appearance_preferences
+    ALTER COLUMN active_preset_palette TYPE VARCHAR USING active_preset_palette::TEXT,
+    ADD CONSTRAINT fk_active_preset_palette
+        FOREIGN KEY (active_preset_palette) REFERENCES preset_palettes(slug);
+
+DROP TYPE preset_palette;
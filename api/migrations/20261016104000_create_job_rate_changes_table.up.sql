@@ -0,0 +1,16 @@
+CREATE TABLE job_rate_changes (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    job_id UUID NOT NULL REFERENCES jobs(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    previous_payment_type payment_type NOT NULL,
+    previous_hourly_rate DECIMAL,
+    previous_number_of_payouts INTEGER,
+    previous_payout_amount DECIMAL,
+    payment_type payment_type NOT NULL,
+    hourly_rate DECIMAL,
+    number_of_payouts INTEGER,
+    payout_amount DECIMAL,
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_job_rate_changes_job_id ON job_rate_changes (job_id, changed_at);
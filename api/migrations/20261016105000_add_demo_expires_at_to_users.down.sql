@@ -0,0 +1,2 @@
+DROP INDEX idx_users_demo_expires_at;
+ALTER TABLE users DROP COLUMN demo_expires_at;
@@ -0,0 +1,4 @@
+ALTER TABLE users ADD COLUMN demo_expires_at TIMESTAMPTZ;
+
+CREATE INDEX idx_users_demo_expires_at ON users (demo_expires_at)
+    WHERE demo_expires_at IS NOT NULL;
@@ -0,0 +1,5 @@
+DROP INDEX idx_refresh_tokens_user_id;
+
+ALTER TABLE refresh_tokens
+    DROP COLUMN last_used_at,
+    DROP COLUMN user_agent;
@@ -0,0 +1,6 @@
+ALTER TABLE refresh_tokens
+    ADD COLUMN user_agent VARCHAR,
+    ADD COLUMN last_used_at TIMESTAMPTZ NOT NULL DEFAULT now();
+
+CREATE INDEX idx_refresh_tokens_user_id ON refresh_tokens (user_id)
+    WHERE revoked = FALSE;
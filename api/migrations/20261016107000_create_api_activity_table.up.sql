@@ -0,0 +1,13 @@
+CREATE TABLE api_activity (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    method VARCHAR NOT NULL,
+    route VARCHAR NOT NULL,
+    status SMALLINT NOT NULL,
+    ip_address VARCHAR,
+    user_agent VARCHAR,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_api_activity_user_id ON api_activity (user_id, created_at DESC);
+CREATE INDEX idx_api_activity_created_at ON api_activity (created_at);
@@ -0,0 +1,4 @@
+ALTER TABLE users
+    DROP COLUMN first_payment_recorded_at,
+    DROP COLUMN first_session_tracked_at,
+    DROP COLUMN first_company_created_at;
@@ -0,0 +1,9 @@
+ALTER TABLE users
+    ADD COLUMN first_company_created_at TIMESTAMPTZ,
+    ADD COLUMN first_session_tracked_at TIMESTAMPTZ,
+    ADD COLUMN first_payment_recorded_at TIMESTAMPTZ;
+
+UPDATE users u
+SET first_company_created_at = (SELECT MIN(c.created_at) FROM companies c WHERE c.user_id = u.id),
+    first_session_tracked_at = (SELECT MIN(ws.created_at) FROM work_sessions ws WHERE ws.user_id = u.id),
+    first_payment_recorded_at = (SELECT MIN(p.created_at) FROM payments p WHERE p.user_id = u.id);
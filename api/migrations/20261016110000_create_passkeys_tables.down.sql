@@ -0,0 +1,3 @@
+DROP TABLE passkey_challenges;
+DROP TYPE passkey_ceremony;
+DROP TABLE passkeys;
@@ -0,0 +1,4 @@
+DROP TABLE stripe_webhook_events;
+DROP TABLE subscriptions;
+DROP TYPE subscription_status;
+DROP TYPE subscription_plan;
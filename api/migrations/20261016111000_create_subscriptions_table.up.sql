@@ -0,0 +1,32 @@
+CREATE TYPE subscription_plan AS ENUM ('free', 'pro');
+
+CREATE TYPE subscription_status AS ENUM (
+    'incomplete',
+    'incomplete_expired',
+    'trialing',
+    'active',
+    'past_due',
+    'canceled',
+    'unpaid',
+    'paused'
+);
+
+CREATE TABLE subscriptions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL UNIQUE REFERENCES users(id) ON DELETE CASCADE,
+    stripe_customer_id VARCHAR NOT NULL UNIQUE,
+    stripe_subscription_id VARCHAR UNIQUE,
+    plan subscription_plan NOT NULL DEFAULT 'free',
+    status subscription_status,
+    current_period_end TIMESTAMPTZ,
+    cancel_at_period_end BOOLEAN NOT NULL DEFAULT false,
+    stripe_event_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE TABLE stripe_webhook_events (
+    id VARCHAR PRIMARY KEY,
+    event_type VARCHAR NOT NULL,
+    received_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
@@ -0,0 +1,5 @@
+ALTER TABLE users DROP COLUMN security_hold_at;
+DROP TABLE security_events;
+DROP TYPE security_event_kind;
+DROP TABLE login_countries;
+DROP TABLE failed_logins;
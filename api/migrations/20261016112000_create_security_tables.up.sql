@@ -0,0 +1,29 @@
+CREATE TABLE failed_logins (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    ip_address VARCHAR,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_failed_logins_user_id ON failed_logins (user_id, created_at);
+
+CREATE TABLE login_countries (
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    country VARCHAR(2) NOT NULL,
+    first_seen_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (user_id, country)
+);
+
+CREATE TYPE security_event_kind AS ENUM ('failed_logins', 'new_country_login', 'mass_deletion');
+
+CREATE TABLE security_events (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    kind security_event_kind NOT NULL,
+    detail VARCHAR NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_security_events_user_id ON security_events (user_id, kind, created_at DESC);
+
+ALTER TABLE users ADD COLUMN security_hold_at TIMESTAMPTZ;
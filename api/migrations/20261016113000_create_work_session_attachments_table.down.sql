@@ -0,0 +1,2 @@
+DROP TABLE work_session_attachments;
+DROP TYPE work_session_attachment_kind;
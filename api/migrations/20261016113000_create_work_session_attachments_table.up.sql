@@ -0,0 +1,18 @@
+CREATE TYPE work_session_attachment_kind AS ENUM ('photo', 'voice_memo');
+
+CREATE TABLE work_session_attachments (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    work_session_id UUID NOT NULL REFERENCES work_sessions(id) ON DELETE CASCADE,
+    kind work_session_attachment_kind NOT NULL,
+    file_name VARCHAR(255) NOT NULL,
+    content_type VARCHAR(255) NOT NULL,
+    data BYTEA NOT NULL,
+    transcript TEXT,
+    transcript_language VARCHAR(35),
+    transcribed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_work_session_attachments_work_session_id
+    ON work_session_attachments(work_session_id);
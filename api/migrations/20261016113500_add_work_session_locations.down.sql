@@ -0,0 +1,7 @@
+ALTER TABLE users DROP COLUMN location_tracking_enabled;
+
+ALTER TABLE work_sessions
+    DROP COLUMN start_latitude,
+    DROP COLUMN start_longitude,
+    DROP COLUMN end_latitude,
+    DROP COLUMN end_longitude;
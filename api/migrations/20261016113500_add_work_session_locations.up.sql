@@ -0,0 +1,11 @@
+ALTER TABLE work_sessions
+    ADD COLUMN start_latitude DOUBLE PRECISION CHECK (start_latitude BETWEEN -90 AND 90),
+    ADD COLUMN start_longitude DOUBLE PRECISION CHECK (start_longitude BETWEEN -180 AND 180),
+    ADD COLUMN end_latitude DOUBLE PRECISION CHECK (end_latitude BETWEEN -90 AND 90),
+    ADD COLUMN end_longitude DOUBLE PRECISION CHECK (end_longitude BETWEEN -180 AND 180),
+    ADD CONSTRAINT work_sessions_start_location_pair
+        CHECK ((start_latitude IS NULL) = (start_longitude IS NULL)),
+    ADD CONSTRAINT work_sessions_end_location_pair
+        CHECK ((end_latitude IS NULL) = (end_longitude IS NULL));
+
+ALTER TABLE users ADD COLUMN location_tracking_enabled BOOLEAN NOT NULL DEFAULT FALSE;
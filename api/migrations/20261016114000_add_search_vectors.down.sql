@@ -0,0 +1,5 @@
+DROP INDEX idx_jobs_search_vector;
+DROP INDEX idx_companies_search_vector;
+
+ALTER TABLE jobs DROP COLUMN search_vector;
+ALTER TABLE companies DROP COLUMN search_vector;
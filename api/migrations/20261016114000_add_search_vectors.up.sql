@@ -0,0 +1,10 @@
+ALTER TABLE companies
+    ADD COLUMN search_vector TSVECTOR
+    GENERATED ALWAYS AS (to_tsvector('english', name)) STORED;
+
+ALTER TABLE jobs
+    ADD COLUMN search_vector TSVECTOR
+    GENERATED ALWAYS AS (to_tsvector('english', title)) STORED;
+
+CREATE INDEX idx_companies_search_vector ON companies USING GIN (search_vector);
+CREATE INDEX idx_jobs_search_vector ON jobs USING GIN (search_vector);
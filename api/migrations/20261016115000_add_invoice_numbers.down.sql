@@ -0,0 +1,8 @@
+DROP INDEX idx_invoices_user_id;
+ALTER TABLE invoices
+    DROP CONSTRAINT chk_finalized_invoice_numbered,
+    DROP CONSTRAINT uq_invoices_user_invoice_number,
+    DROP COLUMN finalized_at,
+    DROP COLUMN notes,
+    DROP COLUMN due_date,
+    DROP COLUMN invoice_number;
@@ -0,0 +1,25 @@
+ALTER TABLE invoices
+    ADD COLUMN invoice_number INTEGER,
+    ADD COLUMN due_date DATE,
+    ADD COLUMN notes TEXT,
+    ADD COLUMN finalized_at TIMESTAMPTZ;
+
+UPDATE invoices
+SET invoice_number = numbered.invoice_number,
+    finalized_at = invoices.updated_at
+FROM (
+    SELECT id, ROW_NUMBER() OVER (PARTITION BY user_id ORDER BY created_at, id) AS invoice_number
+    FROM invoices
+    WHERE status = 'finalized'
+) numbered
+WHERE invoices.id = numbered.id;
+
+ALTER TABLE invoices
+    ADD CONSTRAINT uq_invoices_user_invoice_number UNIQUE (user_id, invoice_number),
+    ADD CONSTRAINT chk_finalized_invoice_numbered CHECK (
+        (status = 'draft' AND invoice_number IS NULL AND finalized_at IS NULL)
+        OR
+        (status = 'finalized' AND invoice_number IS NOT NULL AND finalized_at IS NOT NULL)
+    );
+
+CREATE INDEX idx_invoices_user_id ON invoices (user_id, created_at);
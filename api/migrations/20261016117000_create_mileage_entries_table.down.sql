@@ -0,0 +1,3 @@
+ALTER TABLE users DROP COLUMN mileage_rate;
+
+DROP TABLE mileage_entries;
@@ -0,0 +1,15 @@
+CREATE TABLE mileage_entries (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    job_id UUID REFERENCES jobs(id) ON DELETE SET NULL,
+    date DATE NOT NULL,
+    miles DECIMAL NOT NULL CHECK (miles > 0),
+    start_location VARCHAR(255),
+    end_location VARCHAR(255),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_mileage_entries_user_id ON mileage_entries (user_id, date);
+
+ALTER TABLE users ADD COLUMN mileage_rate DECIMAL CHECK (mileage_rate >= 0);
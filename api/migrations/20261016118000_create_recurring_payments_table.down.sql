@@ -0,0 +1,4 @@
+ALTER TABLE payments DROP COLUMN recurring_payment_id;
+
+DROP TABLE recurring_payments;
+DROP TYPE recurrence_cadence;
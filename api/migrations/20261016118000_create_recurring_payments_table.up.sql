@@ -0,0 +1,26 @@
+CREATE TYPE recurrence_cadence AS ENUM ('weekly', 'biweekly', 'monthly', 'quarterly', 'yearly');
+
+CREATE TABLE recurring_payments (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    company_id UUID NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    total DECIMAL NOT NULL CHECK (total > 0),
+    payout_type payout_type NOT NULL,
+    cadence recurrence_cadence NOT NULL,
+    start_date DATE NOT NULL,
+    end_date DATE,
+    next_date DATE NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT chk_recurring_payment_dates CHECK (end_date IS NULL OR end_date >= start_date)
+);
+
+CREATE INDEX idx_recurring_payments_user_id ON recurring_payments (user_id, created_at);
+CREATE INDEX idx_recurring_payments_next_date ON recurring_payments (next_date);
+
+ALTER TABLE payments
+    ADD COLUMN recurring_payment_id UUID REFERENCES recurring_payments(id) ON DELETE SET NULL;
+
+CREATE UNIQUE INDEX uq_payments_recurring_payment_date
+    ON payments (recurring_payment_id, expected_payout_date)
+    WHERE recurring_payment_id IS NOT NULL;
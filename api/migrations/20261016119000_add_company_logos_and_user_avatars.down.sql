@@ -0,0 +1,7 @@
+ALTER TABLE users
+    DROP COLUMN avatar_uploaded_at,
+    DROP COLUMN avatar_data;
+
+ALTER TABLE companies
+    DROP COLUMN logo_uploaded_at,
+    DROP COLUMN logo_data;
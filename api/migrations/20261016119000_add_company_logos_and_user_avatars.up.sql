@@ -0,0 +1,7 @@
+ALTER TABLE companies
+    ADD COLUMN logo_data BYTEA,
+    ADD COLUMN logo_uploaded_at TIMESTAMPTZ;
+
+ALTER TABLE users
+    ADD COLUMN avatar_data BYTEA,
+    ADD COLUMN avatar_uploaded_at TIMESTAMPTZ;
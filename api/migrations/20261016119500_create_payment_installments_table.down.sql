@@ -0,0 +1,5 @@
+DROP TABLE payment_installments;
+
+ALTER TABLE payments
+    DROP CONSTRAINT payments_amount_paid_within_total,
+    DROP COLUMN amount_paid;
@@ -0,0 +1,14 @@
+ALTER TABLE payments
+    ADD COLUMN amount_paid DECIMAL NOT NULL DEFAULT 0,
+    ADD CONSTRAINT payments_amount_paid_within_total
+        CHECK (amount_paid >= 0 AND amount_paid <= total);
+
+CREATE TABLE payment_installments (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    payment_id UUID NOT NULL REFERENCES payments(id) ON DELETE CASCADE,
+    amount DECIMAL NOT NULL CHECK (amount > 0),
+    paid_on DATE NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_payment_installments_payment ON payment_installments (payment_id, paid_on);
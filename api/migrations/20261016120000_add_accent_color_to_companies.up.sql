@@ -0,0 +1 @@
+ALTER TABLE companies ADD COLUMN accent_color VARCHAR(32);
@@ -0,0 +1,5 @@
+DROP TABLE payment_reminders;
+
+DROP TYPE payment_reminder_stage;
+
+ALTER TABLE users DROP COLUMN payment_reminders_enabled;
@@ -0,0 +1,10 @@
+ALTER TABLE users ADD COLUMN payment_reminders_enabled BOOLEAN NOT NULL DEFAULT TRUE;
+
+CREATE TYPE payment_reminder_stage AS ENUM ('upcoming', 'overdue');
+
+CREATE TABLE payment_reminders (
+    payment_id UUID NOT NULL REFERENCES payments(id) ON DELETE CASCADE,
+    stage payment_reminder_stage NOT NULL,
+    sent_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (payment_id, stage)
+);
@@ -0,0 +1,3 @@
+ALTER TABLE companies
+    DROP COLUMN last_invoice_draft_week,
+    DROP COLUMN auto_draft_invoices;
@@ -0,0 +1,3 @@
+ALTER TABLE companies
+    ADD COLUMN auto_draft_invoices BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN last_invoice_draft_week DATE;
@@ -0,0 +1 @@
+ALTER TABLE work_sessions DROP COLUMN hourly_rate;
@@ -0,0 +1,41 @@
+ALTER TABLE work_sessions ADD COLUMN hourly_rate DECIMAL;
+
+-- Backfill completed sessions with the rate they were billed at, or else
+-- the rate in effect when they ended according to the job's rate history,
+-- falling back to the job's current rate.
+WITH rates AS (
+    SELECT ws.id,
+           CASE
+               WHEN billed.invoice_id IS NOT NULL THEN billed.hourly_rate
+               WHEN prior_change.id IS NOT NULL THEN prior_change.hourly_rate
+               WHEN later_change.id IS NOT NULL THEN later_change.previous_hourly_rate
+               ELSE j.hourly_rate
+           END AS hourly_rate
+    FROM work_sessions ws
+    JOIN jobs j ON j.id = ws.job_id
+    LEFT JOIN LATERAL (
+        SELECT li.invoice_id, li.hourly_rate
+        FROM invoice_line_items li
+        WHERE li.invoice_id = ws.invoice_id AND li.job_id = ws.job_id
+        LIMIT 1
+    ) billed ON TRUE
+    LEFT JOIN LATERAL (
+        SELECT rc.id, rc.hourly_rate
+        FROM job_rate_changes rc
+        WHERE rc.job_id = ws.job_id AND rc.changed_at <= ws.end_time
+        ORDER BY rc.changed_at DESC
+        LIMIT 1
+    ) prior_change ON TRUE
+    LEFT JOIN LATERAL (
+        SELECT rc.id, rc.previous_hourly_rate
+        FROM job_rate_changes rc
+        WHERE rc.job_id = ws.job_id AND rc.changed_at > ws.end_time
+        ORDER BY rc.changed_at
+        LIMIT 1
+    ) later_change ON TRUE
+    WHERE ws.is_running = FALSE AND ws.end_time IS NOT NULL
+)
+UPDATE work_sessions ws
+SET hourly_rate = rates.hourly_rate
+FROM rates
+WHERE rates.id = ws.id;
@@ -0,0 +1,5 @@
+DROP INDEX idx_work_sessions_tags;
+
+ALTER TABLE work_sessions
+    DROP COLUMN tags,
+    DROP COLUMN notes;
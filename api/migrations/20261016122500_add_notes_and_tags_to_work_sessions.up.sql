@@ -0,0 +1,5 @@
+ALTER TABLE work_sessions
+    ADD COLUMN notes TEXT,
+    ADD COLUMN tags TEXT[] NOT NULL DEFAULT '{}';
+
+CREATE INDEX idx_work_sessions_tags ON work_sessions USING GIN (tags);
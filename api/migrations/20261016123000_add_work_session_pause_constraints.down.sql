@@ -0,0 +1 @@
+ALTER TABLE work_sessions DROP CONSTRAINT chk_paused_after_start;
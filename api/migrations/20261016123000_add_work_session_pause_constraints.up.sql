@@ -0,0 +1,2 @@
+ALTER TABLE work_sessions
+    ADD CONSTRAINT chk_paused_after_start CHECK (paused_at IS NULL OR paused_at >= start_time);
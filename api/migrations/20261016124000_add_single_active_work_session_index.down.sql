@@ -0,0 +1 @@
+DROP INDEX uq_work_sessions_user_active;
@@ -0,0 +1,24 @@
+-- Complete all but the most recently started active session for each user
+-- so the index can be built.
+WITH superseded AS (
+    SELECT id
+    FROM (
+        SELECT id,
+               ROW_NUMBER() OVER (PARTITION BY user_id ORDER BY start_time DESC, id) AS position
+        FROM work_sessions
+        WHERE end_time IS NULL
+    ) ranked
+    WHERE position > 1
+)
+UPDATE work_sessions ws
+SET accumulated_paused_duration =
+        ws.accumulated_paused_duration + COALESCE(now() - ws.paused_at, interval '0'),
+    paused_at = NULL,
+    end_time = now(),
+    is_running = FALSE,
+    hourly_rate = (SELECT hourly_rate FROM jobs WHERE jobs.id = ws.job_id),
+    updated_at = now()
+FROM superseded
+WHERE superseded.id = ws.id;
+
+CREATE UNIQUE INDEX uq_work_sessions_user_active ON work_sessions (user_id) WHERE end_time IS NULL;
@@ -0,0 +1 @@
+ALTER TABLE work_sessions ADD COLUMN auto_completed BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,2 @@
+ALTER TABLE users ADD COLUMN self_employment_tax_rate DECIMAL
+    CHECK (self_employment_tax_rate >= 0 AND self_employment_tax_rate <= 1);
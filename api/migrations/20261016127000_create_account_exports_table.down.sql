@@ -0,0 +1,2 @@
+DROP TABLE account_exports;
+DROP TYPE account_export_status;
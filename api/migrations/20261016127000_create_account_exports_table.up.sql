@@ -0,0 +1,16 @@
+CREATE TYPE account_export_status AS ENUM ('pending', 'processing', 'completed', 'failed');
+
+CREATE TABLE account_exports (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    status account_export_status NOT NULL DEFAULT 'pending',
+    archive BYTEA,
+    error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    started_at TIMESTAMPTZ,
+    completed_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_account_exports_user_id ON account_exports (user_id);
+CREATE INDEX idx_account_exports_queue ON account_exports (created_at)
+    WHERE status IN ('pending', 'processing');
@@ -0,0 +1,9 @@
+DROP TABLE mileage_suggestions;
+
+DROP TYPE mileage_suggestion_status;
+
+ALTER TABLE mileage_entries DROP COLUMN work_session_id;
+
+ALTER TABLE users
+    DROP COLUMN home_latitude,
+    DROP COLUMN home_longitude;
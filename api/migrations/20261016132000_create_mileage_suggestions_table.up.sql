@@ -0,0 +1,26 @@
+ALTER TABLE users
+    ADD COLUMN home_latitude DOUBLE PRECISION CHECK (home_latitude BETWEEN -90 AND 90),
+    ADD COLUMN home_longitude DOUBLE PRECISION CHECK (home_longitude BETWEEN -180 AND 180),
+    ADD CONSTRAINT users_home_location_pair
+        CHECK ((home_latitude IS NULL) = (home_longitude IS NULL));
+
+ALTER TABLE mileage_entries
+    ADD COLUMN work_session_id UUID UNIQUE REFERENCES work_sessions(id) ON DELETE SET NULL;
+
+CREATE TYPE mileage_suggestion_status AS ENUM ('pending', 'accepted', 'dismissed');
+
+CREATE TABLE mileage_suggestions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    work_session_id UUID NOT NULL UNIQUE REFERENCES work_sessions(id) ON DELETE CASCADE,
+    job_id UUID NOT NULL REFERENCES jobs(id) ON DELETE CASCADE,
+    date DATE NOT NULL,
+    miles DECIMAL NOT NULL CHECK (miles > 0),
+    destination VARCHAR(255) NOT NULL,
+    status mileage_suggestion_status NOT NULL DEFAULT 'pending',
+    mileage_entry_id UUID REFERENCES mileage_entries(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_mileage_suggestions_user_status ON mileage_suggestions (user_id, status, date);
@@ -0,0 +1,8 @@
+DROP TRIGGER trg_companies_report_cache ON companies;
+DROP TRIGGER trg_invoices_report_cache ON invoices;
+DROP TRIGGER trg_payments_report_cache ON payments;
+DROP TRIGGER trg_work_sessions_report_cache ON work_sessions;
+
+DROP FUNCTION mark_report_cache_stale();
+
+DROP TABLE report_cache;
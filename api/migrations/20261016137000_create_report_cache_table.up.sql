@@ -0,0 +1,37 @@
+CREATE TABLE report_cache (
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    report JSONB NOT NULL,
+    data JSONB NOT NULL,
+    stale BOOLEAN NOT NULL DEFAULT FALSE,
+    generated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    last_read_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (user_id, report)
+);
+
+CREATE INDEX idx_report_cache_stale ON report_cache (last_read_at) WHERE stale;
+
+CREATE FUNCTION mark_report_cache_stale() RETURNS TRIGGER AS $$
+BEGIN
+    UPDATE report_cache
+    SET stale = TRUE
+    WHERE user_id IN (OLD.user_id, NEW.user_id) AND NOT stale;
+
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_work_sessions_report_cache
+    AFTER INSERT OR UPDATE OR DELETE ON work_sessions
+    FOR EACH ROW EXECUTE FUNCTION mark_report_cache_stale();
+
+CREATE TRIGGER trg_payments_report_cache
+    AFTER INSERT OR UPDATE OR DELETE ON payments
+    FOR EACH ROW EXECUTE FUNCTION mark_report_cache_stale();
+
+CREATE TRIGGER trg_invoices_report_cache
+    AFTER INSERT OR UPDATE OR DELETE ON invoices
+    FOR EACH ROW EXECUTE FUNCTION mark_report_cache_stale();
+
+CREATE TRIGGER trg_companies_report_cache
+    AFTER INSERT OR UPDATE OR DELETE ON companies
+    FOR EACH ROW EXECUTE FUNCTION mark_report_cache_stale();
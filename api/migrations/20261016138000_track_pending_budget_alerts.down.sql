@@ -0,0 +1,6 @@
+DELETE FROM budget_alerts WHERE sent_at IS NULL;
+
+ALTER TABLE budget_alerts
+    DROP COLUMN claimed_at,
+    ALTER COLUMN sent_at SET DEFAULT now(),
+    ALTER COLUMN sent_at SET NOT NULL;
@@ -0,0 +1,4 @@
+ALTER TABLE budget_alerts
+    ALTER COLUMN sent_at DROP NOT NULL,
+    ALTER COLUMN sent_at DROP DEFAULT,
+    ADD COLUMN claimed_at TIMESTAMPTZ NOT NULL DEFAULT now();
@@ -0,0 +1,5 @@
+DROP TRIGGER trg_payments_withholding_rate ON payments;
+
+DROP FUNCTION snapshot_payment_withholding_rate();
+
+ALTER TABLE payments DROP COLUMN tax_withholding_rate;
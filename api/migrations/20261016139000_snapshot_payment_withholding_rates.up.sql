@@ -0,0 +1,27 @@
+ALTER TABLE payments ADD COLUMN tax_withholding_rate DECIMAL;
+
+UPDATE payments p
+SET tax_withholding_rate = c.tax_withholding_rate
+FROM companies c
+WHERE c.id = p.company_id
+  AND p.payment_received
+  AND c.requires_tax_withholdings;
+
+CREATE FUNCTION snapshot_payment_withholding_rate() RETURNS TRIGGER AS $$
+BEGIN
+    IF NOT NEW.payment_received THEN
+        NEW.tax_withholding_rate := NULL;
+    ELSIF TG_OP = 'INSERT' OR NOT OLD.payment_received THEN
+        SELECT CASE WHEN c.requires_tax_withholdings THEN c.tax_withholding_rate END
+        INTO NEW.tax_withholding_rate
+        FROM companies c
+        WHERE c.id = NEW.company_id;
+    END IF;
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_payments_withholding_rate
+    BEFORE INSERT OR UPDATE OF payment_received ON payments
+    FOR EACH ROW EXECUTE FUNCTION snapshot_payment_withholding_rate();
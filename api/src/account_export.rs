@@ -0,0 +1,74 @@
+//! Full account data exports.
+//!
+//! Provides [`AccountExportUtil`] for building the JSON archive of
+//! everything a user owns: their profile, settings, companies, jobs,
+//! payments, work sessions, and custom palettes. Archives are built by the
+//! [`AccountExportTask`](crate::tasks::account_exports::AccountExportTask)
+//! worker and stored until they expire.
+
+use chrono::{Duration, Utc};
+use gig_log_common::models::{
+    account_export::{AccountArchive, AccountSettings},
+    payment::{ListPaymentsQuery, PaymentSort},
+};
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::repo::{
+    account_export::AccountExportRepo, job::JobRepo, mileage::MileageRepo, payment::PaymentRepo,
+    report::ReportRepo, user::UserRepo,
+};
+
+/// How long finished exports are kept before they are deleted.
+pub const ACCOUNT_EXPORT_RETENTION: Duration = Duration::days(7);
+
+/// How long an export may stay processing before another worker retries it.
+pub const ACCOUNT_EXPORT_STALE_AFTER: Duration = Duration::minutes(15);
+
+/// Utility for building account export archives.
+pub struct AccountExportUtil;
+
+impl AccountExportUtil {
+    /// Builds a user's account archive.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user whose data is exported.
+    ///
+    /// # Returns
+    ///
+    /// The [`AccountArchive`] serialized as pretty-printed JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the user no longer exists,
+    /// or another [`ApiErrorResponse`] if a query or serialization fails.
+    pub async fn build_archive(pool: &Pool<Postgres>, user_id: Uuid) -> ApiResult<Vec<u8>> {
+        let archive = AccountArchive {
+            exported_at: Utc::now(),
+            user: UserRepo::find_user_by_id(pool, user_id).await?,
+            settings: AccountSettings {
+                mileage_rate: MileageRepo::find_custom_rate(pool, user_id).await?,
+                self_employment_tax_rate: ReportRepo::find_self_employment_rate(pool, user_id)
+                    .await?,
+                appearance: AccountExportRepo::find_appearance(pool, user_id).await?,
+            },
+            companies: AccountExportRepo::list_companies(pool, user_id).await?,
+            jobs: JobRepo::list_jobs_for_board(pool, user_id).await?,
+            payments: PaymentRepo::list_for_user(
+                pool,
+                user_id,
+                &ListPaymentsQuery::default(),
+                PaymentSort::default(),
+            )
+            .await?,
+            work_sessions: AccountExportRepo::list_work_sessions(pool, user_id).await?,
+            palettes: AccountExportRepo::list_palettes(pool, user_id).await?,
+        };
+
+        serde_json::to_vec_pretty(&archive)
+            .map_err(|error| ApiErrorResponse::InternalServerError(error.to_string()))
+    }
+}
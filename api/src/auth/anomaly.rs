@@ -0,0 +1,306 @@
+//! Unusual account activity detection.
+//!
+//! Provides [`AnomalyDetector`], which watches for many failed log-ins,
+//! log-ins from a country the user has not logged in from before, and many
+//! deletions in a short time. When one is detected the user is emailed,
+//! their outstanding emailed codes are voided, and a re-authentication hold
+//! is started: until it expires, sensitive endpoints call
+//! [`AnomalyDetector::require_recent_auth`] and reject sessions started
+//! before the activity.
+
+use axum::http::HeaderMap;
+use gig_log_common::models::locale::Locale;
+use log::{error, warn};
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::email::senders::security::SecuritySender;
+use crate::email::templates::security::UnusualActivity;
+use crate::repo::{
+    auth_code::AuthCodeRepo,
+    security::{SecurityEventKind, SecurityRepo},
+    user::UserRepo,
+};
+use crate::routes::app::AppState;
+
+/// Detector for unusual account activity.
+pub struct AnomalyDetector;
+
+impl AnomalyDetector {
+    /// Records a failed log-in and flags the account if too many failed
+    /// log-ins were made within the configured window.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `email` — The email address the log-in was for.
+    /// * `ip_address` — The address the attempt came from, if known.
+    ///
+    /// # Returns
+    ///
+    /// `()` on success, including when no account has the email.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if a database query fails.
+    pub async fn record_failed_login(
+        state: &AppState,
+        email: &str,
+        ip_address: Option<&str>,
+    ) -> ApiResult<()> {
+        let window_seconds = state.config.security_failed_login_window_seconds;
+        let Some(user_id) =
+            SecurityRepo::insert_failed_login(&state.db_pool, email, ip_address, window_seconds)
+                .await?
+        else {
+            return Ok(());
+        };
+
+        let count =
+            SecurityRepo::count_failed_logins(&state.db_pool, user_id, window_seconds).await?;
+
+        if count as u64 >= state.config.security_failed_login_threshold {
+            Self::flag(
+                state,
+                user_id,
+                SecurityEventKind::FailedLogins,
+                UnusualActivity::FailedLogins {
+                    count,
+                    minutes: window_seconds / 60,
+                },
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Records a successful log-in and flags the account if it came from a
+    /// new country.
+    ///
+    /// The country is read from the
+    /// [`Config::security_country_header`](crate::core::config::Config::security_country_header)
+    /// request header, and log-ins without one are not checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `headers` — The log-in request headers.
+    /// * `user_id` — The user who logged in.
+    ///
+    /// # Returns
+    ///
+    /// `()` on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if a database query fails.
+    pub async fn record_login(
+        state: &AppState,
+        headers: &HeaderMap,
+        user_id: Uuid,
+    ) -> ApiResult<()> {
+        SecurityRepo::clear_failed_logins(&state.db_pool, user_id).await?;
+
+        let Some(country) = Self::country(headers, &state.config.security_country_header) else {
+            return Ok(());
+        };
+
+        if SecurityRepo::insert_login_country(&state.db_pool, user_id, &country).await? {
+            Self::flag(
+                state,
+                user_id,
+                SecurityEventKind::NewCountryLogin,
+                UnusualActivity::NewCountryLogin { country },
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Flags the account if the user made too many deletions within the
+    /// configured window.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `user_id` — The user who made a deletion.
+    ///
+    /// # Returns
+    ///
+    /// `()` on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if a database query fails.
+    pub async fn check_mass_deletion(state: &AppState, user_id: Uuid) -> ApiResult<()> {
+        let window_seconds = state.config.security_mass_deletion_window_seconds;
+        let count = SecurityRepo::count_deletions(&state.db_pool, user_id, window_seconds).await?;
+
+        if count as u64 >= state.config.security_mass_deletion_threshold {
+            Self::flag(
+                state,
+                user_id,
+                SecurityEventKind::MassDeletion,
+                UnusualActivity::MassDeletion {
+                    count,
+                    minutes: window_seconds / 60,
+                },
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Requires the user to have logged in since unusual activity was last
+    /// detected on their account.
+    ///
+    /// Requests authenticated with a personal access token or API key have
+    /// no log-in time and are rejected while a hold is active.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `auth` — The authenticated user.
+    ///
+    /// # Returns
+    ///
+    /// `()` if no hold is active or the session started after it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Forbidden`] if the session started before
+    /// an active hold, or an [`ApiErrorResponse`] if the query fails.
+    pub async fn require_recent_auth(state: &AppState, auth: &AuthUser) -> ApiResult<()> {
+        let Some(hold_at) = SecurityRepo::find_active_hold(
+            &state.db_pool,
+            auth.user_id,
+            state.config.security_reauth_hold_seconds,
+        )
+        .await?
+        else {
+            return Ok(());
+        };
+
+        match auth.auth_time {
+            Some(auth_time) if auth_time > hold_at => Ok(()),
+            _ => Err(ApiErrorResponse::Forbidden(
+                "Unusual activity was detected on your account. Log in again to continue."
+                    .to_string(),
+            )),
+        }
+    }
+
+    /// Records unusual activity and, unless the same kind was already
+    /// recorded during the hold, starts a re-authentication hold, voids the
+    /// user's emailed codes, and emails them.
+    ///
+    /// Email failures are logged rather than returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `user_id` — The affected user.
+    /// * `kind` — The [`SecurityEventKind`] detected.
+    /// * `activity` — The [`UnusualActivity`] detected. Its English
+    ///   description is recorded with the event.
+    ///
+    /// # Returns
+    ///
+    /// `()` on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if a database query fails.
+    async fn flag(
+        state: &AppState,
+        user_id: Uuid,
+        kind: SecurityEventKind,
+        activity: UnusualActivity,
+    ) -> ApiResult<()> {
+        let recorded = SecurityRepo::insert_event(
+            &state.db_pool,
+            user_id,
+            kind,
+            &activity.describe(Locale::En),
+            state.config.security_reauth_hold_seconds,
+        )
+        .await?;
+
+        if !recorded {
+            return Ok(());
+        }
+
+        warn!("Unusual activity on user {}: {:?}", user_id, kind);
+
+        SecurityRepo::start_hold(&state.db_pool, user_id).await?;
+        AuthCodeRepo::invalidate_for_user(&state.db_pool, user_id).await?;
+
+        let user = UserRepo::find_user_by_id(&state.db_pool, user_id).await?;
+        let locale = UserRepo::find_locale(&state.db_pool, user_id).await?;
+
+        if let Err(error) = SecuritySender::new(state.email_client.clone(), user.email)
+            .with_locale(locale)
+            .send_unusual_activity(activity)
+            .await
+        {
+            error!("Failed to send unusual activity email: {:?}", error);
+        }
+
+        Ok(())
+    }
+
+    /// Reads the client's country code from a request header.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` — The request headers.
+    /// * `header_name` — The header carrying the country code.
+    ///
+    /// # Returns
+    ///
+    /// The uppercase ISO 3166-1 alpha-2 country code, or `None` if the
+    /// header is missing or not a country code (such as Cloudflare's `XX`
+    /// and `T1` placeholders).
+    fn country(headers: &HeaderMap, header_name: &str) -> Option<String> {
+        let country = headers
+            .get(header_name)?
+            .to_str()
+            .ok()?
+            .trim()
+            .to_ascii_uppercase();
+
+        (country.len() == 2
+            && country.bytes().all(|byte| byte.is_ascii_uppercase())
+            && country != "XX")
+            .then_some(country)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn reads_country_codes() {
+        let mut headers = HeaderMap::new();
+
+        assert_eq!(AnomalyDetector::country(&headers, "cf-ipcountry"), None);
+
+        headers.insert("cf-ipcountry", HeaderValue::from_static(" de "));
+        assert_eq!(
+            AnomalyDetector::country(&headers, "cf-ipcountry").as_deref(),
+            Some("DE")
+        );
+
+        for placeholder in ["XX", "T1", "USA"] {
+            headers.insert("cf-ipcountry", HeaderValue::from_static(placeholder));
+            assert_eq!(AnomalyDetector::country(&headers, "cf-ipcountry"), None);
+        }
+    }
+}
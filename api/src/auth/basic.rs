@@ -0,0 +1,109 @@
+//! HTTP Basic authentication extractor for API clients.
+//!
+//! Provides [`BasicAuthUser`], an Axum [`FromRequestParts`] extractor for
+//! routes used by external tools. It accepts `Authorization: Basic`
+//! credentials made of the user's email and password, or, following Toggl's
+//! convention, a personal access token as the username with the password
+//! `api_token`. It falls back to the `Authorization: Bearer` personal access
+//! token and the `access_token` cookie accepted by [`AuthUser`].
+
+use axum::{
+    extract::FromRequestParts,
+    http::{HeaderMap, header, request::Parts},
+};
+use base64::{Engine, engine::general_purpose::STANDARD};
+use uuid::Uuid;
+
+use crate::auth::{AuthUser, password::PasswordUtil};
+use crate::core::error::ApiErrorResponse;
+use crate::repo::user::UserRepo;
+use crate::routes::app::AppState;
+
+/// A user authenticated with HTTP Basic credentials or an access token.
+pub struct BasicAuthUser {
+    /// The unique identifier of the authenticated user.
+    pub user_id: Uuid,
+}
+
+impl BasicAuthUser {
+    /// Reads `email:password` credentials from an `Authorization: Basic`
+    /// header.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` — The request headers.
+    ///
+    /// # Returns
+    ///
+    /// The email and password, or `None` if the header is missing or
+    /// malformed.
+    fn credentials(headers: &HeaderMap) -> Option<(String, String)> {
+        let value = headers.get(header::AUTHORIZATION)?.to_str().ok()?;
+        let encoded = value.strip_prefix("Basic ")?;
+        let decoded = String::from_utf8(STANDARD.decode(encoded.trim()).ok()?).ok()?;
+        let (email, password) = decoded.split_once(':')?;
+
+        Some((email.to_string(), password.to_string()))
+    }
+}
+
+impl FromRequestParts<AppState> for BasicAuthUser {
+    type Rejection = ApiErrorResponse;
+
+    /// Extracts a [`BasicAuthUser`] from Basic credentials or cookies.
+    ///
+    /// # Arguments
+    ///
+    /// * `parts` — The HTTP request head (headers, URI, etc.).
+    /// * `state` — Shared application state containing the database pool
+    ///   and [`Config`](crate::core::config::Config).
+    ///
+    /// # Returns
+    ///
+    /// A [`BasicAuthUser`] for the user the credentials belong to.
+    ///
+    /// # Errors
+    ///
+    /// * [`ApiErrorResponse::Unauthorized`] — if no credentials are present,
+    ///   the credentials are wrong, the email is unconfirmed, or the access
+    ///   token is invalid/expired.
+    /// * [`ApiErrorResponse::Forbidden`] — if a personal access token lacks
+    ///   the route's scope.
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let Some((email, password)) = Self::credentials(&parts.headers) else {
+            let AuthUser { user_id, .. } = AuthUser::from_request_parts(parts, state).await?;
+
+            return Ok(BasicAuthUser { user_id });
+        };
+
+        if password == "api_token" {
+            let user_id =
+                AuthUser::authenticate_personal_access_token(parts, state, &email).await?;
+
+            return Ok(BasicAuthUser { user_id });
+        }
+
+        let invalid = || ApiErrorResponse::Unauthorized("Invalid credentials".to_string());
+        let credentials = UserRepo::find_credentials_by_email(&state.db_pool, &email).await?;
+        let password_hash = credentials
+            .as_ref()
+            .map(|credentials| credentials.password_hash.as_str());
+
+        if !PasswordUtil::verify_password_uniform(&password, password_hash)? {
+            return Err(invalid());
+        }
+
+        let user = credentials
+            .map(|credentials| credentials.user)
+            .ok_or_else(invalid)?;
+
+        if !user.email_confirmed {
+            return Err(invalid());
+        }
+
+        Ok(BasicAuthUser { user_id: user.id })
+    }
+}
@@ -1,10 +1,13 @@
 //! Authorization code generation.
 //!
-//! Provides a helper for generating random numeric codes used during
-//! email-based authentication flows.
+//! Provides helpers for generating random numeric codes used during
+//! email-based authentication flows and for checking guesses against them
+//! in constant time.
 
 use rand::RngExt;
 
+use crate::auth::token;
+
 /// Generates a random 6-digit authorization code.
 ///
 /// The code is zero-padded so it is always exactly six characters
@@ -17,3 +20,54 @@ pub fn generate() -> String {
     let code: u32 = rand::rng().random_range(0..1_000_000);
     format!("{code:06}")
 }
+
+/// Finds the code a guess matches without revealing which one, or how
+/// early, through timing.
+///
+/// Every candidate is compared in constant time, even after a match.
+///
+/// # Arguments
+///
+/// * `candidates` — The valid codes the guess may match.
+/// * `code_of` — Returns the code string of a candidate.
+/// * `guess` — The guessed code.
+///
+/// # Returns
+///
+/// The first matching candidate, or `None` if the guess matches none.
+pub fn find_match<T>(candidates: Vec<T>, code_of: impl Fn(&T) -> &str, guess: &str) -> Option<T> {
+    candidates.into_iter().fold(None, |found, candidate| {
+        let matches = token::constant_time_eq(code_of(&candidate), guess);
+
+        match found {
+            Some(found) => Some(found),
+            None if matches => Some(candidate),
+            None => None,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generates_six_digit_codes() {
+        let code = generate();
+
+        assert_eq!(code.len(), 6);
+        assert!(code.chars().all(|character| character.is_ascii_digit()));
+    }
+
+    #[test]
+    fn finds_the_matching_code() {
+        let candidates = vec!["004821", "123456", "123456"];
+
+        assert_eq!(
+            find_match(candidates.clone(), |code| code, "123456"),
+            Some("123456")
+        );
+        assert_eq!(find_match(candidates, |code| code, "000000"), None);
+        assert_eq!(find_match(Vec::<&str>::new(), |code| code, "123456"), None);
+    }
+}
@@ -1,9 +1,15 @@
 //! Cookie management for authentication tokens.
 //!
-//! Provides [`CookiesUtil`] with helpers for building and clearing
-//! the HTTP cookies that carry access and refresh JWT tokens.
+//! Provides [`CookiesUtil`] with helpers for building, reading, and
+//! clearing the HTTP cookies that carry access and refresh JWT tokens and
+//! the state of an OAuth sign-in in progress, and for choosing the cookie domain from the request host so one deployment
+//! can serve several domains.
 
-use axum_extra::extract::cookie::{Cookie, SameSite};
+use axum::http::HeaderMap;
+use axum_extra::extract::{
+    CookieJar,
+    cookie::{Cookie, SameSite},
+};
 use time::Duration;
 
 use crate::core::config::Config;
@@ -14,37 +20,112 @@ const ACCESS_TOKEN_COOKIE: &str = "access_token";
 /// Cookie name for the long-lived refresh token.
 const REFRESH_TOKEN_COOKIE: &str = "refresh_token";
 
-/// Utility for constructing and clearing authentication cookies.
+/// Cookie name for the state of an OAuth sign-in in progress.
+const OAUTH_STATE_COOKIE: &str = "oauth_state";
+
+/// How long an OAuth sign-in may take before its state cookie expires.
+const OAUTH_STATE_MAX_AGE: Duration = Duration::minutes(10);
+
+/// Name prefix for secure, host-only cookies.
+const HOST_PREFIX: &str = "__Host-";
+
+/// Name prefix for secure cookies scoped to a domain.
+const SECURE_PREFIX: &str = "__Secure-";
+
+/// Utility for constructing, reading, and clearing authentication cookies.
 ///
-/// All cookies are scoped to the root path (`/`) and marked
-/// `HttpOnly` with `SameSite::Lax`. The `Secure` flag is set
-/// automatically in non-development environments.
+/// All cookies are scoped to the root path (`/`) and marked `HttpOnly`
+/// with the configured `SameSite` attribute. When
+/// [`Config::cookie_secure`] is on, cookies are marked `Secure` and named
+/// with the `__Host-` prefix, or `__Secure-` when scoped to a domain since
+/// `__Host-` cookies cannot carry one. Cookies are scoped to the
+/// configured domain matching the request host, or are host-only when none
+/// matches. The OAuth state cookie is always host-only and `SameSite=Lax`,
+/// since it must be sent when the identity provider redirects back.
 pub struct CookiesUtil;
 
 impl CookiesUtil {
+    /// Selects the cookie domain for a request.
+    ///
+    /// The host is read from `X-Forwarded-Host`, falling back to `Host`.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` — The request headers.
+    /// * `config` — Application configuration providing the allowed
+    ///   cookie domains.
+    ///
+    /// # Returns
+    ///
+    /// The configured domain matching the request host, or `None` for
+    /// host-only cookies.
+    pub fn request_domain(headers: &HeaderMap, config: &Config) -> Option<String> {
+        let host = ["x-forwarded-host", "host"]
+            .into_iter()
+            .find_map(|name| headers.get(name)?.to_str().ok())?;
+
+        Self::domain_for_host(host, &config.cookie_domains)
+    }
+
+    /// Finds the configured domain that a host belongs to.
+    ///
+    /// A host matches a domain when it equals the domain or is one of its
+    /// subdomains. Ports are ignored. When several domains match, the most
+    /// specific one wins.
+    ///
+    /// # Arguments
+    ///
+    /// * `host` — The request host, optionally with a port.
+    /// * `domains` — The allowed cookie domains, lowercased.
+    ///
+    /// # Returns
+    ///
+    /// The matching domain, or `None` if the host matches none.
+    pub fn domain_for_host(host: &str, domains: &[String]) -> Option<String> {
+        let host = host.split(',').next()?.trim();
+        let host = host
+            .rsplit_once(':')
+            .filter(|(_, port)| port.chars().all(|c| c.is_ascii_digit()))
+            .map_or(host, |(name, _)| name)
+            .trim_end_matches('.')
+            .to_ascii_lowercase();
+
+        domains
+            .iter()
+            .filter(|domain| {
+                host == **domain
+                    || host
+                        .strip_suffix(domain.as_str())
+                        .is_some_and(|prefix| prefix.ends_with('.'))
+            })
+            .max_by_key(|domain| domain.len())
+            .cloned()
+    }
+
     /// Builds an access-token cookie.
     ///
     /// # Arguments
     ///
     /// * `token` — The signed JWT access token string.
-    /// * `config` — Application configuration, used for the token
-    ///   expiry and to determine whether the `Secure` flag is set.
+    /// * `domain` — The cookie domain, or `None` for a host-only cookie.
+    /// * `config` — Application configuration, used for the token expiry
+    ///   and the `Secure` and `SameSite` attributes.
     ///
     /// # Returns
     ///
     /// A [`Cookie`] ready to be attached to a response.
-    pub fn build_access_cookie(token: &str, config: &Config) -> Cookie<'static> {
-        let mut cookie = Cookie::build((ACCESS_TOKEN_COOKIE, token.to_string()))
-            .path("/")
-            .http_only(true)
-            .same_site(SameSite::Lax)
-            .max_age(Duration::seconds(
-                config.jwt_access_token_expiry_seconds as i64,
-            ))
-            .build();
-
-        cookie.set_secure(!config.is_development());
-        cookie
+    pub fn build_access_cookie(
+        token: &str,
+        domain: Option<&str>,
+        config: &Config,
+    ) -> Cookie<'static> {
+        Self::build_session_cookie(
+            ACCESS_TOKEN_COOKIE,
+            token,
+            domain,
+            config.jwt_access_token_expiry_seconds,
+            config,
+        )
     }
 
     /// Builds a refresh-token cookie.
@@ -52,53 +133,311 @@ impl CookiesUtil {
     /// # Arguments
     ///
     /// * `token` — The signed JWT refresh token string.
-    /// * `config` — Application configuration, used for the token
-    ///   expiry and to determine whether the `Secure` flag is set.
+    /// * `domain` — The cookie domain, or `None` for a host-only cookie.
+    /// * `config` — Application configuration, used for the token expiry
+    ///   and the `Secure` and `SameSite` attributes.
     ///
     /// # Returns
     ///
     /// A [`Cookie`] ready to be attached to a response.
-    pub fn build_refresh_cookie(token: &str, config: &Config) -> Cookie<'static> {
-        let mut cookie = Cookie::build((REFRESH_TOKEN_COOKIE, token.to_string()))
+    pub fn build_refresh_cookie(
+        token: &str,
+        domain: Option<&str>,
+        config: &Config,
+    ) -> Cookie<'static> {
+        Self::build_session_cookie(
+            REFRESH_TOKEN_COOKIE,
+            token,
+            domain,
+            config.jwt_refresh_token_expiry_seconds,
+            config,
+        )
+    }
+
+    /// Expires both session cookies.
+    ///
+    /// Cookies set without a name prefix, before
+    /// [`Config::cookie_secure`] was turned on, are expired as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `jar` — The [`CookieJar`] to add the expiring cookies to.
+    /// * `domain` — The domain the cookies were set for, or `None` for
+    ///   host-only cookies.
+    /// * `config` — Application configuration, used for the `Secure`
+    ///   attribute.
+    ///
+    /// # Returns
+    ///
+    /// The updated [`CookieJar`].
+    pub fn clear_session_cookies(
+        mut jar: CookieJar,
+        domain: Option<&str>,
+        config: &Config,
+    ) -> CookieJar {
+        for base in [ACCESS_TOKEN_COOKIE, REFRESH_TOKEN_COOKIE] {
+            let name = Self::cookie_name(base, domain, config.cookie_secure);
+            let mut cookie = Cookie::build((name.clone(), ""))
+                .path("/")
+                .max_age(Duration::ZERO)
+                .build();
+
+            if let Some(domain) = domain {
+                cookie.set_domain(domain.to_string());
+            }
+
+            cookie.set_secure(config.cookie_secure);
+            jar = jar.add(cookie);
+
+            if name != base {
+                jar = jar.add(
+                    Cookie::build((base, ""))
+                        .path("/")
+                        .max_age(Duration::ZERO)
+                        .build(),
+                );
+            }
+        }
+
+        jar
+    }
+
+    /// Builds the cookie binding an OAuth sign-in to the browser that
+    /// started it.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The state value sent to the identity provider.
+    /// * `config` — Application configuration, used for the `Secure`
+    ///   attribute.
+    ///
+    /// # Returns
+    ///
+    /// A [`Cookie`] ready to be attached to a response.
+    pub fn build_oauth_state_cookie(state: &str, config: &Config) -> Cookie<'static> {
+        let name = Self::cookie_name(OAUTH_STATE_COOKIE, None, config.cookie_secure);
+        let mut cookie = Cookie::build((name, state.to_string()))
             .path("/")
             .http_only(true)
             .same_site(SameSite::Lax)
-            .max_age(Duration::seconds(
-                config.jwt_refresh_token_expiry_seconds as i64,
-            ))
+            .max_age(OAUTH_STATE_MAX_AGE)
             .build();
 
-        cookie.set_secure(!config.is_development());
+        cookie.set_secure(config.cookie_secure);
         cookie
     }
 
-    /// Builds a cookie that clears the access token.
+    /// Expires the OAuth state cookie.
     ///
-    /// The cookie has an empty value and a zero max-age, which
-    /// instructs the browser to remove it.
+    /// # Arguments
+    ///
+    /// * `jar` — The [`CookieJar`] to add the expiring cookie to.
+    /// * `config` — Application configuration, used for the `Secure`
+    ///   attribute.
     ///
     /// # Returns
     ///
-    /// A [`Cookie`] that expires the access token cookie.
-    pub fn clear_access_cookie() -> Cookie<'static> {
-        Cookie::build((ACCESS_TOKEN_COOKIE, ""))
+    /// The updated [`CookieJar`].
+    pub fn clear_oauth_state_cookie(jar: CookieJar, config: &Config) -> CookieJar {
+        let name = Self::cookie_name(OAUTH_STATE_COOKIE, None, config.cookie_secure);
+        let mut cookie = Cookie::build((name, ""))
             .path("/")
             .max_age(Duration::ZERO)
-            .build()
+            .build();
+
+        cookie.set_secure(config.cookie_secure);
+        jar.add(cookie)
     }
 
-    /// Builds a cookie that clears the refresh token.
+    /// Reads the state of an OAuth sign-in in progress from the request
+    /// cookies.
     ///
-    /// The cookie has an empty value and a zero max-age, which
-    /// instructs the browser to remove it.
+    /// # Arguments
+    ///
+    /// * `headers` — The request headers.
     ///
     /// # Returns
     ///
-    /// A [`Cookie`] that expires the refresh token cookie.
-    pub fn clear_refresh_cookie() -> Cookie<'static> {
-        Cookie::build((REFRESH_TOKEN_COOKIE, ""))
+    /// The state value, or `None` if no OAuth state cookie is present.
+    pub fn oauth_state(headers: &HeaderMap) -> Option<&str> {
+        Self::find_cookie(headers, OAUTH_STATE_COOKIE)
+    }
+
+    /// Reads the access token from the request cookies.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` — The request headers.
+    ///
+    /// # Returns
+    ///
+    /// The token string, or `None` if no access token cookie is present.
+    pub fn access_token(headers: &HeaderMap) -> Option<&str> {
+        Self::find_cookie(headers, ACCESS_TOKEN_COOKIE)
+    }
+
+    /// Reads the refresh token from the request cookies.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` — The request headers.
+    ///
+    /// # Returns
+    ///
+    /// The token string, or `None` if no refresh token cookie is present.
+    pub fn refresh_token(headers: &HeaderMap) -> Option<&str> {
+        Self::find_cookie(headers, REFRESH_TOKEN_COOKIE)
+    }
+
+    /// Finds a session cookie's value under any of its names.
+    ///
+    /// The `__Host-` name is preferred over `__Secure-`, which is preferred
+    /// over the unprefixed name.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` — The request headers.
+    /// * `base` — The unprefixed cookie name.
+    ///
+    /// # Returns
+    ///
+    /// The cookie value, or `None` if the cookie is not present.
+    fn find_cookie<'a>(headers: &'a HeaderMap, base: &str) -> Option<&'a str> {
+        let pairs: Vec<(&str, &str)> = headers
+            .get_all("cookie")
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(';'))
+            .filter_map(|pair| pair.trim().split_once('='))
+            .collect();
+
+        [HOST_PREFIX, SECURE_PREFIX, ""]
+            .into_iter()
+            .find_map(|prefix| {
+                pairs.iter().find_map(|(name, value)| {
+                    (name.strip_prefix(prefix) == Some(base)).then_some(*value)
+                })
+            })
+    }
+
+    /// Returns the name a session cookie is set under.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` — The unprefixed cookie name.
+    /// * `domain` — The cookie domain, or `None` for a host-only cookie.
+    /// * `secure` — Whether the cookie is marked `Secure`.
+    ///
+    /// # Returns
+    ///
+    /// The cookie name with its `__Host-` or `__Secure-` prefix, or `base`
+    /// when the cookie is not secure.
+    fn cookie_name(base: &str, domain: Option<&str>, secure: bool) -> String {
+        match (secure, domain) {
+            (false, _) => base.to_string(),
+            (true, None) => format!("{HOST_PREFIX}{base}"),
+            (true, Some(_)) => format!("{SECURE_PREFIX}{base}"),
+        }
+    }
+
+    /// Builds a session cookie carrying a token.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` — The unprefixed cookie name.
+    /// * `token` — The signed JWT token string.
+    /// * `domain` — The cookie domain, or `None` for a host-only cookie.
+    /// * `max_age_seconds` — How long the cookie lives.
+    /// * `config` — Application configuration, used for the `Secure` and
+    ///   `SameSite` attributes.
+    ///
+    /// # Returns
+    ///
+    /// The configured [`Cookie`].
+    fn build_session_cookie(
+        base: &str,
+        token: &str,
+        domain: Option<&str>,
+        max_age_seconds: u64,
+        config: &Config,
+    ) -> Cookie<'static> {
+        let name = Self::cookie_name(base, domain, config.cookie_secure);
+        let mut cookie = Cookie::build((name, token.to_string()))
             .path("/")
-            .max_age(Duration::ZERO)
-            .build()
+            .http_only(true)
+            .same_site(config.cookie_same_site)
+            .max_age(Duration::seconds(max_age_seconds as i64))
+            .build();
+
+        if let Some(domain) = domain {
+            cookie.set_domain(domain.to_string());
+        }
+
+        cookie.set_secure(config.cookie_secure);
+        cookie
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn domains() -> Vec<String> {
+        vec!["giglog.dev".to_string(), "client.example.com".to_string()]
+    }
+
+    #[test]
+    fn matches_domains_and_subdomains() {
+        assert_eq!(
+            CookiesUtil::domain_for_host("app.giglog.dev", &domains()),
+            Some("giglog.dev".to_string())
+        );
+        assert_eq!(
+            CookiesUtil::domain_for_host("Client.Example.com:8443", &domains()),
+            Some("client.example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn falls_back_to_host_only_cookies() {
+        assert_eq!(
+            CookiesUtil::domain_for_host("evilgiglog.dev", &domains()),
+            None
+        );
+        assert_eq!(
+            CookiesUtil::domain_for_host("localhost:8000", &domains()),
+            None
+        );
+        assert_eq!(CookiesUtil::domain_for_host("app.giglog.dev", &[]), None);
+    }
+
+    #[test]
+    fn prefixes_secure_cookie_names() {
+        assert_eq!(
+            CookiesUtil::cookie_name("access_token", None, true),
+            "__Host-access_token"
+        );
+        assert_eq!(
+            CookiesUtil::cookie_name("access_token", Some("giglog.dev"), true),
+            "__Secure-access_token"
+        );
+        assert_eq!(
+            CookiesUtil::cookie_name("access_token", None, false),
+            "access_token"
+        );
+    }
+
+    #[test]
+    fn reads_prefixed_cookies_first() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "cookie",
+            "access_token=plain; refresh_token=r; __Host-access_token=host"
+                .parse()
+                .unwrap(),
+        );
+
+        assert_eq!(CookiesUtil::access_token(&headers), Some("host"));
+        assert_eq!(CookiesUtil::refresh_token(&headers), Some("r"));
     }
 }
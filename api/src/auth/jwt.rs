@@ -2,8 +2,12 @@
 //!
 //! Provides [`JwtUtil`] for generating and validating access and
 //! refresh tokens, and the [`Claims`] payload embedded in each token.
+//! Every token names the configured issuer and an audience, and access
+//! and refresh tokens use different audiences so neither can be replayed
+//! as the other or against another service sharing the secret.
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use gig_log_common::models::personal_access_token::ApiScope;
 use jsonwebtoken::{DecodingKey, EncodingKey, Header, TokenData, Validation, decode, encode};
 use log::error;
 use serde::{Deserialize, Serialize};
@@ -12,6 +16,9 @@ use uuid::Uuid;
 use crate::core::config::Config;
 use crate::core::error::ApiErrorResponse;
 
+/// Suffix appended to [`Config::jwt_audience`] for refresh tokens.
+const REFRESH_AUDIENCE_SUFFIX: &str = ":refresh";
+
 /// The payload embedded in every JWT issued by the application.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claims {
@@ -21,6 +28,38 @@ pub struct Claims {
     pub exp: i64,
     /// Issued-at time as a Unix timestamp (seconds).
     pub iat: i64,
+    /// Issuer — the service that signed the token.
+    pub iss: String,
+    /// Audience — who the token is intended for.
+    pub aud: String,
+    /// Space-separated [`ApiScope`] names the token is limited to. `None`
+    /// grants full access.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    /// When the user last authenticated, as a Unix timestamp (seconds).
+    /// Carried over when the session is refreshed, and `None` for tokens
+    /// not tied to a log-in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_time: Option<i64>,
+}
+
+impl Claims {
+    /// Returns the scopes the token is limited to.
+    ///
+    /// Unknown scope names are ignored.
+    ///
+    /// # Returns
+    ///
+    /// The [`ApiScope`] values in the `scope` claim, or `None` if the token
+    /// is not limited.
+    pub fn scopes(&self) -> Option<Vec<ApiScope>> {
+        self.scope.as_deref().map(|scope| {
+            scope
+                .split_whitespace()
+                .filter_map(ApiScope::parse)
+                .collect()
+        })
+    }
 }
 
 /// Utility for generating and validating JWT tokens.
@@ -34,8 +73,9 @@ impl JwtUtil {
     /// # Arguments
     ///
     /// * `user_id` — The [`Uuid`] of the authenticated user.
-    /// * `config` — Application configuration providing the JWT secret
-    ///   and access-token expiry duration.
+    /// * `auth_time` — When the user logged in to start the session.
+    /// * `config` — Application configuration providing the JWT secret,
+    ///   issuer, audience, and access-token expiry duration.
     ///
     /// # Returns
     ///
@@ -47,18 +87,16 @@ impl JwtUtil {
     /// encoding fails.
     pub fn generate_access_token(
         user_id: Uuid,
+        auth_time: DateTime<Utc>,
         config: &Config,
     ) -> Result<String, ApiErrorResponse> {
-        let now = Utc::now().timestamp();
-        let claims = Claims {
-            sub: user_id,
-            exp: now + config.jwt_access_token_expiry_seconds as i64,
-            iat: now,
-        };
-        encode(
-            &Header::default(),
-            &claims,
-            &EncodingKey::from_secret(config.jwt_secret.as_bytes()),
+        Self::generate_token(
+            user_id,
+            config.jwt_audience.clone(),
+            config.jwt_access_token_expiry_seconds,
+            None,
+            Some(auth_time.timestamp()),
+            config,
         )
         .map_err(|error| {
             error!("Failed to generate access token: {}", error);
@@ -66,13 +104,55 @@ impl JwtUtil {
         })
     }
 
+    /// Generates a short-lived JWT access token limited to a set of scopes.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` — The [`Uuid`] of the authenticated user.
+    /// * `scopes` — The [`ApiScope`] values the token may use.
+    /// * `config` — Application configuration providing the JWT secret,
+    ///   issuer, audience, and access-token expiry duration.
+    ///
+    /// # Returns
+    ///
+    /// The encoded JWT [`String`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::InternalServerError`] if token
+    /// encoding fails.
+    pub fn generate_scoped_access_token(
+        user_id: Uuid,
+        scopes: &[ApiScope],
+        config: &Config,
+    ) -> Result<String, ApiErrorResponse> {
+        let scope = scopes
+            .iter()
+            .map(ApiScope::as_str)
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        Self::generate_token(
+            user_id,
+            config.jwt_audience.clone(),
+            config.jwt_access_token_expiry_seconds,
+            Some(scope),
+            None,
+            config,
+        )
+        .map_err(|error| {
+            error!("Failed to generate scoped access token: {}", error);
+            ApiErrorResponse::InternalServerError("Failed to generate access token".to_string())
+        })
+    }
+
     /// Generates a long-lived JWT refresh token for the given user.
     ///
     /// # Arguments
     ///
     /// * `user_id` — The [`Uuid`] of the authenticated user.
-    /// * `config` — Application configuration providing the JWT secret
-    ///   and refresh-token expiry duration.
+    /// * `config` — Application configuration providing the JWT secret,
+    ///   issuer, audience, and refresh-token expiry duration.
     ///
     /// # Returns
     ///
@@ -86,51 +166,228 @@ impl JwtUtil {
         user_id: Uuid,
         config: &Config,
     ) -> Result<String, ApiErrorResponse> {
+        Self::generate_token(
+            user_id,
+            Self::refresh_audience(config),
+            config.jwt_refresh_token_expiry_seconds,
+            None,
+            None,
+            config,
+        )
+        .map_err(|error| {
+            error!("Failed to generate refresh token: {}", error);
+            ApiErrorResponse::InternalServerError("Failed to generate refresh token".to_string())
+        })
+    }
+
+    /// Validates and decodes a JWT access token.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` — The raw JWT string to validate.
+    /// * `config` — Application configuration providing the JWT secret,
+    ///   issuer, and audience.
+    ///
+    /// # Returns
+    ///
+    /// A [`TokenData<Claims>`] containing the decoded [`Claims`] and
+    /// token header metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the token is
+    /// malformed, expired, has an invalid signature, or was not issued by
+    /// this service as an access token.
+    pub fn decode_access_token(
+        token: &str,
+        config: &Config,
+    ) -> Result<TokenData<Claims>, ApiErrorResponse> {
+        Self::decode_token(
+            token,
+            &config.jwt_secret,
+            &config.jwt_issuer,
+            &config.jwt_audience,
+        )
+    }
+
+    /// Validates and decodes a JWT refresh token.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` — The raw JWT string to validate.
+    /// * `config` — Application configuration providing the JWT secret,
+    ///   issuer, and audience.
+    ///
+    /// # Returns
+    ///
+    /// A [`TokenData<Claims>`] containing the decoded [`Claims`] and
+    /// token header metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the token is
+    /// malformed, expired, has an invalid signature, or was not issued by
+    /// this service as a refresh token.
+    pub fn decode_refresh_token(
+        token: &str,
+        config: &Config,
+    ) -> Result<TokenData<Claims>, ApiErrorResponse> {
+        Self::decode_token(
+            token,
+            &config.jwt_secret,
+            &config.jwt_issuer,
+            &Self::refresh_audience(config),
+        )
+    }
+
+    /// Signs a token with the given audience and lifetime.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` — The [`Uuid`] of the authenticated user.
+    /// * `audience` — The token's `aud` claim.
+    /// * `expiry_seconds` — How long the token is valid.
+    /// * `scope` — The token's `scope` claim, if limited.
+    /// * `auth_time` — The token's `auth_time` claim, if any.
+    /// * `config` — Application configuration providing the JWT secret
+    ///   and issuer.
+    ///
+    /// # Returns
+    ///
+    /// The encoded JWT [`String`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`jsonwebtoken::errors::Error`] if encoding fails.
+    fn generate_token(
+        user_id: Uuid,
+        audience: String,
+        expiry_seconds: u64,
+        scope: Option<String>,
+        auth_time: Option<i64>,
+        config: &Config,
+    ) -> Result<String, jsonwebtoken::errors::Error> {
         let now = Utc::now().timestamp();
         let claims = Claims {
             sub: user_id,
-            exp: now + config.jwt_refresh_token_expiry_seconds as i64,
+            exp: now + expiry_seconds as i64,
             iat: now,
+            iss: config.jwt_issuer.clone(),
+            aud: audience,
+            scope,
+            auth_time,
         };
+
         encode(
             &Header::default(),
             &claims,
             &EncodingKey::from_secret(config.jwt_secret.as_bytes()),
         )
-        .map_err(|error| {
-            error!("Failed to generate refresh token: {}", error);
-            ApiErrorResponse::InternalServerError("Failed to generate refresh token".to_string())
-        })
     }
 
-    /// Validates and decodes a JWT token.
+    /// Decodes a token, requiring the given issuer and audience.
     ///
     /// # Arguments
     ///
     /// * `token` — The raw JWT string to validate.
-    /// * `config` — Application configuration providing the JWT secret.
+    /// * `secret` — The HMAC signing secret.
+    /// * `issuer` — The required `iss` claim.
+    /// * `audience` — The required `aud` claim.
     ///
     /// # Returns
     ///
-    /// A [`TokenData<Claims>`] containing the decoded [`Claims`] and
-    /// token header metadata.
+    /// A [`TokenData<Claims>`] containing the decoded [`Claims`].
     ///
     /// # Errors
     ///
-    /// Returns [`ApiErrorResponse::BadRequest`] if the token is
-    /// malformed, expired, or has an invalid signature.
-    pub fn validate_token(
+    /// Returns [`ApiErrorResponse::BadRequest`] if the token is invalid.
+    fn decode_token(
         token: &str,
-        config: &Config,
+        secret: &str,
+        issuer: &str,
+        audience: &str,
     ) -> Result<TokenData<Claims>, ApiErrorResponse> {
+        let mut validation = Validation::default();
+        validation.set_issuer(&[issuer]);
+        validation.set_audience(&[audience]);
+        validation.set_required_spec_claims(&["exp", "sub", "iss", "aud"]);
+
         decode::<Claims>(
             token,
-            &DecodingKey::from_secret(config.jwt_secret.as_bytes()),
-            &Validation::default(),
+            &DecodingKey::from_secret(secret.as_bytes()),
+            &validation,
         )
         .map_err(|error| {
             error!("Failed to validate token: {}", error);
             ApiErrorResponse::BadRequest("Invalid or expired token".to_string())
         })
     }
+
+    /// Returns the audience of refresh tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` — Application configuration providing the audience.
+    ///
+    /// # Returns
+    ///
+    /// The configured audience with the refresh suffix.
+    fn refresh_audience(config: &Config) -> String {
+        format!("{}{REFRESH_AUDIENCE_SUFFIX}", config.jwt_audience)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "test-secret";
+
+    fn token(issuer: &str, audience: &str, scope: Option<&str>) -> String {
+        let now = Utc::now().timestamp();
+        let claims = Claims {
+            sub: Uuid::new_v4(),
+            exp: now + 60,
+            iat: now,
+            iss: issuer.to_string(),
+            aud: audience.to_string(),
+            scope: scope.map(ToString::to_string),
+            auth_time: None,
+        };
+
+        encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(SECRET.as_bytes()),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn requires_matching_issuer_and_audience() {
+        let access = token("gig-log-api", "gig-log-web", None);
+
+        assert!(JwtUtil::decode_token(&access, SECRET, "gig-log-api", "gig-log-web").is_ok());
+        assert!(
+            JwtUtil::decode_token(&access, SECRET, "gig-log-api", "gig-log-web:refresh").is_err()
+        );
+        assert!(JwtUtil::decode_token(&access, SECRET, "other-api", "gig-log-web").is_err());
+    }
+
+    #[test]
+    fn reads_scope_claim() {
+        let scoped = token("gig-log-api", "gig-log-web", Some("read:jobs unknown"));
+        let claims = JwtUtil::decode_token(&scoped, SECRET, "gig-log-api", "gig-log-web")
+            .unwrap()
+            .claims;
+
+        assert_eq!(claims.scopes(), Some(vec![ApiScope::ReadJobs]));
+
+        let unscoped = token("gig-log-api", "gig-log-web", None);
+        let claims = JwtUtil::decode_token(&unscoped, SECRET, "gig-log-api", "gig-log-web")
+            .unwrap()
+            .claims;
+
+        assert_eq!(claims.scopes(), None);
+    }
 }
@@ -0,0 +1,266 @@
+//! Signed deep links for emailed authorization codes.
+//!
+//! Provides [`LinkUtil`] for signing one-click links to an authorization
+//! code and for verifying a link's token when it is opened. Link tokens
+//! are JWTs signed with [`Config::jwt_secret`] under their own audience,
+//! so they cannot be replayed as access or refresh tokens, and they expire
+//! together with the code they point at.
+
+use chrono::{DateTime, Utc};
+use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use log::error;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::core::config::Config;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::repo::auth_code::AuthCodeType;
+
+/// Suffix appended to [`Config::jwt_audience`] for link tokens.
+const LINK_AUDIENCE_SUFFIX: &str = ":link";
+
+/// The payload embedded in a link token.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LinkClaims {
+    /// Subject — the ID of the authorization code the link completes.
+    pub sub: Uuid,
+    /// The category of the authorization code.
+    pub purpose: AuthCodeType,
+    /// Expiration time as a Unix timestamp (seconds).
+    pub exp: i64,
+    /// Issued-at time as a Unix timestamp (seconds).
+    pub iat: i64,
+    /// Issuer — the service that signed the token.
+    pub iss: String,
+    /// Audience — always the link audience.
+    pub aud: String,
+}
+
+/// Utility for building and verifying signed email links.
+pub struct LinkUtil;
+
+impl LinkUtil {
+    /// Builds the signed link that completes an authorization code.
+    ///
+    /// Password-reset links point at `GET /auth/reset-password`; every
+    /// other code type points at `GET /auth/confirm`.
+    ///
+    /// # Arguments
+    ///
+    /// * `code_id` — The ID of the authorization code.
+    /// * `purpose` — The category of the authorization code.
+    /// * `expires_at` — When the code, and so the link, expires.
+    /// * `config` — Application configuration providing the signing
+    ///   secret, issuer, audience, and public API URL.
+    ///
+    /// # Returns
+    ///
+    /// The absolute link URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::InternalServerError`] if signing fails.
+    pub fn link_for_code(
+        code_id: Uuid,
+        purpose: AuthCodeType,
+        expires_at: DateTime<Utc>,
+        config: &Config,
+    ) -> ApiResult<String> {
+        let claims = LinkClaims {
+            sub: code_id,
+            purpose,
+            exp: expires_at.timestamp(),
+            iat: Utc::now().timestamp(),
+            iss: config.jwt_issuer.clone(),
+            aud: Self::audience(&config.jwt_audience),
+        };
+
+        let token = encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(config.jwt_secret.as_bytes()),
+        )
+        .map_err(|error| {
+            error!("Failed to sign email link: {}", error);
+            ApiErrorResponse::InternalServerError("Failed to sign email link".to_string())
+        })?;
+
+        let path = match purpose {
+            AuthCodeType::PasswordReset => "reset-password",
+            _ => "confirm",
+        };
+
+        Ok(format!(
+            "{}/auth/{path}?token={token}",
+            config.api_public_url.trim_end_matches('/')
+        ))
+    }
+
+    /// Validates and decodes a link token.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` — The raw token from the link's query string.
+    /// * `config` — Application configuration providing the signing
+    ///   secret, issuer, and audience.
+    ///
+    /// # Returns
+    ///
+    /// The decoded [`LinkClaims`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the token is malformed,
+    /// expired, has an invalid signature, or is not a link token.
+    pub fn decode_link_token(token: &str, config: &Config) -> ApiResult<LinkClaims> {
+        Self::decode_token(
+            token,
+            &config.jwt_secret,
+            &config.jwt_issuer,
+            &Self::audience(&config.jwt_audience),
+        )
+    }
+
+    /// Builds a URL on the web client.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` — The path, including any query string, to append.
+    /// * `config` — Application configuration providing the web origins.
+    ///
+    /// # Returns
+    ///
+    /// The path prefixed with the first configured web origin.
+    pub fn web_url(path: &str, config: &Config) -> String {
+        let origin = config
+            .web_origins
+            .first()
+            .map(|origin| origin.trim_end_matches('/'))
+            .unwrap_or_default();
+
+        format!("{origin}{path}")
+    }
+
+    /// Percent-encodes a value for use in a URL query string.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — The value to encode.
+    ///
+    /// # Returns
+    ///
+    /// The value with every byte outside the unreserved set encoded.
+    pub fn encode_query_value(value: &str) -> String {
+        value
+            .bytes()
+            .map(|byte| match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                    (byte as char).to_string()
+                }
+                _ => format!("%{byte:02X}"),
+            })
+            .collect()
+    }
+
+    /// Decodes a link token, requiring the given issuer and audience.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` — The raw token to validate.
+    /// * `secret` — The HMAC signing secret.
+    /// * `issuer` — The required `iss` claim.
+    /// * `audience` — The required `aud` claim.
+    ///
+    /// # Returns
+    ///
+    /// The decoded [`LinkClaims`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the token is invalid.
+    fn decode_token(
+        token: &str,
+        secret: &str,
+        issuer: &str,
+        audience: &str,
+    ) -> ApiResult<LinkClaims> {
+        let mut validation = Validation::default();
+        validation.set_issuer(&[issuer]);
+        validation.set_audience(&[audience]);
+        validation.set_required_spec_claims(&["exp", "sub", "iss", "aud"]);
+
+        decode::<LinkClaims>(
+            token,
+            &DecodingKey::from_secret(secret.as_bytes()),
+            &validation,
+        )
+        .map(|data| data.claims)
+        .map_err(|error| {
+            error!("Failed to validate email link: {}", error);
+            ApiErrorResponse::BadRequest("Invalid or expired link".to_string())
+        })
+    }
+
+    /// Returns the audience of link tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `jwt_audience` — The configured access-token audience.
+    ///
+    /// # Returns
+    ///
+    /// The audience with the link suffix.
+    fn audience(jwt_audience: &str) -> String {
+        format!("{jwt_audience}{LINK_AUDIENCE_SUFFIX}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "test-secret";
+
+    fn token(audience: &str, purpose: AuthCodeType) -> String {
+        let now = Utc::now().timestamp();
+        let claims = LinkClaims {
+            sub: Uuid::new_v4(),
+            purpose,
+            exp: now + 60,
+            iat: now,
+            iss: "gig-log-api".to_string(),
+            aud: audience.to_string(),
+        };
+
+        encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(SECRET.as_bytes()),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn requires_link_audience() {
+        let link_audience = LinkUtil::audience("gig-log-web");
+        let link = token(&link_audience, AuthCodeType::PasswordReset);
+        let claims = LinkUtil::decode_token(&link, SECRET, "gig-log-api", &link_audience).unwrap();
+
+        assert_eq!(claims.purpose, AuthCodeType::PasswordReset);
+
+        let access = token("gig-log-web", AuthCodeType::EmailVerification);
+
+        assert!(LinkUtil::decode_token(&access, SECRET, "gig-log-api", &link_audience).is_err());
+        assert!(
+            LinkUtil::decode_token(&link, "other-secret", "gig-log-api", &link_audience).is_err()
+        );
+    }
+
+    #[test]
+    fn encodes_query_values() {
+        assert_eq!(
+            LinkUtil::encode_query_value("jo+gigs@example.com"),
+            "jo%2Bgigs%40example.com"
+        );
+    }
+}
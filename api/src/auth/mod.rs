@@ -6,16 +6,28 @@
 //!
 //! # Modules
 //!
+//! - [`anomaly`](crate::auth::anomaly) — Unusual account activity detection and re-authentication holds.
+//! - [`basic`](crate::auth::basic) — [`BasicAuthUser`](crate::auth::basic::BasicAuthUser) extractor for HTTP Basic API clients.
 //! - [`code`](crate::auth::code) — Authorization code generation.
 //! - [`cookies`](crate::auth::cookies) — Cookie construction and clearing for auth tokens.
 //! - [`jwt`](crate::auth::jwt) — JWT token creation and validation.
+//! - [`link`](crate::auth::link) — Signed deep links for emailed authorization codes.
 //! - [`password`](crate::auth::password) — Password hashing and verification with Argon2.
+//! - [`scope`](crate::auth::scope) — Scopes required by routes for personal access tokens.
+//! - [`token`](crate::auth::token) — Opaque token generation and hashing.
 //! - [`user`](crate::auth::user) — [`AuthUser`](crate::auth::AuthUser) Axum extractor for protected routes.
+//! - [`webauthn`](crate::auth::webauthn) — WebAuthn passkey challenge and response verification.
 
+pub mod anomaly;
+pub mod basic;
 pub mod code;
 pub mod cookies;
 pub mod jwt;
+pub mod link;
 pub mod password;
+pub mod scope;
+pub mod token;
 pub mod user;
+pub mod webauthn;
 
 pub use user::AuthUser;
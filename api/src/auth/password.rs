@@ -1,15 +1,26 @@
 //! Password hashing and verification.
 //!
 //! Provides [`PasswordUtil`] for securely hashing passwords with
-//! Argon2 and verifying plaintext passwords against stored hashes.
+//! Argon2, verifying plaintext passwords against stored hashes, including
+//! a uniform-time check for accounts that may not exist, and enforcing the
+//! configured [`PasswordPolicy`] on new passwords.
 
 use argon2::{
     Argon2,
     password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString, rand_core::OsRng},
 };
 
+use gig_log_common::validators::password_strength::PasswordPolicy;
+
+use crate::core::config::Config;
 use crate::core::error::{ApiErrorResponse, ApiResult};
 
+/// Argon2 hash verified in place of a real one when no account exists, so
+/// failed log-ins take the same time whether or not the email is known.
+///
+/// Uses the same parameters as [`Argon2::default`].
+const DUMMY_PASSWORD_HASH: &str = "$argon2id$v=19$m=19456,t=2,p=1$0fWPMRysFG5MJbx4VzvRpw$V3xNw8BevtFby9vKsoLYLt7bFBWUuwB2Z4jxdIwNcRU";
+
 /// Utility for hashing and verifying passwords with Argon2.
 pub struct PasswordUtil;
 
@@ -59,4 +70,97 @@ impl PasswordUtil {
             .verify_password(password.as_bytes(), &parsed_hash)
             .is_ok())
     }
+
+    /// Verifies a plaintext password against a hash that may not exist.
+    ///
+    /// When `hash` is `None` the password is checked against a dummy hash
+    /// and rejected, so the call costs the same as a real verification.
+    ///
+    /// # Arguments
+    ///
+    /// * `password` — The plaintext password to check.
+    /// * `hash` — The stored Argon2 hash, or `None` if there is no account.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a hash exists and the password matches it, `false`
+    /// otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::InternalServerError`] if the hash
+    /// string cannot be parsed.
+    pub fn verify_password_uniform(password: &str, hash: Option<&str>) -> ApiResult<bool> {
+        match hash {
+            Some(hash) => Self::verify_password(password, hash),
+            None => {
+                Self::verify_password(password, DUMMY_PASSWORD_HASH)?;
+
+                Ok(false)
+            }
+        }
+    }
+
+    /// Checks a new password against the configured password policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` — The request field the password was sent in.
+    /// * `password` — The new password.
+    /// * `user_inputs` — The user's name and email address, which the
+    ///   password should not contain.
+    /// * `config` — Application configuration providing the policy.
+    ///
+    /// # Returns
+    ///
+    /// `()` if the password is long and strong enough.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`] with feedback on `field` if
+    /// the password is too short or too easy to guess.
+    pub fn enforce_policy(
+        field: &str,
+        password: &str,
+        user_inputs: &[&str],
+        config: &Config,
+    ) -> ApiResult<()> {
+        let policy = PasswordPolicy {
+            min_length: config.password_min_length,
+            min_score: config.password_min_score,
+        };
+
+        policy
+            .validate(field, password, user_inputs)
+            .map_err(ApiErrorResponse::Validation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use argon2::Params;
+
+    use super::*;
+
+    #[test]
+    fn dummy_hash_costs_the_same_as_real_hashes() {
+        let dummy = PasswordHash::new(DUMMY_PASSWORD_HASH).unwrap();
+        let real_hash = PasswordUtil::hash_password("correct horse").unwrap();
+        let real = PasswordHash::new(&real_hash).unwrap();
+
+        assert_eq!(dummy.algorithm, real.algorithm);
+        assert_eq!(
+            Params::try_from(&dummy).unwrap(),
+            Params::try_from(&real).unwrap()
+        );
+    }
+
+    #[test]
+    fn uniform_verification_rejects_missing_accounts() {
+        let hash = PasswordUtil::hash_password("correct horse").unwrap();
+
+        assert!(PasswordUtil::verify_password_uniform("correct horse", Some(&hash)).unwrap());
+        assert!(!PasswordUtil::verify_password_uniform("wrong", Some(&hash)).unwrap());
+        assert!(!PasswordUtil::verify_password_uniform("correct horse", None).unwrap());
+    }
 }
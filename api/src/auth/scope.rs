@@ -0,0 +1,121 @@
+//! Route-to-scope mapping for personal access tokens.
+//!
+//! Provides [`ScopeUtil`], which determines the [`ApiScope`] a personal
+//! access token needs to call a route. Routes are grouped by their top-level
+//! path segment; `GET` requests need the resource's read scope and all other
+//! methods need its write scope. Imports under `/import` are grouped with
+//! the resource they create. Routes outside these groups, such as account
+//! management, cannot be called with a personal access token. Version
+//! prefixes such as `/v1` are ignored, so every API version shares the same
+//! scopes.
+
+use axum::http::Method;
+use gig_log_common::models::personal_access_token::ApiScope;
+
+use crate::core::versioning::ApiVersion;
+
+/// Utility for resolving the scope a route requires.
+pub struct ScopeUtil;
+
+impl ScopeUtil {
+    /// Determines the scope required to call a route.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` — The request's HTTP method.
+    /// * `path` — The matched route pattern or request path, including its
+    ///   top-level prefix and optional version prefix.
+    ///
+    /// # Returns
+    ///
+    /// The required [`ApiScope`], or `None` if personal access tokens may
+    /// not call the route.
+    pub fn required_scope(method: &Method, path: &str) -> Option<ApiScope> {
+        let read = method == Method::GET;
+        let mut segments = ApiVersion::strip_prefix(path)
+            .trim_start_matches('/')
+            .split('/');
+        let mut segment = segments.next()?;
+        if segment == "import" {
+            segment = segments.next()?;
+        }
+
+        let (read_scope, write_scope) = match segment {
+            "budgets" => (ApiScope::ReadBudgets, ApiScope::WriteBudgets),
+            "companies" => (ApiScope::ReadCompanies, ApiScope::WriteCompanies),
+            "contracts" => (ApiScope::ReadContracts, ApiScope::WriteContracts),
+            "invoices" => (ApiScope::ReadInvoices, ApiScope::WriteInvoices),
+            "jobs" => (ApiScope::ReadJobs, ApiScope::WriteJobs),
+            "leads" => (ApiScope::ReadLeads, ApiScope::WriteLeads),
+            "mileage" => (ApiScope::ReadMileage, ApiScope::WriteMileage),
+            "payments" | "recurring-payments" => (ApiScope::ReadPayments, ApiScope::WritePayments),
+            "time-off" => (ApiScope::ReadTimeOff, ApiScope::WriteTimeOff),
+            "timesheets" => (ApiScope::ReadTimesheets, ApiScope::WriteTimesheets),
+            "work-sessions" | "work-session-drafts" | "reports" | "api" => {
+                (ApiScope::ReadWorkSessions, ApiScope::WriteWorkSessions)
+            }
+            _ => return None,
+        };
+
+        Some(if read { read_scope } else { write_scope })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_requests_need_read_scope() {
+        assert_eq!(
+            ScopeUtil::required_scope(&Method::GET, "/companies/{id}/unbilled"),
+            Some(ApiScope::ReadCompanies)
+        );
+        assert_eq!(
+            ScopeUtil::required_scope(&Method::GET, "/api/v9/me/time_entries"),
+            Some(ApiScope::ReadWorkSessions)
+        );
+    }
+
+    #[test]
+    fn other_methods_need_write_scope() {
+        assert_eq!(
+            ScopeUtil::required_scope(&Method::POST, "/work-sessions/{id}/pause"),
+            Some(ApiScope::WriteWorkSessions)
+        );
+        assert_eq!(
+            ScopeUtil::required_scope(&Method::DELETE, "/time-off/{id}"),
+            Some(ApiScope::WriteTimeOff)
+        );
+        assert_eq!(
+            ScopeUtil::required_scope(&Method::POST, "/import/work-sessions"),
+            Some(ApiScope::WriteWorkSessions)
+        );
+        assert_eq!(
+            ScopeUtil::required_scope(&Method::POST, "/leads/{id}/convert"),
+            Some(ApiScope::WriteLeads)
+        );
+    }
+
+    #[test]
+    fn versioned_routes_share_unversioned_scopes() {
+        assert_eq!(
+            ScopeUtil::required_scope(&Method::GET, "/v1/companies/{id}/unbilled"),
+            Some(ApiScope::ReadCompanies)
+        );
+        assert_eq!(
+            ScopeUtil::required_scope(&Method::POST, "/v1/import/work-sessions"),
+            Some(ApiScope::WriteWorkSessions)
+        );
+        assert_eq!(ScopeUtil::required_scope(&Method::GET, "/v1/auth/me"), None);
+    }
+
+    #[test]
+    fn account_routes_have_no_scope() {
+        assert_eq!(ScopeUtil::required_scope(&Method::GET, "/auth/me"), None);
+        assert_eq!(
+            ScopeUtil::required_scope(&Method::POST, "/personal-access-tokens"),
+            None
+        );
+    }
+}
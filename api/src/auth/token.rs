@@ -0,0 +1,64 @@
+//! Opaque token generation and hashing.
+//!
+//! Provides helpers for generating random URL-safe tokens used in
+//! shareable links, for hashing tokens before storage so raw token
+//! values are never persisted to the database, and for comparing secrets
+//! in constant time.
+
+use rand::RngExt;
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+
+/// Generates a random 256-bit token encoded as lowercase hex.
+///
+/// # Returns
+///
+/// A 64-character hex [`String`].
+pub fn generate() -> String {
+    let bytes: [u8; 32] = rand::rng().random();
+
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Computes a hex-encoded SHA-256 hash of the given input.
+///
+/// # Arguments
+///
+/// * `input` — The string to hash.
+///
+/// # Returns
+///
+/// The lowercase hex-encoded SHA-256 digest as a [`String`].
+pub fn hash(input: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(input.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// Compares two secrets without short-circuiting on the first difference.
+///
+/// Only the lengths of the inputs can affect how long the comparison takes.
+///
+/// # Arguments
+///
+/// * `left` — The first secret.
+/// * `right` — The second secret.
+///
+/// # Returns
+///
+/// `true` if the secrets are equal.
+pub fn constant_time_eq(left: &str, right: &str) -> bool {
+    left.as_bytes().ct_eq(right.as_bytes()).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compares_secrets() {
+        assert!(constant_time_eq(&hash("secret"), &hash("secret")));
+        assert!(!constant_time_eq(&hash("secret"), &hash("Secret")));
+        assert!(!constant_time_eq("secret", "secret-longer"));
+    }
+}
@@ -2,72 +2,294 @@
 //!
 //! Provides [`AuthUser`], an Axum [`FromRequestParts`] extractor that
 //! reads the `access_token` cookie, validates the JWT, and yields the
-//! caller's user ID. Including `AuthUser` as a handler parameter is
-//! sufficient to enforce authentication on a route.
+//! caller's user ID. Requests may instead carry a personal access token as
+//! `Authorization: Bearer <token>`, in which case the token must grant the
+//! scope the matched route requires, or an API key the same way, which
+//! grants the same access as the cookie. Including `AuthUser` as a handler
+//! parameter is sufficient to enforce authentication on a route.
 
-use axum::{extract::FromRequestParts, http::request::Parts};
+use axum::{
+    extract::{FromRequestParts, MatchedPath},
+    http::{HeaderMap, header, request::Parts},
+};
+use chrono::{DateTime, Utc};
+use gig_log_common::models::personal_access_token::ApiScope;
 use log::error;
 use uuid::Uuid;
 
-use crate::auth::jwt::JwtUtil;
-use crate::core::error::ApiErrorResponse;
+use crate::auth::{cookies::CookiesUtil, jwt::JwtUtil, scope::ScopeUtil, token};
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::repo::api_key::ApiKeyRepo;
+use crate::repo::personal_access_token::PersonalAccessTokenRepo;
 use crate::routes::app::AppState;
 
+/// Prefix identifying personal access tokens.
+pub const PERSONAL_ACCESS_TOKEN_PREFIX: &str = "glp_";
+
+/// Prefix identifying API keys.
+pub const API_KEY_PREFIX: &str = "glk_";
+
 /// An authenticated user extracted from an incoming request.
 ///
 /// Add this type to a route handler's parameter list to require a
-/// valid `access_token` cookie. The extractor will reject the request
-/// with [`ApiErrorResponse::Unauthorized`] if the token is missing or
-/// invalid.
+/// valid `access_token` cookie, API key, or personal access token. The extractor
+/// will reject the request with [`ApiErrorResponse::Unauthorized`] if the
+/// token is missing or invalid, and with [`ApiErrorResponse::Forbidden`] if
+/// a personal access token lacks the route's scope.
 pub struct AuthUser {
     /// The unique identifier of the authenticated user.
     pub user_id: Uuid,
+    /// When the user last logged in, for requests authenticated with the
+    /// access token cookie. `None` for personal access tokens and API keys.
+    pub auth_time: Option<DateTime<Utc>>,
+}
+
+impl AuthUser {
+    /// Reads the raw access token cookie value from request headers.
+    ///
+    /// Accepts the `__Host-` and `__Secure-` prefixed cookie names as well
+    /// as the unprefixed one.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` — The request headers.
+    ///
+    /// # Returns
+    ///
+    /// The token string, or `None` if no access token cookie is present.
+    pub fn access_token(headers: &HeaderMap) -> Option<&str> {
+        CookiesUtil::access_token(headers)
+    }
+
+    /// Reads a personal access token from an `Authorization: Bearer` header.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` — The request headers.
+    ///
+    /// # Returns
+    ///
+    /// The raw token, or `None` if no bearer token with the personal access
+    /// token prefix is present.
+    pub fn personal_access_token(headers: &HeaderMap) -> Option<&str> {
+        Self::bearer_token(headers, PERSONAL_ACCESS_TOKEN_PREFIX)
+    }
+
+    /// Reads an API key from an `Authorization: Bearer` header.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` — The request headers.
+    ///
+    /// # Returns
+    ///
+    /// The raw key, or `None` if no bearer token with the API key prefix is
+    /// present.
+    pub fn api_key(headers: &HeaderMap) -> Option<&str> {
+        Self::bearer_token(headers, API_KEY_PREFIX)
+    }
+
+    /// Reads a bearer token with the given prefix from the `Authorization`
+    /// header.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` — The request headers.
+    /// * `prefix` — The prefix identifying the token type.
+    ///
+    /// # Returns
+    ///
+    /// The raw token, or `None` if no bearer token with the prefix is
+    /// present.
+    fn bearer_token<'a>(headers: &'a HeaderMap, prefix: &str) -> Option<&'a str> {
+        headers
+            .get(header::AUTHORIZATION)?
+            .to_str()
+            .ok()?
+            .strip_prefix("Bearer ")
+            .map(str::trim)
+            .filter(|token| token.starts_with(prefix))
+    }
+
+    /// Authenticates a personal access token for the request's route.
+    ///
+    /// # Arguments
+    ///
+    /// * `parts` — The HTTP request head, used to resolve the matched route.
+    /// * `state` — The shared [`AppState`].
+    /// * `raw_token` — The raw personal access token.
+    ///
+    /// # Returns
+    ///
+    /// The ID of the user the token belongs to.
+    ///
+    /// # Errors
+    ///
+    /// * [`ApiErrorResponse::Unauthorized`] — if the token does not exist.
+    /// * [`ApiErrorResponse::Forbidden`] — if the route cannot be called with
+    ///   a personal access token or the token lacks the required scope.
+    pub async fn authenticate_personal_access_token(
+        parts: &Parts,
+        state: &AppState,
+        raw_token: &str,
+    ) -> ApiResult<Uuid> {
+        let grant = PersonalAccessTokenRepo::use_token(&state.db_pool, &token::hash(raw_token))
+            .await?
+            .ok_or_else(|| ApiErrorResponse::Unauthorized("Invalid access token".to_string()))?;
+
+        Self::require_scope(parts, &grant.scopes, "personal access token")?;
+
+        Ok(grant.user_id)
+    }
+
+    /// Checks that a limited token grants the scope the matched route
+    /// requires.
+    ///
+    /// # Arguments
+    ///
+    /// * `parts` — The HTTP request head, used for the matched route.
+    /// * `scopes` — The scopes the token grants.
+    /// * `token_kind` — Name of the token type, used in error messages.
+    ///
+    /// # Returns
+    ///
+    /// `()` if the token may call the route.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Forbidden`] if the route cannot be called
+    /// with a limited token or the token lacks the required scope.
+    fn require_scope(parts: &Parts, scopes: &[ApiScope], token_kind: &str) -> ApiResult<()> {
+        let path = parts
+            .extensions
+            .get::<MatchedPath>()
+            .map(|path| path.as_str())
+            .unwrap_or_else(|| parts.uri.path());
+
+        let required = ScopeUtil::required_scope(&parts.method, path).ok_or_else(|| {
+            ApiErrorResponse::Forbidden(format!(
+                "This route cannot be accessed with a {token_kind}"
+            ))
+        })?;
+
+        if !scopes.contains(&required) {
+            return Err(ApiErrorResponse::Forbidden(format!(
+                "The {token_kind} is missing the {} scope",
+                required.as_str()
+            )));
+        }
+
+        Ok(())
+    }
 }
 
 impl FromRequestParts<AppState> for AuthUser {
     type Rejection = ApiErrorResponse;
 
-    /// Extracts an [`AuthUser`] from the request cookies.
+    /// Extracts an [`AuthUser`] from a personal access token, an API key, or
+    /// the request cookies.
     ///
     /// # Arguments
     ///
     /// * `parts` — The HTTP request head (headers, URI, etc.).
-    /// * `state` — Shared application state containing the
-    ///   [`Config`](crate::core::config::Config) used for JWT
+    /// * `state` — Shared application state containing the database pool
+    ///   and the [`Config`](crate::core::config::Config) used for JWT
     ///   validation.
     ///
     /// # Returns
     ///
-    /// An [`AuthUser`] populated with the user ID from the JWT
-    /// claims.
+    /// An [`AuthUser`] populated with the user ID from the personal access
+    /// token, API key, or JWT claims.
     ///
     /// # Errors
     ///
     /// * [`ApiErrorResponse::Unauthorized`] — if the `access_token`
-    ///   cookie is missing or the JWT is invalid/expired.
+    ///   cookie is missing, the JWT is invalid/expired, or the personal
+    ///   access token or API key does not exist.
+    /// * [`ApiErrorResponse::Forbidden`] — if the personal access token,
+    ///   or a JWT carrying a `scope` claim, lacks the route's scope.
     async fn from_request_parts(
         parts: &mut Parts,
         state: &AppState,
     ) -> Result<Self, Self::Rejection> {
-        let cookies = parts
-            .headers
-            .get_all("cookie")
-            .iter()
-            .filter_map(|value| value.to_str().ok())
-            .flat_map(|s| s.split(';'))
-            .map(|s| s.trim())
-            .find(|s| s.starts_with("access_token="))
-            .ok_or_else(|| ApiErrorResponse::Unauthorized("Missing access token".to_string()))?;
+        if let Some(raw_token) = Self::personal_access_token(&parts.headers) {
+            let user_id = Self::authenticate_personal_access_token(parts, state, raw_token).await?;
+
+            return Ok(AuthUser {
+                user_id,
+                auth_time: None,
+            });
+        }
+
+        if let Some(raw_key) = Self::api_key(&parts.headers) {
+            let user_id = ApiKeyRepo::use_key(&state.db_pool, &token::hash(raw_key))
+                .await?
+                .ok_or_else(|| ApiErrorResponse::Unauthorized("Invalid API key".to_string()))?;
 
-        let token = cookies.strip_prefix("access_token=").unwrap_or("");
+            return Ok(AuthUser {
+                user_id,
+                auth_time: None,
+            });
+        }
 
-        let token_data = JwtUtil::validate_token(token, &state.config).map_err(|error| {
+        let token = Self::access_token(&parts.headers)
+            .ok_or_else(|| ApiErrorResponse::Unauthorized("Missing access token".to_string()))?;
+
+        let token_data = JwtUtil::decode_access_token(token, &state.config).map_err(|error| {
             error!("Failed to validate access token from cookies: {:?}", error);
             ApiErrorResponse::Unauthorized("Invalid or expired token".to_string())
         })?;
 
-        let user_id = token_data.claims.sub;
+        if let Some(scopes) = token_data.claims.scopes() {
+            Self::require_scope(parts, &scopes, "scoped access token")?;
+        }
+
+        Ok(AuthUser {
+            user_id: token_data.claims.sub,
+            auth_time: token_data
+                .claims
+                .auth_time
+                .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderValue;
+
+    use super::*;
+
+    fn bearer(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::AUTHORIZATION, HeaderValue::from_str(value).unwrap());
+        headers
+    }
+
+    #[test]
+    fn reads_api_keys_by_prefix() {
+        let raw_key = format!("{API_KEY_PREFIX}{}", token::generate());
+        let headers = bearer(&format!("Bearer {raw_key} "));
+
+        assert_eq!(AuthUser::api_key(&headers), Some(raw_key.as_str()));
+        assert_eq!(AuthUser::personal_access_token(&headers), None);
+    }
+
+    #[test]
+    fn reads_personal_access_tokens_by_prefix() {
+        let headers = bearer("Bearer glp_abc123");
+
+        assert_eq!(
+            AuthUser::personal_access_token(&headers),
+            Some("glp_abc123")
+        );
+        assert_eq!(AuthUser::api_key(&headers), None);
+    }
 
-        Ok(AuthUser { user_id })
+    #[test]
+    fn ignores_other_authorization_headers() {
+        assert_eq!(AuthUser::api_key(&HeaderMap::new()), None);
+        assert_eq!(AuthUser::api_key(&bearer("Basic glk_abc123")), None);
+        assert_eq!(AuthUser::api_key(&bearer("Bearer eyJhbGciOi")), None);
     }
 }
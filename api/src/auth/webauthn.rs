@@ -0,0 +1,692 @@
+//! WebAuthn passkey verification.
+//!
+//! Provides [`WebAuthnUtil`] for generating ceremony challenges and for
+//! checking the client data, authenticator data, and signatures browsers
+//! return when a passkey is created or used. Only ES256 (P-256) passkeys
+//! are accepted, and attestation statements are not verified, matching the
+//! `"none"` attestation the server requests. The credential public key is
+//! read from the COSE key in the authenticator data rather than trusted
+//! from the client.
+
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use p256::ecdsa::{Signature, VerifyingKey, signature::Verifier};
+use p256::pkcs8::{DecodePublicKey, EncodePublicKey};
+use rand::RngExt;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::repo::passkey::PasskeyCeremony;
+
+/// COSE algorithm identifier of ES256, the only accepted passkey algorithm.
+pub const ES256: i64 = -7;
+
+/// How long a ceremony challenge stays valid, in milliseconds.
+pub const CHALLENGE_TIMEOUT_MS: u64 = 300_000;
+
+/// Authenticator data flag set when the user was present.
+const FLAG_USER_PRESENT: u8 = 0x01;
+
+/// Authenticator data flag set when attested credential data follows.
+const FLAG_ATTESTED_CREDENTIAL_DATA: u8 = 0x40;
+
+/// Length of the fixed authenticator data header: the RP ID hash, flags,
+/// and signature counter.
+const AUTHENTICATOR_DATA_HEADER_LEN: usize = 37;
+
+/// Length of the authenticator model identifier preceding the credential ID.
+const AAGUID_LEN: usize = 16;
+
+/// COSE key label of the key type.
+const COSE_KEY_TYPE: i64 = 1;
+
+/// COSE key label of the key algorithm.
+const COSE_KEY_ALGORITHM: i64 = 3;
+
+/// COSE key label of an EC2 key's curve.
+const COSE_KEY_CURVE: i64 = -1;
+
+/// COSE key label of an EC2 key's x-coordinate.
+const COSE_KEY_X: i64 = -2;
+
+/// COSE key label of an EC2 key's y-coordinate.
+const COSE_KEY_Y: i64 = -3;
+
+/// COSE key type of elliptic curve keys with x- and y-coordinates.
+const COSE_KEY_TYPE_EC2: i64 = 2;
+
+/// COSE curve identifier of P-256.
+const COSE_CURVE_P256: i64 = 1;
+
+/// Length of a P-256 coordinate in bytes.
+const P256_COORDINATE_LEN: usize = 32;
+
+/// The client data a browser signs over during a ceremony.
+#[derive(Debug, Deserialize)]
+pub struct ClientData {
+    /// `"webauthn.create"` or `"webauthn.get"`.
+    #[serde(rename = "type")]
+    pub ceremony_type: String,
+    /// The base64url-encoded challenge the server issued.
+    pub challenge: String,
+    /// The origin of the page that ran the ceremony.
+    pub origin: String,
+}
+
+/// Parsed authenticator data.
+#[derive(Debug, PartialEq, Eq)]
+pub struct AuthenticatorData {
+    /// SHA-256 hash of the relying party ID the authenticator used.
+    pub rp_id_hash: [u8; 32],
+    /// Bit flags describing the ceremony.
+    pub flags: u8,
+    /// The authenticator's signature counter.
+    pub sign_count: u32,
+    /// The new credential's ID, present during registration.
+    pub credential_id: Option<Vec<u8>>,
+    /// The new credential's DER-encoded public key, present during
+    /// registration.
+    pub credential_public_key: Option<Vec<u8>>,
+}
+
+/// A value in a COSE key map.
+enum CoseValue<'a> {
+    /// An integer.
+    Integer(i64),
+    /// A byte string.
+    Bytes(&'a [u8]),
+}
+
+/// Reads the subset of CBOR used by COSE EC2 keys: definite-length maps
+/// of integer labels to integers and byte strings.
+struct CoseReader<'a> {
+    /// The bytes being read.
+    bytes: &'a [u8],
+    /// Offset of the next unread byte.
+    position: usize,
+}
+
+impl<'a> CoseReader<'a> {
+    /// Reads a data item header.
+    ///
+    /// # Returns
+    ///
+    /// The major type and argument, or [`None`] if the header is truncated
+    /// or uses an indefinite length.
+    fn read_header(&mut self) -> Option<(u8, u64)> {
+        let initial = *self.bytes.get(self.position)?;
+        self.position += 1;
+
+        let length = match initial & 0x1f {
+            value @ 0..=23 => return Some((initial >> 5, value.into())),
+            24 => 1,
+            25 => 2,
+            26 => 4,
+            27 => 8,
+            _ => return None,
+        };
+        let argument = self.bytes.get(self.position..self.position + length)?;
+        self.position += length;
+
+        Some((
+            initial >> 5,
+            argument
+                .iter()
+                .fold(0, |value, byte| (value << 8) | u64::from(*byte)),
+        ))
+    }
+
+    /// Reads an integer or byte string.
+    ///
+    /// # Returns
+    ///
+    /// The value, or [`None`] if it is malformed or of another type.
+    fn read_value(&mut self) -> Option<CoseValue<'a>> {
+        match self.read_header()? {
+            (0, value) => i64::try_from(value).ok().map(CoseValue::Integer),
+            (1, value) => i64::try_from(value)
+                .ok()
+                .map(|value| CoseValue::Integer(-1 - value)),
+            (2, length) => {
+                let end = self.position.checked_add(usize::try_from(length).ok()?)?;
+                let bytes = self.bytes.get(self.position..end)?;
+                self.position = end;
+
+                Some(CoseValue::Bytes(bytes))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Utility for WebAuthn ceremonies.
+pub struct WebAuthnUtil;
+
+impl WebAuthnUtil {
+    /// Generates a random 256-bit ceremony challenge.
+    ///
+    /// # Returns
+    ///
+    /// The base64url-encoded challenge.
+    pub fn generate_challenge() -> String {
+        let bytes: [u8; 32] = rand::rng().random();
+
+        Self::encode(&bytes)
+    }
+
+    /// Encodes bytes as unpadded base64url, as WebAuthn JSON uses.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` — The bytes to encode.
+    ///
+    /// # Returns
+    ///
+    /// The encoded [`String`].
+    pub fn encode(bytes: &[u8]) -> String {
+        URL_SAFE_NO_PAD.encode(bytes)
+    }
+
+    /// Decodes a base64url value from a WebAuthn response.
+    ///
+    /// Trailing padding is tolerated.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — The encoded value.
+    /// * `field` — Name of the field, used in the error message.
+    ///
+    /// # Returns
+    ///
+    /// The decoded bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the value is not valid
+    /// base64url.
+    pub fn decode(value: &str, field: &str) -> ApiResult<Vec<u8>> {
+        URL_SAFE_NO_PAD
+            .decode(value.trim_end_matches('='))
+            .map_err(|_| ApiErrorResponse::BadRequest(format!("Invalid {field}")))
+    }
+
+    /// Parses client data JSON.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_data_json` — The raw client data JSON.
+    ///
+    /// # Returns
+    ///
+    /// The parsed [`ClientData`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the JSON is malformed.
+    pub fn parse_client_data(client_data_json: &[u8]) -> ApiResult<ClientData> {
+        serde_json::from_slice(client_data_json)
+            .map_err(|_| ApiErrorResponse::BadRequest("Invalid client data".to_string()))
+    }
+
+    /// Checks that client data belongs to the expected ceremony and came
+    /// from an allowed origin.
+    ///
+    /// The challenge itself is checked by looking it up in the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_data` — The parsed client data.
+    /// * `ceremony` — The ceremony being finished.
+    /// * `origins` — The web client origins ceremonies may run on.
+    ///
+    /// # Returns
+    ///
+    /// `()` if the client data is acceptable.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the type or origin does
+    /// not match.
+    pub fn verify_client_data(
+        client_data: &ClientData,
+        ceremony: PasskeyCeremony,
+        origins: &[String],
+    ) -> ApiResult<()> {
+        if client_data.ceremony_type != ceremony.client_data_type() {
+            return Err(ApiErrorResponse::BadRequest(
+                "Client data is for another ceremony".to_string(),
+            ));
+        }
+
+        if !origins
+            .iter()
+            .any(|origin| origin.trim_end_matches('/') == client_data.origin)
+        {
+            return Err(ApiErrorResponse::BadRequest(
+                "Passkey ceremony ran on an unknown origin".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Parses authenticator data.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` — The raw authenticator data.
+    ///
+    /// # Returns
+    ///
+    /// The parsed [`AuthenticatorData`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the data is truncated or
+    /// the credential public key is not a P-256 ES256 key.
+    pub fn parse_authenticator_data(bytes: &[u8]) -> ApiResult<AuthenticatorData> {
+        let invalid = || ApiErrorResponse::BadRequest("Invalid authenticator data".to_string());
+
+        if bytes.len() < AUTHENTICATOR_DATA_HEADER_LEN {
+            return Err(invalid());
+        }
+
+        let mut rp_id_hash = [0; 32];
+        rp_id_hash.copy_from_slice(&bytes[..32]);
+        let flags = bytes[32];
+        let sign_count = u32::from_be_bytes([bytes[33], bytes[34], bytes[35], bytes[36]]);
+
+        let (credential_id, credential_public_key) = if flags & FLAG_ATTESTED_CREDENTIAL_DATA != 0 {
+            let length_start = AUTHENTICATOR_DATA_HEADER_LEN + AAGUID_LEN;
+            let id_start = length_start + 2;
+            let length_bytes = bytes.get(length_start..id_start).ok_or_else(invalid)?;
+            let length = u16::from_be_bytes([length_bytes[0], length_bytes[1]]) as usize;
+            let id_end = id_start + length;
+            let credential_id = bytes.get(id_start..id_end).ok_or_else(invalid)?.to_vec();
+            let public_key = Self::parse_cose_key(&bytes[id_end..])?;
+
+            (Some(credential_id), Some(public_key))
+        } else {
+            (None, None)
+        };
+
+        Ok(AuthenticatorData {
+            rp_id_hash,
+            flags,
+            sign_count,
+            credential_id,
+            credential_public_key,
+        })
+    }
+
+    /// Parses an ES256 COSE key into a DER `SubjectPublicKeyInfo`.
+    ///
+    /// Any extension data following the key is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` — The CBOR-encoded COSE key.
+    ///
+    /// # Returns
+    ///
+    /// The DER-encoded public key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the key is malformed or
+    /// is not a P-256 ES256 key.
+    fn parse_cose_key(bytes: &[u8]) -> ApiResult<Vec<u8>> {
+        let unsupported =
+            || ApiErrorResponse::BadRequest("Unsupported passkey public key".to_string());
+        let mut reader = CoseReader { bytes, position: 0 };
+
+        let entries = match reader.read_header() {
+            Some((5, entries)) => entries,
+            _ => return Err(unsupported()),
+        };
+
+        let mut key_type = None;
+        let mut algorithm = None;
+        let mut curve = None;
+        let mut x = None;
+        let mut y = None;
+
+        for _ in 0..entries {
+            let label = match reader.read_value() {
+                Some(CoseValue::Integer(label)) => label,
+                _ => return Err(unsupported()),
+            };
+
+            match (label, reader.read_value().ok_or_else(unsupported)?) {
+                (COSE_KEY_TYPE, CoseValue::Integer(value)) => key_type = Some(value),
+                (COSE_KEY_ALGORITHM, CoseValue::Integer(value)) => algorithm = Some(value),
+                (COSE_KEY_CURVE, CoseValue::Integer(value)) => curve = Some(value),
+                (COSE_KEY_X, CoseValue::Bytes(value)) => x = Some(value),
+                (COSE_KEY_Y, CoseValue::Bytes(value)) => y = Some(value),
+                (
+                    COSE_KEY_TYPE | COSE_KEY_ALGORITHM | COSE_KEY_CURVE | COSE_KEY_X | COSE_KEY_Y,
+                    _,
+                ) => {
+                    return Err(unsupported());
+                }
+                _ => {}
+            }
+        }
+
+        if key_type != Some(COSE_KEY_TYPE_EC2)
+            || algorithm != Some(ES256)
+            || curve != Some(COSE_CURVE_P256)
+        {
+            return Err(unsupported());
+        }
+
+        let (Some(x), Some(y)) = (x, y) else {
+            return Err(unsupported());
+        };
+
+        if x.len() != P256_COORDINATE_LEN || y.len() != P256_COORDINATE_LEN {
+            return Err(unsupported());
+        }
+
+        let mut point = vec![0x04];
+        point.extend_from_slice(x);
+        point.extend_from_slice(y);
+
+        VerifyingKey::from_sec1_bytes(&point)
+            .ok()
+            .and_then(|key| key.to_public_key_der().ok())
+            .map(|der| der.into_vec())
+            .ok_or_else(unsupported)
+    }
+
+    /// Checks that authenticator data is bound to this relying party and
+    /// that the user was present.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` — The parsed authenticator data.
+    /// * `rp_id` — The configured relying party ID.
+    ///
+    /// # Returns
+    ///
+    /// `()` if the data is acceptable.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the RP ID hash does not
+    /// match or the user-present flag is not set.
+    pub fn verify_authenticator_data(data: &AuthenticatorData, rp_id: &str) -> ApiResult<()> {
+        if data.rp_id_hash[..] != Sha256::digest(rp_id.as_bytes())[..] {
+            return Err(ApiErrorResponse::BadRequest(
+                "Passkey is for another site".to_string(),
+            ));
+        }
+
+        if data.flags & FLAG_USER_PRESENT == 0 {
+            return Err(ApiErrorResponse::BadRequest(
+                "User presence was not confirmed".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the public key a client reported is the key in the
+    /// authenticator data.
+    ///
+    /// # Arguments
+    ///
+    /// * `public_key` — The DER-encoded public key the client reported.
+    /// * `attested` — The DER-encoded public key from the authenticator
+    ///   data.
+    ///
+    /// # Returns
+    ///
+    /// `()` if both are the same P-256 key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the reported key is not a
+    /// valid P-256 key or is not the attested key.
+    pub fn verify_public_key(public_key: &[u8], attested: &[u8]) -> ApiResult<()> {
+        let reported = VerifyingKey::from_public_key_der(public_key).map_err(|_| {
+            ApiErrorResponse::BadRequest("Unsupported passkey public key".to_string())
+        })?;
+
+        if VerifyingKey::from_public_key_der(attested).ok() != Some(reported) {
+            return Err(ApiErrorResponse::BadRequest(
+                "Public key does not match the authenticator data".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Verifies a login signature.
+    ///
+    /// The authenticator signs the authenticator data followed by the
+    /// SHA-256 hash of the client data JSON.
+    ///
+    /// # Arguments
+    ///
+    /// * `public_key` — The passkey's DER-encoded public key.
+    /// * `authenticator_data` — The raw authenticator data.
+    /// * `client_data_json` — The raw client data JSON.
+    /// * `signature` — The DER-encoded ECDSA signature.
+    ///
+    /// # Returns
+    ///
+    /// `()` if the signature is valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the key or signature is
+    /// malformed or the signature does not verify.
+    pub fn verify_signature(
+        public_key: &[u8],
+        authenticator_data: &[u8],
+        client_data_json: &[u8],
+        signature: &[u8],
+    ) -> ApiResult<()> {
+        let invalid = || ApiErrorResponse::BadRequest("Invalid passkey signature".to_string());
+        let key = VerifyingKey::from_public_key_der(public_key).map_err(|_| invalid())?;
+        let signature = Signature::from_der(signature).map_err(|_| invalid())?;
+
+        let mut message = authenticator_data.to_vec();
+        message.extend_from_slice(&Sha256::digest(client_data_json));
+
+        key.verify(&message, &signature).map_err(|_| invalid())
+    }
+
+    /// Checks that a signature counter moved forward.
+    ///
+    /// Authenticators that do not keep a counter always report zero. A
+    /// counter that does not increase suggests a cloned authenticator.
+    ///
+    /// # Arguments
+    ///
+    /// * `stored` — The counter recorded at the passkey's last use.
+    /// * `reported` — The counter in the new authenticator data.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the reported counter is acceptable.
+    pub fn is_sign_count_valid(stored: i64, reported: u32) -> bool {
+        (stored == 0 && reported == 0) || i64::from(reported) > stored
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p256::ecdsa::{SigningKey, signature::Signer};
+    use p256::pkcs8::EncodePublicKey;
+
+    use super::*;
+
+    fn signing_key() -> SigningKey {
+        SigningKey::from_slice(&[7; 32]).unwrap()
+    }
+
+    fn cose_key(key: &SigningKey, algorithm: u8) -> Vec<u8> {
+        let point = key.verifying_key().to_encoded_point(false);
+
+        // {1: 2, 3: algorithm, -1: 1, -2: x, -3: y}
+        let mut bytes = vec![
+            0xa5, 0x01, 0x02, 0x03, algorithm, 0x20, 0x01, 0x21, 0x58, 0x20,
+        ];
+        bytes.extend_from_slice(point.x().unwrap());
+        bytes.extend_from_slice(&[0x22, 0x58, 0x20]);
+        bytes.extend_from_slice(point.y().unwrap());
+
+        bytes
+    }
+
+    fn authenticator_data(
+        flags: u8,
+        sign_count: u32,
+        credential: Option<(&[u8], &[u8])>,
+    ) -> Vec<u8> {
+        let mut bytes = Sha256::digest(b"localhost").to_vec();
+        bytes.push(flags);
+        bytes.extend_from_slice(&sign_count.to_be_bytes());
+
+        if let Some((credential_id, public_key)) = credential {
+            bytes.extend_from_slice(&[0; AAGUID_LEN]);
+            bytes.extend_from_slice(&(credential_id.len() as u16).to_be_bytes());
+            bytes.extend_from_slice(credential_id);
+            bytes.extend_from_slice(public_key);
+        }
+
+        bytes
+    }
+
+    #[test]
+    fn parses_authenticator_data() {
+        let key = signing_key();
+        let cose_key = cose_key(&key, 0x26);
+        let bytes = authenticator_data(
+            FLAG_USER_PRESENT | FLAG_ATTESTED_CREDENTIAL_DATA,
+            7,
+            Some((b"cred", &cose_key)),
+        );
+        let data = WebAuthnUtil::parse_authenticator_data(&bytes).unwrap();
+        let public_key = key.verifying_key().to_public_key_der().unwrap().into_vec();
+
+        assert_eq!(data.sign_count, 7);
+        assert_eq!(data.credential_id.as_deref(), Some(&b"cred"[..]));
+        assert_eq!(data.credential_public_key, Some(public_key));
+        assert!(WebAuthnUtil::verify_authenticator_data(&data, "localhost").is_ok());
+        assert!(WebAuthnUtil::verify_authenticator_data(&data, "giglog.dev").is_err());
+        assert!(WebAuthnUtil::parse_authenticator_data(&bytes[..40]).is_err());
+
+        let absent =
+            WebAuthnUtil::parse_authenticator_data(&authenticator_data(0, 0, None)).unwrap();
+
+        assert!(WebAuthnUtil::verify_authenticator_data(&absent, "localhost").is_err());
+    }
+
+    #[test]
+    fn rejects_unsupported_credential_public_keys() {
+        let key = signing_key();
+        let flags = FLAG_USER_PRESENT | FLAG_ATTESTED_CREDENTIAL_DATA;
+
+        // RS256 (-257) is not accepted.
+        let mut rs256 = cose_key(&key, 0x26);
+        rs256.splice(4..5, [0x39, 0x01, 0x00]);
+        let mut truncated = cose_key(&key, 0x26);
+        truncated.pop();
+
+        for public_key in [rs256, truncated, Vec::new()] {
+            let bytes = authenticator_data(flags, 1, Some((b"cred", &public_key)));
+
+            assert!(WebAuthnUtil::parse_authenticator_data(&bytes).is_err());
+        }
+    }
+
+    #[test]
+    fn verifies_reported_public_keys() {
+        let attested = signing_key()
+            .verifying_key()
+            .to_public_key_der()
+            .unwrap()
+            .into_vec();
+        let other = SigningKey::from_slice(&[9; 32])
+            .unwrap()
+            .verifying_key()
+            .to_public_key_der()
+            .unwrap()
+            .into_vec();
+
+        assert!(WebAuthnUtil::verify_public_key(&attested, &attested).is_ok());
+        assert!(WebAuthnUtil::verify_public_key(&other, &attested).is_err());
+        assert!(WebAuthnUtil::verify_public_key(b"key", &attested).is_err());
+    }
+
+    #[test]
+    fn verifies_client_data() {
+        let json = br#"{"type":"webauthn.get","challenge":"abc","origin":"http://localhost:3000"}"#;
+        let client_data = WebAuthnUtil::parse_client_data(json).unwrap();
+        let origins = vec!["http://localhost:3000/".to_string()];
+
+        assert_eq!(client_data.challenge, "abc");
+        assert!(
+            WebAuthnUtil::verify_client_data(
+                &client_data,
+                PasskeyCeremony::Authentication,
+                &origins
+            )
+            .is_ok()
+        );
+        assert!(
+            WebAuthnUtil::verify_client_data(&client_data, PasskeyCeremony::Registration, &origins)
+                .is_err()
+        );
+        assert!(
+            WebAuthnUtil::verify_client_data(
+                &client_data,
+                PasskeyCeremony::Authentication,
+                &["https://giglog.dev".to_string()]
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn verifies_signatures() {
+        let signing_key = signing_key();
+        let public_key = signing_key
+            .verifying_key()
+            .to_public_key_der()
+            .unwrap()
+            .into_vec();
+        let data = authenticator_data(FLAG_USER_PRESENT, 1, None);
+        let client_data_json = br#"{"type":"webauthn.get"}"#;
+
+        let mut message = data.clone();
+        message.extend_from_slice(&Sha256::digest(client_data_json));
+        let signature: Signature = signing_key.sign(&message);
+        let signature = signature.to_der();
+
+        assert!(
+            WebAuthnUtil::verify_signature(
+                &public_key,
+                &data,
+                client_data_json,
+                signature.as_bytes()
+            )
+            .is_ok()
+        );
+        assert!(
+            WebAuthnUtil::verify_signature(&public_key, &data, b"{}", signature.as_bytes())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn requires_increasing_sign_counts() {
+        assert!(WebAuthnUtil::is_sign_count_valid(0, 0));
+        assert!(WebAuthnUtil::is_sign_count_valid(4, 5));
+        assert!(!WebAuthnUtil::is_sign_count_valid(5, 5));
+        assert!(!WebAuthnUtil::is_sign_count_valid(5, 0));
+    }
+}
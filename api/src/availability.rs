@@ -0,0 +1,171 @@
+//! Availability calculations based on scheduled time off.
+//!
+//! Provides [`AvailabilityUtil`] for building week views and counting the
+//! weekdays a user is available in a date range, so that forecasts and
+//! summaries can account for planned vacations.
+
+use chrono::{Datelike, NaiveDate, Weekday};
+use gig_log_common::models::time_off::{DayAvailability, TimeOff, WeekAvailability};
+
+/// Utility for computing availability from time-off entries.
+pub struct AvailabilityUtil;
+
+impl AvailabilityUtil {
+    /// Returns the Monday and Sunday of the week containing `date`.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` — Any date within the week.
+    ///
+    /// # Returns
+    ///
+    /// A `(monday, sunday)` tuple.
+    pub fn week_bounds(date: NaiveDate) -> (NaiveDate, NaiveDate) {
+        let week = date.week(Weekday::Mon);
+
+        (week.first_day(), week.last_day())
+    }
+
+    /// Builds the availability view for the week containing `date`.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` — Any date within the week.
+    /// * `time_off` — Time-off entries overlapping the week.
+    ///
+    /// # Returns
+    ///
+    /// A [`WeekAvailability`] with per-day flags and weekday totals.
+    pub fn build_week(date: NaiveDate, time_off: Vec<TimeOff>) -> WeekAvailability {
+        let (week_start, week_end) = Self::week_bounds(date);
+        let days: Vec<DayAvailability> = week_start
+            .iter_days()
+            .take_while(|day| *day <= week_end)
+            .map(|day| DayAvailability {
+                date: day,
+                is_weekend: Self::is_weekend(day),
+                is_time_off: Self::is_time_off(day, &time_off),
+            })
+            .collect();
+        let time_off_weekdays = days
+            .iter()
+            .filter(|day| !day.is_weekend && day.is_time_off)
+            .count() as i64;
+        let weekdays = days.iter().filter(|day| !day.is_weekend).count() as i64;
+
+        WeekAvailability {
+            week_start,
+            week_end,
+            days,
+            available_weekdays: weekdays - time_off_weekdays,
+            time_off_weekdays,
+            time_off,
+        }
+    }
+
+    /// Counts the weekdays in a range that are not covered by time off.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` — First day of the range (inclusive).
+    /// * `end` — Last day of the range (inclusive).
+    /// * `time_off` — Time-off entries to exclude.
+    ///
+    /// # Returns
+    ///
+    /// The number of available weekdays, or `0` if `end` is before `start`.
+    pub fn available_weekdays(start: NaiveDate, end: NaiveDate, time_off: &[TimeOff]) -> i64 {
+        start
+            .iter_days()
+            .take_while(|day| *day <= end)
+            .filter(|day| !Self::is_weekend(*day) && !Self::is_time_off(*day, time_off))
+            .count() as i64
+    }
+
+    /// Returns `true` if `date` falls on a Saturday or Sunday.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` — The date to check.
+    ///
+    /// # Returns
+    ///
+    /// `true` for weekend days.
+    fn is_weekend(date: NaiveDate) -> bool {
+        matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+    }
+
+    /// Returns `true` if any time-off entry covers `date`.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` — The date to check.
+    /// * `time_off` — Time-off entries to search.
+    ///
+    /// # Returns
+    ///
+    /// `true` if `date` is within an entry's inclusive range.
+    fn is_time_off(date: NaiveDate, time_off: &[TimeOff]) -> bool {
+        time_off
+            .iter()
+            .any(|entry| entry.start_date <= date && date <= entry.end_date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, Utc};
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).expect("valid date")
+    }
+
+    fn time_off(start_date: NaiveDate, end_date: NaiveDate) -> TimeOff {
+        TimeOff {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            start_date,
+            end_date,
+            note: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn week_bounds_run_monday_to_sunday() {
+        assert_eq!(
+            AvailabilityUtil::week_bounds(date(2026, 10, 16)),
+            (date(2026, 10, 12), date(2026, 10, 18))
+        );
+    }
+
+    #[test]
+    fn build_week_excludes_weekends_from_time_off_counts() {
+        let week = AvailabilityUtil::build_week(
+            date(2026, 10, 14),
+            vec![time_off(date(2026, 10, 15), date(2026, 10, 20))],
+        );
+
+        assert_eq!(week.days.len(), 7);
+        assert_eq!(week.time_off_weekdays, 2);
+        assert_eq!(week.available_weekdays, 3);
+        assert!(week.days[5].is_weekend && week.days[5].is_time_off);
+    }
+
+    #[test]
+    fn available_weekdays_does_not_double_count_overlapping_entries() {
+        let entries = vec![
+            time_off(date(2026, 10, 12), date(2026, 10, 13)),
+            time_off(date(2026, 10, 13), date(2026, 10, 14)),
+        ];
+
+        assert_eq!(
+            AvailabilityUtil::available_weekdays(date(2026, 10, 12), date(2026, 10, 25), &entries),
+            7
+        );
+    }
+}
@@ -0,0 +1,293 @@
+//! Billing calculations for invoicing tracked work.
+//!
+//! Provides [`BillingUtil`] for rolling completed work sessions up into
+//! per-job billable groups, rounding worked time to the billing increment,
+//! pricing the result at the rate each session was tracked at, and
+//! splitting amounts into payout installments.
+
+use gig_log_common::models::invoice::{InvoiceLineItem, UnbilledJobGroup};
+
+use crate::repo::work_session::UnbilledSessionRecord;
+
+/// Billing increment in minutes that worked time is rounded up to.
+const BILLING_INCREMENT_MINUTES: i64 = 15;
+
+/// Utility for computing billable hours and amounts.
+pub struct BillingUtil;
+
+impl BillingUtil {
+    /// Groups unbilled work sessions by job and rate and prices each group.
+    ///
+    /// Sessions are expected to be ordered so that all sessions for a job
+    /// at the same rate are adjacent, as returned by
+    /// [`WorkSessionRepo::list_unbilled_for_company`](crate::repo::work_session::WorkSessionRepo::list_unbilled_for_company).
+    /// Each session is priced at the rate it was completed at, so a job
+    /// whose rate changed gets one group per rate. Durations are summed per
+    /// group before rounding so that short sessions are not each rounded up
+    /// individually.
+    ///
+    /// # Arguments
+    ///
+    /// * `sessions` — Unbilled work sessions ordered by job and rate.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`UnbilledJobGroup`] values, one per job and rate.
+    pub fn group_unbilled_sessions(sessions: &[UnbilledSessionRecord]) -> Vec<UnbilledJobGroup> {
+        let mut groups: Vec<UnbilledJobGroup> = Vec::new();
+
+        for session in sessions {
+            let seconds = session.net_seconds.max(0);
+
+            match groups.last_mut() {
+                Some(group)
+                    if group.job_id == session.job_id
+                        && group.hourly_rate == session.hourly_rate =>
+                {
+                    group.work_session_ids.push(session.id);
+                    group.duration_seconds += seconds;
+                }
+                _ => groups.push(UnbilledJobGroup {
+                    job_id: session.job_id,
+                    job_title: session.job_title.clone(),
+                    work_session_ids: vec![session.id],
+                    duration_seconds: seconds,
+                    rounded_hours: 0.0,
+                    hourly_rate: session.hourly_rate,
+                    amount: None,
+                }),
+            }
+        }
+
+        for group in &mut groups {
+            group.rounded_hours = Self::round_hours(group.duration_seconds);
+            group.amount = group
+                .hourly_rate
+                .map(|rate| Self::round_currency(group.rounded_hours * rate));
+        }
+
+        groups
+    }
+
+    /// Sums the billable amounts of the given groups.
+    ///
+    /// # Arguments
+    ///
+    /// * `groups` — Priced job groups.
+    ///
+    /// # Returns
+    ///
+    /// The total amount in dollars, rounded to cents.
+    pub fn total_amount(groups: &[UnbilledJobGroup]) -> f64 {
+        Self::round_currency(groups.iter().filter_map(|group| group.amount).sum())
+    }
+
+    /// Sums the amounts of an invoice's line items.
+    ///
+    /// # Arguments
+    ///
+    /// * `line_items` — The invoice's line items.
+    ///
+    /// # Returns
+    ///
+    /// The total amount in dollars, rounded to cents. Line items for
+    /// payout-based jobs have no amount and are skipped.
+    pub fn line_item_total(line_items: &[InvoiceLineItem]) -> f64 {
+        Self::round_currency(line_items.iter().filter_map(|item| item.amount).sum())
+    }
+
+    /// Splits an amount into equal installments.
+    ///
+    /// The amount is divided in whole cents. Any leftover cents go to the
+    /// earliest installments, so the installments always add up to the
+    /// rounded amount.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` — The dollar amount to split.
+    /// * `count` — Number of installments.
+    ///
+    /// # Returns
+    ///
+    /// The installment amounts in dollars, largest first. Empty if `count`
+    /// is not positive.
+    pub fn split_installments(amount: f64, count: i32) -> Vec<f64> {
+        let count = i64::from(count);
+
+        if count <= 0 {
+            return Vec::new();
+        }
+
+        let cents = (amount.max(0.0) * 100.0).round() as i64;
+
+        (0..count)
+            .map(|index| {
+                let share = cents / count + i64::from(index < cents % count);
+                share as f64 / 100.0
+            })
+            .collect()
+    }
+
+    /// Rounds a duration up to the billing increment and converts it to hours.
+    ///
+    /// # Arguments
+    ///
+    /// * `seconds` — Worked duration in seconds.
+    ///
+    /// # Returns
+    ///
+    /// The billable hours as an [`f64`].
+    fn round_hours(seconds: i64) -> f64 {
+        let increment_seconds = BILLING_INCREMENT_MINUTES * 60;
+        let increments = (seconds.max(0) + increment_seconds - 1) / increment_seconds;
+
+        (increments * BILLING_INCREMENT_MINUTES) as f64 / 60.0
+    }
+
+    /// Rounds a dollar amount to the nearest cent.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` — The dollar amount to round.
+    ///
+    /// # Returns
+    ///
+    /// The rounded amount as an [`f64`].
+    fn round_currency(amount: f64) -> f64 {
+        (amount * 100.0).round() / 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use gig_log_common::models::invoice::InvoiceLineItem;
+    use uuid::Uuid;
+
+    use crate::repo::work_session::UnbilledSessionRecord;
+
+    use super::BillingUtil;
+
+    fn session(job_id: Uuid, net_seconds: i64, hourly_rate: Option<f64>) -> UnbilledSessionRecord {
+        UnbilledSessionRecord {
+            id: Uuid::new_v4(),
+            job_id,
+            job_title: "Website".to_string(),
+            hourly_rate,
+            net_seconds,
+        }
+    }
+
+    #[test]
+    fn rounds_partial_increments_up() {
+        assert_eq!(BillingUtil::round_hours(0), 0.0);
+        assert_eq!(BillingUtil::round_hours(1), 0.25);
+        assert_eq!(BillingUtil::round_hours(15 * 60), 0.25);
+        assert_eq!(BillingUtil::round_hours(61 * 60), 1.25);
+    }
+
+    #[test]
+    fn groups_adjacent_sessions_by_job_before_rounding() {
+        let job_a = Uuid::new_v4();
+        let job_b = Uuid::new_v4();
+        let sessions = vec![
+            session(job_a, 10 * 60, Some(40.0)),
+            session(job_a, 10 * 60, Some(40.0)),
+            session(job_b, 3600, None),
+        ];
+
+        let groups = BillingUtil::group_unbilled_sessions(&sessions);
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].work_session_ids.len(), 2);
+        assert_eq!(groups[0].duration_seconds, 20 * 60);
+        assert_eq!(groups[0].rounded_hours, 0.5);
+        assert_eq!(groups[0].amount, Some(20.0));
+        assert_eq!(groups[1].amount, None);
+        assert_eq!(BillingUtil::total_amount(&groups), 20.0);
+    }
+
+    #[test]
+    fn splits_a_job_into_one_group_per_rate() {
+        let job = Uuid::new_v4();
+        let sessions = vec![
+            session(job, 3600, Some(40.0)),
+            session(job, 3600, Some(50.0)),
+            session(job, 1800, Some(50.0)),
+        ];
+
+        let groups = BillingUtil::group_unbilled_sessions(&sessions);
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].amount, Some(40.0));
+        assert_eq!(groups[1].work_session_ids.len(), 2);
+        assert_eq!(groups[1].amount, Some(75.0));
+    }
+
+    #[test]
+    fn rate_changes_after_completion_do_not_reprice_sessions() {
+        let job = Uuid::new_v4();
+        let completed = vec![session(job, 2 * 3600, Some(40.0))];
+        let before = BillingUtil::group_unbilled_sessions(&completed);
+
+        // The job's rate rose to 60 after the first session was completed,
+        // so only sessions completed since carry the new rate.
+        let mut since_change = completed.clone();
+        since_change.push(session(job, 3600, Some(60.0)));
+        let after = BillingUtil::group_unbilled_sessions(&since_change);
+
+        assert_eq!(after[0].work_session_ids, before[0].work_session_ids);
+        assert_eq!(after[0].amount, Some(80.0));
+        assert_eq!(after[0].amount, before[0].amount);
+        assert_eq!(after[1].amount, Some(60.0));
+        assert_eq!(BillingUtil::total_amount(&after), 140.0);
+    }
+
+    #[test]
+    fn totals_priced_line_items() {
+        let line_item = |amount: Option<f64>| InvoiceLineItem {
+            id: Uuid::new_v4(),
+            invoice_id: Uuid::new_v4(),
+            job_id: Uuid::new_v4(),
+            description: "Website".to_string(),
+            session_count: 1,
+            duration_seconds: 3600,
+            rounded_hours: 1.0,
+            hourly_rate: amount,
+            amount,
+            created_at: Utc::now(),
+        };
+
+        assert_eq!(
+            BillingUtil::line_item_total(&[
+                line_item(Some(10.1)),
+                line_item(None),
+                line_item(Some(20.2)),
+            ]),
+            30.3
+        );
+        assert_eq!(BillingUtil::line_item_total(&[]), 0.0);
+    }
+
+    #[test]
+    fn splits_installments_in_whole_cents() {
+        assert_eq!(
+            BillingUtil::split_installments(100.0, 3),
+            vec![33.34, 33.33, 33.33]
+        );
+        assert_eq!(
+            BillingUtil::split_installments(0.02, 3),
+            vec![0.01, 0.01, 0.0]
+        );
+        assert!(BillingUtil::split_installments(50.0, 0).is_empty());
+    }
+
+    #[test]
+    fn clamps_negative_durations_to_zero() {
+        let groups =
+            BillingUtil::group_unbilled_sessions(&[session(Uuid::new_v4(), -30, Some(50.0))]);
+
+        assert_eq!(groups[0].duration_seconds, 0);
+        assert_eq!(groups[0].amount, Some(0.0));
+    }
+}
@@ -0,0 +1,251 @@
+//! iCalendar feeds of work sessions and payouts.
+//!
+//! Provides [`CalendarUtil`] for rendering a user's completed work sessions
+//! and expected payouts as an RFC 5545 calendar that apps such as Google
+//! Calendar can subscribe to. Feeds are read-only and fetched with a
+//! per-user token, so no session cookie is needed.
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+
+use crate::receipt::ReceiptUtil;
+use crate::repo::calendar::{CalendarPayoutRecord, CalendarSessionRecord};
+
+/// How far back a feed lists work sessions and payouts.
+pub const CALENDAR_FEED_HISTORY: Duration = Duration::days(365);
+
+/// Longest content line allowed before folding, in octets.
+const MAX_LINE_OCTETS: usize = 75;
+
+/// Utility for rendering iCalendar feeds.
+pub struct CalendarUtil;
+
+impl CalendarUtil {
+    /// Renders a calendar of work sessions and payouts.
+    ///
+    /// Work sessions become timed events. Payouts become all-day events on
+    /// their expected payout date.
+    ///
+    /// # Arguments
+    ///
+    /// * `sessions` — The completed work sessions to list.
+    /// * `payouts` — The payments with an expected payout date.
+    ///
+    /// # Returns
+    ///
+    /// The `text/calendar` document with CRLF line endings.
+    pub fn render(sessions: &[CalendarSessionRecord], payouts: &[CalendarPayoutRecord]) -> String {
+        let mut lines = vec![
+            "BEGIN:VCALENDAR".to_string(),
+            "VERSION:2.0".to_string(),
+            "PRODID:-//GigLog//Calendar Feed//EN".to_string(),
+            "CALSCALE:GREGORIAN".to_string(),
+            "METHOD:PUBLISH".to_string(),
+            "X-WR-CALNAME:GigLog".to_string(),
+        ];
+
+        for session in sessions {
+            lines.extend([
+                "BEGIN:VEVENT".to_string(),
+                format!("UID:work-session-{}@giglog", session.id),
+                format!("DTSTAMP:{}", Self::timestamp(session.updated_at)),
+                format!("DTSTART:{}", Self::timestamp(session.start_time)),
+                format!("DTEND:{}", Self::timestamp(session.end_time)),
+                format!(
+                    "SUMMARY:{}",
+                    Self::escape(&format!("{} ({})", session.job_title, session.company_name))
+                ),
+                "TRANSP:OPAQUE".to_string(),
+                "END:VEVENT".to_string(),
+            ]);
+        }
+
+        for payout in payouts {
+            let status = if payout.payment_received {
+                "Payout received"
+            } else {
+                "Expected payout"
+            };
+            let transfer = if payout.transfer_initiated {
+                "Transfer initiated"
+            } else {
+                "Transfer not initiated"
+            };
+
+            lines.extend([
+                "BEGIN:VEVENT".to_string(),
+                format!("UID:payout-{}@giglog", payout.id),
+                format!("DTSTAMP:{}", Self::timestamp(payout.updated_at)),
+                format!(
+                    "DTSTART;VALUE=DATE:{}",
+                    Self::date(payout.expected_payout_date)
+                ),
+                format!(
+                    "DTEND;VALUE=DATE:{}",
+                    Self::date(payout.expected_payout_date + Duration::days(1))
+                ),
+                format!(
+                    "SUMMARY:{}",
+                    Self::escape(&format!(
+                        "{status}: {} (${:.2})",
+                        payout.company_name, payout.total
+                    ))
+                ),
+                format!(
+                    "DESCRIPTION:{}",
+                    Self::escape(&format!(
+                        "Payout method: {}\n{transfer}",
+                        ReceiptUtil::payout_label(&payout.payout_type)
+                    ))
+                ),
+                "TRANSP:TRANSPARENT".to_string(),
+                "END:VEVENT".to_string(),
+            ]);
+        }
+
+        lines.push("END:VCALENDAR".to_string());
+
+        lines.iter().map(|line| Self::fold(line) + "\r\n").collect()
+    }
+
+    /// Formats a UTC date-time value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — The timestamp to format.
+    ///
+    /// # Returns
+    ///
+    /// The timestamp in `YYYYMMDDTHHMMSSZ` form.
+    fn timestamp(value: DateTime<Utc>) -> String {
+        value.format("%Y%m%dT%H%M%SZ").to_string()
+    }
+
+    /// Formats a date value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — The date to format.
+    ///
+    /// # Returns
+    ///
+    /// The date in `YYYYMMDD` form.
+    fn date(value: NaiveDate) -> String {
+        value.format("%Y%m%d").to_string()
+    }
+
+    /// Escapes text for inclusion in a property value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — The text to escape.
+    ///
+    /// # Returns
+    ///
+    /// The text with backslashes, semicolons, commas, and newlines escaped.
+    fn escape(value: &str) -> String {
+        value
+            .replace('\\', "\\\\")
+            .replace(';', "\\;")
+            .replace(',', "\\,")
+            .replace("\r\n", "\\n")
+            .replace('\n', "\\n")
+    }
+
+    /// Folds a content line longer than 75 octets.
+    ///
+    /// Continuation lines start with a single space, and lines are only
+    /// split between characters so multi-byte text stays intact.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` — The unfolded content line.
+    ///
+    /// # Returns
+    ///
+    /// The folded line, without a trailing line break.
+    fn fold(line: &str) -> String {
+        let mut folded = String::with_capacity(line.len());
+        let mut octets = 0;
+
+        for character in line.chars() {
+            let width = character.len_utf8();
+            if octets + width > MAX_LINE_OCTETS {
+                folded.push_str("\r\n ");
+                octets = 1;
+            }
+            folded.push(character);
+            octets += width;
+        }
+
+        folded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::repo::payment::PayoutTypeRecord;
+
+    fn payout(payment_received: bool) -> CalendarPayoutRecord {
+        CalendarPayoutRecord {
+            id: Uuid::nil(),
+            company_name: "Acme, Inc.".to_string(),
+            total: 125.5,
+            payout_type: PayoutTypeRecord::DirectDeposit,
+            expected_payout_date: NaiveDate::from_ymd_opt(2026, 3, 31).unwrap(),
+            transfer_initiated: true,
+            payment_received,
+            updated_at: DateTime::from_timestamp(0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn renders_payouts_as_all_day_events() {
+        let calendar = CalendarUtil::render(&[], &[payout(false)]);
+
+        assert!(calendar.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(calendar.ends_with("END:VCALENDAR\r\n"));
+        assert!(calendar.contains("DTSTART;VALUE=DATE:20260331\r\n"));
+        assert!(calendar.contains("DTEND;VALUE=DATE:20260401\r\n"));
+        assert!(calendar.contains("SUMMARY:Expected payout: Acme\\, Inc. ($125.50)\r\n"));
+        assert!(
+            calendar.contains("DESCRIPTION:Payout method: Direct deposit\\nTransfer initiated\r\n")
+        );
+        assert!(
+            CalendarUtil::render(&[], &[payout(true)]).contains("SUMMARY:Payout received: Acme")
+        );
+    }
+
+    #[test]
+    fn renders_sessions_as_timed_events() {
+        let start_time = DateTime::from_timestamp(1_775_000_000, 0).unwrap();
+        let session = CalendarSessionRecord {
+            id: Uuid::nil(),
+            job_title: "Mixing; mastering".to_string(),
+            company_name: "Studio".to_string(),
+            start_time,
+            end_time: start_time + Duration::hours(2),
+            updated_at: start_time,
+        };
+
+        let calendar = CalendarUtil::render(&[session], &[]);
+
+        assert!(calendar.contains("DTSTART:20260331T233320Z\r\n"));
+        assert!(calendar.contains("DTEND:20260401T013320Z\r\n"));
+        assert!(calendar.contains("SUMMARY:Mixing\\; mastering (Studio)\r\n"));
+    }
+
+    #[test]
+    fn folds_long_lines_between_characters() {
+        let line = format!("SUMMARY:{}", "é".repeat(60));
+
+        let folded = CalendarUtil::fold(&line);
+
+        for part in folded.split("\r\n") {
+            assert!(part.len() <= MAX_LINE_OCTETS);
+        }
+        assert_eq!(folded.replace("\r\n ", ""), line);
+    }
+}
@@ -0,0 +1,149 @@
+//! Account data export endpoints.
+//!
+//! Provides [`AccountExportController`] with handlers for requesting a
+//! full export of the authenticated user's data, checking on its progress,
+//! and downloading the finished JSON archive. Archives are built in the
+//! background by the
+//! [`AccountExportTask`](crate::tasks::account_exports::AccountExportTask).
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::header,
+    response::{IntoResponse, Response},
+};
+use gig_log_common::models::account_export::AccountExport;
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::repo::account_export::AccountExportRepo;
+use crate::routes::app::AppState;
+
+/// Handlers for account data export routes.
+pub struct AccountExportController;
+
+impl AccountExportController {
+    /// Requests a full export of the authenticated user's data.
+    ///
+    /// Mapped to `POST /export/account`. Requires authentication. Queues an
+    /// export for the background worker and responds immediately. If the
+    /// user already has an export queued or in progress, that export is
+    /// returned instead of queueing another.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<AccountExport>`] describing the queued export.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the export cannot be queued.
+    pub async fn create(
+        auth: AuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<AccountExport>> {
+        let export = AccountExportRepo::queue_export(&state.db_pool, auth.user_id).await?;
+
+        Ok(Json(export))
+    }
+
+    /// Returns the progress of an account export.
+    ///
+    /// Mapped to `GET /export/account/{id}`. Requires authentication. Once
+    /// the export is completed, its `download_url` points at
+    /// [`download`](Self::download).
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `export_id` — The export's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<AccountExport>`] describing the export.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the export does not exist,
+    /// belongs to another user, or has expired.
+    pub async fn show(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(export_id): Path<Uuid>,
+    ) -> ApiResult<Json<AccountExport>> {
+        let export = AccountExportRepo::find_export(&state.db_pool, auth.user_id, export_id)
+            .await
+            .map_err(Self::not_found)?;
+
+        Ok(Json(export))
+    }
+
+    /// Downloads the archive of a completed account export.
+    ///
+    /// Mapped to `GET /export/account/{id}/download`. Requires
+    /// authentication. Responds with the JSON archive as an attachment.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `export_id` — The export's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Response`] containing the archive.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the export does not exist,
+    /// belongs to another user, or has expired, or
+    /// [`ApiErrorResponse::BadRequest`] if it has not completed.
+    pub async fn download(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(export_id): Path<Uuid>,
+    ) -> ApiResult<Response> {
+        let archive = AccountExportRepo::find_archive(&state.db_pool, auth.user_id, export_id)
+            .await
+            .map_err(Self::not_found)?
+            .ok_or_else(|| {
+                ApiErrorResponse::BadRequest("Account export is not complete".to_string())
+            })?;
+
+        Ok((
+            [
+                (header::CONTENT_TYPE, "application/json".to_string()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"giglog-account-{export_id}.json\""),
+                ),
+            ],
+            archive,
+        )
+            .into_response())
+    }
+
+    /// Replaces a generic not-found error with one naming the export.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` — The error returned by the repository.
+    ///
+    /// # Returns
+    ///
+    /// The remapped [`ApiErrorResponse`].
+    fn not_found(error: ApiErrorResponse) -> ApiErrorResponse {
+        match error {
+            ApiErrorResponse::NotFound(_) => {
+                ApiErrorResponse::NotFound("Account export not found".to_string())
+            }
+            error => error,
+        }
+    }
+}
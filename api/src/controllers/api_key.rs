@@ -0,0 +1,126 @@
+//! API key endpoints.
+//!
+//! Provides [`ApiKeyController`] with handlers for issuing, listing, and
+//! revoking API keys. An API key authenticates headless clients with the
+//! same access as a logged-in session.
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use gig_log_common::models::{
+    api_key::{ApiKey, CreateApiKeyRequest, CreateApiKeyResponse},
+    generic::MessageResponse,
+};
+use uuid::Uuid;
+
+use crate::auth::{AuthUser, anomaly::AnomalyDetector, token, user::API_KEY_PREFIX};
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::extractors::ValidatedJson;
+use crate::repo::api_key::ApiKeyRepo;
+use crate::routes::app::AppState;
+
+/// Handlers for API key routes.
+pub struct ApiKeyController;
+
+impl ApiKeyController {
+    /// Issues an API key.
+    ///
+    /// Mapped to `POST /auth/api-keys`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — A [`ValidatedJson<CreateApiKeyRequest>`] containing the
+    ///   key's name.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<CreateApiKeyResponse>`] containing the raw key, which is not
+    /// retrievable later.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Forbidden`] if unusual activity was detected
+    /// since the user last logged in, or an [`ApiErrorResponse`] if the
+    /// database insert fails.
+    pub async fn create(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedJson(body): ValidatedJson<CreateApiKeyRequest>,
+    ) -> ApiResult<Json<CreateApiKeyResponse>> {
+        AnomalyDetector::require_recent_auth(&state, &auth).await?;
+
+        let raw_key = format!("{API_KEY_PREFIX}{}", token::generate());
+        let api_key = ApiKeyRepo::insert_key(
+            &state.db_pool,
+            auth.user_id,
+            body.name.trim(),
+            &token::hash(&raw_key),
+        )
+        .await?;
+
+        Ok(Json(CreateApiKeyResponse {
+            key: raw_key,
+            api_key,
+        }))
+    }
+
+    /// Lists the authenticated user's API keys.
+    ///
+    /// Mapped to `GET /auth/api-keys`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Vec<ApiKey>>`] ordered newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn list(
+        auth: AuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<Vec<ApiKey>>> {
+        let api_keys = ApiKeyRepo::list_keys(&state.db_pool, auth.user_id).await?;
+
+        Ok(Json(api_keys))
+    }
+
+    /// Revokes one of the authenticated user's API keys.
+    ///
+    /// Mapped to `DELETE /auth/api-keys/{id}`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `key_id` — The key's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MessageResponse>`] confirming the revocation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the key does not exist or
+    /// belongs to another user.
+    pub async fn delete(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(key_id): Path<Uuid>,
+    ) -> ApiResult<Json<MessageResponse>> {
+        if !ApiKeyRepo::delete_key(&state.db_pool, auth.user_id, key_id).await? {
+            return Err(ApiErrorResponse::NotFound("API key not found".to_string()));
+        }
+
+        Ok(Json(MessageResponse {
+            message: "API key revoked.".to_string(),
+        }))
+    }
+}
@@ -0,0 +1,95 @@
+//! Appearance endpoints.
+//!
+//! Provides [`AppearanceController`] with handlers for the preset palette
+//! registry, so clients can render presets without bundling their own
+//! definitions, and for SVG swatch previews of palettes.
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::header,
+    response::{IntoResponse, Response},
+};
+use gig_log_common::models::appearance::PresetPalette;
+use uuid::Uuid;
+
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::palette_preview::PalettePreviewUtil;
+use crate::repo::appearance::AppearanceRepo;
+use crate::routes::app::AppState;
+
+/// `Cache-Control` value for palette previews. Custom palettes can be
+/// edited, so previews are only cached briefly.
+const PREVIEW_CACHE_CONTROL: &str = "public, max-age=300";
+
+/// Handlers for appearance routes.
+pub struct AppearanceController;
+
+impl AppearanceController {
+    /// Lists the preset color palettes.
+    ///
+    /// Mapped to `GET /appearance/presets`. Does not require
+    /// authentication, so presets can be applied before signing in.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Vec<PresetPalette>>`] in display order, each with its seed
+    /// colors and full token set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the presets cannot be loaded.
+    pub async fn list_presets(
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<Vec<PresetPalette>>> {
+        let presets = AppearanceRepo::list_presets(&state.db_pool).await?;
+
+        Ok(Json(presets))
+    }
+
+    /// Renders an SVG swatch preview of a palette.
+    ///
+    /// Mapped to `GET /appearance/palettes/{id}/preview.svg`. Does not
+    /// require authentication, so previews can be embedded in share links.
+    /// Only colors are rendered, never palette names.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `palette_id` — A preset slug or custom palette UUID from the
+    ///   request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Response`] containing the `image/svg+xml` preview.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if no preset or custom palette
+    /// matches the ID.
+    pub async fn preview(
+        State(state): State<AppState>,
+        Path(palette_id): Path<String>,
+    ) -> ApiResult<Response> {
+        let palette = AppearanceRepo::find_palette_swatch(
+            &state.db_pool,
+            &palette_id,
+            Uuid::parse_str(&palette_id).ok(),
+        )
+        .await?
+        .ok_or_else(|| ApiErrorResponse::NotFound("Palette not found".to_string()))?;
+
+        Ok((
+            [
+                (header::CONTENT_TYPE, "image/svg+xml"),
+                (header::CACHE_CONTROL, PREVIEW_CACHE_CONTROL),
+            ],
+            PalettePreviewUtil::render_svg(&palette),
+        )
+            .into_response())
+    }
+}
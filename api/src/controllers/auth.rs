@@ -1,34 +1,55 @@
 //! Authentication and account management endpoints.
 //!
 //! Provides [`AuthController`] with handlers for sign-up, log-in,
-//! log-out, token refresh, password management, and email change flows.
+//! log-out, token refresh, session management, password management, and
+//! email change flows, including the signed links sent in confirmation and
+//! password reset emails.
 
-use axum::{Json, extract::State};
+use std::net::SocketAddr;
+
+use axum::{
+    Extension, Json,
+    extract::{ConnectInfo, Path, Query, State},
+    http::{HeaderMap, header},
+    response::Redirect,
+};
 use axum_extra::extract::CookieJar;
 use chrono::{Duration, Utc};
+use gig_log_common::models::error::{ValidationError, ValidationErrorCode};
 use gig_log_common::models::generic::MessageResponse;
+use gig_log_common::models::locale::{LocalePreference, TimezonePreference};
 use gig_log_common::models::user::{
-    ChangePasswordRequest, ConfirmEmailRequest, ForgotPasswordRequest, LogInRequest,
-    RequestEmailChangeRequest, SetPasswordRequest, SignUpRequest, User,
-    VerifyForgotPasswordRequest,
+    ApiActivity, ChangePasswordRequest, ConfirmEmailRequest, CurrentUserResponse,
+    DemoAccountResponse, EmailLinkQuery, ForgotPasswordRequest, LogInRequest,
+    RequestEmailChangeRequest, ResendConfirmationRequest, SetPasswordRequest, SignUpRequest, User,
+    UserSession, VerifyForgotPasswordRequest,
 };
 use log::{error, warn};
-use sha2::{Digest, Sha256};
+use uuid::Uuid;
 
 use crate::auth::AuthUser;
+use crate::auth::anomaly::AnomalyDetector;
 use crate::auth::cookies::CookiesUtil;
 use crate::auth::jwt::JwtUtil;
-use crate::auth::{code, password::PasswordUtil};
+use crate::auth::link::LinkUtil;
+use crate::auth::{code, password::PasswordUtil, token};
+use crate::core::activity::ActivityRecorder;
 use crate::core::error::{ApiErrorResponse, ApiResult};
 use crate::email::senders::auth::AuthSender;
 use crate::extractors::ValidatedJson;
+use crate::repo::activity::ActivityRepo;
+use crate::repo::demo::DemoRepo;
+use crate::repo::onboarding_state::OnboardingStateRepo;
 use crate::repo::refresh_token::RefreshTokenRepo;
 use crate::repo::{
-    auth_code::{AuthCodeRepo, AuthCodeType},
-    user::UserRepo,
+    auth_code::{AuthCodeRecord, AuthCodeRepo, AuthCodeType},
+    user::{UserCredentials, UserRepo},
 };
 use crate::routes::app::AppState;
 
+/// Minimum time between confirmation codes sent to the same user.
+const RESEND_COOLDOWN: Duration = Duration::seconds(60);
+
 /// Handlers for authentication and account management routes.
 pub struct AuthController;
 
@@ -36,7 +57,8 @@ impl AuthController {
     /// Registers a new user account.
     ///
     /// Mapped to `POST /sign-up`. Creates the user, generates an email
-    /// verification code, and sends a confirmation email.
+    /// verification code, and sends a confirmation email containing the
+    /// code and a signed confirmation link.
     ///
     /// # Arguments
     ///
@@ -50,6 +72,8 @@ impl AuthController {
     ///
     /// # Errors
     ///
+    /// Returns [`ApiErrorResponse::Validation`] if the password does not
+    /// meet the password policy.
     /// Returns [`ApiErrorResponse::BadRequest`] if the email is already in use.
     /// Returns [`ApiErrorResponse::InternalServerError`] if password hashing
     /// or email sending fails.
@@ -57,6 +81,13 @@ impl AuthController {
         State(state): State<AppState>,
         ValidatedJson(body): ValidatedJson<SignUpRequest>,
     ) -> ApiResult<Json<MessageResponse>> {
+        PasswordUtil::enforce_policy(
+            "password",
+            &body.password,
+            &[&body.email, &body.first_name, &body.last_name],
+            &state.config,
+        )?;
+
         match UserRepo::find_user_by_email(&state.db_pool, &body.email).await {
             Ok(_) => {
                 return Err(ApiErrorResponse::BadRequest(
@@ -87,24 +118,7 @@ impl AuthController {
         )
         .await?;
 
-        let verification_code = code::generate();
-        let expires_at = Utc::now() + Duration::minutes(15);
-        AuthCodeRepo::insert_code(
-            &state.db_pool,
-            user.id,
-            &verification_code,
-            AuthCodeType::EmailVerification,
-            expires_at,
-            None,
-        )
-        .await?;
-
-        let sender = AuthSender::new(
-            state.email_client.clone(),
-            user.email.clone(),
-            verification_code.clone(),
-        );
-        sender.send_email_verification().await?;
+        Self::send_verification_code(&state, &user).await?;
 
         let response = MessageResponse {
             message: "Account created. Please check your email to verify.".to_string(),
@@ -115,14 +129,16 @@ impl AuthController {
 
     /// Confirms a new user's email address.
     ///
-    /// Mapped to `POST /confirm-email`. Validates the verification code
-    /// and marks the user's email as confirmed.
+    /// Mapped to `POST /confirm-email`. Validates the verification code,
+    /// marks the user's email as confirmed, and revokes any existing
+    /// refresh tokens for the user. The code is invalidated after too many
+    /// wrong guesses.
     ///
     /// # Arguments
     ///
     /// * `state` — The shared [`AppState`].
     /// * `body` — A [`ValidatedJson<ConfirmEmailRequest>`] containing the
-    ///   verification code.
+    ///   email address and verification code.
     ///
     /// # Returns
     ///
@@ -136,19 +152,18 @@ impl AuthController {
         State(state): State<AppState>,
         ValidatedJson(body): ValidatedJson<ConfirmEmailRequest>,
     ) -> ApiResult<Json<MessageResponse>> {
-        let auth_code = AuthCodeRepo::find_valid_code(
+        let auth_code = AuthCodeRepo::verify_code_for_email(
             &state.db_pool,
+            &body.email,
             &body.code,
             AuthCodeType::EmailVerification,
         )
-        .await
-        .map_err(|error| {
-            error!("Failed to validate email confirmation code: {:?}", error);
-            ApiErrorResponse::BadRequest("Invalid or expired code".to_string())
-        })?;
+        .await?
+        .ok_or_else(|| ApiErrorResponse::BadRequest("Invalid or expired code".to_string()))?;
 
         AuthCodeRepo::mark_used(&state.db_pool, auth_code.id).await?;
         UserRepo::confirm_email(&state.db_pool, auth_code.user_id).await?;
+        RefreshTokenRepo::revoke_all_for_user(&state.db_pool, auth_code.user_id).await?;
 
         let response = MessageResponse {
             message: "Email confirmed successfully.".to_string(),
@@ -157,15 +172,93 @@ impl AuthController {
         Ok(Json::from(response))
     }
 
+    /// Re-sends the email confirmation code for an unconfirmed account.
+    ///
+    /// Mapped to `POST /resend-confirmation`. Invalidates the user's earlier
+    /// confirmation codes and emails a new code and signed link. Unknown
+    /// and already confirmed emails receive the same success message, to
+    /// avoid leaking whether the account exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — A [`ValidatedJson<ResendConfirmationRequest>`] containing
+    ///   the email address.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MessageResponse>`] with a generic confirmation message.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`] with
+    /// [`ValidationErrorCode::ResendTooSoon`] if a code was sent less than
+    /// 60 seconds ago, or an [`ApiErrorResponse`] if storing the code or
+    /// sending the email fails.
+    pub async fn resend_confirmation(
+        State(state): State<AppState>,
+        ValidatedJson(body): ValidatedJson<ResendConfirmationRequest>,
+    ) -> ApiResult<Json<MessageResponse>> {
+        let response = MessageResponse {
+            message: "If an unconfirmed account exists for this email, a new code has been sent."
+                .to_string(),
+        };
+
+        let user = match UserRepo::find_user_by_email(&state.db_pool, &body.email).await {
+            Ok(user) if !user.email_confirmed => user,
+            Ok(_) | Err(ApiErrorResponse::NotFound(_)) => return Ok(Json(response)),
+            Err(error) => {
+                error!(
+                    "Failed to look up user during confirmation resend: {:?}",
+                    error
+                );
+                return Err(error);
+            }
+        };
+
+        let last_issued_at =
+            AuthCodeRepo::last_issued_at(&state.db_pool, user.id, AuthCodeType::EmailVerification)
+                .await?;
+        if let Some(last_issued_at) = last_issued_at {
+            let wait = last_issued_at + RESEND_COOLDOWN - Utc::now();
+            if wait > Duration::zero() {
+                return Err(ApiErrorResponse::Validation(vec![ValidationError::new(
+                    Some("email".to_string()),
+                    ValidationErrorCode::ResendTooSoon,
+                    format!(
+                        "Please wait {} seconds before requesting another code",
+                        wait.num_seconds() + 1
+                    ),
+                )]));
+            }
+        }
+
+        AuthCodeRepo::invalidate_type_for_user(
+            &state.db_pool,
+            user.id,
+            AuthCodeType::EmailVerification,
+        )
+        .await?;
+        Self::send_verification_code(&state, &user).await?;
+
+        Ok(Json(response))
+    }
+
     /// Authenticates a user and issues session tokens.
     ///
     /// Mapped to `POST /log-in`. Verifies credentials, generates JWT
     /// access and refresh tokens, stores the refresh token hash, and
-    /// sets both tokens as HTTP cookies.
+    /// sets both tokens as HTTP cookies. Unknown emails take as long to
+    /// reject as wrong passwords, and whether the email is confirmed is
+    /// only revealed once the password is correct. Failed and successful
+    /// log-ins are passed to the [`AnomalyDetector`].
     ///
     /// # Arguments
     ///
     /// * `state` — The shared [`AppState`].
+    /// * `peer` — The address of the connected peer, if known.
+    /// * `headers` — The request headers, used to select the cookie domain
+    ///   and resolve the client's address and country.
     /// * `jar` — The [`CookieJar`] to receive the new session cookies.
     /// * `body` — A [`ValidatedJson<LogInRequest>`] containing email and
     ///   password.
@@ -181,48 +274,99 @@ impl AuthController {
     /// or the email is not yet confirmed.
     pub async fn log_in(
         state: State<AppState>,
+        peer: Option<Extension<ConnectInfo<SocketAddr>>>,
+        headers: HeaderMap,
         jar: CookieJar,
         ValidatedJson(body): ValidatedJson<LogInRequest>,
     ) -> ApiResult<(CookieJar, Json<User>)> {
-        let user = UserRepo::find_user_by_email(&state.db_pool, &body.email)
-            .await
-            .map_err(|error| {
-                error!("Failed to find user during log-in: {:?}", error);
-                ApiErrorResponse::BadRequest("Invalid credentials".to_string())
-            })?;
+        let credentials = UserRepo::find_credentials_by_email(&state.db_pool, &body.email).await?;
+        let password_hash = credentials
+            .as_ref()
+            .map(|credentials| credentials.password_hash.as_str());
+
+        if !PasswordUtil::verify_password_uniform(&body.password, password_hash)? {
+            let peer = peer.map(|Extension(ConnectInfo(address))| address);
+            let ip_address = ActivityRecorder::client_ip(&headers, peer);
+
+            if let Err(error) =
+                AnomalyDetector::record_failed_login(&state, &body.email, ip_address.as_deref())
+                    .await
+            {
+                error!("Failed to record failed log-in: {:?}", error);
+            }
 
-        if !user.email_confirmed {
             return Err(ApiErrorResponse::BadRequest(
-                "Please confirm your email before logging in".to_string(),
+                "Invalid credentials".to_string(),
             ));
         }
 
-        let password_hash = UserRepo::get_password_hash(&state.db_pool, user.id).await?;
-        if !PasswordUtil::verify_password(&body.password, &password_hash)? {
+        let user = credentials
+            .map(|credentials| credentials.user)
+            .ok_or_else(|| ApiErrorResponse::BadRequest("Invalid credentials".to_string()))?;
+
+        if !user.email_confirmed {
             return Err(ApiErrorResponse::BadRequest(
-                "Invalid credentials".to_string(),
+                "Please confirm your email before logging in".to_string(),
             ));
         }
 
-        let access_token = JwtUtil::generate_access_token(user.id, &state.config)?;
-        let refresh_token = JwtUtil::generate_refresh_token(user.id, &state.config)?;
+        let jar = Self::issue_session(&state, &headers, jar, user.id, None).await?;
 
-        let token_hash = Self::sha256_hash(&refresh_token);
-        RefreshTokenRepo::insert_token(&state.db_pool, user.id, &token_hash).await?;
-
-        let jar = jar
-            .add(CookiesUtil::build_access_cookie(
-                &access_token,
-                &state.config,
-            ))
-            .add(CookiesUtil::build_refresh_cookie(
-                &refresh_token,
-                &state.config,
-            ));
+        if let Err(error) = AnomalyDetector::record_login(&state, &headers, user.id).await {
+            error!("Failed to record log-in: {:?}", error);
+        }
 
         Ok((jar, Json(user)))
     }
 
+    /// Provisions a throwaway demo account and logs into it.
+    ///
+    /// Mapped to `POST /demo`. Does not require authentication. Only
+    /// available when
+    /// [`Config::demo_accounts_enabled`](crate::core::config::Config::demo_accounts_enabled)
+    /// is set. The account is seeded with sample data, its session cannot be
+    /// refreshed past its expiry, and it is purged by the
+    /// [`DemoCleanupTask`](crate::tasks::demo_cleanup::DemoCleanupTask) once
+    /// it expires.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `headers` — The request headers, used to select the cookie domain.
+    /// * `jar` — The [`CookieJar`] to receive the new session cookies.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the updated [`CookieJar`] and
+    /// [`Json<DemoAccountResponse>`] with the demo user and its expiry.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if demo accounts are disabled,
+    /// or an [`ApiErrorResponse`] if provisioning or issuing the session
+    /// fails.
+    pub async fn demo(
+        State(state): State<AppState>,
+        headers: HeaderMap,
+        jar: CookieJar,
+    ) -> ApiResult<(CookieJar, Json<DemoAccountResponse>)> {
+        if !state.config.demo_accounts_enabled {
+            return Err(ApiErrorResponse::NotFound("Resource not found".to_string()));
+        }
+
+        let email = format!("demo-{}@demo.giglog.invalid", Uuid::new_v4().simple());
+        let password_hash = PasswordUtil::hash_password(&token::generate())?;
+        let expires_at =
+            Utc::now() + Duration::seconds(state.config.demo_account_lifetime_seconds as i64);
+
+        let user =
+            DemoRepo::create_demo_account(&state.db_pool, &email, &password_hash, expires_at)
+                .await?;
+        let jar = Self::issue_session(&state, &headers, jar, user.id, None).await?;
+
+        Ok((jar, Json(DemoAccountResponse { user, expires_at })))
+    }
+
     /// Ends the current session and revokes refresh tokens.
     ///
     /// Mapped to `POST /log-out`. Attempts to revoke the specific refresh
@@ -233,7 +377,8 @@ impl AuthController {
     /// # Arguments
     ///
     /// * `state` — The shared [`AppState`].
-    /// * `jar` — The [`CookieJar`] containing the session cookies to clear.
+    /// * `headers` — The request headers carrying the session cookies.
+    /// * `jar` — The [`CookieJar`] to receive the expiring session cookies.
     ///
     /// # Returns
     ///
@@ -243,11 +388,15 @@ impl AuthController {
     ///
     /// Returns an [`ApiErrorResponse`] if database operations fail during
     /// token revocation.
-    pub async fn log_out(State(state): State<AppState>, jar: CookieJar) -> ApiResult<CookieJar> {
+    pub async fn log_out(
+        State(state): State<AppState>,
+        headers: HeaderMap,
+        jar: CookieJar,
+    ) -> ApiResult<CookieJar> {
         let mut revoked_by_refresh_cookie = false;
 
-        if let Some(refresh_cookie) = jar.get("refresh_token") {
-            let token_hash = Self::sha256_hash(refresh_cookie.value());
+        if let Some(refresh_token) = CookiesUtil::refresh_token(&headers) {
+            let token_hash = token::hash(refresh_token);
             revoked_by_refresh_cookie =
                 RefreshTokenRepo::revoke_token(&state.db_pool, &token_hash).await?;
 
@@ -263,8 +412,8 @@ impl AuthController {
         }
 
         if !revoked_by_refresh_cookie {
-            if let Some(access_cookie) = jar.get("access_token") {
-                match JwtUtil::validate_token(access_cookie.value(), &state.config) {
+            if let Some(access_token) = CookiesUtil::access_token(&headers) {
+                match JwtUtil::decode_access_token(access_token, &state.config) {
                     Ok(token_data) => {
                         RefreshTokenRepo::revoke_all_for_user(
                             &state.db_pool,
@@ -286,23 +435,26 @@ impl AuthController {
             }
         }
 
-        let jar = jar
-            .add(CookiesUtil::clear_access_cookie())
-            .add(CookiesUtil::clear_refresh_cookie());
+        let domain = CookiesUtil::request_domain(&headers, &state.config);
 
-        Ok(jar)
+        Ok(CookiesUtil::clear_session_cookies(
+            jar,
+            domain.as_deref(),
+            &state.config,
+        ))
     }
 
     /// Rotates the session tokens using the current refresh token.
     ///
     /// Mapped to `POST /refresh`. Validates the existing refresh token,
-    /// revokes it, issues a new access/refresh token pair, and updates
-    /// the cookies.
+    /// replaces it with a new access/refresh token pair in the same
+    /// session, and updates the cookies.
     ///
     /// # Arguments
     ///
     /// * `state` — The shared [`AppState`].
-    /// * `jar` — The [`CookieJar`] containing the current refresh token.
+    /// * `headers` — The request headers carrying the current refresh token.
+    /// * `jar` — The [`CookieJar`] to receive the new session cookies.
     ///
     /// # Returns
     ///
@@ -315,21 +467,21 @@ impl AuthController {
     /// missing, invalid, or does not match a stored record.
     pub async fn refresh(
         State(state): State<AppState>,
+        headers: HeaderMap,
         jar: CookieJar,
     ) -> ApiResult<(CookieJar, Json<User>)> {
-        let refresh_cookie = jar
-            .get("refresh_token")
+        let old_token = CookiesUtil::refresh_token(&headers)
             .ok_or_else(|| ApiErrorResponse::BadRequest("Missing refresh token".to_string()))?;
 
-        let old_token = refresh_cookie.value();
-        let token_data = JwtUtil::validate_token(old_token, &state.config).map_err(|error| {
-            error!(
-                "Failed to validate refresh token during token refresh: {:?}",
-                error
-            );
-            ApiErrorResponse::BadRequest("Invalid refresh token".to_string())
-        })?;
-        let old_hash = Self::sha256_hash(old_token);
+        let token_data =
+            JwtUtil::decode_refresh_token(old_token, &state.config).map_err(|error| {
+                error!(
+                    "Failed to validate refresh token during token refresh: {:?}",
+                    error
+                );
+                ApiErrorResponse::BadRequest("Invalid refresh token".to_string())
+            })?;
+        let old_hash = token::hash(old_token);
 
         let token_record = RefreshTokenRepo::find_by_hash(&state.db_pool, &old_hash)
             .await
@@ -347,34 +499,18 @@ impl AuthController {
             ));
         }
 
-        RefreshTokenRepo::revoke_token(&state.db_pool, &old_hash).await?;
-
-        let new_access_token = JwtUtil::generate_access_token(token_record.user_id, &state.config)?;
-        let new_refresh_token =
-            JwtUtil::generate_refresh_token(token_record.user_id, &state.config)?;
-        let new_refresh_hash = Self::sha256_hash(&new_refresh_token);
-
-        RefreshTokenRepo::insert_token(&state.db_pool, token_record.user_id, &new_refresh_hash)
+        let jar = Self::issue_session(&state, &headers, jar, token_record.user_id, Some(&old_hash))
             .await?;
-
         let user = UserRepo::find_user_by_id(&state.db_pool, token_record.user_id).await?;
 
-        let jar = jar
-            .add(CookiesUtil::build_access_cookie(
-                &new_access_token,
-                &state.config,
-            ))
-            .add(CookiesUtil::build_refresh_cookie(
-                &new_refresh_token,
-                &state.config,
-            ));
-
         Ok((jar, Json(user)))
     }
 
     /// Returns the currently authenticated user's profile.
     ///
-    /// Mapped to `GET /me`. Requires a valid access token.
+    /// Mapped to `GET /me`. Requires a valid access token. The profile
+    /// includes the user's progress through account setup so the client
+    /// can show a guided checklist.
     ///
     /// # Arguments
     ///
@@ -383,23 +519,255 @@ impl AuthController {
     ///
     /// # Returns
     ///
-    /// A [`Json<User>`] containing the authenticated user's profile.
+    /// A [`Json<CurrentUserResponse>`] containing the authenticated user's
+    /// profile and onboarding state.
     ///
     /// # Errors
     ///
     /// Returns [`ApiErrorResponse::NotFound`] if the user no longer exists.
-    pub async fn me(auth: AuthUser, State(state): State<AppState>) -> ApiResult<Json<User>> {
+    pub async fn me(
+        auth: AuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<CurrentUserResponse>> {
         let user = UserRepo::find_user_by_id(&state.db_pool, auth.user_id).await?;
+        let onboarding_state =
+            OnboardingStateRepo::find_state(&state.db_pool, auth.user_id).await?;
 
-        Ok(Json(user))
+        Ok(Json(CurrentUserResponse {
+            user,
+            onboarding_state,
+        }))
+    }
+
+    /// Lists the authenticated user's recent API activity.
+    ///
+    /// Mapped to `GET /me/activity`. Requires authentication. Activity is
+    /// recorded by the
+    /// [`ActivityRecorder`](crate::core::activity::ActivityRecorder)
+    /// middleware for requests made with an access token cookie.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Vec<ApiActivity>>`] of the most recent requests, newest
+    /// first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn activity(
+        auth: AuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<Vec<ApiActivity>>> {
+        let activity = ActivityRepo::list_for_user(&state.db_pool, auth.user_id).await?;
+
+        Ok(Json(activity))
+    }
+
+    /// Returns the language the authenticated user's emails are sent in.
+    ///
+    /// Mapped to `GET /me/locale`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<LocalePreference>`] with the current preference.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn show_locale(
+        auth: AuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<LocalePreference>> {
+        let locale = UserRepo::find_locale(&state.db_pool, auth.user_id).await?;
+
+        Ok(Json(LocalePreference { locale }))
+    }
+
+    /// Sets the language the authenticated user's emails are sent in.
+    ///
+    /// Mapped to `PUT /me/locale`. Requires authentication. Emails that
+    /// have not been translated into the locale are sent in English.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — A [`Json<LocalePreference>`] with the new preference.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<LocalePreference>`] with the saved preference.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database update fails.
+    pub async fn update_locale(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Json(body): Json<LocalePreference>,
+    ) -> ApiResult<Json<LocalePreference>> {
+        UserRepo::set_locale(&state.db_pool, auth.user_id, body.locale).await?;
+
+        Ok(Json(body))
+    }
+
+    /// Returns the timezone the authenticated user's times are shown in.
+    ///
+    /// Mapped to `GET /me/timezone`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<TimezonePreference>`] with the current preference, whose
+    /// timezone is `None` if the user has not chosen one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn show_timezone(
+        auth: AuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<TimezonePreference>> {
+        let timezone = UserRepo::find_timezone(&state.db_pool, auth.user_id).await?;
+
+        Ok(Json(TimezonePreference { timezone }))
+    }
+
+    /// Sets or clears the timezone the authenticated user's times are shown
+    /// in.
+    ///
+    /// Mapped to `PUT /me/timezone`. Requires authentication. A `null`
+    /// timezone clears the preference, so clients fall back to the
+    /// browser's timezone.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — A [`TimezonePreference`] with the new preference.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<TimezonePreference>`] with the saved preference.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`] if the timezone is not a
+    /// known IANA timezone name, or an [`ApiErrorResponse`] if the database
+    /// update fails.
+    pub async fn update_timezone(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedJson(body): ValidatedJson<TimezonePreference>,
+    ) -> ApiResult<Json<TimezonePreference>> {
+        if let Some(timezone) = &body.timezone
+            && !UserRepo::is_known_timezone(&state.db_pool, timezone).await?
+        {
+            return Err(ApiErrorResponse::Validation(vec![ValidationError::new(
+                Some("timezone".to_string()),
+                ValidationErrorCode::Invalid,
+                "Timezone must be an IANA timezone name such as America/Chicago",
+            )]));
+        }
+
+        UserRepo::set_timezone(&state.db_pool, auth.user_id, body.timezone.as_deref()).await?;
+
+        Ok(Json(body))
+    }
+
+    /// Lists the authenticated user's active login sessions.
+    ///
+    /// Mapped to `GET /sessions`. Requires authentication. The session
+    /// whose refresh token cookie accompanies the request is flagged as
+    /// current.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `headers` — The request headers carrying the refresh token cookie.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Vec<UserSession>>`] of active sessions, most recently used
+    /// first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn sessions(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        headers: HeaderMap,
+    ) -> ApiResult<Json<Vec<UserSession>>> {
+        let current_hash = CookiesUtil::refresh_token(&headers).map(token::hash);
+        let sessions = RefreshTokenRepo::list_active_for_user(&state.db_pool, auth.user_id)
+            .await?
+            .into_iter()
+            .map(|session| session.into_session(current_hash.as_deref()))
+            .collect();
+
+        Ok(Json(sessions))
+    }
+
+    /// Revokes one of the authenticated user's login sessions.
+    ///
+    /// Mapped to `DELETE /sessions/{id}`. Requires authentication. The
+    /// session can no longer be refreshed, though an access token already
+    /// issued to it stays valid until it expires.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `session_id` — The session's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MessageResponse>`] confirming the revocation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if no active session with the
+    /// given ID belongs to the user.
+    pub async fn revoke_session(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(session_id): Path<Uuid>,
+    ) -> ApiResult<Json<MessageResponse>> {
+        let revoked =
+            RefreshTokenRepo::revoke_for_user(&state.db_pool, auth.user_id, session_id).await?;
+
+        if !revoked {
+            return Err(ApiErrorResponse::NotFound("Session not found".to_string()));
+        }
+
+        Ok(Json(MessageResponse {
+            message: "Session revoked successfully.".to_string(),
+        }))
     }
 
     /// Initiates the forgot-password flow.
     ///
     /// Mapped to `POST /forgot-password`. If an account exists for the
     /// given email, generates a password-reset code and sends it via
-    /// email. Always returns a success message to avoid leaking whether
-    /// the account exists.
+    /// email in the background. Always returns the same success message,
+    /// without waiting on the email, to avoid leaking whether the account
+    /// exists.
     ///
     /// # Arguments
     ///
@@ -413,8 +781,7 @@ impl AuthController {
     ///
     /// # Errors
     ///
-    /// Returns [`ApiErrorResponse::InternalServerError`] if the email
-    /// fails to send.
+    /// Returns an error if looking up the account fails.
     pub async fn forgot_password(
         State(state): State<AppState>,
         ValidatedJson(body): ValidatedJson<ForgotPasswordRequest>,
@@ -432,31 +799,11 @@ impl AuthController {
         };
 
         if let Some(user) = user {
-            let reset_code = code::generate();
-            let expires_at = Utc::now() + Duration::minutes(15);
-
-            AuthCodeRepo::insert_code(
-                &state.db_pool,
-                user.id,
-                &reset_code,
-                AuthCodeType::PasswordReset,
-                expires_at,
-                None,
-            )
-            .await?;
-
-            let sender = AuthSender::new(
-                state.email_client.clone(),
-                user.email.clone(),
-                reset_code.clone(),
-            );
-
-            if let Err(error) = sender.send_reset_password().await {
-                error!("Failed to send forgot-password email: {:?}", error);
-                return Err(ApiErrorResponse::InternalServerError(
-                    "Failed to send email".to_string(),
-                ));
-            }
+            tokio::spawn(async move {
+                if let Err(error) = Self::send_reset_code(&state, &user).await {
+                    error!("Failed to send forgot-password email: {:?}", error);
+                }
+            });
         }
 
         let response = MessageResponse {
@@ -470,13 +817,13 @@ impl AuthController {
     ///
     /// Mapped to `POST /verify-forgot-password`. Allows the client to
     /// check whether a reset code is still valid before presenting the
-    /// new-password form.
+    /// new-password form. Wrong guesses count toward invalidating the code.
     ///
     /// # Arguments
     ///
     /// * `state` — The shared [`AppState`].
     /// * `body` — A [`ValidatedJson<VerifyForgotPasswordRequest>`]
-    ///   containing the reset code.
+    ///   containing the email address and reset code.
     ///
     /// # Returns
     ///
@@ -490,12 +837,14 @@ impl AuthController {
         State(state): State<AppState>,
         ValidatedJson(body): ValidatedJson<VerifyForgotPasswordRequest>,
     ) -> ApiResult<Json<MessageResponse>> {
-        AuthCodeRepo::find_valid_code(&state.db_pool, &body.code, AuthCodeType::PasswordReset)
-            .await
-            .map_err(|error| {
-                error!("Failed to verify forgot-password code: {:?}", error);
-                ApiErrorResponse::BadRequest("Invalid or expired reset code".to_string())
-            })?;
+        AuthCodeRepo::verify_code_for_email(
+            &state.db_pool,
+            &body.email,
+            &body.code,
+            AuthCodeType::PasswordReset,
+        )
+        .await?
+        .ok_or_else(|| ApiErrorResponse::BadRequest("Invalid or expired reset code".to_string()))?;
 
         Ok(Json(MessageResponse {
             message: "Reset code is valid.".to_string(),
@@ -512,7 +861,7 @@ impl AuthController {
     ///
     /// * `state` — The shared [`AppState`].
     /// * `body` — A [`ValidatedJson<SetPasswordRequest>`] containing the
-    ///   reset code and new password.
+    ///   email address, reset code, and new password.
     ///
     /// # Returns
     ///
@@ -520,23 +869,30 @@ impl AuthController {
     ///
     /// # Errors
     ///
-    /// Returns [`ApiErrorResponse::BadRequest`] if the reset code is
+    /// Returns [`ApiErrorResponse::Validation`] if the new password does
+    /// not meet the password policy. Returns
+    /// [`ApiErrorResponse::BadRequest`] if the reset code is
     /// invalid or expired. Returns
     /// [`ApiErrorResponse::InternalServerError`] if password hashing fails.
     pub async fn set_password(
         State(state): State<AppState>,
         ValidatedJson(body): ValidatedJson<SetPasswordRequest>,
     ) -> ApiResult<Json<MessageResponse>> {
-        let auth_code =
-            AuthCodeRepo::find_valid_code(&state.db_pool, &body.code, AuthCodeType::PasswordReset)
-                .await
-                .map_err(|error| {
-                    error!(
-                        "Failed to validate reset code during set-password: {:?}",
-                        error
-                    );
-                    ApiErrorResponse::BadRequest("Invalid or expired reset code".to_string())
-                })?;
+        PasswordUtil::enforce_policy(
+            "new_password",
+            &body.new_password,
+            &[&body.email],
+            &state.config,
+        )?;
+
+        let auth_code = AuthCodeRepo::verify_code_for_email(
+            &state.db_pool,
+            &body.email,
+            &body.code,
+            AuthCodeType::PasswordReset,
+        )
+        .await?
+        .ok_or_else(|| ApiErrorResponse::BadRequest("Invalid or expired reset code".to_string()))?;
 
         let password_hash = PasswordUtil::hash_password(&body.new_password).map_err(|error| {
             error!("Failed to hash password during set-password: {:?}", error);
@@ -569,12 +925,16 @@ impl AuthController {
     /// # Errors
     ///
     /// Returns [`ApiErrorResponse::NotFound`] if the user no longer exists.
+    /// Returns [`ApiErrorResponse::Forbidden`] if unusual activity was
+    /// detected since the user last logged in.
     /// Returns [`ApiErrorResponse::InternalServerError`] if email sending
     /// fails.
     pub async fn request_change_password_code(
         auth: AuthUser,
         State(state): State<AppState>,
     ) -> ApiResult<Json<MessageResponse>> {
+        AnomalyDetector::require_recent_auth(&state, &auth).await?;
+
         let user = UserRepo::find_user_by_id(&state.db_pool, auth.user_id).await?;
 
         let verification_code = code::generate();
@@ -594,7 +954,8 @@ impl AuthController {
             state.email_client.clone(),
             user.email.clone(),
             verification_code,
-        );
+        )
+        .with_locale(UserRepo::find_locale(&state.db_pool, user.id).await?);
         sender.send_password_change().await?;
 
         Ok(Json(MessageResponse {
@@ -606,29 +967,44 @@ impl AuthController {
     ///
     /// Mapped to `POST /change-password`. Requires authentication.
     /// Verifies the current password, validates the change code, hashes
-    /// the new password, and revokes all refresh tokens.
+    /// the new password, revokes all refresh tokens, and issues the caller
+    /// a fresh session.
     ///
     /// # Arguments
     ///
     /// * `auth` — The [`AuthUser`] extracted from the access token.
     /// * `state` — The shared [`AppState`].
+    /// * `headers` — The request headers, used to select the cookie domain.
+    /// * `jar` — The [`CookieJar`] to receive the new session cookies.
     /// * `body` — A [`ValidatedJson<ChangePasswordRequest>`] containing
     ///   the current password, verification code, and new password.
     ///
     /// # Returns
     ///
-    /// A [`Json<MessageResponse>`] confirming the password was changed.
+    /// A tuple of the updated [`CookieJar`] and [`Json<MessageResponse>`]
+    /// confirming the password was changed.
     ///
     /// # Errors
     ///
     /// Returns [`ApiErrorResponse::BadRequest`] if the current password is
-    /// incorrect or the verification code is invalid or expired.
+    /// incorrect or the verification code is invalid or expired. Returns
+    /// [`ApiErrorResponse::Validation`] if the new password does not meet
+    /// the password policy.
+    /// Returns [`ApiErrorResponse::Forbidden`] if unusual activity was
+    /// detected since the user last logged in.
     pub async fn change_password(
         auth: AuthUser,
         State(state): State<AppState>,
+        headers: HeaderMap,
+        jar: CookieJar,
         ValidatedJson(body): ValidatedJson<ChangePasswordRequest>,
-    ) -> ApiResult<Json<MessageResponse>> {
-        let current_hash = UserRepo::get_password_hash(&state.db_pool, auth.user_id).await?;
+    ) -> ApiResult<(CookieJar, Json<MessageResponse>)> {
+        AnomalyDetector::require_recent_auth(&state, &auth).await?;
+
+        let UserCredentials {
+            user,
+            password_hash: current_hash,
+        } = UserRepo::find_credentials_by_id(&state.db_pool, auth.user_id).await?;
 
         if !PasswordUtil::verify_password(&body.current_password, &current_hash)? {
             return Err(ApiErrorResponse::BadRequest(
@@ -636,19 +1012,21 @@ impl AuthController {
             ));
         }
 
-        let auth_code =
-            AuthCodeRepo::find_valid_code(&state.db_pool, &body.code, AuthCodeType::PasswordChange)
-                .await
-                .map_err(|error| {
-                    error!("Failed to validate password change code: {:?}", error);
-                    ApiErrorResponse::BadRequest("Invalid or expired code".to_string())
-                })?;
+        PasswordUtil::enforce_policy(
+            "new_password",
+            &body.new_password,
+            &[&user.email, &user.first_name, &user.last_name],
+            &state.config,
+        )?;
 
-        if auth_code.user_id != auth.user_id {
-            return Err(ApiErrorResponse::BadRequest(
-                "Invalid or expired code".to_string(),
-            ));
-        }
+        let auth_code = AuthCodeRepo::verify_code_for_user(
+            &state.db_pool,
+            auth.user_id,
+            &body.code,
+            AuthCodeType::PasswordChange,
+        )
+        .await?
+        .ok_or_else(|| ApiErrorResponse::BadRequest("Invalid or expired code".to_string()))?;
 
         AuthCodeRepo::mark_used(&state.db_pool, auth_code.id).await?;
 
@@ -663,16 +1041,22 @@ impl AuthController {
         UserRepo::update_password(&state.db_pool, auth.user_id, &new_hash).await?;
         RefreshTokenRepo::revoke_all_for_user(&state.db_pool, auth.user_id).await?;
 
-        Ok(Json(MessageResponse {
-            message: "Password changed successfully.".to_string(),
-        }))
+        let jar = Self::issue_session(&state, &headers, jar, auth.user_id, None).await?;
+
+        Ok((
+            jar,
+            Json(MessageResponse {
+                message: "Password changed successfully.".to_string(),
+            }),
+        ))
     }
 
     /// Initiates an email address change for the authenticated user.
     ///
     /// Mapped to `POST /request-email-change`. Requires authentication.
     /// Checks that the new email is not already in use, generates a
-    /// verification code, and sends it to the new address.
+    /// verification code, and sends it to the new address along with a
+    /// signed confirmation link.
     ///
     /// # Arguments
     ///
@@ -689,11 +1073,15 @@ impl AuthController {
     ///
     /// Returns [`ApiErrorResponse::BadRequest`] if the new email is
     /// already in use.
+    /// Returns [`ApiErrorResponse::Forbidden`] if unusual activity was
+    /// detected since the user last logged in.
     pub async fn request_email_change(
         auth: AuthUser,
         State(state): State<AppState>,
         ValidatedJson(body): ValidatedJson<RequestEmailChangeRequest>,
     ) -> ApiResult<Json<MessageResponse>> {
+        AnomalyDetector::require_recent_auth(&state, &auth).await?;
+
         match UserRepo::find_user_by_email(&state.db_pool, &body.new_email).await {
             Ok(_) => {
                 return Err(ApiErrorResponse::BadRequest(
@@ -713,7 +1101,7 @@ impl AuthController {
         let change_code = code::generate();
         let expires_at = Utc::now() + Duration::minutes(15);
 
-        AuthCodeRepo::insert_code(
+        let code_id = AuthCodeRepo::insert_code(
             &state.db_pool,
             auth.user_id,
             &change_code,
@@ -722,12 +1110,20 @@ impl AuthController {
             Some(&body.new_email),
         )
         .await?;
+        let link = LinkUtil::link_for_code(
+            code_id,
+            AuthCodeType::EmailChange,
+            expires_at,
+            &state.config,
+        )?;
 
         let sender = AuthSender::new(
             state.email_client.clone(),
             body.new_email.clone(),
             change_code.clone(),
-        );
+        )
+        .with_link(link)
+        .with_locale(UserRepo::find_locale(&state.db_pool, auth.user_id).await?);
 
         sender.send_email_change().await?;
 
@@ -739,13 +1135,14 @@ impl AuthController {
     /// Completes the email address change using a verification code.
     ///
     /// Mapped to `POST /confirm-email-change`. Validates the email-change
-    /// code, updates the user's email address, and marks the code as used.
+    /// code, updates the user's email address, marks the code as used, and
+    /// revokes all existing refresh tokens for the user.
     ///
     /// # Arguments
     ///
     /// * `state` — The shared [`AppState`].
     /// * `body` — A [`ValidatedJson<ConfirmEmailRequest>`] containing the
-    ///   verification code.
+    ///   new email address and verification code.
     ///
     /// # Returns
     ///
@@ -760,13 +1157,14 @@ impl AuthController {
         State(state): State<AppState>,
         ValidatedJson(body): ValidatedJson<ConfirmEmailRequest>,
     ) -> ApiResult<Json<MessageResponse>> {
-        let auth_code =
-            AuthCodeRepo::find_valid_code(&state.db_pool, &body.code, AuthCodeType::EmailChange)
-                .await
-                .map_err(|error| {
-                    error!("Failed to validate email change code: {:?}", error);
-                    ApiErrorResponse::BadRequest("Invalid or expired code".to_string())
-                })?;
+        let auth_code = AuthCodeRepo::verify_code_for_email(
+            &state.db_pool,
+            &body.email,
+            &body.code,
+            AuthCodeType::EmailChange,
+        )
+        .await?
+        .ok_or_else(|| ApiErrorResponse::BadRequest("Invalid or expired code".to_string()))?;
 
         let new_email = auth_code.new_email.as_ref().ok_or_else(|| {
             ApiErrorResponse::InternalServerError("Email change code missing new email".to_string())
@@ -774,27 +1172,308 @@ impl AuthController {
 
         UserRepo::update_email_and_confirm(&state.db_pool, auth_code.user_id, new_email).await?;
         AuthCodeRepo::mark_used(&state.db_pool, auth_code.id).await?;
+        RefreshTokenRepo::revoke_all_for_user(&state.db_pool, auth_code.user_id).await?;
 
         Ok(Json(MessageResponse {
             message: "Email changed successfully.".to_string(),
         }))
     }
 
-    /// Computes a hex-encoded SHA-256 hash of the given input.
+    /// Confirms an email address from the signed link in a verification or
+    /// email change email.
+    ///
+    /// Mapped to `GET /confirm`. Does not require authentication. Completes
+    /// the same flow as `POST /confirm-email` or `POST /confirm-email-change`
+    /// and redirects the browser to the web client's log-in page. Invalid,
+    /// expired, or already used links redirect to the code entry page
+    /// instead, so the emailed code remains a fallback.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `query` — The [`EmailLinkQuery`] carrying the signed link token.
+    ///
+    /// # Returns
+    ///
+    /// A [`Redirect`] to the web client.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a database query fails. Returns
+    /// [`ApiErrorResponse::InternalServerError`] if an email change code is
+    /// missing the new email value.
+    pub async fn confirm_link(
+        State(state): State<AppState>,
+        Query(query): Query<EmailLinkQuery>,
+    ) -> ApiResult<Redirect> {
+        let Some(auth_code) = Self::resolve_link(
+            &state,
+            &query.token,
+            &[AuthCodeType::EmailVerification, AuthCodeType::EmailChange],
+        )
+        .await?
+        else {
+            return Ok(Redirect::to(&LinkUtil::web_url(
+                "/auth/confirm-email?link=invalid",
+                &state.config,
+            )));
+        };
+
+        if auth_code.code_type == AuthCodeType::EmailChange {
+            let new_email = auth_code.new_email.as_ref().ok_or_else(|| {
+                ApiErrorResponse::InternalServerError(
+                    "Email change code missing new email".to_string(),
+                )
+            })?;
+
+            UserRepo::update_email_and_confirm(&state.db_pool, auth_code.user_id, new_email)
+                .await?;
+        } else {
+            UserRepo::confirm_email(&state.db_pool, auth_code.user_id).await?;
+        }
+
+        AuthCodeRepo::mark_used(&state.db_pool, auth_code.id).await?;
+        RefreshTokenRepo::revoke_all_for_user(&state.db_pool, auth_code.user_id).await?;
+
+        Ok(Redirect::to(&LinkUtil::web_url(
+            "/auth/log-in?email_confirmed=true",
+            &state.config,
+        )))
+    }
+
+    /// Opens the password reset form from the signed link in a password
+    /// reset email.
     ///
-    /// Used internally to hash refresh tokens before storage, so that
-    /// raw token values are never persisted to the database.
+    /// Mapped to `GET /reset-password`. Does not require authentication.
+    /// Redirects the browser to the web client's set-password page with
+    /// the email address and reset code filled in, so the reset is
+    /// completed by `POST /set-password` as with a typed code. Invalid or
+    /// expired links redirect to the forgot-password page instead.
     ///
     /// # Arguments
     ///
-    /// * `input` — The string to hash.
+    /// * `state` — The shared [`AppState`].
+    /// * `query` — The [`EmailLinkQuery`] carrying the signed link token.
+    ///
+    /// # Returns
+    ///
+    /// A [`Redirect`] to the web client.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a database query fails.
+    pub async fn reset_password_link(
+        State(state): State<AppState>,
+        Query(query): Query<EmailLinkQuery>,
+    ) -> ApiResult<Redirect> {
+        let Some(auth_code) =
+            Self::resolve_link(&state, &query.token, &[AuthCodeType::PasswordReset]).await?
+        else {
+            return Ok(Redirect::to(&LinkUtil::web_url(
+                "/auth/forgot-password?link=invalid",
+                &state.config,
+            )));
+        };
+
+        let user = UserRepo::find_user_by_id(&state.db_pool, auth_code.user_id).await?;
+        let path = format!(
+            "/auth/set-password?email={}&code={}",
+            LinkUtil::encode_query_value(&user.email),
+            auth_code.code
+        );
+
+        Ok(Redirect::to(&LinkUtil::web_url(&path, &state.config)))
+    }
+
+    /// Resolves a signed link token to the authorization code it completes.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `token` — The raw link token.
+    /// * `purposes` — The code types the calling endpoint accepts.
     ///
     /// # Returns
     ///
-    /// The lowercase hex-encoded SHA-256 digest as a [`String`].
-    fn sha256_hash(input: &str) -> String {
-        let mut hasher = Sha256::new();
-        hasher.update(input.as_bytes());
-        format!("{:x}", hasher.finalize())
+    /// The valid [`AuthCodeRecord`], or `None` if the token is invalid or
+    /// expired, is for another code type, or its code was already used.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the code lookup fails.
+    async fn resolve_link(
+        state: &AppState,
+        token: &str,
+        purposes: &[AuthCodeType],
+    ) -> ApiResult<Option<AuthCodeRecord>> {
+        let Ok(claims) = LinkUtil::decode_link_token(token, &state.config) else {
+            return Ok(None);
+        };
+
+        if !purposes.contains(&claims.purpose) {
+            warn!(
+                "Email link used at the wrong endpoint: {:?}",
+                claims.purpose
+            );
+            return Ok(None);
+        }
+
+        AuthCodeRepo::find_valid_code(&state.db_pool, claims.sub, claims.purpose).await
+    }
+
+    /// Generates a password-reset code for a user and emails it to them
+    /// along with a signed reset link.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `user` — The user resetting their password.
+    ///
+    /// # Returns
+    ///
+    /// `()` once the email is sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if storing the code or sending the email fails.
+    async fn send_reset_code(state: &AppState, user: &User) -> ApiResult<()> {
+        let reset_code = code::generate();
+        let expires_at = Utc::now() + Duration::minutes(15);
+
+        let code_id = AuthCodeRepo::insert_code(
+            &state.db_pool,
+            user.id,
+            &reset_code,
+            AuthCodeType::PasswordReset,
+            expires_at,
+            None,
+        )
+        .await?;
+        let link = LinkUtil::link_for_code(
+            code_id,
+            AuthCodeType::PasswordReset,
+            expires_at,
+            &state.config,
+        )?;
+
+        AuthSender::new(state.email_client.clone(), user.email.clone(), reset_code)
+            .with_link(link)
+            .with_locale(UserRepo::find_locale(&state.db_pool, user.id).await?)
+            .send_reset_password()
+            .await
+    }
+
+    /// Generates an email verification code and emails it to the user with
+    /// a signed confirmation link.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `user` — The user to send the code to.
+    ///
+    /// # Returns
+    ///
+    /// `()` once the email is sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if storing the code or sending the email fails.
+    async fn send_verification_code(state: &AppState, user: &User) -> ApiResult<()> {
+        let verification_code = code::generate();
+        let expires_at = Utc::now() + Duration::minutes(15);
+
+        let code_id = AuthCodeRepo::insert_code(
+            &state.db_pool,
+            user.id,
+            &verification_code,
+            AuthCodeType::EmailVerification,
+            expires_at,
+            None,
+        )
+        .await?;
+        let link = LinkUtil::link_for_code(
+            code_id,
+            AuthCodeType::EmailVerification,
+            expires_at,
+            &state.config,
+        )?;
+
+        AuthSender::new(
+            state.email_client.clone(),
+            user.email.clone(),
+            verification_code,
+        )
+        .with_link(link)
+        .with_locale(UserRepo::find_locale(&state.db_pool, user.id).await?)
+        .send_email_verification()
+        .await
+    }
+
+    /// Issues a new access/refresh token pair and sets them as cookies.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `headers` — The request headers, used to select the cookie domain
+    ///   and record the device's user agent.
+    /// * `jar` — The [`CookieJar`] to receive the session cookies.
+    /// * `user_id` — The UUID of the user the session belongs to.
+    /// * `replaces` — Hash of the refresh token being rotated, or `None` to
+    ///   start a new session.
+    ///
+    /// # Returns
+    ///
+    /// The updated [`CookieJar`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the rotated refresh token
+    /// is no longer active, or an [`ApiErrorResponse`] if token generation
+    /// or storing the refresh token hash fails.
+    pub(crate) async fn issue_session(
+        state: &AppState,
+        headers: &HeaderMap,
+        jar: CookieJar,
+        user_id: Uuid,
+        replaces: Option<&str>,
+    ) -> ApiResult<CookieJar> {
+        let refresh_token = JwtUtil::generate_refresh_token(user_id, &state.config)?;
+
+        let token_hash = token::hash(&refresh_token);
+        let user_agent = headers
+            .get(header::USER_AGENT)
+            .and_then(|value| value.to_str().ok());
+
+        let auth_time = match replaces {
+            Some(old_hash) => {
+                RefreshTokenRepo::rotate_token(&state.db_pool, old_hash, &token_hash, user_agent)
+                    .await?
+                    .ok_or_else(|| {
+                        ApiErrorResponse::BadRequest("Invalid refresh token".to_string())
+                    })?
+            }
+            None => {
+                RefreshTokenRepo::insert_token(&state.db_pool, user_id, &token_hash, user_agent)
+                    .await?;
+
+                Utc::now()
+            }
+        };
+
+        let access_token = JwtUtil::generate_access_token(user_id, auth_time, &state.config)?;
+
+        let domain = CookiesUtil::request_domain(headers, &state.config);
+
+        Ok(jar
+            .add(CookiesUtil::build_access_cookie(
+                &access_token,
+                domain.as_deref(),
+                &state.config,
+            ))
+            .add(CookiesUtil::build_refresh_cookie(
+                &refresh_token,
+                domain.as_deref(),
+                &state.config,
+            )))
     }
 }
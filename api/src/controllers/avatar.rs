@@ -0,0 +1,113 @@
+//! User avatar endpoints.
+//!
+//! Provides [`AvatarController`] with handlers for uploading, downloading,
+//! and removing the authenticated user's avatar. Uploaded pictures are
+//! cropped and resized to a standard square before they are stored.
+
+use axum::{Json, body::Bytes, extract::State, response::Response};
+use gig_log_common::models::{generic::MessageResponse, user::User};
+
+use crate::auth::AuthUser;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::images::{AVATAR_SIZE, ImageFit, ImageUtil};
+use crate::repo::user::UserRepo;
+use crate::routes::app::AppState;
+
+/// Handlers for user avatar routes.
+pub struct AvatarController;
+
+impl AvatarController {
+    /// Uploads the authenticated user's avatar.
+    ///
+    /// Mapped to `PUT /auth/me/avatar`. Requires authentication. The
+    /// request body is the raw PNG, JPEG, GIF, or WebP file. It is cropped
+    /// to a square, resized to [`AVATAR_SIZE`] pixels, and stored as a PNG,
+    /// replacing any existing avatar. Bodies larger than
+    /// [`MAX_IMAGE_UPLOAD_BYTES`](crate::images::MAX_IMAGE_UPLOAD_BYTES)
+    /// are rejected by the router.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — The raw image bytes.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<User>`] containing the user and their new avatar URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the body is not a
+    /// supported image, or [`ApiErrorResponse::NotFound`] if the user no
+    /// longer exists.
+    pub async fn upload(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        body: Bytes,
+    ) -> ApiResult<Json<User>> {
+        let avatar = ImageUtil::resize(body.to_vec(), AVATAR_SIZE, ImageFit::Cover).await?;
+        let user = UserRepo::set_avatar(&state.db_pool, auth.user_id, &avatar).await?;
+
+        Ok(Json(user))
+    }
+
+    /// Downloads the authenticated user's avatar.
+    ///
+    /// Mapped to `GET /auth/me/avatar`. Requires authentication. Responds
+    /// with the resized PNG.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Response`] containing the avatar.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the user has no avatar.
+    pub async fn show(auth: AuthUser, State(state): State<AppState>) -> ApiResult<Response> {
+        let avatar = UserRepo::find_avatar(&state.db_pool, auth.user_id)
+            .await
+            .map_err(|error| match error {
+                ApiErrorResponse::NotFound(_) => {
+                    ApiErrorResponse::NotFound("Avatar not found".to_string())
+                }
+                error => error,
+            })?;
+
+        Ok(ImageUtil::response(avatar))
+    }
+
+    /// Removes the authenticated user's avatar.
+    ///
+    /// Mapped to `DELETE /auth/me/avatar`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MessageResponse>`] confirming the removal.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the user has no avatar.
+    pub async fn delete(
+        auth: AuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<MessageResponse>> {
+        if !UserRepo::delete_avatar(&state.db_pool, auth.user_id).await? {
+            return Err(ApiErrorResponse::NotFound("Avatar not found".to_string()));
+        }
+
+        Ok(Json(MessageResponse {
+            message: "Avatar deleted.".to_string(),
+        }))
+    }
+}
@@ -0,0 +1,141 @@
+//! Budget endpoints.
+//!
+//! Provides [`BudgetController`] with handlers for creating, listing, and
+//! deleting monthly budgets on companies and jobs.
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use gig_log_common::models::{
+    budget::{BudgetStatus, CreateBudgetRequest},
+    generic::MessageResponse,
+};
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::extractors::ValidatedJson;
+use crate::repo::{budget::BudgetRepo, company::CompanyRepo, job::JobRepo};
+use crate::routes::app::AppState;
+
+/// Handlers for budget routes.
+pub struct BudgetController;
+
+impl BudgetController {
+    /// Creates a monthly budget on a company or job.
+    ///
+    /// Mapped to `POST /budgets`. Requires authentication. The budgeted
+    /// company or job must belong to the authenticated user.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — A [`ValidatedJson<CreateBudgetRequest>`] containing the
+    ///   budget target, type, and monthly limit.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<BudgetStatus>`] with the new budget and its current-month
+    /// consumption.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the company or job does not
+    /// exist or belongs to another user.
+    pub async fn create(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedJson(body): ValidatedJson<CreateBudgetRequest>,
+    ) -> ApiResult<Json<BudgetStatus>> {
+        if let Some(company_id) = body.company_id {
+            CompanyRepo::find_company_by_id(&state.db_pool, auth.user_id, company_id).await?;
+        }
+
+        if let Some(job_id) = body.job_id {
+            JobRepo::find_job_by_id(&state.db_pool, auth.user_id, job_id).await?;
+        }
+
+        let budget_id = BudgetRepo::insert_budget(
+            &state.db_pool,
+            auth.user_id,
+            body.company_id,
+            body.job_id,
+            body.budget_type,
+            body.monthly_limit,
+        )
+        .await?;
+
+        let status = BudgetRepo::list_statuses(
+            &state.db_pool,
+            Some(auth.user_id),
+            body.company_id,
+            body.job_id,
+        )
+        .await?
+        .into_iter()
+        .find(|record| record.id == budget_id)
+        .ok_or_else(|| ApiErrorResponse::NotFound("Budget not found".to_string()))?;
+
+        Ok(Json(status.into()))
+    }
+
+    /// Lists the authenticated user's budgets with current-month consumption.
+    ///
+    /// Mapped to `GET /budgets`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Vec<BudgetStatus>>`] ordered by creation time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn list(
+        auth: AuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<Vec<BudgetStatus>>> {
+        let records =
+            BudgetRepo::list_statuses(&state.db_pool, Some(auth.user_id), None, None).await?;
+
+        Ok(Json(records.into_iter().map(Into::into).collect()))
+    }
+
+    /// Deletes one of the authenticated user's budgets.
+    ///
+    /// Mapped to `DELETE /budgets/{id}`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `budget_id` — The budget's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MessageResponse>`] confirming the deletion.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the budget does not exist
+    /// or belongs to another user.
+    pub async fn delete(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(budget_id): Path<Uuid>,
+    ) -> ApiResult<Json<MessageResponse>> {
+        if !BudgetRepo::delete_budget(&state.db_pool, auth.user_id, budget_id).await? {
+            return Err(ApiErrorResponse::NotFound("Budget not found".to_string()));
+        }
+
+        Ok(Json(MessageResponse {
+            message: "Budget deleted.".to_string(),
+        }))
+    }
+}
@@ -0,0 +1,176 @@
+//! Calendar feed endpoints.
+//!
+//! Provides [`CalendarController`] with handlers for creating, listing, and
+//! revoking calendar feeds, and for serving the tokenized iCalendar feed
+//! that calendar apps subscribe to. Feed tokens are stored hashed, so the
+//! raw token is only returned when a feed is created.
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::header,
+    response::{IntoResponse, Response},
+};
+use chrono::Utc;
+use gig_log_common::models::{
+    calendar::{CalendarFeed, CreateCalendarFeedRequest, CreateCalendarFeedResponse},
+    generic::MessageResponse,
+};
+use uuid::Uuid;
+
+use crate::auth::{AuthUser, token};
+use crate::calendar::{CALENDAR_FEED_HISTORY, CalendarUtil};
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::extractors::ValidatedJson;
+use crate::repo::calendar::CalendarFeedRepo;
+use crate::routes::app::AppState;
+
+/// Handlers for calendar feed routes.
+pub struct CalendarController;
+
+impl CalendarController {
+    /// Creates a calendar feed.
+    ///
+    /// Mapped to `POST /calendar/feeds`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — A [`ValidatedJson<CreateCalendarFeedRequest>`] containing
+    ///   the feed's name.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<CreateCalendarFeedResponse>`] containing the raw token and
+    /// feed path, which are not retrievable later.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database insert fails.
+    pub async fn create(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedJson(body): ValidatedJson<CreateCalendarFeedRequest>,
+    ) -> ApiResult<Json<CreateCalendarFeedResponse>> {
+        let raw_token = token::generate();
+        let calendar_feed = CalendarFeedRepo::insert_feed(
+            &state.db_pool,
+            auth.user_id,
+            body.name.trim(),
+            &token::hash(&raw_token),
+        )
+        .await?;
+
+        Ok(Json(CreateCalendarFeedResponse {
+            path: format!("/calendar/{raw_token}.ics"),
+            token: raw_token,
+            calendar_feed,
+        }))
+    }
+
+    /// Lists the authenticated user's calendar feeds.
+    ///
+    /// Mapped to `GET /calendar/feeds`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Vec<CalendarFeed>>`] ordered newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn list(
+        auth: AuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<Vec<CalendarFeed>>> {
+        let feeds = CalendarFeedRepo::list_feeds(&state.db_pool, auth.user_id).await?;
+
+        Ok(Json(feeds))
+    }
+
+    /// Revokes one of the authenticated user's calendar feeds.
+    ///
+    /// Mapped to `DELETE /calendar/feeds/{id}`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `feed_id` — The feed's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MessageResponse>`] confirming the revocation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the feed does not exist or
+    /// belongs to another user.
+    pub async fn delete(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(feed_id): Path<Uuid>,
+    ) -> ApiResult<Json<MessageResponse>> {
+        if !CalendarFeedRepo::delete_feed(&state.db_pool, auth.user_id, feed_id).await? {
+            return Err(ApiErrorResponse::NotFound(
+                "Calendar feed not found".to_string(),
+            ));
+        }
+
+        Ok(Json(MessageResponse {
+            message: "Calendar feed revoked.".to_string(),
+        }))
+    }
+
+    /// Serves a user's calendar of work sessions and payouts.
+    ///
+    /// Mapped to `GET /calendar/{token}.ics`. Does not require
+    /// authentication; access is granted by the feed token. Lists completed
+    /// work sessions and expected payouts from the last
+    /// [`CALENDAR_FEED_HISTORY`] onward.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `file` — The `<token>.ics` file name from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A `text/calendar` [`Response`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the path does not end in
+    /// `.ics` or the token is unknown or revoked.
+    pub async fn feed(
+        State(state): State<AppState>,
+        Path(file): Path<String>,
+    ) -> ApiResult<Response> {
+        let not_found = || ApiErrorResponse::NotFound("Calendar feed not found".to_string());
+
+        let raw_token = file.strip_suffix(".ics").ok_or_else(not_found)?;
+        let user_id = CalendarFeedRepo::use_token(&state.db_pool, &token::hash(raw_token))
+            .await?
+            .ok_or_else(not_found)?;
+
+        let since = Utc::now() - CALENDAR_FEED_HISTORY;
+        let sessions = CalendarFeedRepo::list_sessions(&state.db_pool, user_id, since).await?;
+        let payouts =
+            CalendarFeedRepo::list_payouts(&state.db_pool, user_id, since.date_naive()).await?;
+
+        Ok((
+            [
+                (header::CONTENT_TYPE, "text/calendar; charset=utf-8"),
+                (header::CACHE_CONTROL, "private, max-age=300"),
+            ],
+            CalendarUtil::render(&sessions, &payouts),
+        )
+            .into_response())
+    }
+}
@@ -0,0 +1,611 @@
+//! Company endpoints.
+//!
+//! Provides [`CompanyController`] with handlers for company details and
+//! company-scoped resources such as budgets, unbilled work, invoice
+//! drafting, emailed statements, work locations, and the company's logo.
+
+use axum::{
+    Json,
+    body::Bytes,
+    extract::{Path, Query, State},
+    response::Response,
+};
+use gig_log_common::models::{
+    company::{
+        Company, CompanyDeletePreview, CompanyDetailResponse, DeleteCompanyQuery,
+        DuplicateCompanyResponse, UpdateCompanyRequest,
+    },
+    generic::{DetailQuery, MessageResponse},
+    invoice::{Invoice, UnbilledWorkResponse},
+    statement::{CompanyStatement, SendStatementRequest},
+    work_session::CompanyLocationSummary,
+};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::billing::BillingUtil;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::email::senders::statement::StatementSender;
+use crate::extractors::{ValidatedJson, ValidatedQuery};
+use crate::images::{ImageFit, ImageUtil, LOGO_SIZE};
+use crate::include::IncludeUtil;
+use crate::repo::{
+    budget::BudgetRepo, company::CompanyRepo, contact::ContactRepo, contract::ContractRepo,
+    invoice::InvoiceRepo, job::JobRepo, payment::PaymentRepo, statement::StatementRepo,
+    user::UserRepo, work_session::WorkSessionRepo,
+};
+use crate::routes::app::AppState;
+use crate::sparse_fields::SparseFieldsUtil;
+use crate::statement::StatementUtil;
+
+/// Handlers for company routes.
+pub struct CompanyController;
+
+impl CompanyController {
+    /// Returns a company with its budgets, contracts, and primary contact.
+    ///
+    /// Mapped to `GET /companies/{id}`. Requires authentication. Accepts
+    /// `?fields=` to return only some top-level fields and
+    /// `?include=jobs,payments` to embed the company's jobs and payments.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `company_id` — The company's UUID from the request path.
+    /// * `query` — The [`DetailQuery`] selecting fields and includes.
+    ///
+    /// # Returns
+    ///
+    /// A [`CompanyDetailResponse`] as JSON, containing the company, its
+    /// budgets with current-month consumption, its contracts, its primary
+    /// contact, and any included relations, limited to the requested fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`](crate::core::error::ApiErrorResponse::NotFound)
+    /// if the company does not exist or belongs to another user,
+    /// [`ApiErrorResponse::Validation`](crate::core::error::ApiErrorResponse::Validation)
+    /// if `fields` or `include` is too long, or
+    /// [`ApiErrorResponse::BadRequest`](crate::core::error::ApiErrorResponse::BadRequest)
+    /// if an unknown field or include is requested.
+    pub async fn show(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(company_id): Path<Uuid>,
+        ValidatedQuery(query): ValidatedQuery<DetailQuery>,
+    ) -> ApiResult<Json<Value>> {
+        let includes = IncludeUtil::parse(query.include.as_deref(), &["jobs", "payments"])?;
+        let company =
+            CompanyRepo::find_company_by_id(&state.db_pool, auth.user_id, company_id).await?;
+        let budgets =
+            BudgetRepo::list_statuses(&state.db_pool, Some(auth.user_id), Some(company_id), None)
+                .await?;
+        let contracts =
+            ContractRepo::list_contracts(&state.db_pool, auth.user_id, Some(company_id)).await?;
+        let primary_contact =
+            ContactRepo::find_primary_contact(&state.db_pool, auth.user_id, company_id).await?;
+
+        let jobs = if includes.contains(&"jobs") {
+            Some(JobRepo::list_company_jobs(&state.db_pool, auth.user_id, company_id).await?)
+        } else {
+            None
+        };
+        let payments = if includes.contains(&"payments") {
+            Some(
+                PaymentRepo::list_company_payments(&state.db_pool, auth.user_id, company_id)
+                    .await?,
+            )
+        } else {
+            None
+        };
+
+        let response = CompanyDetailResponse {
+            company,
+            budgets: budgets.into_iter().map(Into::into).collect(),
+            contracts,
+            primary_contact,
+            jobs,
+            payments,
+        };
+
+        Ok(Json(SparseFieldsUtil::select(
+            &response,
+            query.fields.as_deref(),
+        )?))
+    }
+
+    /// Partially updates one of the authenticated user's companies.
+    ///
+    /// Mapped to `PATCH /companies/{id}`. Requires authentication. Omitted fields
+    /// are left unchanged. The accent color may be a hex color or one of the
+    /// user's palette tokens, which follows the palette when it changes.
+    /// Enabling `auto_draft_invoices` drafts an invoice every Monday from the
+    /// previous week's unbilled sessions for the user to review.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `company_id` — The company's UUID from the request path.
+    /// * `body` — A [`ValidatedJson<UpdateCompanyRequest>`] containing the fields to
+    ///   change.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Company>`] containing the updated company.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`](crate::core::error::ApiErrorResponse::NotFound)
+    /// if the company does not exist or belongs to another user, or
+    /// [`ApiErrorResponse::Validation`](crate::core::error::ApiErrorResponse::Validation)
+    /// if the tax withholding rate would be set without being required or
+    /// required without being set.
+    pub async fn update(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(company_id): Path<Uuid>,
+        ValidatedJson(body): ValidatedJson<UpdateCompanyRequest>,
+    ) -> ApiResult<Json<Company>> {
+        let company =
+            CompanyRepo::update_company(&state.db_pool, auth.user_id, company_id, &body).await?;
+
+        Ok(Json(company))
+    }
+
+    /// Previews what deleting one of the authenticated user's companies
+    /// would affect.
+    ///
+    /// Mapped to `GET /companies/{id}/delete-preview`. Requires
+    /// authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `company_id` — The company's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<CompanyDeletePreview>`] with the number of records of each
+    /// kind that would be deleted, how many of them are finalized invoices
+    /// or locked work sessions, and the mileage entries that would lose
+    /// their job.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the company does not exist
+    /// or belongs to another user.
+    pub async fn delete_preview(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(company_id): Path<Uuid>,
+    ) -> ApiResult<Json<CompanyDeletePreview>> {
+        let preview = CompanyRepo::delete_preview(&state.db_pool, auth.user_id, company_id)
+            .await
+            .map_err(|error| match error {
+                ApiErrorResponse::NotFound(_) => {
+                    ApiErrorResponse::NotFound("Company not found".to_string())
+                }
+                error => error,
+            })?;
+
+        Ok(Json(preview))
+    }
+
+    /// Deletes one of the authenticated user's companies.
+    ///
+    /// Mapped to `DELETE /companies/{id}`. Requires authentication. Records
+    /// that belong to the company are deleted with it, so when it has any,
+    /// `?confirm=true` is required. Deleting finalized invoices or invoiced
+    /// or approved work sessions also requires `?confirm_finalized=true`.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `company_id` — The company's UUID from the request path.
+    /// * `query` — The [`DeleteCompanyQuery`] confirming the deletion.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MessageResponse>`] confirming the deletion.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the company does not exist
+    /// or belongs to another user, or [`ApiErrorResponse::BadRequest`] if it
+    /// has dependent records and the deletion was not confirmed.
+    pub async fn delete(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(company_id): Path<Uuid>,
+        Query(query): Query<DeleteCompanyQuery>,
+    ) -> ApiResult<Json<MessageResponse>> {
+        CompanyRepo::delete_company(&state.db_pool, auth.user_id, company_id, &query).await?;
+
+        Ok(Json(MessageResponse {
+            message: "Company deleted.".to_string(),
+        }))
+    }
+
+    /// Duplicates one of the authenticated user's companies.
+    ///
+    /// Mapped to `POST /companies/{id}/duplicate`. Requires authentication.
+    /// The copy keeps the company's tax withholding settings, accent color,
+    /// and logo, and each of its jobs is copied with its payment terms.
+    /// Contacts, contracts, budgets, payments, and work history are not
+    /// copied.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `company_id` — The UUID of the company to copy, from the request
+    ///   path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<DuplicateCompanyResponse>`] containing the new company and
+    /// its jobs.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the company does not exist
+    /// or belongs to another user.
+    pub async fn duplicate(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(company_id): Path<Uuid>,
+    ) -> ApiResult<Json<DuplicateCompanyResponse>> {
+        let response = CompanyRepo::duplicate_company(&state.db_pool, auth.user_id, company_id)
+            .await
+            .map_err(|error| match error {
+                ApiErrorResponse::NotFound(_) => {
+                    ApiErrorResponse::NotFound("Company not found".to_string())
+                }
+                error => error,
+            })?;
+
+        Ok(Json(response))
+    }
+
+    /// Returns the company's completed work sessions that are not yet invoiced.
+    ///
+    /// Mapped to `GET /companies/{id}/unbilled`. Requires authentication.
+    /// Sessions are grouped by job with durations rounded to the billing
+    /// increment and priced at the rate each session was completed at.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `company_id` — The company's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<UnbilledWorkResponse>`] with unbilled work grouped by job.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`](crate::core::error::ApiErrorResponse::NotFound)
+    /// if the company does not exist or belongs to another user.
+    pub async fn unbilled(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(company_id): Path<Uuid>,
+    ) -> ApiResult<Json<UnbilledWorkResponse>> {
+        CompanyRepo::find_company_by_id(&state.db_pool, auth.user_id, company_id).await?;
+
+        let sessions =
+            WorkSessionRepo::list_unbilled_for_company(&state.db_pool, auth.user_id, company_id)
+                .await?;
+        let jobs = BillingUtil::group_unbilled_sessions(&sessions);
+        let total_amount = BillingUtil::total_amount(&jobs);
+
+        Ok(Json(UnbilledWorkResponse {
+            company_id,
+            jobs,
+            total_amount,
+        }))
+    }
+
+    /// Summarizes where work for the company was done.
+    ///
+    /// Mapped to `GET /companies/{id}/locations`. Requires authentication.
+    /// Only sessions recorded while location tracking was enabled have a
+    /// location. Useful for substantiating mileage to the company's sites.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `company_id` — The company's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<CompanyLocationSummary>`] with session counts and the
+    /// places work was done.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`](crate::core::error::ApiErrorResponse::NotFound)
+    /// if the company does not exist or belongs to another user.
+    pub async fn locations(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(company_id): Path<Uuid>,
+    ) -> ApiResult<Json<CompanyLocationSummary>> {
+        CompanyRepo::find_company_by_id(&state.db_pool, auth.user_id, company_id).await?;
+
+        let summary =
+            WorkSessionRepo::company_location_summary(&state.db_pool, auth.user_id, company_id)
+                .await?;
+
+        Ok(Json(summary))
+    }
+
+    /// Bills the company's unbilled work sessions onto a draft invoice.
+    ///
+    /// Mapped to `POST /companies/{id}/unbilled/bill`. Requires
+    /// authentication. Reuses the company's open draft invoice when one
+    /// exists, adds a line item per job, and marks the sessions as billed
+    /// in a single transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `company_id` — The company's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Invoice>`] containing the draft invoice and its line items.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`](crate::core::error::ApiErrorResponse::NotFound)
+    /// if the company does not exist or belongs to another user. Returns
+    /// [`ApiErrorResponse::BadRequest`](crate::core::error::ApiErrorResponse::BadRequest)
+    /// if there is no unbilled work to attach.
+    pub async fn bill_unbilled(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(company_id): Path<Uuid>,
+    ) -> ApiResult<Json<Invoice>> {
+        CompanyRepo::find_company_by_id(&state.db_pool, auth.user_id, company_id).await?;
+
+        let invoice =
+            InvoiceRepo::attach_unbilled_sessions(&state.db_pool, auth.user_id, company_id, None)
+                .await?;
+
+        Ok(Json(invoice))
+    }
+
+    /// Emails a statement of the company's payments and hours as a PDF.
+    ///
+    /// Mapped to `POST /companies/{id}/send-statement`. Requires
+    /// authentication. The statement covers completed work sessions that
+    /// started and payments dated within the period, and is recorded in the
+    /// company's statement history once sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `company_id` — The company's UUID from the request path.
+    /// * `body` — A [`ValidatedJson<SendStatementRequest>`] containing the
+    ///   recipient and period.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<CompanyStatement>`] describing the sent statement.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`](crate::core::error::ApiErrorResponse::NotFound)
+    /// if the company does not exist or belongs to another user. Returns
+    /// [`ApiErrorResponse::InternalServerError`](crate::core::error::ApiErrorResponse::InternalServerError)
+    /// if the email cannot be sent.
+    pub async fn send_statement(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(company_id): Path<Uuid>,
+        ValidatedJson(body): ValidatedJson<SendStatementRequest>,
+    ) -> ApiResult<Json<CompanyStatement>> {
+        let company =
+            CompanyRepo::find_company_by_id(&state.db_pool, auth.user_id, company_id).await?;
+        let user = UserRepo::find_user_by_id(&state.db_pool, auth.user_id).await?;
+        let sender_name = format!("{} {}", user.first_name, user.last_name);
+
+        let hours = WorkSessionRepo::list_statement_hours(
+            &state.db_pool,
+            auth.user_id,
+            company_id,
+            body.period_start,
+            body.period_end,
+        )
+        .await?;
+        let payments = PaymentRepo::list_statement_payments(
+            &state.db_pool,
+            auth.user_id,
+            company_id,
+            body.period_start,
+            body.period_end,
+        )
+        .await?;
+
+        let lines = StatementUtil::build_lines(
+            &sender_name,
+            &company.name,
+            body.period_start,
+            body.period_end,
+            &hours,
+            &payments,
+        );
+
+        StatementSender::new(state.email_client.clone(), body.recipient_email.clone())
+            .with_locale(UserRepo::find_locale(&state.db_pool, auth.user_id).await?)
+            .send_statement(
+                &sender_name,
+                &company.name,
+                body.period_start,
+                body.period_end,
+                StatementUtil::render_pdf(&lines),
+            )
+            .await?;
+
+        let statement = StatementRepo::insert_statement(
+            &state.db_pool,
+            auth.user_id,
+            company_id,
+            &body,
+            StatementUtil::total_hours(&hours),
+            StatementUtil::total_payments(&payments),
+        )
+        .await?;
+
+        Ok(Json(statement))
+    }
+
+    /// Lists the statements sent to the company, most recent first.
+    ///
+    /// Mapped to `GET /companies/{id}/statements`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `company_id` — The company's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Vec<CompanyStatement>>`] containing the statement history.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`](crate::core::error::ApiErrorResponse::NotFound)
+    /// if the company does not exist or belongs to another user.
+    pub async fn statements(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(company_id): Path<Uuid>,
+    ) -> ApiResult<Json<Vec<CompanyStatement>>> {
+        CompanyRepo::find_company_by_id(&state.db_pool, auth.user_id, company_id).await?;
+
+        let statements =
+            StatementRepo::list_statements(&state.db_pool, auth.user_id, company_id).await?;
+
+        Ok(Json(statements))
+    }
+
+    /// Uploads a logo for one of the authenticated user's companies.
+    ///
+    /// Mapped to `PUT /companies/{id}/logo`. Requires authentication. The
+    /// request body is the raw PNG, JPEG, GIF, or WebP file. It is resized
+    /// to fit within [`LOGO_SIZE`] pixels square and stored as a PNG,
+    /// replacing any existing logo. Bodies larger than
+    /// [`MAX_IMAGE_UPLOAD_BYTES`](crate::images::MAX_IMAGE_UPLOAD_BYTES)
+    /// are rejected by the router.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `company_id` — The company's UUID from the request path.
+    /// * `body` — The raw image bytes.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Company>`] containing the company and its new logo URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the body is not a
+    /// supported image, or [`ApiErrorResponse::NotFound`] if the company
+    /// does not exist or belongs to another user.
+    pub async fn upload_logo(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(company_id): Path<Uuid>,
+        body: Bytes,
+    ) -> ApiResult<Json<Company>> {
+        CompanyRepo::find_company_by_id(&state.db_pool, auth.user_id, company_id).await?;
+
+        let logo = ImageUtil::resize(body.to_vec(), LOGO_SIZE, ImageFit::Contain).await?;
+        let company =
+            CompanyRepo::set_logo(&state.db_pool, auth.user_id, company_id, &logo).await?;
+
+        Ok(Json(company))
+    }
+
+    /// Downloads a company's logo.
+    ///
+    /// Mapped to `GET /companies/{id}/logo`. Requires authentication.
+    /// Responds with the resized PNG.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `company_id` — The company's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Response`] containing the logo.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the company does not exist,
+    /// belongs to another user, or has no logo.
+    pub async fn logo(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(company_id): Path<Uuid>,
+    ) -> ApiResult<Response> {
+        let logo = CompanyRepo::find_logo(&state.db_pool, auth.user_id, company_id)
+            .await
+            .map_err(|error| match error {
+                ApiErrorResponse::NotFound(_) => {
+                    ApiErrorResponse::NotFound("Logo not found".to_string())
+                }
+                error => error,
+            })?;
+
+        Ok(ImageUtil::response(logo))
+    }
+
+    /// Removes a company's logo.
+    ///
+    /// Mapped to `DELETE /companies/{id}/logo`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `company_id` — The company's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MessageResponse>`] confirming the removal.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the company does not exist,
+    /// belongs to another user, or has no logo.
+    pub async fn delete_logo(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(company_id): Path<Uuid>,
+    ) -> ApiResult<Json<MessageResponse>> {
+        if !CompanyRepo::delete_logo(&state.db_pool, auth.user_id, company_id).await? {
+            return Err(ApiErrorResponse::NotFound("Logo not found".to_string()));
+        }
+
+        Ok(Json(MessageResponse {
+            message: "Logo deleted.".to_string(),
+        }))
+    }
+}
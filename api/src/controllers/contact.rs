@@ -0,0 +1,207 @@
+//! Company contact endpoints.
+//!
+//! Provides [`ContactController`] with handlers for adding, listing,
+//! updating, and removing the people the user deals with at a company,
+//! including choosing the company's primary contact.
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use gig_log_common::models::{
+    contact::{Contact, CreateContactRequest, UpdateContactRequest},
+    generic::MessageResponse,
+};
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::extractors::ValidatedJson;
+use crate::repo::{company::CompanyRepo, contact::ContactRepo};
+use crate::routes::app::AppState;
+
+/// Handlers for company contact routes.
+pub struct ContactController;
+
+impl ContactController {
+    /// Adds a contact to one of the authenticated user's companies.
+    ///
+    /// Mapped to `POST /companies/{id}/contacts`. Requires authentication.
+    /// Adding a primary contact replaces the company's existing one.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `company_id` — The company's UUID from the request path.
+    /// * `body` — A [`ValidatedJson<CreateContactRequest>`] containing the
+    ///   contact details.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Contact>`] containing the created contact.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the company does not exist
+    /// or belongs to another user.
+    pub async fn create(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(company_id): Path<Uuid>,
+        ValidatedJson(body): ValidatedJson<CreateContactRequest>,
+    ) -> ApiResult<Json<Contact>> {
+        let contact = ContactRepo::insert_contact(&state.db_pool, auth.user_id, company_id, &body)
+            .await
+            .map_err(|error| match error {
+                ApiErrorResponse::NotFound(_) => {
+                    ApiErrorResponse::NotFound("Company not found".to_string())
+                }
+                error => error,
+            })?;
+
+        Ok(Json(contact))
+    }
+
+    /// Lists the contacts at one of the authenticated user's companies.
+    ///
+    /// Mapped to `GET /companies/{id}/contacts`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `company_id` — The company's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Vec<Contact>>`] with the primary contact first, then the
+    /// rest by name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the company does not exist
+    /// or belongs to another user.
+    pub async fn list(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(company_id): Path<Uuid>,
+    ) -> ApiResult<Json<Vec<Contact>>> {
+        CompanyRepo::find_company_by_id(&state.db_pool, auth.user_id, company_id).await?;
+
+        let contacts = ContactRepo::list_contacts(&state.db_pool, auth.user_id, company_id).await?;
+
+        Ok(Json(contacts))
+    }
+
+    /// Returns one of a company's contacts.
+    ///
+    /// Mapped to `GET /companies/{id}/contacts/{contact_id}`. Requires
+    /// authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `company_id` — The company's UUID from the request path.
+    /// * `contact_id` — The contact's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Contact>`] containing the contact.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the contact does not exist
+    /// on the user's company.
+    pub async fn show(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path((company_id, contact_id)): Path<(Uuid, Uuid)>,
+    ) -> ApiResult<Json<Contact>> {
+        let contact =
+            ContactRepo::find_contact_by_id(&state.db_pool, auth.user_id, company_id, contact_id)
+                .await?;
+
+        Ok(Json(contact))
+    }
+
+    /// Partially updates one of a company's contacts.
+    ///
+    /// Mapped to `PATCH /companies/{id}/contacts/{contact_id}`. Requires
+    /// authentication. Omitted fields are left unchanged. Making the
+    /// contact primary replaces the company's existing primary contact.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `company_id` — The company's UUID from the request path.
+    /// * `contact_id` — The contact's UUID from the request path.
+    /// * `body` — A [`ValidatedJson<UpdateContactRequest>`] containing the
+    ///   fields to change.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Contact>`] containing the updated contact.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the contact does not exist
+    /// on the user's company.
+    pub async fn update(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path((company_id, contact_id)): Path<(Uuid, Uuid)>,
+        ValidatedJson(body): ValidatedJson<UpdateContactRequest>,
+    ) -> ApiResult<Json<Contact>> {
+        let contact = ContactRepo::update_contact(
+            &state.db_pool,
+            auth.user_id,
+            company_id,
+            contact_id,
+            &body,
+        )
+        .await?;
+
+        Ok(Json(contact))
+    }
+
+    /// Removes one of a company's contacts.
+    ///
+    /// Mapped to `DELETE /companies/{id}/contacts/{contact_id}`. Requires
+    /// authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `company_id` — The company's UUID from the request path.
+    /// * `contact_id` — The contact's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MessageResponse>`] confirming the deletion.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the contact does not exist
+    /// on the user's company.
+    pub async fn delete(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path((company_id, contact_id)): Path<(Uuid, Uuid)>,
+    ) -> ApiResult<Json<MessageResponse>> {
+        let deleted =
+            ContactRepo::delete_contact(&state.db_pool, auth.user_id, company_id, contact_id)
+                .await?;
+
+        if !deleted {
+            return Err(ApiErrorResponse::NotFound("Contact not found".to_string()));
+        }
+
+        Ok(Json(MessageResponse {
+            message: "Contact deleted.".to_string(),
+        }))
+    }
+}
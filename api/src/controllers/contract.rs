@@ -0,0 +1,308 @@
+//! Contract endpoints.
+//!
+//! Provides [`ContractController`] with handlers for managing engagement
+//! contracts with companies and their signed document attachments.
+
+use axum::{
+    Json,
+    body::Bytes,
+    extract::{Path, State},
+    http::{HeaderMap, header},
+    response::{IntoResponse, Response},
+};
+use gig_log_common::models::{
+    contract::{
+        Contract, CreateContractRequest, ListContractsQuery, UpdateContractRequest,
+        UploadContractDocumentQuery,
+    },
+    generic::MessageResponse,
+};
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::extractors::{ValidatedJson, ValidatedQuery};
+use crate::repo::{company::CompanyRepo, contract::ContractRepo};
+use crate::routes::app::AppState;
+
+/// Maximum accepted size of a signed contract document, in bytes.
+pub const MAX_CONTRACT_DOCUMENT_BYTES: usize = 10 * 1024 * 1024;
+
+/// Content type used when an upload does not specify one.
+const DEFAULT_DOCUMENT_CONTENT_TYPE: &str = "application/octet-stream";
+
+/// Handlers for contract routes.
+pub struct ContractController;
+
+impl ContractController {
+    /// Creates a contract with one of the user's companies.
+    ///
+    /// Mapped to `POST /contracts`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — A [`ValidatedJson<CreateContractRequest>`] containing the
+    ///   contract dates, rate terms, and reminder settings.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Contract>`] containing the created contract.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the company does not exist
+    /// or belongs to another user.
+    pub async fn create(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedJson(body): ValidatedJson<CreateContractRequest>,
+    ) -> ApiResult<Json<Contract>> {
+        CompanyRepo::find_company_by_id(&state.db_pool, auth.user_id, body.company_id).await?;
+
+        let contract = ContractRepo::insert_contract(&state.db_pool, auth.user_id, body).await?;
+
+        Ok(Json(contract))
+    }
+
+    /// Lists the authenticated user's contracts.
+    ///
+    /// Mapped to `GET /contracts`. Requires authentication. Accepts an
+    /// optional `company_id` query parameter to restrict the results.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `query` — The [`ListContractsQuery`] filters.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Vec<Contract>>`] ordered by start date.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn list(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedQuery(query): ValidatedQuery<ListContractsQuery>,
+    ) -> ApiResult<Json<Vec<Contract>>> {
+        let contracts =
+            ContractRepo::list_contracts(&state.db_pool, auth.user_id, query.company_id).await?;
+
+        Ok(Json(contracts))
+    }
+
+    /// Returns one of the authenticated user's contracts.
+    ///
+    /// Mapped to `GET /contracts/{id}`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `contract_id` — The contract's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Contract>`] containing the contract.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the contract does not exist
+    /// or belongs to another user.
+    pub async fn show(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(contract_id): Path<Uuid>,
+    ) -> ApiResult<Json<Contract>> {
+        let contract =
+            ContractRepo::find_contract_by_id(&state.db_pool, auth.user_id, contract_id).await?;
+
+        Ok(Json(contract))
+    }
+
+    /// Updates one of the authenticated user's contracts.
+    ///
+    /// Mapped to `PUT /contracts/{id}`. Requires authentication. Changing
+    /// the end date or reminder lead time re-arms the renewal reminder.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `contract_id` — The contract's UUID from the request path.
+    /// * `body` — A [`ValidatedJson<UpdateContractRequest>`] with the fields
+    ///   to change.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Contract>`] containing the updated contract.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the contract does not exist
+    /// or belongs to another user. Returns [`ApiErrorResponse::BadRequest`]
+    /// if the update would make the contract end before it starts.
+    pub async fn update(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(contract_id): Path<Uuid>,
+        ValidatedJson(body): ValidatedJson<UpdateContractRequest>,
+    ) -> ApiResult<Json<Contract>> {
+        let existing =
+            ContractRepo::find_contract_by_id(&state.db_pool, auth.user_id, contract_id).await?;
+        let start_date = body.start_date.unwrap_or(existing.start_date);
+
+        if body
+            .end_date
+            .or(existing.end_date)
+            .is_some_and(|end_date| end_date < start_date)
+        {
+            return Err(ApiErrorResponse::BadRequest(
+                "Contract end date must not be before start date".to_string(),
+            ));
+        }
+
+        let contract =
+            ContractRepo::update_contract(&state.db_pool, auth.user_id, contract_id, body).await?;
+
+        Ok(Json(contract))
+    }
+
+    /// Deletes one of the authenticated user's contracts.
+    ///
+    /// Mapped to `DELETE /contracts/{id}`. Requires authentication. Also
+    /// removes the signed document attachment.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `contract_id` — The contract's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MessageResponse>`] confirming the deletion.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the contract does not exist
+    /// or belongs to another user.
+    pub async fn delete(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(contract_id): Path<Uuid>,
+    ) -> ApiResult<Json<MessageResponse>> {
+        if !ContractRepo::delete_contract(&state.db_pool, auth.user_id, contract_id).await? {
+            return Err(ApiErrorResponse::NotFound("Contract not found".to_string()));
+        }
+
+        Ok(Json(MessageResponse {
+            message: "Contract deleted.".to_string(),
+        }))
+    }
+
+    /// Attaches a signed document to a contract, replacing any existing one.
+    ///
+    /// Mapped to `PUT /contracts/{id}/document`. Requires authentication.
+    /// The request body is the raw file; its type is taken from the
+    /// `Content-Type` header and its name from the `file_name` query
+    /// parameter. Bodies larger than [`MAX_CONTRACT_DOCUMENT_BYTES`] are
+    /// rejected by the router.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `contract_id` — The contract's UUID from the request path.
+    /// * `query` — The [`UploadContractDocumentQuery`] naming the file.
+    /// * `headers` — Request headers carrying the document's content type.
+    /// * `body` — The raw document bytes.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Contract>`] with the updated document metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`] if the file name is invalid,
+    /// [`ApiErrorResponse::BadRequest`] if the body is empty, or
+    /// [`ApiErrorResponse::NotFound`] if the contract does not exist or
+    /// belongs to another user.
+    pub async fn upload_document(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(contract_id): Path<Uuid>,
+        ValidatedQuery(query): ValidatedQuery<UploadContractDocumentQuery>,
+        headers: HeaderMap,
+        body: Bytes,
+    ) -> ApiResult<Json<Contract>> {
+        if body.is_empty() {
+            return Err(ApiErrorResponse::BadRequest(
+                "Document must not be empty".to_string(),
+            ));
+        }
+
+        let content_type = headers
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or(DEFAULT_DOCUMENT_CONTENT_TYPE);
+
+        let contract = ContractRepo::set_document(
+            &state.db_pool,
+            auth.user_id,
+            contract_id,
+            &query.file_name,
+            content_type,
+            &body,
+        )
+        .await?;
+
+        Ok(Json(contract))
+    }
+
+    /// Downloads a contract's signed document.
+    ///
+    /// Mapped to `GET /contracts/{id}/document`. Requires authentication.
+    /// Responds with the stored bytes, content type, and an attachment
+    /// `Content-Disposition` carrying the original file name.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `contract_id` — The contract's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Response`] containing the document.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the contract does not exist,
+    /// belongs to another user, or has no document attached.
+    pub async fn download_document(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(contract_id): Path<Uuid>,
+    ) -> ApiResult<Response> {
+        let document =
+            ContractRepo::find_document(&state.db_pool, auth.user_id, contract_id).await?;
+        let file_name = document.file_name.replace(['"', '\\', '\r', '\n'], "_");
+
+        Ok((
+            [
+                (header::CONTENT_TYPE, document.content_type),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"{file_name}\""),
+                ),
+            ],
+            document.data,
+        )
+            .into_response())
+    }
+}
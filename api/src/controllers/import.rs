@@ -0,0 +1,155 @@
+//! CSV import endpoints.
+//!
+//! Provides [`ImportController`] with handlers for importing payments and
+//! work sessions from spreadsheet exports. Every row is validated before
+//! anything is written, and a dry run reports the same row errors without
+//! importing the file.
+
+use axum::{
+    Json,
+    extract::{Multipart, Query, State, multipart::MultipartRejection},
+};
+use gig_log_common::models::import::{ImportQuery, ImportSummary};
+
+use crate::auth::AuthUser;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::import::{ImportDirectory, ImportUtil};
+use crate::repo::import::ImportRepo;
+use crate::routes::app::AppState;
+
+/// Handlers for CSV import routes.
+pub struct ImportController;
+
+impl ImportController {
+    /// Imports payments from a CSV file.
+    ///
+    /// Mapped to `POST /import/payments`. Requires authentication. The file
+    /// is uploaded as the `file` field of a multipart form, and its
+    /// `company` column names one of the user's companies. With
+    /// `?dry_run=true` the file is only validated.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `query` — The [`ImportQuery`] selecting a dry run.
+    /// * `multipart` — The multipart form carrying the file.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<ImportSummary>`] with the number of rows imported.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`] listing every invalid row,
+    /// in which case nothing is imported, or
+    /// [`ApiErrorResponse::BadRequest`] if the upload is not a readable
+    /// multipart form or the file has no rows.
+    pub async fn payments(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Query(query): Query<ImportQuery>,
+        multipart: Result<Multipart, MultipartRejection>,
+    ) -> ApiResult<Json<ImportSummary>> {
+        let data = Self::read_file(multipart).await?;
+        let directory = Self::directory(&state, &auth).await?;
+        let payments = ImportUtil::payments(&data, &directory)?;
+
+        if !query.dry_run {
+            ImportRepo::insert_payments(&state.db_pool, auth.user_id, &payments).await?;
+        }
+
+        Ok(Json(ImportSummary {
+            dry_run: query.dry_run,
+            rows: payments.len(),
+        }))
+    }
+
+    /// Imports completed work sessions from a CSV file.
+    ///
+    /// Mapped to `POST /import/work-sessions`. Requires authentication. The
+    /// file is uploaded as the `file` field of a multipart form, and its
+    /// `company` and `job` columns name one of the user's jobs. With
+    /// `?dry_run=true` the file is only validated.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `query` — The [`ImportQuery`] selecting a dry run.
+    /// * `multipart` — The multipart form carrying the file.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<ImportSummary>`] with the number of rows imported.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`] listing every invalid row,
+    /// in which case nothing is imported, or
+    /// [`ApiErrorResponse::BadRequest`] if the upload is not a readable
+    /// multipart form or the file has no rows.
+    pub async fn work_sessions(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Query(query): Query<ImportQuery>,
+        multipart: Result<Multipart, MultipartRejection>,
+    ) -> ApiResult<Json<ImportSummary>> {
+        let data = Self::read_file(multipart).await?;
+        let directory = Self::directory(&state, &auth).await?;
+        let sessions = ImportUtil::work_sessions(&data, &directory)?;
+
+        if !query.dry_run {
+            ImportRepo::insert_work_sessions(&state.db_pool, auth.user_id, &sessions).await?;
+        }
+
+        Ok(Json(ImportSummary {
+            dry_run: query.dry_run,
+            rows: sessions.len(),
+        }))
+    }
+
+    /// Reads the uploaded CSV file.
+    ///
+    /// # Arguments
+    ///
+    /// * `multipart` — The extracted multipart form, or why it could not
+    ///   be extracted.
+    ///
+    /// # Returns
+    ///
+    /// The file's bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the request is not a
+    /// multipart form, or any error from
+    /// [`ImportUtil::read_upload`].
+    async fn read_file(multipart: Result<Multipart, MultipartRejection>) -> ApiResult<Vec<u8>> {
+        let multipart =
+            multipart.map_err(|error| ApiErrorResponse::BadRequest(error.body_text()))?;
+
+        ImportUtil::read_upload(multipart).await
+    }
+
+    /// Loads the companies and jobs imported rows can refer to.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `auth` — The [`AuthUser`] importing the file.
+    ///
+    /// # Returns
+    ///
+    /// The user's [`ImportDirectory`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a query fails.
+    async fn directory(state: &AppState, auth: &AuthUser) -> ApiResult<ImportDirectory> {
+        let companies = ImportRepo::list_companies(&state.db_pool, auth.user_id).await?;
+        let jobs = ImportRepo::list_jobs(&state.db_pool, auth.user_id).await?;
+
+        Ok(ImportDirectory::new(companies, jobs))
+    }
+}
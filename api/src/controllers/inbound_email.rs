@@ -0,0 +1,111 @@
+//! Inbound email webhook endpoint.
+//!
+//! Provides [`InboundEmailController`], which receives emails forwarded by
+//! the email provider and turns quick-log commands such as
+//! `log 2h on Acme Website` from verified senders into work session drafts.
+
+use axum::{
+    Json,
+    extract::{Query, State},
+};
+use gig_log_common::models::{
+    generic::MessageResponse,
+    work_session_draft::{InboundEmailQuery, InboundEmailWebhook},
+};
+use log::info;
+
+use crate::auth::token;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::quick_log::QuickLogUtil;
+use crate::repo::{job::JobRepo, user::UserRepo, work_session_draft::WorkSessionDraftRepo};
+use crate::routes::app::AppState;
+
+/// Handlers for inbound email routes.
+pub struct InboundEmailController;
+
+impl InboundEmailController {
+    /// Receives an inbound email and records any quick-log command in it.
+    ///
+    /// Mapped to `POST /inbound-email`. Authenticated by the `token` query
+    /// parameter, which must match
+    /// [`Config::inbound_email_secret`](crate::core::config::Config::inbound_email_secret).
+    /// Emails from unknown or unconfirmed senders, and emails without a
+    /// command, are acknowledged and ignored so the provider does not retry.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `query` — The [`InboundEmailQuery`] carrying the shared secret.
+    /// * `body` — The [`InboundEmailWebhook`] payload.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MessageResponse>`] describing how the email was handled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the webhook is disabled,
+    /// [`ApiErrorResponse::Unauthorized`] if the token is wrong, or an
+    /// [`ApiErrorResponse`] if a database query fails.
+    pub async fn receive(
+        State(state): State<AppState>,
+        Query(query): Query<InboundEmailQuery>,
+        Json(body): Json<InboundEmailWebhook>,
+    ) -> ApiResult<Json<MessageResponse>> {
+        let secret = state
+            .config
+            .inbound_email_secret
+            .as_deref()
+            .ok_or_else(|| ApiErrorResponse::NotFound("Resource not found".to_string()))?;
+
+        if !token::constant_time_eq(&token::hash(&query.token), &token::hash(secret)) {
+            return Err(ApiErrorResponse::Unauthorized(
+                "Invalid webhook token".to_string(),
+            ));
+        }
+
+        let email = match body {
+            InboundEmailWebhook::Envelope { data } => data,
+            InboundEmailWebhook::Email(email) => email,
+        };
+        let ignored = |reason: &str| {
+            info!("Ignoring inbound email: {}", reason);
+            Ok(Json(MessageResponse {
+                message: "Email ignored.".to_string(),
+            }))
+        };
+
+        let sender = QuickLogUtil::sender_address(&email.from);
+        let Some(user_id) =
+            UserRepo::find_confirmed_user_id_by_email(&state.db_pool, &sender).await?
+        else {
+            return ignored("sender is not a confirmed user");
+        };
+
+        let Some(command) =
+            QuickLogUtil::parse_email(email.subject.as_deref(), email.text.as_deref())
+        else {
+            return ignored("no quick-log command found");
+        };
+
+        let job_id =
+            JobRepo::find_job_id_by_name(&state.db_pool, user_id, &command.job_name).await?;
+        let draft = WorkSessionDraftRepo::insert_draft(
+            &state.db_pool,
+            user_id,
+            job_id,
+            &command.job_name,
+            command.minutes,
+            email.message_id.as_deref(),
+        )
+        .await?;
+
+        if draft.is_none() {
+            return ignored("message was already processed");
+        }
+
+        Ok(Json(MessageResponse {
+            message: "Draft work session created.".to_string(),
+        }))
+    }
+}
@@ -0,0 +1,298 @@
+//! Invoice endpoints.
+//!
+//! Provides [`InvoiceController`] with handlers for billing a company's
+//! unbilled work onto a draft invoice, editing and deleting drafts,
+//! finalizing invoices with the user's next invoice number, and downloading
+//! invoices as PDFs.
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::header,
+    response::{IntoResponse, Response},
+};
+use gig_log_common::models::{
+    generic::MessageResponse,
+    invoice::{CreateInvoiceRequest, Invoice, ListInvoicesQuery, UpdateInvoiceRequest},
+    webhook::WebhookEvent,
+};
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::extractors::{ValidatedJson, ValidatedQuery};
+use crate::repo::{
+    appearance::AppearanceRepo, company::CompanyRepo, invoice::InvoiceRepo, user::UserRepo,
+};
+use crate::routes::app::AppState;
+use crate::services::pdf::{InvoiceDocument, PdfTheme};
+use crate::webhook::WebhookDispatcher;
+
+/// Handlers for invoice routes.
+pub struct InvoiceController;
+
+impl InvoiceController {
+    /// Bills one of the user's companies for its unbilled work.
+    ///
+    /// Mapped to `POST /invoices`. Requires authentication. Adds a line
+    /// item per job and rate, priced at the rate each session was completed
+    /// at, covering every completed work session not yet on an invoice. If
+    /// the company already has a draft invoice the line items are added to
+    /// it.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — A [`ValidatedJson<CreateInvoiceRequest>`] naming the
+    ///   company to bill.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Invoice>`] containing the draft invoice and its line items.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the company does not exist
+    /// or belongs to another user. Returns [`ApiErrorResponse::BadRequest`]
+    /// if the company has no unbilled work.
+    pub async fn create(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedJson(body): ValidatedJson<CreateInvoiceRequest>,
+    ) -> ApiResult<Json<Invoice>> {
+        CompanyRepo::find_company_by_id(&state.db_pool, auth.user_id, body.company_id).await?;
+
+        let invoice = InvoiceRepo::attach_unbilled_sessions(
+            &state.db_pool,
+            auth.user_id,
+            body.company_id,
+            None,
+        )
+        .await?;
+
+        Ok(Json(invoice))
+    }
+
+    /// Lists the authenticated user's invoices.
+    ///
+    /// Mapped to `GET /invoices`. Requires authentication. Accepts optional
+    /// `company_id` and `status` query parameters to restrict the results.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `query` — The [`ListInvoicesQuery`] filters.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Vec<Invoice>>`] with their line items, newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn list(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedQuery(query): ValidatedQuery<ListInvoicesQuery>,
+    ) -> ApiResult<Json<Vec<Invoice>>> {
+        let invoices = InvoiceRepo::list_invoices(&state.db_pool, auth.user_id, &query).await?;
+
+        Ok(Json(invoices))
+    }
+
+    /// Returns one of the authenticated user's invoices.
+    ///
+    /// Mapped to `GET /invoices/{id}`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `invoice_id` — The invoice's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Invoice>`] containing the invoice and its line items.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the invoice does not exist
+    /// or belongs to another user.
+    pub async fn show(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(invoice_id): Path<Uuid>,
+    ) -> ApiResult<Json<Invoice>> {
+        let invoice =
+            InvoiceRepo::find_invoice_by_id(&state.db_pool, auth.user_id, invoice_id).await?;
+
+        Ok(Json(invoice))
+    }
+
+    /// Partially updates one of the authenticated user's draft invoices.
+    ///
+    /// Mapped to `PATCH /invoices/{id}`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `invoice_id` — The invoice's UUID from the request path.
+    /// * `body` — A [`ValidatedJson<UpdateInvoiceRequest>`] with the fields
+    ///   to change.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Invoice>`] containing the updated invoice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the invoice does not exist
+    /// or belongs to another user. Returns [`ApiErrorResponse::BadRequest`]
+    /// if the invoice is finalized.
+    pub async fn update(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(invoice_id): Path<Uuid>,
+        ValidatedJson(body): ValidatedJson<UpdateInvoiceRequest>,
+    ) -> ApiResult<Json<Invoice>> {
+        let invoice =
+            InvoiceRepo::update_invoice(&state.db_pool, auth.user_id, invoice_id, &body).await?;
+
+        Ok(Json(invoice))
+    }
+
+    /// Deletes one of the authenticated user's draft invoices.
+    ///
+    /// Mapped to `DELETE /invoices/{id}`. Requires authentication. The
+    /// invoice's work sessions become unbilled again.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `invoice_id` — The invoice's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MessageResponse>`] confirming the deletion.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the invoice does not exist
+    /// or belongs to another user. Returns [`ApiErrorResponse::BadRequest`]
+    /// if the invoice is finalized.
+    pub async fn delete(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(invoice_id): Path<Uuid>,
+    ) -> ApiResult<Json<MessageResponse>> {
+        if !InvoiceRepo::delete_invoice(&state.db_pool, auth.user_id, invoice_id).await? {
+            return Err(ApiErrorResponse::NotFound("Invoice not found".to_string()));
+        }
+
+        Ok(Json(MessageResponse {
+            message: "Invoice deleted.".to_string(),
+        }))
+    }
+
+    /// Finalizes one of the authenticated user's draft invoices.
+    ///
+    /// Mapped to `POST /invoices/{id}/finalize`. Requires authentication.
+    /// Assigns the user's next invoice number. Finalized invoices can no
+    /// longer be edited, deleted, or receive new line items.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `invoice_id` — The invoice's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Invoice>`] containing the finalized invoice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the invoice does not exist
+    /// or belongs to another user. Returns [`ApiErrorResponse::BadRequest`]
+    /// if the invoice is already finalized.
+    pub async fn finalize(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(invoice_id): Path<Uuid>,
+    ) -> ApiResult<Json<Invoice>> {
+        let invoice =
+            InvoiceRepo::finalize_invoice(&state.db_pool, auth.user_id, invoice_id).await?;
+        WebhookDispatcher::publish(
+            &state.db_pool,
+            auth.user_id,
+            WebhookEvent::InvoiceFinalized(invoice.clone()),
+        )
+        .await;
+
+        Ok(Json(invoice))
+    }
+
+    /// Downloads one of the authenticated user's invoices as a PDF.
+    ///
+    /// Mapped to `GET /invoices/{id}/pdf`. Requires authentication. The PDF
+    /// is branded with colors from the user's active palette and rendered
+    /// by [`AppState::pdf_renderer`]. It is sent as an attachment named
+    /// after the invoice number, or the invoice ID for drafts.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `invoice_id` — The invoice's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Response`] containing the PDF.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the invoice does not exist
+    /// or belongs to another user, or an [`ApiErrorResponse`] if rendering
+    /// fails.
+    pub async fn pdf(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(invoice_id): Path<Uuid>,
+    ) -> ApiResult<Response> {
+        let invoice =
+            InvoiceRepo::find_invoice_by_id(&state.db_pool, auth.user_id, invoice_id).await?;
+        let company =
+            CompanyRepo::find_company_by_id(&state.db_pool, auth.user_id, invoice.company_id)
+                .await?;
+        let user = UserRepo::find_user_by_id(&state.db_pool, auth.user_id).await?;
+        let palette = AppearanceRepo::find_active_palette(&state.db_pool, auth.user_id).await?;
+        let sender_name = format!("{} {}", user.first_name, user.last_name);
+
+        let pdf = state.pdf_renderer.render_invoice(&InvoiceDocument {
+            invoice: &invoice,
+            sender_name: &sender_name,
+            company_name: &company.name,
+            theme: PdfTheme::from_palette(palette.as_ref()),
+        })?;
+        let file_name = match invoice.invoice_number {
+            Some(number) => format!("invoice-{number}.pdf"),
+            None => format!("invoice-draft-{}.pdf", invoice.id),
+        };
+
+        Ok((
+            [
+                (header::CONTENT_TYPE, "application/pdf".to_string()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"{file_name}\""),
+                ),
+            ],
+            pdf,
+        )
+            .into_response())
+    }
+}
@@ -0,0 +1,331 @@
+//! Job endpoints.
+//!
+//! Provides [`JobController`] with handlers for job-scoped resources and
+//! the job board pipeline.
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+};
+use gig_log_common::models::{
+    generic::{DetailQuery, MessageResponse},
+    job::{
+        ConvertPaymentTypeRequest, ConvertPaymentTypeResponse, Job, JobBoardColumn,
+        JobBoardColumnGroup, JobBoardResponse, JobDetailResponse, ReorderJobsRequest,
+        UpdateJobRequest,
+    },
+    webhook::WebhookEvent,
+    work_session::{ListJobWorkSessionsQuery, WorkSession},
+};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::extractors::{ValidatedJson, ValidatedQuery};
+use crate::include::IncludeUtil;
+use crate::repo::{
+    budget::BudgetRepo, company::CompanyRepo, job::JobRepo, work_session::WorkSessionRepo,
+};
+use crate::routes::app::AppState;
+use crate::sparse_fields::SparseFieldsUtil;
+use crate::webhook::WebhookDispatcher;
+
+/// Handlers for job routes.
+pub struct JobController;
+
+impl JobController {
+    /// Returns a job with its budgets and their current-month consumption.
+    ///
+    /// Mapped to `GET /jobs/{id}`. Requires authentication. Accepts
+    /// `?fields=` to return only some top-level fields and
+    /// `?include=company` to embed the job's company.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `job_id` — The job's UUID from the request path.
+    /// * `query` — The [`DetailQuery`] selecting fields and includes.
+    ///
+    /// # Returns
+    ///
+    /// A [`JobDetailResponse`] as JSON, containing the job, its budgets, and
+    /// any included relations, limited to the requested fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`](crate::core::error::ApiErrorResponse::NotFound)
+    /// if the job does not exist or belongs to another user,
+    /// [`ApiErrorResponse::Validation`](crate::core::error::ApiErrorResponse::Validation)
+    /// if `fields` or `include` is too long, or
+    /// [`ApiErrorResponse::BadRequest`](crate::core::error::ApiErrorResponse::BadRequest)
+    /// if an unknown field or include is requested.
+    pub async fn show(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(job_id): Path<Uuid>,
+        ValidatedQuery(query): ValidatedQuery<DetailQuery>,
+    ) -> ApiResult<Json<Value>> {
+        let includes = IncludeUtil::parse(query.include.as_deref(), &["company"])?;
+        let job = JobRepo::find_job_by_id(&state.db_pool, auth.user_id, job_id).await?;
+        let budgets =
+            BudgetRepo::list_statuses(&state.db_pool, Some(auth.user_id), None, Some(job_id))
+                .await?;
+
+        let company = if includes.contains(&"company") {
+            Some(
+                CompanyRepo::find_company_by_id(&state.db_pool, auth.user_id, job.company_id)
+                    .await?,
+            )
+        } else {
+            None
+        };
+
+        let response = JobDetailResponse {
+            job,
+            budgets: budgets.into_iter().map(Into::into).collect(),
+            company,
+        };
+
+        Ok(Json(SparseFieldsUtil::select(
+            &response,
+            query.fields.as_deref(),
+        )?))
+    }
+
+    /// Partially updates one of the authenticated user's jobs.
+    ///
+    /// Mapped to `PATCH /jobs/{id}`. Requires authentication. Omitted fields
+    /// are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `job_id` — The job's UUID from the request path.
+    /// * `body` — A [`ValidatedJson<UpdateJobRequest>`] containing the fields to
+    ///   change.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Job>`] containing the updated job.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`](crate::core::error::ApiErrorResponse::NotFound)
+    /// if the job does not exist or belongs to another user, or
+    /// [`ApiErrorResponse::Validation`](crate::core::error::ApiErrorResponse::Validation)
+    /// if the merged payment fields do not match the payment type.
+    pub async fn update(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(job_id): Path<Uuid>,
+        ValidatedJson(body): ValidatedJson<UpdateJobRequest>,
+    ) -> ApiResult<Json<Job>> {
+        let job = JobRepo::update_job(&state.db_pool, auth.user_id, job_id, &body).await?;
+
+        Ok(Json(job))
+    }
+
+    /// Duplicates one of the authenticated user's jobs.
+    ///
+    /// Mapped to `POST /jobs/{id}/duplicate`. Requires authentication. The
+    /// copy keeps the job's company and payment terms but none of its work
+    /// sessions, budgets, or rate history.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `job_id` — The UUID of the job to copy, from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Job>`] containing the new job.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the job does not exist or
+    /// belongs to another user.
+    pub async fn duplicate(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(job_id): Path<Uuid>,
+    ) -> ApiResult<Json<Job>> {
+        let job = JobRepo::duplicate_job(&state.db_pool, auth.user_id, job_id)
+            .await
+            .map_err(|error| match error {
+                ApiErrorResponse::NotFound(_) => {
+                    ApiErrorResponse::NotFound("Job not found".to_string())
+                }
+                error => error,
+            })?;
+
+        Ok(Json(job))
+    }
+
+    /// Lists the work sessions tracked against one of the authenticated
+    /// user's jobs.
+    ///
+    /// Mapped to `GET /jobs/{id}/work-sessions`. Requires authentication.
+    /// Accepts `?tag=` to list only sessions with that tag, such as to
+    /// separate meetings from other work.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `job_id` — The job's UUID from the request path.
+    /// * `query` — The [`ListJobWorkSessionsQuery`] with the tag filter.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Vec<WorkSession>>`] ordered by start time, newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the job does not exist or
+    /// belongs to another user.
+    pub async fn work_sessions(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(job_id): Path<Uuid>,
+        Query(query): Query<ListJobWorkSessionsQuery>,
+    ) -> ApiResult<Json<Vec<WorkSession>>> {
+        JobRepo::find_job_by_id(&state.db_pool, auth.user_id, job_id).await?;
+
+        let tag = query.tag.map(|tag| tag.trim().to_lowercase());
+        let sessions = WorkSessionRepo::list_work_sessions_for_job(
+            &state.db_pool,
+            auth.user_id,
+            job_id,
+            tag.as_deref(),
+        )
+        .await?;
+
+        Ok(Json(sessions))
+    }
+
+    /// Converts one of the authenticated user's jobs to another payment
+    /// type.
+    ///
+    /// Mapped to `POST /jobs/{id}/convert-payment-type`. Requires
+    /// authentication. The change is recorded in the job's rate history.
+    /// When converting to payouts with an installment payout type, the
+    /// job's unbilled hours are priced at the rates they were tracked at and
+    /// split into one expected payment per payout.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `job_id` — The job's UUID from the request path.
+    /// * `body` — A [`ValidatedJson<ConvertPaymentTypeRequest>`] containing
+    ///   the new payment terms.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<ConvertPaymentTypeResponse>`] containing the converted job,
+    /// the recorded rate change, and any created installments.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`](crate::core::error::ApiErrorResponse::NotFound)
+    /// if the job does not exist or belongs to another user,
+    /// [`ApiErrorResponse::Validation`](crate::core::error::ApiErrorResponse::Validation)
+    /// if the payment fields do not match the new payment type, or
+    /// [`ApiErrorResponse::BadRequest`](crate::core::error::ApiErrorResponse::BadRequest)
+    /// if the job already has that payment type or has too little unbilled
+    /// work for the installments.
+    pub async fn convert_payment_type(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(job_id): Path<Uuid>,
+        ValidatedJson(body): ValidatedJson<ConvertPaymentTypeRequest>,
+    ) -> ApiResult<Json<ConvertPaymentTypeResponse>> {
+        let response =
+            JobRepo::convert_payment_type(&state.db_pool, auth.user_id, job_id, &body).await?;
+        for installment in &response.installments {
+            WebhookDispatcher::publish(
+                &state.db_pool,
+                auth.user_id,
+                WebhookEvent::PaymentCreated(installment.clone()),
+            )
+            .await;
+        }
+
+        Ok(Json(response))
+    }
+
+    /// Returns the authenticated user's jobs grouped into board columns.
+    ///
+    /// Mapped to `GET /jobs/board`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<JobBoardResponse>`] with every column in pipeline order and
+    /// the jobs in each ordered by position.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`](crate::core::error::ApiErrorResponse)
+    /// if the database query fails.
+    pub async fn board(
+        auth: AuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<JobBoardResponse>> {
+        let mut jobs = JobRepo::list_jobs_for_board(&state.db_pool, auth.user_id).await?;
+
+        let columns = JobBoardColumn::ALL
+            .into_iter()
+            .map(|column| {
+                let (in_column, rest) = jobs.drain(..).partition(|job| job.board_column == column);
+                jobs = rest;
+
+                JobBoardColumnGroup {
+                    column,
+                    jobs: in_column,
+                }
+            })
+            .collect();
+
+        Ok(Json(JobBoardResponse { columns }))
+    }
+
+    /// Moves jobs to new board columns and positions.
+    ///
+    /// Mapped to `PATCH /jobs/reorder`. Requires authentication. All moves
+    /// are applied together or not at all.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — A [`ValidatedJson<ReorderJobsRequest>`] listing the moves.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MessageResponse>`] confirming the update.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`](crate::core::error::ApiErrorResponse::NotFound)
+    /// if any job does not exist or belongs to another user.
+    pub async fn reorder(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedJson(body): ValidatedJson<ReorderJobsRequest>,
+    ) -> ApiResult<Json<MessageResponse>> {
+        JobRepo::reorder_jobs(&state.db_pool, auth.user_id, &body.moves).await?;
+
+        Ok(Json(MessageResponse {
+            message: "Jobs reordered.".to_string(),
+        }))
+    }
+}
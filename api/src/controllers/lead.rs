@@ -0,0 +1,249 @@
+//! Lead endpoints.
+//!
+//! Provides [`LeadController`] with handlers for tracking sales leads,
+//! converting them into a company and job, and reporting conversion
+//! statistics.
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use gig_log_common::models::{
+    generic::MessageResponse,
+    lead::{
+        ConvertLeadRequest, CreateLeadRequest, Lead, LeadConversionResponse, LeadStats, LeadStatus,
+        ListLeadsQuery, UpdateLeadRequest,
+    },
+};
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::extractors::{ValidatedJson, ValidatedQuery};
+use crate::repo::lead::LeadRepo;
+use crate::routes::app::AppState;
+
+/// Handlers for lead routes.
+pub struct LeadController;
+
+impl LeadController {
+    /// Creates a lead.
+    ///
+    /// Mapped to `POST /leads`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — A [`ValidatedJson<CreateLeadRequest>`] containing the lead
+    ///   details.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Lead>`] containing the created lead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database insert fails.
+    pub async fn create(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedJson(body): ValidatedJson<CreateLeadRequest>,
+    ) -> ApiResult<Json<Lead>> {
+        let lead = LeadRepo::insert_lead(&state.db_pool, auth.user_id, body).await?;
+
+        Ok(Json(lead))
+    }
+
+    /// Lists the authenticated user's leads.
+    ///
+    /// Mapped to `GET /leads`. Requires authentication. Accepts an optional
+    /// `status` query parameter.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `query` — The [`ListLeadsQuery`] filter.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Vec<Lead>>`] ordered by follow-up date.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn list(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedQuery(query): ValidatedQuery<ListLeadsQuery>,
+    ) -> ApiResult<Json<Vec<Lead>>> {
+        let leads = LeadRepo::list_leads(&state.db_pool, auth.user_id, query.status).await?;
+
+        Ok(Json(leads))
+    }
+
+    /// Returns the authenticated user's lead pipeline and conversion
+    /// statistics.
+    ///
+    /// Mapped to `GET /leads/stats`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<LeadStats>`] with overall and per-source statistics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn stats(
+        auth: AuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<LeadStats>> {
+        let stats = LeadRepo::stats(&state.db_pool, auth.user_id).await?;
+
+        Ok(Json(stats))
+    }
+
+    /// Returns one of the authenticated user's leads.
+    ///
+    /// Mapped to `GET /leads/{id}`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `lead_id` — The lead's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Lead>`] containing the lead.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the lead does not exist or
+    /// belongs to another user.
+    pub async fn show(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(lead_id): Path<Uuid>,
+    ) -> ApiResult<Json<Lead>> {
+        let lead = LeadRepo::find_lead_by_id(&state.db_pool, auth.user_id, lead_id).await?;
+
+        Ok(Json(lead))
+    }
+
+    /// Updates one of the authenticated user's leads.
+    ///
+    /// Mapped to `PUT /leads/{id}`. Requires authentication. Won leads keep
+    /// their status.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `lead_id` — The lead's UUID from the request path.
+    /// * `body` — A [`ValidatedJson<UpdateLeadRequest>`] containing the
+    ///   fields to update.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Lead>`] containing the updated lead.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the lead does not exist or
+    /// belongs to another user, or [`ApiErrorResponse::BadRequest`] if the
+    /// request changes the status of a won lead.
+    pub async fn update(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(lead_id): Path<Uuid>,
+        ValidatedJson(body): ValidatedJson<UpdateLeadRequest>,
+    ) -> ApiResult<Json<Lead>> {
+        if body.status.is_some() {
+            let lead = LeadRepo::find_lead_by_id(&state.db_pool, auth.user_id, lead_id).await?;
+
+            if lead.status == LeadStatus::Won {
+                return Err(ApiErrorResponse::BadRequest(
+                    "Won leads cannot change status".to_string(),
+                ));
+            }
+        }
+
+        let lead = LeadRepo::update_lead(&state.db_pool, auth.user_id, lead_id, body).await?;
+
+        Ok(Json(lead))
+    }
+
+    /// Deletes one of the authenticated user's leads.
+    ///
+    /// Mapped to `DELETE /leads/{id}`. Requires authentication. A job the
+    /// lead was converted into is kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `lead_id` — The lead's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MessageResponse>`] confirming the deletion.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the lead does not exist or
+    /// belongs to another user.
+    pub async fn delete(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(lead_id): Path<Uuid>,
+    ) -> ApiResult<Json<MessageResponse>> {
+        if !LeadRepo::delete_lead(&state.db_pool, auth.user_id, lead_id).await? {
+            return Err(ApiErrorResponse::NotFound("Lead not found".to_string()));
+        }
+
+        Ok(Json(MessageResponse {
+            message: "Lead deleted.".to_string(),
+        }))
+    }
+
+    /// Converts an open lead into a job, creating its company if needed.
+    ///
+    /// Mapped to `POST /leads/{id}/convert`. Requires authentication. The
+    /// lead is marked as won and linked to the new job.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `lead_id` — The lead's UUID from the request path.
+    /// * `body` — A [`ValidatedJson<ConvertLeadRequest>`] containing the
+    ///   job's payment terms and optional existing company.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<LeadConversionResponse>`] with the lead, company, and job.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the lead or company does not
+    /// exist or belongs to another user, or [`ApiErrorResponse::BadRequest`]
+    /// if the lead is not open.
+    pub async fn convert(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(lead_id): Path<Uuid>,
+        ValidatedJson(body): ValidatedJson<ConvertLeadRequest>,
+    ) -> ApiResult<Json<LeadConversionResponse>> {
+        let conversion =
+            LeadRepo::convert_lead(&state.db_pool, auth.user_id, lead_id, body).await?;
+
+        Ok(Json(conversion))
+    }
+}
@@ -0,0 +1,117 @@
+//! Live sync WebSocket endpoint.
+//!
+//! Provides [`LiveSyncController`], which upgrades authenticated requests
+//! to a WebSocket and pushes the user's domain events from the
+//! [`LiveSyncHub`](crate::live_sync::LiveSyncHub) as JSON
+//! [`LiveSyncMessage`] text frames, so clients do not need to poll.
+
+use axum::{
+    extract::{
+        State, WebSocketUpgrade,
+        ws::{Message, WebSocket},
+    },
+    http::{HeaderMap, header},
+    response::Response,
+};
+use gig_log_common::models::live_sync::LiveSyncMessage;
+use log::error;
+use tokio::sync::broadcast::{Receiver, error::RecvError};
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::live_sync::LiveSyncEvent;
+use crate::routes::app::AppState;
+
+/// Handlers for live sync routes.
+pub struct LiveSyncController;
+
+impl LiveSyncController {
+    /// Opens a live sync WebSocket for the authenticated user.
+    ///
+    /// Mapped to `GET /ws`. Requires authentication. Browsers authenticate
+    /// with the access token cookie, so a request carrying an `Origin`
+    /// header must come from one of the configured web origins. Once
+    /// upgraded, the server sends a [`LiveSyncMessage`] text frame for each
+    /// of the user's work session and payment changes, and a `resync`
+    /// message if the connection fell behind and missed events. Messages
+    /// sent by the client are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `headers` — The request headers, used to check the `Origin`.
+    /// * `upgrade` — The [`WebSocketUpgrade`] for the request.
+    ///
+    /// # Returns
+    ///
+    /// A `101 Switching Protocols` [`Response`] that starts the WebSocket.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Forbidden`] if the request comes from an
+    /// origin other than the web client.
+    pub async fn connect(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        headers: HeaderMap,
+        upgrade: WebSocketUpgrade,
+    ) -> ApiResult<Response> {
+        if let Some(origin) = headers.get(header::ORIGIN) {
+            let allowed = origin.to_str().is_ok_and(|origin| {
+                state
+                    .config
+                    .web_origins
+                    .iter()
+                    .any(|allowed| allowed.trim_end_matches('/') == origin)
+            });
+
+            if !allowed {
+                return Err(ApiErrorResponse::Forbidden(
+                    "Live sync is not available from this origin".to_string(),
+                ));
+            }
+        }
+
+        let receiver = state.live_sync.subscribe();
+
+        Ok(upgrade.on_upgrade(move |socket| Self::forward(socket, auth.user_id, receiver)))
+    }
+
+    /// Forwards the user's events to the socket until either side closes.
+    ///
+    /// # Arguments
+    ///
+    /// * `socket` — The upgraded WebSocket.
+    /// * `user_id` — The UUID of the connected user.
+    /// * `receiver` — The hub subscription created before the upgrade.
+    async fn forward(mut socket: WebSocket, user_id: Uuid, mut receiver: Receiver<LiveSyncEvent>) {
+        loop {
+            let message = tokio::select! {
+                event = receiver.recv() => match event {
+                    Ok(event) if event.user_id == user_id => event.message,
+                    Ok(_) => continue,
+                    Err(RecvError::Lagged(_)) => LiveSyncMessage::Resync,
+                    Err(RecvError::Closed) => break,
+                },
+                incoming = socket.recv() => match incoming {
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                    Some(Ok(_)) => continue,
+                },
+            };
+
+            let text = match serde_json::to_string(&message) {
+                Ok(text) => text,
+                Err(error) => {
+                    error!("Failed to serialize live sync message: {:?}", error);
+                    continue;
+                }
+            };
+
+            if socket.send(Message::Text(text.into())).await.is_err() {
+                break;
+            }
+        }
+    }
+}
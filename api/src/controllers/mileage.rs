@@ -0,0 +1,511 @@
+//! Mileage endpoints.
+//!
+//! Provides [`MileageController`] with handlers for logging business trips,
+//! optionally against a job, reporting mileage totals and deductions over a
+//! date range, and setting the user's own mileage rate. Trips suggested
+//! from work sessions started away from the user's home base can be
+//! confirmed or dismissed.
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+};
+use gig_log_common::models::{
+    generic::MessageResponse,
+    mileage::{
+        AcceptMileageSuggestionRequest, CreateMileageEntryRequest, HomeBase, MileageEntry,
+        MileageRangeQuery, MileageRate, MileageReport, MileageSuggestion,
+        UpdateMileageEntryRequest, UpdateMileageRateRequest,
+    },
+};
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::extractors::ValidatedJson;
+use crate::mileage::MileageUtil;
+use crate::repo::{job::JobRepo, mileage::MileageRepo, mileage_suggestion::MileageSuggestionRepo};
+use crate::routes::app::AppState;
+
+/// Handlers for mileage routes.
+pub struct MileageController;
+
+impl MileageController {
+    /// Logs a business trip.
+    ///
+    /// Mapped to `POST /mileage`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — A [`ValidatedJson<CreateMileageEntryRequest>`] containing
+    ///   the trip details.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MileageEntry>`] containing the created trip and its
+    /// deduction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the job does not exist or
+    /// belongs to another user.
+    pub async fn create(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedJson(body): ValidatedJson<CreateMileageEntryRequest>,
+    ) -> ApiResult<Json<MileageEntry>> {
+        if let Some(job_id) = body.job_id {
+            Self::ensure_job(&state, auth.user_id, job_id).await?;
+        }
+
+        let rate = Self::rate_for(&state, auth.user_id).await?;
+        let record = MileageRepo::insert_entry(&state.db_pool, auth.user_id, &body).await?;
+
+        Ok(Json(record.into_entry(rate)))
+    }
+
+    /// Lists the authenticated user's trips, most recent first.
+    ///
+    /// Mapped to `GET /mileage`. Requires authentication. Accepts optional
+    /// `from`, `to`, and `job_id` query parameters to restrict the results.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `query` — The [`MileageRangeQuery`] filters.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Vec<MileageEntry>>`] with each trip's deduction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn list(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Query(query): Query<MileageRangeQuery>,
+    ) -> ApiResult<Json<Vec<MileageEntry>>> {
+        let rate = Self::rate_for(&state, auth.user_id).await?;
+        let records = MileageRepo::list_entries(&state.db_pool, auth.user_id, &query).await?;
+
+        Ok(Json(
+            records
+                .into_iter()
+                .map(|record| record.into_entry(rate))
+                .collect(),
+        ))
+    }
+
+    /// Reports the authenticated user's mileage totals and deductions.
+    ///
+    /// Mapped to `GET /mileage/report`. Requires authentication. Accepts
+    /// the same `from`, `to`, and `job_id` query parameters as the trip
+    /// list.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `query` — The [`MileageRangeQuery`] filters.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MileageReport>`] with overall and per-job totals.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn report(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Query(query): Query<MileageRangeQuery>,
+    ) -> ApiResult<Json<MileageReport>> {
+        let rate = Self::rate_for(&state, auth.user_id).await?;
+        let totals = MileageRepo::list_job_totals(&state.db_pool, auth.user_id, &query).await?;
+
+        Ok(Json(MileageUtil::build_report(
+            query.from, query.to, rate, totals,
+        )))
+    }
+
+    /// Returns one of the authenticated user's trips.
+    ///
+    /// Mapped to `GET /mileage/{id}`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `entry_id` — The trip's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MileageEntry>`] containing the trip and its deduction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the trip does not exist or
+    /// belongs to another user.
+    pub async fn show(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(entry_id): Path<Uuid>,
+    ) -> ApiResult<Json<MileageEntry>> {
+        let rate = Self::rate_for(&state, auth.user_id).await?;
+        let record = MileageRepo::find_entry_by_id(&state.db_pool, auth.user_id, entry_id).await?;
+
+        Ok(Json(record.into_entry(rate)))
+    }
+
+    /// Partially updates one of the authenticated user's trips.
+    ///
+    /// Mapped to `PATCH /mileage/{id}`. Requires authentication. Omitted
+    /// fields are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `entry_id` — The trip's UUID from the request path.
+    /// * `body` — A [`ValidatedJson<UpdateMileageEntryRequest>`] containing
+    ///   the fields to change.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MileageEntry>`] containing the updated trip.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the trip or the new job
+    /// does not exist or belongs to another user.
+    pub async fn update(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(entry_id): Path<Uuid>,
+        ValidatedJson(body): ValidatedJson<UpdateMileageEntryRequest>,
+    ) -> ApiResult<Json<MileageEntry>> {
+        if let Some(Some(job_id)) = body.job_id {
+            Self::ensure_job(&state, auth.user_id, job_id).await?;
+        }
+
+        let rate = Self::rate_for(&state, auth.user_id).await?;
+        let record =
+            MileageRepo::update_entry(&state.db_pool, auth.user_id, entry_id, &body).await?;
+
+        Ok(Json(record.into_entry(rate)))
+    }
+
+    /// Deletes one of the authenticated user's trips.
+    ///
+    /// Mapped to `DELETE /mileage/{id}`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `entry_id` — The trip's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MessageResponse>`] confirming the deletion.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the trip does not exist or
+    /// belongs to another user.
+    pub async fn delete(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(entry_id): Path<Uuid>,
+    ) -> ApiResult<Json<MessageResponse>> {
+        if !MileageRepo::delete_entry(&state.db_pool, auth.user_id, entry_id).await? {
+            return Err(ApiErrorResponse::NotFound(
+                "Mileage entry not found".to_string(),
+            ));
+        }
+
+        Ok(Json(MessageResponse {
+            message: "Mileage entry deleted.".to_string(),
+        }))
+    }
+
+    /// Returns the mileage rate applied to the authenticated user's trips.
+    ///
+    /// Mapped to `GET /mileage/rate`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MileageRate>`] with the applied and default rates.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn show_rate(
+        auth: AuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<MileageRate>> {
+        let custom_rate = MileageRepo::find_custom_rate(&state.db_pool, auth.user_id).await?;
+
+        Ok(Json(Self::mileage_rate(&state, custom_rate)))
+    }
+
+    /// Sets the authenticated user's own mileage rate.
+    ///
+    /// Mapped to `PUT /mileage/rate`. Requires authentication. A `null`
+    /// rate reverts to the server-wide default. Deductions on existing
+    /// trips follow the new rate.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — A [`ValidatedJson<UpdateMileageRateRequest>`] containing
+    ///   the new rate.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MileageRate>`] with the applied and default rates.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database update fails.
+    pub async fn update_rate(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedJson(body): ValidatedJson<UpdateMileageRateRequest>,
+    ) -> ApiResult<Json<MileageRate>> {
+        MileageRepo::set_custom_rate(&state.db_pool, auth.user_id, body.rate).await?;
+
+        Ok(Json(Self::mileage_rate(&state, body.rate)))
+    }
+
+    /// Returns the home base the authenticated user's trips start from.
+    ///
+    /// Mapped to `GET /mileage/home-base`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<HomeBase>`] with the home base, if set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn show_home_base(
+        auth: AuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<HomeBase>> {
+        let location = MileageRepo::find_home_base(&state.db_pool, auth.user_id).await?;
+
+        Ok(Json(HomeBase { location }))
+    }
+
+    /// Sets or clears the authenticated user's home base.
+    ///
+    /// Mapped to `PUT /mileage/home-base`. Requires authentication. Work
+    /// sessions completed while a home base is set and started away from it
+    /// get a mileage suggestion. Existing suggestions are not recalculated.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — A [`ValidatedJson<HomeBase>`] with the new home base.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<HomeBase>`] with the saved home base.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database update fails.
+    pub async fn update_home_base(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedJson(body): ValidatedJson<HomeBase>,
+    ) -> ApiResult<Json<HomeBase>> {
+        MileageRepo::set_home_base(&state.db_pool, auth.user_id, body.location).await?;
+
+        Ok(Json(body))
+    }
+
+    /// Lists the authenticated user's pending mileage suggestions.
+    ///
+    /// Mapped to `GET /mileage/suggestions`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Vec<MileageSuggestion>>`] with the most recent trip first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn list_suggestions(
+        auth: AuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<Vec<MileageSuggestion>>> {
+        let suggestions = MileageSuggestionRepo::list_pending(&state.db_pool, auth.user_id).await?;
+
+        Ok(Json(suggestions))
+    }
+
+    /// Confirms a mileage suggestion, logging it as a trip.
+    ///
+    /// Mapped to `POST /mileage/suggestions/{id}/accept`. Requires
+    /// authentication. The request body is optional and can correct the
+    /// estimated distance. The trip is linked to the suggestion's work
+    /// session and counts toward the mileage report.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `suggestion_id` — The suggestion's UUID from the request path.
+    /// * `body` — An optional
+    ///   [`ValidatedJson<AcceptMileageSuggestionRequest>`] with the distance
+    ///   to log.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MileageEntry>`] containing the logged trip and its
+    /// deduction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if no pending suggestion with
+    /// the given ID exists for the user.
+    pub async fn accept_suggestion(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(suggestion_id): Path<Uuid>,
+        body: Option<ValidatedJson<AcceptMileageSuggestionRequest>>,
+    ) -> ApiResult<Json<MileageEntry>> {
+        let body = body.map(ValidatedJson::into_inner).unwrap_or_default();
+        let record =
+            MileageSuggestionRepo::accept(&state.db_pool, auth.user_id, suggestion_id, body.miles)
+                .await?
+                .ok_or_else(|| {
+                    ApiErrorResponse::NotFound("Mileage suggestion not found".to_string())
+                })?;
+        let rate = Self::rate_for(&state, auth.user_id).await?;
+
+        Ok(Json(record.into_entry(rate)))
+    }
+
+    /// Dismisses a mileage suggestion.
+    ///
+    /// Mapped to `POST /mileage/suggestions/{id}/dismiss`. Requires
+    /// authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `suggestion_id` — The suggestion's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MessageResponse>`] confirming the dismissal.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if no pending suggestion with
+    /// the given ID exists for the user.
+    pub async fn dismiss_suggestion(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(suggestion_id): Path<Uuid>,
+    ) -> ApiResult<Json<MessageResponse>> {
+        if !MileageSuggestionRepo::dismiss(&state.db_pool, auth.user_id, suggestion_id).await? {
+            return Err(ApiErrorResponse::NotFound(
+                "Mileage suggestion not found".to_string(),
+            ));
+        }
+
+        Ok(Json(MessageResponse {
+            message: "Mileage suggestion dismissed.".to_string(),
+        }))
+    }
+
+    /// Checks that a job belongs to the user.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `user_id` — The user's UUID.
+    /// * `job_id` — The job's UUID.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the job does not exist or
+    /// belongs to another user.
+    async fn ensure_job(state: &AppState, user_id: Uuid, job_id: Uuid) -> ApiResult<()> {
+        JobRepo::find_job_by_id(&state.db_pool, user_id, job_id)
+            .await
+            .map_err(|error| match error {
+                ApiErrorResponse::NotFound(_) => {
+                    ApiErrorResponse::NotFound("Job not found".to_string())
+                }
+                error => error,
+            })?;
+
+        Ok(())
+    }
+
+    /// Resolves the mileage rate applied to a user's trips.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `user_id` — The user's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The user's own rate if set, otherwise
+    /// [`Config::mileage_rate`](crate::core::config::Config::mileage_rate).
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    async fn rate_for(state: &AppState, user_id: Uuid) -> ApiResult<f64> {
+        let custom_rate = MileageRepo::find_custom_rate(&state.db_pool, user_id).await?;
+
+        Ok(Self::mileage_rate(state, custom_rate).rate)
+    }
+
+    /// Describes the mileage rate applied given a user's own rate.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `custom_rate` — The user's own rate, if set.
+    ///
+    /// # Returns
+    ///
+    /// The [`MileageRate`].
+    fn mileage_rate(state: &AppState, custom_rate: Option<f64>) -> MileageRate {
+        MileageRate {
+            rate: custom_rate.unwrap_or(state.config.mileage_rate),
+            default_rate: state.config.mileage_rate,
+            is_custom: custom_rate.is_some(),
+        }
+    }
+}
@@ -6,8 +6,74 @@
 //!
 //! # Modules
 //!
+//! - [`account_export`](crate::controllers::account_export) — Full account data export endpoints.
+//! - [`api_key`](crate::controllers::api_key) — API key endpoints for headless clients.
+//! - [`appearance`](crate::controllers::appearance) — Preset palette endpoints.
 //! - [`auth`](crate::controllers::auth) — Authentication and account management endpoints.
+//! - [`avatar`](crate::controllers::avatar) — User avatar upload and download endpoints.
+//! - [`budget`](crate::controllers::budget) — Monthly budget management endpoints.
+//! - [`calendar`](crate::controllers::calendar) — Calendar feed management and iCalendar feed endpoints.
+//! - [`company`](crate::controllers::company) — Company details, company-scoped billing, and statement endpoints.
+//! - [`contact`](crate::controllers::contact) — Company contact endpoints.
+//! - [`contract`](crate::controllers::contract) — Contract and signed document endpoints.
 //! - [`health`](crate::controllers::health) — Health check endpoints.
+//! - [`import`](crate::controllers::import) — CSV import endpoints for payments and work sessions.
+//! - [`inbound_email`](crate::controllers::inbound_email) — Inbound email quick-logging webhook.
+//! - [`invoice`](crate::controllers::invoice) — Invoice CRUD and finalization endpoints.
+//! - [`job`](crate::controllers::job) — Job detail and job board endpoints.
+//! - [`lead`](crate::controllers::lead) — Lead tracking, conversion, and statistics endpoints.
+//! - [`live_sync`](crate::controllers::live_sync) — Live sync WebSocket endpoint.
+//! - [`mileage`](crate::controllers::mileage) — Mileage logging, reporting, and rate endpoints.
+//! - [`oauth`](crate::controllers::oauth) — OAuth sign-in with Google and GitHub.
+//! - [`onboarding`](crate::controllers::onboarding) — First-run onboarding wizard endpoint.
+//! - [`passkey`](crate::controllers::passkey) — Passkey (WebAuthn) registration and login endpoints.
+//! - [`payment`](crate::controllers::payment) — Payment receipt link, match suggestion, and reconciliation endpoints.
+//! - [`personal_access_token`](crate::controllers::personal_access_token) — Scoped personal access token endpoints.
+//! - [`receipt`](crate::controllers::receipt) — Public payment receipt pages.
+//! - [`recurring_payment`](crate::controllers::recurring_payment) — Recurring payment schedule and upcoming occurrence endpoints.
+//! - [`report`](crate::controllers::report) — Unreported time reconciliation, earnings report, and tax estimate endpoints.
+//! - [`search`](crate::controllers::search) — Full-text search across companies and jobs.
+//! - [`subscription`](crate::controllers::subscription) — Subscription status, Stripe Checkout, and Stripe webhook endpoints.
+//! - [`time_off`](crate::controllers::time_off) — Time-off and weekly availability endpoints.
+//! - [`timesheet`](crate::controllers::timesheet) — Timesheet sharing and client approval endpoints.
+//! - [`toggl`](crate::controllers::toggl) — Toggl Track API compatible time entry endpoints.
+//! - [`webhook`](crate::controllers::webhook) — Outgoing webhook registration and delivery log endpoints.
+//! - [`work_session`](crate::controllers::work_session) — Work session timer endpoints.
+//! - [`work_session_attachment`](crate::controllers::work_session_attachment) — Work session photo and voice memo endpoints.
+//! - [`work_session_draft`](crate::controllers::work_session_draft) — Email-logged work session draft endpoints.
 
+pub mod account_export;
+pub mod api_key;
+pub mod appearance;
 pub mod auth;
+pub mod avatar;
+pub mod budget;
+pub mod calendar;
+pub mod company;
+pub mod contact;
+pub mod contract;
 pub mod health;
+pub mod import;
+pub mod inbound_email;
+pub mod invoice;
+pub mod job;
+pub mod lead;
+pub mod live_sync;
+pub mod mileage;
+pub mod oauth;
+pub mod onboarding;
+pub mod passkey;
+pub mod payment;
+pub mod personal_access_token;
+pub mod receipt;
+pub mod recurring_payment;
+pub mod report;
+pub mod search;
+pub mod subscription;
+pub mod time_off;
+pub mod timesheet;
+pub mod toggl;
+pub mod webhook;
+pub mod work_session;
+pub mod work_session_attachment;
+pub mod work_session_draft;
@@ -0,0 +1,287 @@
+//! OAuth sign-in endpoints.
+//!
+//! Provides [`OAuthController`] with handlers that send the browser to an
+//! external identity provider and complete the sign-in when it returns.
+//! Provider accounts are linked to the existing confirmed account with the
+//! same verified email, or a new confirmed account is created. A successful
+//! sign-in issues the same cookie pair as a password login.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::HeaderMap,
+    response::Redirect,
+};
+use axum_extra::extract::CookieJar;
+use gig_log_common::models::oauth::{OAuthCallbackQuery, OAuthProvider};
+use gig_log_common::models::user::User;
+use log::{error, warn};
+
+use crate::auth::anomaly::AnomalyDetector;
+use crate::auth::cookies::CookiesUtil;
+use crate::auth::link::LinkUtil;
+use crate::auth::{password::PasswordUtil, token};
+use crate::controllers::auth::AuthController;
+use crate::core::config::Config;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::repo::oauth_identity::OAuthIdentityRepo;
+use crate::repo::user::UserRepo;
+use crate::routes::app::AppState;
+use crate::services::oauth::{IdentityProvider, OAuthProfile};
+
+/// Handlers for OAuth sign-in routes.
+pub struct OAuthController;
+
+impl OAuthController {
+    /// Starts signing in with an identity provider.
+    ///
+    /// Mapped to `GET /auth/oauth/{provider}/start`. Does not require
+    /// authentication. Sets a short-lived cookie binding the sign-in to this
+    /// browser and redirects to the provider's authorization page.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `provider` — The [`OAuthProvider`] from the request path.
+    /// * `jar` — The [`CookieJar`] to receive the state cookie.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the updated [`CookieJar`] and a [`Redirect`] to the
+    /// provider.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the provider is not
+    /// configured.
+    pub async fn start(
+        State(state): State<AppState>,
+        Path(provider): Path<OAuthProvider>,
+        jar: CookieJar,
+    ) -> ApiResult<(CookieJar, Redirect)> {
+        let identity_provider = Self::identity_provider(&state, provider)?;
+        let oauth_state = token::generate();
+        let url = identity_provider
+            .authorize_url(&oauth_state, &Self::redirect_uri(provider, &state.config));
+
+        let jar = jar.add(CookiesUtil::build_oauth_state_cookie(
+            &oauth_state,
+            &state.config,
+        ));
+
+        Ok((jar, Redirect::to(&url)))
+    }
+
+    /// Completes signing in with an identity provider.
+    ///
+    /// Mapped to `GET /auth/oauth/{provider}/callback`. Does not require
+    /// authentication. Exchanges the authorization code for the user's
+    /// profile, resolves or creates the account, and redirects to the web
+    /// client's dashboard with the session cookies set. Failures redirect to
+    /// the log-in page with an `oauth_error` of `denied`, `invalid_state`,
+    /// `failed`, `unverified_email`, or `unconfirmed_account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `provider` — The [`OAuthProvider`] from the request path.
+    /// * `headers` — The request headers, used to read the state cookie,
+    ///   select the cookie domain, and record the log-in.
+    /// * `jar` — The [`CookieJar`] to receive the session cookies.
+    /// * `query` — The [`OAuthCallbackQuery`] sent by the provider.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the updated [`CookieJar`] and a [`Redirect`] to the web
+    /// client.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the provider is not
+    /// configured, or an [`ApiErrorResponse`] if a database query or issuing
+    /// the session fails.
+    pub async fn callback(
+        State(state): State<AppState>,
+        Path(provider): Path<OAuthProvider>,
+        headers: HeaderMap,
+        jar: CookieJar,
+        Query(query): Query<OAuthCallbackQuery>,
+    ) -> ApiResult<(CookieJar, Redirect)> {
+        let identity_provider = Self::identity_provider(&state, provider)?;
+        let jar = CookiesUtil::clear_oauth_state_cookie(jar, &state.config);
+
+        let state_matches = CookiesUtil::oauth_state(&headers)
+            .zip(query.state.as_deref())
+            .is_some_and(|(expected, actual)| token::constant_time_eq(expected, actual));
+
+        if !state_matches {
+            warn!(
+                "Rejected {} sign-in with a missing or mismatched state",
+                provider.as_str()
+            );
+            return Ok((jar, Self::failure("invalid_state", &state.config)));
+        }
+
+        let code = match (query.code, query.error) {
+            (Some(code), None) => code,
+            _ => return Ok((jar, Self::failure("denied", &state.config))),
+        };
+
+        let profile = match identity_provider
+            .fetch_profile(&code, &Self::redirect_uri(provider, &state.config))
+            .await
+        {
+            Ok(profile) => profile,
+            Err(error) => {
+                error!(
+                    "Failed to complete {} sign-in: {:?}",
+                    provider.as_str(),
+                    error
+                );
+                return Ok((jar, Self::failure("failed", &state.config)));
+            }
+        };
+
+        let user = match OAuthIdentityRepo::use_identity(&state.db_pool, provider, &profile.subject)
+            .await?
+        {
+            Some(user_id) => UserRepo::find_user_by_id(&state.db_pool, user_id).await?,
+            None => {
+                let Some(email) = profile.verified_email.as_deref() else {
+                    return Ok((jar, Self::failure("unverified_email", &state.config)));
+                };
+
+                let user = match UserRepo::find_user_by_email(&state.db_pool, email).await {
+                    Ok(user) if user.email_confirmed => user,
+                    Ok(_) => {
+                        return Ok((jar, Self::failure("unconfirmed_account", &state.config)));
+                    }
+                    Err(ApiErrorResponse::NotFound(_)) => {
+                        Self::create_user(&state, &profile, email).await?
+                    }
+                    Err(error) => return Err(error),
+                };
+
+                OAuthIdentityRepo::insert_identity(
+                    &state.db_pool,
+                    user.id,
+                    provider,
+                    &profile.subject,
+                    email,
+                )
+                .await?;
+
+                user
+            }
+        };
+
+        let jar = AuthController::issue_session(&state, &headers, jar, user.id, None).await?;
+
+        if let Err(error) = AnomalyDetector::record_login(&state, &headers, user.id).await {
+            error!("Failed to record log-in: {:?}", error);
+        }
+
+        Ok((
+            jar,
+            Redirect::to(&LinkUtil::web_url("/dashboard", &state.config)),
+        ))
+    }
+
+    /// Looks up an enabled identity provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `provider` — The provider to look up.
+    ///
+    /// # Returns
+    ///
+    /// The configured [`IdentityProvider`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the provider is not
+    /// configured.
+    fn identity_provider(
+        state: &AppState,
+        provider: OAuthProvider,
+    ) -> ApiResult<&dyn IdentityProvider> {
+        state
+            .identity_providers
+            .get(provider)
+            .ok_or_else(|| ApiErrorResponse::NotFound("Resource not found".to_string()))
+    }
+
+    /// Creates a confirmed account for a first-time sign-in.
+    ///
+    /// The account gets a random password, so it can only log in with a
+    /// password after a password reset.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `profile` — The user's profile from the identity provider.
+    /// * `email` — The verified email address.
+    ///
+    /// # Returns
+    ///
+    /// The new [`User`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if password hashing or a database
+    /// query fails.
+    async fn create_user(state: &AppState, profile: &OAuthProfile, email: &str) -> ApiResult<User> {
+        let password_hash = PasswordUtil::hash_password(&token::generate())?;
+        let user = UserRepo::insert_user(
+            &state.db_pool,
+            &profile.first_name,
+            &profile.last_name,
+            email,
+            &password_hash,
+        )
+        .await?;
+
+        UserRepo::confirm_email(&state.db_pool, user.id).await?;
+
+        Ok(User {
+            email_confirmed: true,
+            ..user
+        })
+    }
+
+    /// Builds the callback URL registered with a provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` — The identity provider.
+    /// * `config` — Application configuration providing the API's public
+    ///   URL.
+    ///
+    /// # Returns
+    ///
+    /// The absolute callback URL.
+    fn redirect_uri(provider: OAuthProvider, config: &Config) -> String {
+        format!(
+            "{}/auth/oauth/{}/callback",
+            config.api_public_url.trim_end_matches('/'),
+            provider.as_str()
+        )
+    }
+
+    /// Builds the redirect sent when a sign-in cannot be completed.
+    ///
+    /// # Arguments
+    ///
+    /// * `reason` — The `oauth_error` code shown by the web client.
+    /// * `config` — Application configuration providing the web origins.
+    ///
+    /// # Returns
+    ///
+    /// A [`Redirect`] to the web client's log-in page.
+    fn failure(reason: &str, config: &Config) -> Redirect {
+        Redirect::to(&LinkUtil::web_url(
+            &format!("/auth/log-in?oauth_error={reason}"),
+            config,
+        ))
+    }
+}
@@ -0,0 +1,51 @@
+//! Onboarding endpoints.
+//!
+//! Provides [`OnboardingController`] with the handler for the first-run
+//! wizard that sets up a new user's company and job in one request.
+
+use axum::{Json, extract::State};
+use gig_log_common::models::onboarding::{OnboardingRequest, OnboardingResponse};
+
+use crate::auth::AuthUser;
+use crate::core::error::ApiResult;
+use crate::extractors::ValidatedJson;
+use crate::repo::onboarding::OnboardingRepo;
+use crate::routes::app::AppState;
+
+/// Handlers for onboarding routes.
+pub struct OnboardingController;
+
+impl OnboardingController {
+    /// Creates a company, a job, and an optional first payment and work
+    /// session.
+    ///
+    /// Mapped to `POST /onboarding`. Requires authentication. The whole
+    /// payload is validated before anything is written, and all resources
+    /// are created in one transaction. A browser timezone sent with the
+    /// request prefills the user's timezone preference when it is unset.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — A [`ValidatedJson<OnboardingRequest>`] containing the
+    ///   resources to create.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<OnboardingResponse>`] containing every created resource.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`](crate::core::error::ApiErrorResponse)
+    /// if any database insert fails.
+    pub async fn create(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedJson(body): ValidatedJson<OnboardingRequest>,
+    ) -> ApiResult<Json<OnboardingResponse>> {
+        let response = OnboardingRepo::create(&state.db_pool, auth.user_id, body).await?;
+
+        Ok(Json(response))
+    }
+}
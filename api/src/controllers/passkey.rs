@@ -0,0 +1,390 @@
+//! Passkey (WebAuthn) endpoints.
+//!
+//! Provides [`PasskeyController`] with handlers for registering a passkey
+//! on a logged-in account and for logging in with one. Each flow is a
+//! start request that issues a single-use challenge and a finish request
+//! that verifies the browser's response to it. A passkey login issues the
+//! same cookie pair as a password login.
+
+use axum::{Json, extract::State, http::HeaderMap};
+use axum_extra::extract::CookieJar;
+use chrono::{Duration, Utc};
+use gig_log_common::models::passkey::{
+    AuthenticatorSelection, FinishPasskeyLoginRequest, FinishPasskeyRegistrationRequest, Passkey,
+    PasskeyCreationOptions, PasskeyCredentialDescriptor, PasskeyCredentialParameters,
+    PasskeyRequestOptions, PasskeyUserEntity, RelyingParty, StartPasskeyLoginRequest,
+};
+use gig_log_common::models::user::User;
+use log::warn;
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::auth::anomaly::AnomalyDetector;
+use crate::auth::webauthn::{CHALLENGE_TIMEOUT_MS, ES256, WebAuthnUtil};
+use crate::controllers::auth::AuthController;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::extractors::ValidatedJson;
+use crate::repo::passkey::{PasskeyCeremony, PasskeyRepo};
+use crate::repo::user::UserRepo;
+use crate::routes::app::AppState;
+
+/// Credential type of every passkey.
+const PUBLIC_KEY_TYPE: &str = "public-key";
+
+/// Handlers for passkey routes.
+pub struct PasskeyController;
+
+impl PasskeyController {
+    /// Starts registering a passkey for the authenticated user.
+    ///
+    /// Mapped to `POST /auth/passkeys/register/start`. Requires
+    /// authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<PasskeyCreationOptions>`] to pass to
+    /// `navigator.credentials.create`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the user no longer exists,
+    /// [`ApiErrorResponse::Forbidden`] if unusual activity was detected since
+    /// the user last logged in, or an [`ApiErrorResponse`] if storing the
+    /// challenge fails.
+    pub async fn start_registration(
+        auth: AuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<PasskeyCreationOptions>> {
+        AnomalyDetector::require_recent_auth(&state, &auth).await?;
+
+        let user = UserRepo::find_user_by_id(&state.db_pool, auth.user_id).await?;
+        let challenge =
+            Self::issue_challenge(&state, Some(user.id), PasskeyCeremony::Registration).await?;
+        let exclude_credentials = PasskeyRepo::list_credential_ids(&state.db_pool, user.id)
+            .await?
+            .into_iter()
+            .map(Self::descriptor)
+            .collect();
+
+        Ok(Json(PasskeyCreationOptions {
+            rp: RelyingParty {
+                id: state.config.webauthn_rp_id.clone(),
+                name: state.config.webauthn_rp_name.clone(),
+            },
+            user: PasskeyUserEntity {
+                id: WebAuthnUtil::encode(user.id.as_bytes()),
+                display_name: format!("{} {}", user.first_name, user.last_name),
+                name: user.email,
+            },
+            challenge,
+            pub_key_cred_params: vec![PasskeyCredentialParameters {
+                credential_type: PUBLIC_KEY_TYPE.to_string(),
+                alg: ES256,
+            }],
+            timeout: CHALLENGE_TIMEOUT_MS,
+            exclude_credentials,
+            authenticator_selection: AuthenticatorSelection {
+                resident_key: "preferred".to_string(),
+                user_verification: "preferred".to_string(),
+            },
+            attestation: "none".to_string(),
+        }))
+    }
+
+    /// Finishes registering a passkey for the authenticated user.
+    ///
+    /// Mapped to `POST /auth/passkeys/register/finish`. Requires
+    /// authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — A [`ValidatedJson<FinishPasskeyRegistrationRequest>`]
+    ///   containing the passkey's name and the created credential.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Passkey>`] describing the registered passkey.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the challenge is unknown,
+    /// expired, or was issued to another user, if the credential fails
+    /// verification or uses an unsupported algorithm, or if it is already
+    /// registered.
+    pub async fn finish_registration(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedJson(body): ValidatedJson<FinishPasskeyRegistrationRequest>,
+    ) -> ApiResult<Json<Passkey>> {
+        let response = &body.credential.response;
+        let client_data_json = WebAuthnUtil::decode(&response.client_data_json, "client data")?;
+        let authenticator_data =
+            WebAuthnUtil::decode(&response.authenticator_data, "authenticator data")?;
+        let credential_id = WebAuthnUtil::decode(&body.credential.id, "credential ID")?;
+        let public_key = WebAuthnUtil::decode(&response.public_key, "public key")?;
+
+        let client_data = WebAuthnUtil::parse_client_data(&client_data_json)?;
+        let challenge_user_id = PasskeyRepo::take_challenge(
+            &state.db_pool,
+            &client_data.challenge,
+            PasskeyCeremony::Registration,
+        )
+        .await?;
+
+        if challenge_user_id != Some(Some(auth.user_id)) {
+            return Err(ApiErrorResponse::BadRequest(
+                "Invalid or expired challenge".to_string(),
+            ));
+        }
+
+        WebAuthnUtil::verify_client_data(
+            &client_data,
+            PasskeyCeremony::Registration,
+            &state.config.web_origins,
+        )?;
+
+        let data = WebAuthnUtil::parse_authenticator_data(&authenticator_data)?;
+        WebAuthnUtil::verify_authenticator_data(&data, &state.config.webauthn_rp_id)?;
+
+        if data.credential_id.as_deref() != Some(credential_id.as_slice()) {
+            return Err(ApiErrorResponse::BadRequest(
+                "Credential ID does not match the authenticator data".to_string(),
+            ));
+        }
+
+        if response.public_key_algorithm != ES256 {
+            return Err(ApiErrorResponse::BadRequest(
+                "Unsupported passkey algorithm".to_string(),
+            ));
+        }
+
+        let attested_public_key = data.credential_public_key.ok_or_else(|| {
+            ApiErrorResponse::BadRequest("Authenticator data has no public key".to_string())
+        })?;
+        WebAuthnUtil::verify_public_key(&public_key, &attested_public_key)?;
+
+        let passkey = PasskeyRepo::insert_passkey(
+            &state.db_pool,
+            auth.user_id,
+            body.name.trim(),
+            &WebAuthnUtil::encode(&credential_id),
+            &attested_public_key,
+            data.sign_count.into(),
+        )
+        .await?
+        .ok_or_else(|| ApiErrorResponse::BadRequest("Passkey is already registered".to_string()))?;
+
+        Ok(Json(passkey))
+    }
+
+    /// Starts logging in with a passkey.
+    ///
+    /// Mapped to `POST /auth/passkeys/login/start`. Does not require
+    /// authentication. Without an email the user picks a discoverable
+    /// passkey. With one, the account's passkeys are offered; an unknown
+    /// email gets the same response with no passkeys offered, so accounts
+    /// cannot be enumerated.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — A [`ValidatedJson<StartPasskeyLoginRequest>`] with the
+    ///   optional account email.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<PasskeyRequestOptions>`] to pass to
+    /// `navigator.credentials.get`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if a query fails.
+    pub async fn start_login(
+        State(state): State<AppState>,
+        ValidatedJson(body): ValidatedJson<StartPasskeyLoginRequest>,
+    ) -> ApiResult<Json<PasskeyRequestOptions>> {
+        let user_id = match body.email.as_deref() {
+            Some(email) => match UserRepo::find_user_by_email(&state.db_pool, email).await {
+                Ok(user) => Some(user.id),
+                Err(ApiErrorResponse::NotFound(_)) => None,
+                Err(error) => return Err(error),
+            },
+            None => None,
+        };
+
+        let allow_credentials = match user_id {
+            Some(user_id) => PasskeyRepo::list_credential_ids(&state.db_pool, user_id)
+                .await?
+                .into_iter()
+                .map(Self::descriptor)
+                .collect(),
+            None => Vec::new(),
+        };
+        let challenge =
+            Self::issue_challenge(&state, user_id, PasskeyCeremony::Authentication).await?;
+
+        Ok(Json(PasskeyRequestOptions {
+            challenge,
+            rp_id: state.config.webauthn_rp_id.clone(),
+            timeout: CHALLENGE_TIMEOUT_MS,
+            allow_credentials,
+            user_verification: "preferred".to_string(),
+        }))
+    }
+
+    /// Finishes logging in with a passkey and issues session tokens.
+    ///
+    /// Mapped to `POST /auth/passkeys/login/finish`. Does not require
+    /// authentication. Sets the same access and refresh token cookies as
+    /// `POST /auth/log-in`.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `headers` — The request headers, used to select the cookie domain.
+    /// * `jar` — The [`CookieJar`] to receive the new session cookies.
+    /// * `body` — A [`ValidatedJson<FinishPasskeyLoginRequest>`] containing
+    ///   the authenticator's response.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the updated [`CookieJar`] and [`Json<User>`] with the
+    /// authenticated user's profile.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the challenge is unknown
+    /// or expired, the passkey is unknown or belongs to another account
+    /// than the one named at the start, the response fails verification,
+    /// or the email is not yet confirmed.
+    pub async fn finish_login(
+        State(state): State<AppState>,
+        headers: HeaderMap,
+        jar: CookieJar,
+        ValidatedJson(body): ValidatedJson<FinishPasskeyLoginRequest>,
+    ) -> ApiResult<(CookieJar, Json<User>)> {
+        let client_data_json =
+            WebAuthnUtil::decode(&body.response.client_data_json, "client data")?;
+        let authenticator_data =
+            WebAuthnUtil::decode(&body.response.authenticator_data, "authenticator data")?;
+        let signature = WebAuthnUtil::decode(&body.response.signature, "signature")?;
+        let credential_id = WebAuthnUtil::decode(&body.id, "credential ID")?;
+
+        let client_data = WebAuthnUtil::parse_client_data(&client_data_json)?;
+        let challenge_user_id = PasskeyRepo::take_challenge(
+            &state.db_pool,
+            &client_data.challenge,
+            PasskeyCeremony::Authentication,
+        )
+        .await?
+        .ok_or_else(|| ApiErrorResponse::BadRequest("Invalid or expired challenge".to_string()))?;
+
+        WebAuthnUtil::verify_client_data(
+            &client_data,
+            PasskeyCeremony::Authentication,
+            &state.config.web_origins,
+        )?;
+
+        let invalid = || ApiErrorResponse::BadRequest("Invalid credentials".to_string());
+        let passkey = PasskeyRepo::find_by_credential_id(
+            &state.db_pool,
+            &WebAuthnUtil::encode(&credential_id),
+        )
+        .await?
+        .ok_or_else(invalid)?;
+
+        if challenge_user_id.is_some_and(|user_id| user_id != passkey.user_id) {
+            return Err(invalid());
+        }
+
+        let data = WebAuthnUtil::parse_authenticator_data(&authenticator_data)?;
+        WebAuthnUtil::verify_authenticator_data(&data, &state.config.webauthn_rp_id)?;
+        WebAuthnUtil::verify_signature(
+            &passkey.public_key,
+            &authenticator_data,
+            &client_data_json,
+            &signature,
+        )?;
+
+        let recorded = WebAuthnUtil::is_sign_count_valid(passkey.sign_count, data.sign_count)
+            && PasskeyRepo::record_use(
+                &state.db_pool,
+                passkey.id,
+                passkey.sign_count,
+                data.sign_count.into(),
+            )
+            .await?;
+
+        if !recorded {
+            warn!(
+                "Rejected passkey {} with a stale signature counter",
+                passkey.id
+            );
+            return Err(invalid());
+        }
+
+        let user = UserRepo::find_user_by_id(&state.db_pool, passkey.user_id).await?;
+
+        if !user.email_confirmed {
+            return Err(ApiErrorResponse::BadRequest(
+                "Please confirm your email before logging in".to_string(),
+            ));
+        }
+
+        let jar = AuthController::issue_session(&state, &headers, jar, user.id, None).await?;
+
+        Ok((jar, Json(user)))
+    }
+
+    /// Generates and stores a ceremony challenge.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `user_id` — The user the ceremony is for, if known.
+    /// * `ceremony` — The ceremony the challenge is for.
+    ///
+    /// # Returns
+    ///
+    /// The base64url-encoded challenge.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if storing the challenge fails.
+    async fn issue_challenge(
+        state: &AppState,
+        user_id: Option<Uuid>,
+        ceremony: PasskeyCeremony,
+    ) -> ApiResult<String> {
+        let challenge = WebAuthnUtil::generate_challenge();
+        let expires_at = Utc::now() + Duration::milliseconds(CHALLENGE_TIMEOUT_MS as i64);
+
+        PasskeyRepo::insert_challenge(&state.db_pool, user_id, &challenge, ceremony, expires_at)
+            .await?;
+
+        Ok(challenge)
+    }
+
+    /// Wraps a credential ID in a WebAuthn credential descriptor.
+    ///
+    /// # Arguments
+    ///
+    /// * `credential_id` — The base64url-encoded credential ID.
+    ///
+    /// # Returns
+    ///
+    /// The [`PasskeyCredentialDescriptor`].
+    fn descriptor(credential_id: String) -> PasskeyCredentialDescriptor {
+        PasskeyCredentialDescriptor {
+            credential_type: PUBLIC_KEY_TYPE.to_string(),
+            id: credential_id,
+        }
+    }
+}
@@ -0,0 +1,511 @@
+//! Payment endpoints.
+//!
+//! Provides [`PaymentController`] with handlers for listing payments with
+//! filters and sorting, for listing overdue payments, for managing payment
+//! reminder emails, for recording installments, for sharing received
+//! payments through signed public receipt links, for suggesting which
+//! expected payment a received payment settles, for reconciling a month
+//! of payments, and for exporting payments as CSV.
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    response::Response,
+};
+use chrono::{Duration, Utc};
+use gig_log_common::models::{
+    generic::{DetailQuery, ExportQuery, PageQuery, Paginated},
+    live_sync::LiveSyncMessage,
+    payment::{
+        CreatePaymentInstallmentRequest, CreatePaymentInstallmentResponse,
+        CreateReceiptLinkRequest, ListPaymentsQuery, OverduePaymentsResponse, Payment,
+        PaymentDetailResponse, PaymentMatchSuggestion, PaymentReconciliationQuery,
+        PaymentReconciliationResponse, PaymentReminderSetting, PaymentSort, ReceiptLink,
+        UpdatePaymentRequest,
+    },
+    webhook::WebhookEvent,
+};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::core::export::CsvExport;
+use crate::extractors::{ValidatedJson, ValidatedQuery};
+use crate::include::IncludeUtil;
+use crate::payment_matching::PaymentMatchUtil;
+use crate::receipt::ReceiptUtil;
+use crate::reconciliation::ReconciliationUtil;
+use crate::repo::{company::CompanyRepo, payment::PaymentRepo};
+use crate::routes::app::AppState;
+use crate::sparse_fields::SparseFieldsUtil;
+use crate::webhook::WebhookDispatcher;
+
+/// Days a receipt link stays valid when no expiry is requested.
+const DEFAULT_RECEIPT_LINK_DAYS: i64 = 30;
+
+/// Handlers for payment routes.
+pub struct PaymentController;
+
+impl PaymentController {
+    /// Lists the authenticated user's payments.
+    ///
+    /// Mapped to `GET /payments`. Requires authentication. Results can be
+    /// filtered by company, payout type, received and transfer status, and
+    /// an expected payout date range, sorted with `?sort=`, and paged with
+    /// `?page=` and `?per_page=`.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `query` — The [`ListPaymentsQuery`] filters and sort order.
+    /// * `page` — The [`PageQuery`] selecting the page.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Paginated<Payment>>`] page of matching payments, newest
+    /// first unless another order is requested.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`] if `date_from` is after
+    /// `date_to` or the page is out of range,
+    /// [`ApiErrorResponse::BadRequest`] if the sort field is
+    /// unknown, or an [`ApiErrorResponse`] if the database query fails.
+    pub async fn list(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedQuery(query): ValidatedQuery<ListPaymentsQuery>,
+        ValidatedQuery(page): ValidatedQuery<PageQuery>,
+    ) -> ApiResult<Json<Paginated<Payment>>> {
+        let sort = match query.sort.as_deref() {
+            Some(sort) => PaymentSort::parse(sort).ok_or_else(|| {
+                ApiErrorResponse::BadRequest(format!("Unknown sort field: {sort}"))
+            })?,
+            None => PaymentSort::default(),
+        };
+
+        let payments =
+            PaymentRepo::list_page_for_user(&state.db_pool, auth.user_id, &query, sort, &page)
+                .await?;
+
+        Ok(Json(payments))
+    }
+
+    /// Lists the authenticated user's overdue payments with totals per
+    /// company.
+    ///
+    /// Mapped to `GET /payments/overdue`. Requires authentication. A payment
+    /// is overdue once its expected payout date has passed without it being
+    /// received.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<OverduePaymentsResponse>`] with the overdue payments, the
+    /// amount outstanding on them, and totals per company.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if a database query fails.
+    pub async fn overdue(
+        auth: AuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<OverduePaymentsResponse>> {
+        let today = Utc::now().date_naive();
+        let payments =
+            PaymentRepo::list_overdue_payments(&state.db_pool, auth.user_id, today).await?;
+        let companies =
+            PaymentRepo::list_overdue_company_totals(&state.db_pool, auth.user_id, today).await?;
+        let amount_outstanding: f64 = companies
+            .iter()
+            .map(|company| company.amount_outstanding)
+            .sum();
+
+        Ok(Json(OverduePaymentsResponse {
+            payment_count: payments.len(),
+            amount_outstanding: (amount_outstanding * 100.0).round() / 100.0,
+            companies,
+            payments,
+        }))
+    }
+
+    /// Returns whether the authenticated user receives payment reminder
+    /// emails.
+    ///
+    /// Mapped to `GET /payments/reminders`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<PaymentReminderSetting>`] with the current preference.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn show_reminders(
+        auth: AuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<PaymentReminderSetting>> {
+        let enabled = PaymentRepo::find_reminders_enabled(&state.db_pool, auth.user_id).await?;
+
+        Ok(Json(PaymentReminderSetting { enabled }))
+    }
+
+    /// Turns the authenticated user's payment reminder emails on or off.
+    ///
+    /// Mapped to `PUT /payments/reminders`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — A [`Json<PaymentReminderSetting>`] with the new
+    ///   preference.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<PaymentReminderSetting>`] with the saved preference.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database update fails.
+    pub async fn update_reminders(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Json(body): Json<PaymentReminderSetting>,
+    ) -> ApiResult<Json<PaymentReminderSetting>> {
+        PaymentRepo::set_reminders_enabled(&state.db_pool, auth.user_id, body.enabled).await?;
+
+        Ok(Json(body))
+    }
+
+    /// Reconciles the authenticated user's payments for a month.
+    ///
+    /// Mapped to `GET /payments/reconciliation`. Requires authentication.
+    /// Payments are due on their expected payout date, or on the day they
+    /// were created when they have none.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `query` — The [`PaymentReconciliationQuery`] selecting the month.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<PaymentReconciliationResponse>`] with each payment due in
+    /// the month, its discrepancy flags, and expected, transferred, and
+    /// received totals.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the month is not
+    /// formatted as `YYYY-MM`.
+    pub async fn reconciliation(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Query(query): Query<PaymentReconciliationQuery>,
+    ) -> ApiResult<Json<PaymentReconciliationResponse>> {
+        let today = Utc::now().date_naive();
+        let (month_start, month_end) =
+            ReconciliationUtil::month_range(query.month.as_deref(), today)?;
+        let records = PaymentRepo::list_reconciliation_payments(
+            &state.db_pool,
+            auth.user_id,
+            month_start,
+            month_end,
+        )
+        .await?;
+
+        Ok(Json(ReconciliationUtil::build(
+            month_start,
+            month_end,
+            records,
+            today,
+        )))
+    }
+
+    /// Exports the authenticated user's payments as CSV.
+    ///
+    /// Mapped to `GET /payments/export.csv`. Requires authentication. Accepts
+    /// `?from=` and `?to=` to limit the export to an inclusive range of
+    /// payment dates: the expected payout date, or the creation
+    /// date when there is none. Rows are streamed as they are read, oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `query` — The [`ExportQuery`] selecting the date range.
+    ///
+    /// # Returns
+    ///
+    /// A [`Response`] streaming a `payments.csv` attachment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`] if `from` is after `to`.
+    pub async fn export(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedQuery(query): ValidatedQuery<ExportQuery>,
+    ) -> ApiResult<Response> {
+        let ExportQuery { from, to } = query;
+        let pool = state.db_pool.clone();
+
+        Ok(CsvExport::response(
+            "payments.csv",
+            move |writer| async move {
+                writer
+                    .write_all(PaymentRepo::stream_export(&pool, auth.user_id, from, to))
+                    .await
+            },
+        ))
+    }
+
+    /// Returns one of the authenticated user's payments.
+    ///
+    /// Mapped to `GET /payments/{id}`. Requires authentication. Accepts
+    /// `?fields=` to return only some top-level fields and
+    /// `?include=company` to embed the issuing company.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `payment_id` — The payment's UUID from the request path.
+    /// * `query` — The [`DetailQuery`] selecting fields and includes.
+    ///
+    /// # Returns
+    ///
+    /// A [`PaymentDetailResponse`] as JSON, containing the payment and any
+    /// included relations, limited to the requested fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the payment does not exist
+    /// or belongs to another user, [`ApiErrorResponse::Validation`] if
+    /// `fields` or `include` is too long, or [`ApiErrorResponse::BadRequest`]
+    /// if an unknown field or include is requested.
+    pub async fn show(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(payment_id): Path<Uuid>,
+        ValidatedQuery(query): ValidatedQuery<DetailQuery>,
+    ) -> ApiResult<Json<Value>> {
+        let includes = IncludeUtil::parse(query.include.as_deref(), &["company"])?;
+        let payment =
+            PaymentRepo::find_payment_by_id(&state.db_pool, auth.user_id, payment_id).await?;
+
+        let company = if includes.contains(&"company") {
+            Some(
+                CompanyRepo::find_company_by_id(&state.db_pool, auth.user_id, payment.company_id)
+                    .await?,
+            )
+        } else {
+            None
+        };
+
+        let response = PaymentDetailResponse { payment, company };
+
+        Ok(Json(SparseFieldsUtil::select(
+            &response,
+            query.fields.as_deref(),
+        )?))
+    }
+
+    /// Partially updates one of the authenticated user's payments.
+    ///
+    /// Mapped to `PATCH /payments/{id}`. Requires authentication. Omitted fields
+    /// are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `payment_id` — The payment's UUID from the request path.
+    /// * `body` — A [`ValidatedJson<UpdatePaymentRequest>`] containing the fields to
+    ///   change.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Payment>`] containing the updated payment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the payment does not exist
+    /// or belongs to another user, or [`ApiErrorResponse::Validation`] if
+    /// the new total is less than the amount already paid.
+    pub async fn update(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(payment_id): Path<Uuid>,
+        ValidatedJson(body): ValidatedJson<UpdatePaymentRequest>,
+    ) -> ApiResult<Json<Payment>> {
+        let payment =
+            PaymentRepo::update_payment(&state.db_pool, auth.user_id, payment_id, &body).await?;
+        state.live_sync.publish(
+            auth.user_id,
+            LiveSyncMessage::PaymentUpdated(payment.clone()),
+        );
+        WebhookDispatcher::publish(
+            &state.db_pool,
+            auth.user_id,
+            WebhookEvent::PaymentUpdated(payment.clone()),
+        )
+        .await;
+
+        Ok(Json(payment))
+    }
+
+    /// Records an installment toward one of the authenticated user's
+    /// payments.
+    ///
+    /// Mapped to `POST /payments/{id}/installments`. Requires
+    /// authentication. A payment whose installments cover its total is
+    /// marked received.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `payment_id` — The payment's UUID from the request path.
+    /// * `body` — A [`ValidatedJson<CreatePaymentInstallmentRequest>`] with
+    ///   the installment's amount and date.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<CreatePaymentInstallmentResponse>`] containing the
+    /// installment and the payment's updated roll-up fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the payment does not exist
+    /// or belongs to another user, or [`ApiErrorResponse::Validation`] if
+    /// the amount is more than the payment's outstanding amount.
+    pub async fn create_installment(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(payment_id): Path<Uuid>,
+        ValidatedJson(body): ValidatedJson<CreatePaymentInstallmentRequest>,
+    ) -> ApiResult<Json<CreatePaymentInstallmentResponse>> {
+        let (installment, payment) = PaymentRepo::insert_installment(
+            &state.db_pool,
+            auth.user_id,
+            payment_id,
+            body.amount,
+            body.paid_on.unwrap_or_else(|| Utc::now().date_naive()),
+        )
+        .await?;
+        state.live_sync.publish(
+            auth.user_id,
+            LiveSyncMessage::PaymentUpdated(payment.clone()),
+        );
+        WebhookDispatcher::publish(
+            &state.db_pool,
+            auth.user_id,
+            WebhookEvent::PaymentUpdated(payment.clone()),
+        )
+        .await;
+
+        Ok(Json(CreatePaymentInstallmentResponse {
+            installment,
+            payment,
+        }))
+    }
+
+    /// Creates a signed, expiring public receipt link for a received payment.
+    ///
+    /// Mapped to `POST /payments/{id}/receipt-link`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `payment_id` — The payment's UUID from the request path.
+    /// * `body` — A [`ValidatedJson<CreateReceiptLinkRequest>`] with the
+    ///   link's lifetime.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<ReceiptLink>`] containing the public receipt path.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the payment does not exist,
+    /// or [`ApiErrorResponse::BadRequest`] if it has not been received.
+    pub async fn create_receipt_link(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(payment_id): Path<Uuid>,
+        ValidatedJson(body): ValidatedJson<CreateReceiptLinkRequest>,
+    ) -> ApiResult<Json<ReceiptLink>> {
+        let payment =
+            PaymentRepo::find_payment_by_id(&state.db_pool, auth.user_id, payment_id).await?;
+
+        if !payment.payment_received {
+            return Err(ApiErrorResponse::BadRequest(
+                "Receipts can only be shared for received payments".to_string(),
+            ));
+        }
+
+        let expires_at =
+            Utc::now() + Duration::days(body.expires_in_days.unwrap_or(DEFAULT_RECEIPT_LINK_DAYS));
+        let token = ReceiptUtil::generate_token(payment.id, expires_at, &state.config.jwt_secret)?;
+
+        Ok(Json(ReceiptLink {
+            path: format!("/p/{token}"),
+            token,
+            expires_at,
+        }))
+    }
+
+    /// Suggests which expected payments a received payment corresponds to.
+    ///
+    /// Mapped to `GET /payments/{id}/match-suggestions`. Requires
+    /// authentication. Candidates are the user's payments that have not been
+    /// received, ranked by amount, company, and expected date.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `payment_id` — The received payment's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Vec<PaymentMatchSuggestion>>`] with the best match first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the payment does not exist,
+    /// or [`ApiErrorResponse::BadRequest`] if it has not been received.
+    pub async fn match_suggestions(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(payment_id): Path<Uuid>,
+    ) -> ApiResult<Json<Vec<PaymentMatchSuggestion>>> {
+        let payment =
+            PaymentRepo::find_payment_by_id(&state.db_pool, auth.user_id, payment_id).await?;
+
+        if !payment.payment_received {
+            return Err(ApiErrorResponse::BadRequest(
+                "Match suggestions are only available for received payments".to_string(),
+            ));
+        }
+
+        let candidates = PaymentRepo::list_expected_payments(&state.db_pool, auth.user_id).await?;
+
+        Ok(Json(PaymentMatchUtil::suggest(&payment, candidates)))
+    }
+}
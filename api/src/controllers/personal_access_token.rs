@@ -0,0 +1,137 @@
+//! Personal access token endpoints.
+//!
+//! Provides [`PersonalAccessTokenController`] with handlers for issuing,
+//! listing, and revoking scoped personal access tokens for third-party
+//! automations. These routes cannot themselves be called with a personal
+//! access token.
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use gig_log_common::models::{
+    generic::MessageResponse,
+    personal_access_token::{
+        CreatePersonalAccessTokenRequest, CreatePersonalAccessTokenResponse, PersonalAccessToken,
+    },
+};
+use uuid::Uuid;
+
+use crate::auth::{AuthUser, anomaly::AnomalyDetector, token, user::PERSONAL_ACCESS_TOKEN_PREFIX};
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::extractors::ValidatedJson;
+use crate::repo::personal_access_token::PersonalAccessTokenRepo;
+use crate::routes::app::AppState;
+
+/// Handlers for personal access token routes.
+pub struct PersonalAccessTokenController;
+
+impl PersonalAccessTokenController {
+    /// Issues a personal access token with the requested scopes.
+    ///
+    /// Mapped to `POST /personal-access-tokens`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — A [`ValidatedJson<CreatePersonalAccessTokenRequest>`]
+    ///   containing the token's name and scopes.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<CreatePersonalAccessTokenResponse>`] containing the raw
+    /// token, which is not retrievable later.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Forbidden`] if unusual activity was detected
+    /// since the user last logged in, or an [`ApiErrorResponse`] if the
+    /// database insert fails.
+    pub async fn create(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedJson(body): ValidatedJson<CreatePersonalAccessTokenRequest>,
+    ) -> ApiResult<Json<CreatePersonalAccessTokenResponse>> {
+        AnomalyDetector::require_recent_auth(&state, &auth).await?;
+
+        let mut scopes = body.scopes;
+        scopes.sort_by_key(|scope| scope.as_str());
+        scopes.dedup();
+
+        let raw_token = format!("{PERSONAL_ACCESS_TOKEN_PREFIX}{}", token::generate());
+        let personal_access_token = PersonalAccessTokenRepo::insert_token(
+            &state.db_pool,
+            auth.user_id,
+            body.name.trim(),
+            &token::hash(&raw_token),
+            &scopes,
+        )
+        .await?;
+
+        Ok(Json(CreatePersonalAccessTokenResponse {
+            token: raw_token,
+            personal_access_token,
+        }))
+    }
+
+    /// Lists the authenticated user's personal access tokens.
+    ///
+    /// Mapped to `GET /personal-access-tokens`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Vec<PersonalAccessToken>>`] ordered newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn list(
+        auth: AuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<Vec<PersonalAccessToken>>> {
+        let tokens = PersonalAccessTokenRepo::list_tokens(&state.db_pool, auth.user_id).await?;
+
+        Ok(Json(tokens))
+    }
+
+    /// Revokes one of the authenticated user's personal access tokens.
+    ///
+    /// Mapped to `DELETE /personal-access-tokens/{id}`. Requires
+    /// authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `token_id` — The token's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MessageResponse>`] confirming the revocation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the token does not exist or
+    /// belongs to another user.
+    pub async fn delete(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(token_id): Path<Uuid>,
+    ) -> ApiResult<Json<MessageResponse>> {
+        if !PersonalAccessTokenRepo::delete_token(&state.db_pool, auth.user_id, token_id).await? {
+            return Err(ApiErrorResponse::NotFound(
+                "Personal access token not found".to_string(),
+            ));
+        }
+
+        Ok(Json(MessageResponse {
+            message: "Personal access token revoked.".to_string(),
+        }))
+    }
+}
@@ -0,0 +1,63 @@
+//! Public payment receipt pages.
+//!
+//! Provides [`ReceiptController`], which renders the server-side receipt
+//! page behind a signed receipt link without requiring authentication.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Html,
+};
+use chrono::DateTime;
+
+use crate::core::error::ApiResult;
+use crate::receipt::ReceiptUtil;
+use crate::repo::payment::PaymentRepo;
+use crate::routes::app::AppState;
+
+/// Handlers for public receipt routes.
+pub struct ReceiptController;
+
+impl ReceiptController {
+    /// Renders the receipt for a signed receipt link.
+    ///
+    /// Mapped to `GET /p/{token}`. Public — no authentication required. The
+    /// token's signature and expiry are verified, and the payment must still
+    /// be marked as received.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `token` — The signed receipt token from the request path.
+    ///
+    /// # Returns
+    ///
+    /// The receipt page, or a `404` page if the link is invalid, expired,
+    /// or the payment is no longer received.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`](crate::core::error::ApiErrorResponse)
+    /// if the database query fails.
+    pub async fn show(
+        State(state): State<AppState>,
+        Path(token): Path<String>,
+    ) -> ApiResult<(StatusCode, Html<String>)> {
+        let not_found = (StatusCode::NOT_FOUND, Html(ReceiptUtil::render_invalid()));
+
+        let Some(claims) = ReceiptUtil::verify_token(&token, &state.config.jwt_secret) else {
+            return Ok(not_found);
+        };
+        let Some(expires_at) = DateTime::from_timestamp(claims.exp, 0) else {
+            return Ok(not_found);
+        };
+        let Some(receipt) = PaymentRepo::find_receipt(&state.db_pool, claims.sub).await? else {
+            return Ok(not_found);
+        };
+
+        Ok((
+            StatusCode::OK,
+            Html(ReceiptUtil::render_receipt(&receipt, expires_at)),
+        ))
+    }
+}
@@ -0,0 +1,312 @@
+//! Recurring payment endpoints.
+//!
+//! Provides [`RecurringPaymentController`] with handlers for scheduling
+//! payments that a company makes on a regular cadence and previewing their
+//! upcoming occurrences. Concrete payments are created from each schedule by
+//! the [`RecurringPaymentTask`](crate::tasks::recurring_payments::RecurringPaymentTask).
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use gig_log_common::models::{
+    generic::MessageResponse,
+    recurring_payment::{
+        CreateRecurringPaymentRequest, RecurringPayment, UpcomingPayment, UpcomingPaymentsQuery,
+        UpdateRecurringPaymentRequest,
+    },
+};
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::extractors::{ValidatedJson, ValidatedQuery};
+use crate::recurrence::RecurrenceUtil;
+use crate::repo::{company::CompanyRepo, recurring_payment::RecurringPaymentRepo};
+use crate::routes::app::AppState;
+
+/// Occurrences previewed when no count is given.
+const DEFAULT_UPCOMING_COUNT: u32 = 5;
+
+/// Handlers for recurring payment routes.
+pub struct RecurringPaymentController;
+
+impl RecurringPaymentController {
+    /// Schedules a recurring payment from one of the authenticated user's
+    /// companies.
+    ///
+    /// Mapped to `POST /recurring-payments`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — A [`ValidatedJson<CreateRecurringPaymentRequest>`]
+    ///   containing the payment template and schedule.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<RecurringPayment>`] containing the created schedule.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the company does not exist
+    /// or belongs to another user.
+    pub async fn create(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedJson(body): ValidatedJson<CreateRecurringPaymentRequest>,
+    ) -> ApiResult<Json<RecurringPayment>> {
+        CompanyRepo::find_company_by_id(&state.db_pool, auth.user_id, body.company_id)
+            .await
+            .map_err(|error| match error {
+                ApiErrorResponse::NotFound(_) => {
+                    ApiErrorResponse::NotFound("Company not found".to_string())
+                }
+                error => error,
+            })?;
+
+        let recurring_payment =
+            RecurringPaymentRepo::insert_recurring_payment(&state.db_pool, auth.user_id, &body)
+                .await?;
+
+        Ok(Json(recurring_payment))
+    }
+
+    /// Lists the authenticated user's recurring payments.
+    ///
+    /// Mapped to `GET /recurring-payments`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Vec<RecurringPayment>>`], oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn list(
+        auth: AuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<Vec<RecurringPayment>>> {
+        let recurring_payments =
+            RecurringPaymentRepo::list_recurring_payments(&state.db_pool, auth.user_id).await?;
+
+        Ok(Json(recurring_payments))
+    }
+
+    /// Returns one of the authenticated user's recurring payments.
+    ///
+    /// Mapped to `GET /recurring-payments/{id}`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `recurring_payment_id` — The recurring payment's UUID from the
+    ///   request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<RecurringPayment>`] containing the schedule.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the recurring payment does
+    /// not exist or belongs to another user.
+    pub async fn show(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(recurring_payment_id): Path<Uuid>,
+    ) -> ApiResult<Json<RecurringPayment>> {
+        let recurring_payment = Self::find(&state, auth.user_id, recurring_payment_id).await?;
+
+        Ok(Json(recurring_payment))
+    }
+
+    /// Partially updates one of the authenticated user's recurring payments.
+    ///
+    /// Mapped to `PATCH /recurring-payments/{id}`. Requires authentication.
+    /// Omitted fields are left unchanged. Payments already created from the
+    /// schedule keep their values.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `recurring_payment_id` — The recurring payment's UUID from the
+    ///   request path.
+    /// * `body` — A [`ValidatedJson<UpdateRecurringPaymentRequest>`]
+    ///   containing the fields to change.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<RecurringPayment>`] containing the updated schedule.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the recurring payment does
+    /// not exist or belongs to another user, or
+    /// [`ApiErrorResponse::Validation`] if the new end date is before the
+    /// start date.
+    pub async fn update(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(recurring_payment_id): Path<Uuid>,
+        ValidatedJson(body): ValidatedJson<UpdateRecurringPaymentRequest>,
+    ) -> ApiResult<Json<RecurringPayment>> {
+        let recurring_payment = RecurringPaymentRepo::update_recurring_payment(
+            &state.db_pool,
+            auth.user_id,
+            recurring_payment_id,
+            &body,
+        )
+        .await
+        .map_err(|error| match error {
+            ApiErrorResponse::NotFound(_) => {
+                ApiErrorResponse::NotFound("Recurring payment not found".to_string())
+            }
+            error => error,
+        })?;
+
+        Ok(Json(recurring_payment))
+    }
+
+    /// Deletes one of the authenticated user's recurring payments.
+    ///
+    /// Mapped to `DELETE /recurring-payments/{id}`. Requires authentication.
+    /// Payments already created from the schedule are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `recurring_payment_id` — The recurring payment's UUID from the
+    ///   request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MessageResponse>`] confirming the deletion.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the recurring payment does
+    /// not exist or belongs to another user.
+    pub async fn delete(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(recurring_payment_id): Path<Uuid>,
+    ) -> ApiResult<Json<MessageResponse>> {
+        if !RecurringPaymentRepo::delete_recurring_payment(
+            &state.db_pool,
+            auth.user_id,
+            recurring_payment_id,
+        )
+        .await?
+        {
+            return Err(ApiErrorResponse::NotFound(
+                "Recurring payment not found".to_string(),
+            ));
+        }
+
+        Ok(Json(MessageResponse {
+            message: "Recurring payment deleted.".to_string(),
+        }))
+    }
+
+    /// Previews the next occurrences of one of the authenticated user's
+    /// recurring payments.
+    ///
+    /// Mapped to `GET /recurring-payments/{id}/upcoming`. Requires
+    /// authentication. Accepts an optional `count` query parameter for the
+    /// number of occurrences, defaulting to 5. Occurrences that already have
+    /// a payment are not included.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `recurring_payment_id` — The recurring payment's UUID from the
+    ///   request path.
+    /// * `query` — The [`UpcomingPaymentsQuery`] parameters.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Vec<UpcomingPayment>>`] in date order, empty once the
+    /// schedule has ended.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`] if the count is out of
+    /// range, or [`ApiErrorResponse::NotFound`] if the recurring payment
+    /// does not exist or belongs to another user.
+    pub async fn upcoming(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(recurring_payment_id): Path<Uuid>,
+        ValidatedQuery(query): ValidatedQuery<UpcomingPaymentsQuery>,
+    ) -> ApiResult<Json<Vec<UpcomingPayment>>> {
+        let recurring_payment = Self::find(&state, auth.user_id, recurring_payment_id).await?;
+        let Some(next_date) = recurring_payment.next_date else {
+            return Ok(Json(Vec::new()));
+        };
+        let count = query.count.unwrap_or(DEFAULT_UPCOMING_COUNT) as usize;
+
+        let upcoming = RecurrenceUtil::occurrences(
+            recurring_payment.start_date,
+            recurring_payment.cadence,
+            recurring_payment.end_date,
+            next_date,
+            None,
+            count,
+        )
+        .into_iter()
+        .map(|expected_payout_date| UpcomingPayment {
+            expected_payout_date,
+            total: recurring_payment.total,
+            payout_type: recurring_payment.payout_type.clone(),
+        })
+        .collect();
+
+        Ok(Json(upcoming))
+    }
+
+    /// Finds one of a user's recurring payments.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `user_id` — The user's UUID.
+    /// * `recurring_payment_id` — The recurring payment's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The matching [`RecurringPayment`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the recurring payment does
+    /// not exist or belongs to another user.
+    async fn find(
+        state: &AppState,
+        user_id: Uuid,
+        recurring_payment_id: Uuid,
+    ) -> ApiResult<RecurringPayment> {
+        RecurringPaymentRepo::find_recurring_payment_by_id(
+            &state.db_pool,
+            user_id,
+            recurring_payment_id,
+        )
+        .await
+        .map_err(|error| match error {
+            ApiErrorResponse::NotFound(_) => {
+                ApiErrorResponse::NotFound("Recurring payment not found".to_string())
+            }
+            error => error,
+        })
+    }
+}
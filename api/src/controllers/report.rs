@@ -0,0 +1,289 @@
+//! Report endpoints.
+//!
+//! Provides [`ReportController`] with handlers for reconciling completed
+//! work against the time the user has reported, for reporting it in bulk,
+//! for weekly or monthly earnings totals, and for quarterly tax estimates
+//! and the self-employment tax rate they use. Earnings reports and tax
+//! estimates are served from the report cache.
+
+use axum::{Json, extract::State};
+use chrono::Utc;
+use gig_log_common::models::report::{
+    EarningsReportQuery, EarningsReportResponse, ReportTimeRequest, ReportTimeResponse,
+    SelfEmploymentTaxRate, TaxEstimateQuery, TaxEstimateResponse, UnreportedTimeResponse,
+    UpdateSelfEmploymentTaxRateRequest,
+};
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::core::error::ApiResult;
+use crate::extractors::{ValidatedJson, ValidatedQuery};
+use crate::repo::{report::ReportRepo, work_session::WorkSessionRepo};
+use crate::report::ReportUtil;
+use crate::report_cache::ReportCache;
+use crate::routes::app::AppState;
+
+/// Handlers for report routes.
+pub struct ReportController;
+
+impl ReportController {
+    /// Lists completed work sessions whose time has not been reported.
+    ///
+    /// Mapped to `GET /reports/unreported-time`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<UnreportedTimeResponse>`] with sessions grouped by company
+    /// and week, and the total unreported duration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sessions cannot be loaded.
+    pub async fn unreported_time(
+        auth: AuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<UnreportedTimeResponse>> {
+        let sessions = WorkSessionRepo::list_unreported(&state.db_pool, auth.user_id).await?;
+        let groups = ReportUtil::group_unreported_sessions(&sessions);
+        let total_seconds = ReportUtil::total_seconds(&groups);
+
+        Ok(Json(UnreportedTimeResponse {
+            groups,
+            total_seconds,
+        }))
+    }
+
+    /// Reports the worked time of completed work sessions.
+    ///
+    /// Mapped to `POST /reports/unreported-time/report`. Requires
+    /// authentication. Each session's reported time is set to its worked
+    /// duration. Sessions that are running, already reported, or not found
+    /// are skipped, so repeating the request is harmless.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — A [`ValidatedJson<ReportTimeRequest>`] listing the
+    ///   sessions to report.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<ReportTimeResponse>`] with the reported sessions and their
+    /// total duration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`](crate::core::error::ApiErrorResponse::Validation)
+    /// if no sessions are given.
+    pub async fn report_time(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedJson(body): ValidatedJson<ReportTimeRequest>,
+    ) -> ApiResult<Json<ReportTimeResponse>> {
+        let sessions =
+            WorkSessionRepo::report_sessions(&state.db_pool, auth.user_id, &body.work_session_ids)
+                .await?;
+
+        Ok(Json(ReportTimeResponse {
+            duration_seconds: sessions
+                .iter()
+                .map(|session| session.net_seconds.max(0))
+                .sum(),
+            work_session_ids: sessions.into_iter().map(|session| session.id).collect(),
+        }))
+    }
+
+    /// Reports the authenticated user's earnings per week or month.
+    ///
+    /// Mapped to `GET /reports/earnings`. Requires authentication. Accepts
+    /// `?period=weekly|monthly` (default monthly) and `?from=` and `?to=`
+    /// dates selecting the first and last periods; the range defaults to
+    /// the twelve periods ending today. The report is served from the
+    /// report cache, so it can lag recent changes by a few minutes.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `query` — The [`EarningsReportQuery`] choosing the period and range.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<EarningsReportResponse>`] with hours worked, amounts
+    /// invoiced and received, and tax withholdings for each period, and
+    /// when the report was generated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`](crate::core::error::ApiErrorResponse::Validation)
+    /// if `from` is after `to`, or
+    /// [`ApiErrorResponse::BadRequest`](crate::core::error::ApiErrorResponse::BadRequest)
+    /// if the range covers too many periods.
+    pub async fn earnings(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedQuery(query): ValidatedQuery<EarningsReportQuery>,
+    ) -> ApiResult<Json<EarningsReportResponse>> {
+        let (from, to) = ReportUtil::earnings_range(
+            query.period,
+            query.from,
+            query.to,
+            Utc::now().date_naive(),
+        )?;
+        let report =
+            ReportCache::earnings(&state.db_pool, auth.user_id, query.period, from, to).await?;
+
+        Ok(Json(report))
+    }
+
+    /// Estimates the authenticated user's tax for a calendar quarter.
+    ///
+    /// Mapped to `GET /reports/tax-estimate`. Requires authentication.
+    /// Accepts `?year=` and `?quarter=` (1 to 4), both defaulting to the
+    /// current quarter. Received payments dated in the quarter are taxed at
+    /// each company's withholding rate, or at the user's self-employment
+    /// rate for companies that do not withhold. The estimate is served from
+    /// the report cache, so it can lag recent changes by a few minutes.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `query` — The [`TaxEstimateQuery`] choosing the quarter.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<TaxEstimateResponse>`] with the tax owed, covered, and still
+    /// to set aside per company and in total, and when the estimate was
+    /// generated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`](crate::core::error::ApiErrorResponse::Validation)
+    /// if the quarter is not between 1 and 4, or
+    /// [`ApiErrorResponse::BadRequest`](crate::core::error::ApiErrorResponse::BadRequest)
+    /// if the year is out of range.
+    pub async fn tax_estimate(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedQuery(query): ValidatedQuery<TaxEstimateQuery>,
+    ) -> ApiResult<Json<TaxEstimateResponse>> {
+        let (start_date, end_date) =
+            ReportUtil::tax_quarter(query.year, query.quarter, Utc::now().date_naive())?;
+        let rate = Self::self_employment_rate_for(&state, auth.user_id)
+            .await?
+            .rate;
+        let estimate =
+            ReportCache::tax_estimate(&state.db_pool, auth.user_id, start_date, end_date, rate)
+                .await?;
+
+        Ok(Json(estimate))
+    }
+
+    /// Returns the self-employment tax rate applied to the authenticated
+    /// user's tax estimates.
+    ///
+    /// Mapped to `GET /reports/tax-rate`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<SelfEmploymentTaxRate>`] with the applied and default rates.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub async fn show_tax_rate(
+        auth: AuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<SelfEmploymentTaxRate>> {
+        Ok(Json(
+            Self::self_employment_rate_for(&state, auth.user_id).await?,
+        ))
+    }
+
+    /// Sets the authenticated user's own self-employment tax rate.
+    ///
+    /// Mapped to `PUT /reports/tax-rate`. Requires authentication. A
+    /// `null` rate reverts to the server-wide default.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — A [`ValidatedJson<UpdateSelfEmploymentTaxRateRequest>`]
+    ///   containing the new rate.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<SelfEmploymentTaxRate>`] with the applied and default rates.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub async fn update_tax_rate(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedJson(body): ValidatedJson<UpdateSelfEmploymentTaxRateRequest>,
+    ) -> ApiResult<Json<SelfEmploymentTaxRate>> {
+        ReportRepo::set_self_employment_rate(&state.db_pool, auth.user_id, body.rate).await?;
+
+        Ok(Json(Self::self_employment_rate(&state, body.rate)))
+    }
+
+    /// Resolves the self-employment tax rate applied to a user's
+    /// estimates.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `user_id` — The user's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The [`SelfEmploymentTaxRate`] for the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    async fn self_employment_rate_for(
+        state: &AppState,
+        user_id: Uuid,
+    ) -> ApiResult<SelfEmploymentTaxRate> {
+        let custom_rate = ReportRepo::find_self_employment_rate(&state.db_pool, user_id).await?;
+
+        Ok(Self::self_employment_rate(state, custom_rate))
+    }
+
+    /// Describes the self-employment tax rate applied given a user's own
+    /// rate.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `custom_rate` — The user's own rate, if set.
+    ///
+    /// # Returns
+    ///
+    /// A [`SelfEmploymentTaxRate`] using the user's rate if set, otherwise
+    /// [`Config::self_employment_tax_rate`](crate::core::config::Config::self_employment_tax_rate).
+    fn self_employment_rate(state: &AppState, custom_rate: Option<f64>) -> SelfEmploymentTaxRate {
+        let default_rate = state.config.self_employment_tax_rate;
+
+        SelfEmploymentTaxRate {
+            rate: custom_rate.unwrap_or(default_rate),
+            default_rate,
+            is_custom: custom_rate.is_some(),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Search endpoint.
+//!
+//! Provides [`SearchController`] with a handler for full-text search across
+//! the authenticated user's companies and jobs.
+
+use axum::{Json, extract::State};
+use gig_log_common::models::search::{SearchQuery, SearchResponse};
+
+use crate::auth::AuthUser;
+use crate::core::error::ApiResult;
+use crate::extractors::ValidatedQuery;
+use crate::repo::search::SearchRepo;
+use crate::routes::app::AppState;
+
+/// Results returned per group when no limit is given.
+const DEFAULT_SEARCH_LIMIT: i64 = 10;
+
+/// Handler for search routes.
+pub struct SearchController;
+
+impl SearchController {
+    /// Searches the authenticated user's company names and job titles.
+    ///
+    /// Mapped to `GET /search`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `query` — The [`SearchQuery`] with the search terms and limit.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<SearchResponse>`] with matching companies and jobs grouped
+    /// separately, each most relevant first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`](crate::core::error::ApiErrorResponse::Validation)
+    /// if the terms or limit are out of range, or an
+    /// [`ApiErrorResponse`](crate::core::error::ApiErrorResponse) if a
+    /// database query fails.
+    pub async fn search(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedQuery(query): ValidatedQuery<SearchQuery>,
+    ) -> ApiResult<Json<SearchResponse>> {
+        let limit = query.limit.unwrap_or(DEFAULT_SEARCH_LIMIT);
+        let companies =
+            SearchRepo::search_companies(&state.db_pool, auth.user_id, &query.q, limit).await?;
+        let jobs = SearchRepo::search_jobs(&state.db_pool, auth.user_id, &query.q, limit).await?;
+
+        Ok(Json(SearchResponse { companies, jobs }))
+    }
+}
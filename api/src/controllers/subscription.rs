@@ -0,0 +1,225 @@
+//! Subscription endpoints.
+//!
+//! Provides [`SubscriptionController`] with handlers for reading the
+//! authenticated user's subscription, starting a Stripe Checkout session to
+//! upgrade, and receiving the Stripe webhooks that keep the subscription's
+//! status current.
+
+use axum::{Json, body::Bytes, extract::State, http::HeaderMap};
+use chrono::{DateTime, Utc};
+use gig_log_common::models::{
+    generic::MessageResponse,
+    subscription::{
+        CheckoutSessionResponse, CreateCheckoutSessionRequest, Subscription, SubscriptionPlan,
+    },
+};
+use log::{info, warn};
+
+use crate::auth::{AuthUser, link::LinkUtil};
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::repo::{
+    subscription::{SubscriptionRepo, SubscriptionSync},
+    user::UserRepo,
+};
+use crate::routes::app::AppState;
+use crate::subscription::stripe::{StripeClient, StripeEvent, StripeSubscription, StripeWebhook};
+
+/// Handlers for subscription routes.
+pub struct SubscriptionController;
+
+impl SubscriptionController {
+    /// Returns the authenticated user's subscription.
+    ///
+    /// Mapped to `GET /subscriptions/me`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Subscription>`], which is on the free plan if the user never
+    /// subscribed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn show(
+        auth: AuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<Subscription>> {
+        let subscription =
+            SubscriptionRepo::find_subscription(&state.db_pool, auth.user_id).await?;
+
+        Ok(Json(subscription))
+    }
+
+    /// Starts a Stripe Checkout session for upgrading to a paid plan.
+    ///
+    /// Mapped to `POST /subscriptions/checkout`. Requires authentication.
+    /// Creates the user's Stripe customer on first use.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — The [`CreateCheckoutSessionRequest`] naming the plan.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<CheckoutSessionResponse>`] with the checkout page URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if subscriptions are disabled,
+    /// [`ApiErrorResponse::BadRequest`] if the plan is free or the user is
+    /// already on it, or an [`ApiErrorResponse`] if a database query or
+    /// Stripe request fails.
+    pub async fn checkout(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Json(body): Json<CreateCheckoutSessionRequest>,
+    ) -> ApiResult<Json<CheckoutSessionResponse>> {
+        let (Some(secret_key), Some(price_id)) = (
+            state.config.stripe_secret_key.as_deref(),
+            state.config.stripe_pro_price_id.as_deref(),
+        ) else {
+            return Err(ApiErrorResponse::NotFound("Resource not found".to_string()));
+        };
+
+        if body.plan == SubscriptionPlan::Free {
+            return Err(ApiErrorResponse::BadRequest(
+                "Choose a paid plan to check out".to_string(),
+            ));
+        }
+
+        let subscription =
+            SubscriptionRepo::find_subscription(&state.db_pool, auth.user_id).await?;
+
+        if subscription.effective_plan() == body.plan {
+            return Err(ApiErrorResponse::BadRequest(
+                "You are already subscribed to this plan".to_string(),
+            ));
+        }
+
+        let stripe = StripeClient::new(secret_key);
+        let customer_id =
+            match SubscriptionRepo::find_customer_id(&state.db_pool, auth.user_id).await? {
+                Some(customer_id) => customer_id,
+                None => {
+                    let user = UserRepo::find_user_by_id(&state.db_pool, auth.user_id).await?;
+                    let customer_id = stripe.create_customer(auth.user_id, &user.email).await?;
+
+                    SubscriptionRepo::insert_customer(&state.db_pool, auth.user_id, &customer_id)
+                        .await?
+                }
+            };
+
+        let url = stripe
+            .create_checkout_session(
+                &customer_id,
+                price_id,
+                &LinkUtil::web_url("/settings/billing?checkout=success", &state.config),
+                &LinkUtil::web_url("/settings/billing?checkout=canceled", &state.config),
+            )
+            .await?;
+
+        Ok(Json(CheckoutSessionResponse { url }))
+    }
+
+    /// Receives a Stripe webhook event.
+    ///
+    /// Mapped to `POST /subscriptions/webhook`. Authenticated by the
+    /// `Stripe-Signature` header, which must be signed with
+    /// [`Config::stripe_webhook_secret`](crate::core::config::Config::stripe_webhook_secret).
+    /// `customer.subscription.*` events update the customer's subscription;
+    /// other events and redeliveries are acknowledged and ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `headers` — The request headers carrying the signature.
+    /// * `body` — The raw event payload.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MessageResponse>`] describing how the event was handled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the webhook is disabled,
+    /// [`ApiErrorResponse::Unauthorized`] if the signature is invalid,
+    /// [`ApiErrorResponse::BadRequest`] if the payload is malformed, or an
+    /// [`ApiErrorResponse`] if a database query fails.
+    pub async fn webhook(
+        State(state): State<AppState>,
+        headers: HeaderMap,
+        body: Bytes,
+    ) -> ApiResult<Json<MessageResponse>> {
+        let secret = state
+            .config
+            .stripe_webhook_secret
+            .as_deref()
+            .ok_or_else(|| ApiErrorResponse::NotFound("Resource not found".to_string()))?;
+        let signature = headers
+            .get("stripe-signature")
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+
+        if !StripeWebhook::verify_signature(&body, signature, secret, Utc::now().timestamp()) {
+            return Err(ApiErrorResponse::Unauthorized(
+                "Invalid webhook signature".to_string(),
+            ));
+        }
+
+        let invalid_payload =
+            |error: serde_json::Error| ApiErrorResponse::BadRequest(error.to_string());
+        let event: StripeEvent = serde_json::from_slice(&body).map_err(invalid_payload)?;
+        let ignored = |reason: &str| {
+            info!("Ignoring Stripe event {}: {}", event.id, reason);
+            Ok(Json(MessageResponse {
+                message: "Event ignored.".to_string(),
+            }))
+        };
+
+        if !event.event_type.starts_with("customer.subscription.") {
+            return ignored("unhandled event type");
+        }
+
+        let stripe_subscription: StripeSubscription =
+            serde_json::from_value(event.data.object.clone()).map_err(invalid_payload)?;
+        let plan = match state.config.stripe_pro_price_id.as_deref() {
+            Some(price_id) if stripe_subscription.has_price(price_id) => SubscriptionPlan::Pro,
+            _ => SubscriptionPlan::Free,
+        };
+        let sync = SubscriptionSync {
+            stripe_customer_id: &stripe_subscription.customer,
+            stripe_subscription_id: &stripe_subscription.id,
+            plan,
+            status: stripe_subscription.status,
+            current_period_end: stripe_subscription.current_period_end(),
+            cancel_at_period_end: stripe_subscription.cancel_at_period_end,
+            event_at: DateTime::from_timestamp(event.created, 0).unwrap_or_else(Utc::now),
+        };
+
+        let mut tx = state.db_pool.begin().await?;
+
+        if !SubscriptionRepo::record_webhook_event(&mut *tx, &event.id, &event.event_type).await? {
+            return ignored("event was already processed");
+        }
+
+        if !SubscriptionRepo::sync_subscription(&mut *tx, &sync).await? {
+            warn!(
+                "Stripe event {} matched no current subscription for customer {}",
+                event.id, sync.stripe_customer_id
+            );
+        }
+
+        tx.commit().await?;
+
+        Ok(Json(MessageResponse {
+            message: "Subscription updated.".to_string(),
+        }))
+    }
+}
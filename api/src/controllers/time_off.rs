@@ -0,0 +1,157 @@
+//! Time-off and availability endpoints.
+//!
+//! Provides [`TimeOffController`] with handlers for scheduling planned time
+//! off and viewing weekly availability.
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+};
+use chrono::Utc;
+use gig_log_common::models::{
+    generic::MessageResponse,
+    time_off::{
+        CreateTimeOffRequest, TimeOff, TimeOffRangeQuery, WeekAvailability, WeekAvailabilityQuery,
+    },
+};
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::availability::AvailabilityUtil;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::extractors::{ValidatedJson, ValidatedQuery};
+use crate::repo::time_off::TimeOffRepo;
+use crate::routes::app::AppState;
+
+/// Handlers for time-off routes.
+pub struct TimeOffController;
+
+impl TimeOffController {
+    /// Schedules a period of time off.
+    ///
+    /// Mapped to `POST /time-off`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — A [`ValidatedJson<CreateTimeOffRequest>`] containing the
+    ///   dates and an optional note.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<TimeOff>`] containing the created entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database insert fails.
+    pub async fn create(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedJson(body): ValidatedJson<CreateTimeOffRequest>,
+    ) -> ApiResult<Json<TimeOff>> {
+        let time_off = TimeOffRepo::insert_time_off(&state.db_pool, auth.user_id, body).await?;
+
+        Ok(Json(time_off))
+    }
+
+    /// Lists the authenticated user's time off.
+    ///
+    /// Mapped to `GET /time-off`. Requires authentication. Accepts optional
+    /// `from` and `to` query parameters to return only overlapping entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `query` — The [`TimeOffRangeQuery`] bounds.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Vec<TimeOff>>`] ordered by start date.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`] if `from` is after `to`, or
+    /// an [`ApiErrorResponse`] if the database query fails.
+    pub async fn list(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedQuery(query): ValidatedQuery<TimeOffRangeQuery>,
+    ) -> ApiResult<Json<Vec<TimeOff>>> {
+        let entries =
+            TimeOffRepo::list_overlapping(&state.db_pool, auth.user_id, query.from, query.to)
+                .await?;
+
+        Ok(Json(entries))
+    }
+
+    /// Returns the authenticated user's availability for a week.
+    ///
+    /// Mapped to `GET /time-off/week`. Requires authentication. The week runs
+    /// Monday to Sunday and contains the `date` query parameter, defaulting
+    /// to the current week.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `query` — The [`WeekAvailabilityQuery`] selecting the week.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<WeekAvailability>`] with per-day time-off flags.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn week(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Query(query): Query<WeekAvailabilityQuery>,
+    ) -> ApiResult<Json<WeekAvailability>> {
+        let date = query.date.unwrap_or_else(|| Utc::now().date_naive());
+        let (week_start, week_end) = AvailabilityUtil::week_bounds(date);
+        let entries = TimeOffRepo::list_overlapping(
+            &state.db_pool,
+            auth.user_id,
+            Some(week_start),
+            Some(week_end),
+        )
+        .await?;
+
+        Ok(Json(AvailabilityUtil::build_week(date, entries)))
+    }
+
+    /// Removes one of the authenticated user's time-off entries.
+    ///
+    /// Mapped to `DELETE /time-off/{id}`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `time_off_id` — The entry's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MessageResponse>`] confirming the deletion.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the entry does not exist or
+    /// belongs to another user.
+    pub async fn delete(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(time_off_id): Path<Uuid>,
+    ) -> ApiResult<Json<MessageResponse>> {
+        if !TimeOffRepo::delete_time_off(&state.db_pool, auth.user_id, time_off_id).await? {
+            return Err(ApiErrorResponse::NotFound("Time off not found".to_string()));
+        }
+
+        Ok(Json(MessageResponse {
+            message: "Time off deleted.".to_string(),
+        }))
+    }
+}
@@ -0,0 +1,256 @@
+//! Timesheet sharing and client approval endpoints.
+//!
+//! Provides [`TimesheetController`] with handlers for creating shareable
+//! timesheet approval links and for the unauthenticated client-facing
+//! review flow.
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use chrono::{Duration, Utc};
+use gig_log_common::models::timesheet::{
+    ApproveTimesheetRequest, CreateTimesheetApprovalRequest, DisputeTimesheetRequest,
+    SharedTimesheetResponse, TimesheetApproval, TimesheetApprovalLinkResponse,
+};
+use log::error;
+
+use crate::auth::{AuthUser, token};
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::extractors::ValidatedJson;
+use crate::repo::{
+    company::CompanyRepo,
+    timesheet_approval::{TimesheetApprovalRecord, TimesheetApprovalRepo},
+};
+use crate::routes::app::AppState;
+
+/// Number of days a shared timesheet link stays valid.
+const APPROVAL_LINK_EXPIRY_DAYS: i64 = 14;
+
+/// Handlers for timesheet approval routes.
+pub struct TimesheetController;
+
+impl TimesheetController {
+    /// Creates a shareable approval link for a period of a company's hours.
+    ///
+    /// Mapped to `POST /timesheets/approvals`. Requires authentication.
+    /// Generates a random share token, stores only its hash, and returns
+    /// the raw token once so it can be embedded in a link for the client.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — A [`ValidatedJson<CreateTimesheetApprovalRequest>`]
+    ///   containing the company and reviewed period.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<TimesheetApprovalLinkResponse>`] with the approval request
+    /// and its raw share token.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the company does not exist
+    /// or belongs to another user.
+    pub async fn create_approval(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedJson(body): ValidatedJson<CreateTimesheetApprovalRequest>,
+    ) -> ApiResult<Json<TimesheetApprovalLinkResponse>> {
+        CompanyRepo::find_company_by_id(&state.db_pool, auth.user_id, body.company_id).await?;
+
+        let share_token = token::generate();
+        let expires_at = Utc::now() + Duration::days(APPROVAL_LINK_EXPIRY_DAYS);
+
+        let approval = TimesheetApprovalRepo::insert_approval(
+            &state.db_pool,
+            auth.user_id,
+            body.company_id,
+            body.period_start,
+            body.period_end,
+            &token::hash(&share_token),
+            expires_at,
+        )
+        .await?;
+
+        Ok(Json(TimesheetApprovalLinkResponse {
+            approval,
+            token: share_token,
+        }))
+    }
+
+    /// Lists the authenticated user's timesheet approval requests.
+    ///
+    /// Mapped to `GET /timesheets/approvals`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Vec<TimesheetApproval>>`] ordered newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn list_approvals(
+        auth: AuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<Vec<TimesheetApproval>>> {
+        let approvals = TimesheetApprovalRepo::list_for_user(&state.db_pool, auth.user_id).await?;
+
+        Ok(Json(approvals))
+    }
+
+    /// Returns a shared timesheet for client review.
+    ///
+    /// Mapped to `GET /timesheets/shared/{token}`. Does not require
+    /// authentication; access is granted by the share token.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `share_token` — The raw share token from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<SharedTimesheetResponse>`] listing the period's sessions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the token is unknown or
+    /// expired.
+    pub async fn view_shared(
+        State(state): State<AppState>,
+        Path(share_token): Path<String>,
+    ) -> ApiResult<Json<SharedTimesheetResponse>> {
+        let approval = Self::find_shared(&state, &share_token).await?;
+        let company =
+            CompanyRepo::find_company_by_id(&state.db_pool, approval.user_id, approval.company_id)
+                .await?;
+        let entries = TimesheetApprovalRepo::list_entries(&state.db_pool, &approval).await?;
+        let total_seconds = entries.iter().map(|entry| entry.net_seconds.max(0)).sum();
+        let version = TimesheetApprovalRepo::version(&entries);
+
+        Ok(Json(SharedTimesheetResponse {
+            company_name: company.name,
+            period_start: approval.period_start,
+            period_end: approval.period_end,
+            status: approval.status.into(),
+            entries,
+            total_seconds,
+            version,
+        }))
+    }
+
+    /// Approves a shared timesheet on behalf of the client.
+    ///
+    /// Mapped to `POST /timesheets/shared/{token}/approve`. Does not
+    /// require authentication. Records the approver's email and timestamp
+    /// and locks the included sessions against further edits. The request
+    /// must carry the `version` from the shared view, so sessions logged or
+    /// edited after the client loaded the timesheet are never approved
+    /// unseen.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `share_token` — The raw share token from the request path.
+    /// * `body` — A [`ValidatedJson<ApproveTimesheetRequest>`] containing
+    ///   the approver's email and the reviewed version.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<TimesheetApproval>`] with the recorded approval.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the token is unknown or
+    /// expired. Returns [`ApiErrorResponse::BadRequest`] if the timesheet
+    /// was already reviewed or has changed since it was loaded.
+    pub async fn approve_shared(
+        State(state): State<AppState>,
+        Path(share_token): Path<String>,
+        ValidatedJson(body): ValidatedJson<ApproveTimesheetRequest>,
+    ) -> ApiResult<Json<TimesheetApproval>> {
+        let approval = Self::find_shared(&state, &share_token).await?;
+        let approval = TimesheetApprovalRepo::approve(
+            &state.db_pool,
+            &approval,
+            &body.approver_email,
+            &body.version,
+        )
+        .await?;
+
+        Ok(Json(approval))
+    }
+
+    /// Disputes a shared timesheet on behalf of the client.
+    ///
+    /// Mapped to `POST /timesheets/shared/{token}/dispute`. Does not
+    /// require authentication. Records the client's email, reason, and
+    /// timestamp; the included sessions remain editable.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `share_token` — The raw share token from the request path.
+    /// * `body` — A [`ValidatedJson<DisputeTimesheetRequest>`] containing
+    ///   the client's email and dispute reason.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<TimesheetApproval>`] with the recorded dispute.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the token is unknown or
+    /// expired. Returns [`ApiErrorResponse::BadRequest`] if the timesheet
+    /// was already reviewed.
+    pub async fn dispute_shared(
+        State(state): State<AppState>,
+        Path(share_token): Path<String>,
+        ValidatedJson(body): ValidatedJson<DisputeTimesheetRequest>,
+    ) -> ApiResult<Json<TimesheetApproval>> {
+        let approval = Self::find_shared(&state, &share_token).await?;
+        let approval = TimesheetApprovalRepo::dispute(
+            &state.db_pool,
+            approval.id,
+            &body.approver_email,
+            &body.reason,
+        )
+        .await?;
+
+        Ok(Json(approval))
+    }
+
+    /// Resolves a raw share token to its approval request.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `share_token` — The raw share token.
+    ///
+    /// # Returns
+    ///
+    /// The matching [`TimesheetApprovalRecord`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the token is unknown or
+    /// expired.
+    async fn find_shared(
+        state: &AppState,
+        share_token: &str,
+    ) -> ApiResult<TimesheetApprovalRecord> {
+        TimesheetApprovalRepo::find_by_token_hash(&state.db_pool, &token::hash(share_token))
+            .await
+            .map_err(|error| {
+                error!("Failed to resolve timesheet share token: {:?}", error);
+                ApiErrorResponse::NotFound("Timesheet not found".to_string())
+            })
+    }
+}
@@ -0,0 +1,368 @@
+//! Toggl Track API compatibility endpoints.
+//!
+//! Provides [`TogglController`] with a subset of the Toggl Track API v9
+//! time entry endpoints, backed by work sessions, so external time
+//! tracking tools can read and write entries. Jobs are exposed as projects
+//! within a single workspace.
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+};
+use chrono::{Duration, Utc};
+use gig_log_common::models::live_sync::LiveSyncMessage;
+use gig_log_common::models::toggl::{
+    CreateTogglTimeEntryRequest, TogglProject, TogglTimeEntriesQuery, TogglTimeEntry,
+};
+use gig_log_common::models::webhook::WebhookEvent;
+
+use crate::auth::basic::BasicAuthUser;
+use crate::controllers::work_session::WorkSessionController;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::extractors::ValidatedJson;
+use crate::repo::{job::JobRepo, work_session::WorkSessionRepo};
+use crate::routes::app::AppState;
+use crate::toggl::{TOGGL_WORKSPACE_ID, TogglUtil};
+use crate::webhook::WebhookDispatcher;
+
+/// Number of days listed when no `start_date` is given, matching Toggl.
+const DEFAULT_ENTRY_WINDOW_DAYS: i64 = 9;
+
+/// Handlers for Toggl-compatible routes.
+pub struct TogglController;
+
+impl TogglController {
+    /// Lists the authenticated user's time entries.
+    ///
+    /// Mapped to `GET /api/v9/me/time_entries`. Requires authentication.
+    /// Accepts optional `start_date` and `end_date` bounds, defaulting to
+    /// the last nine days.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`BasicAuthUser`] extracted from the request.
+    /// * `state` — The shared [`AppState`].
+    /// * `query` — The [`TogglTimeEntriesQuery`] bounds.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Vec<TogglTimeEntry>>`] ordered newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if a bound cannot be parsed,
+    /// or an [`ApiErrorResponse`] if the database query fails.
+    pub async fn list_entries(
+        auth: BasicAuthUser,
+        State(state): State<AppState>,
+        Query(query): Query<TogglTimeEntriesQuery>,
+    ) -> ApiResult<Json<Vec<TogglTimeEntry>>> {
+        let parse = |value: Option<String>| {
+            value
+                .map(|value| {
+                    TogglUtil::parse_bound(&value).ok_or_else(|| {
+                        ApiErrorResponse::BadRequest(format!("Invalid date bound: {value}"))
+                    })
+                })
+                .transpose()
+        };
+
+        let to = parse(query.end_date)?.unwrap_or_else(|| Utc::now() + Duration::days(1));
+        let from = parse(query.start_date)?
+            .unwrap_or_else(|| Utc::now() - Duration::days(DEFAULT_ENTRY_WINDOW_DAYS));
+
+        let entries = WorkSessionRepo::list_toggl_entries(&state.db_pool, auth.user_id, from, to)
+            .await?
+            .into_iter()
+            .map(TogglUtil::to_time_entry)
+            .collect();
+
+        Ok(Json(entries))
+    }
+
+    /// Returns the authenticated user's running time entry.
+    ///
+    /// Mapped to `GET /api/v9/me/time_entries/current`. Requires
+    /// authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`BasicAuthUser`] extracted from the request.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Option<TogglTimeEntry>>`] that is `null` when nothing is
+    /// running.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn current_entry(
+        auth: BasicAuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<Option<TogglTimeEntry>>> {
+        let entry = WorkSessionRepo::find_current_toggl_entry(&state.db_pool, auth.user_id)
+            .await?
+            .map(TogglUtil::to_time_entry);
+
+        Ok(Json(entry))
+    }
+
+    /// Lists the authenticated user's jobs as projects.
+    ///
+    /// Mapped to `GET /api/v9/me/projects`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`BasicAuthUser`] extracted from the request.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Vec<TogglProject>>`] ordered by name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn list_projects(
+        auth: BasicAuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<Vec<TogglProject>>> {
+        let projects =
+            JobRepo::list_toggl_projects(&state.db_pool, auth.user_id, TOGGL_WORKSPACE_ID).await?;
+
+        Ok(Json(projects))
+    }
+
+    /// Creates a time entry.
+    ///
+    /// Mapped to `POST /api/v9/workspaces/{workspace_id}/time_entries`.
+    /// Requires authentication. A negative `duration` without `stop` starts
+    /// a running entry; otherwise the entry is completed at `stop`, or at
+    /// `start` plus `duration`.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`BasicAuthUser`] extracted from the request.
+    /// * `state` — The shared [`AppState`].
+    /// * `workspace_id` — The workspace ID from the request path.
+    /// * `body` — A [`ValidatedJson<CreateTogglTimeEntryRequest>`] describing
+    ///   the entry.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<TogglTimeEntry>`] containing the created entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the workspace or project
+    /// does not exist, [`ApiErrorResponse::BadRequest`] if a completed
+    /// entry has neither `stop` nor a non-negative `duration`, or
+    /// [`ApiErrorResponse::Validation`] if a running entry is created while
+    /// another is active.
+    pub async fn create_entry(
+        auth: BasicAuthUser,
+        State(state): State<AppState>,
+        Path(workspace_id): Path<i64>,
+        ValidatedJson(body): ValidatedJson<CreateTogglTimeEntryRequest>,
+    ) -> ApiResult<Json<TogglTimeEntry>> {
+        Self::ensure_workspace(workspace_id)?;
+
+        let project_id = body.project_id.unwrap_or_default();
+        let job_id = JobRepo::find_job_id_by_toggl_id(&state.db_pool, auth.user_id, project_id)
+            .await
+            .map_err(|_| ApiErrorResponse::NotFound("Project not found".to_string()))?;
+
+        let end_time = match (body.stop, body.duration) {
+            (Some(stop), _) => Some(stop),
+            (None, Some(duration)) if duration < 0 => None,
+            (None, Some(duration)) => Some(body.start + Duration::seconds(duration)),
+            (None, None) => {
+                return Err(ApiErrorResponse::BadRequest(
+                    "Time entries need a stop time or duration".to_string(),
+                ));
+            }
+        };
+
+        let session_id = WorkSessionRepo::insert_session_with_times(
+            &state.db_pool,
+            auth.user_id,
+            job_id,
+            body.start,
+            end_time,
+        )
+        .await?;
+        let session =
+            WorkSessionRepo::find_session_by_id(&state.db_pool, auth.user_id, session_id).await?;
+        let event = if session.is_running {
+            WebhookEvent::WorkSessionStarted(session.clone())
+        } else {
+            WebhookEvent::WorkSessionCompleted(session.clone())
+        };
+        state
+            .live_sync
+            .publish(auth.user_id, LiveSyncMessage::WorkSessionUpdated(session));
+        WebhookDispatcher::publish(&state.db_pool, auth.user_id, event).await;
+        let record =
+            WorkSessionRepo::find_toggl_entry(&state.db_pool, auth.user_id, session_id).await?;
+
+        Ok(Json(TogglUtil::to_time_entry(record)))
+    }
+
+    /// Stops a running time entry.
+    ///
+    /// Mapped to
+    /// `PATCH /api/v9/workspaces/{workspace_id}/time_entries/{time_entry_id}/stop`.
+    /// Requires authentication. Subject to the same state change rate limit
+    /// as the work session endpoints.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`BasicAuthUser`] extracted from the request.
+    /// * `state` — The shared [`AppState`].
+    /// * `workspace_id` — The workspace ID from the request path.
+    /// * `time_entry_id` — The entry's numeric ID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<TogglTimeEntry>`] containing the stopped entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the workspace or entry does
+    /// not exist, [`ApiErrorResponse::BadRequest`] if the entry is already
+    /// stopped, or [`ApiErrorResponse::TooManyRequests`] if its state
+    /// changed too recently.
+    pub async fn stop_entry(
+        auth: BasicAuthUser,
+        State(state): State<AppState>,
+        Path((workspace_id, time_entry_id)): Path<(i64, i64)>,
+    ) -> ApiResult<Json<TogglTimeEntry>> {
+        Self::ensure_workspace(workspace_id)?;
+
+        let session_id = WorkSessionRepo::find_session_id_by_toggl_id(
+            &state.db_pool,
+            auth.user_id,
+            time_entry_id,
+        )
+        .await?;
+        let completed = WorkSessionRepo::complete_session(
+            &state.db_pool,
+            auth.user_id,
+            session_id,
+            WorkSessionController::min_transition_interval(),
+            None,
+            None,
+            None,
+        )
+        .await?;
+
+        let Some(session) = completed else {
+            return Err(WorkSessionController::rejected_transition(
+                &state,
+                auth.user_id,
+                session_id,
+                |session| session.is_running,
+                "Time entry is already stopped",
+            )
+            .await);
+        };
+        state.live_sync.publish(
+            auth.user_id,
+            LiveSyncMessage::WorkSessionUpdated(session.clone()),
+        );
+        WebhookDispatcher::publish(
+            &state.db_pool,
+            auth.user_id,
+            WebhookEvent::WorkSessionCompleted(session),
+        )
+        .await;
+
+        let record =
+            WorkSessionRepo::find_toggl_entry(&state.db_pool, auth.user_id, session_id).await?;
+
+        Ok(Json(TogglUtil::to_time_entry(record)))
+    }
+
+    /// Deletes a time entry.
+    ///
+    /// Mapped to
+    /// `DELETE /api/v9/workspaces/{workspace_id}/time_entries/{time_entry_id}`.
+    /// Requires authentication. Entries that are invoiced or part of a
+    /// timesheet approval cannot be deleted.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`BasicAuthUser`] extracted from the request.
+    /// * `state` — The shared [`AppState`].
+    /// * `workspace_id` — The workspace ID from the request path.
+    /// * `time_entry_id` — The entry's numeric ID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// An empty `200` response, matching Toggl.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the workspace or entry does
+    /// not exist, or [`ApiErrorResponse::BadRequest`] if the entry is
+    /// locked.
+    pub async fn delete_entry(
+        auth: BasicAuthUser,
+        State(state): State<AppState>,
+        Path((workspace_id, time_entry_id)): Path<(i64, i64)>,
+    ) -> ApiResult<()> {
+        Self::ensure_workspace(workspace_id)?;
+
+        let session_id = WorkSessionRepo::find_session_id_by_toggl_id(
+            &state.db_pool,
+            auth.user_id,
+            time_entry_id,
+        )
+        .await?;
+
+        if !WorkSessionRepo::delete_unlocked_session(&state.db_pool, auth.user_id, session_id)
+            .await?
+        {
+            return Err(ApiErrorResponse::BadRequest(
+                "Invoiced or approved time entries cannot be deleted".to_string(),
+            ));
+        }
+        state.live_sync.publish(
+            auth.user_id,
+            LiveSyncMessage::WorkSessionDeleted { id: session_id },
+        );
+        WebhookDispatcher::publish(
+            &state.db_pool,
+            auth.user_id,
+            WebhookEvent::WorkSessionDeleted { id: session_id },
+        )
+        .await;
+
+        Ok(())
+    }
+
+    /// Checks that a path workspace ID is the user's workspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` — The workspace ID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the workspace exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] for any other workspace ID.
+    fn ensure_workspace(workspace_id: i64) -> ApiResult<()> {
+        if workspace_id != TOGGL_WORKSPACE_ID {
+            return Err(ApiErrorResponse::NotFound(
+                "Workspace not found".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,254 @@
+//! Webhook endpoints.
+//!
+//! Provides [`WebhookController`] with handlers for registering, listing,
+//! deleting, and test-firing outgoing webhooks and for reading their
+//! delivery logs.
+//! These routes cannot be called with a personal access token.
+
+use std::time::Instant;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use gig_log_common::models::{
+    generic::MessageResponse,
+    webhook::{
+        CreateWebhookRequest, CreateWebhookResponse, Webhook, WebhookDelivery, WebhookTestResponse,
+    },
+};
+use uuid::Uuid;
+
+use crate::auth::{AuthUser, anomaly::AnomalyDetector, token};
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::extractors::ValidatedJson;
+use crate::repo::webhook::WebhookRepo;
+use crate::routes::app::AppState;
+use crate::webhook::{TEST_EVENT_TYPE, WebhookDispatcher};
+
+/// Prefix of webhook signing secrets.
+const SECRET_PREFIX: &str = "whsec_";
+
+/// Most recent deliveries returned by the delivery log.
+const DELIVERY_LOG_LIMIT: i64 = 100;
+
+/// Handlers for webhook routes.
+pub struct WebhookController;
+
+impl WebhookController {
+    /// Registers a webhook for the requested event types.
+    ///
+    /// Mapped to `POST /webhooks`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — A [`ValidatedJson<CreateWebhookRequest>`] containing the
+    ///   webhook's URL and event types.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<CreateWebhookResponse>`] containing the signing secret,
+    /// which is not retrievable later.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Forbidden`] if unusual activity was detected
+    /// since the user last logged in, or an [`ApiErrorResponse`] if the
+    /// database insert fails.
+    pub async fn create(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedJson(body): ValidatedJson<CreateWebhookRequest>,
+    ) -> ApiResult<Json<CreateWebhookResponse>> {
+        AnomalyDetector::require_recent_auth(&state, &auth).await?;
+
+        let mut event_types = body.event_types;
+        event_types.sort_by_key(|event_type| event_type.as_str());
+        event_types.dedup();
+
+        let secret = format!("{SECRET_PREFIX}{}", token::generate());
+        let webhook = WebhookRepo::insert_webhook(
+            &state.db_pool,
+            auth.user_id,
+            &body.url,
+            &event_types,
+            &secret,
+        )
+        .await?;
+
+        Ok(Json(CreateWebhookResponse { secret, webhook }))
+    }
+
+    /// Lists the authenticated user's webhooks.
+    ///
+    /// Mapped to `GET /webhooks`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Vec<Webhook>>`] ordered newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn list(
+        auth: AuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<Vec<Webhook>>> {
+        let webhooks = WebhookRepo::list_webhooks(&state.db_pool, auth.user_id).await?;
+
+        Ok(Json(webhooks))
+    }
+
+    /// Deletes one of the authenticated user's webhooks.
+    ///
+    /// Mapped to `DELETE /webhooks/{id}`. Requires authentication. Pending
+    /// deliveries are discarded along with the delivery log.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `webhook_id` — The webhook's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MessageResponse>`] confirming the deletion.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the webhook does not exist
+    /// or belongs to another user.
+    pub async fn delete(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(webhook_id): Path<Uuid>,
+    ) -> ApiResult<Json<MessageResponse>> {
+        if !WebhookRepo::delete_webhook(&state.db_pool, auth.user_id, webhook_id).await? {
+            return Err(ApiErrorResponse::NotFound("Webhook not found".to_string()));
+        }
+
+        Ok(Json(MessageResponse {
+            message: "Webhook deleted.".to_string(),
+        }))
+    }
+
+    /// Sends a signed test delivery to one of the authenticated user's
+    /// webhooks.
+    ///
+    /// Mapped to `POST /webhooks/{id}/test`. Requires authentication. The
+    /// delivery has the `webhook.test` event type and is sent once, without
+    /// being added to the delivery log or retried. A URL that fails or
+    /// responds with a non-`2xx` status is reported in the response body
+    /// rather than as an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `webhook_id` — The webhook's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<WebhookTestResponse>`] with the response status and
+    /// latency.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the webhook does not exist
+    /// or belongs to another user, or [`ApiErrorResponse::InternalServerError`]
+    /// if the HTTP client cannot be built.
+    pub async fn test(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(webhook_id): Path<Uuid>,
+    ) -> ApiResult<Json<WebhookTestResponse>> {
+        let target = WebhookRepo::find_target(&state.db_pool, auth.user_id, webhook_id)
+            .await
+            .map_err(|error| match error {
+                ApiErrorResponse::NotFound(_) => {
+                    ApiErrorResponse::NotFound("Webhook not found".to_string())
+                }
+                error => error,
+            })?;
+
+        let client = WebhookDispatcher::client().map_err(|_| {
+            ApiErrorResponse::InternalServerError("Failed to build webhook HTTP client".to_string())
+        })?;
+        let delivery_id = Uuid::new_v4();
+        let payload = WebhookDispatcher::test_payload(delivery_id, webhook_id);
+
+        let started = Instant::now();
+        let result = WebhookDispatcher::send(
+            &client,
+            &target.url,
+            &target.secret,
+            TEST_EVENT_TYPE,
+            delivery_id,
+            &payload,
+        )
+        .await;
+        let latency_ms = i64::try_from(started.elapsed().as_millis()).unwrap_or(i64::MAX);
+
+        Ok(Json(match result {
+            Ok(response_status) => WebhookTestResponse {
+                success: true,
+                response_status: Some(response_status),
+                latency_ms,
+                error: None,
+            },
+            Err(failure) => WebhookTestResponse {
+                success: false,
+                response_status: failure.response_status,
+                latency_ms,
+                error: Some(failure.error),
+            },
+        }))
+    }
+
+    /// Returns the delivery log of one of the authenticated user's webhooks.
+    ///
+    /// Mapped to `GET /webhooks/{id}/deliveries`. Requires authentication.
+    /// Returns the 100 most recent deliveries; finished deliveries are kept
+    /// for 30 days.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `webhook_id` — The webhook's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Vec<WebhookDelivery>>`] ordered newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the webhook does not exist
+    /// or belongs to another user.
+    pub async fn deliveries(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(webhook_id): Path<Uuid>,
+    ) -> ApiResult<Json<Vec<WebhookDelivery>>> {
+        WebhookRepo::find_webhook(&state.db_pool, auth.user_id, webhook_id)
+            .await
+            .map_err(|error| match error {
+                ApiErrorResponse::NotFound(_) => {
+                    ApiErrorResponse::NotFound("Webhook not found".to_string())
+                }
+                error => error,
+            })?;
+
+        let deliveries =
+            WebhookRepo::list_deliveries(&state.db_pool, webhook_id, DELIVERY_LOG_LIMIT).await?;
+
+        Ok(Json(deliveries))
+    }
+}
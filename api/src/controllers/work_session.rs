@@ -0,0 +1,905 @@
+//! Work session endpoints.
+//!
+//! Provides [`WorkSessionController`] with handlers for starting a session
+//! and moving it through its pause, resume, and complete states, and for
+//! logging and correcting sessions that were not timed. Completed sessions
+//! can be deleted and active ones discarded. State
+//! changes on a session are rate limited to guard against clients that
+//! repeatedly toggle pause and resume. Every state change is pushed to the
+//! user's live sync connections, and clients that cannot hold a streaming
+//! connection can long-poll for changes to the active session instead.
+//! Completed sessions can be listed with per-day totals and exported as
+//! CSV. Users who opt in have the location sent when starting and
+//! completing a session recorded on it. Sessions carry free-form notes and
+//! tags, which stay editable after the session is completed.
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    response::Response,
+};
+use chrono::{DateTime, Duration, Utc};
+use gig_log_common::models::{
+    generic::{ExportQuery, MessageResponse, PageQuery},
+    live_sync::LiveSyncMessage,
+    webhook::WebhookEvent,
+    work_session::{
+        ActiveWorkSessionResponse, CompleteWorkSessionRequest, GeoLocation, ListWorkSessionsQuery,
+        LocationTrackingSetting, StartWorkSessionRequest, UpdateWorkSessionDetailsRequest,
+        UpdateWorkSessionRequest, WaitActiveWorkSessionQuery, WorkSession, WorkSessionListResponse,
+    },
+};
+use tokio::time::{self, Instant};
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::core::export::CsvExport;
+use crate::extractors::{ValidatedJson, ValidatedQuery};
+use crate::mileage::MileageSuggester;
+use crate::repo::{
+    job::JobRepo,
+    work_session::{WorkSessionDetails, WorkSessionRepo},
+};
+use crate::routes::app::AppState;
+use crate::webhook::WebhookDispatcher;
+
+/// Minimum time between state changes on a single session, in milliseconds.
+const MIN_STATE_TRANSITION_INTERVAL_MS: i64 = 2000;
+
+/// Seconds to wait for an active session change when no timeout is given.
+const DEFAULT_WAIT_TIMEOUT_SECONDS: u64 = 30;
+
+/// Longest a client may wait for an active session change, in seconds.
+const MAX_WAIT_TIMEOUT_SECONDS: u64 = 60;
+
+/// How often the active session is re-checked while waiting, in milliseconds.
+const WAIT_POLL_INTERVAL_MS: u64 = 1000;
+
+/// Handlers for work session routes.
+pub struct WorkSessionController;
+
+impl WorkSessionController {
+    /// Starts a work session for one of the user's jobs, or logs one that
+    /// was not timed.
+    ///
+    /// Mapped to `POST /work-sessions`. Requires authentication. When the
+    /// body has a start and end time, a completed session is logged with
+    /// those times. Otherwise a timer is started, and the start location is
+    /// only recorded if the user has location tracking enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — A [`ValidatedJson<StartWorkSessionRequest>`] naming the job
+    ///   and either where the session is being started or when it happened.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<WorkSession>`] containing the running or logged session.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the job does not exist or
+    /// belongs to another user, or [`ApiErrorResponse::Validation`] if the
+    /// user already has an active session or a logged session overlaps
+    /// another session.
+    pub async fn start(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedJson(body): ValidatedJson<StartWorkSessionRequest>,
+    ) -> ApiResult<Json<WorkSession>> {
+        JobRepo::find_job_by_id(&state.db_pool, auth.user_id, body.job_id).await?;
+
+        let notes = Self::normalize_notes(body.notes);
+        let tags = Self::normalize_tags(body.tags);
+        let details = WorkSessionDetails {
+            notes: notes.as_deref(),
+            tags: &tags,
+        };
+
+        if let (Some(start_time), Some(end_time)) = (body.start_time, body.end_time) {
+            let session = WorkSessionRepo::log_session(
+                &state.db_pool,
+                auth.user_id,
+                body.job_id,
+                start_time,
+                end_time,
+                body.accumulated_paused_duration.unwrap_or_default(),
+                details,
+            )
+            .await?;
+            state.live_sync.publish(
+                auth.user_id,
+                LiveSyncMessage::WorkSessionUpdated(session.clone()),
+            );
+            WebhookDispatcher::publish(
+                &state.db_pool,
+                auth.user_id,
+                WebhookEvent::WorkSessionCompleted(session.clone()),
+            )
+            .await;
+
+            return Ok(Json(session));
+        }
+
+        let location = Self::tracked_location(&state, auth.user_id, body.location).await?;
+        let session = WorkSessionRepo::insert_session(
+            &state.db_pool,
+            auth.user_id,
+            body.job_id,
+            location,
+            details,
+        )
+        .await?;
+        state.live_sync.publish(
+            auth.user_id,
+            LiveSyncMessage::WorkSessionUpdated(session.clone()),
+        );
+        WebhookDispatcher::publish(
+            &state.db_pool,
+            auth.user_id,
+            WebhookEvent::WorkSessionStarted(session.clone()),
+        )
+        .await;
+
+        Ok(Json(session))
+    }
+
+    /// Lists the authenticated user's completed sessions across jobs.
+    ///
+    /// Mapped to `GET /work-sessions`. Requires authentication. Results can
+    /// be limited to a start date range with `?from=` and `?to=`, to a job
+    /// with `?job_id=`, or to a company with `?company_id=`, and paged with
+    /// `?page=` and `?per_page=`. Intended for timesheet views, so the
+    /// response also totals worked time per day across every page.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `query` — The [`ListWorkSessionsQuery`] filters.
+    /// * `page` — The [`PageQuery`] selecting the page.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<WorkSessionListResponse>`] with a page of sessions, oldest
+    /// first, and worked time per day.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`] if `from` is after `to` or
+    /// the page is out of range, or an [`ApiErrorResponse`] if a database
+    /// query fails.
+    pub async fn list(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedQuery(query): ValidatedQuery<ListWorkSessionsQuery>,
+        ValidatedQuery(page): ValidatedQuery<PageQuery>,
+    ) -> ApiResult<Json<WorkSessionListResponse>> {
+        let sessions =
+            WorkSessionRepo::list_completed_page(&state.db_pool, auth.user_id, &query, &page)
+                .await?;
+
+        Ok(Json(sessions))
+    }
+
+    /// Waits for the authenticated user's active work session to change.
+    ///
+    /// Mapped to `GET /work-sessions/active/wait`. Requires authentication.
+    /// The request is held open until a session is started, paused,
+    /// resumed, or completed, or until the timeout expires, whichever comes
+    /// first. Intended as a fallback for clients that cannot use a
+    /// streaming connection.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `query` — The [`WaitActiveWorkSessionQuery`] with the timeout.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<ActiveWorkSessionResponse>`] containing the current active
+    /// session and whether it changed while waiting.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if loading the active session fails.
+    pub async fn wait_for_active(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Query(query): Query<WaitActiveWorkSessionQuery>,
+    ) -> ApiResult<Json<ActiveWorkSessionResponse>> {
+        let timeout = time::Duration::from_secs(
+            query
+                .timeout
+                .unwrap_or(DEFAULT_WAIT_TIMEOUT_SECONDS)
+                .min(MAX_WAIT_TIMEOUT_SECONDS),
+        );
+        let deadline = Instant::now() + timeout;
+        let poll_interval = time::Duration::from_millis(WAIT_POLL_INTERVAL_MS);
+
+        let initial = WorkSessionRepo::find_active_session(&state.db_pool, auth.user_id).await?;
+        let initial_version = Self::session_version(initial.as_ref());
+
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+
+            if remaining.is_zero() {
+                return Ok(Json(ActiveWorkSessionResponse {
+                    session: initial,
+                    changed: false,
+                }));
+            }
+
+            time::sleep(remaining.min(poll_interval)).await;
+
+            let current =
+                WorkSessionRepo::find_active_session(&state.db_pool, auth.user_id).await?;
+
+            if Self::session_version(current.as_ref()) != initial_version {
+                return Ok(Json(ActiveWorkSessionResponse {
+                    session: current,
+                    changed: true,
+                }));
+            }
+        }
+    }
+
+    /// Exports the authenticated user's completed work sessions as CSV.
+    ///
+    /// Mapped to `GET /work-sessions/export.csv`. Requires authentication. Accepts
+    /// `?from=` and `?to=` to limit the export to an inclusive range of
+    /// start dates. Rows are streamed as they are read, oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `query` — The [`ExportQuery`] selecting the date range.
+    ///
+    /// # Returns
+    ///
+    /// A [`Response`] streaming a `work-sessions.csv` attachment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`] if `from` is after `to`.
+    pub async fn export(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        ValidatedQuery(query): ValidatedQuery<ExportQuery>,
+    ) -> ApiResult<Response> {
+        let ExportQuery { from, to } = query;
+        let pool = state.db_pool.clone();
+
+        Ok(CsvExport::response(
+            "work-sessions.csv",
+            move |writer| async move {
+                writer
+                    .write_all(WorkSessionRepo::stream_export(
+                        &pool,
+                        auth.user_id,
+                        from,
+                        to,
+                    ))
+                    .await
+            },
+        ))
+    }
+
+    /// Returns one of the authenticated user's work sessions.
+    ///
+    /// Mapped to `GET /work-sessions/{id}`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `session_id` — The session's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<WorkSession>`] containing the session.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the session does not exist
+    /// or belongs to another user.
+    pub async fn show(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(session_id): Path<Uuid>,
+    ) -> ApiResult<Json<WorkSession>> {
+        let session =
+            WorkSessionRepo::find_session_by_id(&state.db_pool, auth.user_id, session_id).await?;
+
+        Ok(Json(session))
+    }
+
+    /// Pauses an active work session.
+    ///
+    /// Mapped to `POST /work-sessions/{id}/pause`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `session_id` — The session's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<WorkSession>`] containing the paused session.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the session does not exist,
+    /// [`ApiErrorResponse::BadRequest`] if it is not active, or
+    /// [`ApiErrorResponse::TooManyRequests`] if its state changed too
+    /// recently.
+    pub async fn pause(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(session_id): Path<Uuid>,
+    ) -> ApiResult<Json<WorkSession>> {
+        let paused = WorkSessionRepo::pause_session(
+            &state.db_pool,
+            auth.user_id,
+            session_id,
+            Self::min_transition_interval(),
+        )
+        .await?;
+
+        match paused {
+            Some(session) => {
+                state.live_sync.publish(
+                    auth.user_id,
+                    LiveSyncMessage::WorkSessionUpdated(session.clone()),
+                );
+
+                Ok(Json(session))
+            }
+            None => Err(Self::rejected_transition(
+                &state,
+                auth.user_id,
+                session_id,
+                |session| session.is_running && session.paused_at.is_none(),
+                "Work session is not active",
+            )
+            .await),
+        }
+    }
+
+    /// Resumes a paused work session.
+    ///
+    /// Mapped to `POST /work-sessions/{id}/resume`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `session_id` — The session's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<WorkSession>`] containing the resumed session.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the session does not exist,
+    /// [`ApiErrorResponse::BadRequest`] if it is not paused, or
+    /// [`ApiErrorResponse::TooManyRequests`] if its state changed too
+    /// recently.
+    pub async fn resume(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(session_id): Path<Uuid>,
+    ) -> ApiResult<Json<WorkSession>> {
+        let resumed = WorkSessionRepo::resume_session(
+            &state.db_pool,
+            auth.user_id,
+            session_id,
+            Self::min_transition_interval(),
+        )
+        .await?;
+
+        match resumed {
+            Some(session) => {
+                state.live_sync.publish(
+                    auth.user_id,
+                    LiveSyncMessage::WorkSessionUpdated(session.clone()),
+                );
+
+                Ok(Json(session))
+            }
+            None => Err(Self::rejected_transition(
+                &state,
+                auth.user_id,
+                session_id,
+                |session| session.is_running && session.paused_at.is_some(),
+                "Work session is not paused",
+            )
+            .await),
+        }
+    }
+
+    /// Completes an active or paused work session.
+    ///
+    /// Mapped to `POST /work-sessions/{id}/complete`. Requires
+    /// authentication. The request body is optional; the end location is
+    /// only recorded if the user has location tracking enabled. Sessions
+    /// started away from the user's home base get a mileage suggestion.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `session_id` — The session's UUID from the request path.
+    /// * `body` — An optional [`ValidatedJson<CompleteWorkSessionRequest>`]
+    ///   with where the session is being completed and its final notes and
+    ///   tags.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<WorkSession>`] containing the completed session.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the session does not exist,
+    /// [`ApiErrorResponse::BadRequest`] if it is already completed, or
+    /// [`ApiErrorResponse::TooManyRequests`] if its state changed too
+    /// recently.
+    pub async fn complete(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(session_id): Path<Uuid>,
+        body: Option<ValidatedJson<CompleteWorkSessionRequest>>,
+    ) -> ApiResult<Json<WorkSession>> {
+        let body = body.map(ValidatedJson::into_inner).unwrap_or_default();
+        let location = Self::tracked_location(&state, auth.user_id, body.location).await?;
+        let notes = body.notes.map(|notes| Self::normalize_notes(Some(notes)));
+        let tags = body.tags.map(Self::normalize_tags);
+        let completed = WorkSessionRepo::complete_session(
+            &state.db_pool,
+            auth.user_id,
+            session_id,
+            Self::min_transition_interval(),
+            location,
+            notes.as_ref().map(Option::as_deref),
+            tags.as_deref(),
+        )
+        .await?;
+
+        match completed {
+            Some(session) => {
+                state.live_sync.publish(
+                    auth.user_id,
+                    LiveSyncMessage::WorkSessionUpdated(session.clone()),
+                );
+                WebhookDispatcher::publish(
+                    &state.db_pool,
+                    auth.user_id,
+                    WebhookEvent::WorkSessionCompleted(session.clone()),
+                )
+                .await;
+                MileageSuggester::suggest(&state, &session).await;
+
+                Ok(Json(session))
+            }
+            None => Err(Self::rejected_transition(
+                &state,
+                auth.user_id,
+                session_id,
+                |session| session.is_running,
+                "Work session is already completed",
+            )
+            .await),
+        }
+    }
+
+    /// Corrects the times of one of the authenticated user's completed
+    /// sessions.
+    ///
+    /// Mapped to `PUT /work-sessions/{id}`. Requires authentication.
+    /// Sessions that are still in progress, invoiced, or locked by a
+    /// timesheet approval cannot be edited.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `session_id` — The work session's UUID from the request path.
+    /// * `body` — A [`ValidatedJson<UpdateWorkSessionRequest>`] with the
+    ///   corrected start time, end time, and paused duration.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<WorkSession>`] containing the updated session.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the session does not exist
+    /// or belongs to another user, [`ApiErrorResponse::BadRequest`] if it
+    /// cannot be edited, or [`ApiErrorResponse::Validation`] if the times
+    /// are invalid or overlap another session.
+    pub async fn update(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(session_id): Path<Uuid>,
+        ValidatedJson(body): ValidatedJson<UpdateWorkSessionRequest>,
+    ) -> ApiResult<Json<WorkSession>> {
+        let session = WorkSessionRepo::update_session_times(
+            &state.db_pool,
+            auth.user_id,
+            session_id,
+            body.start_time,
+            body.end_time,
+            body.accumulated_paused_duration,
+        )
+        .await?;
+        state.live_sync.publish(
+            auth.user_id,
+            LiveSyncMessage::WorkSessionUpdated(session.clone()),
+        );
+
+        Ok(Json(session))
+    }
+
+    /// Deletes one of the authenticated user's completed sessions.
+    ///
+    /// Mapped to `DELETE /work-sessions/{id}`. Requires authentication.
+    /// Sessions that are still active must be discarded instead, and
+    /// invoiced sessions or sessions locked by a timesheet approval cannot
+    /// be deleted.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `session_id` — The work session's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MessageResponse>`] confirming the deletion.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the session does not exist
+    /// or belongs to another user, or [`ApiErrorResponse::BadRequest`] if it
+    /// cannot be deleted.
+    pub async fn delete(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(session_id): Path<Uuid>,
+    ) -> ApiResult<Json<MessageResponse>> {
+        WorkSessionRepo::delete_completed_session(&state.db_pool, auth.user_id, session_id).await?;
+        Self::publish_deleted(&state, auth.user_id, session_id).await;
+
+        Ok(Json(MessageResponse {
+            message: "Work session deleted.".to_string(),
+        }))
+    }
+
+    /// Discards one of the authenticated user's running or paused
+    /// sessions.
+    ///
+    /// Mapped to `POST /work-sessions/{id}/discard`. Requires
+    /// authentication. Removes a session that was started by accident
+    /// without recording any of its time.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `session_id` — The work session's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MessageResponse>`] confirming the session was discarded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the session does not exist
+    /// or belongs to another user, or [`ApiErrorResponse::BadRequest`] if it
+    /// is already completed.
+    pub async fn discard(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(session_id): Path<Uuid>,
+    ) -> ApiResult<Json<MessageResponse>> {
+        WorkSessionRepo::discard_active_session(&state.db_pool, auth.user_id, session_id).await?;
+        Self::publish_deleted(&state, auth.user_id, session_id).await;
+
+        Ok(Json(MessageResponse {
+            message: "Work session discarded.".to_string(),
+        }))
+    }
+
+    /// Updates the notes and tags of one of the authenticated user's
+    /// sessions.
+    ///
+    /// Mapped to `PATCH /work-sessions/{id}`. Requires authentication. Works
+    /// on sessions in any state, including invoiced and approved ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `session_id` — The work session's UUID from the request path.
+    /// * `body` — A [`ValidatedJson<UpdateWorkSessionDetailsRequest>`] with
+    ///   the fields to change.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<WorkSession>`] containing the updated session.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the session does not exist
+    /// or belongs to another user.
+    pub async fn update_details(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(session_id): Path<Uuid>,
+        ValidatedJson(body): ValidatedJson<UpdateWorkSessionDetailsRequest>,
+    ) -> ApiResult<Json<WorkSession>> {
+        let notes = body.notes.map(Self::normalize_notes);
+        let tags = body.tags.map(Self::normalize_tags);
+        let session = WorkSessionRepo::update_session_details(
+            &state.db_pool,
+            auth.user_id,
+            session_id,
+            notes.as_ref().map(Option::as_deref),
+            tags.as_deref(),
+        )
+        .await?;
+        state.live_sync.publish(
+            auth.user_id,
+            LiveSyncMessage::WorkSessionUpdated(session.clone()),
+        );
+
+        Ok(Json(session))
+    }
+
+    /// Returns whether the authenticated user records session locations.
+    ///
+    /// Mapped to `GET /work-sessions/location-tracking`. Requires
+    /// authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<LocationTrackingSetting>`] with the current preference.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn show_location_tracking(
+        auth: AuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<LocationTrackingSetting>> {
+        let enabled = WorkSessionRepo::find_location_tracking(&state.db_pool, auth.user_id).await?;
+
+        Ok(Json(LocationTrackingSetting { enabled }))
+    }
+
+    /// Turns location recording on the authenticated user's sessions on or
+    /// off.
+    ///
+    /// Mapped to `PUT /work-sessions/location-tracking`. Requires
+    /// authentication. Turning it off stops recording new locations;
+    /// locations already recorded are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `body` — A [`Json<LocationTrackingSetting>`] with the new
+    ///   preference.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<LocationTrackingSetting>`] with the saved preference.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database update fails.
+    pub async fn update_location_tracking(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Json(body): Json<LocationTrackingSetting>,
+    ) -> ApiResult<Json<LocationTrackingSetting>> {
+        WorkSessionRepo::set_location_tracking(&state.db_pool, auth.user_id, body.enabled).await?;
+
+        Ok(Json(body))
+    }
+
+    /// Drops a reported location unless the user records session locations.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `user_id` — The user's UUID.
+    /// * `location` — The location sent by the client, if any.
+    ///
+    /// # Returns
+    ///
+    /// The location to store, or `None` if none was sent or tracking is
+    /// off.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    async fn tracked_location(
+        state: &AppState,
+        user_id: Uuid,
+        location: Option<GeoLocation>,
+    ) -> ApiResult<Option<GeoLocation>> {
+        let Some(location) = location else {
+            return Ok(None);
+        };
+
+        let enabled = WorkSessionRepo::find_location_tracking(&state.db_pool, user_id).await?;
+
+        Ok(enabled.then_some(location))
+    }
+
+    /// Tells the user's live sync connections and webhooks that a session
+    /// was removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `user_id` — The UUID of the user who owned the session.
+    /// * `session_id` — The removed session's UUID.
+    async fn publish_deleted(state: &AppState, user_id: Uuid, session_id: Uuid) {
+        state.live_sync.publish(
+            user_id,
+            LiveSyncMessage::WorkSessionDeleted { id: session_id },
+        );
+        WebhookDispatcher::publish(
+            &state.db_pool,
+            user_id,
+            WebhookEvent::WorkSessionDeleted { id: session_id },
+        )
+        .await;
+    }
+
+    /// Trims session notes, dropping them if they are blank.
+    ///
+    /// # Arguments
+    ///
+    /// * `notes` — The notes sent by the client.
+    ///
+    /// # Returns
+    ///
+    /// The trimmed notes, or `None` if none were sent or they were blank.
+    fn normalize_notes(notes: Option<String>) -> Option<String> {
+        notes
+            .map(|notes| notes.trim().to_string())
+            .filter(|notes| !notes.is_empty())
+    }
+
+    /// Trims and lowercases session tags and removes duplicates.
+    ///
+    /// # Arguments
+    ///
+    /// * `tags` — The tags sent by the client.
+    ///
+    /// # Returns
+    ///
+    /// The normalized tags in the order they were first sent.
+    fn normalize_tags(tags: Vec<String>) -> Vec<String> {
+        let mut normalized: Vec<String> = Vec::with_capacity(tags.len());
+
+        for tag in tags {
+            let tag = tag.trim().to_lowercase();
+
+            if !tag.is_empty() && !normalized.contains(&tag) {
+                normalized.push(tag);
+            }
+        }
+
+        normalized
+    }
+
+    /// Identifies a version of the active session for change detection.
+    ///
+    /// # Arguments
+    ///
+    /// * `session` — The active session, if any.
+    ///
+    /// # Returns
+    ///
+    /// The session's ID and last update time, or `None` if no session is
+    /// active.
+    fn session_version(session: Option<&WorkSession>) -> Option<(Uuid, DateTime<Utc>)> {
+        session.map(|session| (session.id, session.updated_at))
+    }
+
+    /// Returns the minimum time between state changes on a session.
+    ///
+    /// # Returns
+    ///
+    /// [`MIN_STATE_TRANSITION_INTERVAL_MS`] as a [`Duration`].
+    pub(crate) fn min_transition_interval() -> Duration {
+        Duration::milliseconds(MIN_STATE_TRANSITION_INTERVAL_MS)
+    }
+
+    /// Explains why a state change on a session was not applied.
+    ///
+    /// Called after a conditional state change matched no row. The session
+    /// is read only to choose the error; the change itself was already
+    /// decided atomically by the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `user_id` — The UUID of the user who owns the session.
+    /// * `session_id` — The session's UUID.
+    /// * `allowed_from` — Whether the change is allowed from a session's
+    ///   current state.
+    /// * `state_message` — Error message when the session is in the wrong
+    ///   state.
+    ///
+    /// # Returns
+    ///
+    /// [`ApiErrorResponse::NotFound`] if the session does not exist,
+    /// [`ApiErrorResponse::BadRequest`] with `state_message` if it is in the
+    /// wrong state, or [`ApiErrorResponse::TooManyRequests`] if it changed
+    /// too recently.
+    pub(crate) async fn rejected_transition(
+        state: &AppState,
+        user_id: Uuid,
+        session_id: Uuid,
+        allowed_from: impl Fn(&WorkSession) -> bool,
+        state_message: &str,
+    ) -> ApiErrorResponse {
+        match WorkSessionRepo::find_session_by_id(&state.db_pool, user_id, session_id).await {
+            Ok(session) if !allowed_from(&session) => {
+                ApiErrorResponse::BadRequest(state_message.to_string())
+            }
+            Ok(_) => ApiErrorResponse::TooManyRequests(format!(
+                "Work session state changed too recently; wait {} ms between changes",
+                MIN_STATE_TRANSITION_INTERVAL_MS
+            )),
+            Err(error) => error,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_tags() {
+        assert_eq!(
+            WorkSessionController::normalize_tags(vec![
+                " Meeting ".to_string(),
+                "build".to_string(),
+                "MEETING".to_string(),
+                "  ".to_string(),
+            ]),
+            vec!["meeting".to_string(), "build".to_string()]
+        );
+    }
+
+    #[test]
+    fn drops_blank_notes() {
+        assert_eq!(
+            WorkSessionController::normalize_notes(Some("  Fixed the sink \n".to_string())),
+            Some("Fixed the sink".to_string())
+        );
+        assert_eq!(
+            WorkSessionController::normalize_notes(Some("   ".to_string())),
+            None
+        );
+    }
+}
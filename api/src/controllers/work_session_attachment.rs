@@ -0,0 +1,284 @@
+//! Work session attachment endpoints.
+//!
+//! Provides [`WorkSessionAttachmentController`] with handlers for attaching
+//! photos and voice memos to work sessions, listing and downloading them,
+//! and removing them. Transcripts of voice memos are stored with each
+//! attachment but not yet produced.
+
+use axum::{
+    Json,
+    body::Bytes,
+    extract::{Path, Query, State},
+    http::{HeaderMap, header},
+    response::{IntoResponse, Response},
+};
+use gig_log_common::models::{
+    generic::MessageResponse,
+    work_session::{
+        UploadWorkSessionAttachmentQuery, WorkSessionAttachment, WorkSessionAttachmentKind,
+    },
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::auth::AuthUser;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::repo::work_session_attachment::{NewWorkSessionAttachment, WorkSessionAttachmentRepo};
+use crate::routes::app::AppState;
+
+/// Maximum accepted size of a work session attachment, in bytes.
+pub const MAX_WORK_SESSION_ATTACHMENT_BYTES: usize = 10 * 1024 * 1024;
+
+/// Handlers for work session attachment routes.
+pub struct WorkSessionAttachmentController;
+
+impl WorkSessionAttachmentController {
+    /// Attaches a photo or voice memo to one of the user's work sessions.
+    ///
+    /// Mapped to `POST /work-sessions/{id}/attachments`. Requires
+    /// authentication. The request body is the raw file; its type is taken
+    /// from the `Content-Type` header, which must be an `image/*` or
+    /// `audio/*` type, and its name from the `file_name` query parameter.
+    /// Bodies larger than [`MAX_WORK_SESSION_ATTACHMENT_BYTES`] are rejected
+    /// by the router.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `session_id` — The session's UUID from the request path.
+    /// * `query` — The [`UploadWorkSessionAttachmentQuery`] naming the file.
+    /// * `headers` — Request headers carrying the file's content type.
+    /// * `body` — The raw file bytes.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<WorkSessionAttachment>`] describing the stored attachment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`] if the file name is invalid,
+    /// [`ApiErrorResponse::BadRequest`] if the body is empty or is not a
+    /// photo or audio recording, or [`ApiErrorResponse::NotFound`] if the
+    /// session does not exist or belongs to another user.
+    pub async fn upload(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(session_id): Path<Uuid>,
+        Query(query): Query<UploadWorkSessionAttachmentQuery>,
+        headers: HeaderMap,
+        body: Bytes,
+    ) -> ApiResult<Json<WorkSessionAttachment>> {
+        query.validate().map_err(ApiErrorResponse::from)?;
+
+        if body.is_empty() {
+            return Err(ApiErrorResponse::BadRequest(
+                "Attachment must not be empty".to_string(),
+            ));
+        }
+
+        let content_type = headers
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+        let kind = Self::kind(content_type).ok_or_else(|| {
+            ApiErrorResponse::BadRequest("Attachments must be a photo or audio memo".to_string())
+        })?;
+
+        let attachment = WorkSessionAttachmentRepo::insert_attachment(
+            &state.db_pool,
+            auth.user_id,
+            session_id,
+            NewWorkSessionAttachment {
+                kind,
+                file_name: &query.file_name,
+                content_type,
+                data: &body,
+            },
+        )
+        .await
+        .map_err(|error| match error {
+            ApiErrorResponse::NotFound(_) => {
+                ApiErrorResponse::NotFound("Work session not found".to_string())
+            }
+            error => error,
+        })?;
+
+        Ok(Json(attachment))
+    }
+
+    /// Lists the attachments on one of the user's work sessions.
+    ///
+    /// Mapped to `GET /work-sessions/{id}/attachments`. Requires
+    /// authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `session_id` — The session's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Vec<WorkSessionAttachment>>`] of the session's attachments,
+    /// oldest first, which is empty if the session does not exist or
+    /// belongs to another user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn list(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(session_id): Path<Uuid>,
+    ) -> ApiResult<Json<Vec<WorkSessionAttachment>>> {
+        let attachments =
+            WorkSessionAttachmentRepo::list_for_session(&state.db_pool, auth.user_id, session_id)
+                .await?;
+
+        Ok(Json(attachments))
+    }
+
+    /// Downloads a work session attachment.
+    ///
+    /// Mapped to `GET /work-sessions/{id}/attachments/{attachment_id}`.
+    /// Requires authentication. Responds with the stored bytes, content
+    /// type, and an inline `Content-Disposition` carrying the original file
+    /// name so photos and memos can be shown in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `session_id` — The session's UUID from the request path.
+    /// * `attachment_id` — The attachment's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Response`] containing the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the attachment does not
+    /// exist on the user's session.
+    pub async fn download(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path((session_id, attachment_id)): Path<(Uuid, Uuid)>,
+    ) -> ApiResult<Response> {
+        let attachment = WorkSessionAttachmentRepo::find_data(
+            &state.db_pool,
+            auth.user_id,
+            session_id,
+            attachment_id,
+        )
+        .await?;
+        let file_name = attachment.file_name.replace(['"', '\\', '\r', '\n'], "_");
+
+        Ok((
+            [
+                (header::CONTENT_TYPE, attachment.content_type),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("inline; filename=\"{file_name}\""),
+                ),
+            ],
+            attachment.data,
+        )
+            .into_response())
+    }
+
+    /// Deletes a work session attachment.
+    ///
+    /// Mapped to `DELETE /work-sessions/{id}/attachments/{attachment_id}`.
+    /// Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `session_id` — The session's UUID from the request path.
+    /// * `attachment_id` — The attachment's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MessageResponse>`] confirming the deletion.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the attachment does not
+    /// exist on the user's session.
+    pub async fn delete(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path((session_id, attachment_id)): Path<(Uuid, Uuid)>,
+    ) -> ApiResult<Json<MessageResponse>> {
+        let deleted = WorkSessionAttachmentRepo::delete_attachment(
+            &state.db_pool,
+            auth.user_id,
+            session_id,
+            attachment_id,
+        )
+        .await?;
+
+        if !deleted {
+            return Err(ApiErrorResponse::NotFound(
+                "Attachment not found".to_string(),
+            ));
+        }
+
+        Ok(Json(MessageResponse {
+            message: "Attachment deleted.".to_string(),
+        }))
+    }
+
+    /// Determines the kind of attachment from its content type.
+    ///
+    /// # Arguments
+    ///
+    /// * `content_type` — The upload's `Content-Type` header value.
+    ///
+    /// # Returns
+    ///
+    /// [`WorkSessionAttachmentKind::Photo`] for `image/*` types,
+    /// [`WorkSessionAttachmentKind::VoiceMemo`] for `audio/*` types, or
+    /// `None` for anything else, including SVG images, which can carry
+    /// scripts when served inline.
+    fn kind(content_type: &str) -> Option<WorkSessionAttachmentKind> {
+        let (kind, subtype) = content_type.split(';').next()?.trim().split_once('/')?;
+
+        if subtype.is_empty() || subtype.eq_ignore_ascii_case("svg+xml") {
+            return None;
+        }
+
+        match kind.to_ascii_lowercase().as_str() {
+            "image" => Some(WorkSessionAttachmentKind::Photo),
+            "audio" => Some(WorkSessionAttachmentKind::VoiceMemo),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kind_follows_content_type() {
+        assert_eq!(
+            WorkSessionAttachmentController::kind("image/jpeg"),
+            Some(WorkSessionAttachmentKind::Photo)
+        );
+        assert_eq!(
+            WorkSessionAttachmentController::kind("Audio/webm; codecs=opus"),
+            Some(WorkSessionAttachmentKind::VoiceMemo)
+        );
+        assert_eq!(
+            WorkSessionAttachmentController::kind("application/pdf"),
+            None
+        );
+        assert_eq!(WorkSessionAttachmentController::kind("image/"), None);
+        assert_eq!(WorkSessionAttachmentController::kind("image/svg+xml"), None);
+        assert_eq!(WorkSessionAttachmentController::kind(""), None);
+    }
+}
@@ -0,0 +1,138 @@
+//! Work session draft endpoints.
+//!
+//! Provides [`WorkSessionDraftController`] with handlers for reviewing
+//! work sessions logged by email, confirming them into completed work
+//! sessions, and dismissing them.
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use gig_log_common::models::{
+    generic::MessageResponse,
+    live_sync::LiveSyncMessage,
+    webhook::WebhookEvent,
+    work_session::WorkSession,
+    work_session_draft::{ConfirmWorkSessionDraftRequest, WorkSessionDraft},
+};
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::extractors::ValidatedJson;
+use crate::repo::{work_session::WorkSessionRepo, work_session_draft::WorkSessionDraftRepo};
+use crate::routes::app::AppState;
+use crate::webhook::WebhookDispatcher;
+
+/// Handlers for work session draft routes.
+pub struct WorkSessionDraftController;
+
+impl WorkSessionDraftController {
+    /// Lists the authenticated user's pending drafts.
+    ///
+    /// Mapped to `GET /work-session-drafts`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<Vec<WorkSessionDraft>>`] ordered newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if the database query fails.
+    pub async fn list(
+        auth: AuthUser,
+        State(state): State<AppState>,
+    ) -> ApiResult<Json<Vec<WorkSessionDraft>>> {
+        let drafts = WorkSessionDraftRepo::list_drafts(&state.db_pool, auth.user_id).await?;
+
+        Ok(Json(drafts))
+    }
+
+    /// Confirms a draft into a completed work session.
+    ///
+    /// Mapped to `POST /work-session-drafts/{id}/confirm`. Requires
+    /// authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `draft_id` — The draft's UUID from the request path.
+    /// * `body` — A [`ValidatedJson<ConfirmWorkSessionDraftRequest>`]
+    ///   optionally choosing the job.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<WorkSession>`] containing the created session.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the draft or job does not
+    /// exist, or [`ApiErrorResponse::BadRequest`] if no job was chosen for
+    /// an unmatched draft.
+    pub async fn confirm(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(draft_id): Path<Uuid>,
+        ValidatedJson(body): ValidatedJson<ConfirmWorkSessionDraftRequest>,
+    ) -> ApiResult<Json<WorkSession>> {
+        let session_id = WorkSessionDraftRepo::confirm_draft(
+            &state.db_pool,
+            auth.user_id,
+            draft_id,
+            body.job_id,
+        )
+        .await?;
+        let session =
+            WorkSessionRepo::find_session_by_id(&state.db_pool, auth.user_id, session_id).await?;
+        state.live_sync.publish(
+            auth.user_id,
+            LiveSyncMessage::WorkSessionUpdated(session.clone()),
+        );
+        WebhookDispatcher::publish(
+            &state.db_pool,
+            auth.user_id,
+            WebhookEvent::WorkSessionCompleted(session.clone()),
+        )
+        .await;
+
+        Ok(Json(session))
+    }
+
+    /// Dismisses a draft without logging it.
+    ///
+    /// Mapped to `DELETE /work-session-drafts/{id}`. Requires authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` — The [`AuthUser`] extracted from the access token.
+    /// * `state` — The shared [`AppState`].
+    /// * `draft_id` — The draft's UUID from the request path.
+    ///
+    /// # Returns
+    ///
+    /// A [`Json<MessageResponse>`] confirming the dismissal.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the draft does not exist or
+    /// belongs to another user.
+    pub async fn delete(
+        auth: AuthUser,
+        State(state): State<AppState>,
+        Path(draft_id): Path<Uuid>,
+    ) -> ApiResult<Json<MessageResponse>> {
+        if !WorkSessionDraftRepo::delete_draft(&state.db_pool, auth.user_id, draft_id).await? {
+            return Err(ApiErrorResponse::NotFound("Draft not found".to_string()));
+        }
+
+        Ok(Json(MessageResponse {
+            message: "Draft dismissed.".to_string(),
+        }))
+    }
+}
@@ -0,0 +1,154 @@
+//! Per-user API activity recording.
+//!
+//! Provides [`ActivityRecorder`], an Axum middleware that records each
+//! request made with an access token cookie so users can review their own
+//! recent activity, and passes successful deletions to the
+//! [`AnomalyDetector`].
+
+use std::net::SocketAddr;
+
+use axum::{
+    extract::{ConnectInfo, MatchedPath, Request, State},
+    http::{HeaderMap, Method, header},
+    middleware::Next,
+    response::Response,
+};
+use log::error;
+
+use crate::{
+    auth::{AuthUser, anomaly::AnomalyDetector, jwt::JwtUtil},
+    repo::activity::{ActivityRepo, NewActivityRecord},
+    routes::app::AppState,
+};
+
+/// Middleware that records authenticated requests to the `api_activity`
+/// table.
+pub struct ActivityRecorder;
+
+impl ActivityRecorder {
+    /// Axum middleware that records the request once the response is ready.
+    ///
+    /// Only requests with a valid access token cookie that matched a route
+    /// are recorded. The entry is written on a separate task so the response
+    /// is not delayed, and write failures are logged. Once a successful
+    /// `DELETE` is recorded, the task checks for mass deletion.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `request` — The incoming HTTP request.
+    /// * `next` — The next middleware or handler in the chain.
+    ///
+    /// # Returns
+    ///
+    /// The HTTP [`Response`] produced by downstream handlers.
+    pub async fn record(State(state): State<AppState>, request: Request, next: Next) -> Response {
+        let user_id = AuthUser::access_token(request.headers()).and_then(|token| {
+            JwtUtil::decode_access_token(token, &state.config)
+                .ok()
+                .map(|token_data| token_data.claims.sub)
+        });
+        let route = request
+            .extensions()
+            .get::<MatchedPath>()
+            .map(|path| path.as_str().to_string());
+
+        let (Some(user_id), Some(route)) = (user_id, route) else {
+            return next.run(request).await;
+        };
+
+        let method = request.method().to_string();
+        let peer = request
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(address)| *address);
+        let ip_address = Self::client_ip(request.headers(), peer);
+        let user_agent = request
+            .headers()
+            .get(header::USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+
+        let response = next.run(request).await;
+
+        let activity = NewActivityRecord {
+            user_id,
+            method,
+            route,
+            status: response.status().as_u16() as i16,
+            ip_address,
+            user_agent,
+        };
+
+        let check_deletion =
+            activity.method == Method::DELETE.as_str() && response.status().is_success();
+
+        tokio::spawn(async move {
+            if let Err(error) = ActivityRepo::insert_activity(&state.db_pool, activity).await {
+                error!("Failed to record API activity: {:?}", error);
+                return;
+            }
+
+            if !check_deletion {
+                return;
+            }
+
+            if let Err(error) = AnomalyDetector::check_mass_deletion(&state, user_id).await {
+                error!("Failed to check for mass deletion: {:?}", error);
+            }
+        });
+
+        response
+    }
+
+    /// Resolves the IP address a request came from.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` — The request headers.
+    /// * `peer` — The address of the connected peer, if known.
+    ///
+    /// # Returns
+    ///
+    /// The first address in `X-Forwarded-For`, falling back to the peer
+    /// address, or `None` if neither is available.
+    pub(crate) fn client_ip(headers: &HeaderMap, peer: Option<SocketAddr>) -> Option<String> {
+        headers
+            .get("x-forwarded-for")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(',').next())
+            .map(str::trim)
+            .filter(|address| !address.is_empty())
+            .map(str::to_string)
+            .or_else(|| peer.map(|address| address.ip().to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn client_ip_prefers_forwarded_for() {
+        let peer: SocketAddr = "10.0.0.2:5000".parse().unwrap();
+        let mut headers = HeaderMap::new();
+
+        assert_eq!(
+            ActivityRecorder::client_ip(&headers, Some(peer)).as_deref(),
+            Some("10.0.0.2")
+        );
+
+        headers.insert(
+            "x-forwarded-for",
+            HeaderValue::from_static("203.0.113.7, 10.0.0.1"),
+        );
+
+        assert_eq!(
+            ActivityRecorder::client_ip(&headers, Some(peer)).as_deref(),
+            Some("203.0.113.7")
+        );
+        assert_eq!(ActivityRecorder::client_ip(&HeaderMap::new(), None), None);
+    }
+}
@@ -1,19 +1,38 @@
 //! Application entry point and server bootstrap.
 //!
-//! This module provides the [`App`] struct with a single async method,
-//! [`App::run`], that orchestrates the full server startup sequence: logging
-//! initialization, configuration loading, database connection, optional
-//! migrations, email client setup, and HTTP listener binding.
+//! This module provides the [`App`] struct. [`App::run`] orchestrates the
+//! full server startup sequence: logging initialization, configuration
+//! loading, database connection, optional migrations, email client setup,
+//! background task startup, and HTTP listener binding. [`App::check_data`]
+//! runs the stored data consistency checks instead of serving requests.
 
+use std::{net::SocketAddr, sync::Arc};
+
+use anyhow::anyhow;
+use log::warn;
 use sqlx::postgres::PgPoolOptions;
 use tokio::net::TcpListener;
 
 use gig_log_common::logging::{log_message, log_success};
 
 use crate::{
-    core::{config::Config, logger::Logger},
+    core::{config::Config, error_reporting::ErrorReporter, logger::Logger},
+    data_check::DataCheckUtil,
     email::client::EmailClient,
+    live_sync::LiveSyncHub,
     routes::app::{AppRouter, AppState},
+    services::{
+        distance::haversine::HaversineDistanceCalculator, oauth::IdentityProviders,
+        pdf::builtin::BuiltinPdfRenderer,
+    },
+    tasks::{
+        account_exports::AccountExportTask, activity_prune::ActivityPruneTask,
+        budget_alerts::BudgetAlertTask, contract_reminders::ContractReminderTask,
+        demo_cleanup::DemoCleanupTask, invoice_drafts::InvoiceDraftTask,
+        payment_reminders::PaymentReminderTask, recurring_payments::RecurringPaymentTask,
+        report_cache::ReportCacheTask, webhook_deliveries::WebhookDeliveryTask,
+        work_session_limits::WorkSessionLimitTask,
+    },
 };
 
 /// Convenience alias for fallible operations during application startup.
@@ -21,8 +40,8 @@ pub type AppResult<T> = anyhow::Result<T>;
 
 /// Entry point for the GigLog API server.
 ///
-/// `App` is a unit struct whose only purpose is to namespace the [`run`](App::run)
-/// method.
+/// `App` is a unit struct whose only purpose is to namespace the
+/// [`run`](App::run) and [`check_data`](App::check_data) methods.
 pub struct App;
 
 impl App {
@@ -36,9 +55,17 @@ impl App {
     /// 4. Connect to PostgreSQL (max 5 connections).
     /// 5. Optionally apply pending SQLx migrations when
     ///    [`Config::auto_apply_migrations`] is `true`.
-    /// 6. Create the [`EmailClient`].
-    /// 7. Build [`AppState`] and [`AppRouter`].
-    /// 8. Bind a TCP listener on `0.0.0.0:8000` and serve requests.
+    /// 6. Create the [`EmailClient`], optional [`ErrorReporter`], and
+    ///    configured [`IdentityProviders`].
+    /// 7. Build [`AppState`] with the [`BuiltinPdfRenderer`] and spawn the
+    ///    [`BudgetAlertTask`], [`ContractReminderTask`],
+    ///    [`PaymentReminderTask`], [`DemoCleanupTask`],
+    ///    [`RecurringPaymentTask`], [`InvoiceDraftTask`],
+    ///    [`ActivityPruneTask`], [`WorkSessionLimitTask`],
+    ///    [`AccountExportTask`], [`ReportCacheTask`], and
+    ///    [`WebhookDeliveryTask`].
+    /// 8. Build the [`AppRouter`].
+    /// 9. Bind a TCP listener on `0.0.0.0:8000` and serve requests.
     ///
     /// # Errors
     ///
@@ -66,18 +93,102 @@ impl App {
         }
 
         let email_client = EmailClient::new(&config);
+        let error_reporter = ErrorReporter::from_config(&config);
+        let identity_providers = IdentityProviders::from_config(&config);
 
         let state = AppState {
             config,
             db_pool,
             email_client,
+            pdf_renderer: Arc::new(BuiltinPdfRenderer),
+            identity_providers,
+            distance_calculator: Arc::new(HaversineDistanceCalculator),
+            error_reporter,
+            live_sync: LiveSyncHub::new(),
         };
+        BudgetAlertTask::spawn(state.clone());
+        ContractReminderTask::spawn(state.clone());
+        PaymentReminderTask::spawn(state.clone());
+        DemoCleanupTask::spawn(state.clone());
+        RecurringPaymentTask::spawn(state.clone());
+        InvoiceDraftTask::spawn(state.clone());
+        ActivityPruneTask::spawn(state.clone());
+        WorkSessionLimitTask::spawn(state.clone());
+        AccountExportTask::spawn(state.clone());
+        ReportCacheTask::spawn(state.clone());
+        WebhookDeliveryTask::spawn(state.clone());
+
         let app = AppRouter::new(state);
 
         let listener = TcpListener::bind("0.0.0.0:8000").await?;
 
         log_success("Server running on port 8000");
-        axum::serve(listener, app).await?;
+        axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Scans stored data for rows that break invariants enforced at write
+    /// time, optionally repairing them.
+    ///
+    /// Loads [`Config`], connects to PostgreSQL, runs every
+    /// [`DataCheck`](crate::data_check::DataCheck), and logs a line per
+    /// check. Migrations are not applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `repair` — Whether to repair violations that have a safe fix.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if configuration is invalid, the database is
+    /// unreachable, a query fails, or any violations remain unrepaired.
+    pub async fn check_data(repair: bool) -> AppResult<()> {
+        Logger::setup_logging_from_env();
+
+        let config = Config::new()?;
+        Logger::setup_logging(&config.log_level, config.log_verbose);
+
+        log_message("Connecting to database");
+
+        let db_pool = PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&config.database_url)
+            .await?;
+
+        log_message("Checking stored data");
+
+        let results = DataCheckUtil::run(&db_pool, repair)
+            .await
+            .map_err(|error| anyhow!("Data check failed: {:?}", error))?;
+        let mut remaining = 0;
+
+        for result in &results {
+            let name = result.check.name();
+
+            if result.violations == 0 {
+                log_success(&format!("{name}: ok"));
+            } else if result.remaining() == 0 {
+                log_success(&format!("{name}: repaired {}", result.repaired));
+            } else {
+                warn!(
+                    "{name}: {} violations, {} repaired",
+                    result.violations, result.repaired
+                );
+            }
+
+            remaining += result.remaining();
+        }
+
+        if remaining > 0 {
+            return Err(anyhow!("{remaining} data check violations remain"));
+        }
+
+        log_success("Stored data is consistent");
 
         Ok(())
     }
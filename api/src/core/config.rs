@@ -5,10 +5,12 @@
 //! `dotenvy`). Required variables cause a startup error when missing; optional
 //! variables fall back to sensible defaults documented on each field.
 
-use std::env;
+use std::{env, net::IpAddr};
 
 use anyhow::Error;
+use axum_extra::extract::cookie::SameSite;
 use dotenvy::dotenv;
+use ipnet::IpNet;
 use log::error;
 
 use crate::core::app::AppResult;
@@ -24,12 +26,18 @@ pub struct Config {
     pub app_env: String,
     /// Allowed web client origins for CORS. **Required** — `WEB_ORIGIN` (comma-separated).
     pub web_origins: Vec<String>,
+    /// Public base URL of this API, used to build links in emails. `API_PUBLIC_URL`, default `"http://localhost:8000"`.
+    pub api_public_url: String,
     /// PostgreSQL connection string. **Required** — `DATABASE_URL`.
     pub database_url: String,
     /// Run pending SQLx migrations on startup. `AUTO_APPLY_MIGRATIONS_ENABLED`, default `true`.
     pub auto_apply_migrations: bool,
     /// Secret key used to sign JWTs. **Required** — `JWT_SECRET`.
     pub jwt_secret: String,
+    /// Issuer (`iss`) claim of issued JWTs. `JWT_ISSUER`, default `"gig-log-api"`.
+    pub jwt_issuer: String,
+    /// Audience (`aud`) claim of issued access tokens; refresh tokens append `:refresh`. `JWT_AUDIENCE`, default `"gig-log-web"`.
+    pub jwt_audience: String,
     /// JWT access token lifetime in seconds. `JWT_ACCESS_TOKEN_EXPIRY_SECONDS`, default `900` (15 min).
     pub jwt_access_token_expiry_seconds: u64,
     /// JWT refresh token lifetime in seconds. `JWT_REFRESH_TOKEN_EXPIRY_SECONDS`, default `604800` (7 days).
@@ -46,6 +54,88 @@ pub struct Config {
     pub log_verbose: bool,
     /// Maximum HTTP body size in bytes to include in logs. `LOG_HTTP_MAX_BODY_BYTES`, default `16384`.
     pub log_http_max_body: usize,
+    /// Interval between budget alert sweeps in seconds. `BUDGET_ALERT_INTERVAL_SECONDS`, default `3600` (1 hour).
+    pub budget_alert_interval_seconds: u64,
+    /// Interval between contract renewal reminder sweeps in seconds. `CONTRACT_REMINDER_INTERVAL_SECONDS`, default `3600` (1 hour).
+    pub contract_reminder_interval_seconds: u64,
+    /// Interval between payment reminder sweeps in seconds. `PAYMENT_REMINDER_INTERVAL_SECONDS`, default `3600` (1 hour).
+    pub payment_reminder_interval_seconds: u64,
+    /// Days before a payment's expected payout date that its upcoming reminder is sent. `PAYMENT_REMINDER_DAYS_BEFORE`, default `3`.
+    pub payment_reminder_days_before: u64,
+    /// Interval between sweeps that create payments from recurring payments in seconds. `RECURRING_PAYMENT_INTERVAL_SECONDS`, default `3600` (1 hour).
+    pub recurring_payment_interval_seconds: u64,
+    /// Interval between sweeps that draft weekly invoices in seconds. `INVOICE_DRAFT_INTERVAL_SECONDS`, default `3600` (1 hour).
+    pub invoice_draft_interval_seconds: u64,
+    /// Interval between checks for queued account data exports in seconds. `ACCOUNT_EXPORT_INTERVAL_SECONDS`, default `10`.
+    pub account_export_interval_seconds: u64,
+    /// Interval between checks for due webhook deliveries in seconds. `WEBHOOK_DELIVERY_INTERVAL_SECONDS`, default `10`.
+    pub webhook_delivery_interval_seconds: u64,
+    /// Worked time in seconds after which a running work session is completed automatically. `WORK_SESSION_MAX_DURATION_SECONDS`, default `43200` (12 hours); `0` disables the limit.
+    pub work_session_max_duration_seconds: u64,
+    /// Sentry-compatible DSN that server errors are reported to. `ERROR_REPORTING_DSN`, reporting disabled when unset.
+    pub error_reporting_dsn: Option<String>,
+    /// Fraction of server errors that are reported, from `0.0` to `1.0`. `ERROR_REPORTING_SAMPLE_RATE`, default `1.0`.
+    pub error_reporting_sample_rate: f64,
+    /// Shared secret expected in the inbound email webhook URL. `INBOUND_EMAIL_SECRET`, webhook disabled when unset.
+    pub inbound_email_secret: Option<String>,
+    /// Allow anyone to provision a throwaway demo account at `POST /auth/demo`. `DEMO_ACCOUNTS_ENABLED`, default `false`.
+    pub demo_accounts_enabled: bool,
+    /// Lifetime of a demo account and its session in seconds. `DEMO_ACCOUNT_LIFETIME_SECONDS`, default `3600` (1 hour).
+    pub demo_account_lifetime_seconds: u64,
+    /// Interval between expired demo account purges in seconds. `DEMO_CLEANUP_INTERVAL_SECONDS`, default `900` (15 min).
+    pub demo_cleanup_interval_seconds: u64,
+    /// WebAuthn relying party ID passkeys are bound to, the web client's registrable domain. `WEBAUTHN_RP_ID`, default `"localhost"`.
+    pub webauthn_rp_id: String,
+    /// Relying party name shown by authenticators when creating a passkey. `WEBAUTHN_RP_NAME`, default `"GigLog"`.
+    pub webauthn_rp_name: String,
+    /// Minimum length of new passwords. `PASSWORD_MIN_LENGTH`, default `8`.
+    pub password_min_length: usize,
+    /// Minimum strength score of new passwords, from `0` to `4`. `PASSWORD_MIN_SCORE`, default `3`.
+    pub password_min_score: u8,
+    /// Failed log-ins within the window that count as unusual activity. `SECURITY_FAILED_LOGIN_THRESHOLD`, default `10`.
+    pub security_failed_login_threshold: u64,
+    /// Window failed log-ins are counted over in seconds. `SECURITY_FAILED_LOGIN_WINDOW_SECONDS`, default `900` (15 min).
+    pub security_failed_login_window_seconds: u64,
+    /// Deletions within the window that count as unusual activity. `SECURITY_MASS_DELETION_THRESHOLD`, default `25`.
+    pub security_mass_deletion_threshold: u64,
+    /// Window deletions are counted over in seconds. `SECURITY_MASS_DELETION_WINDOW_SECONDS`, default `600` (10 min).
+    pub security_mass_deletion_window_seconds: u64,
+    /// How long sensitive endpoints require a fresh log-in after unusual activity, in seconds. `SECURITY_REAUTH_HOLD_SECONDS`, default `86400` (1 day).
+    pub security_reauth_hold_seconds: u64,
+    /// Request header carrying the client's ISO country code, set by the proxy. `SECURITY_COUNTRY_HEADER`, default `"cf-ipcountry"`.
+    pub security_country_header: String,
+    /// Client address ranges that may call the [`ip_allowlist_paths`](Self::ip_allowlist_paths). `IP_ALLOWLIST`, comma-separated CIDRs or addresses, no restriction when empty.
+    pub ip_allowlist: Vec<IpNet>,
+    /// Path prefixes restricted to the [`ip_allowlist`](Self::ip_allowlist). `IP_ALLOWLIST_PATHS`, comma-separated, default `"/admin,/metrics"`.
+    pub ip_allowlist_paths: Vec<String>,
+    /// Client address ranges denied on every path. `IP_DENYLIST`, comma-separated CIDRs or addresses.
+    pub ip_denylist: Vec<IpNet>,
+    /// Reverse proxies in front of the API whose `X-Forwarded-For` entries are trusted when filtering by IP. `IP_FILTER_TRUSTED_PROXY_HOPS`, default `0` (use the peer address).
+    pub ip_filter_trusted_proxy_hops: usize,
+    /// Stripe secret API key used to start checkout sessions. `STRIPE_SECRET_KEY`, subscriptions disabled when unset.
+    pub stripe_secret_key: Option<String>,
+    /// Signing secret of the Stripe webhook endpoint. `STRIPE_WEBHOOK_SECRET`, webhook disabled when unset.
+    pub stripe_webhook_secret: Option<String>,
+    /// Stripe price ID of the Pro plan. `STRIPE_PRO_PRICE_ID`, Pro checkout disabled when unset.
+    pub stripe_pro_price_id: Option<String>,
+    /// OAuth client ID for signing in with Google. `GOOGLE_OAUTH_CLIENT_ID`, Google sign-in disabled when unset.
+    pub google_oauth_client_id: Option<String>,
+    /// OAuth client secret for signing in with Google. `GOOGLE_OAUTH_CLIENT_SECRET`, Google sign-in disabled when unset.
+    pub google_oauth_client_secret: Option<String>,
+    /// OAuth app client ID for signing in with GitHub. `GITHUB_OAUTH_CLIENT_ID`, GitHub sign-in disabled when unset.
+    pub github_oauth_client_id: Option<String>,
+    /// OAuth app client secret for signing in with GitHub. `GITHUB_OAUTH_CLIENT_SECRET`, GitHub sign-in disabled when unset.
+    pub github_oauth_client_secret: Option<String>,
+    /// Parent domains that session cookies may be scoped to, matched against the request host. `COOKIE_DOMAINS`, comma-separated, host-only cookies when empty.
+    pub cookie_domains: Vec<String>,
+    /// Mark session cookies `Secure` and give them the `__Host-` or `__Secure-` name prefix. `COOKIE_SECURE`, default `true` outside development.
+    pub cookie_secure: bool,
+    /// `SameSite` attribute of session cookies. `COOKIE_SAME_SITE` (`strict`, `lax`, or `none`), default `lax`; `none` requires `COOKIE_SECURE`.
+    pub cookie_same_site: SameSite,
+    /// Default mileage deduction rate in dollars per mile, used for users without their own rate. `MILEAGE_RATE`, default `0.70` (the 2025 IRS standard rate).
+    pub mileage_rate: f64,
+    /// Default self-employment tax rate as a decimal, applied in tax estimates to payments from companies that do not withhold, for users without their own rate. `SELF_EMPLOYMENT_TAX_RATE`, default `0.153`.
+    pub self_employment_tax_rate: f64,
 }
 
 impl Config {
@@ -66,9 +156,12 @@ impl Config {
 
         let app_env = Self::get_var_from_env("APP_ENV")?;
         let web_origins = Self::get_origin_list("WEB_ORIGIN")?;
+        let api_public_url = Self::get_optional_string("API_PUBLIC_URL", "http://localhost:8000");
         let database_url = Self::get_var_from_env("DATABASE_URL")?;
         let auto_apply_migrations = Self::get_optional_bool("AUTO_APPLY_MIGRATIONS_ENABLED", true);
         let jwt_secret = Self::get_var_from_env("JWT_SECRET")?;
+        let jwt_issuer = Self::get_optional_string("JWT_ISSUER", "gig-log-api");
+        let jwt_audience = Self::get_optional_string("JWT_AUDIENCE", "gig-log-web");
         let jwt_access_token_expiry_seconds =
             Self::get_optional_number("JWT_ACCESS_TOKEN_EXPIRY_SECONDS", 900);
         let jwt_refresh_token_expiry_seconds =
@@ -80,13 +173,87 @@ impl Config {
         let log_verbose =
             Self::get_optional_bool("LOG_VERBOSE", Self::is_development_env(&app_env));
         let log_http_max_body = Self::get_optional_usize("LOG_HTTP_MAX_BODY_BYTES", 16384);
+        let budget_alert_interval_seconds =
+            Self::get_optional_number("BUDGET_ALERT_INTERVAL_SECONDS", 3600);
+        let contract_reminder_interval_seconds =
+            Self::get_optional_number("CONTRACT_REMINDER_INTERVAL_SECONDS", 3600);
+        let payment_reminder_interval_seconds =
+            Self::get_optional_number("PAYMENT_REMINDER_INTERVAL_SECONDS", 3600);
+        let payment_reminder_days_before =
+            Self::get_optional_number("PAYMENT_REMINDER_DAYS_BEFORE", 3);
+        let recurring_payment_interval_seconds =
+            Self::get_optional_number("RECURRING_PAYMENT_INTERVAL_SECONDS", 3600);
+        let invoice_draft_interval_seconds =
+            Self::get_optional_number("INVOICE_DRAFT_INTERVAL_SECONDS", 3600);
+        let account_export_interval_seconds =
+            Self::get_optional_number("ACCOUNT_EXPORT_INTERVAL_SECONDS", 10);
+        let webhook_delivery_interval_seconds =
+            Self::get_optional_number("WEBHOOK_DELIVERY_INTERVAL_SECONDS", 10);
+        let work_session_max_duration_seconds =
+            Self::get_optional_number("WORK_SESSION_MAX_DURATION_SECONDS", 43200);
+        let error_reporting_dsn = Self::get_var_from_env("ERROR_REPORTING_DSN").ok();
+        let error_reporting_sample_rate =
+            Self::get_optional_rate("ERROR_REPORTING_SAMPLE_RATE", 1.0);
+        let inbound_email_secret = Self::get_var_from_env("INBOUND_EMAIL_SECRET").ok();
+        let demo_accounts_enabled = Self::get_optional_bool("DEMO_ACCOUNTS_ENABLED", false);
+        let demo_account_lifetime_seconds =
+            Self::get_optional_number("DEMO_ACCOUNT_LIFETIME_SECONDS", 3600);
+        let demo_cleanup_interval_seconds =
+            Self::get_optional_number("DEMO_CLEANUP_INTERVAL_SECONDS", 900);
+        let webauthn_rp_id = Self::get_optional_string("WEBAUTHN_RP_ID", "localhost");
+        let webauthn_rp_name = Self::get_optional_string("WEBAUTHN_RP_NAME", "GigLog");
+        let password_min_length = Self::get_optional_usize("PASSWORD_MIN_LENGTH", 8);
+        let password_min_score = Self::get_optional_number("PASSWORD_MIN_SCORE", 3).min(4) as u8;
+        let security_failed_login_threshold =
+            Self::get_optional_number("SECURITY_FAILED_LOGIN_THRESHOLD", 10);
+        let security_failed_login_window_seconds =
+            Self::get_optional_number("SECURITY_FAILED_LOGIN_WINDOW_SECONDS", 900);
+        let security_mass_deletion_threshold =
+            Self::get_optional_number("SECURITY_MASS_DELETION_THRESHOLD", 25);
+        let security_mass_deletion_window_seconds =
+            Self::get_optional_number("SECURITY_MASS_DELETION_WINDOW_SECONDS", 600);
+        let security_reauth_hold_seconds =
+            Self::get_optional_number("SECURITY_REAUTH_HOLD_SECONDS", 86400);
+        let security_country_header =
+            Self::get_optional_string("SECURITY_COUNTRY_HEADER", "cf-ipcountry");
+        let ip_allowlist = Self::get_optional_ip_list("IP_ALLOWLIST")?;
+        let ip_allowlist_paths = match Self::get_optional_list("IP_ALLOWLIST_PATHS") {
+            paths if paths.is_empty() => vec!["/admin".to_string(), "/metrics".to_string()],
+            paths => paths,
+        };
+        let ip_denylist = Self::get_optional_ip_list("IP_DENYLIST")?;
+        let ip_filter_trusted_proxy_hops =
+            Self::get_optional_usize("IP_FILTER_TRUSTED_PROXY_HOPS", 0);
+        let stripe_secret_key = Self::get_var_from_env("STRIPE_SECRET_KEY").ok();
+        let stripe_webhook_secret = Self::get_var_from_env("STRIPE_WEBHOOK_SECRET").ok();
+        let stripe_pro_price_id = Self::get_var_from_env("STRIPE_PRO_PRICE_ID").ok();
+        let google_oauth_client_id = Self::get_var_from_env("GOOGLE_OAUTH_CLIENT_ID").ok();
+        let google_oauth_client_secret = Self::get_var_from_env("GOOGLE_OAUTH_CLIENT_SECRET").ok();
+        let github_oauth_client_id = Self::get_var_from_env("GITHUB_OAUTH_CLIENT_ID").ok();
+        let github_oauth_client_secret = Self::get_var_from_env("GITHUB_OAUTH_CLIENT_SECRET").ok();
+        let cookie_domains = Self::get_optional_list("COOKIE_DOMAINS");
+        let cookie_secure =
+            Self::get_optional_bool("COOKIE_SECURE", !Self::is_development_env(&app_env));
+        let cookie_same_site = match Self::get_optional_same_site("COOKIE_SAME_SITE") {
+            SameSite::None if !cookie_secure => {
+                error!("COOKIE_SAME_SITE=none requires COOKIE_SECURE; using lax");
+                SameSite::Lax
+            }
+            same_site => same_site,
+        };
+        let mileage_rate = Self::get_optional_amount("MILEAGE_RATE", 0.70);
+        let self_employment_tax_rate =
+            Self::get_optional_amount("SELF_EMPLOYMENT_TAX_RATE", 0.153).min(1.0);
 
         Ok(Self {
             app_env,
             web_origins,
+            api_public_url,
             database_url,
             auto_apply_migrations,
             jwt_secret,
+            jwt_issuer,
+            jwt_audience,
             jwt_access_token_expiry_seconds,
             jwt_refresh_token_expiry_seconds,
             resend_api_key,
@@ -95,6 +262,47 @@ impl Config {
             log_level,
             log_verbose,
             log_http_max_body,
+            budget_alert_interval_seconds,
+            contract_reminder_interval_seconds,
+            payment_reminder_interval_seconds,
+            payment_reminder_days_before,
+            recurring_payment_interval_seconds,
+            invoice_draft_interval_seconds,
+            account_export_interval_seconds,
+            webhook_delivery_interval_seconds,
+            work_session_max_duration_seconds,
+            error_reporting_dsn,
+            error_reporting_sample_rate,
+            inbound_email_secret,
+            demo_accounts_enabled,
+            demo_account_lifetime_seconds,
+            demo_cleanup_interval_seconds,
+            webauthn_rp_id,
+            webauthn_rp_name,
+            password_min_length,
+            password_min_score,
+            security_failed_login_threshold,
+            security_failed_login_window_seconds,
+            security_mass_deletion_threshold,
+            security_mass_deletion_window_seconds,
+            security_reauth_hold_seconds,
+            security_country_header,
+            ip_allowlist,
+            ip_allowlist_paths,
+            ip_denylist,
+            ip_filter_trusted_proxy_hops,
+            stripe_secret_key,
+            stripe_webhook_secret,
+            stripe_pro_price_id,
+            google_oauth_client_id,
+            google_oauth_client_secret,
+            github_oauth_client_id,
+            github_oauth_client_secret,
+            cookie_domains,
+            cookie_secure,
+            cookie_same_site,
+            mileage_rate,
+            self_employment_tax_rate,
         })
     }
 
@@ -189,6 +397,62 @@ impl Config {
         }
     }
 
+    /// Reads an optional comma-separated list from the environment.
+    ///
+    /// # Arguments
+    ///
+    /// * `var` — The environment variable name.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] containing each non-empty, lowercased value, or an empty
+    /// [`Vec`] if the variable is unset or empty.
+    fn get_optional_list(var: &str) -> Vec<String> {
+        Self::get_var_from_env(var)
+            .map(|value| {
+                value
+                    .split(',')
+                    .map(|item| item.trim().to_ascii_lowercase())
+                    .filter(|item| !item.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Reads an optional comma-separated list of IP address ranges.
+    ///
+    /// Each entry is a CIDR range such as `10.0.0.0/8` or a single address,
+    /// which is treated as a range containing only that address.
+    ///
+    /// # Arguments
+    ///
+    /// * `var` — The environment variable name.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of the parsed [`IpNet`] ranges, or an empty [`Vec`] if the
+    /// variable is unset or empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any entry is not a valid range or address, so a
+    /// typo cannot silently widen access.
+    fn get_optional_ip_list(var: &str) -> AppResult<Vec<IpNet>> {
+        Self::get_optional_list(var)
+            .iter()
+            .map(|entry| {
+                entry
+                    .parse::<IpNet>()
+                    .or_else(|_| entry.parse::<IpAddr>().map(IpNet::from))
+                    .map_err(|_| {
+                        Error::msg(format!(
+                            "`{var}` environment variable contains an invalid IP range: `{entry}`."
+                        ))
+                    })
+            })
+            .collect()
+    }
+
     /// Reads an optional string variable, falling back to `default`.
     ///
     /// # Arguments
@@ -267,6 +531,35 @@ impl Config {
         }
     }
 
+    /// Reads an optional `SameSite` cookie attribute, falling back to `Lax`.
+    ///
+    /// Accepts `strict`, `lax`, and `none` (case-insensitive). Unrecognized
+    /// values log an error and fall back to `Lax`.
+    ///
+    /// # Arguments
+    ///
+    /// * `var` — The environment variable name.
+    ///
+    /// # Returns
+    ///
+    /// The parsed [`SameSite`] value, or [`SameSite::Lax`] if unavailable.
+    fn get_optional_same_site(var: &str) -> SameSite {
+        match env::var(var) {
+            Ok(value) if !value.trim().is_empty() => {
+                match value.trim().to_ascii_lowercase().as_str() {
+                    "strict" => SameSite::Strict,
+                    "lax" => SameSite::Lax,
+                    "none" => SameSite::None,
+                    _ => {
+                        error!("Invalid SameSite value for {}='{}'; using lax", var, value);
+                        SameSite::Lax
+                    }
+                }
+            }
+            _ => SameSite::Lax,
+        }
+    }
+
     /// Reads an optional `u64` variable, falling back to `default`.
     ///
     /// # Arguments
@@ -293,4 +586,60 @@ impl Config {
             _ => default,
         }
     }
+
+    /// Reads an optional rate between `0.0` and `1.0`, falling back to `default`.
+    ///
+    /// Values outside the range are clamped.
+    ///
+    /// # Arguments
+    ///
+    /// * `var` — The environment variable name.
+    /// * `default` — Value returned when the variable is unset, empty,
+    ///   or not a valid number.
+    ///
+    /// # Returns
+    ///
+    /// The parsed and clamped rate, or `default` if unavailable.
+    fn get_optional_rate(var: &str, default: f64) -> f64 {
+        match env::var(var) {
+            Ok(value) if !value.trim().is_empty() => match value.trim().parse::<f64>() {
+                Ok(value) if value.is_finite() => value.clamp(0.0, 1.0),
+                _ => {
+                    error!(
+                        "Invalid rate value for {}='{}'; using default {}",
+                        var, value, default
+                    );
+                    default
+                }
+            },
+            _ => default,
+        }
+    }
+
+    /// Reads an optional non-negative dollar amount, falling back to `default`.
+    ///
+    /// # Arguments
+    ///
+    /// * `var` — The environment variable name.
+    /// * `default` — Value returned when the variable is unset, empty,
+    ///   negative, or not a valid number.
+    ///
+    /// # Returns
+    ///
+    /// The parsed amount, or `default` if unavailable.
+    fn get_optional_amount(var: &str, default: f64) -> f64 {
+        match env::var(var) {
+            Ok(value) if !value.trim().is_empty() => match value.trim().parse::<f64>() {
+                Ok(value) if value.is_finite() && value >= 0.0 => value,
+                _ => {
+                    error!(
+                        "Invalid amount value for {}='{}'; using default {}",
+                        var, value, default
+                    );
+                    default
+                }
+            },
+            _ => default,
+        }
+    }
 }
@@ -11,9 +11,11 @@ use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
 };
-use gig_log_common::models::error::{ApiError, ValidationError};
+use gig_log_common::models::error::{ApiError, ValidationError, ValidationErrorCode};
 use log::{error, warn};
 
+use crate::core::error_reporting::InternalErrorMessage;
+
 /// Convenience alias for handler return types that may fail with an [`ApiErrorResponse`].
 pub type ApiResult<T> = Result<T, ApiErrorResponse>;
 
@@ -21,7 +23,9 @@ pub type ApiResult<T> = Result<T, ApiErrorResponse>;
 ///
 /// Each variant carries a human-readable message and maps to a specific HTTP
 /// status code. The [`IntoResponse`] implementation logs the
-/// error and serializes it as a JSON [`ApiError`].
+/// error and serializes it as a JSON [`ApiError`], which is also attached as
+/// a response extension so [`ProblemJson`](crate::core::problem::ProblemJson)
+/// can re-render it as problem details.
 #[derive(Debug)]
 pub enum ApiErrorResponse {
     /// Resource not found. Returns HTTP `404 Not Found`.
@@ -33,14 +37,21 @@ pub enum ApiErrorResponse {
     /// Unexpected server-side failure. Returns HTTP `500 Internal Server Error`.
     ///
     /// The original message is logged but not exposed to the client; the
-    /// response body always reads "Something went wrong".
+    /// response body always reads "Something went wrong". The message is
+    /// attached to the response as an [`InternalErrorMessage`] extension for
+    /// error reporting.
     InternalServerError(String),
     /// Missing or invalid authentication credentials. Returns HTTP `401 Unauthorized`.
     Unauthorized(String),
+    /// Authenticated but not permitted to access the resource. Returns HTTP `403 Forbidden`.
+    Forbidden(String),
+    /// The client is sending requests too quickly. Returns HTTP `429 Too Many Requests`.
+    TooManyRequests(String),
 }
 
 impl IntoResponse for ApiErrorResponse {
     fn into_response(self) -> Response {
+        let mut internal_message = None;
         let (status, message, errors) = match self {
             ApiErrorResponse::NotFound(msg) => {
                 warn!("NotFound: {}", msg);
@@ -61,6 +72,7 @@ impl IntoResponse for ApiErrorResponse {
             }
             ApiErrorResponse::InternalServerError(msg) => {
                 error!("InternalServerError: {}", msg);
+                internal_message = Some(InternalErrorMessage(msg));
 
                 (
                     StatusCode::INTERNAL_SERVER_ERROR,
@@ -72,6 +84,14 @@ impl IntoResponse for ApiErrorResponse {
                 warn!("Unauthorized: {}", msg);
                 (StatusCode::UNAUTHORIZED, msg, None)
             }
+            ApiErrorResponse::Forbidden(msg) => {
+                warn!("Forbidden: {}", msg);
+                (StatusCode::FORBIDDEN, msg, None)
+            }
+            ApiErrorResponse::TooManyRequests(msg) => {
+                warn!("TooManyRequests: {}", msg);
+                (StatusCode::TOO_MANY_REQUESTS, msg, None)
+            }
         };
 
         let body = ApiError {
@@ -80,7 +100,14 @@ impl IntoResponse for ApiErrorResponse {
             errors,
         };
 
-        (status, Json(body)).into_response()
+        let mut response = (status, Json(body.clone())).into_response();
+        response.extensions_mut().insert(body);
+
+        if let Some(internal_message) = internal_message {
+            response.extensions_mut().insert(internal_message);
+        }
+
+        response
     }
 }
 
@@ -100,27 +127,242 @@ impl From<sqlx::Error> for ApiErrorResponse {
     }
 }
 
+/// Field name `validator` reports struct-level (schema) errors under.
+const SCHEMA_ERROR_FIELD: &str = "__all__";
+
+/// Maps a `validator` error to its [`ValidationErrorCode`].
+///
+/// Struct-level errors are cross-field checks. A `length` error with a
+/// minimum of one on an empty value is a missing required field.
+///
+/// # Arguments
+///
+/// * `field` — The field the error was reported under.
+/// * `error` — The error from the `validator` crate.
+///
+/// # Returns
+///
+/// The matching [`ValidationErrorCode`], or
+/// [`ValidationErrorCode::Invalid`] for custom checks.
+fn validation_error_code(field: &str, error: &validator::ValidationError) -> ValidationErrorCode {
+    if field == SCHEMA_ERROR_FIELD {
+        return ValidationErrorCode::CrossField;
+    }
+
+    match error.code.as_ref() {
+        "required" => ValidationErrorCode::FieldRequired,
+        "length" => {
+            let requires_value = error.params.get("min").and_then(|min| min.as_u64()) == Some(1);
+            let is_empty = error.params.get("value").is_some_and(|value| {
+                value.as_str() == Some("") || value.as_array().is_some_and(Vec::is_empty)
+            });
+
+            if requires_value && is_empty {
+                ValidationErrorCode::FieldRequired
+            } else {
+                ValidationErrorCode::Length
+            }
+        }
+        "range" => ValidationErrorCode::Range,
+        "email" => ValidationErrorCode::InvalidEmail,
+        "url" => ValidationErrorCode::InvalidUrl,
+        "regex" => ValidationErrorCode::InvalidFormat,
+        "must_match" => ValidationErrorCode::CrossField,
+        _ => ValidationErrorCode::Invalid,
+    }
+}
+
 /// Converts validator field errors into an [`ApiErrorResponse::Validation`] variant.
+///
+/// Each error is given a machine-readable code by
+/// [`validation_error_code`]. Errors in nested structs and lists are
+/// reported under their path, such as `location.latitude` or
+/// `moves[0].position`.
 impl From<validator::ValidationErrors> for ApiErrorResponse {
     fn from(errs: validator::ValidationErrors) -> Self {
-        let validation_errors = errs
-            .field_errors()
-            .into_iter()
-            .flat_map(|(field, errors)| {
-                errors.iter().map(move |e| ValidationError {
-                    field: Some(field.to_string()),
-                    message: e.message.clone().map(|m| m.to_string()).unwrap_or_default(),
-                })
-            })
-            .collect();
+        let mut validation_errors = Vec::new();
+        collect_validation_errors(&errs, None, &mut validation_errors);
 
         ApiErrorResponse::Validation(validation_errors)
     }
 }
 
+/// Flattens validator errors, including those of nested structs and lists.
+///
+/// # Arguments
+///
+/// * `errs` — The errors to flatten.
+/// * `prefix` — The path of the struct the errors belong to, or `None` at
+///   the top level.
+/// * `output` — The list the flattened errors are appended to.
+fn collect_validation_errors(
+    errs: &validator::ValidationErrors,
+    prefix: Option<&str>,
+    output: &mut Vec<ValidationError>,
+) {
+    for (field, kind) in errs.errors() {
+        let path = match prefix {
+            Some(prefix) => format!("{prefix}.{field}"),
+            None => field.to_string(),
+        };
+
+        match kind {
+            validator::ValidationErrorsKind::Field(errors) => {
+                output.extend(errors.iter().map(|e| ValidationError {
+                    field: Some(path.clone()),
+                    code: validation_error_code(field, e),
+                    message: e.message.clone().map(|m| m.to_string()).unwrap_or_default(),
+                }));
+            }
+            validator::ValidationErrorsKind::Struct(errors) => {
+                collect_validation_errors(errors, Some(&path), output);
+            }
+            validator::ValidationErrorsKind::List(items) => {
+                for (index, errors) in items {
+                    collect_validation_errors(errors, Some(&format!("{path}[{index}]")), output);
+                }
+            }
+        }
+    }
+}
+
+/// Converts a struct-level validator error into an
+/// [`ApiErrorResponse::Validation`] variant.
+///
+/// Used when a cross-field validator is re-run outside of
+/// [`validator::Validate`], such as against merged partial updates.
+impl From<validator::ValidationError> for ApiErrorResponse {
+    fn from(err: validator::ValidationError) -> Self {
+        let mut errors = validator::ValidationErrors::new();
+        errors.add(SCHEMA_ERROR_FIELD, err);
+
+        errors.into()
+    }
+}
+
 /// Converts an Axum JSON extraction rejection into an [`ApiErrorResponse::BadRequest`].
 impl From<JsonRejection> for ApiErrorResponse {
     fn from(err: JsonRejection) -> Self {
         ApiErrorResponse::BadRequest(err.body_text())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use validator::Validate;
+
+    use super::*;
+
+    #[derive(Validate)]
+    #[validate(schema(function = "validate_dates"))]
+    struct Payload {
+        #[validate(length(min = 1, max = 5))]
+        name: String,
+        #[validate(range(min = 1))]
+        count: i32,
+        #[validate(email)]
+        email: String,
+    }
+
+    fn validate_dates(_: &Payload) -> Result<(), validator::ValidationError> {
+        Err(validator::ValidationError::new("invalid_dates"))
+    }
+
+    fn codes(payload: Payload) -> Vec<(String, ValidationErrorCode)> {
+        let ApiErrorResponse::Validation(errors) = payload.validate().unwrap_err().into() else {
+            panic!("expected validation error");
+        };
+        let mut codes: Vec<_> = errors
+            .into_iter()
+            .map(|error| (error.field.unwrap(), error.code))
+            .collect();
+        codes.sort_by(|left, right| left.0.cmp(&right.0));
+
+        codes
+    }
+
+    #[test]
+    fn maps_validator_errors_to_codes() {
+        let payload = Payload {
+            name: String::new(),
+            count: 0,
+            email: "not-an-email".to_string(),
+        };
+
+        assert_eq!(
+            codes(payload),
+            vec![
+                ("count".to_string(), ValidationErrorCode::Range),
+                ("email".to_string(), ValidationErrorCode::InvalidEmail),
+                ("name".to_string(), ValidationErrorCode::FieldRequired),
+            ]
+        );
+    }
+
+    #[test]
+    fn maps_schema_errors_to_cross_field() {
+        let payload = Payload {
+            name: "Jo".to_string(),
+            count: 1,
+            email: "jo@example.com".to_string(),
+        };
+
+        assert_eq!(
+            codes(payload),
+            vec![(
+                SCHEMA_ERROR_FIELD.to_string(),
+                ValidationErrorCode::CrossField
+            )]
+        );
+    }
+
+    #[test]
+    fn maps_struct_level_errors_to_cross_field() {
+        let ApiErrorResponse::Validation(errors) =
+            validator::ValidationError::new("invalid_dates").into()
+        else {
+            panic!("expected validation error");
+        };
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, ValidationErrorCode::CrossField);
+    }
+
+    #[derive(Validate)]
+    struct Outer {
+        #[validate(nested)]
+        inner: Inner,
+    }
+
+    #[derive(Validate)]
+    struct Inner {
+        #[validate(range(max = 90))]
+        latitude: i32,
+    }
+
+    #[test]
+    fn reports_nested_errors_under_their_path() {
+        let payload = Outer {
+            inner: Inner { latitude: 91 },
+        };
+
+        let ApiErrorResponse::Validation(errors) = payload.validate().unwrap_err().into() else {
+            panic!("expected validation error");
+        };
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field.as_deref(), Some("inner.latitude"));
+        assert_eq!(errors[0].code, ValidationErrorCode::Range);
+    }
+
+    #[test]
+    fn maps_too_long_values_to_length() {
+        let payload = Payload {
+            name: "too long".to_string(),
+            count: 1,
+            email: "jo@example.com".to_string(),
+        };
+
+        assert!(codes(payload).contains(&("name".to_string(), ValidationErrorCode::Length)));
+    }
+}
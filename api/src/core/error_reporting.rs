@@ -0,0 +1,366 @@
+//! Optional reporting of server errors to a Sentry-compatible endpoint.
+//!
+//! Provides [`ErrorReporter`], which is built from
+//! [`Config::error_reporting_dsn`](crate::core::config::Config::error_reporting_dsn)
+//! and sends events to the DSN's store endpoint, and an Axum middleware that
+//! captures [`ApiErrorResponse::InternalServerError`](crate::core::error::ApiErrorResponse::InternalServerError)
+//! responses and handler panics together with their request context.
+
+use std::any::Any;
+
+use axum::{
+    extract::{MatchedPath, Request, State},
+    http::{HeaderMap, HeaderName, HeaderValue, Method},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use chrono::Utc;
+use log::{error, warn};
+use rand::RngExt;
+use reqwest::{Client, Url};
+use serde_json::{Value, json};
+use uuid::Uuid;
+
+use crate::{
+    auth::{AuthUser, jwt::JwtUtil},
+    core::{config::Config, error::ApiErrorResponse},
+    routes::app::AppState,
+};
+
+/// Header carrying the request ID shared between logs and error reports.
+pub const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Response extension holding the internal message of a server error.
+///
+/// Inserted by [`ApiErrorResponse`]'s `IntoResponse` implementation so the
+/// reporting middleware can see the original message after it has been
+/// hidden from the client.
+#[derive(Debug, Clone)]
+pub struct InternalErrorMessage(pub String);
+
+/// Request context attached to a reported error.
+#[derive(Debug, Clone)]
+pub struct ErrorContext {
+    /// HTTP method of the failed request.
+    pub method: Method,
+    /// Matched route pattern, or the raw path when no route matched.
+    pub route: String,
+    /// Request ID shared with the HTTP logs.
+    pub request_id: Uuid,
+    /// Authenticated user, when the request carried a valid access token.
+    pub user_id: Option<Uuid>,
+}
+
+/// Parsed components of a Sentry-style DSN.
+#[derive(Debug, Clone, PartialEq)]
+struct Dsn {
+    /// Public key sent in the auth header.
+    public_key: String,
+    /// Fully resolved store endpoint URL.
+    store_url: Url,
+}
+
+/// Sends error events to a Sentry-compatible store endpoint.
+#[derive(Debug, Clone)]
+pub struct ErrorReporter {
+    /// HTTP client used to deliver events.
+    client: Client,
+    /// Parsed DSN the events are sent to.
+    dsn: Dsn,
+    /// Fraction of errors that are reported.
+    sample_rate: f64,
+    /// Application environment name attached to events.
+    environment: String,
+}
+
+impl ErrorReporter {
+    /// Creates an [`ErrorReporter`] from configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` — The application [`Config`].
+    ///
+    /// # Returns
+    ///
+    /// `Some` reporter when a valid DSN is configured, `None` when reporting
+    /// is disabled or the DSN cannot be parsed.
+    pub fn from_config(config: &Config) -> Option<Self> {
+        let raw_dsn = config.error_reporting_dsn.as_deref()?;
+
+        match Self::parse_dsn(raw_dsn) {
+            Some(dsn) => Some(Self {
+                client: Client::new(),
+                dsn,
+                sample_rate: config.error_reporting_sample_rate,
+                environment: config.app_env.clone(),
+            }),
+            None => {
+                error!("Invalid ERROR_REPORTING_DSN; error reporting is disabled");
+                None
+            }
+        }
+    }
+
+    /// Axum middleware that reports server errors and panics.
+    ///
+    /// Assigns a request ID (reusing a valid incoming `X-Request-Id`) that is
+    /// echoed on the response, runs the request on its own task so panics
+    /// can be caught, and reports any response carrying an
+    /// [`InternalErrorMessage`]. Passes requests through unchanged when
+    /// reporting is disabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `request` — The incoming HTTP request.
+    /// * `next` — The next middleware or handler in the chain.
+    ///
+    /// # Returns
+    ///
+    /// The downstream [`Response`], or a `500` response if the handler
+    /// panicked.
+    pub async fn capture_errors(
+        State(state): State<AppState>,
+        mut request: Request,
+        next: Next,
+    ) -> Response {
+        let Some(reporter) = state.error_reporter.clone() else {
+            return next.run(request).await;
+        };
+
+        let request_id = request
+            .headers()
+            .get(&REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| Uuid::parse_str(value).ok())
+            .unwrap_or_else(Uuid::new_v4);
+        let request_id_value = HeaderValue::from_str(&request_id.to_string())
+            .unwrap_or_else(|_| HeaderValue::from_static(""));
+        request
+            .headers_mut()
+            .insert(REQUEST_ID_HEADER, request_id_value.clone());
+
+        let context = ErrorContext {
+            method: request.method().clone(),
+            route: request
+                .extensions()
+                .get::<MatchedPath>()
+                .map(|path| path.as_str().to_string())
+                .unwrap_or_else(|| request.uri().path().to_string()),
+            request_id,
+            user_id: Self::user_id(request.headers(), &state.config),
+        };
+
+        let mut response = match tokio::spawn(next.run(request)).await {
+            Ok(response) => {
+                if let Some(InternalErrorMessage(message)) =
+                    response.extensions().get::<InternalErrorMessage>()
+                {
+                    reporter.report(message, "error", &context);
+                }
+
+                response
+            }
+            Err(join_error) => {
+                let message = match join_error.try_into_panic() {
+                    Ok(payload) => format!("Handler panicked: {}", Self::panic_message(&payload)),
+                    Err(join_error) => format!("Handler task failed: {join_error}"),
+                };
+
+                reporter.report(&message, "fatal", &context);
+                ApiErrorResponse::InternalServerError(message).into_response()
+            }
+        };
+
+        response
+            .headers_mut()
+            .insert(REQUEST_ID_HEADER, request_id_value);
+
+        response
+    }
+
+    /// Reports an error event, subject to the configured sample rate.
+    ///
+    /// Delivery happens on a background task; failures are logged.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` — The error message.
+    /// * `level` — Sentry event level, such as `"error"` or `"fatal"`.
+    /// * `context` — The [`ErrorContext`] of the failed request.
+    pub fn report(&self, message: &str, level: &str, context: &ErrorContext) {
+        if self.sample_rate < 1.0 && rand::rng().random::<f64>() >= self.sample_rate {
+            return;
+        }
+
+        let event = self.build_event(message, level, context);
+        let reporter = self.clone();
+
+        tokio::spawn(async move {
+            let result = reporter
+                .client
+                .post(reporter.dsn.store_url.clone())
+                .header("X-Sentry-Auth", reporter.auth_header())
+                .json(&event)
+                .send()
+                .await
+                .and_then(|response| response.error_for_status());
+
+            if let Err(error) = result {
+                warn!("Failed to deliver error report: {}", error);
+            }
+        });
+    }
+
+    /// Builds the JSON event payload for the store endpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` — The error message.
+    /// * `level` — Sentry event level.
+    /// * `context` — The [`ErrorContext`] of the failed request.
+    ///
+    /// # Returns
+    ///
+    /// The event as a JSON [`Value`].
+    fn build_event(&self, message: &str, level: &str, context: &ErrorContext) -> Value {
+        json!({
+            "event_id": Uuid::new_v4().simple().to_string(),
+            "timestamp": Utc::now().to_rfc3339(),
+            "platform": "other",
+            "level": level,
+            "logger": "gig-log-api",
+            "release": concat!("gig-log-api@", env!("CARGO_PKG_VERSION")),
+            "environment": self.environment,
+            "message": { "formatted": message },
+            "transaction": format!("{} {}", context.method, context.route),
+            "request": { "method": context.method.as_str() },
+            "user": context.user_id.map(|id| json!({ "id": id.to_string() })),
+            "tags": {
+                "route": context.route,
+                "request_id": context.request_id.to_string(),
+            },
+        })
+    }
+
+    /// Builds the `X-Sentry-Auth` header value.
+    ///
+    /// # Returns
+    ///
+    /// The header value identifying the client and public key.
+    fn auth_header(&self) -> String {
+        format!(
+            "Sentry sentry_version=7, sentry_client=gig-log-api/{}, sentry_key={}",
+            env!("CARGO_PKG_VERSION"),
+            self.dsn.public_key
+        )
+    }
+
+    /// Parses a `https://<public_key>@<host>[/<path>]/<project_id>` DSN.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw` — The DSN string.
+    ///
+    /// # Returns
+    ///
+    /// The parsed [`Dsn`], or `None` if the DSN is malformed.
+    fn parse_dsn(raw: &str) -> Option<Dsn> {
+        let url = Url::parse(raw.trim()).ok()?;
+        let public_key = url.username();
+
+        if public_key.is_empty() || url.host_str().is_none() {
+            return None;
+        }
+
+        let path = url.path().trim_end_matches('/');
+        let (prefix, project_id) = path.rsplit_once('/')?;
+
+        if project_id.is_empty() {
+            return None;
+        }
+
+        let mut store_url = url.clone();
+        store_url.set_username("").ok()?;
+        store_url.set_password(None).ok()?;
+        store_url.set_path(&format!("{prefix}/api/{project_id}/store/"));
+        store_url.set_query(None);
+
+        Some(Dsn {
+            public_key: public_key.to_string(),
+            store_url,
+        })
+    }
+
+    /// Resolves the authenticated user from the request's access token.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` — The request headers.
+    /// * `config` — The application [`Config`] used for JWT validation.
+    ///
+    /// # Returns
+    ///
+    /// The user ID, or `None` if the request is unauthenticated.
+    fn user_id(headers: &HeaderMap, config: &Config) -> Option<Uuid> {
+        let token = AuthUser::access_token(headers)?;
+
+        JwtUtil::decode_access_token(token, config)
+            .ok()
+            .map(|token_data| token_data.claims.sub)
+    }
+
+    /// Extracts a readable message from a panic payload.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` — The panic payload.
+    ///
+    /// # Returns
+    ///
+    /// The panic message, or a placeholder for non-string payloads.
+    fn panic_message(payload: &Box<dyn Any + Send>) -> String {
+        if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "unknown panic payload".to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_dsn_into_store_url() {
+        let dsn = ErrorReporter::parse_dsn("https://abc123@errors.example.com/42")
+            .expect("dsn should parse");
+
+        assert_eq!(dsn.public_key, "abc123");
+        assert_eq!(
+            dsn.store_url.as_str(),
+            "https://errors.example.com/api/42/store/"
+        );
+    }
+
+    #[test]
+    fn keeps_dsn_path_prefix() {
+        let dsn = ErrorReporter::parse_dsn("http://key@localhost:9000/sentry/7/")
+            .expect("dsn should parse");
+
+        assert_eq!(
+            dsn.store_url.as_str(),
+            "http://localhost:9000/sentry/api/7/store/"
+        );
+    }
+
+    #[test]
+    fn rejects_dsn_without_key_or_project() {
+        assert!(ErrorReporter::parse_dsn("https://errors.example.com/42").is_none());
+        assert!(ErrorReporter::parse_dsn("https://key@errors.example.com/").is_none());
+        assert!(ErrorReporter::parse_dsn("not a url").is_none());
+    }
+}
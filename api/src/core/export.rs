@@ -0,0 +1,221 @@
+//! Streaming CSV exports.
+//!
+//! Provides [`CsvExport`], which turns records produced by a background
+//! query into a streamed `text/csv` download, and the [`CsvRecord`] trait
+//! that export rows implement to define their columns. Fields are escaped
+//! as described in RFC 4180 and every row ends with CRLF. Text that a
+//! spreadsheet would evaluate as a formula is prefixed with `'` so it is
+//! shown as entered.
+
+use std::borrow::Cow;
+use std::io;
+
+use axum::{
+    body::Body,
+    http::header,
+    response::{IntoResponse, Response},
+};
+use futures_util::{Stream, StreamExt, stream};
+use log::error;
+use tokio::sync::mpsc;
+
+use crate::core::error::ApiResult;
+
+/// Rows buffered between the export query and the response body.
+const ROW_BUFFER: usize = 64;
+
+/// Leading characters that make spreadsheet applications treat a cell as a
+/// formula.
+const FORMULA_PREFIXES: [char; 6] = ['=', '+', '-', '@', '\t', '\r'];
+
+/// A record that can be written as one row of a CSV export.
+pub trait CsvRecord {
+    /// Column names written as the header row, in output order.
+    const COLUMNS: &'static [&'static str];
+
+    /// Returns the record's values in the order of [`Self::COLUMNS`].
+    ///
+    /// # Returns
+    ///
+    /// One formatted value per column. Absent values are empty strings.
+    fn fields(&self) -> Vec<String>;
+}
+
+/// Sends the rows of a CSV export to its response body.
+pub struct CsvWriter {
+    /// Channel feeding the response body.
+    sender: mpsc::Sender<io::Result<String>>,
+}
+
+impl CsvWriter {
+    /// Writes a header row and one row per record.
+    ///
+    /// Stops early without an error if the client disconnects.
+    ///
+    /// # Arguments
+    ///
+    /// * `records` — The records to write, typically a database cursor.
+    ///
+    /// # Returns
+    ///
+    /// An empty [`ApiResult`] once every record is written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`](crate::core::error::ApiErrorResponse)
+    /// if a record cannot be fetched.
+    pub async fn write_all<T, S>(self, records: S) -> ApiResult<()>
+    where
+        T: CsvRecord,
+        S: Stream<Item = Result<T, sqlx::Error>>,
+    {
+        if self
+            .sender
+            .send(Ok(CsvExport::row(T::COLUMNS)))
+            .await
+            .is_err()
+        {
+            return Ok(());
+        }
+
+        let mut records = std::pin::pin!(records);
+        while let Some(record) = records.next().await {
+            let row = CsvExport::row(&record?.fields());
+            if self.sender.send(Ok(row)).await.is_err() {
+                return Ok(());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Utility for building CSV export responses.
+pub struct CsvExport;
+
+impl CsvExport {
+    /// Streams a CSV export as a file download.
+    ///
+    /// Runs `export` on a background task and forwards each row it writes
+    /// to the response body as it is produced. If the export fails part
+    /// way through, the error is logged and the body is aborted so the
+    /// client does not mistake the partial file for a complete one.
+    ///
+    /// # Arguments
+    ///
+    /// * `filename` — Name suggested to the client for the downloaded file.
+    /// * `export` — Writes the export's rows to the given [`CsvWriter`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Response`] with a `text/csv` attachment body.
+    pub fn response<F, Fut>(filename: &str, export: F) -> Response
+    where
+        F: FnOnce(CsvWriter) -> Fut,
+        Fut: Future<Output = ApiResult<()>> + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel(ROW_BUFFER);
+        let failure = sender.clone();
+        let export = export(CsvWriter { sender });
+
+        tokio::spawn(async move {
+            if let Err(error) = export.await {
+                error!("CSV export failed: {:?}", error);
+                let _ = failure
+                    .send(Err(io::Error::other("CSV export failed")))
+                    .await;
+            }
+        });
+
+        let rows = stream::unfold(receiver, |mut receiver| async move {
+            receiver.recv().await.map(|row| (row, receiver))
+        });
+
+        (
+            [
+                (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"{filename}\""),
+                ),
+            ],
+            Body::from_stream(rows),
+        )
+            .into_response()
+    }
+
+    /// Formats one CSV row.
+    ///
+    /// # Arguments
+    ///
+    /// * `fields` — The row's values in column order.
+    ///
+    /// # Returns
+    ///
+    /// The escaped values joined by commas and terminated by CRLF.
+    pub fn row<S: AsRef<str>>(fields: &[S]) -> String {
+        let mut row = fields
+            .iter()
+            .map(|field| Self::escape(field.as_ref()))
+            .collect::<Vec<_>>()
+            .join(",");
+        row.push_str("\r\n");
+        row
+    }
+
+    /// Escapes one CSV field.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` — The raw value.
+    ///
+    /// # Returns
+    ///
+    /// The value prefixed with `'` if it could be read as a formula, then
+    /// wrapped in double quotes with embedded quotes doubled if it contains
+    /// a comma, quote, or line break. Numbers such as `-12.50` are left
+    /// unprefixed so they stay numeric.
+    fn escape(field: &str) -> Cow<'_, str> {
+        let field = if field.starts_with(FORMULA_PREFIXES) && field.parse::<f64>().is_err() {
+            Cow::Owned(format!("'{field}"))
+        } else {
+            Cow::Borrowed(field)
+        };
+
+        if field.contains([',', '"', '\r', '\n']) {
+            Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+        } else {
+            field
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_fields_per_rfc_4180() {
+        assert_eq!(
+            CsvExport::row(&["plain", "a,b", "say \"hi\"", "two\nlines", ""]),
+            "plain,\"a,b\",\"say \"\"hi\"\"\",\"two\nlines\",\r\n"
+        );
+    }
+
+    #[test]
+    fn prefixes_fields_that_start_a_formula() {
+        assert_eq!(
+            CsvExport::row(&["=SUM(A1:A2)", "+1 555 0100", "-1+1", "@cmd", "\tx", "a=b"]),
+            "'=SUM(A1:A2),'+1 555 0100,'-1+1,'@cmd,'\tx,a=b\r\n"
+        );
+        assert_eq!(
+            CsvExport::row(&["=HYPERLINK(\"x\",\"y\")"]),
+            "\"'=HYPERLINK(\"\"x\"\",\"\"y\"\")\"\r\n"
+        );
+    }
+
+    #[test]
+    fn leaves_negative_numbers_numeric() {
+        assert_eq!(CsvExport::row(&["-12.50", "-3"]), "-12.50,-3\r\n");
+    }
+}
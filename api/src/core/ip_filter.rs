@@ -0,0 +1,245 @@
+//! IP address allowlist and denylist enforcement.
+//!
+//! Provides [`IpFilter`], an Axum middleware that rejects requests from
+//! addresses in [`Config::ip_denylist`](crate::core::config::Config::ip_denylist)
+//! on every path, and requests to the allowlisted paths from addresses
+//! outside [`Config::ip_allowlist`](crate::core::config::Config::ip_allowlist). Denied attempts are logged for auditing.
+//! Allowlisted path prefixes also cover the same paths under every API
+//! version prefix.
+
+use std::net::{IpAddr, SocketAddr};
+
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::HeaderMap,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use ipnet::IpNet;
+use log::warn;
+
+use crate::{
+    core::{error::ApiErrorResponse, versioning::ApiVersion},
+    routes::app::AppState,
+};
+
+/// Middleware that filters requests by client IP address.
+pub struct IpFilter;
+
+impl IpFilter {
+    /// Axum middleware that rejects requests the configured IP lists deny.
+    ///
+    /// Denied requests get a `403 Forbidden` response and a warning is
+    /// logged with the method, path, client address, and reason. When
+    /// neither list is configured, requests pass through untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `request` — The incoming HTTP request.
+    /// * `next` — The next middleware or handler in the chain.
+    ///
+    /// # Returns
+    ///
+    /// The HTTP [`Response`] produced by downstream handlers, or a
+    /// `403 Forbidden` response if the request is denied.
+    pub async fn enforce(State(state): State<AppState>, request: Request, next: Next) -> Response {
+        let config = &state.config;
+
+        if config.ip_allowlist.is_empty() && config.ip_denylist.is_empty() {
+            return next.run(request).await;
+        }
+
+        let peer = request
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(address)| address.ip());
+        let client_ip =
+            Self::client_ip(request.headers(), peer, config.ip_filter_trusted_proxy_hops);
+
+        let Some(reason) = Self::denial_reason(
+            &config.ip_allowlist,
+            &config.ip_allowlist_paths,
+            &config.ip_denylist,
+            client_ip,
+            request.uri().path(),
+        ) else {
+            return next.run(request).await;
+        };
+
+        warn!(
+            "IP filter denied {} {} from {}: {}",
+            request.method(),
+            request.uri().path(),
+            client_ip.map_or_else(|| "unknown address".to_string(), |ip| ip.to_string()),
+            reason
+        );
+
+        ApiErrorResponse::Forbidden("Access denied".to_string()).into_response()
+    }
+
+    /// Decides whether the IP lists deny a request.
+    ///
+    /// # Arguments
+    ///
+    /// * `allowlist` — Ranges allowed to reach the allowlisted paths.
+    /// * `allowlist_paths` — Path prefixes restricted to the allowlist.
+    /// * `denylist` — Ranges denied on every path.
+    /// * `client_ip` — The client's address, or `None` if it is unknown.
+    /// * `path` — The request path.
+    ///
+    /// # Returns
+    ///
+    /// Why the request is denied, or `None` if it is allowed. Unknown
+    /// addresses are allowed unless the path is allowlisted.
+    fn denial_reason(
+        allowlist: &[IpNet],
+        allowlist_paths: &[String],
+        denylist: &[IpNet],
+        client_ip: Option<IpAddr>,
+        path: &str,
+    ) -> Option<&'static str> {
+        if client_ip.is_some_and(|ip| denylist.iter().any(|range| range.contains(&ip))) {
+            return Some("address is denylisted");
+        }
+
+        let unversioned_path = ApiVersion::strip_prefix(path);
+        let restricted = !allowlist.is_empty()
+            && allowlist_paths.iter().any(|prefix| {
+                Self::path_matches(path, prefix) || Self::path_matches(unversioned_path, prefix)
+            });
+        let allowed = client_ip.is_some_and(|ip| allowlist.iter().any(|range| range.contains(&ip)));
+
+        (restricted && !allowed).then_some("address is not allowlisted for this path")
+    }
+
+    /// Checks whether a path falls under a prefix at a segment boundary.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` — The request path.
+    /// * `prefix` — The configured path prefix, such as `/admin`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the path is the prefix or lies beneath it, so `/admin`
+    /// matches `/admin/users` but not `/administrators`.
+    fn path_matches(path: &str, prefix: &str) -> bool {
+        let prefix = prefix.trim_end_matches('/');
+
+        match path.strip_prefix(prefix) {
+            Some(rest) => rest.is_empty() || rest.starts_with('/') || prefix.is_empty(),
+            None => false,
+        }
+    }
+
+    /// Resolves the client address, trusting a fixed number of proxies.
+    ///
+    /// The peer address and the `X-Forwarded-For` entries form a chain
+    /// ending at the peer. Each trusted proxy hop moves one entry further
+    /// from the peer, so entries a client added before reaching the proxies
+    /// are never used.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` — The request headers.
+    /// * `peer` — The address of the connecting socket, if known.
+    /// * `trusted_proxy_hops` — Number of reverse proxies in front of the
+    ///   API.
+    ///
+    /// # Returns
+    ///
+    /// The client's address with IPv4-mapped IPv6 addresses converted to
+    /// IPv4, or `None` if it is unknown or not a valid address.
+    fn client_ip(
+        headers: &HeaderMap,
+        peer: Option<IpAddr>,
+        trusted_proxy_hops: usize,
+    ) -> Option<IpAddr> {
+        let peer = peer?;
+
+        if trusted_proxy_hops == 0 {
+            return Some(peer.to_canonical());
+        }
+
+        let forwarded: Vec<&str> = headers
+            .get_all("x-forwarded-for")
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(str::trim)
+            .collect();
+        let index = forwarded.len().checked_sub(trusted_proxy_hops)?;
+
+        forwarded[index]
+            .parse::<IpAddr>()
+            .ok()
+            .map(|ip| ip.to_canonical())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderValue;
+
+    use super::*;
+
+    fn ip(value: &str) -> Option<IpAddr> {
+        Some(value.parse().unwrap())
+    }
+
+    fn ranges(values: &[&str]) -> Vec<IpNet> {
+        values.iter().map(|value| value.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn denies_denylisted_and_unlisted_addresses() {
+        let allowlist = ranges(&["10.0.0.0/8"]);
+        let paths = vec!["/admin".to_string(), "/metrics".to_string()];
+        let denylist = ranges(&["203.0.113.0/24"]);
+        let deny = |client_ip: Option<IpAddr>, path: &str| {
+            IpFilter::denial_reason(&allowlist, &paths, &denylist, client_ip, path).is_some()
+        };
+
+        assert!(deny(ip("203.0.113.9"), "/health"));
+        assert!(!deny(ip("198.51.100.1"), "/health"));
+        assert!(!deny(None, "/health"));
+        assert!(!deny(ip("10.1.2.3"), "/metrics"));
+        assert!(deny(ip("198.51.100.1"), "/metrics"));
+        assert!(deny(ip("198.51.100.1"), "/admin/users"));
+        assert!(deny(None, "/admin"));
+        assert!(deny(ip("198.51.100.1"), "/v1/admin/users"));
+        assert!(!deny(ip("10.1.2.3"), "/v1/metrics"));
+        assert!(!deny(ip("198.51.100.1"), "/administrators"));
+        assert!(
+            IpFilter::denial_reason(&[], &paths, &[], ip("198.51.100.1"), "/metrics").is_none()
+        );
+    }
+
+    #[test]
+    fn matches_path_prefixes_at_segment_boundaries() {
+        assert!(IpFilter::path_matches("/admin", "/admin"));
+        assert!(IpFilter::path_matches("/admin/users", "/admin/"));
+        assert!(!IpFilter::path_matches("/administrators", "/admin"));
+        assert!(IpFilter::path_matches("/anything", "/"));
+    }
+
+    #[test]
+    fn resolves_client_ip_from_trusted_hops() {
+        let peer = ip("10.0.0.2");
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-forwarded-for",
+            HeaderValue::from_static("1.1.1.1, 203.0.113.7, 10.0.0.1"),
+        );
+
+        assert_eq!(IpFilter::client_ip(&headers, peer, 0), peer);
+        assert_eq!(IpFilter::client_ip(&headers, peer, 1), ip("10.0.0.1"));
+        assert_eq!(IpFilter::client_ip(&headers, peer, 2), ip("203.0.113.7"));
+        assert_eq!(IpFilter::client_ip(&headers, peer, 4), None);
+        assert_eq!(
+            IpFilter::client_ip(&HeaderMap::new(), ip("::ffff:10.0.0.2"), 0),
+            peer
+        );
+    }
+}
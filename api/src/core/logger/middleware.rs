@@ -19,6 +19,8 @@ use axum::{
 use log::Level;
 use uuid::Uuid;
 
+use crate::core::error_reporting::REQUEST_ID_HEADER;
+
 use super::{
     Logger,
     formatting::{log_compact_http, log_request, log_response},
@@ -75,7 +77,9 @@ impl Logger {
     /// Axum middleware that logs each HTTP request and its response.
     ///
     /// In verbose mode, prints detailed headers and bodies; otherwise prints
-    /// a compact one-line summary. Body logging is gated on
+    /// a compact one-line summary. Reuses the request ID from the
+    /// `X-Request-Id` header when present so logs match error reports.
+    /// Body logging is gated on
     /// [`HttpLoggingConfig::body_enabled`] and respects the configured
     /// [`max_body_bytes`](HttpLoggingConfig::max_body_bytes) limit.
     ///
@@ -97,7 +101,12 @@ impl Logger {
             return next.run(request).await;
         }
 
-        let request_id = Uuid::new_v4();
+        let request_id = request
+            .headers()
+            .get(&REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| Uuid::parse_str(value).ok())
+            .unwrap_or_else(Uuid::new_v4);
         let use_verbose_http_logs = config.verbose || config.body_enabled;
         let (request_parts, request_body) = request.into_parts();
         let method = request_parts.method.clone();
@@ -5,12 +5,24 @@
 //!
 //! # Modules
 //!
+//! - [`activity`](crate::core::activity) — Per-user API activity recording.
 //! - [`app`](crate::core::app) — Application entry point and bootstrap sequence.
 //! - [`config`](crate::core::config) — Environment-based configuration.
 //! - [`error`](crate::core::error) — API error types and HTTP response conversions.
+//! - [`error_reporting`](crate::core::error_reporting) — Optional Sentry-compatible error reporting.
+//! - [`export`](crate::core::export) — Streaming CSV exports.
+//! - [`ip_filter`](crate::core::ip_filter) — IP address allowlist and denylist middleware.
 //! - [`logger`](crate::core::logger) — Structured logging setup.
+//! - [`problem`](crate::core::problem) — RFC 7807 problem details error responses.
+//! - [`versioning`](crate::core::versioning) — API version prefixes and legacy path deprecation.
 
+pub mod activity;
 pub mod app;
 pub mod config;
 pub mod error;
+pub mod error_reporting;
+pub mod export;
+pub mod ip_filter;
 pub mod logger;
+pub mod problem;
+pub mod versioning;
@@ -0,0 +1,203 @@
+//! RFC 7807 problem details error responses.
+//!
+//! Provides [`ProblemJson`], an Axum middleware that re-renders
+//! [`ApiError`] responses as `application/problem+json` when the client's
+//! `Accept` header prefers that media type. Other clients keep receiving
+//! the default [`ApiError`] shape.
+
+use axum::{
+    body::Body,
+    extract::Request,
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    middleware::Next,
+    response::Response,
+};
+use gig_log_common::models::error::{ApiError, ProblemDetails};
+use log::error;
+
+/// Media type of RFC 7807 problem details.
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+/// Middleware that negotiates the problem details error format.
+pub struct ProblemJson;
+
+impl ProblemJson {
+    /// Axum middleware that renders error responses as problem details for
+    /// clients that prefer them.
+    ///
+    /// Only responses produced from an
+    /// [`ApiErrorResponse`](crate::core::error::ApiErrorResponse) are
+    /// rewritten.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` — The incoming HTTP request.
+    /// * `next` — The next middleware or handler in the chain.
+    ///
+    /// # Returns
+    ///
+    /// The downstream [`Response`], with its error body re-rendered when
+    /// requested.
+    pub async fn negotiate(request: Request, next: Next) -> Response {
+        let prefers_problem_json = Self::prefers_problem_json(request.headers());
+        let response = next.run(request).await;
+
+        if !prefers_problem_json {
+            return response;
+        }
+
+        Self::render(response)
+    }
+
+    /// Re-renders an error response's body as problem details.
+    ///
+    /// # Arguments
+    ///
+    /// * `response` — The response to re-render.
+    ///
+    /// # Returns
+    ///
+    /// The response with an `application/problem+json` body, or unchanged
+    /// if it does not carry an [`ApiError`].
+    fn render(mut response: Response) -> Response {
+        let Some(api_error) = response.extensions_mut().remove::<ApiError>() else {
+            return response;
+        };
+
+        let body = match serde_json::to_vec(&Self::problem_details(api_error)) {
+            Ok(body) => body,
+            Err(error) => {
+                error!("Failed to serialize problem details: {}", error);
+                return response;
+            }
+        };
+
+        let headers = response.headers_mut();
+        headers.insert(header::CONTENT_TYPE, HeaderValue::from_static(PROBLEM_JSON));
+        headers.remove(header::CONTENT_LENGTH);
+        *response.body_mut() = Body::from(body);
+
+        response
+    }
+
+    /// Converts an [`ApiError`] into [`ProblemDetails`].
+    ///
+    /// # Arguments
+    ///
+    /// * `api_error` — The error to convert.
+    ///
+    /// # Returns
+    ///
+    /// Problem details with the status's reason phrase as the title and the
+    /// error message as the detail.
+    pub fn problem_details(api_error: ApiError) -> ProblemDetails {
+        let title = StatusCode::from_u16(api_error.status_code)
+            .ok()
+            .and_then(|status| status.canonical_reason())
+            .unwrap_or("Error");
+
+        ProblemDetails {
+            problem_type: "about:blank".to_string(),
+            title: title.to_string(),
+            status: api_error.status_code,
+            detail: api_error.message,
+            errors: api_error.errors,
+        }
+    }
+
+    /// Checks whether a request's `Accept` header prefers problem details
+    /// over plain JSON.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` — The request headers.
+    ///
+    /// # Returns
+    ///
+    /// `true` if `application/problem+json` is accepted with a quality at
+    /// least as high as `application/json`.
+    fn prefers_problem_json(headers: &HeaderMap) -> bool {
+        let mut problem_quality = 0.0;
+        let mut json_quality = 0.0;
+
+        for accept in headers.get_all(header::ACCEPT) {
+            let Ok(accept) = accept.to_str() else {
+                continue;
+            };
+
+            for media_range in accept.split(',') {
+                let mut parts = media_range.split(';').map(str::trim);
+                let media_type = parts.next().unwrap_or_default().to_ascii_lowercase();
+                let quality = parts
+                    .find_map(|param| param.strip_prefix("q="))
+                    .and_then(|quality| quality.parse::<f32>().ok())
+                    .unwrap_or(1.0);
+
+                match media_type.as_str() {
+                    PROBLEM_JSON => problem_quality = quality,
+                    "application/json" => json_quality = quality,
+                    _ => {}
+                }
+            }
+        }
+
+        problem_quality > 0.0 && problem_quality >= json_quality
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::response::IntoResponse;
+
+    use super::*;
+    use crate::core::error::ApiErrorResponse;
+
+    fn accept(value: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ACCEPT, HeaderValue::from_static(value));
+
+        headers
+    }
+
+    #[test]
+    fn negotiates_problem_json_from_accept() {
+        assert!(ProblemJson::prefers_problem_json(&accept(PROBLEM_JSON)));
+        assert!(ProblemJson::prefers_problem_json(&accept(
+            "application/problem+json, application/json;q=0.9"
+        )));
+        assert!(!ProblemJson::prefers_problem_json(&accept(
+            "application/json, application/problem+json;q=0.5"
+        )));
+        assert!(!ProblemJson::prefers_problem_json(&accept("*/*")));
+        assert!(!ProblemJson::prefers_problem_json(&HeaderMap::new()));
+    }
+
+    #[tokio::test]
+    async fn renders_api_errors_as_problem_details() {
+        let response = ProblemJson::render(
+            ApiErrorResponse::NotFound("Job not found".to_string()).into_response(),
+        );
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], PROBLEM_JSON);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let problem: ProblemDetails = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(problem.problem_type, "about:blank");
+        assert_eq!(problem.title, "Not Found");
+        assert_eq!(problem.status, 404);
+        assert_eq!(problem.detail, "Job not found");
+        assert!(problem.errors.is_none());
+    }
+
+    #[test]
+    fn leaves_other_responses_unchanged() {
+        let response = ProblemJson::render(StatusCode::NO_CONTENT.into_response());
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert!(response.headers().get(header::CONTENT_TYPE).is_none());
+    }
+}
@@ -0,0 +1,282 @@
+//! API version prefixes and legacy path deprecation.
+//!
+//! Provides [`ApiVersion`], the versions the API is served under, and
+//! [`ApiVersioning`], which mounts each version's routes under its path
+//! prefix. The unversioned paths the API was first served at remain as
+//! aliases of [`ApiVersion::LEGACY`], and their responses carry a
+//! `Deprecation` header and a `Link` to the versioned path.
+
+use axum::{
+    Router,
+    extract::{Request, State},
+    http::{HeaderName, HeaderValue, Uri, header},
+    middleware::{self, Next},
+    response::Response,
+};
+
+/// Response header naming the API version that served a request.
+pub const API_VERSION_HEADER: HeaderName = HeaderName::from_static("api-version");
+
+/// Response header marking a deprecated path.
+pub const DEPRECATION_HEADER: HeaderName = HeaderName::from_static("deprecation");
+
+/// A version of the API, served under its own path prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiVersion {
+    /// The first versioned API, served under `/v1`.
+    V1,
+}
+
+impl ApiVersion {
+    /// Every version the API serves, oldest first.
+    pub const ALL: [Self; 1] = [Self::V1];
+
+    /// The version the unversioned legacy paths are aliases of.
+    pub const LEGACY: Self = Self::V1;
+
+    /// Returns the path prefix the version is mounted under.
+    ///
+    /// # Returns
+    ///
+    /// The prefix, such as `/v1`.
+    pub fn prefix(self) -> &'static str {
+        match self {
+            Self::V1 => "/v1",
+        }
+    }
+
+    /// Returns the version number reported in the `Api-Version` header.
+    ///
+    /// # Returns
+    ///
+    /// The version number, such as `1`.
+    pub fn number(self) -> &'static str {
+        match self {
+            Self::V1 => "1",
+        }
+    }
+
+    /// Removes a leading version prefix from a request path or route
+    /// pattern.
+    ///
+    /// Lets path-based rules written for the unversioned paths apply to
+    /// every version.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` — The request path or matched route pattern.
+    ///
+    /// # Returns
+    ///
+    /// The path without its version prefix, or the path unchanged when it
+    /// has none. `/v1/jobs` becomes `/jobs`, while `/v10` and `/jobs` are
+    /// left alone.
+    pub fn strip_prefix(path: &str) -> &str {
+        Self::ALL
+            .iter()
+            .find_map(|version| {
+                let rest = path.strip_prefix(version.prefix())?;
+
+                match rest {
+                    "" => Some("/"),
+                    rest if rest.starts_with('/') => Some(rest),
+                    _ => None,
+                }
+            })
+            .unwrap_or(path)
+    }
+}
+
+/// Mounts versioned routes and the deprecated legacy aliases.
+pub struct ApiVersioning;
+
+impl ApiVersioning {
+    /// Builds a router serving every [`ApiVersion`] under its prefix.
+    ///
+    /// The routes of [`ApiVersion::LEGACY`] are also served at the
+    /// unversioned paths, with deprecation headers added. Adding a version
+    /// to [`ApiVersion::ALL`] mounts it beside the existing ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `routes` — Builds the routes served for a version, without its
+    ///   prefix.
+    ///
+    /// # Returns
+    ///
+    /// A [`Router`] with every version and the legacy aliases mounted.
+    pub fn mount<S>(routes: impl Fn(ApiVersion) -> Router<S>) -> Router<S>
+    where
+        S: Clone + Send + Sync + 'static,
+    {
+        let versioned = ApiVersion::ALL
+            .into_iter()
+            .fold(Router::new(), |router, version| {
+                router.nest(version.prefix(), Self::labeled(&routes, version))
+            });
+
+        let legacy = Self::labeled(&routes, ApiVersion::LEGACY)
+            .layer(middleware::from_fn(Self::deprecate_legacy));
+
+        versioned.merge(legacy)
+    }
+
+    /// Axum middleware that reports the version serving a request.
+    ///
+    /// # Arguments
+    ///
+    /// * `version` — The version the routes belong to.
+    /// * `request` — The incoming HTTP request.
+    /// * `next` — The next middleware or handler in the chain.
+    ///
+    /// # Returns
+    ///
+    /// The downstream [`Response`] with an `Api-Version` header.
+    pub async fn label(
+        State(version): State<ApiVersion>,
+        request: Request,
+        next: Next,
+    ) -> Response {
+        let mut response = next.run(request).await;
+
+        response.headers_mut().insert(
+            API_VERSION_HEADER,
+            HeaderValue::from_static(version.number()),
+        );
+
+        response
+    }
+
+    /// Axum middleware that marks a legacy path as deprecated.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` — The incoming HTTP request.
+    /// * `next` — The next middleware or handler in the chain.
+    ///
+    /// # Returns
+    ///
+    /// The downstream [`Response`] with a `Deprecation` header and a `Link`
+    /// header naming the versioned path as its successor.
+    pub async fn deprecate_legacy(request: Request, next: Next) -> Response {
+        let successor = Self::successor_link(request.uri());
+        let mut response = next.run(request).await;
+        let headers = response.headers_mut();
+
+        headers.insert(DEPRECATION_HEADER, HeaderValue::from_static("true"));
+
+        if let Some(successor) = successor {
+            headers.append(header::LINK, successor);
+        }
+
+        response
+    }
+
+    /// Builds a version's routes with the `Api-Version` header added.
+    ///
+    /// # Arguments
+    ///
+    /// * `routes` — Builds the routes served for a version.
+    /// * `version` — The version to build.
+    ///
+    /// # Returns
+    ///
+    /// The version's [`Router`].
+    fn labeled<S>(routes: &impl Fn(ApiVersion) -> Router<S>, version: ApiVersion) -> Router<S>
+    where
+        S: Clone + Send + Sync + 'static,
+    {
+        routes(version).layer(middleware::from_fn_with_state(version, Self::label))
+    }
+
+    /// Builds the `Link` header pointing a legacy path at its successor.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` — The legacy request URI.
+    ///
+    /// # Returns
+    ///
+    /// The header value, or `None` if the URI cannot be written in a
+    /// header.
+    fn successor_link(uri: &Uri) -> Option<HeaderValue> {
+        let path = uri
+            .path_and_query()
+            .map_or_else(|| uri.path(), |path| path.as_str());
+
+        HeaderValue::from_str(&format!(
+            "<{}{}>; rel=\"successor-version\"",
+            ApiVersion::LEGACY.prefix(),
+            path
+        ))
+        .ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{body::Body, http::StatusCode, routing::get};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    fn app() -> Router {
+        ApiVersioning::mount(|_| Router::new().route("/jobs/{id}", get(|| async { "job" })))
+    }
+
+    async fn get_response(uri: &str) -> Response {
+        app()
+            .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn serves_routes_under_version_prefix() {
+        let response = get_response("/v1/jobs/1").await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[API_VERSION_HEADER], "1");
+        assert!(response.headers().get(DEPRECATION_HEADER).is_none());
+        assert!(response.headers().get(header::LINK).is_none());
+    }
+
+    #[tokio::test]
+    async fn serves_legacy_paths_as_deprecated_aliases() {
+        let response = get_response("/jobs/1?expand=company").await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[API_VERSION_HEADER], "1");
+        assert_eq!(response.headers()[DEPRECATION_HEADER], "true");
+        assert_eq!(
+            response.headers()[header::LINK],
+            "</v1/jobs/1?expand=company>; rel=\"successor-version\""
+        );
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(&body[..], b"job");
+    }
+
+    #[tokio::test]
+    async fn leaves_unknown_paths_unrouted() {
+        assert_eq!(
+            get_response("/v2/jobs/1").await.status(),
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(
+            get_response("/v1/v1/jobs/1").await.status(),
+            StatusCode::NOT_FOUND
+        );
+    }
+
+    #[test]
+    fn strips_version_prefixes() {
+        assert_eq!(ApiVersion::strip_prefix("/v1/jobs/{id}"), "/jobs/{id}");
+        assert_eq!(ApiVersion::strip_prefix("/v1"), "/");
+        assert_eq!(ApiVersion::strip_prefix("/v10/jobs"), "/v10/jobs");
+        assert_eq!(ApiVersion::strip_prefix("/jobs"), "/jobs");
+        assert_eq!(ApiVersion::strip_prefix("/api/v9/me"), "/api/v9/me");
+    }
+}
@@ -0,0 +1,171 @@
+//! Stored data consistency checks.
+//!
+//! Provides [`DataCheck`], the invariants that are enforced when rows are
+//! written but not by the database schema, and [`DataCheckUtil`] for
+//! scanning for rows that break them and repairing the ones that have a
+//! safe fix. Run from the command line with `api --check-data`, adding
+//! `--repair` to apply repairs.
+
+use sqlx::{Pool, Postgres};
+
+use crate::core::error::ApiResult;
+use crate::repo::data_check::DataCheckRepo;
+
+/// A consistency invariant on stored data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataCheck {
+    /// Payments must have a total greater than zero. Not repairable.
+    NonPositivePaymentTotals,
+    /// Received payments must have their transfer marked as initiated.
+    /// Repaired by marking the transfer initiated.
+    ReceivedPaymentsWithoutTransfer,
+    /// Initiated payments still awaiting receipt must have an expected
+    /// payout date. Not repairable.
+    PendingPayoutsWithoutDates,
+    /// Paused time must not be negative. Repaired by resetting it to zero.
+    NegativePausedDurations,
+    /// Stopped sessions must not be paused for longer than they ran.
+    /// Repaired by capping the paused time at the session's length.
+    NegativeSessionDurations,
+    /// Auth codes that can no longer be redeemed must not linger. Repaired
+    /// by deleting them.
+    OrphanedAuthCodes,
+}
+
+impl DataCheck {
+    /// Every check, in the order they are run.
+    ///
+    /// Paused durations are fixed before session durations so a negative
+    /// paused time is not mistaken for one that exceeds the session.
+    pub const ALL: [DataCheck; 6] = [
+        DataCheck::NonPositivePaymentTotals,
+        DataCheck::ReceivedPaymentsWithoutTransfer,
+        DataCheck::PendingPayoutsWithoutDates,
+        DataCheck::NegativePausedDurations,
+        DataCheck::NegativeSessionDurations,
+        DataCheck::OrphanedAuthCodes,
+    ];
+
+    /// Returns the check's `snake_case` name.
+    ///
+    /// # Returns
+    ///
+    /// The name used in reports.
+    pub fn name(self) -> &'static str {
+        match self {
+            DataCheck::NonPositivePaymentTotals => "non_positive_payment_totals",
+            DataCheck::ReceivedPaymentsWithoutTransfer => "received_payments_without_transfer",
+            DataCheck::PendingPayoutsWithoutDates => "pending_payouts_without_dates",
+            DataCheck::NegativePausedDurations => "negative_paused_durations",
+            DataCheck::NegativeSessionDurations => "negative_session_durations",
+            DataCheck::OrphanedAuthCodes => "orphaned_auth_codes",
+        }
+    }
+
+    /// Returns whether violations of the check can be repaired safely.
+    ///
+    /// # Returns
+    ///
+    /// `true` if [`DataCheckRepo::repair`] fixes violations of the check.
+    pub fn is_repairable(self) -> bool {
+        !matches!(
+            self,
+            DataCheck::NonPositivePaymentTotals | DataCheck::PendingPayoutsWithoutDates
+        )
+    }
+}
+
+/// The outcome of running one [`DataCheck`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataCheckResult {
+    /// The check that was run.
+    pub check: DataCheck,
+    /// Rows found breaking the invariant.
+    pub violations: i64,
+    /// Rows repaired.
+    pub repaired: u64,
+}
+
+impl DataCheckResult {
+    /// Returns the number of violations left unrepaired.
+    ///
+    /// # Returns
+    ///
+    /// `violations` minus `repaired`, never below zero.
+    pub fn remaining(&self) -> i64 {
+        (self.violations - i64::try_from(self.repaired).unwrap_or(i64::MAX)).max(0)
+    }
+}
+
+/// Utility for running stored data consistency checks.
+pub struct DataCheckUtil;
+
+impl DataCheckUtil {
+    /// Runs every [`DataCheck`], optionally repairing violations.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `repair` — Whether to repair violations of repairable checks.
+    ///
+    /// # Returns
+    ///
+    /// A [`DataCheckResult`] per check, in [`DataCheck::ALL`] order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any query fails.
+    pub async fn run(pool: &Pool<Postgres>, repair: bool) -> ApiResult<Vec<DataCheckResult>> {
+        let mut results = Vec::with_capacity(DataCheck::ALL.len());
+
+        for check in DataCheck::ALL {
+            let violations = DataCheckRepo::count_violations(pool, check).await?;
+            let repaired = if repair && violations > 0 && check.is_repairable() {
+                DataCheckRepo::repair(pool, check).await?
+            } else {
+                0
+            };
+
+            results.push(DataCheckResult {
+                check,
+                violations,
+                repaired,
+            });
+        }
+
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remaining_subtracts_repairs() {
+        let result = DataCheckResult {
+            check: DataCheck::OrphanedAuthCodes,
+            violations: 5,
+            repaired: 3,
+        };
+
+        assert_eq!(result.remaining(), 2);
+        assert_eq!(
+            DataCheckResult {
+                repaired: 7,
+                ..result
+            }
+            .remaining(),
+            0
+        );
+    }
+
+    #[test]
+    fn check_names_are_unique() {
+        let mut names: Vec<&str> = DataCheck::ALL.iter().map(|check| check.name()).collect();
+        names.sort_unstable();
+        names.dedup();
+
+        assert_eq!(names.len(), DataCheck::ALL.len());
+    }
+}
@@ -2,18 +2,30 @@
 //!
 //! This module provides [`EmailClient`], a thin wrapper around
 //! [`reqwest::Client`] that authenticates with the Resend API and
-//! delivers plain-text email messages.
+//! delivers plain-text email messages, optionally with file attachments.
 
+use base64::{Engine, engine::general_purpose::STANDARD};
 use reqwest::Client;
 use serde_json::json;
 
 use crate::core::config::Config;
 use crate::core::error::{ApiErrorResponse, ApiResult};
 
+/// A file attached to an outgoing email.
+#[derive(Debug, Clone)]
+pub struct EmailAttachment {
+    /// File name shown to the recipient.
+    pub filename: String,
+    /// Raw file contents.
+    pub content: Vec<u8>,
+}
+
 /// HTTP client for sending emails through the Resend API.
 ///
 /// Wraps a [`reqwest::Client`] with Resend API credentials and provides
-/// a single [`send_email`](Self::send_email) method for delivering messages.
+/// [`send_email`](Self::send_email) and
+/// [`send_email_with_attachment`](Self::send_email_with_attachment) for
+/// delivering messages.
 #[derive(Debug, Clone)]
 pub struct EmailClient {
     /// Underlying HTTP client used for API requests.
@@ -75,4 +87,49 @@ impl EmailClient {
 
         Ok(())
     }
+
+    /// Sends a plain-text email with a file attachment to a single
+    /// recipient via the Resend API.
+    ///
+    /// # Arguments
+    ///
+    /// * `to` — Recipient email address.
+    /// * `subject` — Email subject line.
+    /// * `body` — Plain-text email body.
+    /// * `attachment` — The [`EmailAttachment`] to include.
+    ///
+    /// # Returns
+    ///
+    /// An empty [`ApiResult`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::InternalServerError`] if the HTTP request
+    /// to the Resend API fails.
+    pub async fn send_email_with_attachment(
+        &self,
+        to: &str,
+        subject: &str,
+        body: &str,
+        attachment: &EmailAttachment,
+    ) -> ApiResult<()> {
+        self.client
+            .post("https://api.resend.com/emails")
+            .header("Authorization", format!("Bearer {}", self.api_key))
+            .json(&json!({
+                "from": self.from_email,
+                "to": [to],
+                "subject": subject,
+                "text": body,
+                "attachments": [{
+                    "filename": attachment.filename,
+                    "content": STANDARD.encode(&attachment.content),
+                }],
+            }))
+            .send()
+            .await
+            .map_err(|error| ApiErrorResponse::InternalServerError(error.to_string()))?;
+
+        Ok(())
+    }
 }
@@ -2,12 +2,15 @@
 //!
 //! This module provides email sending capabilities through the
 //! [Resend](https://resend.com) API. It is split into a low-level client and
-//! higher-level sender abstractions that compose emails for specific features.
+//! higher-level sender abstractions that compose emails for specific features
+//! from localized templates.
 //!
 //! # Modules
 //!
 //! - [`client`](crate::email::client) — Core HTTP client for the Resend API.
 //! - [`senders`](crate::email::senders) — Specialized email sender implementations.
+//! - [`templates`](crate::email::templates) — Localized email subjects and bodies.
 
 pub mod client;
 pub mod senders;
+pub mod templates;
@@ -2,23 +2,41 @@
 //!
 //! This module provides [`AuthSender`], which composes and delivers
 //! emails for account actions such as email verification, password
-//! resets, and credential change confirmations.
+//! resets, and credential change confirmations, optionally with a signed
+//! link that completes the action in one click.
 
-use crate::{core::error::ApiResult, email::client::EmailClient};
+use gig_log_common::models::locale::Locale;
+
+use crate::{
+    core::error::ApiResult,
+    email::{
+        client::EmailClient,
+        templates::{
+            EmailTemplate,
+            auth::{AuthCodeEmail, AuthEmailKind},
+        },
+    },
+};
 
 /// Sends authentication-related emails to users.
 ///
 /// Composes and delivers emails for account actions such as email
 /// verification, password resets, and credential change confirmations.
-/// Each method formats a message containing a one-time code and delegates
-/// delivery to the underlying [`EmailClient`].
+/// Each method renders a message containing a one-time code, and the link
+/// set with [`AuthSender::with_link`] if any, in the locale set with
+/// [`AuthSender::with_locale`], and delegates delivery to the underlying
+/// [`EmailClient`].
 pub struct AuthSender {
     /// Email client used to deliver messages.
     client: EmailClient,
     /// Recipient email address.
     to: String,
+    /// Language the email is written in.
+    locale: Locale,
     /// One-time code included in the email body.
     code: String,
+    /// Signed link that completes the action, included after the code.
+    link: Option<String>,
 }
 
 impl AuthSender {
@@ -37,11 +55,46 @@ impl AuthSender {
         Self {
             client,
             to: to.into(),
+            locale: Locale::default(),
             code: code.into(),
+            link: None,
         }
     }
 
-    /// Sends an email verification code to the recipient.
+    /// Sets the language the email is written in. Defaults to English.
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` — The recipient's preferred [`Locale`].
+    ///
+    /// # Returns
+    ///
+    /// The [`AuthSender`] with the locale set.
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Adds a signed link to the email so the recipient can complete the
+    /// action without typing the code.
+    ///
+    /// # Arguments
+    ///
+    /// * `link` — The absolute link URL.
+    ///
+    /// # Returns
+    ///
+    /// The [`AuthSender`] with the link set.
+    pub fn with_link(mut self, link: impl Into<String>) -> Self {
+        self.link = Some(link.into());
+        self
+    }
+
+    /// Renders an email in the recipient's locale and sends it.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` — The account action the code is for.
     ///
     /// # Returns
     ///
@@ -50,16 +103,32 @@ impl AuthSender {
     /// # Errors
     ///
     /// Returns [`ApiErrorResponse::InternalServerError`](crate::core::error::ApiErrorResponse::InternalServerError) if email delivery fails.
-    pub async fn send_email_verification(&self) -> ApiResult<()> {
+    async fn send(&self, kind: AuthEmailKind) -> ApiResult<()> {
+        let content = AuthCodeEmail {
+            kind,
+            code: &self.code,
+            link: self.link.as_deref(),
+        }
+        .render(self.locale);
+
         self.client
-            .send_email(
-                &self.to,
-                "Verify your email",
-                &format!("Your verification code is: {}", self.code),
-            )
+            .send_email(&self.to, &content.subject, &content.body)
             .await
     }
 
+    /// Sends an email verification code to the recipient.
+    ///
+    /// # Returns
+    ///
+    /// An empty [`ApiResult`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::InternalServerError`](crate::core::error::ApiErrorResponse::InternalServerError) if email delivery fails.
+    pub async fn send_email_verification(&self) -> ApiResult<()> {
+        self.send(AuthEmailKind::EmailVerification).await
+    }
+
     /// Sends a password reset code to the recipient.
     ///
     /// # Returns
@@ -70,13 +139,7 @@ impl AuthSender {
     ///
     /// Returns [`ApiErrorResponse::InternalServerError`](crate::core::error::ApiErrorResponse::InternalServerError) if email delivery fails.
     pub async fn send_reset_password(&self) -> ApiResult<()> {
-        self.client
-            .send_email(
-                &self.to,
-                "Reset your password",
-                &format!("Your password reset code is: {}", self.code),
-            )
-            .await
+        self.send(AuthEmailKind::PasswordReset).await
     }
 
     /// Sends an email change confirmation code to the recipient.
@@ -89,13 +152,7 @@ impl AuthSender {
     ///
     /// Returns [`ApiErrorResponse::InternalServerError`](crate::core::error::ApiErrorResponse::InternalServerError) if email delivery fails.
     pub async fn send_email_change(&self) -> ApiResult<()> {
-        self.client
-            .send_email(
-                &self.to,
-                "Confirm your email change",
-                &format!("Your email change code is: {}", self.code),
-            )
-            .await
+        self.send(AuthEmailKind::EmailChange).await
     }
 
     /// Sends a password change confirmation code to the recipient.
@@ -108,12 +165,6 @@ impl AuthSender {
     ///
     /// Returns [`ApiErrorResponse::InternalServerError`](crate::core::error::ApiErrorResponse::InternalServerError) if email delivery fails.
     pub async fn send_password_change(&self) -> ApiResult<()> {
-        self.client
-            .send_email(
-                &self.to,
-                "Confirm your password change",
-                &format!("Your password change code is: {}", self.code),
-            )
-            .await
+        self.send(AuthEmailKind::PasswordChange).await
     }
 }
@@ -0,0 +1,97 @@
+//! Budget alert email senders.
+//!
+//! This module provides [`BudgetSender`], which composes and delivers
+//! threshold alerts when a company or job budget is nearly or fully used.
+
+use gig_log_common::models::{budget::BudgetType, locale::Locale};
+
+use crate::{
+    core::error::ApiResult,
+    email::{
+        client::EmailClient,
+        templates::{EmailTemplate, budget::BudgetThresholdEmail},
+    },
+};
+
+/// Sends budget threshold alerts to users.
+pub struct BudgetSender {
+    /// Email client used to deliver messages.
+    client: EmailClient,
+    /// Recipient email address.
+    to: String,
+    /// Language the email is written in.
+    locale: Locale,
+}
+
+impl BudgetSender {
+    /// Creates a new [`BudgetSender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `client` — [`EmailClient`] used to send emails.
+    /// * `to` — Recipient email address.
+    ///
+    /// # Returns
+    ///
+    /// A configured [`BudgetSender`] instance.
+    pub fn new(client: EmailClient, to: impl Into<String>) -> Self {
+        Self {
+            client,
+            to: to.into(),
+            locale: Locale::default(),
+        }
+    }
+
+    /// Sets the language the email is written in. Defaults to English.
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` — The recipient's preferred [`Locale`].
+    ///
+    /// # Returns
+    ///
+    /// The [`BudgetSender`] with the locale set.
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Sends an alert that a budget crossed a usage threshold.
+    ///
+    /// # Arguments
+    ///
+    /// * `target_name` — Name of the budgeted company or job.
+    /// * `budget_type` — Whether the budget limits hours or dollars.
+    /// * `threshold_percent` — The threshold that was crossed.
+    /// * `consumed` — Hours or dollars consumed this month.
+    /// * `monthly_limit` — The budget's monthly limit.
+    ///
+    /// # Returns
+    ///
+    /// An empty [`ApiResult`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::InternalServerError`](crate::core::error::ApiErrorResponse::InternalServerError) if email delivery fails.
+    pub async fn send_threshold_alert(
+        &self,
+        target_name: &str,
+        budget_type: &BudgetType,
+        threshold_percent: i32,
+        consumed: f64,
+        monthly_limit: f64,
+    ) -> ApiResult<()> {
+        let content = BudgetThresholdEmail {
+            target_name,
+            budget_type,
+            threshold_percent,
+            consumed,
+            monthly_limit,
+        }
+        .render(self.locale);
+
+        self.client
+            .send_email(&self.to, &content.subject, &content.body)
+            .await
+    }
+}
@@ -0,0 +1,92 @@
+//! Contract reminder email senders.
+//!
+//! This module provides [`ContractSender`], which composes and delivers
+//! renewal reminders for contracts that are about to expire.
+
+use chrono::NaiveDate;
+use gig_log_common::models::locale::Locale;
+
+use crate::{
+    core::error::ApiResult,
+    email::{
+        client::EmailClient,
+        templates::{EmailTemplate, contract::ContractRenewalEmail},
+    },
+};
+
+/// Sends contract renewal reminders to users.
+pub struct ContractSender {
+    /// Email client used to deliver messages.
+    client: EmailClient,
+    /// Recipient email address.
+    to: String,
+    /// Language the email is written in.
+    locale: Locale,
+}
+
+impl ContractSender {
+    /// Creates a new [`ContractSender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `client` — [`EmailClient`] used to send emails.
+    /// * `to` — Recipient email address.
+    ///
+    /// # Returns
+    ///
+    /// A configured [`ContractSender`] instance.
+    pub fn new(client: EmailClient, to: impl Into<String>) -> Self {
+        Self {
+            client,
+            to: to.into(),
+            locale: Locale::default(),
+        }
+    }
+
+    /// Sets the language the email is written in. Defaults to English.
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` — The recipient's preferred [`Locale`].
+    ///
+    /// # Returns
+    ///
+    /// The [`ContractSender`] with the locale set.
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Sends a reminder that a contract is approaching its end date.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_title` — Title of the expiring contract.
+    /// * `company_name` — Name of the company the contract is with.
+    /// * `end_date` — Last day the contract is in effect.
+    ///
+    /// # Returns
+    ///
+    /// An empty [`ApiResult`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::InternalServerError`](crate::core::error::ApiErrorResponse::InternalServerError) if email delivery fails.
+    pub async fn send_renewal_reminder(
+        &self,
+        contract_title: &str,
+        company_name: &str,
+        end_date: NaiveDate,
+    ) -> ApiResult<()> {
+        let content = ContractRenewalEmail {
+            contract_title,
+            company_name,
+            end_date,
+        }
+        .render(self.locale);
+
+        self.client
+            .send_email(&self.to, &content.subject, &content.body)
+            .await
+    }
+}
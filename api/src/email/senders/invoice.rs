@@ -0,0 +1,92 @@
+//! Invoice draft email senders.
+//!
+//! This module provides [`InvoiceSender`], which lets users know an invoice
+//! was drafted for them and is waiting for review.
+
+use chrono::NaiveDate;
+use gig_log_common::models::locale::Locale;
+
+use crate::{
+    core::error::ApiResult,
+    email::{
+        client::EmailClient,
+        templates::{EmailTemplate, invoice::InvoiceDraftEmail},
+    },
+};
+
+/// Sends invoice draft notifications to users.
+pub struct InvoiceSender {
+    /// Email client used to deliver messages.
+    client: EmailClient,
+    /// Recipient email address.
+    to: String,
+    /// Language the email is written in.
+    locale: Locale,
+}
+
+impl InvoiceSender {
+    /// Creates a new [`InvoiceSender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `client` — [`EmailClient`] used to send emails.
+    /// * `to` — Recipient email address.
+    ///
+    /// # Returns
+    ///
+    /// A configured [`InvoiceSender`] instance.
+    pub fn new(client: EmailClient, to: impl Into<String>) -> Self {
+        Self {
+            client,
+            to: to.into(),
+            locale: Locale::default(),
+        }
+    }
+
+    /// Sets the language the email is written in. Defaults to English.
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` — The recipient's preferred [`Locale`].
+    ///
+    /// # Returns
+    ///
+    /// The [`InvoiceSender`] with the locale set.
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Sends a notice that a weekly invoice draft is ready for review.
+    ///
+    /// # Arguments
+    ///
+    /// * `company_name` — Name of the billed company.
+    /// * `week_start` — Monday of the week the drafted work was done in.
+    /// * `total_amount` — Total of the draft invoice in dollars.
+    ///
+    /// # Returns
+    ///
+    /// An empty [`ApiResult`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::InternalServerError`](crate::core::error::ApiErrorResponse::InternalServerError) if email delivery fails.
+    pub async fn send_draft_ready(
+        &self,
+        company_name: &str,
+        week_start: NaiveDate,
+        total_amount: f64,
+    ) -> ApiResult<()> {
+        let content = InvoiceDraftEmail {
+            company_name,
+            week_start,
+            total_amount,
+        }
+        .render(self.locale);
+
+        self.client
+            .send_email(&self.to, &content.subject, &content.body)
+            .await
+    }
+}
@@ -6,5 +6,17 @@
 //! # Modules
 //!
 //! - [`auth`] — Authentication-related emails (verification, password reset, etc.).
+//! - [`budget`] — Budget threshold alerts.
+//! - [`contract`] — Contract renewal reminders.
+//! - [`invoice`] — Weekly invoice draft notices.
+//! - [`payment`] — Upcoming and overdue payment reminders.
+//! - [`security`] — Unusual account activity notifications.
+//! - [`statement`] — Company statements with PDF attachments.
 
 pub mod auth;
+pub mod budget;
+pub mod contract;
+pub mod invoice;
+pub mod payment;
+pub mod security;
+pub mod statement;
@@ -0,0 +1,96 @@
+//! Payment reminder email senders.
+//!
+//! This module provides [`PaymentSender`], which composes and delivers
+//! reminders for payments that are coming up or overdue.
+
+use chrono::NaiveDate;
+use gig_log_common::models::locale::Locale;
+
+use crate::{
+    core::error::ApiResult,
+    email::{
+        client::EmailClient,
+        templates::{EmailTemplate, payment::PaymentReminderEmail},
+    },
+    repo::payment::PaymentReminderStage,
+};
+
+/// Sends payment reminders to users.
+pub struct PaymentSender {
+    /// Email client used to deliver messages.
+    client: EmailClient,
+    /// Recipient email address.
+    to: String,
+    /// Language the email is written in.
+    locale: Locale,
+}
+
+impl PaymentSender {
+    /// Creates a new [`PaymentSender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `client` — [`EmailClient`] used to send emails.
+    /// * `to` — Recipient email address.
+    ///
+    /// # Returns
+    ///
+    /// A configured [`PaymentSender`] instance.
+    pub fn new(client: EmailClient, to: impl Into<String>) -> Self {
+        Self {
+            client,
+            to: to.into(),
+            locale: Locale::default(),
+        }
+    }
+
+    /// Sets the language the email is written in. Defaults to English.
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` — The recipient's preferred [`Locale`].
+    ///
+    /// # Returns
+    ///
+    /// The [`PaymentSender`] with the locale set.
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Sends a reminder about a payment that has not been received.
+    ///
+    /// # Arguments
+    ///
+    /// * `stage` — Whether the payment is coming up or overdue.
+    /// * `company_name` — Name of the company the payment is from.
+    /// * `amount_outstanding` — Amount still outstanding in dollars.
+    /// * `expected_payout_date` — Date the payment was expected.
+    ///
+    /// # Returns
+    ///
+    /// An empty [`ApiResult`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::InternalServerError`](crate::core::error::ApiErrorResponse::InternalServerError) if email delivery fails.
+    pub async fn send_reminder(
+        &self,
+        stage: PaymentReminderStage,
+        company_name: &str,
+        amount_outstanding: f64,
+        expected_payout_date: NaiveDate,
+    ) -> ApiResult<()> {
+        let content = PaymentReminderEmail {
+            stage,
+            company_name,
+            amount_outstanding,
+            expected_payout_date,
+        }
+        .render(self.locale);
+
+        self.client
+            .send_email(&self.to, &content.subject, &content.body)
+            .await
+    }
+}
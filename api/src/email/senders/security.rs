@@ -0,0 +1,82 @@
+//! Unusual account activity email senders.
+//!
+//! This module provides [`SecuritySender`], which tells users when unusual
+//! activity is detected on their account.
+
+use gig_log_common::models::locale::Locale;
+
+use crate::{
+    core::error::ApiResult,
+    email::{
+        client::EmailClient,
+        templates::{
+            EmailTemplate,
+            security::{UnusualActivity, UnusualActivityEmail},
+        },
+    },
+};
+
+/// Sends unusual activity notifications to users.
+pub struct SecuritySender {
+    /// Email client used to deliver messages.
+    client: EmailClient,
+    /// Recipient email address.
+    to: String,
+    /// Language the email is written in.
+    locale: Locale,
+}
+
+impl SecuritySender {
+    /// Creates a new [`SecuritySender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `client` — [`EmailClient`] used to send emails.
+    /// * `to` — Recipient email address.
+    ///
+    /// # Returns
+    ///
+    /// A configured [`SecuritySender`] instance.
+    pub fn new(client: EmailClient, to: impl Into<String>) -> Self {
+        Self {
+            client,
+            to: to.into(),
+            locale: Locale::default(),
+        }
+    }
+
+    /// Sets the language the email is written in. Defaults to English.
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` — The recipient's preferred [`Locale`].
+    ///
+    /// # Returns
+    ///
+    /// The [`SecuritySender`] with the locale set.
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Sends a notification that unusual activity was detected.
+    ///
+    /// # Arguments
+    ///
+    /// * `activity` — The [`UnusualActivity`] that was detected.
+    ///
+    /// # Returns
+    ///
+    /// An empty [`ApiResult`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::InternalServerError`](crate::core::error::ApiErrorResponse::InternalServerError) if email delivery fails.
+    pub async fn send_unusual_activity(&self, activity: UnusualActivity) -> ApiResult<()> {
+        let content = UnusualActivityEmail { activity }.render(self.locale);
+
+        self.client
+            .send_email(&self.to, &content.subject, &content.body)
+            .await
+    }
+}
@@ -0,0 +1,105 @@
+//! Company statement email senders.
+//!
+//! This module provides [`StatementSender`], which delivers statements of
+//! payments and hours to company contacts as PDF attachments.
+
+use chrono::NaiveDate;
+use gig_log_common::models::locale::Locale;
+
+use crate::{
+    core::error::ApiResult,
+    email::{
+        client::{EmailAttachment, EmailClient},
+        templates::{EmailTemplate, statement::StatementEmail},
+    },
+};
+
+/// Sends company statements to company contacts.
+pub struct StatementSender {
+    /// Email client used to deliver messages.
+    client: EmailClient,
+    /// Recipient email address.
+    to: String,
+    /// Language the email is written in.
+    locale: Locale,
+}
+
+impl StatementSender {
+    /// Creates a new [`StatementSender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `client` — [`EmailClient`] used to send emails.
+    /// * `to` — Recipient email address.
+    ///
+    /// # Returns
+    ///
+    /// A configured [`StatementSender`] instance.
+    pub fn new(client: EmailClient, to: impl Into<String>) -> Self {
+        Self {
+            client,
+            to: to.into(),
+            locale: Locale::default(),
+        }
+    }
+
+    /// Sets the language the email is written in. Defaults to English.
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` — The sending user's preferred [`Locale`].
+    ///
+    /// # Returns
+    ///
+    /// The [`StatementSender`] with the locale set.
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Sends a statement PDF covering a date range.
+    ///
+    /// # Arguments
+    ///
+    /// * `sender_name` — Full name of the user sending the statement.
+    /// * `company_name` — Name of the company the statement covers.
+    /// * `period_start` — First day covered by the statement.
+    /// * `period_end` — Last day covered by the statement.
+    /// * `pdf` — The rendered statement PDF.
+    ///
+    /// # Returns
+    ///
+    /// An empty [`ApiResult`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::InternalServerError`](crate::core::error::ApiErrorResponse::InternalServerError) if email delivery fails.
+    pub async fn send_statement(
+        &self,
+        sender_name: &str,
+        company_name: &str,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+        pdf: Vec<u8>,
+    ) -> ApiResult<()> {
+        let content = StatementEmail {
+            sender_name,
+            company_name,
+            period_start,
+            period_end,
+        }
+        .render(self.locale);
+
+        self.client
+            .send_email_with_attachment(
+                &self.to,
+                &content.subject,
+                &content.body,
+                &EmailAttachment {
+                    filename: format!("statement-{period_start}-{period_end}.pdf"),
+                    content: pdf,
+                },
+            )
+            .await
+    }
+}
@@ -0,0 +1,134 @@
+//! One-time code email templates.
+//!
+//! Provides [`AuthCodeEmail`], which renders the emails carrying a one-time
+//! code, and optionally a signed link, for account actions. Translated into
+//! Spanish and French.
+
+use gig_log_common::models::locale::Locale;
+
+use crate::email::templates::{EmailContent, EmailTemplate};
+
+/// The account action a one-time code email is for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthEmailKind {
+    /// Confirming the email address of a new account.
+    EmailVerification,
+    /// Resetting a forgotten password.
+    PasswordReset,
+    /// Confirming a change of email address.
+    EmailChange,
+    /// Confirming a change of password.
+    PasswordChange,
+}
+
+/// An email carrying a one-time code for an account action.
+pub struct AuthCodeEmail<'a> {
+    /// The action the code completes.
+    pub kind: AuthEmailKind,
+    /// The one-time code.
+    pub code: &'a str,
+    /// Signed link that completes the action, included after the code.
+    pub link: Option<&'a str>,
+}
+
+impl AuthCodeEmail<'_> {
+    /// Formats an email from its translated phrases.
+    ///
+    /// # Arguments
+    ///
+    /// * `subject` — The subject line.
+    /// * `code_label` — Text preceding the code, e.g. `"Your verification code is"`.
+    /// * `link_intro` — Text preceding the link, e.g. `"Or verify your email by opening this link"`.
+    ///
+    /// # Returns
+    ///
+    /// The [`EmailContent`], with the link paragraph only if a link is set.
+    fn content(&self, subject: &str, code_label: &str, link_intro: &str) -> EmailContent {
+        let body = match self.link {
+            Some(link) => format!("{code_label}: {}\n\n{link_intro}:\n{link}", self.code),
+            None => format!("{code_label}: {}", self.code),
+        };
+
+        EmailContent {
+            subject: subject.to_string(),
+            body,
+        }
+    }
+}
+
+impl EmailTemplate for AuthCodeEmail<'_> {
+    fn render_english(&self) -> EmailContent {
+        let (subject, code_label, link_intro) = match self.kind {
+            AuthEmailKind::EmailVerification => (
+                "Verify your email",
+                "Your verification code is",
+                "Or verify your email by opening this link",
+            ),
+            AuthEmailKind::PasswordReset => (
+                "Reset your password",
+                "Your password reset code is",
+                "Or reset your password by opening this link",
+            ),
+            AuthEmailKind::EmailChange => (
+                "Confirm your email change",
+                "Your email change code is",
+                "Or confirm the change by opening this link",
+            ),
+            AuthEmailKind::PasswordChange => (
+                "Confirm your password change",
+                "Your password change code is",
+                "Or confirm the change by opening this link",
+            ),
+        };
+
+        self.content(subject, code_label, link_intro)
+    }
+
+    fn render_translated(&self, locale: Locale) -> Option<EmailContent> {
+        let (subject, code_label, link_intro) = match (locale, self.kind) {
+            (Locale::En, _) => return None,
+            (Locale::Es, AuthEmailKind::EmailVerification) => (
+                "Verifica tu correo electrónico",
+                "Tu código de verificación es",
+                "O verifica tu correo electrónico abriendo este enlace",
+            ),
+            (Locale::Es, AuthEmailKind::PasswordReset) => (
+                "Restablece tu contraseña",
+                "Tu código para restablecer la contraseña es",
+                "O restablece tu contraseña abriendo este enlace",
+            ),
+            (Locale::Es, AuthEmailKind::EmailChange) => (
+                "Confirma el cambio de correo electrónico",
+                "Tu código para cambiar el correo electrónico es",
+                "O confirma el cambio abriendo este enlace",
+            ),
+            (Locale::Es, AuthEmailKind::PasswordChange) => (
+                "Confirma el cambio de contraseña",
+                "Tu código para cambiar la contraseña es",
+                "O confirma el cambio abriendo este enlace",
+            ),
+            (Locale::Fr, AuthEmailKind::EmailVerification) => (
+                "Vérifiez votre adresse e-mail",
+                "Votre code de vérification est",
+                "Ou vérifiez votre adresse e-mail en ouvrant ce lien",
+            ),
+            (Locale::Fr, AuthEmailKind::PasswordReset) => (
+                "Réinitialisez votre mot de passe",
+                "Votre code de réinitialisation du mot de passe est",
+                "Ou réinitialisez votre mot de passe en ouvrant ce lien",
+            ),
+            (Locale::Fr, AuthEmailKind::EmailChange) => (
+                "Confirmez le changement d'adresse e-mail",
+                "Votre code de changement d'adresse e-mail est",
+                "Ou confirmez le changement en ouvrant ce lien",
+            ),
+            (Locale::Fr, AuthEmailKind::PasswordChange) => (
+                "Confirmez le changement de mot de passe",
+                "Votre code de changement de mot de passe est",
+                "Ou confirmez le changement en ouvrant ce lien",
+            ),
+        };
+
+        Some(self.content(subject, code_label, link_intro))
+    }
+}
@@ -0,0 +1,77 @@
+//! Budget alert email templates.
+//!
+//! Provides [`BudgetThresholdEmail`], which renders the alert sent when a
+//! company or job budget crosses a usage threshold. Translated into
+//! Spanish.
+
+use gig_log_common::models::{budget::BudgetType, locale::Locale};
+
+use crate::email::templates::{EmailContent, EmailTemplate};
+
+/// An alert that a budget crossed a usage threshold.
+pub struct BudgetThresholdEmail<'a> {
+    /// Name of the budgeted company or job.
+    pub target_name: &'a str,
+    /// Whether the budget limits hours or dollars.
+    pub budget_type: &'a BudgetType,
+    /// The threshold that was crossed.
+    pub threshold_percent: i32,
+    /// Hours or dollars consumed this month.
+    pub consumed: f64,
+    /// The budget's monthly limit.
+    pub monthly_limit: f64,
+}
+
+impl EmailTemplate for BudgetThresholdEmail<'_> {
+    fn render_english(&self) -> EmailContent {
+        let Self {
+            target_name,
+            threshold_percent,
+            consumed,
+            monthly_limit,
+            ..
+        } = self;
+        let usage = match self.budget_type {
+            BudgetType::Hours => format!("{consumed:.2} of {monthly_limit:.2} hours"),
+            BudgetType::Amount => format!("${consumed:.2} of ${monthly_limit:.2}"),
+        };
+
+        EmailContent {
+            subject: format!("{target_name} has reached {threshold_percent}% of its budget"),
+            body: format!(
+                "Your monthly budget for {target_name} has reached {threshold_percent}%. You \
+                 have used {usage} this month."
+            ),
+        }
+    }
+
+    fn render_translated(&self, locale: Locale) -> Option<EmailContent> {
+        let Self {
+            target_name,
+            threshold_percent,
+            consumed,
+            monthly_limit,
+            ..
+        } = self;
+
+        match locale {
+            Locale::Es => {
+                let usage = match self.budget_type {
+                    BudgetType::Hours => format!("{consumed:.2} de {monthly_limit:.2} horas"),
+                    BudgetType::Amount => format!("${consumed:.2} de ${monthly_limit:.2}"),
+                };
+
+                Some(EmailContent {
+                    subject: format!(
+                        "{target_name} ha alcanzado el {threshold_percent}% de su presupuesto"
+                    ),
+                    body: format!(
+                        "Tu presupuesto mensual para {target_name} ha alcanzado el \
+                         {threshold_percent}%. Has usado {usage} este mes."
+                    ),
+                })
+            }
+            Locale::En | Locale::Fr => None,
+        }
+    }
+}
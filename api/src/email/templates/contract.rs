@@ -0,0 +1,58 @@
+//! Contract reminder email templates.
+//!
+//! Provides [`ContractRenewalEmail`], which renders the reminder sent when
+//! a contract is about to expire. Translated into Spanish.
+
+use chrono::NaiveDate;
+use gig_log_common::models::locale::Locale;
+
+use crate::email::templates::{EmailContent, EmailTemplate, format_date};
+
+/// A reminder that a contract is approaching its end date.
+pub struct ContractRenewalEmail<'a> {
+    /// Title of the expiring contract.
+    pub contract_title: &'a str,
+    /// Name of the company the contract is with.
+    pub company_name: &'a str,
+    /// Last day the contract is in effect.
+    pub end_date: NaiveDate,
+}
+
+impl EmailTemplate for ContractRenewalEmail<'_> {
+    fn render_english(&self) -> EmailContent {
+        let Self {
+            contract_title,
+            company_name,
+            ..
+        } = self;
+        let end_date = format_date(self.end_date, Locale::En);
+
+        EmailContent {
+            subject: format!("Your {company_name} contract ends on {end_date}"),
+            body: format!(
+                "Your contract \"{contract_title}\" with {company_name} ends on {end_date}. \
+                 Reach out to discuss renewal before it expires."
+            ),
+        }
+    }
+
+    fn render_translated(&self, locale: Locale) -> Option<EmailContent> {
+        let Self {
+            contract_title,
+            company_name,
+            ..
+        } = self;
+        let end_date = format_date(self.end_date, locale);
+
+        match locale {
+            Locale::Es => Some(EmailContent {
+                subject: format!("Tu contrato con {company_name} termina el {end_date}"),
+                body: format!(
+                    "Tu contrato \"{contract_title}\" con {company_name} termina el {end_date}. \
+                     Ponte en contacto para hablar de la renovación antes de que venza."
+                ),
+            }),
+            Locale::En | Locale::Fr => None,
+        }
+    }
+}
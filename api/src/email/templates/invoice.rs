@@ -0,0 +1,63 @@
+//! Invoice draft email templates.
+//!
+//! Provides [`InvoiceDraftEmail`], which renders the notice sent when a
+//! weekly invoice draft is ready for review. Translated into Spanish.
+
+use chrono::NaiveDate;
+use gig_log_common::models::locale::Locale;
+
+use crate::email::templates::{EmailContent, EmailTemplate, format_date};
+
+/// A notice that a weekly invoice draft is ready for review.
+pub struct InvoiceDraftEmail<'a> {
+    /// Name of the billed company.
+    pub company_name: &'a str,
+    /// Monday of the week the drafted work was done in.
+    pub week_start: NaiveDate,
+    /// Total of the draft invoice in dollars.
+    pub total_amount: f64,
+}
+
+impl EmailTemplate for InvoiceDraftEmail<'_> {
+    fn render_english(&self) -> EmailContent {
+        let Self {
+            company_name,
+            total_amount,
+            ..
+        } = self;
+        let week_start = format_date(self.week_start, Locale::En);
+
+        EmailContent {
+            subject: format!("Your {company_name} invoice draft is ready for review"),
+            body: format!(
+                "Last week's work for {company_name}, starting {week_start}, has been added to \
+                 a draft invoice totaling ${total_amount:.2}. Review and finalize it before \
+                 sending it to {company_name}."
+            ),
+        }
+    }
+
+    fn render_translated(&self, locale: Locale) -> Option<EmailContent> {
+        let Self {
+            company_name,
+            total_amount,
+            ..
+        } = self;
+        let week_start = format_date(self.week_start, locale);
+
+        match locale {
+            Locale::Es => Some(EmailContent {
+                subject: format!(
+                    "Tu borrador de factura para {company_name} está listo para revisar"
+                ),
+                body: format!(
+                    "El trabajo de la semana pasada para {company_name}, a partir del \
+                     {week_start}, se ha añadido a un borrador de factura por un total de \
+                     ${total_amount:.2}. Revísalo y finalízalo antes de enviarlo a \
+                     {company_name}."
+                ),
+            }),
+            Locale::En | Locale::Fr => None,
+        }
+    }
+}
@@ -0,0 +1,348 @@
+//! Localized email templates.
+//!
+//! Each template renders the subject and plain-text body of one kind of
+//! email in a requested [`Locale`]. Every template is written in English;
+//! templates that have not been translated into a locale fall back to
+//! English, so a locale can be added one template at a time. The rendered
+//! output of every template in every locale is checked against the
+//! snapshot files in `src/email/templates/snapshots`, which are rewritten
+//! by running the tests with `UPDATE_EMAIL_SNAPSHOTS` set.
+//!
+//! # Modules
+//!
+//! - [`auth`](crate::email::templates::auth) — One-time code emails for account actions.
+//! - [`budget`](crate::email::templates::budget) — Budget threshold alerts.
+//! - [`contract`](crate::email::templates::contract) — Contract renewal reminders.
+//! - [`invoice`](crate::email::templates::invoice) — Weekly invoice draft notices.
+//! - [`payment`](crate::email::templates::payment) — Upcoming and overdue payment reminders.
+//! - [`security`](crate::email::templates::security) — Unusual account activity notifications.
+//! - [`statement`](crate::email::templates::statement) — Company statement cover emails.
+
+pub mod auth;
+pub mod budget;
+pub mod contract;
+pub mod invoice;
+pub mod payment;
+pub mod security;
+pub mod statement;
+
+use chrono::{Datelike, NaiveDate};
+use gig_log_common::models::locale::Locale;
+
+/// Spanish month names, starting with January.
+const SPANISH_MONTHS: [&str; 12] = [
+    "enero",
+    "febrero",
+    "marzo",
+    "abril",
+    "mayo",
+    "junio",
+    "julio",
+    "agosto",
+    "septiembre",
+    "octubre",
+    "noviembre",
+    "diciembre",
+];
+
+/// French month names, starting with January.
+const FRENCH_MONTHS: [&str; 12] = [
+    "janvier",
+    "février",
+    "mars",
+    "avril",
+    "mai",
+    "juin",
+    "juillet",
+    "août",
+    "septembre",
+    "octobre",
+    "novembre",
+    "décembre",
+];
+
+/// The rendered subject and body of an email.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailContent {
+    /// Subject line.
+    pub subject: String,
+    /// Plain-text body.
+    pub body: String,
+}
+
+/// An email that can be rendered in the user's language.
+pub trait EmailTemplate {
+    /// Renders the email in English.
+    ///
+    /// # Returns
+    ///
+    /// The English [`EmailContent`].
+    fn render_english(&self) -> EmailContent;
+
+    /// Renders the email in a language other than English.
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` — The language to render in.
+    ///
+    /// # Returns
+    ///
+    /// The translated [`EmailContent`], or `None` if the template has not
+    /// been translated into the locale.
+    fn render_translated(&self, locale: Locale) -> Option<EmailContent>;
+
+    /// Renders the email in a locale, falling back to English.
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` — The recipient's preferred language.
+    ///
+    /// # Returns
+    ///
+    /// The [`EmailContent`] in the locale if the template has been
+    /// translated into it, otherwise in English.
+    fn render(&self, locale: Locale) -> EmailContent {
+        match locale {
+            Locale::En => self.render_english(),
+            locale => self
+                .render_translated(locale)
+                .unwrap_or_else(|| self.render_english()),
+        }
+    }
+}
+
+/// Formats a date the way it is written in a locale.
+///
+/// # Arguments
+///
+/// * `date` — The date to format.
+/// * `locale` — The language to write it in.
+///
+/// # Returns
+///
+/// The date with its month spelled out, e.g. `"October 16, 2026"` in
+/// English or `"16 de octubre de 2026"` in Spanish.
+pub fn format_date(date: NaiveDate, locale: Locale) -> String {
+    let month = date.month0() as usize;
+
+    match locale {
+        Locale::En => date.format("%B %-d, %Y").to_string(),
+        Locale::Es => format!(
+            "{} de {} de {}",
+            date.day(),
+            SPANISH_MONTHS[month],
+            date.year()
+        ),
+        Locale::Fr => {
+            let day = match date.day() {
+                1 => "1er".to_string(),
+                day => day.to_string(),
+            };
+
+            format!("{day} {} {}", FRENCH_MONTHS[month], date.year())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeSet, fs, path::PathBuf};
+
+    use gig_log_common::models::budget::BudgetType;
+
+    use super::*;
+    use crate::email::templates::{
+        auth::{AuthCodeEmail, AuthEmailKind},
+        budget::BudgetThresholdEmail,
+        contract::ContractRenewalEmail,
+        invoice::InvoiceDraftEmail,
+        payment::PaymentReminderEmail,
+        security::{UnusualActivity, UnusualActivityEmail},
+        statement::StatementEmail,
+    };
+    use crate::repo::payment::PaymentReminderStage;
+
+    const LINK: &str = "https://app.giglog.test/auth/confirm?token=abc123";
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn snapshot_dir() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src/email/templates/snapshots")
+    }
+
+    fn samples() -> Vec<(&'static str, Box<dyn EmailTemplate>)> {
+        let auth = |kind| {
+            Box::new(AuthCodeEmail {
+                kind,
+                code: "482913",
+                link: Some(LINK),
+            }) as Box<dyn EmailTemplate>
+        };
+
+        vec![
+            (
+                "auth_email_verification",
+                auth(AuthEmailKind::EmailVerification),
+            ),
+            ("auth_password_reset", auth(AuthEmailKind::PasswordReset)),
+            ("auth_email_change", auth(AuthEmailKind::EmailChange)),
+            ("auth_password_change", auth(AuthEmailKind::PasswordChange)),
+            (
+                "budget_threshold",
+                Box::new(BudgetThresholdEmail {
+                    target_name: "Acme Design Studio",
+                    budget_type: &BudgetType::Amount,
+                    threshold_percent: 80,
+                    consumed: 1600.0,
+                    monthly_limit: 2000.0,
+                }),
+            ),
+            (
+                "contract_renewal",
+                Box::new(ContractRenewalEmail {
+                    contract_title: "Retainer 2026",
+                    company_name: "Acme Design Studio",
+                    end_date: date(2026, 11, 1),
+                }),
+            ),
+            (
+                "invoice_draft_ready",
+                Box::new(InvoiceDraftEmail {
+                    company_name: "Acme Design Studio",
+                    week_start: date(2026, 10, 5),
+                    total_amount: 1234.5,
+                }),
+            ),
+            (
+                "payment_upcoming",
+                Box::new(PaymentReminderEmail {
+                    stage: PaymentReminderStage::Upcoming,
+                    company_name: "Acme Design Studio",
+                    amount_outstanding: 1500.0,
+                    expected_payout_date: date(2026, 10, 20),
+                }),
+            ),
+            (
+                "payment_overdue",
+                Box::new(PaymentReminderEmail {
+                    stage: PaymentReminderStage::Overdue,
+                    company_name: "Acme Design Studio",
+                    amount_outstanding: 750.25,
+                    expected_payout_date: date(2026, 10, 1),
+                }),
+            ),
+            (
+                "security_failed_logins",
+                Box::new(UnusualActivityEmail {
+                    activity: UnusualActivity::FailedLogins {
+                        count: 10,
+                        minutes: 15,
+                    },
+                }),
+            ),
+            (
+                "security_new_country",
+                Box::new(UnusualActivityEmail {
+                    activity: UnusualActivity::NewCountryLogin {
+                        country: "BR".to_string(),
+                    },
+                }),
+            ),
+            (
+                "security_mass_deletion",
+                Box::new(UnusualActivityEmail {
+                    activity: UnusualActivity::MassDeletion {
+                        count: 25,
+                        minutes: 10,
+                    },
+                }),
+            ),
+            (
+                "statement",
+                Box::new(StatementEmail {
+                    sender_name: "Jordan Smith",
+                    company_name: "Acme Design Studio",
+                    period_start: date(2026, 9, 1),
+                    period_end: date(2026, 9, 30),
+                }),
+            ),
+        ]
+    }
+
+    fn snapshot(content: &EmailContent) -> String {
+        format!("Subject: {}\n\n{}\n", content.subject, content.body)
+    }
+
+    #[test]
+    fn every_template_matches_its_snapshot_in_every_locale() {
+        let update = std::env::var_os("UPDATE_EMAIL_SNAPSHOTS").is_some();
+        let mut mismatches = Vec::new();
+
+        for (name, template) in samples() {
+            for locale in Locale::ALL {
+                let path = snapshot_dir().join(format!("{name}.{}.txt", locale.as_str()));
+                let actual = snapshot(&template.render(locale));
+
+                if update {
+                    fs::write(&path, &actual).unwrap();
+                } else if fs::read_to_string(&path).ok().as_deref() != Some(actual.as_str()) {
+                    mismatches.push(path.display().to_string());
+                }
+            }
+        }
+
+        assert!(
+            mismatches.is_empty(),
+            "email snapshots differ; rerun with UPDATE_EMAIL_SNAPSHOTS=1 to accept: {mismatches:#?}"
+        );
+    }
+
+    #[test]
+    fn every_snapshot_belongs_to_a_template_and_locale() {
+        let expected: BTreeSet<String> = samples()
+            .iter()
+            .flat_map(|(name, _)| {
+                Locale::ALL
+                    .iter()
+                    .map(move |locale| format!("{name}.{}.txt", locale.as_str()))
+            })
+            .collect();
+        let actual: BTreeSet<String> = fs::read_dir(snapshot_dir())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn untranslated_templates_fall_back_to_english() {
+        let template = ContractRenewalEmail {
+            contract_title: "Retainer",
+            company_name: "Acme",
+            end_date: date(2026, 11, 1),
+        };
+
+        assert!(template.render_translated(Locale::Fr).is_none());
+        assert_eq!(template.render(Locale::Fr), template.render_english());
+        assert_ne!(template.render(Locale::Es), template.render_english());
+    }
+
+    #[test]
+    fn formats_dates_per_locale() {
+        assert_eq!(
+            format_date(date(2026, 10, 16), Locale::En),
+            "October 16, 2026"
+        );
+        assert_eq!(
+            format_date(date(2026, 10, 16), Locale::Es),
+            "16 de octubre de 2026"
+        );
+        assert_eq!(
+            format_date(date(2026, 2, 1), Locale::Fr),
+            "1er février 2026"
+        );
+    }
+}
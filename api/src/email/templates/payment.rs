@@ -0,0 +1,89 @@
+//! Payment reminder email templates.
+//!
+//! Provides [`PaymentReminderEmail`], which renders the reminders sent
+//! before a payment's expected payout date and once it is overdue.
+//! Translated into Spanish.
+
+use chrono::NaiveDate;
+use gig_log_common::models::locale::Locale;
+
+use crate::email::templates::{EmailContent, EmailTemplate, format_date};
+use crate::repo::payment::PaymentReminderStage;
+
+/// A reminder about a payment that has not been received yet.
+pub struct PaymentReminderEmail<'a> {
+    /// Whether the payment is coming up or overdue.
+    pub stage: PaymentReminderStage,
+    /// Name of the company the payment is from.
+    pub company_name: &'a str,
+    /// Amount still outstanding in dollars.
+    pub amount_outstanding: f64,
+    /// Date the payment was expected.
+    pub expected_payout_date: NaiveDate,
+}
+
+impl EmailTemplate for PaymentReminderEmail<'_> {
+    fn render_english(&self) -> EmailContent {
+        let Self {
+            company_name,
+            amount_outstanding,
+            ..
+        } = self;
+        let date = format_date(self.expected_payout_date, Locale::En);
+        let opt_out = "You can turn off payment reminders in your settings.";
+
+        match self.stage {
+            PaymentReminderStage::Upcoming => EmailContent {
+                subject: format!("Your {company_name} payment is expected on {date}"),
+                body: format!(
+                    "Your ${amount_outstanding:.2} payment from {company_name} is expected on \
+                     {date}. Mark it received in GigLog once it arrives. {opt_out}"
+                ),
+            },
+            PaymentReminderStage::Overdue => EmailContent {
+                subject: format!("Your {company_name} payment is overdue"),
+                body: format!(
+                    "Your ${amount_outstanding:.2} payment from {company_name} was expected on \
+                     {date} and has not been marked received. Follow up with {company_name} or \
+                     update the payment in GigLog. {opt_out}"
+                ),
+            },
+        }
+    }
+
+    fn render_translated(&self, locale: Locale) -> Option<EmailContent> {
+        let Self {
+            company_name,
+            amount_outstanding,
+            ..
+        } = self;
+        let date = format_date(self.expected_payout_date, locale);
+
+        match locale {
+            Locale::Es => {
+                let opt_out = "Puedes desactivar los recordatorios de pago en tu configuración.";
+
+                Some(match self.stage {
+                    PaymentReminderStage::Upcoming => EmailContent {
+                        subject: format!("Tu pago de {company_name} está previsto para el {date}"),
+                        body: format!(
+                            "Tu pago de ${amount_outstanding:.2} de {company_name} está previsto \
+                             para el {date}. Márcalo como recibido en GigLog cuando llegue. \
+                             {opt_out}"
+                        ),
+                    },
+                    PaymentReminderStage::Overdue => EmailContent {
+                        subject: format!("Tu pago de {company_name} está vencido"),
+                        body: format!(
+                            "Tu pago de ${amount_outstanding:.2} de {company_name} estaba \
+                             previsto para el {date} y aún no se ha marcado como recibido. Haz \
+                             un seguimiento con {company_name} o actualiza el pago en GigLog. \
+                             {opt_out}"
+                        ),
+                    },
+                })
+            }
+            Locale::En | Locale::Fr => None,
+        }
+    }
+}
@@ -0,0 +1,136 @@
+//! Unusual account activity email templates.
+//!
+//! Provides [`UnusualActivityEmail`], which renders the notification sent
+//! when unusual activity is detected on an account, and
+//! [`UnusualActivity`], which describes what was detected. Translated into
+//! Spanish and French.
+
+use gig_log_common::models::locale::Locale;
+
+use crate::email::templates::{EmailContent, EmailTemplate};
+
+/// Unusual activity detected on an account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnusualActivity {
+    /// Too many failed log-ins in a short time.
+    FailedLogins {
+        /// Number of failed log-ins in the window.
+        count: i64,
+        /// Length of the window in minutes.
+        minutes: u64,
+    },
+    /// A log-in from a country the user has not logged in from before.
+    NewCountryLogin {
+        /// The country's code.
+        country: String,
+    },
+    /// Too many deletions in a short time.
+    MassDeletion {
+        /// Number of deletions in the window.
+        count: i64,
+        /// Length of the window in minutes.
+        minutes: u64,
+    },
+}
+
+impl UnusualActivity {
+    /// Describes the activity as a sentence.
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` — The language to write the sentence in. Locales without
+    ///   a translation are written in English.
+    ///
+    /// # Returns
+    ///
+    /// The description, e.g. `"We blocked 10 failed attempts to log in to
+    /// your account in the last 15 minutes."`.
+    pub fn describe(&self, locale: Locale) -> String {
+        match (locale, self) {
+            (Locale::Es, UnusualActivity::FailedLogins { count, minutes }) => format!(
+                "Bloqueamos {count} intentos fallidos de iniciar sesión en tu cuenta en los \
+                 últimos {minutes} minutos."
+            ),
+            (Locale::Es, UnusualActivity::NewCountryLogin { country }) => {
+                format!("Se inició sesión en tu cuenta desde un país nuevo ({country}).")
+            }
+            (Locale::Es, UnusualActivity::MassDeletion { count, minutes }) => format!(
+                "Se eliminaron {count} registros de tu cuenta en los últimos {minutes} minutos."
+            ),
+            (Locale::Fr, UnusualActivity::FailedLogins { count, minutes }) => format!(
+                "Nous avons bloqué {count} tentatives de connexion échouées à votre compte au \
+                 cours des {minutes} dernières minutes."
+            ),
+            (Locale::Fr, UnusualActivity::NewCountryLogin { country }) => format!(
+                "Une connexion à votre compte a été effectuée depuis un nouveau pays \
+                 ({country})."
+            ),
+            (Locale::Fr, UnusualActivity::MassDeletion { count, minutes }) => format!(
+                "{count} enregistrements ont été supprimés de votre compte au cours des \
+                 {minutes} dernières minutes."
+            ),
+            (Locale::En, UnusualActivity::FailedLogins { count, minutes }) => format!(
+                "We blocked {count} failed attempts to log in to your account in the last \
+                 {minutes} minutes."
+            ),
+            (Locale::En, UnusualActivity::NewCountryLogin { country }) => {
+                format!("Your account was logged in to from a new country ({country}).")
+            }
+            (Locale::En, UnusualActivity::MassDeletion { count, minutes }) => {
+                format!(
+                    "{count} records were deleted from your account in the last {minutes} minutes."
+                )
+            }
+        }
+    }
+}
+
+/// A notification that unusual activity was detected on the account.
+pub struct UnusualActivityEmail {
+    /// What was detected.
+    pub activity: UnusualActivity,
+}
+
+impl EmailTemplate for UnusualActivityEmail {
+    fn render_english(&self) -> EmailContent {
+        EmailContent {
+            subject: "Unusual activity on your GigLog account".to_string(),
+            body: format!(
+                "{} If this was you, no action is needed. If not, change your password and \
+                 review your active sessions. Until you log in again, changes to your \
+                 password, email, and sign-in methods are blocked, and any codes we emailed \
+                 you before now no longer work.",
+                self.activity.describe(Locale::En)
+            ),
+        }
+    }
+
+    fn render_translated(&self, locale: Locale) -> Option<EmailContent> {
+        let description = self.activity.describe(locale);
+
+        match locale {
+            Locale::Es => Some(EmailContent {
+                subject: "Actividad inusual en tu cuenta de GigLog".to_string(),
+                body: format!(
+                    "{description} Si fuiste tú, no necesitas hacer nada. Si no, cambia tu \
+                     contraseña y revisa tus sesiones activas. Hasta que vuelvas a iniciar \
+                     sesión, los cambios en tu contraseña, correo electrónico y métodos de \
+                     inicio de sesión están bloqueados, y los códigos que te enviamos por \
+                     correo antes de ahora ya no funcionan."
+                ),
+            }),
+            Locale::Fr => Some(EmailContent {
+                subject: "Activité inhabituelle sur votre compte GigLog".to_string(),
+                body: format!(
+                    "{description} Si c'était vous, aucune action n'est requise. Sinon, \
+                     changez votre mot de passe et vérifiez vos sessions actives. Tant que vous \
+                     ne vous êtes pas reconnecté, les modifications de votre mot de passe, de \
+                     votre adresse e-mail et de vos méthodes de connexion sont bloquées, et les \
+                     codes que nous vous avons envoyés par e-mail jusqu'à présent ne \
+                     fonctionnent plus."
+                ),
+            }),
+            Locale::En => None,
+        }
+    }
+}
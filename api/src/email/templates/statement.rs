@@ -0,0 +1,62 @@
+//! Company statement email templates.
+//!
+//! Provides [`StatementEmail`], which renders the cover email sent with a
+//! company statement PDF. Translated into Spanish.
+
+use chrono::NaiveDate;
+use gig_log_common::models::locale::Locale;
+
+use crate::email::templates::{EmailContent, EmailTemplate, format_date};
+
+/// The cover email for a statement of hours and payments.
+pub struct StatementEmail<'a> {
+    /// Full name of the user sending the statement.
+    pub sender_name: &'a str,
+    /// Name of the company the statement covers.
+    pub company_name: &'a str,
+    /// First day covered by the statement.
+    pub period_start: NaiveDate,
+    /// Last day covered by the statement.
+    pub period_end: NaiveDate,
+}
+
+impl EmailTemplate for StatementEmail<'_> {
+    fn render_english(&self) -> EmailContent {
+        let Self {
+            sender_name,
+            company_name,
+            ..
+        } = self;
+        let start = format_date(self.period_start, Locale::En);
+        let end = format_date(self.period_end, Locale::En);
+
+        EmailContent {
+            subject: format!("Statement from {sender_name} for {start} - {end}"),
+            body: format!(
+                "Attached is a statement of hours worked for {company_name} and payments \
+                 received from {start} to {end}."
+            ),
+        }
+    }
+
+    fn render_translated(&self, locale: Locale) -> Option<EmailContent> {
+        let Self {
+            sender_name,
+            company_name,
+            ..
+        } = self;
+        let start = format_date(self.period_start, locale);
+        let end = format_date(self.period_end, locale);
+
+        match locale {
+            Locale::Es => Some(EmailContent {
+                subject: format!("Estado de cuenta de {sender_name} del {start} al {end}"),
+                body: format!(
+                    "Se adjunta un estado de cuenta de las horas trabajadas para {company_name} \
+                     y los pagos recibidos del {start} al {end}."
+                ),
+            }),
+            Locale::En | Locale::Fr => None,
+        }
+    }
+}
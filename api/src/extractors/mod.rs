@@ -7,7 +7,11 @@
 //! # Modules
 //!
 //! - `validated_json` — JSON extractor with automatic validation.
+//! - `validated_query` — Query string extractor with automatic validation.
 
 mod validated_json;
+mod validated_query;
 
 pub use validated_json::ValidatedJson;
+pub(crate) use validated_json::format_field_name;
+pub use validated_query::ValidatedQuery;
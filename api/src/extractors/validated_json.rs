@@ -7,9 +7,9 @@
 
 use axum::{
     extract::rejection::JsonRejection,
-    extract::{FromRequest, Json, Request},
+    extract::{FromRequest, Json, OptionalFromRequest, Request},
 };
-use gig_log_common::models::error::ValidationError;
+use gig_log_common::models::error::{ValidationError, ValidationErrorCode};
 use validator::Validate;
 
 use crate::core::error::ApiErrorResponse;
@@ -69,15 +69,7 @@ where
     async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
         let Json(value) = Json::<T>::from_request(req, state)
             .await
-            .map_err(|err| match err {
-                JsonRejection::JsonDataError(error) => {
-                    let message = error.body_text();
-
-                    map_missing_field_error(&message)
-                        .unwrap_or_else(|| ApiErrorResponse::BadRequest(message))
-                }
-                error => ApiErrorResponse::from(error),
-            })?;
+            .map_err(map_json_rejection)?;
 
         value.validate().map_err(ApiErrorResponse::from)?;
 
@@ -85,6 +77,69 @@ where
     }
 }
 
+impl<S, T> OptionalFromRequest<S> for ValidatedJson<T>
+where
+    S: Send + Sync,
+    T: Validate + Send,
+    Json<T>: OptionalFromRequest<S, Rejection = JsonRejection>,
+{
+    type Rejection = ApiErrorResponse;
+
+    /// Extracts and validates an optional JSON payload.
+    ///
+    /// Requests without a `Content-Type` header are treated as having no
+    /// payload. Otherwise the payload is deserialized and validated as in
+    /// [`FromRequest::from_request`].
+    ///
+    /// # Arguments
+    ///
+    /// * `req` — The incoming HTTP request.
+    /// * `state` — The Axum application state.
+    ///
+    /// # Returns
+    ///
+    /// A [`ValidatedJson<T>`] containing the validated value, or `None` if
+    /// the request has no payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`FromRequest::from_request`] when a
+    /// payload is present.
+    async fn from_request(req: Request, state: &S) -> Result<Option<Self>, Self::Rejection> {
+        let Some(Json(value)) = <Json<T> as OptionalFromRequest<S>>::from_request(req, state)
+            .await
+            .map_err(map_json_rejection)?
+        else {
+            return Ok(None);
+        };
+
+        value.validate().map_err(ApiErrorResponse::from)?;
+
+        Ok(Some(Self(value)))
+    }
+}
+
+/// Converts a JSON extraction failure into an [`ApiErrorResponse`].
+///
+/// # Arguments
+///
+/// * `rejection` — The rejection returned by Axum's [`Json`] extractor.
+///
+/// # Returns
+///
+/// An [`ApiErrorResponse::Validation`] for missing fields, or an
+/// [`ApiErrorResponse::BadRequest`] for other deserialization failures.
+fn map_json_rejection(rejection: JsonRejection) -> ApiErrorResponse {
+    match rejection {
+        JsonRejection::JsonDataError(error) => {
+            let message = error.body_text();
+
+            map_missing_field_error(&message).unwrap_or(ApiErrorResponse::BadRequest(message))
+        }
+        error => ApiErrorResponse::from(error),
+    }
+}
+
 /// Maps a serde missing-field error message into a structured validation error.
 ///
 /// # Arguments
@@ -95,11 +150,12 @@ where
 ///
 /// An [`ApiErrorResponse::Validation`] if the message contains a missing-field
 /// error, or [`None`] if the message does not match the expected pattern.
-fn map_missing_field_error(message: &str) -> Option<ApiErrorResponse> {
+pub(super) fn map_missing_field_error(message: &str) -> Option<ApiErrorResponse> {
     let field = extract_missing_field_name(message)?;
 
     Some(ApiErrorResponse::Validation(vec![ValidationError {
         field: Some(field.to_string()),
+        code: ValidationErrorCode::FieldRequired,
         message: format!("{} is required", format_field_name(field)),
     }]))
 }
@@ -138,7 +194,7 @@ fn extract_missing_field_name(message: &str) -> Option<&str> {
 ///
 /// A [`String`] with underscores replaced by spaces and the first letter
 /// capitalized (e.g., `"first_name"` becomes `"First name"`).
-fn format_field_name(field: &str) -> String {
+pub(crate) fn format_field_name(field: &str) -> String {
     let normalized = field.replace('_', " ");
     let mut chars = normalized.chars();
 
@@ -156,6 +212,7 @@ mod tests {
         http::{Request, StatusCode, header::CONTENT_TYPE},
         response::IntoResponse,
     };
+    use gig_log_common::models::error::ValidationErrorCode;
     use serde::Deserialize;
     use validator::Validate;
 
@@ -186,6 +243,7 @@ mod tests {
             ApiErrorResponse::Validation(errors) => {
                 assert_eq!(errors.len(), 1);
                 assert_eq!(errors[0].field.as_deref(), Some("name"));
+                assert_eq!(errors[0].code, ValidationErrorCode::FieldRequired);
             }
             _ => panic!("expected validation error"),
         }
@@ -210,6 +268,7 @@ mod tests {
             ApiErrorResponse::Validation(errors) => {
                 assert_eq!(errors.len(), 1);
                 assert_eq!(errors[0].field.as_deref(), Some("name"));
+                assert_eq!(errors[0].code, ValidationErrorCode::FieldRequired);
             }
             _ => panic!("expected validation error"),
         }
@@ -240,6 +299,37 @@ mod tests {
         assert_eq!(err.into_response().status(), StatusCode::BAD_REQUEST);
     }
 
+    #[tokio::test]
+    async fn allows_missing_optional_payload() {
+        let req = Request::builder()
+            .method("POST")
+            .uri("/")
+            .body(Body::empty())
+            .expect("request should build");
+
+        let extracted = Option::<ValidatedJson<TestPayload>>::from_request(req, &())
+            .await
+            .expect("extractor should accept a missing payload");
+
+        assert!(extracted.is_none());
+    }
+
+    #[tokio::test]
+    async fn rejects_optional_payload_when_validation_fails() {
+        let req = Request::builder()
+            .method("POST")
+            .uri("/")
+            .header(CONTENT_TYPE, "application/json")
+            .body(Body::from(r#"{"name":""}"#))
+            .expect("request should build");
+
+        let err = Option::<ValidatedJson<TestPayload>>::from_request(req, &())
+            .await
+            .expect_err("extractor should reject invalid payload");
+
+        assert!(matches!(err, ApiErrorResponse::Validation(_)));
+    }
+
     #[tokio::test]
     async fn allows_payload_when_json_and_validation_are_valid() {
         let req = Request::builder()
@@ -0,0 +1,191 @@
+//! Validated query string extractor for Axum request handlers.
+//!
+//! This module provides [`ValidatedQuery<T>`], a custom Axum extractor that
+//! combines query string deserialization with automatic validation using
+//! the [`validator`] crate. Failures are reported with the same structured
+//! [`ApiErrorResponse`] variants as [`ValidatedJson`](super::ValidatedJson).
+
+use axum::{
+    extract::rejection::QueryRejection,
+    extract::{FromRequestParts, Query},
+    http::request::Parts,
+};
+use validator::Validate;
+
+use crate::core::error::ApiErrorResponse;
+
+use super::validated_json::map_missing_field_error;
+
+/// Axum extractor that deserializes the query string and validates the
+/// result.
+///
+/// Wraps Axum's [`Query`] extractor with an additional validation step
+/// using the [`Validate`] trait. If deserialization or validation fails, a
+/// structured [`ApiErrorResponse`] is returned to the client.
+#[derive(Debug)]
+pub struct ValidatedQuery<T>(
+    /// The validated inner value.
+    pub T,
+);
+
+impl<T> ValidatedQuery<T> {
+    /// Consumes the extractor and returns the inner validated value.
+    ///
+    /// # Returns
+    ///
+    /// The inner `T` that was deserialized and validated.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<S, T> FromRequestParts<S> for ValidatedQuery<T>
+where
+    S: Send + Sync,
+    T: Validate,
+    Query<T>: FromRequestParts<S, Rejection = QueryRejection>,
+{
+    type Rejection = ApiErrorResponse;
+
+    /// Extracts and validates the query string of an incoming request.
+    ///
+    /// Deserializes the query string using Axum's [`Query`] extractor, then
+    /// runs [`Validate::validate`] on the result. Missing-field errors from
+    /// serde are converted into structured [`ApiErrorResponse::Validation`]
+    /// responses with human-readable field names.
+    ///
+    /// # Arguments
+    ///
+    /// * `parts` — The incoming request's head.
+    /// * `state` — The Axum application state.
+    ///
+    /// # Returns
+    ///
+    /// A [`ValidatedQuery<T>`] containing the deserialized and validated
+    /// value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`] if a required parameter is
+    /// missing or validation constraints are violated.
+    /// Returns [`ApiErrorResponse::BadRequest`] if deserialization fails for
+    /// other reasons (e.g., a malformed date).
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Query(value) = Query::<T>::from_request_parts(parts, state)
+            .await
+            .map_err(map_query_rejection)?;
+
+        value.validate().map_err(ApiErrorResponse::from)?;
+
+        Ok(Self(value))
+    }
+}
+
+/// Converts a query string extraction failure into an [`ApiErrorResponse`].
+///
+/// # Arguments
+///
+/// * `rejection` — The rejection returned by Axum's [`Query`] extractor.
+///
+/// # Returns
+///
+/// An [`ApiErrorResponse::Validation`] for missing parameters, or an
+/// [`ApiErrorResponse::BadRequest`] for other deserialization failures.
+fn map_query_rejection(rejection: QueryRejection) -> ApiErrorResponse {
+    let message = rejection.body_text();
+
+    map_missing_field_error(&message).unwrap_or(ApiErrorResponse::BadRequest(message))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        extract::FromRequestParts,
+        http::{Request, StatusCode},
+        response::IntoResponse,
+    };
+    use gig_log_common::models::error::ValidationErrorCode;
+    use serde::Deserialize;
+    use validator::Validate;
+
+    use crate::core::error::ApiErrorResponse;
+
+    use super::ValidatedQuery;
+
+    #[derive(Debug, Deserialize, Validate)]
+    struct TestQuery {
+        #[validate(length(min = 1, message = "Name is required"))]
+        name: String,
+        #[validate(range(min = 1, max = 50, message = "Limit must be between 1 and 50"))]
+        limit: Option<i64>,
+    }
+
+    async fn extract(uri: &str) -> Result<ValidatedQuery<TestQuery>, ApiErrorResponse> {
+        let (mut parts, _) = Request::builder()
+            .uri(uri)
+            .body(())
+            .expect("request should build")
+            .into_parts();
+
+        ValidatedQuery::<TestQuery>::from_request_parts(&mut parts, &()).await
+    }
+
+    #[tokio::test]
+    async fn rejects_query_when_validation_fails() {
+        let err = extract("/?name=GigLog&limit=100")
+            .await
+            .expect_err("extractor should reject invalid query");
+
+        match &err {
+            ApiErrorResponse::Validation(errors) => {
+                assert_eq!(errors.len(), 1);
+                assert_eq!(errors[0].field.as_deref(), Some("limit"));
+                assert_eq!(errors[0].code, ValidationErrorCode::Range);
+            }
+            _ => panic!("expected validation error"),
+        }
+
+        assert_eq!(err.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn rejects_query_when_required_parameter_is_missing() {
+        let err = extract("/?limit=5")
+            .await
+            .expect_err("extractor should reject missing required parameters");
+
+        match &err {
+            ApiErrorResponse::Validation(errors) => {
+                assert_eq!(errors.len(), 1);
+                assert_eq!(errors[0].field.as_deref(), Some("name"));
+                assert_eq!(errors[0].code, ValidationErrorCode::FieldRequired);
+            }
+            _ => panic!("expected validation error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn rejects_query_when_parameter_is_malformed() {
+        let err = extract("/?name=GigLog&limit=many")
+            .await
+            .expect_err("extractor should reject malformed parameters");
+
+        match &err {
+            ApiErrorResponse::BadRequest(message) => {
+                assert!(message.contains("Failed to deserialize"));
+            }
+            _ => panic!("expected bad request error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn allows_query_when_parameters_are_valid() {
+        let extracted = extract("/?name=GigLog&limit=10")
+            .await
+            .expect("extractor should accept valid query");
+
+        let query = extracted.into_inner();
+        assert_eq!(query.name, "GigLog");
+        assert_eq!(query.limit, Some(10));
+    }
+}
@@ -0,0 +1,190 @@
+//! Resizing of uploaded logos and avatars.
+//!
+//! Provides [`ImageUtil`] for decoding an uploaded picture and re-encoding
+//! it as a PNG at a standard size, so stored images have predictable
+//! dimensions and any metadata in the original file is dropped.
+
+use std::io::Cursor;
+
+use axum::{
+    http::header,
+    response::{IntoResponse, Response},
+};
+use image::{DynamicImage, ImageFormat, ImageReader, Limits, imageops::FilterType};
+
+use crate::core::error::{ApiErrorResponse, ApiResult};
+
+/// Maximum accepted size of an uploaded logo or avatar, in bytes.
+pub const MAX_IMAGE_UPLOAD_BYTES: usize = 5 * 1024 * 1024;
+
+/// Width and height, in pixels, of the box a company logo is fitted into.
+pub const LOGO_SIZE: u32 = 256;
+
+/// Width and height, in pixels, of a user avatar.
+pub const AVATAR_SIZE: u32 = 256;
+
+/// Largest width or height, in pixels, of an uploaded image.
+const MAX_SOURCE_DIMENSION: u32 = 8192;
+
+/// `Cache-Control` value for served images. Image URLs change whenever a
+/// new image is uploaded, so a cached copy never goes stale.
+const IMAGE_CACHE_CONTROL: &str = "private, max-age=31536000, immutable";
+
+/// How an image is resized to a square of the standard size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFit {
+    /// Scale the whole image to fit inside the square, keeping its aspect
+    /// ratio. Used for logos, which must not be cropped.
+    Contain,
+    /// Scale the image to cover the square and crop the overflow from the
+    /// center. Used for avatars.
+    Cover,
+}
+
+/// Utility for resizing uploaded images.
+pub struct ImageUtil;
+
+impl ImageUtil {
+    /// Resizes an uploaded image on a blocking thread.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` — The uploaded file bytes.
+    /// * `size` — Width and height of the target square, in pixels.
+    /// * `fit` — How the image is fitted to the square.
+    ///
+    /// # Returns
+    ///
+    /// The resized image encoded as a PNG.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the upload is not a
+    /// supported image or is too large, or
+    /// [`ApiErrorResponse::InternalServerError`] if the resized image
+    /// cannot be encoded.
+    pub async fn resize(data: Vec<u8>, size: u32, fit: ImageFit) -> ApiResult<Vec<u8>> {
+        tokio::task::spawn_blocking(move || Self::resize_blocking(&data, size, fit))
+            .await
+            .map_err(|error| ApiErrorResponse::InternalServerError(error.to_string()))?
+    }
+
+    /// Builds the response that serves a stored image.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` — The stored PNG bytes.
+    ///
+    /// # Returns
+    ///
+    /// A [`Response`] containing the image with long-lived private caching.
+    pub fn response(data: Vec<u8>) -> Response {
+        (
+            [
+                (header::CONTENT_TYPE, "image/png"),
+                (header::CACHE_CONTROL, IMAGE_CACHE_CONTROL),
+            ],
+            data,
+        )
+            .into_response()
+    }
+
+    /// Decodes, resizes, and re-encodes an image.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` — The uploaded file bytes.
+    /// * `size` — Width and height of the target square, in pixels.
+    /// * `fit` — How the image is fitted to the square.
+    ///
+    /// # Returns
+    ///
+    /// The resized image encoded as a PNG.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the upload is not a
+    /// supported image or is too large, or
+    /// [`ApiErrorResponse::InternalServerError`] if the resized image
+    /// cannot be encoded.
+    fn resize_blocking(data: &[u8], size: u32, fit: ImageFit) -> ApiResult<Vec<u8>> {
+        let image = Self::decode(data).ok_or_else(|| {
+            ApiErrorResponse::BadRequest(format!(
+                "Image must be a PNG, JPEG, GIF, or WebP file no larger than \
+                 {MAX_SOURCE_DIMENSION}x{MAX_SOURCE_DIMENSION} pixels"
+            ))
+        })?;
+
+        let resized = match fit {
+            ImageFit::Contain => image.resize(size, size, FilterType::Lanczos3),
+            ImageFit::Cover => image.resize_to_fill(size, size, FilterType::Lanczos3),
+        };
+
+        let mut png = Vec::new();
+        resized
+            .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+            .map_err(|error| ApiErrorResponse::InternalServerError(error.to_string()))?;
+
+        Ok(png)
+    }
+
+    /// Decodes an uploaded image, detecting its format from its contents.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` — The uploaded file bytes.
+    ///
+    /// # Returns
+    ///
+    /// The decoded image, or `None` if the format is unsupported, the file
+    /// is corrupt, or its dimensions exceed the upload limit.
+    fn decode(data: &[u8]) -> Option<DynamicImage> {
+        let mut reader = ImageReader::new(Cursor::new(data))
+            .with_guessed_format()
+            .ok()?;
+        let mut limits = Limits::default();
+        limits.max_image_width = Some(MAX_SOURCE_DIMENSION);
+        limits.max_image_height = Some(MAX_SOURCE_DIMENSION);
+        reader.limits(limits);
+
+        reader.decode().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use image::{GenericImageView, RgbaImage};
+
+    use super::*;
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut data = Vec::new();
+        DynamicImage::ImageRgba8(RgbaImage::new(width, height))
+            .write_to(&mut Cursor::new(&mut data), ImageFormat::Png)
+            .unwrap();
+
+        data
+    }
+
+    fn dimensions(data: &[u8]) -> (u32, u32) {
+        image::load_from_memory(data).unwrap().dimensions()
+    }
+
+    #[test]
+    fn resizes_to_standard_dimensions() {
+        let wide = png(800, 400);
+
+        let logo = ImageUtil::resize_blocking(&wide, 256, ImageFit::Contain).unwrap();
+        let avatar = ImageUtil::resize_blocking(&wide, 256, ImageFit::Cover).unwrap();
+
+        assert_eq!(dimensions(&logo), (256, 128));
+        assert_eq!(dimensions(&avatar), (256, 256));
+    }
+
+    #[test]
+    fn rejects_files_that_are_not_images() {
+        let result = ImageUtil::resize_blocking(b"%PDF-1.7", 256, ImageFit::Cover);
+
+        assert!(matches!(result, Err(ApiErrorResponse::BadRequest(_))));
+    }
+}
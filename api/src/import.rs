@@ -0,0 +1,980 @@
+//! CSV imports of payments and work sessions.
+//!
+//! Provides [`ImportUtil`] for reading an uploaded spreadsheet and
+//! validating every row against the user's companies and jobs before
+//! anything is written. Columns are matched by header name, ignoring case
+//! and whether words are separated by spaces or underscores, and unknown
+//! columns are ignored, so files written by the CSV exports can be imported
+//! as they are. Row errors name the offending cell as
+//! `rows[<line>].<column>`, where `<line>` is the row's line in the file
+//! and the header is line 1.
+
+use std::collections::HashMap;
+
+use axum::extract::Multipart;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use csv::{ReaderBuilder, StringRecord, Trim};
+use gig_log_common::models::{
+    error::{ValidationError, ValidationErrorCode},
+    payment::PayoutType,
+};
+use uuid::Uuid;
+
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::extractors::format_field_name;
+use crate::repo::import::{ImportCompanyRecord, ImportJobRecord};
+
+/// Maximum accepted size of an uploaded CSV file, in bytes.
+pub const MAX_IMPORT_BYTES: usize = 10 * 1024 * 1024;
+
+/// Most row errors reported for one file.
+const MAX_IMPORT_ERRORS: usize = 100;
+
+/// Multipart field the CSV file is uploaded in.
+const IMPORT_FILE_FIELD: &str = "file";
+
+/// Columns a payments import must have.
+const PAYMENT_COLUMNS: &[&str] = &["company", "total", "payout_type"];
+
+/// Columns a work sessions import must have.
+const WORK_SESSION_COLUMNS: &[&str] = &["company", "job", "start_time", "end_time"];
+
+/// Accepted date formats, tried in order.
+const DATE_FORMATS: &[&str] = &["%Y-%m-%d", "%m/%d/%Y"];
+
+/// Accepted date and time formats without an offset, read as UTC.
+const DATE_TIME_FORMATS: &[&str] = &[
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%dT%H:%M",
+    "%m/%d/%Y %H:%M:%S",
+    "%m/%d/%Y %H:%M",
+];
+
+/// Accepted time of day formats, combined with the row's `date` column.
+const TIME_FORMATS: &[&str] = &["%H:%M:%S", "%H:%M", "%I:%M %p", "%I:%M%p"];
+
+/// A validated payment row, ready to insert.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaymentImport {
+    /// The company that issued the payment.
+    pub company_id: Uuid,
+    /// Total payment amount in dollars.
+    pub total: f64,
+    /// Method used to receive the payment.
+    pub payout_type: PayoutType,
+    /// Expected date the payment will be received.
+    pub expected_payout_date: Option<NaiveDate>,
+    /// Whether the payment transfer has been initiated by the payer.
+    pub transfer_initiated: bool,
+    /// Whether the payment has been received by the user.
+    pub payment_received: bool,
+    /// Whether tax withholdings have been accounted for.
+    pub tax_withholdings_covered: bool,
+}
+
+/// A validated, completed work session row, ready to insert.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkSessionImport {
+    /// The job the session was worked on.
+    pub job_id: Uuid,
+    /// When the session started.
+    pub start_time: DateTime<Utc>,
+    /// When the session ended.
+    pub end_time: DateTime<Utc>,
+    /// Hourly rate in dollars the session is billed at. `None` for
+    /// payout-based jobs.
+    pub hourly_rate: Option<f64>,
+}
+
+/// A user's companies and jobs, looked up by name.
+///
+/// Names are matched ignoring case and surrounding spaces.
+#[derive(Debug, Default)]
+pub struct ImportDirectory {
+    /// Company IDs keyed by normalized name.
+    companies: HashMap<String, Vec<Uuid>>,
+    /// Jobs keyed by company ID and normalized title.
+    jobs: HashMap<(Uuid, String), Vec<ImportJobRecord>>,
+}
+
+impl ImportDirectory {
+    /// Indexes a user's companies and jobs.
+    ///
+    /// # Arguments
+    ///
+    /// * `companies` — Every company the user owns.
+    /// * `jobs` — Every job the user owns.
+    ///
+    /// # Returns
+    ///
+    /// An [`ImportDirectory`] for resolving rows.
+    pub fn new(companies: Vec<ImportCompanyRecord>, jobs: Vec<ImportJobRecord>) -> Self {
+        let mut directory = Self::default();
+
+        for company in companies {
+            directory
+                .companies
+                .entry(Self::key(&company.name))
+                .or_default()
+                .push(company.id);
+        }
+
+        for job in jobs {
+            directory
+                .jobs
+                .entry((job.company_id, Self::key(&job.title)))
+                .or_default()
+                .push(job);
+        }
+
+        directory
+    }
+
+    /// Resolves the company named in a row's `company` column.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` — The row being imported.
+    ///
+    /// # Returns
+    ///
+    /// The company's UUID.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] if the column is empty, or no company
+    /// or more than one company has that name.
+    fn company(&self, row: &CsvRow) -> Result<Uuid, ValidationError> {
+        let name = row.required("company")?;
+
+        match self.companies.get(&Self::key(name)).map(Vec::as_slice) {
+            Some([id]) => Ok(*id),
+            Some(_) => Err(row.error(
+                "company",
+                ValidationErrorCode::Invalid,
+                format!("More than one company is named \"{name}\""),
+            )),
+            None => Err(row.error(
+                "company",
+                ValidationErrorCode::Invalid,
+                format!("No company is named \"{name}\""),
+            )),
+        }
+    }
+
+    /// Resolves the job titled in a row's `job` column.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` — The row being imported.
+    /// * `company_id` — The company the job belongs to.
+    ///
+    /// # Returns
+    ///
+    /// The matching [`ImportJobRecord`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] if the column is empty, or the company
+    /// has no job or more than one job with that title.
+    fn job(&self, row: &CsvRow, company_id: Uuid) -> Result<&ImportJobRecord, ValidationError> {
+        let title = row.required("job")?;
+
+        match self
+            .jobs
+            .get(&(company_id, Self::key(title)))
+            .map(Vec::as_slice)
+        {
+            Some([job]) => Ok(job),
+            Some(_) => Err(row.error(
+                "job",
+                ValidationErrorCode::Invalid,
+                format!("The company has more than one job titled \"{title}\""),
+            )),
+            None => Err(row.error(
+                "job",
+                ValidationErrorCode::Invalid,
+                format!("The company has no job titled \"{title}\""),
+            )),
+        }
+    }
+
+    /// Normalizes a company name or job title for lookup.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` — The name as written.
+    ///
+    /// # Returns
+    ///
+    /// The trimmed, lowercase name.
+    fn key(name: &str) -> String {
+        name.trim().to_lowercase()
+    }
+}
+
+/// One data row of an uploaded CSV file.
+struct CsvRow<'a> {
+    /// Column positions keyed by normalized header name.
+    columns: &'a HashMap<String, usize>,
+    /// The row's line in the file.
+    line: u64,
+    /// The row's values.
+    record: StringRecord,
+}
+
+impl CsvRow<'_> {
+    /// Returns a cell's value.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` — The normalized column name.
+    ///
+    /// # Returns
+    ///
+    /// The trimmed value, or `None` if the column is missing or the cell is
+    /// empty.
+    fn text(&self, column: &str) -> Option<&str> {
+        let index = *self.columns.get(column)?;
+
+        self.record.get(index).filter(|value| !value.is_empty())
+    }
+
+    /// Returns a cell's value, requiring one.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` — The normalized column name.
+    ///
+    /// # Returns
+    ///
+    /// The trimmed value.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] if the cell is empty.
+    fn required(&self, column: &str) -> Result<&str, ValidationError> {
+        self.text(column).ok_or_else(|| self.missing(column))
+    }
+
+    /// Parses a cell with the first parser that accepts it.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` — The normalized column name.
+    /// * `expected` — Description of a valid value, used in the error.
+    /// * `parse` — Converts the cell's text.
+    ///
+    /// # Returns
+    ///
+    /// The parsed value, or `None` if the cell is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] if the cell cannot be parsed.
+    fn parse<T>(
+        &self,
+        column: &str,
+        expected: &str,
+        parse: impl FnOnce(&str) -> Option<T>,
+    ) -> Result<Option<T>, ValidationError> {
+        let Some(value) = self.text(column) else {
+            return Ok(None);
+        };
+
+        parse(value).map(Some).ok_or_else(|| {
+            self.error(
+                column,
+                ValidationErrorCode::InvalidFormat,
+                format!("{} must be {expected}", format_field_name(column)),
+            )
+        })
+    }
+
+    /// Parses a dollar amount greater than zero.
+    ///
+    /// Currency symbols and thousands separators are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` — The normalized column name.
+    ///
+    /// # Returns
+    ///
+    /// The amount, or `None` if the cell is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] if the cell is not a number or is not
+    /// greater than zero.
+    fn amount(&self, column: &str) -> Result<Option<f64>, ValidationError> {
+        let amount = self.parse(column, "a number", |value| {
+            value
+                .replace(['$', ','], "")
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|amount| amount.is_finite())
+        })?;
+
+        match amount {
+            Some(amount) if amount <= 0.0 => Err(self.error(
+                column,
+                ValidationErrorCode::Range,
+                format!("{} must be greater than 0", format_field_name(column)),
+            )),
+            amount => Ok(amount),
+        }
+    }
+
+    /// Parses a yes or no value. Empty cells are `false`.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` — The normalized column name.
+    ///
+    /// # Returns
+    ///
+    /// The parsed flag.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] if the cell is not `true`, `false`,
+    /// `yes`, `no`, `1`, or `0`.
+    fn flag(&self, column: &str) -> Result<bool, ValidationError> {
+        let flag = self.parse(column, "true or false", |value| {
+            match value.to_lowercase().as_str() {
+                "true" | "yes" | "y" | "1" => Some(true),
+                "false" | "no" | "n" | "0" => Some(false),
+                _ => None,
+            }
+        })?;
+
+        Ok(flag.unwrap_or(false))
+    }
+
+    /// Parses a calendar date.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` — The normalized column name.
+    ///
+    /// # Returns
+    ///
+    /// The date, or `None` if the cell is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] if the cell is not a date.
+    fn date(&self, column: &str) -> Result<Option<NaiveDate>, ValidationError> {
+        self.parse(column, "a date such as 2025-01-31", |value| {
+            DATE_FORMATS
+                .iter()
+                .find_map(|format| NaiveDate::parse_from_str(value, format).ok())
+        })
+    }
+
+    /// Parses a point in time.
+    ///
+    /// Accepts an RFC 3339 timestamp, a date and time read as UTC, or a
+    /// time of day on the date in the row's `date` column.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` — The normalized column name.
+    ///
+    /// # Returns
+    ///
+    /// The timestamp, or `None` if the cell is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] if the cell cannot be parsed or is a
+    /// time of day without a valid `date`.
+    fn timestamp(&self, column: &str) -> Result<Option<DateTime<Utc>>, ValidationError> {
+        let date = self.date("date").ok().flatten();
+
+        self.parse(
+            column,
+            "a date and time such as 2025-01-31 09:00",
+            |value| {
+                if let Ok(timestamp) = DateTime::parse_from_rfc3339(value) {
+                    return Some(timestamp.with_timezone(&Utc));
+                }
+
+                if let Some(timestamp) = DATE_TIME_FORMATS
+                    .iter()
+                    .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+                {
+                    return Some(timestamp.and_utc());
+                }
+
+                let time = TIME_FORMATS.iter().find_map(|format| {
+                    NaiveTime::parse_from_str(&value.to_uppercase(), format).ok()
+                })?;
+
+                Some(date?.and_time(time).and_utc())
+            },
+        )
+    }
+
+    /// Builds the error for an empty required cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` — The normalized column name.
+    ///
+    /// # Returns
+    ///
+    /// A [`ValidationError`] with code
+    /// [`FieldRequired`](ValidationErrorCode::FieldRequired).
+    fn missing(&self, column: &str) -> ValidationError {
+        self.error(
+            column,
+            ValidationErrorCode::FieldRequired,
+            format!("{} is required", format_field_name(column)),
+        )
+    }
+
+    /// Builds an error for one of the row's cells.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` — The normalized column name.
+    /// * `code` — Machine-readable category of the failure.
+    /// * `message` — Description of the failure.
+    ///
+    /// # Returns
+    ///
+    /// A [`ValidationError`] whose field is `rows[<line>].<column>`.
+    fn error(
+        &self,
+        column: &str,
+        code: ValidationErrorCode,
+        message: impl Into<String>,
+    ) -> ValidationError {
+        ValidationError::new(Some(format!("rows[{}].{column}", self.line)), code, message)
+    }
+}
+
+/// Utility for reading and validating CSV imports.
+pub struct ImportUtil;
+
+impl ImportUtil {
+    /// Reads the CSV file from a multipart upload.
+    ///
+    /// # Arguments
+    ///
+    /// * `multipart` — The request's multipart form.
+    ///
+    /// # Returns
+    ///
+    /// The contents of the form's `file` field.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`] if the form has no `file`
+    /// field, or [`ApiErrorResponse::BadRequest`] if the form cannot be
+    /// read or is too large.
+    pub async fn read_upload(mut multipart: Multipart) -> ApiResult<Vec<u8>> {
+        while let Some(field) = multipart
+            .next_field()
+            .await
+            .map_err(|error| ApiErrorResponse::BadRequest(error.body_text()))?
+        {
+            if field.name() == Some(IMPORT_FILE_FIELD) {
+                let data = field
+                    .bytes()
+                    .await
+                    .map_err(|error| ApiErrorResponse::BadRequest(error.body_text()))?;
+
+                return Ok(data.to_vec());
+            }
+        }
+
+        Err(ApiErrorResponse::Validation(vec![ValidationError::new(
+            Some(IMPORT_FILE_FIELD.to_string()),
+            ValidationErrorCode::FieldRequired,
+            "File is required",
+        )]))
+    }
+
+    /// Validates a payments CSV file.
+    ///
+    /// Requires `company`, `total`, and `payout_type` columns. The optional
+    /// `expected_payout_date` falls back to `date`, and the optional
+    /// `transfer_initiated`, `payment_received`, and
+    /// `tax_withholdings_covered` flags default to `false`.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` — The uploaded file.
+    /// * `directory` — The user's companies and jobs.
+    ///
+    /// # Returns
+    ///
+    /// One [`PaymentImport`] per data row, in file order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`] listing a missing column or
+    /// every invalid cell, or [`ApiErrorResponse::BadRequest`] if the file
+    /// has no data rows.
+    pub fn payments(data: &[u8], directory: &ImportDirectory) -> ApiResult<Vec<PaymentImport>> {
+        Self::rows(data, PAYMENT_COLUMNS, |row| Self::payment(row, directory))
+    }
+
+    /// Validates a work sessions CSV file.
+    ///
+    /// Requires `company`, `job`, `start_time`, and `end_time` columns.
+    /// Times may be times of day when a `date` column is present. The
+    /// optional `hourly_rate` defaults to the job's current rate.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` — The uploaded file.
+    /// * `directory` — The user's companies and jobs.
+    ///
+    /// # Returns
+    ///
+    /// One [`WorkSessionImport`] per data row, in file order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`] listing a missing column or
+    /// every invalid cell, or [`ApiErrorResponse::BadRequest`] if the file
+    /// has no data rows.
+    pub fn work_sessions(
+        data: &[u8],
+        directory: &ImportDirectory,
+    ) -> ApiResult<Vec<WorkSessionImport>> {
+        Self::rows(data, WORK_SESSION_COLUMNS, |row| {
+            Self::work_session(row, directory)
+        })
+    }
+
+    /// Reads a CSV file and validates each of its data rows.
+    ///
+    /// Rows whose cells are all empty are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` — The uploaded file.
+    /// * `required` — Columns the file must have.
+    /// * `parse` — Validates one row.
+    ///
+    /// # Returns
+    ///
+    /// The validated rows, in file order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`] listing missing columns or
+    /// up to [`MAX_IMPORT_ERRORS`] row errors, or
+    /// [`ApiErrorResponse::BadRequest`] if the header cannot be read or the
+    /// file has no data rows.
+    fn rows<T>(
+        data: &[u8],
+        required: &[&str],
+        parse: impl Fn(&CsvRow) -> Result<T, Vec<ValidationError>>,
+    ) -> ApiResult<Vec<T>> {
+        let mut reader = ReaderBuilder::new()
+            .flexible(true)
+            .trim(Trim::All)
+            .from_reader(data);
+
+        let headers = reader.headers().map_err(|error| {
+            ApiErrorResponse::BadRequest(format!("Invalid CSV header: {error}"))
+        })?;
+        let columns = headers
+            .iter()
+            .enumerate()
+            .map(|(index, header)| (Self::column_key(header), index))
+            .collect::<HashMap<_, _>>();
+
+        let missing = required
+            .iter()
+            .filter(|column| !columns.contains_key(**column))
+            .map(|column| {
+                ValidationError::new(
+                    Some(column.to_string()),
+                    ValidationErrorCode::FieldRequired,
+                    format!("The file needs a {column} column"),
+                )
+            })
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            return Err(ApiErrorResponse::Validation(missing));
+        }
+
+        let mut values = Vec::new();
+        let mut errors = Vec::new();
+
+        for (index, record) in reader.records().enumerate() {
+            let line = index as u64 + 2;
+
+            let record = match record {
+                Ok(record) => record,
+                Err(error) => {
+                    let line = error.position().map_or(line, |position| position.line());
+                    errors.push(ValidationError::new(
+                        Some(format!("rows[{line}]")),
+                        ValidationErrorCode::InvalidFormat,
+                        format!("Row could not be read: {error}"),
+                    ));
+                    continue;
+                }
+            };
+
+            if record.iter().all(str::is_empty) {
+                continue;
+            }
+
+            let row = CsvRow {
+                columns: &columns,
+                line: record.position().map_or(line, |position| position.line()),
+                record,
+            };
+            match parse(&row) {
+                Ok(value) => values.push(value),
+                Err(row_errors) => errors.extend(row_errors),
+            }
+        }
+
+        if !errors.is_empty() {
+            errors.truncate(MAX_IMPORT_ERRORS);
+            return Err(ApiErrorResponse::Validation(errors));
+        }
+
+        if values.is_empty() {
+            return Err(ApiErrorResponse::BadRequest(
+                "The file has no rows to import".to_string(),
+            ));
+        }
+
+        Ok(values)
+    }
+
+    /// Validates one payment row.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` — The row being imported.
+    /// * `directory` — The user's companies and jobs.
+    ///
+    /// # Returns
+    ///
+    /// The row as a [`PaymentImport`].
+    ///
+    /// # Errors
+    ///
+    /// Returns every [`ValidationError`] found in the row.
+    fn payment(
+        row: &CsvRow,
+        directory: &ImportDirectory,
+    ) -> Result<PaymentImport, Vec<ValidationError>> {
+        let mut errors = Vec::new();
+
+        let company_id = Self::check(&mut errors, directory.company(row));
+        let total = Self::check(
+            &mut errors,
+            row.amount("total")
+                .and_then(|total| total.ok_or_else(|| row.missing("total"))),
+        );
+        let payout_type = Self::check(
+            &mut errors,
+            row.required("payout_type").and_then(|_| {
+                row.parse(
+                    "payout_type",
+                    "paypal, cash, check, zelle, venmo, or direct_deposit",
+                    Self::payout_type,
+                )
+            }),
+        )
+        .flatten();
+        let expected_payout_date = Self::check(
+            &mut errors,
+            row.date("expected_payout_date")
+                .and_then(|date| date.map_or_else(|| row.date("date"), |date| Ok(Some(date)))),
+        );
+        let transfer_initiated = Self::check(&mut errors, row.flag("transfer_initiated"));
+        let payment_received = Self::check(&mut errors, row.flag("payment_received"));
+        let tax_withholdings_covered =
+            Self::check(&mut errors, row.flag("tax_withholdings_covered"));
+
+        let (
+            Some(company_id),
+            Some(total),
+            Some(payout_type),
+            Some(expected_payout_date),
+            Some(transfer_initiated),
+            Some(payment_received),
+            Some(tax_withholdings_covered),
+        ) = (
+            company_id,
+            total,
+            payout_type,
+            expected_payout_date,
+            transfer_initiated,
+            payment_received,
+            tax_withholdings_covered,
+        )
+        else {
+            return Err(errors);
+        };
+
+        Ok(PaymentImport {
+            company_id,
+            total,
+            payout_type,
+            expected_payout_date,
+            transfer_initiated,
+            payment_received,
+            tax_withholdings_covered,
+        })
+    }
+
+    /// Validates one work session row.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` — The row being imported.
+    /// * `directory` — The user's companies and jobs.
+    ///
+    /// # Returns
+    ///
+    /// The row as a [`WorkSessionImport`].
+    ///
+    /// # Errors
+    ///
+    /// Returns every [`ValidationError`] found in the row.
+    fn work_session(
+        row: &CsvRow,
+        directory: &ImportDirectory,
+    ) -> Result<WorkSessionImport, Vec<ValidationError>> {
+        let mut errors = Vec::new();
+
+        let job = Self::check(
+            &mut errors,
+            directory
+                .company(row)
+                .and_then(|company_id| directory.job(row, company_id)),
+        );
+        let start_time = Self::check(
+            &mut errors,
+            row.timestamp("start_time")
+                .and_then(|time| time.ok_or_else(|| row.missing("start_time"))),
+        );
+        let end_time = Self::check(
+            &mut errors,
+            row.timestamp("end_time")
+                .and_then(|time| time.ok_or_else(|| row.missing("end_time"))),
+        );
+        let hourly_rate = Self::check(&mut errors, row.amount("hourly_rate"));
+
+        if let (Some(start_time), Some(end_time)) = (start_time, end_time)
+            && end_time <= start_time
+        {
+            errors.push(row.error(
+                "end_time",
+                ValidationErrorCode::CrossField,
+                "End time must be after start time",
+            ));
+        }
+
+        let (Some(job), Some(start_time), Some(end_time), Some(hourly_rate)) =
+            (job, start_time, end_time, hourly_rate)
+        else {
+            return Err(errors);
+        };
+
+        let hourly_rate = match (job.hourly_rate, hourly_rate) {
+            (None, Some(_)) => {
+                errors.push(row.error(
+                    "hourly_rate",
+                    ValidationErrorCode::Invalid,
+                    "Hourly rate only applies to hourly jobs",
+                ));
+                None
+            }
+            (job_rate, rate) => rate.or(job_rate),
+        };
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(WorkSessionImport {
+            job_id: job.id,
+            start_time,
+            end_time,
+            hourly_rate,
+        })
+    }
+
+    /// Parses a payout method, accepting its display name.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — The cell's text, such as `direct_deposit` or
+    ///   `Direct Deposit`.
+    ///
+    /// # Returns
+    ///
+    /// The matching [`PayoutType`], or `None` if it is unknown.
+    fn payout_type(value: &str) -> Option<PayoutType> {
+        match Self::column_key(value).as_str() {
+            "paypal" => Some(PayoutType::Paypal),
+            "cash" => Some(PayoutType::Cash),
+            "check" => Some(PayoutType::Check),
+            "zelle" => Some(PayoutType::Zelle),
+            "venmo" => Some(PayoutType::Venmo),
+            "direct_deposit" => Some(PayoutType::DirectDeposit),
+            _ => None,
+        }
+    }
+
+    /// Normalizes a header name.
+    ///
+    /// # Arguments
+    ///
+    /// * `header` — The header as written, possibly with a byte order mark.
+    ///
+    /// # Returns
+    ///
+    /// The lowercase name with spaces and hyphens replaced by underscores.
+    fn column_key(header: &str) -> String {
+        header
+            .trim_start_matches('\u{feff}')
+            .trim()
+            .to_lowercase()
+            .replace([' ', '-'], "_")
+    }
+
+    /// Records a cell's error, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `errors` — The row's errors so far.
+    /// * `result` — The result of validating a cell.
+    ///
+    /// # Returns
+    ///
+    /// The cell's value, or `None` if it was invalid.
+    fn check<T>(
+        errors: &mut Vec<ValidationError>,
+        result: Result<T, ValidationError>,
+    ) -> Option<T> {
+        result.map_err(|error| errors.push(error)).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn directory() -> (ImportDirectory, Uuid, Uuid) {
+        let company_id = Uuid::new_v4();
+        let hourly_job_id = Uuid::new_v4();
+        let directory = ImportDirectory::new(
+            vec![ImportCompanyRecord {
+                id: company_id,
+                name: "Acme Design Studio".to_string(),
+            }],
+            vec![
+                ImportJobRecord {
+                    id: hourly_job_id,
+                    company_id,
+                    title: "Website redesign".to_string(),
+                    hourly_rate: Some(65.0),
+                },
+                ImportJobRecord {
+                    id: Uuid::new_v4(),
+                    company_id,
+                    title: "Brand guidelines".to_string(),
+                    hourly_rate: None,
+                },
+            ],
+        );
+
+        (directory, company_id, hourly_job_id)
+    }
+
+    fn fields(result: ApiResult<impl std::fmt::Debug>) -> Vec<String> {
+        match result {
+            Err(ApiErrorResponse::Validation(errors)) => errors
+                .into_iter()
+                .map(|error| error.field.unwrap_or_default())
+                .collect(),
+            other => panic!("expected validation errors, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn reads_spreadsheet_formatted_payments() {
+        let (directory, company_id, _) = directory();
+        let csv = "\u{feff}Company,Total,Payout Type,Date,Payment Received,Notes\n\
+                   acme design studio,\"$1,200.50\",Direct Deposit,01/31/2025,yes,January\n\
+                   ,,,,,\n";
+
+        let payments = ImportUtil::payments(csv.as_bytes(), &directory).unwrap();
+
+        assert_eq!(
+            payments,
+            vec![PaymentImport {
+                company_id,
+                total: 1200.5,
+                payout_type: PayoutType::DirectDeposit,
+                expected_payout_date: NaiveDate::from_ymd_opt(2025, 1, 31),
+                transfer_initiated: false,
+                payment_received: true,
+                tax_withholdings_covered: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_every_invalid_cell_by_line() {
+        let (directory, _, _) = directory();
+        let csv = "company,total,payout_type\n\
+                   Acme Design Studio,100,cash\n\
+                   Globex,-5,wire\n";
+
+        assert_eq!(
+            fields(ImportUtil::payments(csv.as_bytes(), &directory)),
+            vec!["rows[3].company", "rows[3].total", "rows[3].payout_type"]
+        );
+        assert_eq!(
+            fields(ImportUtil::payments(b"company,total\nAcme,1\n", &directory)),
+            vec!["payout_type"]
+        );
+    }
+
+    #[test]
+    fn reads_work_sessions_with_times_of_day() {
+        let (directory, _, hourly_job_id) = directory();
+        let csv = "date,company,job,start_time,end_time,hourly_rate\n\
+                   2025-03-03,Acme Design Studio,Website Redesign,09:00,5:30 pm,\n\
+                   2025-03-04,Acme Design Studio,Brand guidelines,13:00,12:00,80\n";
+
+        assert_eq!(
+            fields(ImportUtil::work_sessions(csv.as_bytes(), &directory)),
+            vec!["rows[3].end_time", "rows[3].hourly_rate"]
+        );
+
+        let csv = csv.lines().take(2).collect::<Vec<_>>().join("\n");
+        let sessions = ImportUtil::work_sessions(csv.as_bytes(), &directory).unwrap();
+
+        assert_eq!(
+            sessions,
+            vec![WorkSessionImport {
+                job_id: hourly_job_id,
+                start_time: "2025-03-03T09:00:00Z".parse().unwrap(),
+                end_time: "2025-03-03T17:30:00Z".parse().unwrap(),
+                hourly_rate: Some(65.0),
+            }]
+        );
+    }
+}
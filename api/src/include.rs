@@ -0,0 +1,92 @@
+//! Related-resource expansion for detail responses.
+//!
+//! Provides [`IncludeUtil`] for parsing the `?include=` parameter that lets
+//! clients embed related resources in a detail response instead of fetching
+//! each one separately.
+
+use crate::core::error::{ApiErrorResponse, ApiResult};
+
+/// Deepest relation path a client may include, e.g. `jobs` is one level
+/// and `jobs.company` would be two.
+pub const MAX_INCLUDE_DEPTH: usize = 1;
+
+/// Utility for parsing requested related-resource expansions.
+pub struct IncludeUtil;
+
+impl IncludeUtil {
+    /// Parses a comma-separated list of relations to include.
+    ///
+    /// Blank entries and duplicates are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `include` — Comma-separated relation names, or `None` for none.
+    /// * `allowed` — Relations the endpoint can expand.
+    ///
+    /// # Returns
+    ///
+    /// The requested relations in the order first given.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if a relation is nested
+    /// deeper than [`MAX_INCLUDE_DEPTH`] or is not one of `allowed`.
+    pub fn parse<'a>(include: Option<&'a str>, allowed: &[&str]) -> ApiResult<Vec<&'a str>> {
+        let mut relations: Vec<&str> = Vec::new();
+
+        for relation in include
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|relation| !relation.is_empty())
+        {
+            if relation.split('.').count() > MAX_INCLUDE_DEPTH {
+                return Err(ApiErrorResponse::BadRequest(format!(
+                    "Include '{relation}' is nested more than {MAX_INCLUDE_DEPTH} level deep"
+                )));
+            }
+
+            if !allowed.contains(&relation) {
+                return Err(ApiErrorResponse::BadRequest(format!(
+                    "Unknown include '{relation}'"
+                )));
+            }
+
+            if !relations.contains(&relation) {
+                relations.push(relation);
+            }
+        }
+
+        Ok(relations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_allowed_relations_once() {
+        let relations =
+            IncludeUtil::parse(Some(" payments, ,jobs,payments"), &["jobs", "payments"]).unwrap();
+
+        assert_eq!(relations, vec!["payments", "jobs"]);
+        assert!(IncludeUtil::parse(None, &["jobs"]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn rejects_unknown_relations() {
+        let error = IncludeUtil::parse(Some("jobs,owner"), &["jobs"]).unwrap_err();
+
+        assert!(
+            matches!(error, ApiErrorResponse::BadRequest(message) if message == "Unknown include 'owner'")
+        );
+    }
+
+    #[test]
+    fn rejects_nested_relations() {
+        let error = IncludeUtil::parse(Some("jobs.company"), &["jobs"]).unwrap_err();
+
+        assert!(matches!(error, ApiErrorResponse::BadRequest(_)));
+    }
+}
@@ -6,25 +6,100 @@
 //!
 //! # Modules
 //!
+//! - [`account_export`] — Full account data export archives.
 //! - [`auth`] — Authentication and authorization primitives.
+//! - [`availability`] — Availability calculations from scheduled time off.
+//! - [`billing`] — Billing calculations for invoicing tracked work.
+//! - [`calendar`] — iCalendar feeds of work sessions and payouts.
 //! - [`controllers`] — HTTP request handlers mapped to API endpoints.
 //! - [`core`] — Application bootstrap, configuration, errors, and logging.
+//! - [`data_check`] — Stored data consistency checks and repairs.
 //! - [`email`] — Email delivery clients and feature-specific senders.
 //! - [`extractors`] — Custom Axum request extractors.
+//! - [`images`] — Resizing of uploaded logos and avatars.
+//! - [`import`] — CSV imports of payments and work sessions.
+//! - [`include`] — Related-resource expansion for detail responses.
+//! - [`live_sync`] — Live sync of domain events over WebSockets.
+//! - [`mileage`] — Mileage deduction calculations and reports.
+//! - [`palette_preview`] — SVG swatch previews of color palettes.
+//! - [`quick_log`] — Parsing of email quick-log commands.
+//! - [`payment_matching`] — Matching of received payments to expected payments.
+//! - [`receipt`] — Signed public payment receipts.
+//! - [`report`] — Grouping of unreported work, earnings report ranges, and tax quarters.
+//! - [`report_cache`] — Cached earnings and tax estimate reports.
+//! - [`recurrence`] — Recurring schedule date calculations.
+//! - [`reconciliation`] — Monthly payment reconciliation checklists.
 //! - [`repo`] — Database repository layer for SQLx queries.
 //! - [`routes`] — Router construction and route group definitions.
+//! - [`services`] — Swappable service implementations, such as PDF rendering.
+//! - [`sparse_fields`] — Sparse field selection for detail responses.
+//! - [`statement`] — Company statement totals and PDF rendering.
+//! - [`subscription`] — Paid plan subscriptions billed through Stripe.
+//! - [`tasks`] — Background tasks spawned at startup.
+//! - [`toggl`] — Toggl Track API compatibility helpers.
+//! - [`webhook`] — Outgoing webhook publishing, signing, and delivery.
 
+/// Full account data export archives.
+pub mod account_export;
 /// Authentication and authorization primitives.
 pub mod auth;
+/// Availability calculations from scheduled time off.
+pub mod availability;
+/// Billing calculations for invoicing tracked work.
+pub mod billing;
+/// iCalendar feeds of work sessions and payouts.
+pub mod calendar;
 /// HTTP request handlers for API endpoints.
 pub mod controllers;
 /// Core application bootstrap, configuration, and error handling.
 pub mod core;
+/// Stored data consistency checks and repairs.
+pub mod data_check;
 /// Email delivery clients and sender implementations.
 pub mod email;
 /// Custom Axum request extractors.
 pub mod extractors;
+/// Resizing of uploaded logos and avatars.
+pub mod images;
+/// CSV imports of payments and work sessions.
+pub mod import;
+/// Related-resource expansion for detail responses.
+pub mod include;
+/// Live sync of domain events over WebSockets.
+pub mod live_sync;
+/// Mileage deduction calculations and reports.
+pub mod mileage;
+/// SVG swatch previews of color palettes.
+pub mod palette_preview;
+/// Matching of received payments to expected payments.
+pub mod payment_matching;
+/// Parsing of email quick-log commands.
+pub mod quick_log;
+/// Signed public payment receipts.
+pub mod receipt;
+/// Monthly payment reconciliation checklists.
+pub mod reconciliation;
+/// Recurring schedule date calculations.
+pub mod recurrence;
 /// Database repository layer for SQLx operations.
 pub mod repo;
+/// Grouping of unreported work, earnings report ranges, and tax quarters.
+pub mod report;
+/// Cached earnings and tax estimate reports.
+pub mod report_cache;
 /// Application route definitions and router composition.
 pub mod routes;
+/// Swappable service implementations, such as PDF rendering.
+pub mod services;
+/// Sparse field selection for detail responses.
+pub mod sparse_fields;
+/// Company statement totals and PDF rendering.
+pub mod statement;
+/// Paid plan subscriptions billed through Stripe.
+pub mod subscription;
+/// Background tasks spawned during application startup.
+pub mod tasks;
+/// Toggl Track API compatibility helpers.
+pub mod toggl;
+/// Outgoing webhook publishing, signing, and delivery.
+pub mod webhook;
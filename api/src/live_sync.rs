@@ -0,0 +1,92 @@
+//! Live sync of domain events to connected clients.
+//!
+//! Provides [`LiveSyncHub`], which fans out [`LiveSyncMessage`]s published
+//! by handlers and background tasks to every open live sync WebSocket.
+//! Each connection only forwards the events of the user it authenticated
+//! as.
+
+use gig_log_common::models::live_sync::LiveSyncMessage;
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+/// Events buffered for a slow connection before it misses some and is
+/// told to resync.
+const CHANNEL_CAPACITY: usize = 256;
+
+/// A [`LiveSyncMessage`] addressed to one user.
+#[derive(Debug, Clone)]
+pub struct LiveSyncEvent {
+    /// The user whose connections receive the message.
+    pub user_id: Uuid,
+    /// The event to deliver.
+    pub message: LiveSyncMessage,
+}
+
+/// Broadcast hub for live sync events.
+///
+/// Cloning the hub shares the same channel, so it can live in the
+/// [`AppState`](crate::routes::app::AppState).
+#[derive(Debug, Clone)]
+pub struct LiveSyncHub {
+    /// Sender shared by every publisher and subscriber.
+    sender: broadcast::Sender<LiveSyncEvent>,
+}
+
+impl LiveSyncHub {
+    /// Creates a hub with no subscribers.
+    ///
+    /// # Returns
+    ///
+    /// A new [`LiveSyncHub`].
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+
+        Self { sender }
+    }
+
+    /// Publishes an event to the user's open connections.
+    ///
+    /// Events published while the user has no connections are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` — The user the event belongs to.
+    /// * `message` — The event to deliver.
+    pub fn publish(&self, user_id: Uuid, message: LiveSyncMessage) {
+        let _ = self.sender.send(LiveSyncEvent { user_id, message });
+    }
+
+    /// Subscribes to events published from now on.
+    ///
+    /// # Returns
+    ///
+    /// A [`broadcast::Receiver`] of every user's events, which the caller
+    /// filters to its own user.
+    pub fn subscribe(&self) -> broadcast::Receiver<LiveSyncEvent> {
+        self.sender.subscribe()
+    }
+}
+
+impl Default for LiveSyncHub {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn delivers_published_events_to_subscribers() {
+        let hub = LiveSyncHub::new();
+        let mut receiver = hub.subscribe();
+        let user_id = Uuid::new_v4();
+
+        hub.publish(user_id, LiveSyncMessage::Resync);
+
+        let event = receiver.recv().await.unwrap();
+        assert_eq!(event.user_id, user_id);
+        assert!(matches!(event.message, LiveSyncMessage::Resync));
+    }
+}
@@ -1,7 +1,11 @@
 //! Binary entry point for the GigLog API server.
 //!
-//! Starts the Tokio runtime and delegates server startup to [`App::run`]. Any
-//! startup or runtime failure is logged before the process exits.
+//! Starts the Tokio runtime and delegates server startup to [`App::run`], or
+//! runs [`App::check_data`] when started with `--check-data` (plus
+//! `--repair` to fix what it can). Any startup or runtime failure is logged
+//! before the process exits.
+
+use std::{env, process};
 
 use gig_log_api::core::app::App;
 use log::error;
@@ -9,12 +13,25 @@ use log::error;
 /// Starts the GigLog API server process.
 ///
 /// Initializes the async runtime via [`tokio::main`] and invokes [`App::run`]
-/// to bootstrap configuration, infrastructure, and HTTP serving.
+/// to bootstrap configuration, infrastructure, and HTTP serving, or
+/// [`App::check_data`] when `--check-data` is passed. Exits with status `1`
+/// when the data check fails.
 #[tokio::main]
 async fn main() {
-    let result = App::run().await;
+    let args: Vec<String> = env::args().skip(1).collect();
+    let check_data = args.iter().any(|arg| arg == "--check-data");
+
+    let result = if check_data {
+        App::check_data(args.iter().any(|arg| arg == "--repair")).await
+    } else {
+        App::run().await
+    };
 
     if let Err(error) = result {
         error!("Error: {:#}", error);
+
+        if check_data {
+            process::exit(1);
+        }
     }
 }
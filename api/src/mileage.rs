@@ -0,0 +1,233 @@
+//! Mileage deduction calculations and trip suggestions.
+//!
+//! Provides [`MileageUtil`] for pricing trips at a per-mile rate and
+//! rolling per-job trip totals up into a [`MileageReport`], and
+//! [`MileageSuggester`] for suggesting trips from the locations of
+//! completed work sessions.
+
+use chrono::NaiveDate;
+use gig_log_common::models::{
+    mileage::{MileageJobTotal, MileageReport},
+    work_session::{GeoLocation, WorkSession},
+};
+use log::error;
+
+use crate::core::error::ApiResult;
+use crate::repo::mileage::{MileageJobTotalRecord, MileageRepo};
+use crate::repo::mileage_suggestion::MileageSuggestionRepo;
+use crate::routes::app::AppState;
+
+/// Shortest one-way distance from the home base, in miles, that is
+/// suggested as a trip. Sessions closer than this are treated as worked
+/// from home.
+const MIN_SUGGESTION_MILES: f64 = 0.5;
+
+/// Utility for computing mileage deductions.
+pub struct MileageUtil;
+
+impl MileageUtil {
+    /// Computes the deduction for a distance.
+    ///
+    /// # Arguments
+    ///
+    /// * `miles` — Distance driven in miles.
+    /// * `rate` — Deduction rate in dollars per mile.
+    ///
+    /// # Returns
+    ///
+    /// The deduction in dollars, rounded to cents.
+    pub fn deduction(miles: f64, rate: f64) -> f64 {
+        Self::round_currency(miles * rate)
+    }
+
+    /// Builds a mileage report from per-job trip totals.
+    ///
+    /// The report's totals are computed from the unrounded miles so that
+    /// rounding each job's deduction does not skew the overall deduction.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` — First date covered, if bounded.
+    /// * `to` — Last date covered, if bounded.
+    /// * `rate` — Deduction rate in dollars per mile.
+    /// * `totals` — Trip totals per job.
+    ///
+    /// # Returns
+    ///
+    /// The [`MileageReport`], with jobs in the order given.
+    pub fn build_report(
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+        rate: f64,
+        totals: Vec<MileageJobTotalRecord>,
+    ) -> MileageReport {
+        let total_trips = totals.iter().map(|total| total.trips).sum();
+        let total_miles: f64 = totals.iter().map(|total| total.miles).sum();
+
+        MileageReport {
+            from,
+            to,
+            rate,
+            total_trips,
+            total_miles: Self::round_miles(total_miles),
+            total_deduction: Self::deduction(total_miles, rate),
+            jobs: totals
+                .into_iter()
+                .map(|total| MileageJobTotal {
+                    job_id: total.job_id,
+                    job_title: total.job_title,
+                    trips: total.trips,
+                    miles: Self::round_miles(total.miles),
+                    deduction: Self::deduction(total.miles, rate),
+                })
+                .collect(),
+        }
+    }
+
+    /// Rounds a distance to a tenth of a mile.
+    ///
+    /// # Arguments
+    ///
+    /// * `miles` — The distance to round.
+    ///
+    /// # Returns
+    ///
+    /// The rounded distance as an [`f64`].
+    pub fn round_miles(miles: f64) -> f64 {
+        (miles * 10.0).round() / 10.0
+    }
+
+    /// Rounds a dollar amount to the nearest cent.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` — The dollar amount to round.
+    ///
+    /// # Returns
+    ///
+    /// The rounded amount as an [`f64`].
+    fn round_currency(amount: f64) -> f64 {
+        (amount * 100.0).round() / 100.0
+    }
+}
+
+/// Suggests trips from the locations of completed work sessions.
+pub struct MileageSuggester;
+
+impl MileageSuggester {
+    /// Suggests a round trip from the user's home base to where a completed
+    /// session started.
+    ///
+    /// Nothing is suggested if the session has no start location, the user
+    /// has no home base, or the session started within
+    /// [`MIN_SUGGESTION_MILES`] of it. Failures are logged rather than
+    /// returned, so completing the session is not reported as failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `session` — The completed session.
+    pub async fn suggest(state: &AppState, session: &WorkSession) {
+        if let Err(error) = Self::try_suggest(state, session).await {
+            error!(
+                "Failed to suggest mileage for work session {}: {:?}",
+                session.id, error
+            );
+        }
+    }
+
+    /// Suggests a trip for a completed session.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `session` — The completed session.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`](crate::core::error::ApiErrorResponse)
+    /// if the distance cannot be calculated or a database query fails.
+    async fn try_suggest(state: &AppState, session: &WorkSession) -> ApiResult<()> {
+        let Some(site) =
+            session
+                .start_latitude
+                .zip(session.start_longitude)
+                .map(|(latitude, longitude)| GeoLocation {
+                    latitude,
+                    longitude,
+                })
+        else {
+            return Ok(());
+        };
+
+        let Some(home_base) = MileageRepo::find_home_base(&state.db_pool, session.user_id).await?
+        else {
+            return Ok(());
+        };
+
+        let one_way = state
+            .distance_calculator
+            .driving_miles(home_base, site)
+            .await?;
+
+        if one_way < MIN_SUGGESTION_MILES {
+            return Ok(());
+        }
+
+        MileageSuggestionRepo::insert_suggestion(
+            &state.db_pool,
+            session.user_id,
+            session.id,
+            session.job_id,
+            session.start_time.date_naive(),
+            MileageUtil::round_miles(one_way * 2.0),
+        )
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+
+    #[test]
+    fn deduction_is_rounded_to_cents() {
+        assert_eq!(MileageUtil::deduction(12.3, 0.7), 8.61);
+        assert_eq!(MileageUtil::deduction(10.0, 0.655), 6.55);
+    }
+
+    #[test]
+    fn report_totals_unrounded_miles() {
+        let job_id = Uuid::new_v4();
+        let report = MileageUtil::build_report(
+            None,
+            None,
+            0.7,
+            vec![
+                MileageJobTotalRecord {
+                    job_id: Some(job_id),
+                    job_title: Some("Site visits".to_string()),
+                    trips: 2,
+                    miles: 10.05,
+                },
+                MileageJobTotalRecord {
+                    job_id: None,
+                    job_title: None,
+                    trips: 1,
+                    miles: 10.05,
+                },
+            ],
+        );
+
+        assert_eq!(report.total_trips, 3);
+        assert_eq!(report.total_miles, 20.1);
+        assert_eq!(report.total_deduction, 14.07);
+        assert_eq!(report.jobs[0].job_id, Some(job_id));
+        assert_eq!(report.jobs[0].deduction, 7.04);
+        assert_eq!(report.jobs[1].miles, 10.1);
+    }
+}
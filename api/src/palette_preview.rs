@@ -0,0 +1,175 @@
+//! Palette swatch previews.
+//!
+//! Provides [`PalettePreviewUtil`] for rendering a small SVG swatch of a
+//! color palette from its stored tokens, for use in palette pickers and
+//! share links.
+
+use serde_json::Value;
+
+use crate::repo::appearance::PaletteSwatchRecord;
+
+/// Hues shown as swatch columns, in display order.
+const SWATCH_HUES: [&str; 6] = ["green", "red", "yellow", "blue", "magenta", "cyan"];
+
+/// Token shade suffixes shown as swatch rows, from strongest to lightest.
+const SWATCH_SHADES: [&str; 3] = ["100", "80", "60"];
+
+/// Width and height of the gap around and between swatches, in pixels.
+const SWATCH_PADDING: usize = 8;
+
+/// Width of one swatch, in pixels.
+const SWATCH_WIDTH: usize = 32;
+
+/// Height of one swatch, in pixels.
+const SWATCH_HEIGHT: usize = 24;
+
+/// Background color used when a palette has no valid `background` token.
+const FALLBACK_BACKGROUND: &str = "#ffffff";
+
+/// Utility for rendering palette swatch previews.
+pub struct PalettePreviewUtil;
+
+impl PalettePreviewUtil {
+    /// Renders a palette as an SVG swatch grid.
+    ///
+    /// Each hue is a column with one row per shade, drawn over the
+    /// palette's background color. Shades missing from the tokens fall back
+    /// to the hue's seed color, and values that are not hex colors are
+    /// skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `palette` — The palette's seed colors and tokens.
+    ///
+    /// # Returns
+    ///
+    /// The SVG document.
+    pub fn render_svg(palette: &PaletteSwatchRecord) -> String {
+        let width = SWATCH_PADDING * 2 + SWATCH_WIDTH * SWATCH_HUES.len();
+        let height = SWATCH_PADDING * 2 + SWATCH_HEIGHT * SWATCH_SHADES.len();
+        let seeds = [
+            &palette.seed_green,
+            &palette.seed_red,
+            &palette.seed_yellow,
+            &palette.seed_blue,
+            &palette.seed_magenta,
+            &palette.seed_cyan,
+        ];
+        let background =
+            Self::token(&palette.generated_tokens, "background").unwrap_or(FALLBACK_BACKGROUND);
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {width} {height}\">\
+             <rect width=\"{width}\" height=\"{height}\" rx=\"{SWATCH_PADDING}\" fill=\"{background}\"/>"
+        );
+
+        for (column, (hue, seed)) in SWATCH_HUES.iter().zip(seeds).enumerate() {
+            for (row, shade) in SWATCH_SHADES.iter().enumerate() {
+                let color = Self::token(&palette.generated_tokens, &format!("{hue}-{shade}"))
+                    .or_else(|| Self::hex_color(seed));
+
+                let Some(color) = color else {
+                    continue;
+                };
+
+                svg.push_str(&format!(
+                    "<rect x=\"{}\" y=\"{}\" width=\"{SWATCH_WIDTH}\" height=\"{SWATCH_HEIGHT}\" \
+                     fill=\"{color}\"/>",
+                    SWATCH_PADDING + column * SWATCH_WIDTH,
+                    SWATCH_PADDING + row * SWATCH_HEIGHT,
+                ));
+            }
+        }
+
+        svg.push_str("</svg>");
+
+        svg
+    }
+
+    /// Looks up a hex color token.
+    ///
+    /// # Arguments
+    ///
+    /// * `tokens` — The palette's token object.
+    /// * `name` — The token name.
+    ///
+    /// # Returns
+    ///
+    /// The token's value, or `None` if it is missing or not a hex color.
+    fn token<'a>(tokens: &'a Value, name: &str) -> Option<&'a str> {
+        tokens.get(name)?.as_str().and_then(Self::hex_color)
+    }
+
+    /// Checks that a value is a `#rgb`, `#rrggbb`, or `#rrggbbaa` color.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — The value to check.
+    ///
+    /// # Returns
+    ///
+    /// The value, or `None` if it is not a hex color and so cannot be
+    /// embedded in the SVG safely.
+    fn hex_color(value: &str) -> Option<&str> {
+        let digits = value.strip_prefix('#')?;
+
+        (matches!(digits.len(), 3 | 6 | 8) && digits.chars().all(|c| c.is_ascii_hexdigit()))
+            .then_some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn palette(generated_tokens: Value) -> PaletteSwatchRecord {
+        PaletteSwatchRecord {
+            seed_green: "#9ece6a".to_string(),
+            seed_red: "#f7768e".to_string(),
+            seed_yellow: "#e0af68".to_string(),
+            seed_blue: "#7aa2f7".to_string(),
+            seed_magenta: "#bb9af7".to_string(),
+            seed_cyan: "#7dcfff".to_string(),
+            generated_tokens,
+        }
+    }
+
+    #[test]
+    fn renders_tokens_over_background() {
+        let svg = PalettePreviewUtil::render_svg(&palette(json!({
+            "background": "#c0caf5",
+            "green-80": "#b1d888",
+        })));
+
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.contains("fill=\"#c0caf5\""));
+        assert!(svg.contains("y=\"32\" width=\"32\" height=\"24\" fill=\"#b1d888\""));
+        assert_eq!(svg.matches("<rect").count(), 1 + 6 * 3);
+    }
+
+    #[test]
+    fn falls_back_to_seed_colors() {
+        let svg = PalettePreviewUtil::render_svg(&palette(json!({})));
+
+        assert!(svg.contains(&format!("fill=\"{FALLBACK_BACKGROUND}\"")));
+        assert_eq!(svg.matches("fill=\"#7dcfff\"").count(), 3);
+    }
+
+    #[test]
+    fn skips_values_that_are_not_hex_colors() {
+        let mut record = palette(json!({
+            "background": "red\"/><script>alert(1)</script>",
+            "cyan-100": "url(#x)",
+        }));
+        record.seed_cyan = "javascript:".to_string();
+
+        let svg = PalettePreviewUtil::render_svg(&record);
+
+        assert!(!svg.contains("script"));
+        assert!(!svg.contains("url("));
+        assert_eq!(svg.matches("<rect").count(), 1 + 5 * 3);
+    }
+}
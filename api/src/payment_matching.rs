@@ -0,0 +1,173 @@
+//! Matching of received payments to expected payments.
+//!
+//! Provides [`PaymentMatchUtil`], which scores how likely an expected
+//! payment is to be the one a received bank or Stripe transaction settles,
+//! using amount, company, and date heuristics.
+
+use gig_log_common::models::payment::{Payment, PaymentMatchSuggestion};
+
+/// Minimum score for a candidate to be suggested.
+const MIN_MATCH_SCORE: f64 = 0.3;
+
+/// Maximum number of suggestions returned.
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Utility for suggesting expected payments that match a received payment.
+pub struct PaymentMatchUtil;
+
+impl PaymentMatchUtil {
+    /// Ranks expected payments by how well they match a received payment.
+    ///
+    /// # Arguments
+    ///
+    /// * `received` — The received payment to match.
+    /// * `candidates` — The user's expected payments.
+    ///
+    /// # Returns
+    ///
+    /// Up to five [`PaymentMatchSuggestion`] values, best match first.
+    pub fn suggest(received: &Payment, candidates: Vec<Payment>) -> Vec<PaymentMatchSuggestion> {
+        let mut suggestions: Vec<PaymentMatchSuggestion> = candidates
+            .into_iter()
+            .filter(|candidate| candidate.id != received.id)
+            .filter_map(|candidate| {
+                let (score, reasons) = Self::score(received, &candidate);
+
+                (score >= MIN_MATCH_SCORE).then_some(PaymentMatchSuggestion {
+                    payment: candidate,
+                    score,
+                    reasons,
+                })
+            })
+            .collect();
+
+        suggestions.sort_by(|a, b| b.score.total_cmp(&a.score));
+        suggestions.truncate(MAX_SUGGESTIONS);
+        suggestions
+    }
+
+    /// Scores one candidate against a received payment.
+    ///
+    /// Amount closeness contributes up to `0.5`, a shared company `0.3`, and
+    /// expected date proximity up to `0.2`.
+    ///
+    /// # Arguments
+    ///
+    /// * `received` — The received payment to match.
+    /// * `candidate` — The expected payment being scored.
+    ///
+    /// # Returns
+    ///
+    /// The score from `0.0` to `1.0` and the reasons behind it.
+    fn score(received: &Payment, candidate: &Payment) -> (f64, Vec<String>) {
+        let mut score = 0.0;
+        let mut reasons = Vec::new();
+
+        let largest = received.total.abs().max(candidate.total.abs());
+        let difference = if largest > 0.0 {
+            (received.total - candidate.total).abs() / largest
+        } else {
+            0.0
+        };
+
+        if difference <= 0.005 {
+            score += 0.5;
+            reasons.push("Amount matches".to_string());
+        } else if difference <= 0.05 {
+            score += 0.35;
+            reasons.push("Amount within 5%".to_string());
+        } else if difference <= 0.2 {
+            score += 0.15;
+            reasons.push("Amount within 20%".to_string());
+        }
+
+        if received.company_id == candidate.company_id {
+            score += 0.3;
+            reasons.push("Same company".to_string());
+        }
+
+        if let Some(expected_date) = candidate.expected_payout_date {
+            let received_date = received
+                .expected_payout_date
+                .unwrap_or_else(|| received.created_at.date_naive());
+            let days = (received_date - expected_date).num_days().abs();
+
+            if days <= 3 {
+                score += 0.2;
+                reasons.push("Expected within 3 days".to_string());
+            } else if days <= 14 {
+                score += 0.1;
+                reasons.push("Expected within 2 weeks".to_string());
+            }
+        }
+
+        (score, reasons)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use gig_log_common::models::payment::{PaymentStatus, PayoutType};
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn payment(company_id: Uuid, total: f64, expected_day: Option<u32>) -> Payment {
+        let created_at = Utc.with_ymd_and_hms(2026, 10, 16, 12, 0, 0).unwrap();
+
+        Payment {
+            id: Uuid::new_v4(),
+            user_id: Uuid::nil(),
+            company_id,
+            total,
+            payout_type: PayoutType::DirectDeposit,
+            expected_payout_date: expected_day
+                .map(|day| NaiveDate::from_ymd_opt(2026, 10, day).unwrap()),
+            transfer_initiated: false,
+            payment_received: false,
+            tax_withholdings_covered: false,
+            tax_withholding_rate: None,
+            amount_paid: 0.0,
+            amount_outstanding: total,
+            status: PaymentStatus::Unpaid,
+            overdue: false,
+            created_at,
+            updated_at: created_at,
+        }
+    }
+
+    #[test]
+    fn ranks_exact_company_and_date_match_first() {
+        let company = Uuid::new_v4();
+        let received = payment(company, 1500.0, None);
+        let exact = payment(company, 1500.0, Some(15));
+        let close = payment(company, 1450.0, Some(1));
+
+        let suggestions = PaymentMatchUtil::suggest(&received, vec![close.clone(), exact.clone()]);
+
+        assert_eq!(suggestions.len(), 2);
+        assert_eq!(suggestions[0].payment.id, exact.id);
+        assert!((suggestions[0].score - 1.0).abs() < f64::EPSILON);
+        assert_eq!(suggestions[1].payment.id, close.id);
+    }
+
+    #[test]
+    fn drops_weak_matches() {
+        let received = payment(Uuid::new_v4(), 1500.0, None);
+        let unrelated = payment(Uuid::new_v4(), 200.0, Some(16));
+
+        assert!(PaymentMatchUtil::suggest(&received, vec![unrelated]).is_empty());
+    }
+
+    #[test]
+    fn matches_amount_across_companies() {
+        let received = payment(Uuid::new_v4(), 980.0, None);
+        let candidate = payment(Uuid::new_v4(), 1000.0, None);
+
+        let suggestions = PaymentMatchUtil::suggest(&received, vec![candidate]);
+
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].reasons, vec!["Amount within 5%".to_string()]);
+    }
+}
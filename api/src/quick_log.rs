@@ -0,0 +1,188 @@
+//! Parsing of email quick-log commands.
+//!
+//! Provides [`QuickLogUtil`] for reading commands like
+//! `log 2h on Acme Website` from inbound emails and for extracting the
+//! sender's address from a `From` header.
+
+/// A parsed quick-log command.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuickLogCommand {
+    /// Logged duration in minutes.
+    pub minutes: i32,
+    /// Job name as written in the command.
+    pub job_name: String,
+}
+
+/// Utility for parsing quick-log emails.
+pub struct QuickLogUtil;
+
+impl QuickLogUtil {
+    /// Finds the first quick-log command in an email.
+    ///
+    /// The subject is checked first, then each body line. Quoted reply
+    /// lines starting with `>` are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `subject` — The email subject.
+    /// * `text` — The plain-text email body.
+    ///
+    /// # Returns
+    ///
+    /// The first [`QuickLogCommand`] found, or `None` if the email has none.
+    pub fn parse_email(subject: Option<&str>, text: Option<&str>) -> Option<QuickLogCommand> {
+        subject
+            .into_iter()
+            .chain(text.into_iter().flat_map(str::lines))
+            .map(str::trim)
+            .filter(|line| !line.starts_with('>'))
+            .find_map(Self::parse_command)
+    }
+
+    /// Parses a single `log <duration> on <job>` command.
+    ///
+    /// Durations combine hour and minute parts such as `2h`, `1.5 hours`,
+    /// `90m`, or `2h 30m`.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` — The line to parse.
+    ///
+    /// # Returns
+    ///
+    /// The parsed [`QuickLogCommand`], or `None` if the line is not a valid
+    /// command.
+    pub fn parse_command(line: &str) -> Option<QuickLogCommand> {
+        let line = line.trim();
+        let lower = line.to_ascii_lowercase();
+        let rest = lower.strip_prefix("log ")?;
+        let on_index = rest.find(" on ")?;
+        let job_start = line.len() - rest.len() + on_index + " on ".len();
+
+        let minutes = Self::parse_duration(&rest[..on_index])?;
+        let job_name = line[job_start..].trim().trim_end_matches('.').trim();
+
+        if job_name.is_empty() {
+            return None;
+        }
+
+        Some(QuickLogCommand {
+            minutes,
+            job_name: job_name.to_string(),
+        })
+    }
+
+    /// Extracts the bare address from a `From` header value.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` — The header value, e.g. `Jane Doe <jane@example.com>`.
+    ///
+    /// # Returns
+    ///
+    /// The lowercased address.
+    pub fn sender_address(from: &str) -> String {
+        let address = match (from.rfind('<'), from.rfind('>')) {
+            (Some(start), Some(end)) if start < end => &from[start + 1..end],
+            _ => from,
+        };
+
+        address.trim().to_lowercase()
+    }
+
+    /// Parses a duration made of hour and minute parts.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — The lowercased duration text.
+    ///
+    /// # Returns
+    ///
+    /// The total number of minutes, or `None` if the text is not a positive
+    /// duration.
+    fn parse_duration(value: &str) -> Option<i32> {
+        let mut total = 0.0;
+        let mut rest = value.trim();
+
+        if rest.is_empty() {
+            return None;
+        }
+
+        while !rest.is_empty() {
+            let number_end = rest
+                .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+                .unwrap_or(rest.len());
+            let amount: f64 = rest[..number_end].parse().ok()?;
+            rest = rest[number_end..].trim_start();
+
+            let unit_end = rest
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(rest.len());
+            let factor = match &rest[..unit_end] {
+                "h" | "hr" | "hrs" | "hour" | "hours" => 60.0,
+                "m" | "min" | "mins" | "minute" | "minutes" => 1.0,
+                _ => return None,
+            };
+            rest = rest[unit_end..].trim_start();
+
+            total += amount * factor;
+        }
+
+        let minutes = total.round() as i32;
+
+        (minutes > 0).then_some(minutes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_hours_and_job_name() {
+        assert_eq!(
+            QuickLogUtil::parse_command("Log 2h on Acme Website"),
+            Some(QuickLogCommand {
+                minutes: 120,
+                job_name: "Acme Website".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn parses_combined_and_spelled_out_durations() {
+        let minutes = |line| QuickLogUtil::parse_command(line).map(|command| command.minutes);
+
+        assert_eq!(minutes("log 2h 30m on Site"), Some(150));
+        assert_eq!(minutes("log 1.5 hours on Site"), Some(90));
+        assert_eq!(minutes("log 45 min on Site"), Some(45));
+        assert_eq!(minutes("log 0h on Site"), None);
+        assert_eq!(minutes("log 2 days on Site"), None);
+        assert_eq!(minutes("lunch 2h on Site"), None);
+    }
+
+    #[test]
+    fn finds_command_in_body_and_skips_quotes() {
+        let command = QuickLogUtil::parse_email(
+            Some("Re: hours"),
+            Some("> log 9h on Old Job\nlog 3h on New Job.\n"),
+        );
+
+        assert_eq!(
+            command.map(|command| command.job_name),
+            Some("New Job".to_string())
+        );
+    }
+
+    #[test]
+    fn extracts_sender_address() {
+        assert_eq!(
+            QuickLogUtil::sender_address("Jane Doe <Jane@Example.com>"),
+            "jane@example.com"
+        );
+        assert_eq!(
+            QuickLogUtil::sender_address(" jane@example.com "),
+            "jane@example.com"
+        );
+    }
+}
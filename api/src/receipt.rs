@@ -0,0 +1,278 @@
+//! Signed public payment receipts.
+//!
+//! Provides [`ReceiptUtil`] for issuing and verifying the signed, expiring
+//! tokens embedded in public receipt links, and for rendering receipt
+//! pages. Tokens are JWTs signed with a key derived from
+//! [`Config::jwt_secret`](crate::core::config::Config::jwt_secret), so they
+//! cannot be used as access tokens.
+
+use chrono::{DateTime, Utc};
+use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use log::error;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::repo::payment::{PaymentReceiptRecord, PayoutTypeRecord};
+
+/// The payload embedded in a receipt token.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReceiptClaims {
+    /// Subject — the receipted payment's ID.
+    pub sub: Uuid,
+    /// Expiration time as a Unix timestamp (seconds).
+    pub exp: i64,
+    /// Issued-at time as a Unix timestamp (seconds).
+    pub iat: i64,
+}
+
+/// Utility for signing, verifying, and rendering payment receipts.
+pub struct ReceiptUtil;
+
+impl ReceiptUtil {
+    /// Signs a receipt token for a payment.
+    ///
+    /// # Arguments
+    ///
+    /// * `payment_id` — The payment's UUID.
+    /// * `expires_at` — When the link stops working.
+    /// * `secret` — The application's JWT secret.
+    ///
+    /// # Returns
+    ///
+    /// The encoded token.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::InternalServerError`] if encoding fails.
+    pub fn generate_token(
+        payment_id: Uuid,
+        expires_at: DateTime<Utc>,
+        secret: &str,
+    ) -> ApiResult<String> {
+        let claims = ReceiptClaims {
+            sub: payment_id,
+            exp: expires_at.timestamp(),
+            iat: Utc::now().timestamp(),
+        };
+
+        encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(Self::signing_key(secret).as_bytes()),
+        )
+        .map_err(|error| {
+            error!("Failed to encode receipt token: {:?}", error);
+            ApiErrorResponse::InternalServerError("Failed to sign receipt".to_string())
+        })
+    }
+
+    /// Verifies a receipt token's signature and expiry.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` — The token from the receipt link.
+    /// * `secret` — The application's JWT secret.
+    ///
+    /// # Returns
+    ///
+    /// The token's [`ReceiptClaims`], or `None` if the token is invalid or
+    /// expired.
+    pub fn verify_token(token: &str, secret: &str) -> Option<ReceiptClaims> {
+        let mut validation = Validation::default();
+        validation.leeway = 0;
+
+        decode::<ReceiptClaims>(
+            token,
+            &DecodingKey::from_secret(Self::signing_key(secret).as_bytes()),
+            &validation,
+        )
+        .ok()
+        .map(|token_data| token_data.claims)
+    }
+
+    /// Renders the public page for a verified receipt.
+    ///
+    /// # Arguments
+    ///
+    /// * `receipt` — The payment's receipt details.
+    /// * `expires_at` — When the link stops working.
+    ///
+    /// # Returns
+    ///
+    /// A complete HTML document.
+    pub fn render_receipt(receipt: &PaymentReceiptRecord, expires_at: DateTime<Utc>) -> String {
+        let rows = [
+            ("Receipt number", receipt.id.to_string()),
+            ("Paid by", receipt.company_name.clone()),
+            ("Paid to", receipt.payee_name.clone()),
+            ("Amount", format!("${:.2}", receipt.total)),
+            (
+                "Method",
+                Self::payout_label(&receipt.payout_type).to_string(),
+            ),
+            (
+                "Date",
+                receipt.payment_date.format("%B %-d, %Y").to_string(),
+            ),
+        ]
+        .iter()
+        .map(|(label, value)| {
+            format!(
+                "<tr><th>{}</th><td>{}</td></tr>",
+                label,
+                Self::escape_html(value)
+            )
+        })
+        .collect::<String>();
+
+        Self::render_page(
+            "Payment receipt",
+            &format!(
+                "<p class=\"verified\">&#10003; Verified by GigLog</p>\
+                 <table>{rows}</table>\
+                 <p class=\"note\">This receipt was issued by GigLog and its link is \
+                 signed to prevent tampering. It is valid until {}.</p>",
+                expires_at.format("%B %-d, %Y %H:%M UTC")
+            ),
+        )
+    }
+
+    /// Renders the page shown for invalid or expired receipt links.
+    ///
+    /// # Returns
+    ///
+    /// A complete HTML document.
+    pub fn render_invalid() -> String {
+        Self::render_page(
+            "Receipt unavailable",
+            "<p>This receipt link is invalid or has expired. \
+             Ask the payee for a new link.</p>",
+        )
+    }
+
+    /// Wraps page content in a minimal styled HTML document.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` — The page title and heading.
+    /// * `content` — The page body HTML.
+    ///
+    /// # Returns
+    ///
+    /// A complete HTML document.
+    fn render_page(title: &str, content: &str) -> String {
+        format!(
+            "<!DOCTYPE html><html lang=\"en\"><head><meta charset=\"utf-8\">\
+             <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\
+             <meta name=\"robots\" content=\"noindex\">\
+             <title>{title}</title><style>\
+             body{{font-family:system-ui,sans-serif;max-width:32rem;margin:3rem auto;padding:0 1rem;color:#1f2937}}\
+             table{{width:100%;border-collapse:collapse}}\
+             th,td{{padding:.5rem 0;border-bottom:1px solid #e5e7eb;text-align:left}}\
+             th{{color:#6b7280;font-weight:500}}\
+             .verified{{color:#047857;font-weight:600}}\
+             .note{{color:#6b7280;font-size:.875rem}}\
+             </style></head><body><h1>{title}</h1>{content}</body></html>"
+        )
+    }
+
+    /// Returns a display label for a payout method.
+    ///
+    /// # Arguments
+    ///
+    /// * `payout_type` — The payout method.
+    ///
+    /// # Returns
+    ///
+    /// The human-readable label.
+    pub(crate) fn payout_label(payout_type: &PayoutTypeRecord) -> &'static str {
+        match payout_type {
+            PayoutTypeRecord::Paypal => "PayPal",
+            PayoutTypeRecord::Cash => "Cash",
+            PayoutTypeRecord::Check => "Check",
+            PayoutTypeRecord::Zelle => "Zelle",
+            PayoutTypeRecord::Venmo => "Venmo",
+            PayoutTypeRecord::DirectDeposit => "Direct deposit",
+        }
+    }
+
+    /// Escapes text for inclusion in HTML.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — The text to escape.
+    ///
+    /// # Returns
+    ///
+    /// The escaped text.
+    fn escape_html(value: &str) -> String {
+        value
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+            .replace('\'', "&#39;")
+    }
+
+    /// Derives the receipt signing key from the JWT secret.
+    ///
+    /// # Arguments
+    ///
+    /// * `secret` — The application's JWT secret.
+    ///
+    /// # Returns
+    ///
+    /// The signing key.
+    fn signing_key(secret: &str) -> String {
+        format!("{secret}:payment-receipt")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, NaiveDate};
+
+    use super::*;
+
+    const SECRET: &str = "test-secret";
+
+    #[test]
+    fn verifies_signed_tokens() {
+        let payment_id = Uuid::new_v4();
+        let token = ReceiptUtil::generate_token(payment_id, Utc::now() + Duration::days(1), SECRET)
+            .expect("token should encode");
+
+        let claims = ReceiptUtil::verify_token(&token, SECRET).expect("token should verify");
+
+        assert_eq!(claims.sub, payment_id);
+        assert!(ReceiptUtil::verify_token(&token, "other-secret").is_none());
+    }
+
+    #[test]
+    fn rejects_expired_tokens() {
+        let token =
+            ReceiptUtil::generate_token(Uuid::new_v4(), Utc::now() - Duration::hours(1), SECRET)
+                .expect("token should encode");
+
+        assert!(ReceiptUtil::verify_token(&token, SECRET).is_none());
+    }
+
+    #[test]
+    fn escapes_receipt_fields() {
+        let receipt = PaymentReceiptRecord {
+            id: Uuid::nil(),
+            total: 1250.5,
+            payout_type: PayoutTypeRecord::DirectDeposit,
+            payment_date: NaiveDate::from_ymd_opt(2026, 10, 16).unwrap(),
+            company_name: "<script>Acme</script>".to_string(),
+            payee_name: "Jane Doe".to_string(),
+        };
+
+        let html = ReceiptUtil::render_receipt(&receipt, Utc::now());
+
+        assert!(html.contains("&lt;script&gt;Acme&lt;/script&gt;"));
+        assert!(html.contains("$1250.50"));
+        assert!(html.contains("October 16, 2026"));
+    }
+}
@@ -0,0 +1,280 @@
+//! Monthly payment reconciliation.
+//!
+//! Provides [`ReconciliationUtil`] for resolving the reconciled month,
+//! flagging payments whose status booleans need attention, and totalling
+//! expected, transferred, and received amounts.
+
+use chrono::{Datelike, Months, NaiveDate};
+use gig_log_common::models::payment::{
+    Payment, PaymentReconciliationItem, PaymentReconciliationResponse, PaymentStatus,
+    ReconciliationFlag,
+};
+
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::repo::payment::{PaymentRepo, ReconciliationPaymentRecord};
+
+/// Utility for building payment reconciliation checklists.
+pub struct ReconciliationUtil;
+
+impl ReconciliationUtil {
+    /// Resolves the first and last day of the month to reconcile.
+    ///
+    /// # Arguments
+    ///
+    /// * `month` — The month as `YYYY-MM`, or `None` for the current month.
+    /// * `today` — The current date.
+    ///
+    /// # Returns
+    ///
+    /// The first and last day of the month.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if `month` is not a valid
+    /// `YYYY-MM` month.
+    pub fn month_range(month: Option<&str>, today: NaiveDate) -> ApiResult<(NaiveDate, NaiveDate)> {
+        let start = match month {
+            Some(month) => {
+                NaiveDate::parse_from_str(&format!("{month}-01"), "%Y-%m-%d").map_err(|_| {
+                    ApiErrorResponse::BadRequest("Month must be formatted as YYYY-MM".to_string())
+                })?
+            }
+            None => today.with_day(1).unwrap_or(today),
+        };
+        let end = start
+            .checked_add_months(Months::new(1))
+            .and_then(|next| next.pred_opt())
+            .ok_or_else(|| ApiErrorResponse::BadRequest("Month is out of range".to_string()))?;
+
+        Ok((start, end))
+    }
+
+    /// Flags the discrepancies on a payment.
+    ///
+    /// # Arguments
+    ///
+    /// * `record` — The payment and its company's details.
+    /// * `today` — The current date, used to find overdue payments.
+    ///
+    /// # Returns
+    ///
+    /// The payment's [`ReconciliationFlag`] values, empty if nothing needs
+    /// attention.
+    pub fn flags(
+        record: &ReconciliationPaymentRecord,
+        today: NaiveDate,
+    ) -> Vec<ReconciliationFlag> {
+        let mut flags = Vec::new();
+
+        if PaymentRepo::is_overdue(record.expected_payout_date, record.payment_received, today) {
+            flags.push(ReconciliationFlag::Overdue);
+        } else if !record.payment_received && record.expected_payout_date.is_none() {
+            flags.push(ReconciliationFlag::MissingExpectedDate);
+        }
+
+        if record.payment_received && !record.transfer_initiated {
+            flags.push(ReconciliationFlag::ReceivedWithoutTransfer);
+        }
+
+        if record.requires_tax_withholdings && !record.tax_withholdings_covered {
+            flags.push(ReconciliationFlag::TaxWithholdingsNotCovered);
+        }
+
+        flags
+    }
+
+    /// Builds the reconciliation checklist for a month.
+    ///
+    /// # Arguments
+    ///
+    /// * `month_start` — First day of the reconciled month.
+    /// * `month_end` — Last day of the reconciled month.
+    /// * `records` — Payments due in the month, ordered by date.
+    /// * `today` — The current date, used to find overdue payments.
+    ///
+    /// # Returns
+    ///
+    /// A [`PaymentReconciliationResponse`] with flagged payments and totals
+    /// rounded to the cent.
+    pub fn build(
+        month_start: NaiveDate,
+        month_end: NaiveDate,
+        records: Vec<ReconciliationPaymentRecord>,
+        today: NaiveDate,
+    ) -> PaymentReconciliationResponse {
+        let mut expected_total = 0.0;
+        let mut transferred_total = 0.0;
+        let mut received_total = 0.0;
+        let mut payments = Vec::with_capacity(records.len());
+
+        for record in records {
+            let flags = Self::flags(&record, today);
+
+            expected_total += record.total;
+
+            if record.transfer_initiated {
+                transferred_total += record.total;
+            }
+
+            if record.payment_received {
+                received_total += record.total;
+            }
+
+            let (status, amount_outstanding) = PaymentStatus::from_amounts(
+                record.total,
+                record.amount_paid,
+                record.payment_received,
+            );
+
+            payments.push(PaymentReconciliationItem {
+                company_name: record.company_name,
+                flags,
+                payment: Payment {
+                    id: record.id,
+                    user_id: record.user_id,
+                    company_id: record.company_id,
+                    total: record.total,
+                    payout_type: record.payout_type.into(),
+                    expected_payout_date: record.expected_payout_date,
+                    transfer_initiated: record.transfer_initiated,
+                    payment_received: record.payment_received,
+                    tax_withholdings_covered: record.tax_withholdings_covered,
+                    tax_withholding_rate: record.tax_withholding_rate,
+                    amount_paid: record.amount_paid,
+                    amount_outstanding,
+                    status,
+                    overdue: PaymentRepo::is_overdue(
+                        record.expected_payout_date,
+                        record.payment_received,
+                        today,
+                    ),
+                    created_at: record.created_at,
+                    updated_at: record.updated_at,
+                },
+            });
+        }
+
+        PaymentReconciliationResponse {
+            month_start,
+            month_end,
+            expected_total: Self::round_cents(expected_total),
+            transferred_total: Self::round_cents(transferred_total),
+            received_total: Self::round_cents(received_total),
+            flagged_count: payments
+                .iter()
+                .filter(|item| !item.flags.is_empty())
+                .count(),
+            payments,
+        }
+    }
+
+    /// Rounds a dollar amount to the nearest cent.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` — The dollar amount to round.
+    ///
+    /// # Returns
+    ///
+    /// The rounded amount.
+    fn round_cents(amount: f64) -> f64 {
+        (amount * 100.0).round() / 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    use crate::repo::payment::PayoutTypeRecord;
+
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn record(
+        total: f64,
+        expected_payout_date: Option<NaiveDate>,
+        transfer_initiated: bool,
+        payment_received: bool,
+    ) -> ReconciliationPaymentRecord {
+        ReconciliationPaymentRecord {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            company_id: Uuid::new_v4(),
+            total,
+            payout_type: PayoutTypeRecord::Zelle,
+            expected_payout_date,
+            transfer_initiated,
+            payment_received,
+            tax_withholdings_covered: false,
+            tax_withholding_rate: None,
+            amount_paid: 0.0,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            company_name: "Acme".to_string(),
+            requires_tax_withholdings: false,
+        }
+    }
+
+    #[test]
+    fn resolves_month_ranges() {
+        let today = date(2026, 2, 14);
+
+        assert_eq!(
+            ReconciliationUtil::month_range(None, today).unwrap(),
+            (date(2026, 2, 1), date(2026, 2, 28))
+        );
+        assert_eq!(
+            ReconciliationUtil::month_range(Some("2026-12"), today).unwrap(),
+            (date(2026, 12, 1), date(2026, 12, 31))
+        );
+        assert!(ReconciliationUtil::month_range(Some("2026-13"), today).is_err());
+        assert!(ReconciliationUtil::month_range(Some("March"), today).is_err());
+    }
+
+    #[test]
+    fn flags_discrepancies() {
+        let today = date(2026, 3, 15);
+        let mut taxed = record(100.0, Some(date(2026, 3, 20)), true, true);
+        taxed.requires_tax_withholdings = true;
+
+        assert_eq!(
+            ReconciliationUtil::flags(&record(10.0, Some(date(2026, 3, 1)), true, false), today),
+            vec![ReconciliationFlag::Overdue]
+        );
+        assert_eq!(
+            ReconciliationUtil::flags(&record(10.0, None, false, false), today),
+            vec![ReconciliationFlag::MissingExpectedDate]
+        );
+        assert_eq!(
+            ReconciliationUtil::flags(&record(10.0, Some(date(2026, 3, 1)), false, true), today),
+            vec![ReconciliationFlag::ReceivedWithoutTransfer]
+        );
+        assert_eq!(
+            ReconciliationUtil::flags(&taxed, today),
+            vec![ReconciliationFlag::TaxWithholdingsNotCovered]
+        );
+    }
+
+    #[test]
+    fn totals_by_status() {
+        let today = date(2026, 3, 15);
+        let records = vec![
+            record(100.10, Some(date(2026, 3, 1)), true, true),
+            record(50.20, Some(date(2026, 3, 10)), true, false),
+            record(25.00, Some(date(2026, 3, 30)), false, false),
+        ];
+
+        let response =
+            ReconciliationUtil::build(date(2026, 3, 1), date(2026, 3, 31), records, today);
+
+        assert_eq!(response.expected_total, 175.3);
+        assert_eq!(response.transferred_total, 150.3);
+        assert_eq!(response.received_total, 100.1);
+        assert_eq!(response.flagged_count, 1);
+    }
+}
@@ -0,0 +1,240 @@
+//! Recurring schedule calculations.
+//!
+//! Provides [`RecurrenceUtil`] for computing the dates a recurring payment
+//! falls on. Every occurrence is computed from the start date rather than
+//! from the previous occurrence, so a monthly schedule starting on the 31st
+//! falls on the last day of shorter months without drifting earlier.
+
+use chrono::{Datelike, Days, Months, NaiveDate};
+use gig_log_common::models::recurring_payment::RecurrenceCadence;
+
+/// Utility for computing recurring schedule dates.
+pub struct RecurrenceUtil;
+
+impl RecurrenceUtil {
+    /// Lists a schedule's occurrences from a date onward.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_date` — Date of the schedule's first occurrence.
+    /// * `cadence` — How often the schedule repeats.
+    /// * `end_date` — Date after which the schedule stops, if any.
+    /// * `from` — Earliest occurrence to include.
+    /// * `until` — Latest occurrence to include, if bounded.
+    /// * `limit` — Maximum number of occurrences to return.
+    ///
+    /// # Returns
+    ///
+    /// The occurrences in order, on or after `from` and on or before both
+    /// `end_date` and `until`.
+    pub fn occurrences(
+        start_date: NaiveDate,
+        cadence: RecurrenceCadence,
+        end_date: Option<NaiveDate>,
+        from: NaiveDate,
+        until: Option<NaiveDate>,
+        limit: usize,
+    ) -> Vec<NaiveDate> {
+        let last = match (end_date, until) {
+            (Some(end_date), Some(until)) => Some(end_date.min(until)),
+            (end_date, until) => end_date.or(until),
+        };
+        let mut dates = Vec::new();
+        let mut index = Self::first_index_on_or_after(start_date, cadence, from);
+
+        while dates.len() < limit {
+            let Some(date) = Self::occurrence(start_date, cadence, index) else {
+                break;
+            };
+
+            if last.is_some_and(|last| date > last) {
+                break;
+            }
+
+            dates.push(date);
+            index += 1;
+        }
+
+        dates
+    }
+
+    /// Finds a schedule's first occurrence on or after a date.
+    ///
+    /// The schedule's end date is not applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_date` — Date of the schedule's first occurrence.
+    /// * `cadence` — How often the schedule repeats.
+    /// * `date` — The earliest date to consider.
+    ///
+    /// # Returns
+    ///
+    /// The occurrence, or `None` if it is beyond the supported date range.
+    pub fn next_on_or_after(
+        start_date: NaiveDate,
+        cadence: RecurrenceCadence,
+        date: NaiveDate,
+    ) -> Option<NaiveDate> {
+        let index = Self::first_index_on_or_after(start_date, cadence, date);
+
+        Self::occurrence(start_date, cadence, index)
+    }
+
+    /// Computes the date of a schedule's `index`th occurrence.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_date` — Date of the schedule's first occurrence.
+    /// * `cadence` — How often the schedule repeats.
+    /// * `index` — Zero-based occurrence number.
+    ///
+    /// # Returns
+    ///
+    /// The occurrence's date, clamped to the end of the month for monthly
+    /// cadences, or `None` if it is beyond the supported date range.
+    fn occurrence(
+        start_date: NaiveDate,
+        cadence: RecurrenceCadence,
+        index: u32,
+    ) -> Option<NaiveDate> {
+        match cadence {
+            RecurrenceCadence::Weekly => {
+                start_date.checked_add_days(Days::new(7 * u64::from(index)))
+            }
+            RecurrenceCadence::Biweekly => {
+                start_date.checked_add_days(Days::new(14 * u64::from(index)))
+            }
+            RecurrenceCadence::Monthly => start_date.checked_add_months(Months::new(index)),
+            RecurrenceCadence::Quarterly => {
+                start_date.checked_add_months(Months::new(index.checked_mul(3)?))
+            }
+            RecurrenceCadence::Yearly => {
+                start_date.checked_add_months(Months::new(index.checked_mul(12)?))
+            }
+        }
+    }
+
+    /// Finds the index of a schedule's first occurrence on or after a date.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_date` — Date of the schedule's first occurrence.
+    /// * `cadence` — How often the schedule repeats.
+    /// * `date` — The earliest date to consider.
+    ///
+    /// # Returns
+    ///
+    /// The zero-based occurrence index.
+    fn first_index_on_or_after(
+        start_date: NaiveDate,
+        cadence: RecurrenceCadence,
+        date: NaiveDate,
+    ) -> u32 {
+        if date <= start_date {
+            return 0;
+        }
+
+        let days = (date - start_date).num_days();
+        let months = i64::from(date.year() - start_date.year()) * 12 + i64::from(date.month0())
+            - i64::from(start_date.month0());
+        let estimate = match cadence {
+            RecurrenceCadence::Weekly => days / 7,
+            RecurrenceCadence::Biweekly => days / 14,
+            RecurrenceCadence::Monthly => months,
+            RecurrenceCadence::Quarterly => months / 3,
+            RecurrenceCadence::Yearly => months / 12,
+        };
+        let mut index = u32::try_from(estimate.max(0)).unwrap_or(u32::MAX);
+
+        while Self::occurrence(start_date, cadence, index)
+            .is_some_and(|occurrence| occurrence < date)
+        {
+            index += 1;
+        }
+
+        index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(value: &str) -> NaiveDate {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn monthly_occurrences_clamp_without_drifting() {
+        let dates = RecurrenceUtil::occurrences(
+            date("2026-01-31"),
+            RecurrenceCadence::Monthly,
+            None,
+            date("2026-01-01"),
+            None,
+            4,
+        );
+
+        assert_eq!(
+            dates,
+            vec![
+                date("2026-01-31"),
+                date("2026-02-28"),
+                date("2026-03-31"),
+                date("2026-04-30"),
+            ]
+        );
+    }
+
+    #[test]
+    fn occurrences_stop_at_end_date_and_until() {
+        let start = date("2026-03-02");
+        let weekly = |end_date, until| {
+            RecurrenceUtil::occurrences(
+                start,
+                RecurrenceCadence::Weekly,
+                end_date,
+                date("2026-03-10"),
+                until,
+                10,
+            )
+        };
+
+        assert_eq!(
+            weekly(Some(date("2026-03-23")), None),
+            vec![date("2026-03-16"), date("2026-03-23")]
+        );
+        assert_eq!(
+            weekly(None, Some(date("2026-03-22"))),
+            vec![date("2026-03-16")]
+        );
+        assert_eq!(weekly(None, None).len(), 10);
+    }
+
+    #[test]
+    fn next_occurrence_on_or_after_date() {
+        let start = date("2024-02-29");
+
+        assert_eq!(
+            RecurrenceUtil::next_on_or_after(start, RecurrenceCadence::Yearly, date("2024-03-01")),
+            Some(date("2025-02-28"))
+        );
+        assert_eq!(
+            RecurrenceUtil::next_on_or_after(
+                start,
+                RecurrenceCadence::Quarterly,
+                date("2024-05-29")
+            ),
+            Some(date("2024-05-29"))
+        );
+        assert_eq!(
+            RecurrenceUtil::next_on_or_after(
+                start,
+                RecurrenceCadence::Biweekly,
+                date("2024-01-01")
+            ),
+            Some(start)
+        );
+    }
+}
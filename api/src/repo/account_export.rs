@@ -0,0 +1,560 @@
+//! Account export database operations.
+//!
+//! Provides [`AccountExportRepo`] for queueing account data exports in the
+//! `account_exports` table, claiming them for the background worker, and
+//! storing finished archives, along with the queries that gather the
+//! records an archive contains. All request-facing queries are scoped to
+//! the owning user.
+
+use chrono::{DateTime, Duration, Utc};
+use sqlx::{FromRow, Pool, Postgres};
+use uuid::Uuid;
+
+use gig_log_common::models::{
+    account_export::{AccountExport, AccountExportStatus},
+    appearance::{AppearancePreferences, ColorPalette},
+    company::Company,
+    work_session::WorkSession,
+};
+
+use crate::core::error::ApiResult;
+use crate::repo::appearance::PaletteTypeRecord;
+
+/// Progress of an account export row.
+///
+/// Maps to the PostgreSQL `account_export_status` enum.
+#[derive(Debug, Clone, sqlx::Type)]
+#[sqlx(type_name = "account_export_status", rename_all = "snake_case")]
+pub enum AccountExportStatusRecord {
+    /// Waiting for the background worker to pick it up.
+    Pending,
+    /// The archive is being built.
+    Processing,
+    /// The archive is ready to download.
+    Completed,
+    /// The archive could not be built.
+    Failed,
+}
+
+impl From<AccountExportStatusRecord> for AccountExportStatus {
+    fn from(status: AccountExportStatusRecord) -> Self {
+        match status {
+            AccountExportStatusRecord::Pending => AccountExportStatus::Pending,
+            AccountExportStatusRecord::Processing => AccountExportStatus::Processing,
+            AccountExportStatusRecord::Completed => AccountExportStatus::Completed,
+            AccountExportStatusRecord::Failed => AccountExportStatus::Failed,
+        }
+    }
+}
+
+/// A row from the `account_exports` table, without its archive.
+#[derive(Debug, Clone, FromRow)]
+pub struct AccountExportRecord {
+    /// Unique identifier for the export.
+    pub id: Uuid,
+    /// Progress of the export.
+    pub status: AccountExportStatusRecord,
+    /// Why the export failed, if it did.
+    pub error: Option<String>,
+    /// Timestamp when the export was requested.
+    pub created_at: DateTime<Utc>,
+    /// Timestamp when the export finished, successfully or not.
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl From<AccountExportRecord> for AccountExport {
+    fn from(record: AccountExportRecord) -> Self {
+        let status = AccountExportStatus::from(record.status);
+        let download_url = (status == AccountExportStatus::Completed)
+            .then(|| format!("/export/account/{}/download", record.id));
+
+        Self {
+            id: record.id,
+            status,
+            error: record.error,
+            download_url,
+            created_at: record.created_at,
+            completed_at: record.completed_at,
+        }
+    }
+}
+
+/// An export claimed by the background worker.
+#[derive(Debug, Clone, FromRow)]
+pub struct ClaimedExportRecord {
+    /// Unique identifier for the export.
+    pub id: Uuid,
+    /// The user whose data is exported.
+    pub user_id: Uuid,
+}
+
+/// A row from the `user_appearance_preferences` table.
+#[derive(Debug, Clone, FromRow)]
+struct AppearancePreferencesRecord {
+    /// Unique identifier for the preferences record.
+    id: Uuid,
+    /// The user these preferences belong to.
+    user_id: Uuid,
+    /// Whether the user is using a preset or custom palette.
+    active_palette_type: PaletteTypeRecord,
+    /// Slug of the active preset palette.
+    active_preset_palette: Option<String>,
+    /// The ID of the active custom palette.
+    active_custom_palette_id: Option<Uuid>,
+}
+
+impl From<AppearancePreferencesRecord> for AppearancePreferences {
+    fn from(record: AppearancePreferencesRecord) -> Self {
+        Self {
+            id: record.id,
+            user_id: record.user_id,
+            active_palette_type: record.active_palette_type.into(),
+            active_preset_palette: record.active_preset_palette,
+            active_custom_palette_id: record.active_custom_palette_id,
+        }
+    }
+}
+
+/// Repository for account export database operations.
+pub struct AccountExportRepo;
+
+impl AccountExportRepo {
+    /// Queues an account export, unless one is already queued.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user whose data is exported.
+    ///
+    /// # Returns
+    ///
+    /// The user's pending or processing [`AccountExport`] if they have one,
+    /// otherwise a newly queued one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a query fails.
+    pub async fn queue_export(pool: &Pool<Postgres>, user_id: Uuid) -> ApiResult<AccountExport> {
+        let existing = sqlx::query_as!(
+            AccountExportRecord,
+            r#"
+        SELECT id, status AS "status: AccountExportStatusRecord", error, created_at,
+               completed_at
+        FROM account_exports
+        WHERE user_id = $1 AND status IN ('pending', 'processing')
+        ORDER BY created_at
+        LIMIT 1
+        "#,
+            user_id,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        if let Some(existing) = existing {
+            return Ok(existing.into());
+        }
+
+        let record = sqlx::query_as!(
+            AccountExportRecord,
+            r#"
+        INSERT INTO account_exports (user_id)
+        VALUES ($1)
+        RETURNING id, status AS "status: AccountExportStatusRecord", error, created_at,
+                  completed_at
+        "#,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record.into())
+    }
+
+    /// Finds an account export by ID, scoped to the owning user.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who requested the export.
+    /// * `export_id` — The export's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The [`AccountExport`] matching the given ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`](crate::core::error::ApiErrorResponse::NotFound)
+    /// if no export with the given ID exists for the user.
+    pub async fn find_export(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        export_id: Uuid,
+    ) -> ApiResult<AccountExport> {
+        let record = sqlx::query_as!(
+            AccountExportRecord,
+            r#"
+        SELECT id, status AS "status: AccountExportStatusRecord", error, created_at,
+               completed_at
+        FROM account_exports
+        WHERE id = $1 AND user_id = $2
+        "#,
+            export_id,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record.into())
+    }
+
+    /// Finds the archive of an account export, scoped to the owning user.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who requested the export.
+    /// * `export_id` — The export's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The archive's JSON bytes, or `None` if the export has not completed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`](crate::core::error::ApiErrorResponse::NotFound)
+    /// if no export with the given ID exists for the user.
+    pub async fn find_archive(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        export_id: Uuid,
+    ) -> ApiResult<Option<Vec<u8>>> {
+        let archive = sqlx::query_scalar!(
+            r#"
+        SELECT archive
+        FROM account_exports
+        WHERE id = $1 AND user_id = $2
+        "#,
+            export_id,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(archive)
+    }
+
+    /// Claims the oldest queued export for processing.
+    ///
+    /// Exports left processing for longer than `stale_after`, such as by a
+    /// server that stopped mid-build, are claimed again.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `stale_after` — How long an export may stay processing before it
+    ///   is retried.
+    ///
+    /// # Returns
+    ///
+    /// The [`ClaimedExportRecord`], or `None` if nothing is queued.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn claim_next(
+        pool: &Pool<Postgres>,
+        stale_after: Duration,
+    ) -> ApiResult<Option<ClaimedExportRecord>> {
+        let record = sqlx::query_as!(
+            ClaimedExportRecord,
+            r#"
+        UPDATE account_exports
+        SET status = 'processing', started_at = now()
+        WHERE id = (
+            SELECT id
+            FROM account_exports
+            WHERE status = 'pending'
+               OR (status = 'processing'
+                   AND started_at < now() - $1::BIGINT * INTERVAL '1 millisecond')
+            ORDER BY created_at
+            LIMIT 1
+            FOR UPDATE SKIP LOCKED
+        )
+        RETURNING id, user_id
+        "#,
+            stale_after.num_milliseconds(),
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// Stores a finished archive and marks its export completed.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `export_id` — The export's UUID.
+    /// * `archive` — The archive's JSON bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn complete_export(
+        pool: &Pool<Postgres>,
+        export_id: Uuid,
+        archive: &[u8],
+    ) -> ApiResult<()> {
+        sqlx::query!(
+            r#"
+        UPDATE account_exports
+        SET status = 'completed', archive = $2, error = NULL, completed_at = now()
+        WHERE id = $1
+        "#,
+            export_id,
+            archive,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Marks an export failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `export_id` — The export's UUID.
+    /// * `error` — Why the archive could not be built.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn fail_export(pool: &Pool<Postgres>, export_id: Uuid, error: &str) -> ApiResult<()> {
+        sqlx::query!(
+            r#"
+        UPDATE account_exports
+        SET status = 'failed', error = $2, completed_at = now()
+        WHERE id = $1
+        "#,
+            export_id,
+            error,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Deletes finished exports older than a retention period.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `retention` — How long finished exports are kept.
+    ///
+    /// # Returns
+    ///
+    /// The number of exports deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete query fails.
+    pub async fn delete_expired(pool: &Pool<Postgres>, retention: Duration) -> ApiResult<u64> {
+        let result = sqlx::query!(
+            r#"
+        DELETE FROM account_exports
+        WHERE completed_at < now() - $1::BIGINT * INTERVAL '1 millisecond'
+        "#,
+            retention.num_milliseconds(),
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Lists every company a user owns.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the companies.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`Company`] values, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_companies(pool: &Pool<Postgres>, user_id: Uuid) -> ApiResult<Vec<Company>> {
+        let companies = sqlx::query_as!(
+            Company,
+            r#"
+        SELECT id, user_id, name, requires_tax_withholdings,
+               tax_withholding_rate::FLOAT8 AS tax_withholding_rate, accent_color,
+               auto_draft_invoices,
+               '/companies/' || id || '/logo?v=' || (EXTRACT(EPOCH FROM logo_uploaded_at) * 1000)::BIGINT AS logo_url,
+               created_at, updated_at
+        FROM companies
+        WHERE user_id = $1
+        ORDER BY created_at, id
+        "#,
+            user_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(companies)
+    }
+
+    /// Lists every work session a user owns.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the sessions.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`WorkSession`] values, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_work_sessions(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+    ) -> ApiResult<Vec<WorkSession>> {
+        let sessions = sqlx::query_as!(
+            WorkSession,
+            r#"
+        SELECT id, user_id, job_id, start_time, end_time, is_running,
+               EXTRACT(EPOCH FROM accumulated_paused_duration)::BIGINT
+                   AS "accumulated_paused_duration!",
+               paused_at,
+               EXTRACT(EPOCH FROM time_reported)::BIGINT AS time_reported,
+               hourly_rate::FLOAT8 AS hourly_rate, auto_completed,
+               start_latitude, start_longitude, end_latitude, end_longitude, notes, tags,
+               created_at, updated_at
+        FROM work_sessions
+        WHERE user_id = $1
+        ORDER BY start_time, id
+        "#,
+            user_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(sessions)
+    }
+
+    /// Lists every custom color palette a user owns.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the palettes.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`ColorPalette`] values, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_palettes(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+    ) -> ApiResult<Vec<ColorPalette>> {
+        let palettes = sqlx::query_as!(
+            ColorPalette,
+            r#"
+        SELECT id, user_id, name,
+               ARRAY[seed_green, seed_red, seed_yellow, seed_blue, seed_magenta, seed_cyan]
+                   AS "seed_colors!: Vec<String>",
+               generated_tokens, created_at, updated_at
+        FROM user_color_palettes
+        WHERE user_id = $1
+        ORDER BY created_at, id
+        "#,
+            user_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(palettes)
+    }
+
+    /// Finds a user's appearance preferences.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user.
+    ///
+    /// # Returns
+    ///
+    /// The user's [`AppearancePreferences`], or `None` if they have not
+    /// chosen a palette.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn find_appearance(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+    ) -> ApiResult<Option<AppearancePreferences>> {
+        let record = sqlx::query_as!(
+            AppearancePreferencesRecord,
+            r#"
+        SELECT id, user_id, active_palette_type AS "active_palette_type: PaletteTypeRecord",
+               active_preset_palette::TEXT AS active_preset_palette, active_custom_palette_id
+        FROM user_appearance_preferences
+        WHERE user_id = $1
+        "#,
+            user_id,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(record.map(Into::into))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(status: AccountExportStatusRecord) -> AccountExportRecord {
+        AccountExportRecord {
+            id: Uuid::nil(),
+            status,
+            error: None,
+            created_at: Utc::now(),
+            completed_at: None,
+        }
+    }
+
+    #[test]
+    fn links_the_archive_only_once_completed() {
+        let completed = AccountExport::from(record(AccountExportStatusRecord::Completed));
+        assert_eq!(completed.status, AccountExportStatus::Completed);
+        assert_eq!(
+            completed.download_url.as_deref(),
+            Some("/export/account/00000000-0000-0000-0000-000000000000/download")
+        );
+
+        for status in [
+            AccountExportStatusRecord::Pending,
+            AccountExportStatusRecord::Processing,
+            AccountExportStatusRecord::Failed,
+        ] {
+            assert_eq!(AccountExport::from(record(status)).download_url, None);
+        }
+    }
+}
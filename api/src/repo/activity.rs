@@ -0,0 +1,141 @@
+//! API activity database operations.
+//!
+//! Provides [`ActivityRepo`] for recording the requests users make, listing
+//! a user's recent activity, and pruning old entries from the
+//! `api_activity` table.
+
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use gig_log_common::models::user::ApiActivity;
+
+use crate::core::error::ApiResult;
+
+/// Most activity entries returned for a user.
+pub const MAX_ACTIVITY_ENTRIES: i64 = 100;
+
+/// Days activity entries are kept before being pruned.
+pub const ACTIVITY_RETENTION_DAYS: i32 = 90;
+
+/// A request to be recorded in the `api_activity` table.
+#[derive(Debug)]
+pub struct NewActivityRecord {
+    /// The user who made the request.
+    pub user_id: Uuid,
+    /// HTTP method of the request.
+    pub method: String,
+    /// Route pattern the request matched.
+    pub route: String,
+    /// HTTP status code of the response.
+    pub status: i16,
+    /// IP address the request came from, if known.
+    pub ip_address: Option<String>,
+    /// `User-Agent` of the device that made the request, if sent.
+    pub user_agent: Option<String>,
+}
+
+/// Repository for API activity database operations.
+pub struct ActivityRepo;
+
+impl ActivityRepo {
+    /// Records a request.
+    ///
+    /// User agents are truncated to 512 characters.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `activity` — The request to record.
+    ///
+    /// # Returns
+    ///
+    /// `()` on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert fails.
+    pub async fn insert_activity(
+        pool: &Pool<Postgres>,
+        activity: NewActivityRecord,
+    ) -> ApiResult<()> {
+        sqlx::query!(
+            r#"
+        INSERT INTO api_activity (user_id, method, route, status, ip_address, user_agent)
+        VALUES ($1, $2, $3, $4, $5, LEFT($6, 512))
+        "#,
+            activity.user_id,
+            activity.method,
+            activity.route,
+            activity.status,
+            activity.ip_address,
+            activity.user_agent,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Lists a user's most recent requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user whose activity to list.
+    ///
+    /// # Returns
+    ///
+    /// Up to [`MAX_ACTIVITY_ENTRIES`] entries, newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_for_user(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+    ) -> ApiResult<Vec<ApiActivity>> {
+        let activity = sqlx::query_as!(
+            ApiActivity,
+            r#"
+        SELECT id, method, route, status, ip_address, user_agent, created_at
+        FROM api_activity
+        WHERE user_id = $1
+        ORDER BY created_at DESC
+        LIMIT $2
+        "#,
+            user_id,
+            MAX_ACTIVITY_ENTRIES,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(activity)
+    }
+
+    /// Deletes entries older than [`ACTIVITY_RETENTION_DAYS`].
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    ///
+    /// # Returns
+    ///
+    /// The number of entries deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete fails.
+    pub async fn delete_expired(pool: &Pool<Postgres>) -> ApiResult<u64> {
+        let result = sqlx::query!(
+            r#"
+        DELETE FROM api_activity
+        WHERE created_at < now() - make_interval(days => $1)
+        "#,
+            ACTIVITY_RETENTION_DAYS,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
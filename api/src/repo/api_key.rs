@@ -0,0 +1,145 @@
+//! API key database operations.
+//!
+//! Provides [`ApiKeyRepo`] for creating, listing, revoking, and
+//! authenticating API keys stored in the `api_keys` table.
+
+use gig_log_common::models::api_key::ApiKey;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::core::error::ApiResult;
+
+/// Repository for API key database operations.
+pub struct ApiKeyRepo;
+
+impl ApiKeyRepo {
+    /// Inserts a new API key.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user the key belongs to.
+    /// * `name` — Label describing what the key is used for.
+    /// * `key_hash` — The SHA-256 hash of the raw key.
+    ///
+    /// # Returns
+    ///
+    /// The created [`ApiKey`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert query fails.
+    pub async fn insert_key(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        name: &str,
+        key_hash: &str,
+    ) -> ApiResult<ApiKey> {
+        let api_key = sqlx::query_as!(
+            ApiKey,
+            r#"
+        INSERT INTO api_keys (user_id, name, key_hash)
+        VALUES ($1, $2, $3)
+        RETURNING id, name, last_used_at, created_at
+        "#,
+            user_id,
+            name,
+            key_hash,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(api_key)
+    }
+
+    /// Lists a user's API keys, newest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user whose keys to list.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`ApiKey`] values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_keys(pool: &Pool<Postgres>, user_id: Uuid) -> ApiResult<Vec<ApiKey>> {
+        let api_keys = sqlx::query_as!(
+            ApiKey,
+            r#"
+        SELECT id, name, last_used_at, created_at
+        FROM api_keys
+        WHERE user_id = $1
+        ORDER BY created_at DESC
+        "#,
+            user_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(api_keys)
+    }
+
+    /// Deletes one of a user's API keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user the key belongs to.
+    /// * `key_id` — The key's UUID.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a key was deleted, `false` if none matched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete query fails.
+    pub async fn delete_key(pool: &Pool<Postgres>, user_id: Uuid, key_id: Uuid) -> ApiResult<bool> {
+        let result = sqlx::query!(
+            r#"
+        DELETE FROM api_keys
+        WHERE id = $1 AND user_id = $2
+        "#,
+            key_id,
+            user_id,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Looks up a key by hash and records that it was used.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `key_hash` — The SHA-256 hash of the raw key.
+    ///
+    /// # Returns
+    ///
+    /// The ID of the user the key belongs to, or `None` if no key matches.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn use_key(pool: &Pool<Postgres>, key_hash: &str) -> ApiResult<Option<Uuid>> {
+        let user_id = sqlx::query_scalar!(
+            r#"
+        UPDATE api_keys
+        SET last_used_at = now()
+        WHERE key_hash = $1
+        RETURNING user_id
+        "#,
+            key_hash,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(user_id)
+    }
+}
@@ -0,0 +1,226 @@
+//! Appearance database operations.
+//!
+//! Provides [`AppearanceRepo`] for reading the preset palette registry in
+//! the `preset_palettes` table, the colors of custom palettes in the
+//! `user_color_palettes` table, and each user's active palette from
+//! `user_appearance_preferences`.
+
+use serde_json::Value;
+use sqlx::{FromRow, Pool, Postgres};
+use uuid::Uuid;
+
+use gig_log_common::models::appearance::{PaletteType, PresetPalette};
+
+use crate::core::error::ApiResult;
+
+/// A row from the `preset_palettes` table.
+#[derive(Debug, FromRow)]
+pub struct PresetPaletteRecord {
+    /// Stable identifier of the preset.
+    pub slug: String,
+    /// Display name of the preset.
+    pub name: String,
+    /// Green seed color.
+    pub seed_green: String,
+    /// Red seed color.
+    pub seed_red: String,
+    /// Yellow seed color.
+    pub seed_yellow: String,
+    /// Blue seed color.
+    pub seed_blue: String,
+    /// Magenta seed color.
+    pub seed_magenta: String,
+    /// Cyan seed color.
+    pub seed_cyan: String,
+    /// Full set of design tokens for the preset.
+    pub generated_tokens: Value,
+}
+
+impl From<PresetPaletteRecord> for PresetPalette {
+    fn from(record: PresetPaletteRecord) -> Self {
+        Self {
+            slug: record.slug,
+            name: record.name,
+            seed_colors: vec![
+                record.seed_green,
+                record.seed_red,
+                record.seed_yellow,
+                record.seed_blue,
+                record.seed_magenta,
+                record.seed_cyan,
+            ],
+            generated_tokens: record.generated_tokens,
+        }
+    }
+}
+
+/// The colors of a preset or custom palette.
+#[derive(Debug, FromRow)]
+pub struct PaletteSwatchRecord {
+    /// Green seed color.
+    pub seed_green: String,
+    /// Red seed color.
+    pub seed_red: String,
+    /// Yellow seed color.
+    pub seed_yellow: String,
+    /// Blue seed color.
+    pub seed_blue: String,
+    /// Magenta seed color.
+    pub seed_magenta: String,
+    /// Cyan seed color.
+    pub seed_cyan: String,
+    /// Design tokens generated for the palette.
+    pub generated_tokens: Value,
+}
+
+/// Whether a user's active palette is a preset or a custom palette.
+///
+/// Maps to the PostgreSQL `palette_type` enum.
+#[derive(Debug, Clone, sqlx::Type)]
+#[sqlx(type_name = "palette_type", rename_all = "snake_case")]
+pub enum PaletteTypeRecord {
+    /// A built-in preset palette.
+    Preset,
+    /// A user-created custom palette.
+    Custom,
+}
+
+impl From<PaletteTypeRecord> for PaletteType {
+    fn from(palette_type: PaletteTypeRecord) -> Self {
+        match palette_type {
+            PaletteTypeRecord::Preset => PaletteType::Preset,
+            PaletteTypeRecord::Custom => PaletteType::Custom,
+        }
+    }
+}
+
+/// Repository for appearance database operations.
+pub struct AppearanceRepo;
+
+impl AppearanceRepo {
+    /// Lists every preset palette in the registry.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`PresetPalette`] values in display order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_presets(pool: &Pool<Postgres>) -> ApiResult<Vec<PresetPalette>> {
+        let records = sqlx::query_as!(
+            PresetPaletteRecord,
+            r#"
+        SELECT slug, name, seed_green, seed_red, seed_yellow, seed_blue, seed_magenta,
+               seed_cyan, generated_tokens
+        FROM preset_palettes
+        ORDER BY position, name
+        "#,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records.into_iter().map(Into::into).collect())
+    }
+
+    /// Finds the colors of a preset palette by slug or a custom palette by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `slug` — The preset palette's slug.
+    /// * `custom_palette_id` — The custom palette's UUID, or `None` to only
+    ///   look up presets.
+    ///
+    /// # Returns
+    ///
+    /// The palette's [`PaletteSwatchRecord`], or `None` if no palette
+    /// matches. Presets take precedence over custom palettes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn find_palette_swatch(
+        pool: &Pool<Postgres>,
+        slug: &str,
+        custom_palette_id: Option<Uuid>,
+    ) -> ApiResult<Option<PaletteSwatchRecord>> {
+        let record = sqlx::query_as!(
+            PaletteSwatchRecord,
+            r#"
+        SELECT seed_green AS "seed_green!", seed_red AS "seed_red!",
+               seed_yellow AS "seed_yellow!", seed_blue AS "seed_blue!",
+               seed_magenta AS "seed_magenta!", seed_cyan AS "seed_cyan!",
+               generated_tokens AS "generated_tokens!"
+        FROM (
+            SELECT 0 AS priority, seed_green, seed_red, seed_yellow, seed_blue, seed_magenta,
+                   seed_cyan, generated_tokens
+            FROM preset_palettes
+            WHERE slug = $1
+            UNION ALL
+            SELECT 1, seed_green, seed_red, seed_yellow, seed_blue, seed_magenta, seed_cyan,
+                   generated_tokens
+            FROM user_color_palettes
+            WHERE id = $2
+        ) palettes
+        ORDER BY priority
+        LIMIT 1
+        "#,
+            slug,
+            custom_palette_id,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// Finds the colors of a user's active palette.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user.
+    ///
+    /// # Returns
+    ///
+    /// The active palette's [`PaletteSwatchRecord`], or `None` if the user
+    /// has not chosen a palette.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn find_active_palette(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+    ) -> ApiResult<Option<PaletteSwatchRecord>> {
+        let record = sqlx::query_as!(
+            PaletteSwatchRecord,
+            r#"
+        SELECT COALESCE(pp.seed_green, cp.seed_green) AS "seed_green!",
+               COALESCE(pp.seed_red, cp.seed_red) AS "seed_red!",
+               COALESCE(pp.seed_yellow, cp.seed_yellow) AS "seed_yellow!",
+               COALESCE(pp.seed_blue, cp.seed_blue) AS "seed_blue!",
+               COALESCE(pp.seed_magenta, cp.seed_magenta) AS "seed_magenta!",
+               COALESCE(pp.seed_cyan, cp.seed_cyan) AS "seed_cyan!",
+               COALESCE(pp.generated_tokens, cp.generated_tokens) AS "generated_tokens!"
+        FROM user_appearance_preferences p
+        LEFT JOIN preset_palettes pp
+            ON p.active_palette_type = 'preset' AND pp.slug = p.active_preset_palette
+        LEFT JOIN user_color_palettes cp
+            ON p.active_palette_type = 'custom' AND cp.id = p.active_custom_palette_id
+        WHERE p.user_id = $1
+          AND (pp.slug IS NOT NULL OR cp.id IS NOT NULL)
+        "#,
+            user_id,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(record)
+    }
+}
@@ -1,18 +1,28 @@
 //! Authorization code database operations.
 //!
-//! Provides [`AuthCodeRepo`] for inserting, finding, and consuming
-//! authorization codes stored in the `auth_codes` table.
+//! Provides [`AuthCodeRepo`] for inserting, verifying, and consuming
+//! authorization codes stored in the `auth_codes` table, whether entered
+//! by hand or reached through a signed email link. Every guess reserves
+//! an attempt on the code before it is compared, so concurrent guesses
+//! cannot get past the limit, and a code is invalidated after
+//! [`MAX_FAILED_ATTEMPTS`] wrong guesses.
 
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use sqlx::{FromRow, Pool, Postgres};
 use uuid::Uuid;
 
+use crate::auth::code;
 use crate::core::error::ApiResult;
 
+/// Number of wrong guesses after which a code is invalidated.
+pub const MAX_FAILED_ATTEMPTS: i32 = 5;
+
 /// The category of an authorization code.
 ///
 /// Maps to the PostgreSQL `code_type` enum.
-#[derive(Debug, Clone, sqlx::Type)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
 #[sqlx(type_name = "code_type", rename_all = "snake_case")]
 pub enum AuthCodeType {
     /// Confirms a newly registered email address.
@@ -44,6 +54,52 @@ pub struct AuthCodeRecord {
     pub used: bool,
 }
 
+/// A code whose attempt counter was incremented to reserve a guess.
+#[derive(Debug, FromRow)]
+pub struct ReservedAuthCodeRecord {
+    /// Unique identifier for the auth code record.
+    pub id: Uuid,
+    /// The user this code belongs to.
+    pub user_id: Uuid,
+    /// The authorization code string.
+    pub code: String,
+    /// The category of operation this code authorizes.
+    pub code_type: AuthCodeType,
+    /// The new email address, present only for [`AuthCodeType::EmailChange`] codes.
+    pub new_email: Option<String>,
+    /// When this code expires.
+    pub expires_at: DateTime<Utc>,
+    /// Whether this code has already been consumed.
+    pub used: bool,
+    /// Attempts counted against the code, including the reserved one.
+    pub failed_attempts: i32,
+}
+
+impl From<ReservedAuthCodeRecord> for AuthCodeRecord {
+    fn from(record: ReservedAuthCodeRecord) -> Self {
+        Self {
+            id: record.id,
+            user_id: record.user_id,
+            code: record.code,
+            code_type: record.code_type,
+            new_email: record.new_email,
+            expires_at: record.expires_at,
+            used: record.used,
+        }
+    }
+}
+
+/// What to do with reserved codes once a guess has been compared.
+#[derive(Debug)]
+struct GuessSettlement {
+    /// The code the guess matched, if any.
+    matched: Option<AuthCodeRecord>,
+    /// Codes whose reserved attempt is given back because the guess was right.
+    refund: Vec<Uuid>,
+    /// Codes that reached [`MAX_FAILED_ATTEMPTS`] and must be invalidated.
+    lock: Vec<Uuid>,
+}
+
 /// Repository for authorization code database operations.
 pub struct AuthCodeRepo;
 
@@ -61,7 +117,7 @@ impl AuthCodeRepo {
     ///
     /// # Returns
     ///
-    /// `()` on success.
+    /// The ID of the new code.
     ///
     /// # Errors
     ///
@@ -73,11 +129,12 @@ impl AuthCodeRepo {
         code_type: AuthCodeType,
         expires_at: DateTime<Utc>,
         new_email: Option<&str>,
-    ) -> ApiResult<()> {
-        sqlx::query!(
+    ) -> ApiResult<Uuid> {
+        let id = sqlx::query_scalar!(
             r#"
         INSERT INTO auth_codes (user_id, code, code_type, expires_at, new_email)
         VALUES ($1, $2, $3, $4, $5)
+        RETURNING id
         "#,
             user_id,
             code,
@@ -85,49 +142,247 @@ impl AuthCodeRepo {
             expires_at,
             new_email,
         )
-        .execute(pool)
+        .fetch_one(pool)
         .await?;
 
-        Ok(())
+        Ok(id)
     }
 
-    /// Finds a valid (unused and non-expired) authorization code.
+    /// Finds a valid code by ID.
+    ///
+    /// Used when a code is reached through a signed email link, so no guess
+    /// is involved and no failed attempt is recorded.
     ///
     /// # Arguments
     ///
     /// * `pool` — The database connection pool.
-    /// * `code` — The authorization code string to look up.
+    /// * `code_id` — The UUID of the auth code record.
     /// * `code_type` — The expected code category.
     ///
     /// # Returns
     ///
-    /// The matching [`AuthCodeRecord`].
+    /// The matching [`AuthCodeRecord`], or `None` if the code does not
+    /// exist, has the wrong type, or is used or expired.
     ///
     /// # Errors
     ///
-    /// Returns an error if no valid code is found.
+    /// Returns an error if the query fails.
     pub async fn find_valid_code(
         pool: &Pool<Postgres>,
-        code: &str,
+        code_id: Uuid,
         code_type: AuthCodeType,
-    ) -> ApiResult<AuthCodeRecord> {
-        let auth_code = sqlx::query_as!(
+    ) -> ApiResult<Option<AuthCodeRecord>> {
+        let record = sqlx::query_as!(
             AuthCodeRecord,
             r#"
         SELECT id, user_id, code, code_type AS "code_type: AuthCodeType", new_email, expires_at, used
         FROM auth_codes
-        WHERE code = $1
+        WHERE id = $1
           AND code_type = $2
           AND used = FALSE
           AND expires_at > NOW()
         "#,
-            code,
+            code_id,
             code_type as AuthCodeType,
         )
-        .fetch_one(pool)
+        .fetch_optional(pool)
         .await?;
 
-        Ok(auth_code)
+        Ok(record)
+    }
+
+    /// Checks a guessed code against the valid codes sent to an email
+    /// address.
+    ///
+    /// The email is matched against the code's new email address for
+    /// [`AuthCodeType::EmailChange`] codes and against the owning user's
+    /// email otherwise. The guess reserves an attempt on every valid code
+    /// sent to the address, and the attempt stays counted if it is wrong.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `email` — The email address the code was sent to.
+    /// * `code` — The guessed authorization code.
+    /// * `code_type` — The expected code category.
+    ///
+    /// # Returns
+    ///
+    /// The matching [`AuthCodeRecord`], or `None` if the guess is wrong or
+    /// no valid code was sent to the address.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a query fails.
+    pub async fn verify_code_for_email(
+        pool: &Pool<Postgres>,
+        email: &str,
+        code: &str,
+        code_type: AuthCodeType,
+    ) -> ApiResult<Option<AuthCodeRecord>> {
+        let reserved = sqlx::query_as!(
+            ReservedAuthCodeRecord,
+            r#"
+        UPDATE auth_codes ac
+        SET failed_attempts = ac.failed_attempts + 1
+        FROM users u
+        WHERE u.id = ac.user_id
+          AND COALESCE(ac.new_email, u.email) = $1
+          AND ac.code_type = $2
+          AND ac.used = FALSE
+          AND ac.expires_at > NOW()
+          AND ac.failed_attempts < $3
+        RETURNING ac.id, ac.user_id, ac.code, ac.code_type AS "code_type: AuthCodeType",
+                  ac.new_email, ac.expires_at, ac.used, ac.failed_attempts
+        "#,
+            email,
+            code_type as AuthCodeType,
+            MAX_FAILED_ATTEMPTS,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Self::check_guess(pool, reserved, code).await
+    }
+
+    /// Checks a guessed code against a user's valid codes.
+    ///
+    /// The guess reserves an attempt on every valid code of the given type
+    /// belonging to the user, and the attempt stays counted if it is wrong.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user the code belongs to.
+    /// * `code` — The guessed authorization code.
+    /// * `code_type` — The expected code category.
+    ///
+    /// # Returns
+    ///
+    /// The matching [`AuthCodeRecord`], or `None` if the guess is wrong or
+    /// the user has no valid code.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a query fails.
+    pub async fn verify_code_for_user(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        code: &str,
+        code_type: AuthCodeType,
+    ) -> ApiResult<Option<AuthCodeRecord>> {
+        let reserved = sqlx::query_as!(
+            ReservedAuthCodeRecord,
+            r#"
+        UPDATE auth_codes
+        SET failed_attempts = failed_attempts + 1
+        WHERE user_id = $1
+          AND code_type = $2
+          AND used = FALSE
+          AND expires_at > NOW()
+          AND failed_attempts < $3
+        RETURNING id, user_id, code, code_type AS "code_type: AuthCodeType", new_email,
+                  expires_at, used, failed_attempts
+        "#,
+            user_id,
+            code_type as AuthCodeType,
+            MAX_FAILED_ATTEMPTS,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Self::check_guess(pool, reserved, code).await
+    }
+
+    /// Matches a guess against the codes it reserved an attempt on.
+    ///
+    /// Only reserved codes are compared, so codes already at
+    /// [`MAX_FAILED_ATTEMPTS`] are never matched. A right guess gives the
+    /// reserved attempts back; a wrong one keeps them and marks codes that
+    /// reached the limit used so a new code must be requested.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `reserved` — The codes the guess reserved an attempt on.
+    /// * `code` — The guessed authorization code.
+    ///
+    /// # Returns
+    ///
+    /// The matching [`AuthCodeRecord`], or `None` if no reserved code
+    /// matches.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if settling the reserved attempts fails.
+    async fn check_guess(
+        pool: &Pool<Postgres>,
+        reserved: Vec<ReservedAuthCodeRecord>,
+        code: &str,
+    ) -> ApiResult<Option<AuthCodeRecord>> {
+        let settlement = Self::settle_guess(reserved, code);
+
+        if !settlement.refund.is_empty() {
+            sqlx::query!(
+                r#"
+            UPDATE auth_codes
+            SET failed_attempts = failed_attempts - 1
+            WHERE id = ANY($1)
+            "#,
+                &settlement.refund,
+            )
+            .execute(pool)
+            .await?;
+        }
+
+        if !settlement.lock.is_empty() {
+            sqlx::query!(
+                r#"
+            UPDATE auth_codes
+            SET used = TRUE
+            WHERE id = ANY($1)
+            "#,
+                &settlement.lock,
+            )
+            .execute(pool)
+            .await?;
+        }
+
+        Ok(settlement.matched)
+    }
+
+    /// Compares a guess against reserved codes and decides how to settle
+    /// their attempts.
+    ///
+    /// # Arguments
+    ///
+    /// * `reserved` — The codes the guess reserved an attempt on.
+    /// * `code` — The guessed authorization code.
+    ///
+    /// # Returns
+    ///
+    /// A [`GuessSettlement`] refunding every reservation on a match, or
+    /// locking the codes that reached [`MAX_FAILED_ATTEMPTS`] otherwise.
+    fn settle_guess(reserved: Vec<ReservedAuthCodeRecord>, code: &str) -> GuessSettlement {
+        let ids: Vec<Uuid> = reserved.iter().map(|record| record.id).collect();
+        let lock = reserved
+            .iter()
+            .filter(|record| record.failed_attempts >= MAX_FAILED_ATTEMPTS)
+            .map(|record| record.id)
+            .collect();
+
+        match code::find_match(reserved, |record| &record.code, code) {
+            Some(matched) => GuessSettlement {
+                matched: Some(matched.into()),
+                refund: ids,
+                lock: Vec::new(),
+            },
+            None => GuessSettlement {
+                matched: None,
+                refund: Vec::new(),
+                lock,
+            },
+        }
     }
 
     /// Marks an authorization code as used.
@@ -158,4 +413,165 @@ impl AuthCodeRepo {
 
         Ok(())
     }
+
+    /// Returns when the user was last sent a code of the given type.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user the codes belong to.
+    /// * `code_type` — The code category.
+    ///
+    /// # Returns
+    ///
+    /// The creation time of the user's newest code of the type, or `None`
+    /// if the user has never been sent one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn last_issued_at(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        code_type: AuthCodeType,
+    ) -> ApiResult<Option<DateTime<Utc>>> {
+        let issued_at = sqlx::query_scalar!(
+            r#"
+        SELECT MAX(created_at)
+        FROM auth_codes
+        WHERE user_id = $1
+          AND code_type = $2
+        "#,
+            user_id,
+            code_type as AuthCodeType,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(issued_at)
+    }
+
+    /// Marks a user's unused authorization codes of one type as used.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user whose codes to void.
+    /// * `code_type` — The code category to void.
+    ///
+    /// # Returns
+    ///
+    /// `()` on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn invalidate_type_for_user(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        code_type: AuthCodeType,
+    ) -> ApiResult<()> {
+        sqlx::query!(
+            r#"
+        UPDATE auth_codes
+        SET used = TRUE
+        WHERE user_id = $1
+          AND code_type = $2
+          AND used = FALSE
+        "#,
+            user_id,
+            code_type as AuthCodeType,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Marks all of a user's unused authorization codes as used.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user whose codes to void.
+    ///
+    /// # Returns
+    ///
+    /// `()` on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn invalidate_for_user(pool: &Pool<Postgres>, user_id: Uuid) -> ApiResult<()> {
+        sqlx::query!(
+            r#"
+        UPDATE auth_codes
+        SET used = TRUE
+        WHERE user_id = $1
+          AND used = FALSE
+        "#,
+            user_id,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reserved(code: &str, failed_attempts: i32) -> ReservedAuthCodeRecord {
+        ReservedAuthCodeRecord {
+            id: Uuid::new_v4(),
+            user_id: Uuid::nil(),
+            code: code.to_string(),
+            code_type: AuthCodeType::PasswordReset,
+            new_email: None,
+            expires_at: Utc::now(),
+            used: false,
+            failed_attempts,
+        }
+    }
+
+    #[test]
+    fn right_guess_gives_back_every_reserved_attempt() {
+        let codes = vec![
+            reserved("111111", 1),
+            reserved("222222", MAX_FAILED_ATTEMPTS),
+        ];
+        let ids: Vec<Uuid> = codes.iter().map(|code| code.id).collect();
+
+        let settlement = AuthCodeRepo::settle_guess(codes, "222222");
+
+        assert_eq!(settlement.matched.map(|code| code.id), Some(ids[1]));
+        assert_eq!(settlement.refund, ids);
+        assert!(settlement.lock.is_empty());
+    }
+
+    #[test]
+    fn wrong_guess_keeps_the_attempt_and_locks_codes_at_the_limit() {
+        let below = reserved("111111", MAX_FAILED_ATTEMPTS - 1);
+        let at_limit = reserved("222222", MAX_FAILED_ATTEMPTS);
+        let at_limit_id = at_limit.id;
+
+        let settlement = AuthCodeRepo::settle_guess(vec![below, at_limit], "333333");
+
+        assert!(settlement.matched.is_none());
+        assert!(settlement.refund.is_empty());
+        assert_eq!(settlement.lock, vec![at_limit_id]);
+    }
+
+    #[test]
+    fn locked_out_codes_are_never_compared() {
+        // Codes at the limit are not reserved, so even the right code is
+        // rejected once every attempt has been used.
+        let settlement = AuthCodeRepo::settle_guess(Vec::new(), "111111");
+
+        assert!(settlement.matched.is_none());
+        assert!(settlement.refund.is_empty());
+        assert!(settlement.lock.is_empty());
+    }
 }
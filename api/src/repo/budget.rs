@@ -0,0 +1,435 @@
+//! Budget database operations.
+//!
+//! Provides [`BudgetRepo`] for creating and deleting monthly budgets,
+//! measuring their current-month consumption, and tracking threshold
+//! alerts in the `budgets` and `budget_alerts` tables. An alert is claimed
+//! before its email is sent and only marked sent once the email goes out,
+//! so a failed send is retried on a later sweep.
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use sqlx::{FromRow, Pool, Postgres};
+use uuid::Uuid;
+
+use gig_log_common::models::budget::{Budget, BudgetStatus, BudgetType};
+
+use crate::core::error::ApiResult;
+
+/// What a budget row limits.
+///
+/// Maps to the PostgreSQL `budget_type` enum.
+#[derive(Debug, Clone, sqlx::Type)]
+#[sqlx(type_name = "budget_type", rename_all = "snake_case")]
+pub enum BudgetTypeRecord {
+    /// Limits hours worked per month.
+    Hours,
+    /// Limits billable dollars earned per month.
+    Amount,
+}
+
+impl From<BudgetType> for BudgetTypeRecord {
+    fn from(budget_type: BudgetType) -> Self {
+        match budget_type {
+            BudgetType::Hours => BudgetTypeRecord::Hours,
+            BudgetType::Amount => BudgetTypeRecord::Amount,
+        }
+    }
+}
+
+impl From<BudgetTypeRecord> for BudgetType {
+    fn from(budget_type: BudgetTypeRecord) -> Self {
+        match budget_type {
+            BudgetTypeRecord::Hours => BudgetType::Hours,
+            BudgetTypeRecord::Amount => BudgetType::Amount,
+        }
+    }
+}
+
+/// A budget row joined with its current-month consumption.
+#[derive(Debug, FromRow)]
+pub struct BudgetStatusRecord {
+    /// Unique identifier for the budget.
+    pub id: Uuid,
+    /// The user who owns the budget.
+    pub user_id: Uuid,
+    /// The budgeted company, if any.
+    pub company_id: Option<Uuid>,
+    /// The budgeted job, if any.
+    pub job_id: Option<Uuid>,
+    /// What the budget limits.
+    pub budget_type: BudgetTypeRecord,
+    /// Monthly limit in hours or dollars.
+    pub monthly_limit: f64,
+    /// When the budget was created.
+    pub created_at: DateTime<Utc>,
+    /// When the budget was last updated.
+    pub updated_at: DateTime<Utc>,
+    /// Seconds worked this month on the budgeted company or job.
+    pub consumed_seconds: i64,
+    /// Billable dollars earned this month, at the rate each session was
+    /// completed at or the job's current rate for sessions in progress.
+    pub consumed_amount: f64,
+}
+
+impl From<BudgetStatusRecord> for BudgetStatus {
+    fn from(record: BudgetStatusRecord) -> Self {
+        let budget_type: BudgetType = record.budget_type.into();
+        let consumed = match budget_type {
+            BudgetType::Hours => record.consumed_seconds as f64 / 3600.0,
+            BudgetType::Amount => record.consumed_amount,
+        };
+        let percent_used = consumed / record.monthly_limit * 100.0;
+
+        Self {
+            budget: Budget {
+                id: record.id,
+                user_id: record.user_id,
+                company_id: record.company_id,
+                job_id: record.job_id,
+                budget_type,
+                monthly_limit: record.monthly_limit,
+                created_at: record.created_at,
+                updated_at: record.updated_at,
+            },
+            consumed: (consumed * 100.0).round() / 100.0,
+            percent_used: (percent_used * 10.0).round() / 10.0,
+        }
+    }
+}
+
+/// Repository for budget database operations.
+pub struct BudgetRepo;
+
+impl BudgetRepo {
+    /// Inserts a new budget.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the budget.
+    /// * `company_id` — The budgeted company, if targeting a company.
+    /// * `job_id` — The budgeted job, if targeting a job.
+    /// * `budget_type` — What the budget limits.
+    /// * `monthly_limit` — Monthly limit in hours or dollars.
+    ///
+    /// # Returns
+    ///
+    /// The UUID of the newly created budget.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert query fails.
+    pub async fn insert_budget(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        company_id: Option<Uuid>,
+        job_id: Option<Uuid>,
+        budget_type: BudgetType,
+        monthly_limit: f64,
+    ) -> ApiResult<Uuid> {
+        let id = sqlx::query_scalar!(
+            r#"
+        INSERT INTO budgets (user_id, company_id, job_id, budget_type, monthly_limit)
+        VALUES ($1, $2, $3, $4, $5::FLOAT8)
+        RETURNING id
+        "#,
+            user_id,
+            company_id,
+            job_id,
+            BudgetTypeRecord::from(budget_type) as BudgetTypeRecord,
+            monthly_limit,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(id)
+    }
+
+    /// Lists budgets with their current-month consumption.
+    ///
+    /// Every filter is optional; passing `None` for all of them returns
+    /// every budget in the system, which is used by the alert sweeper.
+    /// Consumption counts sessions started since the beginning of the
+    /// current month, measuring running sessions up to now (or up to the
+    /// pause point when paused).
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — Restrict to budgets owned by this user.
+    /// * `company_id` — Restrict to budgets on this company.
+    /// * `job_id` — Restrict to budgets on this job.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`BudgetStatusRecord`] values ordered by creation time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_statuses(
+        pool: &Pool<Postgres>,
+        user_id: Option<Uuid>,
+        company_id: Option<Uuid>,
+        job_id: Option<Uuid>,
+    ) -> ApiResult<Vec<BudgetStatusRecord>> {
+        let records = sqlx::query_as!(
+            BudgetStatusRecord,
+            r#"
+        SELECT b.id, b.user_id, b.company_id, b.job_id,
+               b.budget_type AS "budget_type: BudgetTypeRecord",
+               b.monthly_limit::FLOAT8 AS "monthly_limit!",
+               b.created_at, b.updated_at,
+               COALESCE(SUM(s.net_seconds), 0)::BIGINT AS "consumed_seconds!",
+               COALESCE(SUM(s.net_seconds / 3600.0 * s.hourly_rate), 0)::FLOAT8
+                   AS "consumed_amount!"
+        FROM budgets b
+        LEFT JOIN (
+            SELECT ws.job_id, j.company_id, COALESCE(ws.hourly_rate, j.hourly_rate) AS hourly_rate,
+                   EXTRACT(EPOCH FROM (
+                       COALESCE(ws.end_time, ws.paused_at, NOW())
+                       - ws.start_time - ws.accumulated_paused_duration
+                   )) AS net_seconds
+            FROM work_sessions ws
+            JOIN jobs j ON j.id = ws.job_id
+            WHERE ws.start_time >= date_trunc('month', NOW())
+        ) s ON s.job_id = b.job_id OR s.company_id = b.company_id
+        WHERE ($1::UUID IS NULL OR b.user_id = $1)
+          AND ($2::UUID IS NULL OR b.company_id = $2)
+          AND ($3::UUID IS NULL OR b.job_id = $3)
+        GROUP BY b.id
+        ORDER BY b.created_at
+        "#,
+            user_id,
+            company_id,
+            job_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    /// Deletes a budget, scoped to the owning user.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the budget.
+    /// * `budget_id` — The budget's UUID.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a budget was deleted, `false` if none matched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete query fails.
+    pub async fn delete_budget(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        budget_id: Uuid,
+    ) -> ApiResult<bool> {
+        let result = sqlx::query!(
+            r#"
+        DELETE FROM budgets
+        WHERE id = $1 AND user_id = $2
+        "#,
+            budget_id,
+            user_id,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Claims a threshold alert for a budget this period so it can be sent.
+    ///
+    /// Uses the `(budget_id, period_month, threshold_percent)` unique key so
+    /// that each alert is sent at most once. An alert that was claimed but
+    /// never marked sent can be claimed again once `claim_timeout` has
+    /// passed, which covers sweeps that stopped before releasing it.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `budget_id` — The budget the alert is for.
+    /// * `period_month` — First day of the month the alert covers.
+    /// * `threshold_percent` — The threshold that was crossed.
+    /// * `claim_timeout` — How long an unsent claim blocks other sweeps.
+    ///
+    /// # Returns
+    ///
+    /// The alert's ID if it was claimed, or `None` if it was already sent
+    /// or is claimed by another sweep.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any query fails.
+    pub async fn claim_alert(
+        pool: &Pool<Postgres>,
+        budget_id: Uuid,
+        period_month: NaiveDate,
+        threshold_percent: i32,
+        claim_timeout: Duration,
+    ) -> ApiResult<Option<Uuid>> {
+        let mut tx = pool.begin().await?;
+
+        let existing = sqlx::query!(
+            r#"
+        SELECT id, sent_at, claimed_at
+        FROM budget_alerts
+        WHERE budget_id = $1 AND period_month = $2 AND threshold_percent = $3
+        FOR UPDATE
+        "#,
+            budget_id,
+            period_month,
+            threshold_percent,
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let alert_id = match existing {
+            None => {
+                sqlx::query_scalar!(
+                    r#"
+        INSERT INTO budget_alerts (budget_id, period_month, threshold_percent)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (budget_id, period_month, threshold_percent) DO NOTHING
+        RETURNING id
+        "#,
+                    budget_id,
+                    period_month,
+                    threshold_percent,
+                )
+                .fetch_optional(&mut *tx)
+                .await?
+            }
+            Some(alert)
+                if Self::is_claimable(
+                    alert.sent_at,
+                    alert.claimed_at,
+                    Utc::now(),
+                    claim_timeout,
+                ) =>
+            {
+                sqlx::query!(
+                    "UPDATE budget_alerts SET claimed_at = now() WHERE id = $1",
+                    alert.id,
+                )
+                .execute(&mut *tx)
+                .await?;
+
+                Some(alert.id)
+            }
+            Some(_) => None,
+        };
+
+        tx.commit().await?;
+
+        Ok(alert_id)
+    }
+
+    /// Marks a claimed threshold alert as sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `alert_id` — The alert returned by [`claim_alert`](Self::claim_alert).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn mark_alert_sent(pool: &Pool<Postgres>, alert_id: Uuid) -> ApiResult<()> {
+        sqlx::query!(
+            "UPDATE budget_alerts SET sent_at = now() WHERE id = $1",
+            alert_id,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Releases a claimed threshold alert whose email could not be sent, so
+    /// the next sweep retries it.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `alert_id` — The alert returned by [`claim_alert`](Self::claim_alert).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete query fails.
+    pub async fn release_alert(pool: &Pool<Postgres>, alert_id: Uuid) -> ApiResult<()> {
+        sqlx::query!(
+            "DELETE FROM budget_alerts WHERE id = $1 AND sent_at IS NULL",
+            alert_id,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Decides whether an existing alert row can be claimed for sending.
+    ///
+    /// # Arguments
+    ///
+    /// * `sent_at` — When the alert email was sent, if it was.
+    /// * `claimed_at` — When the alert was last claimed.
+    /// * `now` — The current time.
+    /// * `claim_timeout` — How long an unsent claim blocks other sweeps.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the alert is unsent and its last claim has expired.
+    fn is_claimable(
+        sent_at: Option<DateTime<Utc>>,
+        claimed_at: DateTime<Utc>,
+        now: DateTime<Utc>,
+        claim_timeout: Duration,
+    ) -> bool {
+        sent_at.is_none() && now - claimed_at >= claim_timeout
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn never_claims_a_sent_alert() {
+        let claimed_at = Utc.with_ymd_and_hms(2026, 10, 1, 9, 0, 0).unwrap();
+        let now = claimed_at + Duration::days(3);
+
+        assert!(!BudgetRepo::is_claimable(
+            Some(claimed_at),
+            claimed_at,
+            now,
+            Duration::minutes(10)
+        ));
+    }
+
+    #[test]
+    fn reclaims_an_unsent_alert_once_its_claim_expires() {
+        let claimed_at = Utc.with_ymd_and_hms(2026, 10, 1, 9, 0, 0).unwrap();
+        let timeout = Duration::minutes(10);
+
+        assert!(!BudgetRepo::is_claimable(
+            None,
+            claimed_at,
+            claimed_at + Duration::minutes(9),
+            timeout
+        ));
+        assert!(BudgetRepo::is_claimable(
+            None,
+            claimed_at,
+            claimed_at + timeout,
+            timeout
+        ));
+    }
+}
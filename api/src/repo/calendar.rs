@@ -0,0 +1,301 @@
+//! Calendar feed database operations.
+//!
+//! Provides [`CalendarFeedRepo`] for creating, listing, revoking, and
+//! authenticating calendar feed tokens stored in the `calendar_feeds`
+//! table, and for loading the work sessions and payouts a feed lists.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use gig_log_common::models::calendar::CalendarFeed;
+use sqlx::{FromRow, Pool, Postgres};
+use uuid::Uuid;
+
+use crate::core::error::ApiResult;
+use crate::repo::payment::PayoutTypeRecord;
+
+/// A row from the `calendar_feeds` table, without its hash.
+#[derive(Debug, FromRow)]
+struct CalendarFeedRecord {
+    /// Unique identifier for the feed.
+    id: Uuid,
+    /// Label describing where the feed is subscribed.
+    name: String,
+    /// When a calendar app last fetched the feed.
+    last_used_at: Option<DateTime<Utc>>,
+    /// Timestamp when the feed was created.
+    created_at: DateTime<Utc>,
+}
+
+impl From<CalendarFeedRecord> for CalendarFeed {
+    fn from(record: CalendarFeedRecord) -> Self {
+        Self {
+            id: record.id,
+            name: record.name,
+            last_used_at: record.last_used_at,
+            created_at: record.created_at,
+        }
+    }
+}
+
+/// A completed work session shown as a calendar event.
+#[derive(Debug, Clone, FromRow)]
+pub struct CalendarSessionRecord {
+    /// Unique identifier for the work session.
+    pub id: Uuid,
+    /// Title of the job the session was tracked for.
+    pub job_title: String,
+    /// Name of the company the job belongs to.
+    pub company_name: String,
+    /// When the session started.
+    pub start_time: DateTime<Utc>,
+    /// When the session ended.
+    pub end_time: DateTime<Utc>,
+    /// When the session was last modified.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A payment with an expected payout date, shown as an all-day event.
+#[derive(Debug, Clone, FromRow)]
+pub struct CalendarPayoutRecord {
+    /// Unique identifier for the payment.
+    pub id: Uuid,
+    /// Name of the company paying.
+    pub company_name: String,
+    /// Total amount of the payment in dollars.
+    pub total: f64,
+    /// How the payment is paid out.
+    pub payout_type: PayoutTypeRecord,
+    /// The date the payout is expected.
+    pub expected_payout_date: NaiveDate,
+    /// Whether the transfer has been initiated.
+    pub transfer_initiated: bool,
+    /// Whether the payment has been received.
+    pub payment_received: bool,
+    /// When the payment was last modified.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Repository for calendar feed database operations.
+pub struct CalendarFeedRepo;
+
+impl CalendarFeedRepo {
+    /// Inserts a new calendar feed.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user the feed belongs to.
+    /// * `name` — Label describing where the feed is subscribed.
+    /// * `token_hash` — The SHA-256 hash of the raw feed token.
+    ///
+    /// # Returns
+    ///
+    /// The created [`CalendarFeed`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert query fails.
+    pub async fn insert_feed(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        name: &str,
+        token_hash: &str,
+    ) -> ApiResult<CalendarFeed> {
+        let record = sqlx::query_as!(
+            CalendarFeedRecord,
+            r#"
+        INSERT INTO calendar_feeds (user_id, name, token_hash)
+        VALUES ($1, $2, $3)
+        RETURNING id, name, last_used_at, created_at
+        "#,
+            user_id,
+            name,
+            token_hash,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record.into())
+    }
+
+    /// Lists a user's calendar feeds, newest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user whose feeds to list.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`CalendarFeed`] values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_feeds(pool: &Pool<Postgres>, user_id: Uuid) -> ApiResult<Vec<CalendarFeed>> {
+        let records = sqlx::query_as!(
+            CalendarFeedRecord,
+            r#"
+        SELECT id, name, last_used_at, created_at
+        FROM calendar_feeds
+        WHERE user_id = $1
+        ORDER BY created_at DESC
+        "#,
+            user_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records.into_iter().map(Into::into).collect())
+    }
+
+    /// Deletes one of a user's calendar feeds.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user the feed belongs to.
+    /// * `feed_id` — The feed's UUID.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a feed was deleted, `false` if none matched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete query fails.
+    pub async fn delete_feed(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        feed_id: Uuid,
+    ) -> ApiResult<bool> {
+        let result = sqlx::query!(
+            r#"
+        DELETE FROM calendar_feeds
+        WHERE id = $1 AND user_id = $2
+        "#,
+            feed_id,
+            user_id,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Looks up a feed by token hash and records that it was fetched.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `token_hash` — The SHA-256 hash of the raw feed token.
+    ///
+    /// # Returns
+    ///
+    /// The UUID of the user the feed belongs to, or `None` if no feed
+    /// matches.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn use_token(pool: &Pool<Postgres>, token_hash: &str) -> ApiResult<Option<Uuid>> {
+        let user_id = sqlx::query_scalar!(
+            r#"
+        UPDATE calendar_feeds
+        SET last_used_at = now()
+        WHERE token_hash = $1
+        RETURNING user_id
+        "#,
+            token_hash,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(user_id)
+    }
+
+    /// Lists a user's completed work sessions that started after a cutoff.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user who owns the sessions.
+    /// * `since` — Sessions starting before this are omitted.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`CalendarSessionRecord`] values ordered by start time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_sessions(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        since: DateTime<Utc>,
+    ) -> ApiResult<Vec<CalendarSessionRecord>> {
+        let sessions = sqlx::query_as!(
+            CalendarSessionRecord,
+            r#"
+        SELECT ws.id, j.title AS job_title, c.name AS company_name, ws.start_time,
+               ws.end_time AS "end_time!", ws.updated_at
+        FROM work_sessions ws
+        JOIN jobs j ON j.id = ws.job_id
+        JOIN companies c ON c.id = j.company_id
+        WHERE ws.user_id = $1
+          AND ws.is_running = FALSE
+          AND ws.end_time IS NOT NULL
+          AND ws.start_time >= $2
+        ORDER BY ws.start_time
+        "#,
+            user_id,
+            since,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(sessions)
+    }
+
+    /// Lists a user's payments expected to pay out on or after a date.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user who owns the payments.
+    /// * `since` — Payouts expected before this date are omitted.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`CalendarPayoutRecord`] values ordered by expected
+    /// payout date.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_payouts(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        since: NaiveDate,
+    ) -> ApiResult<Vec<CalendarPayoutRecord>> {
+        let payouts = sqlx::query_as!(
+            CalendarPayoutRecord,
+            r#"
+        SELECT p.id, c.name AS company_name, p.total::FLOAT8 AS "total!",
+               p.payout_type AS "payout_type: PayoutTypeRecord",
+               p.expected_payout_date AS "expected_payout_date!", p.transfer_initiated,
+               p.payment_received, p.updated_at
+        FROM payments p
+        JOIN companies c ON c.id = p.company_id
+        WHERE p.user_id = $1
+          AND p.expected_payout_date >= $2
+        ORDER BY p.expected_payout_date
+        "#,
+            user_id,
+            since,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(payouts)
+    }
+}
@@ -0,0 +1,747 @@
+//! Company database operations.
+//!
+//! Provides [`CompanyRepo`] for querying company records in the
+//! `companies` table. All queries are scoped to the owning user.
+//! Partial updates are merged with the stored row before validation. Each
+//! company's logo is stored alongside its row, already resized. Companies
+//! can be duplicated together with their jobs' payment terms, and deleting
+//! a company with dependent records must be confirmed, with a separate
+//! confirmation for finalized invoices and approved work. Companies with
+//! invoice auto-drafting enabled are claimed once a week for a draft.
+
+use chrono::NaiveDate;
+use sqlx::{FromRow, PgExecutor, Pool, Postgres};
+use uuid::Uuid;
+
+use gig_log_common::{
+    models::{
+        company::{
+            Company, CompanyDeletePreview, DeleteCompanyQuery, DuplicateCompanyResponse,
+            UpdateCompanyRequest,
+        },
+        job::Job,
+    },
+    validators::company::validate_tax_withholding,
+};
+
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::repo::{
+    job::{JobBoardColumnRecord, JobRecord, PaymentTypeRecord},
+    onboarding_state::{OnboardingEvent, OnboardingStateRepo},
+};
+
+/// A company whose weekly invoice draft has just been claimed for creation.
+#[derive(Debug, FromRow)]
+pub struct DueInvoiceDraftRecord {
+    /// The company's UUID.
+    pub id: Uuid,
+    /// The user who owns the company.
+    pub user_id: Uuid,
+    /// Name of the company.
+    pub name: String,
+    /// Email address of the company owner.
+    pub email: String,
+    /// Language tag of the company owner's preferred email locale.
+    pub locale: String,
+}
+
+/// Repository for company database operations.
+pub struct CompanyRepo;
+
+impl CompanyRepo {
+    /// Finds a company by ID, scoped to the owning user.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the company.
+    /// * `company_id` — The company's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The [`Company`] matching the given ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no company with the given ID exists for the user.
+    pub async fn find_company_by_id(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        company_id: Uuid,
+    ) -> ApiResult<Company> {
+        let company = sqlx::query_as!(
+            Company,
+            r#"
+        SELECT id, user_id, name, requires_tax_withholdings,
+               tax_withholding_rate::FLOAT8 AS tax_withholding_rate, accent_color,
+               auto_draft_invoices,
+               '/companies/' || id || '/logo?v=' || (EXTRACT(EPOCH FROM logo_uploaded_at) * 1000)::BIGINT AS logo_url,
+               created_at, updated_at
+        FROM companies
+        WHERE id = $1 AND user_id = $2
+        "#,
+            company_id,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(company)
+    }
+
+    /// Applies a partial update to a company.
+    ///
+    /// Omitted fields keep their stored values. The merged tax withholding
+    /// fields are validated before the row is written.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the company.
+    /// * `company_id` — The company's UUID.
+    /// * `request` — The fields to change.
+    ///
+    /// # Returns
+    ///
+    /// The updated [`Company`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`](crate::core::error::ApiErrorResponse::NotFound)
+    /// if no company with the given ID exists for the user, or
+    /// [`ApiErrorResponse::Validation`](crate::core::error::ApiErrorResponse::Validation)
+    /// if the merged company is invalid.
+    pub async fn update_company(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        company_id: Uuid,
+        request: &UpdateCompanyRequest,
+    ) -> ApiResult<Company> {
+        let mut tx = pool.begin().await?;
+
+        let company = sqlx::query_as!(
+            Company,
+            r#"
+        SELECT id, user_id, name, requires_tax_withholdings,
+               tax_withholding_rate::FLOAT8 AS tax_withholding_rate, accent_color,
+               auto_draft_invoices,
+               '/companies/' || id || '/logo?v=' || (EXTRACT(EPOCH FROM logo_uploaded_at) * 1000)::BIGINT AS logo_url,
+               created_at, updated_at
+        FROM companies
+        WHERE id = $1 AND user_id = $2
+        FOR UPDATE
+        "#,
+            company_id,
+            user_id,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let name = request.name.clone().unwrap_or(company.name);
+        let requires_tax_withholdings = request
+            .requires_tax_withholdings
+            .unwrap_or(company.requires_tax_withholdings);
+        let tax_withholding_rate = request
+            .tax_withholding_rate
+            .unwrap_or(company.tax_withholding_rate);
+        let accent_color = request.accent_color.clone().unwrap_or(company.accent_color);
+        let auto_draft_invoices = request
+            .auto_draft_invoices
+            .unwrap_or(company.auto_draft_invoices);
+
+        validate_tax_withholding(requires_tax_withholdings, tax_withholding_rate)?;
+
+        let company = sqlx::query_as!(
+            Company,
+            r#"
+        UPDATE companies
+        SET name = $3,
+            requires_tax_withholdings = $4,
+            tax_withholding_rate = $5::FLOAT8::DECIMAL,
+            accent_color = $6,
+            auto_draft_invoices = $7,
+            updated_at = now()
+        WHERE id = $1 AND user_id = $2
+        RETURNING id, user_id, name, requires_tax_withholdings,
+                  tax_withholding_rate::FLOAT8 AS tax_withholding_rate, accent_color,
+                  auto_draft_invoices,
+                  '/companies/' || id || '/logo?v=' || (EXTRACT(EPOCH FROM logo_uploaded_at) * 1000)::BIGINT AS logo_url,
+                  created_at, updated_at
+        "#,
+            company_id,
+            user_id,
+            name,
+            requires_tax_withholdings,
+            tax_withholding_rate,
+            accent_color,
+            auto_draft_invoices,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(company)
+    }
+
+    /// Creates a copy of a company along with copies of its jobs.
+    ///
+    /// Runs in a single transaction. The copy keeps the company's tax
+    /// withholding settings, accent color, and logo, and its name gets a
+    /// ` (copy)` suffix. Each job is copied with its payment terms and
+    /// appended to the active board column. Contacts, contracts, budgets,
+    /// payments, and work history are not copied.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the company.
+    /// * `company_id` — The UUID of the company to copy.
+    ///
+    /// # Returns
+    ///
+    /// A [`DuplicateCompanyResponse`] with the new company and its jobs.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`](crate::core::error::ApiErrorResponse::NotFound)
+    /// if no company with the given ID exists for the user, or an error if
+    /// any query fails.
+    pub async fn duplicate_company(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        company_id: Uuid,
+    ) -> ApiResult<DuplicateCompanyResponse> {
+        let mut tx = pool.begin().await?;
+
+        let company = sqlx::query_as!(
+            Company,
+            r#"
+        INSERT INTO companies (user_id, name, requires_tax_withholdings, tax_withholding_rate,
+                               accent_color, auto_draft_invoices, logo_data, logo_uploaded_at)
+        SELECT user_id, LEFT(name, 248) || ' (copy)', requires_tax_withholdings,
+               tax_withholding_rate, accent_color, auto_draft_invoices, logo_data,
+               CASE WHEN logo_data IS NULL THEN NULL ELSE now() END
+        FROM companies
+        WHERE id = $1 AND user_id = $2
+        RETURNING id, user_id, name, requires_tax_withholdings,
+                  tax_withholding_rate::FLOAT8 AS tax_withholding_rate, accent_color,
+                  auto_draft_invoices,
+                  '/companies/' || id || '/logo?v=' || (EXTRACT(EPOCH FROM logo_uploaded_at) * 1000)::BIGINT AS logo_url,
+                  created_at, updated_at
+        "#,
+            company_id,
+            user_id,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let records = sqlx::query_as!(
+            JobRecord,
+            r#"
+        INSERT INTO jobs (company_id, user_id, title, payment_type, hourly_rate,
+                          number_of_payouts, payout_amount, board_position)
+        SELECT $3, user_id, title, payment_type, hourly_rate, number_of_payouts, payout_amount,
+               ((SELECT COALESCE(MAX(board_position) + 1, 0)
+                 FROM jobs
+                 WHERE user_id = $2 AND board_column = 'active')
+                + ROW_NUMBER() OVER (ORDER BY title, created_at) - 1)::INT
+        FROM jobs
+        WHERE company_id = $1 AND user_id = $2
+        RETURNING id, company_id, user_id, title, payment_type AS "payment_type: PaymentTypeRecord",
+                  hourly_rate::FLOAT8 AS hourly_rate, number_of_payouts,
+                  payout_amount::FLOAT8 AS payout_amount,
+                  board_column AS "board_column: JobBoardColumnRecord", board_position,
+                  created_at, updated_at
+        "#,
+            company_id,
+            user_id,
+            company.id,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        OnboardingStateRepo::record(&mut *tx, user_id, OnboardingEvent::CompanyCreated).await?;
+
+        tx.commit().await?;
+
+        let mut jobs: Vec<Job> = records.into_iter().map(Into::into).collect();
+        jobs.sort_by_key(|job| job.board_position);
+
+        Ok(DuplicateCompanyResponse { company, jobs })
+    }
+
+    /// Counts the records that deleting a company would remove or detach.
+    ///
+    /// # Arguments
+    ///
+    /// * `executor` — The connection or transaction to query with.
+    /// * `user_id` — The UUID of the user who owns the company.
+    /// * `company_id` — The company's UUID.
+    ///
+    /// # Returns
+    ///
+    /// A [`CompanyDeletePreview`] with every record that cascades from the
+    /// company and the mileage entries that would lose their job.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no company with the given ID exists for the user.
+    pub async fn delete_preview<'e>(
+        executor: impl PgExecutor<'e>,
+        user_id: Uuid,
+        company_id: Uuid,
+    ) -> ApiResult<CompanyDeletePreview> {
+        let preview = sqlx::query_as!(
+            CompanyDeletePreview,
+            r#"
+        SELECT (SELECT COUNT(*) FROM jobs j WHERE j.company_id = c.id) AS "jobs!",
+               (SELECT COUNT(*) FROM payments p WHERE p.company_id = c.id) AS "payments!",
+               (SELECT COUNT(*)
+                FROM work_sessions ws
+                JOIN jobs j ON j.id = ws.job_id
+                WHERE j.company_id = c.id) AS "work_sessions!",
+               (SELECT COUNT(*)
+                FROM work_sessions ws
+                JOIN jobs j ON j.id = ws.job_id
+                WHERE j.company_id = c.id
+                  AND (ws.invoice_id IS NOT NULL OR ws.approval_id IS NOT NULL))
+                   AS "locked_work_sessions!",
+               (SELECT COUNT(*) FROM invoices i WHERE i.company_id = c.id) AS "invoices!",
+               (SELECT COUNT(*)
+                FROM invoices i
+                WHERE i.company_id = c.id AND i.status = 'finalized') AS "finalized_invoices!",
+               (SELECT COUNT(*)
+                FROM timesheet_approvals ta
+                WHERE ta.company_id = c.id) AS "timesheet_approvals!",
+               (SELECT COUNT(*) FROM budgets b WHERE b.company_id = c.id) AS "budgets!",
+               (SELECT COUNT(*) FROM contracts ct WHERE ct.company_id = c.id) AS "contracts!",
+               (SELECT COUNT(*)
+                FROM company_statements cs
+                WHERE cs.company_id = c.id) AS "company_statements!",
+               (SELECT COUNT(*) FROM contacts co WHERE co.company_id = c.id) AS "contacts!",
+               (SELECT COUNT(*)
+                FROM recurring_payments rp
+                WHERE rp.company_id = c.id) AS "recurring_payments!",
+               (SELECT COUNT(*)
+                FROM mileage_entries m
+                JOIN jobs j ON j.id = m.job_id
+                WHERE j.company_id = c.id) AS "mileage_entries!"
+        FROM companies c
+        WHERE c.id = $1 AND c.user_id = $2
+        "#,
+            company_id,
+            user_id,
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(preview)
+    }
+
+    /// Deletes a company and everything that belongs to it.
+    ///
+    /// Runs in a single transaction: locks the company, counts the records
+    /// the deletion affects, and refuses to delete unless
+    /// [`check_deletable`](Self::check_deletable) allows it. Every record
+    /// counted by [`CompanyDeletePreview`] is deleted with the company
+    /// except mileage entries, which are kept without their job.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the company.
+    /// * `company_id` — The company's UUID.
+    /// * `query` — The caller's [`DeleteCompanyQuery`] confirmations.
+    ///
+    /// # Returns
+    ///
+    /// `()` on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if no company with the given
+    /// ID exists for the user, or [`ApiErrorResponse::BadRequest`] if the
+    /// deletion was not confirmed.
+    pub async fn delete_company(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        company_id: Uuid,
+        query: &DeleteCompanyQuery,
+    ) -> ApiResult<()> {
+        let mut tx = pool.begin().await?;
+
+        sqlx::query_scalar!(
+            r#"
+        SELECT id
+        FROM companies
+        WHERE id = $1 AND user_id = $2
+        FOR UPDATE
+        "#,
+            company_id,
+            user_id,
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| ApiErrorResponse::NotFound("Company not found".to_string()))?;
+
+        let preview = Self::delete_preview(&mut *tx, user_id, company_id).await?;
+
+        Self::check_deletable(&preview, query)?;
+
+        sqlx::query!(
+            r#"
+        DELETE FROM companies
+        WHERE id = $1 AND user_id = $2
+        "#,
+            company_id,
+            user_id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// Checks that a company deletion has the confirmations it needs.
+    ///
+    /// Any affected record requires `confirm`. Finalized invoices and work
+    /// sessions that are invoiced or approved by the client also require
+    /// `confirm_finalized`, since they record billed or agreed work.
+    ///
+    /// # Arguments
+    ///
+    /// * `preview` — The records the deletion affects.
+    /// * `query` — The caller's confirmations.
+    ///
+    /// # Returns
+    ///
+    /// An empty [`ApiResult`] if the deletion may go ahead.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] naming the affected records
+    /// if a required confirmation is missing.
+    fn check_deletable(
+        preview: &CompanyDeletePreview,
+        query: &DeleteCompanyQuery,
+    ) -> ApiResult<()> {
+        if !query.confirm && !preview.is_empty() {
+            let deleted = [
+                (preview.jobs, "jobs"),
+                (preview.payments, "payments"),
+                (preview.work_sessions, "work sessions"),
+                (preview.invoices, "invoices"),
+                (preview.timesheet_approvals, "timesheet approvals"),
+                (preview.budgets, "budgets"),
+                (preview.contracts, "contracts"),
+                (preview.company_statements, "statements"),
+                (preview.contacts, "contacts"),
+                (preview.recurring_payments, "recurring payments"),
+            ]
+            .into_iter()
+            .filter(|(count, _)| *count > 0)
+            .map(|(count, label)| format!("{count} {label}"))
+            .collect::<Vec<_>>();
+
+            let mut effects = Vec::new();
+            if !deleted.is_empty() {
+                effects.push(format!("deletes {}", deleted.join(", ")));
+            }
+            if preview.mileage_entries > 0 {
+                effects.push(format!(
+                    "detaches {} mileage entries",
+                    preview.mileage_entries
+                ));
+            }
+
+            return Err(ApiErrorResponse::BadRequest(format!(
+                "Deleting this company also {}. Pass confirm=true to delete it",
+                effects.join(" and "),
+            )));
+        }
+
+        if !query.confirm_finalized && preview.has_finalized_records() {
+            return Err(ApiErrorResponse::BadRequest(format!(
+                "This company has {} finalized invoices and {} invoiced or approved work \
+                 sessions. Pass confirm_finalized=true to delete them",
+                preview.finalized_invoices, preview.locked_work_sessions,
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Claims the companies that are due a weekly invoice draft.
+    ///
+    /// Marks every company with invoice auto-drafting enabled that has not
+    /// been drafted for the given week, so each company is claimed at most
+    /// once per week even when several sweeps overlap.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `week_start` — The Monday starting the current week.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`DueInvoiceDraftRecord`] values to draft invoices for.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn claim_due_invoice_drafts(
+        pool: &Pool<Postgres>,
+        week_start: NaiveDate,
+    ) -> ApiResult<Vec<DueInvoiceDraftRecord>> {
+        let records = sqlx::query_as!(
+            DueInvoiceDraftRecord,
+            r#"
+        UPDATE companies c
+        SET last_invoice_draft_week = $1
+        FROM users u
+        WHERE u.id = c.user_id
+          AND c.auto_draft_invoices = TRUE
+          AND (c.last_invoice_draft_week IS NULL OR c.last_invoice_draft_week < $1)
+        RETURNING c.id, c.user_id, c.name, u.email, u.locale
+        "#,
+            week_start,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    /// Stores a company's logo, replacing any existing one.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the company.
+    /// * `company_id` — The company's UUID.
+    /// * `data` — The resized logo, encoded as a PNG.
+    ///
+    /// # Returns
+    ///
+    /// The updated [`Company`] with its new logo URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no company with the given ID exists for the user.
+    pub async fn set_logo(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        company_id: Uuid,
+        data: &[u8],
+    ) -> ApiResult<Company> {
+        let company = sqlx::query_as!(
+            Company,
+            r#"
+        UPDATE companies
+        SET logo_data = $3,
+            logo_uploaded_at = now(),
+            updated_at = now()
+        WHERE id = $1 AND user_id = $2
+        RETURNING id, user_id, name, requires_tax_withholdings,
+                  tax_withholding_rate::FLOAT8 AS tax_withholding_rate, accent_color,
+                  auto_draft_invoices,
+                  '/companies/' || id || '/logo?v=' || (EXTRACT(EPOCH FROM logo_uploaded_at) * 1000)::BIGINT AS logo_url,
+                  created_at, updated_at
+        "#,
+            company_id,
+            user_id,
+            data,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(company)
+    }
+
+    /// Loads a company's logo.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the company.
+    /// * `company_id` — The company's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The logo's PNG bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no company with the given ID exists for the user
+    /// or it has no logo.
+    pub async fn find_logo(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        company_id: Uuid,
+    ) -> ApiResult<Vec<u8>> {
+        let data = sqlx::query_scalar!(
+            r#"
+        SELECT logo_data AS "logo_data!"
+        FROM companies
+        WHERE id = $1 AND user_id = $2 AND logo_data IS NOT NULL
+        "#,
+            company_id,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(data)
+    }
+
+    /// Removes a company's logo.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the company.
+    /// * `company_id` — The company's UUID.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a logo was removed, `false` if the company does not exist
+    /// or has no logo.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn delete_logo(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        company_id: Uuid,
+    ) -> ApiResult<bool> {
+        let result = sqlx::query!(
+            r#"
+        UPDATE companies
+        SET logo_data = NULL,
+            logo_uploaded_at = NULL,
+            updated_at = now()
+        WHERE id = $1 AND user_id = $2 AND logo_data IS NOT NULL
+        "#,
+            company_id,
+            user_id,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn confirmations(confirm: bool, confirm_finalized: bool) -> DeleteCompanyQuery {
+        DeleteCompanyQuery {
+            confirm,
+            confirm_finalized,
+        }
+    }
+
+    fn message(result: ApiResult<()>) -> String {
+        match result {
+            Err(ApiErrorResponse::BadRequest(message)) => message,
+            other => panic!("expected a bad request, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn counts_every_cascaded_record() {
+        let cascaded = [
+            CompanyDeletePreview {
+                timesheet_approvals: 1,
+                ..Default::default()
+            },
+            CompanyDeletePreview {
+                budgets: 1,
+                ..Default::default()
+            },
+            CompanyDeletePreview {
+                contracts: 1,
+                ..Default::default()
+            },
+            CompanyDeletePreview {
+                company_statements: 1,
+                ..Default::default()
+            },
+            CompanyDeletePreview {
+                contacts: 1,
+                ..Default::default()
+            },
+            CompanyDeletePreview {
+                recurring_payments: 1,
+                ..Default::default()
+            },
+        ];
+
+        assert!(CompanyDeletePreview::default().is_empty());
+        for preview in cascaded {
+            assert!(!preview.is_empty());
+            assert!(CompanyRepo::check_deletable(&preview, &confirmations(false, false)).is_err());
+            assert!(CompanyRepo::check_deletable(&preview, &confirmations(true, false)).is_ok());
+        }
+    }
+
+    #[test]
+    fn names_the_affected_records_when_unconfirmed() {
+        let preview = CompanyDeletePreview {
+            jobs: 2,
+            contacts: 3,
+            mileage_entries: 1,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            message(CompanyRepo::check_deletable(
+                &preview,
+                &confirmations(false, false)
+            )),
+            "Deleting this company also deletes 2 jobs, 3 contacts and detaches 1 mileage \
+             entries. Pass confirm=true to delete it"
+        );
+        assert!(
+            CompanyRepo::check_deletable(
+                &CompanyDeletePreview::default(),
+                &confirmations(false, false)
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn finalized_records_need_their_own_confirmation() {
+        let finalized_invoice = CompanyDeletePreview {
+            invoices: 1,
+            finalized_invoices: 1,
+            ..Default::default()
+        };
+        let approved_session = CompanyDeletePreview {
+            jobs: 1,
+            work_sessions: 1,
+            locked_work_sessions: 1,
+            ..Default::default()
+        };
+
+        for preview in [finalized_invoice, approved_session] {
+            assert!(preview.has_finalized_records());
+            assert!(CompanyRepo::check_deletable(&preview, &confirmations(false, false)).is_err());
+            assert!(
+                message(CompanyRepo::check_deletable(
+                    &preview,
+                    &confirmations(true, false)
+                ))
+                .contains("confirm_finalized=true")
+            );
+            assert!(CompanyRepo::check_deletable(&preview, &confirmations(true, true)).is_ok());
+        }
+    }
+}
@@ -0,0 +1,449 @@
+//! Company contact database operations.
+//!
+//! Provides [`ContactRepo`] for managing records in the `contacts` table.
+//! All queries are scoped to the owning user and company. Making a contact
+//! primary clears the flag on the company's other contacts in the same
+//! transaction, with the company row locked so concurrent changes cannot
+//! leave two primaries.
+
+use sqlx::{PgExecutor, Pool, Postgres};
+use uuid::Uuid;
+
+use gig_log_common::models::contact::{Contact, CreateContactRequest, UpdateContactRequest};
+
+use crate::core::error::ApiResult;
+
+/// Repository for company contact database operations.
+pub struct ContactRepo;
+
+impl ContactRepo {
+    /// Adds a contact to one of the user's companies.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the company.
+    /// * `company_id` — The company's UUID.
+    /// * `request` — The contact details.
+    ///
+    /// # Returns
+    ///
+    /// The created [`Contact`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`](crate::core::error::ApiErrorResponse::NotFound)
+    /// if no company with the given ID exists for the user.
+    pub async fn insert_contact(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        company_id: Uuid,
+        request: &CreateContactRequest,
+    ) -> ApiResult<Contact> {
+        let mut tx = pool.begin().await?;
+
+        Self::lock_company(&mut *tx, user_id, company_id).await?;
+
+        if request.is_primary {
+            Self::clear_primary(&mut *tx, user_id, company_id).await?;
+        }
+
+        let contact = sqlx::query_as!(
+            Contact,
+            r#"
+        INSERT INTO contacts (user_id, company_id, name, email, phone, role, is_primary)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING id, user_id, company_id, name, email, phone, role, is_primary,
+                  created_at, updated_at
+        "#,
+            user_id,
+            company_id,
+            request.name,
+            request.email,
+            request.phone,
+            request.role,
+            request.is_primary,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(contact)
+    }
+
+    /// Lists a company's contacts, primary contact first, then by name.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the company.
+    /// * `company_id` — The company's UUID.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`Contact`] values, empty if the company does not exist
+    /// or belongs to another user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_contacts(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        company_id: Uuid,
+    ) -> ApiResult<Vec<Contact>> {
+        let contacts = sqlx::query_as!(
+            Contact,
+            r#"
+        SELECT id, user_id, company_id, name, email, phone, role, is_primary,
+               created_at, updated_at
+        FROM contacts
+        WHERE company_id = $1 AND user_id = $2
+        ORDER BY is_primary DESC, name, created_at
+        "#,
+            company_id,
+            user_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(contacts)
+    }
+
+    /// Finds one of a company's contacts by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the company.
+    /// * `company_id` — The company's UUID.
+    /// * `contact_id` — The contact's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The matching [`Contact`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`](crate::core::error::ApiErrorResponse::NotFound)
+    /// if no such contact exists on the user's company.
+    pub async fn find_contact_by_id(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        company_id: Uuid,
+        contact_id: Uuid,
+    ) -> ApiResult<Contact> {
+        let contact = sqlx::query_as!(
+            Contact,
+            r#"
+        SELECT id, user_id, company_id, name, email, phone, role, is_primary,
+               created_at, updated_at
+        FROM contacts
+        WHERE id = $1 AND company_id = $2 AND user_id = $3
+        "#,
+            contact_id,
+            company_id,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(contact)
+    }
+
+    /// Finds a company's primary contact.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the company.
+    /// * `company_id` — The company's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The primary [`Contact`], or `None` if the company has none.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn find_primary_contact(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        company_id: Uuid,
+    ) -> ApiResult<Option<Contact>> {
+        let contact = sqlx::query_as!(
+            Contact,
+            r#"
+        SELECT id, user_id, company_id, name, email, phone, role, is_primary,
+               created_at, updated_at
+        FROM contacts
+        WHERE company_id = $1 AND user_id = $2 AND is_primary
+        "#,
+            company_id,
+            user_id,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(contact)
+    }
+
+    /// Applies a partial update to one of a company's contacts.
+    ///
+    /// Omitted fields keep their stored values.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the company.
+    /// * `company_id` — The company's UUID.
+    /// * `contact_id` — The contact's UUID.
+    /// * `request` — The fields to change.
+    ///
+    /// # Returns
+    ///
+    /// The updated [`Contact`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`](crate::core::error::ApiErrorResponse::NotFound)
+    /// if no such contact exists on the user's company.
+    pub async fn update_contact(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        company_id: Uuid,
+        contact_id: Uuid,
+        request: &UpdateContactRequest,
+    ) -> ApiResult<Contact> {
+        let mut tx = pool.begin().await?;
+
+        Self::lock_company(&mut *tx, user_id, company_id).await?;
+
+        let contact = sqlx::query_as!(
+            Contact,
+            r#"
+        SELECT id, user_id, company_id, name, email, phone, role, is_primary,
+               created_at, updated_at
+        FROM contacts
+        WHERE id = $1 AND company_id = $2 AND user_id = $3
+        FOR UPDATE
+        "#,
+            contact_id,
+            company_id,
+            user_id,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let was_primary = contact.is_primary;
+        let contact = Self::apply_update(contact, request);
+
+        if contact.is_primary && !was_primary {
+            Self::clear_primary(&mut *tx, user_id, company_id).await?;
+        }
+
+        let contact = sqlx::query_as!(
+            Contact,
+            r#"
+        UPDATE contacts
+        SET name = $4,
+            email = $5,
+            phone = $6,
+            role = $7,
+            is_primary = $8,
+            updated_at = now()
+        WHERE id = $1 AND company_id = $2 AND user_id = $3
+        RETURNING id, user_id, company_id, name, email, phone, role, is_primary,
+                  created_at, updated_at
+        "#,
+            contact_id,
+            company_id,
+            user_id,
+            contact.name,
+            contact.email,
+            contact.phone,
+            contact.role,
+            contact.is_primary,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(contact)
+    }
+
+    /// Applies an update request to a stored contact.
+    ///
+    /// # Arguments
+    ///
+    /// * `contact` — The contact as currently stored.
+    /// * `request` — The fields to change.
+    ///
+    /// # Returns
+    ///
+    /// The contact with provided fields replaced, cleared fields set to
+    /// `None`, and omitted fields unchanged.
+    fn apply_update(contact: Contact, request: &UpdateContactRequest) -> Contact {
+        Contact {
+            name: request.name.clone().unwrap_or(contact.name),
+            email: request.email.clone().unwrap_or(contact.email),
+            phone: request.phone.clone().unwrap_or(contact.phone),
+            role: request.role.clone().unwrap_or(contact.role),
+            is_primary: request.is_primary.unwrap_or(contact.is_primary),
+            ..contact
+        }
+    }
+
+    /// Deletes one of a company's contacts.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the company.
+    /// * `company_id` — The company's UUID.
+    /// * `contact_id` — The contact's UUID.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a contact was deleted, `false` if none matched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete query fails.
+    pub async fn delete_contact(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        company_id: Uuid,
+        contact_id: Uuid,
+    ) -> ApiResult<bool> {
+        let result = sqlx::query!(
+            r#"
+        DELETE FROM contacts
+        WHERE id = $1 AND company_id = $2 AND user_id = $3
+        "#,
+            contact_id,
+            company_id,
+            user_id,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Locks one of the user's companies for the rest of the transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `executor` — The transaction to take the lock in.
+    /// * `user_id` — The UUID of the user who owns the company.
+    /// * `company_id` — The company's UUID.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`](crate::core::error::ApiErrorResponse::NotFound)
+    /// if no company with the given ID exists for the user.
+    async fn lock_company<'e>(
+        executor: impl PgExecutor<'e>,
+        user_id: Uuid,
+        company_id: Uuid,
+    ) -> ApiResult<()> {
+        sqlx::query!(
+            r#"
+        SELECT id
+        FROM companies
+        WHERE id = $1 AND user_id = $2
+        FOR NO KEY UPDATE
+        "#,
+            company_id,
+            user_id,
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Clears the primary flag on a company's current primary contact.
+    ///
+    /// # Arguments
+    ///
+    /// * `executor` — The transaction holding the company lock.
+    /// * `user_id` — The UUID of the user who owns the company.
+    /// * `company_id` — The company's UUID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    async fn clear_primary<'e>(
+        executor: impl PgExecutor<'e>,
+        user_id: Uuid,
+        company_id: Uuid,
+    ) -> ApiResult<()> {
+        sqlx::query!(
+            r#"
+        UPDATE contacts
+        SET is_primary = false, updated_at = now()
+        WHERE company_id = $1 AND user_id = $2 AND is_primary
+        "#,
+            company_id,
+            user_id,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    fn contact() -> Contact {
+        Contact {
+            id: Uuid::nil(),
+            user_id: Uuid::nil(),
+            company_id: Uuid::nil(),
+            name: "Dana Reyes".to_string(),
+            email: Some("dana@example.com".to_string()),
+            phone: Some("555-0100".to_string()),
+            role: Some("Producer".to_string()),
+            is_primary: false,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn request(json: &str) -> UpdateContactRequest {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn omitted_fields_are_left_unchanged() {
+        let updated = ContactRepo::apply_update(contact(), &request(r#"{"role": "Director"}"#));
+
+        assert_eq!(updated.name, "Dana Reyes");
+        assert_eq!(updated.email.as_deref(), Some("dana@example.com"));
+        assert_eq!(updated.phone.as_deref(), Some("555-0100"));
+        assert_eq!(updated.role.as_deref(), Some("Director"));
+        assert!(!updated.is_primary);
+    }
+
+    #[test]
+    fn null_clears_optional_fields() {
+        let updated = ContactRepo::apply_update(
+            contact(),
+            &request(r#"{"email": null, "phone": null, "is_primary": true}"#),
+        );
+
+        assert_eq!(updated.email, None);
+        assert_eq!(updated.phone, None);
+        assert_eq!(updated.role.as_deref(), Some("Producer"));
+        assert!(updated.is_primary);
+    }
+}
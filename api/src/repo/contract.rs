@@ -0,0 +1,628 @@
+//! Contract database operations.
+//!
+//! Provides [`ContractRepo`] for managing engagement contracts and their
+//! signed document attachments in the `contracts` table, and for claiming
+//! due renewal reminders. User-facing queries are scoped to the owning user.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{FromRow, Pool, Postgres};
+use uuid::Uuid;
+
+use gig_log_common::models::contract::{
+    Contract, ContractDocument, ContractRateType, CreateContractRequest, UpdateContractRequest,
+};
+
+use crate::core::error::ApiResult;
+
+/// How a contract row prices the work.
+///
+/// Maps to the PostgreSQL `contract_rate_type` enum.
+#[derive(Debug, Clone, sqlx::Type)]
+#[sqlx(type_name = "contract_rate_type", rename_all = "snake_case")]
+pub enum ContractRateTypeRecord {
+    /// Billed per hour worked.
+    Hourly,
+    /// A single fixed fee.
+    Fixed,
+    /// A recurring monthly retainer.
+    Retainer,
+}
+
+impl From<ContractRateType> for ContractRateTypeRecord {
+    fn from(rate_type: ContractRateType) -> Self {
+        match rate_type {
+            ContractRateType::Hourly => ContractRateTypeRecord::Hourly,
+            ContractRateType::Fixed => ContractRateTypeRecord::Fixed,
+            ContractRateType::Retainer => ContractRateTypeRecord::Retainer,
+        }
+    }
+}
+
+impl From<ContractRateTypeRecord> for ContractRateType {
+    fn from(rate_type: ContractRateTypeRecord) -> Self {
+        match rate_type {
+            ContractRateTypeRecord::Hourly => ContractRateType::Hourly,
+            ContractRateTypeRecord::Fixed => ContractRateType::Fixed,
+            ContractRateTypeRecord::Retainer => ContractRateType::Retainer,
+        }
+    }
+}
+
+/// A row from the `contracts` table, excluding document bytes.
+#[derive(Debug, FromRow)]
+pub struct ContractRecord {
+    /// Unique identifier for the contract.
+    pub id: Uuid,
+    /// The user who owns the contract.
+    pub user_id: Uuid,
+    /// The company the contract is with.
+    pub company_id: Uuid,
+    /// Short title describing the engagement.
+    pub title: String,
+    /// First day the contract is in effect.
+    pub start_date: NaiveDate,
+    /// Last day the contract is in effect.
+    pub end_date: Option<NaiveDate>,
+    /// How the contract prices the work.
+    pub rate_type: ContractRateTypeRecord,
+    /// Rate in dollars.
+    pub rate_amount: f64,
+    /// Free-form terms.
+    pub terms: Option<String>,
+    /// Days before `end_date` to send a renewal reminder.
+    pub renewal_reminder_days: Option<i32>,
+    /// When the renewal reminder was sent.
+    pub renewal_reminder_sent_at: Option<DateTime<Utc>>,
+    /// Original file name of the signed document.
+    pub document_file_name: Option<String>,
+    /// MIME type of the signed document.
+    pub document_content_type: Option<String>,
+    /// Size of the signed document in bytes.
+    pub document_size_bytes: Option<i64>,
+    /// When the signed document was uploaded.
+    pub document_uploaded_at: Option<DateTime<Utc>>,
+    /// When the contract was created.
+    pub created_at: DateTime<Utc>,
+    /// When the contract was last updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<ContractRecord> for Contract {
+    fn from(record: ContractRecord) -> Self {
+        let document = match (
+            record.document_file_name,
+            record.document_content_type,
+            record.document_uploaded_at,
+        ) {
+            (Some(file_name), Some(content_type), Some(uploaded_at)) => Some(ContractDocument {
+                file_name,
+                content_type,
+                size_bytes: record.document_size_bytes.unwrap_or_default(),
+                uploaded_at,
+            }),
+            _ => None,
+        };
+
+        Self {
+            id: record.id,
+            user_id: record.user_id,
+            company_id: record.company_id,
+            title: record.title,
+            start_date: record.start_date,
+            end_date: record.end_date,
+            rate_type: record.rate_type.into(),
+            rate_amount: record.rate_amount,
+            terms: record.terms,
+            renewal_reminder_days: record.renewal_reminder_days,
+            renewal_reminder_sent_at: record.renewal_reminder_sent_at,
+            document,
+            created_at: record.created_at,
+            updated_at: record.updated_at,
+        }
+    }
+}
+
+/// A contract's signed document contents.
+#[derive(Debug, FromRow)]
+pub struct ContractDocumentRecord {
+    /// Original file name of the document.
+    pub file_name: String,
+    /// MIME type of the document.
+    pub content_type: String,
+    /// Raw document bytes.
+    pub data: Vec<u8>,
+}
+
+/// A contract whose renewal reminder has just been claimed for sending.
+#[derive(Debug, FromRow)]
+pub struct DueRenewalRecord {
+    /// The contract's UUID.
+    pub id: Uuid,
+    /// The contract's title.
+    pub title: String,
+    /// Name of the company the contract is with.
+    pub company_name: String,
+    /// Last day the contract is in effect.
+    pub end_date: NaiveDate,
+    /// Email address of the contract owner.
+    pub email: String,
+    /// Language tag of the contract owner's preferred email locale.
+    pub locale: String,
+}
+
+/// Repository for contract database operations.
+pub struct ContractRepo;
+
+impl ContractRepo {
+    /// Inserts a new contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the contract.
+    /// * `request` — The validated [`CreateContractRequest`].
+    ///
+    /// # Returns
+    ///
+    /// The newly created [`Contract`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert query fails.
+    pub async fn insert_contract(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        request: CreateContractRequest,
+    ) -> ApiResult<Contract> {
+        let record = sqlx::query_as!(
+            ContractRecord,
+            r#"
+        INSERT INTO contracts (
+            user_id, company_id, title, start_date, end_date, rate_type,
+            rate_amount, terms, renewal_reminder_days
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7::FLOAT8, $8, $9)
+        RETURNING id, user_id, company_id, title, start_date, end_date,
+                  rate_type AS "rate_type: ContractRateTypeRecord",
+                  rate_amount::FLOAT8 AS "rate_amount!", terms,
+                  renewal_reminder_days, renewal_reminder_sent_at,
+                  document_file_name, document_content_type,
+                  octet_length(document_data)::BIGINT AS document_size_bytes,
+                  document_uploaded_at, created_at, updated_at
+        "#,
+            user_id,
+            request.company_id,
+            request.title,
+            request.start_date,
+            request.end_date,
+            ContractRateTypeRecord::from(request.rate_type) as ContractRateTypeRecord,
+            request.rate_amount,
+            request.terms,
+            request.renewal_reminder_days,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record.into())
+    }
+
+    /// Lists a user's contracts, optionally restricted to one company.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the contracts.
+    /// * `company_id` — Restrict to contracts with this company.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`Contract`] values ordered by start date.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_contracts(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        company_id: Option<Uuid>,
+    ) -> ApiResult<Vec<Contract>> {
+        let records = sqlx::query_as!(
+            ContractRecord,
+            r#"
+        SELECT id, user_id, company_id, title, start_date, end_date,
+               rate_type AS "rate_type: ContractRateTypeRecord",
+               rate_amount::FLOAT8 AS "rate_amount!", terms,
+               renewal_reminder_days, renewal_reminder_sent_at,
+               document_file_name, document_content_type,
+               octet_length(document_data)::BIGINT AS document_size_bytes,
+               document_uploaded_at, created_at, updated_at
+        FROM contracts
+        WHERE user_id = $1 AND ($2::UUID IS NULL OR company_id = $2)
+        ORDER BY start_date, created_at
+        "#,
+            user_id,
+            company_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records.into_iter().map(Into::into).collect())
+    }
+
+    /// Finds a contract by ID, scoped to the owning user.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the contract.
+    /// * `contract_id` — The contract's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The [`Contract`] matching the given ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no contract with the given ID exists for the user.
+    pub async fn find_contract_by_id(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        contract_id: Uuid,
+    ) -> ApiResult<Contract> {
+        let record = sqlx::query_as!(
+            ContractRecord,
+            r#"
+        SELECT id, user_id, company_id, title, start_date, end_date,
+               rate_type AS "rate_type: ContractRateTypeRecord",
+               rate_amount::FLOAT8 AS "rate_amount!", terms,
+               renewal_reminder_days, renewal_reminder_sent_at,
+               document_file_name, document_content_type,
+               octet_length(document_data)::BIGINT AS document_size_bytes,
+               document_uploaded_at, created_at, updated_at
+        FROM contracts
+        WHERE id = $1 AND user_id = $2
+        "#,
+            contract_id,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record.into())
+    }
+
+    /// Applies a partial update to a contract, scoped to the owning user.
+    ///
+    /// Fields left as `None` keep their stored values. When `end_date` or
+    /// `renewal_reminder_days` is provided, the renewal reminder is re-armed
+    /// so that it is sent again for the new schedule.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the contract.
+    /// * `contract_id` — The contract's UUID.
+    /// * `request` — The validated [`UpdateContractRequest`].
+    ///
+    /// # Returns
+    ///
+    /// The updated [`Contract`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no contract with the given ID exists for the user
+    /// or the update query fails.
+    pub async fn update_contract(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        contract_id: Uuid,
+        request: UpdateContractRequest,
+    ) -> ApiResult<Contract> {
+        let rearm_reminder = Self::rearms_reminder(&request);
+
+        let record = sqlx::query_as!(
+            ContractRecord,
+            r#"
+        UPDATE contracts
+        SET title = COALESCE($3, title),
+            start_date = COALESCE($4, start_date),
+            end_date = COALESCE($5, end_date),
+            rate_type = COALESCE($6, rate_type),
+            rate_amount = COALESCE($7::FLOAT8::DECIMAL, rate_amount),
+            terms = COALESCE($8, terms),
+            renewal_reminder_days = COALESCE($9, renewal_reminder_days),
+            renewal_reminder_sent_at = CASE WHEN $10 THEN NULL ELSE renewal_reminder_sent_at END,
+            updated_at = now()
+        WHERE id = $1 AND user_id = $2
+        RETURNING id, user_id, company_id, title, start_date, end_date,
+                  rate_type AS "rate_type: ContractRateTypeRecord",
+                  rate_amount::FLOAT8 AS "rate_amount!", terms,
+                  renewal_reminder_days, renewal_reminder_sent_at,
+                  document_file_name, document_content_type,
+                  octet_length(document_data)::BIGINT AS document_size_bytes,
+                  document_uploaded_at, created_at, updated_at
+        "#,
+            contract_id,
+            user_id,
+            request.title,
+            request.start_date,
+            request.end_date,
+            request.rate_type.map(ContractRateTypeRecord::from) as Option<ContractRateTypeRecord>,
+            request.rate_amount,
+            request.terms,
+            request.renewal_reminder_days,
+            rearm_reminder,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record.into())
+    }
+
+    /// Decides whether an update re-arms the renewal reminder.
+    ///
+    /// Moving the end date or changing the lead time opens a new reminder
+    /// window, so a reminder that was already sent must be sent again.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` — The validated [`UpdateContractRequest`].
+    ///
+    /// # Returns
+    ///
+    /// `true` if the request sets `end_date` or `renewal_reminder_days`.
+    fn rearms_reminder(request: &UpdateContractRequest) -> bool {
+        request.end_date.is_some() || request.renewal_reminder_days.is_some()
+    }
+
+    /// Deletes a contract, scoped to the owning user.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the contract.
+    /// * `contract_id` — The contract's UUID.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a contract was deleted, `false` if none matched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete query fails.
+    pub async fn delete_contract(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        contract_id: Uuid,
+    ) -> ApiResult<bool> {
+        let result = sqlx::query!(
+            r#"
+        DELETE FROM contracts
+        WHERE id = $1 AND user_id = $2
+        "#,
+            contract_id,
+            user_id,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Stores a contract's signed document, replacing any existing one.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the contract.
+    /// * `contract_id` — The contract's UUID.
+    /// * `file_name` — Original file name of the document.
+    /// * `content_type` — MIME type of the document.
+    /// * `data` — Raw document bytes.
+    ///
+    /// # Returns
+    ///
+    /// The updated [`Contract`] with its document metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no contract with the given ID exists for the user
+    /// or the update query fails.
+    pub async fn set_document(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        contract_id: Uuid,
+        file_name: &str,
+        content_type: &str,
+        data: &[u8],
+    ) -> ApiResult<Contract> {
+        let record = sqlx::query_as!(
+            ContractRecord,
+            r#"
+        UPDATE contracts
+        SET document_file_name = $3,
+            document_content_type = $4,
+            document_data = $5,
+            document_uploaded_at = now(),
+            updated_at = now()
+        WHERE id = $1 AND user_id = $2
+        RETURNING id, user_id, company_id, title, start_date, end_date,
+                  rate_type AS "rate_type: ContractRateTypeRecord",
+                  rate_amount::FLOAT8 AS "rate_amount!", terms,
+                  renewal_reminder_days, renewal_reminder_sent_at,
+                  document_file_name, document_content_type,
+                  octet_length(document_data)::BIGINT AS document_size_bytes,
+                  document_uploaded_at, created_at, updated_at
+        "#,
+            contract_id,
+            user_id,
+            file_name,
+            content_type,
+            data,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record.into())
+    }
+
+    /// Loads a contract's signed document, scoped to the owning user.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the contract.
+    /// * `contract_id` — The contract's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The [`ContractDocumentRecord`] with the document bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the contract does not exist for the user or has
+    /// no document attached.
+    pub async fn find_document(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        contract_id: Uuid,
+    ) -> ApiResult<ContractDocumentRecord> {
+        let record = sqlx::query_as!(
+            ContractDocumentRecord,
+            r#"
+        SELECT document_file_name AS "file_name!",
+               document_content_type AS "content_type!",
+               document_data AS "data!"
+        FROM contracts
+        WHERE id = $1 AND user_id = $2 AND document_data IS NOT NULL
+        "#,
+            contract_id,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// Claims every contract whose renewal reminder is due.
+    ///
+    /// A reminder is due once the current date is within
+    /// `renewal_reminder_days` of `end_date` and the contract has not yet
+    /// ended. Claimed contracts are marked as reminded in the same statement
+    /// so that concurrent sweeps never send a reminder twice.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`DueRenewalRecord`] values to send reminders for.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn claim_due_renewal_reminders(
+        pool: &Pool<Postgres>,
+    ) -> ApiResult<Vec<DueRenewalRecord>> {
+        let records = sqlx::query_as!(
+            DueRenewalRecord,
+            r#"
+        UPDATE contracts c
+        SET renewal_reminder_sent_at = now()
+        FROM companies co, users u
+        WHERE co.id = c.company_id
+          AND u.id = c.user_id
+          AND c.renewal_reminder_sent_at IS NULL
+          AND c.renewal_reminder_days IS NOT NULL
+          AND c.end_date >= CURRENT_DATE
+          AND c.end_date - c.renewal_reminder_days <= CURRENT_DATE
+        RETURNING c.id, c.title, co.name AS company_name,
+                  c.end_date AS "end_date!", u.email, u.locale
+        "#,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record() -> ContractRecord {
+        ContractRecord {
+            id: Uuid::nil(),
+            user_id: Uuid::nil(),
+            company_id: Uuid::nil(),
+            title: "Retainer".to_string(),
+            start_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            end_date: None,
+            rate_type: ContractRateTypeRecord::Retainer,
+            rate_amount: 1500.0,
+            terms: None,
+            renewal_reminder_days: None,
+            renewal_reminder_sent_at: None,
+            document_file_name: None,
+            document_content_type: None,
+            document_size_bytes: None,
+            document_uploaded_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn update() -> UpdateContractRequest {
+        UpdateContractRequest {
+            title: None,
+            start_date: None,
+            end_date: None,
+            rate_type: None,
+            rate_amount: None,
+            terms: None,
+            renewal_reminder_days: None,
+        }
+    }
+
+    #[test]
+    fn maps_document_metadata_only_when_a_document_is_attached() {
+        assert!(Contract::from(record()).document.is_none());
+
+        let uploaded_at = Utc::now();
+        let contract = Contract::from(ContractRecord {
+            document_file_name: Some("signed.pdf".to_string()),
+            document_content_type: Some("application/pdf".to_string()),
+            document_size_bytes: Some(2048),
+            document_uploaded_at: Some(uploaded_at),
+            ..record()
+        });
+        let document = contract.document.expect("document metadata");
+
+        assert_eq!(contract.rate_type, ContractRateType::Retainer);
+        assert_eq!(document.file_name, "signed.pdf");
+        assert_eq!(document.content_type, "application/pdf");
+        assert_eq!(document.size_bytes, 2048);
+        assert_eq!(document.uploaded_at, uploaded_at);
+    }
+
+    #[test]
+    fn rearms_reminder_when_the_window_moves() {
+        assert!(!ContractRepo::rearms_reminder(&update()));
+        assert!(!ContractRepo::rearms_reminder(&UpdateContractRequest {
+            title: Some("Renamed".to_string()),
+            rate_amount: Some(90.0),
+            ..update()
+        }));
+        assert!(ContractRepo::rearms_reminder(&UpdateContractRequest {
+            end_date: NaiveDate::from_ymd_opt(2027, 1, 1),
+            ..update()
+        }));
+        assert!(ContractRepo::rearms_reminder(&UpdateContractRequest {
+            renewal_reminder_days: Some(14),
+            ..update()
+        }));
+    }
+}
@@ -0,0 +1,181 @@
+//! Data consistency check queries.
+//!
+//! Provides [`DataCheckRepo`] for counting and repairing rows that break a
+//! [`DataCheck`] invariant. Queries span all users.
+
+use sqlx::{Pool, Postgres};
+
+use crate::core::error::ApiResult;
+use crate::data_check::DataCheck;
+
+/// Repository for data consistency check queries.
+pub struct DataCheckRepo;
+
+impl DataCheckRepo {
+    /// Counts the rows that break a check's invariant.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `check` — The invariant to check.
+    ///
+    /// # Returns
+    ///
+    /// The number of violating rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn count_violations(pool: &Pool<Postgres>, check: DataCheck) -> ApiResult<i64> {
+        let count = match check {
+            DataCheck::NonPositivePaymentTotals => {
+                sqlx::query_scalar!(
+                    r#"
+        SELECT COUNT(*) AS "count!"
+        FROM payments
+        WHERE total <= 0
+        "#,
+                )
+                .fetch_one(pool)
+                .await?
+            }
+            DataCheck::ReceivedPaymentsWithoutTransfer => {
+                sqlx::query_scalar!(
+                    r#"
+        SELECT COUNT(*) AS "count!"
+        FROM payments
+        WHERE payment_received = TRUE AND transfer_initiated = FALSE
+        "#,
+                )
+                .fetch_one(pool)
+                .await?
+            }
+            DataCheck::PendingPayoutsWithoutDates => {
+                sqlx::query_scalar!(
+                    r#"
+        SELECT COUNT(*) AS "count!"
+        FROM payments
+        WHERE transfer_initiated = TRUE
+          AND payment_received = FALSE
+          AND expected_payout_date IS NULL
+        "#,
+                )
+                .fetch_one(pool)
+                .await?
+            }
+            DataCheck::NegativePausedDurations => {
+                sqlx::query_scalar!(
+                    r#"
+        SELECT COUNT(*) AS "count!"
+        FROM work_sessions
+        WHERE accumulated_paused_duration < INTERVAL '0'
+        "#,
+                )
+                .fetch_one(pool)
+                .await?
+            }
+            DataCheck::NegativeSessionDurations => {
+                sqlx::query_scalar!(
+                    r#"
+        SELECT COUNT(*) AS "count!"
+        FROM work_sessions
+        WHERE is_running = FALSE
+          AND end_time - start_time - accumulated_paused_duration < INTERVAL '0'
+        "#,
+                )
+                .fetch_one(pool)
+                .await?
+            }
+            DataCheck::OrphanedAuthCodes => {
+                sqlx::query_scalar!(
+                    r#"
+        SELECT COUNT(*) AS "count!"
+        FROM auth_codes ac
+        JOIN users u ON u.id = ac.user_id
+        WHERE ac.used = TRUE
+           OR ac.expires_at < now()
+           OR (ac.code_type = 'email_verification' AND u.email_confirmed = TRUE)
+        "#,
+                )
+                .fetch_one(pool)
+                .await?
+            }
+        };
+
+        Ok(count)
+    }
+
+    /// Repairs the rows that break a check's invariant.
+    ///
+    /// Checks without a safe repair are left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `check` — The invariant to repair.
+    ///
+    /// # Returns
+    ///
+    /// The number of rows repaired.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn repair(pool: &Pool<Postgres>, check: DataCheck) -> ApiResult<u64> {
+        let result = match check {
+            DataCheck::NonPositivePaymentTotals | DataCheck::PendingPayoutsWithoutDates => {
+                return Ok(0);
+            }
+            DataCheck::ReceivedPaymentsWithoutTransfer => {
+                sqlx::query!(
+                    r#"
+        UPDATE payments
+        SET transfer_initiated = TRUE, updated_at = now()
+        WHERE payment_received = TRUE AND transfer_initiated = FALSE
+        "#,
+                )
+                .execute(pool)
+                .await?
+            }
+            DataCheck::NegativePausedDurations => {
+                sqlx::query!(
+                    r#"
+        UPDATE work_sessions
+        SET accumulated_paused_duration = INTERVAL '0', updated_at = now()
+        WHERE accumulated_paused_duration < INTERVAL '0'
+        "#,
+                )
+                .execute(pool)
+                .await?
+            }
+            DataCheck::NegativeSessionDurations => {
+                sqlx::query!(
+                    r#"
+        UPDATE work_sessions
+        SET accumulated_paused_duration = end_time - start_time, updated_at = now()
+        WHERE is_running = FALSE
+          AND end_time - start_time - accumulated_paused_duration < INTERVAL '0'
+        "#,
+                )
+                .execute(pool)
+                .await?
+            }
+            DataCheck::OrphanedAuthCodes => {
+                sqlx::query!(
+                    r#"
+        DELETE FROM auth_codes ac
+        USING users u
+        WHERE u.id = ac.user_id
+          AND (ac.used = TRUE
+               OR ac.expires_at < now()
+               OR (ac.code_type = 'email_verification' AND u.email_confirmed = TRUE))
+        "#,
+                )
+                .execute(pool)
+                .await?
+            }
+        };
+
+        Ok(result.rows_affected())
+    }
+}
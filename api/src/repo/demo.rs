@@ -0,0 +1,199 @@
+//! Demo account database operations.
+//!
+//! Provides [`DemoRepo`] for provisioning throwaway demo accounts seeded
+//! with sample companies, jobs, work sessions, and payments, and for
+//! purging them once they expire.
+
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Postgres};
+
+use gig_log_common::models::user::User;
+
+use crate::core::error::ApiResult;
+use crate::repo::onboarding_state::{OnboardingEvent, OnboardingStateRepo};
+
+/// Repository for demo account database operations.
+pub struct DemoRepo;
+
+impl DemoRepo {
+    /// Creates a demo user and seeds it with sample data.
+    ///
+    /// The user has a confirmed email, so it is usable straight away, and is
+    /// seeded with two companies, three jobs, a week of completed work
+    /// sessions, and two payments. Everything is written in one transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `email` — The unique email address for the demo user.
+    /// * `password_hash` — The Argon2 hash of an unguessable password.
+    /// * `expires_at` — When the demo account is to be purged.
+    ///
+    /// # Returns
+    ///
+    /// The newly created demo [`User`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any insert fails.
+    pub async fn create_demo_account(
+        pool: &Pool<Postgres>,
+        email: &str,
+        password_hash: &str,
+        expires_at: DateTime<Utc>,
+    ) -> ApiResult<User> {
+        let mut tx = pool.begin().await?;
+
+        let user = sqlx::query_as!(
+            User,
+            r#"
+        INSERT INTO users (first_name, last_name, email, hashed_password, email_confirmed,
+                           demo_expires_at)
+        VALUES ('Demo', 'User', $1, $2, TRUE, $3)
+        RETURNING id, first_name, last_name, email, email_confirmed,
+                  NULL::TEXT AS avatar_url, created_at, updated_at
+        "#,
+            email,
+            password_hash,
+            expires_at,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let studio_id = sqlx::query_scalar!(
+            r#"
+        INSERT INTO companies (user_id, name, requires_tax_withholdings, tax_withholding_rate)
+        VALUES ($1, 'Acme Design Studio', TRUE, 0.25)
+        RETURNING id
+        "#,
+            user.id,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let labs_id = sqlx::query_scalar!(
+            r#"
+        INSERT INTO companies (user_id, name)
+        VALUES ($1, 'Northwind Labs')
+        RETURNING id
+        "#,
+            user.id,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let redesign_id = sqlx::query_scalar!(
+            r#"
+        INSERT INTO jobs (company_id, user_id, title, payment_type, hourly_rate)
+        VALUES ($1, $2, 'Website redesign', 'hourly', 65)
+        RETURNING id
+        "#,
+            studio_id,
+            user.id,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+        INSERT INTO jobs (company_id, user_id, title, payment_type, number_of_payouts,
+                          payout_amount)
+        VALUES ($1, $2, 'Brand guidelines', 'payouts', 3, 1200)
+        "#,
+            studio_id,
+            user.id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let integration_id = sqlx::query_scalar!(
+            r#"
+        INSERT INTO jobs (company_id, user_id, title, payment_type, hourly_rate)
+        VALUES ($1, $2, 'API integration', 'hourly', 80)
+        RETURNING id
+        "#,
+            labs_id,
+            user.id,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+        INSERT INTO work_sessions (user_id, job_id, start_time, end_time, is_running, hourly_rate)
+        SELECT $1, sessions.job_id, sessions.start_time,
+               sessions.start_time + make_interval(mins => sessions.minutes), FALSE, j.hourly_rate
+        FROM (
+            SELECT $2::UUID AS job_id,
+                   date_trunc('day', now()) - make_interval(days => day) + INTERVAL '9 hours'
+                       AS start_time,
+                   minutes
+            FROM (VALUES (1, 180), (2, 240), (4, 150), (6, 210)) AS days (day, minutes)
+            UNION ALL
+            SELECT $3::UUID,
+                   date_trunc('day', now()) - make_interval(days => day) + INTERVAL '14 hours',
+                   minutes
+            FROM (VALUES (1, 120), (3, 300), (5, 90)) AS days (day, minutes)
+        ) AS sessions
+        JOIN jobs j ON j.id = sessions.job_id
+        "#,
+            user.id,
+            redesign_id,
+            integration_id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+        INSERT INTO payments (user_id, company_id, total, payout_type, expected_payout_date,
+                              transfer_initiated, payment_received, tax_withholdings_covered)
+        VALUES ($1, $2, 1200, 'direct_deposit', CURRENT_DATE - 3, TRUE, TRUE, FALSE),
+               ($1, $3, 640, 'paypal', CURRENT_DATE + 7, FALSE, FALSE, FALSE)
+        "#,
+            user.id,
+            studio_id,
+            labs_id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        for event in [
+            OnboardingEvent::CompanyCreated,
+            OnboardingEvent::SessionTracked,
+            OnboardingEvent::PaymentRecorded,
+        ] {
+            OnboardingStateRepo::record(&mut *tx, user.id, event).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(user)
+    }
+
+    /// Deletes every demo account that has expired, along with its data.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    ///
+    /// # Returns
+    ///
+    /// The number of demo accounts deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete query fails.
+    pub async fn delete_expired(pool: &Pool<Postgres>) -> ApiResult<u64> {
+        let result = sqlx::query!(
+            r#"
+        DELETE FROM users
+        WHERE demo_expires_at IS NOT NULL AND demo_expires_at <= now()
+        "#,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,236 @@
+//! CSV import database operations.
+//!
+//! Provides [`ImportRepo`] for looking up the companies and jobs that
+//! imported rows refer to and for inserting validated payments and work
+//! sessions. Each import is written in a single transaction, so a file is
+//! either imported completely or not at all.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{FromRow, Pool, Postgres};
+use uuid::Uuid;
+
+use crate::core::error::ApiResult;
+use crate::import::{PaymentImport, WorkSessionImport};
+use crate::repo::onboarding_state::{OnboardingEvent, OnboardingStateRepo};
+use crate::repo::payment::PayoutTypeRecord;
+
+/// A company that imported rows can refer to by name.
+#[derive(Debug, Clone, FromRow)]
+pub struct ImportCompanyRecord {
+    /// Unique identifier for the company.
+    pub id: Uuid,
+    /// Name of the company.
+    pub name: String,
+}
+
+/// A job that imported work sessions can refer to by title.
+#[derive(Debug, Clone, FromRow)]
+pub struct ImportJobRecord {
+    /// Unique identifier for the job.
+    pub id: Uuid,
+    /// The company the job belongs to.
+    pub company_id: Uuid,
+    /// Title of the job.
+    pub title: String,
+    /// Current hourly rate in dollars. `None` for payout-based jobs.
+    pub hourly_rate: Option<f64>,
+}
+
+/// Repository for CSV import database operations.
+pub struct ImportRepo;
+
+impl ImportRepo {
+    /// Lists every company a user owns.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the companies.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`ImportCompanyRecord`] values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_companies(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+    ) -> ApiResult<Vec<ImportCompanyRecord>> {
+        let companies = sqlx::query_as!(
+            ImportCompanyRecord,
+            r#"
+        SELECT id, name
+        FROM companies
+        WHERE user_id = $1
+        "#,
+            user_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(companies)
+    }
+
+    /// Lists every job a user owns.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the jobs.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`ImportJobRecord`] values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_jobs(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+    ) -> ApiResult<Vec<ImportJobRecord>> {
+        let jobs = sqlx::query_as!(
+            ImportJobRecord,
+            r#"
+        SELECT id, company_id, title, hourly_rate::FLOAT8 AS hourly_rate
+        FROM jobs
+        WHERE user_id = $1
+        "#,
+            user_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(jobs)
+    }
+
+    /// Inserts imported payments in one transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the payments.
+    /// * `payments` — The validated rows.
+    ///
+    /// # Returns
+    ///
+    /// The number of payments inserted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert fails, in which case nothing is
+    /// inserted.
+    pub async fn insert_payments(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        payments: &[PaymentImport],
+    ) -> ApiResult<u64> {
+        let company_ids: Vec<Uuid> = payments.iter().map(|payment| payment.company_id).collect();
+        let totals: Vec<f64> = payments.iter().map(|payment| payment.total).collect();
+        let payout_types: Vec<PayoutTypeRecord> = payments
+            .iter()
+            .map(|payment| payment.payout_type.clone().into())
+            .collect();
+        let expected_payout_dates: Vec<Option<NaiveDate>> = payments
+            .iter()
+            .map(|payment| payment.expected_payout_date)
+            .collect();
+        let transfers_initiated: Vec<bool> = payments
+            .iter()
+            .map(|payment| payment.transfer_initiated)
+            .collect();
+        let payments_received: Vec<bool> = payments
+            .iter()
+            .map(|payment| payment.payment_received)
+            .collect();
+        let tax_withholdings_covered: Vec<bool> = payments
+            .iter()
+            .map(|payment| payment.tax_withholdings_covered)
+            .collect();
+
+        let mut tx = pool.begin().await?;
+
+        let result = sqlx::query!(
+            r#"
+        INSERT INTO payments (user_id, company_id, total, payout_type, expected_payout_date,
+                              transfer_initiated, payment_received, tax_withholdings_covered)
+        SELECT $1, rows.company_id, rows.total, rows.payout_type, rows.expected_payout_date,
+               rows.transfer_initiated, rows.payment_received, rows.tax_withholdings_covered
+        FROM UNNEST($2::UUID[], $3::FLOAT8[], $4::payout_type[], $5::DATE[], $6::BOOLEAN[],
+                    $7::BOOLEAN[], $8::BOOLEAN[])
+            AS rows (company_id, total, payout_type, expected_payout_date, transfer_initiated,
+                     payment_received, tax_withholdings_covered)
+        "#,
+            user_id,
+            &company_ids,
+            &totals,
+            &payout_types as &[PayoutTypeRecord],
+            &expected_payout_dates as &[Option<NaiveDate>],
+            &transfers_initiated,
+            &payments_received,
+            &tax_withholdings_covered,
+        )
+        .execute(&mut *tx)
+        .await?;
+        OnboardingStateRepo::record(&mut *tx, user_id, OnboardingEvent::PaymentRecorded).await?;
+
+        tx.commit().await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Inserts imported, completed work sessions in one transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the sessions.
+    /// * `sessions` — The validated rows.
+    ///
+    /// # Returns
+    ///
+    /// The number of work sessions inserted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert fails, in which case nothing is
+    /// inserted.
+    pub async fn insert_work_sessions(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        sessions: &[WorkSessionImport],
+    ) -> ApiResult<u64> {
+        let job_ids: Vec<Uuid> = sessions.iter().map(|session| session.job_id).collect();
+        let start_times: Vec<DateTime<Utc>> =
+            sessions.iter().map(|session| session.start_time).collect();
+        let end_times: Vec<DateTime<Utc>> =
+            sessions.iter().map(|session| session.end_time).collect();
+        let hourly_rates: Vec<Option<f64>> =
+            sessions.iter().map(|session| session.hourly_rate).collect();
+
+        let mut tx = pool.begin().await?;
+
+        let result = sqlx::query!(
+            r#"
+        INSERT INTO work_sessions (user_id, job_id, start_time, end_time, is_running, hourly_rate)
+        SELECT $1, rows.job_id, rows.start_time, rows.end_time, FALSE, rows.hourly_rate
+        FROM UNNEST($2::UUID[], $3::TIMESTAMPTZ[], $4::TIMESTAMPTZ[], $5::FLOAT8[])
+            AS rows (job_id, start_time, end_time, hourly_rate)
+        "#,
+            user_id,
+            &job_ids,
+            &start_times,
+            &end_times,
+            &hourly_rates as &[Option<f64>],
+        )
+        .execute(&mut *tx)
+        .await?;
+        OnboardingStateRepo::record(&mut *tx, user_id, OnboardingEvent::SessionTracked).await?;
+
+        tx.commit().await?;
+
+        Ok(result.rows_affected())
+    }
+}
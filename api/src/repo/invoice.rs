@@ -0,0 +1,606 @@
+//! Invoice database operations.
+//!
+//! Provides [`InvoiceRepo`] for creating draft invoices, attaching unbilled
+//! work sessions as line items, editing, finalizing, and deleting invoices,
+//! and loading invoices with their line items from the `invoices` and
+//! `invoice_line_items` tables.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{FromRow, Pool, Postgres};
+use uuid::Uuid;
+
+use gig_log_common::models::invoice::{
+    Invoice, InvoiceLineItem, InvoiceStatus, ListInvoicesQuery, UpdateInvoiceRequest,
+};
+
+use crate::billing::BillingUtil;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::repo::work_session::UnbilledSessionRecord;
+
+/// The lifecycle state of an invoice row.
+///
+/// Maps to the PostgreSQL `invoice_status` enum.
+#[derive(Debug, Clone, sqlx::Type)]
+#[sqlx(type_name = "invoice_status", rename_all = "snake_case")]
+pub enum InvoiceStatusRecord {
+    /// The invoice is still being assembled.
+    Draft,
+    /// The invoice has been finalized.
+    Finalized,
+}
+
+impl From<InvoiceStatus> for InvoiceStatusRecord {
+    fn from(status: InvoiceStatus) -> Self {
+        match status {
+            InvoiceStatus::Draft => InvoiceStatusRecord::Draft,
+            InvoiceStatus::Finalized => InvoiceStatusRecord::Finalized,
+        }
+    }
+}
+
+impl From<InvoiceStatusRecord> for InvoiceStatus {
+    fn from(status: InvoiceStatusRecord) -> Self {
+        match status {
+            InvoiceStatusRecord::Draft => InvoiceStatus::Draft,
+            InvoiceStatusRecord::Finalized => InvoiceStatus::Finalized,
+        }
+    }
+}
+
+/// A row from the `invoices` table.
+#[derive(Debug, FromRow)]
+pub struct InvoiceRecord {
+    /// Unique identifier for the invoice.
+    pub id: Uuid,
+    /// The user who owns the invoice.
+    pub user_id: Uuid,
+    /// The company being billed.
+    pub company_id: Uuid,
+    /// Current lifecycle state of the invoice.
+    pub status: InvoiceStatusRecord,
+    /// Sequential number among the user's invoices. `None` for drafts.
+    pub invoice_number: Option<i32>,
+    /// Date payment is due.
+    pub due_date: Option<NaiveDate>,
+    /// Free-form notes shown on the invoice.
+    pub notes: Option<String>,
+    /// When the invoice was finalized. `None` for drafts.
+    pub finalized_at: Option<DateTime<Utc>>,
+    /// When the invoice was created.
+    pub created_at: DateTime<Utc>,
+    /// When the invoice was last updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl InvoiceRecord {
+    /// Converts the record into an [`Invoice`] with the given line items.
+    ///
+    /// # Arguments
+    ///
+    /// * `line_items` — The invoice's line items.
+    ///
+    /// # Returns
+    ///
+    /// The [`Invoice`], with its total computed from the line items.
+    pub fn into_invoice(self, line_items: Vec<InvoiceLineItem>) -> Invoice {
+        Invoice {
+            id: self.id,
+            user_id: self.user_id,
+            company_id: self.company_id,
+            status: self.status.into(),
+            invoice_number: self.invoice_number,
+            due_date: self.due_date,
+            notes: self.notes,
+            total_amount: BillingUtil::line_item_total(&line_items),
+            line_items,
+            finalized_at: self.finalized_at,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+/// Repository for invoice database operations.
+pub struct InvoiceRepo;
+
+impl InvoiceRepo {
+    /// Attaches a company's unbilled work sessions to a draft invoice.
+    ///
+    /// Runs in a single transaction: locks the company's unbilled sessions,
+    /// reuses the company's open draft invoice (or creates one), inserts one
+    /// line item per job, and marks the sessions as billed by setting their
+    /// `invoice_id`. Either every session is billed or none are.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the company.
+    /// * `company_id` — The UUID of the company being billed.
+    /// * `started_between` — When set, only sessions that started at or
+    ///   after the first instant and before the second are billed.
+    ///
+    /// # Returns
+    ///
+    /// The draft [`Invoice`] with all of its line items.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the company has no
+    /// unbilled work sessions. Returns an error if any query fails.
+    pub async fn attach_unbilled_sessions(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        company_id: Uuid,
+        started_between: Option<(DateTime<Utc>, DateTime<Utc>)>,
+    ) -> ApiResult<Invoice> {
+        let (started_from, started_before) = started_between.unzip();
+        let mut tx = pool.begin().await?;
+
+        let sessions = sqlx::query_as!(
+            UnbilledSessionRecord,
+            r#"
+        SELECT ws.id,
+               ws.job_id,
+               j.title AS job_title,
+               ws.hourly_rate::FLOAT8 AS hourly_rate,
+               EXTRACT(EPOCH FROM (ws.end_time - ws.start_time - ws.accumulated_paused_duration))::BIGINT
+                   AS "net_seconds!"
+        FROM work_sessions ws
+        JOIN jobs j ON j.id = ws.job_id
+        WHERE ws.user_id = $1
+          AND j.company_id = $2
+          AND ws.is_running = FALSE
+          AND ws.end_time IS NOT NULL
+          AND ws.invoice_id IS NULL
+          AND ($3::TIMESTAMPTZ IS NULL OR ws.start_time >= $3)
+          AND ($4::TIMESTAMPTZ IS NULL OR ws.start_time < $4)
+        ORDER BY j.title, ws.job_id, ws.hourly_rate, ws.start_time
+        FOR UPDATE OF ws
+        "#,
+            user_id,
+            company_id,
+            started_from,
+            started_before,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        if sessions.is_empty() {
+            return Err(ApiErrorResponse::BadRequest(
+                "No unbilled work sessions for this company".to_string(),
+            ));
+        }
+
+        let existing_draft_id = sqlx::query_scalar!(
+            r#"
+        SELECT id
+        FROM invoices
+        WHERE user_id = $1 AND company_id = $2 AND status = 'draft'
+        ORDER BY created_at DESC
+        LIMIT 1
+        FOR UPDATE
+        "#,
+            user_id,
+            company_id,
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let invoice_id = match existing_draft_id {
+            Some(id) => id,
+            None => {
+                sqlx::query_scalar!(
+                    r#"
+        INSERT INTO invoices (user_id, company_id)
+        VALUES ($1, $2)
+        RETURNING id
+        "#,
+                    user_id,
+                    company_id,
+                )
+                .fetch_one(&mut *tx)
+                .await?
+            }
+        };
+
+        for group in BillingUtil::group_unbilled_sessions(&sessions) {
+            sqlx::query!(
+                r#"
+        INSERT INTO invoice_line_items
+            (invoice_id, job_id, description, session_count, duration_seconds,
+             rounded_hours, hourly_rate, amount)
+        VALUES ($1, $2, $3, $4, $5, $6::FLOAT8, $7::FLOAT8, $8::FLOAT8)
+        "#,
+                invoice_id,
+                group.job_id,
+                group.job_title,
+                group.work_session_ids.len() as i32,
+                group.duration_seconds,
+                group.rounded_hours,
+                group.hourly_rate,
+                group.amount,
+            )
+            .execute(&mut *tx)
+            .await?;
+
+            sqlx::query!(
+                r#"
+        UPDATE work_sessions
+        SET invoice_id = $1, updated_at = NOW()
+        WHERE id = ANY($2) AND user_id = $3
+        "#,
+                invoice_id,
+                &group.work_session_ids,
+                user_id,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        sqlx::query!(
+            r#"
+        UPDATE invoices
+        SET updated_at = NOW()
+        WHERE id = $1
+        "#,
+            invoice_id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Self::find_invoice_by_id(pool, user_id, invoice_id).await
+    }
+
+    /// Lists the user's invoices, including their line items.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the invoices.
+    /// * `query` — The [`ListInvoicesQuery`] filters.
+    ///
+    /// # Returns
+    ///
+    /// The matching [`Invoice`] values, newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a query fails.
+    pub async fn list_invoices(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        query: &ListInvoicesQuery,
+    ) -> ApiResult<Vec<Invoice>> {
+        let records = sqlx::query_as!(
+            InvoiceRecord,
+            r#"
+        SELECT id, user_id, company_id, status AS "status: InvoiceStatusRecord",
+               invoice_number, due_date, notes, finalized_at, created_at, updated_at
+        FROM invoices
+        WHERE user_id = $1
+          AND ($2::UUID IS NULL OR company_id = $2)
+          AND ($3::invoice_status IS NULL OR status = $3)
+        ORDER BY created_at DESC, id
+        "#,
+            user_id,
+            query.company_id,
+            query.status.map(InvoiceStatusRecord::from) as Option<InvoiceStatusRecord>,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let invoice_ids: Vec<Uuid> = records.iter().map(|record| record.id).collect();
+        let mut line_items: HashMap<Uuid, Vec<InvoiceLineItem>> = HashMap::new();
+
+        for line_item in Self::list_line_items(pool, &invoice_ids).await? {
+            line_items
+                .entry(line_item.invoice_id)
+                .or_default()
+                .push(line_item);
+        }
+
+        Ok(records
+            .into_iter()
+            .map(|record| {
+                let items = line_items.remove(&record.id).unwrap_or_default();
+                record.into_invoice(items)
+            })
+            .collect())
+    }
+
+    /// Finds an invoice by ID, including its line items.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the invoice.
+    /// * `invoice_id` — The invoice's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The matching [`Invoice`] with its line items.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no invoice with the given ID exists for the user.
+    pub async fn find_invoice_by_id(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        invoice_id: Uuid,
+    ) -> ApiResult<Invoice> {
+        let record = sqlx::query_as!(
+            InvoiceRecord,
+            r#"
+        SELECT id, user_id, company_id, status AS "status: InvoiceStatusRecord",
+               invoice_number, due_date, notes, finalized_at, created_at, updated_at
+        FROM invoices
+        WHERE id = $1 AND user_id = $2
+        "#,
+            invoice_id,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        let line_items = Self::list_line_items(pool, &[record.id]).await?;
+
+        Ok(record.into_invoice(line_items))
+    }
+
+    /// Updates a draft invoice's due date and notes.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the invoice.
+    /// * `invoice_id` — The invoice's UUID.
+    /// * `request` — The [`UpdateInvoiceRequest`] with the fields to change.
+    ///
+    /// # Returns
+    ///
+    /// The updated [`Invoice`] with its line items.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the invoice is finalized.
+    /// Returns an error if no invoice with the given ID exists for the user
+    /// or a query fails.
+    pub async fn update_invoice(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        invoice_id: Uuid,
+        request: &UpdateInvoiceRequest,
+    ) -> ApiResult<Invoice> {
+        let mut tx = pool.begin().await?;
+
+        let invoice = sqlx::query!(
+            r#"
+        SELECT status AS "status: InvoiceStatusRecord", due_date, notes
+        FROM invoices
+        WHERE id = $1 AND user_id = $2
+        FOR UPDATE
+        "#,
+            invoice_id,
+            user_id,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if matches!(invoice.status, InvoiceStatusRecord::Finalized) {
+            return Err(ApiErrorResponse::BadRequest(
+                "Finalized invoices cannot be modified".to_string(),
+            ));
+        }
+
+        let due_date = request.due_date.unwrap_or(invoice.due_date);
+        let notes = request.notes.clone().unwrap_or(invoice.notes);
+
+        sqlx::query!(
+            r#"
+        UPDATE invoices
+        SET due_date = $3, notes = $4, updated_at = NOW()
+        WHERE id = $1 AND user_id = $2
+        "#,
+            invoice_id,
+            user_id,
+            due_date,
+            notes,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Self::find_invoice_by_id(pool, user_id, invoice_id).await
+    }
+
+    /// Finalizes a draft invoice and assigns it the user's next invoice
+    /// number.
+    ///
+    /// Numbers start at 1 for each user and are assigned in the order
+    /// invoices are finalized, so drafts that are deleted leave no gaps.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the invoice.
+    /// * `invoice_id` — The invoice's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The finalized [`Invoice`] with its line items.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the invoice is already
+    /// finalized. Returns an error if no invoice with the given ID exists
+    /// for the user or a query fails.
+    pub async fn finalize_invoice(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        invoice_id: Uuid,
+    ) -> ApiResult<Invoice> {
+        let mut tx = pool.begin().await?;
+
+        // Serializes numbering per user without blocking inserts that
+        // reference the user row.
+        sqlx::query!(
+            r#"
+        SELECT id
+        FROM users
+        WHERE id = $1
+        FOR NO KEY UPDATE
+        "#,
+            user_id,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let status = sqlx::query_scalar!(
+            r#"
+        SELECT status AS "status: InvoiceStatusRecord"
+        FROM invoices
+        WHERE id = $1 AND user_id = $2
+        FOR UPDATE
+        "#,
+            invoice_id,
+            user_id,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if matches!(status, InvoiceStatusRecord::Finalized) {
+            return Err(ApiErrorResponse::BadRequest(
+                "Invoice is already finalized".to_string(),
+            ));
+        }
+
+        sqlx::query!(
+            r#"
+        UPDATE invoices
+        SET status = 'finalized',
+            invoice_number = (
+                SELECT COALESCE(MAX(invoice_number), 0) + 1
+                FROM invoices
+                WHERE user_id = $2
+            ),
+            finalized_at = NOW(),
+            updated_at = NOW()
+        WHERE id = $1 AND user_id = $2
+        "#,
+            invoice_id,
+            user_id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Self::find_invoice_by_id(pool, user_id, invoice_id).await
+    }
+
+    /// Deletes a draft invoice, returning its work sessions to the unbilled
+    /// pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the invoice.
+    /// * `invoice_id` — The invoice's UUID.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the invoice was deleted, `false` if it was not found.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the invoice is finalized.
+    /// Returns an error if a query fails.
+    pub async fn delete_invoice(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        invoice_id: Uuid,
+    ) -> ApiResult<bool> {
+        let mut tx = pool.begin().await?;
+
+        let status = sqlx::query_scalar!(
+            r#"
+        SELECT status AS "status: InvoiceStatusRecord"
+        FROM invoices
+        WHERE id = $1 AND user_id = $2
+        FOR UPDATE
+        "#,
+            invoice_id,
+            user_id,
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        match status {
+            None => return Ok(false),
+            Some(InvoiceStatusRecord::Finalized) => {
+                return Err(ApiErrorResponse::BadRequest(
+                    "Finalized invoices cannot be deleted".to_string(),
+                ));
+            }
+            Some(InvoiceStatusRecord::Draft) => {}
+        }
+
+        sqlx::query!(
+            r#"
+        DELETE FROM invoices
+        WHERE id = $1 AND user_id = $2
+        "#,
+            invoice_id,
+            user_id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(true)
+    }
+
+    /// Loads the line items of the given invoices.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `invoice_ids` — The UUIDs of the invoices.
+    ///
+    /// # Returns
+    ///
+    /// The [`InvoiceLineItem`] values of every listed invoice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    async fn list_line_items(
+        pool: &Pool<Postgres>,
+        invoice_ids: &[Uuid],
+    ) -> ApiResult<Vec<InvoiceLineItem>> {
+        let line_items = sqlx::query_as!(
+            InvoiceLineItem,
+            r#"
+        SELECT id, invoice_id, job_id, description, session_count, duration_seconds,
+               rounded_hours::FLOAT8 AS "rounded_hours!",
+               hourly_rate::FLOAT8 AS hourly_rate,
+               amount::FLOAT8 AS amount,
+               created_at
+        FROM invoice_line_items
+        WHERE invoice_id = ANY($1)
+        ORDER BY created_at, description
+        "#,
+            invoice_ids,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(line_items)
+    }
+}
@@ -0,0 +1,824 @@
+//! Job database operations.
+//!
+//! Provides [`JobRepo`] for querying job records in the `jobs` table.
+//! All queries are scoped to the owning user. Payment type conversions are
+//! recorded in the `job_rate_changes` table.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Pool, Postgres};
+use uuid::Uuid;
+
+use gig_log_common::{
+    models::{
+        job::{
+            ConvertPaymentTypeRequest, ConvertPaymentTypeResponse, Job, JobBoardColumn, JobMove,
+            JobRateChange, PaymentType, UpdateJobRequest,
+        },
+        toggl::TogglProject,
+    },
+    validators::job::validate_payment_fields,
+};
+
+use crate::billing::BillingUtil;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::repo::{
+    onboarding_state::{OnboardingEvent, OnboardingStateRepo},
+    payment::{PaymentRecord, PayoutTypeRecord},
+    work_session::UnbilledSessionRecord,
+};
+
+/// How a job row compensates the worker.
+///
+/// Maps to the PostgreSQL `payment_type` enum.
+#[derive(Debug, Clone, sqlx::Type)]
+#[sqlx(type_name = "payment_type", rename_all = "snake_case")]
+pub enum PaymentTypeRecord {
+    /// Paid based on hours worked.
+    Hourly,
+    /// Paid via a fixed number of payouts.
+    Payouts,
+}
+
+impl From<PaymentTypeRecord> for PaymentType {
+    fn from(payment_type: PaymentTypeRecord) -> Self {
+        match payment_type {
+            PaymentTypeRecord::Hourly => PaymentType::Hourly,
+            PaymentTypeRecord::Payouts => PaymentType::Payouts,
+        }
+    }
+}
+
+impl From<PaymentType> for PaymentTypeRecord {
+    fn from(payment_type: PaymentType) -> Self {
+        match payment_type {
+            PaymentType::Hourly => PaymentTypeRecord::Hourly,
+            PaymentType::Payouts => PaymentTypeRecord::Payouts,
+        }
+    }
+}
+
+/// Job board column of a job row.
+///
+/// Maps to the PostgreSQL `job_board_column` enum.
+#[derive(Debug, Clone, sqlx::Type)]
+#[sqlx(type_name = "job_board_column", rename_all = "snake_case")]
+pub enum JobBoardColumnRecord {
+    /// Prospective work that has not started.
+    Lead,
+    /// Work in progress.
+    Active,
+    /// Work that has been invoiced.
+    Invoiced,
+    /// Work that has been paid for.
+    Paid,
+}
+
+impl From<JobBoardColumnRecord> for JobBoardColumn {
+    fn from(column: JobBoardColumnRecord) -> Self {
+        match column {
+            JobBoardColumnRecord::Lead => JobBoardColumn::Lead,
+            JobBoardColumnRecord::Active => JobBoardColumn::Active,
+            JobBoardColumnRecord::Invoiced => JobBoardColumn::Invoiced,
+            JobBoardColumnRecord::Paid => JobBoardColumn::Paid,
+        }
+    }
+}
+
+impl From<JobBoardColumn> for JobBoardColumnRecord {
+    fn from(column: JobBoardColumn) -> Self {
+        match column {
+            JobBoardColumn::Lead => JobBoardColumnRecord::Lead,
+            JobBoardColumn::Active => JobBoardColumnRecord::Active,
+            JobBoardColumn::Invoiced => JobBoardColumnRecord::Invoiced,
+            JobBoardColumn::Paid => JobBoardColumnRecord::Paid,
+        }
+    }
+}
+
+/// A row from the `jobs` table.
+#[derive(Debug, FromRow)]
+pub struct JobRecord {
+    /// Unique identifier for the job.
+    pub id: Uuid,
+    /// The company this job belongs to.
+    pub company_id: Uuid,
+    /// The user who owns this job.
+    pub user_id: Uuid,
+    /// Title of the job.
+    pub title: String,
+    /// How the job compensates the worker.
+    pub payment_type: PaymentTypeRecord,
+    /// Hourly rate in dollars.
+    pub hourly_rate: Option<f64>,
+    /// Total number of payouts.
+    pub number_of_payouts: Option<i32>,
+    /// Amount per payout in dollars.
+    pub payout_amount: Option<f64>,
+    /// Job board column the job is in.
+    pub board_column: JobBoardColumnRecord,
+    /// Position of the job within its board column.
+    pub board_position: i32,
+    /// When the job was created.
+    pub created_at: DateTime<Utc>,
+    /// When the job was last updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<JobRecord> for Job {
+    fn from(record: JobRecord) -> Self {
+        Self {
+            id: record.id,
+            company_id: record.company_id,
+            user_id: record.user_id,
+            title: record.title,
+            payment_type: record.payment_type.into(),
+            hourly_rate: record.hourly_rate,
+            number_of_payouts: record.number_of_payouts,
+            payout_amount: record.payout_amount,
+            board_column: record.board_column.into(),
+            board_position: record.board_position,
+            created_at: record.created_at,
+            updated_at: record.updated_at,
+        }
+    }
+}
+
+/// A row from the `job_rate_changes` table.
+#[derive(Debug, FromRow)]
+pub struct JobRateChangeRecord {
+    /// Unique identifier for the change.
+    pub id: Uuid,
+    /// The job whose terms changed.
+    pub job_id: Uuid,
+    /// Payment type before the change.
+    pub previous_payment_type: PaymentTypeRecord,
+    /// Hourly rate in dollars before the change.
+    pub previous_hourly_rate: Option<f64>,
+    /// Number of payouts before the change.
+    pub previous_number_of_payouts: Option<i32>,
+    /// Amount per payout in dollars before the change.
+    pub previous_payout_amount: Option<f64>,
+    /// Payment type after the change.
+    pub payment_type: PaymentTypeRecord,
+    /// Hourly rate in dollars after the change.
+    pub hourly_rate: Option<f64>,
+    /// Number of payouts after the change.
+    pub number_of_payouts: Option<i32>,
+    /// Amount per payout in dollars after the change.
+    pub payout_amount: Option<f64>,
+    /// When the change was made.
+    pub changed_at: DateTime<Utc>,
+}
+
+impl From<JobRateChangeRecord> for JobRateChange {
+    fn from(record: JobRateChangeRecord) -> Self {
+        Self {
+            id: record.id,
+            job_id: record.job_id,
+            previous_payment_type: record.previous_payment_type.into(),
+            previous_hourly_rate: record.previous_hourly_rate,
+            previous_number_of_payouts: record.previous_number_of_payouts,
+            previous_payout_amount: record.previous_payout_amount,
+            payment_type: record.payment_type.into(),
+            hourly_rate: record.hourly_rate,
+            number_of_payouts: record.number_of_payouts,
+            payout_amount: record.payout_amount,
+            changed_at: record.changed_at,
+        }
+    }
+}
+
+/// Repository for job database operations.
+pub struct JobRepo;
+
+impl JobRepo {
+    /// Finds a job by ID, scoped to the owning user.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the job.
+    /// * `job_id` — The job's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The [`Job`] matching the given ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no job with the given ID exists for the user.
+    pub async fn find_job_by_id(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        job_id: Uuid,
+    ) -> ApiResult<Job> {
+        let record = sqlx::query_as!(
+            JobRecord,
+            r#"
+        SELECT id, company_id, user_id, title, payment_type AS "payment_type: PaymentTypeRecord",
+               hourly_rate::FLOAT8 AS hourly_rate, number_of_payouts,
+               payout_amount::FLOAT8 AS payout_amount,
+               board_column AS "board_column: JobBoardColumnRecord", board_position,
+               created_at, updated_at
+        FROM jobs
+        WHERE id = $1 AND user_id = $2
+        "#,
+            job_id,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record.into())
+    }
+
+    /// Finds a job's UUID from its Toggl ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the job.
+    /// * `toggl_id` — The job's numeric Toggl ID.
+    ///
+    /// # Returns
+    ///
+    /// The job's UUID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no job with the Toggl ID exists for the user.
+    pub async fn find_job_id_by_toggl_id(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        toggl_id: i64,
+    ) -> ApiResult<Uuid> {
+        let id = sqlx::query_scalar!(
+            r#"
+        SELECT id
+        FROM jobs
+        WHERE toggl_id = $1 AND user_id = $2
+        "#,
+            toggl_id,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(id)
+    }
+
+    /// Lists a user's jobs as Toggl projects.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the jobs.
+    /// * `workspace_id` — The workspace ID to report on each project.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`TogglProject`] values ordered by title.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_toggl_projects(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        workspace_id: i64,
+    ) -> ApiResult<Vec<TogglProject>> {
+        let projects = sqlx::query_as!(
+            TogglProject,
+            r#"
+        SELECT toggl_id AS id, $2::BIGINT AS "workspace_id!", title AS name,
+               TRUE AS "active!", payment_type = 'hourly' AS "billable!",
+               hourly_rate::FLOAT8 AS rate
+        FROM jobs
+        WHERE user_id = $1
+        ORDER BY title
+        "#,
+            user_id,
+            workspace_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(projects)
+    }
+
+    /// Finds a job by name, matching either its title or its company name
+    /// followed by its title, ignoring case.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the job.
+    /// * `name` — The job name to match.
+    ///
+    /// # Returns
+    ///
+    /// The most recently updated matching job's UUID, or `None` if no job
+    /// matches.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn find_job_id_by_name(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        name: &str,
+    ) -> ApiResult<Option<Uuid>> {
+        let id = sqlx::query_scalar!(
+            r#"
+        SELECT j.id
+        FROM jobs j
+        JOIN companies c ON c.id = j.company_id
+        WHERE j.user_id = $1
+          AND (LOWER(j.title) = LOWER($2) OR LOWER(c.name || ' ' || j.title) = LOWER($2))
+        ORDER BY j.updated_at DESC
+        LIMIT 1
+        "#,
+            user_id,
+            name,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(id)
+    }
+
+    /// Lists a user's jobs in job board order.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the jobs.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`Job`] values ordered by board column, then position.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_jobs_for_board(pool: &Pool<Postgres>, user_id: Uuid) -> ApiResult<Vec<Job>> {
+        let records = sqlx::query_as!(
+            JobRecord,
+            r#"
+        SELECT id, company_id, user_id, title, payment_type AS "payment_type: PaymentTypeRecord",
+               hourly_rate::FLOAT8 AS hourly_rate, number_of_payouts,
+               payout_amount::FLOAT8 AS payout_amount,
+               board_column AS "board_column: JobBoardColumnRecord", board_position,
+               created_at, updated_at
+        FROM jobs
+        WHERE user_id = $1
+        ORDER BY board_column, board_position, created_at
+        "#,
+            user_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records.into_iter().map(Into::into).collect())
+    }
+
+    /// Lists the jobs for one of a user's companies.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the jobs.
+    /// * `company_id` — The UUID of the company the jobs are for.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`Job`] values ordered by title.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_company_jobs(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        company_id: Uuid,
+    ) -> ApiResult<Vec<Job>> {
+        let records = sqlx::query_as!(
+            JobRecord,
+            r#"
+        SELECT id, company_id, user_id, title, payment_type AS "payment_type: PaymentTypeRecord",
+               hourly_rate::FLOAT8 AS hourly_rate, number_of_payouts,
+               payout_amount::FLOAT8 AS payout_amount,
+               board_column AS "board_column: JobBoardColumnRecord", board_position,
+               created_at, updated_at
+        FROM jobs
+        WHERE user_id = $1 AND company_id = $2
+        ORDER BY title, created_at
+        "#,
+            user_id,
+            company_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records.into_iter().map(Into::into).collect())
+    }
+
+    /// Moves jobs to new board columns and positions in one transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the jobs.
+    /// * `moves` — The new column and position of each moved job.
+    ///
+    /// # Returns
+    ///
+    /// `()` on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if any job does not exist for
+    /// the user, in which case no job is moved. Returns an error if the
+    /// update fails.
+    pub async fn reorder_jobs(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        moves: &[JobMove],
+    ) -> ApiResult<()> {
+        let mut tx = pool.begin().await?;
+
+        for job_move in moves {
+            let result = sqlx::query!(
+                r#"
+            UPDATE jobs
+            SET board_column = $3, board_position = $4, updated_at = now()
+            WHERE id = $1 AND user_id = $2
+            "#,
+                job_move.job_id,
+                user_id,
+                JobBoardColumnRecord::from(job_move.board_column) as JobBoardColumnRecord,
+                job_move.board_position,
+            )
+            .execute(&mut *tx)
+            .await?;
+
+            if result.rows_affected() == 0 {
+                return Err(ApiErrorResponse::NotFound("Job not found".to_string()));
+            }
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// Applies a partial update to a job.
+    ///
+    /// Omitted fields keep their stored values. The merged payment fields
+    /// are validated against the merged payment type before the row is
+    /// written.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the job.
+    /// * `job_id` — The job's UUID.
+    /// * `request` — The fields to change.
+    ///
+    /// # Returns
+    ///
+    /// The updated [`Job`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if no job with the given ID
+    /// exists for the user, or [`ApiErrorResponse::Validation`] if the
+    /// merged job is invalid.
+    pub async fn update_job(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        job_id: Uuid,
+        request: &UpdateJobRequest,
+    ) -> ApiResult<Job> {
+        let mut tx = pool.begin().await?;
+
+        let job: Job = sqlx::query_as!(
+            JobRecord,
+            r#"
+        SELECT id, company_id, user_id, title, payment_type AS "payment_type: PaymentTypeRecord",
+               hourly_rate::FLOAT8 AS hourly_rate, number_of_payouts,
+               payout_amount::FLOAT8 AS payout_amount,
+               board_column AS "board_column: JobBoardColumnRecord", board_position,
+               created_at, updated_at
+        FROM jobs
+        WHERE id = $1 AND user_id = $2
+        FOR UPDATE
+        "#,
+            job_id,
+            user_id,
+        )
+        .fetch_one(&mut *tx)
+        .await?
+        .into();
+
+        let title = request.title.clone().unwrap_or(job.title);
+        let payment_type = request.payment_type.clone().unwrap_or(job.payment_type);
+        let hourly_rate = request.hourly_rate.unwrap_or(job.hourly_rate);
+        let number_of_payouts = request.number_of_payouts.unwrap_or(job.number_of_payouts);
+        let payout_amount = request.payout_amount.unwrap_or(job.payout_amount);
+
+        validate_payment_fields(&payment_type, hourly_rate, number_of_payouts, payout_amount)?;
+
+        let record = sqlx::query_as!(
+            JobRecord,
+            r#"
+        UPDATE jobs
+        SET title = $3,
+            payment_type = $4,
+            hourly_rate = $5::FLOAT8::DECIMAL,
+            number_of_payouts = $6,
+            payout_amount = $7::FLOAT8::DECIMAL,
+            updated_at = now()
+        WHERE id = $1 AND user_id = $2
+        RETURNING id, company_id, user_id, title,
+                  payment_type AS "payment_type: PaymentTypeRecord",
+                  hourly_rate::FLOAT8 AS hourly_rate, number_of_payouts,
+                  payout_amount::FLOAT8 AS payout_amount,
+                  board_column AS "board_column: JobBoardColumnRecord", board_position,
+                  created_at, updated_at
+        "#,
+            job_id,
+            user_id,
+            title,
+            PaymentTypeRecord::from(payment_type) as PaymentTypeRecord,
+            hourly_rate,
+            number_of_payouts,
+            payout_amount,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(record.into())
+    }
+
+    /// Creates a copy of a job with the same company and payment terms.
+    ///
+    /// The copy's title gets a ` (copy)` suffix and it is placed at the end
+    /// of the active board column. Work sessions, budgets, and rate history
+    /// are not copied.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the job.
+    /// * `job_id` — The UUID of the job to copy.
+    ///
+    /// # Returns
+    ///
+    /// The newly created [`Job`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if no job with the given ID
+    /// exists for the user, or an error if the insert fails.
+    pub async fn duplicate_job(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        job_id: Uuid,
+    ) -> ApiResult<Job> {
+        let record = sqlx::query_as!(
+            JobRecord,
+            r#"
+        INSERT INTO jobs (company_id, user_id, title, payment_type, hourly_rate,
+                          number_of_payouts, payout_amount, board_position)
+        SELECT company_id, user_id, LEFT(title, 248) || ' (copy)', payment_type, hourly_rate,
+               number_of_payouts, payout_amount,
+               (SELECT COALESCE(MAX(board_position) + 1, 0)
+                FROM jobs
+                WHERE user_id = $2 AND board_column = 'active')
+        FROM jobs
+        WHERE id = $1 AND user_id = $2
+        RETURNING id, company_id, user_id, title, payment_type AS "payment_type: PaymentTypeRecord",
+                  hourly_rate::FLOAT8 AS hourly_rate, number_of_payouts,
+                  payout_amount::FLOAT8 AS payout_amount,
+                  board_column AS "board_column: JobBoardColumnRecord", board_position,
+                  created_at, updated_at
+        "#,
+            job_id,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record.into())
+    }
+
+    /// Converts a job to another payment type.
+    ///
+    /// Runs in a single transaction: locks the job, optionally prices its
+    /// unbilled hours at the rates they were tracked at and creates one
+    /// expected payment per payout from the total, updates the job's payment
+    /// terms, and records the change in the job's rate history.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the job.
+    /// * `job_id` — The job's UUID.
+    /// * `request` — The new payment terms.
+    ///
+    /// # Returns
+    ///
+    /// A [`ConvertPaymentTypeResponse`] with the converted job, the
+    /// recorded change, and any created installments.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if no job with the given ID
+    /// exists for the user, or [`ApiErrorResponse::BadRequest`] if the job
+    /// already has the requested payment type or has too little unbilled
+    /// work to fill every installment. Returns an error if any query fails.
+    pub async fn convert_payment_type(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        job_id: Uuid,
+        request: &ConvertPaymentTypeRequest,
+    ) -> ApiResult<ConvertPaymentTypeResponse> {
+        let mut tx = pool.begin().await?;
+
+        let job: Job = sqlx::query_as!(
+            JobRecord,
+            r#"
+        SELECT id, company_id, user_id, title, payment_type AS "payment_type: PaymentTypeRecord",
+               hourly_rate::FLOAT8 AS hourly_rate, number_of_payouts,
+               payout_amount::FLOAT8 AS payout_amount,
+               board_column AS "board_column: JobBoardColumnRecord", board_position,
+               created_at, updated_at
+        FROM jobs
+        WHERE id = $1 AND user_id = $2
+        FOR UPDATE
+        "#,
+            job_id,
+            user_id,
+        )
+        .fetch_one(&mut *tx)
+        .await?
+        .into();
+
+        if job.payment_type == request.payment_type {
+            return Err(ApiErrorResponse::BadRequest(format!(
+                "Job is already paid {}",
+                match job.payment_type {
+                    PaymentType::Hourly => "hourly",
+                    PaymentType::Payouts => "in payouts",
+                }
+            )));
+        }
+
+        let mut payout_amount = request.payout_amount;
+        let mut installments = Vec::new();
+
+        if let (Some(payout_type), Some(number_of_payouts)) =
+            (&request.installment_payout_type, request.number_of_payouts)
+        {
+            let sessions = sqlx::query_as!(
+                UnbilledSessionRecord,
+                r#"
+        SELECT ws.id,
+               ws.job_id,
+               j.title AS job_title,
+               ws.hourly_rate::FLOAT8 AS hourly_rate,
+               EXTRACT(EPOCH FROM (ws.end_time - ws.start_time - ws.accumulated_paused_duration))::BIGINT
+                   AS "net_seconds!"
+        FROM work_sessions ws
+        JOIN jobs j ON j.id = ws.job_id
+        WHERE ws.user_id = $1
+          AND ws.job_id = $2
+          AND ws.is_running = FALSE
+          AND ws.end_time IS NOT NULL
+          AND ws.invoice_id IS NULL
+        ORDER BY ws.hourly_rate, ws.start_time
+        FOR UPDATE OF ws
+        "#,
+                user_id,
+                job_id,
+            )
+            .fetch_all(&mut *tx)
+            .await?;
+
+            let accrued =
+                BillingUtil::total_amount(&BillingUtil::group_unbilled_sessions(&sessions));
+            let amounts = BillingUtil::split_installments(accrued, number_of_payouts);
+
+            if amounts.iter().any(|amount| *amount <= 0.0) {
+                return Err(ApiErrorResponse::BadRequest(
+                    "Not enough unbilled work to fill every installment".to_string(),
+                ));
+            }
+
+            payout_amount = amounts.first().copied();
+
+            for total in amounts {
+                let record = sqlx::query_as!(
+                    PaymentRecord,
+                    r#"
+        INSERT INTO payments (user_id, company_id, total, payout_type)
+        VALUES ($1, $2, $3::FLOAT8::DECIMAL, $4)
+        RETURNING id, user_id, company_id, total::FLOAT8 AS "total!",
+                  payout_type AS "payout_type: PayoutTypeRecord",
+                  expected_payout_date, transfer_initiated, payment_received,
+                  tax_withholdings_covered, tax_withholding_rate::FLOAT8 AS tax_withholding_rate,
+                  amount_paid::FLOAT8 AS "amount_paid!",
+                  created_at, updated_at
+        "#,
+                    user_id,
+                    job.company_id,
+                    total,
+                    PayoutTypeRecord::from(payout_type.clone()) as PayoutTypeRecord,
+                )
+                .fetch_one(&mut *tx)
+                .await?;
+
+                installments.push(record.into());
+            }
+
+            OnboardingStateRepo::record(&mut *tx, user_id, OnboardingEvent::PaymentRecorded)
+                .await?;
+        }
+
+        let record = sqlx::query_as!(
+            JobRecord,
+            r#"
+        UPDATE jobs
+        SET payment_type = $3,
+            hourly_rate = $4::FLOAT8::DECIMAL,
+            number_of_payouts = $5,
+            payout_amount = $6::FLOAT8::DECIMAL,
+            updated_at = now()
+        WHERE id = $1 AND user_id = $2
+        RETURNING id, company_id, user_id, title,
+                  payment_type AS "payment_type: PaymentTypeRecord",
+                  hourly_rate::FLOAT8 AS hourly_rate, number_of_payouts,
+                  payout_amount::FLOAT8 AS payout_amount,
+                  board_column AS "board_column: JobBoardColumnRecord", board_position,
+                  created_at, updated_at
+        "#,
+            job_id,
+            user_id,
+            PaymentTypeRecord::from(request.payment_type.clone()) as PaymentTypeRecord,
+            request.hourly_rate,
+            request.number_of_payouts,
+            payout_amount,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let rate_change = sqlx::query_as!(
+            JobRateChangeRecord,
+            r#"
+        INSERT INTO job_rate_changes (
+            job_id, user_id,
+            previous_payment_type, previous_hourly_rate, previous_number_of_payouts,
+            previous_payout_amount,
+            payment_type, hourly_rate, number_of_payouts, payout_amount
+        )
+        VALUES (
+            $1, $2,
+            $3, $4::FLOAT8::DECIMAL, $5, $6::FLOAT8::DECIMAL,
+            $7, $8::FLOAT8::DECIMAL, $9, $10::FLOAT8::DECIMAL
+        )
+        RETURNING id, job_id,
+                  previous_payment_type AS "previous_payment_type: PaymentTypeRecord",
+                  previous_hourly_rate::FLOAT8 AS previous_hourly_rate,
+                  previous_number_of_payouts,
+                  previous_payout_amount::FLOAT8 AS previous_payout_amount,
+                  payment_type AS "payment_type: PaymentTypeRecord",
+                  hourly_rate::FLOAT8 AS hourly_rate, number_of_payouts,
+                  payout_amount::FLOAT8 AS payout_amount,
+                  changed_at
+        "#,
+            job_id,
+            user_id,
+            PaymentTypeRecord::from(job.payment_type) as PaymentTypeRecord,
+            job.hourly_rate,
+            job.number_of_payouts,
+            job.payout_amount,
+            PaymentTypeRecord::from(request.payment_type.clone()) as PaymentTypeRecord,
+            request.hourly_rate,
+            request.number_of_payouts,
+            payout_amount,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(ConvertPaymentTypeResponse {
+            job: record.into(),
+            rate_change: rate_change.into(),
+            installments,
+        })
+    }
+}
@@ -0,0 +1,618 @@
+//! Lead database operations.
+//!
+//! Provides [`LeadRepo`] for managing records in the `leads` table,
+//! converting leads into a company and job, and computing conversion
+//! statistics. All queries are scoped to the owning user.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{FromRow, Pool, Postgres};
+use uuid::Uuid;
+
+use gig_log_common::models::{
+    company::Company,
+    job::Job,
+    lead::{
+        ConvertLeadRequest, CreateLeadRequest, Lead, LeadConversionResponse, LeadSourceStats,
+        LeadStats, LeadStatus, UpdateLeadRequest,
+    },
+};
+
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::repo::job::{JobBoardColumnRecord, JobRecord, PaymentTypeRecord};
+use crate::repo::onboarding_state::{OnboardingEvent, OnboardingStateRepo};
+
+/// Pipeline status of a lead row.
+///
+/// Maps to the PostgreSQL `lead_status` enum.
+#[derive(Debug, Clone, PartialEq, sqlx::Type)]
+#[sqlx(type_name = "lead_status", rename_all = "snake_case")]
+pub enum LeadStatusRecord {
+    /// Still being pursued.
+    Open,
+    /// Converted into a company and job.
+    Won,
+    /// Did not turn into work.
+    Lost,
+}
+
+impl From<LeadStatusRecord> for LeadStatus {
+    fn from(status: LeadStatusRecord) -> Self {
+        match status {
+            LeadStatusRecord::Open => LeadStatus::Open,
+            LeadStatusRecord::Won => LeadStatus::Won,
+            LeadStatusRecord::Lost => LeadStatus::Lost,
+        }
+    }
+}
+
+impl From<LeadStatus> for LeadStatusRecord {
+    fn from(status: LeadStatus) -> Self {
+        match status {
+            LeadStatus::Open => LeadStatusRecord::Open,
+            LeadStatus::Won => LeadStatusRecord::Won,
+            LeadStatus::Lost => LeadStatusRecord::Lost,
+        }
+    }
+}
+
+/// A row from the `leads` table.
+#[derive(Debug, FromRow)]
+pub struct LeadRecord {
+    /// Unique identifier for the lead.
+    pub id: Uuid,
+    /// The user who owns this lead.
+    pub user_id: Uuid,
+    /// Short description of the opportunity.
+    pub title: String,
+    /// Name of the prospective client.
+    pub company_name: String,
+    /// Where the lead came from.
+    pub source: Option<String>,
+    /// Expected value of the work in dollars.
+    pub expected_value: Option<f64>,
+    /// Estimated chance of winning the work, from 0 to 100.
+    pub probability: i32,
+    /// Date to follow up with the prospective client.
+    pub follow_up_date: Option<NaiveDate>,
+    /// Free-form notes.
+    pub notes: Option<String>,
+    /// Where the lead is in the pipeline.
+    pub status: LeadStatusRecord,
+    /// The job the lead was converted into.
+    pub converted_job_id: Option<Uuid>,
+    /// When the lead was won or lost.
+    pub closed_at: Option<DateTime<Utc>>,
+    /// When the lead was created.
+    pub created_at: DateTime<Utc>,
+    /// When the lead was last updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<LeadRecord> for Lead {
+    fn from(record: LeadRecord) -> Self {
+        Self {
+            id: record.id,
+            user_id: record.user_id,
+            title: record.title,
+            company_name: record.company_name,
+            source: record.source,
+            expected_value: record.expected_value,
+            probability: record.probability,
+            follow_up_date: record.follow_up_date,
+            notes: record.notes,
+            status: record.status.into(),
+            converted_job_id: record.converted_job_id,
+            closed_at: record.closed_at,
+            created_at: record.created_at,
+            updated_at: record.updated_at,
+        }
+    }
+}
+
+/// Repository for lead database operations.
+pub struct LeadRepo;
+
+impl LeadRepo {
+    /// Inserts a new lead.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the lead.
+    /// * `request` — The lead details.
+    ///
+    /// # Returns
+    ///
+    /// The created [`Lead`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert query fails.
+    pub async fn insert_lead(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        request: CreateLeadRequest,
+    ) -> ApiResult<Lead> {
+        let record = sqlx::query_as!(
+            LeadRecord,
+            r#"
+        INSERT INTO leads (user_id, title, company_name, source, expected_value, probability,
+                           follow_up_date, notes)
+        VALUES ($1, $2, $3, $4, $5::FLOAT8::DECIMAL, COALESCE($6, 50), $7, $8)
+        RETURNING id, user_id, title, company_name, source,
+                  expected_value::FLOAT8 AS expected_value, probability, follow_up_date, notes,
+                  status AS "status: LeadStatusRecord", converted_job_id, closed_at,
+                  created_at, updated_at
+        "#,
+            user_id,
+            request.title,
+            request.company_name,
+            request.source,
+            request.expected_value,
+            request.probability,
+            request.follow_up_date,
+            request.notes,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record.into())
+    }
+
+    /// Lists a user's leads, soonest follow-up first.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the leads.
+    /// * `status` — Only include leads with this status, if set.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`Lead`] values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_leads(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        status: Option<LeadStatus>,
+    ) -> ApiResult<Vec<Lead>> {
+        let records = sqlx::query_as!(
+            LeadRecord,
+            r#"
+        SELECT id, user_id, title, company_name, source,
+               expected_value::FLOAT8 AS expected_value, probability, follow_up_date, notes,
+               status AS "status: LeadStatusRecord", converted_job_id, closed_at,
+               created_at, updated_at
+        FROM leads
+        WHERE user_id = $1 AND ($2::lead_status IS NULL OR status = $2)
+        ORDER BY follow_up_date NULLS LAST, created_at DESC
+        "#,
+            user_id,
+            status.map(LeadStatusRecord::from) as Option<LeadStatusRecord>,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records.into_iter().map(Into::into).collect())
+    }
+
+    /// Finds one of a user's leads by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the lead.
+    /// * `lead_id` — The lead's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The matching [`Lead`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no lead with the given ID exists for the user.
+    pub async fn find_lead_by_id(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        lead_id: Uuid,
+    ) -> ApiResult<Lead> {
+        let record = sqlx::query_as!(
+            LeadRecord,
+            r#"
+        SELECT id, user_id, title, company_name, source,
+               expected_value::FLOAT8 AS expected_value, probability, follow_up_date, notes,
+               status AS "status: LeadStatusRecord", converted_job_id, closed_at,
+               created_at, updated_at
+        FROM leads
+        WHERE id = $1 AND user_id = $2
+        "#,
+            lead_id,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record.into())
+    }
+
+    /// Updates a lead's details and status.
+    ///
+    /// Setting the status to `lost` records when the lead was closed;
+    /// reopening clears it.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the lead.
+    /// * `lead_id` — The lead's UUID.
+    /// * `request` — The fields to update.
+    ///
+    /// # Returns
+    ///
+    /// The updated [`Lead`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no lead with the given ID exists for the user.
+    pub async fn update_lead(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        lead_id: Uuid,
+        request: UpdateLeadRequest,
+    ) -> ApiResult<Lead> {
+        let record = sqlx::query_as!(
+            LeadRecord,
+            r#"
+        UPDATE leads
+        SET title = COALESCE($3, title),
+            company_name = COALESCE($4, company_name),
+            source = COALESCE($5, source),
+            expected_value = COALESCE($6::FLOAT8::DECIMAL, expected_value),
+            probability = COALESCE($7, probability),
+            follow_up_date = COALESCE($8, follow_up_date),
+            notes = COALESCE($9, notes),
+            closed_at = CASE
+                WHEN $10::lead_status IS NULL OR $10 = status THEN closed_at
+                WHEN $10 = 'open' THEN NULL
+                ELSE now()
+            END,
+            status = COALESCE($10, status),
+            updated_at = now()
+        WHERE id = $1 AND user_id = $2
+        RETURNING id, user_id, title, company_name, source,
+                  expected_value::FLOAT8 AS expected_value, probability, follow_up_date, notes,
+                  status AS "status: LeadStatusRecord", converted_job_id, closed_at,
+                  created_at, updated_at
+        "#,
+            lead_id,
+            user_id,
+            request.title,
+            request.company_name,
+            request.source,
+            request.expected_value,
+            request.probability,
+            request.follow_up_date,
+            request.notes,
+            request.status.map(LeadStatusRecord::from) as Option<LeadStatusRecord>,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record.into())
+    }
+
+    /// Deletes one of a user's leads.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the lead.
+    /// * `lead_id` — The lead's UUID.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a lead was deleted, `false` if none matched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete query fails.
+    pub async fn delete_lead(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        lead_id: Uuid,
+    ) -> ApiResult<bool> {
+        let result = sqlx::query!(
+            r#"
+        DELETE FROM leads
+        WHERE id = $1 AND user_id = $2
+        "#,
+            lead_id,
+            user_id,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Converts an open lead into a job, creating its company if needed.
+    ///
+    /// The company, job, and lead update are written in one transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the lead.
+    /// * `lead_id` — The lead's UUID.
+    /// * `request` — The job's payment terms and optional existing company.
+    ///
+    /// # Returns
+    ///
+    /// A [`LeadConversionResponse`] with the won lead, company, and job.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the lead or company does not
+    /// exist, [`ApiErrorResponse::BadRequest`] if the lead is not open, or an
+    /// error if any query fails.
+    pub async fn convert_lead(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        lead_id: Uuid,
+        request: ConvertLeadRequest,
+    ) -> ApiResult<LeadConversionResponse> {
+        let mut tx = pool.begin().await?;
+
+        let lead = sqlx::query!(
+            r#"
+        SELECT title, company_name, status AS "status: LeadStatusRecord"
+        FROM leads
+        WHERE id = $1 AND user_id = $2
+        FOR UPDATE
+        "#,
+            lead_id,
+            user_id,
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| ApiErrorResponse::NotFound("Lead not found".to_string()))?;
+
+        Self::check_convertible(&lead.status)?;
+
+        let company = match request.company_id {
+            Some(company_id) => sqlx::query_as!(
+                Company,
+                r#"
+            SELECT id, user_id, name, requires_tax_withholdings,
+                   tax_withholding_rate::FLOAT8 AS tax_withholding_rate, accent_color,
+                   auto_draft_invoices,
+                   '/companies/' || id || '/logo?v=' || (EXTRACT(EPOCH FROM logo_uploaded_at) * 1000)::BIGINT AS logo_url,
+                   created_at, updated_at
+            FROM companies
+            WHERE id = $1 AND user_id = $2
+            "#,
+                company_id,
+                user_id,
+            )
+            .fetch_optional(&mut *tx)
+            .await?
+            .ok_or_else(|| ApiErrorResponse::NotFound("Company not found".to_string()))?,
+            None => {
+                let company = sqlx::query_as!(
+                    Company,
+                    r#"
+            INSERT INTO companies (user_id, name)
+            VALUES ($1, $2)
+            RETURNING id, user_id, name, requires_tax_withholdings,
+                      tax_withholding_rate::FLOAT8 AS tax_withholding_rate, accent_color,
+                      auto_draft_invoices,
+                      NULL::TEXT AS logo_url, created_at, updated_at
+            "#,
+                    user_id,
+                    lead.company_name,
+                )
+                .fetch_one(&mut *tx)
+                .await?;
+                OnboardingStateRepo::record(&mut *tx, user_id, OnboardingEvent::CompanyCreated)
+                    .await?;
+
+                company
+            }
+        };
+
+        let job = sqlx::query_as!(
+            JobRecord,
+            r#"
+        INSERT INTO jobs (company_id, user_id, title, payment_type, hourly_rate,
+                          number_of_payouts, payout_amount)
+        VALUES ($1, $2, $3, $4, $5::FLOAT8::DECIMAL, $6, $7::FLOAT8::DECIMAL)
+        RETURNING id, company_id, user_id, title, payment_type AS "payment_type: PaymentTypeRecord",
+                  hourly_rate::FLOAT8 AS hourly_rate, number_of_payouts,
+                  payout_amount::FLOAT8 AS payout_amount,
+                  board_column AS "board_column: JobBoardColumnRecord", board_position,
+                  created_at, updated_at
+        "#,
+            company.id,
+            user_id,
+            request.job_title.unwrap_or(lead.title),
+            PaymentTypeRecord::from(request.payment_type) as PaymentTypeRecord,
+            request.hourly_rate,
+            request.number_of_payouts,
+            request.payout_amount,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let lead = sqlx::query_as!(
+            LeadRecord,
+            r#"
+        UPDATE leads
+        SET status = 'won', converted_job_id = $3, closed_at = now(), updated_at = now()
+        WHERE id = $1 AND user_id = $2
+        RETURNING id, user_id, title, company_name, source,
+                  expected_value::FLOAT8 AS expected_value, probability, follow_up_date, notes,
+                  status AS "status: LeadStatusRecord", converted_job_id, closed_at,
+                  created_at, updated_at
+        "#,
+            lead_id,
+            user_id,
+            job.id,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(LeadConversionResponse {
+            lead: lead.into(),
+            company,
+            job: Job::from(job),
+        })
+    }
+
+    /// Checks that a lead can be converted.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` — The lead's current status.
+    ///
+    /// # Returns
+    ///
+    /// An empty [`ApiResult`] if the lead is open.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the lead is already won
+    /// or lost.
+    fn check_convertible(status: &LeadStatusRecord) -> ApiResult<()> {
+        if *status != LeadStatusRecord::Open {
+            return Err(ApiErrorResponse::BadRequest(
+                "Only open leads can be converted".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Computes a user's lead pipeline and conversion statistics.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the leads.
+    ///
+    /// # Returns
+    ///
+    /// The user's [`LeadStats`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either query fails.
+    pub async fn stats(pool: &Pool<Postgres>, user_id: Uuid) -> ApiResult<LeadStats> {
+        let totals = sqlx::query!(
+            r#"
+        SELECT COUNT(*) AS "total_leads!",
+               COUNT(*) FILTER (WHERE status = 'open') AS "open_leads!",
+               COUNT(*) FILTER (WHERE status = 'won') AS "won_leads!",
+               COUNT(*) FILTER (WHERE status = 'lost') AS "lost_leads!",
+               COALESCE(SUM(expected_value) FILTER (WHERE status = 'open'), 0)::FLOAT8
+                   AS "open_pipeline_value!",
+               COALESCE(SUM(expected_value * probability / 100.0)
+                   FILTER (WHERE status = 'open'), 0)::FLOAT8 AS "weighted_pipeline_value!",
+               COALESCE(SUM(expected_value) FILTER (WHERE status = 'won'), 0)::FLOAT8
+                   AS "won_value!",
+               (AVG(EXTRACT(EPOCH FROM closed_at - created_at))
+                   FILTER (WHERE status = 'won') / 86400)::FLOAT8 AS average_days_to_convert
+        FROM leads
+        WHERE user_id = $1
+        "#,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        let sources = sqlx::query!(
+            r#"
+        SELECT source,
+               COUNT(*) AS "total_leads!",
+               COUNT(*) FILTER (WHERE status = 'won') AS "won_leads!",
+               COUNT(*) FILTER (WHERE status = 'lost') AS "lost_leads!"
+        FROM leads
+        WHERE user_id = $1
+        GROUP BY source
+        ORDER BY COUNT(*) DESC, source
+        "#,
+            user_id,
+        )
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .map(|row| LeadSourceStats {
+            source: row.source,
+            total_leads: row.total_leads,
+            won_leads: row.won_leads,
+            conversion_rate: Self::conversion_rate(row.won_leads, row.lost_leads),
+        })
+        .collect();
+
+        Ok(LeadStats {
+            total_leads: totals.total_leads,
+            open_leads: totals.open_leads,
+            won_leads: totals.won_leads,
+            lost_leads: totals.lost_leads,
+            conversion_rate: Self::conversion_rate(totals.won_leads, totals.lost_leads),
+            open_pipeline_value: totals.open_pipeline_value,
+            weighted_pipeline_value: totals.weighted_pipeline_value,
+            won_value: totals.won_value,
+            average_days_to_convert: totals.average_days_to_convert,
+            sources,
+        })
+    }
+
+    /// Computes won leads as a fraction of closed leads.
+    ///
+    /// # Arguments
+    ///
+    /// * `won` — Number of won leads.
+    /// * `lost` — Number of lost leads.
+    ///
+    /// # Returns
+    ///
+    /// The conversion rate, or `0.0` when no leads are closed.
+    fn conversion_rate(won: i64, lost: i64) -> f64 {
+        let closed = won + lost;
+
+        if closed == 0 {
+            0.0
+        } else {
+            won as f64 / closed as f64
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_only_open_leads() {
+        assert!(LeadRepo::check_convertible(&LeadStatusRecord::Open).is_ok());
+        for status in [LeadStatusRecord::Won, LeadStatusRecord::Lost] {
+            assert!(matches!(
+                LeadRepo::check_convertible(&status),
+                Err(ApiErrorResponse::BadRequest(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn conversion_rate_counts_only_closed_leads() {
+        assert_eq!(LeadRepo::conversion_rate(0, 0), 0.0);
+        assert_eq!(LeadRepo::conversion_rate(3, 1), 0.75);
+        assert_eq!(LeadRepo::conversion_rate(0, 4), 0.0);
+        assert_eq!(LeadRepo::conversion_rate(2, 0), 1.0);
+    }
+}
@@ -0,0 +1,509 @@
+//! Mileage database operations.
+//!
+//! Provides [`MileageRepo`] for managing records in the `mileage_entries`
+//! table, totalling trips per job, and storing each user's own mileage
+//! rate and home base. All queries are scoped to the owning user. Deductions are not
+//! stored; they are computed from the miles at the user's current rate.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{FromRow, Pool, Postgres};
+use uuid::Uuid;
+
+use gig_log_common::models::{
+    mileage::{
+        CreateMileageEntryRequest, MileageEntry, MileageRangeQuery, UpdateMileageEntryRequest,
+    },
+    work_session::GeoLocation,
+};
+
+use crate::core::error::ApiResult;
+use crate::mileage::MileageUtil;
+
+/// A row from the `mileage_entries` table.
+#[derive(Debug, FromRow)]
+pub struct MileageEntryRecord {
+    /// Unique identifier for the entry.
+    pub id: Uuid,
+    /// The user who drove the trip.
+    pub user_id: Uuid,
+    /// The job the trip was for.
+    pub job_id: Option<Uuid>,
+    /// Date of the trip.
+    pub date: NaiveDate,
+    /// Distance driven in miles.
+    pub miles: f64,
+    /// Where the trip started.
+    pub start_location: Option<String>,
+    /// Where the trip ended.
+    pub end_location: Option<String>,
+    /// The work session the trip was confirmed from.
+    pub work_session_id: Option<Uuid>,
+    /// When the entry was created.
+    pub created_at: DateTime<Utc>,
+    /// When the entry was last updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl MileageEntryRecord {
+    /// Converts the row into a [`MileageEntry`] with its deduction.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate` — The user's mileage rate in dollars per mile.
+    ///
+    /// # Returns
+    ///
+    /// The [`MileageEntry`].
+    pub fn into_entry(self, rate: f64) -> MileageEntry {
+        MileageEntry {
+            id: self.id,
+            user_id: self.user_id,
+            job_id: self.job_id,
+            date: self.date,
+            miles: self.miles,
+            start_location: self.start_location,
+            end_location: self.end_location,
+            work_session_id: self.work_session_id,
+            deduction: MileageUtil::deduction(self.miles, rate),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+/// Trip totals for one job, as aggregated by
+/// [`MileageRepo::list_job_totals`].
+#[derive(Debug, FromRow)]
+pub struct MileageJobTotalRecord {
+    /// The job, or `None` for trips not tied to a job.
+    pub job_id: Option<Uuid>,
+    /// Title of the job.
+    pub job_title: Option<String>,
+    /// Number of trips.
+    pub trips: i64,
+    /// Distance driven in miles.
+    pub miles: f64,
+}
+
+/// Repository for mileage database operations.
+pub struct MileageRepo;
+
+impl MileageRepo {
+    /// Inserts a new trip.
+    ///
+    /// The job, if any, must already be known to belong to the user.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who drove the trip.
+    /// * `request` — The trip details.
+    ///
+    /// # Returns
+    ///
+    /// The created [`MileageEntryRecord`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert query fails.
+    pub async fn insert_entry(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        request: &CreateMileageEntryRequest,
+    ) -> ApiResult<MileageEntryRecord> {
+        let record = sqlx::query_as!(
+            MileageEntryRecord,
+            r#"
+        INSERT INTO mileage_entries (user_id, job_id, date, miles, start_location, end_location)
+        VALUES ($1, $2, $3, $4::FLOAT8::DECIMAL, $5, $6)
+        RETURNING id, user_id, job_id, date, miles::FLOAT8 AS "miles!", start_location,
+                  end_location, work_session_id, created_at, updated_at
+        "#,
+            user_id,
+            request.job_id,
+            request.date,
+            request.miles,
+            request.start_location,
+            request.end_location,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// Lists a user's trips, most recent first.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who drove the trips.
+    /// * `query` — The date range and job to restrict the results to.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`MileageEntryRecord`] values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_entries(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        query: &MileageRangeQuery,
+    ) -> ApiResult<Vec<MileageEntryRecord>> {
+        let records = sqlx::query_as!(
+            MileageEntryRecord,
+            r#"
+        SELECT id, user_id, job_id, date, miles::FLOAT8 AS "miles!", start_location,
+               end_location, work_session_id, created_at, updated_at
+        FROM mileage_entries
+        WHERE user_id = $1
+          AND ($2::DATE IS NULL OR date >= $2)
+          AND ($3::DATE IS NULL OR date <= $3)
+          AND ($4::UUID IS NULL OR job_id = $4)
+        ORDER BY date DESC, created_at DESC
+        "#,
+            user_id,
+            query.from,
+            query.to,
+            query.job_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    /// Finds one of a user's trips by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who drove the trip.
+    /// * `entry_id` — The entry's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The matching [`MileageEntryRecord`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no entry with the given ID exists for the user.
+    pub async fn find_entry_by_id(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        entry_id: Uuid,
+    ) -> ApiResult<MileageEntryRecord> {
+        let record = sqlx::query_as!(
+            MileageEntryRecord,
+            r#"
+        SELECT id, user_id, job_id, date, miles::FLOAT8 AS "miles!", start_location,
+               end_location, work_session_id, created_at, updated_at
+        FROM mileage_entries
+        WHERE id = $1 AND user_id = $2
+        "#,
+            entry_id,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// Applies a partial update to one of a user's trips.
+    ///
+    /// Omitted fields keep their stored values. A new job, if any, must
+    /// already be known to belong to the user.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who drove the trip.
+    /// * `entry_id` — The entry's UUID.
+    /// * `request` — The fields to change.
+    ///
+    /// # Returns
+    ///
+    /// The updated [`MileageEntryRecord`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`](crate::core::error::ApiErrorResponse::NotFound)
+    /// if no entry with the given ID exists for the user.
+    pub async fn update_entry(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        entry_id: Uuid,
+        request: &UpdateMileageEntryRequest,
+    ) -> ApiResult<MileageEntryRecord> {
+        let mut tx = pool.begin().await?;
+
+        let entry = sqlx::query_as!(
+            MileageEntryRecord,
+            r#"
+        SELECT id, user_id, job_id, date, miles::FLOAT8 AS "miles!", start_location,
+               end_location, work_session_id, created_at, updated_at
+        FROM mileage_entries
+        WHERE id = $1 AND user_id = $2
+        FOR UPDATE
+        "#,
+            entry_id,
+            user_id,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let job_id = request.job_id.unwrap_or(entry.job_id);
+        let date = request.date.unwrap_or(entry.date);
+        let miles = request.miles.unwrap_or(entry.miles);
+        let start_location = request
+            .start_location
+            .clone()
+            .unwrap_or(entry.start_location);
+        let end_location = request.end_location.clone().unwrap_or(entry.end_location);
+
+        let record = sqlx::query_as!(
+            MileageEntryRecord,
+            r#"
+        UPDATE mileage_entries
+        SET job_id = $3,
+            date = $4,
+            miles = $5::FLOAT8::DECIMAL,
+            start_location = $6,
+            end_location = $7,
+            updated_at = now()
+        WHERE id = $1 AND user_id = $2
+        RETURNING id, user_id, job_id, date, miles::FLOAT8 AS "miles!", start_location,
+                  end_location, work_session_id, created_at, updated_at
+        "#,
+            entry_id,
+            user_id,
+            job_id,
+            date,
+            miles,
+            start_location,
+            end_location,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(record)
+    }
+
+    /// Deletes one of a user's trips.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who drove the trip.
+    /// * `entry_id` — The entry's UUID.
+    ///
+    /// # Returns
+    ///
+    /// `true` if an entry was deleted, `false` if none matched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete query fails.
+    pub async fn delete_entry(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        entry_id: Uuid,
+    ) -> ApiResult<bool> {
+        let result = sqlx::query!(
+            r#"
+        DELETE FROM mileage_entries
+        WHERE id = $1 AND user_id = $2
+        "#,
+            entry_id,
+            user_id,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Totals a user's trips per job.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who drove the trips.
+    /// * `query` — The date range and job to restrict the totals to.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`MileageJobTotalRecord`] values, largest distance
+    /// first, with trips not tied to a job grouped under a `None` job.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_job_totals(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        query: &MileageRangeQuery,
+    ) -> ApiResult<Vec<MileageJobTotalRecord>> {
+        let records = sqlx::query_as!(
+            MileageJobTotalRecord,
+            r#"
+        SELECT m.job_id, j.title AS "job_title?", COUNT(*) AS "trips!",
+               SUM(m.miles)::FLOAT8 AS "miles!"
+        FROM mileage_entries m
+        LEFT JOIN jobs j ON j.id = m.job_id
+        WHERE m.user_id = $1
+          AND ($2::DATE IS NULL OR m.date >= $2)
+          AND ($3::DATE IS NULL OR m.date <= $3)
+          AND ($4::UUID IS NULL OR m.job_id = $4)
+        GROUP BY m.job_id, j.title
+        ORDER BY SUM(m.miles) DESC, j.title
+        "#,
+            user_id,
+            query.from,
+            query.to,
+            query.job_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    /// Finds the mileage rate a user has set for themselves.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The user's rate in dollars per mile, or `None` if they use the
+    /// default rate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the user does not exist.
+    pub async fn find_custom_rate(pool: &Pool<Postgres>, user_id: Uuid) -> ApiResult<Option<f64>> {
+        let rate = sqlx::query_scalar!(
+            r#"
+        SELECT mileage_rate::FLOAT8
+        FROM users
+        WHERE id = $1
+        "#,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(rate)
+    }
+
+    /// Sets or clears the mileage rate a user has set for themselves.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    /// * `rate` — The rate in dollars per mile, or `None` to use the
+    ///   default rate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn set_custom_rate(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        rate: Option<f64>,
+    ) -> ApiResult<()> {
+        sqlx::query!(
+            r#"
+        UPDATE users
+        SET mileage_rate = $2::FLOAT8::DECIMAL
+        WHERE id = $1
+        "#,
+            user_id,
+            rate,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Finds the home base a user's trips start from.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The home base's [`GeoLocation`], or `None` if it is not set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the user does not exist.
+    pub async fn find_home_base(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+    ) -> ApiResult<Option<GeoLocation>> {
+        let row = sqlx::query!(
+            r#"
+        SELECT home_latitude, home_longitude
+        FROM users
+        WHERE id = $1
+        "#,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(row
+            .home_latitude
+            .zip(row.home_longitude)
+            .map(|(latitude, longitude)| GeoLocation {
+                latitude,
+                longitude,
+            }))
+    }
+
+    /// Sets or clears the home base a user's trips start from.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    /// * `location` — The home base, or `None` to clear it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn set_home_base(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        location: Option<GeoLocation>,
+    ) -> ApiResult<()> {
+        sqlx::query!(
+            r#"
+        UPDATE users
+        SET home_latitude = $2,
+            home_longitude = $3
+        WHERE id = $1
+        "#,
+            user_id,
+            location.map(|location| location.latitude),
+            location.map(|location| location.longitude),
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,295 @@
+//! Mileage suggestion database operations.
+//!
+//! Provides [`MileageSuggestionRepo`] for storing trips suggested from work
+//! session locations in the `mileage_suggestions` table and for accepting
+//! or dismissing them. Accepting a suggestion logs a trip in
+//! `mileage_entries` linked to the session. All request-facing queries are
+//! scoped to the owning user.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use gig_log_common::models::mileage::{MileageSuggestion, MileageSuggestionStatus};
+use sqlx::{FromRow, Pool, Postgres};
+use uuid::Uuid;
+
+use crate::core::error::ApiResult;
+use crate::repo::mileage::MileageEntryRecord;
+
+/// Where trips confirmed from suggestions start.
+const HOME_BASE_LABEL: &str = "Home base";
+
+/// State of a mileage suggestion row.
+///
+/// Maps to the PostgreSQL `mileage_suggestion_status` enum.
+#[derive(Debug, Clone, sqlx::Type)]
+#[sqlx(type_name = "mileage_suggestion_status", rename_all = "snake_case")]
+pub enum MileageSuggestionStatusRecord {
+    /// Waiting for the user to confirm or dismiss it.
+    Pending,
+    /// Confirmed and logged as a trip.
+    Accepted,
+    /// Dismissed by the user.
+    Dismissed,
+}
+
+impl From<MileageSuggestionStatusRecord> for MileageSuggestionStatus {
+    fn from(status: MileageSuggestionStatusRecord) -> Self {
+        match status {
+            MileageSuggestionStatusRecord::Pending => MileageSuggestionStatus::Pending,
+            MileageSuggestionStatusRecord::Accepted => MileageSuggestionStatus::Accepted,
+            MileageSuggestionStatusRecord::Dismissed => MileageSuggestionStatus::Dismissed,
+        }
+    }
+}
+
+/// A row from the `mileage_suggestions` table.
+#[derive(Debug, FromRow)]
+struct MileageSuggestionRecord {
+    /// Unique identifier for the suggestion.
+    id: Uuid,
+    /// The work session the trip was suggested from.
+    work_session_id: Uuid,
+    /// The session's job.
+    job_id: Uuid,
+    /// Date of the trip.
+    date: NaiveDate,
+    /// Estimated round-trip distance in miles.
+    miles: f64,
+    /// Name of the company the trip was to.
+    destination: String,
+    /// The suggestion's current state.
+    status: MileageSuggestionStatusRecord,
+    /// The trip logged when the suggestion was accepted.
+    mileage_entry_id: Option<Uuid>,
+    /// Timestamp when the suggestion was made.
+    created_at: DateTime<Utc>,
+}
+
+impl From<MileageSuggestionRecord> for MileageSuggestion {
+    fn from(record: MileageSuggestionRecord) -> Self {
+        Self {
+            id: record.id,
+            work_session_id: record.work_session_id,
+            job_id: record.job_id,
+            date: record.date,
+            miles: record.miles,
+            destination: record.destination,
+            status: record.status.into(),
+            mileage_entry_id: record.mileage_entry_id,
+            created_at: record.created_at,
+        }
+    }
+}
+
+/// Repository for mileage suggestion database operations.
+pub struct MileageSuggestionRepo;
+
+impl MileageSuggestionRepo {
+    /// Suggests a trip for a work session.
+    ///
+    /// The destination is the name of the session's company. Sessions that
+    /// already have a suggestion are left alone.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the session.
+    /// * `work_session_id` — The session's UUID.
+    /// * `job_id` — The session's job.
+    /// * `date` — Date of the trip.
+    /// * `miles` — Estimated round-trip distance in miles.
+    ///
+    /// # Returns
+    ///
+    /// The new [`MileageSuggestion`], or `None` if the session already had
+    /// one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert query fails.
+    pub async fn insert_suggestion(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        work_session_id: Uuid,
+        job_id: Uuid,
+        date: NaiveDate,
+        miles: f64,
+    ) -> ApiResult<Option<MileageSuggestion>> {
+        let record = sqlx::query_as!(
+            MileageSuggestionRecord,
+            r#"
+        INSERT INTO mileage_suggestions (user_id, work_session_id, job_id, date, miles, destination)
+        SELECT $1, $2, j.id, $4, $5::FLOAT8::DECIMAL, LEFT(c.name, 255)
+        FROM jobs j
+        JOIN companies c ON c.id = j.company_id
+        WHERE j.id = $3 AND j.user_id = $1
+        ON CONFLICT (work_session_id) DO NOTHING
+        RETURNING id, work_session_id, job_id, date, miles::FLOAT8 AS "miles!", destination,
+                  status AS "status: MileageSuggestionStatusRecord", mileage_entry_id, created_at
+        "#,
+            user_id,
+            work_session_id,
+            job_id,
+            date,
+            miles,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(record.map(MileageSuggestion::from))
+    }
+
+    /// Lists a user's pending suggestions, most recent trip first.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of pending [`MileageSuggestion`] values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_pending(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+    ) -> ApiResult<Vec<MileageSuggestion>> {
+        let records = sqlx::query_as!(
+            MileageSuggestionRecord,
+            r#"
+        SELECT id, work_session_id, job_id, date, miles::FLOAT8 AS "miles!", destination,
+               status AS "status: MileageSuggestionStatusRecord", mileage_entry_id, created_at
+        FROM mileage_suggestions
+        WHERE user_id = $1 AND status = 'pending'
+        ORDER BY date DESC, created_at DESC
+        "#,
+            user_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records.into_iter().map(MileageSuggestion::from).collect())
+    }
+
+    /// Accepts a pending suggestion, logging it as a trip linked to its
+    /// work session.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    /// * `suggestion_id` — The suggestion's UUID.
+    /// * `miles` — Distance to log instead of the estimate, if given.
+    ///
+    /// # Returns
+    ///
+    /// The logged [`MileageEntryRecord`], or `None` if no pending
+    /// suggestion with the given ID exists for the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a database query fails.
+    pub async fn accept(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        suggestion_id: Uuid,
+        miles: Option<f64>,
+    ) -> ApiResult<Option<MileageEntryRecord>> {
+        let mut tx = pool.begin().await?;
+
+        let Some(suggestion) = sqlx::query_as!(
+            MileageSuggestionRecord,
+            r#"
+        SELECT id, work_session_id, job_id, date, miles::FLOAT8 AS "miles!", destination,
+               status AS "status: MileageSuggestionStatusRecord", mileage_entry_id, created_at
+        FROM mileage_suggestions
+        WHERE id = $1 AND user_id = $2 AND status = 'pending'
+        FOR UPDATE
+        "#,
+            suggestion_id,
+            user_id,
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        else {
+            return Ok(None);
+        };
+
+        let entry = sqlx::query_as!(
+            MileageEntryRecord,
+            r#"
+        INSERT INTO mileage_entries
+            (user_id, job_id, date, miles, start_location, end_location, work_session_id)
+        VALUES ($1, $2, $3, $4::FLOAT8::DECIMAL, $5, $6, $7)
+        RETURNING id, user_id, job_id, date, miles::FLOAT8 AS "miles!", start_location,
+                  end_location, work_session_id, created_at, updated_at
+        "#,
+            user_id,
+            suggestion.job_id,
+            suggestion.date,
+            miles.unwrap_or(suggestion.miles),
+            HOME_BASE_LABEL,
+            suggestion.destination,
+            suggestion.work_session_id,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+        UPDATE mileage_suggestions
+        SET status = 'accepted',
+            mileage_entry_id = $2,
+            updated_at = now()
+        WHERE id = $1
+        "#,
+            suggestion.id,
+            entry.id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(Some(entry))
+    }
+
+    /// Dismisses a pending suggestion.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    /// * `suggestion_id` — The suggestion's UUID.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a suggestion was dismissed, `false` if no pending
+    /// suggestion matched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn dismiss(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        suggestion_id: Uuid,
+    ) -> ApiResult<bool> {
+        let result = sqlx::query!(
+            r#"
+        UPDATE mileage_suggestions
+        SET status = 'dismissed',
+            updated_at = now()
+        WHERE id = $1 AND user_id = $2 AND status = 'pending'
+        "#,
+            suggestion_id,
+            user_id,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -6,10 +6,80 @@
 //!
 //! # Modules
 //!
+//! - [`account_export`](crate::repo::account_export) — Account data export jobs and the records archives contain.
+//! - [`activity`](crate::repo::activity) — Per-user API activity log.
+//! - [`api_key`](crate::repo::api_key) — API key storage and lookup.
+//! - [`appearance`](crate::repo::appearance) — Preset palette registry and palette types.
 //! - [`auth_code`](crate::repo::auth_code) — Authorization code storage and validation.
+//! - [`budget`](crate::repo::budget) — Budgets, consumption, and sent alerts.
+//! - [`calendar`](crate::repo::calendar) — Calendar feed tokens and the sessions and payouts feeds list.
+//! - [`company`](crate::repo::company) — Company lookups.
+//! - [`contact`](crate::repo::contact) — Company contacts and primary contact selection.
+//! - [`contract`](crate::repo::contract) — Contracts, signed documents, and renewal reminders.
+//! - [`data_check`](crate::repo::data_check) — Data consistency check queries.
+//! - [`demo`](crate::repo::demo) — Demo account provisioning and expiry.
+//! - [`import`](crate::repo::import) — Company and job lookups and bulk inserts for CSV imports.
+//! - [`invoice`](crate::repo::invoice) — Invoice drafting and line items.
+//! - [`job`](crate::repo::job) — Job lookups.
+//! - [`lead`](crate::repo::lead) — Sales leads, conversion, and pipeline statistics.
+//! - [`mileage`](crate::repo::mileage) — Mileage entries, per-job totals, and user mileage rates and home bases.
+//! - [`mileage_suggestion`](crate::repo::mileage_suggestion) — Trips suggested from work session locations and their confirmation.
+//! - [`oauth_identity`](crate::repo::oauth_identity) — Provider accounts linked for OAuth sign-in.
+//! - [`onboarding`](crate::repo::onboarding) — First-run company, job, payment, and session creation.
+//! - [`onboarding_state`](crate::repo::onboarding_state) — Account setup progress and the events that advance it.
+//! - [`passkey`](crate::repo::passkey) — Passkey credentials and ceremony challenges.
+//! - [`payment`](crate::repo::payment) — Payment lookups, installments, and receipt details.
+//! - [`personal_access_token`](crate::repo::personal_access_token) — Personal access token storage and lookup.
+//! - [`recurring_payment`](crate::repo::recurring_payment) — Recurring payment schedules and materializing due payments.
 //! - [`refresh_token`](crate::repo::refresh_token) — Refresh token storage and revocation.
+//! - [`report`](crate::repo::report) — Per-period earnings, quarterly tax estimates, and self-employment tax rates.
+//! - [`search`](crate::repo::search) — Full-text search over company names and job titles.
+//! - [`security`](crate::repo::security) — Failed log-ins, log-in countries, unusual activity events, and re-authentication holds.
+//! - [`statement`](crate::repo::statement) — Sent company statement history.
+//! - [`subscription`](crate::repo::subscription) — Stripe customers, subscription status, and processed webhook events.
+//! - [`time_off`](crate::repo::time_off) — Planned time off.
+//! - [`timesheet_approval`](crate::repo::timesheet_approval) — Shared timesheet approvals and session locking.
 //! - [`user`](crate::repo::user) — User account CRUD operations.
+//! - [`webhook`](crate::repo::webhook) — Webhook registrations and the delivery queue and log.
+//! - [`work_session`](crate::repo::work_session) — Work session queries.
+//! - [`work_session_attachment`](crate::repo::work_session_attachment) — Work session photo and voice memo storage.
+//! - [`work_session_draft`](crate::repo::work_session_draft) — Email-logged work session draft storage and confirmation.
 
+pub mod account_export;
+pub mod activity;
+pub mod api_key;
+pub mod appearance;
 pub mod auth_code;
+pub mod budget;
+pub mod calendar;
+pub mod company;
+pub mod contact;
+pub mod contract;
+pub mod data_check;
+pub mod demo;
+pub mod import;
+pub mod invoice;
+pub mod job;
+pub mod lead;
+pub mod mileage;
+pub mod mileage_suggestion;
+pub mod oauth_identity;
+pub mod onboarding;
+pub mod onboarding_state;
+pub mod passkey;
+pub mod payment;
+pub mod personal_access_token;
+pub mod recurring_payment;
 pub mod refresh_token;
+pub mod report;
+pub mod search;
+pub mod security;
+pub mod statement;
+pub mod subscription;
+pub mod time_off;
+pub mod timesheet_approval;
 pub mod user;
+pub mod webhook;
+pub mod work_session;
+pub mod work_session_attachment;
+pub mod work_session_draft;
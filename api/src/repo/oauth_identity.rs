@@ -0,0 +1,117 @@
+//! OAuth identity database operations.
+//!
+//! Provides [`OAuthIdentityRepo`] for linking external identity provider
+//! accounts to users and resolving them at sign-in, stored in the
+//! `oauth_identities` table.
+
+use gig_log_common::models::oauth::OAuthProvider;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::core::error::ApiResult;
+
+/// An external identity provider.
+///
+/// Maps to the PostgreSQL `oauth_provider` enum.
+#[derive(Debug, Clone, sqlx::Type)]
+#[sqlx(type_name = "oauth_provider", rename_all = "lowercase")]
+pub enum OAuthProviderRecord {
+    /// Sign in with Google.
+    Google,
+    /// Sign in with GitHub.
+    GitHub,
+}
+
+impl From<OAuthProvider> for OAuthProviderRecord {
+    fn from(provider: OAuthProvider) -> Self {
+        match provider {
+            OAuthProvider::Google => OAuthProviderRecord::Google,
+            OAuthProvider::GitHub => OAuthProviderRecord::GitHub,
+        }
+    }
+}
+
+/// Repository for OAuth identity database operations.
+pub struct OAuthIdentityRepo;
+
+impl OAuthIdentityRepo {
+    /// Looks up the user linked to a provider account and records that it
+    /// was used to sign in.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `provider` — The identity provider.
+    /// * `subject` — The provider's identifier for the account.
+    ///
+    /// # Returns
+    ///
+    /// The UUID of the linked user, or `None` if the account is not linked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn use_identity(
+        pool: &Pool<Postgres>,
+        provider: OAuthProvider,
+        subject: &str,
+    ) -> ApiResult<Option<Uuid>> {
+        let user_id = sqlx::query_scalar!(
+            r#"
+        UPDATE oauth_identities
+        SET last_used_at = now()
+        WHERE provider = $1 AND subject = $2
+        RETURNING user_id
+        "#,
+            OAuthProviderRecord::from(provider) as OAuthProviderRecord,
+            subject,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(user_id)
+    }
+
+    /// Links a provider account to a user.
+    ///
+    /// Linking an account that is already linked has no effect.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user to link the account to.
+    /// * `provider` — The identity provider.
+    /// * `subject` — The provider's identifier for the account.
+    /// * `email` — The verified email address the provider reported.
+    ///
+    /// # Returns
+    ///
+    /// `()` on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert query fails.
+    pub async fn insert_identity(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        provider: OAuthProvider,
+        subject: &str,
+        email: &str,
+    ) -> ApiResult<()> {
+        sqlx::query!(
+            r#"
+        INSERT INTO oauth_identities (user_id, provider, subject, email, last_used_at)
+        VALUES ($1, $2, $3, $4, now())
+        ON CONFLICT (provider, subject) DO NOTHING
+        "#,
+            user_id,
+            OAuthProviderRecord::from(provider) as OAuthProviderRecord,
+            subject,
+            email,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,186 @@
+//! Onboarding database operations.
+//!
+//! Provides [`OnboardingRepo`] for creating a new user's first company, job,
+//! and optional payment and work session in a single transaction.
+
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use gig_log_common::models::{
+    company::Company,
+    job::Job,
+    onboarding::{OnboardingRequest, OnboardingResponse},
+    work_session::WorkSession,
+};
+
+use crate::core::error::ApiResult;
+use crate::repo::{
+    job::{JobBoardColumnRecord, JobRecord, PaymentTypeRecord},
+    onboarding_state::{OnboardingEvent, OnboardingStateRepo},
+    payment::{PaymentRecord, PayoutTypeRecord},
+};
+
+/// Repository for the first-run onboarding wizard.
+pub struct OnboardingRepo;
+
+impl OnboardingRepo {
+    /// Creates a company, a job, and an optional first payment and work
+    /// session.
+    ///
+    /// Everything is written in one transaction, so a failure leaves no
+    /// partially onboarded records behind. The request's timezone fills in
+    /// the user's timezone preference only if it is unset.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user being onboarded.
+    /// * `request` — The resources to create.
+    ///
+    /// # Returns
+    ///
+    /// An [`OnboardingResponse`] with every created resource.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any query fails.
+    pub async fn create(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        request: OnboardingRequest,
+    ) -> ApiResult<OnboardingResponse> {
+        let mut tx = pool.begin().await?;
+
+        let company = sqlx::query_as!(
+            Company,
+            r#"
+        INSERT INTO companies (user_id, name, requires_tax_withholdings, tax_withholding_rate)
+        VALUES ($1, $2, $3, $4::FLOAT8::DECIMAL)
+        RETURNING id, user_id, name, requires_tax_withholdings,
+                  tax_withholding_rate::FLOAT8 AS tax_withholding_rate, accent_color,
+                  auto_draft_invoices,
+                  NULL::TEXT AS logo_url, created_at, updated_at
+        "#,
+            user_id,
+            request.company.name,
+            request.company.requires_tax_withholdings,
+            request.company.tax_withholding_rate,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        OnboardingStateRepo::record(&mut *tx, user_id, OnboardingEvent::CompanyCreated).await?;
+
+        let job = sqlx::query_as!(
+            JobRecord,
+            r#"
+        INSERT INTO jobs (company_id, user_id, title, payment_type, hourly_rate,
+                          number_of_payouts, payout_amount)
+        VALUES ($1, $2, $3, $4, $5::FLOAT8::DECIMAL, $6, $7::FLOAT8::DECIMAL)
+        RETURNING id, company_id, user_id, title, payment_type AS "payment_type: PaymentTypeRecord",
+                  hourly_rate::FLOAT8 AS hourly_rate, number_of_payouts,
+                  payout_amount::FLOAT8 AS payout_amount,
+                  board_column AS "board_column: JobBoardColumnRecord", board_position,
+                  created_at, updated_at
+        "#,
+            company.id,
+            user_id,
+            request.job.title,
+            PaymentTypeRecord::from(request.job.payment_type) as PaymentTypeRecord,
+            request.job.hourly_rate,
+            request.job.number_of_payouts,
+            request.job.payout_amount,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let payment = match request.payment {
+            Some(payment) => {
+                let record = sqlx::query_as!(
+                    PaymentRecord,
+                    r#"
+        INSERT INTO payments (user_id, company_id, total, payout_type, expected_payout_date,
+                              transfer_initiated, payment_received, tax_withholdings_covered)
+        VALUES ($1, $2, $3::FLOAT8::DECIMAL, $4, $5, $6, $7, $8)
+        RETURNING id, user_id, company_id, total::FLOAT8 AS "total!",
+                  payout_type AS "payout_type: PayoutTypeRecord",
+                  expected_payout_date, transfer_initiated, payment_received,
+                  tax_withholdings_covered, tax_withholding_rate::FLOAT8 AS tax_withholding_rate,
+                  amount_paid::FLOAT8 AS "amount_paid!",
+                  created_at, updated_at
+        "#,
+                    user_id,
+                    company.id,
+                    payment.total,
+                    PayoutTypeRecord::from(payment.payout_type) as PayoutTypeRecord,
+                    payment.expected_payout_date,
+                    payment.transfer_initiated,
+                    payment.payment_received,
+                    payment.tax_withholdings_covered,
+                )
+                .fetch_one(&mut *tx)
+                .await?;
+                OnboardingStateRepo::record(&mut *tx, user_id, OnboardingEvent::PaymentRecorded)
+                    .await?;
+
+                Some(record.into())
+            }
+            None => None,
+        };
+
+        let work_session = match request.work_session {
+            Some(session) => {
+                let work_session = sqlx::query_as!(
+                    WorkSession,
+                    r#"
+        INSERT INTO work_sessions (user_id, job_id, start_time, end_time, is_running, hourly_rate)
+        VALUES ($1, $2, $3, $4, FALSE, (SELECT hourly_rate FROM jobs WHERE id = $2))
+        RETURNING id, user_id, job_id, start_time, end_time, is_running,
+                  EXTRACT(EPOCH FROM accumulated_paused_duration)::BIGINT
+                      AS "accumulated_paused_duration!",
+                  paused_at,
+                  EXTRACT(EPOCH FROM time_reported)::BIGINT AS time_reported,
+                  hourly_rate::FLOAT8 AS hourly_rate, auto_completed,
+                  start_latitude, start_longitude, end_latitude, end_longitude, notes, tags,
+                  created_at, updated_at
+        "#,
+                    user_id,
+                    job.id,
+                    session.start_time,
+                    session.end_time,
+                )
+                .fetch_one(&mut *tx)
+                .await?;
+                OnboardingStateRepo::record(&mut *tx, user_id, OnboardingEvent::SessionTracked)
+                    .await?;
+
+                Some(work_session)
+            }
+            None => None,
+        };
+
+        if let Some(timezone) = request.timezone {
+            sqlx::query!(
+                r#"
+        UPDATE users
+        SET timezone = $2, updated_at = NOW()
+        WHERE id = $1
+          AND timezone IS NULL
+          AND EXISTS (SELECT 1 FROM pg_timezone_names WHERE name = $2)
+        "#,
+                user_id,
+                timezone,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(OnboardingResponse {
+            company,
+            job: Job::from(job),
+            payment,
+            work_session,
+        })
+    }
+}
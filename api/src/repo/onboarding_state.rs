@@ -0,0 +1,117 @@
+//! Account setup progress database operations.
+//!
+//! Provides [`OnboardingStateRepo`] for reading a user's
+//! [`OnboardingState`] and for recording the [`OnboardingEvent`]s that
+//! advance it. Each event stamps a column on the `users` table the first
+//! time it happens; later occurrences leave the stamp unchanged.
+
+use sqlx::{PgExecutor, Pool, Postgres};
+use uuid::Uuid;
+
+use gig_log_common::models::user::OnboardingState;
+
+use crate::core::error::ApiResult;
+
+/// A domain event that can complete an account setup step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnboardingEvent {
+    /// A company was created.
+    CompanyCreated,
+    /// A work session was tracked.
+    SessionTracked,
+    /// A payment was recorded.
+    PaymentRecorded,
+}
+
+/// Repository for account setup progress.
+pub struct OnboardingStateRepo;
+
+impl OnboardingStateRepo {
+    /// Retrieves a user's account setup progress.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The user's [`OnboardingState`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no user with the given ID exists.
+    pub async fn find_state(pool: &Pool<Postgres>, user_id: Uuid) -> ApiResult<OnboardingState> {
+        let state = sqlx::query_as!(
+            OnboardingState,
+            r#"
+        SELECT email_confirmed AS confirmed_email,
+               first_company_created_at IS NOT NULL AS "created_first_company!",
+               first_session_tracked_at IS NOT NULL AS "tracked_first_session!",
+               first_payment_recorded_at IS NOT NULL AS "recorded_first_payment!"
+        FROM users
+        WHERE id = $1
+        "#,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(state)
+    }
+
+    /// Records a domain event against a user's account setup progress.
+    ///
+    /// Accepts a pool or an open transaction so the event is recorded
+    /// together with the change that caused it.
+    ///
+    /// # Arguments
+    ///
+    /// * `executor` — The pool or transaction to run the update on.
+    /// * `user_id` — The user the event happened to.
+    /// * `event` — The [`OnboardingEvent`] that happened.
+    ///
+    /// # Returns
+    ///
+    /// `()` on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn record<'e>(
+        executor: impl PgExecutor<'e>,
+        user_id: Uuid,
+        event: OnboardingEvent,
+    ) -> ApiResult<()> {
+        let query = match event {
+            OnboardingEvent::CompanyCreated => sqlx::query!(
+                r#"
+            UPDATE users
+            SET first_company_created_at = COALESCE(first_company_created_at, NOW())
+            WHERE id = $1
+            "#,
+                user_id,
+            ),
+            OnboardingEvent::SessionTracked => sqlx::query!(
+                r#"
+            UPDATE users
+            SET first_session_tracked_at = COALESCE(first_session_tracked_at, NOW())
+            WHERE id = $1
+            "#,
+                user_id,
+            ),
+            OnboardingEvent::PaymentRecorded => sqlx::query!(
+                r#"
+            UPDATE users
+            SET first_payment_recorded_at = COALESCE(first_payment_recorded_at, NOW())
+            WHERE id = $1
+            "#,
+                user_id,
+            ),
+        };
+
+        query.execute(executor).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,297 @@
+//! Passkey database operations.
+//!
+//! Provides [`PasskeyRepo`] for storing passkey credentials in the
+//! `passkeys` table and the single-use ceremony challenges issued for them
+//! in the `passkey_challenges` table.
+
+use chrono::{DateTime, Utc};
+use gig_log_common::models::passkey::Passkey;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::core::error::ApiResult;
+
+/// The WebAuthn ceremony a challenge was issued for.
+///
+/// Maps to the PostgreSQL `passkey_ceremony` enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "passkey_ceremony", rename_all = "snake_case")]
+pub enum PasskeyCeremony {
+    /// Creating a new passkey.
+    Registration,
+    /// Logging in with an existing passkey.
+    Authentication,
+}
+
+impl PasskeyCeremony {
+    /// Returns the client data `type` browsers report for the ceremony.
+    ///
+    /// # Returns
+    ///
+    /// `"webauthn.create"` or `"webauthn.get"`.
+    pub fn client_data_type(self) -> &'static str {
+        match self {
+            PasskeyCeremony::Registration => "webauthn.create",
+            PasskeyCeremony::Authentication => "webauthn.get",
+        }
+    }
+}
+
+/// A passkey's verification data, as needed to check a login.
+#[derive(Debug)]
+pub struct PasskeyCredentialRecord {
+    /// Unique identifier for the passkey.
+    pub id: Uuid,
+    /// The user the passkey belongs to.
+    pub user_id: Uuid,
+    /// The DER-encoded public key.
+    pub public_key: Vec<u8>,
+    /// The signature counter recorded at the passkey's last use.
+    pub sign_count: i64,
+}
+
+/// Repository for passkey database operations.
+pub struct PasskeyRepo;
+
+impl PasskeyRepo {
+    /// Stores a ceremony challenge, purging expired ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user the ceremony is for, or `None` for a login
+    ///   that lets the user pick a discoverable passkey.
+    /// * `challenge` — The base64url-encoded challenge.
+    /// * `ceremony` — The ceremony the challenge is for.
+    /// * `expires_at` — When the challenge expires.
+    ///
+    /// # Returns
+    ///
+    /// `()` on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a query fails.
+    pub async fn insert_challenge(
+        pool: &Pool<Postgres>,
+        user_id: Option<Uuid>,
+        challenge: &str,
+        ceremony: PasskeyCeremony,
+        expires_at: DateTime<Utc>,
+    ) -> ApiResult<()> {
+        sqlx::query!(
+            r#"
+        DELETE FROM passkey_challenges
+        WHERE expires_at <= now()
+        "#,
+        )
+        .execute(pool)
+        .await?;
+
+        sqlx::query!(
+            r#"
+        INSERT INTO passkey_challenges (user_id, challenge, ceremony, expires_at)
+        VALUES ($1, $2, $3, $4)
+        "#,
+            user_id,
+            challenge,
+            ceremony as PasskeyCeremony,
+            expires_at,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Consumes an unexpired ceremony challenge.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `challenge` — The base64url-encoded challenge from the client data.
+    /// * `ceremony` — The ceremony being finished.
+    ///
+    /// # Returns
+    ///
+    /// `Some` with the user the challenge was issued for, if any, or `None`
+    /// if no matching unexpired challenge exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn take_challenge(
+        pool: &Pool<Postgres>,
+        challenge: &str,
+        ceremony: PasskeyCeremony,
+    ) -> ApiResult<Option<Option<Uuid>>> {
+        let row = sqlx::query!(
+            r#"
+        DELETE FROM passkey_challenges
+        WHERE challenge = $1 AND ceremony = $2 AND expires_at > now()
+        RETURNING user_id
+        "#,
+            challenge,
+            ceremony as PasskeyCeremony,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| row.user_id))
+    }
+
+    /// Stores a new passkey.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user the passkey belongs to.
+    /// * `name` — Label describing where the passkey is held.
+    /// * `credential_id` — The base64url-encoded credential ID.
+    /// * `public_key` — The DER-encoded public key.
+    /// * `sign_count` — The authenticator's initial signature counter.
+    ///
+    /// # Returns
+    ///
+    /// The created [`Passkey`], or `None` if the credential is already
+    /// registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert query fails.
+    pub async fn insert_passkey(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        name: &str,
+        credential_id: &str,
+        public_key: &[u8],
+        sign_count: i64,
+    ) -> ApiResult<Option<Passkey>> {
+        let passkey = sqlx::query_as!(
+            Passkey,
+            r#"
+        INSERT INTO passkeys (user_id, name, credential_id, public_key, sign_count)
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (credential_id) DO NOTHING
+        RETURNING id, name, last_used_at, created_at
+        "#,
+            user_id,
+            name,
+            credential_id,
+            public_key,
+            sign_count,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(passkey)
+    }
+
+    /// Lists the credential IDs of a user's passkeys.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user whose passkeys to list.
+    ///
+    /// # Returns
+    ///
+    /// The base64url-encoded credential IDs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_credential_ids(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+    ) -> ApiResult<Vec<String>> {
+        let credential_ids = sqlx::query_scalar!(
+            r#"
+        SELECT credential_id
+        FROM passkeys
+        WHERE user_id = $1
+        ORDER BY created_at
+        "#,
+            user_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(credential_ids)
+    }
+
+    /// Finds a passkey by credential ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `credential_id` — The base64url-encoded credential ID.
+    ///
+    /// # Returns
+    ///
+    /// The matching [`PasskeyCredentialRecord`], or `None` if no passkey
+    /// has the ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn find_by_credential_id(
+        pool: &Pool<Postgres>,
+        credential_id: &str,
+    ) -> ApiResult<Option<PasskeyCredentialRecord>> {
+        let record = sqlx::query_as!(
+            PasskeyCredentialRecord,
+            r#"
+        SELECT id, user_id, public_key, sign_count
+        FROM passkeys
+        WHERE credential_id = $1
+        "#,
+            credential_id,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// Records a login with a passkey.
+    ///
+    /// The counter is only stored if it has not moved since it was read, so
+    /// two logins racing with the same counter cannot both succeed.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `id` — The passkey's UUID.
+    /// * `previous_sign_count` — The counter read before verifying.
+    /// * `sign_count` — The counter reported by the authenticator.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the use was recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn record_use(
+        pool: &Pool<Postgres>,
+        id: Uuid,
+        previous_sign_count: i64,
+        sign_count: i64,
+    ) -> ApiResult<bool> {
+        let result = sqlx::query!(
+            r#"
+        UPDATE passkeys
+        SET sign_count = $3, last_used_at = now()
+        WHERE id = $1 AND sign_count = $2
+        "#,
+            id,
+            previous_sign_count,
+            sign_count,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,1354 @@
+//! Payment database operations.
+//!
+//! Provides [`PaymentRepo`] for querying payment records in the `payments`
+//! table, recording installments in the `payment_installments` table, and
+//! streaming payments for CSV exports.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use futures_util::stream::BoxStream;
+use sqlx::{FromRow, Pool, Postgres, QueryBuilder};
+use uuid::Uuid;
+
+use gig_log_common::models::error::{ValidationError, ValidationErrorCode};
+use gig_log_common::models::generic::{PageQuery, Paginated};
+use gig_log_common::models::payment::{
+    ListPaymentsQuery, OverdueCompanyTotal, Payment, PaymentInstallment, PaymentSort,
+    PaymentSortField, PaymentStatus, PayoutType, UpdatePaymentRequest,
+};
+
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::core::export::CsvRecord;
+
+/// Start of the payment list queries, ending where the owning user's ID
+/// is bound.
+const LIST_SELECT: &str = r#"
+        SELECT id, user_id, company_id, total::FLOAT8 AS total, payout_type,
+               expected_payout_date, transfer_initiated, payment_received,
+               tax_withholdings_covered, tax_withholding_rate::FLOAT8 AS tax_withholding_rate,
+               amount_paid::FLOAT8 AS amount_paid,
+               created_at, updated_at
+        FROM payments
+        WHERE user_id = "#;
+
+/// How a payment row was received.
+///
+/// Maps to the PostgreSQL `payout_type` enum.
+#[derive(Debug, Clone, sqlx::Type)]
+#[sqlx(type_name = "payout_type", rename_all = "snake_case")]
+pub enum PayoutTypeRecord {
+    /// Payment via PayPal.
+    Paypal,
+    /// Payment in cash.
+    Cash,
+    /// Payment by check.
+    Check,
+    /// Payment via Zelle.
+    Zelle,
+    /// Payment via Venmo.
+    Venmo,
+    /// Payment via direct deposit.
+    DirectDeposit,
+}
+
+impl From<PayoutTypeRecord> for PayoutType {
+    fn from(payout_type: PayoutTypeRecord) -> Self {
+        match payout_type {
+            PayoutTypeRecord::Paypal => PayoutType::Paypal,
+            PayoutTypeRecord::Cash => PayoutType::Cash,
+            PayoutTypeRecord::Check => PayoutType::Check,
+            PayoutTypeRecord::Zelle => PayoutType::Zelle,
+            PayoutTypeRecord::Venmo => PayoutType::Venmo,
+            PayoutTypeRecord::DirectDeposit => PayoutType::DirectDeposit,
+        }
+    }
+}
+
+impl From<PayoutType> for PayoutTypeRecord {
+    fn from(payout_type: PayoutType) -> Self {
+        match payout_type {
+            PayoutType::Paypal => PayoutTypeRecord::Paypal,
+            PayoutType::Cash => PayoutTypeRecord::Cash,
+            PayoutType::Check => PayoutTypeRecord::Check,
+            PayoutType::Zelle => PayoutTypeRecord::Zelle,
+            PayoutType::Venmo => PayoutTypeRecord::Venmo,
+            PayoutType::DirectDeposit => PayoutTypeRecord::DirectDeposit,
+        }
+    }
+}
+
+/// Which reminder about an unreceived payment was sent.
+///
+/// Maps to the PostgreSQL `payment_reminder_stage` enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "payment_reminder_stage", rename_all = "snake_case")]
+pub enum PaymentReminderStage {
+    /// Sent shortly before the expected payout date.
+    Upcoming,
+    /// Sent once the expected payout date has passed.
+    Overdue,
+}
+
+/// A row from the `payments` table.
+#[derive(Debug, FromRow)]
+pub struct PaymentRecord {
+    /// Unique identifier for the payment.
+    pub id: Uuid,
+    /// The user who received this payment.
+    pub user_id: Uuid,
+    /// The company that issued this payment.
+    pub company_id: Uuid,
+    /// Total payment amount in dollars.
+    pub total: f64,
+    /// Method used to receive the payment.
+    pub payout_type: PayoutTypeRecord,
+    /// Expected date the payment will be received.
+    pub expected_payout_date: Option<NaiveDate>,
+    /// Whether the payment transfer has been initiated by the payer.
+    pub transfer_initiated: bool,
+    /// Whether the payment has been received by the user.
+    pub payment_received: bool,
+    /// Whether tax withholdings have been accounted for.
+    pub tax_withholdings_covered: bool,
+    /// The company's tax withholding rate when the payment was received.
+    pub tax_withholding_rate: Option<f64>,
+    /// Sum of the payment's installments in dollars.
+    pub amount_paid: f64,
+    /// When the payment was created.
+    pub created_at: DateTime<Utc>,
+    /// When the payment was last updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<PaymentRecord> for Payment {
+    fn from(record: PaymentRecord) -> Self {
+        let (status, amount_outstanding) =
+            PaymentStatus::from_amounts(record.total, record.amount_paid, record.payment_received);
+
+        Self {
+            id: record.id,
+            user_id: record.user_id,
+            company_id: record.company_id,
+            total: record.total,
+            payout_type: record.payout_type.into(),
+            expected_payout_date: record.expected_payout_date,
+            transfer_initiated: record.transfer_initiated,
+            payment_received: record.payment_received,
+            tax_withholdings_covered: record.tax_withholdings_covered,
+            tax_withholding_rate: record.tax_withholding_rate,
+            amount_paid: record.amount_paid,
+            amount_outstanding,
+            status,
+            overdue: PaymentRepo::is_overdue(
+                record.expected_payout_date,
+                record.payment_received,
+                Utc::now().date_naive(),
+            ),
+            created_at: record.created_at,
+            updated_at: record.updated_at,
+        }
+    }
+}
+
+/// A row from the `payment_installments` table.
+#[derive(Debug, FromRow)]
+pub struct PaymentInstallmentRecord {
+    /// Unique identifier for the installment.
+    pub id: Uuid,
+    /// The payment the installment was made toward.
+    pub payment_id: Uuid,
+    /// Installment amount in dollars.
+    pub amount: f64,
+    /// Date the installment was paid.
+    pub paid_on: NaiveDate,
+    /// When the installment was recorded.
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<PaymentInstallmentRecord> for PaymentInstallment {
+    fn from(record: PaymentInstallmentRecord) -> Self {
+        Self {
+            id: record.id,
+            payment_id: record.payment_id,
+            amount: record.amount,
+            paid_on: record.paid_on,
+            created_at: record.created_at,
+        }
+    }
+}
+
+/// A company's overdue payment totals.
+#[derive(Debug, FromRow)]
+pub struct OverdueCompanyTotalRecord {
+    /// The company that issued the payments.
+    pub company_id: Uuid,
+    /// Name of the company.
+    pub company_name: String,
+    /// Number of overdue payments from the company.
+    pub payment_count: i64,
+    /// Amount still outstanding across the payments, in dollars.
+    pub amount_outstanding: f64,
+    /// Earliest expected payout date among the payments.
+    pub oldest_expected_payout_date: NaiveDate,
+}
+
+impl From<OverdueCompanyTotalRecord> for OverdueCompanyTotal {
+    fn from(record: OverdueCompanyTotalRecord) -> Self {
+        Self {
+            company_id: record.company_id,
+            company_name: record.company_name,
+            payment_count: record.payment_count,
+            amount_outstanding: record.amount_outstanding,
+            oldest_expected_payout_date: record.oldest_expected_payout_date,
+        }
+    }
+}
+
+/// A payment reminder that has just been claimed for sending.
+#[derive(Debug, FromRow)]
+pub struct DuePaymentReminderRecord {
+    /// The payment's UUID.
+    pub id: Uuid,
+    /// Which reminder to send.
+    pub stage: PaymentReminderStage,
+    /// Name of the company the payment is from.
+    pub company_name: String,
+    /// Amount still outstanding in dollars.
+    pub amount_outstanding: f64,
+    /// Date the payment was expected.
+    pub expected_payout_date: NaiveDate,
+    /// Email address of the payment owner.
+    pub email: String,
+    /// Language tag of the payment owner's preferred email locale.
+    pub locale: String,
+}
+
+/// A payment due in a reconciled month, with its company's details.
+#[derive(Debug, FromRow)]
+pub struct ReconciliationPaymentRecord {
+    /// Unique identifier for the payment.
+    pub id: Uuid,
+    /// The user who received this payment.
+    pub user_id: Uuid,
+    /// The company that issued this payment.
+    pub company_id: Uuid,
+    /// Total payment amount in dollars.
+    pub total: f64,
+    /// Method used to receive the payment.
+    pub payout_type: PayoutTypeRecord,
+    /// Expected date the payment will be received.
+    pub expected_payout_date: Option<NaiveDate>,
+    /// Whether the payment transfer has been initiated by the payer.
+    pub transfer_initiated: bool,
+    /// Whether the payment has been received by the user.
+    pub payment_received: bool,
+    /// Whether tax withholdings have been accounted for.
+    pub tax_withholdings_covered: bool,
+    /// The company's tax withholding rate when the payment was received.
+    pub tax_withholding_rate: Option<f64>,
+    /// Sum of the payment's installments in dollars.
+    pub amount_paid: f64,
+    /// When the payment was created.
+    pub created_at: DateTime<Utc>,
+    /// When the payment was last updated.
+    pub updated_at: DateTime<Utc>,
+    /// Name of the company that issued the payment.
+    pub company_name: String,
+    /// Whether the company requires tax withholdings.
+    pub requires_tax_withholdings: bool,
+}
+
+/// The details shown on a public payment receipt.
+#[derive(Debug, Clone, FromRow)]
+pub struct PaymentReceiptRecord {
+    /// Unique identifier for the payment, shown as the receipt number.
+    pub id: Uuid,
+    /// Total payment amount in dollars.
+    pub total: f64,
+    /// Method used to receive the payment.
+    pub payout_type: PayoutTypeRecord,
+    /// Date the payment was made: its expected payout date, or the date it
+    /// was last updated.
+    pub payment_date: NaiveDate,
+    /// Name of the company that issued the payment.
+    pub company_name: String,
+    /// Full name of the user who received the payment.
+    pub payee_name: String,
+}
+
+/// A payment listed on a company statement.
+#[derive(Debug, Clone, FromRow)]
+pub struct StatementPaymentRecord {
+    /// Date the payment was made: its expected payout date, or the date it
+    /// was created.
+    pub payment_date: NaiveDate,
+    /// Total payment amount in dollars.
+    pub total: f64,
+    /// Method used to receive the payment.
+    pub payout_type: PayoutTypeRecord,
+    /// Whether the payment has been received by the user.
+    pub payment_received: bool,
+}
+
+/// A payment row in a CSV export, with its company's name.
+#[derive(Debug, Clone, FromRow)]
+pub struct PaymentExportRecord {
+    /// Unique identifier for the payment.
+    pub id: Uuid,
+    /// Date the payment was made: its expected payout date, or the date it
+    /// was created.
+    pub payment_date: NaiveDate,
+    /// Name of the company that issued the payment.
+    pub company_name: String,
+    /// Total payment amount in dollars.
+    pub total: f64,
+    /// Method used to receive the payment, as its `snake_case` name.
+    pub payout_type: String,
+    /// Expected date the payment will be received.
+    pub expected_payout_date: Option<NaiveDate>,
+    /// Whether the payment transfer has been initiated by the payer.
+    pub transfer_initiated: bool,
+    /// Whether the payment has been received by the user.
+    pub payment_received: bool,
+    /// Whether tax withholdings have been accounted for.
+    pub tax_withholdings_covered: bool,
+    /// Timestamp when the payment record was created.
+    pub created_at: DateTime<Utc>,
+}
+
+impl CsvRecord for PaymentExportRecord {
+    const COLUMNS: &'static [&'static str] = &[
+        "id",
+        "date",
+        "company",
+        "total",
+        "payout_type",
+        "expected_payout_date",
+        "transfer_initiated",
+        "payment_received",
+        "tax_withholdings_covered",
+        "created_at",
+    ];
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.id.to_string(),
+            self.payment_date.to_string(),
+            self.company_name.clone(),
+            format!("{:.2}", self.total),
+            self.payout_type.clone(),
+            self.expected_payout_date
+                .map(|date| date.to_string())
+                .unwrap_or_default(),
+            self.transfer_initiated.to_string(),
+            self.payment_received.to_string(),
+            self.tax_withholdings_covered.to_string(),
+            self.created_at.to_rfc3339(),
+        ]
+    }
+}
+
+/// Repository for payment database operations.
+pub struct PaymentRepo;
+
+impl PaymentRepo {
+    /// Finds one of a user's payments by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the payment.
+    /// * `payment_id` — The payment's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The matching [`Payment`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no payment with the given ID exists for the user.
+    pub async fn find_payment_by_id(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        payment_id: Uuid,
+    ) -> ApiResult<Payment> {
+        let record = sqlx::query_as!(
+            PaymentRecord,
+            r#"
+        SELECT id, user_id, company_id, total::FLOAT8 AS "total!",
+               payout_type AS "payout_type: PayoutTypeRecord",
+               expected_payout_date, transfer_initiated, payment_received,
+               tax_withholdings_covered, tax_withholding_rate::FLOAT8 AS tax_withholding_rate,
+               amount_paid::FLOAT8 AS "amount_paid!",
+               created_at, updated_at
+        FROM payments
+        WHERE id = $1 AND user_id = $2
+        "#,
+            payment_id,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record.into())
+    }
+
+    /// Finds the receipt details of a received payment.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `payment_id` — The payment's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The [`PaymentReceiptRecord`], or `None` if the payment does not exist
+    /// or has not been received.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn find_receipt(
+        pool: &Pool<Postgres>,
+        payment_id: Uuid,
+    ) -> ApiResult<Option<PaymentReceiptRecord>> {
+        let record = sqlx::query_as!(
+            PaymentReceiptRecord,
+            r#"
+        SELECT p.id, p.total::FLOAT8 AS "total!",
+               p.payout_type AS "payout_type: PayoutTypeRecord",
+               COALESCE(p.expected_payout_date, p.updated_at::DATE) AS "payment_date!",
+               c.name AS company_name,
+               u.first_name || ' ' || u.last_name AS "payee_name!"
+        FROM payments p
+        JOIN companies c ON c.id = p.company_id
+        JOIN users u ON u.id = p.user_id
+        WHERE p.id = $1 AND p.payment_received = TRUE
+        "#,
+            payment_id,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// Lists a user's payments that have not been received yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the payments.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of expected [`Payment`] values ordered by expected payout
+    /// date.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_expected_payments(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+    ) -> ApiResult<Vec<Payment>> {
+        let records = sqlx::query_as!(
+            PaymentRecord,
+            r#"
+        SELECT id, user_id, company_id, total::FLOAT8 AS "total!",
+               payout_type AS "payout_type: PayoutTypeRecord",
+               expected_payout_date, transfer_initiated, payment_received,
+               tax_withholdings_covered, tax_withholding_rate::FLOAT8 AS tax_withholding_rate,
+               amount_paid::FLOAT8 AS "amount_paid!",
+               created_at, updated_at
+        FROM payments
+        WHERE user_id = $1 AND payment_received = FALSE
+        ORDER BY expected_payout_date NULLS LAST, created_at
+        "#,
+            user_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns whether a payment is overdue.
+    ///
+    /// A payment is overdue once its expected payout date has passed
+    /// without it being received. Payments without an expected payout date
+    /// are never overdue. [`PaymentRepo::list_overdue_payments`] and
+    /// [`PaymentRepo::list_overdue_company_totals`] apply the same rule in
+    /// SQL.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected_payout_date` — The payment's expected payout date.
+    /// * `payment_received` — Whether the payment has been received.
+    /// * `today` — The current date.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the payment is overdue.
+    pub fn is_overdue(
+        expected_payout_date: Option<NaiveDate>,
+        payment_received: bool,
+        today: NaiveDate,
+    ) -> bool {
+        !payment_received && expected_payout_date.is_some_and(|date| date < today)
+    }
+
+    /// Lists a user's overdue payments.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the payments.
+    /// * `today` — The current date.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of overdue [`Payment`] values, oldest expected payout date
+    /// first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_overdue_payments(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        today: NaiveDate,
+    ) -> ApiResult<Vec<Payment>> {
+        let records = sqlx::query_as!(
+            PaymentRecord,
+            r#"
+        SELECT id, user_id, company_id, total::FLOAT8 AS "total!",
+               payout_type AS "payout_type: PayoutTypeRecord",
+               expected_payout_date, transfer_initiated, payment_received,
+               tax_withholdings_covered, tax_withholding_rate::FLOAT8 AS tax_withholding_rate,
+               amount_paid::FLOAT8 AS "amount_paid!",
+               created_at, updated_at
+        FROM payments
+        WHERE user_id = $1 AND payment_received = FALSE AND expected_payout_date < $2
+        ORDER BY expected_payout_date, created_at
+        "#,
+            user_id,
+            today,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records.into_iter().map(Into::into).collect())
+    }
+
+    /// Totals a user's overdue payments per company.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the payments.
+    /// * `today` — The current date.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`OverdueCompanyTotal`] values, largest outstanding
+    /// amount first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_overdue_company_totals(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        today: NaiveDate,
+    ) -> ApiResult<Vec<OverdueCompanyTotal>> {
+        let records = sqlx::query_as!(
+            OverdueCompanyTotalRecord,
+            r#"
+        SELECT p.company_id, c.name AS company_name,
+               COUNT(*) AS "payment_count!",
+               SUM(p.total - p.amount_paid)::FLOAT8 AS "amount_outstanding!",
+               MIN(p.expected_payout_date) AS "oldest_expected_payout_date!"
+        FROM payments p
+        JOIN companies c ON c.id = p.company_id
+        WHERE p.user_id = $1 AND p.payment_received = FALSE AND p.expected_payout_date < $2
+        GROUP BY p.company_id, c.name
+        ORDER BY 4 DESC, c.name
+        "#,
+            user_id,
+            today,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records.into_iter().map(Into::into).collect())
+    }
+
+    /// Claims every payment reminder that is due.
+    ///
+    /// An upcoming reminder is due when an unreceived payment's expected
+    /// payout date is between today and `days_before` days from now. An
+    /// overdue reminder is due once the date has passed, for up to
+    /// `overdue_window_days` days, so turning reminders on does not send
+    /// one for every long-forgotten payment. Users who turned reminders off
+    /// are skipped. Claimed reminders are recorded in the same statement, so
+    /// each payment gets at most one reminder per stage even across
+    /// concurrent sweeps.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `today` — The current date.
+    /// * `days_before` — Days before the expected payout date that the
+    ///   upcoming reminder is sent.
+    /// * `overdue_window_days` — Days after the expected payout date that
+    ///   an overdue reminder can still be sent.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`DuePaymentReminderRecord`] values to send, ordered by
+    /// expected payout date.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn claim_due_reminders(
+        pool: &Pool<Postgres>,
+        today: NaiveDate,
+        days_before: i32,
+        overdue_window_days: i32,
+    ) -> ApiResult<Vec<DuePaymentReminderRecord>> {
+        let records = sqlx::query_as!(
+            DuePaymentReminderRecord,
+            r#"
+        WITH due AS (
+            SELECT p.id,
+                   CASE WHEN p.expected_payout_date < $1::DATE
+                        THEN 'overdue'::payment_reminder_stage
+                        ELSE 'upcoming'::payment_reminder_stage
+                   END AS stage
+            FROM payments p
+            JOIN users u ON u.id = p.user_id
+            WHERE u.payment_reminders_enabled = TRUE
+              AND p.payment_received = FALSE
+              AND p.expected_payout_date BETWEEN $1::DATE - $3::INT AND $1::DATE + $2::INT
+        ),
+        claimed AS (
+            INSERT INTO payment_reminders (payment_id, stage)
+            SELECT id, stage FROM due
+            ON CONFLICT DO NOTHING
+            RETURNING payment_id, stage
+        )
+        SELECT p.id, claimed.stage AS "stage: PaymentReminderStage",
+               c.name AS company_name,
+               (p.total - p.amount_paid)::FLOAT8 AS "amount_outstanding!",
+               p.expected_payout_date AS "expected_payout_date!",
+               u.email, u.locale
+        FROM claimed
+        JOIN payments p ON p.id = claimed.payment_id
+        JOIN companies c ON c.id = p.company_id
+        JOIN users u ON u.id = p.user_id
+        ORDER BY p.expected_payout_date
+        "#,
+            today,
+            days_before,
+            overdue_window_days,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    /// Returns whether a user receives payment reminder emails.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    ///
+    /// # Returns
+    ///
+    /// `true` if upcoming and overdue payment reminders are sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the user does not exist.
+    pub async fn find_reminders_enabled(pool: &Pool<Postgres>, user_id: Uuid) -> ApiResult<bool> {
+        let enabled = sqlx::query_scalar!(
+            r#"
+        SELECT payment_reminders_enabled
+        FROM users
+        WHERE id = $1
+        "#,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(enabled)
+    }
+
+    /// Turns a user's payment reminder emails on or off.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    /// * `enabled` — Whether reminders should be sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn set_reminders_enabled(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        enabled: bool,
+    ) -> ApiResult<()> {
+        sqlx::query!(
+            r#"
+        UPDATE users
+        SET payment_reminders_enabled = $2
+        WHERE id = $1
+        "#,
+            user_id,
+            enabled,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Lists a user's payments matching a set of filters.
+    ///
+    /// Each filter in `query` that is set adds a condition to the query.
+    /// The date range applies to the expected payout date, so payments
+    /// without one are excluded when either bound is set. Ties in the sort
+    /// order are broken by ID so pages are stable.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the payments.
+    /// * `query` — The [`ListPaymentsQuery`] filters. Its `sort` field is
+    ///   ignored in favor of `sort`.
+    /// * `sort` — The [`PaymentSort`] order.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of matching [`Payment`] values in the requested order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_for_user(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        query: &ListPaymentsQuery,
+        sort: PaymentSort,
+    ) -> ApiResult<Vec<Payment>> {
+        let mut builder = QueryBuilder::<Postgres>::new(LIST_SELECT);
+        builder.push_bind(user_id);
+
+        Self::push_filters(&mut builder, query);
+        builder.push(Self::order_by(sort));
+
+        let records = builder
+            .build_query_as::<PaymentRecord>()
+            .fetch_all(pool)
+            .await?;
+
+        Ok(records.into_iter().map(Into::into).collect())
+    }
+
+    /// Lists one page of a user's payments, filtered and sorted as in
+    /// [`PaymentRepo::list_for_user`].
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the payments.
+    /// * `query` — The [`ListPaymentsQuery`] filters. Its `sort` field is
+    ///   ignored in favor of `sort`.
+    /// * `sort` — The [`PaymentSort`] order.
+    /// * `page` — The [`PageQuery`] selecting the page.
+    ///
+    /// # Returns
+    ///
+    /// A [`Paginated`] page of matching [`Payment`] values with the number
+    /// of matches across every page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a query fails.
+    pub async fn list_page_for_user(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        query: &ListPaymentsQuery,
+        sort: PaymentSort,
+        page: &PageQuery,
+    ) -> ApiResult<Paginated<Payment>> {
+        let mut builder = QueryBuilder::<Postgres>::new(LIST_SELECT);
+        builder.push_bind(user_id);
+        Self::push_filters(&mut builder, query);
+        builder
+            .push(Self::order_by(sort))
+            .push(" LIMIT ")
+            .push_bind(i64::from(page.per_page()) + 1)
+            .push(" OFFSET ")
+            .push_bind(page.offset());
+
+        let records = builder
+            .build_query_as::<PaymentRecord>()
+            .fetch_all(pool)
+            .await?;
+
+        let mut count_builder =
+            QueryBuilder::<Postgres>::new("SELECT COUNT(*) FROM payments WHERE user_id = ");
+        count_builder.push_bind(user_id);
+        Self::push_filters(&mut count_builder, query);
+
+        let total: i64 = count_builder.build_query_scalar().fetch_one(pool).await?;
+
+        Ok(Paginated::from_lookahead(
+            records.into_iter().map(Into::into).collect(),
+            page,
+            total,
+        ))
+    }
+
+    /// Lists the payments from one of a user's companies.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the payments.
+    /// * `company_id` — The UUID of the company that issued the payments.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`Payment`] values, newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_company_payments(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        company_id: Uuid,
+    ) -> ApiResult<Vec<Payment>> {
+        let records = sqlx::query_as!(
+            PaymentRecord,
+            r#"
+        SELECT id, user_id, company_id, total::FLOAT8 AS "total!",
+               payout_type AS "payout_type: PayoutTypeRecord",
+               expected_payout_date, transfer_initiated, payment_received,
+               tax_withholdings_covered, tax_withholding_rate::FLOAT8 AS tax_withholding_rate,
+               amount_paid::FLOAT8 AS "amount_paid!",
+               created_at, updated_at
+        FROM payments
+        WHERE user_id = $1 AND company_id = $2
+        ORDER BY created_at DESC
+        "#,
+            user_id,
+            company_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records.into_iter().map(Into::into).collect())
+    }
+
+    /// Lists a user's payments due within a range.
+    ///
+    /// A payment is due on its expected payout date, or on the day it was
+    /// created when it has none.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the payments.
+    /// * `from` — First day of the range (inclusive).
+    /// * `to` — Last day of the range (inclusive).
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`ReconciliationPaymentRecord`] values ordered by due
+    /// date.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_reconciliation_payments(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> ApiResult<Vec<ReconciliationPaymentRecord>> {
+        let records = sqlx::query_as!(
+            ReconciliationPaymentRecord,
+            r#"
+        SELECT p.id, p.user_id, p.company_id, p.total::FLOAT8 AS "total!",
+               p.payout_type AS "payout_type: PayoutTypeRecord",
+               p.expected_payout_date, p.transfer_initiated, p.payment_received,
+               p.tax_withholdings_covered, p.tax_withholding_rate::FLOAT8 AS tax_withholding_rate,
+               p.amount_paid::FLOAT8 AS "amount_paid!",
+               p.created_at, p.updated_at,
+               c.name AS company_name, c.requires_tax_withholdings
+        FROM payments p
+        JOIN companies c ON c.id = p.company_id
+        WHERE p.user_id = $1
+          AND COALESCE(p.expected_payout_date, p.created_at::DATE) BETWEEN $2 AND $3
+        ORDER BY COALESCE(p.expected_payout_date, p.created_at::DATE), p.created_at
+        "#,
+            user_id,
+            from,
+            to,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    /// Lists a company's payments dated within a range.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the payments.
+    /// * `company_id` — The UUID of the company that issued the payments.
+    /// * `from` — First day of the range (inclusive).
+    /// * `to` — Last day of the range (inclusive).
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`StatementPaymentRecord`] values ordered by date.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_statement_payments(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        company_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> ApiResult<Vec<StatementPaymentRecord>> {
+        let records = sqlx::query_as!(
+            StatementPaymentRecord,
+            r#"
+        SELECT COALESCE(expected_payout_date, created_at::DATE) AS "payment_date!",
+               total::FLOAT8 AS "total!",
+               payout_type AS "payout_type: PayoutTypeRecord",
+               payment_received
+        FROM payments
+        WHERE user_id = $1
+          AND company_id = $2
+          AND COALESCE(expected_payout_date, created_at::DATE) BETWEEN $3 AND $4
+        ORDER BY 1, created_at
+        "#,
+            user_id,
+            company_id,
+            from,
+            to,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    /// Applies a partial update to a payment.
+    ///
+    /// Omitted fields keep their stored values.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the payment.
+    /// * `payment_id` — The payment's UUID.
+    /// * `request` — The fields to change.
+    ///
+    /// # Returns
+    ///
+    /// The updated [`Payment`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no payment with the given ID exists for the user.
+    pub async fn update_payment(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        payment_id: Uuid,
+        request: &UpdatePaymentRequest,
+    ) -> ApiResult<Payment> {
+        let mut tx = pool.begin().await?;
+
+        let payment: Payment = sqlx::query_as!(
+            PaymentRecord,
+            r#"
+        SELECT id, user_id, company_id, total::FLOAT8 AS "total!",
+               payout_type AS "payout_type: PayoutTypeRecord",
+               expected_payout_date, transfer_initiated, payment_received,
+               tax_withholdings_covered, tax_withholding_rate::FLOAT8 AS tax_withholding_rate,
+               amount_paid::FLOAT8 AS "amount_paid!",
+               created_at, updated_at
+        FROM payments
+        WHERE id = $1 AND user_id = $2
+        FOR UPDATE
+        "#,
+            payment_id,
+            user_id,
+        )
+        .fetch_one(&mut *tx)
+        .await?
+        .into();
+
+        if let Some(total) = request.total
+            && Self::cents(total) < Self::cents(payment.amount_paid)
+        {
+            return Err(ApiErrorResponse::Validation(vec![ValidationError::new(
+                Some("total".to_string()),
+                ValidationErrorCode::Range,
+                format!(
+                    "Total cannot be less than the ${:.2} already paid",
+                    payment.amount_paid
+                ),
+            )]));
+        }
+
+        let payout_type = request.payout_type.clone().unwrap_or(payment.payout_type);
+
+        let record = sqlx::query_as!(
+            PaymentRecord,
+            r#"
+        UPDATE payments
+        SET total = $3::FLOAT8::DECIMAL,
+            payout_type = $4,
+            expected_payout_date = $5,
+            transfer_initiated = $6,
+            payment_received = $7,
+            tax_withholdings_covered = $8,
+            updated_at = now()
+        WHERE id = $1 AND user_id = $2
+        RETURNING id, user_id, company_id, total::FLOAT8 AS "total!",
+                  payout_type AS "payout_type: PayoutTypeRecord",
+                  expected_payout_date, transfer_initiated, payment_received,
+                  tax_withholdings_covered, tax_withholding_rate::FLOAT8 AS tax_withholding_rate,
+                  amount_paid::FLOAT8 AS "amount_paid!",
+                  created_at, updated_at
+        "#,
+            payment_id,
+            user_id,
+            request.total.unwrap_or(payment.total),
+            PayoutTypeRecord::from(payout_type) as PayoutTypeRecord,
+            request
+                .expected_payout_date
+                .unwrap_or(payment.expected_payout_date),
+            request
+                .transfer_initiated
+                .unwrap_or(payment.transfer_initiated),
+            request.payment_received.unwrap_or(payment.payment_received),
+            request
+                .tax_withholdings_covered
+                .unwrap_or(payment.tax_withholdings_covered),
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(record.into())
+    }
+
+    /// Records an installment toward one of a user's payments.
+    ///
+    /// The amount is rounded to whole cents and added to the payment's
+    /// `amount_paid`. A payment whose installments cover its total is
+    /// marked received.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the payment.
+    /// * `payment_id` — The payment's UUID.
+    /// * `amount` — Installment amount in dollars.
+    /// * `paid_on` — Date the installment was paid.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the new [`PaymentInstallment`] and the updated
+    /// [`Payment`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`] if the amount rounds to zero
+    /// or is more than the payment's outstanding amount, or an error if no
+    /// payment with the given ID exists for the user.
+    pub async fn insert_installment(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        payment_id: Uuid,
+        amount: f64,
+        paid_on: NaiveDate,
+    ) -> ApiResult<(PaymentInstallment, Payment)> {
+        let mut tx = pool.begin().await?;
+
+        let payment: Payment = sqlx::query_as!(
+            PaymentRecord,
+            r#"
+        SELECT id, user_id, company_id, total::FLOAT8 AS "total!",
+               payout_type AS "payout_type: PayoutTypeRecord",
+               expected_payout_date, transfer_initiated, payment_received,
+               tax_withholdings_covered, tax_withholding_rate::FLOAT8 AS tax_withholding_rate,
+               amount_paid::FLOAT8 AS "amount_paid!",
+               created_at, updated_at
+        FROM payments
+        WHERE id = $1 AND user_id = $2
+        FOR UPDATE
+        "#,
+            payment_id,
+            user_id,
+        )
+        .fetch_one(&mut *tx)
+        .await?
+        .into();
+
+        let amount_cents = Self::cents(amount);
+
+        if amount_cents == 0 || amount_cents > Self::cents(payment.amount_outstanding) {
+            return Err(ApiErrorResponse::Validation(vec![ValidationError::new(
+                Some("amount".to_string()),
+                ValidationErrorCode::Range,
+                format!(
+                    "Amount must be between $0.01 and the ${:.2} still outstanding",
+                    payment.amount_outstanding
+                ),
+            )]));
+        }
+
+        let amount = amount_cents as f64 / 100.0;
+
+        let installment = sqlx::query_as!(
+            PaymentInstallmentRecord,
+            r#"
+        INSERT INTO payment_installments (payment_id, amount, paid_on)
+        VALUES ($1, $2::FLOAT8::DECIMAL, $3)
+        RETURNING id, payment_id, amount::FLOAT8 AS "amount!", paid_on, created_at
+        "#,
+            payment_id,
+            amount,
+            paid_on,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let record = sqlx::query_as!(
+            PaymentRecord,
+            r#"
+        UPDATE payments
+        SET amount_paid = amount_paid + $3::FLOAT8::DECIMAL,
+            payment_received = payment_received OR amount_paid + $3::FLOAT8::DECIMAL >= total,
+            updated_at = now()
+        WHERE id = $1 AND user_id = $2
+        RETURNING id, user_id, company_id, total::FLOAT8 AS "total!",
+                  payout_type AS "payout_type: PayoutTypeRecord",
+                  expected_payout_date, transfer_initiated, payment_received,
+                  tax_withholdings_covered, tax_withholding_rate::FLOAT8 AS tax_withholding_rate,
+                  amount_paid::FLOAT8 AS "amount_paid!",
+                  created_at, updated_at
+        "#,
+            payment_id,
+            user_id,
+            amount,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok((installment.into(), record.into()))
+    }
+
+    /// Adds a [`ListPaymentsQuery`]'s filters to a query whose `WHERE`
+    /// clause already restricts it to one user's payments.
+    ///
+    /// # Arguments
+    ///
+    /// * `builder` — The query being built.
+    /// * `query` — The filters to add. Unset filters add nothing.
+    fn push_filters(builder: &mut QueryBuilder<'_, Postgres>, query: &ListPaymentsQuery) {
+        if let Some(company_id) = query.company_id {
+            builder.push(" AND company_id = ").push_bind(company_id);
+        }
+
+        if let Some(payout_type) = query.payout_type.clone() {
+            builder
+                .push(" AND payout_type = ")
+                .push_bind(PayoutTypeRecord::from(payout_type));
+        }
+
+        if let Some(payment_received) = query.payment_received {
+            builder
+                .push(" AND payment_received = ")
+                .push_bind(payment_received);
+        }
+
+        if let Some(transfer_initiated) = query.transfer_initiated {
+            builder
+                .push(" AND transfer_initiated = ")
+                .push_bind(transfer_initiated);
+        }
+
+        if let Some(date_from) = query.date_from {
+            builder
+                .push(" AND expected_payout_date >= ")
+                .push_bind(date_from);
+        }
+
+        if let Some(date_to) = query.date_to {
+            builder
+                .push(" AND expected_payout_date <= ")
+                .push_bind(date_to);
+        }
+    }
+
+    /// Converts a dollar amount to whole cents.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` — The amount in dollars.
+    ///
+    /// # Returns
+    ///
+    /// The amount rounded to the nearest cent.
+    fn cents(amount: f64) -> i64 {
+        (amount * 100.0).round() as i64
+    }
+
+    /// Streams a user's payments for a CSV export.
+    ///
+    /// Payments are dated by their expected payout date, or their creation
+    /// date when they have none, and both date bounds are inclusive.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the payments.
+    /// * `from` — The earliest payment date to include, if bounded.
+    /// * `to` — The latest payment date to include, if bounded.
+    ///
+    /// # Returns
+    ///
+    /// A stream of [`PaymentExportRecord`] values, oldest first.
+    pub fn stream_export(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    ) -> BoxStream<'_, Result<PaymentExportRecord, sqlx::Error>> {
+        sqlx::query_as!(
+            PaymentExportRecord,
+            r#"
+        SELECT pay.id,
+               COALESCE(pay.expected_payout_date, pay.created_at::DATE) AS "payment_date!",
+               c.name AS company_name,
+               pay.total::FLOAT8 AS "total!",
+               pay.payout_type::TEXT AS "payout_type!",
+               pay.expected_payout_date,
+               pay.transfer_initiated,
+               pay.payment_received,
+               pay.tax_withholdings_covered,
+               pay.created_at
+        FROM payments pay
+        JOIN companies c ON c.id = pay.company_id
+        WHERE pay.user_id = $1
+          AND ($2::DATE IS NULL OR COALESCE(pay.expected_payout_date, pay.created_at::DATE) >= $2)
+          AND ($3::DATE IS NULL OR COALESCE(pay.expected_payout_date, pay.created_at::DATE) <= $3)
+        ORDER BY 2, pay.created_at, pay.id
+        "#,
+            user_id,
+            from,
+            to,
+        )
+        .fetch(pool)
+    }
+
+    /// Builds the `ORDER BY` clause for a payment sort.
+    ///
+    /// # Arguments
+    ///
+    /// * `sort` — The [`PaymentSort`] order.
+    ///
+    /// # Returns
+    ///
+    /// The clause, with missing expected payout dates sorted last in either
+    /// direction and ties broken by ID.
+    fn order_by(sort: PaymentSort) -> String {
+        let column = match sort.field {
+            PaymentSortField::ExpectedPayoutDate => "expected_payout_date",
+            PaymentSortField::Total => "total",
+            PaymentSortField::CreatedAt => "created_at",
+            PaymentSortField::UpdatedAt => "updated_at",
+        };
+        let direction = if sort.descending { "DESC" } else { "ASC" };
+
+        format!(" ORDER BY {column} {direction} NULLS LAST, id {direction}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orders_by_requested_field() {
+        assert_eq!(
+            PaymentRepo::order_by(PaymentSort::parse("-expected_payout_date").unwrap()),
+            " ORDER BY expected_payout_date DESC NULLS LAST, id DESC"
+        );
+        assert_eq!(
+            PaymentRepo::order_by(PaymentSort::parse("total").unwrap()),
+            " ORDER BY total ASC NULLS LAST, id ASC"
+        );
+        assert_eq!(
+            PaymentRepo::order_by(PaymentSort::default()),
+            " ORDER BY created_at DESC NULLS LAST, id DESC"
+        );
+        assert_eq!(PaymentSort::parse("-company_id"), None);
+        assert_eq!(PaymentSort::parse("total; DROP TABLE payments"), None);
+    }
+
+    #[test]
+    fn rolls_up_installments() {
+        assert_eq!(
+            PaymentStatus::from_amounts(100.0, 0.0, false),
+            (PaymentStatus::Unpaid, 100.0)
+        );
+        assert_eq!(
+            PaymentStatus::from_amounts(100.0, 33.33, false),
+            (PaymentStatus::PartiallyPaid, 66.67)
+        );
+        assert_eq!(
+            PaymentStatus::from_amounts(0.3, 0.1 + 0.2, false),
+            (PaymentStatus::Paid, 0.0)
+        );
+        assert_eq!(
+            PaymentStatus::from_amounts(100.0, 0.0, true),
+            (PaymentStatus::Paid, 0.0)
+        );
+        assert_eq!(PaymentRepo::cents(19.999), 2000);
+    }
+
+    #[test]
+    fn detects_overdue_payments() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 16).unwrap();
+        let yesterday = today.pred_opt();
+
+        assert!(PaymentRepo::is_overdue(yesterday, false, today));
+        assert!(!PaymentRepo::is_overdue(yesterday, true, today));
+        assert!(!PaymentRepo::is_overdue(Some(today), false, today));
+        assert!(!PaymentRepo::is_overdue(None, false, today));
+    }
+}
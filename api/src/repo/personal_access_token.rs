@@ -0,0 +1,212 @@
+//! Personal access token database operations.
+//!
+//! Provides [`PersonalAccessTokenRepo`] for creating, listing, revoking,
+//! and authenticating personal access tokens stored in the
+//! `personal_access_tokens` table.
+
+use chrono::{DateTime, Utc};
+use gig_log_common::models::personal_access_token::{ApiScope, PersonalAccessToken};
+use sqlx::{FromRow, Pool, Postgres};
+use uuid::Uuid;
+
+use crate::core::error::ApiResult;
+
+/// A row from the `personal_access_tokens` table, without its hash.
+#[derive(Debug, FromRow)]
+struct PersonalAccessTokenRecord {
+    /// Unique identifier for the token.
+    id: Uuid,
+    /// Label describing what the token is used for.
+    name: String,
+    /// Serialized scope names.
+    scopes: Vec<String>,
+    /// When the token last authenticated a request.
+    last_used_at: Option<DateTime<Utc>>,
+    /// Timestamp when the token was created.
+    created_at: DateTime<Utc>,
+}
+
+impl From<PersonalAccessTokenRecord> for PersonalAccessToken {
+    fn from(record: PersonalAccessTokenRecord) -> Self {
+        Self {
+            id: record.id,
+            name: record.name,
+            scopes: record
+                .scopes
+                .iter()
+                .filter_map(|scope| ApiScope::parse(scope))
+                .collect(),
+            last_used_at: record.last_used_at,
+            created_at: record.created_at,
+        }
+    }
+}
+
+/// The owner and scopes of an authenticated personal access token.
+#[derive(Debug)]
+pub struct TokenGrant {
+    /// The user the token belongs to.
+    pub user_id: Uuid,
+    /// Scopes the token grants.
+    pub scopes: Vec<ApiScope>,
+}
+
+/// Repository for personal access token database operations.
+pub struct PersonalAccessTokenRepo;
+
+impl PersonalAccessTokenRepo {
+    /// Inserts a new personal access token.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user the token belongs to.
+    /// * `name` — Label describing what the token is used for.
+    /// * `token_hash` — The SHA-256 hash of the raw token.
+    /// * `scopes` — Scopes the token grants.
+    ///
+    /// # Returns
+    ///
+    /// The created [`PersonalAccessToken`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert query fails.
+    pub async fn insert_token(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        name: &str,
+        token_hash: &str,
+        scopes: &[ApiScope],
+    ) -> ApiResult<PersonalAccessToken> {
+        let scopes: Vec<String> = scopes
+            .iter()
+            .map(|scope| scope.as_str().to_string())
+            .collect();
+
+        let record = sqlx::query_as!(
+            PersonalAccessTokenRecord,
+            r#"
+        INSERT INTO personal_access_tokens (user_id, name, token_hash, scopes)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id, name, scopes, last_used_at, created_at
+        "#,
+            user_id,
+            name,
+            token_hash,
+            &scopes,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record.into())
+    }
+
+    /// Lists a user's personal access tokens, newest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user whose tokens to list.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`PersonalAccessToken`] values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_tokens(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+    ) -> ApiResult<Vec<PersonalAccessToken>> {
+        let records = sqlx::query_as!(
+            PersonalAccessTokenRecord,
+            r#"
+        SELECT id, name, scopes, last_used_at, created_at
+        FROM personal_access_tokens
+        WHERE user_id = $1
+        ORDER BY created_at DESC
+        "#,
+            user_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records.into_iter().map(Into::into).collect())
+    }
+
+    /// Deletes one of a user's personal access tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user the token belongs to.
+    /// * `token_id` — The token's UUID.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a token was deleted, `false` if none matched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete query fails.
+    pub async fn delete_token(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        token_id: Uuid,
+    ) -> ApiResult<bool> {
+        let result = sqlx::query!(
+            r#"
+        DELETE FROM personal_access_tokens
+        WHERE id = $1 AND user_id = $2
+        "#,
+            token_id,
+            user_id,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Looks up a token by hash and records that it was used.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `token_hash` — The SHA-256 hash of the raw token.
+    ///
+    /// # Returns
+    ///
+    /// The token's [`TokenGrant`], or `None` if no token matches.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn use_token(
+        pool: &Pool<Postgres>,
+        token_hash: &str,
+    ) -> ApiResult<Option<TokenGrant>> {
+        let row = sqlx::query!(
+            r#"
+        UPDATE personal_access_tokens
+        SET last_used_at = now()
+        WHERE token_hash = $1
+        RETURNING user_id, scopes
+        "#,
+            token_hash,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| TokenGrant {
+            user_id: row.user_id,
+            scopes: row
+                .scopes
+                .iter()
+                .filter_map(|scope| ApiScope::parse(scope))
+                .collect(),
+        }))
+    }
+}
@@ -0,0 +1,483 @@
+//! Recurring payment database operations.
+//!
+//! Provides [`RecurringPaymentRepo`] for managing records in the
+//! `recurring_payments` table and materializing their due occurrences as
+//! rows in the `payments` table. Each schedule stores the `next_date` of
+//! the first occurrence not yet materialized.
+
+use chrono::{DateTime, Days, NaiveDate, Utc};
+use sqlx::{FromRow, Pool, Postgres};
+use uuid::Uuid;
+
+use gig_log_common::{
+    models::{
+        payment::Payment,
+        recurring_payment::{
+            CreateRecurringPaymentRequest, RecurrenceCadence, RecurringPayment,
+            UpdateRecurringPaymentRequest,
+        },
+    },
+    validators::recurring_payment::validate_recurring_payment_dates,
+};
+
+use crate::core::error::ApiResult;
+use crate::recurrence::RecurrenceUtil;
+use crate::repo::payment::{PaymentRecord, PayoutTypeRecord};
+
+/// How often a recurring payment row repeats.
+///
+/// Maps to the PostgreSQL `recurrence_cadence` enum.
+#[derive(Debug, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "recurrence_cadence", rename_all = "snake_case")]
+pub enum RecurrenceCadenceRecord {
+    /// Every week.
+    Weekly,
+    /// Every two weeks.
+    Biweekly,
+    /// Every month.
+    Monthly,
+    /// Every three months.
+    Quarterly,
+    /// Every year.
+    Yearly,
+}
+
+impl From<RecurrenceCadenceRecord> for RecurrenceCadence {
+    fn from(cadence: RecurrenceCadenceRecord) -> Self {
+        match cadence {
+            RecurrenceCadenceRecord::Weekly => RecurrenceCadence::Weekly,
+            RecurrenceCadenceRecord::Biweekly => RecurrenceCadence::Biweekly,
+            RecurrenceCadenceRecord::Monthly => RecurrenceCadence::Monthly,
+            RecurrenceCadenceRecord::Quarterly => RecurrenceCadence::Quarterly,
+            RecurrenceCadenceRecord::Yearly => RecurrenceCadence::Yearly,
+        }
+    }
+}
+
+impl From<RecurrenceCadence> for RecurrenceCadenceRecord {
+    fn from(cadence: RecurrenceCadence) -> Self {
+        match cadence {
+            RecurrenceCadence::Weekly => RecurrenceCadenceRecord::Weekly,
+            RecurrenceCadence::Biweekly => RecurrenceCadenceRecord::Biweekly,
+            RecurrenceCadence::Monthly => RecurrenceCadenceRecord::Monthly,
+            RecurrenceCadence::Quarterly => RecurrenceCadenceRecord::Quarterly,
+            RecurrenceCadence::Yearly => RecurrenceCadenceRecord::Yearly,
+        }
+    }
+}
+
+/// A row from the `recurring_payments` table.
+#[derive(Debug, FromRow)]
+pub struct RecurringPaymentRecord {
+    /// Unique identifier for the recurring payment.
+    pub id: Uuid,
+    /// The user who receives the payments.
+    pub user_id: Uuid,
+    /// The company that makes the payments.
+    pub company_id: Uuid,
+    /// Amount of each payment in dollars.
+    pub total: f64,
+    /// Method used to receive each payment.
+    pub payout_type: PayoutTypeRecord,
+    /// How often the payment repeats.
+    pub cadence: RecurrenceCadenceRecord,
+    /// Date of the first payment.
+    pub start_date: NaiveDate,
+    /// Date after which no more payments are made, if any.
+    pub end_date: Option<NaiveDate>,
+    /// Date of the first occurrence not yet materialized.
+    pub next_date: NaiveDate,
+    /// When the recurring payment was created.
+    pub created_at: DateTime<Utc>,
+    /// When the recurring payment was last updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<RecurringPaymentRecord> for RecurringPayment {
+    fn from(record: RecurringPaymentRecord) -> Self {
+        let next_date = Some(record.next_date).filter(|next_date| {
+            record
+                .end_date
+                .is_none_or(|end_date| *next_date <= end_date)
+        });
+
+        Self {
+            id: record.id,
+            user_id: record.user_id,
+            company_id: record.company_id,
+            total: record.total,
+            payout_type: record.payout_type.into(),
+            cadence: record.cadence.into(),
+            start_date: record.start_date,
+            end_date: record.end_date,
+            next_date,
+            created_at: record.created_at,
+            updated_at: record.updated_at,
+        }
+    }
+}
+
+/// Repository for recurring payment database operations.
+pub struct RecurringPaymentRepo;
+
+impl RecurringPaymentRepo {
+    /// Inserts a new recurring payment.
+    ///
+    /// The company must already be known to belong to the user. The first
+    /// occurrence is the start date.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who receives the payments.
+    /// * `request` — The recurring payment details.
+    ///
+    /// # Returns
+    ///
+    /// The created [`RecurringPayment`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert query fails.
+    pub async fn insert_recurring_payment(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        request: &CreateRecurringPaymentRequest,
+    ) -> ApiResult<RecurringPayment> {
+        let record = sqlx::query_as!(
+            RecurringPaymentRecord,
+            r#"
+        INSERT INTO recurring_payments
+            (user_id, company_id, total, payout_type, cadence, start_date, end_date, next_date)
+        VALUES ($1, $2, $3::FLOAT8::DECIMAL, $4, $5, $6, $7, $6)
+        RETURNING id, user_id, company_id, total::FLOAT8 AS "total!",
+                  payout_type AS "payout_type: PayoutTypeRecord",
+                  cadence AS "cadence: RecurrenceCadenceRecord",
+                  start_date, end_date, next_date, created_at, updated_at
+        "#,
+            user_id,
+            request.company_id,
+            request.total,
+            PayoutTypeRecord::from(request.payout_type.clone()) as PayoutTypeRecord,
+            RecurrenceCadenceRecord::from(request.cadence) as RecurrenceCadenceRecord,
+            request.start_date,
+            request.end_date,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record.into())
+    }
+
+    /// Lists a user's recurring payments, oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who receives the payments.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`RecurringPayment`] values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_recurring_payments(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+    ) -> ApiResult<Vec<RecurringPayment>> {
+        let records = sqlx::query_as!(
+            RecurringPaymentRecord,
+            r#"
+        SELECT id, user_id, company_id, total::FLOAT8 AS "total!",
+               payout_type AS "payout_type: PayoutTypeRecord",
+               cadence AS "cadence: RecurrenceCadenceRecord",
+               start_date, end_date, next_date, created_at, updated_at
+        FROM recurring_payments
+        WHERE user_id = $1
+        ORDER BY created_at ASC
+        "#,
+            user_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records.into_iter().map(RecurringPayment::from).collect())
+    }
+
+    /// Finds one of a user's recurring payments by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who receives the payments.
+    /// * `recurring_payment_id` — The recurring payment's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The matching [`RecurringPayment`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no recurring payment with the given ID exists for
+    /// the user.
+    pub async fn find_recurring_payment_by_id(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        recurring_payment_id: Uuid,
+    ) -> ApiResult<RecurringPayment> {
+        let record = sqlx::query_as!(
+            RecurringPaymentRecord,
+            r#"
+        SELECT id, user_id, company_id, total::FLOAT8 AS "total!",
+               payout_type AS "payout_type: PayoutTypeRecord",
+               cadence AS "cadence: RecurrenceCadenceRecord",
+               start_date, end_date, next_date, created_at, updated_at
+        FROM recurring_payments
+        WHERE id = $1 AND user_id = $2
+        "#,
+            recurring_payment_id,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record.into())
+    }
+
+    /// Applies a partial update to one of a user's recurring payments.
+    ///
+    /// Omitted fields keep their stored values. Payments already created
+    /// from the schedule are not changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who receives the payments.
+    /// * `recurring_payment_id` — The recurring payment's UUID.
+    /// * `request` — The fields to change.
+    ///
+    /// # Returns
+    ///
+    /// The updated [`RecurringPayment`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`](crate::core::error::ApiErrorResponse::NotFound)
+    /// if no recurring payment with the given ID exists for the user.
+    /// Returns [`ApiErrorResponse::Validation`](crate::core::error::ApiErrorResponse::Validation)
+    /// if the merged end date is before the start date.
+    pub async fn update_recurring_payment(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        recurring_payment_id: Uuid,
+        request: &UpdateRecurringPaymentRequest,
+    ) -> ApiResult<RecurringPayment> {
+        let mut tx = pool.begin().await?;
+
+        let recurring_payment = sqlx::query_as!(
+            RecurringPaymentRecord,
+            r#"
+        SELECT id, user_id, company_id, total::FLOAT8 AS "total!",
+               payout_type AS "payout_type: PayoutTypeRecord",
+               cadence AS "cadence: RecurrenceCadenceRecord",
+               start_date, end_date, next_date, created_at, updated_at
+        FROM recurring_payments
+        WHERE id = $1 AND user_id = $2
+        FOR UPDATE
+        "#,
+            recurring_payment_id,
+            user_id,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let total = request.total.unwrap_or(recurring_payment.total);
+        let payout_type = request
+            .payout_type
+            .clone()
+            .map(PayoutTypeRecord::from)
+            .unwrap_or(recurring_payment.payout_type);
+        let end_date = request.end_date.unwrap_or(recurring_payment.end_date);
+
+        validate_recurring_payment_dates(recurring_payment.start_date, end_date)?;
+
+        let record = sqlx::query_as!(
+            RecurringPaymentRecord,
+            r#"
+        UPDATE recurring_payments
+        SET total = $3::FLOAT8::DECIMAL,
+            payout_type = $4,
+            end_date = $5,
+            updated_at = now()
+        WHERE id = $1 AND user_id = $2
+        RETURNING id, user_id, company_id, total::FLOAT8 AS "total!",
+                  payout_type AS "payout_type: PayoutTypeRecord",
+                  cadence AS "cadence: RecurrenceCadenceRecord",
+                  start_date, end_date, next_date, created_at, updated_at
+        "#,
+            recurring_payment_id,
+            user_id,
+            total,
+            payout_type as PayoutTypeRecord,
+            end_date,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(record.into())
+    }
+
+    /// Deletes one of a user's recurring payments.
+    ///
+    /// Payments already created from the schedule are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who receives the payments.
+    /// * `recurring_payment_id` — The recurring payment's UUID.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a recurring payment was deleted, `false` if none matched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete query fails.
+    pub async fn delete_recurring_payment(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        recurring_payment_id: Uuid,
+    ) -> ApiResult<bool> {
+        let result = sqlx::query!(
+            r#"
+        DELETE FROM recurring_payments
+        WHERE id = $1 AND user_id = $2
+        "#,
+            recurring_payment_id,
+            user_id,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Creates payments for every recurring payment occurrence due by a date.
+    ///
+    /// Each due schedule is locked, its occurrences up to `today` are
+    /// inserted into `payments`, and its `next_date` is advanced past
+    /// `today`. Schedules locked by a concurrent sweep are skipped, and an
+    /// occurrence that already has a payment is not created twice.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `today` — The latest occurrence date to materialize.
+    ///
+    /// # Returns
+    ///
+    /// The created [`Payment`] values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any query fails. No payments are created in that
+    /// case.
+    pub async fn materialize_due(
+        pool: &Pool<Postgres>,
+        today: NaiveDate,
+    ) -> ApiResult<Vec<Payment>> {
+        let mut tx = pool.begin().await?;
+
+        let due = sqlx::query_as!(
+            RecurringPaymentRecord,
+            r#"
+        SELECT id, user_id, company_id, total::FLOAT8 AS "total!",
+               payout_type AS "payout_type: PayoutTypeRecord",
+               cadence AS "cadence: RecurrenceCadenceRecord",
+               start_date, end_date, next_date, created_at, updated_at
+        FROM recurring_payments
+        WHERE next_date <= $1
+          AND (end_date IS NULL OR next_date <= end_date)
+        FOR UPDATE SKIP LOCKED
+        "#,
+            today,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let mut created = Vec::new();
+
+        for recurring_payment in due {
+            let cadence = recurring_payment.cadence.into();
+            let dates = RecurrenceUtil::occurrences(
+                recurring_payment.start_date,
+                cadence,
+                recurring_payment.end_date,
+                recurring_payment.next_date,
+                Some(today),
+                usize::MAX,
+            );
+            let next_date = today
+                .checked_add_days(Days::new(1))
+                .and_then(|tomorrow| {
+                    RecurrenceUtil::next_on_or_after(
+                        recurring_payment.start_date,
+                        cadence,
+                        tomorrow,
+                    )
+                })
+                .unwrap_or(NaiveDate::MAX);
+
+            let payments = sqlx::query_as!(
+                PaymentRecord,
+                r#"
+            INSERT INTO payments
+                (user_id, company_id, total, payout_type, expected_payout_date,
+                 recurring_payment_id)
+            SELECT $1, $2, $3::FLOAT8::DECIMAL, $4, date, $5
+            FROM UNNEST($6::DATE[]) AS occurrences(date)
+            ON CONFLICT (recurring_payment_id, expected_payout_date)
+                WHERE recurring_payment_id IS NOT NULL
+                DO NOTHING
+            RETURNING id, user_id, company_id, total::FLOAT8 AS "total!",
+                      payout_type AS "payout_type: PayoutTypeRecord",
+                      expected_payout_date, transfer_initiated, payment_received,
+                      tax_withholdings_covered, tax_withholding_rate::FLOAT8 AS tax_withholding_rate,
+                      amount_paid::FLOAT8 AS "amount_paid!",
+                      created_at, updated_at
+            "#,
+                recurring_payment.user_id,
+                recurring_payment.company_id,
+                recurring_payment.total,
+                recurring_payment.payout_type as PayoutTypeRecord,
+                recurring_payment.id,
+                &dates,
+            )
+            .fetch_all(&mut *tx)
+            .await?;
+
+            sqlx::query!(
+                r#"
+            UPDATE recurring_payments
+            SET next_date = $2
+            WHERE id = $1
+            "#,
+                recurring_payment.id,
+                next_date,
+            )
+            .execute(&mut *tx)
+            .await?;
+
+            created.extend(payments.into_iter().map(Payment::from));
+        }
+
+        tx.commit().await?;
+
+        Ok(created)
+    }
+}
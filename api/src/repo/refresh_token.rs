@@ -1,13 +1,16 @@
 //! Refresh token database operations.
 //!
-//! Provides [`RefreshTokenRepo`] for inserting, finding, and revoking
-//! refresh tokens stored in the `refresh_tokens` table.
+//! Provides [`RefreshTokenRepo`] for inserting, rotating, finding, and
+//! revoking refresh tokens stored in the `refresh_tokens` table. Each
+//! refresh token row is one login session, and keeps its ID when rotated.
 
 use chrono::{DateTime, Utc};
 use log::warn;
 use sqlx::{FromRow, Pool, Postgres};
 use uuid::Uuid;
 
+use gig_log_common::models::user::UserSession;
+
 use crate::core::error::ApiResult;
 
 /// A row from the `refresh_tokens` table.
@@ -25,17 +28,61 @@ pub struct RefreshTokenRecord {
     pub revoked: bool,
 }
 
+/// An active session row from the `refresh_tokens` table.
+#[derive(Debug, FromRow)]
+pub struct RefreshTokenSessionRecord {
+    /// Unique identifier for the token record.
+    pub id: Uuid,
+    /// SHA-256 hash of the raw refresh token.
+    pub token_hash: String,
+    /// `User-Agent` of the device that started the session.
+    pub user_agent: Option<String>,
+    /// When the session was started.
+    pub created_at: DateTime<Utc>,
+    /// When the token was last issued or rotated.
+    pub last_used_at: DateTime<Utc>,
+    /// When this token expires.
+    pub expires_at: DateTime<Utc>,
+}
+
+impl RefreshTokenSessionRecord {
+    /// Converts the record into a [`UserSession`].
+    ///
+    /// # Arguments
+    ///
+    /// * `current_hash` — Hash of the refresh token making the request, if
+    ///   any, used to flag the current session.
+    ///
+    /// # Returns
+    ///
+    /// The [`UserSession`] for this record.
+    pub fn into_session(self, current_hash: Option<&str>) -> UserSession {
+        UserSession {
+            current: current_hash == Some(self.token_hash.as_str()),
+            id: self.id,
+            user_agent: self.user_agent,
+            created_at: self.created_at,
+            last_used_at: self.last_used_at,
+            expires_at: self.expires_at,
+        }
+    }
+}
+
 /// Repository for refresh token database operations.
 pub struct RefreshTokenRepo;
 
 impl RefreshTokenRepo {
     /// Inserts a new refresh token with a 30-day expiration.
     ///
+    /// Tokens for demo accounts expire no later than the account itself, and
+    /// user agents are truncated to 512 characters.
+    ///
     /// # Arguments
     ///
     /// * `pool` — The database connection pool.
     /// * `user_id` — The user the token belongs to.
     /// * `token_hash` — The SHA-256 hash of the raw refresh token.
+    /// * `user_agent` — The `User-Agent` of the device logging in, if sent.
     ///
     /// # Returns
     ///
@@ -48,14 +95,19 @@ impl RefreshTokenRepo {
         pool: &Pool<Postgres>,
         user_id: Uuid,
         token_hash: &str,
+        user_agent: Option<&str>,
     ) -> ApiResult<()> {
         sqlx::query!(
             r#"
-        INSERT INTO refresh_tokens (user_id, token_hash, expires_at)
-        VALUES ($1, $2, NOW() + INTERVAL '30 days')
+        INSERT INTO refresh_tokens (user_id, token_hash, expires_at, user_agent)
+        VALUES ($1, $2, LEAST(
+            NOW() + INTERVAL '30 days',
+            (SELECT demo_expires_at FROM users WHERE id = $1)
+        ), LEFT($3, 512))
         "#,
             user_id,
             token_hash,
+            user_agent,
         )
         .execute(pool)
         .await?;
@@ -63,6 +115,131 @@ impl RefreshTokenRepo {
         Ok(())
     }
 
+    /// Replaces an active refresh token with a new one in the same session.
+    ///
+    /// The session keeps its ID and start time; its expiry is extended as
+    /// for a newly inserted token and its last-used time is updated.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `old_hash` — The SHA-256 hash of the refresh token being replaced.
+    /// * `new_hash` — The SHA-256 hash of the replacement refresh token.
+    /// * `user_agent` — The `User-Agent` of the refreshing device, if sent.
+    ///
+    /// # Returns
+    ///
+    /// When the session was started, or `None` if no active token matched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn rotate_token(
+        pool: &Pool<Postgres>,
+        old_hash: &str,
+        new_hash: &str,
+        user_agent: Option<&str>,
+    ) -> ApiResult<Option<DateTime<Utc>>> {
+        let created_at = sqlx::query_scalar!(
+            r#"
+        UPDATE refresh_tokens
+        SET token_hash = $2,
+            user_agent = COALESCE(LEFT($3, 512), user_agent),
+            last_used_at = NOW(),
+            expires_at = LEAST(
+                NOW() + INTERVAL '30 days',
+                (SELECT demo_expires_at FROM users WHERE id = refresh_tokens.user_id)
+            )
+        WHERE token_hash = $1
+          AND revoked = FALSE
+          AND expires_at > NOW()
+        RETURNING created_at
+        "#,
+            old_hash,
+            new_hash,
+            user_agent,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(created_at)
+    }
+
+    /// Lists a user's active (non-revoked and non-expired) sessions.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user whose sessions to list.
+    ///
+    /// # Returns
+    ///
+    /// The active sessions, most recently used first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_active_for_user(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+    ) -> ApiResult<Vec<RefreshTokenSessionRecord>> {
+        let sessions = sqlx::query_as!(
+            RefreshTokenSessionRecord,
+            r#"
+        SELECT id, token_hash, user_agent, created_at, last_used_at, expires_at
+        FROM refresh_tokens
+        WHERE user_id = $1
+          AND revoked = FALSE
+          AND expires_at > NOW()
+        ORDER BY last_used_at DESC
+        "#,
+            user_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(sessions)
+    }
+
+    /// Revokes one of a user's sessions by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user who owns the session.
+    /// * `id` — The session's UUID.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the session was revoked, `false` if no active session
+    /// matched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn revoke_for_user(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        id: Uuid,
+    ) -> ApiResult<bool> {
+        let result = sqlx::query!(
+            r#"
+        UPDATE refresh_tokens
+        SET revoked = TRUE
+        WHERE id = $1
+          AND user_id = $2
+          AND revoked = FALSE
+          AND expires_at > NOW()
+        "#,
+            id,
+            user_id,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
     /// Finds a valid (non-revoked and non-expired) refresh token by its hash.
     ///
     /// # Arguments
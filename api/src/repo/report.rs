@@ -0,0 +1,419 @@
+//! Report database operations.
+//!
+//! Provides [`ReportRepo`] for aggregating a user's work, invoicing, and
+//! payments into per-period earnings totals and per-company tax estimates,
+//! for the user's self-employment tax rate, and for the `report_cache`
+//! table that stores generated reports. Queries for a user's reports are
+//! scoped to the owning user.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde_json::Value;
+use sqlx::{FromRow, Pool, Postgres};
+use uuid::Uuid;
+
+use gig_log_common::models::report::{CompanyTaxEstimate, EarningsPeriod, EarningsPeriodTotals};
+
+use crate::core::error::ApiResult;
+
+/// A cached report due for regeneration.
+#[derive(Debug, Clone, FromRow)]
+pub struct StaleReportRecord {
+    /// The user the report belongs to.
+    pub user_id: Uuid,
+    /// The report's kind and parameters.
+    pub report: Value,
+}
+
+/// Repository for report queries.
+pub struct ReportRepo;
+
+impl ReportRepo {
+    /// Totals a user's earnings for every week or month in a range.
+    ///
+    /// Each period counts completed sessions by start time, invoices by
+    /// when they were finalized, and received payments by their expected
+    /// payout date, or their creation date when they have none. Tax
+    /// withholdings apply the withholding rate each payment recorded when
+    /// it was received, so later changes to a company's rate do not alter
+    /// past periods.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user to report on.
+    /// * `period` — Length of each period.
+    /// * `from` — A day in the first period.
+    /// * `to` — A day in the last period.
+    ///
+    /// # Returns
+    ///
+    /// One [`EarningsPeriodTotals`] per period from the one containing
+    /// `from` through the one containing `to`, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn earnings(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        period: EarningsPeriod,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> ApiResult<Vec<EarningsPeriodTotals>> {
+        let unit = match period {
+            EarningsPeriod::Weekly => "week",
+            EarningsPeriod::Monthly => "month",
+        };
+
+        let totals = sqlx::query_as!(
+            EarningsPeriodTotals,
+            r#"
+        WITH periods AS (
+            SELECT start::DATE AS period_start,
+                   (start + ('1 ' || $2)::INTERVAL)::DATE AS period_end
+            FROM generate_series(
+                date_trunc($2, $3::DATE::TIMESTAMP),
+                date_trunc($2, $4::DATE::TIMESTAMP),
+                ('1 ' || $2)::INTERVAL
+            ) AS start
+        )
+        SELECT p.period_start AS "period_start!",
+               ROUND(COALESCE(worked.seconds, 0) / 3600, 2)::FLOAT8 AS "hours_worked!",
+               ROUND(COALESCE(invoiced.amount, 0), 2)::FLOAT8 AS "invoiced!",
+               ROUND(COALESCE(received.amount, 0), 2)::FLOAT8 AS "received!",
+               ROUND(COALESCE(received.withholdings, 0), 2)::FLOAT8 AS "tax_withholdings!"
+        FROM periods p
+        LEFT JOIN LATERAL (
+            SELECT SUM(EXTRACT(EPOCH FROM (
+                       ws.end_time - ws.start_time - ws.accumulated_paused_duration
+                   ))) AS seconds
+            FROM work_sessions ws
+            WHERE ws.user_id = $1
+              AND ws.end_time IS NOT NULL
+              AND ws.start_time >= p.period_start
+              AND ws.start_time < p.period_end
+        ) worked ON TRUE
+        LEFT JOIN LATERAL (
+            SELECT SUM(li.amount) AS amount
+            FROM invoices i
+            JOIN invoice_line_items li ON li.invoice_id = i.id
+            WHERE i.user_id = $1
+              AND i.status = 'finalized'
+              AND i.finalized_at >= p.period_start
+              AND i.finalized_at < p.period_end
+        ) invoiced ON TRUE
+        LEFT JOIN LATERAL (
+            SELECT SUM(pay.total) AS amount,
+                   SUM(pay.total * COALESCE(pay.tax_withholding_rate, 0)) AS withholdings
+            FROM payments pay
+            WHERE pay.user_id = $1
+              AND pay.payment_received
+              AND COALESCE(pay.expected_payout_date, pay.created_at::DATE) >= p.period_start
+              AND COALESCE(pay.expected_payout_date, pay.created_at::DATE) < p.period_end
+        ) received ON TRUE
+        ORDER BY p.period_start
+        "#,
+            user_id,
+            unit,
+            from,
+            to,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(totals)
+    }
+
+    /// Estimates the tax owed on a user's payments in a date range, per
+    /// company.
+    ///
+    /// Counts received payments by their expected payout date, or their
+    /// creation date when they have none. Each payment is taxed at the
+    /// withholding rate it recorded when it was received, or at the
+    /// self-employment rate if its company did not withhold then, so a
+    /// company whose rate changed gets one estimate per rate. Payments
+    /// marked as having their withholdings covered count toward the
+    /// covered amount.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user to report on.
+    /// * `from` — The first day of the range.
+    /// * `to` — The last day of the range.
+    /// * `self_employment_rate` — Rate applied to companies that do not
+    ///   withhold.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`CompanyTaxEstimate`] values for companies with
+    /// received payments in the range, largest shortfall first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn tax_estimate(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+        self_employment_rate: f64,
+    ) -> ApiResult<Vec<CompanyTaxEstimate>> {
+        let estimates = sqlx::query_as!(
+            CompanyTaxEstimate,
+            r#"
+        WITH rated AS (
+            SELECT c.id AS company_id,
+                   c.name AS company_name,
+                   COALESCE(pay.tax_withholding_rate, $4::FLOAT8::DECIMAL) AS tax_rate,
+                   pay.total,
+                   pay.tax_withholdings_covered
+            FROM payments pay
+            JOIN companies c ON c.id = pay.company_id
+            WHERE pay.user_id = $1
+              AND pay.payment_received
+              AND COALESCE(pay.expected_payout_date, pay.created_at::DATE) >= $2
+              AND COALESCE(pay.expected_payout_date, pay.created_at::DATE) <= $3
+        ),
+        totals AS (
+            SELECT company_id,
+                   company_name,
+                   tax_rate,
+                   SUM(total) AS income,
+                   SUM(total * tax_rate) AS owed,
+                   SUM(total * tax_rate) FILTER (WHERE tax_withholdings_covered) AS covered
+            FROM rated
+            GROUP BY company_id, company_name, tax_rate
+        )
+        SELECT company_id AS "company_id!",
+               company_name AS "company_name!",
+               tax_rate::FLOAT8 AS "tax_rate!",
+               ROUND(income, 2)::FLOAT8 AS "income!",
+               ROUND(owed, 2)::FLOAT8 AS "estimated_owed!",
+               ROUND(COALESCE(covered, 0), 2)::FLOAT8 AS "tax_withholdings_covered!",
+               (ROUND(owed, 2) - ROUND(COALESCE(covered, 0), 2))::FLOAT8 AS "shortfall!"
+        FROM totals
+        ORDER BY owed - COALESCE(covered, 0) DESC, company_name
+        "#,
+            user_id,
+            from,
+            to,
+            self_employment_rate,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(estimates)
+    }
+
+    /// Finds the self-employment tax rate a user has set for themselves.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The user's rate as a decimal, or `None` if they use the default
+    /// rate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the user does not exist.
+    pub async fn find_self_employment_rate(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+    ) -> ApiResult<Option<f64>> {
+        let rate = sqlx::query_scalar!(
+            r#"
+        SELECT self_employment_tax_rate::FLOAT8
+        FROM users
+        WHERE id = $1
+        "#,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(rate)
+    }
+
+    /// Sets or clears the self-employment tax rate a user has set for
+    /// themselves.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    /// * `rate` — The rate as a decimal, or `None` to use the default rate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn set_self_employment_rate(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        rate: Option<f64>,
+    ) -> ApiResult<()> {
+        sqlx::query!(
+            r#"
+        UPDATE users
+        SET self_employment_tax_rate = $2::FLOAT8::DECIMAL
+        WHERE id = $1
+        "#,
+            user_id,
+            rate,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Reads a cached report that has not been marked stale, recording
+    /// that it was read.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user the report belongs to.
+    /// * `report` — The report's kind and parameters.
+    ///
+    /// # Returns
+    ///
+    /// The cached report data, or `None` if the report is not cached or is
+    /// stale.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn find_cached_report(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        report: &Value,
+    ) -> ApiResult<Option<Value>> {
+        let data = sqlx::query_scalar!(
+            r#"
+        UPDATE report_cache
+        SET last_read_at = now()
+        WHERE user_id = $1 AND report = $2 AND NOT stale
+        RETURNING data
+        "#,
+            user_id,
+            report,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(data)
+    }
+
+    /// Stores a freshly generated report, replacing any cached copy.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user the report belongs to.
+    /// * `report` — The report's kind and parameters.
+    /// * `data` — The generated report.
+    /// * `generated_at` — When the report was generated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert query fails.
+    pub async fn store_cached_report(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        report: &Value,
+        data: &Value,
+        generated_at: DateTime<Utc>,
+    ) -> ApiResult<()> {
+        sqlx::query!(
+            r#"
+        INSERT INTO report_cache (user_id, report, data, generated_at)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (user_id, report)
+        DO UPDATE SET data = EXCLUDED.data, stale = FALSE, generated_at = EXCLUDED.generated_at
+        "#,
+            user_id,
+            report,
+            data,
+            generated_at,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Lists cached reports that are stale or older than a maximum age and
+    /// have been read recently.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `max_age_seconds` — Age after which a report is regenerated even
+    ///   if it was not marked stale.
+    /// * `idle_days` — Days without a read after which a report is no
+    ///   longer regenerated.
+    /// * `limit` — Most reports to return.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`StaleReportRecord`] values, most recently read first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_stale_reports(
+        pool: &Pool<Postgres>,
+        max_age_seconds: i64,
+        idle_days: i64,
+        limit: i64,
+    ) -> ApiResult<Vec<StaleReportRecord>> {
+        let reports = sqlx::query_as!(
+            StaleReportRecord,
+            r#"
+        SELECT user_id, report
+        FROM report_cache
+        WHERE (stale OR generated_at < now() - $1::BIGINT * INTERVAL '1 second')
+          AND last_read_at > now() - $2::BIGINT * INTERVAL '1 day'
+        ORDER BY last_read_at DESC
+        LIMIT $3
+        "#,
+            max_age_seconds,
+            idle_days,
+            limit,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(reports)
+    }
+
+    /// Deletes cached reports that have not been read recently.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `idle_days` — Days without a read after which a report is deleted.
+    ///
+    /// # Returns
+    ///
+    /// The number of deleted reports.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete query fails.
+    pub async fn delete_idle_reports(pool: &Pool<Postgres>, idle_days: i64) -> ApiResult<u64> {
+        let result = sqlx::query!(
+            "DELETE FROM report_cache WHERE last_read_at <= now() - $1::BIGINT * INTERVAL '1 day'",
+            idle_days,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
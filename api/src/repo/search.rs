@@ -0,0 +1,209 @@
+//! Full-text search database operations.
+//!
+//! Provides [`SearchRepo`] for matching search terms against the
+//! `search_vector` columns of the `companies` and `jobs` tables. Terms are
+//! parsed with `websearch_to_tsquery`, results are ranked with `ts_rank`,
+//! and `ts_headline` marks the matched words, which are turned into
+//! `<mark>` tags once the rest of the text is HTML-escaped.
+
+use sqlx::{FromRow, Pool, Postgres};
+use uuid::Uuid;
+
+use gig_log_common::models::search::{SearchEntityType, SearchResult};
+
+use crate::core::error::ApiResult;
+
+/// Character `ts_headline` places before a matched word.
+const HIGHLIGHT_START: char = '\u{2}';
+
+/// Character `ts_headline` places after a matched word.
+const HIGHLIGHT_STOP: char = '\u{3}';
+
+/// A company or job matching a search.
+#[derive(Debug, FromRow)]
+pub struct SearchRecord {
+    /// Unique identifier of the matched record.
+    pub id: Uuid,
+    /// The company the record belongs to.
+    pub company_id: Uuid,
+    /// The matched company name or job title.
+    pub title: String,
+    /// `title` with matched words between [`HIGHLIGHT_START`] and
+    /// [`HIGHLIGHT_STOP`].
+    pub headline: String,
+    /// Relevance of the match.
+    pub rank: f32,
+}
+
+impl SearchRecord {
+    /// Converts the record into a [`SearchResult`].
+    ///
+    /// # Arguments
+    ///
+    /// * `entity_type` — The kind of record matched.
+    ///
+    /// # Returns
+    ///
+    /// The [`SearchResult`], with an HTML-escaped snippet highlighting the
+    /// matched words.
+    pub fn into_result(self, entity_type: SearchEntityType) -> SearchResult {
+        SearchResult {
+            entity_type,
+            id: self.id,
+            company_id: self.company_id,
+            title: self.title,
+            snippet: Self::snippet(&self.headline),
+            rank: self.rank,
+        }
+    }
+
+    /// Escapes a headline for HTML and wraps its matched words in `<mark>`
+    /// tags.
+    ///
+    /// # Arguments
+    ///
+    /// * `headline` — The `ts_headline` output.
+    ///
+    /// # Returns
+    ///
+    /// The highlighted snippet.
+    fn snippet(headline: &str) -> String {
+        let mut snippet = String::with_capacity(headline.len());
+
+        for character in headline.chars() {
+            match character {
+                HIGHLIGHT_START => snippet.push_str("<mark>"),
+                HIGHLIGHT_STOP => snippet.push_str("</mark>"),
+                '&' => snippet.push_str("&amp;"),
+                '<' => snippet.push_str("&lt;"),
+                '>' => snippet.push_str("&gt;"),
+                '"' => snippet.push_str("&quot;"),
+                '\'' => snippet.push_str("&#39;"),
+                character => snippet.push(character),
+            }
+        }
+
+        snippet
+    }
+}
+
+/// Repository for full-text search queries.
+pub struct SearchRepo;
+
+impl SearchRepo {
+    /// Searches a user's company names.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the companies.
+    /// * `terms` — The search terms in web search syntax.
+    /// * `limit` — Maximum number of results.
+    ///
+    /// # Returns
+    ///
+    /// The matching companies as [`SearchResult`] values, most relevant
+    /// first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn search_companies(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        terms: &str,
+        limit: i64,
+    ) -> ApiResult<Vec<SearchResult>> {
+        let records = sqlx::query_as!(
+            SearchRecord,
+            r#"
+        WITH search AS (
+            SELECT websearch_to_tsquery('english', $2) AS query
+        )
+        SELECT c.id, c.id AS "company_id!", c.name AS title,
+               ts_headline('english', c.name, search.query,
+                           'HighlightAll=true, StartSel=' || chr(2) || ', StopSel=' || chr(3))
+                   AS "headline!",
+               ts_rank(c.search_vector, search.query) AS "rank!"
+        FROM companies c, search
+        WHERE c.user_id = $1 AND c.search_vector @@ search.query
+        ORDER BY 5 DESC, c.name, c.id
+        LIMIT $3
+        "#,
+            user_id,
+            terms,
+            limit,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records
+            .into_iter()
+            .map(|record| record.into_result(SearchEntityType::Company))
+            .collect())
+    }
+
+    /// Searches a user's job titles.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the jobs.
+    /// * `terms` — The search terms in web search syntax.
+    /// * `limit` — Maximum number of results.
+    ///
+    /// # Returns
+    ///
+    /// The matching jobs as [`SearchResult`] values, most relevant first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn search_jobs(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        terms: &str,
+        limit: i64,
+    ) -> ApiResult<Vec<SearchResult>> {
+        let records = sqlx::query_as!(
+            SearchRecord,
+            r#"
+        WITH search AS (
+            SELECT websearch_to_tsquery('english', $2) AS query
+        )
+        SELECT j.id, j.company_id, j.title,
+               ts_headline('english', j.title, search.query,
+                           'HighlightAll=true, StartSel=' || chr(2) || ', StopSel=' || chr(3))
+                   AS "headline!",
+               ts_rank(j.search_vector, search.query) AS "rank!"
+        FROM jobs j, search
+        WHERE j.user_id = $1 AND j.search_vector @@ search.query
+        ORDER BY 5 DESC, j.title, j.id
+        LIMIT $3
+        "#,
+            user_id,
+            terms,
+            limit,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records
+            .into_iter()
+            .map(|record| record.into_result(SearchEntityType::Job))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snippet_escapes_and_highlights() {
+        assert_eq!(
+            SearchRecord::snippet("\u{2}Acme\u{3} <Design> & Co"),
+            "<mark>Acme</mark> &lt;Design&gt; &amp; Co"
+        );
+    }
+}
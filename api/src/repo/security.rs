@@ -0,0 +1,344 @@
+//! Unusual account activity database operations.
+//!
+//! Provides [`SecurityRepo`] for the signals anomaly detection relies on
+//! (recent failed log-ins in the `failed_logins` table, the countries a user
+//! has logged in from in the `login_countries` table, and recent deletions
+//! in the `api_activity` table) and for recording the resulting
+//! [`SecurityEventKind`] events and re-authentication holds.
+
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::core::error::ApiResult;
+
+/// A kind of unusual account activity.
+///
+/// Maps to the PostgreSQL `security_event_kind` enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "security_event_kind", rename_all = "snake_case")]
+pub enum SecurityEventKind {
+    /// Many failed log-ins in a short time.
+    FailedLogins,
+    /// A log-in from a country the user never logged in from before.
+    NewCountryLogin,
+    /// Many deletions in a short time.
+    MassDeletion,
+}
+
+/// Repository for unusual account activity.
+pub struct SecurityRepo;
+
+impl SecurityRepo {
+    /// Records a failed log-in to an account, purging attempts older than
+    /// the detection window.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `email` — The email address the log-in was for.
+    /// * `ip_address` — The address the attempt came from, if known.
+    /// * `window_seconds` — How long failed log-ins are counted for.
+    ///
+    /// # Returns
+    ///
+    /// The account's user ID, or `None` if no account has the email.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a query fails.
+    pub async fn insert_failed_login(
+        pool: &Pool<Postgres>,
+        email: &str,
+        ip_address: Option<&str>,
+        window_seconds: u64,
+    ) -> ApiResult<Option<Uuid>> {
+        sqlx::query!(
+            r#"
+        DELETE FROM failed_logins
+        WHERE created_at < NOW() - make_interval(secs => $1)
+        "#,
+            window_seconds as f64,
+        )
+        .execute(pool)
+        .await?;
+
+        let user_id = sqlx::query_scalar!(
+            r#"
+        INSERT INTO failed_logins (user_id, ip_address)
+        SELECT id, $2
+        FROM users
+        WHERE email = $1
+        RETURNING user_id
+        "#,
+            email,
+            ip_address,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(user_id)
+    }
+
+    /// Counts a user's failed log-ins within the detection window.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    /// * `window_seconds` — How far back to count.
+    ///
+    /// # Returns
+    ///
+    /// The number of failed log-ins.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn count_failed_logins(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        window_seconds: u64,
+    ) -> ApiResult<i64> {
+        let count = sqlx::query_scalar!(
+            r#"
+        SELECT COUNT(*) AS "count!"
+        FROM failed_logins
+        WHERE user_id = $1
+          AND created_at > NOW() - make_interval(secs => $2)
+        "#,
+            user_id,
+            window_seconds as f64,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(count)
+    }
+
+    /// Clears a user's failed log-ins after a successful one.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    ///
+    /// # Returns
+    ///
+    /// `()` on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete fails.
+    pub async fn clear_failed_logins(pool: &Pool<Postgres>, user_id: Uuid) -> ApiResult<()> {
+        sqlx::query!(
+            r#"
+        DELETE FROM failed_logins
+        WHERE user_id = $1
+        "#,
+            user_id,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Records the country a user logged in from.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    /// * `country` — The ISO 3166-1 alpha-2 country code.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the user never logged in from the country before but has
+    /// logged in from others.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a query fails.
+    pub async fn insert_login_country(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        country: &str,
+    ) -> ApiResult<bool> {
+        let has_history = sqlx::query_scalar!(
+            r#"
+        SELECT EXISTS (
+            SELECT 1 FROM login_countries WHERE user_id = $1
+        ) AS "exists!"
+        "#,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        let result = sqlx::query!(
+            r#"
+        INSERT INTO login_countries (user_id, country)
+        VALUES ($1, $2)
+        ON CONFLICT (user_id, country) DO NOTHING
+        "#,
+            user_id,
+            country,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(has_history && result.rows_affected() > 0)
+    }
+
+    /// Counts a user's successful deletions within the detection window.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    /// * `window_seconds` — How far back to count.
+    ///
+    /// # Returns
+    ///
+    /// The number of `DELETE` requests that succeeded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn count_deletions(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        window_seconds: u64,
+    ) -> ApiResult<i64> {
+        let count = sqlx::query_scalar!(
+            r#"
+        SELECT COUNT(*) AS "count!"
+        FROM api_activity
+        WHERE user_id = $1
+          AND method = 'DELETE'
+          AND status BETWEEN 200 AND 299
+          AND created_at > NOW() - make_interval(secs => $2)
+        "#,
+            user_id,
+            window_seconds as f64,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(count)
+    }
+
+    /// Records unusual activity unless the same kind was recorded for the
+    /// user within the cooldown.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    /// * `kind` — The [`SecurityEventKind`] detected.
+    /// * `detail` — Description of what happened.
+    /// * `cooldown_seconds` — How long a recorded event suppresses repeats.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the event was recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert query fails.
+    pub async fn insert_event(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        kind: SecurityEventKind,
+        detail: &str,
+        cooldown_seconds: u64,
+    ) -> ApiResult<bool> {
+        let result = sqlx::query!(
+            r#"
+        INSERT INTO security_events (user_id, kind, detail)
+        SELECT $1, $2, $3
+        WHERE NOT EXISTS (
+            SELECT 1
+            FROM security_events
+            WHERE user_id = $1
+              AND kind = $2
+              AND created_at > NOW() - make_interval(secs => $4)
+        )
+        "#,
+            user_id,
+            kind as SecurityEventKind,
+            detail,
+            cooldown_seconds as f64,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Starts a re-authentication hold on a user's account.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    ///
+    /// # Returns
+    ///
+    /// `()` on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn start_hold(pool: &Pool<Postgres>, user_id: Uuid) -> ApiResult<()> {
+        sqlx::query!(
+            r#"
+        UPDATE users
+        SET security_hold_at = NOW()
+        WHERE id = $1
+        "#,
+            user_id,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Retrieves when a user's active re-authentication hold started.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    /// * `hold_seconds` — How long a hold lasts.
+    ///
+    /// # Returns
+    ///
+    /// The start of the hold, or `None` if no hold is active.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn find_active_hold(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        hold_seconds: u64,
+    ) -> ApiResult<Option<DateTime<Utc>>> {
+        let hold_at = sqlx::query_scalar!(
+            r#"
+        SELECT security_hold_at AS "security_hold_at!"
+        FROM users
+        WHERE id = $1
+          AND security_hold_at > NOW() - make_interval(secs => $2)
+        "#,
+            user_id,
+            hold_seconds as f64,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(hold_at)
+    }
+}
@@ -0,0 +1,106 @@
+//! Company statement database operations.
+//!
+//! Provides [`StatementRepo`] for recording and listing the statements
+//! emailed to companies in the `company_statements` table. All queries are
+//! scoped to the owning user.
+
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use gig_log_common::models::statement::{CompanyStatement, SendStatementRequest};
+
+use crate::core::error::ApiResult;
+
+/// Repository for company statement database operations.
+pub struct StatementRepo;
+
+impl StatementRepo {
+    /// Records a statement that was sent to a company.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who sent the statement.
+    /// * `company_id` — The UUID of the company the statement covers.
+    /// * `request` — The recipient and period the statement was sent for.
+    /// * `total_hours` — Hours worked during the period.
+    /// * `total_payments` — Total of payments during the period in dollars.
+    ///
+    /// # Returns
+    ///
+    /// The recorded [`CompanyStatement`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert query fails.
+    pub async fn insert_statement(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        company_id: Uuid,
+        request: &SendStatementRequest,
+        total_hours: f64,
+        total_payments: f64,
+    ) -> ApiResult<CompanyStatement> {
+        let statement = sqlx::query_as!(
+            CompanyStatement,
+            r#"
+        INSERT INTO company_statements (user_id, company_id, recipient_email, period_start,
+                                        period_end, total_hours, total_payments)
+        VALUES ($1, $2, $3, $4, $5, $6::FLOAT8::DECIMAL, $7::FLOAT8::DECIMAL)
+        RETURNING id, user_id, company_id, recipient_email, period_start, period_end,
+                  total_hours::FLOAT8 AS "total_hours!", total_payments::FLOAT8 AS "total_payments!",
+                  sent_at
+        "#,
+            user_id,
+            company_id,
+            request.recipient_email,
+            request.period_start,
+            request.period_end,
+            total_hours,
+            total_payments,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(statement)
+    }
+
+    /// Lists the statements sent to a company, most recent first.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who sent the statements.
+    /// * `company_id` — The UUID of the company the statements cover.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`CompanyStatement`] values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_statements(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        company_id: Uuid,
+    ) -> ApiResult<Vec<CompanyStatement>> {
+        let statements = sqlx::query_as!(
+            CompanyStatement,
+            r#"
+        SELECT id, user_id, company_id, recipient_email, period_start, period_end,
+               total_hours::FLOAT8 AS "total_hours!", total_payments::FLOAT8 AS "total_payments!",
+               sent_at
+        FROM company_statements
+        WHERE user_id = $1 AND company_id = $2
+        ORDER BY sent_at DESC
+        "#,
+            user_id,
+            company_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(statements)
+    }
+}
@@ -0,0 +1,336 @@
+//! Subscription database operations.
+//!
+//! Provides [`SubscriptionRepo`] for the `subscriptions` table, which links
+//! users to their Stripe customer and mirrors their subscription's billing
+//! status, and for the `stripe_webhook_events` table, which records the
+//! webhook events already processed.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgExecutor, Pool, Postgres};
+use uuid::Uuid;
+
+use gig_log_common::models::subscription::{Subscription, SubscriptionPlan, SubscriptionStatus};
+
+use crate::core::error::ApiResult;
+
+/// A pricing tier as stored in the database.
+///
+/// Maps to the PostgreSQL `subscription_plan` enum.
+#[derive(Debug, Clone, sqlx::Type)]
+#[sqlx(type_name = "subscription_plan", rename_all = "snake_case")]
+pub enum SubscriptionPlanRecord {
+    /// The free tier.
+    Free,
+    /// The paid tier.
+    Pro,
+}
+
+impl From<SubscriptionPlanRecord> for SubscriptionPlan {
+    fn from(plan: SubscriptionPlanRecord) -> Self {
+        match plan {
+            SubscriptionPlanRecord::Free => SubscriptionPlan::Free,
+            SubscriptionPlanRecord::Pro => SubscriptionPlan::Pro,
+        }
+    }
+}
+
+impl From<SubscriptionPlan> for SubscriptionPlanRecord {
+    fn from(plan: SubscriptionPlan) -> Self {
+        match plan {
+            SubscriptionPlan::Free => SubscriptionPlanRecord::Free,
+            SubscriptionPlan::Pro => SubscriptionPlanRecord::Pro,
+        }
+    }
+}
+
+/// A subscription's Stripe status as stored in the database.
+///
+/// Maps to the PostgreSQL `subscription_status` enum.
+#[derive(Debug, Clone, sqlx::Type)]
+#[sqlx(type_name = "subscription_status", rename_all = "snake_case")]
+pub enum SubscriptionStatusRecord {
+    /// Awaiting the first payment.
+    Incomplete,
+    /// The first payment never succeeded.
+    IncompleteExpired,
+    /// In its free trial.
+    Trialing,
+    /// Paid up.
+    Active,
+    /// A renewal payment is being retried.
+    PastDue,
+    /// Ended.
+    Canceled,
+    /// Renewal retries were exhausted.
+    Unpaid,
+    /// The trial ended without a payment method.
+    Paused,
+}
+
+impl From<SubscriptionStatusRecord> for SubscriptionStatus {
+    fn from(status: SubscriptionStatusRecord) -> Self {
+        match status {
+            SubscriptionStatusRecord::Incomplete => SubscriptionStatus::Incomplete,
+            SubscriptionStatusRecord::IncompleteExpired => SubscriptionStatus::IncompleteExpired,
+            SubscriptionStatusRecord::Trialing => SubscriptionStatus::Trialing,
+            SubscriptionStatusRecord::Active => SubscriptionStatus::Active,
+            SubscriptionStatusRecord::PastDue => SubscriptionStatus::PastDue,
+            SubscriptionStatusRecord::Canceled => SubscriptionStatus::Canceled,
+            SubscriptionStatusRecord::Unpaid => SubscriptionStatus::Unpaid,
+            SubscriptionStatusRecord::Paused => SubscriptionStatus::Paused,
+        }
+    }
+}
+
+impl From<SubscriptionStatus> for SubscriptionStatusRecord {
+    fn from(status: SubscriptionStatus) -> Self {
+        match status {
+            SubscriptionStatus::Incomplete => SubscriptionStatusRecord::Incomplete,
+            SubscriptionStatus::IncompleteExpired => SubscriptionStatusRecord::IncompleteExpired,
+            SubscriptionStatus::Trialing => SubscriptionStatusRecord::Trialing,
+            SubscriptionStatus::Active => SubscriptionStatusRecord::Active,
+            SubscriptionStatus::PastDue => SubscriptionStatusRecord::PastDue,
+            SubscriptionStatus::Canceled => SubscriptionStatusRecord::Canceled,
+            SubscriptionStatus::Unpaid => SubscriptionStatusRecord::Unpaid,
+            SubscriptionStatus::Paused => SubscriptionStatusRecord::Paused,
+        }
+    }
+}
+
+/// A row from the `subscriptions` table.
+#[derive(Debug, FromRow)]
+struct SubscriptionRecord {
+    /// The subscribed plan.
+    plan: SubscriptionPlanRecord,
+    /// The Stripe status, if a subscription was ever created.
+    status: Option<SubscriptionStatusRecord>,
+    /// When the current billing period ends.
+    current_period_end: Option<DateTime<Utc>>,
+    /// Whether the subscription ends at the end of the period.
+    cancel_at_period_end: bool,
+}
+
+impl From<SubscriptionRecord> for Subscription {
+    fn from(record: SubscriptionRecord) -> Self {
+        Self {
+            plan: record.plan.into(),
+            status: record.status.map(Into::into),
+            current_period_end: record.current_period_end,
+            cancel_at_period_end: record.cancel_at_period_end,
+        }
+    }
+}
+
+/// A subscription change reported by a Stripe webhook.
+#[derive(Debug)]
+pub struct SubscriptionSync<'a> {
+    /// The Stripe ID of the customer who owns the subscription.
+    pub stripe_customer_id: &'a str,
+    /// The subscription's Stripe ID.
+    pub stripe_subscription_id: &'a str,
+    /// The plan the subscribed price belongs to.
+    pub plan: SubscriptionPlan,
+    /// The subscription's Stripe status.
+    pub status: SubscriptionStatus,
+    /// When the current billing period ends.
+    pub current_period_end: Option<DateTime<Utc>>,
+    /// Whether the subscription ends at the end of the period.
+    pub cancel_at_period_end: bool,
+    /// When the reporting event happened.
+    pub event_at: DateTime<Utc>,
+}
+
+/// Repository for subscription database operations.
+pub struct SubscriptionRepo;
+
+impl SubscriptionRepo {
+    /// Retrieves a user's subscription.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The user's [`Subscription`], or the default free subscription if the
+    /// user never started a checkout.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn find_subscription(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+    ) -> ApiResult<Subscription> {
+        let record = sqlx::query_as!(
+            SubscriptionRecord,
+            r#"
+        SELECT plan AS "plan: SubscriptionPlanRecord",
+               status AS "status: SubscriptionStatusRecord",
+               current_period_end,
+               cancel_at_period_end
+        FROM subscriptions
+        WHERE user_id = $1
+        "#,
+            user_id,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(record.map(Into::into).unwrap_or_default())
+    }
+
+    /// Retrieves the Stripe customer linked to a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The customer's Stripe ID, or `None` if the user has none yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn find_customer_id(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+    ) -> ApiResult<Option<String>> {
+        let customer_id = sqlx::query_scalar!(
+            r#"
+        SELECT stripe_customer_id
+        FROM subscriptions
+        WHERE user_id = $1
+        "#,
+            user_id,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(customer_id)
+    }
+
+    /// Links a Stripe customer to a user.
+    ///
+    /// If the user was linked to a customer concurrently, that link is kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    /// * `stripe_customer_id` — The new customer's Stripe ID.
+    ///
+    /// # Returns
+    ///
+    /// The Stripe ID of the customer linked to the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn insert_customer(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        stripe_customer_id: &str,
+    ) -> ApiResult<String> {
+        let customer_id = sqlx::query_scalar!(
+            r#"
+        INSERT INTO subscriptions (user_id, stripe_customer_id)
+        VALUES ($1, $2)
+        ON CONFLICT (user_id) DO UPDATE SET user_id = EXCLUDED.user_id
+        RETURNING stripe_customer_id
+        "#,
+            user_id,
+            stripe_customer_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(customer_id)
+    }
+
+    /// Records that a webhook event is being processed.
+    ///
+    /// # Arguments
+    ///
+    /// * `executor` — The pool or transaction to run the insert on.
+    /// * `event_id` — The event's Stripe ID.
+    /// * `event_type` — The event type.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the event had not been processed before.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert query fails.
+    pub async fn record_webhook_event<'e>(
+        executor: impl PgExecutor<'e>,
+        event_id: &str,
+        event_type: &str,
+    ) -> ApiResult<bool> {
+        let result = sqlx::query!(
+            r#"
+        INSERT INTO stripe_webhook_events (id, event_type)
+        VALUES ($1, $2)
+        ON CONFLICT (id) DO NOTHING
+        "#,
+            event_id,
+            event_type,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Applies a subscription change reported by Stripe.
+    ///
+    /// Stripe does not guarantee delivery order, so the change is skipped if
+    /// a later event was already applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `executor` — The pool or transaction to run the update on.
+    /// * `sync` — The [`SubscriptionSync`] to apply.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a subscription was updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn sync_subscription<'e>(
+        executor: impl PgExecutor<'e>,
+        sync: &SubscriptionSync<'_>,
+    ) -> ApiResult<bool> {
+        let result = sqlx::query!(
+            r#"
+        UPDATE subscriptions
+        SET stripe_subscription_id = $2,
+            plan = $3,
+            status = $4,
+            current_period_end = $5,
+            cancel_at_period_end = $6,
+            stripe_event_at = $7,
+            updated_at = NOW()
+        WHERE stripe_customer_id = $1
+          AND (stripe_event_at IS NULL OR stripe_event_at <= $7)
+        "#,
+            sync.stripe_customer_id,
+            sync.stripe_subscription_id,
+            SubscriptionPlanRecord::from(sync.plan) as SubscriptionPlanRecord,
+            SubscriptionStatusRecord::from(sync.status) as SubscriptionStatusRecord,
+            sync.current_period_end,
+            sync.cancel_at_period_end,
+            sync.event_at,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,132 @@
+//! Time-off database operations.
+//!
+//! Provides [`TimeOffRepo`] for scheduling, listing, and removing planned
+//! time off in the `time_off` table. All queries are scoped to the owning
+//! user.
+
+use chrono::NaiveDate;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use gig_log_common::models::time_off::{CreateTimeOffRequest, TimeOff};
+
+use crate::core::error::ApiResult;
+
+/// Repository for time-off database operations.
+pub struct TimeOffRepo;
+
+impl TimeOffRepo {
+    /// Inserts a new time-off entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user taking the time off.
+    /// * `request` — The validated [`CreateTimeOffRequest`].
+    ///
+    /// # Returns
+    ///
+    /// The newly created [`TimeOff`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert query fails.
+    pub async fn insert_time_off(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        request: CreateTimeOffRequest,
+    ) -> ApiResult<TimeOff> {
+        let time_off = sqlx::query_as!(
+            TimeOff,
+            r#"
+        INSERT INTO time_off (user_id, start_date, end_date, note)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id, user_id, start_date, end_date, note, created_at, updated_at
+        "#,
+            user_id,
+            request.start_date,
+            request.end_date,
+            request.note,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(time_off)
+    }
+
+    /// Lists a user's time off overlapping an optional date range.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user.
+    /// * `from` — Only include entries ending on or after this date.
+    /// * `to` — Only include entries starting on or before this date.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`TimeOff`] values ordered by start date.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_overlapping(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    ) -> ApiResult<Vec<TimeOff>> {
+        let entries = sqlx::query_as!(
+            TimeOff,
+            r#"
+        SELECT id, user_id, start_date, end_date, note, created_at, updated_at
+        FROM time_off
+        WHERE user_id = $1
+          AND ($2::DATE IS NULL OR end_date >= $2)
+          AND ($3::DATE IS NULL OR start_date <= $3)
+        ORDER BY start_date, created_at
+        "#,
+            user_id,
+            from,
+            to,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(entries)
+    }
+
+    /// Deletes a time-off entry, scoped to the owning user.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user.
+    /// * `time_off_id` — The time-off entry's UUID.
+    ///
+    /// # Returns
+    ///
+    /// `true` if an entry was deleted, `false` if none matched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete query fails.
+    pub async fn delete_time_off(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        time_off_id: Uuid,
+    ) -> ApiResult<bool> {
+        let result = sqlx::query!(
+            r#"
+        DELETE FROM time_off
+        WHERE id = $1 AND user_id = $2
+        "#,
+            time_off_id,
+            user_id,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,556 @@
+//! Timesheet approval database operations.
+//!
+//! Provides [`TimesheetApprovalRepo`] for creating shareable timesheet
+//! approval requests, loading the sessions they cover, and recording a
+//! client's approval or dispute in the `timesheet_approvals` table. An
+//! approval only covers the sessions the client reviewed: the shared view
+//! carries a version of its entries, and approving with a stale version is
+//! rejected.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sha2::{Digest, Sha256};
+use sqlx::{FromRow, Pool, Postgres, Transaction};
+use uuid::Uuid;
+
+use gig_log_common::models::timesheet::{ApprovalStatus, TimesheetApproval, TimesheetEntry};
+
+use crate::core::error::{ApiErrorResponse, ApiResult};
+
+/// The review state of a timesheet approval row.
+///
+/// Maps to the PostgreSQL `approval_status` enum.
+#[derive(Debug, Clone, sqlx::Type)]
+#[sqlx(type_name = "approval_status", rename_all = "snake_case")]
+pub enum ApprovalStatusRecord {
+    /// The client has not yet responded.
+    Pending,
+    /// The client approved the hours.
+    Approved,
+    /// The client disputed the hours.
+    Disputed,
+}
+
+impl From<ApprovalStatusRecord> for ApprovalStatus {
+    fn from(status: ApprovalStatusRecord) -> Self {
+        match status {
+            ApprovalStatusRecord::Pending => ApprovalStatus::Pending,
+            ApprovalStatusRecord::Approved => ApprovalStatus::Approved,
+            ApprovalStatusRecord::Disputed => ApprovalStatus::Disputed,
+        }
+    }
+}
+
+/// A row from the `timesheet_approvals` table.
+#[derive(Debug, FromRow)]
+pub struct TimesheetApprovalRecord {
+    /// Unique identifier for the approval request.
+    pub id: Uuid,
+    /// The user who shared the timesheet.
+    pub user_id: Uuid,
+    /// The company whose work is being reviewed.
+    pub company_id: Uuid,
+    /// First day of the reviewed period (inclusive).
+    pub period_start: NaiveDate,
+    /// Last day of the reviewed period (inclusive).
+    pub period_end: NaiveDate,
+    /// Current review state.
+    pub status: ApprovalStatusRecord,
+    /// Email address of the client who responded.
+    pub approver_email: Option<String>,
+    /// Reason given when the hours were disputed.
+    pub dispute_reason: Option<String>,
+    /// When the client responded.
+    pub responded_at: Option<DateTime<Utc>>,
+    /// When the shareable link expires.
+    pub expires_at: DateTime<Utc>,
+    /// When the approval request was created.
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<TimesheetApprovalRecord> for TimesheetApproval {
+    fn from(record: TimesheetApprovalRecord) -> Self {
+        Self {
+            id: record.id,
+            user_id: record.user_id,
+            company_id: record.company_id,
+            period_start: record.period_start,
+            period_end: record.period_end,
+            status: record.status.into(),
+            approver_email: record.approver_email,
+            dispute_reason: record.dispute_reason,
+            responded_at: record.responded_at,
+            expires_at: record.expires_at,
+            created_at: record.created_at,
+        }
+    }
+}
+
+/// Repository for timesheet approval database operations.
+pub struct TimesheetApprovalRepo;
+
+impl TimesheetApprovalRepo {
+    /// Inserts a new pending timesheet approval request.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user sharing the timesheet.
+    /// * `company_id` — The UUID of the company whose work is reviewed.
+    /// * `period_start` — First day of the reviewed period.
+    /// * `period_end` — Last day of the reviewed period.
+    /// * `token_hash` — SHA-256 hash of the raw share token.
+    /// * `expires_at` — When the share link should stop working.
+    ///
+    /// # Returns
+    ///
+    /// The newly created [`TimesheetApproval`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert query fails.
+    pub async fn insert_approval(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        company_id: Uuid,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+        token_hash: &str,
+        expires_at: DateTime<Utc>,
+    ) -> ApiResult<TimesheetApproval> {
+        let record = sqlx::query_as!(
+            TimesheetApprovalRecord,
+            r#"
+        INSERT INTO timesheet_approvals
+            (user_id, company_id, period_start, period_end, token_hash, expires_at)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING id, user_id, company_id, period_start, period_end,
+                  status AS "status: ApprovalStatusRecord", approver_email, dispute_reason,
+                  responded_at, expires_at, created_at
+        "#,
+            user_id,
+            company_id,
+            period_start,
+            period_end,
+            token_hash,
+            expires_at,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record.into())
+    }
+
+    /// Lists all timesheet approval requests created by a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who shared the timesheets.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`TimesheetApproval`] values, newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_for_user(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+    ) -> ApiResult<Vec<TimesheetApproval>> {
+        let records = sqlx::query_as!(
+            TimesheetApprovalRecord,
+            r#"
+        SELECT id, user_id, company_id, period_start, period_end,
+               status AS "status: ApprovalStatusRecord", approver_email, dispute_reason,
+               responded_at, expires_at, created_at
+        FROM timesheet_approvals
+        WHERE user_id = $1
+        ORDER BY created_at DESC
+        "#,
+            user_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records.into_iter().map(TimesheetApproval::from).collect())
+    }
+
+    /// Finds an unexpired timesheet approval request by its share-token hash.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `token_hash` — SHA-256 hash of the raw share token.
+    ///
+    /// # Returns
+    ///
+    /// The matching [`TimesheetApprovalRecord`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no unexpired request matches the hash.
+    pub async fn find_by_token_hash(
+        pool: &Pool<Postgres>,
+        token_hash: &str,
+    ) -> ApiResult<TimesheetApprovalRecord> {
+        let record = sqlx::query_as!(
+            TimesheetApprovalRecord,
+            r#"
+        SELECT id, user_id, company_id, period_start, period_end,
+               status AS "status: ApprovalStatusRecord", approver_email, dispute_reason,
+               responded_at, expires_at, created_at
+        FROM timesheet_approvals
+        WHERE token_hash = $1
+          AND expires_at > NOW()
+        "#,
+            token_hash,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// Lists the completed work sessions covered by an approval request.
+    ///
+    /// Includes sessions for the request's company that started within the
+    /// reviewed period (UTC dates, inclusive).
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `approval` — The approval request whose sessions are listed.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`TimesheetEntry`] values ordered by start time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_entries(
+        pool: &Pool<Postgres>,
+        approval: &TimesheetApprovalRecord,
+    ) -> ApiResult<Vec<TimesheetEntry>> {
+        let entries = sqlx::query_as!(
+            TimesheetEntry,
+            r#"
+        SELECT ws.id AS work_session_id,
+               j.title AS job_title,
+               ws.start_time,
+               ws.end_time AS "end_time!",
+               EXTRACT(EPOCH FROM (ws.end_time - ws.start_time - ws.accumulated_paused_duration))::BIGINT
+                   AS "net_seconds!"
+        FROM work_sessions ws
+        JOIN jobs j ON j.id = ws.job_id
+        WHERE ws.user_id = $1
+          AND j.company_id = $2
+          AND ws.is_running = FALSE
+          AND ws.end_time IS NOT NULL
+          AND (ws.start_time AT TIME ZONE 'UTC')::DATE BETWEEN $3 AND $4
+        ORDER BY ws.start_time, ws.id
+        "#,
+            approval.user_id,
+            approval.company_id,
+            approval.period_start,
+            approval.period_end,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(entries)
+    }
+
+    /// Records a client's approval and locks the covered sessions.
+    ///
+    /// Runs in a single transaction: locks the sessions in the period,
+    /// checks that they still match the version the client reviewed,
+    /// transitions the request from pending to approved, and stamps those
+    /// sessions with the approval's ID so later edits can be rejected.
+    /// Sessions logged after the check are left unapproved.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `approval` — The approval request being approved.
+    /// * `approver_email` — Email address of the approving client.
+    /// * `version` — The [`version`](Self::version) of the entries the
+    ///   client reviewed.
+    ///
+    /// # Returns
+    ///
+    /// The updated [`TimesheetApproval`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the sessions changed
+    /// since the client loaded the timesheet, or if the request has already
+    /// been responded to or has expired. Returns an error if any query fails.
+    pub async fn approve(
+        pool: &Pool<Postgres>,
+        approval: &TimesheetApprovalRecord,
+        approver_email: &str,
+        version: &str,
+    ) -> ApiResult<TimesheetApproval> {
+        let mut tx = pool.begin().await?;
+
+        let entries = Self::lock_entries(&mut tx, approval).await?;
+        Self::check_version(&entries, version)?;
+
+        let record = sqlx::query_as!(
+            TimesheetApprovalRecord,
+            r#"
+        UPDATE timesheet_approvals
+        SET status = 'approved', approver_email = $2, responded_at = NOW(), updated_at = NOW()
+        WHERE id = $1
+          AND status = 'pending'
+          AND expires_at > NOW()
+        RETURNING id, user_id, company_id, period_start, period_end,
+                  status AS "status: ApprovalStatusRecord", approver_email, dispute_reason,
+                  responded_at, expires_at, created_at
+        "#,
+            approval.id,
+            approver_email,
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| {
+            ApiErrorResponse::BadRequest("Timesheet has already been reviewed".to_string())
+        })?;
+
+        let session_ids: Vec<Uuid> = entries.iter().map(|entry| entry.work_session_id).collect();
+
+        sqlx::query!(
+            r#"
+        UPDATE work_sessions
+        SET approval_id = $1, updated_at = NOW()
+        WHERE id = ANY($2)
+          AND approval_id IS NULL
+        "#,
+            record.id,
+            &session_ids,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(record.into())
+    }
+
+    /// Records a client's dispute of a shared timesheet.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `approval_id` — The UUID of the approval request.
+    /// * `approver_email` — Email address of the disputing client.
+    /// * `reason` — Explanation of what is disputed.
+    ///
+    /// # Returns
+    ///
+    /// The updated [`TimesheetApproval`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the request has already
+    /// been responded to or has expired. Returns an error if the query fails.
+    pub async fn dispute(
+        pool: &Pool<Postgres>,
+        approval_id: Uuid,
+        approver_email: &str,
+        reason: &str,
+    ) -> ApiResult<TimesheetApproval> {
+        let record = sqlx::query_as!(
+            TimesheetApprovalRecord,
+            r#"
+        UPDATE timesheet_approvals
+        SET status = 'disputed', approver_email = $2, dispute_reason = $3,
+            responded_at = NOW(), updated_at = NOW()
+        WHERE id = $1
+          AND status = 'pending'
+          AND expires_at > NOW()
+        RETURNING id, user_id, company_id, period_start, period_end,
+                  status AS "status: ApprovalStatusRecord", approver_email, dispute_reason,
+                  responded_at, expires_at, created_at
+        "#,
+            approval_id,
+            approver_email,
+            reason,
+        )
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| {
+            ApiErrorResponse::BadRequest("Timesheet has already been reviewed".to_string())
+        })?;
+
+        Ok(record.into())
+    }
+
+    /// Computes the version of a shared timesheet's entries.
+    ///
+    /// The version changes whenever a session is added to or removed from
+    /// the period, or has its times changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` — The entries shown to the client, in display order.
+    ///
+    /// # Returns
+    ///
+    /// A hex-encoded SHA-256 digest of the entries.
+    pub fn version(entries: &[TimesheetEntry]) -> String {
+        let mut hasher = Sha256::new();
+
+        for entry in entries {
+            hasher.update(
+                format!(
+                    "{}|{}|{}|{}\n",
+                    entry.work_session_id,
+                    entry.start_time.timestamp_micros(),
+                    entry.end_time.timestamp_micros(),
+                    entry.net_seconds,
+                )
+                .as_bytes(),
+            );
+        }
+
+        format!("{:x}", hasher.finalize())
+    }
+
+    /// Checks that entries still match the version the client reviewed.
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` — The current entries for the period.
+    /// * `version` — The version sent by the client.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the versions differ.
+    fn check_version(entries: &[TimesheetEntry], version: &str) -> ApiResult<()> {
+        if Self::version(entries) != version {
+            return Err(ApiErrorResponse::BadRequest(
+                "Timesheet has changed since it was opened. Reload it to review the current hours"
+                    .to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Lists an approval request's entries and locks their sessions until
+    /// the transaction ends.
+    ///
+    /// Matches the sessions and order of [`list_entries`](Self::list_entries).
+    ///
+    /// # Arguments
+    ///
+    /// * `tx` — The open transaction.
+    /// * `approval` — The approval request whose sessions are locked.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`TimesheetEntry`] values ordered by start time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    async fn lock_entries(
+        tx: &mut Transaction<'_, Postgres>,
+        approval: &TimesheetApprovalRecord,
+    ) -> ApiResult<Vec<TimesheetEntry>> {
+        let entries = sqlx::query_as!(
+            TimesheetEntry,
+            r#"
+        SELECT ws.id AS work_session_id,
+               j.title AS job_title,
+               ws.start_time,
+               ws.end_time AS "end_time!",
+               EXTRACT(EPOCH FROM (ws.end_time - ws.start_time - ws.accumulated_paused_duration))::BIGINT
+                   AS "net_seconds!"
+        FROM work_sessions ws
+        JOIN jobs j ON j.id = ws.job_id
+        WHERE ws.user_id = $1
+          AND j.company_id = $2
+          AND ws.is_running = FALSE
+          AND ws.end_time IS NOT NULL
+          AND (ws.start_time AT TIME ZONE 'UTC')::DATE BETWEEN $3 AND $4
+        ORDER BY ws.start_time, ws.id
+        FOR UPDATE OF ws
+        "#,
+            approval.user_id,
+            approval.company_id,
+            approval.period_start,
+            approval.period_end,
+        )
+        .fetch_all(&mut **tx)
+        .await?;
+
+        Ok(entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone};
+
+    use super::*;
+
+    fn entry(id: u128, start_hour: u32, hours: i64) -> TimesheetEntry {
+        let start_time = Utc
+            .with_ymd_and_hms(2026, 10, 12, start_hour, 0, 0)
+            .unwrap();
+
+        TimesheetEntry {
+            work_session_id: Uuid::from_u128(id),
+            job_title: "Site visit".to_string(),
+            start_time,
+            end_time: start_time + Duration::hours(hours),
+            net_seconds: hours * 3600,
+        }
+    }
+
+    #[test]
+    fn version_is_stable_for_the_same_entries() {
+        let entries = vec![entry(1, 9, 2), entry(2, 13, 3)];
+
+        assert_eq!(
+            TimesheetApprovalRepo::version(&entries),
+            TimesheetApprovalRepo::version(&entries.clone())
+        );
+        assert!(
+            TimesheetApprovalRepo::check_version(
+                &entries,
+                &TimesheetApprovalRepo::version(&entries)
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn rejects_approval_after_a_session_is_added() {
+        let reviewed = vec![entry(1, 9, 2)];
+        let version = TimesheetApprovalRepo::version(&reviewed);
+        let current = vec![entry(1, 9, 2), entry(2, 13, 3)];
+
+        assert!(matches!(
+            TimesheetApprovalRepo::check_version(&current, &version),
+            Err(ApiErrorResponse::BadRequest(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_approval_after_a_session_is_edited_or_removed() {
+        let reviewed = vec![entry(1, 9, 2), entry(2, 13, 3)];
+        let version = TimesheetApprovalRepo::version(&reviewed);
+
+        assert!(
+            TimesheetApprovalRepo::check_version(&[entry(1, 9, 2), entry(2, 13, 4)], &version)
+                .is_err()
+        );
+        assert!(TimesheetApprovalRepo::check_version(&[entry(1, 9, 2)], &version).is_err());
+        assert!(TimesheetApprovalRepo::check_version(&[], &version).is_err());
+    }
+}
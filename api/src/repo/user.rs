@@ -3,13 +3,66 @@
 //! Provides [`UserRepo`] for creating, querying, and updating user
 //! records in the `users` table.
 
+use chrono::{DateTime, Utc};
 use sqlx::{Pool, Postgres};
 use uuid::Uuid;
 
-use gig_log_common::models::user::User;
+use gig_log_common::models::{locale::Locale, user::User};
 
 use crate::core::error::ApiResult;
 
+/// A user together with their stored password hash.
+///
+/// Read in one query wherever a password is verified, so the user does
+/// not have to be looked up again afterwards.
+#[derive(Debug)]
+pub struct UserCredentials {
+    /// The user's account.
+    pub user: User,
+    /// The Argon2 hash of the user's password.
+    pub password_hash: String,
+}
+
+/// A `users` row with its password hash.
+struct UserCredentialsRecord {
+    /// The user's UUID.
+    id: Uuid,
+    /// The user's first name.
+    first_name: String,
+    /// The user's last name.
+    last_name: String,
+    /// The user's email address.
+    email: String,
+    /// Whether the user has confirmed their email address.
+    email_confirmed: bool,
+    /// Path of the user's avatar image, if one has been uploaded.
+    avatar_url: Option<String>,
+    /// When the account was created.
+    created_at: DateTime<Utc>,
+    /// When the account was last updated.
+    updated_at: DateTime<Utc>,
+    /// The Argon2 hash of the user's password.
+    hashed_password: String,
+}
+
+impl From<UserCredentialsRecord> for UserCredentials {
+    fn from(record: UserCredentialsRecord) -> Self {
+        Self {
+            user: User {
+                id: record.id,
+                first_name: record.first_name,
+                last_name: record.last_name,
+                email: record.email,
+                email_confirmed: record.email_confirmed,
+                avatar_url: record.avatar_url,
+                created_at: record.created_at,
+                updated_at: record.updated_at,
+            },
+            password_hash: record.hashed_password,
+        }
+    }
+}
+
 /// Repository for user account database operations.
 pub struct UserRepo;
 
@@ -43,7 +96,8 @@ impl UserRepo {
             r#"
         INSERT INTO users (first_name, last_name, email, hashed_password)
         VALUES ($1, $2, $3, $4)
-        RETURNING id, first_name, last_name, email, email_confirmed, created_at, updated_at
+        RETURNING id, first_name, last_name, email, email_confirmed,
+                  NULL::TEXT AS avatar_url, created_at, updated_at
         "#,
             first_name,
             last_name,
@@ -74,7 +128,9 @@ impl UserRepo {
         let user = sqlx::query_as!(
             User,
             r#"
-        SELECT id, first_name, last_name, email, email_confirmed, created_at, updated_at
+        SELECT id, first_name, last_name, email, email_confirmed,
+               '/auth/me/avatar?v=' || (EXTRACT(EPOCH FROM avatar_uploaded_at) * 1000)::BIGINT AS avatar_url,
+               created_at, updated_at
         FROM users
         WHERE email = $1
         "#,
@@ -104,7 +160,9 @@ impl UserRepo {
         let user = sqlx::query_as!(
             User,
             r#"
-        SELECT id, first_name, last_name, email, email_confirmed, created_at, updated_at
+        SELECT id, first_name, last_name, email, email_confirmed,
+               '/auth/me/avatar?v=' || (EXTRACT(EPOCH FROM avatar_uploaded_at) * 1000)::BIGINT AS avatar_url,
+               created_at, updated_at
         FROM users
         WHERE id = $1
         "#,
@@ -116,7 +174,42 @@ impl UserRepo {
         Ok(user)
     }
 
-    /// Retrieves the stored password hash for a user.
+    /// Finds a user and their stored password hash by email address.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `email` — The account's email address.
+    ///
+    /// # Returns
+    ///
+    /// The [`UserCredentials`], or `None` if no user has the email.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn find_credentials_by_email(
+        pool: &Pool<Postgres>,
+        email: &str,
+    ) -> ApiResult<Option<UserCredentials>> {
+        let record = sqlx::query_as!(
+            UserCredentialsRecord,
+            r#"
+        SELECT id, first_name, last_name, email, email_confirmed,
+               '/auth/me/avatar?v=' || (EXTRACT(EPOCH FROM avatar_uploaded_at) * 1000)::BIGINT AS avatar_url,
+               created_at, updated_at, hashed_password
+        FROM users
+        WHERE email = $1
+        "#,
+            email,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(record.map(Into::into))
+    }
+
+    /// Finds a user and their stored password hash by ID.
     ///
     /// # Arguments
     ///
@@ -125,15 +218,21 @@ impl UserRepo {
     ///
     /// # Returns
     ///
-    /// The Argon2 password hash as a [`String`].
+    /// The user's [`UserCredentials`].
     ///
     /// # Errors
     ///
     /// Returns an error if no user with the given ID exists.
-    pub async fn get_password_hash(pool: &Pool<Postgres>, user_id: Uuid) -> ApiResult<String> {
-        let hashed_password = sqlx::query_scalar!(
+    pub async fn find_credentials_by_id(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+    ) -> ApiResult<UserCredentials> {
+        let record = sqlx::query_as!(
+            UserCredentialsRecord,
             r#"
-        SELECT hashed_password
+        SELECT id, first_name, last_name, email, email_confirmed,
+               '/auth/me/avatar?v=' || (EXTRACT(EPOCH FROM avatar_uploaded_at) * 1000)::BIGINT AS avatar_url,
+               created_at, updated_at, hashed_password
         FROM users
         WHERE id = $1
         "#,
@@ -142,7 +241,7 @@ impl UserRepo {
         .fetch_one(pool)
         .await?;
 
-        Ok(hashed_password)
+        Ok(record.into())
     }
 
     /// Updates a user's password hash.
@@ -272,4 +371,275 @@ impl UserRepo {
 
         Ok(())
     }
+
+    /// Finds a confirmed user's ID by email, ignoring case.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `email` — The email address to search for.
+    ///
+    /// # Returns
+    ///
+    /// The user's UUID, or `None` if no confirmed user has the address.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn find_confirmed_user_id_by_email(
+        pool: &Pool<Postgres>,
+        email: &str,
+    ) -> ApiResult<Option<Uuid>> {
+        let id = sqlx::query_scalar!(
+            r#"
+        SELECT id
+        FROM users
+        WHERE LOWER(email) = LOWER($1) AND email_confirmed = TRUE
+        "#,
+            email,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(id)
+    }
+
+    /// Finds the language a user's emails are sent in.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The user's preferred [`Locale`], or [`Locale::En`] if the stored
+    /// value is not recognized.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the user does not exist.
+    pub async fn find_locale(pool: &Pool<Postgres>, user_id: Uuid) -> ApiResult<Locale> {
+        let locale = sqlx::query_scalar!(
+            r#"
+        SELECT locale
+        FROM users
+        WHERE id = $1
+        "#,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(Locale::parse(&locale).unwrap_or_default())
+    }
+
+    /// Sets the language a user's emails are sent in.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    /// * `locale` — The preferred locale.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn set_locale(pool: &Pool<Postgres>, user_id: Uuid, locale: Locale) -> ApiResult<()> {
+        sqlx::query!(
+            r#"
+        UPDATE users
+        SET locale = $2, updated_at = NOW()
+        WHERE id = $1
+        "#,
+            user_id,
+            locale.as_str(),
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Finds the timezone a user's times are shown in.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The user's IANA timezone name, or `None` if they have not chosen one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the user does not exist.
+    pub async fn find_timezone(pool: &Pool<Postgres>, user_id: Uuid) -> ApiResult<Option<String>> {
+        let timezone = sqlx::query_scalar!(
+            r#"
+        SELECT timezone
+        FROM users
+        WHERE id = $1
+        "#,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(timezone)
+    }
+
+    /// Sets or clears the timezone a user's times are shown in.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    /// * `timezone` — The IANA timezone name, or `None` to use the
+    ///   browser's timezone.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn set_timezone(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        timezone: Option<&str>,
+    ) -> ApiResult<()> {
+        sqlx::query!(
+            r#"
+        UPDATE users
+        SET timezone = $2, updated_at = NOW()
+        WHERE id = $1
+        "#,
+            user_id,
+            timezone,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Checks whether a timezone name is in the database's IANA timezone
+    /// list.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `timezone` — The timezone name to check, e.g. `"America/Chicago"`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the name is a known timezone.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn is_known_timezone(pool: &Pool<Postgres>, timezone: &str) -> ApiResult<bool> {
+        let is_known = sqlx::query_scalar!(
+            r#"
+        SELECT EXISTS (SELECT 1 FROM pg_timezone_names WHERE name = $1) AS "is_known!"
+        "#,
+            timezone,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(is_known)
+    }
+
+    /// Stores a user's avatar, replacing any existing one.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    /// * `data` — The resized avatar, encoded as a PNG.
+    ///
+    /// # Returns
+    ///
+    /// The updated [`User`] with their new avatar URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no user with the given ID exists.
+    pub async fn set_avatar(pool: &Pool<Postgres>, user_id: Uuid, data: &[u8]) -> ApiResult<User> {
+        let user = sqlx::query_as!(
+            User,
+            r#"
+        UPDATE users
+        SET avatar_data = $2, avatar_uploaded_at = NOW(), updated_at = NOW()
+        WHERE id = $1
+        RETURNING id, first_name, last_name, email, email_confirmed,
+                  '/auth/me/avatar?v=' || (EXTRACT(EPOCH FROM avatar_uploaded_at) * 1000)::BIGINT AS avatar_url,
+                  created_at, updated_at
+        "#,
+            user_id,
+            data,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(user)
+    }
+
+    /// Loads a user's avatar.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The avatar's PNG bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the user has no avatar.
+    pub async fn find_avatar(pool: &Pool<Postgres>, user_id: Uuid) -> ApiResult<Vec<u8>> {
+        let data = sqlx::query_scalar!(
+            r#"
+        SELECT avatar_data AS "avatar_data!"
+        FROM users
+        WHERE id = $1 AND avatar_data IS NOT NULL
+        "#,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(data)
+    }
+
+    /// Removes a user's avatar.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    ///
+    /// # Returns
+    ///
+    /// `true` if an avatar was removed, `false` if the user had none.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn delete_avatar(pool: &Pool<Postgres>, user_id: Uuid) -> ApiResult<bool> {
+        let result = sqlx::query!(
+            r#"
+        UPDATE users
+        SET avatar_data = NULL, avatar_uploaded_at = NULL, updated_at = NOW()
+        WHERE id = $1 AND avatar_data IS NOT NULL
+        "#,
+            user_id,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
 }
@@ -0,0 +1,579 @@
+//! Webhook database operations.
+//!
+//! Provides [`WebhookRepo`] for registering, listing, and deleting webhooks
+//! stored in the `webhooks` table, and for queueing, claiming, and
+//! recording the outcome of their deliveries in `webhook_deliveries`. All
+//! request-facing queries are scoped to the owning user.
+
+use chrono::{DateTime, Duration, Utc};
+use gig_log_common::models::webhook::{
+    Webhook, WebhookDelivery, WebhookDeliveryStatus, WebhookEventType,
+};
+use sqlx::{FromRow, Pool, Postgres};
+use uuid::Uuid;
+
+use crate::core::error::ApiResult;
+
+/// State of a webhook delivery row.
+///
+/// Maps to the PostgreSQL `webhook_delivery_status` enum.
+#[derive(Debug, Clone, sqlx::Type)]
+#[sqlx(type_name = "webhook_delivery_status", rename_all = "snake_case")]
+pub enum WebhookDeliveryStatusRecord {
+    /// Waiting for its next attempt.
+    Pending,
+    /// Accepted by the webhook's URL.
+    Succeeded,
+    /// Abandoned after its final attempt.
+    Failed,
+}
+
+impl From<WebhookDeliveryStatusRecord> for WebhookDeliveryStatus {
+    fn from(status: WebhookDeliveryStatusRecord) -> Self {
+        match status {
+            WebhookDeliveryStatusRecord::Pending => WebhookDeliveryStatus::Pending,
+            WebhookDeliveryStatusRecord::Succeeded => WebhookDeliveryStatus::Succeeded,
+            WebhookDeliveryStatusRecord::Failed => WebhookDeliveryStatus::Failed,
+        }
+    }
+}
+
+/// A row from the `webhooks` table, without its secret.
+#[derive(Debug, FromRow)]
+struct WebhookRecord {
+    /// Unique identifier for the webhook.
+    id: Uuid,
+    /// URL events are POSTed to.
+    url: String,
+    /// Serialized event type names.
+    event_types: Vec<String>,
+    /// Timestamp when the webhook was created.
+    created_at: DateTime<Utc>,
+}
+
+impl From<WebhookRecord> for Webhook {
+    fn from(record: WebhookRecord) -> Self {
+        Self {
+            id: record.id,
+            url: record.url,
+            event_types: record
+                .event_types
+                .iter()
+                .filter_map(|event_type| WebhookEventType::parse(event_type))
+                .collect(),
+            created_at: record.created_at,
+        }
+    }
+}
+
+/// A row from the `webhook_deliveries` table, without its payload.
+#[derive(Debug, FromRow)]
+struct WebhookDeliveryRecord {
+    /// Unique identifier for the delivery.
+    id: Uuid,
+    /// The delivered event's ID.
+    event_id: Uuid,
+    /// Serialized event type name.
+    event_type: String,
+    /// The delivery's current state.
+    status: WebhookDeliveryStatusRecord,
+    /// Number of attempts made so far.
+    attempts: i32,
+    /// HTTP status of the most recent response.
+    response_status: Option<i32>,
+    /// Why the most recent attempt failed.
+    error: Option<String>,
+    /// When the next attempt is due, while the delivery is pending.
+    next_attempt_at: Option<DateTime<Utc>>,
+    /// Timestamp when the event was queued.
+    created_at: DateTime<Utc>,
+    /// When the delivery succeeded.
+    delivered_at: Option<DateTime<Utc>>,
+}
+
+impl WebhookDeliveryRecord {
+    /// Converts the row into a [`WebhookDelivery`].
+    ///
+    /// # Returns
+    ///
+    /// The [`WebhookDelivery`], or `None` if its event type is unknown.
+    fn into_delivery(self) -> Option<WebhookDelivery> {
+        Some(WebhookDelivery {
+            id: self.id,
+            event_id: self.event_id,
+            event_type: WebhookEventType::parse(&self.event_type)?,
+            status: self.status.into(),
+            attempts: self.attempts,
+            response_status: self.response_status,
+            error: self.error,
+            next_attempt_at: self.next_attempt_at,
+            created_at: self.created_at,
+            delivered_at: self.delivered_at,
+        })
+    }
+}
+
+/// A delivery claimed by the background worker, with everything needed to
+/// send it.
+#[derive(Debug, Clone, FromRow)]
+pub struct ClaimedDeliveryRecord {
+    /// Unique identifier for the delivery.
+    pub id: Uuid,
+    /// Serialized event type name.
+    pub event_type: String,
+    /// The JSON body to POST.
+    pub payload: String,
+    /// Number of attempts made before this one.
+    pub attempts: i32,
+    /// URL to POST the payload to.
+    pub url: String,
+    /// Secret used to sign the payload.
+    pub secret: String,
+}
+
+/// A webhook's URL and signing secret, used to send a test delivery.
+#[derive(Debug, Clone, FromRow)]
+pub struct WebhookTargetRecord {
+    /// URL to POST the payload to.
+    pub url: String,
+    /// Secret used to sign the payload.
+    pub secret: String,
+}
+
+/// Repository for webhook database operations.
+pub struct WebhookRepo;
+
+impl WebhookRepo {
+    /// Registers a new webhook.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user the webhook belongs to.
+    /// * `url` — URL events are POSTed to.
+    /// * `event_types` — Event types delivered to the webhook.
+    /// * `secret` — Secret used to sign deliveries.
+    ///
+    /// # Returns
+    ///
+    /// The created [`Webhook`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert query fails.
+    pub async fn insert_webhook(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        url: &str,
+        event_types: &[WebhookEventType],
+        secret: &str,
+    ) -> ApiResult<Webhook> {
+        let event_types: Vec<String> = event_types
+            .iter()
+            .map(|event_type| event_type.as_str().to_string())
+            .collect();
+
+        let record = sqlx::query_as!(
+            WebhookRecord,
+            r#"
+        INSERT INTO webhooks (user_id, url, event_types, secret)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id, url, event_types, created_at
+        "#,
+            user_id,
+            url,
+            &event_types,
+            secret,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record.into())
+    }
+
+    /// Lists a user's webhooks, newest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user whose webhooks to list.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`Webhook`] values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_webhooks(pool: &Pool<Postgres>, user_id: Uuid) -> ApiResult<Vec<Webhook>> {
+        let records = sqlx::query_as!(
+            WebhookRecord,
+            r#"
+        SELECT id, url, event_types, created_at
+        FROM webhooks
+        WHERE user_id = $1
+        ORDER BY created_at DESC
+        "#,
+            user_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records.into_iter().map(Into::into).collect())
+    }
+
+    /// Finds one of a user's webhooks.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user the webhook belongs to.
+    /// * `webhook_id` — The webhook's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The matching [`Webhook`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the webhook does not exist or belongs to another
+    /// user.
+    pub async fn find_webhook(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        webhook_id: Uuid,
+    ) -> ApiResult<Webhook> {
+        let record = sqlx::query_as!(
+            WebhookRecord,
+            r#"
+        SELECT id, url, event_types, created_at
+        FROM webhooks
+        WHERE id = $1 AND user_id = $2
+        "#,
+            webhook_id,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record.into())
+    }
+
+    /// Finds the URL and signing secret of one of a user's webhooks.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user the webhook belongs to.
+    /// * `webhook_id` — The webhook's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The matching [`WebhookTargetRecord`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the webhook does not exist or belongs to another
+    /// user.
+    pub async fn find_target(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        webhook_id: Uuid,
+    ) -> ApiResult<WebhookTargetRecord> {
+        let record = sqlx::query_as!(
+            WebhookTargetRecord,
+            r#"
+        SELECT url, secret
+        FROM webhooks
+        WHERE id = $1 AND user_id = $2
+        "#,
+            webhook_id,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// Deletes one of a user's webhooks along with its delivery log.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user the webhook belongs to.
+    /// * `webhook_id` — The webhook's UUID.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a webhook was deleted, `false` if none matched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete query fails.
+    pub async fn delete_webhook(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        webhook_id: Uuid,
+    ) -> ApiResult<bool> {
+        let result = sqlx::query!(
+            r#"
+        DELETE FROM webhooks
+        WHERE id = $1 AND user_id = $2
+        "#,
+            webhook_id,
+            user_id,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Lists a webhook's most recent deliveries, newest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `webhook_id` — The webhook's UUID.
+    /// * `limit` — The maximum number of deliveries to return.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`WebhookDelivery`] values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_deliveries(
+        pool: &Pool<Postgres>,
+        webhook_id: Uuid,
+        limit: i64,
+    ) -> ApiResult<Vec<WebhookDelivery>> {
+        let records = sqlx::query_as!(
+            WebhookDeliveryRecord,
+            r#"
+        SELECT id, event_id, event_type,
+               status AS "status: WebhookDeliveryStatusRecord",
+               attempts, response_status, error,
+               CASE WHEN status = 'pending' THEN next_attempt_at END AS next_attempt_at,
+               created_at, delivered_at
+        FROM webhook_deliveries
+        WHERE webhook_id = $1
+        ORDER BY created_at DESC
+        LIMIT $2
+        "#,
+            webhook_id,
+            limit,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records
+            .into_iter()
+            .filter_map(WebhookDeliveryRecord::into_delivery)
+            .collect())
+    }
+
+    /// Queues an event for every webhook of a user subscribed to its type.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user the event belongs to.
+    /// * `event_id` — The event's UUID.
+    /// * `event_type` — The event's type.
+    /// * `payload` — The JSON body to deliver.
+    ///
+    /// # Returns
+    ///
+    /// The number of deliveries queued.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert query fails.
+    pub async fn enqueue(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        event_id: Uuid,
+        event_type: WebhookEventType,
+        payload: &str,
+    ) -> ApiResult<u64> {
+        let result = sqlx::query!(
+            r#"
+        INSERT INTO webhook_deliveries (webhook_id, event_id, event_type, payload)
+        SELECT id, $2, $3::TEXT, $4
+        FROM webhooks
+        WHERE user_id = $1 AND $3 = ANY(event_types)
+        "#,
+            user_id,
+            event_id,
+            event_type.as_str(),
+            payload,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Claims pending deliveries whose next attempt is due.
+    ///
+    /// Each claimed delivery's next attempt is pushed back by `lease`, so a
+    /// delivery claimed by a worker that stops before recording the outcome
+    /// is retried once the lease runs out. Deliveries claimed by a
+    /// concurrent worker are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `limit` — The maximum number of deliveries to claim.
+    /// * `lease` — How long the claim lasts.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`ClaimedDeliveryRecord`] values, oldest due first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn claim_due(
+        pool: &Pool<Postgres>,
+        limit: i64,
+        lease: Duration,
+    ) -> ApiResult<Vec<ClaimedDeliveryRecord>> {
+        let deliveries = sqlx::query_as!(
+            ClaimedDeliveryRecord,
+            r#"
+        UPDATE webhook_deliveries d
+        SET next_attempt_at = now() + $2::BIGINT * INTERVAL '1 millisecond'
+        FROM webhooks w
+        WHERE w.id = d.webhook_id
+          AND d.id IN (
+              SELECT id
+              FROM webhook_deliveries
+              WHERE status = 'pending' AND next_attempt_at <= now()
+              ORDER BY next_attempt_at
+              LIMIT $1
+              FOR UPDATE SKIP LOCKED
+          )
+        RETURNING d.id, d.event_type, d.payload, d.attempts, w.url, w.secret
+        "#,
+            limit,
+            lease.num_milliseconds(),
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(deliveries)
+    }
+
+    /// Marks a delivery succeeded.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `delivery_id` — The delivery's UUID.
+    /// * `response_status` — The HTTP status the webhook's URL responded with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn record_success(
+        pool: &Pool<Postgres>,
+        delivery_id: Uuid,
+        response_status: i32,
+    ) -> ApiResult<()> {
+        sqlx::query!(
+            r#"
+        UPDATE webhook_deliveries
+        SET status = 'succeeded',
+            attempts = attempts + 1,
+            response_status = $2,
+            error = NULL,
+            delivered_at = now()
+        WHERE id = $1
+        "#,
+            delivery_id,
+            response_status,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Records a failed attempt, scheduling a retry or abandoning the
+    /// delivery.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `delivery_id` — The delivery's UUID.
+    /// * `response_status` — The HTTP status received, if any.
+    /// * `error` — Why the attempt failed.
+    /// * `retry_at` — When to try again, or `None` to mark the delivery
+    ///   failed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn record_failure(
+        pool: &Pool<Postgres>,
+        delivery_id: Uuid,
+        response_status: Option<i32>,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> ApiResult<()> {
+        sqlx::query!(
+            r#"
+        UPDATE webhook_deliveries
+        SET status = CASE WHEN $4::TIMESTAMPTZ IS NULL
+                          THEN 'failed'::webhook_delivery_status
+                          ELSE 'pending'::webhook_delivery_status END,
+            attempts = attempts + 1,
+            response_status = $2,
+            error = $3,
+            next_attempt_at = COALESCE($4, next_attempt_at)
+        WHERE id = $1
+        "#,
+            delivery_id,
+            response_status,
+            error,
+            retry_at,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Deletes finished deliveries older than the retention period.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `retention` — How long deliveries are kept after being queued.
+    ///
+    /// # Returns
+    ///
+    /// The number of deliveries deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete query fails.
+    pub async fn delete_expired(pool: &Pool<Postgres>, retention: Duration) -> ApiResult<u64> {
+        let result = sqlx::query!(
+            r#"
+        DELETE FROM webhook_deliveries
+        WHERE status <> 'pending'
+          AND created_at < now() - $1::BIGINT * INTERVAL '1 millisecond'
+        "#,
+            retention.num_milliseconds(),
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,1883 @@
+//! Work session database operations.
+//!
+//! Provides [`WorkSessionRepo`] for starting, pausing, resuming, and
+//! completing time-tracking records in the `work_sessions` table, for
+//! logging, correcting, and removing sessions by hand, and for querying
+//! and exporting them. All queries are scoped to the owning user.
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use futures_util::stream::BoxStream;
+use sqlx::{FromRow, Pool, Postgres, Transaction};
+use uuid::Uuid;
+
+use gig_log_common::models::{
+    error::{ValidationError, ValidationErrorCode},
+    generic::{PageQuery, Paginated},
+    work_session::{
+        CompanyLocation, CompanyLocationSummary, CompletedWorkSession, GeoLocation,
+        ListWorkSessionsQuery, WorkSession, WorkSessionDayTotal, WorkSessionListResponse,
+    },
+};
+
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::core::export::CsvRecord;
+use crate::repo::onboarding_state::{OnboardingEvent, OnboardingStateRepo};
+
+/// Name of the unique index that allows one active session per user.
+const ACTIVE_SESSION_INDEX: &str = "uq_work_sessions_user_active";
+
+/// A completed work session that has not yet been attached to an invoice.
+#[derive(Debug, Clone, FromRow)]
+pub struct UnbilledSessionRecord {
+    /// Unique identifier for the work session.
+    pub id: Uuid,
+    /// The job the session was tracked against.
+    pub job_id: Uuid,
+    /// Title of the job.
+    pub job_title: String,
+    /// Hourly rate in dollars of the job when the session was completed.
+    /// `None` for payout-based jobs.
+    pub hourly_rate: Option<f64>,
+    /// Worked duration in seconds, excluding paused time.
+    pub net_seconds: i64,
+}
+
+/// A work session joined with the Toggl IDs used by the compatibility API.
+#[derive(Debug, Clone, FromRow)]
+pub struct TogglEntryRecord {
+    /// Numeric Toggl ID of the session.
+    pub toggl_id: i64,
+    /// Numeric Toggl ID of the session's job.
+    pub project_toggl_id: i64,
+    /// Whether the job is paid hourly.
+    pub billable: bool,
+    /// When the session started.
+    pub start_time: DateTime<Utc>,
+    /// When the session ended. `None` while running.
+    pub end_time: Option<DateTime<Utc>>,
+    /// Whether the session is running or paused.
+    pub is_running: bool,
+    /// Worked duration in seconds so far, excluding paused time.
+    pub net_seconds: i64,
+    /// When the session was last updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A completed work session whose time has not been reported.
+#[derive(Debug, Clone, FromRow)]
+pub struct UnreportedSessionRecord {
+    /// Unique identifier for the work session.
+    pub id: Uuid,
+    /// The company whose job the session was tracked against.
+    pub company_id: Uuid,
+    /// Name of the company.
+    pub company_name: String,
+    /// Monday of the week the session started in.
+    pub week_start: NaiveDate,
+    /// Worked duration in seconds, excluding paused time.
+    pub net_seconds: i64,
+}
+
+/// A work session whose time was just reported.
+#[derive(Debug, Clone, FromRow)]
+pub struct ReportedSessionRecord {
+    /// Unique identifier for the work session.
+    pub id: Uuid,
+    /// Reported duration in seconds.
+    pub net_seconds: i64,
+}
+
+/// Hours worked on one job, listed on a company statement.
+#[derive(Debug, Clone, FromRow)]
+pub struct StatementHoursRecord {
+    /// Title of the job.
+    pub job_title: String,
+    /// Worked duration in seconds, excluding paused time.
+    pub net_seconds: i64,
+}
+
+/// A completed work session row with its worked time, as read from the
+/// database.
+#[derive(Debug, Clone, FromRow)]
+pub struct CompletedWorkSessionRecord {
+    /// Unique identifier for the work session.
+    pub id: Uuid,
+    /// The user who owns this work session.
+    pub user_id: Uuid,
+    /// The job this work session is associated with.
+    pub job_id: Uuid,
+    /// When the work session started.
+    pub start_time: DateTime<Utc>,
+    /// When the work session ended.
+    pub end_time: Option<DateTime<Utc>>,
+    /// Whether the session timer is currently running.
+    pub is_running: bool,
+    /// Total accumulated time spent paused, in seconds.
+    pub accumulated_paused_duration: i64,
+    /// When the session was last paused.
+    pub paused_at: Option<DateTime<Utc>>,
+    /// Manually reported time in seconds, if provided.
+    pub time_reported: Option<i64>,
+    /// Hourly rate in dollars of the job when the session was completed.
+    pub hourly_rate: Option<f64>,
+    /// Whether the session was completed automatically.
+    pub auto_completed: bool,
+    /// Latitude where the session was started, if recorded.
+    pub start_latitude: Option<f64>,
+    /// Longitude where the session was started, if recorded.
+    pub start_longitude: Option<f64>,
+    /// Latitude where the session was completed, if recorded.
+    pub end_latitude: Option<f64>,
+    /// Longitude where the session was completed, if recorded.
+    pub end_longitude: Option<f64>,
+    /// Free-form notes about the work done.
+    pub notes: Option<String>,
+    /// Labels for the session.
+    pub tags: Vec<String>,
+    /// Timestamp when the work session was created.
+    pub created_at: DateTime<Utc>,
+    /// Timestamp when the work session was last updated.
+    pub updated_at: DateTime<Utc>,
+    /// Worked duration in seconds, excluding paused time.
+    pub net_seconds: i64,
+}
+
+impl From<CompletedWorkSessionRecord> for CompletedWorkSession {
+    fn from(record: CompletedWorkSessionRecord) -> Self {
+        Self {
+            session: WorkSession {
+                id: record.id,
+                user_id: record.user_id,
+                job_id: record.job_id,
+                start_time: record.start_time,
+                end_time: record.end_time,
+                is_running: record.is_running,
+                accumulated_paused_duration: record.accumulated_paused_duration,
+                paused_at: record.paused_at,
+                time_reported: record.time_reported,
+                hourly_rate: record.hourly_rate,
+                auto_completed: record.auto_completed,
+                start_latitude: record.start_latitude,
+                start_longitude: record.start_longitude,
+                end_latitude: record.end_latitude,
+                end_longitude: record.end_longitude,
+                notes: record.notes,
+                tags: record.tags,
+                created_at: record.created_at,
+                updated_at: record.updated_at,
+            },
+            net_seconds: record.net_seconds,
+        }
+    }
+}
+
+/// Notes and tags stored on a new work session.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WorkSessionDetails<'a> {
+    /// Free-form notes about the work, if any.
+    pub notes: Option<&'a str>,
+    /// Normalized tags for the session.
+    pub tags: &'a [String],
+}
+
+/// A completed work session row in a CSV export, with its job and company.
+#[derive(Debug, Clone, FromRow)]
+pub struct WorkSessionExportRecord {
+    /// Unique identifier for the work session.
+    pub id: Uuid,
+    /// Date the session started.
+    pub session_date: NaiveDate,
+    /// Name of the company whose job the session was tracked against.
+    pub company_name: String,
+    /// Title of the job.
+    pub job_title: String,
+    /// When the session started.
+    pub start_time: DateTime<Utc>,
+    /// When the session ended.
+    pub end_time: DateTime<Utc>,
+    /// Worked duration in seconds, excluding paused time.
+    pub net_seconds: i64,
+    /// Hourly rate in dollars of the job when the session was completed.
+    /// `None` for payout-based jobs.
+    pub hourly_rate: Option<f64>,
+    /// Whether the session was completed automatically after running past
+    /// the maximum session duration.
+    pub auto_completed: bool,
+}
+
+impl CsvRecord for WorkSessionExportRecord {
+    const COLUMNS: &'static [&'static str] = &[
+        "id",
+        "date",
+        "company",
+        "job",
+        "start_time",
+        "end_time",
+        "hours",
+        "hourly_rate",
+        "amount",
+        "auto_completed",
+    ];
+
+    fn fields(&self) -> Vec<String> {
+        let hours = self.net_seconds.max(0) as f64 / 3600.0;
+
+        vec![
+            self.id.to_string(),
+            self.session_date.to_string(),
+            self.company_name.clone(),
+            self.job_title.clone(),
+            self.start_time.to_rfc3339(),
+            self.end_time.to_rfc3339(),
+            format!("{hours:.2}"),
+            self.hourly_rate
+                .map(|rate| format!("{rate:.2}"))
+                .unwrap_or_default(),
+            self.hourly_rate
+                .map(|rate| format!("{:.2}", hours * rate))
+                .unwrap_or_default(),
+            self.auto_completed.to_string(),
+        ]
+    }
+}
+
+/// Repository for work session database operations.
+pub struct WorkSessionRepo;
+
+impl WorkSessionRepo {
+    /// Lists completed, unbilled work sessions for a company.
+    ///
+    /// Sessions are ordered by job title and then start time so callers can
+    /// group them by job without re-sorting.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the sessions.
+    /// * `company_id` — The UUID of the company whose jobs are included.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`UnbilledSessionRecord`] values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_unbilled_for_company(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        company_id: Uuid,
+    ) -> ApiResult<Vec<UnbilledSessionRecord>> {
+        let sessions = sqlx::query_as!(
+            UnbilledSessionRecord,
+            r#"
+        SELECT ws.id,
+               ws.job_id,
+               j.title AS job_title,
+               ws.hourly_rate::FLOAT8 AS hourly_rate,
+               EXTRACT(EPOCH FROM (ws.end_time - ws.start_time - ws.accumulated_paused_duration))::BIGINT
+                   AS "net_seconds!"
+        FROM work_sessions ws
+        JOIN jobs j ON j.id = ws.job_id
+        WHERE ws.user_id = $1
+          AND j.company_id = $2
+          AND ws.is_running = FALSE
+          AND ws.end_time IS NOT NULL
+          AND ws.invoice_id IS NULL
+        ORDER BY j.title, ws.job_id, ws.hourly_rate, ws.start_time
+        "#,
+            user_id,
+            company_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(sessions)
+    }
+
+    /// Lists completed work sessions whose time has not been reported.
+    ///
+    /// Sessions are ordered by company name, then week, so callers can
+    /// group them without re-sorting.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the sessions.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`UnreportedSessionRecord`] values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_unreported(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+    ) -> ApiResult<Vec<UnreportedSessionRecord>> {
+        let sessions = sqlx::query_as!(
+            UnreportedSessionRecord,
+            r#"
+        SELECT ws.id,
+               c.id AS company_id,
+               c.name AS company_name,
+               DATE_TRUNC('week', ws.start_time)::DATE AS "week_start!",
+               EXTRACT(EPOCH FROM (ws.end_time - ws.start_time - ws.accumulated_paused_duration))::BIGINT
+                   AS "net_seconds!"
+        FROM work_sessions ws
+        JOIN jobs j ON j.id = ws.job_id
+        JOIN companies c ON c.id = j.company_id
+        WHERE ws.user_id = $1
+          AND ws.is_running = FALSE
+          AND ws.end_time IS NOT NULL
+          AND ws.time_reported IS NULL
+        ORDER BY c.name, c.id, 4, ws.start_time
+        "#,
+            user_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(sessions)
+    }
+
+    /// Reports the worked time of completed, unreported work sessions.
+    ///
+    /// Each session's reported time is set to its worked duration,
+    /// excluding paused time. Sessions that are running, already reported,
+    /// or owned by another user are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the sessions.
+    /// * `session_ids` — The sessions to report.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`ReportedSessionRecord`] values for the sessions that
+    /// were reported.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn report_sessions(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        session_ids: &[Uuid],
+    ) -> ApiResult<Vec<ReportedSessionRecord>> {
+        let sessions = sqlx::query_as!(
+            ReportedSessionRecord,
+            r#"
+        UPDATE work_sessions
+        SET time_reported = end_time - start_time - accumulated_paused_duration,
+            updated_at = now()
+        WHERE user_id = $1
+          AND id = ANY($2)
+          AND is_running = FALSE
+          AND end_time IS NOT NULL
+          AND time_reported IS NULL
+        RETURNING id, EXTRACT(EPOCH FROM time_reported)::BIGINT AS "net_seconds!"
+        "#,
+            user_id,
+            session_ids,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(sessions)
+    }
+
+    /// Totals a company's completed work sessions per job within a range.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the sessions.
+    /// * `company_id` — The UUID of the company whose jobs are included.
+    /// * `from` — First day of the range (inclusive).
+    /// * `to` — Last day of the range (inclusive).
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`StatementHoursRecord`] values ordered by job title.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_statement_hours(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        company_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> ApiResult<Vec<StatementHoursRecord>> {
+        let records = sqlx::query_as!(
+            StatementHoursRecord,
+            r#"
+        SELECT j.title AS job_title,
+               SUM(EXTRACT(EPOCH FROM (ws.end_time - ws.start_time - ws.accumulated_paused_duration)))::BIGINT
+                   AS "net_seconds!"
+        FROM work_sessions ws
+        JOIN jobs j ON j.id = ws.job_id
+        WHERE ws.user_id = $1
+          AND j.company_id = $2
+          AND ws.is_running = FALSE
+          AND ws.end_time IS NOT NULL
+          AND ws.start_time::DATE BETWEEN $3 AND $4
+        GROUP BY j.id, j.title
+        ORDER BY j.title
+        "#,
+            user_id,
+            company_id,
+            from,
+            to,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    /// Starts a new running work session for a job.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the session.
+    /// * `job_id` — The UUID of the job to track time against.
+    /// * `location` — Where the session was started, if it should be
+    ///   recorded.
+    /// * `details` — The session's notes and tags.
+    /// * `details` — The session's notes and tags.
+    ///
+    /// # Returns
+    ///
+    /// The newly created [`WorkSession`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`] if the user already has an
+    /// active session, or an error if the insert query fails.
+    pub async fn insert_session(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        job_id: Uuid,
+        location: Option<GeoLocation>,
+        details: WorkSessionDetails<'_>,
+    ) -> ApiResult<WorkSession> {
+        let session = sqlx::query_as!(
+            WorkSession,
+            r#"
+        INSERT INTO work_sessions (user_id, job_id, start_latitude, start_longitude, notes, tags)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING id, user_id, job_id, start_time, end_time, is_running,
+                  EXTRACT(EPOCH FROM accumulated_paused_duration)::BIGINT
+                      AS "accumulated_paused_duration!",
+                  paused_at,
+                  EXTRACT(EPOCH FROM time_reported)::BIGINT AS time_reported,
+                  hourly_rate::FLOAT8 AS hourly_rate, auto_completed,
+                  start_latitude, start_longitude, end_latitude, end_longitude, notes, tags,
+                  created_at, updated_at
+        "#,
+            user_id,
+            job_id,
+            location.map(|location| location.latitude),
+            location.map(|location| location.longitude),
+            details.notes,
+            details.tags,
+        )
+        .fetch_one(pool)
+        .await
+        .map_err(Self::active_session_conflict)?;
+        OnboardingStateRepo::record(pool, user_id, OnboardingEvent::SessionTracked).await?;
+
+        Ok(session)
+    }
+
+    /// Finds a work session by ID, scoped to the owning user.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the session.
+    /// * `session_id` — The work session's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The [`WorkSession`] matching the given ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no session with the given ID exists for the user.
+    pub async fn find_session_by_id(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        session_id: Uuid,
+    ) -> ApiResult<WorkSession> {
+        let session = sqlx::query_as!(
+            WorkSession,
+            r#"
+        SELECT id, user_id, job_id, start_time, end_time, is_running,
+               EXTRACT(EPOCH FROM accumulated_paused_duration)::BIGINT
+                   AS "accumulated_paused_duration!",
+               paused_at,
+               EXTRACT(EPOCH FROM time_reported)::BIGINT AS time_reported,
+               hourly_rate::FLOAT8 AS hourly_rate, auto_completed,
+               start_latitude, start_longitude, end_latitude, end_longitude, notes, tags,
+               created_at, updated_at
+        FROM work_sessions
+        WHERE id = $1 AND user_id = $2
+        "#,
+            session_id,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(session)
+    }
+
+    /// Finds the user's most recently started running or paused session.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the session.
+    ///
+    /// # Returns
+    ///
+    /// The active [`WorkSession`], or `None` if no session is running.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn find_active_session(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+    ) -> ApiResult<Option<WorkSession>> {
+        let session = sqlx::query_as!(
+            WorkSession,
+            r#"
+        SELECT id, user_id, job_id, start_time, end_time, is_running,
+               EXTRACT(EPOCH FROM accumulated_paused_duration)::BIGINT
+                   AS "accumulated_paused_duration!",
+               paused_at,
+               EXTRACT(EPOCH FROM time_reported)::BIGINT AS time_reported,
+               hourly_rate::FLOAT8 AS hourly_rate, auto_completed,
+               start_latitude, start_longitude, end_latitude, end_longitude, notes, tags,
+               created_at, updated_at
+        FROM work_sessions
+        WHERE user_id = $1 AND is_running = TRUE
+        ORDER BY start_time DESC
+        LIMIT 1
+        "#,
+            user_id,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(session)
+    }
+
+    /// Pauses a running work session.
+    ///
+    /// The state check and the change are a single update that only applies
+    /// if the session is running, unpaused, and last changed at least
+    /// `min_interval` ago, so concurrent state changes cannot both succeed.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the session.
+    /// * `session_id` — The work session's UUID.
+    /// * `min_interval` — Minimum time since the session's last change.
+    ///
+    /// # Returns
+    ///
+    /// The paused [`WorkSession`], or `None` if the session does not exist,
+    /// is not active, or changed too recently.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn pause_session(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        session_id: Uuid,
+        min_interval: Duration,
+    ) -> ApiResult<Option<WorkSession>> {
+        let session = sqlx::query_as!(
+            WorkSession,
+            r#"
+        UPDATE work_sessions
+        SET paused_at = now(), updated_at = now()
+        WHERE id = $1 AND user_id = $2 AND updated_at <= now() - $3::BIGINT * INTERVAL '1 millisecond'
+          AND is_running = TRUE AND paused_at IS NULL
+        RETURNING id, user_id, job_id, start_time, end_time, is_running,
+                  EXTRACT(EPOCH FROM accumulated_paused_duration)::BIGINT
+                      AS "accumulated_paused_duration!",
+                  paused_at,
+                  EXTRACT(EPOCH FROM time_reported)::BIGINT AS time_reported,
+                  hourly_rate::FLOAT8 AS hourly_rate, auto_completed,
+                  start_latitude, start_longitude, end_latitude, end_longitude, notes, tags,
+                  created_at, updated_at
+        "#,
+            session_id,
+            user_id,
+            min_interval.num_milliseconds(),
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(session)
+    }
+
+    /// Resumes a paused work session, adding the pause to its paused total.
+    ///
+    /// The state check and the change are a single update that only applies
+    /// if the session is paused and last changed at least `min_interval`
+    /// ago.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the session.
+    /// * `session_id` — The work session's UUID.
+    /// * `min_interval` — Minimum time since the session's last change.
+    ///
+    /// # Returns
+    ///
+    /// The resumed [`WorkSession`], or `None` if the session does not exist,
+    /// is not paused, or changed too recently.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn resume_session(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        session_id: Uuid,
+        min_interval: Duration,
+    ) -> ApiResult<Option<WorkSession>> {
+        let session = sqlx::query_as!(
+            WorkSession,
+            r#"
+        UPDATE work_sessions
+        SET accumulated_paused_duration = accumulated_paused_duration + (now() - paused_at),
+            paused_at = NULL,
+            updated_at = now()
+        WHERE id = $1 AND user_id = $2 AND updated_at <= now() - $3::BIGINT * INTERVAL '1 millisecond'
+          AND is_running = TRUE AND paused_at IS NOT NULL
+        RETURNING id, user_id, job_id, start_time, end_time, is_running,
+                  EXTRACT(EPOCH FROM accumulated_paused_duration)::BIGINT
+                      AS "accumulated_paused_duration!",
+                  paused_at,
+                  EXTRACT(EPOCH FROM time_reported)::BIGINT AS time_reported,
+                  hourly_rate::FLOAT8 AS hourly_rate, auto_completed,
+                  start_latitude, start_longitude, end_latitude, end_longitude, notes, tags,
+                  created_at, updated_at
+        "#,
+            session_id,
+            user_id,
+            min_interval.num_milliseconds(),
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(session)
+    }
+
+    /// Completes a running or paused work session.
+    ///
+    /// An open pause is closed and added to the paused total. The state
+    /// check and the change are a single update that only applies if the
+    /// session is running and last changed at least `min_interval` ago.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the session.
+    /// * `session_id` — The work session's UUID.
+    /// * `min_interval` — Minimum time since the session's last change.
+    /// * `location` — Where the session was completed, if it should be
+    ///   recorded.
+    /// * `notes` — Notes replacing the session's current ones,
+    ///   `Some(None)` to clear them, or `None` to leave them unchanged.
+    /// * `tags` — Normalized tags replacing the session's current ones, if
+    ///   set.
+    ///
+    /// # Returns
+    ///
+    /// The completed [`WorkSession`], or `None` if the session does not
+    /// exist, is already completed, or changed too recently.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn complete_session(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        session_id: Uuid,
+        min_interval: Duration,
+        location: Option<GeoLocation>,
+        notes: Option<Option<&str>>,
+        tags: Option<&[String]>,
+    ) -> ApiResult<Option<WorkSession>> {
+        let session = sqlx::query_as!(
+            WorkSession,
+            r#"
+        UPDATE work_sessions
+        SET accumulated_paused_duration =
+                accumulated_paused_duration + COALESCE(now() - paused_at, interval '0'),
+            paused_at = NULL,
+            end_time = now(),
+            is_running = FALSE,
+            hourly_rate = (SELECT hourly_rate FROM jobs WHERE jobs.id = work_sessions.job_id),
+            end_latitude = $4,
+            end_longitude = $5,
+            notes = CASE WHEN $6 THEN $7 ELSE notes END,
+            tags = COALESCE($8, tags),
+            updated_at = now()
+        WHERE id = $1 AND user_id = $2 AND updated_at <= now() - $3::BIGINT * INTERVAL '1 millisecond'
+          AND is_running = TRUE
+        RETURNING id, user_id, job_id, start_time, end_time, is_running,
+                  EXTRACT(EPOCH FROM accumulated_paused_duration)::BIGINT
+                      AS "accumulated_paused_duration!",
+                  paused_at,
+                  EXTRACT(EPOCH FROM time_reported)::BIGINT AS time_reported,
+                  hourly_rate::FLOAT8 AS hourly_rate, auto_completed,
+                  start_latitude, start_longitude, end_latitude, end_longitude, notes, tags,
+                  created_at, updated_at
+        "#,
+            session_id,
+            user_id,
+            min_interval.num_milliseconds(),
+            location.map(|location| location.latitude),
+            location.map(|location| location.longitude),
+            notes.is_some(),
+            notes.flatten(),
+            tags,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(session)
+    }
+
+    /// Completes running sessions whose worked time exceeds a limit.
+    ///
+    /// Each session ends at the moment its worked time reached the limit,
+    /// is flagged as auto-completed, and has its job's rate snapshotted.
+    /// Paused sessions are only completed if they passed the limit before
+    /// they were paused.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `max_duration` — Longest worked time a session may run for.
+    ///
+    /// # Returns
+    ///
+    /// The completed [`WorkSession`]s.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn complete_overdue_sessions(
+        pool: &Pool<Postgres>,
+        max_duration: Duration,
+    ) -> ApiResult<Vec<WorkSession>> {
+        let sessions = sqlx::query_as!(
+            WorkSession,
+            r#"
+        UPDATE work_sessions
+        SET end_time = start_time + accumulated_paused_duration
+                + $1::BIGINT * INTERVAL '1 millisecond',
+            paused_at = NULL,
+            is_running = FALSE,
+            auto_completed = TRUE,
+            hourly_rate = (SELECT hourly_rate FROM jobs WHERE jobs.id = work_sessions.job_id),
+            updated_at = now()
+        WHERE is_running = TRUE
+          AND COALESCE(paused_at, now()) - start_time - accumulated_paused_duration
+              > $1::BIGINT * INTERVAL '1 millisecond'
+        RETURNING id, user_id, job_id, start_time, end_time, is_running,
+                  EXTRACT(EPOCH FROM accumulated_paused_duration)::BIGINT
+                      AS "accumulated_paused_duration!",
+                  paused_at,
+                  EXTRACT(EPOCH FROM time_reported)::BIGINT AS time_reported,
+                  hourly_rate::FLOAT8 AS hourly_rate, auto_completed,
+                  start_latitude, start_longitude, end_latitude, end_longitude, notes, tags,
+                  created_at, updated_at
+        "#,
+            max_duration.num_milliseconds(),
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(sessions)
+    }
+
+    /// Inserts a work session with explicit start and optional end times.
+    ///
+    /// Sessions without an end time are created running.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the session.
+    /// * `job_id` — The UUID of the job to track time against.
+    /// * `start_time` — When the session started.
+    /// * `end_time` — When the session ended, for completed sessions.
+    ///
+    /// # Returns
+    ///
+    /// The UUID of the newly created session.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`] if the session would be
+    /// running while the user already has an active session, or an error if
+    /// the insert query fails.
+    pub async fn insert_session_with_times(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        job_id: Uuid,
+        start_time: DateTime<Utc>,
+        end_time: Option<DateTime<Utc>>,
+    ) -> ApiResult<Uuid> {
+        let id = sqlx::query_scalar!(
+            r#"
+        INSERT INTO work_sessions (user_id, job_id, start_time, end_time, is_running, hourly_rate)
+        VALUES ($1, $2, $3, $4, $4::TIMESTAMPTZ IS NULL,
+                (SELECT hourly_rate FROM jobs WHERE id = $2 AND $4::TIMESTAMPTZ IS NOT NULL))
+        RETURNING id
+        "#,
+            user_id,
+            job_id,
+            start_time,
+            end_time,
+        )
+        .fetch_one(pool)
+        .await
+        .map_err(Self::active_session_conflict)?;
+        OnboardingStateRepo::record(pool, user_id, OnboardingEvent::SessionTracked).await?;
+
+        Ok(id)
+    }
+
+    /// Logs a completed work session with explicit times.
+    ///
+    /// The job's current hourly rate is snapshotted onto the session, as
+    /// when a timed session is completed.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the session.
+    /// * `job_id` — The UUID of the job to track time against.
+    /// * `start_time` — When the session started.
+    /// * `end_time` — When the session ended.
+    /// * `paused_seconds` — Time spent paused, in seconds.
+    /// * `details` — The session's notes and tags.
+    ///
+    /// # Returns
+    ///
+    /// The newly created [`WorkSession`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`] if the session overlaps
+    /// another of the user's sessions, or an error if a query fails.
+    pub async fn log_session(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        job_id: Uuid,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+        paused_seconds: i64,
+        details: WorkSessionDetails<'_>,
+    ) -> ApiResult<WorkSession> {
+        let mut tx = pool.begin().await?;
+
+        Self::ensure_no_overlap(&mut tx, user_id, None, start_time, end_time).await?;
+
+        let session = sqlx::query_as!(
+            WorkSession,
+            r#"
+        INSERT INTO work_sessions (user_id, job_id, start_time, end_time, is_running,
+                                   accumulated_paused_duration, hourly_rate, notes, tags)
+        VALUES ($1, $2, $3, $4, FALSE, $5::BIGINT * INTERVAL '1 second',
+                (SELECT hourly_rate FROM jobs WHERE id = $2), $6, $7)
+        RETURNING id, user_id, job_id, start_time, end_time, is_running,
+                  EXTRACT(EPOCH FROM accumulated_paused_duration)::BIGINT
+                      AS "accumulated_paused_duration!",
+                  paused_at,
+                  EXTRACT(EPOCH FROM time_reported)::BIGINT AS time_reported,
+                  hourly_rate::FLOAT8 AS hourly_rate, auto_completed,
+                  start_latitude, start_longitude, end_latitude, end_longitude, notes, tags,
+                  created_at, updated_at
+        "#,
+            user_id,
+            job_id,
+            start_time,
+            end_time,
+            paused_seconds,
+            details.notes,
+            details.tags,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        OnboardingStateRepo::record(&mut *tx, user_id, OnboardingEvent::SessionTracked).await?;
+
+        tx.commit().await?;
+
+        Ok(session)
+    }
+
+    /// Corrects the times of a completed work session.
+    ///
+    /// The session keeps its snapshotted hourly rate.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the session.
+    /// * `session_id` — The work session's UUID.
+    /// * `start_time` — When the session started.
+    /// * `end_time` — When the session ended.
+    /// * `paused_seconds` — Time spent paused, in seconds.
+    ///
+    /// # Returns
+    ///
+    /// The updated [`WorkSession`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if no session with the given
+    /// ID exists for the user, [`ApiErrorResponse::BadRequest`] if the
+    /// session is still in progress, invoiced, or locked by an approval,
+    /// [`ApiErrorResponse::Validation`] if the new times overlap another of
+    /// the user's sessions, or an error if a query fails.
+    pub async fn update_session_times(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        session_id: Uuid,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+        paused_seconds: i64,
+    ) -> ApiResult<WorkSession> {
+        let mut tx = pool.begin().await?;
+
+        let current = sqlx::query!(
+            r#"
+        SELECT end_time, invoice_id, approval_id
+        FROM work_sessions
+        WHERE id = $1 AND user_id = $2
+        FOR UPDATE
+        "#,
+            session_id,
+            user_id,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if current.end_time.is_none() {
+            return Err(ApiErrorResponse::BadRequest(
+                "Only completed sessions can be edited".to_string(),
+            ));
+        }
+
+        if current.invoice_id.is_some() || current.approval_id.is_some() {
+            return Err(ApiErrorResponse::BadRequest(
+                "Invoiced or approved sessions cannot be edited".to_string(),
+            ));
+        }
+
+        Self::ensure_no_overlap(&mut tx, user_id, Some(session_id), start_time, end_time).await?;
+
+        let session = sqlx::query_as!(
+            WorkSession,
+            r#"
+        UPDATE work_sessions
+        SET start_time = $3,
+            end_time = $4,
+            accumulated_paused_duration = $5::BIGINT * INTERVAL '1 second',
+            updated_at = now()
+        WHERE id = $1 AND user_id = $2
+        RETURNING id, user_id, job_id, start_time, end_time, is_running,
+                  EXTRACT(EPOCH FROM accumulated_paused_duration)::BIGINT
+                      AS "accumulated_paused_duration!",
+                  paused_at,
+                  EXTRACT(EPOCH FROM time_reported)::BIGINT AS time_reported,
+                  hourly_rate::FLOAT8 AS hourly_rate, auto_completed,
+                  start_latitude, start_longitude, end_latitude, end_longitude, notes, tags,
+                  created_at, updated_at
+        "#,
+            session_id,
+            user_id,
+            start_time,
+            end_time,
+            paused_seconds,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(session)
+    }
+
+    /// Updates a work session's notes and tags.
+    ///
+    /// Unlike its times, a session's notes and tags can be edited while it
+    /// is in progress, invoiced, or locked by an approval.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the session.
+    /// * `session_id` — The work session's UUID.
+    /// * `notes` — New notes, `Some(None)` to clear them, or `None` to leave
+    ///   them unchanged.
+    /// * `tags` — Normalized tags replacing the current ones, or `None` to
+    ///   leave them unchanged.
+    ///
+    /// # Returns
+    ///
+    /// The updated [`WorkSession`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if no session with the given
+    /// ID exists for the user, or an error if the update query fails.
+    pub async fn update_session_details(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        session_id: Uuid,
+        notes: Option<Option<&str>>,
+        tags: Option<&[String]>,
+    ) -> ApiResult<WorkSession> {
+        let session = sqlx::query_as!(
+            WorkSession,
+            r#"
+        UPDATE work_sessions
+        SET notes = CASE WHEN $3 THEN $4 ELSE notes END,
+            tags = COALESCE($5, tags),
+            updated_at = now()
+        WHERE id = $1 AND user_id = $2
+        RETURNING id, user_id, job_id, start_time, end_time, is_running,
+                  EXTRACT(EPOCH FROM accumulated_paused_duration)::BIGINT
+                      AS "accumulated_paused_duration!",
+                  paused_at,
+                  EXTRACT(EPOCH FROM time_reported)::BIGINT AS time_reported,
+                  hourly_rate::FLOAT8 AS hourly_rate, auto_completed,
+                  start_latitude, start_longitude, end_latitude, end_longitude, notes, tags,
+                  created_at, updated_at
+        "#,
+            session_id,
+            user_id,
+            notes.is_some(),
+            notes.flatten(),
+            tags,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(session)
+    }
+
+    /// Lists one page of a user's completed work sessions across jobs, with
+    /// worked time per day.
+    ///
+    /// Sessions are dated by the UTC day they started, and both date bounds
+    /// are inclusive. The day totals cover every matching session, not just
+    /// the page.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the sessions.
+    /// * `query` — The [`ListWorkSessionsQuery`] filters.
+    /// * `page` — The [`PageQuery`] selecting the page.
+    ///
+    /// # Returns
+    ///
+    /// A [`WorkSessionListResponse`] with the page of sessions, oldest
+    /// first, and the per-day and overall worked time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a query fails.
+    pub async fn list_completed_page(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        query: &ListWorkSessionsQuery,
+        page: &PageQuery,
+    ) -> ApiResult<WorkSessionListResponse> {
+        let records = sqlx::query_as!(
+            CompletedWorkSessionRecord,
+            r#"
+        SELECT ws.id, ws.user_id, ws.job_id, ws.start_time, ws.end_time, ws.is_running,
+               EXTRACT(EPOCH FROM ws.accumulated_paused_duration)::BIGINT
+                   AS "accumulated_paused_duration!",
+               ws.paused_at,
+               EXTRACT(EPOCH FROM ws.time_reported)::BIGINT AS time_reported,
+               ws.hourly_rate::FLOAT8 AS hourly_rate, ws.auto_completed,
+               ws.start_latitude, ws.start_longitude, ws.end_latitude, ws.end_longitude,
+               ws.notes, ws.tags, ws.created_at, ws.updated_at,
+               EXTRACT(EPOCH FROM (ws.end_time - ws.start_time - ws.accumulated_paused_duration))::BIGINT
+                   AS "net_seconds!"
+        FROM work_sessions ws
+        JOIN jobs j ON j.id = ws.job_id
+        WHERE ws.user_id = $1
+          AND ws.end_time IS NOT NULL
+          AND ($2::DATE IS NULL OR ws.start_time::DATE >= $2)
+          AND ($3::DATE IS NULL OR ws.start_time::DATE <= $3)
+          AND ($4::UUID IS NULL OR ws.job_id = $4)
+          AND ($5::UUID IS NULL OR j.company_id = $5)
+        ORDER BY ws.start_time, ws.id
+        LIMIT $6 OFFSET $7
+        "#,
+            user_id,
+            query.from,
+            query.to,
+            query.job_id,
+            query.company_id,
+            i64::from(page.per_page()) + 1,
+            page.offset(),
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let days = sqlx::query_as!(
+            WorkSessionDayTotal,
+            r#"
+        SELECT ws.start_time::DATE AS "date!",
+               COUNT(*) AS "session_count!",
+               SUM(EXTRACT(EPOCH FROM (ws.end_time - ws.start_time - ws.accumulated_paused_duration)))::BIGINT
+                   AS "net_seconds!"
+        FROM work_sessions ws
+        JOIN jobs j ON j.id = ws.job_id
+        WHERE ws.user_id = $1
+          AND ws.end_time IS NOT NULL
+          AND ($2::DATE IS NULL OR ws.start_time::DATE >= $2)
+          AND ($3::DATE IS NULL OR ws.start_time::DATE <= $3)
+          AND ($4::UUID IS NULL OR ws.job_id = $4)
+          AND ($5::UUID IS NULL OR j.company_id = $5)
+        GROUP BY ws.start_time::DATE
+        ORDER BY ws.start_time::DATE
+        "#,
+            user_id,
+            query.from,
+            query.to,
+            query.job_id,
+            query.company_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let total = days.iter().map(|day| day.session_count).sum();
+        let net_seconds = days.iter().map(|day| day.net_seconds).sum();
+
+        Ok(WorkSessionListResponse {
+            page: Paginated::from_lookahead(
+                records.into_iter().map(Into::into).collect(),
+                page,
+                total,
+            ),
+            days,
+            net_seconds,
+        })
+    }
+
+    /// Lists a job's work sessions, optionally only those with a tag.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the sessions.
+    /// * `job_id` — The UUID of the job the sessions were tracked against.
+    /// * `tag` — A normalized tag the sessions must have, if any.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`WorkSession`] values, most recently started first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_work_sessions_for_job(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        job_id: Uuid,
+        tag: Option<&str>,
+    ) -> ApiResult<Vec<WorkSession>> {
+        let sessions = sqlx::query_as!(
+            WorkSession,
+            r#"
+        SELECT id, user_id, job_id, start_time, end_time, is_running,
+               EXTRACT(EPOCH FROM accumulated_paused_duration)::BIGINT
+                   AS "accumulated_paused_duration!",
+               paused_at,
+               EXTRACT(EPOCH FROM time_reported)::BIGINT AS time_reported,
+               hourly_rate::FLOAT8 AS hourly_rate, auto_completed,
+               start_latitude, start_longitude, end_latitude, end_longitude, notes, tags,
+               created_at, updated_at
+        FROM work_sessions
+        WHERE user_id = $1 AND job_id = $2
+          AND ($3::TEXT IS NULL OR tags @> ARRAY[$3::TEXT])
+        ORDER BY start_time DESC
+        "#,
+            user_id,
+            job_id,
+            tag,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(sessions)
+    }
+
+    /// Fails if a time range overlaps any of a user's other sessions.
+    ///
+    /// Running sessions are treated as ending now. The user's row is locked
+    /// for the rest of the transaction so two concurrent writes cannot both
+    /// pass the check.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx` — The open transaction.
+    /// * `user_id` — The UUID of the user who owns the sessions.
+    /// * `exclude_id` — A session to leave out, when editing it.
+    /// * `start_time` — Start of the range.
+    /// * `end_time` — End of the range.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Validation`] naming the overlapping
+    /// session's times, or an error if a query fails.
+    async fn ensure_no_overlap(
+        tx: &mut Transaction<'_, Postgres>,
+        user_id: Uuid,
+        exclude_id: Option<Uuid>,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> ApiResult<()> {
+        sqlx::query!("SELECT id FROM users WHERE id = $1 FOR UPDATE", user_id)
+            .fetch_one(&mut **tx)
+            .await?;
+
+        let overlap = sqlx::query!(
+            r#"
+        SELECT start_time, end_time
+        FROM work_sessions
+        WHERE user_id = $1
+          AND ($2::UUID IS NULL OR id <> $2)
+          AND start_time < $4
+          AND COALESCE(end_time, now()) > $3
+        ORDER BY start_time
+        LIMIT 1
+        "#,
+            user_id,
+            exclude_id,
+            start_time,
+            end_time,
+        )
+        .fetch_optional(&mut **tx)
+        .await?;
+
+        match overlap {
+            Some(overlap) => {
+                let ends = overlap
+                    .end_time
+                    .map(|end_time| end_time.to_rfc3339())
+                    .unwrap_or_else(|| "now".to_string());
+
+                Err(ApiErrorResponse::Validation(vec![ValidationError::new(
+                    None,
+                    ValidationErrorCode::Invalid,
+                    format!(
+                        "Session overlaps another session from {} to {ends}",
+                        overlap.start_time.to_rfc3339()
+                    ),
+                )]))
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Deletes one of a user's completed work sessions.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the session.
+    /// * `session_id` — The work session's UUID.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if no session with the given
+    /// ID exists for the user, [`ApiErrorResponse::BadRequest`] if the
+    /// session is still active, invoiced, or locked by an approval, or an
+    /// error if a query fails.
+    pub async fn delete_completed_session(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        session_id: Uuid,
+    ) -> ApiResult<()> {
+        Self::delete_session(pool, user_id, session_id, false).await
+    }
+
+    /// Discards one of a user's running or paused work sessions.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the session.
+    /// * `session_id` — The work session's UUID.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if no session with the given
+    /// ID exists for the user, [`ApiErrorResponse::BadRequest`] if the
+    /// session is already completed, or an error if a query fails.
+    pub async fn discard_active_session(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        session_id: Uuid,
+    ) -> ApiResult<()> {
+        Self::delete_session(pool, user_id, session_id, true).await
+    }
+
+    /// Deletes a work session after checking it is in the expected state.
+    ///
+    /// The session's row is locked while it is checked so a concurrent
+    /// completion or invoice cannot slip in before the delete.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the session.
+    /// * `session_id` — The work session's UUID.
+    /// * `discard` — Whether an active session is being discarded rather
+    ///   than a completed one deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if no session with the given
+    /// ID exists for the user, the errors described in
+    /// [`check_deletable`](Self::check_deletable), or an error if a query
+    /// fails.
+    async fn delete_session(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        session_id: Uuid,
+        discard: bool,
+    ) -> ApiResult<()> {
+        let mut tx = pool.begin().await?;
+
+        let current = sqlx::query!(
+            r#"
+        SELECT is_running, (invoice_id IS NOT NULL OR approval_id IS NOT NULL) AS "locked!"
+        FROM work_sessions
+        WHERE id = $1 AND user_id = $2
+        FOR UPDATE
+        "#,
+            session_id,
+            user_id,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        Self::check_deletable(current.is_running, current.locked, discard)?;
+
+        sqlx::query!(
+            "DELETE FROM work_sessions WHERE id = $1 AND user_id = $2",
+            session_id,
+            user_id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// Checks whether a session in a given state may be removed.
+    ///
+    /// Active sessions can only be discarded and completed sessions can
+    /// only be deleted, so a client cannot remove a running timer or a
+    /// finished session by mistake.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_running` — Whether the session is running or paused.
+    /// * `locked` — Whether the session is invoiced or locked by an
+    ///   approval.
+    /// * `discard` — Whether an active session is being discarded rather
+    ///   than a completed one deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the session is in the
+    /// wrong state for the removal or is locked.
+    fn check_deletable(is_running: bool, locked: bool, discard: bool) -> ApiResult<()> {
+        match (is_running, discard) {
+            (false, true) => Err(ApiErrorResponse::BadRequest(
+                "Only active sessions can be discarded".to_string(),
+            )),
+            (true, false) => Err(ApiErrorResponse::BadRequest(
+                "Active sessions must be discarded instead of deleted".to_string(),
+            )),
+            _ if locked => Err(ApiErrorResponse::BadRequest(
+                "Invoiced or approved sessions cannot be deleted".to_string(),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Deletes a work session that is not invoiced or locked by an approval.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the session.
+    /// * `session_id` — The work session's UUID.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a session was deleted, `false` if none matched or the
+    /// session is locked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete query fails.
+    pub async fn delete_unlocked_session(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        session_id: Uuid,
+    ) -> ApiResult<bool> {
+        let result = sqlx::query!(
+            r#"
+        DELETE FROM work_sessions
+        WHERE id = $1 AND user_id = $2 AND invoice_id IS NULL AND approval_id IS NULL
+        "#,
+            session_id,
+            user_id,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Finds a work session's UUID from its Toggl ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the session.
+    /// * `toggl_id` — The session's numeric Toggl ID.
+    ///
+    /// # Returns
+    ///
+    /// The session's UUID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no session with the Toggl ID exists for the user.
+    pub async fn find_session_id_by_toggl_id(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        toggl_id: i64,
+    ) -> ApiResult<Uuid> {
+        let id = sqlx::query_scalar!(
+            r#"
+        SELECT id
+        FROM work_sessions
+        WHERE toggl_id = $1 AND user_id = $2
+        "#,
+            toggl_id,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(id)
+    }
+
+    /// Lists a user's work sessions in Toggl form, newest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the sessions.
+    /// * `from` — Only include sessions starting at or after this time.
+    /// * `to` — Only include sessions starting before this time.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`TogglEntryRecord`] values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_toggl_entries(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> ApiResult<Vec<TogglEntryRecord>> {
+        let records = sqlx::query_as!(
+            TogglEntryRecord,
+            r#"
+        SELECT ws.toggl_id, j.toggl_id AS project_toggl_id,
+               j.payment_type = 'hourly' AS "billable!",
+               ws.start_time, ws.end_time, ws.is_running,
+               EXTRACT(EPOCH FROM (
+                   COALESCE(ws.end_time, ws.paused_at, now())
+                   - ws.start_time - ws.accumulated_paused_duration
+               ))::BIGINT AS "net_seconds!",
+               ws.updated_at
+        FROM work_sessions ws
+        JOIN jobs j ON j.id = ws.job_id
+        WHERE ws.user_id = $1 AND ws.start_time >= $2 AND ws.start_time < $3
+        ORDER BY ws.start_time DESC
+        "#,
+            user_id,
+            from,
+            to,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    /// Finds one of a user's work sessions in Toggl form.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the session.
+    /// * `session_id` — The work session's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The session's [`TogglEntryRecord`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no session with the given ID exists for the user.
+    pub async fn find_toggl_entry(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        session_id: Uuid,
+    ) -> ApiResult<TogglEntryRecord> {
+        let record = sqlx::query_as!(
+            TogglEntryRecord,
+            r#"
+        SELECT ws.toggl_id, j.toggl_id AS project_toggl_id,
+               j.payment_type = 'hourly' AS "billable!",
+               ws.start_time, ws.end_time, ws.is_running,
+               EXTRACT(EPOCH FROM (
+                   COALESCE(ws.end_time, ws.paused_at, now())
+                   - ws.start_time - ws.accumulated_paused_duration
+               ))::BIGINT AS "net_seconds!",
+               ws.updated_at
+        FROM work_sessions ws
+        JOIN jobs j ON j.id = ws.job_id
+        WHERE ws.id = $1 AND ws.user_id = $2
+        "#,
+            session_id,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// Finds a user's most recently started running session in Toggl form.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the session.
+    ///
+    /// # Returns
+    ///
+    /// The running session's [`TogglEntryRecord`], or `None` if no session
+    /// is running.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn find_current_toggl_entry(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+    ) -> ApiResult<Option<TogglEntryRecord>> {
+        let record = sqlx::query_as!(
+            TogglEntryRecord,
+            r#"
+        SELECT ws.toggl_id, j.toggl_id AS project_toggl_id,
+               j.payment_type = 'hourly' AS "billable!",
+               ws.start_time, ws.end_time, ws.is_running,
+               EXTRACT(EPOCH FROM (
+                   COALESCE(ws.end_time, ws.paused_at, now())
+                   - ws.start_time - ws.accumulated_paused_duration
+               ))::BIGINT AS "net_seconds!",
+               ws.updated_at
+        FROM work_sessions ws
+        JOIN jobs j ON j.id = ws.job_id
+        WHERE ws.user_id = $1 AND ws.is_running = TRUE
+        ORDER BY ws.start_time DESC
+        LIMIT 1
+        "#,
+            user_id,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// Returns whether a user records locations on their work sessions.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    ///
+    /// # Returns
+    ///
+    /// `true` if locations sent when starting and completing sessions are
+    /// recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the user does not exist.
+    pub async fn find_location_tracking(pool: &Pool<Postgres>, user_id: Uuid) -> ApiResult<bool> {
+        let enabled = sqlx::query_scalar!(
+            r#"
+        SELECT location_tracking_enabled
+        FROM users
+        WHERE id = $1
+        "#,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(enabled)
+    }
+
+    /// Turns location recording on a user's work sessions on or off.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user's UUID.
+    /// * `enabled` — Whether locations should be recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update query fails.
+    pub async fn set_location_tracking(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        enabled: bool,
+    ) -> ApiResult<()> {
+        sqlx::query!(
+            r#"
+        UPDATE users
+        SET location_tracking_enabled = $2
+        WHERE id = $1
+        "#,
+            user_id,
+            enabled,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Summarizes where work for a company was done.
+    ///
+    /// Each session is placed at its start location, or its end location if
+    /// only that was recorded. Locations are rounded to three decimal
+    /// places, roughly 100 meters, so visits to the same site are grouped.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the sessions.
+    /// * `company_id` — The company's UUID.
+    ///
+    /// # Returns
+    ///
+    /// A [`CompanyLocationSummary`] with places ordered by visit count, then
+    /// by most recent visit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a database query fails.
+    pub async fn company_location_summary(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        company_id: Uuid,
+    ) -> ApiResult<CompanyLocationSummary> {
+        let counts = sqlx::query!(
+            r#"
+        SELECT COUNT(*) AS "session_count!",
+               COUNT(COALESCE(ws.start_latitude, ws.end_latitude)) AS "located_session_count!"
+        FROM work_sessions ws
+        JOIN jobs j ON j.id = ws.job_id
+        WHERE ws.user_id = $1 AND j.company_id = $2
+        "#,
+            user_id,
+            company_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        let locations = sqlx::query_as!(
+            CompanyLocation,
+            r#"
+        SELECT ROUND(COALESCE(ws.start_latitude, ws.end_latitude)::NUMERIC, 3)::FLOAT8
+                   AS "latitude!",
+               ROUND(COALESCE(ws.start_longitude, ws.end_longitude)::NUMERIC, 3)::FLOAT8
+                   AS "longitude!",
+               COUNT(*) AS "session_count!",
+               MIN(ws.start_time) AS "first_visited_at!",
+               MAX(ws.start_time) AS "last_visited_at!"
+        FROM work_sessions ws
+        JOIN jobs j ON j.id = ws.job_id
+        WHERE ws.user_id = $1 AND j.company_id = $2
+          AND COALESCE(ws.start_latitude, ws.end_latitude) IS NOT NULL
+        GROUP BY 1, 2
+        ORDER BY 3 DESC, 5 DESC
+        "#,
+            user_id,
+            company_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(CompanyLocationSummary {
+            company_id,
+            session_count: counts.session_count,
+            located_session_count: counts.located_session_count,
+            locations,
+        })
+    }
+
+    /// Streams a user's completed work sessions for a CSV export.
+    ///
+    /// Sessions are dated by the UTC day they started, and both date bounds
+    /// are inclusive.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the sessions.
+    /// * `from` — The earliest start date to include, if bounded.
+    /// * `to` — The latest start date to include, if bounded.
+    ///
+    /// # Returns
+    ///
+    /// A stream of [`WorkSessionExportRecord`] values, oldest first.
+    pub fn stream_export(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    ) -> BoxStream<'_, Result<WorkSessionExportRecord, sqlx::Error>> {
+        sqlx::query_as!(
+            WorkSessionExportRecord,
+            r#"
+        SELECT ws.id,
+               ws.start_time::DATE AS "session_date!",
+               c.name AS company_name,
+               j.title AS job_title,
+               ws.start_time,
+               ws.end_time AS "end_time!",
+               EXTRACT(EPOCH FROM (ws.end_time - ws.start_time - ws.accumulated_paused_duration))::BIGINT
+                   AS "net_seconds!",
+               ws.hourly_rate::FLOAT8 AS hourly_rate,
+               ws.auto_completed
+        FROM work_sessions ws
+        JOIN jobs j ON j.id = ws.job_id
+        JOIN companies c ON c.id = j.company_id
+        WHERE ws.user_id = $1
+          AND ws.end_time IS NOT NULL
+          AND ($2::DATE IS NULL OR ws.start_time::DATE >= $2)
+          AND ($3::DATE IS NULL OR ws.start_time::DATE <= $3)
+        ORDER BY ws.start_time, ws.id
+        "#,
+            user_id,
+            from,
+            to,
+        )
+        .fetch(pool)
+    }
+
+    /// Maps an insert error, turning a violation of the one-active-session
+    /// index into a validation error.
+    ///
+    /// The index closes the race where two concurrent start requests both
+    /// see no active session.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` — The error returned by the insert query.
+    ///
+    /// # Returns
+    ///
+    /// An [`ApiErrorResponse::Validation`] if the user already has an active
+    /// session, or the converted database error otherwise.
+    fn active_session_conflict(error: sqlx::Error) -> ApiErrorResponse {
+        let is_conflict = error.as_database_error().is_some_and(|database_error| {
+            database_error.is_unique_violation()
+                && database_error.constraint() == Some(ACTIVE_SESSION_INDEX)
+        });
+
+        if !is_conflict {
+            return error.into();
+        }
+
+        ApiErrorResponse::Validation(vec![ValidationError {
+            field: None,
+            code: ValidationErrorCode::Invalid,
+            message: "Another work session is already active".to_string(),
+        }])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_removes_sessions_in_the_expected_state() {
+        assert!(WorkSessionRepo::check_deletable(false, false, false).is_ok());
+        assert!(WorkSessionRepo::check_deletable(true, false, true).is_ok());
+        assert!(matches!(
+            WorkSessionRepo::check_deletable(true, false, false),
+            Err(ApiErrorResponse::BadRequest(_))
+        ));
+        assert!(matches!(
+            WorkSessionRepo::check_deletable(false, false, true),
+            Err(ApiErrorResponse::BadRequest(_))
+        ));
+        assert!(matches!(
+            WorkSessionRepo::check_deletable(false, true, false),
+            Err(ApiErrorResponse::BadRequest(_))
+        ));
+    }
+}
@@ -0,0 +1,284 @@
+//! Work session attachment database operations.
+//!
+//! Provides [`WorkSessionAttachmentRepo`] for storing, listing, loading, and
+//! deleting the photos and voice memos in the `work_session_attachments`
+//! table. All queries are scoped to the owning user.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Pool, Postgres};
+use uuid::Uuid;
+
+use gig_log_common::models::work_session::{WorkSessionAttachment, WorkSessionAttachmentKind};
+
+use crate::core::error::ApiResult;
+
+/// The kind of media attached to a work session.
+///
+/// Maps to the PostgreSQL `work_session_attachment_kind` enum.
+#[derive(Debug, Clone, sqlx::Type)]
+#[sqlx(type_name = "work_session_attachment_kind", rename_all = "snake_case")]
+pub enum WorkSessionAttachmentKindRecord {
+    /// A photo.
+    Photo,
+    /// A recorded audio memo.
+    VoiceMemo,
+}
+
+impl From<WorkSessionAttachmentKind> for WorkSessionAttachmentKindRecord {
+    fn from(kind: WorkSessionAttachmentKind) -> Self {
+        match kind {
+            WorkSessionAttachmentKind::Photo => WorkSessionAttachmentKindRecord::Photo,
+            WorkSessionAttachmentKind::VoiceMemo => WorkSessionAttachmentKindRecord::VoiceMemo,
+        }
+    }
+}
+
+impl From<WorkSessionAttachmentKindRecord> for WorkSessionAttachmentKind {
+    fn from(kind: WorkSessionAttachmentKindRecord) -> Self {
+        match kind {
+            WorkSessionAttachmentKindRecord::Photo => WorkSessionAttachmentKind::Photo,
+            WorkSessionAttachmentKindRecord::VoiceMemo => WorkSessionAttachmentKind::VoiceMemo,
+        }
+    }
+}
+
+/// A row from the `work_session_attachments` table, excluding the file
+/// bytes.
+#[derive(Debug, FromRow)]
+pub struct WorkSessionAttachmentRecord {
+    /// Unique identifier for the attachment.
+    pub id: Uuid,
+    /// The work session the attachment belongs to.
+    pub work_session_id: Uuid,
+    /// Whether the attachment is a photo or a voice memo.
+    pub kind: WorkSessionAttachmentKindRecord,
+    /// Original file name of the upload.
+    pub file_name: String,
+    /// MIME type of the upload.
+    pub content_type: String,
+    /// Size of the upload in bytes.
+    pub size_bytes: i64,
+    /// Transcript of a voice memo.
+    pub transcript: Option<String>,
+    /// BCP 47 language tag of the transcript.
+    pub transcript_language: Option<String>,
+    /// When the voice memo was transcribed.
+    pub transcribed_at: Option<DateTime<Utc>>,
+    /// When the attachment was uploaded.
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<WorkSessionAttachmentRecord> for WorkSessionAttachment {
+    fn from(record: WorkSessionAttachmentRecord) -> Self {
+        WorkSessionAttachment {
+            id: record.id,
+            work_session_id: record.work_session_id,
+            kind: record.kind.into(),
+            file_name: record.file_name,
+            content_type: record.content_type,
+            size_bytes: record.size_bytes,
+            transcript: record.transcript,
+            transcript_language: record.transcript_language,
+            transcribed_at: record.transcribed_at,
+            created_at: record.created_at,
+        }
+    }
+}
+
+/// A work session attachment's contents.
+#[derive(Debug, FromRow)]
+pub struct WorkSessionAttachmentDataRecord {
+    /// Original file name of the upload.
+    pub file_name: String,
+    /// MIME type of the upload.
+    pub content_type: String,
+    /// Raw file bytes.
+    pub data: Vec<u8>,
+}
+
+/// A new attachment to store.
+#[derive(Debug)]
+pub struct NewWorkSessionAttachment<'a> {
+    /// Whether the attachment is a photo or a voice memo.
+    pub kind: WorkSessionAttachmentKind,
+    /// Original file name of the upload.
+    pub file_name: &'a str,
+    /// MIME type of the upload.
+    pub content_type: &'a str,
+    /// Raw file bytes.
+    pub data: &'a [u8],
+}
+
+/// Repository for work session attachment database operations.
+pub struct WorkSessionAttachmentRepo;
+
+impl WorkSessionAttachmentRepo {
+    /// Attaches a file to one of the user's work sessions.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the session.
+    /// * `session_id` — The work session's UUID.
+    /// * `attachment` — The [`NewWorkSessionAttachment`] to store.
+    ///
+    /// # Returns
+    ///
+    /// The stored [`WorkSessionAttachment`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no work session with the given ID exists for the
+    /// user or the insert query fails.
+    pub async fn insert_attachment(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        session_id: Uuid,
+        attachment: NewWorkSessionAttachment<'_>,
+    ) -> ApiResult<WorkSessionAttachment> {
+        let record = sqlx::query_as!(
+            WorkSessionAttachmentRecord,
+            r#"
+        INSERT INTO work_session_attachments
+            (user_id, work_session_id, kind, file_name, content_type, data)
+        SELECT user_id, id, $3, $4, $5, $6
+        FROM work_sessions
+        WHERE id = $1 AND user_id = $2
+        RETURNING id, work_session_id,
+                  kind AS "kind: WorkSessionAttachmentKindRecord",
+                  file_name, content_type,
+                  octet_length(data)::BIGINT AS "size_bytes!",
+                  transcript, transcript_language, transcribed_at, created_at
+        "#,
+            session_id,
+            user_id,
+            WorkSessionAttachmentKindRecord::from(attachment.kind)
+                as WorkSessionAttachmentKindRecord,
+            attachment.file_name,
+            attachment.content_type,
+            attachment.data,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record.into())
+    }
+
+    /// Lists the attachments on one of the user's work sessions.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the session.
+    /// * `session_id` — The work session's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The session's [`WorkSessionAttachment`] values, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_for_session(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        session_id: Uuid,
+    ) -> ApiResult<Vec<WorkSessionAttachment>> {
+        let records = sqlx::query_as!(
+            WorkSessionAttachmentRecord,
+            r#"
+        SELECT id, work_session_id,
+               kind AS "kind: WorkSessionAttachmentKindRecord",
+               file_name, content_type,
+               octet_length(data)::BIGINT AS "size_bytes!",
+               transcript, transcript_language, transcribed_at, created_at
+        FROM work_session_attachments
+        WHERE work_session_id = $1 AND user_id = $2
+        ORDER BY created_at, id
+        "#,
+            session_id,
+            user_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records.into_iter().map(Into::into).collect())
+    }
+
+    /// Loads an attachment's contents, scoped to the owning user.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the session.
+    /// * `session_id` — The work session's UUID.
+    /// * `attachment_id` — The attachment's UUID.
+    ///
+    /// # Returns
+    ///
+    /// The [`WorkSessionAttachmentDataRecord`] with the file bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the attachment does not exist on the user's
+    /// session or the query fails.
+    pub async fn find_data(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        session_id: Uuid,
+        attachment_id: Uuid,
+    ) -> ApiResult<WorkSessionAttachmentDataRecord> {
+        let record = sqlx::query_as!(
+            WorkSessionAttachmentDataRecord,
+            r#"
+        SELECT file_name, content_type, data
+        FROM work_session_attachments
+        WHERE id = $1 AND work_session_id = $2 AND user_id = $3
+        "#,
+            attachment_id,
+            session_id,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// Deletes an attachment, scoped to the owning user.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the session.
+    /// * `session_id` — The work session's UUID.
+    /// * `attachment_id` — The attachment's UUID.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the attachment was deleted, `false` if it was not found.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete query fails.
+    pub async fn delete_attachment(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        session_id: Uuid,
+        attachment_id: Uuid,
+    ) -> ApiResult<bool> {
+        let result = sqlx::query!(
+            r#"
+        DELETE FROM work_session_attachments
+        WHERE id = $1 AND work_session_id = $2 AND user_id = $3
+        "#,
+            attachment_id,
+            session_id,
+            user_id,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
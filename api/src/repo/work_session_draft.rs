@@ -0,0 +1,206 @@
+//! Work session draft database operations.
+//!
+//! Provides [`WorkSessionDraftRepo`] for storing work sessions logged by
+//! email and for confirming them into completed work sessions.
+
+use chrono::{DateTime, Duration, Utc};
+use gig_log_common::models::work_session_draft::WorkSessionDraft;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::repo::onboarding_state::{OnboardingEvent, OnboardingStateRepo};
+
+/// Repository for work session draft database operations.
+pub struct WorkSessionDraftRepo;
+
+impl WorkSessionDraftRepo {
+    /// Inserts a work session draft.
+    ///
+    /// Drafts repeating a user's earlier `message_id` are ignored so webhook
+    /// redeliveries do not create duplicates.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who sent the email.
+    /// * `job_id` — The matched job, if any.
+    /// * `job_name` — Job name as written in the email.
+    /// * `duration_minutes` — Logged duration in minutes.
+    /// * `message_id` — The email's message identifier, if known.
+    ///
+    /// # Returns
+    ///
+    /// The created [`WorkSessionDraft`], or `None` if the message was
+    /// already processed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert query fails.
+    pub async fn insert_draft(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        job_id: Option<Uuid>,
+        job_name: &str,
+        duration_minutes: i32,
+        message_id: Option<&str>,
+    ) -> ApiResult<Option<WorkSessionDraft>> {
+        let draft = sqlx::query_as!(
+            WorkSessionDraft,
+            r#"
+        INSERT INTO work_session_drafts (user_id, job_id, job_name, duration_minutes, message_id)
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (user_id, message_id) WHERE message_id IS NOT NULL DO NOTHING
+        RETURNING id, job_id, job_name, duration_minutes, received_at, created_at
+        "#,
+            user_id,
+            job_id,
+            job_name,
+            duration_minutes,
+            message_id,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(draft)
+    }
+
+    /// Lists a user's pending drafts, newest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the drafts.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`WorkSessionDraft`] values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_drafts(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+    ) -> ApiResult<Vec<WorkSessionDraft>> {
+        let drafts = sqlx::query_as!(
+            WorkSessionDraft,
+            r#"
+        SELECT id, job_id, job_name, duration_minutes, received_at, created_at
+        FROM work_session_drafts
+        WHERE user_id = $1
+        ORDER BY received_at DESC
+        "#,
+            user_id,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(drafts)
+    }
+
+    /// Confirms a draft into a completed work session.
+    ///
+    /// The session ends when the email was received and starts the logged
+    /// duration earlier. The draft is removed in the same transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the draft.
+    /// * `draft_id` — The draft's UUID.
+    /// * `job_id` — Job to log against, overriding the draft's matched job.
+    ///
+    /// # Returns
+    ///
+    /// The UUID of the created work session.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::NotFound`] if the draft does not exist,
+    /// [`ApiErrorResponse::BadRequest`] if no job was given or matched, or an
+    /// error if any query fails.
+    pub async fn confirm_draft(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        draft_id: Uuid,
+        job_id: Option<Uuid>,
+    ) -> ApiResult<Uuid> {
+        let mut tx = pool.begin().await?;
+
+        let draft = sqlx::query!(
+            r#"
+        DELETE FROM work_session_drafts
+        WHERE id = $1 AND user_id = $2
+        RETURNING job_id, duration_minutes, received_at
+        "#,
+            draft_id,
+            user_id,
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| ApiErrorResponse::NotFound("Draft not found".to_string()))?;
+
+        let job_id = job_id.or(draft.job_id).ok_or_else(|| {
+            ApiErrorResponse::BadRequest("Choose a job for this draft".to_string())
+        })?;
+        let end_time: DateTime<Utc> = draft.received_at;
+        let start_time = end_time - Duration::minutes(draft.duration_minutes.into());
+
+        let session_id = sqlx::query_scalar!(
+            r#"
+        INSERT INTO work_sessions (user_id, job_id, start_time, end_time, is_running, hourly_rate)
+        SELECT $1, id, $3, $4, FALSE, hourly_rate
+        FROM jobs
+        WHERE id = $2 AND user_id = $1
+        RETURNING id
+        "#,
+            user_id,
+            job_id,
+            start_time,
+            end_time,
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| ApiErrorResponse::NotFound("Job not found".to_string()))?;
+        OnboardingStateRepo::record(&mut *tx, user_id, OnboardingEvent::SessionTracked).await?;
+
+        tx.commit().await?;
+
+        Ok(session_id)
+    }
+
+    /// Dismisses a draft without logging it.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user who owns the draft.
+    /// * `draft_id` — The draft's UUID.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a draft was deleted, `false` if none matched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete query fails.
+    pub async fn delete_draft(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        draft_id: Uuid,
+    ) -> ApiResult<bool> {
+        let result = sqlx::query!(
+            r#"
+        DELETE FROM work_session_drafts
+        WHERE id = $1 AND user_id = $2
+        "#,
+            draft_id,
+            user_id,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
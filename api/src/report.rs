@@ -0,0 +1,277 @@
+//! Time reporting reconciliation and earnings report ranges.
+//!
+//! Provides [`ReportUtil`] for rolling completed work sessions whose time
+//! has not been reported up into per-company, per-week groups, and for
+//! resolving the date ranges of earnings reports and quarterly tax
+//! estimates.
+
+use chrono::{Datelike, Days, Months, NaiveDate};
+use gig_log_common::models::report::{EarningsPeriod, UnreportedTimeGroup};
+
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::repo::work_session::UnreportedSessionRecord;
+
+/// Periods an earnings report covers when no start date is given.
+const DEFAULT_EARNINGS_PERIODS: u32 = 12;
+
+/// Most periods a single earnings report may cover.
+const MAX_EARNINGS_PERIODS: i64 = 260;
+
+/// Utility for grouping and totalling unreported work.
+pub struct ReportUtil;
+
+impl ReportUtil {
+    /// Groups unreported work sessions by company and week.
+    ///
+    /// Sessions are expected to be ordered so that all sessions for a
+    /// company and week are adjacent, as returned by
+    /// [`WorkSessionRepo::list_unreported`](crate::repo::work_session::WorkSessionRepo::list_unreported).
+    ///
+    /// # Arguments
+    ///
+    /// * `sessions` — Unreported work sessions ordered by company and week.
+    ///
+    /// # Returns
+    ///
+    /// A [`Vec`] of [`UnreportedTimeGroup`] values, one per company and
+    /// week.
+    pub fn group_unreported_sessions(
+        sessions: &[UnreportedSessionRecord],
+    ) -> Vec<UnreportedTimeGroup> {
+        let mut groups: Vec<UnreportedTimeGroup> = Vec::new();
+
+        for session in sessions {
+            let seconds = session.net_seconds.max(0);
+
+            match groups.last_mut() {
+                Some(group)
+                    if group.company_id == session.company_id
+                        && group.week_start == session.week_start =>
+                {
+                    group.work_session_ids.push(session.id);
+                    group.duration_seconds += seconds;
+                }
+                _ => groups.push(UnreportedTimeGroup {
+                    company_id: session.company_id,
+                    company_name: session.company_name.clone(),
+                    week_start: session.week_start,
+                    work_session_ids: vec![session.id],
+                    duration_seconds: seconds,
+                }),
+            }
+        }
+
+        groups
+    }
+
+    /// Sums the worked durations of the given groups.
+    ///
+    /// # Arguments
+    ///
+    /// * `groups` — Unreported work groups.
+    ///
+    /// # Returns
+    ///
+    /// The total duration in seconds.
+    pub fn total_seconds(groups: &[UnreportedTimeGroup]) -> i64 {
+        groups.iter().map(|group| group.duration_seconds).sum()
+    }
+
+    /// Resolves the range of an earnings report, filling in defaults.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` — Length of each period.
+    /// * `from` — The requested first day, if any.
+    /// * `to` — The requested last day, if any.
+    /// * `today` — The current date, used when `to` is omitted.
+    ///
+    /// # Returns
+    ///
+    /// The `(from, to)` range. `to` defaults to `today` and `from` to the
+    /// period [`DEFAULT_EARNINGS_PERIODS`] - 1 periods before `to`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if the range spans more than
+    /// [`MAX_EARNINGS_PERIODS`] periods.
+    pub fn earnings_range(
+        period: EarningsPeriod,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+        today: NaiveDate,
+    ) -> ApiResult<(NaiveDate, NaiveDate)> {
+        let to = to.unwrap_or(today);
+        let from = from.unwrap_or_else(|| match period {
+            EarningsPeriod::Weekly => to - Days::new(7 * u64::from(DEFAULT_EARNINGS_PERIODS - 1)),
+            EarningsPeriod::Monthly => to - Months::new(DEFAULT_EARNINGS_PERIODS - 1),
+        });
+
+        let periods = match period {
+            EarningsPeriod::Weekly => {
+                let monday = |date: NaiveDate| {
+                    date.num_days_from_ce() - date.weekday().num_days_from_monday() as i32
+                };
+
+                i64::from(monday(to) - monday(from)) / 7 + 1
+            }
+            EarningsPeriod::Monthly => {
+                let months =
+                    |date: NaiveDate| i64::from(date.year()) * 12 + i64::from(date.month0());
+
+                months(to) - months(from) + 1
+            }
+        };
+
+        if periods > MAX_EARNINGS_PERIODS {
+            return Err(ApiErrorResponse::BadRequest(format!(
+                "Earnings reports cover at most {MAX_EARNINGS_PERIODS} periods"
+            )));
+        }
+
+        Ok((from, to))
+    }
+
+    /// Resolves the days of a tax estimate's quarter, filling in defaults.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` — The requested calendar year, if any.
+    /// * `quarter` — The requested quarter from 1 to 4, if any. Values
+    ///   outside that range are clamped;
+    ///   [`TaxEstimateQuery`](gig_log_common::models::report::TaxEstimateQuery)
+    ///   rejects them before this is called.
+    /// * `today` — The current date, used for omitted values.
+    ///
+    /// # Returns
+    ///
+    /// The first and last days of the quarter.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if `year` is out of range.
+    pub fn tax_quarter(
+        year: Option<i32>,
+        quarter: Option<u32>,
+        today: NaiveDate,
+    ) -> ApiResult<(NaiveDate, NaiveDate)> {
+        let year = year.unwrap_or(today.year());
+        let quarter = quarter.unwrap_or(today.month0() / 3 + 1).clamp(1, 4);
+
+        let start = NaiveDate::from_ymd_opt(year, (quarter - 1) * 3 + 1, 1);
+        let end = start
+            .and_then(|start| start.checked_add_months(Months::new(3)))
+            .and_then(|next| next.pred_opt());
+
+        match (start, end) {
+            (Some(start), Some(end)) => Ok((start, end)),
+            _ => Err(ApiErrorResponse::BadRequest(
+                "year is out of range".to_string(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use gig_log_common::models::report::EarningsPeriod;
+    use uuid::Uuid;
+
+    use crate::core::error::ApiErrorResponse;
+    use crate::repo::work_session::UnreportedSessionRecord;
+
+    use super::ReportUtil;
+
+    fn session(
+        company_id: Uuid,
+        week_start: NaiveDate,
+        net_seconds: i64,
+    ) -> UnreportedSessionRecord {
+        UnreportedSessionRecord {
+            id: Uuid::new_v4(),
+            company_id,
+            company_name: "Acme".to_string(),
+            week_start,
+            net_seconds,
+        }
+    }
+
+    #[test]
+    fn groups_adjacent_sessions_by_company_and_week() {
+        let company_a = Uuid::new_v4();
+        let company_b = Uuid::new_v4();
+        let week_1 = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        let week_2 = NaiveDate::from_ymd_opt(2026, 3, 9).unwrap();
+        let sessions = vec![
+            session(company_a, week_1, 1800),
+            session(company_a, week_1, 600),
+            session(company_a, week_2, 3600),
+            session(company_b, week_2, -5),
+        ];
+
+        let groups = ReportUtil::group_unreported_sessions(&sessions);
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!(
+            groups[0].work_session_ids,
+            vec![sessions[0].id, sessions[1].id]
+        );
+        assert_eq!(groups[0].duration_seconds, 2400);
+        assert_eq!(groups[1].week_start, week_2);
+        assert_eq!(groups[2].company_id, company_b);
+        assert_eq!(groups[2].duration_seconds, 0);
+        assert_eq!(ReportUtil::total_seconds(&groups), 6000);
+    }
+
+    #[test]
+    fn resolves_earnings_ranges() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let today = date(2026, 10, 16);
+
+        assert_eq!(
+            ReportUtil::earnings_range(EarningsPeriod::Monthly, None, None, today).unwrap(),
+            (date(2025, 11, 16), today)
+        );
+        assert_eq!(
+            ReportUtil::earnings_range(EarningsPeriod::Weekly, None, None, today).unwrap(),
+            (date(2026, 7, 31), today)
+        );
+        assert!(
+            ReportUtil::earnings_range(
+                EarningsPeriod::Monthly,
+                Some(date(2006, 1, 1)),
+                None,
+                today
+            )
+            .is_ok()
+        );
+        assert!(matches!(
+            ReportUtil::earnings_range(EarningsPeriod::Weekly, Some(date(2020, 1, 1)), None, today),
+            Err(ApiErrorResponse::BadRequest(_))
+        ));
+    }
+
+    #[test]
+    fn resolves_tax_quarters() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let today = date(2026, 10, 16);
+
+        assert_eq!(
+            ReportUtil::tax_quarter(None, None, today).unwrap(),
+            (date(2026, 10, 1), date(2026, 12, 31))
+        );
+        assert_eq!(
+            ReportUtil::tax_quarter(Some(2024), Some(1), today).unwrap(),
+            (date(2024, 1, 1), date(2024, 3, 31))
+        );
+        assert_eq!(
+            ReportUtil::tax_quarter(Some(2024), Some(5), today).unwrap(),
+            (date(2024, 10, 1), date(2024, 12, 31))
+        );
+        assert!(matches!(
+            ReportUtil::tax_quarter(Some(i32::MAX), Some(1), today),
+            Err(ApiErrorResponse::BadRequest(_))
+        ));
+    }
+}
@@ -0,0 +1,359 @@
+//! Cached earnings and tax estimate reports.
+//!
+//! Provides [`ReportCache`], which serves the earnings report and the
+//! quarterly tax estimate from the `report_cache` table and generates them
+//! on a miss. Database triggers mark a user's cached reports stale whenever
+//! their work sessions, payments, invoices, or companies change, and the
+//! [`ReportCacheTask`](crate::tasks::report_cache::ReportCacheTask)
+//! regenerates stale reports that are still being read. Each report
+//! carries the time it was generated.
+
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use gig_log_common::models::report::{EarningsPeriod, EarningsReportResponse, TaxEstimateResponse};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::Value;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::repo::report::ReportRepo;
+
+/// The kind and resolved parameters of a cached report. Stored as the
+/// cache key, so reports requested with different parameters are cached
+/// separately.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum CachedReport {
+    /// An earnings report.
+    Earnings {
+        /// Length of each period.
+        period: EarningsPeriod,
+        /// A day in the first period.
+        from: NaiveDate,
+        /// A day in the last period.
+        to: NaiveDate,
+    },
+    /// A quarterly tax estimate.
+    TaxEstimate {
+        /// First day of the quarter.
+        start_date: NaiveDate,
+        /// Last day of the quarter.
+        end_date: NaiveDate,
+        /// Self-employment rate applied to companies that do not withhold.
+        self_employment_rate: f64,
+    },
+}
+
+/// Cached report generation.
+pub struct ReportCache;
+
+impl ReportCache {
+    /// Returns a user's earnings report, generating it if it is not
+    /// cached.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user to report on.
+    /// * `period` — Length of each period.
+    /// * `from` — A day in the first period.
+    /// * `to` — A day in the last period.
+    ///
+    /// # Returns
+    ///
+    /// The [`EarningsReportResponse`], possibly from the cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a database query fails.
+    pub async fn earnings(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        period: EarningsPeriod,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> ApiResult<EarningsReportResponse> {
+        Self::load(pool, user_id, &CachedReport::Earnings { period, from, to }).await
+    }
+
+    /// Returns a user's tax estimate for a quarter, generating it if it is
+    /// not cached.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user to report on.
+    /// * `start_date` — First day of the quarter.
+    /// * `end_date` — Last day of the quarter.
+    /// * `self_employment_rate` — Rate applied to companies that do not
+    ///   withhold.
+    ///
+    /// # Returns
+    ///
+    /// The [`TaxEstimateResponse`], possibly from the cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a database query fails.
+    pub async fn tax_estimate(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+        self_employment_rate: f64,
+    ) -> ApiResult<TaxEstimateResponse> {
+        let report = CachedReport::TaxEstimate {
+            start_date,
+            end_date,
+            self_employment_rate,
+        };
+
+        Self::load(pool, user_id, &report).await
+    }
+
+    /// Regenerates a cached report and stores the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user the report belongs to.
+    /// * `report` — The report to regenerate.
+    ///
+    /// # Returns
+    ///
+    /// The regenerated report as JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a database query fails or the report cannot be
+    /// serialized.
+    pub async fn refresh(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        report: &CachedReport,
+    ) -> ApiResult<Value> {
+        let key = Self::to_json(report)?;
+        let generated_at = Utc::now();
+        let data = match report {
+            CachedReport::Earnings { period, from, to } => Self::to_json(
+                &Self::generate_earnings(pool, user_id, *period, *from, *to, generated_at).await?,
+            )?,
+            CachedReport::TaxEstimate {
+                start_date,
+                end_date,
+                self_employment_rate,
+            } => Self::to_json(
+                &Self::generate_tax_estimate(
+                    pool,
+                    user_id,
+                    *start_date,
+                    *end_date,
+                    *self_employment_rate,
+                    generated_at,
+                )
+                .await?,
+            )?,
+        };
+
+        ReportRepo::store_cached_report(pool, user_id, &key, &data, generated_at).await?;
+
+        Ok(data)
+    }
+
+    /// Reads a report from the cache, regenerating it on a miss.
+    ///
+    /// Cached data that no longer matches the response type, such as after
+    /// a field was added, is treated as a miss.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user the report belongs to.
+    /// * `report` — The report to read.
+    ///
+    /// # Returns
+    ///
+    /// The report, deserialized as `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a database query fails or the report cannot be
+    /// serialized.
+    async fn load<T: DeserializeOwned>(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        report: &CachedReport,
+    ) -> ApiResult<T> {
+        let key = Self::to_json(report)?;
+
+        if let Some(data) = ReportRepo::find_cached_report(pool, user_id, &key).await?
+            && let Ok(cached) = serde_json::from_value(data)
+        {
+            return Ok(cached);
+        }
+
+        let data = Self::refresh(pool, user_id, report).await?;
+
+        serde_json::from_value(data)
+            .map_err(|error| ApiErrorResponse::InternalServerError(error.to_string()))
+    }
+
+    /// Generates an earnings report.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user to report on.
+    /// * `period` — Length of each period.
+    /// * `from` — A day in the first period.
+    /// * `to` — A day in the last period.
+    /// * `generated_at` — When generation started.
+    ///
+    /// # Returns
+    ///
+    /// The generated [`EarningsReportResponse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    async fn generate_earnings(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        period: EarningsPeriod,
+        from: NaiveDate,
+        to: NaiveDate,
+        generated_at: DateTime<Utc>,
+    ) -> ApiResult<EarningsReportResponse> {
+        let periods = ReportRepo::earnings(pool, user_id, period, from, to).await?;
+
+        Ok(EarningsReportResponse {
+            period,
+            periods,
+            generated_at,
+        })
+    }
+
+    /// Generates a quarterly tax estimate.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The UUID of the user to report on.
+    /// * `start_date` — First day of the quarter.
+    /// * `end_date` — Last day of the quarter.
+    /// * `rate` — Self-employment rate applied to companies that do not
+    ///   withhold.
+    /// * `generated_at` — When generation started.
+    ///
+    /// # Returns
+    ///
+    /// The generated [`TaxEstimateResponse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    async fn generate_tax_estimate(
+        pool: &Pool<Postgres>,
+        user_id: Uuid,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+        rate: f64,
+        generated_at: DateTime<Utc>,
+    ) -> ApiResult<TaxEstimateResponse> {
+        let companies = ReportRepo::tax_estimate(pool, user_id, start_date, end_date, rate).await?;
+        let total = |amount: fn(&_) -> f64| {
+            let cents = companies
+                .iter()
+                .fold(0.0, |cents, company| cents + amount(company) * 100.0);
+            cents.round() / 100.0
+        };
+
+        Ok(TaxEstimateResponse {
+            year: start_date.year(),
+            quarter: start_date.month0() / 3 + 1,
+            start_date,
+            end_date,
+            self_employment_tax_rate: rate,
+            estimated_owed: total(|company| company.estimated_owed),
+            tax_withholdings_covered: total(|company| company.tax_withholdings_covered),
+            shortfall: total(|company| company.shortfall),
+            companies,
+            generated_at,
+        })
+    }
+
+    /// Serializes a value for the cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — The value to serialize.
+    ///
+    /// # Returns
+    ///
+    /// The value as JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::InternalServerError`] if the value cannot
+    /// be serialized.
+    fn to_json<T: Serialize>(value: &T) -> ApiResult<Value> {
+        serde_json::to_value(value)
+            .map_err(|error| ApiErrorResponse::InternalServerError(error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use gig_log_common::models::report::EarningsPeriod;
+    use serde_json::json;
+
+    use super::CachedReport;
+
+    #[test]
+    fn cache_keys_serialize_with_kind_and_parameters() {
+        let earnings = CachedReport::Earnings {
+            period: EarningsPeriod::Monthly,
+            from: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            to: NaiveDate::from_ymd_opt(2026, 3, 31).unwrap(),
+        };
+        let estimate = CachedReport::TaxEstimate {
+            start_date: NaiveDate::from_ymd_opt(2026, 4, 1).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2026, 6, 30).unwrap(),
+            self_employment_rate: 0.153,
+        };
+
+        assert_eq!(
+            serde_json::to_value(&earnings).unwrap(),
+            json!({
+                "kind": "earnings",
+                "period": "monthly",
+                "from": "2026-01-01",
+                "to": "2026-03-31",
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(&estimate).unwrap(),
+            json!({
+                "kind": "tax_estimate",
+                "start_date": "2026-04-01",
+                "end_date": "2026-06-30",
+                "self_employment_rate": 0.153,
+            })
+        );
+    }
+
+    #[test]
+    fn cache_keys_round_trip() {
+        let key = json!({
+            "kind": "earnings",
+            "period": "weekly",
+            "from": "2026-01-05",
+            "to": "2026-03-30",
+        });
+
+        let report: CachedReport = serde_json::from_value(key.clone()).unwrap();
+
+        assert_eq!(serde_json::to_value(&report).unwrap(), key);
+    }
+}
@@ -0,0 +1,42 @@
+//! Account data export route definitions.
+//!
+//! This module defines the [`AccountExportRouter`], which exposes the
+//! endpoints for requesting, checking on, and downloading full account
+//! data exports.
+
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::controllers::account_export::AccountExportController;
+use crate::routes::app::AppState;
+
+/// Router for account data export endpoints.
+pub struct AccountExportRouter;
+
+impl AccountExportRouter {
+    /// Creates a [`Router`] with the account data export routes.
+    ///
+    /// Registers the following routes:
+    ///
+    /// - `POST /account` mapped to
+    ///   [`AccountExportController::create`](crate::controllers::account_export::AccountExportController::create).
+    /// - `GET /account/{id}` mapped to
+    ///   [`AccountExportController::show`](crate::controllers::account_export::AccountExportController::show).
+    /// - `GET /account/{id}/download` mapped to
+    ///   [`AccountExportController::download`](crate::controllers::account_export::AccountExportController::download).
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with the account data export routes registered.
+    pub fn new() -> Router<AppState> {
+        Router::new()
+            .route("/account", post(AccountExportController::create))
+            .route("/account/{id}", get(AccountExportController::show))
+            .route(
+                "/account/{id}/download",
+                get(AccountExportController::download),
+            )
+    }
+}
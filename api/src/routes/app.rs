@@ -1,12 +1,16 @@
 //! Application router and shared state for the GigLog API.
 //!
 //! This module defines [`AppState`], the shared state available to all
-//! request handlers, and [`AppRouter`], which assembles every route group,
-//! configures CORS, and applies HTTP logging middleware.
+//! request handlers, and [`AppRouter`], which assembles every route group
+//! under its API version prefix, configures CORS, and applies IP filtering,
+//! error format negotiation, API activity recording, HTTP logging, and error
+//! reporting middleware.
+
+use std::sync::Arc;
 
 use axum::{
     Router,
-    http::{HeaderName, HeaderValue, Method},
+    http::{HeaderName, HeaderValue, Method, header},
     middleware,
 };
 use log::error;
@@ -15,17 +19,38 @@ use tower_http::cors::{AllowOrigin, CorsLayer};
 
 use crate::{
     core::{
+        activity::ActivityRecorder,
         config::Config,
+        error_reporting::ErrorReporter,
+        ip_filter::IpFilter,
         logger::{HttpLoggingConfig, Logger},
+        problem::ProblemJson,
+        versioning::{API_VERSION_HEADER, ApiVersion, ApiVersioning, DEPRECATION_HEADER},
     },
     email::client::EmailClient,
-    routes::{auth::AuthRouter, health::HealthRouter},
+    live_sync::LiveSyncHub,
+    routes::{
+        account_export::AccountExportRouter, appearance::AppearanceRouter, auth::AuthRouter,
+        budget::BudgetRouter, calendar::CalendarRouter, company::CompanyRouter,
+        contract::ContractRouter, health::HealthRouter, import::ImportRouter,
+        inbound_email::InboundEmailRouter, invoice::InvoiceRouter, job::JobRouter,
+        lead::LeadRouter, live_sync::LiveSyncRouter, mileage::MileageRouter,
+        onboarding::OnboardingRouter, payment::PaymentRouter,
+        personal_access_token::PersonalAccessTokenRouter, receipt::ReceiptRouter,
+        recurring_payment::RecurringPaymentRouter, report::ReportRouter, search::SearchRouter,
+        subscription::SubscriptionRouter, time_off::TimeOffRouter, timesheet::TimesheetRouter,
+        toggl::TogglRouter, webhook::WebhookRouter, work_session::WorkSessionRouter,
+        work_session_draft::WorkSessionDraftRouter,
+    },
+    services::{distance::DistanceCalculator, oauth::IdentityProviders, pdf::PdfRenderer},
 };
 
 /// Shared application state passed to every Axum handler.
 ///
-/// Holds the runtime configuration, a PostgreSQL connection pool, and an
-/// email client. Axum clones this state for each request via its [`Clone`]
+/// Holds the runtime configuration, a PostgreSQL connection pool, an email
+/// client, the PDF renderer, the enabled OAuth identity providers, the
+/// distance calculator, the optional error reporter, and the live sync
+/// hub. Axum clones this state for each request via its [`Clone`]
 /// implementation.
 #[derive(Debug, Clone)]
 pub struct AppState {
@@ -35,6 +60,16 @@ pub struct AppState {
     pub db_pool: Pool<Postgres>,
     /// Email client for sending transactional emails.
     pub email_client: EmailClient,
+    /// Renderer for PDF documents such as invoices.
+    pub pdf_renderer: Arc<dyn PdfRenderer>,
+    /// Identity providers users can sign in with.
+    pub identity_providers: IdentityProviders,
+    /// Estimator for the driving distance to job sites.
+    pub distance_calculator: Arc<dyn DistanceCalculator>,
+    /// Reporter for server errors. `None` when error reporting is disabled.
+    pub error_reporter: Option<ErrorReporter>,
+    /// Hub that pushes domain events to live sync connections.
+    pub live_sync: LiveSyncHub,
 }
 
 /// Top-level router builder for the GigLog API.
@@ -44,9 +79,14 @@ impl AppRouter {
     /// Creates a fully configured [`Router`] with all route groups and middleware.
     ///
     /// Parses configured web origins for CORS, falling back to
-    /// `http://localhost:3000` when none are valid. Nests [`HealthRouter`]
-    /// at `/health` and [`AuthRouter`] at `/auth`, then applies HTTP request/response
-    /// logging and CORS middleware layers.
+    /// `http://localhost:3000` when none are valid. Mounts the
+    /// [`versioned_routes`](Self::versioned_routes) of every [`ApiVersion`]
+    /// under its prefix, such as `/v1`, and keeps the unversioned paths as
+    /// deprecated aliases of [`ApiVersion::LEGACY`]. Nests [`TogglRouter`] at
+    /// `/api/v9` outside the versioned routes, since its paths mirror the
+    /// Toggl Track API. Then applies IP filtering, problem details
+    /// negotiation, API activity recording, HTTP request/response logging,
+    /// error reporting, and CORS middleware layers.
     ///
     /// # Arguments
     ///
@@ -69,6 +109,7 @@ impl AppRouter {
                 Method::GET,
                 Method::POST,
                 Method::PUT,
+                Method::PATCH,
                 Method::DELETE,
                 Method::OPTIONS,
             ])
@@ -76,19 +117,97 @@ impl AppRouter {
                 HeaderName::from_static("content-type"),
                 HeaderName::from_static("authorization"),
             ])
+            .expose_headers([API_VERSION_HEADER, DEPRECATION_HEADER, header::LINK])
             .allow_credentials(true);
 
         Router::new()
-            .nest("/health", HealthRouter::new())
-            .nest("/auth", AuthRouter::new())
+            .nest("/api/v9", TogglRouter::new())
+            .merge(ApiVersioning::mount(Self::versioned_routes))
+            .layer(middleware::from_fn_with_state(
+                state.clone(),
+                IpFilter::enforce,
+            ))
+            .layer(middleware::from_fn(ProblemJson::negotiate))
+            .layer(middleware::from_fn_with_state(
+                state.clone(),
+                ActivityRecorder::record,
+            ))
             .layer(middleware::from_fn_with_state(
                 http_logging_config,
                 Logger::log_request_and_response,
             ))
+            .layer(middleware::from_fn_with_state(
+                state.clone(),
+                ErrorReporter::capture_errors,
+            ))
             .layer(cors)
             .with_state(state)
     }
 
+    /// Creates the route groups served under an API version's prefix.
+    ///
+    /// Nests [`HealthRouter`] at `/health`, [`AuthRouter`] at `/auth`,
+    /// [`AppearanceRouter`] at `/appearance`, [`CompanyRouter`] at
+    /// `/companies`, [`JobRouter`] at `/jobs`, [`BudgetRouter`] at
+    /// `/budgets`, [`ContractRouter`] at `/contracts`, [`TimeOffRouter`] at
+    /// `/time-off`, [`TimesheetRouter`] at `/timesheets`,
+    /// [`WorkSessionRouter`] at `/work-sessions`,
+    /// [`WorkSessionDraftRouter`] at `/work-session-drafts`,
+    /// [`PersonalAccessTokenRouter`] at `/personal-access-tokens`,
+    /// [`InboundEmailRouter`] at `/inbound-email`, [`InvoiceRouter`] at
+    /// `/invoices`, [`PaymentRouter`] at `/payments`,
+    /// [`RecurringPaymentRouter`] at `/recurring-payments`, [`LeadRouter`] at
+    /// `/leads`, [`MileageRouter`] at `/mileage`, [`ReportRouter`] at
+    /// `/reports`, [`AccountExportRouter`] at `/export`, [`ImportRouter`] at
+    /// `/import`, [`CalendarRouter`] at `/calendar`, [`WebhookRouter`] at
+    /// `/webhooks`, [`OnboardingRouter`] at `/onboarding`,
+    /// [`SubscriptionRouter`] at `/subscriptions`, [`SearchRouter`] at
+    /// `/search`, [`ReceiptRouter`] at `/p`, and [`LiveSyncRouter`] at `/ws`.
+    ///
+    /// A future version starts as a copy of these routes and diverges where
+    /// its behavior changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `version` — The API version to build routes for.
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with the version's routes, without its prefix.
+    fn versioned_routes(version: ApiVersion) -> Router<AppState> {
+        match version {
+            ApiVersion::V1 => Router::new()
+                .nest("/health", HealthRouter::new())
+                .nest("/auth", AuthRouter::new())
+                .nest("/appearance", AppearanceRouter::new())
+                .nest("/companies", CompanyRouter::new())
+                .nest("/jobs", JobRouter::new())
+                .nest("/budgets", BudgetRouter::new())
+                .nest("/contracts", ContractRouter::new())
+                .nest("/time-off", TimeOffRouter::new())
+                .nest("/timesheets", TimesheetRouter::new())
+                .nest("/work-sessions", WorkSessionRouter::new())
+                .nest("/work-session-drafts", WorkSessionDraftRouter::new())
+                .nest("/personal-access-tokens", PersonalAccessTokenRouter::new())
+                .nest("/inbound-email", InboundEmailRouter::new())
+                .nest("/invoices", InvoiceRouter::new())
+                .nest("/payments", PaymentRouter::new())
+                .nest("/recurring-payments", RecurringPaymentRouter::new())
+                .nest("/leads", LeadRouter::new())
+                .nest("/mileage", MileageRouter::new())
+                .nest("/reports", ReportRouter::new())
+                .nest("/export", AccountExportRouter::new())
+                .nest("/import", ImportRouter::new())
+                .nest("/calendar", CalendarRouter::new())
+                .nest("/webhooks", WebhookRouter::new())
+                .nest("/onboarding", OnboardingRouter::new())
+                .nest("/subscriptions", SubscriptionRouter::new())
+                .nest("/search", SearchRouter::new())
+                .nest("/p", ReceiptRouter::new())
+                .nest("/ws", LiveSyncRouter::new()),
+        }
+    }
+
     /// Builds a validated CORS allow-list from configured origins.
     ///
     /// # Arguments
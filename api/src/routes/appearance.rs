@@ -0,0 +1,33 @@
+//! Appearance route definitions.
+//!
+//! This module defines the [`AppearanceRouter`], which maps appearance
+//! HTTP endpoints to [`AppearanceController`] handler methods.
+
+use axum::{Router, routing::get};
+
+use crate::{controllers::appearance::AppearanceController, routes::app::AppState};
+
+/// Router for appearance endpoints.
+pub struct AppearanceRouter;
+
+impl AppearanceRouter {
+    /// Creates a [`Router`] with all appearance routes.
+    ///
+    /// Registers the following endpoints under the `/appearance` prefix:
+    ///
+    /// - `GET /presets` — List the preset color palettes.
+    /// - `GET /palettes/{id}/preview.svg` — Render a swatch preview of a
+    ///   preset or custom palette.
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with all appearance routes registered.
+    pub fn new() -> Router<AppState> {
+        Router::new()
+            .route("/presets", get(AppearanceController::list_presets))
+            .route(
+                "/palettes/{id}/preview.svg",
+                get(AppearanceController::preview),
+            )
+    }
+}
@@ -6,10 +6,18 @@
 
 use axum::{
     Router,
-    routing::{get, post},
+    extract::DefaultBodyLimit,
+    routing::{delete, get, post},
 };
 
-use crate::{controllers::auth::AuthController, routes::app::AppState};
+use crate::{
+    controllers::{
+        api_key::ApiKeyController, auth::AuthController, avatar::AvatarController,
+        oauth::OAuthController, passkey::PasskeyController,
+    },
+    images::MAX_IMAGE_UPLOAD_BYTES,
+    routes::app::AppState,
+};
 
 /// Router for authentication and account management endpoints.
 pub struct AuthRouter;
@@ -21,12 +29,40 @@ impl AuthRouter {
     ///
     /// - `POST /sign-up` — Register a new user account.
     /// - `POST /confirm-email` — Confirm an email address.
+    /// - `POST /resend-confirmation` — Re-send the email confirmation code.
+    /// - `GET /confirm` — Confirm an email address or email change from an
+    ///   emailed link.
     /// - `POST /log-in` — Authenticate and obtain tokens.
+    /// - `POST /demo` — Create a throwaway demo account and log into it.
     /// - `POST /log-out` — Revoke the current session.
     /// - `POST /refresh` — Refresh an access token.
     /// - `GET /me` — Retrieve the authenticated user's profile.
+    /// - `GET /me/activity` — List the authenticated user's recent API
+    ///   activity.
+    /// - `GET /me/locale` — Get the language emails are sent in.
+    /// - `PUT /me/locale` — Set the language emails are sent in.
+    /// - `GET /me/timezone` — Get the timezone times are shown in.
+    /// - `PUT /me/timezone` — Set or clear the timezone times are shown in.
+    /// - `PUT /me/avatar` — Upload an avatar, resized to a standard size.
+    /// - `GET /me/avatar` — Download the avatar.
+    /// - `DELETE /me/avatar` — Remove the avatar.
+    /// - `GET /sessions` — List the authenticated user's active sessions.
+    /// - `DELETE /sessions/{id}` — Revoke one of the user's sessions.
+    /// - `POST /api-keys` — Issue an API key.
+    /// - `GET /api-keys` — List the user's API keys.
+    /// - `DELETE /api-keys/{id}` — Revoke an API key.
+    /// - `POST /passkeys/register/start` — Start registering a passkey.
+    /// - `POST /passkeys/register/finish` — Finish registering a passkey.
+    /// - `POST /passkeys/login/start` — Start logging in with a passkey.
+    /// - `POST /passkeys/login/finish` — Finish logging in with a passkey.
+    /// - `GET /oauth/{provider}/start` — Start signing in with Google or
+    ///   GitHub.
+    /// - `GET /oauth/{provider}/callback` — Complete signing in with Google
+    ///   or GitHub.
     /// - `POST /forgot-password` — Request a password reset code.
     /// - `POST /verify-forgot-password` — Verify a password reset code.
+    /// - `GET /reset-password` — Open the set-password form from an emailed
+    ///   reset link.
     /// - `POST /set-password` — Set a new password after reset.
     /// - `POST /request-change-password` — Request a password change code.
     /// - `POST /change-password` — Change the current password.
@@ -40,15 +76,63 @@ impl AuthRouter {
         Router::new()
             .route("/sign-up", post(AuthController::sign_up))
             .route("/confirm-email", post(AuthController::confirm_email))
+            .route(
+                "/resend-confirmation",
+                post(AuthController::resend_confirmation),
+            )
+            .route("/confirm", get(AuthController::confirm_link))
             .route("/log-in", post(AuthController::log_in))
+            .route("/demo", post(AuthController::demo))
             .route("/log-out", post(AuthController::log_out))
             .route("/refresh", post(AuthController::refresh))
             .route("/me", get(AuthController::me))
+            .route("/me/activity", get(AuthController::activity))
+            .route(
+                "/me/locale",
+                get(AuthController::show_locale).put(AuthController::update_locale),
+            )
+            .route(
+                "/me/timezone",
+                get(AuthController::show_timezone).put(AuthController::update_timezone),
+            )
+            .route(
+                "/me/avatar",
+                get(AvatarController::show)
+                    .put(AvatarController::upload)
+                    .delete(AvatarController::delete)
+                    .layer(DefaultBodyLimit::max(MAX_IMAGE_UPLOAD_BYTES)),
+            )
+            .route("/sessions", get(AuthController::sessions))
+            .route("/sessions/{id}", delete(AuthController::revoke_session))
+            .route(
+                "/api-keys",
+                get(ApiKeyController::list).post(ApiKeyController::create),
+            )
+            .route("/api-keys/{id}", delete(ApiKeyController::delete))
+            .route(
+                "/passkeys/register/start",
+                post(PasskeyController::start_registration),
+            )
+            .route(
+                "/passkeys/register/finish",
+                post(PasskeyController::finish_registration),
+            )
+            .route(
+                "/passkeys/login/start",
+                post(PasskeyController::start_login),
+            )
+            .route(
+                "/passkeys/login/finish",
+                post(PasskeyController::finish_login),
+            )
+            .route("/oauth/{provider}/start", get(OAuthController::start))
+            .route("/oauth/{provider}/callback", get(OAuthController::callback))
             .route("/forgot-password", post(AuthController::forgot_password))
             .route(
                 "/verify-forgot-password",
                 post(AuthController::verify_forgot_password),
             )
+            .route("/reset-password", get(AuthController::reset_password_link))
             .route("/set-password", post(AuthController::set_password))
             .route(
                 "/request-change-password",
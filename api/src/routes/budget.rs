@@ -0,0 +1,36 @@
+//! Budget route definitions.
+//!
+//! This module defines the [`BudgetRouter`], which maps budget HTTP
+//! endpoints to [`BudgetController`] handler methods.
+
+use axum::{
+    Router,
+    routing::{delete, get},
+};
+
+use crate::{controllers::budget::BudgetController, routes::app::AppState};
+
+/// Router for budget endpoints.
+pub struct BudgetRouter;
+
+impl BudgetRouter {
+    /// Creates a [`Router`] with all budget routes.
+    ///
+    /// Registers the following endpoints under the `/budgets` prefix:
+    ///
+    /// - `POST /` — Create a budget on a company or job.
+    /// - `GET /` — List budgets with current-month consumption.
+    /// - `DELETE /{id}` — Delete a budget.
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with all budget routes registered.
+    pub fn new() -> Router<AppState> {
+        Router::new()
+            .route(
+                "/",
+                get(BudgetController::list).post(BudgetController::create),
+            )
+            .route("/{id}", delete(BudgetController::delete))
+    }
+}
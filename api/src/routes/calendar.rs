@@ -0,0 +1,38 @@
+//! Calendar feed route definitions.
+//!
+//! This module defines the [`CalendarRouter`], which maps calendar feed
+//! HTTP endpoints to [`CalendarController`] handler methods.
+
+use axum::{
+    Router,
+    routing::{delete, get},
+};
+
+use crate::{controllers::calendar::CalendarController, routes::app::AppState};
+
+/// Router for calendar feed endpoints.
+pub struct CalendarRouter;
+
+impl CalendarRouter {
+    /// Creates a [`Router`] with all calendar feed routes.
+    ///
+    /// Registers the following endpoints under the `/calendar` prefix:
+    ///
+    /// - `POST /feeds` — Create a calendar feed.
+    /// - `GET /feeds` — List the user's calendar feeds.
+    /// - `DELETE /feeds/{id}` — Revoke a calendar feed.
+    /// - `GET /{token}.ics` — Fetch a calendar feed (public).
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with all calendar feed routes registered.
+    pub fn new() -> Router<AppState> {
+        Router::new()
+            .route(
+                "/feeds",
+                get(CalendarController::list).post(CalendarController::create),
+            )
+            .route("/feeds/{id}", delete(CalendarController::delete))
+            .route("/{file}", get(CalendarController::feed))
+    }
+}
@@ -0,0 +1,84 @@
+//! Company route definitions.
+//!
+//! This module defines the [`CompanyRouter`], which maps company-scoped
+//! HTTP endpoints to [`CompanyController`] handler methods.
+
+use axum::{
+    Router,
+    extract::DefaultBodyLimit,
+    routing::{get, post},
+};
+
+use crate::{
+    controllers::company::CompanyController,
+    images::MAX_IMAGE_UPLOAD_BYTES,
+    routes::{app::AppState, contact::ContactRouter},
+};
+
+/// Router for company endpoints.
+pub struct CompanyRouter;
+
+impl CompanyRouter {
+    /// Creates a [`Router`] with all company routes.
+    ///
+    /// Registers the following endpoints under the `/companies` prefix:
+    ///
+    /// - `GET /{id}` — Get a company with its budgets, contracts, and primary
+    ///   contact, optionally limited to `?fields=` and expanded with
+    ///   `?include=jobs,payments`.
+    /// - `PATCH /{id}` — Partially update a company.
+    /// - `DELETE /{id}` — Delete a company, requiring `?confirm=true` when it
+    ///   has dependent records and `?confirm_finalized=true` when it has
+    ///   finalized invoices or invoiced or approved work sessions.
+    /// - `GET /{id}/delete-preview` — Count the records a deletion would
+    ///   remove or detach.
+    /// - `POST /{id}/duplicate` — Copy a company's settings and jobs into a
+    ///   new company.
+    /// - `GET /{id}/unbilled` — List unbilled work sessions grouped by job.
+    /// - `GET /{id}/locations` — Summarize where the company's work was done.
+    /// - `POST /{id}/unbilled/bill` — Attach unbilled sessions to a draft invoice.
+    /// - `POST /{id}/send-statement` — Email a statement of payments and hours.
+    /// - `GET /{id}/statements` — List statements sent to a company.
+    /// - `PUT /{id}/logo` — Upload a logo, resized to a standard size.
+    /// - `GET /{id}/logo` — Download the logo.
+    /// - `DELETE /{id}/logo` — Remove the logo.
+    ///
+    /// Nests [`ContactRouter`] at `/{id}/contacts` for the company's contacts.
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with all company routes registered.
+    pub fn new() -> Router<AppState> {
+        Router::new()
+            .route(
+                "/{id}",
+                get(CompanyController::show)
+                    .patch(CompanyController::update)
+                    .delete(CompanyController::delete),
+            )
+            .route(
+                "/{id}/delete-preview",
+                get(CompanyController::delete_preview),
+            )
+            .route("/{id}/duplicate", post(CompanyController::duplicate))
+            .route("/{id}/unbilled", get(CompanyController::unbilled))
+            .route("/{id}/locations", get(CompanyController::locations))
+            .route(
+                "/{id}/unbilled/bill",
+                post(CompanyController::bill_unbilled),
+            )
+            .route(
+                "/{id}/send-statement",
+                post(CompanyController::send_statement),
+            )
+            .route("/{id}/statements", get(CompanyController::statements))
+            .route(
+                "/{id}/logo",
+                get(CompanyController::logo)
+                    .put(CompanyController::upload_logo)
+                    .delete(CompanyController::delete_logo)
+                    .layer(DefaultBodyLimit::max(MAX_IMAGE_UPLOAD_BYTES)),
+            )
+            .nest("/{id}/contacts", ContactRouter::new())
+    }
+}
@@ -0,0 +1,42 @@
+//! Company contact route definitions.
+//!
+//! This module defines the [`ContactRouter`], which maps company contact
+//! HTTP endpoints to [`ContactController`] handler methods. It is nested
+//! under each company by the [`CompanyRouter`](crate::routes::company::CompanyRouter).
+
+use axum::{Router, routing::get};
+
+use crate::{controllers::contact::ContactController, routes::app::AppState};
+
+/// Router for company contact endpoints.
+pub struct ContactRouter;
+
+impl ContactRouter {
+    /// Creates a [`Router`] with all company contact routes.
+    ///
+    /// Registers the following endpoints under the `/companies/{id}/contacts`
+    /// prefix:
+    ///
+    /// - `GET /` — List the company's contacts, primary contact first.
+    /// - `POST /` — Add a contact to the company.
+    /// - `GET /{contact_id}` — Get a contact.
+    /// - `PATCH /{contact_id}` — Partially update a contact.
+    /// - `DELETE /{contact_id}` — Remove a contact.
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with all company contact routes registered.
+    pub fn new() -> Router<AppState> {
+        Router::new()
+            .route(
+                "/",
+                get(ContactController::list).post(ContactController::create),
+            )
+            .route(
+                "/{contact_id}",
+                get(ContactController::show)
+                    .patch(ContactController::update)
+                    .delete(ContactController::delete),
+            )
+    }
+}
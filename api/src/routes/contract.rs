@@ -0,0 +1,51 @@
+//! Contract route definitions.
+//!
+//! This module defines the [`ContractRouter`], which maps contract HTTP
+//! endpoints to [`ContractController`] handler methods.
+
+use axum::{Router, extract::DefaultBodyLimit, routing::get};
+
+use crate::{
+    controllers::contract::{ContractController, MAX_CONTRACT_DOCUMENT_BYTES},
+    routes::app::AppState,
+};
+
+/// Router for contract endpoints.
+pub struct ContractRouter;
+
+impl ContractRouter {
+    /// Creates a [`Router`] with all contract routes.
+    ///
+    /// Registers the following endpoints under the `/contracts` prefix:
+    ///
+    /// - `POST /` — Create a contract with a company.
+    /// - `GET /` — List contracts, optionally filtered by company.
+    /// - `GET /{id}` — Get a contract.
+    /// - `PUT /{id}` — Update a contract.
+    /// - `DELETE /{id}` — Delete a contract.
+    /// - `PUT /{id}/document` — Upload the signed document.
+    /// - `GET /{id}/document` — Download the signed document.
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with all contract routes registered.
+    pub fn new() -> Router<AppState> {
+        Router::new()
+            .route(
+                "/",
+                get(ContractController::list).post(ContractController::create),
+            )
+            .route(
+                "/{id}",
+                get(ContractController::show)
+                    .put(ContractController::update)
+                    .delete(ContractController::delete),
+            )
+            .route(
+                "/{id}/document",
+                get(ContractController::download_document)
+                    .put(ContractController::upload_document)
+                    .layer(DefaultBodyLimit::max(MAX_CONTRACT_DOCUMENT_BYTES)),
+            )
+    }
+}
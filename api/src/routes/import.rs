@@ -0,0 +1,35 @@
+//! CSV import route definitions.
+//!
+//! This module defines the [`ImportRouter`], which maps CSV import HTTP
+//! endpoints to [`ImportController`] handler methods.
+
+use axum::{Router, extract::DefaultBodyLimit, routing::post};
+
+use crate::{
+    controllers::import::ImportController, import::MAX_IMPORT_BYTES, routes::app::AppState,
+};
+
+/// Router for CSV import endpoints.
+pub struct ImportRouter;
+
+impl ImportRouter {
+    /// Creates a [`Router`] with all CSV import routes.
+    ///
+    /// Registers the following endpoints under the `/import` prefix:
+    ///
+    /// - `POST /payments` — Import payments from a CSV file.
+    /// - `POST /work-sessions` — Import completed work sessions from a CSV
+    ///   file.
+    ///
+    /// Uploads larger than [`MAX_IMPORT_BYTES`] are rejected.
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with all CSV import routes registered.
+    pub fn new() -> Router<AppState> {
+        Router::new()
+            .route("/payments", post(ImportController::payments))
+            .route("/work-sessions", post(ImportController::work_sessions))
+            .layer(DefaultBodyLimit::max(MAX_IMPORT_BYTES))
+    }
+}
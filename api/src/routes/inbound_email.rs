@@ -0,0 +1,26 @@
+//! Inbound email route definitions.
+//!
+//! This module defines the [`InboundEmailRouter`], which maps the email
+//! provider's inbound webhook to [`InboundEmailController`] handler methods.
+
+use axum::{Router, routing::post};
+
+use crate::{controllers::inbound_email::InboundEmailController, routes::app::AppState};
+
+/// Router for inbound email endpoints.
+pub struct InboundEmailRouter;
+
+impl InboundEmailRouter {
+    /// Creates a [`Router`] with all inbound email routes.
+    ///
+    /// Registers the following endpoints under the `/inbound-email` prefix:
+    ///
+    /// - `POST /` — Receive an inbound email (webhook token).
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with all inbound email routes registered.
+    pub fn new() -> Router<AppState> {
+        Router::new().route("/", post(InboundEmailController::receive))
+    }
+}
@@ -0,0 +1,47 @@
+//! Invoice route definitions.
+//!
+//! This module defines the [`InvoiceRouter`], which maps invoice HTTP
+//! endpoints to [`InvoiceController`] handler methods.
+
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::{controllers::invoice::InvoiceController, routes::app::AppState};
+
+/// Router for invoice endpoints.
+pub struct InvoiceRouter;
+
+impl InvoiceRouter {
+    /// Creates a [`Router`] with all invoice routes.
+    ///
+    /// Registers the following endpoints under the `/invoices` prefix:
+    ///
+    /// - `POST /` — Bill a company's unbilled work onto a draft invoice.
+    /// - `GET /` — List invoices, optionally filtered by company and status.
+    /// - `GET /{id}` — Get an invoice with its line items.
+    /// - `PATCH /{id}` — Partially update a draft invoice.
+    /// - `DELETE /{id}` — Delete a draft invoice.
+    /// - `POST /{id}/finalize` — Finalize a draft and assign its number.
+    /// - `GET /{id}/pdf` — Download the invoice as a PDF.
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with all invoice routes registered.
+    pub fn new() -> Router<AppState> {
+        Router::new()
+            .route(
+                "/",
+                get(InvoiceController::list).post(InvoiceController::create),
+            )
+            .route(
+                "/{id}",
+                get(InvoiceController::show)
+                    .patch(InvoiceController::update)
+                    .delete(InvoiceController::delete),
+            )
+            .route("/{id}/finalize", post(InvoiceController::finalize))
+            .route("/{id}/pdf", get(InvoiceController::pdf))
+    }
+}
@@ -0,0 +1,50 @@
+//! Job route definitions.
+//!
+//! This module defines the [`JobRouter`], which maps job-scoped HTTP
+//! endpoints to [`JobController`] handler methods.
+
+use axum::{
+    Router,
+    routing::{get, patch, post},
+};
+
+use crate::{controllers::job::JobController, routes::app::AppState};
+
+/// Router for job endpoints.
+pub struct JobRouter;
+
+impl JobRouter {
+    /// Creates a [`Router`] with all job routes.
+    ///
+    /// Registers the following endpoints under the `/jobs` prefix:
+    ///
+    /// - `GET /board` — Get jobs grouped into board columns.
+    /// - `PATCH /reorder` — Move jobs between board columns and positions.
+    /// - `GET /{id}` — Get a job with its budgets, optionally limited to
+    ///   `?fields=` and expanded with `?include=company`.
+    /// - `PATCH /{id}` — Partially update a job.
+    /// - `POST /{id}/duplicate` — Copy a job's payment terms into a new job.
+    /// - `GET /{id}/work-sessions` — List a job's work sessions, optionally
+    ///   limited to those tagged `?tag=`.
+    /// - `POST /{id}/convert-payment-type` — Switch a job between hourly and
+    ///   payouts, optionally creating installments from accrued hours.
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with all job routes registered.
+    pub fn new() -> Router<AppState> {
+        Router::new()
+            .route("/board", get(JobController::board))
+            .route("/reorder", patch(JobController::reorder))
+            .route(
+                "/{id}",
+                get(JobController::show).patch(JobController::update),
+            )
+            .route("/{id}/duplicate", post(JobController::duplicate))
+            .route("/{id}/work-sessions", get(JobController::work_sessions))
+            .route(
+                "/{id}/convert-payment-type",
+                post(JobController::convert_payment_type),
+            )
+    }
+}
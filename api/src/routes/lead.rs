@@ -0,0 +1,44 @@
+//! Lead route definitions.
+//!
+//! This module defines the [`LeadRouter`], which maps lead HTTP endpoints
+//! to [`LeadController`] handler methods.
+
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::{controllers::lead::LeadController, routes::app::AppState};
+
+/// Router for lead endpoints.
+pub struct LeadRouter;
+
+impl LeadRouter {
+    /// Creates a [`Router`] with all lead routes.
+    ///
+    /// Registers the following endpoints under the `/leads` prefix:
+    ///
+    /// - `POST /` — Create a lead.
+    /// - `GET /` — List leads, optionally by status.
+    /// - `GET /stats` — Get pipeline and conversion statistics.
+    /// - `GET /{id}` — Get a lead.
+    /// - `PUT /{id}` — Update a lead.
+    /// - `DELETE /{id}` — Delete a lead.
+    /// - `POST /{id}/convert` — Convert a lead into a company and job.
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with all lead routes registered.
+    pub fn new() -> Router<AppState> {
+        Router::new()
+            .route("/", get(LeadController::list).post(LeadController::create))
+            .route("/stats", get(LeadController::stats))
+            .route(
+                "/{id}",
+                get(LeadController::show)
+                    .put(LeadController::update)
+                    .delete(LeadController::delete),
+            )
+            .route("/{id}/convert", post(LeadController::convert))
+    }
+}
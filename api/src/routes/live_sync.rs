@@ -0,0 +1,26 @@
+//! Live sync route definitions.
+//!
+//! This module defines the [`LiveSyncRouter`], which exposes the WebSocket
+//! endpoint that pushes domain events to the web client.
+
+use axum::{Router, routing::get};
+
+use crate::controllers::live_sync::LiveSyncController;
+use crate::routes::app::AppState;
+
+/// Router for live sync endpoints.
+pub struct LiveSyncRouter;
+
+impl LiveSyncRouter {
+    /// Creates a [`Router`] with the live sync route.
+    ///
+    /// Registers `GET /` mapped to
+    /// [`LiveSyncController::connect`](crate::controllers::live_sync::LiveSyncController::connect).
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with the live sync route registered.
+    pub fn new() -> Router<AppState> {
+        Router::new().route("/", get(LiveSyncController::connect))
+    }
+}
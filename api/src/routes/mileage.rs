@@ -0,0 +1,70 @@
+//! Mileage route definitions.
+//!
+//! This module defines the [`MileageRouter`], which maps mileage HTTP
+//! endpoints to [`MileageController`] handler methods.
+
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::{controllers::mileage::MileageController, routes::app::AppState};
+
+/// Router for mileage endpoints.
+pub struct MileageRouter;
+
+impl MileageRouter {
+    /// Creates a [`Router`] with all mileage routes.
+    ///
+    /// Registers the following endpoints under the `/mileage` prefix:
+    ///
+    /// - `POST /` — Log a trip.
+    /// - `GET /` — List trips, optionally filtered by `?from=`, `?to=`, and
+    ///   `?job_id=`.
+    /// - `GET /report` — Total miles and deductions, overall and per job.
+    /// - `GET /rate` — Get the mileage rate applied to the user's trips.
+    /// - `PUT /rate` — Set or clear the user's own mileage rate.
+    /// - `GET /home-base` — Get the home base trips start from.
+    /// - `PUT /home-base` — Set or clear the home base.
+    /// - `GET /suggestions` — List pending trips suggested from work sessions.
+    /// - `POST /suggestions/{id}/accept` — Log a suggested trip.
+    /// - `POST /suggestions/{id}/dismiss` — Dismiss a suggested trip.
+    /// - `GET /{id}` — Get a trip.
+    /// - `PATCH /{id}` — Partially update a trip.
+    /// - `DELETE /{id}` — Delete a trip.
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with all mileage routes registered.
+    pub fn new() -> Router<AppState> {
+        Router::new()
+            .route(
+                "/",
+                get(MileageController::list).post(MileageController::create),
+            )
+            .route("/report", get(MileageController::report))
+            .route(
+                "/rate",
+                get(MileageController::show_rate).put(MileageController::update_rate),
+            )
+            .route(
+                "/home-base",
+                get(MileageController::show_home_base).put(MileageController::update_home_base),
+            )
+            .route("/suggestions", get(MileageController::list_suggestions))
+            .route(
+                "/suggestions/{id}/accept",
+                post(MileageController::accept_suggestion),
+            )
+            .route(
+                "/suggestions/{id}/dismiss",
+                post(MileageController::dismiss_suggestion),
+            )
+            .route(
+                "/{id}",
+                get(MileageController::show)
+                    .patch(MileageController::update)
+                    .delete(MileageController::delete),
+            )
+    }
+}
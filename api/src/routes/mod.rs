@@ -7,10 +7,68 @@
 //!
 //! # Modules
 //!
+//! - [`account_export`](crate::routes::account_export) — Full account data export routes.
 //! - [`app`](crate::routes::app) — Application router, shared state, and middleware configuration.
+//! - [`appearance`](crate::routes::appearance) — Appearance and palette routes.
 //! - [`auth`](crate::routes::auth) — Authentication and account management routes.
+//! - [`budget`](crate::routes::budget) — Monthly budget routes.
+//! - [`calendar`](crate::routes::calendar) — Calendar feed routes.
+//! - [`company`](crate::routes::company) — Company-scoped routes.
+//! - [`contact`](crate::routes::contact) — Company contact routes.
+//! - [`contract`](crate::routes::contract) — Contract routes.
 //! - [`health`](crate::routes::health) — Health check routes.
+//! - [`import`](crate::routes::import) — CSV import routes.
+//! - [`inbound_email`](crate::routes::inbound_email) — Inbound email webhook routes.
+//! - [`invoice`](crate::routes::invoice) — Invoice routes.
+//! - [`job`](crate::routes::job) — Job-scoped routes.
+//! - [`lead`](crate::routes::lead) — Lead routes.
+//! - [`live_sync`](crate::routes::live_sync) — Live sync WebSocket routes.
+//! - [`mileage`](crate::routes::mileage) — Mileage routes.
+//! - [`onboarding`](crate::routes::onboarding) — Onboarding wizard routes.
+//! - [`payment`](crate::routes::payment) — Payment routes.
+//! - [`personal_access_token`](crate::routes::personal_access_token) — Personal access token routes.
+//! - [`receipt`](crate::routes::receipt) — Public payment receipt routes.
+//! - [`recurring_payment`](crate::routes::recurring_payment) — Recurring payment routes.
+//! - [`report`](crate::routes::report) — Time reporting reconciliation, earnings report, and tax estimate routes.
+//! - [`search`](crate::routes::search) — Full-text search routes.
+//! - [`subscription`](crate::routes::subscription) — Subscription and Stripe webhook routes.
+//! - [`time_off`](crate::routes::time_off) — Time-off and availability routes.
+//! - [`timesheet`](crate::routes::timesheet) — Timesheet sharing and client approval routes.
+//! - [`toggl`](crate::routes::toggl) — Toggl Track API compatible routes.
+//! - [`webhook`](crate::routes::webhook) — Outgoing webhook routes.
+//! - [`work_session`](crate::routes::work_session) — Work session timer routes.
+//! - [`work_session_draft`](crate::routes::work_session_draft) — Email-logged work session draft routes.
 
+#![allow(clippy::new_ret_no_self)]
+
+pub mod account_export;
 pub mod app;
+pub mod appearance;
 pub mod auth;
+pub mod budget;
+pub mod calendar;
+pub mod company;
+pub mod contact;
+pub mod contract;
 pub mod health;
+pub mod import;
+pub mod inbound_email;
+pub mod invoice;
+pub mod job;
+pub mod lead;
+pub mod live_sync;
+pub mod mileage;
+pub mod onboarding;
+pub mod payment;
+pub mod personal_access_token;
+pub mod receipt;
+pub mod recurring_payment;
+pub mod report;
+pub mod search;
+pub mod subscription;
+pub mod time_off;
+pub mod timesheet;
+pub mod toggl;
+pub mod webhook;
+pub mod work_session;
+pub mod work_session_draft;
@@ -0,0 +1,27 @@
+//! Onboarding route definitions.
+//!
+//! This module defines the [`OnboardingRouter`], which maps onboarding HTTP
+//! endpoints to [`OnboardingController`] handler methods.
+
+use axum::{Router, routing::post};
+
+use crate::{controllers::onboarding::OnboardingController, routes::app::AppState};
+
+/// Router for onboarding endpoints.
+pub struct OnboardingRouter;
+
+impl OnboardingRouter {
+    /// Creates a [`Router`] with all onboarding routes.
+    ///
+    /// Registers the following endpoints under the `/onboarding` prefix:
+    ///
+    /// - `POST /` — Create a company, job, and optional first payment and
+    ///   work session.
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with all onboarding routes registered.
+    pub fn new() -> Router<AppState> {
+        Router::new().route("/", post(OnboardingController::create))
+    }
+}
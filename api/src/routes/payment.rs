@@ -0,0 +1,69 @@
+//! Payment route definitions.
+//!
+//! This module defines the [`PaymentRouter`], which maps payment HTTP
+//! endpoints to [`PaymentController`] handler methods.
+
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::{controllers::payment::PaymentController, routes::app::AppState};
+
+/// Router for payment endpoints.
+pub struct PaymentRouter;
+
+impl PaymentRouter {
+    /// Creates a [`Router`] with all payment routes.
+    ///
+    /// Registers the following endpoints under the `/payments` prefix:
+    ///
+    /// - `GET /` — List payments, filtered by `?company_id=`,
+    ///   `?payout_type=`, `?payment_received=`, `?transfer_initiated=`,
+    ///   `?date_from=`, and `?date_to=`, sorted by `?sort=`, and paged by
+    ///   `?page=` and `?per_page=`.
+    /// - `GET /overdue` — List overdue payments with totals per company.
+    /// - `GET /reminders` — Get whether payment reminders are emailed.
+    /// - `PUT /reminders` — Turn payment reminder emails on or off.
+    /// - `GET /reconciliation` — Reconcile a month's expected, transferred,
+    ///   and received payments.
+    /// - `GET /export.csv` — Export payments as CSV, limited by `?from=` and
+    ///   `?to=`.
+    /// - `GET /{id}` — Get a payment, optionally limited to `?fields=` and
+    ///   expanded with `?include=company`.
+    /// - `PATCH /{id}` — Partially update a payment.
+    /// - `POST /{id}/installments` — Record an installment toward a payment.
+    /// - `POST /{id}/receipt-link` — Create a public receipt link.
+    /// - `GET /{id}/match-suggestions` — Suggest expected payments a received payment settles.
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with all payment routes registered.
+    pub fn new() -> Router<AppState> {
+        Router::new()
+            .route("/", get(PaymentController::list))
+            .route("/overdue", get(PaymentController::overdue))
+            .route(
+                "/reminders",
+                get(PaymentController::show_reminders).put(PaymentController::update_reminders),
+            )
+            .route("/reconciliation", get(PaymentController::reconciliation))
+            .route("/export.csv", get(PaymentController::export))
+            .route(
+                "/{id}",
+                get(PaymentController::show).patch(PaymentController::update),
+            )
+            .route(
+                "/{id}/installments",
+                post(PaymentController::create_installment),
+            )
+            .route(
+                "/{id}/receipt-link",
+                post(PaymentController::create_receipt_link),
+            )
+            .route(
+                "/{id}/match-suggestions",
+                get(PaymentController::match_suggestions),
+            )
+    }
+}
@@ -0,0 +1,41 @@
+//! Personal access token route definitions.
+//!
+//! This module defines the [`PersonalAccessTokenRouter`], which maps
+//! personal access token HTTP endpoints to
+//! [`PersonalAccessTokenController`] handler methods.
+
+use axum::{
+    Router,
+    routing::{delete, get},
+};
+
+use crate::{
+    controllers::personal_access_token::PersonalAccessTokenController, routes::app::AppState,
+};
+
+/// Router for personal access token endpoints.
+pub struct PersonalAccessTokenRouter;
+
+impl PersonalAccessTokenRouter {
+    /// Creates a [`Router`] with all personal access token routes.
+    ///
+    /// Registers the following endpoints under the `/personal-access-tokens`
+    /// prefix:
+    ///
+    /// - `POST /` — Issue a scoped token.
+    /// - `GET /` — List the user's tokens.
+    /// - `DELETE /{id}` — Revoke a token.
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with all personal access token routes registered.
+    pub fn new() -> Router<AppState> {
+        Router::new()
+            .route(
+                "/",
+                get(PersonalAccessTokenController::list)
+                    .post(PersonalAccessTokenController::create),
+            )
+            .route("/{id}", delete(PersonalAccessTokenController::delete))
+    }
+}
@@ -0,0 +1,26 @@
+//! Public receipt route definitions.
+//!
+//! This module defines the [`ReceiptRouter`], which maps public receipt
+//! links to [`ReceiptController`] handler methods.
+
+use axum::{Router, routing::get};
+
+use crate::{controllers::receipt::ReceiptController, routes::app::AppState};
+
+/// Router for public receipt endpoints.
+pub struct ReceiptRouter;
+
+impl ReceiptRouter {
+    /// Creates a [`Router`] with all public receipt routes.
+    ///
+    /// Registers the following endpoints under the `/p` prefix:
+    ///
+    /// - `GET /{token}` — Render a signed payment receipt (public).
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with all public receipt routes registered.
+    pub fn new() -> Router<AppState> {
+        Router::new().route("/{token}", get(ReceiptController::show))
+    }
+}
@@ -0,0 +1,44 @@
+//! Recurring payment route definitions.
+//!
+//! This module defines the [`RecurringPaymentRouter`], which maps recurring
+//! payment HTTP endpoints to [`RecurringPaymentController`] handler methods.
+
+use axum::{Router, routing::get};
+
+use crate::{controllers::recurring_payment::RecurringPaymentController, routes::app::AppState};
+
+/// Router for recurring payment endpoints.
+pub struct RecurringPaymentRouter;
+
+impl RecurringPaymentRouter {
+    /// Creates a [`Router`] with all recurring payment routes.
+    ///
+    /// Registers the following endpoints under the `/recurring-payments`
+    /// prefix:
+    ///
+    /// - `POST /` — Schedule a recurring payment.
+    /// - `GET /` — List recurring payments.
+    /// - `GET /{id}` — Get a recurring payment.
+    /// - `PATCH /{id}` — Partially update a recurring payment.
+    /// - `DELETE /{id}` — Delete a recurring payment.
+    /// - `GET /{id}/upcoming` — Preview the next occurrences, optionally
+    ///   limited by `?count=`.
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with all recurring payment routes registered.
+    pub fn new() -> Router<AppState> {
+        Router::new()
+            .route(
+                "/",
+                get(RecurringPaymentController::list).post(RecurringPaymentController::create),
+            )
+            .route(
+                "/{id}",
+                get(RecurringPaymentController::show)
+                    .patch(RecurringPaymentController::update)
+                    .delete(RecurringPaymentController::delete),
+            )
+            .route("/{id}/upcoming", get(RecurringPaymentController::upcoming))
+    }
+}
@@ -0,0 +1,51 @@
+//! Report route definitions.
+//!
+//! This module defines the [`ReportRouter`], which maps report HTTP
+//! endpoints to [`ReportController`] handler methods.
+
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::{controllers::report::ReportController, routes::app::AppState};
+
+/// Router for report endpoints.
+pub struct ReportRouter;
+
+impl ReportRouter {
+    /// Creates a [`Router`] with all report routes.
+    ///
+    /// Registers the following endpoints under the `/reports` prefix:
+    ///
+    /// - `GET /unreported-time` — List unreported work grouped by company
+    ///   and week.
+    /// - `POST /unreported-time/report` — Report the time of completed
+    ///   sessions.
+    /// - `GET /earnings` — Total earnings per week or month, selected by
+    ///   `?period=`, `?from=`, and `?to=`.
+    /// - `GET /tax-estimate` — Estimated tax owed, covered, and short per
+    ///   company for the quarter selected by `?year=` and `?quarter=`.
+    /// - `GET /tax-rate` — Get the self-employment tax rate applied to
+    ///   estimates.
+    /// - `PUT /tax-rate` — Set or clear the user's own self-employment tax
+    ///   rate.
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with all report routes registered.
+    pub fn new() -> Router<AppState> {
+        Router::new()
+            .route("/unreported-time", get(ReportController::unreported_time))
+            .route(
+                "/unreported-time/report",
+                post(ReportController::report_time),
+            )
+            .route("/earnings", get(ReportController::earnings))
+            .route("/tax-estimate", get(ReportController::tax_estimate))
+            .route(
+                "/tax-rate",
+                get(ReportController::show_tax_rate).put(ReportController::update_tax_rate),
+            )
+    }
+}
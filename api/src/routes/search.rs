@@ -0,0 +1,26 @@
+//! Search route definitions.
+//!
+//! This module defines the [`SearchRouter`], which maps the search endpoint
+//! to [`SearchController`] handler methods.
+
+use axum::{Router, routing::get};
+
+use crate::{controllers::search::SearchController, routes::app::AppState};
+
+/// Router for search endpoints.
+pub struct SearchRouter;
+
+impl SearchRouter {
+    /// Creates a [`Router`] with the search route.
+    ///
+    /// Registers the following endpoint under the `/search` prefix:
+    ///
+    /// - `GET /` — Search company names and job titles with `?q=`.
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with the search route registered.
+    pub fn new() -> Router<AppState> {
+        Router::new().route("/", get(SearchController::search))
+    }
+}
@@ -0,0 +1,34 @@
+//! Subscription route definitions.
+//!
+//! This module defines the [`SubscriptionRouter`], which maps subscription
+//! HTTP endpoints to [`SubscriptionController`] handler methods.
+
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::{controllers::subscription::SubscriptionController, routes::app::AppState};
+
+/// Router for subscription endpoints.
+pub struct SubscriptionRouter;
+
+impl SubscriptionRouter {
+    /// Creates a [`Router`] with all subscription routes.
+    ///
+    /// Registers the following endpoints under the `/subscriptions` prefix:
+    ///
+    /// - `GET /me` — Get the user's subscription.
+    /// - `POST /checkout` — Start a Stripe Checkout session.
+    /// - `POST /webhook` — Receive a Stripe webhook event (signed payload).
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with all subscription routes registered.
+    pub fn new() -> Router<AppState> {
+        Router::new()
+            .route("/me", get(SubscriptionController::show))
+            .route("/checkout", post(SubscriptionController::checkout))
+            .route("/webhook", post(SubscriptionController::webhook))
+    }
+}
@@ -0,0 +1,38 @@
+//! Time-off route definitions.
+//!
+//! This module defines the [`TimeOffRouter`], which maps time-off and
+//! availability HTTP endpoints to [`TimeOffController`] handler methods.
+
+use axum::{
+    Router,
+    routing::{delete, get},
+};
+
+use crate::{controllers::time_off::TimeOffController, routes::app::AppState};
+
+/// Router for time-off endpoints.
+pub struct TimeOffRouter;
+
+impl TimeOffRouter {
+    /// Creates a [`Router`] with all time-off routes.
+    ///
+    /// Registers the following endpoints under the `/time-off` prefix:
+    ///
+    /// - `POST /` — Schedule time off.
+    /// - `GET /` — List time off, optionally within a date range.
+    /// - `GET /week` — Get the availability view for a week.
+    /// - `DELETE /{id}` — Remove scheduled time off.
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with all time-off routes registered.
+    pub fn new() -> Router<AppState> {
+        Router::new()
+            .route(
+                "/",
+                get(TimeOffController::list).post(TimeOffController::create),
+            )
+            .route("/week", get(TimeOffController::week))
+            .route("/{id}", delete(TimeOffController::delete))
+    }
+}
@@ -0,0 +1,47 @@
+//! Timesheet approval route definitions.
+//!
+//! This module defines the [`TimesheetRouter`], which maps timesheet
+//! sharing and client review endpoints to [`TimesheetController`]
+//! handler methods.
+
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::{controllers::timesheet::TimesheetController, routes::app::AppState};
+
+/// Router for timesheet approval endpoints.
+pub struct TimesheetRouter;
+
+impl TimesheetRouter {
+    /// Creates a [`Router`] with all timesheet approval routes.
+    ///
+    /// Registers the following endpoints under the `/timesheets` prefix:
+    ///
+    /// - `POST /approvals` — Create a shareable approval link.
+    /// - `GET /approvals` — List the user's approval requests.
+    /// - `GET /shared/{token}` — View a shared timesheet (public).
+    /// - `POST /shared/{token}/approve` — Approve a shared timesheet (public).
+    /// - `POST /shared/{token}/dispute` — Dispute a shared timesheet (public).
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with all timesheet approval routes registered.
+    pub fn new() -> Router<AppState> {
+        Router::new()
+            .route(
+                "/approvals",
+                post(TimesheetController::create_approval).get(TimesheetController::list_approvals),
+            )
+            .route("/shared/{token}", get(TimesheetController::view_shared))
+            .route(
+                "/shared/{token}/approve",
+                post(TimesheetController::approve_shared),
+            )
+            .route(
+                "/shared/{token}/dispute",
+                post(TimesheetController::dispute_shared),
+            )
+    }
+}
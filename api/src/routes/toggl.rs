@@ -0,0 +1,52 @@
+//! Toggl-compatible route definitions.
+//!
+//! This module defines the [`TogglRouter`], which maps the supported subset
+//! of Toggl Track API v9 endpoints to [`TogglController`] handler methods.
+
+use axum::{
+    Router,
+    routing::{delete, get, patch, post},
+};
+
+use crate::{controllers::toggl::TogglController, routes::app::AppState};
+
+/// Router for Toggl-compatible endpoints.
+pub struct TogglRouter;
+
+impl TogglRouter {
+    /// Creates a [`Router`] with all Toggl-compatible routes.
+    ///
+    /// Registers the following endpoints under the `/api/v9` prefix:
+    ///
+    /// - `GET /me/time_entries` — List time entries.
+    /// - `GET /me/time_entries/current` — Get the running time entry.
+    /// - `GET /me/projects` — List jobs as projects.
+    /// - `POST /workspaces/{workspace_id}/time_entries` — Create a time entry.
+    /// - `PATCH /workspaces/{workspace_id}/time_entries/{time_entry_id}/stop` — Stop a time entry.
+    /// - `DELETE /workspaces/{workspace_id}/time_entries/{time_entry_id}` — Delete a time entry.
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with all Toggl-compatible routes registered.
+    pub fn new() -> Router<AppState> {
+        Router::new()
+            .route("/me/time_entries", get(TogglController::list_entries))
+            .route(
+                "/me/time_entries/current",
+                get(TogglController::current_entry),
+            )
+            .route("/me/projects", get(TogglController::list_projects))
+            .route(
+                "/workspaces/{workspace_id}/time_entries",
+                post(TogglController::create_entry),
+            )
+            .route(
+                "/workspaces/{workspace_id}/time_entries/{time_entry_id}/stop",
+                patch(TogglController::stop_entry),
+            )
+            .route(
+                "/workspaces/{workspace_id}/time_entries/{time_entry_id}",
+                delete(TogglController::delete_entry),
+            )
+    }
+}
@@ -0,0 +1,40 @@
+//! Webhook route definitions.
+//!
+//! This module defines the [`WebhookRouter`], which maps webhook HTTP
+//! endpoints to [`WebhookController`] handler methods.
+
+use axum::{
+    Router,
+    routing::{delete, get, post},
+};
+
+use crate::{controllers::webhook::WebhookController, routes::app::AppState};
+
+/// Router for webhook endpoints.
+pub struct WebhookRouter;
+
+impl WebhookRouter {
+    /// Creates a [`Router`] with all webhook routes.
+    ///
+    /// Registers the following endpoints under the `/webhooks` prefix:
+    ///
+    /// - `POST /` — Register a webhook.
+    /// - `GET /` — List the user's webhooks.
+    /// - `DELETE /{id}` — Delete a webhook.
+    /// - `GET /{id}/deliveries` — Read a webhook's delivery log.
+    /// - `POST /{id}/test` — Send a test delivery to a webhook.
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with all webhook routes registered.
+    pub fn new() -> Router<AppState> {
+        Router::new()
+            .route(
+                "/",
+                get(WebhookController::list).post(WebhookController::create),
+            )
+            .route("/{id}", delete(WebhookController::delete))
+            .route("/{id}/deliveries", get(WebhookController::deliveries))
+            .route("/{id}/test", post(WebhookController::test))
+    }
+}
@@ -0,0 +1,94 @@
+//! Work session route definitions.
+//!
+//! This module defines the [`WorkSessionRouter`], which maps work session
+//! HTTP endpoints to [`WorkSessionController`] and
+//! [`WorkSessionAttachmentController`] handler methods.
+
+use axum::{
+    Router,
+    extract::DefaultBodyLimit,
+    routing::{get, post},
+};
+
+use crate::{
+    controllers::{
+        work_session::WorkSessionController,
+        work_session_attachment::{
+            MAX_WORK_SESSION_ATTACHMENT_BYTES, WorkSessionAttachmentController,
+        },
+    },
+    routes::app::AppState,
+};
+
+/// Router for work session endpoints.
+pub struct WorkSessionRouter;
+
+impl WorkSessionRouter {
+    /// Creates a [`Router`] with all work session routes.
+    ///
+    /// Registers the following endpoints under the `/work-sessions` prefix:
+    ///
+    /// - `GET /` — List completed sessions with per-day totals, limited by
+    ///   `?from=`, `?to=`, `?job_id=`, and `?company_id=`.
+    /// - `POST /` — Start a work session, or log one with explicit times.
+    /// - `GET /active/wait` — Wait for the active session to change, up to
+    ///   `?timeout=` seconds.
+    /// - `GET /export.csv` — Export completed sessions as CSV, limited by
+    ///   `?from=` and `?to=`.
+    /// - `GET /location-tracking` — Get whether session locations are
+    ///   recorded.
+    /// - `PUT /location-tracking` — Turn session location recording on or
+    ///   off.
+    /// - `GET /{id}` — Get a work session.
+    /// - `PUT /{id}` — Correct a completed session's times.
+    /// - `PATCH /{id}` — Update a session's notes and tags.
+    /// - `DELETE /{id}` — Delete a completed session.
+    /// - `POST /{id}/pause` — Pause an active session.
+    /// - `POST /{id}/resume` — Resume a paused session.
+    /// - `POST /{id}/complete` — Complete a session.
+    /// - `POST /{id}/discard` — Discard an active session.
+    /// - `GET /{id}/attachments` — List a session's photos and voice memos.
+    /// - `POST /{id}/attachments` — Attach a photo or voice memo.
+    /// - `GET /{id}/attachments/{attachment_id}` — Download an attachment.
+    /// - `DELETE /{id}/attachments/{attachment_id}` — Delete an attachment.
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with all work session routes registered.
+    pub fn new() -> Router<AppState> {
+        Router::new()
+            .route(
+                "/",
+                get(WorkSessionController::list).post(WorkSessionController::start),
+            )
+            .route("/active/wait", get(WorkSessionController::wait_for_active))
+            .route("/export.csv", get(WorkSessionController::export))
+            .route(
+                "/location-tracking",
+                get(WorkSessionController::show_location_tracking)
+                    .put(WorkSessionController::update_location_tracking),
+            )
+            .route(
+                "/{id}",
+                get(WorkSessionController::show)
+                    .put(WorkSessionController::update)
+                    .patch(WorkSessionController::update_details)
+                    .delete(WorkSessionController::delete),
+            )
+            .route("/{id}/pause", post(WorkSessionController::pause))
+            .route("/{id}/resume", post(WorkSessionController::resume))
+            .route("/{id}/complete", post(WorkSessionController::complete))
+            .route("/{id}/discard", post(WorkSessionController::discard))
+            .route(
+                "/{id}/attachments",
+                get(WorkSessionAttachmentController::list)
+                    .post(WorkSessionAttachmentController::upload)
+                    .layer(DefaultBodyLimit::max(MAX_WORK_SESSION_ATTACHMENT_BYTES)),
+            )
+            .route(
+                "/{id}/attachments/{attachment_id}",
+                get(WorkSessionAttachmentController::download)
+                    .delete(WorkSessionAttachmentController::delete),
+            )
+    }
+}
@@ -0,0 +1,36 @@
+//! Work session draft route definitions.
+//!
+//! This module defines the [`WorkSessionDraftRouter`], which maps work
+//! session draft HTTP endpoints to [`WorkSessionDraftController`] handler
+//! methods.
+
+use axum::{
+    Router,
+    routing::{delete, get, post},
+};
+
+use crate::{controllers::work_session_draft::WorkSessionDraftController, routes::app::AppState};
+
+/// Router for work session draft endpoints.
+pub struct WorkSessionDraftRouter;
+
+impl WorkSessionDraftRouter {
+    /// Creates a [`Router`] with all work session draft routes.
+    ///
+    /// Registers the following endpoints under the `/work-session-drafts`
+    /// prefix:
+    ///
+    /// - `GET /` — List pending drafts.
+    /// - `POST /{id}/confirm` — Confirm a draft into a work session.
+    /// - `DELETE /{id}` — Dismiss a draft.
+    ///
+    /// # Returns
+    ///
+    /// A [`Router<AppState>`] with all work session draft routes registered.
+    pub fn new() -> Router<AppState> {
+        Router::new()
+            .route("/", get(WorkSessionDraftController::list))
+            .route("/{id}", delete(WorkSessionDraftController::delete))
+            .route("/{id}/confirm", post(WorkSessionDraftController::confirm))
+    }
+}
@@ -0,0 +1,95 @@
+//! Offline driving distance estimates.
+//!
+//! Provides [`HaversineDistanceCalculator`], which scales the great-circle
+//! distance between two points by a typical ratio of road to straight-line
+//! distance, so no routing service is needed.
+
+use futures_util::future::{self, BoxFuture};
+use gig_log_common::models::work_session::GeoLocation;
+
+use crate::core::error::ApiResult;
+use crate::services::distance::DistanceCalculator;
+
+/// Mean radius of the Earth in miles.
+const EARTH_RADIUS_MILES: f64 = 3958.8;
+
+/// Typical ratio of driving distance to straight-line distance.
+const ROAD_DISTANCE_FACTOR: f64 = 1.2;
+
+/// Distance calculator using the haversine formula.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HaversineDistanceCalculator;
+
+impl HaversineDistanceCalculator {
+    /// Computes the great-circle distance between two points.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` — The first point.
+    /// * `to` — The second point.
+    ///
+    /// # Returns
+    ///
+    /// The straight-line distance in miles.
+    fn great_circle_miles(from: GeoLocation, to: GeoLocation) -> f64 {
+        let from_latitude = from.latitude.to_radians();
+        let to_latitude = to.latitude.to_radians();
+        let latitude_delta = (to.latitude - from.latitude).to_radians();
+        let longitude_delta = (to.longitude - from.longitude).to_radians();
+
+        let haversine = (latitude_delta / 2.0).sin().powi(2)
+            + from_latitude.cos() * to_latitude.cos() * (longitude_delta / 2.0).sin().powi(2);
+
+        2.0 * EARTH_RADIUS_MILES * haversine.sqrt().min(1.0).asin()
+    }
+}
+
+impl DistanceCalculator for HaversineDistanceCalculator {
+    fn driving_miles(&self, from: GeoLocation, to: GeoLocation) -> BoxFuture<'_, ApiResult<f64>> {
+        Box::pin(future::ready(Ok(
+            Self::great_circle_miles(from, to) * ROAD_DISTANCE_FACTOR
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NEW_YORK: GeoLocation = GeoLocation {
+        latitude: 40.7128,
+        longitude: -74.0060,
+    };
+
+    const PHILADELPHIA: GeoLocation = GeoLocation {
+        latitude: 39.9526,
+        longitude: -75.1652,
+    };
+
+    #[test]
+    fn computes_great_circle_distance() {
+        let miles = HaversineDistanceCalculator::great_circle_miles(NEW_YORK, PHILADELPHIA);
+
+        assert!((miles - 80.6).abs() < 0.5, "got {miles}");
+    }
+
+    #[tokio::test]
+    async fn scales_driving_distance_by_road_factor() {
+        let miles = HaversineDistanceCalculator
+            .driving_miles(NEW_YORK, PHILADELPHIA)
+            .await
+            .unwrap();
+
+        assert!((miles - 96.7).abs() < 0.6, "got {miles}");
+    }
+
+    #[tokio::test]
+    async fn same_point_is_zero_miles() {
+        let miles = HaversineDistanceCalculator
+            .driving_miles(NEW_YORK, NEW_YORK)
+            .await
+            .unwrap();
+
+        assert_eq!(miles, 0.0);
+    }
+}
@@ -0,0 +1,42 @@
+//! Distance calculation between locations.
+//!
+//! Defines the [`DistanceCalculator`] trait used to estimate how far the
+//! user drove to a job site.
+//! [`HaversineDistanceCalculator`](crate::services::distance::haversine::HaversineDistanceCalculator)
+//! is the default implementation.
+//!
+//! # Modules
+//!
+//! - [`haversine`](crate::services::distance::haversine) — Offline estimate from the straight-line distance.
+
+pub mod haversine;
+
+use std::fmt::Debug;
+
+use futures_util::future::BoxFuture;
+use gig_log_common::models::work_session::GeoLocation;
+
+use crate::core::error::ApiResult;
+
+/// Estimates driving distances between locations.
+///
+/// Implementations are stored in
+/// [`AppState::distance_calculator`](crate::routes::app::AppState::distance_calculator).
+pub trait DistanceCalculator: Debug + Send + Sync {
+    /// Estimates the one-way driving distance between two locations.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` — Where the trip starts.
+    /// * `to` — Where the trip ends.
+    ///
+    /// # Returns
+    ///
+    /// The distance in miles.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`](crate::core::error::ApiErrorResponse)
+    /// if the distance cannot be calculated.
+    fn driving_miles(&self, from: GeoLocation, to: GeoLocation) -> BoxFuture<'_, ApiResult<f64>>;
+}
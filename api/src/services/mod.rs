@@ -0,0 +1,15 @@
+//! Swappable service implementations.
+//!
+//! Each service is defined by a trait whose implementation is chosen at
+//! startup and stored in [`AppState`](crate::routes::app::AppState), so it
+//! can be replaced without touching the handlers that use it.
+//!
+//! # Modules
+//!
+//! - [`distance`](crate::services::distance) — Driving distance estimates between locations.
+//! - [`oauth`](crate::services::oauth) — OAuth sign-in with external identity providers.
+//! - [`pdf`](crate::services::pdf) — PDF rendering of invoices.
+
+pub mod distance;
+pub mod oauth;
+pub mod pdf;
@@ -0,0 +1,252 @@
+//! Sign in with GitHub.
+//!
+//! Provides [`GitHubIdentityProvider`], which runs GitHub's OAuth app
+//! authorization code flow and reads the user's profile and primary
+//! verified email from the REST API.
+
+use futures_util::future::BoxFuture;
+use reqwest::{Client, Url, header};
+use serde::Deserialize;
+
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::services::oauth::{IdentityProvider, OAuthProfile, read_json};
+
+/// GitHub's authorization endpoint.
+const AUTHORIZE_URL: &str = "https://github.com/login/oauth/authorize";
+
+/// GitHub's token endpoint.
+const TOKEN_URL: &str = "https://github.com/login/oauth/access_token";
+
+/// GitHub REST API endpoint for the authenticated user.
+const USER_URL: &str = "https://api.github.com/user";
+
+/// GitHub REST API endpoint for the authenticated user's emails.
+const EMAILS_URL: &str = "https://api.github.com/user/emails";
+
+/// Scopes requested from GitHub.
+const SCOPES: &str = "read:user user:email";
+
+/// User agent sent to the GitHub API, which rejects requests without one.
+const USER_AGENT: &str = "GigLog";
+
+/// GitHub OAuth app client.
+#[derive(Debug, Clone)]
+pub struct GitHubIdentityProvider {
+    /// Underlying HTTP client used for API requests.
+    client: Client,
+    /// OAuth client ID of the GigLog app.
+    client_id: String,
+    /// OAuth client secret of the GigLog app.
+    client_secret: String,
+}
+
+/// Response of GitHub's token endpoint, which reports errors with a `200`
+/// status.
+#[derive(Debug, Deserialize)]
+struct GitHubToken {
+    /// Access token for the REST API.
+    access_token: Option<String>,
+    /// Error code, e.g. `"bad_verification_code"`.
+    error: Option<String>,
+}
+
+/// The authenticated GitHub user.
+#[derive(Debug, Deserialize)]
+struct GitHubUser {
+    /// GitHub's stable identifier for the user.
+    id: u64,
+    /// The user's handle, used when no name is set.
+    login: String,
+    /// The user's display name.
+    name: Option<String>,
+}
+
+/// An email address on the user's GitHub account.
+#[derive(Debug, Deserialize)]
+struct GitHubEmail {
+    /// The email address.
+    email: String,
+    /// Whether this is the account's primary address.
+    primary: bool,
+    /// Whether GitHub has verified the address.
+    verified: bool,
+}
+
+impl GitHubIdentityProvider {
+    /// Creates a new [`GitHubIdentityProvider`].
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` — The OAuth app's client ID.
+    /// * `client_secret` — The OAuth app's client secret.
+    ///
+    /// # Returns
+    ///
+    /// A configured [`GitHubIdentityProvider`].
+    pub fn new(client_id: &str, client_secret: &str) -> Self {
+        Self {
+            client: Client::new(),
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+        }
+    }
+
+    /// Builds a profile from the user and their email addresses.
+    ///
+    /// The display name is split at its last space into first and last
+    /// names.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` — The authenticated [`GitHubUser`].
+    /// * `emails` — The user's email addresses.
+    ///
+    /// # Returns
+    ///
+    /// The [`OAuthProfile`], with the primary address as the verified email
+    /// if GitHub has verified it.
+    fn profile(user: GitHubUser, emails: Vec<GitHubEmail>) -> OAuthProfile {
+        let verified_email = emails
+            .into_iter()
+            .find(|email| email.primary && email.verified)
+            .map(|email| email.email);
+        let name = user
+            .name
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty());
+        let (first_name, last_name) = match name {
+            Some(name) => match name.rsplit_once(' ') {
+                Some((first, last)) => (first.trim().to_string(), last.to_string()),
+                None => (name, String::new()),
+            },
+            None => (user.login, String::new()),
+        };
+
+        OAuthProfile {
+            subject: user.id.to_string(),
+            verified_email,
+            first_name,
+            last_name,
+        }
+    }
+}
+
+impl IdentityProvider for GitHubIdentityProvider {
+    fn authorize_url(&self, state: &str, redirect_uri: &str) -> String {
+        Url::parse_with_params(
+            AUTHORIZE_URL,
+            [
+                ("client_id", self.client_id.as_str()),
+                ("redirect_uri", redirect_uri),
+                ("scope", SCOPES),
+                ("state", state),
+            ],
+        )
+        .map(String::from)
+        .unwrap_or_default()
+    }
+
+    fn fetch_profile<'a>(
+        &'a self,
+        code: &'a str,
+        redirect_uri: &'a str,
+    ) -> BoxFuture<'a, ApiResult<OAuthProfile>> {
+        Box::pin(async move {
+            let token: GitHubToken = read_json(
+                self.client
+                    .post(TOKEN_URL)
+                    .header(header::ACCEPT, "application/json")
+                    .form(&[
+                        ("code", code),
+                        ("client_id", &self.client_id),
+                        ("client_secret", &self.client_secret),
+                        ("redirect_uri", redirect_uri),
+                    ])
+                    .send()
+                    .await,
+            )
+            .await?;
+
+            let access_token = token.access_token.ok_or_else(|| {
+                ApiErrorResponse::InternalServerError(format!(
+                    "GitHub rejected the authorization code: {}",
+                    token.error.unwrap_or_default()
+                ))
+            })?;
+
+            let user: GitHubUser = read_json(
+                self.client
+                    .get(USER_URL)
+                    .bearer_auth(&access_token)
+                    .header(header::USER_AGENT, USER_AGENT)
+                    .send()
+                    .await,
+            )
+            .await?;
+            let emails: Vec<GitHubEmail> = read_json(
+                self.client
+                    .get(EMAILS_URL)
+                    .bearer_auth(&access_token)
+                    .header(header::USER_AGENT, USER_AGENT)
+                    .send()
+                    .await,
+            )
+            .await?;
+
+            Ok(Self::profile(user, emails))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uses_primary_verified_email_and_splits_name() {
+        let user = GitHubUser {
+            id: 7,
+            login: "ada".to_string(),
+            name: Some("Ada King Lovelace".to_string()),
+        };
+        let emails = vec![
+            GitHubEmail {
+                email: "old@example.com".to_string(),
+                primary: false,
+                verified: true,
+            },
+            GitHubEmail {
+                email: "ada@example.com".to_string(),
+                primary: true,
+                verified: true,
+            },
+        ];
+
+        let profile = GitHubIdentityProvider::profile(user, emails);
+
+        assert_eq!(profile.subject, "7");
+        assert_eq!(profile.verified_email.as_deref(), Some("ada@example.com"));
+        assert_eq!(profile.first_name, "Ada King");
+        assert_eq!(profile.last_name, "Lovelace");
+    }
+
+    #[test]
+    fn falls_back_to_login_without_verified_primary_email() {
+        let user = GitHubUser {
+            id: 7,
+            login: "ada".to_string(),
+            name: None,
+        };
+        let emails = vec![GitHubEmail {
+            email: "ada@example.com".to_string(),
+            primary: true,
+            verified: false,
+        }];
+
+        let profile = GitHubIdentityProvider::profile(user, emails);
+
+        assert_eq!(profile.verified_email, None);
+        assert_eq!(profile.first_name, "ada");
+        assert_eq!(profile.last_name, "");
+    }
+}
@@ -0,0 +1,175 @@
+//! Sign in with Google.
+//!
+//! Provides [`GoogleIdentityProvider`], which runs Google's OAuth 2.0
+//! authorization code flow and reads the user's profile from its OpenID
+//! Connect userinfo endpoint.
+
+use futures_util::future::BoxFuture;
+use reqwest::{Client, Url};
+use serde::Deserialize;
+
+use crate::core::error::ApiResult;
+use crate::services::oauth::{IdentityProvider, OAuthProfile, read_json};
+
+/// Google's authorization endpoint.
+const AUTHORIZE_URL: &str = "https://accounts.google.com/o/oauth2/v2/auth";
+
+/// Google's token endpoint.
+const TOKEN_URL: &str = "https://oauth2.googleapis.com/token";
+
+/// Google's OpenID Connect userinfo endpoint.
+const USERINFO_URL: &str = "https://openidconnect.googleapis.com/v1/userinfo";
+
+/// Scopes requested from Google.
+const SCOPES: &str = "openid email profile";
+
+/// Google OAuth 2.0 client.
+#[derive(Debug, Clone)]
+pub struct GoogleIdentityProvider {
+    /// Underlying HTTP client used for API requests.
+    client: Client,
+    /// OAuth client ID of the GigLog app.
+    client_id: String,
+    /// OAuth client secret of the GigLog app.
+    client_secret: String,
+}
+
+/// Response of Google's token endpoint.
+#[derive(Debug, Deserialize)]
+struct GoogleToken {
+    /// Access token for the userinfo endpoint.
+    access_token: String,
+}
+
+/// Response of Google's userinfo endpoint.
+#[derive(Debug, Deserialize)]
+struct GoogleUserInfo {
+    /// Google's stable identifier for the user.
+    sub: String,
+    /// The user's email address.
+    email: Option<String>,
+    /// Whether Google has verified the email address.
+    #[serde(default)]
+    email_verified: bool,
+    /// The user's given name.
+    given_name: Option<String>,
+    /// The user's family name.
+    family_name: Option<String>,
+    /// The user's full name, used when no given name is set.
+    name: Option<String>,
+}
+
+impl GoogleIdentityProvider {
+    /// Creates a new [`GoogleIdentityProvider`].
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` — The OAuth client ID.
+    /// * `client_secret` — The OAuth client secret.
+    ///
+    /// # Returns
+    ///
+    /// A configured [`GoogleIdentityProvider`].
+    pub fn new(client_id: &str, client_secret: &str) -> Self {
+        Self {
+            client: Client::new(),
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+        }
+    }
+}
+
+impl IdentityProvider for GoogleIdentityProvider {
+    fn authorize_url(&self, state: &str, redirect_uri: &str) -> String {
+        Url::parse_with_params(
+            AUTHORIZE_URL,
+            [
+                ("client_id", self.client_id.as_str()),
+                ("redirect_uri", redirect_uri),
+                ("response_type", "code"),
+                ("scope", SCOPES),
+                ("state", state),
+            ],
+        )
+        .map(String::from)
+        .unwrap_or_default()
+    }
+
+    fn fetch_profile<'a>(
+        &'a self,
+        code: &'a str,
+        redirect_uri: &'a str,
+    ) -> BoxFuture<'a, ApiResult<OAuthProfile>> {
+        Box::pin(async move {
+            let token: GoogleToken = read_json(
+                self.client
+                    .post(TOKEN_URL)
+                    .form(&[
+                        ("code", code),
+                        ("client_id", &self.client_id),
+                        ("client_secret", &self.client_secret),
+                        ("redirect_uri", redirect_uri),
+                        ("grant_type", "authorization_code"),
+                    ])
+                    .send()
+                    .await,
+            )
+            .await?;
+
+            let info: GoogleUserInfo = read_json(
+                self.client
+                    .get(USERINFO_URL)
+                    .bearer_auth(&token.access_token)
+                    .send()
+                    .await,
+            )
+            .await?;
+
+            Ok(info.into())
+        })
+    }
+}
+
+impl From<GoogleUserInfo> for OAuthProfile {
+    fn from(info: GoogleUserInfo) -> Self {
+        let first_name = info.given_name.or(info.name).unwrap_or_default();
+
+        OAuthProfile {
+            subject: info.sub,
+            verified_email: info.email.filter(|_| info.email_verified),
+            first_name,
+            last_name: info.family_name.unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drops_unverified_emails() {
+        let info: GoogleUserInfo = serde_json::from_str(
+            r#"{"sub":"42","email":"ada@example.com","email_verified":false,"given_name":"Ada"}"#,
+        )
+        .unwrap();
+
+        let profile = OAuthProfile::from(info);
+
+        assert_eq!(profile.subject, "42");
+        assert_eq!(profile.verified_email, None);
+        assert_eq!(profile.first_name, "Ada");
+    }
+
+    #[test]
+    fn builds_authorize_url() {
+        let provider = GoogleIdentityProvider::new("client", "secret");
+
+        let url = provider.authorize_url("abc", "https://api.example.com/callback");
+
+        assert!(url.starts_with(AUTHORIZE_URL));
+        assert!(url.contains("state=abc"));
+        assert!(url.contains("redirect_uri=https%3A%2F%2Fapi.example.com%2Fcallback"));
+        assert!(url.contains("scope=openid+email+profile"));
+    }
+}
@@ -0,0 +1,161 @@
+//! OAuth sign-in with external identity providers.
+//!
+//! Defines the [`IdentityProvider`] trait, the [`OAuthProfile`] it returns,
+//! and [`IdentityProviders`], which holds the providers configured at
+//! startup. A provider is enabled when its client ID and secret are set.
+//!
+//! # Modules
+//!
+//! - [`github`](crate::services::oauth::github) — Sign in with GitHub.
+//! - [`google`](crate::services::oauth::google) — Sign in with Google.
+
+pub mod github;
+pub mod google;
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use futures_util::future::BoxFuture;
+use gig_log_common::models::oauth::OAuthProvider;
+use reqwest::Response;
+use serde::de::DeserializeOwned;
+
+use crate::core::config::Config;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::services::oauth::{github::GitHubIdentityProvider, google::GoogleIdentityProvider};
+
+/// An OAuth 2.0 identity provider.
+///
+/// Implementations are stored in
+/// [`AppState::identity_providers`](crate::routes::app::AppState::identity_providers).
+pub trait IdentityProvider: Debug + Send + Sync {
+    /// Builds the provider's authorization URL the browser is sent to.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The value the provider must echo back to the callback.
+    /// * `redirect_uri` — The callback URL registered with the provider.
+    ///
+    /// # Returns
+    ///
+    /// The authorization URL.
+    fn authorize_url(&self, state: &str, redirect_uri: &str) -> String;
+
+    /// Exchanges an authorization code for the signed-in user's profile.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` — The authorization code sent to the callback.
+    /// * `redirect_uri` — The callback URL the code was issued for.
+    ///
+    /// # Returns
+    ///
+    /// The user's [`OAuthProfile`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::InternalServerError`] if the code is
+    /// rejected or a provider request fails.
+    fn fetch_profile<'a>(
+        &'a self,
+        code: &'a str,
+        redirect_uri: &'a str,
+    ) -> BoxFuture<'a, ApiResult<OAuthProfile>>;
+}
+
+/// A user's profile as reported by an identity provider.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OAuthProfile {
+    /// The provider's stable identifier for the user.
+    pub subject: String,
+    /// The user's email address, only present if the provider has verified
+    /// it.
+    pub verified_email: Option<String>,
+    /// The user's given name.
+    pub first_name: String,
+    /// The user's family name, empty if unknown.
+    pub last_name: String,
+}
+
+/// The identity providers enabled in this deployment.
+#[derive(Debug, Clone, Default)]
+pub struct IdentityProviders {
+    /// Sign in with Google, if configured.
+    google: Option<Arc<dyn IdentityProvider>>,
+    /// Sign in with GitHub, if configured.
+    github: Option<Arc<dyn IdentityProvider>>,
+}
+
+impl IdentityProviders {
+    /// Creates the providers whose client credentials are configured.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` — Application configuration providing the client
+    ///   credentials.
+    ///
+    /// # Returns
+    ///
+    /// The configured [`IdentityProviders`].
+    pub fn from_config(config: &Config) -> Self {
+        let google = config
+            .google_oauth_client_id
+            .as_deref()
+            .zip(config.google_oauth_client_secret.as_deref())
+            .map(|(client_id, client_secret)| {
+                Arc::new(GoogleIdentityProvider::new(client_id, client_secret))
+                    as Arc<dyn IdentityProvider>
+            });
+        let github = config
+            .github_oauth_client_id
+            .as_deref()
+            .zip(config.github_oauth_client_secret.as_deref())
+            .map(|(client_id, client_secret)| {
+                Arc::new(GitHubIdentityProvider::new(client_id, client_secret))
+                    as Arc<dyn IdentityProvider>
+            });
+
+        Self { google, github }
+    }
+
+    /// Returns a provider if it is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` — The provider to look up.
+    ///
+    /// # Returns
+    ///
+    /// The [`IdentityProvider`], or `None` if it is not configured.
+    pub fn get(&self, provider: OAuthProvider) -> Option<&dyn IdentityProvider> {
+        match provider {
+            OAuthProvider::Google => self.google.as_deref(),
+            OAuthProvider::GitHub => self.github.as_deref(),
+        }
+    }
+}
+
+/// Reads a provider's JSON response, rejecting error statuses.
+///
+/// # Arguments
+///
+/// * `response` — The provider's response, or the error sending the
+///   request.
+///
+/// # Returns
+///
+/// The deserialized response body.
+///
+/// # Errors
+///
+/// Returns [`ApiErrorResponse::InternalServerError`] if the request failed,
+/// the provider responded with an error status, or the body cannot be
+/// parsed.
+async fn read_json<T: DeserializeOwned>(response: reqwest::Result<Response>) -> ApiResult<T> {
+    response
+        .and_then(|response| response.error_for_status())
+        .map_err(|error| ApiErrorResponse::InternalServerError(error.to_string()))?
+        .json()
+        .await
+        .map_err(|error| ApiErrorResponse::InternalServerError(error.to_string()))
+}
@@ -0,0 +1,513 @@
+//! Built-in PDF renderer.
+//!
+//! Provides [`BuiltinPdfRenderer`], which lays invoices out by hand on US
+//! Letter pages using the standard Helvetica fonts, so no template engine
+//! or font files are needed. Characters outside printable ASCII are
+//! replaced with `?`.
+
+use chrono::NaiveDate;
+use gig_log_common::models::invoice::InvoiceLineItem;
+
+use crate::core::error::ApiResult;
+use crate::services::pdf::{InvoiceDocument, PdfColor, PdfRenderer};
+
+/// Page width in points.
+const PAGE_WIDTH: u32 = 612;
+
+/// Page height in points.
+const PAGE_HEIGHT: u32 = 792;
+
+/// Left and bottom page margin in points.
+const MARGIN: u32 = 50;
+
+/// Height of the header band at the top of the first page, in points.
+const HEADER_HEIGHT: u32 = 90;
+
+/// Baseline of the line item table header on the first page.
+const FIRST_PAGE_TABLE_TOP: u32 = 585;
+
+/// Baseline of the line item table header on following pages.
+const PAGE_TABLE_TOP: u32 = 742;
+
+/// Distance between table rows in points.
+const ROW_HEIGHT: u32 = 18;
+
+/// Left edge of the hours, rate, and amount columns.
+const COLUMNS: [u32; 3] = [330, 400, 480];
+
+/// Maximum characters of a line item description.
+const DESCRIPTION_CHARS: usize = 48;
+
+/// Distance between lines of notes in points.
+const NOTE_LINE_HEIGHT: u32 = 14;
+
+/// Maximum characters per line of wrapped notes.
+const NOTE_LINE_CHARS: usize = 90;
+
+/// Renders PDFs without external dependencies.
+#[derive(Debug, Default)]
+pub struct BuiltinPdfRenderer;
+
+impl PdfRenderer for BuiltinPdfRenderer {
+    /// Renders an invoice with a header band in the theme's accent color,
+    /// sender, recipient, and dates, a table of line items, the total, and
+    /// any notes. Line items and notes continue onto further pages as
+    /// needed.
+    fn render_invoice(&self, document: &InvoiceDocument<'_>) -> ApiResult<Vec<u8>> {
+        let invoice = document.invoice;
+        let theme = &document.theme;
+        let mut pages = Vec::new();
+        let mut page = Self::invoice_header(document);
+        let mut y = FIRST_PAGE_TABLE_TOP;
+
+        Self::push_table_header(&mut page, document, y);
+
+        for line_item in &invoice.line_items {
+            if y < MARGIN + ROW_HEIGHT * 2 {
+                pages.push(std::mem::take(&mut page));
+                y = PAGE_TABLE_TOP;
+                Self::push_table_header(&mut page, document, y);
+            }
+
+            y -= ROW_HEIGHT;
+            Self::push_line_item(&mut page, document, line_item, y);
+        }
+
+        let notes = invoice.notes.as_deref().map(Self::wrap).unwrap_or_default();
+        let notes_height = if notes.is_empty() {
+            0
+        } else {
+            ROW_HEIGHT * 2 + NOTE_LINE_HEIGHT
+        };
+
+        if y < MARGIN + ROW_HEIGHT * 2 + notes_height {
+            pages.push(std::mem::take(&mut page));
+            y = PAGE_TABLE_TOP;
+        }
+
+        y -= ROW_HEIGHT / 2;
+        page.push_str(&format!(
+            "{} RG 1 w {MARGIN} {y} m {} {y} l S\n",
+            theme.accent,
+            PAGE_WIDTH - MARGIN
+        ));
+        y -= ROW_HEIGHT;
+        page.push_str(&Self::text("F2", 12, COLUMNS[1], y, theme.text, "Total"));
+        page.push_str(&Self::text(
+            "F2",
+            12,
+            COLUMNS[2],
+            y,
+            theme.text,
+            &format!("${:.2}", invoice.total_amount),
+        ));
+
+        if !notes.is_empty() {
+            y -= ROW_HEIGHT * 2;
+            page.push_str(&Self::text("F2", 10, MARGIN, y, theme.text, "Notes"));
+
+            for line in &notes {
+                if y < MARGIN + NOTE_LINE_HEIGHT {
+                    pages.push(std::mem::take(&mut page));
+                    y = PAGE_TABLE_TOP + NOTE_LINE_HEIGHT;
+                }
+
+                y -= NOTE_LINE_HEIGHT;
+                page.push_str(&Self::text("F1", 10, MARGIN, y, theme.text, line));
+            }
+        }
+
+        pages.push(page);
+
+        Ok(Self::assemble(&pages))
+    }
+}
+
+impl BuiltinPdfRenderer {
+    /// Lays out the header band, sender, recipient, and dates.
+    ///
+    /// # Arguments
+    ///
+    /// * `document` — The invoice being rendered.
+    ///
+    /// # Returns
+    ///
+    /// The first page's content stream so far.
+    fn invoice_header(document: &InvoiceDocument<'_>) -> String {
+        let theme = &document.theme;
+        let number = match document.invoice.invoice_number {
+            Some(number) => format!("No. {number}"),
+            None => "Draft".to_string(),
+        };
+        let date = |date: Option<NaiveDate>, missing: &str| {
+            date.map(|date| date.format("%B %-d, %Y").to_string())
+                .unwrap_or_else(|| missing.to_string())
+        };
+
+        let mut stream = format!(
+            "{} rg 0 {} {PAGE_WIDTH} {HEADER_HEIGHT} re f\n",
+            theme.accent,
+            PAGE_HEIGHT - HEADER_HEIGHT
+        );
+
+        stream.push_str(&Self::text("F2", 26, MARGIN, 740, theme.text, "INVOICE"));
+        stream.push_str(&Self::text("F1", 12, MARGIN, 718, theme.text, &number));
+        stream.push_str(&Self::text("F2", 10, MARGIN, 670, theme.text, "From"));
+        stream.push_str(&Self::text(
+            "F1",
+            11,
+            MARGIN,
+            655,
+            theme.text,
+            document.sender_name,
+        ));
+        stream.push_str(&Self::text("F2", 10, 300, 670, theme.text, "Bill to"));
+        stream.push_str(&Self::text(
+            "F1",
+            11,
+            300,
+            655,
+            theme.text,
+            document.company_name,
+        ));
+        stream.push_str(&Self::text(
+            "F1",
+            10,
+            MARGIN,
+            625,
+            theme.text,
+            &format!("Issued: {}", date(document.issued_on(), "Not finalized")),
+        ));
+        stream.push_str(&Self::text(
+            "F1",
+            10,
+            300,
+            625,
+            theme.text,
+            &format!("Due: {}", date(document.invoice.due_date, "On receipt")),
+        ));
+
+        stream
+    }
+
+    /// Adds the line item table header to a page.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` — The page's content stream.
+    /// * `document` — The invoice being rendered.
+    /// * `y` — Baseline of the header row.
+    fn push_table_header(page: &mut String, document: &InvoiceDocument<'_>, y: u32) {
+        let theme = &document.theme;
+
+        page.push_str(&format!(
+            "{} rg {} {} {} {ROW_HEIGHT} re f\n",
+            theme.accent_light,
+            MARGIN,
+            y - 5,
+            PAGE_WIDTH - MARGIN * 2
+        ));
+
+        for (x, label) in [(MARGIN + 6, "Description")]
+            .into_iter()
+            .chain(COLUMNS.into_iter().zip(["Hours", "Rate", "Amount"]))
+        {
+            page.push_str(&Self::text("F2", 10, x, y, theme.text, label));
+        }
+    }
+
+    /// Adds a line item row to a page.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` — The page's content stream.
+    /// * `document` — The invoice being rendered.
+    /// * `line_item` — The line item to add.
+    /// * `y` — Baseline of the row.
+    fn push_line_item(
+        page: &mut String,
+        document: &InvoiceDocument<'_>,
+        line_item: &InvoiceLineItem,
+        y: u32,
+    ) {
+        let description = if line_item.description.chars().count() > DESCRIPTION_CHARS {
+            let truncated: String = line_item
+                .description
+                .chars()
+                .take(DESCRIPTION_CHARS - 3)
+                .collect();
+            format!("{truncated}...")
+        } else {
+            line_item.description.clone()
+        };
+        let cells = [
+            (MARGIN + 6, description),
+            (COLUMNS[0], format!("{:.2}", line_item.rounded_hours)),
+            (
+                COLUMNS[1],
+                line_item
+                    .hourly_rate
+                    .map_or_else(|| "-".to_string(), |rate| format!("${rate:.2}/h")),
+            ),
+            (
+                COLUMNS[2],
+                line_item
+                    .amount
+                    .map_or_else(|| "-".to_string(), |amount| format!("${amount:.2}")),
+            ),
+        ];
+
+        for (x, value) in cells {
+            page.push_str(&Self::text("F1", 10, x, y, document.theme.text, &value));
+        }
+    }
+
+    /// Builds the content stream operators that draw a line of text.
+    ///
+    /// # Arguments
+    ///
+    /// * `font` — Resource name of the font, `F1` for regular or `F2` for
+    ///   bold.
+    /// * `size` — Font size in points.
+    /// * `x` — Left edge of the text.
+    /// * `y` — Baseline of the text.
+    /// * `color` — Fill color of the text.
+    /// * `value` — The text.
+    ///
+    /// # Returns
+    ///
+    /// The content stream operators.
+    fn text(font: &str, size: u32, x: u32, y: u32, color: PdfColor, value: &str) -> String {
+        format!(
+            "BT /{font} {size} Tf {color} rg {x} {y} Td ({}) Tj ET\n",
+            Self::escape_text(value)
+        )
+    }
+
+    /// Wraps notes into lines at word boundaries.
+    ///
+    /// # Arguments
+    ///
+    /// * `notes` — The notes, which may contain line breaks.
+    ///
+    /// # Returns
+    ///
+    /// The wrapped lines. Words longer than a line are split.
+    fn wrap(notes: &str) -> Vec<String> {
+        let mut lines = Vec::new();
+
+        for paragraph in notes.lines() {
+            let mut line = String::new();
+
+            for word in paragraph.split_whitespace() {
+                let mut word: Vec<char> = word.chars().collect();
+
+                while !word.is_empty() {
+                    let used = line.chars().count();
+                    let separator = usize::from(used > 0);
+
+                    if used + separator + word.len() <= NOTE_LINE_CHARS {
+                        if separator > 0 {
+                            line.push(' ');
+                        }
+                        line.extend(word.drain(..));
+                    } else if used > 0 {
+                        lines.push(std::mem::take(&mut line));
+                    } else {
+                        line.extend(word.drain(..NOTE_LINE_CHARS));
+                        lines.push(std::mem::take(&mut line));
+                    }
+                }
+            }
+
+            lines.push(line);
+        }
+
+        lines
+    }
+
+    /// Assembles page content streams into a PDF file.
+    ///
+    /// # Arguments
+    ///
+    /// * `pages` — One content stream per page.
+    ///
+    /// # Returns
+    ///
+    /// The PDF file contents.
+    fn assemble(pages: &[String]) -> Vec<u8> {
+        let page_ids: Vec<usize> = (0..pages.len()).map(|index| 5 + index * 2).collect();
+
+        let mut objects = vec![
+            "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+            format!(
+                "<< /Type /Pages /Kids [{}] /Count {} >>",
+                page_ids
+                    .iter()
+                    .map(|id| format!("{id} 0 R"))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                pages.len()
+            ),
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>".to_string(),
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold >>".to_string(),
+        ];
+
+        for (stream, page_id) in pages.iter().zip(&page_ids) {
+            objects.push(format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] \
+                 /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+                page_id + 1
+            ));
+            objects.push(format!(
+                "<< /Length {} >>\nstream\n{stream}\nendstream",
+                stream.len()
+            ));
+        }
+
+        let mut pdf = b"%PDF-1.4\n".to_vec();
+        let mut offsets = Vec::with_capacity(objects.len());
+
+        for (index, object) in objects.iter().enumerate() {
+            offsets.push(pdf.len());
+            pdf.extend_from_slice(format!("{} 0 obj\n{object}\nendobj\n", index + 1).as_bytes());
+        }
+
+        let xref_offset = pdf.len();
+        let mut trailer = format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+
+        for offset in offsets {
+            trailer.push_str(&format!("{offset:010} 00000 n \n"));
+        }
+
+        trailer.push_str(&format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+            objects.len() + 1
+        ));
+        pdf.extend_from_slice(trailer.as_bytes());
+
+        pdf
+    }
+
+    /// Escapes text for inclusion in a PDF string literal.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — The text to escape.
+    ///
+    /// # Returns
+    ///
+    /// The escaped text, with characters outside printable ASCII replaced.
+    fn escape_text(value: &str) -> String {
+        value
+            .chars()
+            .map(|character| match character {
+                '\\' | '(' | ')' => format!("\\{character}"),
+                ' '..='~' => character.to_string(),
+                _ => "?".to_string(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use gig_log_common::models::invoice::{Invoice, InvoiceStatus};
+    use uuid::Uuid;
+
+    use crate::services::pdf::PdfTheme;
+
+    use super::*;
+
+    fn invoice(line_items: usize, notes: Option<&str>) -> Invoice {
+        let id = Uuid::new_v4();
+
+        Invoice {
+            id,
+            user_id: Uuid::new_v4(),
+            company_id: Uuid::new_v4(),
+            status: InvoiceStatus::Finalized,
+            invoice_number: Some(7),
+            due_date: NaiveDate::from_ymd_opt(2026, 11, 15),
+            notes: notes.map(str::to_string),
+            line_items: (0..line_items)
+                .map(|index| InvoiceLineItem {
+                    id: Uuid::new_v4(),
+                    invoice_id: id,
+                    job_id: Uuid::new_v4(),
+                    description: format!("Job {index} (café)"),
+                    session_count: 1,
+                    duration_seconds: 3600,
+                    rounded_hours: 1.0,
+                    hourly_rate: Some(40.0),
+                    amount: Some(40.0),
+                    created_at: Utc::now(),
+                })
+                .collect(),
+            total_amount: 40.0 * line_items as f64,
+            finalized_at: Some(Utc::now()),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn render(invoice: &Invoice) -> String {
+        let document = InvoiceDocument {
+            invoice,
+            sender_name: "Jo Doe",
+            company_name: "Acme",
+            theme: PdfTheme {
+                accent: PdfColor::rgb(255, 0, 0),
+                ..PdfTheme::default()
+            },
+        };
+
+        String::from_utf8(BuiltinPdfRenderer.render_invoice(&document).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn renders_branded_invoice() {
+        let pdf = render(&invoice(2, Some("Thanks (really)!")));
+
+        assert!(pdf.starts_with("%PDF-1.4\n"));
+        assert!(pdf.ends_with("%%EOF\n"));
+        assert!(pdf.contains("/Count 1"));
+        assert!(pdf.contains("1.000 0.000 0.000 rg 0 702 612 90 re f"));
+        assert!(pdf.contains("(No. 7) Tj"));
+        assert!(pdf.contains("(Due: November 15, 2026) Tj"));
+        assert!(pdf.contains("(Job 1 \\(caf?\\)) Tj"));
+        assert!(pdf.contains("($40.00/h) Tj"));
+        assert!(pdf.contains("($80.00) Tj"));
+        assert!(pdf.contains("(Thanks \\(really\\)!) Tj"));
+    }
+
+    #[test]
+    fn continues_line_items_onto_new_pages() {
+        let pdf = render(&invoice(70, None));
+
+        assert!(pdf.contains("/Count 3"));
+        assert_eq!(pdf.matches("(Description) Tj").count(), 3);
+        assert!(pdf.contains("($2800.00) Tj"));
+    }
+
+    #[test]
+    fn continues_notes_onto_new_pages() {
+        let notes = "-\n".repeat(100);
+        let pdf = render(&invoice(1, Some(&notes)));
+
+        assert!(pdf.contains("/Count 3"));
+        assert_eq!(pdf.matches("(-) Tj").count(), 100);
+    }
+
+    #[test]
+    fn wraps_notes_at_word_boundaries() {
+        let long_word = "x".repeat(NOTE_LINE_CHARS + 5);
+        let lines = BuiltinPdfRenderer::wrap(&format!("Pay by check.\n\n{long_word} end"));
+
+        assert_eq!(lines[0], "Pay by check.");
+        assert_eq!(lines[1], "");
+        assert_eq!(lines[2].len(), NOTE_LINE_CHARS);
+        assert_eq!(lines[3], "xxxxx end");
+    }
+}
@@ -0,0 +1,261 @@
+//! PDF rendering of invoices.
+//!
+//! Defines the [`PdfRenderer`] trait, the [`InvoiceDocument`] it renders,
+//! and the [`PdfTheme`] that brands the output with colors from the user's
+//! active palette. [`BuiltinPdfRenderer`](crate::services::pdf::builtin::BuiltinPdfRenderer)
+//! is the default implementation.
+//!
+//! # Modules
+//!
+//! - [`builtin`](crate::services::pdf::builtin) — Dependency-free renderer using the standard PDF fonts.
+
+pub mod builtin;
+
+use std::fmt::{self, Debug, Display};
+
+use chrono::NaiveDate;
+use gig_log_common::models::invoice::Invoice;
+use serde_json::Value;
+
+use crate::core::error::ApiResult;
+use crate::repo::appearance::PaletteSwatchRecord;
+
+/// Accent color used when the palette has no valid `primary-100` token.
+const FALLBACK_ACCENT: PdfColor = PdfColor::rgb(0x7a, 0xa2, 0xf7);
+
+/// Light accent color used when the palette has no valid `primary-60`
+/// token.
+const FALLBACK_ACCENT_LIGHT: PdfColor = PdfColor::rgb(0xd6, 0xe2, 0xfc);
+
+/// Text color used when the palette has no valid `text` token.
+const FALLBACK_TEXT: PdfColor = PdfColor::rgb(0x1f, 0x23, 0x35);
+
+/// Renders documents as PDF files.
+///
+/// Implementations are stored in
+/// [`AppState::pdf_renderer`](crate::routes::app::AppState::pdf_renderer)
+/// and called from request handlers, so rendering should not block for
+/// long.
+pub trait PdfRenderer: Debug + Send + Sync {
+    /// Renders an invoice.
+    ///
+    /// # Arguments
+    ///
+    /// * `document` — The [`InvoiceDocument`] to render.
+    ///
+    /// # Returns
+    ///
+    /// The PDF file contents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`](crate::core::error::ApiErrorResponse)
+    /// if the document cannot be rendered.
+    fn render_invoice(&self, document: &InvoiceDocument<'_>) -> ApiResult<Vec<u8>>;
+}
+
+/// An invoice and the details needed to print it.
+#[derive(Debug)]
+pub struct InvoiceDocument<'a> {
+    /// The invoice, with its line items.
+    pub invoice: &'a Invoice,
+    /// Full name of the user sending the invoice.
+    pub sender_name: &'a str,
+    /// Name of the company being billed.
+    pub company_name: &'a str,
+    /// Colors to brand the document with.
+    pub theme: PdfTheme,
+}
+
+impl InvoiceDocument<'_> {
+    /// Returns the date the invoice was issued.
+    ///
+    /// # Returns
+    ///
+    /// The date the invoice was finalized, or `None` for drafts.
+    pub fn issued_on(&self) -> Option<NaiveDate> {
+        self.invoice
+            .finalized_at
+            .map(|finalized_at| finalized_at.date_naive())
+    }
+}
+
+/// An RGB color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PdfColor {
+    /// Red component.
+    pub red: u8,
+    /// Green component.
+    pub green: u8,
+    /// Blue component.
+    pub blue: u8,
+}
+
+impl PdfColor {
+    /// Creates a color from its components.
+    ///
+    /// # Arguments
+    ///
+    /// * `red` — Red component.
+    /// * `green` — Green component.
+    /// * `blue` — Blue component.
+    ///
+    /// # Returns
+    ///
+    /// The [`PdfColor`].
+    pub const fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self { red, green, blue }
+    }
+
+    /// Parses a `#rgb`, `#rrggbb`, or `#rrggbbaa` color. Alpha is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — The hex color.
+    ///
+    /// # Returns
+    ///
+    /// The [`PdfColor`], or `None` if the value is not a hex color.
+    pub fn from_hex(value: &str) -> Option<Self> {
+        let digits = value.strip_prefix('#')?;
+
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let component = |index: usize, width: usize| {
+            let value = u8::from_str_radix(&digits[index * width..(index + 1) * width], 16).ok()?;
+            Some(if width == 1 { value * 17 } else { value })
+        };
+        let width = match digits.len() {
+            3 => 1,
+            6 | 8 => 2,
+            _ => return None,
+        };
+
+        Some(Self::rgb(
+            component(0, width)?,
+            component(1, width)?,
+            component(2, width)?,
+        ))
+    }
+}
+
+impl Display for PdfColor {
+    /// Formats the color as PDF color operands, each component between 0
+    /// and 1.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:.3} {:.3} {:.3}",
+            f32::from(self.red) / 255.0,
+            f32::from(self.green) / 255.0,
+            f32::from(self.blue) / 255.0
+        )
+    }
+}
+
+/// Colors used to brand a rendered document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PdfTheme {
+    /// Strong accent for the header band and rules.
+    pub accent: PdfColor,
+    /// Light accent for table header backgrounds.
+    pub accent_light: PdfColor,
+    /// Body text color.
+    pub text: PdfColor,
+}
+
+impl Default for PdfTheme {
+    fn default() -> Self {
+        Self {
+            accent: FALLBACK_ACCENT,
+            accent_light: FALLBACK_ACCENT_LIGHT,
+            text: FALLBACK_TEXT,
+        }
+    }
+}
+
+impl PdfTheme {
+    /// Builds a theme from a palette's tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `palette` — The user's active palette, or `None` if they have not
+    ///   chosen one.
+    ///
+    /// # Returns
+    ///
+    /// The [`PdfTheme`], using the palette's `primary-100`, `primary-60`,
+    /// and `text` tokens where they are valid hex colors and the default
+    /// colors otherwise.
+    pub fn from_palette(palette: Option<&PaletteSwatchRecord>) -> Self {
+        let defaults = Self::default();
+        let Some(palette) = palette else {
+            return defaults;
+        };
+        let token = |name: &str| {
+            palette
+                .generated_tokens
+                .get(name)
+                .and_then(Value::as_str)
+                .and_then(PdfColor::from_hex)
+        };
+
+        Self {
+            accent: token("primary-100").unwrap_or(defaults.accent),
+            accent_light: token("primary-60").unwrap_or(defaults.accent_light),
+            text: token("text").unwrap_or(defaults.text),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parses_hex_colors() {
+        assert_eq!(
+            PdfColor::from_hex("#89b4fa"),
+            Some(PdfColor::rgb(0x89, 0xb4, 0xfa))
+        );
+        assert_eq!(
+            PdfColor::from_hex("#fA0"),
+            Some(PdfColor::rgb(0xff, 0xaa, 0x00))
+        );
+        assert_eq!(
+            PdfColor::from_hex("#11223380"),
+            Some(PdfColor::rgb(0x11, 0x22, 0x33))
+        );
+        assert_eq!(PdfColor::from_hex("89b4fa"), None);
+        assert_eq!(PdfColor::from_hex("#ééé"), None);
+        assert_eq!(PdfColor::rgb(255, 0, 51).to_string(), "1.000 0.000 0.200");
+    }
+
+    #[test]
+    fn themes_from_palette_tokens() {
+        let palette = PaletteSwatchRecord {
+            seed_green: "#a6e3a1".to_string(),
+            seed_red: "#f38ba8".to_string(),
+            seed_yellow: "#f9e2af".to_string(),
+            seed_blue: "#89b4fa".to_string(),
+            seed_magenta: "#cba6f7".to_string(),
+            seed_cyan: "#94e2d5".to_string(),
+            generated_tokens: json!({
+                "primary-100": "#89b4fa",
+                "primary-60": "url(#x)",
+                "text": "#1e1e2e",
+            }),
+        };
+
+        let theme = PdfTheme::from_palette(Some(&palette));
+
+        assert_eq!(theme.accent, PdfColor::rgb(0x89, 0xb4, 0xfa));
+        assert_eq!(theme.accent_light, FALLBACK_ACCENT_LIGHT);
+        assert_eq!(theme.text, PdfColor::rgb(0x1e, 0x1e, 0x2e));
+        assert_eq!(PdfTheme::from_palette(None), PdfTheme::default());
+    }
+}
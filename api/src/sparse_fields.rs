@@ -0,0 +1,101 @@
+//! Sparse field selection for detail responses.
+//!
+//! Provides [`SparseFieldsUtil`] for trimming a serialized response down
+//! to the top-level fields a client asked for with `?fields=`.
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::core::error::{ApiErrorResponse, ApiResult};
+
+/// Utility for filtering serialized responses to requested fields.
+pub struct SparseFieldsUtil;
+
+impl SparseFieldsUtil {
+    /// Serializes a response and keeps only the requested top-level fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — The response to serialize.
+    /// * `fields` — Comma-separated field names, or `None` for every field.
+    ///
+    /// # Returns
+    ///
+    /// The serialized response, limited to the requested fields in their
+    /// original order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::BadRequest`] if a requested field does
+    /// not exist on the response, or
+    /// [`ApiErrorResponse::InternalServerError`] if the response does not
+    /// serialize to a JSON object.
+    pub fn select<T: Serialize>(value: &T, fields: Option<&str>) -> ApiResult<Value> {
+        let value = serde_json::to_value(value)
+            .map_err(|error| ApiErrorResponse::InternalServerError(error.to_string()))?;
+
+        let Some(fields) = fields else {
+            return Ok(value);
+        };
+
+        let Value::Object(object) = value else {
+            return Err(ApiErrorResponse::InternalServerError(
+                "Sparse fields can only be selected from objects".to_string(),
+            ));
+        };
+
+        let requested: Vec<&str> = fields
+            .split(',')
+            .map(str::trim)
+            .filter(|field| !field.is_empty())
+            .collect();
+
+        if let Some(unknown) = requested.iter().find(|field| !object.contains_key(**field)) {
+            return Err(ApiErrorResponse::BadRequest(format!(
+                "Unknown field '{unknown}'"
+            )));
+        }
+
+        let selected: Map<String, Value> = object
+            .into_iter()
+            .filter(|(key, _)| requested.contains(&key.as_str()))
+            .collect();
+
+        Ok(Value::Object(selected))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn response() -> Value {
+        json!({ "id": 1, "name": "Acme", "budgets": [] })
+    }
+
+    #[test]
+    fn keeps_requested_fields() {
+        let selected = SparseFieldsUtil::select(&response(), Some("name, id,")).unwrap();
+
+        assert_eq!(selected, json!({ "id": 1, "name": "Acme" }));
+    }
+
+    #[test]
+    fn keeps_every_field_without_selection() {
+        assert_eq!(
+            SparseFieldsUtil::select(&response(), None).unwrap(),
+            response()
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_fields() {
+        let error = SparseFieldsUtil::select(&response(), Some("id,secret")).unwrap_err();
+
+        assert!(
+            matches!(error, ApiErrorResponse::BadRequest(message) if message == "Unknown field 'secret'")
+        );
+    }
+}
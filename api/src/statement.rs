@@ -0,0 +1,298 @@
+//! Company statement rendering.
+//!
+//! Provides [`StatementUtil`] for totalling a company's payments and hours
+//! over a date range and rendering them as a single-font, text-only PDF
+//! suitable for emailing to a company contact.
+
+use chrono::NaiveDate;
+
+use crate::receipt::ReceiptUtil;
+use crate::repo::{payment::StatementPaymentRecord, work_session::StatementHoursRecord};
+
+/// Number of text lines that fit on one statement page.
+const LINES_PER_PAGE: usize = 48;
+
+/// Font size of statement text in points.
+const FONT_SIZE: u32 = 10;
+
+/// Distance between statement lines in points.
+const LINE_HEIGHT: u32 = 14;
+
+/// Utility for totalling and rendering company statements.
+pub struct StatementUtil;
+
+impl StatementUtil {
+    /// Totals the hours worked across jobs.
+    ///
+    /// # Arguments
+    ///
+    /// * `hours` — Worked time per job.
+    ///
+    /// # Returns
+    ///
+    /// The total hours, rounded to two decimal places.
+    pub fn total_hours(hours: &[StatementHoursRecord]) -> f64 {
+        let seconds: i64 = hours.iter().map(|job| job.net_seconds.max(0)).sum();
+
+        Self::round_hundredths(seconds as f64 / 3600.0)
+    }
+
+    /// Totals the amounts of payments.
+    ///
+    /// # Arguments
+    ///
+    /// * `payments` — The payments to total.
+    ///
+    /// # Returns
+    ///
+    /// The total in dollars, rounded to the cent.
+    pub fn total_payments(payments: &[StatementPaymentRecord]) -> f64 {
+        Self::round_hundredths(payments.iter().map(|payment| payment.total).sum())
+    }
+
+    /// Lays out the text lines of a statement.
+    ///
+    /// # Arguments
+    ///
+    /// * `sender_name` — Full name of the user sending the statement.
+    /// * `company_name` — Name of the company the statement covers.
+    /// * `period_start` — First day covered by the statement.
+    /// * `period_end` — Last day covered by the statement.
+    /// * `hours` — Worked time per job during the period.
+    /// * `payments` — Payments dated within the period.
+    ///
+    /// # Returns
+    ///
+    /// The statement's lines, in order.
+    pub fn build_lines(
+        sender_name: &str,
+        company_name: &str,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+        hours: &[StatementHoursRecord],
+        payments: &[StatementPaymentRecord],
+    ) -> Vec<String> {
+        let mut lines = vec![
+            format!("Statement for {company_name}"),
+            format!("From {sender_name}"),
+            format!(
+                "Period: {} to {}",
+                period_start.format("%B %-d, %Y"),
+                period_end.format("%B %-d, %Y")
+            ),
+            String::new(),
+            "Hours".to_string(),
+        ];
+
+        if hours.is_empty() {
+            lines.push("  No hours recorded.".to_string());
+        }
+
+        for job in hours {
+            lines.push(format!(
+                "  {}: {:.2} h",
+                job.job_title,
+                Self::total_hours(std::slice::from_ref(job))
+            ));
+        }
+
+        lines.push(format!("  Total: {:.2} h", Self::total_hours(hours)));
+        lines.push(String::new());
+        lines.push("Payments".to_string());
+
+        if payments.is_empty() {
+            lines.push("  No payments recorded.".to_string());
+        }
+
+        for payment in payments {
+            lines.push(format!(
+                "  {}  {}  ${:.2}  {}",
+                payment.payment_date.format("%Y-%m-%d"),
+                ReceiptUtil::payout_label(&payment.payout_type),
+                payment.total,
+                if payment.payment_received {
+                    "Received"
+                } else {
+                    "Pending"
+                }
+            ));
+        }
+
+        lines.push(format!("  Total: ${:.2}", Self::total_payments(payments)));
+
+        lines
+    }
+
+    /// Renders text lines as a US Letter PDF in Helvetica.
+    ///
+    /// Lines are split across as many pages as needed. Characters outside
+    /// printable ASCII are replaced with `?`.
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` — The lines to render.
+    ///
+    /// # Returns
+    ///
+    /// The PDF file contents.
+    pub fn render_pdf(lines: &[String]) -> Vec<u8> {
+        let pages: Vec<&[String]> = if lines.is_empty() {
+            vec![&[]]
+        } else {
+            lines.chunks(LINES_PER_PAGE).collect()
+        };
+        let page_ids: Vec<usize> = (0..pages.len()).map(|index| 4 + index * 2).collect();
+
+        let mut objects = vec![
+            "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+            format!(
+                "<< /Type /Pages /Kids [{}] /Count {} >>",
+                page_ids
+                    .iter()
+                    .map(|id| format!("{id} 0 R"))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                pages.len()
+            ),
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>".to_string(),
+        ];
+
+        for (page, page_id) in pages.iter().zip(&page_ids) {
+            let mut stream = format!("BT /F1 {FONT_SIZE} Tf {LINE_HEIGHT} TL 50 742 Td\n");
+
+            for line in page.iter() {
+                stream.push_str(&format!("({}) Tj T*\n", Self::escape_pdf_text(line)));
+            }
+
+            stream.push_str("ET");
+
+            objects.push(format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] \
+                 /Resources << /Font << /F1 3 0 R >> >> /Contents {} 0 R >>",
+                page_id + 1
+            ));
+            objects.push(format!(
+                "<< /Length {} >>\nstream\n{stream}\nendstream",
+                stream.len()
+            ));
+        }
+
+        let mut pdf = b"%PDF-1.4\n".to_vec();
+        let mut offsets = Vec::with_capacity(objects.len());
+
+        for (index, object) in objects.iter().enumerate() {
+            offsets.push(pdf.len());
+            pdf.extend_from_slice(format!("{} 0 obj\n{object}\nendobj\n", index + 1).as_bytes());
+        }
+
+        let xref_offset = pdf.len();
+        let mut trailer = format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+
+        for offset in offsets {
+            trailer.push_str(&format!("{offset:010} 00000 n \n"));
+        }
+
+        trailer.push_str(&format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+            objects.len() + 1
+        ));
+        pdf.extend_from_slice(trailer.as_bytes());
+
+        pdf
+    }
+
+    /// Escapes text for inclusion in a PDF string literal.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — The text to escape.
+    ///
+    /// # Returns
+    ///
+    /// The escaped text, with characters outside printable ASCII replaced.
+    fn escape_pdf_text(value: &str) -> String {
+        value
+            .chars()
+            .map(|character| match character {
+                '\\' | '(' | ')' => format!("\\{character}"),
+                ' '..='~' => character.to_string(),
+                _ => "?".to_string(),
+            })
+            .collect()
+    }
+
+    /// Rounds a value to two decimal places.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — The value to round.
+    ///
+    /// # Returns
+    ///
+    /// The rounded value.
+    fn round_hundredths(value: f64) -> f64 {
+        (value * 100.0).round() / 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::repo::payment::PayoutTypeRecord;
+
+    use super::*;
+
+    fn hours(job_title: &str, net_seconds: i64) -> StatementHoursRecord {
+        StatementHoursRecord {
+            job_title: job_title.to_string(),
+            net_seconds,
+        }
+    }
+
+    fn payment(total: f64) -> StatementPaymentRecord {
+        StatementPaymentRecord {
+            payment_date: NaiveDate::from_ymd_opt(2026, 3, 15).unwrap(),
+            total,
+            payout_type: PayoutTypeRecord::Zelle,
+            payment_received: true,
+        }
+    }
+
+    #[test]
+    fn totals_hours_and_payments() {
+        let hours = [hours("Design", 5400), hours("Build", 1200)];
+        let payments = [payment(100.25), payment(49.999)];
+
+        assert_eq!(StatementUtil::total_hours(&hours), 1.83);
+        assert_eq!(StatementUtil::total_payments(&payments), 150.25);
+    }
+
+    #[test]
+    fn lists_jobs_and_payments() {
+        let lines = StatementUtil::build_lines(
+            "Jo Doe",
+            "Acme",
+            NaiveDate::from_ymd_opt(2026, 3, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 3, 31).unwrap(),
+            &[hours("Design", 5400)],
+            &[payment(250.0)],
+        );
+
+        assert!(lines.contains(&"Period: March 1, 2026 to March 31, 2026".to_string()));
+        assert!(lines.contains(&"  Design: 1.50 h".to_string()));
+        assert!(lines.contains(&"  2026-03-15  Zelle  $250.00  Received".to_string()));
+        assert_eq!(lines.last().unwrap(), "  Total: $250.00");
+    }
+
+    #[test]
+    fn renders_paged_pdf_with_escaped_text() {
+        let mut lines = vec!["Fees (net) \\ café".to_string()];
+        lines.extend((0..LINES_PER_PAGE).map(|index| format!("Line {index}")));
+
+        let pdf = String::from_utf8(StatementUtil::render_pdf(&lines)).unwrap();
+
+        assert!(pdf.starts_with("%PDF-1.4\n"));
+        assert!(pdf.ends_with("%%EOF\n"));
+        assert!(pdf.contains("/Count 2"));
+        assert!(pdf.contains("(Fees \\(net\\) \\\\ caf?) Tj"));
+    }
+}
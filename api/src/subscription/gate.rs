@@ -0,0 +1,62 @@
+//! Plan gating middleware.
+//!
+//! Provides [`PlanGate`], an Axum middleware that restricts a route group to
+//! users whose subscription is in good standing on a paid plan. Apply it
+//! with `route_layer` and `middleware::from_fn_with_state` so unmatched
+//! paths still return `404 Not Found`.
+
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::Response,
+};
+use gig_log_common::models::subscription::SubscriptionPlan;
+
+use crate::{
+    auth::AuthUser,
+    core::error::{ApiErrorResponse, ApiResult},
+    repo::subscription::SubscriptionRepo,
+    routes::app::AppState,
+};
+
+/// Middleware that restricts routes to paid plans.
+pub struct PlanGate;
+
+impl PlanGate {
+    /// Axum middleware that only lets Pro subscribers through.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `auth_user` — The authenticated user.
+    /// * `request` — The incoming HTTP request.
+    /// * `next` — The next middleware or handler in the chain.
+    ///
+    /// # Returns
+    ///
+    /// The HTTP [`Response`] produced by downstream handlers.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::Unauthorized`] if the request is not
+    /// authenticated, [`ApiErrorResponse::Forbidden`] if the user is not on
+    /// an active Pro subscription, or an [`ApiErrorResponse`] if the
+    /// subscription query fails.
+    pub async fn require_pro(
+        State(state): State<AppState>,
+        auth_user: AuthUser,
+        request: Request,
+        next: Next,
+    ) -> ApiResult<Response> {
+        let subscription =
+            SubscriptionRepo::find_subscription(&state.db_pool, auth_user.user_id).await?;
+
+        if subscription.effective_plan() != SubscriptionPlan::Pro {
+            return Err(ApiErrorResponse::Forbidden(
+                "This feature requires a Pro subscription".to_string(),
+            ));
+        }
+
+        Ok(next.run(request).await)
+    }
+}
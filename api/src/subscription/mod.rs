@@ -0,0 +1,14 @@
+//! Paid plan subscriptions billed through Stripe.
+//!
+//! Users start on the free plan. Upgrading goes through a Stripe Checkout
+//! session, and Stripe webhooks keep the `subscriptions` table in sync with
+//! the subscription's billing status. Routes reserved for paid plans are
+//! guarded by [`PlanGate`](crate::subscription::gate::PlanGate).
+//!
+//! # Modules
+//!
+//! - [`gate`](crate::subscription::gate) — Middleware restricting routes to paid plans.
+//! - [`stripe`](crate::subscription::stripe) — Stripe API client and webhook verification.
+
+pub mod gate;
+pub mod stripe;
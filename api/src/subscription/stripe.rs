@@ -0,0 +1,379 @@
+//! Stripe Billing integration.
+//!
+//! Provides [`StripeClient`], a thin wrapper around [`reqwest::Client`] that
+//! creates Stripe customers and Checkout sessions, and [`StripeWebhook`],
+//! which verifies the signature Stripe attaches to webhook deliveries.
+
+use chrono::{DateTime, Utc};
+use gig_log_common::models::subscription::SubscriptionStatus;
+use hmac::{Hmac, Mac};
+use reqwest::Client;
+use serde::{Deserialize, de::DeserializeOwned};
+use sha2::Sha256;
+use uuid::Uuid;
+
+use crate::auth::token;
+use crate::core::error::{ApiErrorResponse, ApiResult};
+
+/// Base URL of the Stripe REST API.
+const STRIPE_API_URL: &str = "https://api.stripe.com/v1";
+
+/// HTTP client for the Stripe API.
+#[derive(Debug, Clone)]
+pub struct StripeClient {
+    /// Underlying HTTP client used for API requests.
+    client: Client,
+    /// Stripe secret API key for authentication.
+    secret_key: String,
+}
+
+/// A Stripe object identified only by its ID.
+#[derive(Debug, Deserialize)]
+struct StripeObject {
+    /// The object's Stripe ID.
+    id: String,
+}
+
+/// A created Stripe Checkout session.
+#[derive(Debug, Deserialize)]
+struct StripeCheckoutSession {
+    /// The Stripe-hosted checkout page.
+    url: String,
+}
+
+impl StripeClient {
+    /// Creates a new [`StripeClient`].
+    ///
+    /// # Arguments
+    ///
+    /// * `secret_key` — The Stripe secret API key.
+    ///
+    /// # Returns
+    ///
+    /// A configured [`StripeClient`].
+    pub fn new(secret_key: &str) -> Self {
+        Self {
+            client: Client::new(),
+            secret_key: secret_key.to_string(),
+        }
+    }
+
+    /// Creates a Stripe customer for a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` — The user the customer represents, stored in the
+    ///   customer's metadata.
+    /// * `email` — The user's email address.
+    ///
+    /// # Returns
+    ///
+    /// The new customer's Stripe ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::InternalServerError`] if the Stripe
+    /// request fails.
+    pub async fn create_customer(&self, user_id: Uuid, email: &str) -> ApiResult<String> {
+        let customer: StripeObject = self
+            .post(
+                "/customers",
+                &[
+                    ("email", email.to_string()),
+                    ("metadata[user_id]", user_id.to_string()),
+                ],
+            )
+            .await?;
+
+        Ok(customer.id)
+    }
+
+    /// Creates a Checkout session that subscribes a customer to a price.
+    ///
+    /// # Arguments
+    ///
+    /// * `customer_id` — The customer's Stripe ID.
+    /// * `price_id` — The Stripe price ID of the plan.
+    /// * `success_url` — Where Stripe sends the user after paying.
+    /// * `cancel_url` — Where Stripe sends the user if they back out.
+    ///
+    /// # Returns
+    ///
+    /// The URL of the Stripe-hosted checkout page.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::InternalServerError`] if the Stripe
+    /// request fails.
+    pub async fn create_checkout_session(
+        &self,
+        customer_id: &str,
+        price_id: &str,
+        success_url: &str,
+        cancel_url: &str,
+    ) -> ApiResult<String> {
+        let session: StripeCheckoutSession = self
+            .post(
+                "/checkout/sessions",
+                &[
+                    ("mode", "subscription".to_string()),
+                    ("customer", customer_id.to_string()),
+                    ("line_items[0][price]", price_id.to_string()),
+                    ("line_items[0][quantity]", "1".to_string()),
+                    ("success_url", success_url.to_string()),
+                    ("cancel_url", cancel_url.to_string()),
+                ],
+            )
+            .await?;
+
+        Ok(session.url)
+    }
+
+    /// Sends a form-encoded `POST` request to the Stripe API.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` — The API path, relative to the `/v1` base URL.
+    /// * `form` — The request parameters.
+    ///
+    /// # Returns
+    ///
+    /// The deserialized response body.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiErrorResponse::InternalServerError`] if the request
+    /// fails, Stripe rejects it, or the response cannot be parsed.
+    async fn post<T: DeserializeOwned>(&self, path: &str, form: &[(&str, String)]) -> ApiResult<T> {
+        self.client
+            .post(format!("{STRIPE_API_URL}{path}"))
+            .bearer_auth(&self.secret_key)
+            .form(form)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|error| ApiErrorResponse::InternalServerError(error.to_string()))?
+            .json()
+            .await
+            .map_err(|error| ApiErrorResponse::InternalServerError(error.to_string()))
+    }
+}
+
+/// A Stripe webhook event.
+#[derive(Debug, Deserialize)]
+pub struct StripeEvent {
+    /// The event's Stripe ID, used to skip redelivered events.
+    pub id: String,
+    /// The event type, such as `customer.subscription.updated`.
+    #[serde(rename = "type")]
+    pub event_type: String,
+    /// When the event happened, as a Unix timestamp.
+    pub created: i64,
+    /// The object the event is about.
+    pub data: StripeEventData,
+}
+
+/// The payload of a [`StripeEvent`].
+#[derive(Debug, Deserialize)]
+pub struct StripeEventData {
+    /// The object the event is about, whose shape depends on the event type.
+    pub object: serde_json::Value,
+}
+
+/// A Stripe subscription, as sent in `customer.subscription.*` events.
+#[derive(Debug, Deserialize)]
+pub struct StripeSubscription {
+    /// The subscription's Stripe ID.
+    pub id: String,
+    /// The Stripe ID of the customer who owns the subscription.
+    pub customer: String,
+    /// The subscription's billing status.
+    pub status: SubscriptionStatus,
+    /// Whether the subscription ends instead of renewing.
+    pub cancel_at_period_end: bool,
+    /// End of the current period, as a Unix timestamp. Sent by API versions
+    /// that track periods per subscription.
+    pub current_period_end: Option<i64>,
+    /// The subscribed prices.
+    pub items: StripeList<StripeSubscriptionItem>,
+}
+
+impl StripeSubscription {
+    /// Returns whether the subscription includes a price.
+    ///
+    /// # Arguments
+    ///
+    /// * `price_id` — The Stripe price ID to look for.
+    ///
+    /// # Returns
+    ///
+    /// `true` if any item is for the price.
+    pub fn has_price(&self, price_id: &str) -> bool {
+        self.items.data.iter().any(|item| item.price.id == price_id)
+    }
+
+    /// Returns when the current billing period ends.
+    ///
+    /// # Returns
+    ///
+    /// The subscription's period end, falling back to its first item's for
+    /// API versions that track periods per item, or `None` if neither is
+    /// set.
+    pub fn current_period_end(&self) -> Option<DateTime<Utc>> {
+        self.current_period_end
+            .or_else(|| {
+                self.items
+                    .data
+                    .first()
+                    .and_then(|item| item.current_period_end)
+            })
+            .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0))
+    }
+}
+
+/// A page of Stripe objects.
+#[derive(Debug, Deserialize)]
+pub struct StripeList<T> {
+    /// The objects on the page.
+    pub data: Vec<T>,
+}
+
+/// A price a subscription is billed for.
+#[derive(Debug, Deserialize)]
+pub struct StripeSubscriptionItem {
+    /// The subscribed price.
+    pub price: StripePrice,
+    /// End of the item's current period, as a Unix timestamp.
+    pub current_period_end: Option<i64>,
+}
+
+/// A Stripe price.
+#[derive(Debug, Deserialize)]
+pub struct StripePrice {
+    /// The price's Stripe ID.
+    pub id: String,
+}
+
+/// Utility for verifying Stripe webhook deliveries.
+pub struct StripeWebhook;
+
+impl StripeWebhook {
+    /// How far a delivery's timestamp may be from the current time, in
+    /// seconds, before it is treated as a replay.
+    pub const TOLERANCE_SECONDS: i64 = 300;
+
+    /// Verifies the `Stripe-Signature` header of a webhook delivery.
+    ///
+    /// The header has the form `t=<timestamp>,v1=<signature>[,v1=...]`,
+    /// where each signature is a hex-encoded HMAC-SHA256 of
+    /// `<timestamp>.<payload>` keyed with the endpoint's signing secret.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` — The raw request body.
+    /// * `header` — The `Stripe-Signature` header value.
+    /// * `secret` — The endpoint's signing secret.
+    /// * `now` — The current Unix timestamp.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a signature matches and the timestamp is within
+    /// [`TOLERANCE_SECONDS`](Self::TOLERANCE_SECONDS) of `now`.
+    pub fn verify_signature(payload: &[u8], header: &str, secret: &str, now: i64) -> bool {
+        let mut timestamp = None;
+        let mut signatures = Vec::new();
+
+        for part in header.split(',') {
+            match part.trim().split_once('=') {
+                Some(("t", value)) => timestamp = value.parse::<i64>().ok(),
+                Some(("v1", value)) => signatures.push(value),
+                _ => {}
+            }
+        }
+
+        let Some(timestamp) = timestamp else {
+            return false;
+        };
+
+        if (now - timestamp).abs() > Self::TOLERANCE_SECONDS {
+            return false;
+        }
+
+        let expected = Self::sign(payload, timestamp, secret);
+
+        signatures
+            .iter()
+            .any(|signature| token::constant_time_eq(signature, &expected))
+    }
+
+    /// Computes the signature Stripe sends for a payload.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` — The raw request body.
+    /// * `timestamp` — The delivery's Unix timestamp.
+    /// * `secret` — The endpoint's signing secret.
+    ///
+    /// # Returns
+    ///
+    /// The lowercase hex-encoded HMAC-SHA256 signature.
+    fn sign(payload: &[u8], timestamp: i64, secret: &str) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(timestamp.to_string().as_bytes());
+        mac.update(b".");
+        mac.update(payload);
+
+        mac.finalize()
+            .into_bytes()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "whsec_test";
+    const PAYLOAD: &[u8] = br#"{"id":"evt_1"}"#;
+
+    #[test]
+    fn accepts_valid_signatures() {
+        let signature = StripeWebhook::sign(PAYLOAD, 1_000, SECRET);
+        let header = format!("t=1000,v1=deadbeef,v1={signature}");
+
+        assert!(StripeWebhook::verify_signature(
+            PAYLOAD, &header, SECRET, 1_060
+        ));
+    }
+
+    #[test]
+    fn rejects_tampered_or_stale_deliveries() {
+        let signature = StripeWebhook::sign(PAYLOAD, 1_000, SECRET);
+        let header = format!("t=1000,v1={signature}");
+
+        assert!(!StripeWebhook::verify_signature(
+            br#"{"id":"evt_2"}"#,
+            &header,
+            SECRET,
+            1_000
+        ));
+        assert!(!StripeWebhook::verify_signature(
+            PAYLOAD,
+            &header,
+            "whsec_other",
+            1_000
+        ));
+        assert!(!StripeWebhook::verify_signature(
+            PAYLOAD, &header, SECRET, 2_000
+        ));
+        assert!(!StripeWebhook::verify_signature(
+            PAYLOAD,
+            &format!("v1={signature}"),
+            SECRET,
+            1_000
+        ));
+    }
+}
@@ -0,0 +1,92 @@
+//! Account data export worker.
+//!
+//! Provides [`AccountExportTask`], which builds the archives of queued
+//! account exports one at a time and deletes finished exports once their
+//! retention period has passed.
+
+use std::time::Duration;
+
+use log::{error, info};
+
+use crate::account_export::{
+    ACCOUNT_EXPORT_RETENTION, ACCOUNT_EXPORT_STALE_AFTER, AccountExportUtil,
+};
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::repo::account_export::AccountExportRepo;
+use crate::routes::app::AppState;
+
+/// Background task that builds queued account exports.
+pub struct AccountExportTask;
+
+impl AccountExportTask {
+    /// Spawns the account export worker on the Tokio runtime.
+    ///
+    /// Checks for queued exports immediately and then every
+    /// [`Config::account_export_interval_seconds`](crate::core::config::Config::account_export_interval_seconds),
+    /// building every export queued since the last check. Failures are
+    /// logged and do not stop the loop.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    pub fn spawn(state: AppState) {
+        let period = Duration::from_secs(state.config.account_export_interval_seconds.max(1));
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(period);
+
+            loop {
+                interval.tick().await;
+
+                if let Err(error) = Self::sweep(&state).await {
+                    error!("Account export sweep failed: {:?}", error);
+                }
+            }
+        });
+    }
+
+    /// Deletes expired exports, then claims and builds queued exports until
+    /// none are left.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// An empty [`ApiResult`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if expired exports cannot be deleted
+    /// or the next export cannot be claimed or updated. An archive that
+    /// cannot be built marks its export failed instead.
+    async fn sweep(state: &AppState) -> ApiResult<()> {
+        let deleted =
+            AccountExportRepo::delete_expired(&state.db_pool, ACCOUNT_EXPORT_RETENTION).await?;
+        if deleted > 0 {
+            info!("Deleted {} expired account exports", deleted);
+        }
+
+        while let Some(export) =
+            AccountExportRepo::claim_next(&state.db_pool, ACCOUNT_EXPORT_STALE_AFTER).await?
+        {
+            match AccountExportUtil::build_archive(&state.db_pool, export.user_id).await {
+                Ok(archive) => {
+                    AccountExportRepo::complete_export(&state.db_pool, export.id, &archive).await?;
+                    info!("Built account export {}", export.id);
+                }
+                Err(error) => {
+                    error!("Account export {} failed: {:?}", export.id, error);
+                    let message = match error {
+                        ApiErrorResponse::NotFound(_) => "The account no longer exists.",
+                        _ => "The archive could not be built.",
+                    };
+                    AccountExportRepo::fail_export(&state.db_pool, export.id, message).await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
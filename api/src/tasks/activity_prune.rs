@@ -0,0 +1,44 @@
+//! API activity pruner.
+//!
+//! Provides [`ActivityPruneTask`], which periodically deletes API activity
+//! entries older than the retention period.
+
+use std::time::Duration;
+
+use log::{error, info};
+
+use crate::repo::activity::ActivityRepo;
+use crate::routes::app::AppState;
+
+/// Seconds between activity prunes.
+const PRUNE_INTERVAL_SECONDS: u64 = 3600;
+
+/// Background task that prunes old API activity.
+pub struct ActivityPruneTask;
+
+impl ActivityPruneTask {
+    /// Spawns the activity pruner on the Tokio runtime.
+    ///
+    /// Prunes immediately and then hourly, deleting entries older than
+    /// [`ACTIVITY_RETENTION_DAYS`](crate::repo::activity::ACTIVITY_RETENTION_DAYS).
+    /// Prune failures are logged and do not stop the loop.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    pub fn spawn(state: AppState) {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(PRUNE_INTERVAL_SECONDS));
+
+            loop {
+                interval.tick().await;
+
+                match ActivityRepo::delete_expired(&state.db_pool).await {
+                    Ok(0) => {}
+                    Ok(count) => info!("Pruned {} API activity entries", count),
+                    Err(error) => error!("API activity prune failed: {:?}", error),
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,228 @@
+//! Budget threshold alert sweeper.
+//!
+//! Provides [`BudgetAlertTask`], which periodically measures every budget's
+//! current-month consumption and emails the owner the first time a budget
+//! crosses each alert threshold in a month. An alert only counts as sent
+//! once its email goes out; failed sends are retried on the next sweep.
+
+use std::time::Duration;
+
+use chrono::{Datelike, Utc};
+use gig_log_common::models::budget::{BUDGET_ALERT_THRESHOLDS, BudgetStatus};
+use log::{error, info};
+
+use crate::core::error::ApiResult;
+use crate::email::senders::budget::BudgetSender;
+use crate::repo::{budget::BudgetRepo, company::CompanyRepo, job::JobRepo, user::UserRepo};
+use crate::routes::app::AppState;
+
+/// How long a claimed alert blocks other sweeps before it can be retried.
+const ALERT_CLAIM_TIMEOUT_MINUTES: i64 = 10;
+
+/// Background task that delivers budget threshold alerts.
+pub struct BudgetAlertTask;
+
+impl BudgetAlertTask {
+    /// Spawns the alert sweeper on the Tokio runtime.
+    ///
+    /// Sweeps immediately and then every
+    /// [`Config::budget_alert_interval_seconds`](crate::core::config::Config::budget_alert_interval_seconds).
+    /// Sweep failures are logged and do not stop the loop.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    pub fn spawn(state: AppState) {
+        let period = Duration::from_secs(state.config.budget_alert_interval_seconds.max(1));
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(period);
+
+            loop {
+                interval.tick().await;
+
+                if let Err(error) = Self::sweep(&state).await {
+                    error!("Budget alert sweep failed: {:?}", error);
+                }
+            }
+        });
+    }
+
+    /// Checks every budget and sends any newly crossed threshold alerts.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// An empty [`ApiResult`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`](crate::core::error::ApiErrorResponse)
+    /// if budgets cannot be loaded. Failures for individual alerts are logged
+    /// and skipped.
+    async fn sweep(state: &AppState) -> ApiResult<()> {
+        let records = BudgetRepo::list_statuses(&state.db_pool, None, None, None).await?;
+
+        for status in records.into_iter().map(BudgetStatus::from) {
+            for threshold in Self::crossed_thresholds(status.percent_used) {
+                if let Err(error) = Self::alert(state, &status, threshold).await {
+                    error!(
+                        "Failed to send {}% alert for budget {}: {:?}",
+                        threshold, status.budget.id, error
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the alert thresholds a budget's consumption has reached.
+    ///
+    /// # Arguments
+    ///
+    /// * `percent_used` — Consumption as a percentage of the monthly limit.
+    ///
+    /// # Returns
+    ///
+    /// The reached thresholds from [`BUDGET_ALERT_THRESHOLDS`], lowest first.
+    fn crossed_thresholds(percent_used: f64) -> impl Iterator<Item = i32> {
+        BUDGET_ALERT_THRESHOLDS
+            .into_iter()
+            .filter(move |threshold| percent_used >= *threshold as f64)
+    }
+
+    /// Sends a single threshold alert if not already sent this month.
+    ///
+    /// Claims the alert first so concurrent sweeps do not send it twice,
+    /// then marks it sent after the email goes out. If sending fails, the
+    /// claim is released so the next sweep retries.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `status` — The budget and its current consumption.
+    /// * `threshold` — The crossed threshold percentage.
+    ///
+    /// # Returns
+    ///
+    /// An empty [`ApiResult`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`](crate::core::error::ApiErrorResponse)
+    /// if claiming the alert, resolving its target, or sending the email
+    /// fails.
+    async fn alert(state: &AppState, status: &BudgetStatus, threshold: i32) -> ApiResult<()> {
+        let budget = &status.budget;
+        let today = Utc::now().date_naive();
+        let period_month = today.with_day(1).unwrap_or(today);
+
+        let Some(alert_id) = BudgetRepo::claim_alert(
+            &state.db_pool,
+            budget.id,
+            period_month,
+            threshold,
+            chrono::Duration::minutes(ALERT_CLAIM_TIMEOUT_MINUTES),
+        )
+        .await?
+        else {
+            return Ok(());
+        };
+
+        if let Err(error) = Self::send(state, status, threshold).await {
+            if let Err(release_error) = BudgetRepo::release_alert(&state.db_pool, alert_id).await {
+                error!(
+                    "Failed to release {}% alert for budget {}: {:?}",
+                    threshold, budget.id, release_error
+                );
+            }
+
+            return Err(error);
+        }
+
+        BudgetRepo::mark_alert_sent(&state.db_pool, alert_id).await?;
+
+        info!("Sent {}% budget alert for budget {}", threshold, budget.id);
+
+        Ok(())
+    }
+
+    /// Emails a budget's owner that it crossed a threshold.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `status` — The budget and its current consumption.
+    /// * `threshold` — The crossed threshold percentage.
+    ///
+    /// # Returns
+    ///
+    /// An empty [`ApiResult`] on success, including when the budget has no
+    /// target to name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`](crate::core::error::ApiErrorResponse)
+    /// if resolving the target or sending the email fails.
+    async fn send(state: &AppState, status: &BudgetStatus, threshold: i32) -> ApiResult<()> {
+        let budget = &status.budget;
+        let target_name = match (budget.company_id, budget.job_id) {
+            (Some(company_id), _) => {
+                CompanyRepo::find_company_by_id(&state.db_pool, budget.user_id, company_id)
+                    .await?
+                    .name
+            }
+            (None, Some(job_id)) => {
+                JobRepo::find_job_by_id(&state.db_pool, budget.user_id, job_id)
+                    .await?
+                    .title
+            }
+            (None, None) => return Ok(()),
+        };
+        let user = UserRepo::find_user_by_id(&state.db_pool, budget.user_id).await?;
+
+        let locale = UserRepo::find_locale(&state.db_pool, budget.user_id).await?;
+
+        BudgetSender::new(state.email_client.clone(), user.email)
+            .with_locale(locale)
+            .send_threshold_alert(
+                &target_name,
+                &budget.budget_type,
+                threshold,
+                status.consumed,
+                budget.monthly_limit,
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alerts_on_each_reached_threshold() {
+        assert_eq!(
+            BudgetAlertTask::crossed_thresholds(79.9).collect::<Vec<_>>(),
+            Vec::<i32>::new()
+        );
+        assert_eq!(
+            BudgetAlertTask::crossed_thresholds(80.0).collect::<Vec<_>>(),
+            vec![80]
+        );
+        assert_eq!(
+            BudgetAlertTask::crossed_thresholds(99.5).collect::<Vec<_>>(),
+            vec![80]
+        );
+        assert_eq!(
+            BudgetAlertTask::crossed_thresholds(130.0).collect::<Vec<_>>(),
+            vec![80, 100]
+        );
+    }
+}
@@ -0,0 +1,80 @@
+//! Contract renewal reminder sweeper.
+//!
+//! Provides [`ContractReminderTask`], which periodically claims contracts
+//! that have entered their renewal reminder window and emails their owners.
+
+use std::time::Duration;
+
+use gig_log_common::models::locale::Locale;
+use log::{error, info};
+
+use crate::core::error::ApiResult;
+use crate::email::senders::contract::ContractSender;
+use crate::repo::contract::ContractRepo;
+use crate::routes::app::AppState;
+
+/// Background task that delivers contract renewal reminders.
+pub struct ContractReminderTask;
+
+impl ContractReminderTask {
+    /// Spawns the reminder sweeper on the Tokio runtime.
+    ///
+    /// Sweeps immediately and then every
+    /// [`Config::contract_reminder_interval_seconds`](crate::core::config::Config::contract_reminder_interval_seconds).
+    /// Sweep failures are logged and do not stop the loop.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    pub fn spawn(state: AppState) {
+        let period = Duration::from_secs(state.config.contract_reminder_interval_seconds.max(1));
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(period);
+
+            loop {
+                interval.tick().await;
+
+                if let Err(error) = Self::sweep(&state).await {
+                    error!("Contract reminder sweep failed: {:?}", error);
+                }
+            }
+        });
+    }
+
+    /// Claims due renewal reminders and emails each contract owner.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// An empty [`ApiResult`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`](crate::core::error::ApiErrorResponse)
+    /// if due reminders cannot be claimed. Failures for individual emails are
+    /// logged and skipped.
+    async fn sweep(state: &AppState) -> ApiResult<()> {
+        let due = ContractRepo::claim_due_renewal_reminders(&state.db_pool).await?;
+
+        for contract in due {
+            let result = ContractSender::new(state.email_client.clone(), contract.email)
+                .with_locale(Locale::parse(&contract.locale).unwrap_or_default())
+                .send_renewal_reminder(&contract.title, &contract.company_name, contract.end_date)
+                .await;
+
+            match result {
+                Ok(()) => info!("Sent renewal reminder for contract {}", contract.id),
+                Err(error) => error!(
+                    "Failed to send renewal reminder for contract {}: {:?}",
+                    contract.id, error
+                ),
+            }
+        }
+
+        Ok(())
+    }
+}
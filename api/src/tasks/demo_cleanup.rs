@@ -0,0 +1,45 @@
+//! Expired demo account purger.
+//!
+//! Provides [`DemoCleanupTask`], which periodically deletes demo accounts
+//! whose lifetime has ended, along with all of their sample data.
+
+use std::time::Duration;
+
+use log::{error, info};
+
+use crate::repo::demo::DemoRepo;
+use crate::routes::app::AppState;
+
+/// Background task that purges expired demo accounts.
+pub struct DemoCleanupTask;
+
+impl DemoCleanupTask {
+    /// Spawns the demo account purger on the Tokio runtime.
+    ///
+    /// Purges immediately and then every
+    /// [`Config::demo_cleanup_interval_seconds`](crate::core::config::Config::demo_cleanup_interval_seconds).
+    /// The purger runs even when demo accounts are disabled so that accounts
+    /// created before they were turned off still expire. Purge failures are
+    /// logged and do not stop the loop.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    pub fn spawn(state: AppState) {
+        let period = Duration::from_secs(state.config.demo_cleanup_interval_seconds.max(1));
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(period);
+
+            loop {
+                interval.tick().await;
+
+                match DemoRepo::delete_expired(&state.db_pool).await {
+                    Ok(0) => {}
+                    Ok(count) => info!("Purged {} expired demo accounts", count),
+                    Err(error) => error!("Demo account purge failed: {:?}", error),
+                }
+            }
+        });
+    }
+}
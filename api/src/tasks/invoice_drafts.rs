@@ -0,0 +1,142 @@
+//! Weekly invoice draft sweeper.
+//!
+//! Provides [`InvoiceDraftTask`], which drafts an invoice each week from
+//! the previous week's unbilled work sessions for every company with
+//! invoice auto-drafting enabled, then emails the owner to review it.
+//! Drafts are never finalized or sent to the company automatically.
+
+use std::time::Duration;
+
+use chrono::{Datelike, Days, NaiveDate, Utc};
+use gig_log_common::models::locale::Locale;
+use log::{error, info};
+
+use crate::core::error::{ApiErrorResponse, ApiResult};
+use crate::email::senders::invoice::InvoiceSender;
+use crate::repo::{company::CompanyRepo, invoice::InvoiceRepo};
+use crate::routes::app::AppState;
+
+/// Background task that drafts weekly invoices.
+pub struct InvoiceDraftTask;
+
+impl InvoiceDraftTask {
+    /// Spawns the invoice draft sweeper on the Tokio runtime.
+    ///
+    /// Sweeps immediately and then every
+    /// [`Config::invoice_draft_interval_seconds`](crate::core::config::Config::invoice_draft_interval_seconds),
+    /// so a week missed while the server was down is drafted on the next
+    /// sweep. Sweep failures are logged and do not stop the loop.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    pub fn spawn(state: AppState) {
+        let period = Duration::from_secs(state.config.invoice_draft_interval_seconds.max(1));
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(period);
+
+            loop {
+                interval.tick().await;
+
+                if let Err(error) = Self::sweep(&state).await {
+                    error!("Invoice draft sweep failed: {:?}", error);
+                }
+            }
+        });
+    }
+
+    /// Claims companies due a draft for the current week and drafts an
+    /// invoice from each one's work in the previous week.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// An empty [`ApiResult`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`] if due companies cannot be claimed.
+    /// Failures for individual companies are logged and skipped.
+    async fn sweep(state: &AppState) -> ApiResult<()> {
+        let week_start = Self::week_start(Utc::now().date_naive());
+        let previous_week_start = week_start - Days::new(7);
+        let period = (
+            previous_week_start.and_hms_opt(0, 0, 0).unwrap().and_utc(),
+            week_start.and_hms_opt(0, 0, 0).unwrap().and_utc(),
+        );
+
+        let due = CompanyRepo::claim_due_invoice_drafts(&state.db_pool, week_start).await?;
+
+        for company in due {
+            let invoice = match InvoiceRepo::attach_unbilled_sessions(
+                &state.db_pool,
+                company.user_id,
+                company.id,
+                Some(period),
+            )
+            .await
+            {
+                Ok(invoice) => invoice,
+                Err(ApiErrorResponse::BadRequest(_)) => continue,
+                Err(error) => {
+                    error!(
+                        "Failed to draft weekly invoice for company {}: {:?}",
+                        company.id, error
+                    );
+                    continue;
+                }
+            };
+
+            let result = InvoiceSender::new(state.email_client.clone(), company.email)
+                .with_locale(Locale::parse(&company.locale).unwrap_or_default())
+                .send_draft_ready(&company.name, previous_week_start, invoice.total_amount)
+                .await;
+
+            match result {
+                Ok(()) => info!(
+                    "Drafted weekly invoice {} for company {}",
+                    invoice.id, company.id
+                ),
+                Err(error) => error!(
+                    "Failed to send invoice draft notice for company {}: {:?}",
+                    company.id, error
+                ),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Finds the Monday that starts the week containing a date.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` — Any day of the week.
+    ///
+    /// # Returns
+    ///
+    /// The Monday on or before `date`.
+    fn week_start(date: NaiveDate) -> NaiveDate {
+        date - Days::new(u64::from(date.weekday().num_days_from_monday()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn week_starts_on_the_preceding_monday() {
+        let monday = NaiveDate::from_ymd_opt(2026, 10, 12).unwrap();
+
+        assert_eq!(InvoiceDraftTask::week_start(monday), monday);
+        assert_eq!(
+            InvoiceDraftTask::week_start(NaiveDate::from_ymd_opt(2026, 10, 18).unwrap()),
+            monday
+        );
+    }
+}
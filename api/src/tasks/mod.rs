@@ -0,0 +1,31 @@
+//! Background tasks for the GigLog API.
+//!
+//! Each sub-module exposes a task struct with a `spawn` method that starts a
+//! Tokio interval loop during application startup. Tasks share the
+//! [`AppState`](crate::routes::app::AppState) used by request handlers.
+//!
+//! # Modules
+//!
+//! - [`account_exports`](crate::tasks::account_exports) — Account data export worker.
+//! - [`activity_prune`](crate::tasks::activity_prune) — Old API activity pruner.
+//! - [`budget_alerts`](crate::tasks::budget_alerts) — Budget threshold alert sweeper.
+//! - [`contract_reminders`](crate::tasks::contract_reminders) — Contract renewal reminder sweeper.
+//! - [`demo_cleanup`](crate::tasks::demo_cleanup) — Expired demo account purger.
+//! - [`invoice_drafts`](crate::tasks::invoice_drafts) — Weekly invoice draft sweeper.
+//! - [`payment_reminders`](crate::tasks::payment_reminders) — Upcoming and overdue payment reminder sweeper.
+//! - [`recurring_payments`](crate::tasks::recurring_payments) — Recurring payment scheduler.
+//! - [`report_cache`](crate::tasks::report_cache) — Stale report regenerator.
+//! - [`webhook_deliveries`](crate::tasks::webhook_deliveries) — Webhook delivery worker.
+//! - [`work_session_limits`](crate::tasks::work_session_limits) — Overlong work session auto-completer.
+
+pub mod account_exports;
+pub mod activity_prune;
+pub mod budget_alerts;
+pub mod contract_reminders;
+pub mod demo_cleanup;
+pub mod invoice_drafts;
+pub mod payment_reminders;
+pub mod recurring_payments;
+pub mod report_cache;
+pub mod webhook_deliveries;
+pub mod work_session_limits;
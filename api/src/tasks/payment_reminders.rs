@@ -0,0 +1,98 @@
+//! Payment reminder sweeper.
+//!
+//! Provides [`PaymentReminderTask`], which periodically claims upcoming
+//! and overdue payment reminders and emails the payments' owners.
+
+use std::time::Duration;
+
+use chrono::Utc;
+use gig_log_common::models::locale::Locale;
+use log::{error, info};
+
+use crate::core::error::ApiResult;
+use crate::email::senders::payment::PaymentSender;
+use crate::repo::payment::PaymentRepo;
+use crate::routes::app::AppState;
+
+/// Days after the expected payout date that an overdue reminder can still
+/// be sent.
+const OVERDUE_REMINDER_WINDOW_DAYS: i32 = 30;
+
+/// Background task that delivers payment reminders.
+pub struct PaymentReminderTask;
+
+impl PaymentReminderTask {
+    /// Spawns the reminder sweeper on the Tokio runtime.
+    ///
+    /// Sweeps immediately and then every
+    /// [`Config::payment_reminder_interval_seconds`](crate::core::config::Config::payment_reminder_interval_seconds).
+    /// Sweep failures are logged and do not stop the loop.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    pub fn spawn(state: AppState) {
+        let period = Duration::from_secs(state.config.payment_reminder_interval_seconds.max(1));
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(period);
+
+            loop {
+                interval.tick().await;
+
+                if let Err(error) = Self::sweep(&state).await {
+                    error!("Payment reminder sweep failed: {:?}", error);
+                }
+            }
+        });
+    }
+
+    /// Claims due payment reminders and emails each payment owner.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// An empty [`ApiResult`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`](crate::core::error::ApiErrorResponse)
+    /// if due reminders cannot be claimed. Failures for individual emails are
+    /// logged and skipped.
+    async fn sweep(state: &AppState) -> ApiResult<()> {
+        let days_before =
+            i32::try_from(state.config.payment_reminder_days_before).unwrap_or(i32::MAX);
+        let due = PaymentRepo::claim_due_reminders(
+            &state.db_pool,
+            Utc::now().date_naive(),
+            days_before,
+            OVERDUE_REMINDER_WINDOW_DAYS,
+        )
+        .await?;
+
+        for reminder in due {
+            let result = PaymentSender::new(state.email_client.clone(), reminder.email)
+                .with_locale(Locale::parse(&reminder.locale).unwrap_or_default())
+                .send_reminder(
+                    reminder.stage,
+                    &reminder.company_name,
+                    reminder.amount_outstanding,
+                    reminder.expected_payout_date,
+                )
+                .await;
+
+            match result {
+                Ok(()) => info!("Sent payment reminder for payment {}", reminder.id),
+                Err(error) => error!(
+                    "Failed to send payment reminder for payment {}: {:?}",
+                    reminder.id, error
+                ),
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+//! Recurring payment scheduler.
+//!
+//! Provides [`RecurringPaymentTask`], which periodically creates concrete
+//! payments for recurring payment occurrences whose date has arrived.
+
+use std::time::Duration;
+
+use chrono::Utc;
+use gig_log_common::models::webhook::WebhookEvent;
+use log::{error, info};
+
+use crate::repo::recurring_payment::RecurringPaymentRepo;
+use crate::routes::app::AppState;
+use crate::webhook::WebhookDispatcher;
+
+/// Background task that materializes due recurring payments.
+pub struct RecurringPaymentTask;
+
+impl RecurringPaymentTask {
+    /// Spawns the recurring payment scheduler on the Tokio runtime.
+    ///
+    /// Runs immediately and then every
+    /// [`Config::recurring_payment_interval_seconds`](crate::core::config::Config::recurring_payment_interval_seconds),
+    /// catching up on any occurrences missed while the server was down.
+    /// Failures are logged and do not stop the loop.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    pub fn spawn(state: AppState) {
+        let period = Duration::from_secs(state.config.recurring_payment_interval_seconds.max(1));
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(period);
+
+            loop {
+                interval.tick().await;
+
+                let today = Utc::now().date_naive();
+
+                match RecurringPaymentRepo::materialize_due(&state.db_pool, today).await {
+                    Ok(payments) if payments.is_empty() => {}
+                    Ok(payments) => {
+                        info!(
+                            "Created {} payments from recurring schedules",
+                            payments.len()
+                        );
+
+                        for payment in payments {
+                            WebhookDispatcher::publish(
+                                &state.db_pool,
+                                payment.user_id,
+                                WebhookEvent::PaymentCreated(payment),
+                            )
+                            .await;
+                        }
+                    }
+                    Err(error) => error!("Recurring payment sweep failed: {:?}", error),
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,103 @@
+//! Stale report regenerator.
+//!
+//! Provides [`ReportCacheTask`], which periodically regenerates cached
+//! reports that were marked stale or have grown old, and deletes cached
+//! reports nobody has read in a while.
+
+use std::time::Duration;
+
+use log::{error, info};
+
+use crate::core::error::ApiResult;
+use crate::repo::report::ReportRepo;
+use crate::report_cache::{CachedReport, ReportCache};
+use crate::routes::app::AppState;
+
+/// Seconds between refresh sweeps.
+const REFRESH_INTERVAL_SECONDS: u64 = 300;
+
+/// Age after which a cached report is regenerated even if no write marked
+/// it stale.
+const REPORT_MAX_AGE_SECONDS: i64 = 3600;
+
+/// Days without a read after which a cached report is no longer
+/// regenerated and is deleted.
+const REPORT_IDLE_DAYS: i64 = 7;
+
+/// Most reports regenerated per sweep.
+const REFRESH_BATCH_SIZE: i64 = 100;
+
+/// Background task that keeps cached reports fresh.
+pub struct ReportCacheTask;
+
+impl ReportCacheTask {
+    /// Spawns the report regenerator on the Tokio runtime.
+    ///
+    /// Sweeps immediately and then every five minutes. Sweep failures are
+    /// logged and do not stop the loop.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    pub fn spawn(state: AppState) {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(REFRESH_INTERVAL_SECONDS));
+
+            loop {
+                interval.tick().await;
+
+                if let Err(error) = Self::sweep(&state).await {
+                    error!("Report cache sweep failed: {:?}", error);
+                }
+            }
+        });
+    }
+
+    /// Deletes idle cached reports and regenerates stale ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    ///
+    /// # Returns
+    ///
+    /// An empty [`ApiResult`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`](crate::core::error::ApiErrorResponse)
+    /// if idle reports cannot be deleted or stale reports cannot be listed.
+    /// Failures for individual reports are logged and skipped.
+    async fn sweep(state: &AppState) -> ApiResult<()> {
+        let deleted = ReportRepo::delete_idle_reports(&state.db_pool, REPORT_IDLE_DAYS).await?;
+
+        if deleted > 0 {
+            info!("Deleted {} idle cached reports", deleted);
+        }
+
+        let stale = ReportRepo::list_stale_reports(
+            &state.db_pool,
+            REPORT_MAX_AGE_SECONDS,
+            REPORT_IDLE_DAYS,
+            REFRESH_BATCH_SIZE,
+        )
+        .await?;
+
+        for record in stale {
+            let report = match serde_json::from_value::<CachedReport>(record.report) {
+                Ok(report) => report,
+                Err(error) => {
+                    error!("Skipped unreadable cached report key: {:?}", error);
+                    continue;
+                }
+            };
+
+            if let Err(error) = ReportCache::refresh(&state.db_pool, record.user_id, &report).await
+            {
+                error!("Failed to refresh cached report: {:?}", error);
+            }
+        }
+
+        Ok(())
+    }
+}
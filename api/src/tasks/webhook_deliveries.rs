@@ -0,0 +1,126 @@
+//! Webhook delivery worker.
+//!
+//! Provides [`WebhookDeliveryTask`], which sends queued webhook deliveries,
+//! schedules retries for failed attempts, and prunes old entries from the
+//! delivery log.
+
+use std::time::Duration;
+
+use chrono::Utc;
+use log::{error, info, warn};
+use reqwest::Client;
+
+use crate::core::error::ApiResult;
+use crate::repo::webhook::WebhookRepo;
+use crate::routes::app::AppState;
+use crate::webhook::{WEBHOOK_DELIVERY_RETENTION, WebhookDispatcher};
+
+/// Deliveries claimed at a time.
+const CLAIM_BATCH_SIZE: i64 = 50;
+
+/// How long a claimed delivery is reserved before another worker may retry
+/// it.
+const CLAIM_LEASE: chrono::Duration = chrono::Duration::minutes(5);
+
+/// Background task that sends webhook deliveries.
+pub struct WebhookDeliveryTask;
+
+impl WebhookDeliveryTask {
+    /// Spawns the webhook delivery worker on the Tokio runtime.
+    ///
+    /// Checks for due deliveries immediately and then every
+    /// [`Config::webhook_delivery_interval_seconds`](crate::core::config::Config::webhook_delivery_interval_seconds),
+    /// sending every delivery that has come due since the last check.
+    /// Redirects are not followed. Failures are logged and do not stop the
+    /// loop.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    pub fn spawn(state: AppState) {
+        let period = Duration::from_secs(state.config.webhook_delivery_interval_seconds.max(1));
+
+        tokio::spawn(async move {
+            let client = match WebhookDispatcher::client() {
+                Ok(client) => client,
+                Err(error) => {
+                    error!("Failed to build webhook HTTP client: {:?}", error);
+                    return;
+                }
+            };
+            let mut interval = tokio::time::interval(period);
+
+            loop {
+                interval.tick().await;
+
+                if let Err(error) = Self::sweep(&state, &client).await {
+                    error!("Webhook delivery sweep failed: {:?}", error);
+                }
+            }
+        });
+    }
+
+    /// Deletes expired deliveries, then claims and sends due deliveries
+    /// until none are left.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    /// * `client` — The HTTP client to send with.
+    ///
+    /// # Returns
+    ///
+    /// An empty [`ApiResult`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApiErrorResponse`](crate::core::error::ApiErrorResponse)
+    /// if expired deliveries cannot be deleted or due deliveries cannot be
+    /// claimed or updated. A failed attempt is recorded on its delivery
+    /// instead.
+    async fn sweep(state: &AppState, client: &Client) -> ApiResult<()> {
+        let deleted =
+            WebhookRepo::delete_expired(&state.db_pool, WEBHOOK_DELIVERY_RETENTION).await?;
+        if deleted > 0 {
+            info!("Deleted {} expired webhook deliveries", deleted);
+        }
+
+        loop {
+            let deliveries =
+                WebhookRepo::claim_due(&state.db_pool, CLAIM_BATCH_SIZE, CLAIM_LEASE).await?;
+            if deliveries.is_empty() {
+                return Ok(());
+            }
+
+            for delivery in deliveries {
+                match WebhookDispatcher::deliver(client, &delivery).await {
+                    Ok(response_status) => {
+                        WebhookRepo::record_success(&state.db_pool, delivery.id, response_status)
+                            .await?;
+                    }
+                    Err(failure) => {
+                        let attempts = delivery.attempts + 1;
+                        let retry_at = WebhookDispatcher::retry_delay(attempts)
+                            .map(|delay| Utc::now() + delay);
+
+                        if retry_at.is_none() {
+                            warn!(
+                                "Abandoned webhook delivery {} after {} attempts: {}",
+                                delivery.id, attempts, failure.error
+                            );
+                        }
+
+                        WebhookRepo::record_failure(
+                            &state.db_pool,
+                            delivery.id,
+                            failure.response_status,
+                            &failure.error,
+                            retry_at,
+                        )
+                        .await?;
+                    }
+                }
+            }
+        }
+    }
+}
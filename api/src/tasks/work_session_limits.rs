@@ -0,0 +1,83 @@
+//! Work session duration limit sweeper.
+//!
+//! Provides [`WorkSessionLimitTask`], which periodically completes work
+//! sessions that have run past the maximum session duration, so a
+//! forgotten timer does not record days of work. Completed sessions are
+//! pushed to their owners' live sync connections and get mileage
+//! suggestions like manually completed ones.
+
+use std::time::Duration;
+
+use gig_log_common::models::{live_sync::LiveSyncMessage, webhook::WebhookEvent};
+use log::{error, info};
+
+use crate::mileage::MileageSuggester;
+use crate::repo::work_session::WorkSessionRepo;
+use crate::routes::app::AppState;
+use crate::webhook::WebhookDispatcher;
+
+/// Seconds between work session limit sweeps.
+const SWEEP_INTERVAL_SECONDS: u64 = 300;
+
+/// Background task that auto-completes overlong work sessions.
+pub struct WorkSessionLimitTask;
+
+impl WorkSessionLimitTask {
+    /// Spawns the work session limit sweeper on the Tokio runtime.
+    ///
+    /// Sweeps immediately and then every five minutes, completing sessions
+    /// with more worked time than
+    /// [`Config::work_session_max_duration_seconds`](crate::core::config::Config::work_session_max_duration_seconds).
+    /// Does nothing when the limit is `0`. Sweep failures are logged and do
+    /// not stop the loop.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` — The shared [`AppState`].
+    pub fn spawn(state: AppState) {
+        let max_seconds = state.config.work_session_max_duration_seconds;
+
+        if max_seconds == 0 {
+            return;
+        }
+
+        let max_duration = chrono::Duration::seconds(max_seconds as i64);
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(SWEEP_INTERVAL_SECONDS));
+
+            loop {
+                interval.tick().await;
+
+                let sessions =
+                    match WorkSessionRepo::complete_overdue_sessions(&state.db_pool, max_duration)
+                        .await
+                    {
+                        Ok(sessions) => sessions,
+                        Err(error) => {
+                            error!("Work session limit sweep failed: {:?}", error);
+                            continue;
+                        }
+                    };
+
+                if !sessions.is_empty() {
+                    info!("Auto-completed {} overlong work sessions", sessions.len());
+                }
+
+                for session in sessions {
+                    MileageSuggester::suggest(&state, &session).await;
+                    state.live_sync.publish(
+                        session.user_id,
+                        LiveSyncMessage::WorkSessionUpdated(session.clone()),
+                    );
+                    WebhookDispatcher::publish(
+                        &state.db_pool,
+                        session.user_id,
+                        WebhookEvent::WorkSessionCompleted(session),
+                    )
+                    .await;
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,129 @@
+//! Toggl Track API compatibility helpers.
+//!
+//! Provides [`TogglUtil`] for converting work sessions into Toggl's time
+//! entry shape and for parsing the date bounds Toggl clients send, along
+//! with the fixed workspace ID every user's entries are reported under.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use gig_log_common::models::toggl::TogglTimeEntry;
+
+use crate::repo::work_session::TogglEntryRecord;
+
+/// Workspace ID reported for every entry; each user has a single workspace.
+pub const TOGGL_WORKSPACE_ID: i64 = 1;
+
+/// Utility for mapping work sessions to Toggl API shapes.
+pub struct TogglUtil;
+
+impl TogglUtil {
+    /// Converts a work session record into a Toggl time entry.
+    ///
+    /// Running and paused sessions are reported as running entries with a
+    /// `None` stop and a negated start epoch as their duration.
+    ///
+    /// # Arguments
+    ///
+    /// * `record` — The work session joined with its job's Toggl ID.
+    ///
+    /// # Returns
+    ///
+    /// The equivalent [`TogglTimeEntry`].
+    pub fn to_time_entry(record: TogglEntryRecord) -> TogglTimeEntry {
+        let (stop, duration) = if record.is_running {
+            (None, -record.start_time.timestamp())
+        } else {
+            (record.end_time, record.net_seconds.max(0))
+        };
+
+        TogglTimeEntry {
+            id: record.toggl_id,
+            workspace_id: TOGGL_WORKSPACE_ID,
+            project_id: Some(record.project_toggl_id),
+            task_id: None,
+            billable: record.billable,
+            start: record.start_time,
+            stop,
+            duration,
+            description: None,
+            tags: Vec::new(),
+            duronly: false,
+            at: record.updated_at,
+        }
+    }
+
+    /// Parses a Toggl date bound.
+    ///
+    /// Accepts RFC 3339 timestamps and `YYYY-MM-DD` dates, which are read as
+    /// midnight UTC.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — The raw query parameter value.
+    ///
+    /// # Returns
+    ///
+    /// The parsed timestamp, or `None` if the value is not recognized.
+    pub fn parse_bound(value: &str) -> Option<DateTime<Utc>> {
+        let value = value.trim();
+
+        if let Ok(timestamp) = DateTime::parse_from_rfc3339(value) {
+            return Some(timestamp.with_timezone(&Utc));
+        }
+
+        NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .ok()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .map(|timestamp| timestamp.and_utc())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn record(is_running: bool) -> TogglEntryRecord {
+        let start_time = Utc.with_ymd_and_hms(2026, 10, 16, 9, 0, 0).unwrap();
+
+        TogglEntryRecord {
+            toggl_id: 7,
+            project_toggl_id: 3,
+            billable: true,
+            start_time,
+            end_time: (!is_running).then(|| start_time + chrono::Duration::hours(2)),
+            is_running,
+            net_seconds: 5400,
+            updated_at: start_time,
+        }
+    }
+
+    #[test]
+    fn completed_sessions_report_net_duration() {
+        let entry = TogglUtil::to_time_entry(record(false));
+
+        assert_eq!(entry.duration, 5400);
+        assert!(entry.stop.is_some());
+        assert_eq!(entry.project_id, Some(3));
+    }
+
+    #[test]
+    fn running_sessions_report_negated_start() {
+        let entry = TogglUtil::to_time_entry(record(true));
+
+        assert_eq!(entry.duration, -entry.start.timestamp());
+        assert!(entry.stop.is_none());
+    }
+
+    #[test]
+    fn parses_dates_and_timestamps() {
+        let midnight = Utc.with_ymd_and_hms(2026, 10, 16, 0, 0, 0).unwrap();
+
+        assert_eq!(TogglUtil::parse_bound("2026-10-16"), Some(midnight));
+        assert_eq!(
+            TogglUtil::parse_bound("2026-10-16T02:00:00+02:00"),
+            Some(midnight)
+        );
+        assert_eq!(TogglUtil::parse_bound("yesterday"), None);
+    }
+}
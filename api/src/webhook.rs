@@ -0,0 +1,479 @@
+//! Outgoing webhooks.
+//!
+//! Provides [`WebhookDispatcher`], which handlers and background tasks use
+//! to publish [`WebhookEvent`]s. Publishing queues one delivery per
+//! subscribed webhook; the
+//! [`WebhookDeliveryTask`](crate::tasks::webhook_deliveries::WebhookDeliveryTask)
+//! worker POSTs each one with an HMAC-SHA256 signature and retries failures
+//! with exponential backoff. Test deliveries are sent the same way but are
+//! not queued or retried. Deliveries are only sent to public addresses,
+//! checked after DNS resolution so a registered host name cannot be rebound
+//! to a private one.
+
+use std::net::SocketAddr;
+
+use chrono::{Duration, Utc};
+use gig_log_common::models::webhook::{WebhookEvent, WebhookPayload};
+use gig_log_common::validators::webhook::{is_public_ip, is_reserved_host_name};
+use hmac::{Hmac, Mac};
+use log::error;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use reqwest::{Client, Url, header, redirect};
+use serde_json::json;
+use sha2::Sha256;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::repo::webhook::{ClaimedDeliveryRecord, WebhookRepo};
+
+/// Attempts made before a delivery is abandoned.
+pub const WEBHOOK_MAX_ATTEMPTS: i32 = 8;
+
+/// Delay before the first retry. Each later retry waits twice as long.
+pub const WEBHOOK_RETRY_BASE_DELAY: Duration = Duration::minutes(1);
+
+/// How long a webhook's URL has to respond to a delivery.
+pub const WEBHOOK_TIMEOUT: Duration = Duration::seconds(10);
+
+/// How long finished deliveries are kept in the delivery log.
+pub const WEBHOOK_DELIVERY_RETENTION: Duration = Duration::days(30);
+
+/// Header carrying the delivery's signature.
+pub const SIGNATURE_HEADER: &str = "GigLog-Signature";
+
+/// Header carrying the delivery's event type.
+pub const EVENT_HEADER: &str = "GigLog-Event";
+
+/// Header carrying the delivery's ID.
+pub const DELIVERY_HEADER: &str = "GigLog-Delivery";
+
+/// Event type of test deliveries. Webhooks cannot subscribe to it.
+pub const TEST_EVENT_TYPE: &str = "webhook.test";
+
+/// Why a delivery attempt failed.
+#[derive(Debug)]
+pub struct DeliveryFailure {
+    /// The HTTP status received, if the URL responded.
+    pub response_status: Option<i32>,
+    /// Description of the failure shown in the delivery log.
+    pub error: String,
+}
+
+/// DNS resolver that only returns public addresses.
+///
+/// Used by the delivery client so a host name that passed registration
+/// cannot later resolve to a loopback, private, or metadata address.
+struct PublicAddressResolver;
+
+impl Resolve for PublicAddressResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addrs = tokio::net::lookup_host((name.as_str(), 0)).await?;
+            let addrs = Self::public_addrs(name.as_str(), addrs)?;
+
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+impl PublicAddressResolver {
+    /// Drops the non-public addresses a host name resolved to.
+    ///
+    /// # Arguments
+    ///
+    /// * `host` — The resolved host name, used in the error message.
+    /// * `addrs` — The addresses the host name resolved to.
+    ///
+    /// # Returns
+    ///
+    /// The public addresses.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if none of the addresses are public.
+    fn public_addrs(
+        host: &str,
+        addrs: impl IntoIterator<Item = SocketAddr>,
+    ) -> Result<Vec<SocketAddr>, String> {
+        let addrs: Vec<SocketAddr> = addrs
+            .into_iter()
+            .filter(|addr| is_public_ip(addr.ip()))
+            .collect();
+
+        if addrs.is_empty() {
+            return Err(format!("{host} does not resolve to a public address"));
+        }
+
+        Ok(addrs)
+    }
+}
+
+/// Utility for publishing and delivering webhook events.
+pub struct WebhookDispatcher;
+
+impl WebhookDispatcher {
+    /// Publishes an event to the user's subscribed webhooks.
+    ///
+    /// Failures to queue the event are logged rather than returned, so the
+    /// change that caused the event is not reported as failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` — The database connection pool.
+    /// * `user_id` — The user the event belongs to.
+    /// * `event` — The event to deliver.
+    pub async fn publish(pool: &Pool<Postgres>, user_id: Uuid, event: WebhookEvent) {
+        let event_type = event.event_type();
+        let payload = WebhookPayload {
+            id: Uuid::new_v4(),
+            created_at: Utc::now(),
+            event,
+        };
+
+        let body = match serde_json::to_string(&payload) {
+            Ok(body) => body,
+            Err(error) => {
+                error!(
+                    "Failed to serialize {} webhook event: {:?}",
+                    event_type.as_str(),
+                    error
+                );
+                return;
+            }
+        };
+
+        if let Err(error) = WebhookRepo::enqueue(pool, user_id, payload.id, event_type, &body).await
+        {
+            error!(
+                "Failed to queue {} webhook event: {:?}",
+                event_type.as_str(),
+                error
+            );
+        }
+    }
+
+    /// Builds the HTTP client deliveries are sent with.
+    ///
+    /// Requests time out after [`WEBHOOK_TIMEOUT`], redirects are not
+    /// followed, and host names are only resolved to public addresses.
+    ///
+    /// # Returns
+    ///
+    /// The configured [`Client`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`reqwest::Error`] if the client cannot be built.
+    pub fn client() -> reqwest::Result<Client> {
+        Client::builder()
+            .timeout(
+                WEBHOOK_TIMEOUT
+                    .to_std()
+                    .unwrap_or(std::time::Duration::from_secs(10)),
+            )
+            .redirect(redirect::Policy::none())
+            .dns_resolver(PublicAddressResolver)
+            .build()
+    }
+
+    /// Sends a claimed delivery to its webhook's URL.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` — The HTTP client to send with.
+    /// * `delivery` — The claimed delivery.
+    ///
+    /// # Returns
+    ///
+    /// The `2xx` status the URL responded with.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DeliveryFailure`] if the request fails or the URL
+    /// responds with any other status.
+    pub async fn deliver(
+        client: &Client,
+        delivery: &ClaimedDeliveryRecord,
+    ) -> Result<i32, DeliveryFailure> {
+        Self::send(
+            client,
+            &delivery.url,
+            &delivery.secret,
+            &delivery.event_type,
+            delivery.id,
+            &delivery.payload,
+        )
+        .await
+    }
+
+    /// Builds the body of a test delivery.
+    ///
+    /// The payload has the same shape as real events, with a
+    /// [`TEST_EVENT_TYPE`] type and the webhook's ID as its data.
+    ///
+    /// # Arguments
+    ///
+    /// * `event_id` — The test event's ID.
+    /// * `webhook_id` — The webhook being tested.
+    ///
+    /// # Returns
+    ///
+    /// The JSON body to POST.
+    pub fn test_payload(event_id: Uuid, webhook_id: Uuid) -> String {
+        json!({
+            "id": event_id,
+            "created_at": Utc::now(),
+            "type": TEST_EVENT_TYPE,
+            "data": { "webhook_id": webhook_id },
+        })
+        .to_string()
+    }
+
+    /// Signs and POSTs a payload to a webhook's URL.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` — The HTTP client to send with.
+    /// * `url` — The webhook's URL.
+    /// * `secret` — The webhook's signing secret.
+    /// * `event_type` — The serialized event type name.
+    /// * `delivery_id` — The delivery's ID.
+    /// * `payload` — The JSON body to POST.
+    ///
+    /// # Returns
+    ///
+    /// The `2xx` status the URL responded with.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DeliveryFailure`] if the URL does not point to a public
+    /// host, the request fails, or the URL responds with any other status.
+    pub async fn send(
+        client: &Client,
+        url: &str,
+        secret: &str,
+        event_type: &str,
+        delivery_id: Uuid,
+        payload: &str,
+    ) -> Result<i32, DeliveryFailure> {
+        if !Self::is_public_url(url) {
+            return Err(DeliveryFailure {
+                response_status: None,
+                error: "URL does not point to a public host".to_string(),
+            });
+        }
+
+        let timestamp = Utc::now().timestamp();
+        let signature = Self::sign(payload.as_bytes(), timestamp, secret);
+
+        let response = client
+            .post(url)
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(SIGNATURE_HEADER, format!("t={timestamp},v1={signature}"))
+            .header(EVENT_HEADER, event_type)
+            .header(DELIVERY_HEADER, delivery_id.to_string())
+            .body(payload.to_string())
+            .send()
+            .await
+            .map_err(|error| DeliveryFailure {
+                response_status: None,
+                error: error.to_string(),
+            })?;
+
+        let status = response.status();
+        if status.is_success() {
+            Ok(i32::from(status.as_u16()))
+        } else {
+            Err(DeliveryFailure {
+                response_status: Some(i32::from(status.as_u16())),
+                error: format!("Endpoint responded with {status}"),
+            })
+        }
+    }
+
+    /// Checks whether a URL's host may receive deliveries.
+    ///
+    /// IP addresses are checked here, since the client does not resolve
+    /// them. Host names only have to be unreserved; the addresses they
+    /// resolve to are checked by the client's resolver.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` — The webhook's URL.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the URL parses and its host is a public IP address or an
+    /// unreserved host name.
+    pub fn is_public_url(url: &str) -> bool {
+        let Ok(url) = Url::parse(url) else {
+            return false;
+        };
+        let Some(host) = url.host_str() else {
+            return false;
+        };
+
+        match host.trim_start_matches('[').trim_end_matches(']').parse() {
+            Ok(ip) => is_public_ip(ip),
+            Err(_) => !is_reserved_host_name(host),
+        }
+    }
+
+    /// Returns how long to wait before retrying a failed delivery.
+    ///
+    /// # Arguments
+    ///
+    /// * `attempts` — The number of attempts made so far, including the one
+    ///   that just failed.
+    ///
+    /// # Returns
+    ///
+    /// The delay before the next attempt, or `None` if the delivery has
+    /// used all [`WEBHOOK_MAX_ATTEMPTS`] and should be abandoned.
+    pub fn retry_delay(attempts: i32) -> Option<Duration> {
+        if attempts >= WEBHOOK_MAX_ATTEMPTS {
+            return None;
+        }
+
+        Some(WEBHOOK_RETRY_BASE_DELAY * 2_i32.pow((attempts.max(1) - 1) as u32))
+    }
+
+    /// Computes the signature sent with a delivery.
+    ///
+    /// Receivers verify it by computing the HMAC-SHA256 of
+    /// `<timestamp>.<body>` with the webhook's secret and comparing it with
+    /// the `v1` value of the signature header.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` — The raw request body.
+    /// * `timestamp` — The attempt's Unix timestamp.
+    /// * `secret` — The webhook's signing secret.
+    ///
+    /// # Returns
+    ///
+    /// The lowercase hex-encoded HMAC-SHA256 signature.
+    pub fn sign(payload: &[u8], timestamp: i64, secret: &str) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(timestamp.to_string().as_bytes());
+        mac.update(b".");
+        mac.update(payload);
+
+        mac.finalize()
+            .into_bytes()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gig_log_common::models::webhook::WebhookEventType;
+
+    use super::*;
+    use crate::subscription::stripe::StripeWebhook;
+
+    #[test]
+    fn signs_payloads_like_stripe() {
+        let payload = br#"{"id":"evt_1"}"#;
+        let signature = WebhookDispatcher::sign(payload, 1_000, "whsec_test");
+
+        assert!(StripeWebhook::verify_signature(
+            payload,
+            &format!("t=1000,v1={signature}"),
+            "whsec_test",
+            1_000
+        ));
+    }
+
+    #[test]
+    fn backs_off_exponentially_until_abandoned() {
+        assert_eq!(
+            WebhookDispatcher::retry_delay(1),
+            Some(Duration::minutes(1))
+        );
+        assert_eq!(
+            WebhookDispatcher::retry_delay(3),
+            Some(Duration::minutes(4))
+        );
+        assert_eq!(
+            WebhookDispatcher::retry_delay(WEBHOOK_MAX_ATTEMPTS - 1),
+            Some(Duration::minutes(64))
+        );
+        assert_eq!(WebhookDispatcher::retry_delay(WEBHOOK_MAX_ATTEMPTS), None);
+    }
+
+    #[test]
+    fn only_sends_to_public_urls() {
+        assert!(WebhookDispatcher::is_public_url(
+            "https://hooks.example.com/giglog"
+        ));
+        assert!(WebhookDispatcher::is_public_url(
+            "http://93.184.216.34/hook"
+        ));
+        assert!(!WebhookDispatcher::is_public_url(
+            "http://127.0.0.1:8000/hook"
+        ));
+        assert!(!WebhookDispatcher::is_public_url(
+            "http://169.254.169.254/latest/meta-data/"
+        ));
+        assert!(!WebhookDispatcher::is_public_url("http://[::1]/hook"));
+        assert!(!WebhookDispatcher::is_public_url("http://localhost/hook"));
+        assert!(!WebhookDispatcher::is_public_url("not a url"));
+    }
+
+    #[test]
+    fn drops_private_addresses_after_resolution() {
+        let public: SocketAddr = "93.184.216.34:0".parse().unwrap();
+        let rebound = [
+            "127.0.0.1:0".parse().unwrap(),
+            "169.254.169.254:0".parse().unwrap(),
+            "[fd00:ec2::254]:0".parse().unwrap(),
+        ];
+
+        assert_eq!(
+            PublicAddressResolver::public_addrs("hooks.example.com", [rebound[0], public]),
+            Ok(vec![public])
+        );
+        assert!(PublicAddressResolver::public_addrs("hooks.example.com", rebound).is_err());
+    }
+
+    #[tokio::test]
+    async fn refuses_host_names_resolving_to_loopback() {
+        let name = "localhost".parse().unwrap();
+
+        assert!(PublicAddressResolver.resolve(name).await.is_err());
+    }
+
+    #[test]
+    fn builds_test_payloads_shaped_like_events() {
+        let event_id = Uuid::new_v4();
+        let webhook_id = Uuid::new_v4();
+
+        let value: serde_json::Value =
+            serde_json::from_str(&WebhookDispatcher::test_payload(event_id, webhook_id)).unwrap();
+
+        assert_eq!(value["type"], TEST_EVENT_TYPE);
+        assert_eq!(value["id"], event_id.to_string());
+        assert_eq!(value["data"]["webhook_id"], webhook_id.to_string());
+        assert!(WebhookEventType::parse(TEST_EVENT_TYPE).is_none());
+    }
+
+    #[test]
+    fn serializes_events_with_type_and_data() {
+        let event_id = Uuid::nil();
+        let payload = WebhookPayload {
+            id: event_id,
+            created_at: Utc::now(),
+            event: WebhookEvent::WorkSessionDeleted { id: event_id },
+        };
+
+        let value = serde_json::to_value(&payload).unwrap();
+
+        assert_eq!(value["type"], "work_session.deleted");
+        assert_eq!(value["data"]["id"], event_id.to_string());
+        assert_eq!(value["id"], event_id.to_string());
+    }
+}
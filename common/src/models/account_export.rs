@@ -0,0 +1,75 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::{
+    appearance::{AppearancePreferences, ColorPalette},
+    company::Company,
+    job::Job,
+    payment::Payment,
+    user::User,
+    work_session::WorkSession,
+};
+
+/// Progress of an account export. Serialized as `snake_case`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountExportStatus {
+    /// Waiting for the background worker to pick it up.
+    Pending,
+    /// The archive is being built.
+    Processing,
+    /// The archive is ready to download.
+    Completed,
+    /// The archive could not be built.
+    Failed,
+}
+
+/// A request to export all of a user's account data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountExport {
+    /// Unique identifier for the export.
+    pub id: Uuid,
+    /// Progress of the export.
+    pub status: AccountExportStatus,
+    /// Why the export failed, if it did.
+    pub error: Option<String>,
+    /// Path to download the archive from, once the export is completed.
+    pub download_url: Option<String>,
+    /// Timestamp when the export was requested.
+    pub created_at: DateTime<Utc>,
+    /// Timestamp when the export finished, successfully or not.
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+/// Settings a user has changed from the server defaults.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountSettings {
+    /// The user's own mileage rate in dollars per mile, if set.
+    pub mileage_rate: Option<f64>,
+    /// The user's own self-employment tax rate as a decimal, if set.
+    pub self_employment_tax_rate: Option<f64>,
+    /// The user's appearance preferences, if they have chosen a palette.
+    pub appearance: Option<AppearancePreferences>,
+}
+
+/// Every record a user owns, as written to an account export archive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountArchive {
+    /// Timestamp when the archive was built.
+    pub exported_at: DateTime<Utc>,
+    /// The user's profile.
+    pub user: User,
+    /// The user's settings.
+    pub settings: AccountSettings,
+    /// The user's companies.
+    pub companies: Vec<Company>,
+    /// The user's jobs.
+    pub jobs: Vec<Job>,
+    /// The user's payments.
+    pub payments: Vec<Payment>,
+    /// The user's work sessions.
+    pub work_sessions: Vec<WorkSession>,
+    /// The user's custom color palettes.
+    pub palettes: Vec<ColorPalette>,
+}
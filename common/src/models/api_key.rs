@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// An API key, without its secret value.
+///
+/// API keys grant the same access as a logged-in session, for headless
+/// clients such as scripts and command-line tools.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiKey {
+    /// Unique identifier for the key.
+    pub id: Uuid,
+    /// Label describing what the key is used for.
+    pub name: String,
+    /// When the key last authenticated a request.
+    pub last_used_at: Option<DateTime<Utc>>,
+    /// Timestamp when the key was created.
+    pub created_at: DateTime<Utc>,
+}
+
+/// Request payload for creating an API key.
+///
+/// When the `"validation"` feature is enabled, `name` must not be empty.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct CreateApiKeyRequest {
+    /// Label describing what the key is used for.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(min = 1, message = "Name is required"))
+    )]
+    pub name: String,
+}
+
+/// Response returned when an API key is created.
+///
+/// This is the only time the raw key is returned.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateApiKeyResponse {
+    /// The raw key to send as `Authorization: Bearer <key>`.
+    pub key: String,
+    /// The created key's details.
+    pub api_key: ApiKey,
+}
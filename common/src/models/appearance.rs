@@ -14,16 +14,18 @@ pub enum PaletteType {
     Custom,
 }
 
-/// Available preset color palettes. Serialized as `kebab-case`.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-#[serde(rename_all = "kebab-case")]
-pub enum PresetPalette {
-    /// Catppuccin color scheme.
-    Catppuccin,
-    /// Tokyo Night color scheme.
-    TokyoNight,
-    /// Everforest color scheme.
-    Everforest,
+/// A built-in color palette from the preset registry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PresetPalette {
+    /// Stable identifier of the preset, e.g. `"tokyo-night"`.
+    pub slug: String,
+    /// Display name of the preset.
+    pub name: String,
+    /// Base seed colors used to generate the palette, in green, red,
+    /// yellow, blue, magenta, cyan order.
+    pub seed_colors: Vec<String>,
+    /// Full set of design tokens for the preset.
+    pub generated_tokens: Value,
 }
 
 /// A user-created custom color palette.
@@ -54,8 +56,9 @@ pub struct AppearancePreferences {
     pub user_id: Uuid,
     /// Whether the user is using a preset or custom palette.
     pub active_palette_type: PaletteType,
-    /// The active preset palette, if `active_palette_type` is `Preset`.
-    pub active_preset_palette: Option<PresetPalette>,
+    /// Slug of the active preset palette, if `active_palette_type` is
+    /// `Preset`.
+    pub active_preset_palette: Option<String>,
     /// The ID of the active custom palette, if `active_palette_type` is `Custom`.
     pub active_custom_palette_id: Option<Uuid>,
 }
@@ -83,8 +86,9 @@ pub struct UpdatePaletteRequest {
 pub struct UpdateActivePaletteRequest {
     /// Whether to activate a preset or custom palette.
     pub active_palette_type: PaletteType,
-    /// The preset palette to activate, if `active_palette_type` is `Preset`.
-    pub active_preset_palette: Option<PresetPalette>,
+    /// Slug of the preset palette to activate, if `active_palette_type` is
+    /// `Preset`.
+    pub active_preset_palette: Option<String>,
     /// The custom palette ID to activate, if `active_palette_type` is `Custom`.
     pub active_custom_palette_id: Option<Uuid>,
 }
@@ -0,0 +1,77 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[cfg(feature = "validation")]
+use crate::validators::budget::validate_budget_target;
+
+/// Percent-of-limit thresholds that trigger budget alerts.
+pub const BUDGET_ALERT_THRESHOLDS: [i32; 2] = [80, 100];
+
+/// What a budget limits. Serialized as `snake_case`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BudgetType {
+    /// Limits hours worked per month.
+    Hours,
+    /// Limits billable dollars earned per month.
+    Amount,
+}
+
+/// A monthly budget on a company or a job.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Budget {
+    /// Unique identifier for the budget.
+    pub id: Uuid,
+    /// The user who owns this budget.
+    pub user_id: Uuid,
+    /// The company this budget applies to. Mutually exclusive with `job_id`.
+    pub company_id: Option<Uuid>,
+    /// The job this budget applies to. Mutually exclusive with `company_id`.
+    pub job_id: Option<Uuid>,
+    /// What the budget limits.
+    pub budget_type: BudgetType,
+    /// Monthly limit in hours or dollars, depending on `budget_type`.
+    pub monthly_limit: f64,
+    /// Timestamp when the budget was created.
+    pub created_at: DateTime<Utc>,
+    /// Timestamp when the budget was last updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A budget together with its consumption for the current month.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BudgetStatus {
+    /// The budget being measured.
+    #[serde(flatten)]
+    pub budget: Budget,
+    /// Hours or dollars consumed so far this month.
+    pub consumed: f64,
+    /// Consumption as a percentage of `monthly_limit`.
+    pub percent_used: f64,
+}
+
+/// Request payload for creating a budget.
+///
+/// When the `"validation"` feature is enabled, exactly one of `company_id`
+/// or `job_id` must be set and `monthly_limit` must be positive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+#[cfg_attr(
+    feature = "validation",
+    validate(schema(function = "validate_budget_target"))
+)]
+pub struct CreateBudgetRequest {
+    /// The company to budget. Mutually exclusive with `job_id`.
+    pub company_id: Option<Uuid>,
+    /// The job to budget. Mutually exclusive with `company_id`.
+    pub job_id: Option<Uuid>,
+    /// What the budget limits.
+    pub budget_type: BudgetType,
+    /// Monthly limit in hours or dollars, depending on `budget_type`.
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(exclusive_min = 0.0, message = "Monthly limit must be greater than 0"))
+    )]
+    pub monthly_limit: f64,
+}
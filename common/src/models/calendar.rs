@@ -0,0 +1,43 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A calendar feed subscription, without its secret token.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalendarFeed {
+    /// Unique identifier for the feed.
+    pub id: Uuid,
+    /// Label describing where the feed is subscribed.
+    pub name: String,
+    /// When a calendar app last fetched the feed.
+    pub last_used_at: Option<DateTime<Utc>>,
+    /// Timestamp when the feed was created.
+    pub created_at: DateTime<Utc>,
+}
+
+/// Request payload for creating a calendar feed.
+///
+/// When the `"validation"` feature is enabled, `name` must not be empty.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct CreateCalendarFeedRequest {
+    /// Label describing where the feed is subscribed.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(min = 1, message = "Name is required"))
+    )]
+    pub name: String,
+}
+
+/// Response returned when a calendar feed is created.
+///
+/// This is the only time the raw token is returned.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateCalendarFeedResponse {
+    /// The raw feed token.
+    pub token: String,
+    /// Path of the iCalendar feed, `/calendar/<token>.ics`.
+    pub path: String,
+    /// The created feed's details.
+    pub calendar_feed: CalendarFeed,
+}
@@ -2,6 +2,14 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::models::{
+    budget::BudgetStatus, contact::Contact, contract::Contract, job::Job, patch::nullable,
+    payment::Payment,
+};
+
+#[cfg(feature = "validation")]
+use crate::validators::company::validate_accent_color;
+
 /// A company that a user works for.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Company {
@@ -16,6 +24,16 @@ pub struct Company {
     /// Tax withholding rate as a decimal (e.g., 0.15 for 15%). Only applicable
     /// when `requires_tax_withholdings` is true.
     pub tax_withholding_rate: Option<f64>,
+    /// Color used to mark the company's jobs and sessions: either a hex
+    /// color such as `#89b4fa`, or a token from the user's palette such as
+    /// `blue-100` that follows the active palette.
+    pub accent_color: Option<String>,
+    /// Whether an invoice is drafted automatically every Monday from the
+    /// previous week's unbilled work sessions.
+    pub auto_draft_invoices: bool,
+    /// Path of the company's resized logo image, or `None` if no logo has
+    /// been uploaded. Changes whenever a new logo is uploaded.
+    pub logo_url: Option<String>,
     /// Timestamp when the company was created.
     pub created_at: DateTime<Utc>,
     /// Timestamp when the company was last updated.
@@ -33,13 +51,162 @@ pub struct CreateCompanyRequest {
     pub tax_withholding_rate: Option<f64>,
 }
 
-/// Request payload for updating an existing company. All fields are optional.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Request payload for partially updating an existing company.
+///
+/// Omitted fields are left unchanged; `tax_withholding_rate` and
+/// `accent_color` may be `null` to clear them. When the `"validation"`
+/// feature is enabled, `name` must not be empty, `tax_withholding_rate`
+/// must be between 0 and 1, and `accent_color` must be a hex color or
+/// palette token. The tax fields are checked against each other once merged
+/// with the stored company.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
 pub struct UpdateCompanyRequest {
     /// Updated company name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(min = 1, max = 255, message = "Name must be 1-255 characters"))
+    )]
     pub name: Option<String>,
     /// Updated tax withholding requirement.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub requires_tax_withholdings: Option<bool>,
-    /// Updated tax withholding rate.
-    pub tax_withholding_rate: Option<f64>,
+    /// Updated tax withholding rate, or `Some(None)` to clear it.
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(
+            min = 0.0,
+            max = 1.0,
+            message = "Tax withholding rate must be between 0 and 1"
+        ))
+    )]
+    pub tax_withholding_rate: Option<Option<f64>>,
+    /// Updated accent color, or `Some(None)` to clear it.
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(
+        feature = "validation",
+        validate(custom(function = "validate_accent_color"))
+    )]
+    pub accent_color: Option<Option<String>>,
+    /// Updated weekly invoice auto-draft setting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_draft_invoices: Option<bool>,
+}
+
+/// Response payload for the company detail endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompanyDetailResponse {
+    /// The requested company.
+    #[serde(flatten)]
+    pub company: Company,
+    /// Budgets on the company with their current-month consumption.
+    pub budgets: Vec<BudgetStatus>,
+    /// Contracts with the company, ordered by start date.
+    pub contracts: Vec<Contract>,
+    /// The company's primary contact, if one is set.
+    pub primary_contact: Option<Contact>,
+    /// Jobs for the company, present when requested with `?include=jobs`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jobs: Option<Vec<Job>>,
+    /// Payments from the company, present when requested with
+    /// `?include=payments`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payments: Option<Vec<Payment>>,
+}
+
+/// Response payload for the company duplication endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateCompanyResponse {
+    /// The newly created company.
+    #[serde(flatten)]
+    pub company: Company,
+    /// Copies of the original company's jobs, in board order.
+    pub jobs: Vec<Job>,
+}
+
+/// Records that deleting a company would remove or detach.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CompanyDeletePreview {
+    /// Jobs that would be deleted.
+    pub jobs: i64,
+    /// Payments that would be deleted.
+    pub payments: i64,
+    /// Work sessions on the company's jobs that would be deleted.
+    pub work_sessions: i64,
+    /// Work sessions among `work_sessions` that are invoiced or approved by
+    /// the client.
+    pub locked_work_sessions: i64,
+    /// Invoices that would be deleted.
+    pub invoices: i64,
+    /// Invoices among `invoices` that are finalized.
+    pub finalized_invoices: i64,
+    /// Timesheet approval requests that would be deleted.
+    pub timesheet_approvals: i64,
+    /// Budgets that would be deleted.
+    pub budgets: i64,
+    /// Contracts that would be deleted.
+    pub contracts: i64,
+    /// Company statements that would be deleted.
+    pub company_statements: i64,
+    /// Contacts that would be deleted.
+    pub contacts: i64,
+    /// Recurring payment schedules that would be deleted.
+    pub recurring_payments: i64,
+    /// Mileage entries that would be kept but lose their job.
+    pub mileage_entries: i64,
+}
+
+impl CompanyDeletePreview {
+    /// Returns whether deleting the company would affect no other records.
+    ///
+    /// # Returns
+    ///
+    /// `true` if every count is zero.
+    pub fn is_empty(&self) -> bool {
+        self.jobs == 0
+            && self.payments == 0
+            && self.work_sessions == 0
+            && self.invoices == 0
+            && self.timesheet_approvals == 0
+            && self.budgets == 0
+            && self.contracts == 0
+            && self.company_statements == 0
+            && self.contacts == 0
+            && self.recurring_payments == 0
+            && self.mileage_entries == 0
+    }
+
+    /// Returns whether deleting the company would remove billed or
+    /// client-approved records.
+    ///
+    /// # Returns
+    ///
+    /// `true` if any finalized invoice or locked work session would be
+    /// deleted.
+    pub fn has_finalized_records(&self) -> bool {
+        self.finalized_invoices > 0 || self.locked_work_sessions > 0
+    }
+}
+
+/// Query parameters for deleting a company.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeleteCompanyQuery {
+    /// Confirms the deletion of a company that has any of the records
+    /// counted by [`CompanyDeletePreview`]. Defaults to `false`.
+    #[serde(default)]
+    pub confirm: bool,
+    /// Also confirms deleting finalized invoices and work sessions that are
+    /// invoiced or approved by the client. Defaults to `false`.
+    #[serde(default)]
+    pub confirm_finalized: bool,
 }
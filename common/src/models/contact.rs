@@ -0,0 +1,129 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::patch::nullable;
+
+/// A person at a company the user works with.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Contact {
+    /// Unique identifier for the contact.
+    pub id: Uuid,
+    /// The user who owns this contact.
+    pub user_id: Uuid,
+    /// The company the contact works at.
+    pub company_id: Uuid,
+    /// Full name of the contact.
+    pub name: String,
+    /// Email address of the contact.
+    pub email: Option<String>,
+    /// Phone number of the contact.
+    pub phone: Option<String>,
+    /// The contact's role at the company, e.g. `"Accounts payable"`.
+    pub role: Option<String>,
+    /// Whether this is the company's primary contact. A company has at most
+    /// one.
+    pub is_primary: bool,
+    /// Timestamp when the contact was created.
+    pub created_at: DateTime<Utc>,
+    /// Timestamp when the contact was last updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Request payload for adding a contact to a company.
+///
+/// When the `"validation"` feature is enabled, `name` must not be empty,
+/// `email` must be a valid address, and `phone` must be at most 50
+/// characters.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct CreateContactRequest {
+    /// Full name of the contact.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(min = 1, max = 255, message = "Name must be 1-255 characters"))
+    )]
+    pub name: String,
+    /// Email address of the contact.
+    #[cfg_attr(
+        feature = "validation",
+        validate(
+            email(message = "Email is invalid"),
+            length(max = 255, message = "Email must be at most 255 characters")
+        )
+    )]
+    pub email: Option<String>,
+    /// Phone number of the contact.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(max = 50, message = "Phone must be at most 50 characters"))
+    )]
+    pub phone: Option<String>,
+    /// The contact's role at the company.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(max = 255, message = "Role must be at most 255 characters"))
+    )]
+    pub role: Option<String>,
+    /// Whether to make this the company's primary contact, replacing any
+    /// existing one. Defaults to `false`.
+    #[serde(default)]
+    pub is_primary: bool,
+}
+
+/// Request payload for partially updating a contact.
+///
+/// Omitted fields are left unchanged and `null` clears the optional ones.
+/// When the `"validation"` feature is enabled, the same constraints as
+/// [`CreateContactRequest`] apply to any provided field.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct UpdateContactRequest {
+    /// Updated name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(min = 1, max = 255, message = "Name must be 1-255 characters"))
+    )]
+    pub name: Option<String>,
+    /// Updated email address, or `Some(None)` to clear it.
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(
+        feature = "validation",
+        validate(
+            email(message = "Email is invalid"),
+            length(max = 255, message = "Email must be at most 255 characters")
+        )
+    )]
+    pub email: Option<Option<String>>,
+    /// Updated phone number, or `Some(None)` to clear it.
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(max = 50, message = "Phone must be at most 50 characters"))
+    )]
+    pub phone: Option<Option<String>>,
+    /// Updated role, or `Some(None)` to clear it.
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(max = 255, message = "Role must be at most 255 characters"))
+    )]
+    pub role: Option<Option<String>>,
+    /// Whether the contact is the company's primary contact. Setting it to
+    /// `true` replaces any existing primary contact.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_primary: Option<bool>,
+}
@@ -0,0 +1,174 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[cfg(feature = "validation")]
+use crate::validators::contract::{validate_create_contract_dates, validate_update_contract_dates};
+
+/// How a contract prices the work. Serialized as `snake_case`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ContractRateType {
+    /// Billed per hour worked at `rate_amount`.
+    Hourly,
+    /// A single fixed fee of `rate_amount` for the engagement.
+    Fixed,
+    /// A recurring monthly retainer of `rate_amount`.
+    Retainer,
+}
+
+/// Metadata for a contract's signed document attachment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractDocument {
+    /// Original file name of the uploaded document.
+    pub file_name: String,
+    /// MIME type of the uploaded document.
+    pub content_type: String,
+    /// Size of the document in bytes.
+    pub size_bytes: i64,
+    /// When the document was uploaded.
+    pub uploaded_at: DateTime<Utc>,
+}
+
+/// An engagement contract with a company.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Contract {
+    /// Unique identifier for the contract.
+    pub id: Uuid,
+    /// The user who owns this contract.
+    pub user_id: Uuid,
+    /// The company the contract is with.
+    pub company_id: Uuid,
+    /// Short title describing the engagement.
+    pub title: String,
+    /// First day the contract is in effect.
+    pub start_date: NaiveDate,
+    /// Last day the contract is in effect. `None` for open-ended contracts.
+    pub end_date: Option<NaiveDate>,
+    /// How the contract prices the work.
+    pub rate_type: ContractRateType,
+    /// Rate in dollars, interpreted according to `rate_type`.
+    pub rate_amount: f64,
+    /// Free-form notes on payment terms or other conditions.
+    pub terms: Option<String>,
+    /// Days before `end_date` to send a renewal reminder. `None` disables reminders.
+    pub renewal_reminder_days: Option<i32>,
+    /// When the renewal reminder was sent. `None` until sent.
+    pub renewal_reminder_sent_at: Option<DateTime<Utc>>,
+    /// The signed document attachment, if one has been uploaded.
+    pub document: Option<ContractDocument>,
+    /// Timestamp when the contract was created.
+    pub created_at: DateTime<Utc>,
+    /// Timestamp when the contract was last updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Request payload for creating a contract.
+///
+/// When the `"validation"` feature is enabled, `end_date` must not be before
+/// `start_date`, `rate_amount` must not be negative, and
+/// `renewal_reminder_days` must be between 1 and 365.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+#[cfg_attr(
+    feature = "validation",
+    validate(schema(function = "validate_create_contract_dates"))
+)]
+pub struct CreateContractRequest {
+    /// The company the contract is with.
+    pub company_id: Uuid,
+    /// Short title describing the engagement.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(min = 1, max = 255, message = "Title must be 1-255 characters"))
+    )]
+    pub title: String,
+    /// First day the contract is in effect.
+    pub start_date: NaiveDate,
+    /// Last day the contract is in effect.
+    pub end_date: Option<NaiveDate>,
+    /// How the contract prices the work.
+    pub rate_type: ContractRateType,
+    /// Rate in dollars, interpreted according to `rate_type`.
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(min = 0.0, message = "Rate amount must not be negative"))
+    )]
+    pub rate_amount: f64,
+    /// Free-form notes on payment terms or other conditions.
+    pub terms: Option<String>,
+    /// Days before `end_date` to send a renewal reminder.
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(
+            min = 1,
+            max = 365,
+            message = "Renewal reminder must be between 1 and 365 days"
+        ))
+    )]
+    pub renewal_reminder_days: Option<i32>,
+}
+
+/// Request payload for updating a contract. All fields are optional.
+///
+/// When the `"validation"` feature is enabled, the same constraints as
+/// [`CreateContractRequest`] apply to any provided field. Changing
+/// `end_date` or `renewal_reminder_days` re-arms the renewal reminder.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+#[cfg_attr(
+    feature = "validation",
+    validate(schema(function = "validate_update_contract_dates"))
+)]
+pub struct UpdateContractRequest {
+    /// Updated title.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(min = 1, max = 255, message = "Title must be 1-255 characters"))
+    )]
+    pub title: Option<String>,
+    /// Updated start date.
+    pub start_date: Option<NaiveDate>,
+    /// Updated end date.
+    pub end_date: Option<NaiveDate>,
+    /// Updated rate type.
+    pub rate_type: Option<ContractRateType>,
+    /// Updated rate amount.
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(min = 0.0, message = "Rate amount must not be negative"))
+    )]
+    pub rate_amount: Option<f64>,
+    /// Updated terms.
+    pub terms: Option<String>,
+    /// Updated renewal reminder lead time in days.
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(
+            min = 1,
+            max = 365,
+            message = "Renewal reminder must be between 1 and 365 days"
+        ))
+    )]
+    pub renewal_reminder_days: Option<i32>,
+}
+
+/// Query parameters for uploading a contract's signed document.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct UploadContractDocumentQuery {
+    /// Original file name of the document.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(min = 1, max = 255, message = "File name must be 1-255 characters"))
+    )]
+    pub file_name: String,
+}
+
+/// Query parameters for listing contracts.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct ListContractsQuery {
+    /// Restrict results to contracts with this company.
+    pub company_id: Option<Uuid>,
+}
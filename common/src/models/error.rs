@@ -1,27 +1,55 @@
 use serde::{Deserialize, Serialize};
 
+/// Machine-readable category of a validation failure.
+///
+/// Serialized in `SCREAMING_SNAKE_CASE` (e.g. `"FIELD_REQUIRED"`) so
+/// clients can localize messages themselves.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ValidationErrorCode {
+    /// A required field is missing or empty.
+    FieldRequired,
+    /// A value is too short or too long.
+    Length,
+    /// A number is outside its allowed range.
+    Range,
+    /// A value is not a valid email address.
+    InvalidEmail,
+    /// A value is not a valid URL.
+    InvalidUrl,
+    /// A value does not match its required format.
+    InvalidFormat,
+    /// Fields are inconsistent with each other.
+    CrossField,
+    /// A password is too easy to guess.
+    WeakPassword,
+    /// A code was requested again before the resend cooldown elapsed.
+    ResendTooSoon,
+    /// A value failed a check without a more specific code.
+    #[default]
+    Invalid,
+}
+
 /// A validation error for a specific field.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ValidationError {
     /// The field that failed validation, if applicable.
     pub field: Option<String>,
+    /// Machine-readable category of the failure.
+    pub code: ValidationErrorCode,
     /// Description of the validation failure.
     pub message: String,
 }
 
-impl Default for ValidationError {
-    fn default() -> Self {
-        Self {
-            field: None,
-            message: "".to_string(),
-        }
-    }
-}
-
 impl ValidationError {
-    pub fn new(field: Option<String>, message: impl Into<String>) -> Self {
+    pub fn new(
+        field: Option<String>,
+        code: ValidationErrorCode,
+        message: impl Into<String>,
+    ) -> Self {
         Self {
-            field: field,
+            field,
+            code,
             message: message.into(),
         }
     }
@@ -37,3 +65,24 @@ pub struct ApiError {
     /// Detailed validation errors, if any.
     pub errors: Option<Vec<ValidationError>>,
 }
+
+/// An RFC 7807 problem details response.
+///
+/// Sent as `application/problem+json` instead of [`ApiError`] when the
+/// client prefers that media type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProblemDetails {
+    /// URI identifying the problem type. Always `"about:blank"`, meaning
+    /// the problem is described by its HTTP status.
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    /// Short summary of the problem type, the status's reason phrase.
+    pub title: String,
+    /// HTTP status code for the error.
+    pub status: u16,
+    /// Explanation specific to this occurrence of the problem.
+    pub detail: String,
+    /// Detailed validation errors, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errors: Option<Vec<ValidationError>>,
+}
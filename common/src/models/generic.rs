@@ -1,5 +1,153 @@
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "validation")]
+use crate::validators::generic::validate_export_range;
+
+/// Query parameters accepted by detail endpoints.
+///
+/// When the `"validation"` feature is enabled, `fields` and `include` must
+/// be at most 500 characters. Which names are
+/// accepted depends on the endpoint.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct DetailQuery {
+    /// Comma-separated top-level fields to return, e.g. `"id,name"`.
+    /// Every field is returned when omitted.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(max = 500, message = "Fields must be at most 500 characters"))
+    )]
+    pub fields: Option<String>,
+    /// Comma-separated related resources to embed, e.g. `"jobs,payments"`.
+    /// Nothing is embedded when omitted.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(max = 500, message = "Include must be at most 500 characters"))
+    )]
+    pub include: Option<String>,
+}
+
+/// Query parameters selecting one page of a list.
+///
+/// When the `"validation"` feature is enabled, `page` must be at least 1
+/// and `per_page` between 1 and [`PageQuery::MAX_PER_PAGE`].
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct PageQuery {
+    /// One-based page number. Defaults to 1.
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(min = 1, message = "Page must be at least 1"))
+    )]
+    pub page: Option<u32>,
+    /// Items per page. Defaults to [`PageQuery::DEFAULT_PER_PAGE`].
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(min = 1, max = 100, message = "Per page must be between 1 and 100"))
+    )]
+    pub per_page: Option<u32>,
+}
+
+impl PageQuery {
+    /// Items per page when none is requested.
+    pub const DEFAULT_PER_PAGE: u32 = 25;
+    /// Most items a page can hold.
+    pub const MAX_PER_PAGE: u32 = 100;
+
+    /// Returns the requested page number.
+    ///
+    /// # Returns
+    ///
+    /// The one-based page number, at least 1.
+    pub fn page(&self) -> u32 {
+        self.page.unwrap_or(1).max(1)
+    }
+
+    /// Returns the requested page size.
+    ///
+    /// # Returns
+    ///
+    /// The number of items per page, between 1 and
+    /// [`PageQuery::MAX_PER_PAGE`].
+    pub fn per_page(&self) -> u32 {
+        self.per_page
+            .unwrap_or(Self::DEFAULT_PER_PAGE)
+            .clamp(1, Self::MAX_PER_PAGE)
+    }
+
+    /// Returns the number of items before the requested page.
+    ///
+    /// # Returns
+    ///
+    /// The offset of the page's first item.
+    pub fn offset(&self) -> i64 {
+        i64::from(self.page() - 1) * i64::from(self.per_page())
+    }
+}
+
+/// One page of a list, as returned by list endpoints.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Paginated<T> {
+    /// The items on this page.
+    pub items: Vec<T>,
+    /// One-based page number.
+    pub page: u32,
+    /// Items per page.
+    pub per_page: u32,
+    /// Whether a later page has items.
+    pub has_more: bool,
+    /// Number of items across every page when the page was read. Items
+    /// added or removed since may change it.
+    pub total_estimate: i64,
+}
+
+impl<T> Paginated<T> {
+    /// Builds a page from items fetched with one extra item of lookahead.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` — Up to `per_page + 1` items starting at the page's
+    ///   offset. The extra item, if present, is dropped and sets
+    ///   `has_more`.
+    /// * `query` — The [`PageQuery`] the items were fetched for.
+    /// * `total_estimate` — Number of items across every page.
+    ///
+    /// # Returns
+    ///
+    /// The [`Paginated`] page.
+    pub fn from_lookahead(mut items: Vec<T>, query: &PageQuery, total_estimate: i64) -> Self {
+        let per_page = query.per_page();
+        let has_more = items.len() > per_page as usize;
+        items.truncate(per_page as usize);
+
+        Self {
+            items,
+            page: query.page(),
+            per_page,
+            has_more,
+            total_estimate,
+        }
+    }
+}
+
+/// Query parameters accepted by CSV export endpoints.
+///
+/// When the `"validation"` feature is enabled, `from` must not be after
+/// `to`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+#[cfg_attr(
+    feature = "validation",
+    validate(schema(function = "validate_export_range"))
+)]
+pub struct ExportQuery {
+    /// Earliest date to export, inclusive. Unbounded when omitted.
+    pub from: Option<NaiveDate>,
+    /// Latest date to export, inclusive. Unbounded when omitted.
+    pub to: Option<NaiveDate>,
+}
+
 /// A generic response containing a single message.
 #[derive(Serialize, Deserialize)]
 pub struct MessageResponse {
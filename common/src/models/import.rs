@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/// Query parameters accepted by CSV import endpoints.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImportQuery {
+    /// Validate the file without importing anything. Defaults to `false`.
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// Outcome of a CSV import that passed validation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportSummary {
+    /// Whether the file was only validated.
+    pub dry_run: bool,
+    /// Number of rows imported, or that would be imported on a dry run.
+    pub rows: usize,
+}
@@ -0,0 +1,152 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::patch::nullable;
+
+/// The lifecycle state of an invoice. Serialized as `snake_case`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum InvoiceStatus {
+    /// The invoice is still being assembled and can receive new line items.
+    Draft,
+    /// The invoice has been finalized and can no longer be modified.
+    Finalized,
+}
+
+/// An invoice billed to a company.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Invoice {
+    /// Unique identifier for the invoice.
+    pub id: Uuid,
+    /// The user who owns this invoice.
+    pub user_id: Uuid,
+    /// The company being billed.
+    pub company_id: Uuid,
+    /// Current lifecycle state of the invoice.
+    pub status: InvoiceStatus,
+    /// Sequential number among the user's invoices, assigned when the
+    /// invoice is finalized. `None` for drafts.
+    pub invoice_number: Option<i32>,
+    /// Date payment is due.
+    pub due_date: Option<NaiveDate>,
+    /// Free-form notes shown on the invoice.
+    pub notes: Option<String>,
+    /// Line items included on the invoice.
+    pub line_items: Vec<InvoiceLineItem>,
+    /// Sum of the line item amounts in dollars.
+    pub total_amount: f64,
+    /// When the invoice was finalized. `None` for drafts.
+    pub finalized_at: Option<DateTime<Utc>>,
+    /// Timestamp when the invoice was created.
+    pub created_at: DateTime<Utc>,
+    /// Timestamp when the invoice was last updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A single billed line on an invoice, covering one job's sessions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InvoiceLineItem {
+    /// Unique identifier for the line item.
+    pub id: Uuid,
+    /// The invoice this line item belongs to.
+    pub invoice_id: Uuid,
+    /// The job whose work sessions this line item covers.
+    pub job_id: Uuid,
+    /// Human-readable description of the billed work.
+    pub description: String,
+    /// Number of work sessions rolled into this line item.
+    pub session_count: i32,
+    /// Exact worked duration in seconds before rounding.
+    pub duration_seconds: i64,
+    /// Billable hours after rounding to the billing increment.
+    pub rounded_hours: f64,
+    /// Hourly rate in dollars. `None` for payout-based jobs.
+    pub hourly_rate: Option<f64>,
+    /// Billed amount in dollars. `None` for payout-based jobs.
+    pub amount: Option<f64>,
+    /// Timestamp when the line item was created.
+    pub created_at: DateTime<Utc>,
+}
+
+/// Unbilled work sessions for a single job at one rate, rolled up for
+/// invoicing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnbilledJobGroup {
+    /// The job the sessions belong to.
+    pub job_id: Uuid,
+    /// Title of the job.
+    pub job_title: String,
+    /// IDs of the completed work sessions that have not been billed.
+    pub work_session_ids: Vec<Uuid>,
+    /// Exact worked duration in seconds before rounding.
+    pub duration_seconds: i64,
+    /// Billable hours after rounding to the billing increment.
+    pub rounded_hours: f64,
+    /// Hourly rate in dollars the sessions were completed at. `None` for
+    /// payout-based jobs.
+    pub hourly_rate: Option<f64>,
+    /// Billable amount in dollars. `None` for payout-based jobs.
+    pub amount: Option<f64>,
+}
+
+/// Response payload listing a company's unbilled work grouped by job.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnbilledWorkResponse {
+    /// The company the unbilled work belongs to.
+    pub company_id: Uuid,
+    /// Unbilled sessions grouped by job.
+    pub jobs: Vec<UnbilledJobGroup>,
+    /// Sum of all billable amounts in dollars.
+    pub total_amount: f64,
+}
+
+/// Request payload for creating an invoice.
+///
+/// The invoice's line items are computed from the company's unbilled work
+/// sessions. If the company already has a draft invoice, the sessions are
+/// added to it instead.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct CreateInvoiceRequest {
+    /// The company to bill.
+    pub company_id: Uuid,
+}
+
+/// Request payload for partially updating a draft invoice.
+///
+/// Omitted fields are left unchanged and `null` clears them. When the
+/// `"validation"` feature is enabled, `notes` must be at most 2000
+/// characters.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct UpdateInvoiceRequest {
+    /// Updated due date, or `Some(None)` to clear it.
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub due_date: Option<Option<NaiveDate>>,
+    /// Updated notes, or `Some(None)` to clear them.
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(max = 2000, message = "Notes must be at most 2000 characters"))
+    )]
+    pub notes: Option<Option<String>>,
+}
+
+/// Query parameters for listing invoices.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct ListInvoicesQuery {
+    /// Restrict results to invoices billed to this company.
+    pub company_id: Option<Uuid>,
+    /// Restrict results to invoices in this state.
+    pub status: Option<InvoiceStatus>,
+}
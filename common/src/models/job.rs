@@ -2,6 +2,18 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::models::{
+    budget::BudgetStatus,
+    company::Company,
+    patch::nullable,
+    payment::{Payment, PayoutType},
+};
+
+#[cfg(feature = "validation")]
+use crate::validators::job::{validate_payment_type_conversion, validate_unique_job_moves};
+#[cfg(feature = "validation")]
+use validator::Validate;
+
 /// How a job compensates the worker. Serialized as `snake_case`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -12,6 +24,31 @@ pub enum PaymentType {
     Payouts,
 }
 
+/// Pipeline stage a job is shown in on the job board. Serialized as
+/// `snake_case`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum JobBoardColumn {
+    /// Prospective work that has not started.
+    Lead,
+    /// Work in progress.
+    Active,
+    /// Work that has been invoiced.
+    Invoiced,
+    /// Work that has been paid for.
+    Paid,
+}
+
+impl JobBoardColumn {
+    /// Every column, in pipeline order.
+    pub const ALL: [JobBoardColumn; 4] = [
+        JobBoardColumn::Lead,
+        JobBoardColumn::Active,
+        JobBoardColumn::Invoiced,
+        JobBoardColumn::Paid,
+    ];
+}
+
 /// A job associated with a company.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Job {
@@ -31,6 +68,10 @@ pub struct Job {
     pub number_of_payouts: Option<i32>,
     /// Amount per payout in dollars. Used when `payment_type` is `Payouts`.
     pub payout_amount: Option<f64>,
+    /// Job board column the job is in.
+    pub board_column: JobBoardColumn,
+    /// Zero-based position of the job within its board column.
+    pub board_position: i32,
     /// Timestamp when the job was created.
     pub created_at: DateTime<Utc>,
     /// Timestamp when the job was last updated.
@@ -54,17 +95,178 @@ pub struct CreateJobRequest {
     pub payout_amount: Option<f64>,
 }
 
-/// Request payload for updating an existing job. All fields are optional.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Request payload for partially updating an existing job.
+///
+/// Omitted fields are left unchanged; the payment amount fields may be
+/// `null` to clear them. When the `"validation"` feature is enabled,
+/// `title` must not be empty. The payment fields are checked against the
+/// payment type once merged with the stored job.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
 pub struct UpdateJobRequest {
     /// Updated job title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(min = 1, max = 255, message = "Title must be 1-255 characters"))
+    )]
     pub title: Option<String>,
     /// Updated payment type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub payment_type: Option<PaymentType>,
-    /// Updated hourly rate.
+    /// Updated hourly rate, or `Some(None)` to clear it.
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub hourly_rate: Option<Option<f64>>,
+    /// Updated number of payouts, or `Some(None)` to clear it.
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub number_of_payouts: Option<Option<i32>>,
+    /// Updated payout amount, or `Some(None)` to clear it.
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub payout_amount: Option<Option<f64>>,
+}
+
+/// Response payload for the job detail endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobDetailResponse {
+    /// The requested job.
+    #[serde(flatten)]
+    pub job: Job,
+    /// Budgets on the job with their current-month consumption.
+    pub budgets: Vec<BudgetStatus>,
+    /// The job's company, present when requested with `?include=company`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub company: Option<Company>,
+}
+
+/// A single job move in a board reorder.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct JobMove {
+    /// The job being moved.
+    pub job_id: Uuid,
+    /// Column to place the job in.
+    pub board_column: JobBoardColumn,
+    /// Zero-based position within the column.
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(min = 0, message = "Position must not be negative"))
+    )]
+    pub board_position: i32,
+}
+
+/// Request payload for moving jobs on the job board in bulk.
+///
+/// When the `"validation"` feature is enabled, at least one move is
+/// required and each job may only be moved once.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+#[cfg_attr(
+    feature = "validation",
+    validate(schema(function = "validate_unique_job_moves"))
+)]
+pub struct ReorderJobsRequest {
+    /// The new column and position of each moved job.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(min = 1, message = "At least one move is required"), nested)
+    )]
+    pub moves: Vec<JobMove>,
+}
+
+/// The jobs in one job board column.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobBoardColumnGroup {
+    /// The board column.
+    pub column: JobBoardColumn,
+    /// Jobs in the column, ordered by position.
+    pub jobs: Vec<Job>,
+}
+
+/// Response payload for the job board endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobBoardResponse {
+    /// Every board column in pipeline order, including empty ones.
+    pub columns: Vec<JobBoardColumnGroup>,
+}
+
+/// Request payload for converting a job to another payment type.
+///
+/// When the `"validation"` feature is enabled, the payment fields must
+/// match the new payment type. When installments are requested, the job
+/// must be converting to payouts and the payout amount is derived from its
+/// accrued hours, so it must be omitted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+#[cfg_attr(
+    feature = "validation",
+    validate(schema(function = "validate_payment_type_conversion"))
+)]
+pub struct ConvertPaymentTypeRequest {
+    /// The payment type to convert the job to.
+    pub payment_type: PaymentType,
+    /// Hourly rate in dollars, required when converting to hourly.
+    #[serde(default)]
     pub hourly_rate: Option<f64>,
-    /// Updated number of payouts.
+    /// Total number of payouts, required when converting to payouts.
+    #[serde(default)]
     pub number_of_payouts: Option<i32>,
-    /// Updated payout amount.
+    /// Amount per payout in dollars, required when converting to payouts
+    /// without installments.
+    #[serde(default)]
     pub payout_amount: Option<f64>,
+    /// When set, the job's unbilled hours are priced at its current hourly
+    /// rate and split into one expected payment per payout, received via
+    /// this method.
+    #[serde(default)]
+    pub installment_payout_type: Option<PayoutType>,
+}
+
+/// A recorded change to a job's payment terms.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobRateChange {
+    /// Unique identifier for the change.
+    pub id: Uuid,
+    /// The job whose terms changed.
+    pub job_id: Uuid,
+    /// Payment type before the change.
+    pub previous_payment_type: PaymentType,
+    /// Hourly rate in dollars before the change.
+    pub previous_hourly_rate: Option<f64>,
+    /// Number of payouts before the change.
+    pub previous_number_of_payouts: Option<i32>,
+    /// Amount per payout in dollars before the change.
+    pub previous_payout_amount: Option<f64>,
+    /// Payment type after the change.
+    pub payment_type: PaymentType,
+    /// Hourly rate in dollars after the change.
+    pub hourly_rate: Option<f64>,
+    /// Number of payouts after the change.
+    pub number_of_payouts: Option<i32>,
+    /// Amount per payout in dollars after the change.
+    pub payout_amount: Option<f64>,
+    /// When the change was made.
+    pub changed_at: DateTime<Utc>,
+}
+
+/// Response payload for converting a job's payment type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvertPaymentTypeResponse {
+    /// The converted job.
+    pub job: Job,
+    /// The recorded change to the job's payment terms.
+    pub rate_change: JobRateChange,
+    /// Expected payments created from accrued hours, if requested.
+    pub installments: Vec<Payment>,
 }
@@ -0,0 +1,230 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::{
+    company::Company,
+    job::{Job, PaymentType},
+};
+
+#[cfg(feature = "validation")]
+use crate::validators::lead::{validate_convert_lead_payment, validate_update_lead_status};
+
+/// Where a lead is in the sales pipeline. Serialized as `snake_case`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum LeadStatus {
+    /// Still being pursued.
+    Open,
+    /// Converted into a company and job.
+    Won,
+    /// Did not turn into work.
+    Lost,
+}
+
+/// A prospective piece of work that has not become a job yet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Lead {
+    /// Unique identifier for the lead.
+    pub id: Uuid,
+    /// The user who owns this lead.
+    pub user_id: Uuid,
+    /// Short description of the opportunity.
+    pub title: String,
+    /// Name of the prospective client.
+    pub company_name: String,
+    /// Where the lead came from, e.g. `"referral"`.
+    pub source: Option<String>,
+    /// Expected value of the work in dollars.
+    pub expected_value: Option<f64>,
+    /// Estimated chance of winning the work, from 0 to 100.
+    pub probability: i32,
+    /// Date to follow up with the prospective client.
+    pub follow_up_date: Option<NaiveDate>,
+    /// Free-form notes.
+    pub notes: Option<String>,
+    /// Where the lead is in the pipeline.
+    pub status: LeadStatus,
+    /// The job the lead was converted into. `None` unless won.
+    pub converted_job_id: Option<Uuid>,
+    /// When the lead was won or lost.
+    pub closed_at: Option<DateTime<Utc>>,
+    /// Timestamp when the lead was created.
+    pub created_at: DateTime<Utc>,
+    /// Timestamp when the lead was last updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Request payload for creating a lead.
+///
+/// When the `"validation"` feature is enabled, `title` and `company_name`
+/// must not be empty, `expected_value` must not be negative, and
+/// `probability` must be between 0 and 100.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct CreateLeadRequest {
+    /// Short description of the opportunity.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(min = 1, max = 255, message = "Title must be 1-255 characters"))
+    )]
+    pub title: String,
+    /// Name of the prospective client.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(min = 1, max = 255, message = "Company name must be 1-255 characters"))
+    )]
+    pub company_name: String,
+    /// Where the lead came from.
+    pub source: Option<String>,
+    /// Expected value of the work in dollars.
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(min = 0.0, message = "Expected value must not be negative"))
+    )]
+    pub expected_value: Option<f64>,
+    /// Estimated chance of winning the work. Defaults to 50.
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(min = 0, max = 100, message = "Probability must be between 0 and 100"))
+    )]
+    pub probability: Option<i32>,
+    /// Date to follow up with the prospective client.
+    pub follow_up_date: Option<NaiveDate>,
+    /// Free-form notes.
+    pub notes: Option<String>,
+}
+
+/// Request payload for updating a lead. All fields are optional.
+///
+/// When the `"validation"` feature is enabled, the same constraints as
+/// [`CreateLeadRequest`] apply to any provided field, and `status` may only
+/// be set to `open` or `lost`; leads are won by converting them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+#[cfg_attr(
+    feature = "validation",
+    validate(schema(function = "validate_update_lead_status"))
+)]
+pub struct UpdateLeadRequest {
+    /// Updated title.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(min = 1, max = 255, message = "Title must be 1-255 characters"))
+    )]
+    pub title: Option<String>,
+    /// Updated prospective client name.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(min = 1, max = 255, message = "Company name must be 1-255 characters"))
+    )]
+    pub company_name: Option<String>,
+    /// Updated source.
+    pub source: Option<String>,
+    /// Updated expected value.
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(min = 0.0, message = "Expected value must not be negative"))
+    )]
+    pub expected_value: Option<f64>,
+    /// Updated probability.
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(min = 0, max = 100, message = "Probability must be between 0 and 100"))
+    )]
+    pub probability: Option<i32>,
+    /// Updated follow-up date.
+    pub follow_up_date: Option<NaiveDate>,
+    /// Updated notes.
+    pub notes: Option<String>,
+    /// Updated status. Only `open` and `lost` are accepted.
+    pub status: Option<LeadStatus>,
+}
+
+/// Query parameters for listing leads.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct ListLeadsQuery {
+    /// Only include leads with this status.
+    pub status: Option<LeadStatus>,
+}
+
+/// Request payload for converting a lead into a company and job.
+///
+/// When the `"validation"` feature is enabled, the payment fields must match
+/// `payment_type`: hourly jobs need `hourly_rate`, payout jobs need
+/// `number_of_payouts` and `payout_amount`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+#[cfg_attr(
+    feature = "validation",
+    validate(schema(function = "validate_convert_lead_payment"))
+)]
+pub struct ConvertLeadRequest {
+    /// Existing company to add the job to. A company named after the lead's
+    /// `company_name` is created when omitted.
+    pub company_id: Option<Uuid>,
+    /// Title for the job. Defaults to the lead's title.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(min = 1, max = 255, message = "Job title must be 1-255 characters"))
+    )]
+    pub job_title: Option<String>,
+    /// How the job compensates the worker.
+    pub payment_type: PaymentType,
+    /// Hourly rate in dollars, for hourly jobs.
+    pub hourly_rate: Option<f64>,
+    /// Total number of payouts, for payout jobs.
+    pub number_of_payouts: Option<i32>,
+    /// Amount per payout in dollars, for payout jobs.
+    pub payout_amount: Option<f64>,
+}
+
+/// Response payload for a lead conversion.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeadConversionResponse {
+    /// The lead, now marked as won.
+    pub lead: Lead,
+    /// The company the job belongs to.
+    pub company: Company,
+    /// The created job.
+    pub job: Job,
+}
+
+/// Conversion statistics for leads from a single source.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeadSourceStats {
+    /// The lead source. `None` groups leads without a source.
+    pub source: Option<String>,
+    /// Number of leads from the source.
+    pub total_leads: i64,
+    /// Number of those leads that were won.
+    pub won_leads: i64,
+    /// Won leads as a fraction of closed leads, from `0.0` to `1.0`.
+    pub conversion_rate: f64,
+}
+
+/// Lead pipeline and conversion statistics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeadStats {
+    /// Number of leads.
+    pub total_leads: i64,
+    /// Number of open leads.
+    pub open_leads: i64,
+    /// Number of won leads.
+    pub won_leads: i64,
+    /// Number of lost leads.
+    pub lost_leads: i64,
+    /// Won leads as a fraction of closed leads, from `0.0` to `1.0`.
+    pub conversion_rate: f64,
+    /// Total expected value of open leads in dollars.
+    pub open_pipeline_value: f64,
+    /// Expected value of open leads weighted by probability, in dollars.
+    pub weighted_pipeline_value: f64,
+    /// Total expected value of won leads in dollars.
+    pub won_value: f64,
+    /// Average days from creation to conversion for won leads.
+    pub average_days_to_convert: Option<f64>,
+    /// Statistics per lead source, largest source first.
+    pub sources: Vec<LeadSourceStats>,
+}
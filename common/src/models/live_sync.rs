@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::{payment::Payment, work_session::WorkSession};
+
+/// A domain event pushed over the live sync WebSocket. Serialized as an
+/// object with a `snake_case` `type` and the event payload in `data`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum LiveSyncMessage {
+    /// A work session was started, paused, resumed, or completed.
+    WorkSessionUpdated(WorkSession),
+    /// A work session was deleted.
+    WorkSessionDeleted {
+        /// The deleted work session's ID.
+        id: Uuid,
+    },
+    /// A payment was updated.
+    PaymentUpdated(Payment),
+    /// Events were dropped because the connection fell behind. Clients
+    /// should reload their data.
+    Resync,
+}
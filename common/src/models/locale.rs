@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+
+/// A language the app's emails can be sent in. Serialized as its BCP 47
+/// language tag, e.g. `"es"`.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    /// English. Every email is available in English.
+    #[default]
+    En,
+    /// Spanish.
+    Es,
+    /// French.
+    Fr,
+}
+
+impl Locale {
+    /// Every supported locale, in serialization order.
+    pub const ALL: [Locale; 3] = [Locale::En, Locale::Es, Locale::Fr];
+
+    /// Returns the locale's BCP 47 language tag.
+    ///
+    /// # Returns
+    ///
+    /// The lowercase language tag, e.g. `"es"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Es => "es",
+            Locale::Fr => "fr",
+        }
+    }
+
+    /// Parses a locale from a BCP 47 language tag.
+    ///
+    /// Region subtags are ignored, so `"es-MX"` is Spanish.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — The language tag.
+    ///
+    /// # Returns
+    ///
+    /// The matching [`Locale`], or `None` if the language is not supported.
+    pub fn parse(value: &str) -> Option<Self> {
+        let language = value.split(['-', '_']).next()?.to_ascii_lowercase();
+
+        Self::ALL
+            .into_iter()
+            .find(|locale| locale.as_str() == language)
+    }
+}
+
+/// The user's preferred language for emails.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocalePreference {
+    /// The preferred locale. Emails not yet translated into it are sent in
+    /// English.
+    pub locale: Locale,
+}
+
+/// The user's preferred timezone for displaying times.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct TimezonePreference {
+    /// IANA timezone name such as `"America/Chicago"`, or `None` to show
+    /// times in the browser's timezone.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(
+            min = 1,
+            max = 64,
+            message = "Timezone must be between 1 and 64 characters"
+        ))
+    )]
+    pub timezone: Option<String>,
+}
@@ -0,0 +1,252 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::{patch::nullable, work_session::GeoLocation};
+#[cfg(feature = "validation")]
+use validator::Validate;
+
+/// A business trip driven by the user, deductible at a per-mile rate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MileageEntry {
+    /// Unique identifier for the entry.
+    pub id: Uuid,
+    /// The user who drove the trip.
+    pub user_id: Uuid,
+    /// The job the trip was for, if any.
+    pub job_id: Option<Uuid>,
+    /// Date of the trip.
+    pub date: NaiveDate,
+    /// Distance driven in miles.
+    pub miles: f64,
+    /// Where the trip started.
+    pub start_location: Option<String>,
+    /// Where the trip ended.
+    pub end_location: Option<String>,
+    /// The work session the trip was confirmed from, if it came from a
+    /// mileage suggestion.
+    pub work_session_id: Option<Uuid>,
+    /// Deduction for the trip in dollars at the user's current mileage
+    /// rate, rounded to cents.
+    pub deduction: f64,
+    /// Timestamp when the entry was created.
+    pub created_at: DateTime<Utc>,
+    /// Timestamp when the entry was last updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Request payload for logging a trip.
+///
+/// When the `"validation"` feature is enabled, `miles` must be positive and
+/// locations are limited to 255 characters.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct CreateMileageEntryRequest {
+    /// The job the trip was for. Must belong to the user.
+    pub job_id: Option<Uuid>,
+    /// Date of the trip.
+    pub date: NaiveDate,
+    /// Distance driven in miles.
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(exclusive_min = 0.0, message = "Miles must be positive"))
+    )]
+    pub miles: f64,
+    /// Where the trip started.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(max = 255, message = "Start location must be at most 255 characters"))
+    )]
+    pub start_location: Option<String>,
+    /// Where the trip ended.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(max = 255, message = "End location must be at most 255 characters"))
+    )]
+    pub end_location: Option<String>,
+}
+
+/// Request payload for partially updating a trip.
+///
+/// Omitted fields are left unchanged and `null` clears the optional ones.
+/// When the `"validation"` feature is enabled, the same constraints as
+/// [`CreateMileageEntryRequest`] apply to any provided field.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct UpdateMileageEntryRequest {
+    /// Updated job, or `Some(None)` to detach the trip from its job.
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub job_id: Option<Option<Uuid>>,
+    /// Updated trip date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<NaiveDate>,
+    /// Updated distance in miles.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(exclusive_min = 0.0, message = "Miles must be positive"))
+    )]
+    pub miles: Option<f64>,
+    /// Updated start location, or `Some(None)` to clear it.
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(max = 255, message = "Start location must be at most 255 characters"))
+    )]
+    pub start_location: Option<Option<String>>,
+    /// Updated end location, or `Some(None)` to clear it.
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(max = 255, message = "End location must be at most 255 characters"))
+    )]
+    pub end_location: Option<Option<String>>,
+}
+
+/// Query parameters for listing trips and the mileage report.
+///
+/// Either bound may be omitted.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MileageRangeQuery {
+    /// Only include trips on or after this date.
+    pub from: Option<NaiveDate>,
+    /// Only include trips on or before this date.
+    pub to: Option<NaiveDate>,
+    /// Only include trips for this job.
+    pub job_id: Option<Uuid>,
+}
+
+/// Mileage totals for one job in a [`MileageReport`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MileageJobTotal {
+    /// The job, or `None` for trips not tied to a job.
+    pub job_id: Option<Uuid>,
+    /// Title of the job, or `None` for trips not tied to a job.
+    pub job_title: Option<String>,
+    /// Number of trips.
+    pub trips: i64,
+    /// Distance driven in miles.
+    pub miles: f64,
+    /// Deduction in dollars, rounded to cents.
+    pub deduction: f64,
+}
+
+/// Mileage totals over a date range, for feeding into tax estimates.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MileageReport {
+    /// First date covered, if bounded.
+    pub from: Option<NaiveDate>,
+    /// Last date covered, if bounded.
+    pub to: Option<NaiveDate>,
+    /// Rate the deductions were computed at, in dollars per mile.
+    pub rate: f64,
+    /// Number of trips.
+    pub total_trips: i64,
+    /// Distance driven in miles.
+    pub total_miles: f64,
+    /// Deduction in dollars, rounded to cents.
+    pub total_deduction: f64,
+    /// Totals per job, largest distance first.
+    pub jobs: Vec<MileageJobTotal>,
+}
+
+/// The mileage deduction rate applied to a user's trips.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MileageRate {
+    /// Rate applied to the user's trips, in dollars per mile.
+    pub rate: f64,
+    /// Server-wide default rate, in dollars per mile.
+    pub default_rate: f64,
+    /// Whether the user has set their own rate.
+    pub is_custom: bool,
+}
+
+/// Request payload for setting the user's mileage rate.
+///
+/// When the `"validation"` feature is enabled, `rate` must not be negative.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct UpdateMileageRateRequest {
+    /// Rate in dollars per mile, or `None` to use the default rate.
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(min = 0.0, message = "Rate must not be negative"))
+    )]
+    pub rate: Option<f64>,
+}
+
+/// The place the user's trips to job sites start from.
+///
+/// When the `"validation"` feature is enabled, the location must be a valid
+/// coordinate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct HomeBase {
+    /// The home base's location, or `None` if it is not set. Mileage is
+    /// only suggested while it is set.
+    #[cfg_attr(feature = "validation", validate(nested))]
+    pub location: Option<GeoLocation>,
+}
+
+/// The state of a mileage suggestion. Serialized as `snake_case`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MileageSuggestionStatus {
+    /// The suggestion is waiting for the user to confirm or dismiss it.
+    Pending,
+    /// The user confirmed the suggestion and a trip was logged from it.
+    Accepted,
+    /// The user dismissed the suggestion.
+    Dismissed,
+}
+
+/// A round trip suggested from a work session that started away from the
+/// user's home base.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MileageSuggestion {
+    /// Unique identifier for the suggestion.
+    pub id: Uuid,
+    /// The work session the trip was suggested from.
+    pub work_session_id: Uuid,
+    /// The session's job.
+    pub job_id: Uuid,
+    /// Date of the trip.
+    pub date: NaiveDate,
+    /// Estimated round-trip distance in miles.
+    pub miles: f64,
+    /// Name of the company the trip was to.
+    pub destination: String,
+    /// Whether the suggestion is pending, accepted, or dismissed.
+    pub status: MileageSuggestionStatus,
+    /// The trip logged when the suggestion was accepted.
+    pub mileage_entry_id: Option<Uuid>,
+    /// Timestamp when the suggestion was made.
+    pub created_at: DateTime<Utc>,
+}
+
+/// Optional request payload for accepting a mileage suggestion.
+///
+/// When the `"validation"` feature is enabled, `miles` must be positive.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct AcceptMileageSuggestionRequest {
+    /// Distance to log instead of the estimate, in miles.
+    #[serde(default)]
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(exclusive_min = 0.0, message = "Miles must be positive"))
+    )]
+    pub miles: Option<f64>,
+}
@@ -1,20 +1,75 @@
 //! Shared data models used across the GigLog application.
 
+/// Account data export requests and archive contents.
+pub mod account_export;
+/// API keys for headless clients.
+pub mod api_key;
 /// Appearance preferences and color palette models.
 pub mod appearance;
+/// Monthly budget entities and consumption models.
+pub mod budget;
+/// Calendar feed subscriptions.
+pub mod calendar;
 /// Company entities and CRUD request models.
 pub mod company;
+/// Company contact people and CRUD request models.
+pub mod contact;
+/// Contract entities, rate terms, and document attachment models.
+pub mod contract;
 /// API error and validation error models.
 pub mod error;
-/// Generic response models.
+/// Generic query and response models.
 pub mod generic;
 /// Health check response model.
 pub mod health;
+/// CSV import options and results.
+pub mod import;
+/// Invoice entities, line items, and unbilled work summaries.
+pub mod invoice;
 /// Job entities, payment types, and CRUD request models.
 pub mod job;
+/// Sales leads, conversion, and pipeline statistics models.
+pub mod lead;
+/// Domain events pushed to live sync WebSocket connections.
+pub mod live_sync;
+/// Supported email languages and the user's language and timezone
+/// preferences.
+pub mod locale;
+/// Business mileage entries, deduction rates, and reports.
+pub mod mileage;
+/// External identity providers for OAuth sign-in.
+pub mod oauth;
+/// First-run onboarding wizard models.
+pub mod onboarding;
+/// Passkeys and WebAuthn ceremony models.
+pub mod passkey;
+/// Helpers for partial update request payloads.
+pub mod patch;
 /// Payment tracking entities and CRUD request models.
 pub mod payment;
+/// Personal access tokens and their API scopes.
+pub mod personal_access_token;
+/// Recurring payment templates and schedule previews.
+pub mod recurring_payment;
+/// Unreported time, bulk reporting, earnings report, and tax estimate models.
+pub mod report;
+/// Full-text search across companies and jobs.
+pub mod search;
+/// Emailed company statement models.
+pub mod statement;
+/// Paid plan subscriptions billed through Stripe.
+pub mod subscription;
+/// Time-off entries and weekly availability models.
+pub mod time_off;
+/// Timesheet sharing and client approval models.
+pub mod timesheet;
+/// Toggl Track API compatibility models.
+pub mod toggl;
 /// User accounts and authentication request models.
 pub mod user;
-/// Work session time-tracking models.
+/// Outgoing webhooks, their events, and delivery logs.
+pub mod webhook;
+/// Work session time-tracking and attachment models.
 pub mod work_session;
+/// Email-logged work session drafts and inbound email models.
+pub mod work_session_draft;
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+/// An external identity provider users can sign in with. Serialized in
+/// lowercase, e.g. `"github"`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum OAuthProvider {
+    /// Sign in with Google.
+    Google,
+    /// Sign in with GitHub.
+    GitHub,
+}
+
+impl OAuthProvider {
+    /// Returns the provider's serialized name.
+    ///
+    /// # Returns
+    ///
+    /// The lowercase name used in URLs, e.g. `"github"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OAuthProvider::Google => "google",
+            OAuthProvider::GitHub => "github",
+        }
+    }
+}
+
+/// Query parameters the identity provider sends back to the OAuth callback.
+///
+/// Either `code` or `error` is present, along with the `state` issued when
+/// the sign-in started.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OAuthCallbackQuery {
+    /// Authorization code to exchange for the user's profile.
+    pub code: Option<String>,
+    /// The state issued when the sign-in started.
+    pub state: Option<String>,
+    /// Error reported by the provider, e.g. `"access_denied"`.
+    pub error: Option<String>,
+}
@@ -0,0 +1,165 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::{
+    company::Company,
+    job::{Job, PaymentType},
+    payment::{Payment, PayoutType},
+    work_session::WorkSession,
+};
+
+#[cfg(feature = "validation")]
+use crate::validators::onboarding::{
+    validate_onboarding_company, validate_onboarding_job, validate_onboarding_work_session,
+};
+#[cfg(feature = "validation")]
+use validator::Validate;
+
+/// The company created by the onboarding wizard.
+///
+/// When the `"validation"` feature is enabled, `name` must not be empty and
+/// a tax withholding rate between 0 and 1 must be set exactly when tax
+/// withholdings are required.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+#[cfg_attr(
+    feature = "validation",
+    validate(schema(function = "validate_onboarding_company"))
+)]
+pub struct OnboardingCompany {
+    /// Name of the company.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(min = 1, max = 255, message = "Name must be 1-255 characters"))
+    )]
+    pub name: String,
+    /// Whether this company requires tax withholdings on payments.
+    #[serde(default)]
+    pub requires_tax_withholdings: bool,
+    /// Tax withholding rate as a decimal, required when withholdings are.
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(
+            min = 0.0,
+            max = 1.0,
+            message = "Tax withholding rate must be between 0 and 1"
+        ))
+    )]
+    pub tax_withholding_rate: Option<f64>,
+}
+
+/// The job created by the onboarding wizard.
+///
+/// When the `"validation"` feature is enabled, `title` must not be empty
+/// and the payment fields must match `payment_type`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+#[cfg_attr(
+    feature = "validation",
+    validate(schema(function = "validate_onboarding_job"))
+)]
+pub struct OnboardingJob {
+    /// Title of the job.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(min = 1, max = 255, message = "Title must be 1-255 characters"))
+    )]
+    pub title: String,
+    /// How the job compensates the worker.
+    pub payment_type: PaymentType,
+    /// Hourly rate in dollars, for hourly jobs.
+    pub hourly_rate: Option<f64>,
+    /// Total number of payouts, for payout jobs.
+    pub number_of_payouts: Option<i32>,
+    /// Amount per payout in dollars, for payout jobs.
+    pub payout_amount: Option<f64>,
+}
+
+/// The optional first payment created by the onboarding wizard.
+///
+/// When the `"validation"` feature is enabled, `total` must be greater
+/// than 0.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct OnboardingPayment {
+    /// Total payment amount in dollars.
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(exclusive_min = 0.0, message = "Total must be greater than 0"))
+    )]
+    pub total: f64,
+    /// Method used to receive the payment.
+    pub payout_type: PayoutType,
+    /// Expected date the payment will be received.
+    pub expected_payout_date: Option<NaiveDate>,
+    /// Whether the payment transfer has been initiated by the payer.
+    #[serde(default)]
+    pub transfer_initiated: bool,
+    /// Whether the payment has been received by the user.
+    #[serde(default)]
+    pub payment_received: bool,
+    /// Whether tax withholdings have been accounted for.
+    #[serde(default)]
+    pub tax_withholdings_covered: bool,
+}
+
+/// The optional first work session logged by the onboarding wizard.
+///
+/// When the `"validation"` feature is enabled, `end_time` must be after
+/// `start_time`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+#[cfg_attr(
+    feature = "validation",
+    validate(schema(function = "validate_onboarding_work_session"))
+)]
+pub struct OnboardingWorkSession {
+    /// When the session started.
+    pub start_time: DateTime<Utc>,
+    /// When the session ended.
+    pub end_time: DateTime<Utc>,
+}
+
+/// Request payload for the first-run onboarding wizard.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct OnboardingRequest {
+    /// The company to create.
+    #[cfg_attr(feature = "validation", validate(nested))]
+    pub company: OnboardingCompany,
+    /// The job to create for the company.
+    #[cfg_attr(feature = "validation", validate(nested))]
+    pub job: OnboardingJob,
+    /// A first payment from the company, if any.
+    #[cfg_attr(feature = "validation", validate(nested))]
+    pub payment: Option<OnboardingPayment>,
+    /// A first completed work session on the job, if any.
+    #[cfg_attr(feature = "validation", validate(nested))]
+    pub work_session: Option<OnboardingWorkSession>,
+    /// The browser's IANA timezone name, used to prefill the user's
+    /// timezone preference if they have not chosen one. Unknown names are
+    /// ignored.
+    #[serde(default)]
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(
+            min = 1,
+            max = 64,
+            message = "Timezone must be between 1 and 64 characters"
+        ))
+    )]
+    pub timezone: Option<String>,
+}
+
+/// Response payload for the onboarding wizard.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OnboardingResponse {
+    /// The created company.
+    pub company: Company,
+    /// The created job.
+    pub job: Job,
+    /// The created payment, if one was requested.
+    pub payment: Option<Payment>,
+    /// The created work session, if one was requested.
+    pub work_session: Option<WorkSession>,
+}
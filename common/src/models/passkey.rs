@@ -0,0 +1,193 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A passkey registered to a user, without its key material.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Passkey {
+    /// Unique identifier for the passkey.
+    pub id: Uuid,
+    /// Label describing the device or password manager holding the passkey.
+    pub name: String,
+    /// When the passkey was last used to log in.
+    pub last_used_at: Option<DateTime<Utc>>,
+    /// Timestamp when the passkey was registered.
+    pub created_at: DateTime<Utc>,
+}
+
+/// A WebAuthn relying party, i.e. the GigLog deployment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelyingParty {
+    /// The relying party ID passkeys are bound to.
+    pub id: String,
+    /// Name shown by authenticators.
+    pub name: String,
+}
+
+/// The account a new passkey is created for.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PasskeyUserEntity {
+    /// Base64url-encoded user handle.
+    pub id: String,
+    /// The account's email address.
+    pub name: String,
+    /// The account holder's full name.
+    pub display_name: String,
+}
+
+/// A public key algorithm the server accepts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PasskeyCredentialParameters {
+    /// Always `"public-key"`.
+    #[serde(rename = "type")]
+    pub credential_type: String,
+    /// COSE algorithm identifier.
+    pub alg: i64,
+}
+
+/// Identifies an existing passkey credential.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PasskeyCredentialDescriptor {
+    /// Always `"public-key"`.
+    #[serde(rename = "type")]
+    pub credential_type: String,
+    /// Base64url-encoded credential ID.
+    pub id: String,
+}
+
+/// Requirements on the authenticator creating a passkey.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthenticatorSelection {
+    /// Whether the credential should be discoverable.
+    pub resident_key: String,
+    /// Whether the authenticator should verify the user.
+    pub user_verification: String,
+}
+
+/// Options for creating a passkey.
+///
+/// Serialized in the WebAuthn JSON format accepted by
+/// `PublicKeyCredential.parseCreationOptionsFromJSON`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PasskeyCreationOptions {
+    /// The relying party the passkey is bound to.
+    pub rp: RelyingParty,
+    /// The account the passkey is for.
+    pub user: PasskeyUserEntity,
+    /// Base64url-encoded one-time challenge.
+    pub challenge: String,
+    /// Accepted public key algorithms.
+    pub pub_key_cred_params: Vec<PasskeyCredentialParameters>,
+    /// How long the ceremony may take in milliseconds.
+    pub timeout: u64,
+    /// The user's existing passkeys, which must not be registered again.
+    pub exclude_credentials: Vec<PasskeyCredentialDescriptor>,
+    /// Requirements on the authenticator.
+    pub authenticator_selection: AuthenticatorSelection,
+    /// Attestation conveyance preference.
+    pub attestation: String,
+}
+
+/// Options for logging in with a passkey.
+///
+/// Serialized in the WebAuthn JSON format accepted by
+/// `PublicKeyCredential.parseRequestOptionsFromJSON`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PasskeyRequestOptions {
+    /// Base64url-encoded one-time challenge.
+    pub challenge: String,
+    /// The relying party ID passkeys are bound to.
+    pub rp_id: String,
+    /// How long the ceremony may take in milliseconds.
+    pub timeout: u64,
+    /// Passkeys that may be used, or empty to let the user pick a
+    /// discoverable passkey.
+    pub allow_credentials: Vec<PasskeyCredentialDescriptor>,
+    /// Whether the authenticator should verify the user.
+    pub user_verification: String,
+}
+
+/// The authenticator's response to a passkey creation request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PasskeyAttestationResponse {
+    /// Base64url-encoded client data JSON.
+    #[serde(rename = "clientDataJSON")]
+    pub client_data_json: String,
+    /// Base64url-encoded authenticator data.
+    pub authenticator_data: String,
+    /// Base64url-encoded DER `SubjectPublicKeyInfo` of the new credential.
+    pub public_key: String,
+    /// COSE algorithm identifier of the public key.
+    pub public_key_algorithm: i64,
+}
+
+/// A newly created passkey credential.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PasskeyRegistrationCredential {
+    /// Base64url-encoded credential ID.
+    pub id: String,
+    /// The authenticator's response.
+    pub response: PasskeyAttestationResponse,
+}
+
+/// Request payload for finishing passkey registration.
+///
+/// `credential` is the output of `PublicKeyCredential.toJSON()`. When the
+/// `"validation"` feature is enabled, `name` must not be empty.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct FinishPasskeyRegistrationRequest {
+    /// Label describing the device or password manager holding the passkey.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(min = 1, message = "Name is required"))
+    )]
+    pub name: String,
+    /// The created credential.
+    pub credential: PasskeyRegistrationCredential,
+}
+
+/// Request payload for starting a passkey login.
+///
+/// When the `"validation"` feature is enabled, `email` must be a valid
+/// email address if present.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct StartPasskeyLoginRequest {
+    /// Email of the account to log into, or `None` to use a discoverable
+    /// passkey.
+    #[cfg_attr(feature = "validation", validate(email(message = "Email is invalid")))]
+    pub email: Option<String>,
+}
+
+/// The authenticator's response to a passkey login request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PasskeyAssertionResponse {
+    /// Base64url-encoded client data JSON.
+    #[serde(rename = "clientDataJSON")]
+    pub client_data_json: String,
+    /// Base64url-encoded authenticator data.
+    pub authenticator_data: String,
+    /// Base64url-encoded DER ECDSA signature.
+    pub signature: String,
+    /// Base64url-encoded user handle, returned for discoverable passkeys.
+    pub user_handle: Option<String>,
+}
+
+/// Request payload for finishing a passkey login.
+///
+/// The output of `PublicKeyCredential.toJSON()`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct FinishPasskeyLoginRequest {
+    /// Base64url-encoded credential ID.
+    pub id: String,
+    /// The authenticator's response.
+    pub response: PasskeyAssertionResponse,
+}
@@ -0,0 +1,30 @@
+//! Helpers for partial update (`PATCH`) request payloads.
+//!
+//! Nullable fields in partial updates are `Option<Option<T>>`: an absent
+//! field is `None` and leaves the value unchanged, `null` is `Some(None)`
+//! and clears it, and a value is `Some(Some(value))`. Annotate such fields
+//! with
+//! `#[serde(default, deserialize_with = "nullable", skip_serializing_if = "Option::is_none")]`.
+
+use serde::{Deserialize, Deserializer};
+
+/// Deserializes a present field, including `null`, as `Some`.
+///
+/// # Arguments
+///
+/// * `deserializer` — The deserializer for the field's value.
+///
+/// # Returns
+///
+/// `Some(None)` for `null`, or `Some(Some(value))` otherwise.
+///
+/// # Errors
+///
+/// Returns an error if the value cannot be deserialized as `T`.
+pub fn nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
@@ -2,6 +2,10 @@ use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::models::{company::Company, patch::nullable};
+#[cfg(feature = "validation")]
+use crate::validators::payment::validate_payment_date_range;
+
 /// The method used to receive a payment. Serialized as `snake_case`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -20,7 +24,52 @@ pub enum PayoutType {
     DirectDeposit,
 }
 
+/// How much of a payment has been paid. Serialized as `snake_case`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentStatus {
+    /// Nothing has been paid yet.
+    Unpaid,
+    /// Some installments have been paid, but an amount is still
+    /// outstanding.
+    PartiallyPaid,
+    /// The payment was received, or its installments cover the total.
+    Paid,
+}
+
+impl PaymentStatus {
+    /// Works out a payment's status and outstanding amount.
+    ///
+    /// A payment marked received counts as paid in full, whatever its
+    /// installments add up to. Amounts are compared in whole cents.
+    ///
+    /// # Arguments
+    ///
+    /// * `total` — Total payment amount in dollars.
+    /// * `amount_paid` — Sum of the payment's installments in dollars.
+    /// * `payment_received` — Whether the payment has been received.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the [`PaymentStatus`] and the amount still outstanding in
+    /// dollars.
+    pub fn from_amounts(total: f64, amount_paid: f64, payment_received: bool) -> (Self, f64) {
+        let outstanding_cents = ((total - amount_paid) * 100.0).round().max(0.0);
+
+        if payment_received || outstanding_cents == 0.0 {
+            (PaymentStatus::Paid, 0.0)
+        } else if amount_paid > 0.0 {
+            (PaymentStatus::PartiallyPaid, outstanding_cents / 100.0)
+        } else {
+            (PaymentStatus::Unpaid, outstanding_cents / 100.0)
+        }
+    }
+}
+
 /// A payment received from a company.
+///
+/// `amount_paid`, `amount_outstanding`, and `status` roll up the payment's
+/// installments.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Payment {
     /// Unique identifier for the payment.
@@ -41,12 +90,68 @@ pub struct Payment {
     pub payment_received: bool,
     /// Whether tax withholdings have been accounted for.
     pub tax_withholdings_covered: bool,
+    /// The company's tax withholding rate as a decimal when the payment was
+    /// received. `None` until it is received, or if the company did not
+    /// require withholdings then. Reports use this rather than the
+    /// company's current rate.
+    pub tax_withholding_rate: Option<f64>,
+    /// Sum of the installments paid so far, in dollars.
+    pub amount_paid: f64,
+    /// Amount still to be paid, in dollars. Zero once the payment is paid.
+    pub amount_outstanding: f64,
+    /// How much of the payment has been paid.
+    pub status: PaymentStatus,
+    /// Whether the expected payout date has passed without the payment
+    /// being received.
+    pub overdue: bool,
     /// Timestamp when the payment record was created.
     pub created_at: DateTime<Utc>,
     /// Timestamp when the payment record was last updated.
     pub updated_at: DateTime<Utc>,
 }
 
+/// A partial payment made toward a [`Payment`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentInstallment {
+    /// Unique identifier for the installment.
+    pub id: Uuid,
+    /// The payment the installment was made toward.
+    pub payment_id: Uuid,
+    /// Installment amount in dollars.
+    pub amount: f64,
+    /// Date the installment was paid.
+    pub paid_on: NaiveDate,
+    /// Timestamp when the installment was recorded.
+    pub created_at: DateTime<Utc>,
+}
+
+/// Request payload for recording an installment toward a payment.
+///
+/// When the `"validation"` feature is enabled, `amount` must be greater
+/// than 0. The API also rejects installments larger than the payment's
+/// outstanding amount.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct CreatePaymentInstallmentRequest {
+    /// Installment amount in dollars.
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(exclusive_min = 0.0, message = "Amount must be greater than 0"))
+    )]
+    pub amount: f64,
+    /// Date the installment was paid. Defaults to today.
+    pub paid_on: Option<NaiveDate>,
+}
+
+/// Response payload for recording an installment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatePaymentInstallmentResponse {
+    /// The recorded installment.
+    pub installment: PaymentInstallment,
+    /// The payment with its updated roll-up fields.
+    pub payment: Payment,
+}
+
 /// Request payload for creating a new payment.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreatePaymentRequest {
@@ -66,19 +171,287 @@ pub struct CreatePaymentRequest {
     pub tax_withholdings_covered: bool,
 }
 
-/// Request payload for updating an existing payment. All fields are optional.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Request payload for partially updating an existing payment.
+///
+/// Omitted fields are left unchanged; `expected_payout_date` may be `null`
+/// to clear it. When the `"validation"` feature is enabled, `total` must be
+/// greater than 0.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
 pub struct UpdatePaymentRequest {
     /// Updated total payment amount.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(exclusive_min = 0.0, message = "Total must be greater than 0"))
+    )]
     pub total: Option<f64>,
     /// Updated payout method.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub payout_type: Option<PayoutType>,
-    /// Updated expected payout date.
-    pub expected_payout_date: Option<NaiveDate>,
+    /// Updated expected payout date, or `Some(None)` to clear it.
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub expected_payout_date: Option<Option<NaiveDate>>,
     /// Updated transfer initiation status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub transfer_initiated: Option<bool>,
     /// Updated payment received status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub payment_received: Option<bool>,
     /// Updated tax withholdings status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tax_withholdings_covered: Option<bool>,
 }
+
+/// Request payload for creating a public receipt link for a payment.
+///
+/// When the `"validation"` feature is enabled, `expires_in_days` must be
+/// between 1 and 365.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct CreateReceiptLinkRequest {
+    /// Days until the link expires. Defaults to 30.
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(min = 1, max = 365, message = "Expiry must be between 1 and 365 days"))
+    )]
+    pub expires_in_days: Option<i64>,
+}
+
+/// A signed, expiring public receipt link for a received payment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReceiptLink {
+    /// Signed token identifying the payment.
+    pub token: String,
+    /// Path of the public receipt page, relative to the API origin.
+    pub path: String,
+    /// When the link stops working.
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Response payload for the payment detail endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentDetailResponse {
+    /// The requested payment.
+    #[serde(flatten)]
+    pub payment: Payment,
+    /// The company that issued the payment, present when requested with
+    /// `?include=company`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub company: Option<Company>,
+}
+
+/// An expected payment that a received payment may correspond to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentMatchSuggestion {
+    /// The expected, not yet received, payment.
+    pub payment: Payment,
+    /// Match confidence from `0.0` to `1.0`.
+    pub score: f64,
+    /// Human-readable reasons contributing to the score.
+    pub reasons: Vec<String>,
+}
+
+/// Query parameters for listing payments.
+///
+/// Every filter is optional and filters are combined with `AND`. When the
+/// `"validation"` feature is enabled, `date_from` must not be after
+/// `date_to`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+#[cfg_attr(
+    feature = "validation",
+    validate(schema(function = "validate_payment_date_range"))
+)]
+pub struct ListPaymentsQuery {
+    /// Restrict results to payments from this company.
+    pub company_id: Option<Uuid>,
+    /// Restrict results to payments received this way.
+    pub payout_type: Option<PayoutType>,
+    /// Restrict results by whether the payment has been received.
+    pub payment_received: Option<bool>,
+    /// Restrict results by whether the payer initiated the transfer. Also
+    /// accepted as `transfer_received`.
+    #[serde(alias = "transfer_received")]
+    pub transfer_initiated: Option<bool>,
+    /// Earliest expected payout date to include.
+    pub date_from: Option<NaiveDate>,
+    /// Latest expected payout date to include.
+    pub date_to: Option<NaiveDate>,
+    /// Field to sort by, prefixed with `-` for descending order, such as
+    /// `-expected_payout_date`. Defaults to `-created_at`.
+    pub sort: Option<String>,
+}
+
+/// A field payments can be sorted by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaymentSortField {
+    /// Sort by expected payout date; payments without one sort last.
+    ExpectedPayoutDate,
+    /// Sort by total amount.
+    Total,
+    /// Sort by when the payment was created.
+    CreatedAt,
+    /// Sort by when the payment was last updated.
+    UpdatedAt,
+}
+
+impl PaymentSortField {
+    /// Every sort field.
+    pub const ALL: [PaymentSortField; 4] = [
+        PaymentSortField::ExpectedPayoutDate,
+        PaymentSortField::Total,
+        PaymentSortField::CreatedAt,
+        PaymentSortField::UpdatedAt,
+    ];
+
+    /// Returns the field's name as used in the `sort` query parameter.
+    ///
+    /// # Returns
+    ///
+    /// The field name, matching the [`Payment`] field.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PaymentSortField::ExpectedPayoutDate => "expected_payout_date",
+            PaymentSortField::Total => "total",
+            PaymentSortField::CreatedAt => "created_at",
+            PaymentSortField::UpdatedAt => "updated_at",
+        }
+    }
+}
+
+/// The order to list payments in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaymentSort {
+    /// The field to sort by.
+    pub field: PaymentSortField,
+    /// Whether to sort from highest to lowest.
+    pub descending: bool,
+}
+
+impl PaymentSort {
+    /// Parses a `sort` query parameter.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — A field name, optionally prefixed with `-` for
+    ///   descending order.
+    ///
+    /// # Returns
+    ///
+    /// The matching [`PaymentSort`], or `None` if the field is unknown.
+    pub fn parse(value: &str) -> Option<Self> {
+        let (descending, name) = match value.strip_prefix('-') {
+            Some(name) => (true, name),
+            None => (false, value),
+        };
+
+        PaymentSortField::ALL
+            .into_iter()
+            .find(|field| field.as_str() == name)
+            .map(|field| PaymentSort { field, descending })
+    }
+}
+
+impl Default for PaymentSort {
+    fn default() -> Self {
+        PaymentSort {
+            field: PaymentSortField::CreatedAt,
+            descending: true,
+        }
+    }
+}
+
+/// Overdue payments from one company.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverdueCompanyTotal {
+    /// The company that issued the payments.
+    pub company_id: Uuid,
+    /// Name of the company.
+    pub company_name: String,
+    /// Number of overdue payments from the company.
+    pub payment_count: i64,
+    /// Amount still outstanding across the company's overdue payments, in
+    /// dollars.
+    pub amount_outstanding: f64,
+    /// Earliest expected payout date among the company's overdue payments.
+    pub oldest_expected_payout_date: NaiveDate,
+}
+
+/// Response payload for the overdue payments endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverduePaymentsResponse {
+    /// Number of overdue payments.
+    pub payment_count: usize,
+    /// Amount still outstanding across every overdue payment, in dollars.
+    pub amount_outstanding: f64,
+    /// Totals per company, largest outstanding amount first.
+    pub companies: Vec<OverdueCompanyTotal>,
+    /// The overdue payments, oldest expected payout date first.
+    pub payments: Vec<Payment>,
+}
+
+/// A user's payment reminder email preference.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentReminderSetting {
+    /// Whether reminders are emailed before a payment's expected payout
+    /// date and once it is overdue. On by default.
+    pub enabled: bool,
+}
+
+/// Query parameters for the monthly payment reconciliation.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PaymentReconciliationQuery {
+    /// The month to reconcile as `YYYY-MM`. Defaults to the current month.
+    pub month: Option<String>,
+}
+
+/// A discrepancy that needs attention during reconciliation. Serialized as
+/// `snake_case`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReconciliationFlag {
+    /// The expected payout date has passed without the payment arriving.
+    Overdue,
+    /// The payment has not arrived and has no expected payout date.
+    MissingExpectedDate,
+    /// The payment arrived without a transfer ever being marked initiated.
+    ReceivedWithoutTransfer,
+    /// The company requires tax withholdings that have not been covered.
+    TaxWithholdingsNotCovered,
+}
+
+/// A payment on the reconciliation checklist.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentReconciliationItem {
+    /// The payment.
+    #[serde(flatten)]
+    pub payment: Payment,
+    /// Name of the company that issued the payment.
+    pub company_name: String,
+    /// Discrepancies found on the payment. Empty when nothing is needed.
+    pub flags: Vec<ReconciliationFlag>,
+}
+
+/// Response payload for the monthly payment reconciliation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentReconciliationResponse {
+    /// First day of the reconciled month.
+    pub month_start: NaiveDate,
+    /// Last day of the reconciled month.
+    pub month_end: NaiveDate,
+    /// Sum of every payment due in the month, in dollars.
+    pub expected_total: f64,
+    /// Sum of payments whose transfer has been initiated, in dollars.
+    pub transferred_total: f64,
+    /// Sum of payments that have been received, in dollars.
+    pub received_total: f64,
+    /// Number of payments with at least one flag.
+    pub flagged_count: usize,
+    /// Payments due in the month, ordered by date.
+    pub payments: Vec<PaymentReconciliationItem>,
+}
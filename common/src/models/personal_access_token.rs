@@ -0,0 +1,198 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A permission granted to a personal access token.
+///
+/// Serialized as `<access>:<resource>`, e.g. `"read:payments"`. Read scopes
+/// allow `GET` requests on a resource's routes; write scopes allow every
+/// other method.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ApiScope {
+    /// Read budgets and their consumption.
+    #[serde(rename = "read:budgets")]
+    ReadBudgets,
+    /// Create and delete budgets.
+    #[serde(rename = "write:budgets")]
+    WriteBudgets,
+    /// Read companies and their unbilled work.
+    #[serde(rename = "read:companies")]
+    ReadCompanies,
+    /// Modify companies and bill their unbilled work.
+    #[serde(rename = "write:companies")]
+    WriteCompanies,
+    /// Read contracts and signed documents.
+    #[serde(rename = "read:contracts")]
+    ReadContracts,
+    /// Create, update, and delete contracts and documents.
+    #[serde(rename = "write:contracts")]
+    WriteContracts,
+    /// Read invoices and their line items.
+    #[serde(rename = "read:invoices")]
+    ReadInvoices,
+    /// Create, edit, finalize, and delete invoices.
+    #[serde(rename = "write:invoices")]
+    WriteInvoices,
+    /// Read jobs.
+    #[serde(rename = "read:jobs")]
+    ReadJobs,
+    /// Modify jobs.
+    #[serde(rename = "write:jobs")]
+    WriteJobs,
+    /// Read leads and conversion statistics.
+    #[serde(rename = "read:leads")]
+    ReadLeads,
+    /// Create, update, convert, and delete leads.
+    #[serde(rename = "write:leads")]
+    WriteLeads,
+    /// Read mileage entries, reports, and the mileage rate.
+    #[serde(rename = "read:mileage")]
+    ReadMileage,
+    /// Log, update, and delete mileage entries and set the mileage rate.
+    #[serde(rename = "write:mileage")]
+    WriteMileage,
+    /// Read payments.
+    #[serde(rename = "read:payments")]
+    ReadPayments,
+    /// Record and modify payments.
+    #[serde(rename = "write:payments")]
+    WritePayments,
+    /// Read scheduled time off and availability.
+    #[serde(rename = "read:time_off")]
+    ReadTimeOff,
+    /// Schedule and remove time off.
+    #[serde(rename = "write:time_off")]
+    WriteTimeOff,
+    /// Read timesheet approval requests.
+    #[serde(rename = "read:timesheets")]
+    ReadTimesheets,
+    /// Create timesheet approval links.
+    #[serde(rename = "write:timesheets")]
+    WriteTimesheets,
+    /// Read work sessions and time entries.
+    #[serde(rename = "read:work_sessions")]
+    ReadWorkSessions,
+    /// Start, stop, create, and delete work sessions and time entries.
+    #[serde(rename = "write:work_sessions")]
+    WriteWorkSessions,
+}
+
+impl ApiScope {
+    /// Every scope, in serialization order.
+    pub const ALL: [ApiScope; 22] = [
+        ApiScope::ReadBudgets,
+        ApiScope::WriteBudgets,
+        ApiScope::ReadCompanies,
+        ApiScope::WriteCompanies,
+        ApiScope::ReadContracts,
+        ApiScope::WriteContracts,
+        ApiScope::ReadInvoices,
+        ApiScope::WriteInvoices,
+        ApiScope::ReadJobs,
+        ApiScope::WriteJobs,
+        ApiScope::ReadLeads,
+        ApiScope::WriteLeads,
+        ApiScope::ReadMileage,
+        ApiScope::WriteMileage,
+        ApiScope::ReadPayments,
+        ApiScope::WritePayments,
+        ApiScope::ReadTimeOff,
+        ApiScope::WriteTimeOff,
+        ApiScope::ReadTimesheets,
+        ApiScope::WriteTimesheets,
+        ApiScope::ReadWorkSessions,
+        ApiScope::WriteWorkSessions,
+    ];
+
+    /// Returns the scope's serialized name.
+    ///
+    /// # Returns
+    ///
+    /// The `<access>:<resource>` name, e.g. `"read:payments"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiScope::ReadBudgets => "read:budgets",
+            ApiScope::WriteBudgets => "write:budgets",
+            ApiScope::ReadCompanies => "read:companies",
+            ApiScope::WriteCompanies => "write:companies",
+            ApiScope::ReadContracts => "read:contracts",
+            ApiScope::WriteContracts => "write:contracts",
+            ApiScope::ReadInvoices => "read:invoices",
+            ApiScope::WriteInvoices => "write:invoices",
+            ApiScope::ReadJobs => "read:jobs",
+            ApiScope::WriteJobs => "write:jobs",
+            ApiScope::ReadLeads => "read:leads",
+            ApiScope::WriteLeads => "write:leads",
+            ApiScope::ReadMileage => "read:mileage",
+            ApiScope::WriteMileage => "write:mileage",
+            ApiScope::ReadPayments => "read:payments",
+            ApiScope::WritePayments => "write:payments",
+            ApiScope::ReadTimeOff => "read:time_off",
+            ApiScope::WriteTimeOff => "write:time_off",
+            ApiScope::ReadTimesheets => "read:timesheets",
+            ApiScope::WriteTimesheets => "write:timesheets",
+            ApiScope::ReadWorkSessions => "read:work_sessions",
+            ApiScope::WriteWorkSessions => "write:work_sessions",
+        }
+    }
+
+    /// Parses a scope from its serialized name.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — The `<access>:<resource>` name.
+    ///
+    /// # Returns
+    ///
+    /// The matching [`ApiScope`], or `None` if the name is unknown.
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|scope| scope.as_str() == value)
+    }
+}
+
+/// A personal access token, without its secret value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersonalAccessToken {
+    /// Unique identifier for the token.
+    pub id: Uuid,
+    /// Label describing what the token is used for.
+    pub name: String,
+    /// Scopes the token grants.
+    pub scopes: Vec<ApiScope>,
+    /// When the token last authenticated a request.
+    pub last_used_at: Option<DateTime<Utc>>,
+    /// Timestamp when the token was created.
+    pub created_at: DateTime<Utc>,
+}
+
+/// Request payload for creating a personal access token.
+///
+/// When the `"validation"` feature is enabled, `name` must not be empty and
+/// at least one scope must be granted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct CreatePersonalAccessTokenRequest {
+    /// Label describing what the token is used for.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(min = 1, message = "Name is required"))
+    )]
+    pub name: String,
+    /// Scopes to grant.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(min = 1, message = "At least one scope is required"))
+    )]
+    pub scopes: Vec<ApiScope>,
+}
+
+/// Response returned when a personal access token is created.
+///
+/// This is the only time the raw token is returned.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatePersonalAccessTokenResponse {
+    /// The raw token to send as `Authorization: Bearer <token>`.
+    pub token: String,
+    /// The created token's details.
+    pub personal_access_token: PersonalAccessToken,
+}
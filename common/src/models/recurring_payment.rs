@@ -0,0 +1,138 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::{patch::nullable, payment::PayoutType};
+
+#[cfg(feature = "validation")]
+use crate::validators::recurring_payment::validate_create_recurring_payment;
+
+/// How often a recurring payment repeats. Serialized as `snake_case`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RecurrenceCadence {
+    /// Every week.
+    Weekly,
+    /// Every two weeks.
+    Biweekly,
+    /// Every month, on the start date's day of the month.
+    Monthly,
+    /// Every three months, on the start date's day of the month.
+    Quarterly,
+    /// Every year, on the start date's month and day.
+    Yearly,
+}
+
+/// A template for a payment that a company makes on a regular schedule.
+///
+/// A concrete [`Payment`](crate::models::payment::Payment) is created from
+/// the template when each occurrence's date arrives.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecurringPayment {
+    /// Unique identifier for the recurring payment.
+    pub id: Uuid,
+    /// The user who receives the payments.
+    pub user_id: Uuid,
+    /// The company that makes the payments.
+    pub company_id: Uuid,
+    /// Amount of each payment in dollars.
+    pub total: f64,
+    /// Method used to receive each payment.
+    pub payout_type: PayoutType,
+    /// How often the payment repeats.
+    pub cadence: RecurrenceCadence,
+    /// Date of the first payment.
+    pub start_date: NaiveDate,
+    /// Date after which no more payments are made, if any.
+    pub end_date: Option<NaiveDate>,
+    /// Date of the next payment still to be created, or `None` once the
+    /// schedule has ended.
+    pub next_date: Option<NaiveDate>,
+    /// Timestamp when the recurring payment was created.
+    pub created_at: DateTime<Utc>,
+    /// Timestamp when the recurring payment was last updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Request payload for scheduling a recurring payment.
+///
+/// When the `"validation"` feature is enabled, `total` must be greater than
+/// 0 and `end_date` must not be before `start_date`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+#[cfg_attr(
+    feature = "validation",
+    validate(schema(function = "validate_create_recurring_payment"))
+)]
+pub struct CreateRecurringPaymentRequest {
+    /// The company that makes the payments.
+    pub company_id: Uuid,
+    /// Amount of each payment in dollars.
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(exclusive_min = 0.0, message = "Total must be greater than 0"))
+    )]
+    pub total: f64,
+    /// Method used to receive each payment.
+    pub payout_type: PayoutType,
+    /// How often the payment repeats.
+    pub cadence: RecurrenceCadence,
+    /// Date of the first payment.
+    pub start_date: NaiveDate,
+    /// Date after which no more payments are made.
+    pub end_date: Option<NaiveDate>,
+}
+
+/// Request payload for partially updating a recurring payment.
+///
+/// Omitted fields are left unchanged; `end_date` may be `null` to repeat
+/// indefinitely. Changes apply to payments not yet created. When the
+/// `"validation"` feature is enabled, `total` must be greater than 0. The
+/// end date is checked against the stored start date once merged.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct UpdateRecurringPaymentRequest {
+    /// Updated amount of each payment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(exclusive_min = 0.0, message = "Total must be greater than 0"))
+    )]
+    pub total: Option<f64>,
+    /// Updated payout method.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payout_type: Option<PayoutType>,
+    /// Updated end date, or `Some(None)` to repeat indefinitely.
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub end_date: Option<Option<NaiveDate>>,
+}
+
+/// Query parameters for previewing upcoming payments.
+///
+/// When the `"validation"` feature is enabled, `count` must be between 1
+/// and 52.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct UpcomingPaymentsQuery {
+    /// Number of occurrences to preview. Defaults to 5.
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(min = 1, max = 52, message = "Count must be between 1 and 52"))
+    )]
+    pub count: Option<u32>,
+}
+
+/// A future occurrence of a recurring payment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpcomingPayment {
+    /// Date the payment is expected.
+    pub expected_payout_date: NaiveDate,
+    /// Amount of the payment in dollars.
+    pub total: f64,
+    /// Method used to receive the payment.
+    pub payout_type: PayoutType,
+}
@@ -0,0 +1,205 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[cfg(feature = "validation")]
+use crate::validators::report::validate_earnings_range;
+
+/// Completed work sessions for one company and week whose time has not
+/// been reported.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnreportedTimeGroup {
+    /// The company whose jobs the sessions were tracked against.
+    pub company_id: Uuid,
+    /// Name of the company.
+    pub company_name: String,
+    /// Monday of the week the sessions started in.
+    pub week_start: NaiveDate,
+    /// IDs of the unreported work sessions.
+    pub work_session_ids: Vec<Uuid>,
+    /// Worked duration of the sessions in seconds, excluding paused time.
+    pub duration_seconds: i64,
+}
+
+/// Response payload listing unreported work grouped by company and week.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnreportedTimeResponse {
+    /// Unreported sessions grouped by company, then week.
+    pub groups: Vec<UnreportedTimeGroup>,
+    /// Worked duration of every unreported session in seconds.
+    pub total_seconds: i64,
+}
+
+/// Request payload for marking work sessions as reported.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct ReportTimeRequest {
+    /// The completed work sessions to report.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(min = 1, message = "At least one work session is required"))
+    )]
+    pub work_session_ids: Vec<Uuid>,
+}
+
+/// Response payload for marking work sessions as reported.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportTimeResponse {
+    /// IDs of the sessions that were reported. Sessions that were running,
+    /// already reported, or not found are left out.
+    pub work_session_ids: Vec<Uuid>,
+    /// Worked duration reported across the sessions in seconds.
+    pub duration_seconds: i64,
+}
+
+/// Length of each period in an earnings report. Serialized as
+/// `snake_case`.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EarningsPeriod {
+    /// Weeks starting on Monday.
+    Weekly,
+    /// Calendar months.
+    #[default]
+    Monthly,
+}
+
+/// Query parameters for the earnings report.
+///
+/// When the `"validation"` feature is enabled, `from` must not be after
+/// `to`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+#[cfg_attr(
+    feature = "validation",
+    validate(schema(function = "validate_earnings_range"))
+)]
+pub struct EarningsReportQuery {
+    /// Length of each period. Defaults to monthly.
+    #[serde(default)]
+    pub period: EarningsPeriod,
+    /// A day in the first period to report. Defaults to eleven periods
+    /// before `to`, giving twelve periods.
+    pub from: Option<NaiveDate>,
+    /// A day in the last period to report. Defaults to today.
+    pub to: Option<NaiveDate>,
+}
+
+/// Earnings totals for one week or month.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EarningsPeriodTotals {
+    /// First day of the period.
+    pub period_start: NaiveDate,
+    /// Hours worked in completed work sessions that started in the period,
+    /// excluding paused time.
+    pub hours_worked: f64,
+    /// Amount in dollars of invoices finalized in the period.
+    pub invoiced: f64,
+    /// Amount in dollars of received payments dated in the period.
+    pub received: f64,
+    /// Tax in dollars to withhold from the period's received payments, at
+    /// each company's withholding rate.
+    pub tax_withholdings: f64,
+}
+
+/// Response payload for the earnings report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EarningsReportResponse {
+    /// Length of each period.
+    pub period: EarningsPeriod,
+    /// Totals for every period in the range, oldest first, including
+    /// periods with no activity.
+    pub periods: Vec<EarningsPeriodTotals>,
+    /// When the report was generated. Reports are cached and regenerated
+    /// shortly after the underlying data changes.
+    pub generated_at: DateTime<Utc>,
+}
+
+/// Query parameters for the quarterly tax estimate.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct TaxEstimateQuery {
+    /// Calendar year of the quarter. Defaults to the current year.
+    pub year: Option<i32>,
+    /// Quarter of the year, from 1 to 4. Defaults to the current quarter.
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(min = 1, max = 4, message = "Quarter must be between 1 and 4"))
+    )]
+    pub quarter: Option<u32>,
+}
+
+/// Estimated tax on one company's payments in a quarter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompanyTaxEstimate {
+    /// The company that issued the payments.
+    pub company_id: Uuid,
+    /// Name of the company.
+    pub company_name: String,
+    /// Rate applied to the company's payments as a decimal: the company's
+    /// withholding rate when it requires withholdings, otherwise the
+    /// user's self-employment rate.
+    pub tax_rate: f64,
+    /// Amount in dollars of received payments dated in the quarter.
+    pub income: f64,
+    /// Tax in dollars owed on the quarter's payments.
+    pub estimated_owed: f64,
+    /// Tax in dollars on payments whose withholdings are marked covered.
+    pub tax_withholdings_covered: f64,
+    /// Tax in dollars still to set aside.
+    pub shortfall: f64,
+}
+
+/// Response payload for the quarterly tax estimate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaxEstimateResponse {
+    /// Calendar year of the quarter.
+    pub year: i32,
+    /// Quarter of the year, from 1 to 4.
+    pub quarter: u32,
+    /// First day of the quarter.
+    pub start_date: NaiveDate,
+    /// Last day of the quarter.
+    pub end_date: NaiveDate,
+    /// Self-employment rate applied to companies that do not withhold.
+    pub self_employment_tax_rate: f64,
+    /// Estimates per company with received payments in the quarter,
+    /// largest shortfall first.
+    pub companies: Vec<CompanyTaxEstimate>,
+    /// Tax in dollars owed across all companies.
+    pub estimated_owed: f64,
+    /// Tax in dollars already covered across all companies.
+    pub tax_withholdings_covered: f64,
+    /// Tax in dollars still to set aside across all companies.
+    pub shortfall: f64,
+    /// When the estimate was generated. Estimates are cached and
+    /// regenerated shortly after the underlying data changes.
+    pub generated_at: DateTime<Utc>,
+}
+
+/// The self-employment tax rate applied to a user's tax estimates.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SelfEmploymentTaxRate {
+    /// Rate applied to the user's estimates, as a decimal.
+    pub rate: f64,
+    /// Server-wide default rate, as a decimal.
+    pub default_rate: f64,
+    /// Whether the user has set their own rate.
+    pub is_custom: bool,
+}
+
+/// Request payload for setting the user's self-employment tax rate.
+///
+/// When the `"validation"` feature is enabled, `rate` must be between 0
+/// and 1.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct UpdateSelfEmploymentTaxRateRequest {
+    /// Rate as a decimal (e.g., 0.153 for 15.3%), or `None` to use the
+    /// default rate.
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(min = 0.0, max = 1.0, message = "Rate must be between 0 and 1"))
+    )]
+    pub rate: Option<f64>,
+}
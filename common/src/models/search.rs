@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// The kind of record a search result points to. Serialized as
+/// `snake_case`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchEntityType {
+    /// A company, matched on its name.
+    Company,
+    /// A job, matched on its title.
+    Job,
+}
+
+/// A record matching a search.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResult {
+    /// The kind of record matched.
+    pub entity_type: SearchEntityType,
+    /// Unique identifier of the matched record.
+    pub id: Uuid,
+    /// The company the record belongs to: the company itself, or the job's
+    /// company.
+    pub company_id: Uuid,
+    /// The matched company name or job title.
+    pub title: String,
+    /// HTML-escaped `title` with matched terms wrapped in `<mark>` tags.
+    pub snippet: String,
+    /// Relevance of the match; higher is better.
+    pub rank: f32,
+}
+
+/// Search results grouped by the kind of record matched.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SearchResponse {
+    /// Matching companies, most relevant first.
+    pub companies: Vec<SearchResult>,
+    /// Matching jobs, most relevant first.
+    pub jobs: Vec<SearchResult>,
+}
+
+/// Query parameters for searching companies and jobs.
+///
+/// When the `"validation"` feature is enabled, `q` must be 1-200 characters
+/// and `limit` between 1 and 50.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct SearchQuery {
+    /// Search terms. Supports quoted phrases, `or`, and `-` to exclude a
+    /// term.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(min = 1, max = 200, message = "Search must be 1-200 characters"))
+    )]
+    pub q: String,
+    /// Maximum results returned per group. Defaults to 10.
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(min = 1, max = 50, message = "Limit must be between 1 and 50"))
+    )]
+    pub limit: Option<i64>,
+}
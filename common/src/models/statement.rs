@@ -0,0 +1,49 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[cfg(feature = "validation")]
+use crate::validators::statement::validate_statement_period;
+
+/// A statement of payments and hours that was emailed to a company.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompanyStatement {
+    /// Unique identifier for the statement.
+    pub id: Uuid,
+    /// The user who sent the statement.
+    pub user_id: Uuid,
+    /// The company the statement covers.
+    pub company_id: Uuid,
+    /// Email address the statement was sent to.
+    pub recipient_email: String,
+    /// First day covered by the statement (inclusive).
+    pub period_start: NaiveDate,
+    /// Last day covered by the statement (inclusive).
+    pub period_end: NaiveDate,
+    /// Hours worked for the company during the period.
+    pub total_hours: f64,
+    /// Total of the company's payments during the period in dollars.
+    pub total_payments: f64,
+    /// When the statement was sent.
+    pub sent_at: DateTime<Utc>,
+}
+
+/// Request payload for emailing a company statement.
+///
+/// When the `"validation"` feature is enabled, `recipient_email` must be a
+/// valid email address and `period_end` must not be before `period_start`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+#[cfg_attr(
+    feature = "validation",
+    validate(schema(function = "validate_statement_period"))
+)]
+pub struct SendStatementRequest {
+    /// Email address of the company contact to send the statement to.
+    #[cfg_attr(feature = "validation", validate(email(message = "Email is invalid")))]
+    pub recipient_email: String,
+    /// First day to cover (inclusive).
+    pub period_start: NaiveDate,
+    /// Last day to cover (inclusive).
+    pub period_end: NaiveDate,
+}
@@ -0,0 +1,92 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A GigLog pricing tier. Serialized as `snake_case`.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriptionPlan {
+    /// The free tier every account starts on.
+    #[default]
+    Free,
+    /// The paid tier billed through Stripe.
+    Pro,
+}
+
+/// The Stripe status of a subscription. Serialized as `snake_case`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriptionStatus {
+    /// Checkout finished but the first payment has not succeeded yet.
+    Incomplete,
+    /// The first payment never succeeded and the subscription lapsed.
+    IncompleteExpired,
+    /// The subscription is in its free trial.
+    Trialing,
+    /// The subscription is paid up.
+    Active,
+    /// The latest renewal payment failed and is being retried.
+    PastDue,
+    /// The subscription has ended.
+    Canceled,
+    /// Renewal payments failed and retries were exhausted.
+    Unpaid,
+    /// The trial ended without a payment method.
+    Paused,
+}
+
+/// A user's current subscription.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Subscription {
+    /// The plan the user is subscribed to.
+    pub plan: SubscriptionPlan,
+    /// The subscription's Stripe status, or `None` if the user never
+    /// subscribed.
+    pub status: Option<SubscriptionStatus>,
+    /// When the current billing period ends.
+    pub current_period_end: Option<DateTime<Utc>>,
+    /// Whether the subscription ends instead of renewing at the end of the
+    /// current period.
+    pub cancel_at_period_end: bool,
+}
+
+impl Subscription {
+    /// Returns the plan whose features the user may use.
+    ///
+    /// Paid plans only count while the subscription is active or trialing.
+    ///
+    /// # Returns
+    ///
+    /// [`plan`](Self::plan) for a subscription in good standing, otherwise
+    /// [`SubscriptionPlan::Free`].
+    pub fn effective_plan(&self) -> SubscriptionPlan {
+        match self.status {
+            Some(SubscriptionStatus::Active | SubscriptionStatus::Trialing) => self.plan,
+            _ => SubscriptionPlan::Free,
+        }
+    }
+}
+
+impl Default for Subscription {
+    fn default() -> Self {
+        Self {
+            plan: SubscriptionPlan::Free,
+            status: None,
+            current_period_end: None,
+            cancel_at_period_end: false,
+        }
+    }
+}
+
+/// Request payload for starting a Stripe Checkout session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateCheckoutSessionRequest {
+    /// The paid plan to subscribe to.
+    pub plan: SubscriptionPlan,
+}
+
+/// Response body for a started Stripe Checkout session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckoutSessionResponse {
+    /// The Stripe-hosted checkout page to send the user to.
+    pub url: String,
+}
@@ -0,0 +1,101 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[cfg(feature = "validation")]
+use crate::validators::time_off::{validate_time_off_dates, validate_time_off_range};
+
+/// A planned period away from work, such as a vacation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeOff {
+    /// Unique identifier for the time-off entry.
+    pub id: Uuid,
+    /// The user taking the time off.
+    pub user_id: Uuid,
+    /// First day off (inclusive).
+    pub start_date: NaiveDate,
+    /// Last day off (inclusive).
+    pub end_date: NaiveDate,
+    /// Optional note describing the time off.
+    pub note: Option<String>,
+    /// Timestamp when the entry was created.
+    pub created_at: DateTime<Utc>,
+    /// Timestamp when the entry was last updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Request payload for scheduling time off.
+///
+/// When the `"validation"` feature is enabled, `end_date` must not be before
+/// `start_date` and `note` is limited to 500 characters.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+#[cfg_attr(
+    feature = "validation",
+    validate(schema(function = "validate_time_off_dates"))
+)]
+pub struct CreateTimeOffRequest {
+    /// First day off (inclusive).
+    pub start_date: NaiveDate,
+    /// Last day off (inclusive).
+    pub end_date: NaiveDate,
+    /// Optional note describing the time off.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(max = 500, message = "Note must be at most 500 characters"))
+    )]
+    pub note: Option<String>,
+}
+
+/// Query parameters for listing time off.
+///
+/// Entries overlapping the given range are returned; either bound may be
+/// omitted. When the `"validation"` feature is enabled, `from` must not be
+/// after `to`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+#[cfg_attr(
+    feature = "validation",
+    validate(schema(function = "validate_time_off_range"))
+)]
+pub struct TimeOffRangeQuery {
+    /// Only include entries ending on or after this date.
+    pub from: Option<NaiveDate>,
+    /// Only include entries starting on or before this date.
+    pub to: Option<NaiveDate>,
+}
+
+/// Query parameters for the week availability view.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WeekAvailabilityQuery {
+    /// Any date within the requested week. Defaults to today.
+    pub date: Option<NaiveDate>,
+}
+
+/// Availability for a single day in a week view.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DayAvailability {
+    /// The calendar date.
+    pub date: NaiveDate,
+    /// Whether the date falls on a Saturday or Sunday.
+    pub is_weekend: bool,
+    /// Whether the date is covered by scheduled time off.
+    pub is_time_off: bool,
+}
+
+/// Availability for a Monday-to-Sunday week.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeekAvailability {
+    /// Monday of the week.
+    pub week_start: NaiveDate,
+    /// Sunday of the week.
+    pub week_end: NaiveDate,
+    /// Per-day availability, Monday first.
+    pub days: Vec<DayAvailability>,
+    /// Number of weekdays not covered by time off.
+    pub available_weekdays: i64,
+    /// Number of weekdays covered by time off.
+    pub time_off_weekdays: i64,
+    /// Time-off entries overlapping the week.
+    pub time_off: Vec<TimeOff>,
+}
@@ -0,0 +1,140 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[cfg(feature = "validation")]
+use crate::validators::timesheet::validate_timesheet_period;
+
+/// The review state of a shared timesheet. Serialized as `snake_case`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalStatus {
+    /// The client has not yet responded.
+    Pending,
+    /// The client approved the hours; the included sessions are locked.
+    Approved,
+    /// The client disputed the hours.
+    Disputed,
+}
+
+/// A request for a client to review a period of tracked hours.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimesheetApproval {
+    /// Unique identifier for the approval request.
+    pub id: Uuid,
+    /// The user who shared the timesheet.
+    pub user_id: Uuid,
+    /// The company whose work is being reviewed.
+    pub company_id: Uuid,
+    /// First day of the reviewed period (inclusive).
+    pub period_start: NaiveDate,
+    /// Last day of the reviewed period (inclusive).
+    pub period_end: NaiveDate,
+    /// Current review state.
+    pub status: ApprovalStatus,
+    /// Email address of the client who responded, once responded.
+    pub approver_email: Option<String>,
+    /// Reason given by the client when disputing the hours.
+    pub dispute_reason: Option<String>,
+    /// When the client responded. `None` while pending.
+    pub responded_at: Option<DateTime<Utc>>,
+    /// When the shareable link stops working.
+    pub expires_at: DateTime<Utc>,
+    /// Timestamp when the approval request was created.
+    pub created_at: DateTime<Utc>,
+}
+
+/// Request payload for sharing a timesheet with a client.
+///
+/// When the `"validation"` feature is enabled, `period_end` must not be
+/// before `period_start`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+#[cfg_attr(
+    feature = "validation",
+    validate(schema(function = "validate_timesheet_period"))
+)]
+pub struct CreateTimesheetApprovalRequest {
+    /// The company whose work is being reviewed.
+    pub company_id: Uuid,
+    /// First day of the reviewed period (inclusive).
+    pub period_start: NaiveDate,
+    /// Last day of the reviewed period (inclusive).
+    pub period_end: NaiveDate,
+}
+
+/// Response returned when a timesheet approval link is created.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimesheetApprovalLinkResponse {
+    /// The created approval request.
+    pub approval: TimesheetApproval,
+    /// Raw share token. Only returned once; the server stores a hash.
+    pub token: String,
+}
+
+/// Request payload for a client approving a shared timesheet.
+///
+/// When the `"validation"` feature is enabled, `approver_email` is validated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct ApproveTimesheetRequest {
+    /// Email address of the approving client.
+    #[cfg_attr(feature = "validation", validate(email(message = "Email is invalid")))]
+    pub approver_email: String,
+    /// The `version` of the [`SharedTimesheetResponse`] the client
+    /// reviewed. The approval is rejected if the sessions have changed
+    /// since.
+    pub version: String,
+}
+
+/// Request payload for a client disputing a shared timesheet.
+///
+/// When the `"validation"` feature is enabled, fields are validated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct DisputeTimesheetRequest {
+    /// Email address of the disputing client.
+    #[cfg_attr(feature = "validation", validate(email(message = "Email is invalid")))]
+    pub approver_email: String,
+    /// Explanation of what is disputed.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(min = 1, message = "Reason is required"))
+    )]
+    pub reason: String,
+}
+
+/// A single work session shown on a shared timesheet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimesheetEntry {
+    /// The work session's ID.
+    pub work_session_id: Uuid,
+    /// Title of the job the session was tracked against.
+    pub job_title: String,
+    /// When the session started.
+    pub start_time: DateTime<Utc>,
+    /// When the session ended.
+    pub end_time: DateTime<Utc>,
+    /// Worked duration in seconds, excluding paused time.
+    pub net_seconds: i64,
+}
+
+/// Public view of a shared timesheet, shown to the reviewing client.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SharedTimesheetResponse {
+    /// Name of the company being billed.
+    pub company_name: String,
+    /// First day of the reviewed period (inclusive).
+    pub period_start: NaiveDate,
+    /// Last day of the reviewed period (inclusive).
+    pub period_end: NaiveDate,
+    /// Current review state.
+    pub status: ApprovalStatus,
+    /// Completed work sessions within the period.
+    pub entries: Vec<TimesheetEntry>,
+    /// Sum of all entry durations in seconds.
+    pub total_seconds: i64,
+    /// Fingerprint of `entries`, sent back when approving so the approval
+    /// covers exactly the sessions shown.
+    pub version: String,
+}
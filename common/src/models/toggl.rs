@@ -0,0 +1,93 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "validation")]
+use crate::validators::toggl::validate_toggl_time_entry;
+
+/// A work session in the Toggl Track API v9 time entry shape.
+///
+/// Running entries report `stop` as `None` and `duration` as the negated
+/// start time in epoch seconds, matching Toggl's convention.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TogglTimeEntry {
+    /// Numeric time entry ID.
+    pub id: i64,
+    /// Workspace the entry belongs to.
+    pub workspace_id: i64,
+    /// Numeric ID of the job the entry is tracked against.
+    pub project_id: Option<i64>,
+    /// Always `None`; tasks are not supported.
+    pub task_id: Option<i64>,
+    /// Whether the entry is billable at an hourly rate.
+    pub billable: bool,
+    /// When the entry started.
+    pub start: DateTime<Utc>,
+    /// When the entry stopped. `None` while running.
+    pub stop: Option<DateTime<Utc>>,
+    /// Worked seconds, or the negated start epoch while running.
+    pub duration: i64,
+    /// Entry description.
+    pub description: Option<String>,
+    /// Always empty; tags are not supported.
+    pub tags: Vec<String>,
+    /// Always `false`; entries always carry start and stop times.
+    pub duronly: bool,
+    /// When the entry was last updated.
+    pub at: DateTime<Utc>,
+}
+
+/// A job in the Toggl Track API v9 project shape.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TogglProject {
+    /// Numeric project ID.
+    pub id: i64,
+    /// Workspace the project belongs to.
+    pub workspace_id: i64,
+    /// Project name, taken from the job title.
+    pub name: String,
+    /// Always `true`; archived jobs are not supported.
+    pub active: bool,
+    /// Whether the job is paid hourly.
+    pub billable: bool,
+    /// Hourly rate in dollars, for hourly jobs.
+    pub rate: Option<f64>,
+}
+
+/// Request payload for creating a Toggl time entry.
+///
+/// A negative `duration` without `stop` starts a running entry; otherwise
+/// the entry is recorded as completed. When the `"validation"` feature is
+/// enabled, `project_id` is required and `stop` must not be before `start`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+#[cfg_attr(
+    feature = "validation",
+    validate(schema(function = "validate_toggl_time_entry"))
+)]
+pub struct CreateTogglTimeEntryRequest {
+    /// Name of the client creating the entry. Ignored.
+    pub created_with: Option<String>,
+    /// Entry description. Ignored.
+    pub description: Option<String>,
+    /// Numeric ID of the job to track against.
+    pub project_id: Option<i64>,
+    /// When the entry started.
+    pub start: DateTime<Utc>,
+    /// When the entry stopped, for completed entries.
+    pub stop: Option<DateTime<Utc>>,
+    /// Worked seconds, or a negative value for a running entry.
+    pub duration: Option<i64>,
+    /// Workspace ID from the request body. Ignored in favor of the path.
+    pub workspace_id: Option<i64>,
+}
+
+/// Query parameters for listing Toggl time entries.
+///
+/// Bounds accept either `YYYY-MM-DD` dates or RFC 3339 timestamps.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TogglTimeEntriesQuery {
+    /// Only include entries starting at or after this bound.
+    pub start_date: Option<String>,
+    /// Only include entries starting before this bound.
+    pub end_date: Option<String>,
+}
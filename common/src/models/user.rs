@@ -20,12 +20,41 @@ pub struct User {
     pub email: String,
     /// Whether the user has confirmed their email address.
     pub email_confirmed: bool,
+    /// Path of the user's resized avatar image, or `None` if no avatar has
+    /// been uploaded. Changes whenever a new avatar is uploaded.
+    pub avatar_url: Option<String>,
     /// Timestamp when the user account was created.
     pub created_at: DateTime<Utc>,
     /// Timestamp when the user account was last updated.
     pub updated_at: DateTime<Utc>,
 }
 
+/// Progress through the guided account setup checklist.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OnboardingState {
+    /// Whether the user has confirmed their email address.
+    pub confirmed_email: bool,
+    /// Whether the user has created a company.
+    pub created_first_company: bool,
+    /// Whether the user has tracked a work session.
+    pub tracked_first_session: bool,
+    /// Whether the user has recorded a payment.
+    pub recorded_first_payment: bool,
+}
+
+/// Response payload for the authenticated user's profile.
+///
+/// Serializes the [`User`] fields at the top level alongside the
+/// onboarding state.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurrentUserResponse {
+    /// The authenticated user.
+    #[serde(flatten)]
+    pub user: User,
+    /// The user's progress through account setup.
+    pub onboarding_state: OnboardingState,
+}
+
 /// Request payload for creating a new user account.
 ///
 /// When the `"validation"` feature is enabled, fields are validated and
@@ -52,10 +81,11 @@ pub struct SignUpRequest {
     /// User's email address.
     #[cfg_attr(feature = "validation", validate(email(message = "Email is invalid")))]
     pub email: String,
-    /// Desired password (minimum 8 characters).
+    /// Desired password. Length and strength are checked against the
+    /// server's [`PasswordPolicy`](crate::validators::password_strength::PasswordPolicy).
     #[cfg_attr(
         feature = "validation",
-        validate(length(min = 8, message = "Password must have at least 8 characters"))
+        validate(length(min = 1, message = "Password is required"))
     )]
     pub password: String,
     /// Must match `password`.
@@ -153,6 +183,9 @@ pub struct ForgotPasswordRequest {
     validate(schema(function = "validate_set_password_match"))
 )]
 pub struct SetPasswordRequest {
+    /// The email address the code was sent to.
+    #[cfg_attr(feature = "validation", validate(email(message = "Email is invalid")))]
+    pub email: String,
     /// Verification code sent to the user.
     #[cfg_attr(
         feature = "validation",
@@ -175,10 +208,13 @@ pub struct SetPasswordRequest {
 
 /// Request payload for verifying a forgot-password code.
 ///
-/// When the `"validation"` feature is enabled, `code` is validated.
+/// When the `"validation"` feature is enabled, `email` and `code` are validated.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "validation", derive(validator::Validate))]
 pub struct VerifyForgotPasswordRequest {
+    /// The email address the code was sent to.
+    #[cfg_attr(feature = "validation", validate(email(message = "Email is invalid")))]
+    pub email: String,
     /// Verification code sent to the user.
     #[cfg_attr(
         feature = "validation",
@@ -189,10 +225,15 @@ pub struct VerifyForgotPasswordRequest {
 
 /// Request payload for confirming a user's email address.
 ///
-/// When the `"validation"` feature is enabled, `code` is validated.
+/// Also used to confirm an email change, in which case `email` is the new
+/// address. When the `"validation"` feature is enabled, `email` and `code`
+/// are validated.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "validation", derive(validator::Validate))]
 pub struct ConfirmEmailRequest {
+    /// The email address the code was sent to.
+    #[cfg_attr(feature = "validation", validate(email(message = "Email is invalid")))]
+    pub email: String,
     /// Authentication code sent to the user's email.
     #[cfg_attr(
         feature = "validation",
@@ -200,3 +241,67 @@ pub struct ConfirmEmailRequest {
     )]
     pub code: String,
 }
+
+/// Request payload for re-sending a sign-up confirmation code.
+///
+/// When the `"validation"` feature is enabled, `email` is validated as a valid email.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct ResendConfirmationRequest {
+    /// The email address the account was registered with.
+    #[cfg_attr(feature = "validation", validate(email(message = "Email is invalid")))]
+    pub email: String,
+}
+
+/// Query parameters of a signed link sent in a confirmation or password
+/// reset email.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmailLinkQuery {
+    /// The signed link token.
+    pub token: String,
+}
+
+/// Response payload for a newly provisioned demo account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DemoAccountResponse {
+    /// The throwaway demo user.
+    pub user: User,
+    /// When the demo account and all of its data are purged.
+    pub expires_at: DateTime<Utc>,
+}
+
+/// An active login session, backed by a refresh token.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserSession {
+    /// Unique identifier for the session.
+    pub id: Uuid,
+    /// `User-Agent` of the device that started the session, if sent.
+    pub user_agent: Option<String>,
+    /// When the session was started by logging in.
+    pub created_at: DateTime<Utc>,
+    /// When the session was last refreshed.
+    pub last_used_at: DateTime<Utc>,
+    /// When the session expires unless refreshed.
+    pub expires_at: DateTime<Utc>,
+    /// Whether this is the session making the request.
+    pub current: bool,
+}
+
+/// A request the user made to the API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiActivity {
+    /// Unique identifier for the activity entry.
+    pub id: Uuid,
+    /// HTTP method of the request.
+    pub method: String,
+    /// Route pattern the request matched, e.g. `/jobs/{id}`.
+    pub route: String,
+    /// HTTP status code of the response.
+    pub status: i16,
+    /// IP address the request came from, if known.
+    pub ip_address: Option<String>,
+    /// `User-Agent` of the device that made the request, if sent.
+    pub user_agent: Option<String>,
+    /// When the request was made.
+    pub created_at: DateTime<Utc>,
+}
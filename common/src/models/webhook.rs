@@ -0,0 +1,234 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::{invoice::Invoice, payment::Payment, work_session::WorkSession};
+#[cfg(feature = "validation")]
+use crate::validators::webhook::validate_webhook_url;
+
+/// A kind of event a webhook can subscribe to.
+///
+/// Serialized as `<resource>.<action>`, e.g. `"payment.created"`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum WebhookEventType {
+    /// A payment was created from installments or a recurring schedule.
+    #[serde(rename = "payment.created")]
+    PaymentCreated,
+    /// A payment was updated.
+    #[serde(rename = "payment.updated")]
+    PaymentUpdated,
+    /// A work session timer was started.
+    #[serde(rename = "work_session.started")]
+    WorkSessionStarted,
+    /// A work session was completed, logged, or auto-completed.
+    #[serde(rename = "work_session.completed")]
+    WorkSessionCompleted,
+    /// A work session was deleted.
+    #[serde(rename = "work_session.deleted")]
+    WorkSessionDeleted,
+    /// An invoice was finalized.
+    #[serde(rename = "invoice.finalized")]
+    InvoiceFinalized,
+}
+
+impl WebhookEventType {
+    /// Every event type, in serialization order.
+    pub const ALL: [WebhookEventType; 6] = [
+        WebhookEventType::PaymentCreated,
+        WebhookEventType::PaymentUpdated,
+        WebhookEventType::WorkSessionStarted,
+        WebhookEventType::WorkSessionCompleted,
+        WebhookEventType::WorkSessionDeleted,
+        WebhookEventType::InvoiceFinalized,
+    ];
+
+    /// Returns the event type's serialized name.
+    ///
+    /// # Returns
+    ///
+    /// The `<resource>.<action>` name, e.g. `"payment.created"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookEventType::PaymentCreated => "payment.created",
+            WebhookEventType::PaymentUpdated => "payment.updated",
+            WebhookEventType::WorkSessionStarted => "work_session.started",
+            WebhookEventType::WorkSessionCompleted => "work_session.completed",
+            WebhookEventType::WorkSessionDeleted => "work_session.deleted",
+            WebhookEventType::InvoiceFinalized => "invoice.finalized",
+        }
+    }
+
+    /// Parses an event type from its serialized name.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — The `<resource>.<action>` name.
+    ///
+    /// # Returns
+    ///
+    /// The matching [`WebhookEventType`], or `None` if the name is unknown.
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|event_type| event_type.as_str() == value)
+    }
+}
+
+/// An event delivered to webhooks. Serialized with its event type in
+/// `type` and the event payload in `data`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum WebhookEvent {
+    /// A payment was created from installments or a recurring schedule.
+    #[serde(rename = "payment.created")]
+    PaymentCreated(Payment),
+    /// A payment was updated.
+    #[serde(rename = "payment.updated")]
+    PaymentUpdated(Payment),
+    /// A work session timer was started.
+    #[serde(rename = "work_session.started")]
+    WorkSessionStarted(WorkSession),
+    /// A work session was completed, logged, or auto-completed.
+    #[serde(rename = "work_session.completed")]
+    WorkSessionCompleted(WorkSession),
+    /// A work session was deleted.
+    #[serde(rename = "work_session.deleted")]
+    WorkSessionDeleted {
+        /// The deleted work session's ID.
+        id: Uuid,
+    },
+    /// An invoice was finalized.
+    #[serde(rename = "invoice.finalized")]
+    InvoiceFinalized(Invoice),
+}
+
+impl WebhookEvent {
+    /// Returns the type of the event.
+    ///
+    /// # Returns
+    ///
+    /// The [`WebhookEventType`] webhooks subscribe to for this event.
+    pub fn event_type(&self) -> WebhookEventType {
+        match self {
+            WebhookEvent::PaymentCreated(_) => WebhookEventType::PaymentCreated,
+            WebhookEvent::PaymentUpdated(_) => WebhookEventType::PaymentUpdated,
+            WebhookEvent::WorkSessionStarted(_) => WebhookEventType::WorkSessionStarted,
+            WebhookEvent::WorkSessionCompleted(_) => WebhookEventType::WorkSessionCompleted,
+            WebhookEvent::WorkSessionDeleted { .. } => WebhookEventType::WorkSessionDeleted,
+            WebhookEvent::InvoiceFinalized(_) => WebhookEventType::InvoiceFinalized,
+        }
+    }
+}
+
+/// The JSON body POSTed to a webhook's URL.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookPayload {
+    /// Unique identifier for the event, shared by every webhook it is
+    /// delivered to and kept across retries.
+    pub id: Uuid,
+    /// When the event occurred.
+    pub created_at: DateTime<Utc>,
+    /// The event's type and data.
+    #[serde(flatten)]
+    pub event: WebhookEvent,
+}
+
+/// A registered webhook, without its signing secret.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Webhook {
+    /// Unique identifier for the webhook.
+    pub id: Uuid,
+    /// URL events are POSTed to.
+    pub url: String,
+    /// Event types delivered to the webhook.
+    pub event_types: Vec<WebhookEventType>,
+    /// Timestamp when the webhook was created.
+    pub created_at: DateTime<Utc>,
+}
+
+/// Request payload for registering a webhook.
+///
+/// When the `"validation"` feature is enabled, `url` must be a valid `http`
+/// or `https` URL pointing to a public host and at least one event type
+/// must be chosen.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct CreateWebhookRequest {
+    /// URL events are POSTed to.
+    #[cfg_attr(
+        feature = "validation",
+        validate(
+            url(message = "URL must be a valid URL"),
+            custom(function = "validate_webhook_url")
+        )
+    )]
+    pub url: String,
+    /// Event types to deliver.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(min = 1, message = "At least one event type is required"))
+    )]
+    pub event_types: Vec<WebhookEventType>,
+}
+
+/// Response returned when a webhook is registered.
+///
+/// This is the only time the signing secret is returned.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWebhookResponse {
+    /// Secret used to sign the webhook's deliveries.
+    pub secret: String,
+    /// The registered webhook's details.
+    pub webhook: Webhook,
+}
+
+/// Result of sending a test delivery to a webhook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookTestResponse {
+    /// Whether the URL accepted the delivery with a `2xx` response.
+    pub success: bool,
+    /// HTTP status of the response, if one was received.
+    pub response_status: Option<i32>,
+    /// How long the URL took to respond or fail, in milliseconds.
+    pub latency_ms: i64,
+    /// Why the delivery failed, if it did.
+    pub error: Option<String>,
+}
+
+/// The delivery state of a webhook event. Serialized as `snake_case`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookDeliveryStatus {
+    /// The delivery has not succeeded yet and will be attempted again.
+    Pending,
+    /// The webhook's URL accepted the delivery with a `2xx` response.
+    Succeeded,
+    /// Every attempt failed and the delivery was abandoned.
+    Failed,
+}
+
+/// One event's delivery to a webhook, as shown in its delivery log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDelivery {
+    /// Unique identifier for the delivery.
+    pub id: Uuid,
+    /// The delivered event's ID, matching the payload's `id`.
+    pub event_id: Uuid,
+    /// The delivered event's type.
+    pub event_type: WebhookEventType,
+    /// The delivery's current state.
+    pub status: WebhookDeliveryStatus,
+    /// Number of attempts made so far.
+    pub attempts: i32,
+    /// HTTP status of the most recent response, if one was received.
+    pub response_status: Option<i32>,
+    /// Why the most recent attempt failed, if it did.
+    pub error: Option<String>,
+    /// When the next attempt is due. `None` once the delivery has
+    /// succeeded or failed.
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    /// Timestamp when the event was queued.
+    pub created_at: DateTime<Utc>,
+    /// When the delivery succeeded, if it has.
+    pub delivered_at: Option<DateTime<Utc>>,
+}
@@ -1,6 +1,15 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
+#[cfg(feature = "validation")]
+use validator::Validate;
+
+use crate::models::{generic::Paginated, patch::nullable};
+#[cfg(feature = "validation")]
+use crate::validators::work_session::{
+    validate_start_work_session, validate_work_session_date_range, validate_work_session_tags,
+    validate_work_session_times,
+};
 
 /// The current status of a work session. Serialized as `snake_case`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -35,8 +44,345 @@ pub struct WorkSession {
     pub paused_at: Option<DateTime<Utc>>,
     /// Manually reported time in seconds, if provided.
     pub time_reported: Option<i64>,
+    /// Hourly rate in dollars of the job when the session was completed, so
+    /// later rate changes do not reprice it. `None` while the session is in
+    /// progress or for payout-based jobs.
+    pub hourly_rate: Option<f64>,
+    /// Whether the session was completed automatically after running past
+    /// the maximum session duration.
+    pub auto_completed: bool,
+    /// Latitude where the session was started, if location was recorded.
+    pub start_latitude: Option<f64>,
+    /// Longitude where the session was started, if location was recorded.
+    pub start_longitude: Option<f64>,
+    /// Latitude where the session was completed, if location was recorded.
+    pub end_latitude: Option<f64>,
+    /// Longitude where the session was completed, if location was recorded.
+    pub end_longitude: Option<f64>,
+    /// Free-form notes about the work done.
+    pub notes: Option<String>,
+    /// Lowercase labels for separating kinds of work, such as `"meeting"`.
+    pub tags: Vec<String>,
     /// Timestamp when the work session was created.
     pub created_at: DateTime<Utc>,
     /// Timestamp when the work session was last updated.
     pub updated_at: DateTime<Utc>,
 }
+
+/// Query parameters for listing completed work sessions across jobs.
+///
+/// Sessions are dated by the UTC day they started. Every filter is
+/// optional and filters are combined with `AND`. When the `"validation"`
+/// feature is enabled, `from` must not be after `to`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+#[cfg_attr(
+    feature = "validation",
+    validate(schema(function = "validate_work_session_date_range"))
+)]
+pub struct ListWorkSessionsQuery {
+    /// Earliest start date to include, inclusive.
+    pub from: Option<NaiveDate>,
+    /// Latest start date to include, inclusive.
+    pub to: Option<NaiveDate>,
+    /// Restrict results to sessions tracked against this job.
+    pub job_id: Option<Uuid>,
+    /// Restrict results to sessions for this company's jobs.
+    pub company_id: Option<Uuid>,
+}
+
+/// A completed work session with its worked time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompletedWorkSession {
+    /// The session's details.
+    #[serde(flatten)]
+    pub session: WorkSession,
+    /// Worked duration in seconds, excluding paused time.
+    pub net_seconds: i64,
+}
+
+/// Worked time on one day of a work session list.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkSessionDayTotal {
+    /// The UTC day the sessions started.
+    pub date: NaiveDate,
+    /// Number of sessions started that day.
+    pub session_count: i64,
+    /// Worked duration of those sessions in seconds, excluding paused time.
+    pub net_seconds: i64,
+}
+
+/// Response payload for listing completed work sessions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkSessionListResponse {
+    /// The requested page of sessions.
+    #[serde(flatten)]
+    pub page: Paginated<CompletedWorkSession>,
+    /// Worked time per day across every page, oldest day first.
+    pub days: Vec<WorkSessionDayTotal>,
+    /// Worked duration of every matching session in seconds.
+    pub net_seconds: i64,
+}
+
+/// Query parameters for waiting on a change to the active work session.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WaitActiveWorkSessionQuery {
+    /// Seconds to wait for a change before responding. Defaults to 30 and is
+    /// capped at 60.
+    pub timeout: Option<u64>,
+}
+
+/// Response payload for waiting on the active work session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActiveWorkSessionResponse {
+    /// The running or paused session, or `None` if no session is active.
+    pub session: Option<WorkSession>,
+    /// Whether the active session changed before the timeout expired.
+    pub changed: bool,
+}
+
+/// A point on the globe reported by the client's device.
+///
+/// When the `"validation"` feature is enabled, `latitude` must be between
+/// -90 and 90 and `longitude` between -180 and 180.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct GeoLocation {
+    /// Latitude in decimal degrees.
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(min = -90.0, max = 90.0, message = "Latitude must be between -90 and 90"))
+    )]
+    pub latitude: f64,
+    /// Longitude in decimal degrees.
+    #[cfg_attr(
+        feature = "validation",
+        validate(range(
+            min = -180.0,
+            max = 180.0,
+            message = "Longitude must be between -180 and 180"
+        ))
+    )]
+    pub longitude: f64,
+}
+
+/// Request payload for starting a work session, or for logging one that
+/// was not timed.
+///
+/// When `start_time` and `end_time` are both set, a completed session is
+/// logged with those times instead of starting a timer. When the
+/// `"validation"` feature is enabled, the times must be set together, the
+/// session must end after it starts, the paused duration must be shorter
+/// than the session and only set for logged sessions, and the notes and
+/// tags must be within the limits described on
+/// [`UpdateWorkSessionDetailsRequest`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+#[cfg_attr(
+    feature = "validation",
+    validate(schema(function = "validate_start_work_session"))
+)]
+pub struct StartWorkSessionRequest {
+    /// The job to track time against.
+    pub job_id: Uuid,
+    /// Where the session is being started. Ignored unless the user has
+    /// location tracking enabled, and for logged sessions.
+    #[serde(default)]
+    #[cfg_attr(feature = "validation", validate(nested))]
+    pub location: Option<GeoLocation>,
+    /// When a logged session started.
+    #[serde(default)]
+    pub start_time: Option<DateTime<Utc>>,
+    /// When a logged session ended.
+    #[serde(default)]
+    pub end_time: Option<DateTime<Utc>>,
+    /// Time spent paused during a logged session, in seconds. Defaults to
+    /// 0.
+    #[serde(default)]
+    pub accumulated_paused_duration: Option<i64>,
+    /// Free-form notes about the work.
+    #[serde(default)]
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(max = 2000, message = "Notes must be at most 2000 characters"))
+    )]
+    pub notes: Option<String>,
+    /// Labels for the session. Trimmed, lowercased, and deduplicated.
+    #[serde(default)]
+    #[cfg_attr(
+        feature = "validation",
+        validate(custom(function = "validate_work_session_tags"))
+    )]
+    pub tags: Vec<String>,
+}
+
+/// Request payload for correcting the times of a completed work session.
+///
+/// When the `"validation"` feature is enabled, the session must end after
+/// it starts and the paused duration must be at least 0 and shorter than
+/// the session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+#[cfg_attr(
+    feature = "validation",
+    validate(schema(function = "validate_work_session_times"))
+)]
+pub struct UpdateWorkSessionRequest {
+    /// When the session started.
+    pub start_time: DateTime<Utc>,
+    /// When the session ended.
+    pub end_time: DateTime<Utc>,
+    /// Time spent paused during the session, in seconds. Defaults to 0.
+    #[serde(default)]
+    pub accumulated_paused_duration: i64,
+}
+
+/// Optional request payload for completing a work session.
+///
+/// Notes and tags that are set replace the session's current ones, and
+/// blank notes clear them. They are validated as on
+/// [`UpdateWorkSessionDetailsRequest`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct CompleteWorkSessionRequest {
+    /// Where the session is being completed. Ignored unless the user has
+    /// location tracking enabled.
+    #[serde(default)]
+    #[cfg_attr(feature = "validation", validate(nested))]
+    pub location: Option<GeoLocation>,
+    /// Notes about the work, replacing any set when the session started.
+    #[serde(default)]
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(max = 2000, message = "Notes must be at most 2000 characters"))
+    )]
+    pub notes: Option<String>,
+    /// Labels for the session, replacing any set when it started.
+    #[serde(default)]
+    #[cfg_attr(
+        feature = "validation",
+        validate(custom(function = "validate_work_session_tags"))
+    )]
+    pub tags: Option<Vec<String>>,
+}
+
+/// Request payload for partially updating a work session's notes and tags.
+///
+/// Omitted fields are left unchanged and `null` clears the notes. Tags are
+/// trimmed, lowercased, and deduplicated. When the `"validation"` feature
+/// is enabled, `notes` must be at most 2000 characters and there may be at
+/// most 20 tags of 1-50 characters each.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct UpdateWorkSessionDetailsRequest {
+    /// Updated notes, or `Some(None)` to clear them.
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(max = 2000, message = "Notes must be at most 2000 characters"))
+    )]
+    pub notes: Option<Option<String>>,
+    /// Updated labels, replacing the current ones.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "validation",
+        validate(custom(function = "validate_work_session_tags"))
+    )]
+    pub tags: Option<Vec<String>>,
+}
+
+/// Query parameters for listing a job's work sessions.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListJobWorkSessionsQuery {
+    /// Restrict results to sessions with this tag. Matched case-insensitively.
+    pub tag: Option<String>,
+}
+
+/// The user's location tracking preference for work sessions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocationTrackingSetting {
+    /// Whether locations sent when starting and completing sessions are
+    /// recorded. Off by default.
+    pub enabled: bool,
+}
+
+/// A place where work for a company was done, with nearby session
+/// locations grouped together.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompanyLocation {
+    /// Latitude of the place, rounded to three decimal places.
+    pub latitude: f64,
+    /// Longitude of the place, rounded to three decimal places.
+    pub longitude: f64,
+    /// Number of sessions recorded at the place.
+    pub session_count: i64,
+    /// Start time of the first session recorded at the place.
+    pub first_visited_at: DateTime<Utc>,
+    /// Start time of the most recent session recorded at the place.
+    pub last_visited_at: DateTime<Utc>,
+}
+
+/// Summary of where work for a company was done.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompanyLocationSummary {
+    /// The company the summary covers.
+    pub company_id: Uuid,
+    /// Number of the company's sessions.
+    pub session_count: i64,
+    /// Number of the company's sessions with a recorded location.
+    pub located_session_count: i64,
+    /// Places where work was done, most visited first.
+    pub locations: Vec<CompanyLocation>,
+}
+
+/// The kind of media attached to a work session. Serialized as
+/// `snake_case`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkSessionAttachmentKind {
+    /// A photo, such as of the job site.
+    Photo,
+    /// A recorded audio memo.
+    VoiceMemo,
+}
+
+/// A photo or voice memo attached to a work session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkSessionAttachment {
+    /// Unique identifier for the attachment.
+    pub id: Uuid,
+    /// The work session the attachment belongs to.
+    pub work_session_id: Uuid,
+    /// Whether the attachment is a photo or a voice memo.
+    pub kind: WorkSessionAttachmentKind,
+    /// Original file name of the upload.
+    pub file_name: String,
+    /// MIME type of the upload.
+    pub content_type: String,
+    /// Size of the upload in bytes.
+    pub size_bytes: i64,
+    /// Transcript of a voice memo. `None` until transcribed.
+    pub transcript: Option<String>,
+    /// BCP 47 language tag of the transcript. `None` until transcribed.
+    pub transcript_language: Option<String>,
+    /// When the voice memo was transcribed. `None` until transcribed.
+    pub transcribed_at: Option<DateTime<Utc>>,
+    /// Timestamp when the attachment was uploaded.
+    pub created_at: DateTime<Utc>,
+}
+
+/// Query parameters for uploading a work session attachment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct UploadWorkSessionAttachmentQuery {
+    /// Original file name of the upload.
+    #[cfg_attr(
+        feature = "validation",
+        validate(length(min = 1, max = 255, message = "File name must be 1-255 characters"))
+    )]
+    pub file_name: String,
+}
@@ -0,0 +1,65 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A work session logged by email that is waiting for confirmation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkSessionDraft {
+    /// Unique identifier for the draft.
+    pub id: Uuid,
+    /// The job the draft was matched to. `None` if no job matched `job_name`.
+    pub job_id: Option<Uuid>,
+    /// Job name as written in the email.
+    pub job_name: String,
+    /// Logged duration in minutes.
+    pub duration_minutes: i32,
+    /// When the email was received. Confirmed sessions end at this time.
+    pub received_at: DateTime<Utc>,
+    /// Timestamp when the draft was created.
+    pub created_at: DateTime<Utc>,
+}
+
+/// Request payload for confirming a work session draft.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "validation", derive(validator::Validate))]
+pub struct ConfirmWorkSessionDraftRequest {
+    /// Job to log the session against. Required when the draft has no
+    /// matched job; overrides the matched job otherwise.
+    pub job_id: Option<Uuid>,
+}
+
+/// An inbound email delivered by the email provider's webhook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InboundEmail {
+    /// Sender address, optionally with a display name (`Name <addr>`).
+    pub from: String,
+    /// Message subject.
+    pub subject: Option<String>,
+    /// Plain-text message body.
+    pub text: Option<String>,
+    /// Provider or `Message-ID` identifier, used to ignore redelivery.
+    pub message_id: Option<String>,
+}
+
+/// Inbound email webhook payload.
+///
+/// Accepts the email either at the top level or wrapped in a `data`
+/// envelope, as sent by Resend.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum InboundEmailWebhook {
+    /// The email wrapped in an event envelope.
+    Envelope {
+        /// The received email.
+        data: InboundEmail,
+    },
+    /// The email at the top level of the payload.
+    Email(InboundEmail),
+}
+
+/// Query parameters for the inbound email webhook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InboundEmailQuery {
+    /// Shared secret configured in the webhook URL.
+    pub token: String,
+}
@@ -0,0 +1,29 @@
+//! Validators for budget request payloads.
+
+#[cfg(feature = "validation")]
+use crate::models::budget::CreateBudgetRequest;
+
+/// Validates that a [`CreateBudgetRequest`] targets exactly one company or job.
+///
+/// # Arguments
+///
+/// * `req` — The budget request to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if exactly one target is set.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"invalid_budget_target"` if neither or both targets are set.
+#[cfg(feature = "validation")]
+pub fn validate_budget_target(req: &CreateBudgetRequest) -> Result<(), validator::ValidationError> {
+    if req.company_id.is_some() == req.job_id.is_some() {
+        let mut error = validator::ValidationError::new("invalid_budget_target");
+        error.message = Some("Budget must target exactly one company or job".into());
+        return Err(error);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+//! Validators for company request payloads.
+
+/// Color families available as palette tokens.
+#[cfg(feature = "validation")]
+const PALETTE_TOKEN_HUES: [&str; 8] = [
+    "green",
+    "red",
+    "yellow",
+    "blue",
+    "magenta",
+    "cyan",
+    "primary",
+    "secondary",
+];
+
+/// Shades generated for each palette token color family.
+#[cfg(feature = "validation")]
+const PALETTE_TOKEN_SHADES: [&str; 3] = ["60", "80", "100"];
+
+/// Validates that a company's tax withholding rate is set exactly when it
+/// requires tax withholdings.
+///
+/// # Arguments
+///
+/// * `requires_tax_withholdings` — Whether the company requires tax
+///   withholdings on payments.
+/// * `tax_withholding_rate` — The company's tax withholding rate.
+///
+/// # Returns
+///
+/// `Ok(())` if the rate is present only when withholdings are required.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"invalid_tax_withholding"` if the rate is missing while required or set
+/// while not required.
+#[cfg(feature = "validation")]
+pub fn validate_tax_withholding(
+    requires_tax_withholdings: bool,
+    tax_withholding_rate: Option<f64>,
+) -> Result<(), validator::ValidationError> {
+    if requires_tax_withholdings != tax_withholding_rate.is_some() {
+        let mut error = validator::ValidationError::new("invalid_tax_withholding");
+        error.message = Some(
+            "A tax withholding rate is required exactly when tax withholdings are required".into(),
+        );
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+/// Validates a company accent color.
+///
+/// # Arguments
+///
+/// * `accent_color` — A hex color such as `#89b4fa` or `#fa0`, or a palette
+///   token such as `blue-100`.
+///
+/// # Returns
+///
+/// `Ok(())` if the accent color is a valid hex color or palette token.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"invalid_accent_color"` if the value is neither.
+#[cfg(feature = "validation")]
+pub fn validate_accent_color(accent_color: &str) -> Result<(), validator::ValidationError> {
+    let is_hex = accent_color.strip_prefix('#').is_some_and(|digits| {
+        matches!(digits.len(), 3 | 6) && digits.chars().all(|c| c.is_ascii_hexdigit())
+    });
+    let is_token = accent_color.rsplit_once('-').is_some_and(|(hue, shade)| {
+        PALETTE_TOKEN_HUES.contains(&hue) && PALETTE_TOKEN_SHADES.contains(&shade)
+    });
+
+    if !is_hex && !is_token {
+        let mut error = validator::ValidationError::new("invalid_accent_color");
+        error.message = Some(
+            "Accent color must be a hex color such as #89b4fa or a palette token such as blue-100"
+                .into(),
+        );
+        return Err(error);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+//! Validators for contract request payloads.
+
+#[cfg(feature = "validation")]
+use crate::models::contract::{CreateContractRequest, UpdateContractRequest};
+
+/// Validates that `end_date` is not before `start_date` on a
+/// [`CreateContractRequest`].
+///
+/// # Arguments
+///
+/// * `req` — The contract creation request to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if the dates are valid or no end date is set.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"invalid_contract_dates"` if the contract ends before it starts.
+#[cfg(feature = "validation")]
+pub fn validate_create_contract_dates(
+    req: &CreateContractRequest,
+) -> Result<(), validator::ValidationError> {
+    match req.end_date {
+        Some(end_date) if end_date < req.start_date => Err(invalid_contract_dates()),
+        _ => Ok(()),
+    }
+}
+
+/// Validates that `end_date` is not before `start_date` on an
+/// [`UpdateContractRequest`] when both are provided.
+///
+/// Dates provided without their counterpart are checked against the stored
+/// contract when the update is applied.
+///
+/// # Arguments
+///
+/// * `req` — The contract update request to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if the provided dates are valid.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"invalid_contract_dates"` if the contract ends before it starts.
+#[cfg(feature = "validation")]
+pub fn validate_update_contract_dates(
+    req: &UpdateContractRequest,
+) -> Result<(), validator::ValidationError> {
+    match (req.start_date, req.end_date) {
+        (Some(start_date), Some(end_date)) if end_date < start_date => {
+            Err(invalid_contract_dates())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Builds the validation error for a contract that ends before it starts.
+///
+/// # Returns
+///
+/// A [`ValidationError`](validator::ValidationError) with code
+/// `"invalid_contract_dates"`.
+#[cfg(feature = "validation")]
+fn invalid_contract_dates() -> validator::ValidationError {
+    let mut error = validator::ValidationError::new("invalid_contract_dates");
+    error.message = Some("Contract end date must not be before start date".into());
+    error
+}
+
+#[cfg(all(test, feature = "validation"))]
+mod tests {
+    use chrono::NaiveDate;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::models::contract::ContractRateType;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, day).unwrap()
+    }
+
+    fn create(end_date: Option<NaiveDate>) -> CreateContractRequest {
+        CreateContractRequest {
+            company_id: Uuid::nil(),
+            title: "Retainer".to_string(),
+            start_date: date(10),
+            end_date,
+            rate_type: ContractRateType::Hourly,
+            rate_amount: 80.0,
+            terms: None,
+            renewal_reminder_days: None,
+        }
+    }
+
+    fn update(start_date: Option<NaiveDate>, end_date: Option<NaiveDate>) -> UpdateContractRequest {
+        UpdateContractRequest {
+            title: None,
+            start_date,
+            end_date,
+            rate_type: None,
+            rate_amount: None,
+            terms: None,
+            renewal_reminder_days: None,
+        }
+    }
+
+    #[test]
+    fn rejects_contracts_that_end_before_they_start() {
+        assert!(validate_create_contract_dates(&create(None)).is_ok());
+        assert!(validate_create_contract_dates(&create(Some(date(10)))).is_ok());
+
+        let error = validate_create_contract_dates(&create(Some(date(9)))).unwrap_err();
+        assert_eq!(error.code, "invalid_contract_dates");
+    }
+
+    #[test]
+    fn checks_update_dates_only_when_both_are_provided() {
+        assert!(validate_update_contract_dates(&update(None, Some(date(1)))).is_ok());
+        assert!(validate_update_contract_dates(&update(Some(date(20)), None)).is_ok());
+        assert!(validate_update_contract_dates(&update(Some(date(1)), Some(date(2)))).is_ok());
+        assert!(validate_update_contract_dates(&update(Some(date(2)), Some(date(1)))).is_err());
+    }
+}
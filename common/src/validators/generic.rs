@@ -0,0 +1,31 @@
+//! Validators for shared query parameters.
+
+#[cfg(feature = "validation")]
+use crate::models::generic::ExportQuery;
+
+/// Validates that `from` is not after `to` on an [`ExportQuery`].
+///
+/// # Arguments
+///
+/// * `query` — The export query to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if the range is valid or either bound is open.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"invalid_export_range"` if `from` is after `to`.
+#[cfg(feature = "validation")]
+pub fn validate_export_range(query: &ExportQuery) -> Result<(), validator::ValidationError> {
+    if let (Some(from), Some(to)) = (query.from, query.to)
+        && from > to
+    {
+        let mut error = validator::ValidationError::new("invalid_export_range");
+        error.message = Some("From must not be after to".into());
+        return Err(error);
+    }
+
+    Ok(())
+}
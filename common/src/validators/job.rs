@@ -0,0 +1,140 @@
+//! Validators for job request payloads.
+
+#[cfg(feature = "validation")]
+use crate::models::job::{ConvertPaymentTypeRequest, PaymentType, ReorderJobsRequest};
+
+/// Validates that a job's payment fields match its payment type.
+///
+/// Hourly jobs need a positive hourly rate and no payout fields; payout
+/// jobs need a positive payout count and amount and no hourly rate.
+///
+/// # Arguments
+///
+/// * `payment_type` — How the job compensates the worker.
+/// * `hourly_rate` — Hourly rate in dollars.
+/// * `number_of_payouts` — Total number of payouts.
+/// * `payout_amount` — Amount per payout in dollars.
+///
+/// # Returns
+///
+/// `Ok(())` if the payment fields are consistent and positive.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"invalid_payment_fields"` if required fields are missing, not positive,
+/// or set for the other payment type.
+#[cfg(feature = "validation")]
+pub fn validate_payment_fields(
+    payment_type: &PaymentType,
+    hourly_rate: Option<f64>,
+    number_of_payouts: Option<i32>,
+    payout_amount: Option<f64>,
+) -> Result<(), validator::ValidationError> {
+    let valid = match payment_type {
+        PaymentType::Hourly => {
+            hourly_rate.is_some_and(|rate| rate > 0.0)
+                && number_of_payouts.is_none()
+                && payout_amount.is_none()
+        }
+        PaymentType::Payouts => {
+            hourly_rate.is_none()
+                && number_of_payouts.is_some_and(|count| count > 0)
+                && payout_amount.is_some_and(|amount| amount > 0.0)
+        }
+    };
+
+    if !valid {
+        let mut error = validator::ValidationError::new("invalid_payment_fields");
+        error.message = Some(
+            "Hourly jobs need an hourly rate; payout jobs need a payout count and amount".into(),
+        );
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+/// Validates the payment fields of a [`ConvertPaymentTypeRequest`].
+///
+/// Without installments the fields must match the new payment type. With
+/// installments the job must be converting to payouts with a positive
+/// payout count, and the hourly rate and payout amount must be omitted
+/// because the amount is derived from accrued hours.
+///
+/// # Arguments
+///
+/// * `req` — The conversion request to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if the payment fields are consistent.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"invalid_payment_fields"` if the fields do not match the payment type,
+/// or `"invalid_installments"` if installments cannot be created.
+#[cfg(feature = "validation")]
+pub fn validate_payment_type_conversion(
+    req: &ConvertPaymentTypeRequest,
+) -> Result<(), validator::ValidationError> {
+    if req.installment_payout_type.is_none() {
+        return validate_payment_fields(
+            &req.payment_type,
+            req.hourly_rate,
+            req.number_of_payouts,
+            req.payout_amount,
+        );
+    }
+
+    let valid = req.payment_type == PaymentType::Payouts
+        && req.hourly_rate.is_none()
+        && req.number_of_payouts.is_some_and(|count| count > 0)
+        && req.payout_amount.is_none();
+
+    if !valid {
+        let mut error = validator::ValidationError::new("invalid_installments");
+        error.message = Some(
+            "Installments need a payout count, are only created when converting to payouts, \
+             and derive the payout amount from accrued hours"
+                .into(),
+        );
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+/// Validates that a [`ReorderJobsRequest`] moves each job at most once.
+///
+/// # Arguments
+///
+/// * `req` — The reorder request to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if no job appears twice.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"duplicate_job_move"` if a job is moved more than once.
+#[cfg(feature = "validation")]
+pub fn validate_unique_job_moves(
+    req: &ReorderJobsRequest,
+) -> Result<(), validator::ValidationError> {
+    let mut job_ids = std::collections::HashSet::new();
+
+    if !req
+        .moves
+        .iter()
+        .all(|job_move| job_ids.insert(job_move.job_id))
+    {
+        let mut error = validator::ValidationError::new("duplicate_job_move");
+        error.message = Some("Each job can only be moved once".into());
+        return Err(error);
+    }
+
+    Ok(())
+}
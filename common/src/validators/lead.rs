@@ -0,0 +1,89 @@
+//! Validators for lead request payloads.
+
+#[cfg(feature = "validation")]
+use crate::models::lead::{ConvertLeadRequest, LeadStatus, UpdateLeadRequest};
+#[cfg(feature = "validation")]
+use crate::validators::job::validate_payment_fields;
+
+/// Validates that an [`UpdateLeadRequest`] does not mark a lead as won.
+///
+/// # Arguments
+///
+/// * `req` — The lead update request to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if `status` is unset, `open`, or `lost`.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"invalid_lead_status"` if `status` is `won`.
+#[cfg(feature = "validation")]
+pub fn validate_update_lead_status(
+    req: &UpdateLeadRequest,
+) -> Result<(), validator::ValidationError> {
+    if req.status == Some(LeadStatus::Won) {
+        let mut error = validator::ValidationError::new("invalid_lead_status");
+        error.message = Some("Convert a lead to mark it as won".into());
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+/// Validates that a [`ConvertLeadRequest`]'s payment fields match its
+/// payment type.
+///
+/// # Arguments
+///
+/// * `req` — The conversion request to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if the payment fields are consistent and positive.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"invalid_payment_fields"` if required fields are missing, not positive,
+/// or set for the other payment type.
+#[cfg(feature = "validation")]
+pub fn validate_convert_lead_payment(
+    req: &ConvertLeadRequest,
+) -> Result<(), validator::ValidationError> {
+    validate_payment_fields(
+        &req.payment_type,
+        req.hourly_rate,
+        req.number_of_payouts,
+        req.payout_amount,
+    )
+}
+
+#[cfg(all(test, feature = "validation"))]
+mod tests {
+    use super::*;
+
+    fn update(status: Option<LeadStatus>) -> UpdateLeadRequest {
+        UpdateLeadRequest {
+            title: None,
+            company_name: None,
+            source: None,
+            expected_value: None,
+            probability: None,
+            follow_up_date: None,
+            notes: None,
+            status,
+        }
+    }
+
+    #[test]
+    fn leads_are_won_only_by_converting_them() {
+        assert!(validate_update_lead_status(&update(None)).is_ok());
+        assert!(validate_update_lead_status(&update(Some(LeadStatus::Open))).is_ok());
+        assert!(validate_update_lead_status(&update(Some(LeadStatus::Lost))).is_ok());
+
+        let error = validate_update_lead_status(&update(Some(LeadStatus::Won))).unwrap_err();
+        assert_eq!(error.code, "invalid_lead_status");
+    }
+}
@@ -3,7 +3,43 @@
 //! These validators are used with the `validator` crate and are only available
 //! when the `"validation"` feature is enabled. They enforce cross-field
 //! constraints (e.g., password confirmation matching) that cannot be expressed
-//! with field-level derive attributes alone.
+//! with field-level derive attributes alone. The
+//! [`password_strength`](crate::validators::password_strength) module is
+//! always available so clients can score passwords as the user types.
 
+/// Budget-related validation functions.
+pub mod budget;
+/// Company-related validation functions.
+pub mod company;
+/// Contract-related validation functions.
+pub mod contract;
+/// Validation functions for shared query parameters.
+pub mod generic;
+/// Job-related validation functions.
+pub mod job;
+/// Lead-related validation functions.
+pub mod lead;
+/// Onboarding-related validation functions.
+pub mod onboarding;
+/// Password strength scoring and policy enforcement.
+pub mod password_strength;
+/// Payment-related validation functions.
+pub mod payment;
+/// Recurring-payment-related validation functions.
+pub mod recurring_payment;
+/// Report-related validation functions.
+pub mod report;
+/// Statement-related validation functions.
+pub mod statement;
+/// Time-off-related validation functions.
+pub mod time_off;
+/// Timesheet-related validation functions.
+pub mod timesheet;
+/// Toggl-related validation functions.
+pub mod toggl;
 /// User-related validation functions.
 pub mod user;
+/// Webhook-related validation functions.
+pub mod webhook;
+/// Work-session-related validation functions.
+pub mod work_session;
@@ -0,0 +1,85 @@
+//! Validators for onboarding request payloads.
+
+#[cfg(feature = "validation")]
+use crate::models::onboarding::{OnboardingCompany, OnboardingJob, OnboardingWorkSession};
+#[cfg(feature = "validation")]
+use crate::validators::{company::validate_tax_withholding, job::validate_payment_fields};
+
+/// Validates that an [`OnboardingCompany`]'s tax withholding rate is set
+/// exactly when it requires tax withholdings.
+///
+/// # Arguments
+///
+/// * `company` — The onboarding company to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if the rate is present only when withholdings are required.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"invalid_tax_withholding"` if the rate is missing while required or set
+/// while not required.
+#[cfg(feature = "validation")]
+pub fn validate_onboarding_company(
+    company: &OnboardingCompany,
+) -> Result<(), validator::ValidationError> {
+    validate_tax_withholding(
+        company.requires_tax_withholdings,
+        company.tax_withholding_rate,
+    )
+}
+
+/// Validates that an [`OnboardingJob`]'s payment fields match its payment
+/// type.
+///
+/// # Arguments
+///
+/// * `job` — The onboarding job to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if the payment fields are consistent and positive.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"invalid_payment_fields"` if required fields are missing, not positive,
+/// or set for the other payment type.
+#[cfg(feature = "validation")]
+pub fn validate_onboarding_job(job: &OnboardingJob) -> Result<(), validator::ValidationError> {
+    validate_payment_fields(
+        &job.payment_type,
+        job.hourly_rate,
+        job.number_of_payouts,
+        job.payout_amount,
+    )
+}
+
+/// Validates that an [`OnboardingWorkSession`] ends after it starts.
+///
+/// # Arguments
+///
+/// * `session` — The onboarding work session to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if `end_time` is after `start_time`.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"invalid_session_range"` if the session does not end after it starts.
+#[cfg(feature = "validation")]
+pub fn validate_onboarding_work_session(
+    session: &OnboardingWorkSession,
+) -> Result<(), validator::ValidationError> {
+    if session.end_time <= session.start_time {
+        let mut error = validator::ValidationError::new("invalid_session_range");
+        error.message = Some("Work session must end after it starts".into());
+        return Err(error);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,641 @@
+//! Password strength scoring and policy enforcement.
+//!
+//! [`PasswordStrength::estimate`] scores a password from 0 to 4 in the style
+//! of zxcvbn: the password is split into guessable patterns (common
+//! passwords and words, personal details, keyboard runs and sequences,
+//! repeats, and years) and the remaining characters are treated as brute
+//! force. The score follows from the estimated number of guesses.
+//! [`PasswordPolicy`] turns a score below its threshold into validation
+//! errors carrying actionable feedback.
+//!
+//! Unlike the other validators, this module does not require the
+//! `"validation"` feature, so clients can show feedback as the user types.
+
+use crate::models::error::{ValidationError, ValidationErrorCode};
+
+/// Passwords rejected outright, compared case-insensitively.
+const COMMON_PASSWORDS: &[&str] = &[
+    "000000",
+    "111111",
+    "112233",
+    "121212",
+    "123123",
+    "123321",
+    "1234",
+    "12345",
+    "123456",
+    "1234567",
+    "12345678",
+    "123456789",
+    "1234567890",
+    "123qwe",
+    "131313",
+    "159753",
+    "1qaz2wsx",
+    "555555",
+    "654321",
+    "666666",
+    "696969",
+    "777777",
+    "7777777",
+    "987654321",
+    "aaaaaa",
+    "abc123",
+    "abcd1234",
+    "abcdef",
+    "access",
+    "admin",
+    "admin123",
+    "asdfgh",
+    "baseball",
+    "batman",
+    "biteme",
+    "buster",
+    "changeme",
+    "charlie",
+    "cheese",
+    "computer",
+    "dragon",
+    "football",
+    "freedom",
+    "giglog",
+    "ginger",
+    "hockey",
+    "hunter",
+    "iloveyou",
+    "iloveyou1",
+    "jennifer",
+    "jessica",
+    "jordan",
+    "killer",
+    "letmein",
+    "letmein1",
+    "login",
+    "love",
+    "master",
+    "matrix",
+    "michael",
+    "monkey",
+    "mustang",
+    "p@ssw0rd",
+    "pass",
+    "passw0rd",
+    "password",
+    "password1",
+    "password123",
+    "pepper",
+    "princess",
+    "qazwsx",
+    "qwerty",
+    "qwerty123",
+    "qwertyuiop",
+    "ranger",
+    "secret",
+    "shadow",
+    "soccer",
+    "starwars",
+    "summer",
+    "sunshine",
+    "superman",
+    "thunder",
+    "tigger",
+    "trustno1",
+    "welcome",
+    "welcome1",
+    "zxcvbn",
+    "zxcvbnm",
+];
+
+/// Words that make a password easy to guess when they appear in it.
+const COMMON_WORDS: &[&str] = &[
+    "admin", "autumn", "banana", "baseball", "batman", "buster", "charlie", "cheese", "computer",
+    "cookie", "dragon", "flower", "football", "freedom", "giglog", "ginger", "golden", "hello",
+    "hockey", "hunter", "iloveyou", "internet", "jordan", "killer", "letmein", "login", "love",
+    "master", "matrix", "monkey", "mustang", "orange", "pass", "password", "pepper", "pokemon",
+    "princess", "qwerty", "ranger", "secret", "shadow", "silver", "soccer", "spring", "starwars",
+    "summer", "sunshine", "superman", "thunder", "tigger", "trustno", "welcome", "whatever",
+    "winter",
+];
+
+/// Suggestion given for every weak password.
+const ADD_WORDS_SUGGESTION: &str = "Add another word or two. Uncommon words are better.";
+
+/// Keyboard rows whose runs are easy to guess.
+const KEYBOARD_ROWS: &[&str] = &["1234567890", "qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+/// Estimated guesses, as a power of ten, to find a common word.
+const WORD_GUESSES_LOG10: f64 = 2.0;
+
+/// Estimated guesses, as a power of ten, to find the user's own details.
+const USER_INPUT_GUESSES_LOG10: f64 = 1.0;
+
+/// Estimated guesses, as a power of ten, to find a year.
+const YEAR_GUESSES_LOG10: f64 = 2.0;
+
+/// Upper bounds on estimated guesses, as powers of ten, for scores 0–3.
+const SCORE_THRESHOLDS_LOG10: [f64; 4] = [3.0, 6.0, 8.0, 10.0];
+
+/// A guessable pattern found in a password.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Pattern {
+    /// Part of the user's name or email address.
+    UserInput,
+    /// A common password or word.
+    Word,
+    /// A keyboard run or alphabetical or numeric sequence.
+    Sequence,
+    /// A character repeated several times.
+    Repeat,
+    /// A year from 1900 to 2099.
+    Year,
+}
+
+/// The estimated strength of a password, with feedback for improving it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PasswordStrength {
+    /// Score from 0 (trivially guessable) to 4 (very unguessable).
+    pub score: u8,
+    /// Estimated number of guesses needed, as a power of ten.
+    pub guesses_log10: f64,
+    /// Explains what makes the password weak, if anything does.
+    pub warning: Option<String>,
+    /// Suggestions for choosing a stronger password.
+    pub suggestions: Vec<String>,
+}
+
+impl PasswordStrength {
+    /// Estimates the strength of a password.
+    ///
+    /// # Arguments
+    ///
+    /// * `password` — The password to score.
+    /// * `user_inputs` — Details about the user, such as their name and
+    ///   email address, that count against passwords containing them.
+    ///
+    /// # Returns
+    ///
+    /// The estimated [`PasswordStrength`].
+    pub fn estimate(password: &str, user_inputs: &[&str]) -> Self {
+        let lower: Vec<char> = password.chars().map(|c| c.to_ascii_lowercase()).collect();
+        let lower_string: String = lower.iter().collect();
+
+        if COMMON_PASSWORDS.contains(&lower_string.as_str()) {
+            return Self::from_guesses(
+                0.0,
+                Some("This is a commonly used password.".to_string()),
+                vec![ADD_WORDS_SUGGESTION.to_string()],
+            );
+        }
+
+        let unleet: Vec<char> = lower.iter().map(|&c| Self::unleet(c)).collect();
+        let mut covered = vec![false; lower.len()];
+        let mut patterns = Vec::new();
+        let mut guesses_log10 = 0.0;
+
+        let user_tokens = user_inputs
+            .iter()
+            .flat_map(|input| input.split(|c: char| !c.is_ascii_alphanumeric()))
+            .map(str::to_ascii_lowercase)
+            .filter(|token| token.len() >= 3);
+
+        for token in user_tokens {
+            let token: Vec<char> = token.chars().collect();
+
+            for text in [&lower, &unleet] {
+                if Self::cover(text, &token, &mut covered) {
+                    patterns.push(Pattern::UserInput);
+                    guesses_log10 += USER_INPUT_GUESSES_LOG10;
+                }
+            }
+        }
+
+        for word in COMMON_WORDS {
+            let word: Vec<char> = word.chars().collect();
+
+            for text in [&lower, &unleet] {
+                if Self::cover(text, &word, &mut covered) {
+                    patterns.push(Pattern::Word);
+                    guesses_log10 += WORD_GUESSES_LOG10;
+                }
+            }
+        }
+
+        guesses_log10 += Self::cover_years(&lower, &mut covered, &mut patterns);
+        guesses_log10 += Self::cover_runs(&lower, &mut covered, &mut patterns);
+
+        let charset_log10 = (Self::charset_size(password) as f64).log10();
+        let uncovered = covered.iter().filter(|covered| !**covered).count();
+        guesses_log10 += uncovered as f64 * charset_log10;
+
+        let stripped = lower_string.trim_end_matches(|c: char| !c.is_ascii_alphabetic());
+        let stripped_unleet: String = stripped.chars().map(Self::unleet).collect();
+        let similar_to_common = stripped.len() < lower_string.len()
+            && (COMMON_PASSWORDS.contains(&stripped)
+                || COMMON_PASSWORDS.contains(&stripped_unleet.as_str()));
+
+        if similar_to_common {
+            guesses_log10 = guesses_log10.min(SCORE_THRESHOLDS_LOG10[1] - 0.01);
+        }
+
+        patterns.sort();
+        patterns.dedup();
+
+        let warning = if similar_to_common {
+            Some("This is similar to a commonly used password.")
+        } else {
+            patterns.first().map(|pattern| match pattern {
+                Pattern::UserInput => "Passwords containing your name or email are easy to guess.",
+                Pattern::Word => "Common words are easy to guess.",
+                Pattern::Sequence => "Sequences like \"abc\" or \"qwerty\" are easy to guess.",
+                Pattern::Repeat => "Repeated characters like \"aaa\" are easy to guess.",
+                Pattern::Year => "Recent years are easy to guess.",
+            })
+        };
+
+        let mut suggestions = vec![ADD_WORDS_SUGGESTION.to_string()];
+
+        for pattern in &patterns {
+            suggestions.push(
+                match pattern {
+                    Pattern::UserInput => "Avoid using your name or email address.",
+                    Pattern::Word => "Avoid common words, even with symbols swapped in.",
+                    Pattern::Sequence => "Avoid keyboard patterns and sequences.",
+                    Pattern::Repeat => "Avoid repeated characters.",
+                    Pattern::Year => "Avoid years that are associated with you.",
+                }
+                .to_string(),
+            );
+        }
+
+        Self::from_guesses(guesses_log10, warning.map(str::to_string), suggestions)
+    }
+
+    /// Builds a [`PasswordStrength`] from an estimated number of guesses.
+    ///
+    /// Strong passwords get no feedback.
+    ///
+    /// # Arguments
+    ///
+    /// * `guesses_log10` — Estimated guesses, as a power of ten.
+    /// * `warning` — What makes the password weak, if anything.
+    /// * `suggestions` — Suggestions for a stronger password.
+    ///
+    /// # Returns
+    ///
+    /// The scored [`PasswordStrength`].
+    fn from_guesses(guesses_log10: f64, warning: Option<String>, suggestions: Vec<String>) -> Self {
+        let score = SCORE_THRESHOLDS_LOG10
+            .iter()
+            .take_while(|threshold| guesses_log10 >= **threshold)
+            .count() as u8;
+
+        if score >= 3 {
+            return Self {
+                score,
+                guesses_log10,
+                warning: None,
+                suggestions: Vec::new(),
+            };
+        }
+
+        Self {
+            score,
+            guesses_log10,
+            warning,
+            suggestions,
+        }
+    }
+
+    /// Undoes a common letter-to-symbol substitution.
+    ///
+    /// # Arguments
+    ///
+    /// * `c` — A lowercase character.
+    ///
+    /// # Returns
+    ///
+    /// The letter `c` usually stands in for, or `c` itself.
+    fn unleet(c: char) -> char {
+        match c {
+            '4' | '@' => 'a',
+            '3' => 'e',
+            '1' | '!' => 'i',
+            '0' => 'o',
+            '5' | '$' => 's',
+            '7' => 't',
+            c => c,
+        }
+    }
+
+    /// Marks every uncovered occurrence of a pattern as covered.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` — The lowercase password.
+    /// * `needle` — The pattern to look for.
+    /// * `covered` — Which characters already belong to a pattern.
+    ///
+    /// # Returns
+    ///
+    /// `true` if any occurrence was newly covered.
+    fn cover(text: &[char], needle: &[char], covered: &mut [bool]) -> bool {
+        if needle.is_empty() || needle.len() > text.len() {
+            return false;
+        }
+
+        let mut found = false;
+
+        for start in 0..=text.len() - needle.len() {
+            let end = start + needle.len();
+
+            if text[start..end] == *needle && !covered[start..end].iter().any(|c| *c) {
+                covered[start..end].fill(true);
+                found = true;
+            }
+        }
+
+        found
+    }
+
+    /// Covers four-digit years from 1900 to 2099.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` — The lowercase password.
+    /// * `covered` — Which characters already belong to a pattern.
+    /// * `patterns` — Receives [`Pattern::Year`] for each year found.
+    ///
+    /// # Returns
+    ///
+    /// The estimated guesses for the years found, as a power of ten.
+    fn cover_years(text: &[char], covered: &mut [bool], patterns: &mut Vec<Pattern>) -> f64 {
+        let mut guesses_log10 = 0.0;
+        let mut start = 0;
+
+        while start + 4 <= text.len() {
+            let end = start + 4;
+            let window = &text[start..end];
+            let is_year = window.iter().all(char::is_ascii_digit)
+                && matches!(&window[..2], ['1', '9'] | ['2', '0'])
+                && !covered[start..end].iter().any(|c| *c);
+
+            if is_year {
+                covered[start..end].fill(true);
+                patterns.push(Pattern::Year);
+                guesses_log10 += YEAR_GUESSES_LOG10;
+                start = end;
+            } else {
+                start += 1;
+            }
+        }
+
+        guesses_log10
+    }
+
+    /// Covers runs of three or more repeated, sequential, or keyboard
+    /// adjacent characters.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` — The lowercase password.
+    /// * `covered` — Which characters already belong to a pattern.
+    /// * `patterns` — Receives [`Pattern::Repeat`] or [`Pattern::Sequence`]
+    ///   for each run found.
+    ///
+    /// # Returns
+    ///
+    /// The estimated guesses for the runs found, as a power of ten.
+    fn cover_runs(text: &[char], covered: &mut [bool], patterns: &mut Vec<Pattern>) -> f64 {
+        let mut guesses_log10 = 0.0;
+        let mut start = 0;
+
+        while start < text.len() {
+            let step = |index: usize| Self::step(text[index - 1], text[index]);
+            let first_step = (start + 1 < text.len()).then(|| step(start + 1)).flatten();
+            let mut end = start + 1;
+
+            if let Some(first_step) = first_step {
+                while end < text.len() && step(end) == Some(first_step) {
+                    end += 1;
+                }
+            }
+
+            if end - start >= 3 && !covered[start..end].iter().any(|c| *c) {
+                covered[start..end].fill(true);
+                patterns.push(if first_step == Some(0) {
+                    Pattern::Repeat
+                } else {
+                    Pattern::Sequence
+                });
+                guesses_log10 += 1.0 + ((end - start) as f64).log10();
+                start = end;
+            } else {
+                start += 1;
+            }
+        }
+
+        guesses_log10
+    }
+
+    /// Returns how one character follows another in a run.
+    ///
+    /// # Arguments
+    ///
+    /// * `previous` — The earlier character.
+    /// * `next` — The later character.
+    ///
+    /// # Returns
+    ///
+    /// `Some(0)` for a repeat, `Some(±1)` for alphabetical or numeric
+    /// order, `Some(±2)` for keyboard order, or `None` if the characters do
+    /// not form a run.
+    fn step(previous: char, next: char) -> Option<i32> {
+        if previous == next {
+            return Some(0);
+        }
+
+        if previous.is_ascii_alphanumeric() && next.is_ascii_alphanumeric() {
+            match next as i32 - previous as i32 {
+                1 => return Some(1),
+                -1 => return Some(-1),
+                _ => {}
+            }
+        }
+
+        KEYBOARD_ROWS.iter().find_map(|row| {
+            let previous = row.find(previous)?;
+            let next = row.find(next)?;
+
+            match next as i32 - previous as i32 {
+                1 => Some(2),
+                -1 => Some(-2),
+                _ => None,
+            }
+        })
+    }
+
+    /// Counts the characters an attacker would try per position.
+    ///
+    /// # Arguments
+    ///
+    /// * `password` — The password.
+    ///
+    /// # Returns
+    ///
+    /// The combined size of every character class in the password.
+    fn charset_size(password: &str) -> u32 {
+        let has = |matches: fn(&char) -> bool| password.chars().any(|c| matches(&c));
+        let sizes = [
+            (has(char::is_ascii_lowercase), 26),
+            (has(char::is_ascii_uppercase), 26),
+            (has(char::is_ascii_digit), 10),
+            (has(char::is_ascii_punctuation), 33),
+            (!password.is_ascii(), 100),
+        ];
+
+        sizes
+            .iter()
+            .filter(|(present, _)| *present)
+            .map(|(_, size)| size)
+            .sum::<u32>()
+            .max(10)
+    }
+}
+
+/// Requirements a new password must meet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PasswordPolicy {
+    /// Minimum number of characters.
+    pub min_length: usize,
+    /// Minimum [`PasswordStrength::score`], from 0 to 4.
+    pub min_score: u8,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            min_length: 8,
+            min_score: 3,
+        }
+    }
+}
+
+impl PasswordPolicy {
+    /// Checks a password against the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` — The request field the password was sent in.
+    /// * `password` — The password to check.
+    /// * `user_inputs` — Details about the user, such as their name and
+    ///   email address, that count against passwords containing them.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the password is long and strong enough.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationErrorCode::Length`] error if the password is
+    /// too short, or [`ValidationErrorCode::WeakPassword`] errors carrying
+    /// the warning and suggestions if it is too easy to guess.
+    pub fn validate(
+        &self,
+        field: &str,
+        password: &str,
+        user_inputs: &[&str],
+    ) -> Result<(), Vec<ValidationError>> {
+        if password.chars().count() < self.min_length {
+            return Err(vec![ValidationError::new(
+                Some(field.to_string()),
+                ValidationErrorCode::Length,
+                format!("Password must have at least {} characters", self.min_length),
+            )]);
+        }
+
+        let strength = PasswordStrength::estimate(password, user_inputs);
+
+        if strength.score >= self.min_score {
+            return Ok(());
+        }
+
+        let warning = strength
+            .warning
+            .unwrap_or_else(|| "This password is too easy to guess.".to_string());
+        let suggestions = if strength.suggestions.is_empty() {
+            vec![ADD_WORDS_SUGGESTION.to_string()]
+        } else {
+            strength.suggestions
+        };
+
+        Err(std::iter::once(warning)
+            .chain(suggestions)
+            .map(|message| {
+                ValidationError::new(
+                    Some(field.to_string()),
+                    ValidationErrorCode::WeakPassword,
+                    message,
+                )
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn common_passwords_score_zero() {
+        let strength = PasswordStrength::estimate("Password", &[]);
+
+        assert_eq!(strength.score, 0);
+        assert_eq!(
+            strength.warning.as_deref(),
+            Some("This is a commonly used password.")
+        );
+        assert_eq!(PasswordStrength::estimate("P@ssw0rd2024!", &[]).score, 1);
+    }
+
+    #[test]
+    fn patterns_lower_the_score() {
+        assert!(PasswordStrength::estimate("abcdefgh1234", &[]).score < 3);
+        assert!(PasswordStrength::estimate("qwertyzzzzzz", &[]).score < 3);
+        assert!(
+            PasswordStrength::estimate("janedoe1990", &["Jane", "Doe", "jane.doe@example.com"])
+                .score
+                < 3
+        );
+    }
+
+    #[test]
+    fn long_unpredictable_passwords_score_high() {
+        let strength = PasswordStrength::estimate("correct horse battery staple", &[]);
+
+        assert_eq!(strength.score, 4);
+        assert!(strength.warning.is_none());
+        assert!(strength.suggestions.is_empty());
+    }
+
+    #[test]
+    fn policy_reports_feedback_for_the_field() {
+        let policy = PasswordPolicy::default();
+        let errors = policy
+            .validate("new_password", "summer2024", &[])
+            .unwrap_err();
+
+        assert!(errors.iter().all(|error| {
+            error.field.as_deref() == Some("new_password")
+                && error.code == ValidationErrorCode::WeakPassword
+        }));
+        assert_eq!(
+            policy.validate("password", "short", &[]).unwrap_err()[0].code,
+            ValidationErrorCode::Length
+        );
+        assert!(
+            policy
+                .validate("password", "vivid-otter-lantern", &[])
+                .is_ok()
+        );
+    }
+}
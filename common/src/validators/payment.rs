@@ -0,0 +1,34 @@
+//! Validators for payment request payloads.
+
+#[cfg(feature = "validation")]
+use crate::models::payment::ListPaymentsQuery;
+
+/// Validates that `date_from` is not after `date_to` on a
+/// [`ListPaymentsQuery`].
+///
+/// # Arguments
+///
+/// * `query` — The payment list query to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if the range is valid or either bound is open.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"invalid_payment_date_range"` if `date_from` is after `date_to`.
+#[cfg(feature = "validation")]
+pub fn validate_payment_date_range(
+    query: &ListPaymentsQuery,
+) -> Result<(), validator::ValidationError> {
+    if let (Some(date_from), Some(date_to)) = (query.date_from, query.date_to)
+        && date_from > date_to
+    {
+        let mut error = validator::ValidationError::new("invalid_payment_date_range");
+        error.message = Some("Date from must not be after date to".into());
+        return Err(error);
+    }
+
+    Ok(())
+}
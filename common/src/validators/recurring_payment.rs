@@ -0,0 +1,59 @@
+//! Validators for recurring payment request payloads.
+
+#[cfg(feature = "validation")]
+use chrono::NaiveDate;
+
+#[cfg(feature = "validation")]
+use crate::models::recurring_payment::CreateRecurringPaymentRequest;
+
+/// Validates that a recurring payment does not end before it starts.
+///
+/// # Arguments
+///
+/// * `start_date` — Date of the first payment.
+/// * `end_date` — Date after which no more payments are made, if any.
+///
+/// # Returns
+///
+/// `Ok(())` if the schedule is open-ended or ends on or after its start.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"invalid_recurring_payment_dates"` if the end date is before the start
+/// date.
+#[cfg(feature = "validation")]
+pub fn validate_recurring_payment_dates(
+    start_date: NaiveDate,
+    end_date: Option<NaiveDate>,
+) -> Result<(), validator::ValidationError> {
+    if end_date.is_some_and(|end_date| end_date < start_date) {
+        let mut error = validator::ValidationError::new("invalid_recurring_payment_dates");
+        error.message = Some("Recurring payment end date must not be before start date".into());
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+/// Validates the schedule on a [`CreateRecurringPaymentRequest`].
+///
+/// # Arguments
+///
+/// * `req` — The recurring payment request to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if the dates are valid.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) from
+/// [`validate_recurring_payment_dates`] if the end date is before the start
+/// date.
+#[cfg(feature = "validation")]
+pub fn validate_create_recurring_payment(
+    req: &CreateRecurringPaymentRequest,
+) -> Result<(), validator::ValidationError> {
+    validate_recurring_payment_dates(req.start_date, req.end_date)
+}
@@ -0,0 +1,61 @@
+//! Validators for report query parameters.
+
+#[cfg(feature = "validation")]
+use crate::models::report::EarningsReportQuery;
+
+/// Validates that `from` is not after `to` on an [`EarningsReportQuery`].
+///
+/// # Arguments
+///
+/// * `query` — The earnings report query to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if the range is valid or either bound is omitted.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"invalid_earnings_range"` if `from` is after `to`.
+#[cfg(feature = "validation")]
+pub fn validate_earnings_range(
+    query: &EarningsReportQuery,
+) -> Result<(), validator::ValidationError> {
+    if let (Some(from), Some(to)) = (query.from, query.to)
+        && from > to
+    {
+        let mut error = validator::ValidationError::new("invalid_earnings_range");
+        error.message = Some("From must not be after to".into());
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "validation"))]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn range(from: Option<&str>, to: Option<&str>) -> EarningsReportQuery {
+        let date = |value: &str| value.parse::<NaiveDate>().unwrap();
+
+        EarningsReportQuery {
+            from: from.map(date),
+            to: to.map(date),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn earnings_ranges_must_not_end_before_they_start() {
+        assert!(validate_earnings_range(&range(None, None)).is_ok());
+        assert!(validate_earnings_range(&range(Some("2026-03-01"), None)).is_ok());
+        assert!(validate_earnings_range(&range(Some("2026-03-01"), Some("2026-03-01"))).is_ok());
+
+        let error =
+            validate_earnings_range(&range(Some("2026-03-02"), Some("2026-03-01"))).unwrap_err();
+        assert_eq!(error.code, "invalid_earnings_range");
+    }
+}
@@ -0,0 +1,32 @@
+//! Validators for company statement request payloads.
+
+#[cfg(feature = "validation")]
+use crate::models::statement::SendStatementRequest;
+
+/// Validates that `period_end` is not before `period_start` on a
+/// [`SendStatementRequest`].
+///
+/// # Arguments
+///
+/// * `req` — The statement request to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if the period is valid.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"invalid_statement_period"` if the period ends before it starts.
+#[cfg(feature = "validation")]
+pub fn validate_statement_period(
+    req: &SendStatementRequest,
+) -> Result<(), validator::ValidationError> {
+    if req.period_end < req.period_start {
+        let mut error = validator::ValidationError::new("invalid_statement_period");
+        error.message = Some("Statement end date must not be before start date".into());
+        return Err(error);
+    }
+
+    Ok(())
+}
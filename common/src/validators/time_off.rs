@@ -0,0 +1,88 @@
+//! Validators for time-off request payloads.
+
+#[cfg(feature = "validation")]
+use crate::models::time_off::{CreateTimeOffRequest, TimeOffRangeQuery};
+
+/// Validates that `end_date` is not before `start_date` on a
+/// [`CreateTimeOffRequest`].
+///
+/// # Arguments
+///
+/// * `req` — The time-off request to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if the dates are valid.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"invalid_time_off_dates"` if the time off ends before it starts.
+#[cfg(feature = "validation")]
+pub fn validate_time_off_dates(
+    req: &CreateTimeOffRequest,
+) -> Result<(), validator::ValidationError> {
+    if req.end_date < req.start_date {
+        let mut error = validator::ValidationError::new("invalid_time_off_dates");
+        error.message = Some("Time off end date must not be before start date".into());
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+/// Validates that `from` is not after `to` on a [`TimeOffRangeQuery`].
+///
+/// # Arguments
+///
+/// * `query` — The time-off list query to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if the range is valid or either bound is open.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"invalid_time_off_range"` if `from` is after `to`.
+#[cfg(feature = "validation")]
+pub fn validate_time_off_range(
+    query: &TimeOffRangeQuery,
+) -> Result<(), validator::ValidationError> {
+    if let (Some(from), Some(to)) = (query.from, query.to)
+        && from > to
+    {
+        let mut error = validator::ValidationError::new("invalid_time_off_range");
+        error.message = Some("From must not be after to".into());
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "validation"))]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn range(from: Option<&str>, to: Option<&str>) -> TimeOffRangeQuery {
+        let date = |value: &str| value.parse::<NaiveDate>().unwrap();
+
+        TimeOffRangeQuery {
+            from: from.map(date),
+            to: to.map(date),
+        }
+    }
+
+    #[test]
+    fn time_off_ranges_must_not_end_before_they_start() {
+        assert!(validate_time_off_range(&range(None, None)).is_ok());
+        assert!(validate_time_off_range(&range(Some("2026-03-01"), None)).is_ok());
+        assert!(validate_time_off_range(&range(Some("2026-03-01"), Some("2026-03-01"))).is_ok());
+
+        let error =
+            validate_time_off_range(&range(Some("2026-03-02"), Some("2026-03-01"))).unwrap_err();
+        assert_eq!(error.code, "invalid_time_off_range");
+    }
+}
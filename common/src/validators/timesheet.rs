@@ -0,0 +1,32 @@
+//! Validators for timesheet request payloads.
+
+#[cfg(feature = "validation")]
+use crate::models::timesheet::CreateTimesheetApprovalRequest;
+
+/// Validates that `period_end` is not before `period_start` on a
+/// [`CreateTimesheetApprovalRequest`].
+///
+/// # Arguments
+///
+/// * `req` — The timesheet approval request to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if the period is valid.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"invalid_period"` if the period ends before it starts.
+#[cfg(feature = "validation")]
+pub fn validate_timesheet_period(
+    req: &CreateTimesheetApprovalRequest,
+) -> Result<(), validator::ValidationError> {
+    if req.period_end < req.period_start {
+        let mut error = validator::ValidationError::new("invalid_period");
+        error.message = Some("Period end must not be before period start".into());
+        return Err(error);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+//! Validators for Toggl-compatible request payloads.
+
+#[cfg(feature = "validation")]
+use crate::models::toggl::CreateTogglTimeEntryRequest;
+
+/// Validates a [`CreateTogglTimeEntryRequest`].
+///
+/// # Arguments
+///
+/// * `req` — The time entry request to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if the entry names a project and its times are consistent.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"missing_project"` if `project_id` is not set, or `"invalid_stop"` if
+/// `stop` is before `start`.
+#[cfg(feature = "validation")]
+pub fn validate_toggl_time_entry(
+    req: &CreateTogglTimeEntryRequest,
+) -> Result<(), validator::ValidationError> {
+    if req.project_id.is_none() {
+        let mut error = validator::ValidationError::new("missing_project");
+        error.message = Some("Time entries must have a project".into());
+        return Err(error);
+    }
+
+    if req.stop.is_some_and(|stop| stop < req.start) {
+        let mut error = validator::ValidationError::new("invalid_stop");
+        error.message = Some("Stop must not be before start".into());
+        return Err(error);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,216 @@
+//! Validators for webhook request payloads.
+//!
+//! Webhook URLs must point to a public host, so a webhook cannot be used to
+//! make the server POST to itself, its private network, or a cloud
+//! metadata service. [`is_public_ip`] and [`is_reserved_host_name`] are
+//! also used when deliveries are sent, since a host name can be rebound to
+//! another address after it is registered.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// Host names that always refer to the local machine or a cloud metadata
+/// service.
+const RESERVED_HOST_NAMES: [&str; 3] = ["localhost", "metadata", "metadata.google.internal"];
+
+/// Checks whether a host name is reserved for the local machine or a cloud
+/// metadata service.
+///
+/// # Arguments
+///
+/// * `host` — The host name, without a port.
+///
+/// # Returns
+///
+/// `true` if the host is `localhost`, a `.localhost` subdomain, or a known
+/// metadata service name.
+pub fn is_reserved_host_name(host: &str) -> bool {
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+
+    RESERVED_HOST_NAMES.contains(&host.as_str()) || host.ends_with(".localhost")
+}
+
+/// Checks whether webhooks may be delivered to an IP address.
+///
+/// Loopback, private, shared, link-local, unspecified, multicast, and
+/// reserved addresses are rejected. That covers the cloud metadata
+/// services at `169.254.169.254`, `100.100.100.200`, and `fd00:ec2::254`.
+/// IPv6 addresses embedding an IPv4 address are judged by that address.
+///
+/// # Arguments
+///
+/// * `ip` — The address to check.
+///
+/// # Returns
+///
+/// `true` if the address is publicly routable.
+pub fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4(ip),
+        IpAddr::V6(ip) => is_public_ipv6(ip),
+    }
+}
+
+/// Checks whether an IPv4 address is publicly routable.
+///
+/// # Arguments
+///
+/// * `ip` — The address to check.
+///
+/// # Returns
+///
+/// `true` if the address is publicly routable.
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let [first, second, third, _] = ip.octets();
+
+    !(first == 0
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        // Shared address space used by carrier-grade NAT.
+        || (first == 100 && (64..128).contains(&second))
+        // IETF protocol assignments.
+        || (first == 192 && second == 0 && third == 0)
+        // Benchmarking.
+        || (first == 198 && (18..20).contains(&second))
+        // Reserved for future use.
+        || first >= 240)
+}
+
+/// Checks whether an IPv6 address is publicly routable.
+///
+/// # Arguments
+///
+/// * `ip` — The address to check.
+///
+/// # Returns
+///
+/// `true` if the address is publicly routable.
+fn is_public_ipv6(ip: Ipv6Addr) -> bool {
+    let segments = ip.segments();
+
+    // IPv4-mapped, IPv4-compatible, and NAT64 addresses reach the embedded
+    // IPv4 address.
+    if let Some(ipv4) = ip.to_ipv4() {
+        return is_public_ipv4(ipv4);
+    }
+    if segments[..6] == [0x64, 0xff9b, 0, 0, 0, 0] {
+        let [.., a, b, c, d] = ip.octets();
+        return is_public_ipv4(Ipv4Addr::new(a, b, c, d));
+    }
+
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        || ip.is_unique_local()
+        || ip.is_unicast_link_local()
+        // Deprecated site-local addresses.
+        || segments[0] & 0xffc0 == 0xfec0
+        // Documentation.
+        || segments[..2] == [0x2001, 0x0db8])
+}
+
+/// Validates that a webhook URL uses `http` or `https` and points to a
+/// public host.
+///
+/// Host names are only checked against [`is_reserved_host_name`] here;
+/// the addresses they resolve to are checked when deliveries are sent.
+///
+/// # Arguments
+///
+/// * `url` — The URL events are POSTed to.
+///
+/// # Returns
+///
+/// `Ok(())` if the URL is an `http` or `https` URL whose host is a public
+/// IP address or an unreserved host name.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"url"` if the URL uses any other scheme or points to a loopback,
+/// private, link-local, unspecified, or metadata host.
+#[cfg(feature = "validation")]
+pub fn validate_webhook_url(url: &str) -> Result<(), validator::ValidationError> {
+    let invalid = |message: &'static str| {
+        let mut error = validator::ValidationError::new("url");
+        error.message = Some(message.into());
+        error
+    };
+
+    let url = url::Url::parse(url).map_err(|_| invalid("URL must be a valid URL"))?;
+
+    if url.scheme() != "https" && url.scheme() != "http" {
+        return Err(invalid("URL must use http or https"));
+    }
+
+    let is_public = match url.host() {
+        Some(url::Host::Domain(host)) => !is_reserved_host_name(host),
+        Some(url::Host::Ipv4(ip)) => is_public_ip(IpAddr::V4(ip)),
+        Some(url::Host::Ipv6(ip)) => is_public_ip(IpAddr::V6(ip)),
+        None => false,
+    };
+
+    if !is_public {
+        return Err(invalid("URL must point to a public host"));
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "validation"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_public_http_urls() {
+        assert!(validate_webhook_url("https://hooks.example.com/giglog").is_ok());
+        assert!(validate_webhook_url("http://93.184.216.34:8080/hook").is_ok());
+        assert!(validate_webhook_url("https://[2606:4700::1111]/hook").is_ok());
+    }
+
+    #[test]
+    fn rejects_other_schemes() {
+        let error = validate_webhook_url("ftp://hooks.example.com").unwrap_err();
+
+        assert_eq!(error.code, "url");
+        assert!(validate_webhook_url("file:///etc/passwd").is_err());
+    }
+
+    #[test]
+    fn rejects_internal_hosts() {
+        for url in [
+            "http://127.0.0.1/hook",
+            "http://2130706433/hook",
+            "http://0x7f.1/hook",
+            "http://0.0.0.0/hook",
+            "http://10.0.0.5/hook",
+            "http://172.16.3.4/hook",
+            "http://192.168.1.10/hook",
+            "http://169.254.169.254/latest/meta-data/",
+            "http://100.100.100.200/latest/meta-data/",
+            "http://[::1]/hook",
+            "http://[::]/hook",
+            "http://[::ffff:127.0.0.1]/hook",
+            "http://[fe80::1]/hook",
+            "http://[fd00:ec2::254]/hook",
+            "http://localhost:8000/hook",
+            "http://api.localhost/hook",
+            "http://metadata.google.internal/computeMetadata/v1/",
+            "HTTP://LOCALHOST./hook",
+        ] {
+            let error = validate_webhook_url(url).unwrap_err();
+
+            assert_eq!(error.code, "url", "{url}");
+        }
+    }
+
+    #[test]
+    fn judges_embedded_ipv4_addresses() {
+        assert!(!is_public_ip("64:ff9b::a9fe:a9fe".parse().unwrap()));
+        assert!(is_public_ip("64:ff9b::5db8:d822".parse().unwrap()));
+        assert!(!is_public_ip("::ffff:10.0.0.1".parse().unwrap()));
+    }
+}
@@ -0,0 +1,180 @@
+//! Validators for work session request payloads.
+
+#[cfg(feature = "validation")]
+use chrono::{DateTime, Utc};
+
+#[cfg(feature = "validation")]
+use crate::models::work_session::{
+    ListWorkSessionsQuery, StartWorkSessionRequest, UpdateWorkSessionRequest,
+};
+
+/// Validates the times of a logged session on a
+/// [`StartWorkSessionRequest`].
+///
+/// Requests without times start a timer and only need to leave the paused
+/// duration unset.
+///
+/// # Arguments
+///
+/// * `req` — The start request to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if the request starts a timer or logs a valid session.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"incomplete_session_times"` if only one of `start_time` and `end_time`
+/// is set or a paused duration is given without them, or the errors
+/// described in [`validate_work_session_times`].
+#[cfg(feature = "validation")]
+pub fn validate_start_work_session(
+    req: &StartWorkSessionRequest,
+) -> Result<(), validator::ValidationError> {
+    match (req.start_time, req.end_time) {
+        (Some(start_time), Some(end_time)) => check_session_times(
+            start_time,
+            end_time,
+            req.accumulated_paused_duration.unwrap_or_default(),
+        ),
+        (None, None) if req.accumulated_paused_duration.is_none() => Ok(()),
+        _ => {
+            let mut error = validator::ValidationError::new("incomplete_session_times");
+            error.message = Some("Logged sessions need both a start time and an end time".into());
+            Err(error)
+        }
+    }
+}
+
+/// Validates the times on an [`UpdateWorkSessionRequest`].
+///
+/// # Arguments
+///
+/// * `req` — The update request to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if the times are valid.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"invalid_session_times"` if the session does not end after it starts,
+/// or `"invalid_paused_duration"` if the paused duration is negative or not
+/// shorter than the session.
+#[cfg(feature = "validation")]
+pub fn validate_work_session_times(
+    req: &UpdateWorkSessionRequest,
+) -> Result<(), validator::ValidationError> {
+    check_session_times(
+        req.start_time,
+        req.end_time,
+        req.accumulated_paused_duration,
+    )
+}
+
+/// Validates that `from` is not after `to` on a [`ListWorkSessionsQuery`].
+///
+/// # Arguments
+///
+/// * `query` — The work session list query to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if the range is valid or either bound is open.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"invalid_work_session_date_range"` if `from` is after `to`.
+#[cfg(feature = "validation")]
+pub fn validate_work_session_date_range(
+    query: &ListWorkSessionsQuery,
+) -> Result<(), validator::ValidationError> {
+    if let (Some(from), Some(to)) = (query.from, query.to)
+        && from > to
+    {
+        let mut error = validator::ValidationError::new("invalid_work_session_date_range");
+        error.message = Some("From must not be after to".into());
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+/// Validates the tags on a work session request.
+///
+/// Tags are checked after trimming surrounding whitespace.
+///
+/// # Arguments
+///
+/// * `tags` — The tags to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if there are at most 20 tags of 1-50 characters each.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"too_many_tags"` if there are more than 20 tags, or `"invalid_tag"` if
+/// a tag is blank or longer than 50 characters.
+#[cfg(feature = "validation")]
+pub fn validate_work_session_tags(tags: &[String]) -> Result<(), validator::ValidationError> {
+    if tags.len() > 20 {
+        let mut error = validator::ValidationError::new("too_many_tags");
+        error.message = Some("A session can have at most 20 tags".into());
+        return Err(error);
+    }
+
+    if tags
+        .iter()
+        .any(|tag| tag.trim().is_empty() || tag.trim().chars().count() > 50)
+    {
+        let mut error = validator::ValidationError::new("invalid_tag");
+        error.message = Some("Tags must be 1-50 characters".into());
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+/// Checks that a session ends after it starts and was not paused for all
+/// of it.
+///
+/// # Arguments
+///
+/// * `start_time` — When the session started.
+/// * `end_time` — When the session ended.
+/// * `paused_seconds` — Time spent paused, in seconds.
+///
+/// # Returns
+///
+/// `Ok(())` if the times are valid.
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`](validator::ValidationError) with code
+/// `"invalid_session_times"` or `"invalid_paused_duration"`.
+#[cfg(feature = "validation")]
+fn check_session_times(
+    start_time: DateTime<Utc>,
+    end_time: DateTime<Utc>,
+    paused_seconds: i64,
+) -> Result<(), validator::ValidationError> {
+    if end_time <= start_time {
+        let mut error = validator::ValidationError::new("invalid_session_times");
+        error.message = Some("End time must be after start time".into());
+        return Err(error);
+    }
+
+    if paused_seconds < 0 || paused_seconds >= (end_time - start_time).num_seconds() {
+        let mut error = validator::ValidationError::new("invalid_paused_duration");
+        error.message =
+            Some("Paused duration must be at least 0 and shorter than the session".into());
+        return Err(error);
+    }
+
+    Ok(())
+}
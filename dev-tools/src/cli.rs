@@ -3,6 +3,8 @@
 //! This module defines the top-level CLI parser and the available subcommands
 //! used to run development workflows.
 
+use std::path::PathBuf;
+
 use clap::{Parser, Subcommand};
 
 /// Parses top-level command-line input for `gig-log-dev-tools`.
@@ -48,4 +50,42 @@ pub enum Command {
     ApiTester,
     /// Launches the database viewer TUI.
     DbViewer,
+    /// Checks the env file, database connection, and migration status.
+    Doctor {
+        /// Env file to check. Defaults to the workspace `.env`.
+        #[arg(long)]
+        env_file: Option<PathBuf>,
+    },
+    /// Runs a post-deploy smoke test against a deployed API.
+    Smoke {
+        /// Base URL of the API under test, e.g. `https://api.example.com`.
+        #[arg(long)]
+        base_url: String,
+        /// Mailbox that receives the sign-up confirmation code. Each run
+        /// signs up a plus-addressed variant of it.
+        #[arg(long)]
+        email: String,
+    },
+    /// Scaffolds new workspace code from templates.
+    Generate {
+        /// Kind of code to generate.
+        #[command(subcommand)]
+        target: GenerateTarget,
+    },
+}
+
+/// Enumerates the kinds of code `generate` can scaffold.
+#[derive(Subcommand)]
+pub enum GenerateTarget {
+    /// Scaffolds a route domain: model, repo, controller, router, and migration.
+    Route {
+        /// Singular snake_case name of the domain, e.g. `expense_category`.
+        name: String,
+        /// Plural snake_case name used for the table and URL path.
+        #[arg(long)]
+        plural: Option<String>,
+        /// Prints planned files without writing anything.
+        #[arg(long)]
+        dry_run: bool,
+    },
 }
@@ -0,0 +1,390 @@
+//! Environment variable schema of the GigLog API.
+//!
+//! Mirrors the variables read by `Config::new` in the API crate, with the
+//! value format each one is parsed as. Keep [`ENV_SCHEMA`] in step with the
+//! API configuration when variables are added or renamed.
+
+/// Format an environment variable's value is parsed as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VarKind {
+    /// Any non-empty text.
+    Text,
+    /// An `http` or `https` URL.
+    Url,
+    /// A Postgres connection URL.
+    PostgresUrl,
+    /// An email address.
+    Email,
+    /// `true`, `false`, `1`, `0`, `yes`, `no`, `on`, or `off`.
+    Bool,
+    /// A non-negative whole number.
+    Integer,
+    /// A decimal between `0` and `1`.
+    Rate,
+    /// A non-negative decimal amount.
+    Amount,
+    /// A comma-separated list of `http` or `https` origins.
+    OriginList,
+    /// A comma-separated list of CIDR ranges or IP addresses.
+    IpList,
+    /// A comma-separated list of arbitrary values.
+    List,
+    /// `strict`, `lax`, or `none`.
+    SameSite,
+}
+
+/// One environment variable read by the API.
+pub struct EnvVar {
+    /// Variable name.
+    pub name: &'static str,
+    /// Format the value is parsed as.
+    pub kind: VarKind,
+    /// Whether the API refuses to start without it.
+    pub required: bool,
+    /// Whether the value is a credential that must not be shared between
+    /// environments or left at its example value.
+    pub secret: bool,
+}
+
+impl EnvVar {
+    /// Marks the variable as a credential.
+    ///
+    /// # Returns
+    ///
+    /// The [`EnvVar`] with `secret` set.
+    const fn secret(self) -> Self {
+        Self {
+            secret: true,
+            ..self
+        }
+    }
+}
+
+/// Builds an entry for a variable the API refuses to start without.
+///
+/// # Arguments
+///
+/// * `name` — Variable name.
+/// * `kind` — Format the value is parsed as.
+///
+/// # Returns
+///
+/// The [`EnvVar`] entry.
+const fn required(name: &'static str, kind: VarKind) -> EnvVar {
+    EnvVar {
+        name,
+        kind,
+        required: true,
+        secret: false,
+    }
+}
+
+/// Builds an entry for a variable the API can start without.
+///
+/// # Arguments
+///
+/// * `name` — Variable name.
+/// * `kind` — Format the value is parsed as.
+///
+/// # Returns
+///
+/// The [`EnvVar`] entry.
+const fn optional(name: &'static str, kind: VarKind) -> EnvVar {
+    EnvVar {
+        name,
+        kind,
+        required: false,
+        secret: false,
+    }
+}
+
+/// Every environment variable read by the API.
+pub const ENV_SCHEMA: &[EnvVar] = &[
+    required("APP_ENV", VarKind::Text),
+    required("WEB_ORIGIN", VarKind::OriginList),
+    optional("API_PUBLIC_URL", VarKind::Url),
+    required("DATABASE_URL", VarKind::PostgresUrl).secret(),
+    optional("AUTO_APPLY_MIGRATIONS_ENABLED", VarKind::Bool),
+    required("JWT_SECRET", VarKind::Text).secret(),
+    optional("JWT_ISSUER", VarKind::Text),
+    optional("JWT_AUDIENCE", VarKind::Text),
+    optional("JWT_ACCESS_TOKEN_EXPIRY_SECONDS", VarKind::Integer),
+    optional("JWT_REFRESH_TOKEN_EXPIRY_SECONDS", VarKind::Integer),
+    required("RESEND_API_KEY", VarKind::Text).secret(),
+    required("RESEND_FROM_EMAIL", VarKind::Email),
+    optional("AUTH_CODE_EXPIRY_SECONDS", VarKind::Integer),
+    optional("LOG_LEVEL", VarKind::Text),
+    optional("LOG_VERBOSE", VarKind::Bool),
+    optional("LOG_HTTP_MAX_BODY_BYTES", VarKind::Integer),
+    optional("BUDGET_ALERT_INTERVAL_SECONDS", VarKind::Integer),
+    optional("CONTRACT_REMINDER_INTERVAL_SECONDS", VarKind::Integer),
+    optional("PAYMENT_REMINDER_INTERVAL_SECONDS", VarKind::Integer),
+    optional("PAYMENT_REMINDER_DAYS_BEFORE", VarKind::Integer),
+    optional("RECURRING_PAYMENT_INTERVAL_SECONDS", VarKind::Integer),
+    optional("INVOICE_DRAFT_INTERVAL_SECONDS", VarKind::Integer),
+    optional("ACCOUNT_EXPORT_INTERVAL_SECONDS", VarKind::Integer),
+    optional("WEBHOOK_DELIVERY_INTERVAL_SECONDS", VarKind::Integer),
+    optional("WORK_SESSION_MAX_DURATION_SECONDS", VarKind::Integer),
+    optional("ERROR_REPORTING_DSN", VarKind::Url).secret(),
+    optional("ERROR_REPORTING_SAMPLE_RATE", VarKind::Rate),
+    optional("INBOUND_EMAIL_SECRET", VarKind::Text).secret(),
+    optional("DEMO_ACCOUNTS_ENABLED", VarKind::Bool),
+    optional("DEMO_ACCOUNT_LIFETIME_SECONDS", VarKind::Integer),
+    optional("DEMO_CLEANUP_INTERVAL_SECONDS", VarKind::Integer),
+    optional("WEBAUTHN_RP_ID", VarKind::Text),
+    optional("WEBAUTHN_RP_NAME", VarKind::Text),
+    optional("PASSWORD_MIN_LENGTH", VarKind::Integer),
+    optional("PASSWORD_MIN_SCORE", VarKind::Integer),
+    optional("SECURITY_FAILED_LOGIN_THRESHOLD", VarKind::Integer),
+    optional("SECURITY_FAILED_LOGIN_WINDOW_SECONDS", VarKind::Integer),
+    optional("SECURITY_MASS_DELETION_THRESHOLD", VarKind::Integer),
+    optional("SECURITY_MASS_DELETION_WINDOW_SECONDS", VarKind::Integer),
+    optional("SECURITY_REAUTH_HOLD_SECONDS", VarKind::Integer),
+    optional("SECURITY_COUNTRY_HEADER", VarKind::Text),
+    optional("IP_ALLOWLIST", VarKind::IpList),
+    optional("IP_ALLOWLIST_PATHS", VarKind::List),
+    optional("IP_DENYLIST", VarKind::IpList),
+    optional("IP_FILTER_TRUSTED_PROXY_HOPS", VarKind::Integer),
+    optional("STRIPE_SECRET_KEY", VarKind::Text).secret(),
+    optional("STRIPE_WEBHOOK_SECRET", VarKind::Text).secret(),
+    optional("STRIPE_PRO_PRICE_ID", VarKind::Text),
+    optional("GOOGLE_OAUTH_CLIENT_ID", VarKind::Text),
+    optional("GOOGLE_OAUTH_CLIENT_SECRET", VarKind::Text).secret(),
+    optional("GITHUB_OAUTH_CLIENT_ID", VarKind::Text),
+    optional("GITHUB_OAUTH_CLIENT_SECRET", VarKind::Text).secret(),
+    optional("COOKIE_DOMAINS", VarKind::List),
+    optional("COOKIE_SECURE", VarKind::Bool),
+    optional("COOKIE_SAME_SITE", VarKind::SameSite),
+    optional("MILEAGE_RATE", VarKind::Amount),
+    optional("SELF_EMPLOYMENT_TAX_RATE", VarKind::Amount),
+];
+
+/// Looks up a variable in [`ENV_SCHEMA`].
+///
+/// # Arguments
+///
+/// * `name` — Variable name.
+///
+/// # Returns
+///
+/// The matching [`EnvVar`], or `None` if the API does not read `name`.
+pub fn find(name: &str) -> Option<&'static EnvVar> {
+    ENV_SCHEMA.iter().find(|var| var.name == name)
+}
+
+/// Returns `true` if `app_env` names a development environment, matching the
+/// API's `Config::is_development_env`.
+///
+/// # Arguments
+///
+/// * `app_env` — The `APP_ENV` value.
+///
+/// # Returns
+///
+/// `true` if `app_env` is `development` or `dev`, ignoring case.
+pub fn is_development_env(app_env: &str) -> bool {
+    let normalized = app_env.trim().to_lowercase();
+    normalized == "development" || normalized == "dev"
+}
+
+/// Checks a value against its variable's format.
+///
+/// # Arguments
+///
+/// * `kind` — Format the value is parsed as.
+/// * `value` — Non-empty raw value.
+///
+/// # Returns
+///
+/// `None` if the value is valid, or a description of the problem.
+pub fn validate(kind: VarKind, value: &str) -> Option<String> {
+    let value = value.trim();
+
+    match kind {
+        VarKind::Text | VarKind::List => None,
+        VarKind::Url => (!is_http_url(value)).then(|| "must be an http or https URL".to_string()),
+        VarKind::PostgresUrl => (!value.starts_with("postgres://")
+            && !value.starts_with("postgresql://"))
+        .then(|| "must be a postgres:// URL".to_string()),
+        VarKind::Email => match value.split_once('@') {
+            Some((local, domain)) if !local.is_empty() && domain.contains('.') => None,
+            _ => Some("must be an email address".to_string()),
+        },
+        VarKind::Bool => (!matches!(
+            value.to_ascii_lowercase().as_str(),
+            "true" | "1" | "yes" | "on" | "false" | "0" | "no" | "off"
+        ))
+        .then(|| "must be true or false; the API falls back to its default".to_string()),
+        VarKind::Integer => value
+            .parse::<u64>()
+            .is_err()
+            .then(|| "must be a whole number; the API falls back to its default".to_string()),
+        VarKind::Rate => match value.parse::<f64>() {
+            Ok(rate) if (0.0..=1.0).contains(&rate) => None,
+            _ => Some("must be a decimal between 0 and 1".to_string()),
+        },
+        VarKind::Amount => match value.parse::<f64>() {
+            Ok(amount) if amount.is_finite() && amount >= 0.0 => None,
+            _ => Some(
+                "must be a non-negative decimal; the API falls back to its default".to_string(),
+            ),
+        },
+        VarKind::OriginList => {
+            let invalid = list_items(value)
+                .filter(|origin| !is_http_url(origin))
+                .collect::<Vec<_>>();
+
+            if list_items(value).next().is_none() {
+                Some("must list at least one origin".to_string())
+            } else if !invalid.is_empty() {
+                Some(format!("contains invalid origins: {}", invalid.join(", ")))
+            } else {
+                None
+            }
+        }
+        VarKind::IpList => {
+            let invalid = list_items(value)
+                .filter(|entry| !is_ip_range(entry))
+                .collect::<Vec<_>>();
+
+            (!invalid.is_empty()).then(|| {
+                format!(
+                    "contains invalid IP ranges: {}; the API refuses to start",
+                    invalid.join(", ")
+                )
+            })
+        }
+        VarKind::SameSite => (!matches!(
+            value.to_ascii_lowercase().as_str(),
+            "strict" | "lax" | "none"
+        ))
+        .then(|| "must be strict, lax, or none; the API falls back to lax".to_string()),
+    }
+}
+
+/// Splits a comma-separated value into its non-empty, trimmed items.
+///
+/// # Arguments
+///
+/// * `value` — Comma-separated value.
+///
+/// # Returns
+///
+/// An iterator over the items.
+fn list_items(value: &str) -> impl Iterator<Item = &str> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+}
+
+/// Returns `true` if `value` is an `http` or `https` URL with a host.
+///
+/// # Arguments
+///
+/// * `value` — Value to check.
+///
+/// # Returns
+///
+/// `true` if `value` has an `http://` or `https://` scheme and a host.
+fn is_http_url(value: &str) -> bool {
+    value
+        .strip_prefix("https://")
+        .or_else(|| value.strip_prefix("http://"))
+        .is_some_and(|rest| !rest.is_empty() && !rest.starts_with('/'))
+}
+
+/// Returns `true` if `value` is a CIDR range or a single IP address.
+///
+/// # Arguments
+///
+/// * `value` — Value to check.
+///
+/// # Returns
+///
+/// `true` if the address parses and any prefix length fits it.
+fn is_ip_range(value: &str) -> bool {
+    let (address, prefix) = match value.split_once('/') {
+        Some((address, prefix)) => (address, Some(prefix)),
+        None => (value, None),
+    };
+
+    let Ok(address) = address.parse::<std::net::IpAddr>() else {
+        return false;
+    };
+    let max_prefix = if address.is_ipv4() { 32 } else { 128 };
+
+    prefix.is_none_or(|prefix| {
+        prefix
+            .parse::<u8>()
+            .is_ok_and(|prefix| prefix <= max_prefix)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schema_names_are_unique() {
+        for (index, var) in ENV_SCHEMA.iter().enumerate() {
+            assert!(
+                ENV_SCHEMA[index + 1..]
+                    .iter()
+                    .all(|other| other.name != var.name),
+                "{} is listed twice",
+                var.name
+            );
+        }
+    }
+
+    #[test]
+    fn validates_scalar_kinds() {
+        assert!(validate(VarKind::Bool, "Yes").is_none());
+        assert!(validate(VarKind::Bool, "maybe").is_some());
+        assert!(validate(VarKind::Integer, "900").is_none());
+        assert!(validate(VarKind::Integer, "-1").is_some());
+        assert!(validate(VarKind::Rate, "0.5").is_none());
+        assert!(validate(VarKind::Rate, "1.5").is_some());
+        assert!(validate(VarKind::Amount, "0.70").is_none());
+        assert!(validate(VarKind::Amount, "-2").is_some());
+        assert!(validate(VarKind::SameSite, "None").is_none());
+        assert!(validate(VarKind::SameSite, "loose").is_some());
+        assert!(validate(VarKind::Email, "noreply@example.com").is_none());
+        assert!(validate(VarKind::Email, "noreply").is_some());
+    }
+
+    #[test]
+    fn validates_urls() {
+        assert!(validate(VarKind::Url, "http://localhost:8000").is_none());
+        assert!(validate(VarKind::Url, "localhost:8000").is_some());
+        assert!(validate(VarKind::PostgresUrl, "postgres://postgres@localhost/db").is_none());
+        assert!(validate(VarKind::PostgresUrl, "mysql://localhost/db").is_some());
+    }
+
+    #[test]
+    fn validates_lists() {
+        assert!(
+            validate(
+                VarKind::OriginList,
+                "http://localhost:3000, https://giglog.dev"
+            )
+            .is_none()
+        );
+        assert_eq!(
+            validate(VarKind::OriginList, "http://localhost:3000,giglog.dev").as_deref(),
+            Some("contains invalid origins: giglog.dev")
+        );
+        assert!(validate(VarKind::OriginList, " , ").is_some());
+        assert!(validate(VarKind::IpList, "10.0.0.0/8, 192.168.1.4, ::1/128").is_none());
+        assert!(validate(VarKind::IpList, "10.0.0.0/33").is_some());
+        assert!(validate(VarKind::IpList, "office").is_some());
+    }
+
+    #[test]
+    fn recognizes_development_environments() {
+        assert!(is_development_env(" Dev "));
+        assert!(is_development_env("development"));
+        assert!(!is_development_env("production"));
+    }
+}
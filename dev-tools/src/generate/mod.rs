@@ -0,0 +1,572 @@
+//! Scaffolds new API route domains.
+//!
+//! This module powers the `generate route` subcommand. It renders the
+//! templates under `generate/templates` into a common model, an API repo,
+//! controller, and router, and a reversible migration that creates the
+//! domain's table, then registers the new modules in their parent `mod.rs`
+//! files.
+//!
+//! Generation refuses to overwrite existing files. Mounting the router and
+//! choosing a personal access token scope are left to the developer, and the
+//! remaining steps are printed once the files are written.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{Context, Result, bail};
+use chrono::{NaiveDateTime, Utc};
+
+use crate::utils::env::workspace_root;
+
+/// Template for the common entity and create request models.
+const MODEL_TEMPLATE: &str = include_str!("templates/model.rs.tmpl");
+/// Template for the API repo.
+const REPO_TEMPLATE: &str = include_str!("templates/repo.rs.tmpl");
+/// Template for the API controller.
+const CONTROLLER_TEMPLATE: &str = include_str!("templates/controller.rs.tmpl");
+/// Template for the API router.
+const ROUTER_TEMPLATE: &str = include_str!("templates/router.rs.tmpl");
+/// Template for the up migration.
+const MIGRATION_UP_TEMPLATE: &str = include_str!("templates/migration.up.sql.tmpl");
+/// Template for the down migration.
+const MIGRATION_DOWN_TEMPLATE: &str = include_str!("templates/migration.down.sql.tmpl");
+
+/// Format of the timestamp prefix on SQLx migration file names.
+const MIGRATION_TIMESTAMP_FORMAT: &str = "%Y%m%d%H%M%S";
+
+/// Defines command-line options controlling route generation.
+pub struct RouteOptions {
+    /// Singular `snake_case` name of the domain, e.g. `expense_category`.
+    pub name: String,
+    /// Plural `snake_case` form of the name, used for the table and path.
+    pub plural: Option<String>,
+    /// Prints planned files without writing anything.
+    pub dry_run: bool,
+}
+
+/// Naming variants of a domain, substituted into the templates.
+struct DomainNames {
+    /// Singular `snake_case` name, e.g. `expense_category`.
+    snake: String,
+    /// Plural `snake_case` name, e.g. `expense_categories`.
+    snake_plural: String,
+    /// Singular `PascalCase` name, e.g. `ExpenseCategory`.
+    pascal: String,
+    /// Singular lowercase words, e.g. `expense category`.
+    title: String,
+    /// Plural lowercase words, e.g. `expense categories`.
+    plural_title: String,
+    /// URL path segment, e.g. `expense-categories`.
+    path: String,
+}
+
+impl DomainNames {
+    /// Derives the naming variants of a domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` — Singular `snake_case` name of the domain.
+    /// * `plural` — Optional plural override. When absent the plural is
+    ///   guessed with common English rules.
+    ///
+    /// # Returns
+    ///
+    /// The [`DomainNames`] for the domain.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`anyhow::Error`] if either name is not `snake_case` or
+    /// the plural equals the singular.
+    fn new(name: &str, plural: Option<&str>) -> Result<Self> {
+        validate_snake_case(name)?;
+
+        let snake_plural = match plural {
+            Some(plural) => plural.to_string(),
+            None => pluralize(name),
+        };
+        validate_snake_case(&snake_plural)?;
+        if snake_plural == name {
+            bail!("The plural of `{name}` must differ from the singular; pass --plural");
+        }
+
+        Ok(Self {
+            snake: name.to_string(),
+            pascal: name.split('_').map(capitalize).collect::<Vec<_>>().join(""),
+            title: name.replace('_', " "),
+            plural_title: snake_plural.replace('_', " "),
+            path: snake_plural.replace('_', "-"),
+            snake_plural,
+        })
+    }
+
+    /// Substitutes the naming variants into a template.
+    ///
+    /// # Arguments
+    ///
+    /// * `template` — Template text containing `{{placeholder}}` markers.
+    ///
+    /// # Returns
+    ///
+    /// The rendered text.
+    fn render(&self, template: &str) -> String {
+        template
+            .replace("{{snake_plural}}", &self.snake_plural)
+            .replace("{{snake}}", &self.snake)
+            .replace("{{pascal}}", &self.pascal)
+            .replace("{{plural_title}}", &self.plural_title)
+            .replace("{{Title}}", &capitalize(&self.title))
+            .replace("{{title}}", &self.title)
+            .replace("{{table}}", &self.snake_plural)
+            .replace("{{path}}", &self.path)
+    }
+}
+
+/// A module to register in a parent `mod.rs` file.
+struct ModuleRegistration {
+    /// Path of the parent `mod.rs` file.
+    mod_file: PathBuf,
+    /// Doc line listing the module, or `None` for files without a
+    /// `# Modules` list.
+    doc_line: Option<String>,
+    /// Lines that declare the module.
+    declaration: String,
+}
+
+/// Generates a new route domain.
+///
+/// Writes the model, repo, controller, router, and migration files,
+/// registers the new modules, and prints the remaining manual steps.
+///
+/// # Arguments
+///
+/// * `options` — Route generation options.
+///
+/// # Returns
+///
+/// An empty [`Result`] on success.
+///
+/// # Errors
+///
+/// Returns an [`anyhow::Error`] if the name is invalid, a target file
+/// already exists, or a file cannot be read or written.
+pub fn route(options: RouteOptions) -> Result<()> {
+    let root = workspace_root();
+    let names = DomainNames::new(&options.name, options.plural.as_deref())?;
+    let timestamp = next_migration_timestamp(&root.join("api/migrations"), Utc::now().naive_utc())?;
+    let migration = format!(
+        "api/migrations/{timestamp}_create_{}_table",
+        names.snake_plural
+    );
+
+    let files = [
+        (
+            format!("common/src/models/{}.rs", names.snake),
+            MODEL_TEMPLATE,
+        ),
+        (format!("api/src/repo/{}.rs", names.snake), REPO_TEMPLATE),
+        (
+            format!("api/src/controllers/{}.rs", names.snake),
+            CONTROLLER_TEMPLATE,
+        ),
+        (
+            format!("api/src/routes/{}.rs", names.snake),
+            ROUTER_TEMPLATE,
+        ),
+        (format!("{migration}.up.sql"), MIGRATION_UP_TEMPLATE),
+        (format!("{migration}.down.sql"), MIGRATION_DOWN_TEMPLATE),
+    ];
+
+    for (path, _) in &files {
+        if root.join(path).exists() {
+            bail!("{path} already exists; refusing to overwrite it");
+        }
+    }
+
+    let registrations = [
+        ModuleRegistration {
+            mod_file: root.join("common/src/models/mod.rs"),
+            doc_line: None,
+            declaration: format!(
+                "/// {} entities and request models.\npub mod {};\n",
+                capitalize(&names.title),
+                names.snake
+            ),
+        },
+        api_registration(&root, "repo", &names, "storage and lookup"),
+        api_registration(&root, "controllers", &names, "endpoints"),
+        api_registration(&root, "routes", &names, "routes"),
+    ];
+
+    for (path, template) in &files {
+        if options.dry_run {
+            println!("[dry-run] create {path}");
+            continue;
+        }
+
+        fs::write(root.join(path), names.render(template))
+            .with_context(|| format!("failed to write {path}"))?;
+        println!("created {path}");
+    }
+
+    for registration in &registrations {
+        let relative = registration
+            .mod_file
+            .strip_prefix(&root)
+            .unwrap_or(&registration.mod_file);
+        if options.dry_run {
+            println!(
+                "[dry-run] register `{}` in {}",
+                names.snake,
+                relative.display()
+            );
+            continue;
+        }
+
+        let source = fs::read_to_string(&registration.mod_file)
+            .with_context(|| format!("failed to read {}", relative.display()))?;
+        let updated = register_module(&source, &names.snake, registration);
+        fs::write(&registration.mod_file, updated)
+            .with_context(|| format!("failed to write {}", relative.display()))?;
+        println!("updated {}", relative.display());
+    }
+
+    if !options.dry_run {
+        format_sources(&root, &files);
+    }
+
+    println!();
+    println!("Next steps:");
+    println!(
+        "  1. Nest `{}Router::new()` at \"/{}\" in AppRouter (api/src/routes/app.rs).",
+        names.pascal, names.path
+    );
+    println!(
+        "  2. Map \"{}\" to read and write scopes in ScopeUtil (api/src/auth/scope.rs) if personal access tokens may use it.",
+        names.path
+    );
+    println!("  3. Run `just db-migrate` and `just db-prepare`.");
+
+    Ok(())
+}
+
+/// Formats the generated Rust sources with `rustfmt`.
+///
+/// Line wrapping and import order depend on the domain name, so the
+/// templates are formatted after rendering rather than by hand. A missing
+/// or failing `rustfmt` only produces a warning.
+///
+/// # Arguments
+///
+/// * `root` — Workspace root directory.
+/// * `files` — Generated file paths, relative to `root`, with their
+///   templates.
+fn format_sources(root: &Path, files: &[(String, &str)]) {
+    let sources: Vec<PathBuf> = files
+        .iter()
+        .filter(|(path, _)| path.ends_with(".rs"))
+        .map(|(path, _)| root.join(path))
+        .collect();
+
+    match Command::new("rustfmt")
+        .args(["--edition", "2024"])
+        .args(&sources)
+        .status()
+    {
+        Ok(status) if status.success() => {}
+        Ok(status) => eprintln!("warning: rustfmt exited with {status}; run `cargo fmt`"),
+        Err(error) => eprintln!("warning: failed to run rustfmt ({error}); run `cargo fmt`"),
+    }
+}
+
+/// Builds the registration of a new module in an API `mod.rs` file.
+///
+/// # Arguments
+///
+/// * `root` — Workspace root directory.
+/// * `parent` — Name of the parent module under `api/src`.
+/// * `names` — Naming variants of the domain.
+/// * `summary` — Words that follow the domain title in the doc line.
+///
+/// # Returns
+///
+/// The [`ModuleRegistration`] for the parent module.
+fn api_registration(
+    root: &Path,
+    parent: &str,
+    names: &DomainNames,
+    summary: &str,
+) -> ModuleRegistration {
+    ModuleRegistration {
+        mod_file: root.join(format!("api/src/{parent}/mod.rs")),
+        doc_line: Some(format!(
+            "//! - [`{snake}`](crate::{parent}::{snake}) — {title} {summary}.",
+            snake = names.snake,
+            title = capitalize(&names.title),
+        )),
+        declaration: format!("pub mod {};\n", names.snake),
+    }
+}
+
+/// Inserts a module into a `mod.rs` source in alphabetical position.
+///
+/// The doc line is placed among the existing `//! - [` bullets and the
+/// declaration before the first `pub mod` line that sorts after the new
+/// module, including any `///` lines directly above it.
+///
+/// # Arguments
+///
+/// * `source` — Current contents of the `mod.rs` file.
+/// * `module` — Name of the new module.
+/// * `registration` — Lines to insert.
+///
+/// # Returns
+///
+/// The updated file contents.
+fn register_module(source: &str, module: &str, registration: &ModuleRegistration) -> String {
+    let mut lines: Vec<String> = source.lines().map(str::to_string).collect();
+
+    let declarations: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.starts_with("pub mod "))
+        .map(|(index, _)| index)
+        .collect();
+    let declaration_index = declarations
+        .iter()
+        .find(|&&index| module_name(&lines[index], "pub mod ") > module)
+        .map(|&index| {
+            let mut start = index;
+            while start > 0 && lines[start - 1].starts_with("///") {
+                start -= 1;
+            }
+            start
+        })
+        .unwrap_or_else(|| declarations.last().map_or(lines.len(), |&index| index + 1));
+    let declaration_lines: Vec<String> = registration
+        .declaration
+        .lines()
+        .map(str::to_string)
+        .collect();
+    lines.splice(declaration_index..declaration_index, declaration_lines);
+
+    if let Some(doc_line) = &registration.doc_line {
+        let bullets: Vec<usize> = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.starts_with("//! - [`"))
+            .map(|(index, _)| index)
+            .collect();
+        let doc_index = bullets
+            .iter()
+            .find(|&&index| module_name(&lines[index], "//! - [`") > module)
+            .copied()
+            .unwrap_or_else(|| bullets.last().map_or(0, |&index| index + 1));
+        lines.insert(doc_index, doc_line.clone());
+    }
+
+    let mut output = lines.join("\n");
+    output.push('\n');
+    output
+}
+
+/// Extracts the module name from a declaration or doc bullet line.
+///
+/// # Arguments
+///
+/// * `line` — The line to parse.
+/// * `prefix` — The text preceding the module name.
+///
+/// # Returns
+///
+/// The module name, ending at the first character that cannot appear in
+/// an identifier.
+fn module_name<'a>(line: &'a str, prefix: &str) -> &'a str {
+    let rest = &line[prefix.len()..];
+    let end = rest
+        .find(|character: char| !character.is_ascii_alphanumeric() && character != '_')
+        .unwrap_or(rest.len());
+    &rest[..end]
+}
+
+/// Picks a timestamp for a new migration.
+///
+/// Uses the current time unless an existing migration is dated at or after
+/// it, in which case the timestamp follows the latest migration so SQLx
+/// still applies the new one last.
+///
+/// # Arguments
+///
+/// * `migrations_dir` — Directory containing the SQLx migrations.
+/// * `now` — The current UTC time.
+///
+/// # Returns
+///
+/// The migration timestamp in `YYYYMMDDHHMMSS` form.
+///
+/// # Errors
+///
+/// Returns an [`anyhow::Error`] if the migrations directory cannot be read.
+fn next_migration_timestamp(migrations_dir: &Path, now: NaiveDateTime) -> Result<String> {
+    let now: u64 = now
+        .format(MIGRATION_TIMESTAMP_FORMAT)
+        .to_string()
+        .parse()
+        .context("failed to format the migration timestamp")?;
+    let latest = fs::read_dir(migrations_dir)
+        .with_context(|| format!("failed to read {}", migrations_dir.display()))?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let file_name = entry.file_name();
+            let prefix = file_name.to_str()?.split('_').next()?.to_string();
+            prefix.parse::<u64>().ok()
+        })
+        .max()
+        .unwrap_or(0);
+
+    Ok(now.max(latest + 1).to_string())
+}
+
+/// Checks that a name is lowercase `snake_case` starting with a letter.
+///
+/// # Arguments
+///
+/// * `name` — The name to check.
+///
+/// # Returns
+///
+/// An empty [`Result`] if the name is valid.
+///
+/// # Errors
+///
+/// Returns an [`anyhow::Error`] describing the expected format.
+fn validate_snake_case(name: &str) -> Result<()> {
+    let valid = name.starts_with(|character: char| character.is_ascii_lowercase())
+        && !name.ends_with('_')
+        && !name.contains("__")
+        && name.chars().all(|character| {
+            character.is_ascii_lowercase() || character.is_ascii_digit() || character == '_'
+        });
+
+    if !valid {
+        bail!("`{name}` must be lowercase snake_case, e.g. `expense_category`");
+    }
+
+    Ok(())
+}
+
+/// Guesses the plural of a `snake_case` name by pluralizing its last word.
+///
+/// # Arguments
+///
+/// * `name` — Singular `snake_case` name.
+///
+/// # Returns
+///
+/// The plural name.
+fn pluralize(name: &str) -> String {
+    let consonant_y =
+        name.ends_with('y') && !name[..name.len() - 1].ends_with(['a', 'e', 'i', 'o', 'u']);
+
+    if consonant_y {
+        format!("{}ies", &name[..name.len() - 1])
+    } else if ["s", "x", "z", "ch", "sh"]
+        .iter()
+        .any(|suffix| name.ends_with(suffix))
+    {
+        format!("{name}es")
+    } else {
+        format!("{name}s")
+    }
+}
+
+/// Uppercases the first character of a word.
+///
+/// # Arguments
+///
+/// * `word` — The word to capitalize.
+///
+/// # Returns
+///
+/// The capitalized word.
+fn capitalize(word: &str) -> String {
+    let mut characters = word.chars();
+    match characters.next() {
+        Some(first) => first.to_uppercase().chain(characters).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derives_domain_names() {
+        let names = DomainNames::new("expense_category", None).unwrap();
+
+        assert_eq!(names.pascal, "ExpenseCategory");
+        assert_eq!(names.snake_plural, "expense_categories");
+        assert_eq!(names.path, "expense-categories");
+        assert_eq!(
+            names.render("{{Title}} in {{table}}"),
+            "Expense category in expense_categories"
+        );
+        assert_eq!(pluralize("tax"), "taxes");
+        assert_eq!(pluralize("survey"), "surveys");
+        assert!(DomainNames::new("ExpenseCategory", None).is_err());
+        assert!(DomainNames::new("sheep", Some("sheep")).is_err());
+    }
+
+    #[test]
+    fn registers_modules_in_sorted_position() {
+        let source = "//! # Modules\n//!\n//! - [`alpha`](crate::repo::alpha) — A.\n\
+                      //! - [`gamma`](crate::repo::gamma) — G.\n\npub mod alpha;\npub mod gamma;\n";
+        let registration = ModuleRegistration {
+            mod_file: PathBuf::new(),
+            doc_line: Some("//! - [`beta`](crate::repo::beta) — B.".to_string()),
+            declaration: "pub mod beta;\n".to_string(),
+        };
+
+        let updated = register_module(source, "beta", &registration);
+
+        assert_eq!(
+            updated,
+            "//! # Modules\n//!\n//! - [`alpha`](crate::repo::alpha) — A.\n\
+             //! - [`beta`](crate::repo::beta) — B.\n//! - [`gamma`](crate::repo::gamma) — G.\n\n\
+             pub mod alpha;\npub mod beta;\npub mod gamma;\n"
+        );
+
+        let models = "/// Alpha.\npub mod alpha;\n/// Gamma.\npub mod gamma;\n";
+        let registration = ModuleRegistration {
+            mod_file: PathBuf::new(),
+            doc_line: None,
+            declaration: "/// Beta.\npub mod beta;\n".to_string(),
+        };
+
+        assert_eq!(
+            register_module(models, "beta", &registration),
+            "/// Alpha.\npub mod alpha;\n/// Beta.\npub mod beta;\n/// Gamma.\npub mod gamma;\n"
+        );
+    }
+
+    #[test]
+    fn migration_timestamp_follows_latest_migration() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("20300101000000_future.up.sql"), "").unwrap();
+        let now =
+            NaiveDateTime::parse_from_str("20261016120000", MIGRATION_TIMESTAMP_FORMAT).unwrap();
+
+        assert_eq!(
+            next_migration_timestamp(dir.path(), now).unwrap(),
+            "20300101000001"
+        );
+
+        let later =
+            NaiveDateTime::parse_from_str("20310101000000", MIGRATION_TIMESTAMP_FORMAT).unwrap();
+        assert_eq!(
+            next_migration_timestamp(dir.path(), later).unwrap(),
+            "20310101000000"
+        );
+    }
+}
@@ -10,7 +10,10 @@
 //! - [`db_viewer`]: Terminal database query and table inspection tool.
 //! - [`dev`]: Development orchestrator for running and rebuilding services.
 //! - [`docs`]: Rustdoc build, index generation, and local docs serving.
+//! - [`doctor`]: Env file, database, and migration diagnostics.
+//! - [`generate`]: Route domain scaffolding from templates.
 //! - [`setup`]: Environment bootstrap workflow for local development.
+//! - [`smoke`]: Post-deploy smoke test against a deployed API.
 //! - [`utils`]: Shared helper modules used by multiple commands.
 
 mod api_tester;
@@ -18,12 +21,15 @@ mod cli;
 mod db_viewer;
 mod dev;
 mod docs;
+mod doctor;
+mod generate;
 mod setup;
+mod smoke;
 mod utils;
 
 use clap::Parser;
 
-use cli::{Cli, Command};
+use cli::{Cli, Command, GenerateTarget};
 
 /// Runs the `gig-log-dev-tools` command dispatcher.
 ///
@@ -68,6 +74,22 @@ async fn main() -> anyhow::Result<()> {
         }
         Command::ApiTester => api_tester::run().await?,
         Command::DbViewer => db_viewer::run().await?,
+        Command::Doctor { env_file } => doctor::run(doctor::DoctorOptions { env_file }).await?,
+        Command::Smoke { base_url, email } => {
+            smoke::run(smoke::SmokeOptions { base_url, email }).await?
+        }
+        Command::Generate {
+            target:
+                GenerateTarget::Route {
+                    name,
+                    plural,
+                    dry_run,
+                },
+        } => generate::route(generate::RouteOptions {
+            name,
+            plural,
+            dry_run,
+        })?,
     }
 
     Ok(())
@@ -0,0 +1,762 @@
+//! Runs a post-deploy smoke test against a GigLog API.
+//!
+//! This module powers the `smoke` subcommand. It drives a deployed API
+//! through a curated sequence of requests with `curl`: a health check, a
+//! sign-up confirmed with the code sent to the operator's mailbox, a log-in,
+//! creating a company and job, starting and completing a work session, and
+//! cleaning up. Each step is reported as passed, failed, or skipped.
+//!
+//! The sign-up uses a plus-addressed variant of the given mailbox so every
+//! run creates a fresh account that still delivers to the same inbox. The
+//! API has no account deletion endpoint, so cleanup removes the company,
+//! which deletes its job and work session, and logs out; the confirmed smoke
+//! account is left behind.
+
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{Context, Result, bail};
+use chrono::Utc;
+use dialoguer::Input;
+use rand::distr::Alphanumeric;
+use rand::{Rng, rng};
+use serde_json::{Value, json};
+use tempfile::{NamedTempFile, TempDir};
+use tokio::process::Command;
+
+/// Time to wait between starting and stopping the work session. The API
+/// rejects state changes made within two seconds of the previous one.
+const SESSION_STATE_CHANGE_WAIT: Duration = Duration::from_millis(2500);
+
+/// Defines command-line options controlling the smoke test.
+pub struct SmokeOptions {
+    /// Base URL of the API under test, e.g. `https://api.example.com`.
+    pub base_url: String,
+    /// Mailbox that receives the sign-up confirmation code.
+    pub email: String,
+}
+
+/// Outcome of one smoke test step.
+enum StepOutcome {
+    /// The step's request succeeded.
+    Passed,
+    /// The step's request failed, with the reason.
+    Failed(String),
+    /// The step was not run because an earlier step it depends on failed.
+    Skipped,
+}
+
+/// Collects and prints step outcomes as the smoke test runs.
+struct SmokeReport {
+    /// Step names and their outcomes, in run order.
+    steps: Vec<(&'static str, StepOutcome)>,
+}
+
+impl SmokeReport {
+    /// Creates an empty report.
+    ///
+    /// # Returns
+    ///
+    /// A new [`SmokeReport`] with no steps.
+    fn new() -> Self {
+        Self { steps: Vec::new() }
+    }
+
+    /// Records and prints the result of a step.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` — Step name shown in the report.
+    /// * `result` — The step's result.
+    ///
+    /// # Returns
+    ///
+    /// The step's value if it passed, or `None` if it failed.
+    fn check<T>(&mut self, name: &'static str, result: Result<T>) -> Option<T> {
+        match result {
+            Ok(value) => {
+                println!("PASS {name}");
+                self.steps.push((name, StepOutcome::Passed));
+                Some(value)
+            }
+            Err(error) => {
+                println!("FAIL {name}: {error:#}");
+                self.steps
+                    .push((name, StepOutcome::Failed(format!("{error:#}"))));
+                None
+            }
+        }
+    }
+
+    /// Records and prints a step that was not run.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` — Step name shown in the report.
+    fn skip(&mut self, name: &'static str) {
+        println!("SKIP {name}");
+        self.steps.push((name, StepOutcome::Skipped));
+    }
+
+    /// Counts the steps with each outcome.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of `(passed, failed, skipped)` counts.
+    fn counts(&self) -> (usize, usize, usize) {
+        self.steps.iter().fold(
+            (0, 0, 0),
+            |(passed, failed, skipped), (_, outcome)| match outcome {
+                StepOutcome::Passed => (passed + 1, failed, skipped),
+                StepOutcome::Failed(_) => (passed, failed + 1, skipped),
+                StepOutcome::Skipped => (passed, failed, skipped + 1),
+            },
+        )
+    }
+
+    /// Prints the summary of all recorded steps.
+    fn print_summary(&self) {
+        let (passed, failed, skipped) = self.counts();
+
+        println!();
+        println!("Smoke test finished: {passed} passed, {failed} failed, {skipped} skipped.");
+
+        for (name, outcome) in &self.steps {
+            if let StepOutcome::Failed(reason) = outcome {
+                println!("- {name}: {reason}");
+            }
+        }
+    }
+}
+
+/// Parsed HTTP response returned by [`SmokeClient`].
+#[derive(Debug)]
+struct SmokeResponse {
+    /// Numeric HTTP status code.
+    status_code: u16,
+    /// Raw response body text.
+    body: String,
+}
+
+impl SmokeResponse {
+    /// Requires a `2xx` status.
+    ///
+    /// # Returns
+    ///
+    /// The response if its status is `2xx`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`anyhow::Error`] with the status and body otherwise.
+    fn success(self) -> Result<Self> {
+        if !(200..300).contains(&self.status_code) {
+            bail!("HTTP {}: {}", self.status_code, self.body.trim());
+        }
+
+        Ok(self)
+    }
+
+    /// Parses the body as JSON.
+    ///
+    /// # Returns
+    ///
+    /// The body as a [`Value`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`anyhow::Error`] if the body is not valid JSON.
+    fn json(&self) -> Result<Value> {
+        serde_json::from_str(&self.body).context("response body was not valid JSON")
+    }
+}
+
+/// Sends smoke test requests through `curl` with a private cookie jar.
+struct SmokeClient {
+    /// Base URL without a trailing slash.
+    base_url: String,
+    /// Directory holding the cookie jar, removed when the client is dropped.
+    cookie_dir: TempDir,
+}
+
+impl SmokeClient {
+    /// Creates a client with an empty cookie jar.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` — Base URL of the API under test.
+    ///
+    /// # Returns
+    ///
+    /// A configured [`SmokeClient`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`anyhow::Error`] if the cookie directory cannot be
+    /// created.
+    fn new(base_url: &str) -> Result<Self> {
+        Ok(Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            cookie_dir: TempDir::new().context("failed to create cookie directory")?,
+        })
+    }
+
+    /// Returns the path of the cookie jar.
+    ///
+    /// # Returns
+    ///
+    /// The cookie jar's [`PathBuf`].
+    fn cookie_path(&self) -> PathBuf {
+        self.cookie_dir.path().join("cookies.txt")
+    }
+
+    /// Sends a request and parses the response.
+    ///
+    /// The body, if any, is passed to `curl` through a temporary file so
+    /// the generated password does not appear in the process list.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` — HTTP method, e.g. `"POST"`.
+    /// * `path` — Request path, appended to the base URL.
+    /// * `body` — Optional JSON body.
+    ///
+    /// # Returns
+    ///
+    /// The parsed [`SmokeResponse`], whatever its status.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`anyhow::Error`] if `curl` cannot be run or fails, or its
+    /// output cannot be parsed.
+    async fn send(&self, method: &str, path: &str, body: Option<&Value>) -> Result<SmokeResponse> {
+        let cookie_path = self.cookie_path();
+        let mut command = Command::new("curl");
+        command
+            .args(["--silent", "--show-error", "--request", method])
+            .arg("--cookie")
+            .arg(&cookie_path)
+            .arg("--cookie-jar")
+            .arg(&cookie_path)
+            .args(["--write-out", "\n%{http_code}"]);
+
+        let body_file = match body {
+            Some(body) => {
+                let mut file =
+                    NamedTempFile::new().context("failed to create request body file")?;
+                file.write_all(body.to_string().as_bytes())
+                    .context("failed to write request body file")?;
+                file.flush().context("failed to flush request body file")?;
+
+                command
+                    .args(["--header", "Content-Type: application/json"])
+                    .arg("--data-binary")
+                    .arg(format!("@{}", file.path().display()));
+
+                Some(file)
+            }
+            None => None,
+        };
+
+        let output = command
+            .arg(format!("{}{path}", self.base_url))
+            .output()
+            .await
+            .context("failed to execute curl command")?;
+
+        drop(body_file);
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            bail!("curl failed: {stderr}");
+        }
+
+        let stdout = String::from_utf8(output.stdout).context("curl output was not valid UTF-8")?;
+
+        parse_response(&stdout)
+    }
+}
+
+/// Runs the smoke test against a deployed API.
+///
+/// Steps after a failed step they depend on are skipped, but cleanup still
+/// runs for anything that was created.
+///
+/// # Arguments
+///
+/// * `options` — Smoke test options.
+///
+/// # Returns
+///
+/// An empty [`Result`] if every step passed.
+///
+/// # Errors
+///
+/// Returns an [`anyhow::Error`] if the mailbox address is invalid or any
+/// step failed.
+pub async fn run(options: SmokeOptions) -> Result<()> {
+    let run_id = format!("{}", Utc::now().format("%Y%m%d%H%M%S"));
+    let email = plus_address(&options.email, &format!("smoke-{run_id}"))?;
+    let password = generate_password();
+    let client = SmokeClient::new(&options.base_url)?;
+    let mut report = SmokeReport::new();
+
+    println!("Running smoke test against {}", client.base_url);
+    println!("Smoke account: {email}");
+    println!();
+
+    report.check("health", health(&client).await);
+
+    let confirmed = match report.check("sign up", sign_up(&client, &email, &password).await) {
+        Some(()) => report
+            .check("confirm email", confirm_email(&client, &email).await)
+            .is_some(),
+        None => {
+            report.skip("confirm email");
+            false
+        }
+    };
+
+    let logged_in = if confirmed {
+        report
+            .check("log in", log_in(&client, &email, &password).await)
+            .is_some()
+    } else {
+        report.skip("log in");
+        false
+    };
+
+    let created = if logged_in {
+        report.check(
+            "create company and job",
+            create_company_and_job(&client, &run_id).await,
+        )
+    } else {
+        report.skip("create company and job");
+        None
+    };
+
+    match &created {
+        Some((_, job_id)) => {
+            match report.check("start session", start_session(&client, job_id).await) {
+                Some(session_id) => {
+                    report.check("stop session", complete_session(&client, &session_id).await);
+                }
+                None => report.skip("stop session"),
+            }
+        }
+        None => {
+            report.skip("start session");
+            report.skip("stop session");
+        }
+    }
+
+    match &created {
+        Some((company_id, _)) => {
+            report.check("clean up", delete_company(&client, company_id).await);
+        }
+        None => report.skip("clean up"),
+    }
+
+    if logged_in {
+        report.check("log out", log_out(&client).await);
+    } else {
+        report.skip("log out");
+    }
+
+    report.print_summary();
+
+    let (_, failed, skipped) = report.counts();
+
+    if failed > 0 || skipped > 0 {
+        bail!("smoke test failed");
+    }
+
+    Ok(())
+}
+
+/// Checks that the API reports itself healthy.
+///
+/// # Arguments
+///
+/// * `client` — Smoke test client.
+///
+/// # Returns
+///
+/// An empty [`Result`] on success.
+///
+/// # Errors
+///
+/// Returns an [`anyhow::Error`] if the request fails or is not `2xx`.
+async fn health(client: &SmokeClient) -> Result<()> {
+    client.send("GET", "/health", None).await?.success()?;
+
+    Ok(())
+}
+
+/// Signs up the smoke account.
+///
+/// # Arguments
+///
+/// * `client` — Smoke test client.
+/// * `email` — Smoke account email address.
+/// * `password` — Smoke account password.
+///
+/// # Returns
+///
+/// An empty [`Result`] on success.
+///
+/// # Errors
+///
+/// Returns an [`anyhow::Error`] if the request fails or is not `2xx`.
+async fn sign_up(client: &SmokeClient, email: &str, password: &str) -> Result<()> {
+    let body = json!({
+        "first_name": "Smoke",
+        "last_name": "Test",
+        "email": email,
+        "password": password,
+        "confirm_password": password,
+    });
+
+    client
+        .send("POST", "/auth/sign-up", Some(&body))
+        .await?
+        .success()?;
+
+    Ok(())
+}
+
+/// Confirms the smoke account with the code from the operator's mailbox.
+///
+/// # Arguments
+///
+/// * `client` — Smoke test client.
+/// * `email` — Smoke account email address.
+///
+/// # Returns
+///
+/// An empty [`Result`] on success.
+///
+/// # Errors
+///
+/// Returns an [`anyhow::Error`] if the prompt fails, or the request fails
+/// or is not `2xx`.
+async fn confirm_email(client: &SmokeClient, email: &str) -> Result<()> {
+    let code = Input::<String>::new()
+        .with_prompt(format!("Confirmation code sent to {email}"))
+        .interact_text()
+        .context("Failed to read input")?;
+    let body = json!({ "email": email, "code": code.trim() });
+
+    client
+        .send("POST", "/auth/confirm-email", Some(&body))
+        .await?
+        .success()?;
+
+    Ok(())
+}
+
+/// Logs in as the smoke account, storing the session cookies.
+///
+/// # Arguments
+///
+/// * `client` — Smoke test client.
+/// * `email` — Smoke account email address.
+/// * `password` — Smoke account password.
+///
+/// # Returns
+///
+/// An empty [`Result`] on success.
+///
+/// # Errors
+///
+/// Returns an [`anyhow::Error`] if the request fails or is not `2xx`.
+async fn log_in(client: &SmokeClient, email: &str, password: &str) -> Result<()> {
+    let body = json!({ "email": email, "password": password });
+
+    client
+        .send("POST", "/auth/log-in", Some(&body))
+        .await?
+        .success()?;
+
+    Ok(())
+}
+
+/// Creates a company and an hourly job through onboarding.
+///
+/// # Arguments
+///
+/// * `client` — Smoke test client.
+/// * `run_id` — Identifier of this run, included in the company name.
+///
+/// # Returns
+///
+/// A tuple of the created `(company_id, job_id)`.
+///
+/// # Errors
+///
+/// Returns an [`anyhow::Error`] if the request fails, is not `2xx`, or the
+/// response lacks the created IDs.
+async fn create_company_and_job(client: &SmokeClient, run_id: &str) -> Result<(String, String)> {
+    let body = json!({
+        "company": { "name": format!("Smoke test {run_id}") },
+        "job": {
+            "title": "Smoke test job",
+            "payment_type": "hourly",
+            "hourly_rate": 1.0,
+        },
+    });
+    let response = client
+        .send("POST", "/onboarding", Some(&body))
+        .await?
+        .success()?
+        .json()?;
+
+    Ok((
+        string_at(&response, "/company/id")?,
+        string_at(&response, "/job/id")?,
+    ))
+}
+
+/// Starts a work session timer on the smoke job.
+///
+/// # Arguments
+///
+/// * `client` — Smoke test client.
+/// * `job_id` — The smoke job's ID.
+///
+/// # Returns
+///
+/// The started work session's ID.
+///
+/// # Errors
+///
+/// Returns an [`anyhow::Error`] if the request fails, is not `2xx`, or the
+/// response lacks the session ID.
+async fn start_session(client: &SmokeClient, job_id: &str) -> Result<String> {
+    let body = json!({ "job_id": job_id });
+    let response = client
+        .send("POST", "/work-sessions", Some(&body))
+        .await?
+        .success()?
+        .json()?;
+
+    string_at(&response, "/id")
+}
+
+/// Completes the smoke work session.
+///
+/// Waits out the API's minimum interval between state changes first.
+///
+/// # Arguments
+///
+/// * `client` — Smoke test client.
+/// * `session_id` — The work session's ID.
+///
+/// # Returns
+///
+/// An empty [`Result`] on success.
+///
+/// # Errors
+///
+/// Returns an [`anyhow::Error`] if the request fails or is not `2xx`.
+async fn complete_session(client: &SmokeClient, session_id: &str) -> Result<()> {
+    tokio::time::sleep(SESSION_STATE_CHANGE_WAIT).await;
+
+    client
+        .send(
+            "POST",
+            &format!("/work-sessions/{session_id}/complete"),
+            Some(&json!({})),
+        )
+        .await?
+        .success()?;
+
+    Ok(())
+}
+
+/// Deletes the smoke company along with its job and work session.
+///
+/// # Arguments
+///
+/// * `client` — Smoke test client.
+/// * `company_id` — The smoke company's ID.
+///
+/// # Returns
+///
+/// An empty [`Result`] on success.
+///
+/// # Errors
+///
+/// Returns an [`anyhow::Error`] if the request fails or is not `2xx`.
+async fn delete_company(client: &SmokeClient, company_id: &str) -> Result<()> {
+    client
+        .send(
+            "DELETE",
+            &format!("/companies/{company_id}?confirm=true"),
+            None,
+        )
+        .await?
+        .success()?;
+
+    Ok(())
+}
+
+/// Logs the smoke account out.
+///
+/// # Arguments
+///
+/// * `client` — Smoke test client.
+///
+/// # Returns
+///
+/// An empty [`Result`] on success.
+///
+/// # Errors
+///
+/// Returns an [`anyhow::Error`] if the request fails or is not `2xx`.
+async fn log_out(client: &SmokeClient) -> Result<()> {
+    client
+        .send("POST", "/auth/log-out", None)
+        .await?
+        .success()?;
+
+    Ok(())
+}
+
+/// Parses `curl` output written with a trailing status code line.
+///
+/// # Arguments
+///
+/// * `raw` — Raw `curl` stdout ending in `\n<status>`.
+///
+/// # Returns
+///
+/// The parsed [`SmokeResponse`].
+///
+/// # Errors
+///
+/// Returns an [`anyhow::Error`] if the status line is missing or invalid.
+fn parse_response(raw: &str) -> Result<SmokeResponse> {
+    let (body, status_line) = raw
+        .rsplit_once('\n')
+        .context("missing HTTP status suffix from curl output")?;
+    let status_code = status_line
+        .trim()
+        .parse::<u16>()
+        .context("failed to parse HTTP status code")?;
+
+    Ok(SmokeResponse {
+        status_code,
+        body: body.to_string(),
+    })
+}
+
+/// Reads a string from a JSON response.
+///
+/// # Arguments
+///
+/// * `value` — Parsed response body.
+/// * `pointer` — JSON pointer to the string, e.g. `"/job/id"`.
+///
+/// # Returns
+///
+/// The string at `pointer`.
+///
+/// # Errors
+///
+/// Returns an [`anyhow::Error`] if there is no string at `pointer`.
+fn string_at(value: &Value, pointer: &str) -> Result<String> {
+    value
+        .pointer(pointer)
+        .and_then(Value::as_str)
+        .map(ToOwned::to_owned)
+        .with_context(|| format!("response is missing {pointer}"))
+}
+
+/// Adds a `+tag` suffix to the local part of an email address.
+///
+/// # Arguments
+///
+/// * `email` — Mailbox address, e.g. `ops@example.com`.
+/// * `tag` — Suffix to add, e.g. `smoke-20260101120000`.
+///
+/// # Returns
+///
+/// The plus-addressed email, e.g. `ops+smoke-20260101120000@example.com`.
+///
+/// # Errors
+///
+/// Returns an [`anyhow::Error`] if `email` has no local part or domain.
+fn plus_address(email: &str, tag: &str) -> Result<String> {
+    match email.trim().split_once('@') {
+        Some((local, domain)) if !local.is_empty() && !domain.is_empty() => {
+            Ok(format!("{local}+{tag}@{domain}"))
+        }
+        _ => bail!("invalid mailbox address: {email}"),
+    }
+}
+
+/// Generates a random password for the smoke account.
+///
+/// # Returns
+///
+/// A 32-character alphanumeric [`String`].
+fn generate_password() -> String {
+    let rng = rng();
+    rng.sample_iter(&Alphanumeric)
+        .map(char::from)
+        .take(32)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plus_address_tags_local_part() {
+        assert_eq!(
+            plus_address("ops@example.com", "smoke-1").unwrap(),
+            "ops+smoke-1@example.com"
+        );
+        assert_eq!(
+            plus_address(" ops+deploys@example.com ", "smoke-1").unwrap(),
+            "ops+deploys+smoke-1@example.com"
+        );
+    }
+
+    #[test]
+    fn plus_address_rejects_invalid_mailboxes() {
+        assert!(plus_address("example.com", "smoke-1").is_err());
+        assert!(plus_address("@example.com", "smoke-1").is_err());
+        assert!(plus_address("ops@", "smoke-1").is_err());
+    }
+
+    #[test]
+    fn parse_response_splits_status_suffix() {
+        let response = parse_response("{\"status\":\"ok\"}\n200").unwrap();
+
+        assert_eq!(response.status_code, 200);
+        assert_eq!(response.body, "{\"status\":\"ok\"}");
+        assert!(response.success().is_ok());
+    }
+
+    #[test]
+    fn non_success_status_fails_with_body() {
+        let response = parse_response("{\"message\":\"nope\"}\n400").unwrap();
+        let error = response.success().unwrap_err();
+
+        assert_eq!(error.to_string(), "HTTP 400: {\"message\":\"nope\"}");
+    }
+
+    #[test]
+    fn report_counts_outcomes() {
+        let mut report = SmokeReport::new();
+        report.check("passes", Ok(()));
+        report.check::<()>("fails", Err(anyhow::anyhow!("boom")));
+        report.skip("skipped");
+
+        assert_eq!(report.counts(), (1, 1, 1));
+    }
+}
@@ -1,10 +1,11 @@
 //! Low-level HTTP client wrapper for frontend API requests.
 
 use gig_log_common::models::error::ApiError;
-use reqwest::Client;
+use leptos::prelude::use_context;
+use reqwest::{Client, Response};
 use serde::{Serialize, de::DeserializeOwned};
 
-use crate::api_client::error::ClientError;
+use crate::{api_client::error::ClientError, contexts::ConnectivityContext};
 
 const DEFAULT_BASE_URL: &str = "http://localhost:8000";
 #[cfg(target_arch = "wasm32")]
@@ -17,21 +18,45 @@ pub struct ApiClient {
     client: Client,
     /// Stores the resolved API base URL for all requests.
     base_url: String,
+    /// Stores the connectivity context that request results are reported
+    /// to, when one is provided.
+    connectivity: Option<ConnectivityContext>,
 }
 
 impl ApiClient {
     /// Creates a new [`ApiClient`].
     ///
+    /// Reports whether each request reached the API to the
+    /// [`ConnectivityContext`] when one is provided.
+    ///
     /// # Returns
     ///
     /// An initialized [`ApiClient`].
     pub fn new() -> Self {
+        Self {
+            connectivity: use_context::<ConnectivityContext>(),
+            ..Self::untracked()
+        }
+    }
+
+    /// Creates a new [`ApiClient`] that does not report request results.
+    ///
+    /// Used for the connectivity context's own health checks.
+    ///
+    /// # Returns
+    ///
+    /// An initialized [`ApiClient`].
+    pub fn untracked() -> Self {
         let client = Client::builder()
             .build()
             .expect("Failed to build reqwest client");
         let base_url = Self::resolve_base_url();
 
-        Self { client, base_url }
+        Self {
+            client,
+            base_url,
+            connectivity: None,
+        }
     }
 
     /// Sends a `POST` request and deserializes the response body.
@@ -61,7 +86,8 @@ impl ApiClient {
             Some(b) => request.json(b).send().await,
             None => request.send().await,
         }
-        .map_err(|e| ClientError::Network(e.to_string()))?;
+        .map_err(|e| ClientError::Network(e.to_string()));
+        let response = self.track(response)?;
 
         if response.status().is_success() {
             response
@@ -104,7 +130,8 @@ impl ApiClient {
             Some(b) => request.json(b).send().await,
             None => request.send().await,
         }
-        .map_err(|e| ClientError::Network(e.to_string()))?;
+        .map_err(|e| ClientError::Network(e.to_string()));
+        let response = self.track(response)?;
 
         if response.status().is_success() {
             Ok(())
@@ -117,6 +144,49 @@ impl ApiClient {
         }
     }
 
+    /// Sends a `PUT` request and deserializes the response body.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` — API path appended to the resolved API base URL.
+    /// * `body` — Serializable request payload.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] containing the deserialized response payload on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ClientError`] if request execution fails, response
+    /// deserialization fails, or the API returns an error payload.
+    pub async fn put<T: Serialize, R: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &T,
+    ) -> Result<R, ClientError> {
+        let request = self.client.put(self.build_url(path));
+
+        let response = Self::with_credentials(request)
+            .json(body)
+            .send()
+            .await
+            .map_err(|e| ClientError::Network(e.to_string()));
+        let response = self.track(response)?;
+
+        if response.status().is_success() {
+            response
+                .json::<R>()
+                .await
+                .map_err(|e| ClientError::Network(e.to_string()))
+        } else {
+            let api_error = response
+                .json::<ApiError>()
+                .await
+                .map_err(|e| ClientError::Network(e.to_string()))?;
+            Err(ClientError::Api(api_error))
+        }
+    }
+
     /// Sends a `GET` request and deserializes the response body.
     ///
     /// # Arguments
@@ -137,7 +207,8 @@ impl ApiClient {
         let response = Self::with_credentials(response)
             .send()
             .await
-            .map_err(|e| ClientError::Network(e.to_string()))?;
+            .map_err(|e| ClientError::Network(e.to_string()));
+        let response = self.track(response)?;
 
         if response.status().is_success() {
             response
@@ -153,6 +224,24 @@ impl ApiClient {
         }
     }
 
+    /// Reports whether a request reached the API to the connectivity
+    /// context.
+    ///
+    /// # Arguments
+    ///
+    /// * `result` — Result of sending the request.
+    ///
+    /// # Returns
+    ///
+    /// The unchanged `result`.
+    fn track(&self, result: Result<Response, ClientError>) -> Result<Response, ClientError> {
+        if let Some(connectivity) = self.connectivity {
+            connectivity.record_request(&result);
+        }
+
+        result
+    }
+
     /// Applies browser credential settings to a request builder.
     ///
     /// # Arguments
@@ -177,6 +266,9 @@ impl ApiClient {
 
     /// Builds an absolute request URL by joining the base URL and API path.
     ///
+    /// Also used for links the browser follows directly, such as file
+    /// downloads.
+    ///
     /// # Arguments
     ///
     /// * `path` — API path appended to the resolved base URL.
@@ -184,7 +276,7 @@ impl ApiClient {
     /// # Returns
     ///
     /// A request URL string ready for reqwest.
-    fn build_url(&self, path: &str) -> String {
+    pub fn build_url(&self, path: &str) -> String {
         format!("{}{}", self.base_url, path)
     }
 
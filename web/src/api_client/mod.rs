@@ -10,3 +10,8 @@ pub mod requests;
 pub use client::*;
 pub use error::*;
 pub use requests::auth::AuthRequestRunner;
+pub use requests::company::CompanyRequestRunner;
+pub use requests::job::JobRequestRunner;
+pub use requests::payment::PaymentRequestRunner;
+pub use requests::report::ReportRequestRunner;
+pub use requests::work_session::WorkSessionRequestRunner;
@@ -2,6 +2,7 @@
 
 use gig_log_common::models::{
     generic::MessageResponse,
+    locale::{LocalePreference, TimezonePreference},
     user::{
         ChangePasswordRequest, ConfirmEmailRequest, ForgotPasswordRequest, LogInRequest,
         RequestEmailChangeRequest, SetPasswordRequest, SignUpRequest, User,
@@ -269,4 +270,71 @@ impl AuthRequestRunner {
             .post("/auth/confirm-email-change", Some(request))
             .await
     }
+
+    /// Requests the language the current user's emails are sent in.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] containing the [`LocalePreference`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ClientError`] if the request fails.
+    pub async fn get_locale(&self) -> Result<LocalePreference, ClientError> {
+        self.client.get("/auth/me/locale").await
+    }
+
+    /// Sets the language the current user's emails are sent in.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` — Locale preference payload.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] containing the saved [`LocalePreference`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ClientError`] if the request fails.
+    pub async fn update_locale(
+        &self,
+        request: &LocalePreference,
+    ) -> Result<LocalePreference, ClientError> {
+        self.client.put("/auth/me/locale", request).await
+    }
+
+    /// Requests the timezone the current user's times are shown in.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] containing the [`TimezonePreference`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ClientError`] if the request fails.
+    pub async fn get_timezone(&self) -> Result<TimezonePreference, ClientError> {
+        self.client.get("/auth/me/timezone").await
+    }
+
+    /// Sets or clears the timezone the current user's times are shown in.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` — Timezone preference payload. A `None` timezone falls
+    ///   back to the browser's timezone.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] containing the saved [`TimezonePreference`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ClientError`] if the request fails.
+    pub async fn update_timezone(
+        &self,
+        request: &TimezonePreference,
+    ) -> Result<TimezonePreference, ClientError> {
+        self.client.put("/auth/me/timezone", request).await
+    }
 }
@@ -0,0 +1,50 @@
+//! Company request helpers for frontend API calls.
+
+use gig_log_common::models::company::Company;
+
+use crate::api_client::{client::ApiClient, error::ClientError};
+
+/// Executes company-related API requests.
+#[derive(Clone, Debug)]
+pub struct CompanyRequestRunner {
+    /// Stores the low-level API client used for requests.
+    client: ApiClient,
+}
+
+impl Default for CompanyRequestRunner {
+    /// Creates a new [`CompanyRequestRunner`] with [`CompanyRequestRunner::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CompanyRequestRunner {
+    /// Creates a new [`CompanyRequestRunner`].
+    ///
+    /// # Returns
+    ///
+    /// An initialized [`CompanyRequestRunner`].
+    pub fn new() -> Self {
+        Self {
+            client: ApiClient::new(),
+        }
+    }
+
+    /// Requests one of the current user's companies.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` — ID of the company.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] containing the [`Company`] on success. The budgets,
+    /// contracts, and contact in the response are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ClientError`] if the request fails.
+    pub async fn get(&self, id: &str) -> Result<Company, ClientError> {
+        self.client.get(&format!("/companies/{}", id)).await
+    }
+}
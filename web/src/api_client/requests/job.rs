@@ -0,0 +1,45 @@
+//! Job request helpers for frontend API calls.
+
+use gig_log_common::models::job::JobBoardResponse;
+
+use crate::api_client::{client::ApiClient, error::ClientError};
+
+/// Executes job-related API requests.
+#[derive(Clone, Debug)]
+pub struct JobRequestRunner {
+    /// Stores the low-level API client used for requests.
+    client: ApiClient,
+}
+
+impl Default for JobRequestRunner {
+    /// Creates a new [`JobRequestRunner`] with [`JobRequestRunner::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JobRequestRunner {
+    /// Creates a new [`JobRequestRunner`].
+    ///
+    /// # Returns
+    ///
+    /// An initialized [`JobRequestRunner`].
+    pub fn new() -> Self {
+        Self {
+            client: ApiClient::new(),
+        }
+    }
+
+    /// Requests the user's jobs grouped into board columns.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] containing the [`JobBoardResponse`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ClientError`] if the request fails.
+    pub async fn board(&self) -> Result<JobBoardResponse, ClientError> {
+        self.client.get("/jobs/board").await
+    }
+}
@@ -2,3 +2,13 @@
 
 /// Provides request helpers for authentication endpoints.
 pub mod auth;
+/// Provides request helpers for company endpoints.
+pub mod company;
+/// Provides request helpers for job endpoints.
+pub mod job;
+/// Provides request helpers for payment endpoints.
+pub mod payment;
+/// Provides request helpers for report endpoints.
+pub mod report;
+/// Provides request helpers for work session endpoints.
+pub mod work_session;
@@ -0,0 +1,66 @@
+//! Payment request helpers for frontend API calls.
+
+use gig_log_common::models::payment::PaymentReminderSetting;
+
+use crate::api_client::{client::ApiClient, error::ClientError};
+
+/// Executes payment-related API requests.
+#[derive(Clone, Debug)]
+pub struct PaymentRequestRunner {
+    /// Stores the low-level API client used for requests.
+    client: ApiClient,
+}
+
+impl Default for PaymentRequestRunner {
+    /// Creates a new [`PaymentRequestRunner`] with [`PaymentRequestRunner::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PaymentRequestRunner {
+    /// Creates a new [`PaymentRequestRunner`].
+    ///
+    /// # Returns
+    ///
+    /// An initialized [`PaymentRequestRunner`].
+    pub fn new() -> Self {
+        Self {
+            client: ApiClient::new(),
+        }
+    }
+
+    /// Requests whether payment reminders are emailed to the current user.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] containing the [`PaymentReminderSetting`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ClientError`] if the request fails.
+    pub async fn reminders(&self) -> Result<PaymentReminderSetting, ClientError> {
+        self.client.get("/payments/reminders").await
+    }
+
+    /// Turns payment reminder emails on or off for the current user.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` — Reminder setting payload.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] containing the saved [`PaymentReminderSetting`] on
+    /// success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ClientError`] if the request fails.
+    pub async fn update_reminders(
+        &self,
+        request: &PaymentReminderSetting,
+    ) -> Result<PaymentReminderSetting, ClientError> {
+        self.client.put("/payments/reminders", request).await
+    }
+}
@@ -0,0 +1,219 @@
+//! Report request helpers for frontend API calls.
+
+use gig_log_common::models::{
+    payment::OverduePaymentsResponse,
+    report::{EarningsPeriod, EarningsReportResponse, TaxEstimateResponse},
+    work_session::WorkSessionListResponse,
+};
+
+use crate::api_client::{client::ApiClient, error::ClientError};
+
+/// Executes report API requests and builds report export links.
+#[derive(Clone, Debug)]
+pub struct ReportRequestRunner {
+    /// Stores the low-level API client used for requests.
+    client: ApiClient,
+}
+
+impl Default for ReportRequestRunner {
+    /// Creates a new [`ReportRequestRunner`] with [`ReportRequestRunner::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReportRequestRunner {
+    /// Creates a new [`ReportRequestRunner`].
+    ///
+    /// # Returns
+    ///
+    /// An initialized [`ReportRequestRunner`].
+    pub fn new() -> Self {
+        Self {
+            client: ApiClient::new(),
+        }
+    }
+
+    /// Requests the earnings report.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` — Length of each reported period.
+    /// * `from` — Optional `YYYY-MM-DD` day in the first period.
+    /// * `to` — Optional `YYYY-MM-DD` day in the last period.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] containing the [`EarningsReportResponse`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ClientError`] if the request fails.
+    pub async fn earnings(
+        &self,
+        period: EarningsPeriod,
+        from: Option<&str>,
+        to: Option<&str>,
+    ) -> Result<EarningsReportResponse, ClientError> {
+        let period = match period {
+            EarningsPeriod::Weekly => "weekly",
+            EarningsPeriod::Monthly => "monthly",
+        };
+        let path = with_query(
+            "/reports/earnings",
+            &[("period", Some(period)), ("from", from), ("to", to)],
+        );
+
+        self.client.get(&path).await
+    }
+
+    /// Requests the quarterly tax estimate.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` — Optional calendar year. The API defaults to the current
+    ///   year.
+    /// * `quarter` — Optional quarter from 1 to 4. The API defaults to the
+    ///   current quarter.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] containing the [`TaxEstimateResponse`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ClientError`] if the request fails.
+    pub async fn tax_estimate(
+        &self,
+        year: Option<i32>,
+        quarter: Option<u32>,
+    ) -> Result<TaxEstimateResponse, ClientError> {
+        let year = year.map(|year| year.to_string());
+        let quarter = quarter.map(|quarter| quarter.to_string());
+        let path = with_query(
+            "/reports/tax-estimate",
+            &[("year", year.as_deref()), ("quarter", quarter.as_deref())],
+        );
+
+        self.client.get(&path).await
+    }
+
+    /// Requests worked time per day for the timesheet report.
+    ///
+    /// Only the per-day and overall totals are used, which cover every
+    /// matching session, so a single session is requested per page.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` — Optional `YYYY-MM-DD` first day to include.
+    /// * `to` — Optional `YYYY-MM-DD` last day to include.
+    /// * `job_id` — Optional ID of the job to restrict sessions to.
+    /// * `company_id` — Optional ID of the company to restrict sessions to.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] containing the [`WorkSessionListResponse`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ClientError`] if the request fails.
+    pub async fn timesheet(
+        &self,
+        from: Option<&str>,
+        to: Option<&str>,
+        job_id: Option<&str>,
+        company_id: Option<&str>,
+    ) -> Result<WorkSessionListResponse, ClientError> {
+        let path = with_query(
+            "/work-sessions",
+            &[
+                ("from", from),
+                ("to", to),
+                ("job_id", job_id),
+                ("company_id", company_id),
+                ("per_page", Some("1")),
+            ],
+        );
+
+        self.client.get(&path).await
+    }
+
+    /// Requests the overdue payments for the aging report.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] containing the [`OverduePaymentsResponse`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ClientError`] if the request fails.
+    pub async fn overdue_payments(&self) -> Result<OverduePaymentsResponse, ClientError> {
+        self.client.get("/payments/overdue").await
+    }
+
+    /// Builds the download link for the payments CSV export.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` — Optional `YYYY-MM-DD` first day to export.
+    /// * `to` — Optional `YYYY-MM-DD` last day to export.
+    ///
+    /// # Returns
+    ///
+    /// An absolute URL the browser can download the export from.
+    pub fn payments_export_url(&self, from: Option<&str>, to: Option<&str>) -> String {
+        self.client.build_url(&with_query(
+            "/payments/export.csv",
+            &[("from", from), ("to", to)],
+        ))
+    }
+
+    /// Builds the download link for the work sessions CSV export.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` — Optional `YYYY-MM-DD` first day to export.
+    /// * `to` — Optional `YYYY-MM-DD` last day to export.
+    ///
+    /// # Returns
+    ///
+    /// An absolute URL the browser can download the export from.
+    pub fn work_sessions_export_url(&self, from: Option<&str>, to: Option<&str>) -> String {
+        self.client.build_url(&with_query(
+            "/work-sessions/export.csv",
+            &[("from", from), ("to", to)],
+        ))
+    }
+}
+
+/// Appends the present query parameters to an API path.
+///
+/// Values are expected to be URL-safe, such as dates and numbers.
+///
+/// # Arguments
+///
+/// * `path` — API path without a query string.
+/// * `params` — Parameter names and optional values. Missing or blank
+///   values are left out.
+///
+/// # Returns
+///
+/// The path with a query string when any parameter is present.
+fn with_query(path: &str, params: &[(&str, Option<&str>)]) -> String {
+    let query = params
+        .iter()
+        .filter_map(|(name, value)| {
+            value
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(|value| format!("{}={}", name, value))
+        })
+        .collect::<Vec<_>>()
+        .join("&");
+
+    if query.is_empty() {
+        path.to_string()
+    } else {
+        format!("{}?{}", path, query)
+    }
+}
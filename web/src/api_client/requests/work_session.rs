@@ -0,0 +1,53 @@
+//! Work session request helpers for frontend API calls.
+
+use gig_log_common::models::work_session::{StartWorkSessionRequest, WorkSession};
+
+use crate::api_client::{client::ApiClient, error::ClientError};
+
+/// Executes work session API requests.
+#[derive(Clone, Debug)]
+pub struct WorkSessionRequestRunner {
+    /// Stores the low-level API client used for requests.
+    client: ApiClient,
+}
+
+impl Default for WorkSessionRequestRunner {
+    /// Creates a new [`WorkSessionRequestRunner`] with [`WorkSessionRequestRunner::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WorkSessionRequestRunner {
+    /// Creates a new [`WorkSessionRequestRunner`].
+    ///
+    /// # Returns
+    ///
+    /// An initialized [`WorkSessionRequestRunner`].
+    pub fn new() -> Self {
+        Self {
+            client: ApiClient::new(),
+        }
+    }
+
+    /// Starts a work session timer, or logs a session with explicit times.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` — Start work session request payload.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] containing the running or logged [`WorkSession`] on
+    /// success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ClientError`] if the request fails.
+    pub async fn start(
+        &self,
+        request: &StartWorkSessionRequest,
+    ) -> Result<WorkSession, ClientError> {
+        self.client.post("/work-sessions", Some(request)).await
+    }
+}
@@ -2,7 +2,10 @@
 
 use gig_log_frontend::{
     components::private_route::PrivateRoute,
-    contexts::{provide_auth_context, provide_mobile_context, provide_notification_context},
+    contexts::{
+        provide_auth_context, provide_connectivity_context, provide_mobile_context,
+        provide_notification_context,
+    },
     layouts::root::RootLayout,
     pages::*,
 };
@@ -14,16 +17,19 @@ use leptos_router::{
 
 /// Renders the root GigLog application router.
 ///
-/// Initializes shared contexts and registers all application routes.
+/// Initializes shared contexts and registers all application routes. The
+/// connectivity context is provided before the auth context so the auth
+/// API client reports request results to it.
 ///
 /// # Returns
 ///
 /// A Leptos view containing the application router tree.
 #[component]
 pub fn App() -> impl IntoView {
+    provide_notification_context();
+    provide_connectivity_context();
     provide_auth_context();
     provide_mobile_context();
-    provide_notification_context();
 
     view! {
         <Router>
@@ -64,6 +70,13 @@ pub fn App() -> impl IntoView {
                     <PrivateRoute path=path!("/payments/:id") view=PaymentDetailPage />
                     <PrivateRoute path=path!("/payments/:id/edit") view=PaymentEditPage />
 
+                    // Reports
+                    <PrivateRoute path=path!("/reports") view=ReportListPage />
+                    <PrivateRoute path=path!("/reports/earnings") view=EarningsReportPage />
+                    <PrivateRoute path=path!("/reports/tax-estimate") view=TaxEstimateReportPage />
+                    <PrivateRoute path=path!("/reports/timesheet") view=TimesheetReportPage />
+                    <PrivateRoute path=path!("/reports/aging") view=AgingReportPage />
+
                     // Settings
                     <PrivateRoute path=path!("/settings") view=SettingsPage />
                 </Routes>
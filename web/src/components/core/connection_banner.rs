@@ -0,0 +1,42 @@
+//! Banner shown while the API cannot be reached.
+
+use leptos::prelude::*;
+
+use crate::contexts::use_connectivity;
+
+/// Renders a banner while the browser is offline or the API is unreachable.
+///
+/// Also shows how many queued changes are waiting to be sent.
+///
+/// # Returns
+///
+/// A Leptos view containing the connection banner.
+#[component]
+pub fn ConnectionBanner() -> impl IntoView {
+    let connectivity = use_connectivity();
+
+    let message = move || {
+        if !connectivity.is_online.get() {
+            Some("You're offline. Changes will be sent when you reconnect.")
+        } else if !connectivity.is_api_reachable.get() {
+            Some("Can't reach GigLog. Retrying...")
+        } else {
+            None
+        }
+    };
+
+    let queued = move || match connectivity.queued_mutations.get() {
+        0 => String::new(),
+        1 => "1 change is waiting to be sent.".to_string(),
+        count => format!("{} changes are waiting to be sent.", count),
+    };
+
+    view! {
+        <Show when=move || message().is_some()>
+            <div class="connection-banner" role="status" aria-live="polite">
+                <p>{move || message().unwrap_or_default()}</p>
+                <p class="connection-banner__queued">{queued}</p>
+            </div>
+        </Show>
+    }
+}
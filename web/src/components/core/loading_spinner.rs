@@ -10,8 +10,8 @@ use leptos::prelude::*;
 #[component]
 pub fn LoadingSpinner() -> impl IntoView {
     view! {
-        <div class="loading">
-            <div class="loading__spinner"></div>
+        <div class="loading" role="status">
+            <div class="loading__spinner" aria-hidden="true"></div>
             <h3>Loading ...</h3>
         </div>
     }
@@ -2,16 +2,25 @@
 
 /// Provides the generic card container component.
 pub mod card;
+/// Provides the offline and unreachable-API banner.
+pub mod connection_banner;
 /// Provides the shared loading spinner component.
 pub mod loading_spinner;
+/// Provides the focus-trapping modal dialog.
+pub mod modal;
 /// Provides navigation bar and menu components.
 pub mod nav;
 /// Provides toast-style notification rendering.
 pub mod notifications;
 /// Provides authenticated route guards.
 pub mod private_route;
+/// Provides the floating start-timer action and job picker.
+pub mod timer_launcher;
 
 pub use card::Card;
+pub use connection_banner::ConnectionBanner;
 pub use loading_spinner::LoadingSpinner;
+pub use modal::Modal;
 pub use notifications::Notifications;
 pub use private_route::PrivateRoute;
+pub use timer_launcher::TimerLauncher;
@@ -0,0 +1,163 @@
+//! Modal dialog that keeps keyboard focus inside it while open.
+
+use leptos::{ev, html, prelude::*};
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+
+use crate::utils::class_name::ClassNameUtil;
+
+/// Selector matching the elements inside a dialog that can take focus.
+const FOCUSABLE_SELECTOR: &str = "a[href], button:not([disabled]), input:not([disabled]), \
+     select:not([disabled]), textarea:not([disabled]), [tabindex]:not([tabindex=\"-1\"])";
+
+/// Renders a modal dialog over the page while `is_open` is `true`.
+///
+/// Opening the dialog moves focus to its first focusable element, `Tab`
+/// and `Shift+Tab` cycle through its focusable elements, and closing it
+/// returns focus to the element that was focused before it opened.
+/// Pressing `Escape` or clicking the backdrop closes it.
+///
+/// # Arguments
+///
+/// * `class` — Optional additional CSS class names for the dialog.
+/// * `label` — Accessible name announced for the dialog.
+/// * `is_open` — Signal controlling whether the dialog is shown.
+/// * `children` — Content rendered inside the dialog.
+///
+/// # Returns
+///
+/// A Leptos view containing the backdrop and dialog while open.
+#[component]
+pub fn Modal(
+    #[prop(optional, into)] class: Option<String>,
+    #[prop(into)] label: String,
+    is_open: RwSignal<bool>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    // Classes
+    let class_name = ClassNameUtil::new("modal", class);
+    let modal = class_name.get_root_class();
+    let overlay = class_name.get_sub_class("overlay");
+
+    // State
+    let dialog_ref = NodeRef::<html::Div>::new();
+
+    // Effects
+    Effect::new(move |opener: Option<Option<HtmlElement>>| {
+        if !is_open.get() {
+            if let Some(Some(opener)) = opener {
+                let _ = opener.focus();
+            }
+
+            return None;
+        }
+
+        // The effect re-runs once the dialog mounts, so keep the element
+        // captured when it first opened.
+        let opener = opener.flatten().or_else(active_element);
+
+        if let Some(dialog) = dialog_ref.get() {
+            match focusable_elements(&dialog).first() {
+                Some(first) => {
+                    let _ = first.focus();
+                }
+                None => {
+                    let _ = dialog.focus();
+                }
+            }
+        }
+
+        opener
+    });
+
+    // Event Handlers
+    let on_keydown = move |event: ev::KeyboardEvent| match event.key().as_str() {
+        "Escape" => {
+            event.prevent_default();
+            is_open.set(false);
+        }
+        "Tab" => {
+            let Some(dialog) = dialog_ref.get_untracked() else {
+                return;
+            };
+
+            let focusable = focusable_elements(&dialog);
+            let (Some(first), Some(last)) = (focusable.first(), focusable.last()) else {
+                event.prevent_default();
+                return;
+            };
+
+            let active = active_element();
+            let position = focusable
+                .iter()
+                .position(|element| Some(element) == active.as_ref());
+
+            match (event.shift_key(), position) {
+                (true, None) | (true, Some(0)) => {
+                    event.prevent_default();
+                    let _ = last.focus();
+                }
+                (false, None) => {
+                    event.prevent_default();
+                    let _ = first.focus();
+                }
+                (false, Some(index)) if index == focusable.len() - 1 => {
+                    event.prevent_default();
+                    let _ = first.focus();
+                }
+                _ => {}
+            }
+        }
+        _ => {}
+    };
+
+    view! {
+        <Show when=move || is_open.get()>
+            <div class=overlay.clone() on:click=move |_| is_open.set(false)>
+                <div
+                    node_ref=dialog_ref
+                    class=modal.clone()
+                    role="dialog"
+                    aria-modal="true"
+                    aria-label=label.clone()
+                    tabindex="-1"
+                    on:click=|event| event.stop_propagation()
+                    on:keydown=on_keydown
+                >
+                    {children()}
+                </div>
+            </div>
+        </Show>
+    }
+}
+
+/// Returns the element that currently has keyboard focus.
+///
+/// # Returns
+///
+/// An optional [`HtmlElement`] with focus.
+fn active_element() -> Option<HtmlElement> {
+    document()
+        .active_element()
+        .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+}
+
+/// Collects the focusable elements inside a dialog in tab order.
+///
+/// # Arguments
+///
+/// * `dialog` — Dialog element to search.
+///
+/// # Returns
+///
+/// A [`Vec`] of focusable [`HtmlElement`] values in document order.
+fn focusable_elements(dialog: &HtmlElement) -> Vec<HtmlElement> {
+    let Ok(nodes) = dialog.query_selector_all(FOCUSABLE_SELECTOR) else {
+        return Vec::new();
+    };
+
+    (0..nodes.length())
+        .filter_map(|index| nodes.get(index))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .collect()
+}
@@ -5,7 +5,7 @@ use leptos_router::components::A;
 
 use crate::{
     components::{
-        CompanyIcon, HamburgerIcon, HomeIcon, JobIcon, LogoIcon, PaymentIcon,
+        CompanyIcon, HamburgerIcon, HomeIcon, JobIcon, LogoIcon, PaymentIcon, ReportIcon,
         nav::menu::{NavItem, NavMenu},
         settings::SettingsIcon,
     },
@@ -29,6 +29,7 @@ pub fn NavBar() -> impl IntoView {
         NavItem::new("Companies", "/companies", CompanyIcon.into()),
         NavItem::new("Jobs", "/jobs", JobIcon.into()),
         NavItem::new("Payments", "/payments", PaymentIcon.into()),
+        NavItem::new("Reports", "/reports", ReportIcon.into()),
         NavItem::new("Settings", "/settings", SettingsIcon.into()),
     ];
 
@@ -50,7 +51,7 @@ pub fn NavBar() -> impl IntoView {
             on:mouseleave=move |_| is_active.set(false)
         >
             <div class="nav-bar__top-row">
-                <A href="/dashboard" on:click=close_menu>
+                <A href="/dashboard" attr:aria-label="GigLog dashboard" on:click=close_menu>
                     <div class="nav-bar__logo">
                         <LogoIcon />
                         <Show when=when_show_text>
@@ -62,6 +63,8 @@ pub fn NavBar() -> impl IntoView {
                     type="button"
                     class="nav-bar__menu-button"
                     aria-label="Toggle navigation menu"
+                    aria-controls="nav-menu"
+                    aria-expanded=move || is_mobile_menu_open.get().to_string()
                     on:click=toggle_mobile_menu
                 >
                     <HamburgerIcon />
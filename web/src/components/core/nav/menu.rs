@@ -67,7 +67,7 @@ pub fn NavMenu(
     };
 
     view! {
-        <div class="nav-menu">
+        <div id="nav-menu" class="nav-menu">
             <>
                 {items
                     .into_iter()
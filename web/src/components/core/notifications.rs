@@ -17,7 +17,7 @@ pub fn Notifications() -> impl IntoView {
     let notifications = use_notifications();
 
     view! {
-        <div class="notifications">
+        <div class="notifications" aria-live="polite">
             <For each=move || notifications.notifications.get() key=|n| n.id let(notification)>
                 {
                     let id = notification.id;
@@ -51,7 +51,9 @@ pub fn Notifications() -> impl IntoView {
                                 <p>{notification.message}</p>
                             </div>
                             <button
+                                type="button"
                                 class="notification__close"
+                                aria-label="Dismiss notification"
                                 on:click=move |_| notifications.dismiss(id)
                             >
                                 <CloseIcon />
@@ -0,0 +1,307 @@
+//! Floating action for starting a work session timer from any page.
+
+use gig_log_common::models::{
+    job::{Job, JobBoardColumn},
+    work_session::StartWorkSessionRequest,
+};
+use leptos::{ev, prelude::*, reactive::spawn_local};
+
+use crate::{
+    api_client::{ClientError, JobRequestRunner, WorkSessionRequestRunner},
+    components::{Modal, PlayIcon},
+    contexts::{use_connectivity, use_notifications},
+    utils::fuzzy::FuzzyUtil,
+};
+
+/// Time allowed between the `n` and `t` keys of the shortcut.
+const SHORTCUT_TIMEOUT_MS: u32 = 1_000;
+/// Largest number of jobs listed in the picker.
+const MAX_RESULTS: usize = 8;
+/// Element ID of the picker's result list.
+const RESULTS_ID: &str = "timer-launcher-results";
+
+/// Renders a floating "start timer" button and its job picker.
+///
+/// The picker also opens when `n` then `t` is pressed outside a text
+/// field. It lists jobs that have not been paid, filtered by a fuzzy
+/// search on the job title, and starts a timer for the chosen job. Starting
+/// a timer goes through the connectivity mutation queue, so a timer started
+/// while offline is sent once the API is reachable again. The search field
+/// works as a combobox: arrow keys move through the results and `Enter`
+/// starts the highlighted one.
+///
+/// # Returns
+///
+/// A Leptos view containing the floating button and picker dialog.
+#[component]
+pub fn TimerLauncher() -> impl IntoView {
+    // Context
+    let connectivity = use_connectivity();
+    let notifications = use_notifications();
+
+    // State
+    let work_sessions = StoredValue::new_local(WorkSessionRequestRunner::new());
+    let is_open = RwSignal::new(false);
+    let awaiting_second_key = RwSignal::new(false);
+    let query = RwSignal::new(String::new());
+    let highlighted = RwSignal::new(0_usize);
+    let jobs = RwSignal::new(None::<Result<Vec<Job>, ClientError>>);
+
+    let matches = Signal::derive(move || {
+        let jobs = jobs.get().and_then(Result::ok).unwrap_or_default();
+        let mut matches = FuzzyUtil::rank(&query.get(), jobs, |job| job.title.as_str());
+
+        matches.truncate(MAX_RESULTS);
+        matches
+    });
+
+    // Actions
+    let open = move || {
+        query.set(String::new());
+        highlighted.set(0);
+        jobs.set(None);
+        is_open.set(true);
+
+        spawn_local(async move {
+            let result = JobRequestRunner::new().board().await.map(|board| {
+                board
+                    .columns
+                    .into_iter()
+                    .filter(|group| group.column != JobBoardColumn::Paid)
+                    .flat_map(|group| group.jobs)
+                    .collect::<Vec<_>>()
+            });
+
+            jobs.set(Some(result));
+        });
+    };
+
+    let start_timer = move |job: Job| {
+        let request = StartWorkSessionRequest {
+            job_id: job.id,
+            location: None,
+            start_time: None,
+            end_time: None,
+            accumulated_paused_duration: None,
+            notes: None,
+            tags: Vec::new(),
+        };
+
+        let work_sessions = work_sessions.get_value();
+
+        if !untrack(|| connectivity.is_connected()) {
+            notifications.show_info(
+                "Timer queued",
+                format!("The timer for {} will start when you reconnect.", job.title),
+            );
+        }
+
+        connectivity.queue_mutation("Failed to start timer", move || {
+            let work_sessions = work_sessions.clone();
+            let request = request.clone();
+            let title = job.title.clone();
+
+            async move {
+                let result = work_sessions.start(&request).await;
+
+                if result.is_ok() {
+                    notifications
+                        .show_success("Timer started", format!("Tracking time for {}.", title));
+                }
+
+                result
+            }
+        });
+
+        is_open.set(false);
+    };
+
+    // Effects
+    let shortcut = window_event_listener(ev::keydown, move |event| {
+        if is_open.get_untracked()
+            || event.ctrl_key()
+            || event.meta_key()
+            || event.alt_key()
+            || is_typing()
+        {
+            return;
+        }
+
+        match event.key().as_str() {
+            "t" if awaiting_second_key.get_untracked() => {
+                event.prevent_default();
+                awaiting_second_key.set(false);
+                open();
+            }
+            "n" => {
+                awaiting_second_key.set(true);
+
+                gloo_timers::callback::Timeout::new(SHORTCUT_TIMEOUT_MS, move || {
+                    awaiting_second_key.set(false);
+                })
+                .forget();
+            }
+            _ => awaiting_second_key.set(false),
+        }
+    });
+
+    on_cleanup(move || shortcut.remove());
+
+    // Event Handlers
+    let on_input = move |event| {
+        query.set(event_target_value(&event));
+        highlighted.set(0);
+    };
+
+    let on_keydown = move |event: ev::KeyboardEvent| {
+        let count = matches.with_untracked(Vec::len);
+
+        match event.key().as_str() {
+            "ArrowDown" if count > 0 => {
+                event.prevent_default();
+                highlighted.update(|index| *index = (*index + 1) % count);
+            }
+            "ArrowUp" if count > 0 => {
+                event.prevent_default();
+                highlighted.update(|index| *index = (*index + count - 1) % count);
+            }
+            "Enter" => {
+                event.prevent_default();
+
+                let job = matches
+                    .with_untracked(|matches| matches.get(highlighted.get_untracked()).cloned());
+
+                if let Some(job) = job {
+                    start_timer(job);
+                }
+            }
+            _ => {}
+        }
+    };
+
+    view! {
+        <div class="timer-launcher">
+            <button
+                type="button"
+                class="timer-launcher__button"
+                aria-label="Start timer"
+                aria-haspopup="dialog"
+                aria-expanded=move || is_open.get().to_string()
+                title="Start timer (n t)"
+                on:click=move |_| open()
+            >
+                <PlayIcon />
+            </button>
+            <Modal class="timer-launcher__dialog" label="Start timer" is_open=is_open>
+                <input
+                    type="text"
+                    placeholder="Search jobs"
+                    role="combobox"
+                    aria-label="Search jobs"
+                    aria-autocomplete="list"
+                    aria-expanded="true"
+                    aria-controls=RESULTS_ID
+                    aria-activedescendant=move || {
+                        matches
+                            .with(|matches| !matches.is_empty())
+                            .then(|| result_id(highlighted.get()))
+                    }
+                    prop:value=query
+                    on:input=on_input
+                    on:keydown=on_keydown
+                />
+                {move || match jobs.get() {
+                    None => {
+                        view! {
+                            <p class="timer-launcher__status" role="status">
+                                "Loading jobs..."
+                            </p>
+                        }
+                            .into_any()
+                    }
+                    Some(Err(error)) => {
+                        view! {
+                            <p class="timer-launcher__status" role="status">
+                                {error.to_string()}
+                            </p>
+                        }
+                            .into_any()
+                    }
+                    Some(Ok(_)) if matches.with(Vec::is_empty) => {
+                        view! {
+                            <p class="timer-launcher__status" role="status">
+                                "No matching jobs"
+                            </p>
+                        }
+                            .into_any()
+                    }
+                    Some(Ok(_)) => {
+                        view! {
+                            <ul
+                                id=RESULTS_ID
+                                class="timer-launcher__results"
+                                role="listbox"
+                                aria-label="Jobs"
+                            >
+                                {matches
+                                    .get()
+                                    .into_iter()
+                                    .enumerate()
+                                    .map(|(index, job)| {
+                                        let title = job.title.clone();
+                                        let is_highlighted = move || highlighted.get() == index;
+                                        let class = move || {
+                                            if is_highlighted() {
+                                                "timer-launcher__result timer-launcher__result--highlighted"
+                                            } else {
+                                                "timer-launcher__result"
+                                            }
+                                        };
+
+                                        view! {
+                                            <li
+                                                id=result_id(index)
+                                                class=class
+                                                role="option"
+                                                aria-selected=move || is_highlighted().to_string()
+                                                on:mouseenter=move |_| highlighted.set(index)
+                                                on:click=move |_| start_timer(job.clone())
+                                            >
+                                                {title}
+                                            </li>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </ul>
+                        }
+                            .into_any()
+                    }
+                }}
+            </Modal>
+        </div>
+    }
+}
+
+/// Builds the element ID of a result in the picker.
+///
+/// # Arguments
+///
+/// * `index` — Position of the result in the list.
+///
+/// # Returns
+///
+/// A [`String`] ID referenced by the search field's active descendant.
+fn result_id(index: usize) -> String {
+    format!("{}-{}", RESULTS_ID, index)
+}
+
+/// Returns whether keyboard focus is in a text field.
+///
+/// # Returns
+///
+/// A [`bool`] indicating whether the focused element accepts typing.
+fn is_typing() -> bool {
+    document().active_element().is_some_and(|element| {
+        matches!(element.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT")
+    })
+}
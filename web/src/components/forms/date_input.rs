@@ -0,0 +1,50 @@
+//! Styled date input component.
+
+use leptos::prelude::*;
+
+use crate::utils::class_name::ClassNameUtil;
+
+/// Renders a native date picker bound to a `YYYY-MM-DD` string signal.
+///
+/// An empty value means no date is selected.
+///
+/// # Arguments
+///
+/// * `class` — Optional additional CSS class names.
+/// * `label` — Optional field label text.
+/// * `name` — Field name for the input.
+/// * `value` — Signal containing the selected date.
+///
+/// # Returns
+///
+/// A Leptos view containing the date input field.
+#[component]
+pub fn DateInput(
+    #[prop(optional, into)] class: Option<String>,
+    #[prop(optional, into)] label: Option<String>,
+    #[prop(into)] name: String,
+    value: RwSignal<String>,
+) -> impl IntoView {
+    // Classes
+    let class_name = ClassNameUtil::new_with_parent("text-input", "date-input", class);
+    let date_input = class_name.get_root_class_with_parent();
+
+    // Variables
+    let has_label = label.is_some();
+    let aria_label = label.clone();
+
+    view! {
+        <div class=date_input>
+            <Show when=move || has_label>
+                <label>{label.clone().unwrap_or_default()}</label>
+            </Show>
+            <input
+                name=name
+                aria-label=aria_label
+                type="date"
+                prop:value=value
+                on:change=move |e| value.set(event_target_value(&e))
+            />
+        </div>
+    }
+}
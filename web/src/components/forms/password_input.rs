@@ -48,6 +48,7 @@ pub fn PasswordInput(
 
     // Variables
     let has_label = label.is_some();
+    let aria_label = label.clone().or_else(|| placeholder.clone());
 
     // Event Handlers
     let field = name.clone();
@@ -80,6 +81,8 @@ pub fn PasswordInput(
             <div class="password-input__field">
                 <input
                     name=name.clone()
+                    aria-label=aria_label
+                    aria-invalid=move || validation.has_error.get().to_string()
                     type=input_type
                     placeholder=placeholder.unwrap_or_default()
                     prop:value=value
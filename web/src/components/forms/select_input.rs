@@ -66,6 +66,7 @@ where
 
     // Variables
     let has_label = label.is_some();
+    let aria_label = label.clone().or_else(|| placeholder.clone());
     let has_placeholder = placeholder.is_some();
     let placeholder_label = placeholder.unwrap_or_default();
 
@@ -99,6 +100,7 @@ where
             </Show>
             <div class="select-input__field">
                 <select
+                    aria-label=aria_label
                     prop:value=move || {
                         selected_option
                             .get()
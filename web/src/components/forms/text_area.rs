@@ -43,6 +43,7 @@ pub fn TextArea(
 
     // Variables
     let has_label = label.is_some();
+    let aria_label = label.clone().or_else(|| placeholder.clone());
 
     // Event Handlers
     let field = name.clone();
@@ -60,6 +61,8 @@ pub fn TextArea(
             </Show>
             <textarea
                 name=name.clone()
+                aria-label=aria_label
+                aria-invalid=move || validation.has_error.get().to_string()
                 rows=rows
                 placeholder=placeholder.unwrap_or_default()
                 prop:value=value
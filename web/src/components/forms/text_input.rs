@@ -42,6 +42,7 @@ pub fn TextInput(
 
     // Variables
     let has_label = label.is_some();
+    let aria_label = label.clone().or_else(|| placeholder.clone());
 
     let input_type = match is_password {
         true => "password",
@@ -64,6 +65,8 @@ pub fn TextInput(
             </Show>
             <input
                 name=name.clone()
+                aria-label=aria_label
+                aria-invalid=move || validation.has_error.get().to_string()
                 type=input_type
                 placeholder=placeholder.unwrap_or_default()
                 prop:value=value
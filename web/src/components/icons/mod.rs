@@ -24,6 +24,10 @@ pub mod log_out;
 pub mod logo;
 /// Provides the payments icon component.
 pub mod payment;
+/// Provides the play icon component.
+pub mod play;
+/// Provides the reports icon component.
+pub mod report;
 /// Provides the settings icon component.
 pub mod settings;
 /// Provides the success icon component.
@@ -43,5 +47,7 @@ pub use job::JobIcon;
 pub use log_out::LogOutIcon;
 pub use logo::LogoIcon;
 pub use payment::PaymentIcon;
+pub use play::PlayIcon;
+pub use report::ReportIcon;
 pub use success::SuccessIcon;
 pub use warning::WarningIcon;
@@ -0,0 +1,23 @@
+//! Icon component for `PlayIcon`.
+
+use leptos::prelude::*;
+
+/// Renders the `PlayIcon` component.
+///
+/// # Returns
+///
+/// A Leptos view for the `PlayIcon` UI.
+#[component]
+pub fn PlayIcon() -> impl IntoView {
+    view! {
+        <svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 512 512" width="24" height="24">
+            <path
+                d="M112 111v290c0 17.44 17 28.52 31 20.16l247.9-148.37c12.12-7.25 12.12-26.33 0-33.58L143 92.84c-14-8.36-31 2.72-31 18.16z"
+                fill="none"
+                stroke="currentColor"
+                stroke-miterlimit="10"
+                stroke-width="32"
+            />
+        </svg>
+    }
+}
@@ -0,0 +1,32 @@
+//! Icon component for `ReportIcon`.
+
+use leptos::prelude::*;
+
+/// Renders the `ReportIcon` component.
+///
+/// # Returns
+///
+/// A Leptos view for the `ReportIcon` UI.
+#[component]
+pub fn ReportIcon() -> impl IntoView {
+    view! {
+        <svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 512 512" width="24" height="24">
+            <path
+                fill="none"
+                stroke="currentColor"
+                stroke-linecap="round"
+                stroke-linejoin="round"
+                stroke-width="32"
+                d="M64 64v384h384"
+            />
+            <path
+                fill="none"
+                stroke="currentColor"
+                stroke-linecap="round"
+                stroke-linejoin="round"
+                stroke-width="32"
+                d="M144 320v64M240 224v160M336 272v112M432 160v224"
+            />
+        </svg>
+    }
+}
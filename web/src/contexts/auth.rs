@@ -9,7 +9,10 @@ use gig_log_common::models::{
 };
 use leptos::{prelude::*, reactive::spawn_local};
 
-use crate::api_client::{AuthRequestRunner, ClientError};
+use crate::{
+    api_client::{AuthRequestRunner, ClientError},
+    utils::date_time::DateTimeUtil,
+};
 
 /// Stores authentication state and auth API helper methods.
 #[derive(Debug, Clone)]
@@ -48,12 +51,14 @@ impl AuthContext {
     /// Checks auth status by requesting the current user.
     ///
     /// Updates [`Self::user`] and [`Self::loading`] based on the request
-    /// result.
+    /// result. The user's timezone preference is loaded before loading
+    /// ends, so pages render times in it.
     pub async fn check_auth(&self) {
         self.loading.set(true);
 
         match self.auth_requests.get_me().await {
             Ok(user) => {
+                self.load_timezone().await;
                 self.user.set(Some(user));
             }
             Err(_) => {
@@ -79,6 +84,7 @@ impl AuthContext {
     /// Returns a [`ClientError`] if the login request fails.
     pub async fn login(&self, request: &LogInRequest) -> Result<User, ClientError> {
         let user = self.auth_requests.log_in(request).await?;
+        self.load_timezone().await;
         self.user.set(Some(user.clone()));
         Ok(user)
     }
@@ -191,6 +197,7 @@ impl AuthContext {
     /// Returns a [`ClientError`] if the logout request fails.
     pub async fn logout(&self) -> Result<(), ClientError> {
         self.auth_requests.log_out().await?;
+        DateTimeUtil::set_timezone(None);
         self.user.set(None);
         Ok(())
     }
@@ -209,6 +216,21 @@ impl AuthContext {
         self.user.set(Some(user));
         Ok(())
     }
+
+    /// Applies the current user's timezone preference to date formatting.
+    ///
+    /// Times fall back to the browser's timezone if the preference is
+    /// unset or cannot be loaded.
+    async fn load_timezone(&self) {
+        let timezone = self
+            .auth_requests
+            .get_timezone()
+            .await
+            .ok()
+            .and_then(|preference| preference.timezone);
+
+        DateTimeUtil::set_timezone(timezone);
+    }
 }
 
 /// Provides and initializes the shared [`AuthContext`].
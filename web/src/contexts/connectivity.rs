@@ -0,0 +1,271 @@
+//! Connectivity context tracking browser and API reachability.
+
+use std::{cell::RefCell, collections::VecDeque, future::Future, pin::Pin, rc::Rc};
+
+use gig_log_common::models::health::HealthCheckResponse;
+use gloo_timers::future::TimeoutFuture;
+use leptos::{prelude::*, reactive::spawn_local};
+use web_sys::window;
+
+use crate::{
+    api_client::{ApiClient, ClientError},
+    contexts::{NotificationContext, use_notifications},
+};
+
+/// Delay before the first reconnection attempt.
+const INITIAL_RETRY_DELAY_MS: u32 = 1_000;
+/// Longest delay between reconnection attempts.
+const MAX_RETRY_DELAY_MS: u32 = 30_000;
+
+/// The request future of a queued mutation.
+type MutationFuture = Pin<Box<dyn Future<Output = Result<(), ClientError>>>>;
+/// A mutation waiting to be sent, re-run until it reaches the API.
+type QueuedMutation = Rc<dyn Fn() -> MutationFuture>;
+
+thread_local! {
+    static MUTATION_QUEUE: RefCell<VecDeque<QueuedMutation>> = RefCell::new(VecDeque::new());
+}
+
+/// Stores whether the API can be reached and the queue of unsent
+/// mutations.
+#[derive(Debug, Clone, Copy)]
+pub struct ConnectivityContext {
+    /// Stores whether the browser reports a network connection.
+    pub is_online: RwSignal<bool>,
+    /// Stores whether the latest API request reached the server.
+    pub is_api_reachable: RwSignal<bool>,
+    /// Stores the number of mutations waiting to be sent.
+    pub queued_mutations: RwSignal<usize>,
+    /// Stores whether reconnection attempts are running.
+    is_reconnecting: RwSignal<bool>,
+    /// Stores whether queued mutations are being sent.
+    is_flushing: RwSignal<bool>,
+    /// Stores the notification context used to report failed mutations.
+    notifications: NotificationContext,
+}
+
+impl ConnectivityContext {
+    /// Creates a new [`ConnectivityContext`].
+    ///
+    /// # Arguments
+    ///
+    /// * `is_online` — Initial browser network status.
+    /// * `notifications` — Notification context used to report queued
+    ///   mutations the API rejects.
+    ///
+    /// # Returns
+    ///
+    /// An initialized [`ConnectivityContext`] that assumes the API is
+    /// reachable.
+    pub fn new(is_online: bool, notifications: NotificationContext) -> Self {
+        Self {
+            is_online: RwSignal::new(is_online),
+            is_api_reachable: RwSignal::new(true),
+            queued_mutations: RwSignal::new(0),
+            is_reconnecting: RwSignal::new(false),
+            is_flushing: RwSignal::new(false),
+            notifications,
+        }
+    }
+
+    /// Returns whether requests can currently reach the API.
+    ///
+    /// Polling should be skipped while this is `false`. It resumes once
+    /// reconnection succeeds.
+    ///
+    /// # Returns
+    ///
+    /// A [`bool`] indicating whether the browser is online and the API is
+    /// reachable.
+    pub fn is_connected(&self) -> bool {
+        self.is_online.get() && self.is_api_reachable.get()
+    }
+
+    /// Records whether an API request reached the server.
+    ///
+    /// A network failure marks the API unreachable and starts reconnection
+    /// attempts. Any response, including an error response, marks it
+    /// reachable and sends queued mutations.
+    ///
+    /// # Arguments
+    ///
+    /// * `result` — Result of sending the request.
+    pub fn record_request<T>(&self, result: &Result<T, ClientError>) {
+        match result {
+            Err(ClientError::Network(_)) => self.mark_unreachable(),
+            _ => self.mark_reachable(),
+        }
+    }
+
+    /// Queues a mutation to be sent once the API is reachable.
+    ///
+    /// Mutations are sent in the order they were queued. A mutation that
+    /// fails with a network error stays queued and is retried after
+    /// reconnecting. One the API rejects is dropped and reported with an
+    /// error notification.
+    ///
+    /// # Arguments
+    ///
+    /// * `failure_title` — Error toast title used if the API rejects the
+    ///   mutation.
+    /// * `mutation` — Creates the request future. Called again on each
+    ///   retry.
+    pub fn queue_mutation<T, RequestFuture>(
+        &self,
+        failure_title: impl Into<String>,
+        mutation: impl Fn() -> RequestFuture + 'static,
+    ) where
+        T: 'static,
+        RequestFuture: Future<Output = Result<T, ClientError>> + 'static,
+    {
+        let failure_title = failure_title.into();
+        let notifications = self.notifications;
+
+        let mutation: QueuedMutation = Rc::new(move || -> MutationFuture {
+            let request = mutation();
+            let failure_title = failure_title.clone();
+
+            Box::pin(async move {
+                match request.await {
+                    Err(ClientError::Api(api_error)) => {
+                        notifications.show_error(failure_title, api_error.message);
+                        Ok(())
+                    }
+                    result => result.map(|_| ()),
+                }
+            })
+        });
+
+        MUTATION_QUEUE.with(|queue| queue.borrow_mut().push_back(mutation));
+        self.queued_mutations.update(|count| *count += 1);
+        self.flush_queue();
+    }
+
+    /// Marks the API reachable and sends queued mutations.
+    fn mark_reachable(&self) {
+        if !self.is_api_reachable.get_untracked() {
+            self.is_api_reachable.set(true);
+        }
+
+        self.flush_queue();
+    }
+
+    /// Marks the API unreachable and starts reconnection attempts.
+    fn mark_unreachable(&self) {
+        if self.is_api_reachable.get_untracked() {
+            self.is_api_reachable.set(false);
+        }
+
+        self.reconnect();
+    }
+
+    /// Checks API health with exponential backoff until it responds.
+    fn reconnect(&self) {
+        if self.is_reconnecting.get_untracked() {
+            return;
+        }
+
+        self.is_reconnecting.set(true);
+
+        let connectivity = *self;
+        let client = ApiClient::untracked();
+
+        spawn_local(async move {
+            let mut delay = INITIAL_RETRY_DELAY_MS;
+
+            loop {
+                TimeoutFuture::new(delay).await;
+
+                if connectivity.is_online.get_untracked()
+                    && client.get::<HealthCheckResponse>("/health").await.is_ok()
+                {
+                    break;
+                }
+
+                delay = (delay * 2).min(MAX_RETRY_DELAY_MS);
+            }
+
+            connectivity.is_reconnecting.set(false);
+            connectivity.mark_reachable();
+        });
+    }
+
+    /// Sends queued mutations in order while the API is reachable.
+    fn flush_queue(&self) {
+        let is_connected = self.is_online.get_untracked() && self.is_api_reachable.get_untracked();
+        let is_empty = MUTATION_QUEUE.with(|queue| queue.borrow().is_empty());
+
+        if self.is_flushing.get_untracked() || !is_connected || is_empty {
+            return;
+        }
+
+        self.is_flushing.set(true);
+
+        let connectivity = *self;
+
+        spawn_local(async move {
+            while let Some(mutation) = MUTATION_QUEUE.with(|queue| queue.borrow().front().cloned())
+            {
+                if let Err(ClientError::Network(_)) = mutation().await {
+                    connectivity.mark_unreachable();
+                    break;
+                }
+
+                MUTATION_QUEUE.with(|queue| queue.borrow_mut().pop_front());
+                connectivity
+                    .queued_mutations
+                    .update(|count| *count = count.saturating_sub(1));
+            }
+
+            connectivity.is_flushing.set(false);
+        });
+    }
+}
+
+/// Provides the shared connectivity context and registers browser network
+/// listeners.
+///
+/// Must be called after [`provide_notification_context`](super::provide_notification_context)
+/// and before any API client is created, so clients can report request
+/// results.
+///
+/// # Returns
+///
+/// The created [`ConnectivityContext`] inserted into Leptos context.
+pub fn provide_connectivity_context() -> ConnectivityContext {
+    let is_online = window().is_none_or(|window| window.navigator().on_line());
+
+    let ctx = ConnectivityContext::new(is_online, use_notifications());
+    provide_context(ctx);
+
+    let on_online = window_event_listener_untyped("online", move |_| {
+        ctx.is_online.set(true);
+
+        if ctx.is_api_reachable.get_untracked() {
+            ctx.flush_queue();
+        } else {
+            ctx.reconnect();
+        }
+    });
+    let on_offline = window_event_listener_untyped("offline", move |_| {
+        ctx.is_online.set(false);
+    });
+
+    on_cleanup(move || {
+        on_online.remove();
+        on_offline.remove();
+    });
+
+    ctx
+}
+
+/// Retrieves the shared connectivity context.
+///
+/// # Returns
+///
+/// The current [`ConnectivityContext`] from Leptos context.
+pub fn use_connectivity() -> ConnectivityContext {
+    use_context::<ConnectivityContext>().expect(
+        "ConnectivityContext not provided. Wrap your app with provide_connectivity_context()",
+    )
+}
@@ -2,11 +2,14 @@
 
 /// Provides authentication state and auth actions.
 pub mod auth;
+/// Provides browser and API connectivity state and the mutation queue.
+pub mod connectivity;
 /// Provides viewport/mobile state derived from media queries.
 pub mod mobile;
 /// Provides notification state and helpers.
 pub mod notification;
 
 pub use auth::*;
+pub use connectivity::*;
 pub use mobile::*;
 pub use notification::*;
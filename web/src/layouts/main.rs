@@ -1,11 +1,18 @@
 //! Layout wrapper for authenticated application pages.
 
-use leptos::prelude::*;
+use leptos::{ev, html, prelude::*};
 
-use crate::{components::nav::bar::NavBar, utils::class_name::ClassNameUtil};
+use crate::{
+    components::{TimerLauncher, nav::bar::NavBar},
+    utils::class_name::ClassNameUtil,
+};
 
 /// Renders the primary app layout with navigation and content area.
 ///
+/// Also renders the floating start-timer action, so a timer can be
+/// started from any authenticated page, and a skip link that moves
+/// keyboard focus past the navigation to the page content.
+///
 /// # Arguments
 ///
 /// * `class` — Optional additional CSS classes for the content container.
@@ -24,11 +31,30 @@ pub fn MainLayout(
 
     let main_layout = class_name.get_root_class();
     let content = class_name.get_content_class();
+    let skip_link = class_name.get_sub_class("skip-link");
+
+    // State
+    let content_ref = NodeRef::<html::Div>::new();
+
+    // Event Handlers
+    let skip_to_content = move |event: ev::MouseEvent| {
+        event.prevent_default();
+
+        if let Some(content) = content_ref.get_untracked() {
+            let _ = content.focus();
+        }
+    };
 
     view! {
         <div class=main_layout>
+            <a class=skip_link href="#main-content" on:click=skip_to_content>
+                "Skip to main content"
+            </a>
             <NavBar />
-            <div class=content>{children()}</div>
+            <div node_ref=content_ref id="main-content" class=content tabindex="-1">
+                {children()}
+            </div>
+            <TimerLauncher />
         </div>
     }
 }
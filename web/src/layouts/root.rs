@@ -2,7 +2,10 @@
 
 use leptos::prelude::*;
 
-use crate::{components::notifications::Notifications, utils::class_name::ClassNameUtil};
+use crate::{
+    components::{ConnectionBanner, notifications::Notifications},
+    utils::class_name::ClassNameUtil,
+};
 
 const NUMBER_OF_ORBS: u8 = 20;
 
@@ -14,7 +17,8 @@ const NUMBER_OF_ORBS: u8 = 20;
 ///
 /// # Returns
 ///
-/// A Leptos view containing the root layout and notification portal.
+/// A Leptos view containing the root layout, connection banner, and
+/// notification portal.
 #[component]
 pub fn RootLayout(children: Children) -> impl IntoView {
     // Classes
@@ -26,6 +30,7 @@ pub fn RootLayout(children: Children) -> impl IntoView {
 
     view! {
         <main class=root_layout>
+            <ConnectionBanner />
             <Notifications />
             <div class=ambient>
                 <For
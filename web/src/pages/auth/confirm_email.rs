@@ -4,7 +4,7 @@ use gig_log_common::models::user::ConfirmEmailRequest;
 use leptos::{ev::SubmitEvent, prelude::*};
 use leptos_router::hooks::use_navigate;
 
-use super::shared::{AuthFormCard, submit_auth_form, use_auth_form};
+use super::shared::{AuthFormCard, submit_auth_form, use_auth_form, use_query_email};
 use crate::{
     components::{
         button::{Button, ButtonType},
@@ -27,11 +27,15 @@ pub fn ConfirmEmailPage() -> impl IntoView {
 
     // State
     let form = use_auth_form();
+    let email = use_query_email();
     let code = RwSignal::new(String::new());
 
     // Event Handlers
     let handle_submit = move |_: SubmitEvent| {
-        let request = ConfirmEmailRequest { code: code.get() };
+        let request = ConfirmEmailRequest {
+            email: email.get(),
+            code: code.get(),
+        };
 
         let auth = auth.clone();
         let notifications = notifications;
@@ -56,6 +60,7 @@ pub fn ConfirmEmailPage() -> impl IntoView {
             is_loading=form.is_loading
             on_submit=handle_submit
         >
+            <TextInput name="email" placeholder="Email" value=email errors=form.errors />
             <TextInput
                 name="code"
                 placeholder="Enter confirmation code"
@@ -4,7 +4,7 @@ use gig_log_common::models::user::ForgotPasswordRequest;
 use leptos::{ev::SubmitEvent, prelude::*};
 use leptos_router::hooks::use_navigate;
 
-use super::shared::{AuthFormCard, submit_auth_form, use_auth_form};
+use super::shared::{AuthFormCard, submit_auth_form, use_auth_form, with_email_query};
 use crate::{
     components::{
         button::{Button, ButtonType},
@@ -32,6 +32,7 @@ pub fn ForgotPasswordPage() -> impl IntoView {
     // Event Handlers
     let handle_submit = move |_: SubmitEvent| {
         let request = ForgotPasswordRequest { email: email.get() };
+        let next_path = with_email_query("/auth/verify-forgot-password", &request.email);
 
         let auth = auth.clone();
         let notifications = notifications;
@@ -44,7 +45,7 @@ pub fn ForgotPasswordPage() -> impl IntoView {
             async move { auth.forgot_password(&request).await },
             move |response| {
                 notifications.show_success("Check your email", response.message);
-                navigate("/auth/verify-forgot-password", Default::default());
+                navigate(&next_path, Default::default());
             },
         );
     };
@@ -33,15 +33,18 @@ pub fn SetPasswordPage() -> impl IntoView {
 
     // Event Handlers
     let handle_submit = move |_: SubmitEvent| {
-        let Some(code) = query_map.with(|params| params.get("code")) else {
+        let (Some(email), Some(code)) =
+            query_map.with(|params| (params.get("email"), params.get("code")))
+        else {
             notifications.show_error(
                 "Reset password failed",
-                "Reset code is missing. Request a new reset code.",
+                "Reset code or email is missing. Request a new reset code.",
             );
             return;
         };
 
         let request = SetPasswordRequest {
+            email,
             code,
             new_password: password.get(),
             confirm_new_password: confirm_password.get(),
@@ -0,0 +1,42 @@
+//! Helpers for carrying the account email between auth pages.
+
+use leptos::prelude::*;
+use leptos_router::hooks::use_query_map;
+
+/// Appends an `email` query parameter to a path.
+///
+/// # Arguments
+///
+/// * `path` — The path to navigate to.
+/// * `email` — The email address to carry to the next page.
+///
+/// # Returns
+///
+/// The path with the percent-encoded email appended.
+pub fn with_email_query(path: &str, email: &str) -> String {
+    let encoded: String = email
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect();
+    let separator = if path.contains('?') { '&' } else { '?' };
+
+    format!("{path}{separator}email={encoded}")
+}
+
+/// Creates an email signal seeded from the page's `email` query parameter.
+///
+/// # Returns
+///
+/// An [`RwSignal`] holding the query email, or an empty string if absent.
+pub fn use_query_email() -> RwSignal<String> {
+    let email = use_query_map()
+        .with_untracked(|params| params.get("email"))
+        .unwrap_or_default();
+
+    RwSignal::new(email)
+}
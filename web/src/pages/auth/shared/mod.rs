@@ -2,8 +2,11 @@
 
 /// Provides a composed auth layout + card + form wrapper component.
 pub mod auth_form_card;
+/// Provides helpers for carrying the account email between auth pages.
+pub mod email_query;
 /// Provides shared auth form state and submit lifecycle helpers.
 pub mod form_state;
 
 pub use auth_form_card::AuthFormCard;
+pub use email_query::{use_query_email, with_email_query};
 pub use form_state::{submit_auth_form, use_auth_form};
@@ -4,7 +4,7 @@ use gig_log_common::models::user::SignUpRequest;
 use leptos::{ev::SubmitEvent, prelude::*};
 use leptos_router::hooks::use_navigate;
 
-use super::shared::{AuthFormCard, submit_auth_form, use_auth_form};
+use super::shared::{AuthFormCard, submit_auth_form, use_auth_form, with_email_query};
 use crate::{
     components::{
         button::{Button, ButtonType},
@@ -43,6 +43,7 @@ pub fn SignupPage() -> impl IntoView {
             password: password.get(),
             confirm_password: confirm_password.get(),
         };
+        let next_path = with_email_query("/auth/confirm-email", &request.email);
 
         let auth = auth.clone();
         let notifications = notifications;
@@ -55,7 +56,7 @@ pub fn SignupPage() -> impl IntoView {
             async move { auth.signup(&request).await },
             move |response| {
                 notifications.show_success("Account created", response.message);
-                navigate("/auth/confirm-email", Default::default());
+                navigate(&next_path, Default::default());
             },
         );
     };
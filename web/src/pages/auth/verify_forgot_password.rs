@@ -4,7 +4,9 @@ use gig_log_common::models::user::VerifyForgotPasswordRequest;
 use leptos::{ev::SubmitEvent, prelude::*};
 use leptos_router::hooks::use_navigate;
 
-use super::shared::{AuthFormCard, submit_auth_form, use_auth_form};
+use super::shared::{
+    AuthFormCard, submit_auth_form, use_auth_form, use_query_email, with_email_query,
+};
 use crate::{
     components::{
         button::{Button, ButtonType},
@@ -27,12 +29,19 @@ pub fn VerifyForgotPasswordPage() -> impl IntoView {
 
     // State
     let form = use_auth_form();
+    let email = use_query_email();
     let code = RwSignal::new(String::new());
 
     // Event Handlers
     let handle_submit = move |_: SubmitEvent| {
-        let request = VerifyForgotPasswordRequest { code: code.get() };
-        let next_path = format!("/auth/set-password?code={}", request.code);
+        let request = VerifyForgotPasswordRequest {
+            email: email.get(),
+            code: code.get(),
+        };
+        let next_path = with_email_query(
+            &format!("/auth/set-password?code={}", request.code),
+            &request.email,
+        );
 
         let auth = auth.clone();
         let notifications = notifications;
@@ -57,6 +66,7 @@ pub fn VerifyForgotPasswordPage() -> impl IntoView {
             is_loading=form.is_loading
             on_submit=handle_submit
         >
+            <TextInput name="email" placeholder="Email" value=email errors=form.errors />
             <TextInput
                 name="code"
                 placeholder="Enter reset code"
@@ -14,6 +14,8 @@ pub mod jobs;
 pub mod not_found;
 /// Provides payment management pages.
 pub mod payments;
+/// Provides report pages.
+pub mod reports;
 /// Provides the user settings page.
 pub mod settings;
 
@@ -24,4 +26,7 @@ pub use home::*;
 pub use jobs::{JobCreatePage, JobDetailPage, JobEditPage, JobListPage};
 pub use not_found::*;
 pub use payments::{PaymentCreatePage, PaymentDetailPage, PaymentEditPage, PaymentListPage};
+pub use reports::{
+    AgingReportPage, EarningsReportPage, ReportListPage, TaxEstimateReportPage, TimesheetReportPage,
+};
 pub use settings::*;
@@ -0,0 +1,329 @@
+//! Page component for `AgingReportPage`.
+
+use chrono::{NaiveDate, Utc};
+use gig_log_common::models::payment::{OverdueCompanyTotal, OverduePaymentsResponse, Payment};
+use leptos::prelude::*;
+
+use super::shared::{ExportLinks, format_money};
+use crate::{
+    api_client::ReportRequestRunner,
+    components::{LoadingSpinner, date_input::DateInput},
+    layouts::main::MainLayout,
+    utils::date_time::DateTimeUtil,
+};
+
+/// Renders the `AgingReportPage` component.
+///
+/// Lists payments whose expected payout date has passed without them being
+/// received, totaled per company. The date range restricts payments by
+/// expected payout date; leaving it empty includes every overdue payment.
+///
+/// # Returns
+///
+/// A Leptos view for the `AgingReportPage` UI.
+#[component]
+pub fn AgingReportPage() -> impl IntoView {
+    // State
+    let reports = ReportRequestRunner::new();
+    let from = RwSignal::new(String::new());
+    let to = RwSignal::new(String::new());
+
+    let report = LocalResource::new(move || {
+        let reports = reports.clone();
+
+        async move { reports.overdue_payments().await }
+    });
+
+    view! {
+        <MainLayout class="reports-page">
+            <h1>"Aging"</h1>
+            <div class="reports-page__controls">
+                <DateInput label="Due from" name="from" value=from />
+                <DateInput label="Due to" name="to" value=to />
+            </div>
+            <ExportLinks from=from to=to />
+            <Transition fallback=|| view! { <LoadingSpinner /> }>
+                {move || {
+                    report
+                        .get()
+                        .map(|result| match result {
+                            Ok(report) => {
+                                let range = DueRange::parse(&from.get(), &to.get());
+                                let aging = AgingReport::new(report, range);
+                                let today = Utc::now().date_naive();
+                                let company_name = |payment: &Payment| {
+                                    aging
+                                        .companies
+                                        .iter()
+                                        .find(|company| company.company_id == payment.company_id)
+                                        .map(|company| company.company_name.clone())
+                                        .unwrap_or_default()
+                                };
+                                let payment_rows = aging
+                                    .payments
+                                    .iter()
+                                    .map(|payment| {
+                                        view! {
+                                            <tr>
+                                                <td>{company_name(payment)}</td>
+                                                <td>
+                                                    {payment
+                                                        .expected_payout_date
+                                                        .map(DateTimeUtil::format_date)
+                                                        .unwrap_or_default()}
+                                                </td>
+                                                <td>{days_overdue(payment.expected_payout_date, today)}</td>
+                                                <td>{format_money(payment.amount_outstanding)}</td>
+                                            </tr>
+                                        }
+                                    })
+                                    .collect_view();
+
+                                view! {
+                                    <div class="reports-page__summary">
+                                        <div class="reports-page__stat">
+                                            <p>"Overdue payments"</p>
+                                            <h3>{aging.payments.len()}</h3>
+                                        </div>
+                                        <div class="reports-page__stat">
+                                            <p>"Outstanding"</p>
+                                            <h3>{format_money(aging.amount_outstanding())}</h3>
+                                        </div>
+                                        <div class="reports-page__stat">
+                                            <p>"Companies"</p>
+                                            <h3>{aging.companies.len()}</h3>
+                                        </div>
+                                    </div>
+                                    <div
+                                        class="reports-page__table-scroll"
+                                        role="region"
+                                        aria-label="Overdue payments by company"
+                                        tabindex="0"
+                                    >
+                                        <table class="reports-page__table">
+                                            <thead>
+                                                <tr>
+                                                    <th scope="col">"Company"</th>
+                                                    <th scope="col">"Payments"</th>
+                                                    <th scope="col">"Oldest due"</th>
+                                                    <th scope="col">"Days overdue"</th>
+                                                    <th scope="col">"Outstanding"</th>
+                                                </tr>
+                                            </thead>
+                                            <tbody>
+                                                {aging
+                                                    .companies
+                                                    .iter()
+                                                    .map(|company| {
+                                                        view! {
+                                                            <tr>
+                                                                <td>{company.company_name.clone()}</td>
+                                                                <td>{company.payment_count}</td>
+                                                                <td>
+                                                                    {DateTimeUtil::format_date(
+                                                                        company.oldest_expected_payout_date,
+                                                                    )}
+                                                                </td>
+                                                                <td>
+                                                                    {days_overdue(
+                                                                        Some(company.oldest_expected_payout_date),
+                                                                        today,
+                                                                    )}
+                                                                </td>
+                                                                <td>{format_money(company.amount_outstanding)}</td>
+                                                            </tr>
+                                                        }
+                                                    })
+                                                    .collect_view()}
+                                            </tbody>
+                                        </table>
+                                    </div>
+                                    <div
+                                        class="reports-page__table-scroll"
+                                        role="region"
+                                        aria-label="Overdue payments"
+                                        tabindex="0"
+                                    >
+                                        <table class="reports-page__table">
+                                            <thead>
+                                                <tr>
+                                                    <th scope="col">"Company"</th>
+                                                    <th scope="col">"Expected"</th>
+                                                    <th scope="col">"Days overdue"</th>
+                                                    <th scope="col">"Outstanding"</th>
+                                                </tr>
+                                            </thead>
+                                            <tbody>{payment_rows}</tbody>
+                                        </table>
+                                    </div>
+                                }
+                                    .into_any()
+                            }
+                            Err(error) => {
+                                view! { <p class="reports-page__error">{error.to_string()}</p> }
+                                    .into_any()
+                            }
+                        })
+                }}
+            </Transition>
+        </MainLayout>
+    }
+}
+
+/// Stores the expected payout dates the aging report is restricted to.
+#[derive(Clone, Copy, Debug, Default)]
+struct DueRange {
+    /// Stores the earliest expected payout date to include.
+    from: Option<NaiveDate>,
+    /// Stores the latest expected payout date to include.
+    to: Option<NaiveDate>,
+}
+
+impl DueRange {
+    /// Parses the range from the date inputs.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` — `YYYY-MM-DD` first day, or empty for no lower bound.
+    /// * `to` — `YYYY-MM-DD` last day, or empty for no upper bound.
+    ///
+    /// # Returns
+    ///
+    /// The parsed [`DueRange`]. Values that are not dates are ignored.
+    fn parse(from: &str, to: &str) -> Self {
+        Self {
+            from: from.parse().ok(),
+            to: to.parse().ok(),
+        }
+    }
+
+    /// Returns whether neither bound is set.
+    ///
+    /// # Returns
+    ///
+    /// `true` if every overdue payment is included.
+    fn is_open(&self) -> bool {
+        self.from.is_none() && self.to.is_none()
+    }
+
+    /// Returns whether a payment's expected payout date is in the range.
+    ///
+    /// # Arguments
+    ///
+    /// * `payment` — The overdue payment to check.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the payment should be included.
+    fn contains(&self, payment: &Payment) -> bool {
+        let Some(date) = payment.expected_payout_date else {
+            return self.is_open();
+        };
+
+        self.from.is_none_or(|from| date >= from) && self.to.is_none_or(|to| date <= to)
+    }
+}
+
+/// Stores the overdue payments and company totals shown on the page.
+#[derive(Debug)]
+struct AgingReport {
+    /// Stores totals per company, largest outstanding amount first.
+    companies: Vec<OverdueCompanyTotal>,
+    /// Stores the overdue payments, oldest expected payout date first.
+    payments: Vec<Payment>,
+}
+
+impl AgingReport {
+    /// Restricts an overdue payments response to a due date range.
+    ///
+    /// The API's company totals are used as is when the range is open.
+    /// Otherwise they are recomputed from the payments in the range.
+    ///
+    /// # Arguments
+    ///
+    /// * `report` — The overdue payments response.
+    /// * `range` — Expected payout dates to include.
+    ///
+    /// # Returns
+    ///
+    /// The [`AgingReport`] for the range.
+    fn new(report: OverduePaymentsResponse, range: DueRange) -> Self {
+        if range.is_open() {
+            return Self {
+                companies: report.companies,
+                payments: report.payments,
+            };
+        }
+
+        let payments = report
+            .payments
+            .into_iter()
+            .filter(|payment| range.contains(payment))
+            .collect::<Vec<_>>();
+        let mut companies = Vec::<OverdueCompanyTotal>::new();
+
+        for payment in &payments {
+            let Some(due) = payment.expected_payout_date else {
+                continue;
+            };
+
+            match companies
+                .iter_mut()
+                .find(|company| company.company_id == payment.company_id)
+            {
+                Some(company) => {
+                    company.payment_count += 1;
+                    company.amount_outstanding += payment.amount_outstanding;
+                    company.oldest_expected_payout_date =
+                        company.oldest_expected_payout_date.min(due);
+                }
+                None => companies.push(OverdueCompanyTotal {
+                    company_id: payment.company_id,
+                    company_name: report
+                        .companies
+                        .iter()
+                        .find(|company| company.company_id == payment.company_id)
+                        .map(|company| company.company_name.clone())
+                        .unwrap_or_default(),
+                    payment_count: 1,
+                    amount_outstanding: payment.amount_outstanding,
+                    oldest_expected_payout_date: due,
+                }),
+            }
+        }
+
+        companies.sort_by(|a, b| b.amount_outstanding.total_cmp(&a.amount_outstanding));
+
+        Self {
+            companies,
+            payments,
+        }
+    }
+
+    /// Sums the outstanding amount across the report's companies.
+    ///
+    /// # Returns
+    ///
+    /// The outstanding amount in dollars.
+    fn amount_outstanding(&self) -> f64 {
+        self.companies
+            .iter()
+            .map(|company| company.amount_outstanding)
+            .sum()
+    }
+}
+
+/// Counts the days since an expected payout date.
+///
+/// # Arguments
+///
+/// * `date` — The expected payout date, if any.
+/// * `today` — The current date.
+///
+/// # Returns
+///
+/// The number of days overdue, or zero when no date is set.
+fn days_overdue(date: Option<NaiveDate>, today: NaiveDate) -> i64 {
+    date.map(|date| (today - date).num_days().max(0))
+        .unwrap_or_default()
+}
@@ -0,0 +1,186 @@
+//! Page component for `EarningsReportPage`.
+
+use gig_log_common::models::report::{EarningsPeriod, EarningsPeriodTotals};
+use leptos::prelude::*;
+
+use super::shared::{ExportLinks, format_hours, format_money};
+use crate::{
+    api_client::ReportRequestRunner,
+    components::{
+        LoadingSpinner,
+        date_input::DateInput,
+        select_input::{SelectInput, SelectOption},
+    },
+    layouts::main::MainLayout,
+    utils::date_time::DateTimeUtil,
+};
+
+/// Renders the `EarningsReportPage` component.
+///
+/// Leaving the date range empty reports the twelve periods up to today.
+///
+/// # Returns
+///
+/// A Leptos view for the `EarningsReportPage` UI.
+#[component]
+pub fn EarningsReportPage() -> impl IntoView {
+    // State
+    let reports = ReportRequestRunner::new();
+    let period = RwSignal::new(Some(SelectOption::new(EarningsPeriod::Monthly, "Monthly")));
+    let from = RwSignal::new(String::new());
+    let to = RwSignal::new(String::new());
+
+    let report = LocalResource::new(move || {
+        let reports = reports.clone();
+        let period = period.get().map(|option| option.value).unwrap_or_default();
+        let from = from.get();
+        let to = to.get();
+
+        async move {
+            reports
+                .earnings(period, Some(from.as_str()), Some(to.as_str()))
+                .await
+        }
+    });
+
+    // Variables
+    let period_options = vec![
+        SelectOption::new(EarningsPeriod::Weekly, "Weekly"),
+        SelectOption::new(EarningsPeriod::Monthly, "Monthly"),
+    ];
+
+    view! {
+        <MainLayout class="reports-page">
+            <h1>"Earnings"</h1>
+            <div class="reports-page__controls">
+                <SelectInput label="Group by" options=period_options selected_option=period />
+                <DateInput label="From" name="from" value=from />
+                <DateInput label="To" name="to" value=to />
+            </div>
+            <ExportLinks from=from to=to />
+            <Transition fallback=|| view! { <LoadingSpinner /> }>
+                {move || {
+                    report
+                        .get()
+                        .map(|result| match result {
+                            Ok(report) => {
+                                let period = report.period;
+                                let totals = EarningsTotals::sum(&report.periods);
+
+                                view! {
+                                    <div
+                                        class="reports-page__table-scroll"
+                                        role="region"
+                                        aria-label="Earnings by period"
+                                        tabindex="0"
+                                    >
+                                        <table class="reports-page__table">
+                                            <thead>
+                                                <tr>
+                                                    <th scope="col">"Period"</th>
+                                                    <th scope="col">"Hours"</th>
+                                                    <th scope="col">"Invoiced"</th>
+                                                    <th scope="col">"Received"</th>
+                                                    <th scope="col">"Tax Withholdings"</th>
+                                                </tr>
+                                            </thead>
+                                            <tbody>
+                                                {report
+                                                    .periods
+                                                    .into_iter()
+                                                    .map(|row| {
+                                                        view! {
+                                                            <tr>
+                                                                <td>{period_label(period, &row)}</td>
+                                                                <td>{format_hours(row.hours_worked)}</td>
+                                                                <td>{format_money(row.invoiced)}</td>
+                                                                <td>{format_money(row.received)}</td>
+                                                                <td>{format_money(row.tax_withholdings)}</td>
+                                                            </tr>
+                                                        }
+                                                    })
+                                                    .collect_view()}
+                                            </tbody>
+                                            <tfoot>
+                                                <tr>
+                                                    <th scope="row">"Total"</th>
+                                                    <th>{format_hours(totals.hours_worked)}</th>
+                                                    <th>{format_money(totals.invoiced)}</th>
+                                                    <th>{format_money(totals.received)}</th>
+                                                    <th>{format_money(totals.tax_withholdings)}</th>
+                                                </tr>
+                                            </tfoot>
+                                        </table>
+                                    </div>
+                                    <p class="reports-page__generated-at">
+                                        {format!(
+                                            "Generated {}",
+                                            DateTimeUtil::format_timestamp(&report.generated_at),
+                                        )}
+                                    </p>
+                                }
+                                    .into_any()
+                            }
+                            Err(error) => {
+                                view! { <p class="reports-page__error">{error.to_string()}</p> }
+                                    .into_any()
+                            }
+                        })
+                }}
+            </Transition>
+        </MainLayout>
+    }
+}
+
+/// Stores earnings totals across every period in a report.
+#[derive(Debug, Default)]
+struct EarningsTotals {
+    /// Stores the total hours worked.
+    hours_worked: f64,
+    /// Stores the total amount invoiced in dollars.
+    invoiced: f64,
+    /// Stores the total amount received in dollars.
+    received: f64,
+    /// Stores the total tax withholdings in dollars.
+    tax_withholdings: f64,
+}
+
+impl EarningsTotals {
+    /// Sums the totals of a report's periods.
+    ///
+    /// # Arguments
+    ///
+    /// * `periods` — Period totals from the earnings report.
+    ///
+    /// # Returns
+    ///
+    /// The summed [`EarningsTotals`].
+    fn sum(periods: &[EarningsPeriodTotals]) -> Self {
+        periods.iter().fold(Self::default(), |totals, row| Self {
+            hours_worked: totals.hours_worked + row.hours_worked,
+            invoiced: totals.invoiced + row.invoiced,
+            received: totals.received + row.received,
+            tax_withholdings: totals.tax_withholdings + row.tax_withholdings,
+        })
+    }
+}
+
+/// Builds the label shown for a report period.
+///
+/// # Arguments
+///
+/// * `period` — Length of each period in the report.
+/// * `row` — Totals for the period.
+///
+/// # Returns
+///
+/// A [`String`] such as `March 2026` or `Week of Mar 2, 2026`, formatted
+/// for the user's locale.
+fn period_label(period: EarningsPeriod, row: &EarningsPeriodTotals) -> String {
+    match period {
+        EarningsPeriod::Weekly => {
+            format!("Week of {}", DateTimeUtil::format_date(row.period_start))
+        }
+        EarningsPeriod::Monthly => DateTimeUtil::format_month(row.period_start),
+    }
+}
@@ -0,0 +1,46 @@
+//! Page component for `ReportListPage`.
+
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use crate::layouts::main::MainLayout;
+
+/// Renders the `ReportListPage` component.
+///
+/// # Returns
+///
+/// A Leptos view for the `ReportListPage` UI.
+#[component]
+pub fn ReportListPage() -> impl IntoView {
+    view! {
+        <MainLayout class="reports-page">
+            <h1>"Reports"</h1>
+            <div class="reports-page__links">
+                <A href="/reports/earnings">
+                    <div class="reports-page__link">
+                        <h3>"Earnings"</h3>
+                        <p>"Hours worked, invoiced, and received per week or month."</p>
+                    </div>
+                </A>
+                <A href="/reports/tax-estimate">
+                    <div class="reports-page__link">
+                        <h3>"Tax Estimate"</h3>
+                        <p>"Estimated tax owed, covered, and still to set aside per quarter."</p>
+                    </div>
+                </A>
+                <A href="/reports/timesheet">
+                    <div class="reports-page__link">
+                        <h3>"Timesheet"</h3>
+                        <p>"Hours worked per day, by job or company."</p>
+                    </div>
+                </A>
+                <A href="/reports/aging">
+                    <div class="reports-page__link">
+                        <h3>"Aging"</h3>
+                        <p>"Overdue payments and how long each company has owed them."</p>
+                    </div>
+                </A>
+            </div>
+        </MainLayout>
+    }
+}
@@ -0,0 +1,21 @@
+//! Report route page components.
+
+/// Provides shared helpers for report page implementations.
+mod shared;
+
+/// Provides the overdue payments aging report page component.
+pub mod aging;
+/// Provides the earnings report page component.
+pub mod earnings;
+/// Provides the report index page component.
+pub mod list;
+/// Provides the quarterly tax estimate page component.
+pub mod tax_estimate;
+/// Provides the timesheet report page component.
+pub mod timesheet;
+
+pub use aging::AgingReportPage;
+pub use earnings::EarningsReportPage;
+pub use list::ReportListPage;
+pub use tax_estimate::TaxEstimateReportPage;
+pub use timesheet::TimesheetReportPage;
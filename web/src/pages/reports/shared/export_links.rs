@@ -0,0 +1,42 @@
+//! CSV export links for a report's date range.
+
+use leptos::prelude::*;
+
+use crate::api_client::ReportRequestRunner;
+
+/// Renders download links for the payments and work sessions CSV exports.
+///
+/// # Arguments
+///
+/// * `from` — Signal containing the optional `YYYY-MM-DD` first day to
+///   export. Empty means no lower bound.
+/// * `to` — Signal containing the optional `YYYY-MM-DD` last day to export.
+///   Empty means no upper bound.
+///
+/// # Returns
+///
+/// A Leptos view containing the export links.
+#[component]
+pub fn ExportLinks(
+    #[prop(into)] from: Signal<String>,
+    #[prop(into)] to: Signal<String>,
+) -> impl IntoView {
+    let reports = ReportRequestRunner::new();
+    let payments_reports = reports.clone();
+
+    let payments_url =
+        move || payments_reports.payments_export_url(Some(&from.get()), Some(&to.get()));
+    let work_sessions_url =
+        move || reports.work_sessions_export_url(Some(&from.get()), Some(&to.get()));
+
+    view! {
+        <div class="reports-page__exports">
+            <a class="button button--secondary" href=payments_url download>
+                "Export payments CSV"
+            </a>
+            <a class="button button--secondary" href=work_sessions_url download>
+                "Export work sessions CSV"
+            </a>
+        </div>
+    }
+}
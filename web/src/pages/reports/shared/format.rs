@@ -0,0 +1,44 @@
+//! Number formatting helpers for report tables.
+
+/// Formats a dollar amount with two decimal places.
+///
+/// # Arguments
+///
+/// * `amount` — Amount in dollars.
+///
+/// # Returns
+///
+/// A [`String`] such as `$1234.50`, with negative amounts as `-$12.00`.
+pub fn format_money(amount: f64) -> String {
+    if amount < 0.0 {
+        format!("-${:.2}", amount.abs())
+    } else {
+        format!("${:.2}", amount)
+    }
+}
+
+/// Formats a number of hours with one decimal place.
+///
+/// # Arguments
+///
+/// * `hours` — Hours worked.
+///
+/// # Returns
+///
+/// A [`String`] such as `12.5 h`.
+pub fn format_hours(hours: f64) -> String {
+    format!("{:.1} h", hours)
+}
+
+/// Formats a decimal rate as a percentage.
+///
+/// # Arguments
+///
+/// * `rate` — Rate as a decimal, e.g. `0.153`.
+///
+/// # Returns
+///
+/// A [`String`] such as `15.3%`.
+pub fn format_rate(rate: f64) -> String {
+    format!("{:.1}%", rate * 100.0)
+}
@@ -0,0 +1,9 @@
+//! Shared helpers used across report page components.
+
+/// Provides CSV export links for a report's date range.
+pub mod export_links;
+/// Provides number formatting for report tables.
+pub mod format;
+
+pub use export_links::ExportLinks;
+pub use format::{format_hours, format_money, format_rate};
@@ -0,0 +1,155 @@
+//! Page component for `TaxEstimateReportPage`.
+
+use leptos::prelude::*;
+
+use super::shared::{ExportLinks, format_money, format_rate};
+use crate::{
+    api_client::ReportRequestRunner,
+    components::{
+        LoadingSpinner,
+        select_input::{SelectInput, SelectOption},
+        text_input::TextInput,
+    },
+    layouts::main::MainLayout,
+    utils::date_time::DateTimeUtil,
+};
+
+/// Renders the `TaxEstimateReportPage` component.
+///
+/// Leaving the year or quarter empty estimates the current one.
+///
+/// # Returns
+///
+/// A Leptos view for the `TaxEstimateReportPage` UI.
+#[component]
+pub fn TaxEstimateReportPage() -> impl IntoView {
+    // State
+    let reports = ReportRequestRunner::new();
+    let year = RwSignal::new(String::new());
+    let quarter = RwSignal::new(None::<SelectOption<u32>>);
+    let errors = RwSignal::new(Vec::new());
+
+    let report = LocalResource::new(move || {
+        let reports = reports.clone();
+        let year = year.get().trim().parse::<i32>().ok();
+        let quarter = quarter.get().map(|option| option.value);
+
+        async move { reports.tax_estimate(year, quarter).await }
+    });
+
+    // Variables
+    let quarter_options = (1..=4)
+        .map(|quarter| SelectOption::new(quarter, format!("Q{}", quarter)))
+        .collect::<Vec<_>>();
+
+    view! {
+        <MainLayout class="reports-page">
+            <h1>"Tax Estimate"</h1>
+            <div class="reports-page__controls">
+                <TextInput
+                    label="Year"
+                    placeholder="Current year"
+                    name="year"
+                    errors=errors
+                    value=year
+                />
+                <SelectInput
+                    label="Quarter"
+                    placeholder="Current quarter"
+                    options=quarter_options
+                    selected_option=quarter
+                />
+            </div>
+            <Transition fallback=|| view! { <LoadingSpinner /> }>
+                {move || {
+                    report
+                        .get()
+                        .map(|result| match result {
+                            Ok(report) => {
+                                view! {
+                                    <h3>
+                                        {format!(
+                                            "Q{} {} ({} to {})",
+                                            report.quarter,
+                                            report.year,
+                                            DateTimeUtil::format_date(report.start_date),
+                                            DateTimeUtil::format_date(report.end_date),
+                                        )}
+                                    </h3>
+                                    <ExportLinks
+                                        from=report.start_date.to_string()
+                                        to=report.end_date.to_string()
+                                    />
+                                    <div class="reports-page__summary">
+                                        <div class="reports-page__stat">
+                                            <p>"Estimated owed"</p>
+                                            <h3>{format_money(report.estimated_owed)}</h3>
+                                        </div>
+                                        <div class="reports-page__stat">
+                                            <p>"Covered by withholdings"</p>
+                                            <h3>{format_money(report.tax_withholdings_covered)}</h3>
+                                        </div>
+                                        <div class="reports-page__stat">
+                                            <p>"Shortfall"</p>
+                                            <h3>{format_money(report.shortfall)}</h3>
+                                        </div>
+                                    </div>
+                                    <div
+                                        class="reports-page__table-scroll"
+                                        role="region"
+                                        aria-label="Tax estimate by company"
+                                        tabindex="0"
+                                    >
+                                        <table class="reports-page__table">
+                                            <thead>
+                                                <tr>
+                                                    <th scope="col">"Company"</th>
+                                                    <th scope="col">"Rate"</th>
+                                                    <th scope="col">"Income"</th>
+                                                    <th scope="col">"Owed"</th>
+                                                    <th scope="col">"Covered"</th>
+                                                    <th scope="col">"Shortfall"</th>
+                                                </tr>
+                                            </thead>
+                                            <tbody>
+                                                {report
+                                                    .companies
+                                                    .into_iter()
+                                                    .map(|company| {
+                                                        view! {
+                                                            <tr>
+                                                                <td>{company.company_name}</td>
+                                                                <td>{format_rate(company.tax_rate)}</td>
+                                                                <td>{format_money(company.income)}</td>
+                                                                <td>{format_money(company.estimated_owed)}</td>
+                                                                <td>
+                                                                    {format_money(company.tax_withholdings_covered)}
+                                                                </td>
+                                                                <td>{format_money(company.shortfall)}</td>
+                                                            </tr>
+                                                        }
+                                                    })
+                                                    .collect_view()}
+                                            </tbody>
+                                        </table>
+                                    </div>
+                                    <p class="reports-page__generated-at">
+                                        {format!(
+                                            "Self-employment rate {}. Generated {}",
+                                            format_rate(report.self_employment_tax_rate),
+                                            DateTimeUtil::format_timestamp(&report.generated_at),
+                                        )}
+                                    </p>
+                                }
+                                    .into_any()
+                            }
+                            Err(error) => {
+                                view! { <p class="reports-page__error">{error.to_string()}</p> }
+                                    .into_any()
+                            }
+                        })
+                }}
+            </Transition>
+        </MainLayout>
+    }
+}
@@ -0,0 +1,242 @@
+//! Page component for `TimesheetReportPage`.
+
+use leptos::prelude::*;
+
+use super::shared::{ExportLinks, format_hours};
+use crate::{
+    api_client::{ClientError, CompanyRequestRunner, JobRequestRunner, ReportRequestRunner},
+    components::{
+        LoadingSpinner,
+        date_input::DateInput,
+        select_input::{SelectInput, SelectOption},
+    },
+    layouts::main::MainLayout,
+    utils::date_time::DateTimeUtil,
+};
+
+/// Seconds in one hour.
+const SECONDS_PER_HOUR: f64 = 3600.0;
+
+/// Renders the `TimesheetReportPage` component.
+///
+/// Shows worked time per day for completed work sessions, optionally
+/// restricted to a date range, job, or company. Leaving a filter empty
+/// includes every session.
+///
+/// # Returns
+///
+/// A Leptos view for the `TimesheetReportPage` UI.
+#[component]
+pub fn TimesheetReportPage() -> impl IntoView {
+    // State
+    let reports = ReportRequestRunner::new();
+    let jobs = JobRequestRunner::new();
+    let companies = CompanyRequestRunner::new();
+    let from = RwSignal::new(String::new());
+    let to = RwSignal::new(String::new());
+    let job = RwSignal::new(None::<SelectOption<String>>);
+    let company = RwSignal::new(None::<SelectOption<String>>);
+
+    let filters = LocalResource::new(move || {
+        let jobs = jobs.clone();
+        let companies = companies.clone();
+
+        async move { TimesheetFilters::load(&jobs, &companies).await }
+    });
+
+    let report = LocalResource::new(move || {
+        let reports = reports.clone();
+        let from = from.get();
+        let to = to.get();
+        let job_id = job.get().map(|option| option.value);
+        let company_id = company.get().map(|option| option.value);
+
+        async move {
+            reports
+                .timesheet(
+                    Some(from.as_str()),
+                    Some(to.as_str()),
+                    job_id.as_deref(),
+                    company_id.as_deref(),
+                )
+                .await
+        }
+    });
+
+    view! {
+        <MainLayout class="reports-page">
+            <h1>"Timesheet"</h1>
+            <div class="reports-page__controls">
+                <DateInput label="From" name="from" value=from />
+                <DateInput label="To" name="to" value=to />
+                <Transition>
+                    {move || {
+                        filters
+                            .get()
+                            .and_then(Result::ok)
+                            .map(|filters| {
+                                view! {
+                                    <SelectInput
+                                        label="Job"
+                                        placeholder="All jobs"
+                                        options=filters.jobs
+                                        selected_option=job
+                                    />
+                                    <SelectInput
+                                        label="Company"
+                                        placeholder="All companies"
+                                        options=filters.companies
+                                        selected_option=company
+                                    />
+                                }
+                            })
+                    }}
+                </Transition>
+            </div>
+            <ExportLinks from=from to=to />
+            <Transition fallback=|| view! { <LoadingSpinner /> }>
+                {move || {
+                    report
+                        .get()
+                        .map(|result| match result {
+                            Ok(report) if report.days.is_empty() => {
+                                view! { <p>"No completed work sessions match these filters."</p> }
+                                    .into_any()
+                            }
+                            Ok(report) => {
+                                let session_count = report
+                                    .days
+                                    .iter()
+                                    .map(|day| day.session_count)
+                                    .sum::<i64>();
+
+                                view! {
+                                    <div
+                                        class="reports-page__table-scroll"
+                                        role="region"
+                                        aria-label="Worked time by day"
+                                        tabindex="0"
+                                    >
+                                        <table class="reports-page__table">
+                                            <thead>
+                                                <tr>
+                                                    <th scope="col">"Day"</th>
+                                                    <th scope="col">"Sessions"</th>
+                                                    <th scope="col">"Hours"</th>
+                                                </tr>
+                                            </thead>
+                                            <tbody>
+                                                {report
+                                                    .days
+                                                    .into_iter()
+                                                    .map(|day| {
+                                                        view! {
+                                                            <tr>
+                                                                <td>{DateTimeUtil::format_date(day.date)}</td>
+                                                                <td>{day.session_count}</td>
+                                                                <td>{format_hours(hours(day.net_seconds))}</td>
+                                                            </tr>
+                                                        }
+                                                    })
+                                                    .collect_view()}
+                                            </tbody>
+                                            <tfoot>
+                                                <tr>
+                                                    <th scope="row">"Total"</th>
+                                                    <th>{session_count}</th>
+                                                    <th>{format_hours(hours(report.net_seconds))}</th>
+                                                </tr>
+                                            </tfoot>
+                                        </table>
+                                    </div>
+                                }
+                                    .into_any()
+                            }
+                            Err(error) => {
+                                view! { <p class="reports-page__error">{error.to_string()}</p> }
+                                    .into_any()
+                            }
+                        })
+                }}
+            </Transition>
+        </MainLayout>
+    }
+}
+
+/// Stores the options of the timesheet's job and company filters.
+#[derive(Clone, Debug)]
+struct TimesheetFilters {
+    /// Stores one option per job, valued by job ID.
+    jobs: Vec<SelectOption<String>>,
+    /// Stores one option per company with jobs, valued by company ID.
+    companies: Vec<SelectOption<String>>,
+}
+
+impl TimesheetFilters {
+    /// Loads the user's jobs and the companies they belong to.
+    ///
+    /// # Arguments
+    ///
+    /// * `jobs` — Runner used to request the job board.
+    /// * `companies` — Runner used to request each company's name.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] containing the [`TimesheetFilters`], each sorted by
+    /// label.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ClientError`] if any request fails.
+    async fn load(
+        jobs: &JobRequestRunner,
+        companies: &CompanyRequestRunner,
+    ) -> Result<Self, ClientError> {
+        let board = jobs.board().await?;
+        let board_jobs = board
+            .columns
+            .into_iter()
+            .flat_map(|group| group.jobs)
+            .collect::<Vec<_>>();
+
+        let mut company_ids = board_jobs
+            .iter()
+            .map(|job| job.company_id.to_string())
+            .collect::<Vec<_>>();
+        company_ids.sort();
+        company_ids.dedup();
+
+        let mut company_options = Vec::with_capacity(company_ids.len());
+
+        for id in company_ids {
+            let company = companies.get(&id).await?;
+            company_options.push(SelectOption::new(id, company.name));
+        }
+
+        let mut job_options = board_jobs
+            .into_iter()
+            .map(|job| SelectOption::new(job.id.to_string(), job.title))
+            .collect::<Vec<_>>();
+
+        job_options.sort_by(|a, b| a.label.cmp(&b.label));
+        company_options.sort_by(|a, b| a.label.cmp(&b.label));
+
+        Ok(Self {
+            jobs: job_options,
+            companies: company_options,
+        })
+    }
+}
+
+/// Converts a duration to hours.
+///
+/// # Arguments
+///
+/// * `seconds` — Duration in seconds.
+///
+/// # Returns
+///
+/// The duration in hours.
+fn hours(seconds: i64) -> f64 {
+    seconds as f64 / SECONDS_PER_HOUR
+}
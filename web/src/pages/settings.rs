@@ -1,19 +1,412 @@
 //! Page component for `SettingsPage`.
 
-use leptos::prelude::*;
+use gig_log_common::models::{
+    locale::{Locale, LocalePreference, TimezonePreference},
+    payment::PaymentReminderSetting,
+};
+use leptos::{prelude::*, reactive::spawn_local};
 
-use crate::layouts::main::MainLayout;
+use crate::{
+    api_client::{AuthRequestRunner, PaymentRequestRunner},
+    components::select_input::{SelectInput, SelectOption},
+    contexts::use_notifications,
+    layouts::main::MainLayout,
+    utils::date_time::DateTimeUtil,
+};
 
 /// Renders the `SettingsPage` component.
 ///
+/// Each section saves as soon as a value changes. The new value is shown
+/// right away and put back if the save fails.
+///
 /// # Returns
 ///
 /// A Leptos view for the `SettingsPage` UI.
 #[component]
 pub fn SettingsPage() -> impl IntoView {
     view! {
-        <MainLayout>
+        <MainLayout class="settings-page">
             <h1>"Settings"</h1>
+            <NotificationSettings />
+            <LanguageSettings />
+            <TimezoneSettings />
         </MainLayout>
     }
 }
+
+/// Renders the payment reminder toggle.
+///
+/// # Returns
+///
+/// A Leptos view containing the notifications section.
+#[component]
+fn NotificationSettings() -> impl IntoView {
+    // Context
+    let notifications = use_notifications();
+
+    // State
+    let payments = StoredValue::new_local(PaymentRequestRunner::new());
+    let enabled = RwSignal::new(false);
+    let saved = StoredValue::new(false);
+    let revision = StoredValue::new(0_u32);
+    let save_state = RwSignal::new(SaveState::Loading);
+
+    spawn_local(async move {
+        match payments.get_value().reminders().await {
+            Ok(setting) => {
+                saved.set_value(setting.enabled);
+                enabled.set(setting.enabled);
+                save_state.set(SaveState::Idle);
+            }
+            Err(_) => save_state.set(SaveState::LoadFailed),
+        }
+    });
+
+    // Event Handlers
+    let on_change = move |_| {
+        let request = PaymentReminderSetting {
+            enabled: !enabled.get_untracked(),
+        };
+        let current = next_revision(revision);
+        let payments = payments.get_value();
+
+        enabled.set(request.enabled);
+        save_state.set(SaveState::Saving);
+
+        spawn_local(async move {
+            let result = payments.update_reminders(&request).await;
+
+            if let Ok(setting) = &result {
+                saved.set_value(setting.enabled);
+            }
+
+            if revision.get_value() != current {
+                return;
+            }
+
+            match result {
+                Ok(_) => save_state.set(SaveState::Saved),
+                Err(error) => {
+                    enabled.set(saved.get_value());
+                    save_state.set(SaveState::SaveFailed);
+                    notifications.show_error("Failed to save notifications", error.to_string());
+                }
+            }
+        });
+    };
+
+    view! {
+        <section class="settings-page__section" aria-labelledby="settings-notifications">
+            <div class="settings-page__heading">
+                <h3 id="settings-notifications">"Notifications"</h3>
+                <SaveStatus state=save_state />
+            </div>
+            <div class="check-box">
+                <label>
+                    <input
+                        type="checkbox"
+                        prop:checked=move || enabled.get()
+                        disabled=move || !save_state.get().is_ready()
+                        on:change=on_change
+                    />
+                    "Email payment reminders"
+                </label>
+            </div>
+            <p class="settings-page__hint">
+                "Sent before a payment's expected payout date and once it is overdue."
+            </p>
+        </section>
+    }
+}
+
+/// Renders the email language picker.
+///
+/// # Returns
+///
+/// A Leptos view containing the language section.
+#[component]
+fn LanguageSettings() -> impl IntoView {
+    // Context
+    let notifications = use_notifications();
+
+    // State
+    let auth = StoredValue::new_local(AuthRequestRunner::new());
+    let selected = RwSignal::new(None::<SelectOption<Locale>>);
+    let saved = StoredValue::new(None::<Locale>);
+    let revision = StoredValue::new(0_u32);
+    let save_state = RwSignal::new(SaveState::Loading);
+
+    spawn_local(async move {
+        match auth.get_value().get_locale().await {
+            Ok(preference) => {
+                saved.set_value(Some(preference.locale));
+                selected.set(Some(locale_option(preference.locale)));
+                save_state.set(SaveState::Idle);
+            }
+            Err(_) => save_state.set(SaveState::LoadFailed),
+        }
+    });
+
+    // Effects
+    Effect::new(move || {
+        let Some(locale) = selected.get().map(|option| option.value) else {
+            return;
+        };
+
+        if saved.get_value() == Some(locale) || !save_state.get_untracked().is_ready() {
+            return;
+        }
+
+        let request = LocalePreference { locale };
+        let current = next_revision(revision);
+        let auth = auth.get_value();
+
+        save_state.set(SaveState::Saving);
+
+        spawn_local(async move {
+            let result = auth.update_locale(&request).await;
+
+            if let Ok(preference) = &result {
+                saved.set_value(Some(preference.locale));
+            }
+
+            if revision.get_value() != current {
+                return;
+            }
+
+            match result {
+                Ok(_) => save_state.set(SaveState::Saved),
+                Err(error) => {
+                    selected.set(saved.get_value().map(locale_option));
+                    save_state.set(SaveState::SaveFailed);
+                    notifications.show_error("Failed to save language", error.to_string());
+                }
+            }
+        });
+    });
+
+    // Variables
+    let options = Locale::ALL
+        .into_iter()
+        .map(locale_option)
+        .collect::<Vec<_>>();
+
+    view! {
+        <section class="settings-page__section" aria-labelledby="settings-language">
+            <div class="settings-page__heading">
+                <h3 id="settings-language">"Language"</h3>
+                <SaveStatus state=save_state />
+            </div>
+            <SelectInput label="Email language" options=options selected_option=selected />
+        </section>
+    }
+}
+
+/// Renders the timezone picker.
+///
+/// Leaving the picker on the browser timezone clears the preference, so
+/// times follow the browser's timezone.
+///
+/// # Returns
+///
+/// A Leptos view containing the timezone section.
+#[component]
+fn TimezoneSettings() -> impl IntoView {
+    // Context
+    let notifications = use_notifications();
+
+    // State
+    let auth = StoredValue::new_local(AuthRequestRunner::new());
+    let selected = RwSignal::new(None::<SelectOption<String>>);
+    let saved = StoredValue::new(None::<String>);
+    let revision = StoredValue::new(0_u32);
+    let save_state = RwSignal::new(SaveState::Loading);
+
+    spawn_local(async move {
+        match auth.get_value().get_timezone().await {
+            Ok(preference) => {
+                saved.set_value(preference.timezone.clone());
+                selected.set(preference.timezone.map(timezone_option));
+                save_state.set(SaveState::Idle);
+            }
+            Err(_) => save_state.set(SaveState::LoadFailed),
+        }
+    });
+
+    // Effects
+    Effect::new(move || {
+        let timezone = selected.get().map(|option| option.value);
+
+        if saved.get_value() == timezone || !save_state.get_untracked().is_ready() {
+            return;
+        }
+
+        let request = TimezonePreference { timezone };
+        let current = next_revision(revision);
+        let auth = auth.get_value();
+
+        save_state.set(SaveState::Saving);
+
+        spawn_local(async move {
+            let result = auth.update_timezone(&request).await;
+
+            if let Ok(preference) = &result {
+                saved.set_value(preference.timezone.clone());
+                DateTimeUtil::set_timezone(preference.timezone.clone());
+            }
+
+            if revision.get_value() != current {
+                return;
+            }
+
+            match result {
+                Ok(_) => save_state.set(SaveState::Saved),
+                Err(error) => {
+                    selected.set(saved.get_value().map(timezone_option));
+                    save_state.set(SaveState::SaveFailed);
+                    notifications.show_error("Failed to save timezone", error.to_string());
+                }
+            }
+        });
+    });
+
+    // Variables
+    let placeholder = match DateTimeUtil::browser_timezone() {
+        Some(timezone) => format!("Browser timezone ({timezone})"),
+        None => "Browser timezone".to_string(),
+    };
+    let options = DateTimeUtil::supported_timezones()
+        .into_iter()
+        .map(timezone_option)
+        .collect::<Vec<_>>();
+
+    view! {
+        <section class="settings-page__section" aria-labelledby="settings-timezone">
+            <div class="settings-page__heading">
+                <h3 id="settings-timezone">"Timezone"</h3>
+                <SaveStatus state=save_state />
+            </div>
+            <SelectInput
+                label="Show times in"
+                placeholder=placeholder
+                options=options
+                selected_option=selected
+            />
+        </section>
+    }
+}
+
+/// Renders a section's save state.
+///
+/// # Arguments
+///
+/// * `state` — Signal containing the section's [`SaveState`].
+///
+/// # Returns
+///
+/// A Leptos view containing the status text.
+#[component]
+fn SaveStatus(#[prop(into)] state: Signal<SaveState>) -> impl IntoView {
+    let class = move || match state.get() {
+        SaveState::LoadFailed | SaveState::SaveFailed => {
+            "settings-page__status settings-page__status--error"
+        }
+        _ => "settings-page__status",
+    };
+
+    view! {
+        <p class=class role="status">
+            {move || state.get().label()}
+        </p>
+    }
+}
+
+/// Progress of loading and saving one settings section.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SaveState {
+    /// The saved value is being requested.
+    Loading,
+    /// The saved value could not be requested.
+    LoadFailed,
+    /// The saved value is shown and unchanged.
+    Idle,
+    /// A change is being saved.
+    Saving,
+    /// The latest change was saved.
+    Saved,
+    /// The latest change failed and the saved value was put back.
+    SaveFailed,
+}
+
+impl SaveState {
+    /// Returns the status text shown next to the section heading.
+    ///
+    /// # Returns
+    ///
+    /// A short status such as `Saving...`, or an empty string when idle.
+    fn label(&self) -> &'static str {
+        match self {
+            SaveState::Loading => "Loading...",
+            SaveState::LoadFailed => "Could not load",
+            SaveState::Idle => "",
+            SaveState::Saving => "Saving...",
+            SaveState::Saved => "Saved",
+            SaveState::SaveFailed => "Not saved",
+        }
+    }
+
+    /// Returns whether the section's value can be changed.
+    ///
+    /// # Returns
+    ///
+    /// `true` once the saved value has loaded.
+    fn is_ready(&self) -> bool {
+        !matches!(self, SaveState::Loading | SaveState::LoadFailed)
+    }
+}
+
+/// Starts a new save, so responses to earlier saves are ignored.
+///
+/// # Arguments
+///
+/// * `revision` — The section's save counter.
+///
+/// # Returns
+///
+/// The revision of the new save.
+fn next_revision(revision: StoredValue<u32>) -> u32 {
+    revision.update_value(|revision| *revision = revision.wrapping_add(1));
+    revision.get_value()
+}
+
+/// Builds the select option for a locale.
+///
+/// # Arguments
+///
+/// * `locale` — The locale to show.
+///
+/// # Returns
+///
+/// A [`SelectOption`] labeled with the language's own name.
+fn locale_option(locale: Locale) -> SelectOption<Locale> {
+    let label = match locale {
+        Locale::En => "English",
+        Locale::Es => "Español",
+        Locale::Fr => "Français",
+    };
+
+    SelectOption::new(locale, label)
+}
+
+/// Builds the select option for a timezone.
+///
+/// # Arguments
+///
+/// * `timezone` — The IANA timezone name.
+///
+/// # Returns
+///
+/// A [`SelectOption`] labeled with the timezone name.
+fn timezone_option(timezone: String) -> SelectOption<String> {
+    SelectOption::new(timezone.clone(), timezone)
+}
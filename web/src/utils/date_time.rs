@@ -0,0 +1,201 @@
+//! Locale-aware date and time formatting helpers.
+//!
+//! Formatting is delegated to the browser's `Intl` support, so output
+//! follows the browser's language. Timestamps are shown in the user's
+//! timezone preference, or in the browser's timezone when it is unset.
+
+use std::cell::RefCell;
+
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use js_sys::{Array, Date, Function, Intl, Object, Reflect};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::window;
+
+/// Locale used when the browser does not report a language.
+const FALLBACK_LOCALE: &str = "en-US";
+
+thread_local! {
+    /// The user's preferred IANA timezone, or `None` to use the browser's.
+    static TIMEZONE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Provides date and time formatting in the user's locale and timezone.
+pub struct DateTimeUtil;
+
+impl DateTimeUtil {
+    /// Sets the timezone timestamps are shown in.
+    ///
+    /// A timezone the browser cannot format times in is treated as unset.
+    ///
+    /// # Arguments
+    ///
+    /// * `timezone` — The user's IANA timezone preference, or `None` to use
+    ///   the browser's timezone.
+    pub fn set_timezone(timezone: Option<String>) {
+        let timezone = timezone.filter(|timezone| Self::is_supported_timezone(timezone));
+
+        TIMEZONE.with(|current| *current.borrow_mut() = timezone);
+    }
+
+    /// Returns the browser's timezone.
+    ///
+    /// # Returns
+    ///
+    /// The IANA timezone name such as `America/Chicago`, or `None` if the
+    /// browser does not report one.
+    pub fn browser_timezone() -> Option<String> {
+        let options = Intl::DateTimeFormat::new(&Array::new(), &Object::new()).resolved_options();
+
+        Reflect::get(&options, &"timeZone".into())
+            .ok()
+            .and_then(|timezone| timezone.as_string())
+    }
+
+    /// Lists the timezones the browser can format times in.
+    ///
+    /// # Returns
+    ///
+    /// IANA timezone names in the browser's order.
+    pub fn supported_timezones() -> Vec<String> {
+        Intl::supported_values_of(Intl::SupportedValuesKey::TimeZone)
+            .iter()
+            .map(String::from)
+            .collect()
+    }
+
+    /// Formats a timestamp in the user's timezone.
+    ///
+    /// Uses the timezone set with [`Self::set_timezone`], falling back to
+    /// the browser's timezone when none is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` — UTC timestamp to format.
+    ///
+    /// # Returns
+    ///
+    /// A [`String`] such as `Mar 2, 2026, 3:15 PM EST`, including the
+    /// timezone abbreviation.
+    pub fn format_timestamp(timestamp: &DateTime<Utc>) -> String {
+        let date = Date::new(&JsValue::from_f64(timestamp.timestamp_millis() as f64));
+        let options = Self::options(&[
+            ("year", "numeric"),
+            ("month", "short"),
+            ("day", "numeric"),
+            ("hour", "numeric"),
+            ("minute", "2-digit"),
+            ("timeZoneName", "short"),
+        ]);
+
+        if let Some(timezone) = TIMEZONE.with(|timezone| timezone.borrow().clone()) {
+            let _ = Reflect::set(&options, &"timeZone".into(), &timezone.into());
+        }
+
+        date.to_locale_string(&Self::locale(), &options).into()
+    }
+
+    /// Formats a calendar date.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` — Calendar date to format.
+    ///
+    /// # Returns
+    ///
+    /// A [`String`] such as `Mar 2, 2026`.
+    pub fn format_date(date: NaiveDate) -> String {
+        Self::format_calendar_date(
+            date,
+            &[("year", "numeric"), ("month", "short"), ("day", "numeric")],
+        )
+    }
+
+    /// Formats the month and year of a calendar date.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` — Calendar date in the month to format.
+    ///
+    /// # Returns
+    ///
+    /// A [`String`] such as `March 2026`.
+    pub fn format_month(date: NaiveDate) -> String {
+        Self::format_calendar_date(date, &[("year", "numeric"), ("month", "long")])
+    }
+
+    /// Formats a calendar date without shifting it into another timezone.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` — Calendar date to format.
+    /// * `fields` — `Intl.DateTimeFormat` option names and values.
+    ///
+    /// # Returns
+    ///
+    /// The formatted date [`String`].
+    fn format_calendar_date(date: NaiveDate, fields: &[(&str, &str)]) -> String {
+        let millis = date.and_time(NaiveTime::MIN).and_utc().timestamp_millis();
+        let date = Date::new(&JsValue::from_f64(millis as f64));
+        let options = Self::options(fields);
+
+        // Calendar dates are pinned to UTC midnight, so format them in UTC
+        // to keep the day from moving in timezones behind UTC.
+        let _ = Reflect::set(&options, &"timeZone".into(), &"UTC".into());
+
+        date.to_locale_date_string(&Self::locale(), &options).into()
+    }
+
+    /// Returns whether the browser can format times in a timezone.
+    ///
+    /// # Arguments
+    ///
+    /// * `timezone` — IANA timezone name to check.
+    ///
+    /// # Returns
+    ///
+    /// `true` if `Intl.DateTimeFormat` accepts the timezone.
+    fn is_supported_timezone(timezone: &str) -> bool {
+        let constructor = Reflect::get(&js_sys::global(), &"Intl".into())
+            .and_then(|intl| Reflect::get(&intl, &"DateTimeFormat".into()));
+        let Ok(constructor) = constructor else {
+            return false;
+        };
+        let options = Self::options(&[("timeZone", timezone)]);
+
+        Reflect::construct(
+            constructor.unchecked_ref::<Function>(),
+            &Array::of2(&JsValue::UNDEFINED, &options),
+        )
+        .is_ok()
+    }
+
+    /// Builds an `Intl.DateTimeFormat` options object.
+    ///
+    /// # Arguments
+    ///
+    /// * `fields` — Option names and values.
+    ///
+    /// # Returns
+    ///
+    /// A JavaScript [`Object`] containing the options.
+    fn options(fields: &[(&str, &str)]) -> Object {
+        let options = Object::new();
+
+        for (name, value) in fields {
+            let _ = Reflect::set(&options, &(*name).into(), &(*value).into());
+        }
+
+        options
+    }
+
+    /// Returns the browser's preferred language.
+    ///
+    /// # Returns
+    ///
+    /// A BCP 47 language tag such as `en-US`.
+    fn locale() -> String {
+        window()
+            .and_then(|window| window.navigator().language())
+            .unwrap_or_else(|| FALLBACK_LOCALE.to_string())
+    }
+}
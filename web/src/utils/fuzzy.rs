@@ -0,0 +1,89 @@
+//! Fuzzy text matching helpers for pickers.
+
+use std::cmp::Reverse;
+
+/// Bonus for a query character that directly follows the previous match.
+const CONSECUTIVE_BONUS: i32 = 5;
+/// Bonus for a query character that starts a word.
+const WORD_START_BONUS: i32 = 3;
+/// Largest penalty applied for skipped characters before a match.
+const MAX_GAP_PENALTY: usize = 3;
+
+/// Provides case-insensitive subsequence matching and ranking.
+pub struct FuzzyUtil;
+
+impl FuzzyUtil {
+    /// Scores how well a query matches a piece of text.
+    ///
+    /// Every non-whitespace query character must appear in the text in
+    /// order. Matches that are consecutive or start words score higher, and
+    /// skipped characters lower the score.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` — Text typed by the user.
+    /// * `text` — Candidate text to match against.
+    ///
+    /// # Returns
+    ///
+    /// An optional score, higher for better matches. `None` when the text
+    /// does not contain the query. A blank query matches everything with a
+    /// score of `0`.
+    pub fn score(query: &str, text: &str) -> Option<i32> {
+        let text = text.to_lowercase().chars().collect::<Vec<_>>();
+        let mut score = 0;
+        let mut position = 0;
+        let mut previous: Option<usize> = None;
+
+        for query_char in query
+            .to_lowercase()
+            .chars()
+            .filter(|query_char| !query_char.is_whitespace())
+        {
+            let index = position
+                + text[position..]
+                    .iter()
+                    .position(|text_char| *text_char == query_char)?;
+            let gap = index - previous.map_or(0, |previous| previous + 1);
+
+            score += 1;
+            score -= gap.min(MAX_GAP_PENALTY) as i32;
+
+            if gap == 0 && previous.is_some() {
+                score += CONSECUTIVE_BONUS;
+            }
+
+            if index == 0 || !text[index - 1].is_alphanumeric() {
+                score += WORD_START_BONUS;
+            }
+
+            previous = Some(index);
+            position = index + 1;
+        }
+
+        Some(score)
+    }
+
+    /// Filters items to those matching a query, best matches first.
+    ///
+    /// Items with equal scores keep their original order.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` — Text typed by the user.
+    /// * `items` — Candidate items.
+    /// * `text` — Returns the text of an item to match against.
+    ///
+    /// # Returns
+    ///
+    /// The matching items, sorted by descending score.
+    pub fn rank<T>(query: &str, items: Vec<T>, text: impl Fn(&T) -> &str) -> Vec<T> {
+        let mut scored = items
+            .into_iter()
+            .filter_map(|item| Self::score(query, text(&item)).map(|score| (score, item)))
+            .collect::<Vec<_>>();
+
+        scored.sort_by_key(|(score, _)| Reverse(*score));
+        scored.into_iter().map(|(_, item)| item).collect()
+    }
+}
@@ -2,3 +2,7 @@
 
 /// Provides CSS class string composition helpers.
 pub mod class_name;
+/// Provides locale-aware date and time formatting helpers.
+pub mod date_time;
+/// Provides fuzzy text matching helpers.
+pub mod fuzzy;